@@ -0,0 +1,97 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use crate::CommandExecute;
+use eyre::{eyre, WrapErr};
+use owo_colors::OwoColorize;
+use pgrx_pg_config::get_target_dir;
+use std::os::unix::process::CommandExt;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Attach a debugger to a Postgres backend, by default the one `cargo pgrx test --wait-for-debugger` is waiting on
+#[derive(clap::Args, Debug)]
+#[clap(author)]
+pub(crate) struct Debug {
+    /// The PID of the backend to attach to
+    pid: Option<u32>,
+    /// Use `lldb` rather than `gdb`
+    #[clap(long)]
+    lldb: bool,
+    #[clap(from_global, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+impl CommandExecute for Debug {
+    #[tracing::instrument(level = "error", skip(self))]
+    fn execute(self) -> eyre::Result<()> {
+        let pid = match self.pid {
+            Some(pid) => pid,
+            None => waiting_test_backend()?,
+        };
+
+        // Postgres uses SIGUSR1 for all sorts of inter-process signalling, which would otherwise
+        // constantly stop the debugger, and Rust panics all pass through `rust_panic`
+        let mut command = if self.lldb {
+            let mut command = Command::new("lldb");
+            command
+                .arg("-p")
+                .arg(pid.to_string())
+                .arg("-o")
+                .arg("process handle SIGUSR1 --notify false --pass true --stop false")
+                .arg("-o")
+                .arg("breakpoint set --name rust_panic")
+                .arg("-o")
+                .arg("continue");
+            command
+        } else {
+            let mut command = Command::new("gdb");
+            command
+                .arg("-p")
+                .arg(pid.to_string())
+                .arg("-ex")
+                .arg("handle SIGUSR1 noprint nostop pass")
+                .arg("-ex")
+                .arg("break rust_panic")
+                .arg("-ex")
+                .arg("continue");
+            command
+        };
+
+        println!("{} to backend PID {}", "   Attaching".bold().green(), pid.to_string().cyan());
+
+        // we'll never return from here as we've now become the debugger
+        Err(command.exec()).wrap_err("failed to run the debugger")
+    }
+}
+
+/// The PID most recently written by a test waiting for a debugger
+fn waiting_test_backend() -> eyre::Result<u32> {
+    let target_dir = get_target_dir()?;
+    let mut pid_files = std::fs::read_dir(&target_dir)
+        .wrap_err_with(|| format!("failed to read `{}`", target_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with("pgrx-test-backend-") && name.ends_with(".pid"))
+                .unwrap_or(false)
+        })
+        .filter_map(|path| Some((std::fs::metadata(&path).ok()?.modified().ok()?, path)))
+        .collect::<Vec<(std::time::SystemTime, PathBuf)>>();
+    pid_files.sort();
+
+    let (_, pid_file) = pid_files.pop().ok_or_else(|| {
+        eyre!("no test is waiting for a debugger, run `cargo pgrx test --wait-for-debugger` first")
+    })?;
+    let pid = std::fs::read_to_string(&pid_file)
+        .wrap_err_with(|| format!("failed to read `{}`", pid_file.display()))?;
+    pid.trim().parse().wrap_err_with(|| format!("invalid PID in `{}`", pid_file.display()))
+}
@@ -0,0 +1,146 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use crate::manifest::{get_package_manifest, pg_config_and_version};
+use crate::CommandExecute;
+use eyre::{eyre, WrapErr};
+use pgrx_pg_config::Pgrx;
+use std::path::PathBuf;
+
+/// Print the features and environment `cargo pgrx test` builds with, so that other tools, like
+/// rust-analyzer and clippy, check the code with the same configuration
+#[derive(clap::Args, Debug)]
+#[clap(author)]
+pub(crate) struct Env {
+    /// Do you want the configuration for Postgres `pg11`, `pg12`, `pg13`, `pg14`, `pg15`?
+    #[clap(env = "PG_VERSION")]
+    pg_version: Option<String>,
+    /// Package to determine default `pg_version` with (see `cargo help pkgid`)
+    #[clap(long, short)]
+    package: Option<String>,
+    /// Path to Cargo.toml
+    #[clap(long, value_parser)]
+    manifest_path: Option<PathBuf>,
+    /// Print the settings for rust-analyzer, as JSON for an editor's settings, instead of shell
+    /// `export`s
+    #[clap(long)]
+    rust_analyzer: bool,
+    /// Write to this file instead of printing
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+    #[clap(flatten)]
+    features: clap_cargo::Features,
+    #[clap(from_global, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+impl CommandExecute for Env {
+    #[tracing::instrument(level = "error", skip(self))]
+    fn execute(mut self) -> eyre::Result<()> {
+        let pgrx = Pgrx::from_config()?;
+        let (package_manifest, _package_manifest_path) = get_package_manifest(
+            &self.features,
+            self.package.as_ref(),
+            self.manifest_path.as_ref(),
+        )?;
+        let (pg_config, _pg_version) = pg_config_and_version(
+            &pgrx,
+            &package_manifest,
+            self.pg_version.clone(),
+            Some(&mut self.features),
+            false,
+        )?;
+
+        // as `cargo pgrx test` builds with
+        if self.features.features.iter().all(|f| f != "pg_test") {
+            self.features.features.push("pg_test".to_string());
+        }
+        let pg_config_path = pg_config.path().ok_or(eyre!("no path for pg_config"))?;
+        let rustflags = crate::env::host_rustflags()?;
+
+        let output = if self.rust_analyzer {
+            rust_analyzer_settings(&self.features, &pg_config_path, &rustflags)?
+        } else {
+            shell_exports(&self.features, &pg_config_path, &rustflags)
+        };
+        match self.output {
+            Some(path) => std::fs::write(&path, output)
+                .wrap_err_with(|| format!("failed writing `{}`", path.display())),
+            None => {
+                print!("{output}");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The arguments `cargo` needs to build with `features`
+fn cargo_args(features: &clap_cargo::Features) -> Vec<String> {
+    let mut args = Vec::new();
+    if features.no_default_features {
+        args.push("--no-default-features".to_string());
+    }
+    if features.all_features {
+        args.push("--all-features".to_string());
+    }
+    if !features.features.is_empty() {
+        // feature names can't contain commas, or whitespace, so this survives word splitting
+        args.push("--features".to_string());
+        args.push(features.features.join(","));
+    }
+    args
+}
+
+fn shell_exports(
+    features: &clap_cargo::Features,
+    pg_config_path: &PathBuf,
+    rustflags: &[String],
+) -> String {
+    fn quote(value: &str) -> String {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+
+    let mut exports = String::from(
+        "# use with `cargo check $PGRX_CARGO_ARGS`, `cargo clippy $PGRX_CARGO_ARGS`, etc\n",
+    );
+    exports.push_str(&format!(
+        "export PGRX_PG_CONFIG_PATH={}\n",
+        quote(&pg_config_path.display().to_string())
+    ));
+    exports
+        .push_str(&format!("export PGRX_CARGO_ARGS={}\n", quote(&cargo_args(features).join(" "))));
+    if !rustflags.is_empty() {
+        exports.push_str(&format!("export RUSTFLAGS={}\n", quote(&rustflags.join(" "))));
+    }
+    exports
+}
+
+fn rust_analyzer_settings(
+    features: &clap_cargo::Features,
+    pg_config_path: &PathBuf,
+    rustflags: &[String],
+) -> eyre::Result<String> {
+    let mut extra_env = serde_json::Map::new();
+    extra_env.insert("PGRX_PG_CONFIG_PATH".into(), pg_config_path.display().to_string().into());
+    if !rustflags.is_empty() {
+        extra_env.insert("RUSTFLAGS".into(), rustflags.join(" ").into());
+    }
+
+    let cargo_features = if features.all_features {
+        serde_json::json!("all")
+    } else {
+        serde_json::json!(features.features)
+    };
+    let settings = serde_json::json!({
+        "rust-analyzer.cargo.noDefaultFeatures": features.no_default_features,
+        "rust-analyzer.cargo.features": cargo_features,
+        "rust-analyzer.cargo.extraEnv": extra_env,
+    });
+    Ok(serde_json::to_string_pretty(&settings)? + "\n")
+}
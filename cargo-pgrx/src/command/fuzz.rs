@@ -0,0 +1,639 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use crate::command::get::get_property;
+use crate::command::init::InitdbArgs;
+use crate::command::install::install_extension;
+use crate::command::start::start_postgres;
+use crate::command::stop::stop_postgres;
+use crate::manifest::{get_package_manifest, pg_config_and_version};
+use crate::profile::CargoProfile;
+use crate::CommandExecute;
+use eyre::{eyre, WrapErr};
+use owo_colors::OwoColorize;
+use pgrx_pg_config::{createdb, PgConfig, Pgrx};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::process::{Command, Output};
+use std::time::{Duration, Instant};
+
+/// Run random queries against the extension's functions and types, looking for crashes
+#[derive(clap::Args, Debug)]
+#[clap(author)]
+pub(crate) struct Fuzz {
+    /// Do you want to run against Postgres `pg11`, `pg12`, `pg13`, `pg14`, `pg15`?
+    #[clap(env = "PG_VERSION")]
+    pg_version: Option<String>,
+    /// The database to fuzz in, which is dropped and recreated.  Defaults to `<extname>_fuzz`
+    #[clap(long)]
+    dbname: Option<String>,
+    /// Package to build (see `cargo help pkgid`)
+    #[clap(long, short)]
+    package: Option<String>,
+    /// Path to Cargo.toml
+    #[clap(long)]
+    manifest_path: Option<String>,
+    /// Compile for release mode (default is debug)
+    #[clap(long, short)]
+    release: bool,
+    /// Specific profile to use (conflicts with `--release`)
+    #[clap(long)]
+    profile: Option<String>,
+    #[clap(flatten)]
+    features: clap_cargo::Features,
+    #[clap(from_global, action = ArgAction::Count)]
+    verbose: u8,
+    /// How many random queries to run
+    #[clap(long, short = 'n', default_value_t = 1000)]
+    queries: usize,
+    /// Seed for the query generator.  Use the seed printed by an earlier run to repeat its queries
+    #[clap(long)]
+    seed: Option<u64>,
+    /// Cancel queries that run for longer than this many milliseconds
+    #[clap(long, default_value_t = 5000)]
+    statement_timeout: u64,
+    /// Stop after this many queries have crashed the backend
+    #[clap(long, default_value_t = 1)]
+    max_crashes: usize,
+    #[clap(flatten)]
+    initdb: InitdbArgs,
+}
+
+impl CommandExecute for Fuzz {
+    #[tracing::instrument(level = "error", skip(self))]
+    fn execute(mut self) -> eyre::Result<()> {
+        let pgrx = Pgrx::from_config()?;
+        let (package_manifest, package_manifest_path) = get_package_manifest(
+            &self.features,
+            self.package.as_ref(),
+            self.manifest_path.as_ref(),
+        )?;
+        let (pg_config, _pg_version) = pg_config_and_version(
+            &pgrx,
+            &package_manifest,
+            self.pg_version.clone(),
+            Some(&mut self.features),
+            true,
+        )?;
+        self.initdb.save(&pg_config)?;
+
+        let extname = get_property(&package_manifest_path, "extname")?
+            .ok_or(eyre!("could not determine extension name"))?;
+        let dbname = self.dbname.clone().unwrap_or_else(|| format!("{extname}_fuzz"));
+        let profile = CargoProfile::from_flags(
+            self.profile.as_deref(),
+            self.release.then_some(CargoProfile::Release).unwrap_or(CargoProfile::Dev),
+        )?;
+        let seed = self.seed.unwrap_or_else(|| {
+            use std::time::{SystemTime, UNIX_EPOCH};
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+            now.as_nanos() as u64 ^ std::process::id() as u64
+        });
+
+        stop_postgres(&pg_config)?;
+        install_extension(
+            self.manifest_path.as_ref(),
+            self.package.as_ref(),
+            &package_manifest_path,
+            &pg_config,
+            &profile,
+            false,
+            None,
+            &self.features,
+        )?;
+        start_postgres(&pg_config)?;
+
+        let fuzzer = Fuzzer {
+            pg_config: &pg_config,
+            dbname: &dbname,
+            statement_timeout: self.statement_timeout,
+        };
+        fuzzer.create_database(&extname)?;
+        let schema = fuzzer.load_schema(&extname)?;
+        if schema.functions.is_empty() {
+            return Err(eyre!("extension `{extname}` has no functions that can be fuzzed"));
+        }
+
+        println!(
+            "{} {} functions with {} queries and seed {seed} (rerun with `--seed={seed}`)",
+            "     Fuzzing".bold().green(),
+            schema.functions.len(),
+            self.queries,
+        );
+        let mut generator = QueryGenerator::new(&schema, seed);
+        let mut stats = Stats::default();
+        for n in 1..=self.queries {
+            let query = generator.query();
+            match fuzzer.run(&query)? {
+                Outcome::Ok => stats.ok += 1,
+                Outcome::Error => stats.errors += 1,
+                Outcome::Timeout => {
+                    println!("{} query {n} timed out:\n{query}\n", "     Timeout".bold().yellow());
+                    stats.timeouts += 1;
+                }
+                Outcome::Crash(stderr) => {
+                    println!(
+                        "{} query {n} crashed the backend:\n{query}\n\n{}",
+                        "       Crash".bold().red(),
+                        stderr.trim_end()
+                    );
+                    stats.crashes.push(query);
+                    if stats.crashes.len() >= self.max_crashes {
+                        break;
+                    }
+                    fuzzer.wait_for_recovery()?;
+                }
+            }
+        }
+
+        println!(
+            "{} {} succeeded, {} raised errors, {} timed out, {} crashed",
+            "    Finished".bold().green(),
+            stats.ok,
+            stats.errors,
+            stats.timeouts,
+            stats.crashes.len(),
+        );
+        match stats.crashes.len() {
+            0 => Ok(()),
+            n => Err(eyre!(
+                "{n} queries crashed the backend, see {} for details",
+                pg_config.log_file()?.display()
+            )),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    ok: usize,
+    errors: usize,
+    timeouts: usize,
+    crashes: Vec<String>,
+}
+
+enum Outcome {
+    Ok,
+    /// The query raised an ERROR, which is fine: most random calls are nonsense
+    Error,
+    Timeout,
+    /// The backend died, with what psql printed
+    Crash(String),
+}
+
+struct Fuzzer<'a> {
+    pg_config: &'a PgConfig,
+    dbname: &'a str,
+    statement_timeout: u64,
+}
+
+impl Fuzzer<'_> {
+    fn psql(&self, dbname: &str) -> eyre::Result<Command> {
+        let mut command = Command::new(self.pg_config.psql_path()?);
+        command
+            .env_remove("PGDATABASE")
+            .env_remove("PGHOST")
+            .env_remove("PGPORT")
+            .env_remove("PGUSER")
+            .env("PGOPTIONS", format!("-c statement_timeout={}", self.statement_timeout))
+            .arg("-XqAt")
+            .arg("-v")
+            .arg("ON_ERROR_STOP=1")
+            .arg("-h")
+            .arg(self.pg_config.host())
+            .arg("-p")
+            .arg(self.pg_config.port()?.to_string())
+            .arg(dbname);
+        Ok(command)
+    }
+
+    fn exec(&self, dbname: &str, sql: &str) -> eyre::Result<String> {
+        let output = self.psql(dbname)?.arg("-c").arg(sql).output()?;
+        if !output.status.success() {
+            return Err(eyre!("{}", String::from_utf8_lossy(&output.stderr).trim_end()));
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+
+    /// A fresh database with nothing but the extension in it
+    fn create_database(&self, extname: &str) -> eyre::Result<()> {
+        self.exec("template1", &format!("DROP DATABASE IF EXISTS \"{}\"", self.dbname))?;
+        createdb(self.pg_config, self.dbname, false, false)?;
+        self.exec(self.dbname, &format!("CREATE EXTENSION \"{extname}\" CASCADE"))
+            .wrap_err_with(|| format!("failed to create extension `{extname}`"))?;
+        Ok(())
+    }
+
+    fn load_schema(&self, extname: &str) -> eyre::Result<Schema> {
+        let types = self.exec(self.dbname, &TYPES_QUERY.replace("$1", &quote_literal(extname)))?;
+        let types = types
+            .lines()
+            .map(|line| {
+                let mut fields = line.split(FIELD_SEP);
+                let name = fields.next().unwrap_or_default().to_string();
+                let labels = fields
+                    .next()
+                    .unwrap_or_default()
+                    .split(LIST_SEP)
+                    .filter(|label| !label.is_empty())
+                    .map(quote_literal)
+                    .collect();
+                (name, labels)
+            })
+            .collect();
+
+        let functions =
+            self.exec(self.dbname, &FUNCTIONS_QUERY.replace("$1", &quote_literal(extname)))?;
+        let functions = functions
+            .lines()
+            .filter_map(|line| {
+                let fields = line.split(FIELD_SEP).collect::<Vec<_>>();
+                let [name, kind, args, ndefaults, variadic, returns, retset] = fields[..] else {
+                    return None;
+                };
+                let args = args
+                    .split(LIST_SEP)
+                    .filter(|arg| !arg.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>();
+                // support functions for types, indexes, and the like can't be called from SQL
+                if args.iter().any(|arg| UNCALLABLE.contains(&arg.as_str())) {
+                    return None;
+                }
+                Some(Function {
+                    name: name.to_string(),
+                    kind: match kind {
+                        "a" => FunctionKind::Aggregate,
+                        "p" => FunctionKind::Procedure,
+                        "w" => return None,
+                        _ => FunctionKind::Function,
+                    },
+                    args,
+                    ndefaults: ndefaults.parse().unwrap_or(0),
+                    variadic: variadic == "t",
+                    returns: returns.to_string(),
+                    retset: retset == "t",
+                })
+            })
+            .collect();
+
+        Ok(Schema { functions, enums: types })
+    }
+
+    fn run(&self, query: &str) -> eyre::Result<Outcome> {
+        let Output { status, stderr, .. } =
+            self.psql(self.dbname)?.arg("-c").arg(query).output()?;
+        let stderr = String::from_utf8_lossy(&stderr).into_owned();
+        Ok(match status.code() {
+            Some(0) => Outcome::Ok,
+            // psql's exit code for a lost connection
+            Some(2) => Outcome::Crash(stderr),
+            _ if stderr.contains("server closed the connection unexpectedly") => {
+                Outcome::Crash(stderr)
+            }
+            _ if stderr.contains("canceling statement due to statement timeout") => {
+                Outcome::Timeout
+            }
+            _ => Outcome::Error,
+        })
+    }
+
+    /// After a backend crashes the postmaster restarts every other one, and recovers, before it
+    /// accepts connections again
+    fn wait_for_recovery(&self) -> eyre::Result<()> {
+        let start = Instant::now();
+        while self.exec(self.dbname, "SELECT 1").is_err() {
+            if start.elapsed() > Duration::from_secs(60) {
+                return Err(eyre!("Postgres did not recover from the crash within a minute"));
+            }
+            std::thread::sleep(Duration::from_millis(250));
+        }
+        Ok(())
+    }
+}
+
+const FIELD_SEP: char = '\u{1f}';
+const LIST_SEP: char = '\u{1e}';
+
+/// Pseudo-types that only Postgres itself can pass to a function
+const UNCALLABLE: &[&str] = &[
+    "internal",
+    "cstring",
+    "trigger",
+    "event_trigger",
+    "language_handler",
+    "fdw_handler",
+    "index_am_handler",
+    "tsm_handler",
+    "table_am_handler",
+];
+
+/// Every type the extension created, along with the labels of the enums
+const TYPES_QUERY: &str = "\
+    SELECT format_type(t.oid, NULL), \
+        coalesce((SELECT string_agg(e.enumlabel, E'\\x1e' ORDER BY e.enumsortorder) \
+                  FROM pg_enum e WHERE e.enumtypid = t.oid), '') \
+    FROM pg_depend d JOIN pg_type t ON t.oid = d.objid \
+    WHERE d.classid = 'pg_type'::regclass AND d.refclassid = 'pg_extension'::regclass \
+        AND d.deptype = 'e' AND d.refobjid = (SELECT oid FROM pg_extension WHERE extname = $1)";
+
+/// Every function the extension created
+const FUNCTIONS_QUERY: &str = "\
+    SELECT quote_ident(n.nspname) || '.' || quote_ident(p.proname), p.prokind, \
+        array_to_string(ARRAY(SELECT format_type(a.t, NULL) \
+                              FROM unnest(p.proargtypes) WITH ORDINALITY a(t, i) ORDER BY a.i), \
+                        E'\\x1e'), \
+        p.pronargdefaults, p.provariadic <> 0, format_type(p.prorettype, NULL), p.proretset \
+    FROM pg_depend d JOIN pg_proc p ON p.oid = d.objid JOIN pg_namespace n ON n.oid = p.pronamespace \
+    WHERE d.classid = 'pg_proc'::regclass AND d.refclassid = 'pg_extension'::regclass \
+        AND d.deptype = 'e' AND d.refobjid = (SELECT oid FROM pg_extension WHERE extname = $1) \
+    ORDER BY 1";
+
+fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[derive(Debug)]
+struct Schema {
+    functions: Vec<Function>,
+    /// The extension's enums, by type name, with their quoted labels
+    enums: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Function,
+    Aggregate,
+    Procedure,
+}
+
+#[derive(Debug)]
+struct Function {
+    /// Schema-qualified and quoted
+    name: String,
+    kind: FunctionKind,
+    args: Vec<String>,
+    /// How many of the trailing `args` have defaults
+    ndefaults: usize,
+    variadic: bool,
+    returns: String,
+    retset: bool,
+}
+
+/// Builds random, sqlsmith-style queries over a [`Schema`]: calls with edge-case literals, NULLs,
+/// and the results of other calls as arguments
+struct QueryGenerator<'a> {
+    schema: &'a Schema,
+    rng: StdRng,
+}
+
+/// How deeply calls are nested inside of one another's arguments
+const MAX_DEPTH: usize = 3;
+
+impl<'a> QueryGenerator<'a> {
+    fn new(schema: &'a Schema, seed: u64) -> Self {
+        QueryGenerator { schema, rng: StdRng::seed_from_u64(seed) }
+    }
+
+    fn query(&mut self) -> String {
+        let function = self.schema.functions.choose(&mut self.rng).unwrap();
+        match function.kind {
+            FunctionKind::Procedure => format!("CALL {}", self.call(function, 0)),
+            FunctionKind::Aggregate => self.aggregate(function),
+            FunctionKind::Function if function.retset => {
+                format!("SELECT * FROM {} LIMIT 100", self.call(function, 0))
+            }
+            FunctionKind::Function => match self.rng.gen_range(0..4) {
+                // the same call over a few rows, which exercises any per-call caching
+                0 if !function.args.is_empty() => {
+                    let rows = (0..self.rng.gen_range(2..6))
+                        .map(|_| format!("({})", self.value(&function.args[0], 0)))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let mut args = self.args(function, 0);
+                    args[0] = "v.x".into();
+                    format!(
+                        "SELECT {}({}) FROM (VALUES {rows}) v(x)",
+                        function.name,
+                        args.join(", ")
+                    )
+                }
+                _ => format!("SELECT {}", self.call(function, 0)),
+            },
+        }
+    }
+
+    fn aggregate(&mut self, function: &Function) -> String {
+        let rows = self.rng.gen_range(0..8);
+        let columns = (0..function.args.len()).map(|i| format!("x{i}")).collect::<Vec<_>>();
+        if columns.is_empty() {
+            return format!("SELECT {}(*) FROM generate_series(1, {rows})", function.name);
+        }
+        let rows = (0..rows.max(1))
+            .map(|_| {
+                let values = function
+                    .args
+                    .iter()
+                    .map(|arg| self.value(arg, 0))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("({values})")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "SELECT {}({}) FROM (VALUES {rows}) v({})",
+            function.name,
+            columns.join(", "),
+            columns.join(", ")
+        )
+    }
+
+    fn call(&mut self, function: &Function, depth: usize) -> String {
+        format!("{}({})", function.name, self.args(function, depth).join(", "))
+    }
+
+    fn args(&mut self, function: &Function, depth: usize) -> Vec<String> {
+        let omitted = self.rng.gen_range(0..=function.ndefaults);
+        (0..function.args.len() - omitted)
+            .map(|i| {
+                let value = self.value(&function.args[i], depth);
+                if function.variadic && i == function.args.len() - 1 {
+                    format!("VARIADIC {value}")
+                } else {
+                    value
+                }
+            })
+            .collect()
+    }
+
+    /// An expression of type `ty`
+    fn value(&mut self, ty: &str, depth: usize) -> String {
+        if self.rng.gen_bool(0.1) {
+            return format!("NULL::{ty}");
+        }
+
+        // feed the results of the extension's own functions into one another
+        if depth < MAX_DEPTH && self.rng.gen_bool(0.25) {
+            let producers = self
+                .schema
+                .functions
+                .iter()
+                .filter(|f| f.kind == FunctionKind::Function && !f.retset && f.returns == ty)
+                .collect::<Vec<_>>();
+            if let Some(producer) = producers.choose(&mut self.rng) {
+                return format!("({})", self.call(producer, depth + 1));
+            }
+        }
+
+        if let Some(element) = ty.strip_suffix("[]") {
+            let len = *[0, 1, 3, 100].choose(&mut self.rng).unwrap();
+            let elements =
+                (0..len).map(|_| self.value(element, MAX_DEPTH)).collect::<Vec<_>>().join(", ");
+            return format!("ARRAY[{elements}]::{ty}");
+        }
+
+        if let Some(labels) = self.schema.enums.get(ty).filter(|labels| !labels.is_empty()) {
+            return format!("{}::{ty}", labels.choose(&mut self.rng).unwrap());
+        }
+
+        let literal = match ty {
+            "smallint" => self.integer(i16::MIN as i64, i16::MAX as i64),
+            "integer" | "anyelement" | "anynonarray" | "anycompatible" => {
+                self.integer(i32::MIN as i64, i32::MAX as i64)
+            }
+            "bigint" => self.integer(i64::MIN, i64::MAX),
+            "real" | "double precision" => self.float(),
+            "numeric" => self.pick(&["0", "'NaN'", "(-1.5)", "1e100", "1e-100"], |g| {
+                g.rng.gen::<f64>().to_string()
+            }),
+            "boolean" => self.rng.gen::<bool>().to_string(),
+            "text" | "character varying" | "character" | "name" | "\"char\"" => {
+                self.pick(&["''", "'a'", "'ß☃😀'", "repeat('x', 100000)", "E'\\t\\n'"], Self::text)
+            }
+            "bytea" => self
+                .pick(&["'\\x'", "'\\x00'", "decode(repeat('ab', 100000), 'hex')"], |g| {
+                    format!("'\\x{:x}'", g.rng.gen::<u64>())
+                }),
+            "json" | "jsonb" => self
+                .pick(&["'{}'", "'[]'", "'null'", "'1'", "'{\"a\": [1, {\"b\": null}]}'"], |_| {
+                    "'\"\"'".into()
+                }),
+            "date" | "timestamp without time zone" | "timestamp with time zone" => self.pick(
+                &["'infinity'", "'-infinity'", "'1970-01-01'", "'4713-01-01 BC'", "'2000-02-29'"],
+                |_| "now()".into(),
+            ),
+            "time without time zone" | "time with time zone" | "interval" => {
+                self.pick(&["'00:00'", "'23:59:59.999999'", "'24:00'"], |_| "'12:34:56'".into())
+            }
+            "uuid" => format!("'{:032x}'", self.rng.gen::<u128>()),
+            "anyarray" | "anycompatiblearray" => "ARRAY[1, NULL, 3]".into(),
+            // anything else, including the extension's own types, gets random text for its input
+            // function to make sense of
+            _ => self.text(),
+        };
+        format!("{literal}::{ty}")
+    }
+
+    /// One of the edge cases in `options` most of the time, otherwise a `random` value
+    fn pick(&mut self, options: &[&str], random: impl FnOnce(&mut Self) -> String) -> String {
+        match options.choose(&mut self.rng) {
+            Some(option) if self.rng.gen_bool(0.75) => option.to_string(),
+            _ => random(self),
+        }
+    }
+
+    fn integer(&mut self, min: i64, max: i64) -> String {
+        let value = match self.rng.gen_range(0..3) {
+            0 => *[0, 1, -1, min, max].choose(&mut self.rng).unwrap(),
+            1 => self.rng.gen_range(-100..100),
+            _ => self.rng.gen_range(min..=max),
+        };
+        // a negative literal needs parentheses to be cast
+        format!("({value})")
+    }
+
+    fn float(&mut self) -> String {
+        match self.rng.gen_range(0..3) {
+            0 => self
+                .pick(&["'NaN'", "'Infinity'", "'-Infinity'", "'-0'", "1e308", "1e-308"], |_| {
+                    "0".into()
+                }),
+            _ => format!("({:e})", self.rng.gen::<f64>() * 10f64.powi(self.rng.gen_range(-10..10))),
+        }
+    }
+
+    fn text(&mut self) -> String {
+        let len = self.rng.gen_range(0..32);
+        let s = (0..len)
+            .map(|_| match self.rng.gen_range(0..4) {
+                0 => *['\'', '"', '\\', '{', '}', '(', ')', ',', ' ', '-', '.', 'é', '☃']
+                    .choose(&mut self.rng)
+                    .unwrap(),
+                _ => self.rng.gen_range(' '..='~'),
+            })
+            .collect::<String>();
+        quote_literal(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> Schema {
+        let function = |name: &str, args: &[&str], returns: &str| Function {
+            name: name.into(),
+            kind: FunctionKind::Function,
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            ndefaults: 0,
+            variadic: false,
+            returns: returns.into(),
+            retset: false,
+        };
+        Schema {
+            functions: vec![
+                function("public.make_thing", &["integer", "text"], "thing"),
+                function("public.thing_size", &["thing"], "bigint"),
+                function("public.things", &["thing[]", "color"], "boolean"),
+            ],
+            enums: HashMap::from([("color".into(), vec!["'red'".into(), "'green'".into()])]),
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_queries() {
+        let schema = schema();
+        let mut first = QueryGenerator::new(&schema, 42);
+        let mut again = QueryGenerator::new(&schema, 42);
+        for _ in 0..100 {
+            assert_eq!(first.query(), again.query());
+        }
+    }
+
+    #[test]
+    fn test_queries_call_functions() {
+        let schema = schema();
+        let mut generator = QueryGenerator::new(&schema, 7);
+        for _ in 0..1000 {
+            let query = generator.query();
+            assert!(query.starts_with("SELECT "), "{query}");
+            assert!(query.contains("public."), "{query}");
+            if let Some(i) = query.find("::color") {
+                let before = &query[..i];
+                assert!(
+                    ["'red'", "'green'", "NULL"].iter().any(|v| before.ends_with(v)),
+                    "{query}"
+                );
+            }
+        }
+    }
+}
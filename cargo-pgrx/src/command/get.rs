@@ -8,7 +8,9 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 use crate::CommandExecute;
+use cargo_toml::Manifest;
 use eyre::{eyre, WrapErr};
+use pgrx_pg_config::cargo::PgrxManifestExt;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -84,6 +86,19 @@ pub fn get_property(manifest_path: impl AsRef<Path>, name: &str) -> eyre::Result
     Ok(None)
 }
 
+/// The name the crate is installed under: its extension's, or for a
+/// [module-only](PgrxManifestExt::is_module_only) crate, which has no control file, its
+/// [`extension_name`](PgrxManifestExt::extension_name)
+pub(crate) fn installed_name(manifest_path: impl AsRef<Path>) -> eyre::Result<String> {
+    let manifest = Manifest::from_path(manifest_path.as_ref())
+        .wrap_err_with(|| eyre!("could not parse `{}`", manifest_path.as_ref().display()))?;
+    if manifest.is_module_only() {
+        manifest.extension_name()
+    } else {
+        Ok(find_control_file(manifest_path)?.1)
+    }
+}
+
 pub(crate) fn find_control_file(
     manifest_path: impl AsRef<Path>,
 ) -> eyre::Result<(PathBuf, String)> {
@@ -92,6 +107,29 @@ pub(crate) fn find_control_file(
         .parent()
         .ok_or_else(|| eyre!("could not get parent of `{}`", manifest_path.as_ref().display()))?;
 
+    // an extension named in `Cargo.toml` must have a control file of that name, there may be
+    // others for extensions built from elsewhere
+    let manifest = Manifest::from_path(manifest_path.as_ref())
+        .wrap_err_with(|| eyre!("could not parse `{}`", manifest_path.as_ref().display()))?;
+    if let Some(package) = &manifest.package {
+        let named = package
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("pgrx"))
+            .is_some_and(|pgrx| pgrx.get("extension-name").is_some());
+        if named {
+            let extname = manifest.extension_name()?;
+            let control_file = parent.join(format!("{extname}.control"));
+            if !control_file.exists() {
+                return Err(eyre!(
+                    "`package.metadata.pgrx.extension-name` is `{extname}`, but there's no `{}`",
+                    control_file.display()
+                ));
+            }
+            return Ok((control_file, extname));
+        }
+    }
+
     for f in std::fs::read_dir(parent).wrap_err_with(|| {
         eyre!("cannot open current directory `{}` for reading", parent.display())
     })? {
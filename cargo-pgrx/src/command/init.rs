@@ -12,16 +12,13 @@ use crate::command::version::pgrx_default;
 use crate::CommandExecute;
 use eyre::{eyre, WrapErr};
 use owo_colors::OwoColorize;
-use pgrx_pg_config::{
-    get_c_locale_flags, prefix_path, PgConfig, PgConfigSelector, Pgrx, PgrxHomeError,
-};
+use pgrx_pg_config::{prefix_path, InitdbOptions, PgConfig, PgConfigSelector, Pgrx, PgrxHomeError};
 use rayon::prelude::*;
 
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::PathBuf;
-use std::process::Stdio;
 
 use std::sync::{Arc, Mutex};
 
@@ -70,6 +67,47 @@ pub(crate) struct Init {
     base_testing_port: Option<u16>,
     #[clap(long, help = "Additional flags to pass to the configure script")]
     configure_flag: Vec<String>,
+    #[clap(flatten)]
+    initdb: InitdbArgs,
+}
+
+/// Options for `initdb` when creating a data directory.  Any that are given are saved, per Postgres
+/// version, in `$PGRX_HOME/config.toml` and used whenever that version's data directory is created
+#[derive(clap::Args, Debug, Clone, Default)]
+pub(crate) struct InitdbArgs {
+    /// The locale of new data directories, such as `en_US.UTF-8` (default is `C.UTF-8`, or `C`)
+    #[clap(long)]
+    locale: Option<String>,
+    /// The encoding of new data directories, such as `UTF8` (default is the locale's encoding)
+    #[clap(long)]
+    encoding: Option<String>,
+    /// Enable data page checksums in new data directories
+    #[clap(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    data_checksums: Option<bool>,
+    /// The authentication method of new data directories (default is `trust`).  Methods requiring a
+    /// password use `$PGPASSWORD`
+    #[clap(long)]
+    auth: Option<String>,
+}
+
+impl InitdbArgs {
+    fn options(&self) -> InitdbOptions {
+        InitdbOptions {
+            locale: self.locale.clone(),
+            encoding: self.encoding.clone(),
+            data_checksums: self.data_checksums,
+            auth: self.auth.clone(),
+        }
+    }
+
+    /// Save any options that were given for `pg_config`'s Postgres version
+    pub(crate) fn save(&self, pg_config: &PgConfig) -> eyre::Result<()> {
+        let options = self.options();
+        if !options.is_default() {
+            Pgrx::save_initdb_options(&pg_config.label()?, options)?;
+        }
+        Ok(())
+    }
 }
 
 impl CommandExecute for Init {
@@ -143,6 +181,9 @@ pub(crate) fn init_pgrx(pgrx: &Pgrx, init: &Init) -> eyre::Result<()> {
         },
     };
 
+    // `write_config()` replaces the whole file, so remember the options we already had
+    let saved_pgrx = Pgrx::from_config().ok();
+
     let output_configs = Arc::new(Mutex::new(Vec::new()));
 
     let mut pg_configs = Vec::new();
@@ -182,21 +223,31 @@ pub(crate) fn init_pgrx(pgrx: &Pgrx, init: &Init) -> eyre::Result<()> {
             .unwrap_or_else(|e| panic!("{e}:  could not determine major version for: `{:?}`", a))
             .cmp(&b.major_version().ok().expect("could not determine major version"))
     });
+    let mut initdb_options = Vec::new();
     for pg_config in output_configs.iter() {
         validate_pg_config(pg_config)?;
 
+        let label = pg_config.label()?;
+        let mut options = match &saved_pgrx {
+            Some(saved_pgrx) => saved_pgrx.initdb_options(&label),
+            None => InitdbOptions::default(),
+        };
+        options.merge(init.initdb.options());
+
         if is_root_user() {
             println!("{} initdb as current user is root user", "   Skipping".bold().green(),);
         } else {
-            let datadir = pg_config.data_dir()?;
-            let bindir = pg_config.bin_dir()?;
-            if !datadir.try_exists()? {
-                initdb(&bindir, &datadir)?;
-            }
+            ensure_data_dir(pg_config, &options)?;
         }
+        initdb_options.push((label, options));
     }
 
     write_config(output_configs, init)?;
+    for (label, options) in initdb_options {
+        if !options.is_default() {
+            Pgrx::save_initdb_options(&label, options)?;
+        }
+    }
     Ok(())
 }
 
@@ -445,29 +496,25 @@ fn is_root_user() -> bool {
     Uid::effective().is_root()
 }
 
-pub(crate) fn initdb(bindir: &PathBuf, datadir: &PathBuf) -> eyre::Result<()> {
-    println!(" {} data directory at {}", "Initializing".bold().green(), datadir.display());
-    let mut command = std::process::Command::new(format!("{}/initdb", bindir.display()));
-    command
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .args(get_c_locale_flags())
-        .arg("-D")
-        .arg(&datadir);
-
-    let command_str = format!("{:?}", command);
-    tracing::debug!(command = %command_str, "Running");
-
-    let output = command.output().wrap_err_with(|| eyre!("unable to execute: {}", command_str))?;
-    tracing::trace!(command = %command_str, status_code = %output.status, "Finished");
-
-    if !output.status.success() {
-        return Err(eyre!(
-            "problem running initdb: {}\n{}",
-            command_str,
-            String::from_utf8(output.stderr).unwrap()
-        ));
+/// Create `pg_config`'s data directory if it doesn't exist yet
+///
+/// An existing data directory is left alone, even if it was created with different options, as it
+/// may hold data the user wants to keep.
+pub(crate) fn ensure_data_dir(pg_config: &PgConfig, options: &InitdbOptions) -> eyre::Result<()> {
+    let datadir = pg_config.data_dir()?;
+    if !datadir.try_exists()? {
+        initdb(pg_config, &datadir, options)?;
+    } else if !options.matches_data_dir(&datadir) {
+        println!(
+            "{} {} was created with different initdb options.  Stop Postgres and remove it to have it recreated",
+            "      Warning".bold().yellow(),
+            datadir.display()
+        );
     }
-
     Ok(())
 }
+
+fn initdb(pg_config: &PgConfig, datadir: &PathBuf, options: &InitdbOptions) -> eyre::Result<()> {
+    println!(" {} data directory at {}", "Initializing".bold().green(), datadir.display());
+    pgrx_pg_config::initdb(pg_config, datadir, options)
+}
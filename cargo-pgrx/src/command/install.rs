@@ -8,6 +8,7 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 use crate::command::get::{find_control_file, get_property};
+use crate::command::status::status_postgres;
 use crate::manifest::{display_version_info, PgVersionSource};
 use crate::profile::CargoProfile;
 use crate::CommandExecute;
@@ -15,6 +16,8 @@ use cargo_toml::Manifest;
 use eyre::{eyre, WrapErr};
 use owo_colors::OwoColorize;
 use pgrx_pg_config::{cargo::PgrxManifestExt, get_target_dir, PgConfig, Pgrx};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
@@ -42,6 +45,17 @@ pub(crate) struct Install {
     /// The `pg_config` path (default is first in $PATH)
     #[clap(long, short = 'c')]
     pg_config: Option<String>,
+    /// Build the shared library reproducibly, free of paths from this machine (see `PGRX_REPRODUCIBLE`)
+    #[clap(long)]
+    reproducible: bool,
+    /// Afterwards, if the pgrx-managed Postgres for this version is running and the extension
+    /// was created in `--dbname`, `ALTER EXTENSION ... UPDATE` it to the version just installed
+    #[clap(long)]
+    update: bool,
+    /// The database to update the extension in.  Defaults to a database with the same name as the
+    /// extension
+    #[clap(long, requires = "update")]
+    dbname: Option<String>,
     #[clap(flatten)]
     features: clap_cargo::Features,
     #[clap(from_global, action = ArgAction::Count)]
@@ -54,6 +68,10 @@ impl CommandExecute for Install {
         let metadata = crate::metadata::metadata(&self.features, self.manifest_path.as_ref())
             .wrap_err("couldn't get cargo metadata")?;
         crate::metadata::validate(&metadata)?;
+        if self.reproducible {
+            // so every `cargo` that builds the extension, including for its schema, sees it
+            std::env::set_var("PGRX_REPRODUCIBLE", "true");
+        }
         let package_manifest_path =
             crate::manifest::manifest_path(&metadata, self.package.as_ref())
                 .wrap_err("Couldn't get manifest path")?;
@@ -82,14 +100,122 @@ impl CommandExecute for Install {
         install_extension(
             self.manifest_path.as_ref(),
             self.package.as_ref(),
-            package_manifest_path,
+            &package_manifest_path,
             &pg_config,
             &profile,
             self.test,
             None,
             &self.features,
+        )?;
+
+        if self.update {
+            let (_, extname) = find_control_file(&package_manifest_path)?;
+            let version = get_version(&package_manifest_path)?;
+            let dbname = self.dbname.unwrap_or_else(|| extname.clone());
+            update_extension(&pg_config, &dbname, &extname, &version)?;
+        }
+        Ok(())
+    }
+}
+
+/// `ALTER EXTENSION ... UPDATE` the extension in `dbname` to `version`, using the upgrade scripts
+/// that were just installed.  Nothing happens if Postgres isn't running or the extension wasn't
+/// created in that database.
+#[tracing::instrument(level = "error", skip(pg_config))]
+pub(crate) fn update_extension(
+    pg_config: &PgConfig,
+    dbname: &str,
+    extname: &str,
+    version: &str,
+) -> eyre::Result<()> {
+    if !status_postgres(pg_config)? {
+        println!(
+            "{} update of {extname}, Postgres v{} isn't running",
+            "    Skipping".bold().yellow(),
+            pg_config.major_version()?
+        );
+        return Ok(());
+    }
+
+    let installed = run_psql(
+        pg_config,
+        dbname,
+        &format!(
+            "SELECT extversion FROM pg_catalog.pg_extension WHERE extname = {}",
+            quote_literal(extname)
+        ),
+    )?;
+    let installed = installed.trim();
+    if installed.is_empty() {
+        println!(
+            "{} update of {extname}, it hasn't been created in database {dbname}",
+            "    Skipping".bold().yellow()
+        );
+        return Ok(());
+    } else if installed == version {
+        println!(
+            "{} {extname} in database {dbname} is already version {version}, reconnect to load \
+             the new shared library",
+            "    Skipping".bold().yellow()
+        );
+        return Ok(());
+    }
+
+    run_psql(
+        pg_config,
+        dbname,
+        &format!(
+            "ALTER EXTENSION \"{}\" UPDATE TO {}",
+            extname.replace('"', "\"\""),
+            quote_literal(version)
+        ),
+    )
+    .wrap_err_with(|| {
+        format!(
+            "couldn't update {extname} from version {installed} to {version}, is there an \
+             upgrade path of sql/{extname}--{installed}--*.sql scripts to it?"
         )
+    })?;
+    println!(
+        "{} {extname} from version {installed} to {version} in database {dbname}",
+        "     Updated".bold().green()
+    );
+    Ok(())
+}
+
+/// Run `sql` with `psql` in the pgrx-managed Postgres, returning its unaligned output
+fn run_psql(pg_config: &PgConfig, dbname: &str, sql: &str) -> eyre::Result<String> {
+    let mut command = std::process::Command::new(pg_config.psql_path()?);
+    command
+        .env_remove("PGDATABASE")
+        .env_remove("PGHOST")
+        .env_remove("PGPORT")
+        .env_remove("PGUSER")
+        .arg("-h")
+        .arg(pg_config.host())
+        .arg("-p")
+        .arg(pg_config.port()?.to_string())
+        .arg("-X")
+        .arg("-A")
+        .arg("-t")
+        .arg("-v")
+        .arg("ON_ERROR_STOP=1")
+        .arg("-c")
+        .arg(sql)
+        .arg(dbname);
+    let command_str = format!("{:?}", command);
+    tracing::debug!(command = %command_str, "Running");
+
+    let output =
+        command.output().wrap_err_with(|| format!("failed to spawn psql: {}", command_str))?;
+    if !output.status.success() {
+        return Err(eyre!("{}", String::from_utf8_lossy(&output.stderr).trim()));
     }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
 }
 
 #[tracing::instrument(skip_all, fields(
@@ -114,30 +240,49 @@ pub(crate) fn install_extension(
         .record("base_directory", &tracing::field::display(&base_directory.display()));
 
     let manifest = Manifest::from_path(&package_manifest_path)?;
+    if manifest.is_module_only() {
+        return install_module(
+            user_manifest_path,
+            user_package,
+            &package_manifest_path,
+            &manifest,
+            pg_config,
+            profile,
+            base_directory,
+            features,
+        );
+    }
     let (control_file, extname) = find_control_file(&package_manifest_path)?;
 
-    if get_property(&package_manifest_path, "relocatable")? != Some("false".into()) {
-        return Err(eyre!(
-            "{}:  The `relocatable` property MUST be `false`.  Please update your .control file.",
-            control_file.display()
-        ));
+    let module_pathname = get_property(&package_manifest_path, "module_pathname")?;
+    let versioned_so = module_pathname.is_none();
+    if let Some(module_pathname) = &module_pathname {
+        // the shared library is installed under the extension's name, whatever the crate calls it
+        let installed = format!("$libdir/{extname}");
+        if *module_pathname != installed && *module_pathname != format!("{installed}.so") {
+            return Err(eyre!(
+                "{}:  The `module_pathname` property is `{module_pathname}`, but the shared library is installed as `{installed}`.  Please update your .control file.",
+                control_file.display()
+            ));
+        }
     }
 
-    let versioned_so = get_property(&package_manifest_path, "module_pathname")?.is_none();
-
-    let build_command_output =
-        build_extension(user_manifest_path.as_ref(), user_package, &profile, &features)?;
-    let build_command_bytes = build_command_output.stdout;
-    let build_command_reader = BufReader::new(build_command_bytes.as_slice());
-    let build_command_stream = cargo_metadata::Message::parse_stream(build_command_reader);
     let build_command_messages =
-        build_command_stream.collect::<Result<Vec<_>, std::io::Error>>()?;
+        build_messages(user_manifest_path.as_ref(), user_package, &profile, &features)?;
 
     println!("{} extension", "  Installing".bold().green(),);
     let pkgdir = make_relative(pg_config.pkglibdir()?);
     let extdir = make_relative(pg_config.extension_dir()?);
     let shlibpath = find_library_file(&manifest, &build_command_messages)?;
 
+    let mut install_manifest_path = base_directory.clone();
+    install_manifest_path.push(&extdir);
+    install_manifest_path.push(InstallManifest::filename(&extname));
+    let mut install_manifest = InstallManifest::load(&install_manifest_path)?.unwrap_or_default();
+    // files an earlier install left behind, like the shared library of another version, stay
+    // listed so that `cargo pgrx uninstall` still removes them
+    install_manifest.retain_existing(&base_directory);
+
     {
         let mut dest = base_directory.clone();
         dest.push(&extdir);
@@ -147,6 +292,7 @@ pub(crate) fn install_extension(
                 .ok_or_else(|| eyre!("Could not get filename for `{}`", control_file.display()))?,
         );
         copy_file(&control_file, &dest, "control file", true, &package_manifest_path)?;
+        install_manifest.record(&base_directory, &dest)?;
     }
 
     {
@@ -160,18 +306,8 @@ pub(crate) fn install_extension(
             extname.clone()
         };
         dest.push(format!("{}.so", so_name));
-
-        if cfg!(target_os = "macos") {
-            // Remove the existing .so if present. This is a workaround for an
-            // issue highlighted by the following apple documentation:
-            // https://developer.apple.com/documentation/security/updating_mac_software
-            if dest.exists() {
-                std::fs::remove_file(&dest).wrap_err_with(|| {
-                    format!("unable to remove existing file {}", dest.display())
-                })?;
-            }
-        }
-        copy_file(&shlibpath, &dest, "shared library", false, &package_manifest_path)?;
+        copy_shared_library(&shlibpath, &dest, &package_manifest_path)?;
+        install_manifest.record(&base_directory, &dest)?;
     }
 
     copy_sql_files(
@@ -185,12 +321,173 @@ pub(crate) fn install_extension(
         &extdir,
         &base_directory,
         true,
+        &mut install_manifest,
     )?;
 
+    println!(
+        "{} install manifest to {}",
+        "     Writing".bold().green(),
+        format_display_path(&install_manifest_path)?.cyan()
+    );
+    install_manifest.save(&install_manifest_path)?;
+
     println!("{} installing {}", "    Finished".bold().green(), extname);
     Ok(())
 }
 
+/// Install a [module-only](PgrxManifestExt::is_module_only) crate, which is only its shared
+/// library.  There's no schema to generate, nor control file or SQL to copy.
+#[allow(clippy::too_many_arguments)]
+fn install_module(
+    user_manifest_path: Option<impl AsRef<Path>>,
+    user_package: Option<&String>,
+    package_manifest_path: impl AsRef<Path>,
+    manifest: &Manifest,
+    pg_config: &PgConfig,
+    profile: &CargoProfile,
+    base_directory: PathBuf,
+    features: &clap_cargo::Features,
+) -> eyre::Result<()> {
+    let name = manifest.extension_name()?;
+    let build_command_messages =
+        build_messages(user_manifest_path.as_ref(), user_package, profile, features)?;
+
+    println!("{} module", "  Installing".bold().green(),);
+    let pkgdir = make_relative(pg_config.pkglibdir()?);
+    let extdir = make_relative(pg_config.extension_dir()?);
+    let shlibpath = find_library_file(manifest, &build_command_messages)?;
+
+    // the install manifest goes where an extension's would, for `cargo pgrx uninstall` to find
+    let mut install_manifest_path = base_directory.clone();
+    install_manifest_path.push(&extdir);
+    install_manifest_path.push(InstallManifest::filename(&name));
+    let mut install_manifest = InstallManifest::load(&install_manifest_path)?.unwrap_or_default();
+    install_manifest.retain_existing(&base_directory);
+
+    let mut dest = base_directory.clone();
+    dest.push(&pkgdir);
+    dest.push(format!("{name}.so"));
+    copy_shared_library(&shlibpath, &dest, &package_manifest_path)?;
+    install_manifest.record(&base_directory, &dest)?;
+
+    println!(
+        "{} install manifest to {}",
+        "     Writing".bold().green(),
+        format_display_path(&install_manifest_path)?.cyan()
+    );
+    if let Some(parent) = install_manifest_path.parent() {
+        fs::create_dir_all(parent)
+            .wrap_err_with(|| format!("failed to create `{}`", parent.display()))?;
+    }
+    install_manifest.save(&install_manifest_path)?;
+
+    println!(
+        "{} installing {name}, add it to `shared_preload_libraries` to load it",
+        "    Finished".bold().green()
+    );
+    Ok(())
+}
+
+/// Build the extension, returning the messages cargo printed about it
+fn build_messages(
+    user_manifest_path: Option<impl AsRef<Path>>,
+    user_package: Option<&String>,
+    profile: &CargoProfile,
+    features: &clap_cargo::Features,
+) -> eyre::Result<Vec<cargo_metadata::Message>> {
+    let build_command_output =
+        build_extension(user_manifest_path, user_package, profile, features)?;
+    let build_command_reader = BufReader::new(build_command_output.stdout.as_slice());
+    let build_command_stream = cargo_metadata::Message::parse_stream(build_command_reader);
+    Ok(build_command_stream.collect::<Result<Vec<_>, std::io::Error>>()?)
+}
+
+fn copy_shared_library(
+    shlibpath: &PathBuf,
+    dest: &PathBuf,
+    package_manifest_path: impl AsRef<Path>,
+) -> eyre::Result<()> {
+    if cfg!(target_os = "macos") {
+        // Remove the existing .so if present. This is a workaround for an
+        // issue highlighted by the following apple documentation:
+        // https://developer.apple.com/documentation/security/updating_mac_software
+        if dest.exists() {
+            std::fs::remove_file(dest)
+                .wrap_err_with(|| format!("unable to remove existing file {}", dest.display()))?;
+        }
+    }
+    copy_file(shlibpath, dest, "shared library", false, package_manifest_path)
+}
+
+/// The files `cargo pgrx install` copied into a Postgres installation, kept in its extension
+/// directory as `$EXTNAME.pgrx-manifest` so that `cargo pgrx uninstall` removes exactly those
+/// files.  Each line is the SHA-256 of a file's contents and its path, as `sha256sum` prints them.
+#[derive(Debug, Default)]
+pub(crate) struct InstallManifest {
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl InstallManifest {
+    pub(crate) fn filename(extname: &str) -> String {
+        format!("{extname}.pgrx-manifest")
+    }
+
+    /// `None` if there's no manifest at `path`
+    pub(crate) fn load(path: &Path) -> eyre::Result<Option<Self>> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e).wrap_err_with(|| format!("failed to read `{}`", path.display()))
+            }
+        };
+
+        let mut files = BTreeMap::new();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let (hash, file) = line
+                .split_once("  ")
+                .ok_or_else(|| eyre!("malformed line in `{}`: {line}", path.display()))?;
+            files.insert(PathBuf::from(file), hash.to_string());
+        }
+        Ok(Some(Self { files }))
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> eyre::Result<()> {
+        let contents = self
+            .files
+            .iter()
+            .map(|(file, hash)| format!("{hash}  {}\n", file.display()))
+            .collect::<String>();
+        std::fs::write(path, contents)
+            .wrap_err_with(|| format!("failed writing `{}`", path.display()))
+    }
+
+    /// Record the file at `dest`, which is its path in the installation plus `base_directory`
+    fn record(&mut self, base_directory: &Path, dest: &Path) -> eyre::Result<()> {
+        let installed = Path::new("/").join(dest.strip_prefix(base_directory)?);
+        self.files.insert(installed, sha256_file(dest)?);
+        Ok(())
+    }
+
+    fn retain_existing(&mut self, base_directory: &Path) {
+        self.files.retain(|file, _| base_directory.join(make_relative(file.clone())).exists());
+    }
+
+    /// Each installed file's path and the hash of its contents when it was installed
+    pub(crate) fn files(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.files.iter().map(|(file, hash)| (file.as_path(), hash.as_str()))
+    }
+}
+
+pub(crate) fn sha256_file(path: &Path) -> eyre::Result<String> {
+    let mut file = std::fs::File::open(path)
+        .wrap_err_with(|| format!("failed to open `{}`", path.display()))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .wrap_err_with(|| format!("failed to read `{}`", path.display()))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 fn copy_file(
     src: &PathBuf,
     dest: &PathBuf,
@@ -224,8 +521,14 @@ fn copy_file(
             format!("failed writing `{}` to `{}`", src.display(), dest.display())
         })?;
     } else {
-        std::fs::copy(&src, &dest).wrap_err_with(|| {
-            format!("failed copying `{}` to `{}`", src.display(), dest.display())
+        // copy to a temporary file and rename it into place, rather than overwriting `dest`, so
+        // that Postgres backends that have the old shared library mapped into memory don't crash
+        let tmp = dest.with_extension("pgrx-tmp");
+        std::fs::copy(&src, &tmp).wrap_err_with(|| {
+            format!("failed copying `{}` to `{}`", src.display(), tmp.display())
+        })?;
+        std::fs::rename(&tmp, &dest).wrap_err_with(|| {
+            format!("failed renaming `{}` to `{}`", tmp.display(), dest.display())
         })?;
     }
 
@@ -243,7 +546,7 @@ pub(crate) fn build_extension(
     let mut command = crate::env::cargo();
     command.arg("build");
 
-    if let Some(user_manifest_path) = user_manifest_path {
+    if let Some(user_manifest_path) = &user_manifest_path {
         command.arg("--manifest-path");
         command.arg(user_manifest_path.as_ref());
     }
@@ -269,6 +572,13 @@ pub(crate) fn build_extension(
     }
 
     command.arg("--message-format=json-render-diagnostics");
+    crate::env::set_rustflags(&mut command)?;
+    crate::env::set_build_info(
+        &mut command,
+        user_manifest_path.as_ref().map(|path| path.as_ref()),
+        profile,
+        features,
+    );
 
     for arg in flags.split_ascii_whitespace() {
         command.arg(arg);
@@ -314,6 +624,7 @@ fn copy_sql_files(
     extdir: &PathBuf,
     base_directory: &PathBuf,
     skip_build: bool,
+    install_manifest: &mut InstallManifest,
 ) -> eyre::Result<()> {
     let dest = get_target_sql_file(&package_manifest_path, extdir, base_directory)?;
     let (_, extname) = find_control_file(&package_manifest_path)?;
@@ -331,6 +642,7 @@ fn copy_sql_files(
         None,
         skip_build,
     )?;
+    install_manifest.record(base_directory, &dest)?;
 
     // now copy all the version upgrade files too
     if let Ok(dir) = std::fs::read_dir("sql/") {
@@ -350,6 +662,7 @@ fn copy_sql_files(
                         true,
                         &package_manifest_path,
                     )?;
+                    install_manifest.record(base_directory, &dest)?;
                 }
             }
         }
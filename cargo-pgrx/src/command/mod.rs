@@ -12,6 +12,9 @@ use ureq::{Agent, AgentBuilder, Proxy};
 
 pub(crate) mod connect;
 pub(crate) mod cross;
+pub(crate) mod debug;
+pub(crate) mod env;
+pub(crate) mod fuzz;
 pub(crate) mod get;
 pub(crate) mod info;
 pub(crate) mod init;
@@ -25,6 +28,7 @@ pub(crate) mod start;
 pub(crate) mod status;
 pub(crate) mod stop;
 pub(crate) mod test;
+pub(crate) mod uninstall;
 pub(crate) mod version;
 
 // Build a ureq::Agent by the given url. Requests from this agent are proxied if we have
@@ -56,6 +56,9 @@ pub(crate) fn create_crate_template(
     create_cargo_toml(&path, name)?;
     create_dotcargo_config_toml(&path, name)?;
     create_lib_rs(&path, name, is_bgworker)?;
+    if !is_bgworker {
+        create_sql_examples(&path, name)?;
+    }
     create_git_ignore(&path, name)?;
 
     Ok(())
@@ -128,6 +131,18 @@ fn create_lib_rs(path: &PathBuf, name: &str, is_bgworker: bool) -> Result<(), st
     Ok(())
 }
 
+fn create_sql_examples(path: &PathBuf, name: &str) -> Result<(), std::io::Error> {
+    let mut filename = path.clone();
+
+    filename.push("sql");
+    filename.push("examples.sql");
+    let mut file = std::fs::File::create(filename)?;
+
+    file.write_all(&format!(include_str!("../templates/sql_examples"), name = name).as_bytes())?;
+
+    Ok(())
+}
+
 fn create_git_ignore(path: &PathBuf, _name: &str) -> Result<(), std::io::Error> {
     let mut filename = path.clone();
 
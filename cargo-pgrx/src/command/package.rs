@@ -41,6 +41,9 @@ pub(crate) struct Package {
     /// The directory to output the package (default is `./target/[debug|release]/extname-pgXX/`)
     #[clap(long, value_parser)]
     out_dir: Option<PathBuf>,
+    /// Build the shared library reproducibly, free of paths from this machine (see `PGRX_REPRODUCIBLE`)
+    #[clap(long)]
+    reproducible: bool,
     #[clap(flatten)]
     features: clap_cargo::Features,
     #[clap(from_global, action = ArgAction::Count)]
@@ -53,6 +56,10 @@ impl CommandExecute for Package {
         let metadata = crate::metadata::metadata(&self.features, self.manifest_path.as_ref())
             .wrap_err("couldn't get cargo metadata")?;
         crate::metadata::validate(&metadata)?;
+        if self.reproducible {
+            // so every `cargo` that builds the extension, including for its schema, sees it
+            std::env::set_var("PGRX_REPRODUCIBLE", "true");
+        }
         let package_manifest_path =
             crate::manifest::manifest_path(&metadata, self.package.as_ref())
                 .wrap_err("Couldn't get manifest path")?;
@@ -35,11 +35,15 @@ enum CargoPgrxSubCommands {
     Status(super::status::Status),
     New(super::new::New),
     Install(super::install::Install),
+    Uninstall(super::uninstall::Uninstall),
     Package(super::package::Package),
     Schema(super::schema::Schema),
     Run(super::run::Run),
     Connect(super::connect::Connect),
     Test(super::test::Test),
+    Fuzz(super::fuzz::Fuzz),
+    Debug(super::debug::Debug),
+    Env(super::env::Env),
     Get(super::get::Get),
     Cross(super::cross::Cross),
 }
@@ -56,11 +60,15 @@ impl CommandExecute for CargoPgrxSubCommands {
             Status(c) => c.execute(),
             New(c) => c.execute(),
             Install(c) => c.execute(),
+            Uninstall(c) => c.execute(),
             Package(c) => c.execute(),
             Schema(c) => c.execute(),
             Run(c) => c.execute(),
             Connect(c) => c.execute(),
             Test(c) => c.execute(),
+            Fuzz(c) => c.execute(),
+            Debug(c) => c.execute(),
+            Env(c) => c.execute(),
             Get(c) => c.execute(),
             Cross(c) => c.execute(),
         }
@@ -7,19 +7,23 @@
 //LICENSE All rights reserved.
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
-use crate::command::get::get_property;
+use crate::command::get::installed_name;
+use crate::command::init::InitdbArgs;
 use crate::command::install::install_extension;
 use crate::command::start::start_postgres;
 use crate::command::stop::stop_postgres;
 use crate::manifest::{get_package_manifest, pg_config_and_version};
 use crate::profile::CargoProfile;
 use crate::CommandExecute;
+use cargo_toml::Manifest;
 use eyre::eyre;
 use owo_colors::OwoColorize;
-use pgrx_pg_config::{createdb, PgConfig, Pgrx};
+use pgrx_pg_config::{cargo::PgrxManifestExt, createdb, PgConfig, Pgrx};
+use std::collections::HashMap;
 use std::os::unix::process::CommandExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::{Duration, SystemTime};
 
 /// Compile/install extension to a pgrx-managed Postgres instance and start psql
 #[derive(clap::Args, Debug)]
@@ -49,6 +53,12 @@ pub(crate) struct Run {
     /// Use an existing `pgcli` on the $PATH.
     #[clap(env = "PGRX_PGCLI", long)]
     pgcli: bool,
+    /// Watch the source tree and, whenever it changes, rebuild, reinstall, and recreate the
+    /// extension while the psql session stays open
+    #[clap(long)]
+    watch: bool,
+    #[clap(flatten)]
+    initdb: InitdbArgs,
 }
 
 impl CommandExecute for Run {
@@ -67,11 +77,11 @@ impl CommandExecute for Run {
             Some(&mut self.features),
             true,
         )?;
+        self.initdb.save(&pg_config)?;
 
         let dbname = match self.dbname {
             Some(dbname) => dbname,
-            None => get_property(&package_manifest_path, "extname")?
-                .ok_or(eyre!("could not determine extension name"))?,
+            None => installed_name(&package_manifest_path)?,
         };
         let profile = CargoProfile::from_flags(
             self.profile.as_deref(),
@@ -86,6 +96,7 @@ impl CommandExecute for Run {
             &dbname,
             &profile,
             self.pgcli,
+            self.watch,
             &self.features,
         )
     }
@@ -104,14 +115,18 @@ pub(crate) fn run(
     dbname: &str,
     profile: &CargoProfile,
     pgcli: bool,
+    watch: bool,
     features: &clap_cargo::Features,
 ) -> eyre::Result<()> {
+    let user_manifest_path = user_manifest_path.map(|path| path.as_ref().to_path_buf());
+    let package_manifest_path = package_manifest_path.as_ref();
+
     // stop postgres
     stop_postgres(pg_config)?;
 
     // install the extension
     install_extension(
-        user_manifest_path,
+        user_manifest_path.as_ref(),
         user_package,
         package_manifest_path,
         pg_config,
@@ -129,11 +144,31 @@ pub(crate) fn run(
         println!("{} existing database {}", "    Re-using".bold().cyan(), dbname);
     }
 
+    if watch {
+        return watch_and_reload(
+            pg_config,
+            user_manifest_path.as_ref(),
+            user_package,
+            package_manifest_path,
+            dbname,
+            profile,
+            pgcli,
+            features,
+        );
+    }
+
     // run psql
     exec_psql(pg_config, dbname, pgcli)
 }
 
 pub(crate) fn exec_psql(pg_config: &PgConfig, dbname: &str, pgcli: bool) -> eyre::Result<()> {
+    let mut command = psql_command(pg_config, dbname, pgcli)?;
+
+    // we'll never return from here as we've now become psql
+    panic!("{}", command.exec());
+}
+
+fn psql_command(pg_config: &PgConfig, dbname: &str, pgcli: bool) -> eyre::Result<Command> {
     let mut command = Command::new(match pgcli {
         false => pg_config.psql_path()?.into_os_string(),
         true => "pgcli".to_string().into(),
@@ -148,7 +183,131 @@ pub(crate) fn exec_psql(pg_config: &PgConfig, dbname: &str, pgcli: bool) -> eyre
         .arg("-p")
         .arg(pg_config.port()?.to_string())
         .arg(dbname);
+    Ok(command)
+}
 
-    // we'll never return from here as we've now become psql
-    panic!("{}", command.exec());
+/// Run psql as a child process, and every time the extension's sources change, rebuild and
+/// reinstall it, then recreate it in `dbname`.  Returns once psql exits.
+#[allow(clippy::too_many_arguments)]
+fn watch_and_reload(
+    pg_config: &PgConfig,
+    user_manifest_path: Option<&PathBuf>,
+    user_package: Option<&String>,
+    package_manifest_path: &Path,
+    dbname: &str,
+    profile: &CargoProfile,
+    pgcli: bool,
+    features: &clap_cargo::Features,
+) -> eyre::Result<()> {
+    let extname = installed_name(package_manifest_path)?;
+    let module_only = Manifest::from_path(package_manifest_path)?.is_module_only();
+    let package_dir = package_manifest_path
+        .parent()
+        .ok_or(eyre!("could not determine the package directory"))?
+        .to_path_buf();
+
+    println!(
+        "{} {} for changes, quit psql to stop",
+        "    Watching".bold().cyan(),
+        package_dir.display()
+    );
+    let mut psql = psql_command(pg_config, dbname, pgcli)?.spawn()?;
+
+    // psql handles ^C itself, to cancel the running query, and we shouldn't exit because of it
+    unsafe {
+        nix::libc::signal(nix::libc::SIGINT, nix::libc::SIG_IGN);
+    }
+
+    let mut sources = snapshot_sources(&package_dir)?;
+    loop {
+        if psql.try_wait()?.is_some() {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(500));
+
+        let current = snapshot_sources(&package_dir)?;
+        if current == sources {
+            continue;
+        }
+        sources = current;
+
+        println!("\n{} {extname}", "   Reloading".bold().cyan());
+        let reloaded = install_extension(
+            user_manifest_path,
+            user_package,
+            package_manifest_path,
+            pg_config,
+            profile,
+            false,
+            None,
+            features,
+        )
+        .and_then(|()| recreate_extension(pg_config, dbname, &extname, module_only));
+        match reloaded {
+            Ok(()) => println!(
+                "{} {extname}, the psql session will reconnect to load it",
+                "    Reloaded".bold().green()
+            ),
+            Err(e) => println!("{} {e:?}", "       Error".bold().red()),
+        }
+    }
+}
+
+/// The sessions that have the old shared library loaded are terminated, so that psql reconnects
+/// with a backend that will load the new one.  A module-only crate has no extension to recreate.
+fn recreate_extension(
+    pg_config: &PgConfig,
+    dbname: &str,
+    extname: &str,
+    module_only: bool,
+) -> eyre::Result<()> {
+    let mut sql = "SELECT count(pg_terminate_backend(pid)) FROM pg_stat_activity \
+        WHERE datname = current_database() AND pid <> pg_backend_pid();"
+        .to_string();
+    if !module_only {
+        sql.push_str(&format!(
+            " DROP EXTENSION IF EXISTS \"{extname}\" CASCADE; CREATE EXTENSION \"{extname}\";"
+        ));
+    }
+    let output = psql_command(pg_config, dbname, false)?
+        .arg("-v")
+        .arg("ON_ERROR_STOP=1")
+        .arg("-q")
+        .arg("-c")
+        .arg(sql)
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(
+            "failed to recreate extension `{extname}`:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+/// The modification time of every file that could affect the built extension
+fn snapshot_sources(package_dir: &Path) -> eyre::Result<HashMap<PathBuf, SystemTime>> {
+    fn visit(dir: &Path, sources: &mut HashMap<PathBuf, SystemTime>) -> eyre::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.') || name == "target" {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                visit(&path, sources)?;
+            } else {
+                sources.insert(path, metadata.modified()?);
+            }
+        }
+        Ok(())
+    }
+
+    let mut sources = HashMap::new();
+    visit(package_dir, &mut sources)?;
+    Ok(sources)
 }
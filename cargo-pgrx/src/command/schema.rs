@@ -15,14 +15,16 @@ use crate::CommandExecute;
 use cargo_toml::Manifest;
 use eyre::{eyre, WrapErr};
 use object::read::macho::{FatArch, FatHeader};
-use object::{Architecture, FileKind, Object};
+use object::{Architecture, FileKind, Object, ObjectSection};
 use once_cell::sync::OnceCell;
 use owo_colors::OwoColorize;
 use pgrx_pg_config::{cargo::PgrxManifestExt, get_target_dir, PgConfig, Pgrx};
-use std::collections::HashSet;
+use pgrx_sql_entity_graph::EntitySignatures;
+use std::collections::{BTreeMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::time::SystemTime;
 // Since we support extensions with `#[no_std]`
 extern crate alloc;
 use crate::manifest::{get_package_manifest, pg_config_and_version};
@@ -74,6 +76,9 @@ pub(crate) struct Schema {
     /// Skip building a fresh extension shared object.
     #[clap(long)]
     skip_build: bool,
+    /// Regenerate the schema whenever the extension's sources change
+    #[clap(long, conflicts_with = "skip_build")]
+    watch: bool,
 }
 
 impl CommandExecute for Schema {
@@ -96,6 +101,9 @@ impl CommandExecute for Schema {
             self.package.as_ref(),
             self.manifest_path.as_ref(),
         )?;
+        if self.watch {
+            return watch_schema(&package_manifest_path, self.out.as_ref());
+        }
         let (pg_config, _pg_version) = pg_config_and_version(
             &pgrx,
             &package_manifest,
@@ -125,6 +133,99 @@ impl CommandExecute for Schema {
     }
 }
 
+/// Generates the schema again, in a new `cargo pgrx schema` with the same arguments but `--watch`,
+/// whenever a source file in the extension's package changes.  Each generation needs its own
+/// process because the extension's shared library can't be reloaded once it's been `dlopen`ed,
+/// and cargo only rebuilds what changed, so after the first build this is quick.
+fn watch_schema(package_manifest_path: &Path, out: Option<&PathBuf>) -> eyre::Result<()> {
+    let package_dir = package_manifest_path
+        .parent()
+        .ok_or_else(|| eyre!("no directory for {}", package_manifest_path.display()))?
+        .canonicalize()?;
+    let target_dir = get_target_dir()?;
+    let target_dir = target_dir.canonicalize().unwrap_or(target_dir);
+    let cargo_pgrx = env::current_exe().wrap_err("couldn't find the `cargo-pgrx` executable")?;
+    let args = env::args_os().skip(1).filter(|arg| arg != "--watch").collect::<Vec<_>>();
+
+    let mut generated = None;
+    loop {
+        let mut sources = source_mtimes(&package_dir, &target_dir, out);
+        if generated.as_ref() == Some(&sources) {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            continue;
+        }
+
+        // wait for things like editors and `git checkout` to finish writing files
+        loop {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            let settled = source_mtimes(&package_dir, &target_dir, out);
+            if settled == sources {
+                break;
+            }
+            sources = settled;
+        }
+
+        let status = std::process::Command::new(&cargo_pgrx)
+            .args(&args)
+            .status()
+            .wrap_err("couldn't run `cargo pgrx schema`")?;
+        if !status.success() {
+            eprintln!("{} generating the schema: {status}", "      Failed".bold().red());
+        }
+        eprintln!(
+            "{} for changes in {}",
+            "    Watching".bold().green(),
+            format_display_path(&package_dir)?.cyan()
+        );
+        generated = Some(sources);
+    }
+}
+
+/// When every file that could change the schema was last modified: the package's Rust sources,
+/// manifests, control file, and SQL files other than the one the schema is written to
+fn source_mtimes(
+    package_dir: &Path,
+    target_dir: &Path,
+    out: Option<&PathBuf>,
+) -> BTreeMap<PathBuf, SystemTime> {
+    fn visit(
+        dir: &Path,
+        target_dir: &Path,
+        out: Option<&Path>,
+        mtimes: &mut BTreeMap<PathBuf, SystemTime>,
+    ) {
+        // files can come and go while we look, so anything we can't read is skipped
+        let Ok(entries) = std::fs::read_dir(dir) else { return };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') || path == target_dir {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else { continue };
+            if metadata.is_dir() {
+                visit(&path, target_dir, out, mtimes);
+            } else if matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("rs" | "toml" | "control" | "sql")
+            ) && Some(path.as_path()) != out
+            {
+                if let Ok(modified) = metadata.modified() {
+                    mtimes.insert(path, modified);
+                }
+            }
+        }
+    }
+
+    // the schema might not have been written yet, but the directory it goes in could exist
+    let out = out.and_then(|out| {
+        let dir = out.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        Some(dir.canonicalize().ok()?.join(out.file_name()?))
+    });
+    let mut mtimes = BTreeMap::new();
+    visit(package_dir, target_dir, out.as_deref(), &mut mtimes);
+    mtimes
+}
+
 // This is *mostly* a copy of the function in `build.rs`, except using
 // `CARGO`/`cargo` rather than `RUSTC`/`rustc`. It seems too painful to try and
 // share them, given how they're close-but-not-identical.
@@ -195,17 +296,25 @@ pub(crate) fn generate_schema(
 ) -> eyre::Result<()> {
     check_rust_version()?;
     let manifest = Manifest::from_path(&package_manifest_path)?;
-    let (control_file, _extname) = find_control_file(&package_manifest_path)?;
-
-    if get_property(&package_manifest_path, "relocatable")? != Some("false".into()) {
+    if manifest.is_module_only() {
         return Err(eyre!(
-            "{}:  The `relocatable` property MUST be `false`.  Please update your .control file.",
-            control_file.display()
+            "{} is module-only (`package.metadata.pgrx.module-only`), so it has no schema",
+            manifest.extension_name()?
         ));
     }
+    let (control_file, extname) = find_control_file(&package_manifest_path)?;
 
     let versioned_so = get_property(&package_manifest_path, "module_pathname")?.is_none();
 
+    // the control file is read here, rather than by the extension, because it's named after the
+    // extension, which needn't be the name of the library
+    let control_file_entity = std::fs::read_to_string(&control_file)
+        .wrap_err_with(|| format!("couldn't read {}", control_file.display()))?
+        .replace("@CARGO_VERSION@", &manifest.package_version()?);
+    let control_file_entity =
+        pgrx_sql_entity_graph::ControlFile::try_from(control_file_entity.as_str())
+            .wrap_err_with(|| format!("couldn't parse {}", control_file.display()))?;
+
     let flags = std::env::var("PGRX_BUILD_FLAGS").unwrap_or_default();
 
     let mut target_dir_with_profile = get_target_dir()?;
@@ -253,6 +362,14 @@ pub(crate) fn generate_schema(
             command.arg("--all-features");
         }
 
+        crate::env::set_rustflags(&mut command)?;
+        crate::env::set_build_info(
+            &mut command,
+            Some(package_manifest_path.as_ref()),
+            profile,
+            features,
+        );
+
         for arg in flags.split_ascii_whitespace() {
             command.arg(arg);
         }
@@ -305,28 +422,8 @@ pub(crate) fn generate_schema(
     let lib_so_data = std::fs::read(&lib_so).wrap_err("couldn't read extension shared object")?;
     let lib_so_obj_file =
         parse_object(&*lib_so_data).wrap_err("couldn't parse extension shared object")?;
-    let lib_so_exports =
-        lib_so_obj_file.exports().wrap_err("couldn't get exports from extension shared object")?;
-
-    // Some users reported experiencing duplicate entries if we don't ensure `fns_to_call`
-    // has unique entries.
-    let mut fns_to_call = HashSet::new();
-    for export in lib_so_exports {
-        let name = std::str::from_utf8(export.name())?.to_string();
-        #[cfg(target_os = "macos")]
-        let name = {
-            // Mac will prefix symbols with `_` automatically, so we remove it to avoid getting
-            // two.
-            let mut name = name;
-            let rename = name.split_off(1);
-            assert_eq!(name, "_");
-            rename
-        };
-
-        if name.starts_with("__pgrx_internals") {
-            fns_to_call.insert(name);
-        }
-    }
+    let fns_to_call = entity_fns(&lib_so_obj_file)
+        .wrap_err("couldn't find SQL entities in extension shared object")?;
     let mut seen_schemas = Vec::new();
     let mut num_funcs = 0_usize;
     let mut num_triggers = 0_usize;
@@ -335,6 +432,7 @@ pub(crate) fn generate_schema(
     let mut num_sqls = 0_usize;
     let mut num_ords = 0_usize;
     let mut num_hashes = 0_usize;
+    let mut num_opclasses = 0_usize;
     let mut num_aggregates = 0_usize;
     for func in &fns_to_call {
         if func.starts_with("__pgrx_internals_schema_") {
@@ -358,13 +456,15 @@ pub(crate) fn generate_schema(
             num_ords += 1;
         } else if func.starts_with("__pgrx_internals_hash_") {
             num_hashes += 1;
+        } else if func.starts_with("__pgrx_internals_opclass_") {
+            num_opclasses += 1;
         } else if func.starts_with("__pgrx_internals_aggregate_") {
             num_aggregates += 1;
         }
     }
 
     eprintln!(
-        "{} {} SQL entities: {} schemas ({} unique), {} functions, {} types, {} enums, {} sqls, {} ords, {} hashes, {} aggregates, {} triggers",
+        "{} {} SQL entities: {} schemas ({} unique), {} functions, {} types, {} enums, {} sqls, {} ords, {} hashes, {} opclasses, {} aggregates, {} triggers",
         "  Discovered".bold().green(),
         fns_to_call.len().to_string().bold().cyan(),
         seen_schemas.iter().count().to_string().bold().cyan(),
@@ -375,6 +475,7 @@ pub(crate) fn generate_schema(
         num_sqls.to_string().bold().cyan(),
         num_ords.to_string().bold().cyan(),
         num_hashes.to_string().bold().cyan(),
+        num_opclasses.to_string().bold().cyan(),
         num_aggregates.to_string().bold().cyan(),
         num_triggers.to_string().bold().cyan(),
     );
@@ -404,14 +505,11 @@ pub(crate) fn generate_schema(
             })
             .wrap_err_with(|| format!("Couldn't libload {}", lib_so.display()))?;
 
-        let symbol: libloading::os::unix::Symbol<
-            unsafe extern "Rust" fn() -> eyre::Result<pgrx_sql_entity_graph::ControlFile>,
-        > = lib
-            .get("__pgrx_marker".as_bytes())
-            .expect("Couldn't call __pgrx_marker");
-        let control_file_entity = pgrx_sql_entity_graph::SqlGraphEntity::ExtensionRoot(
-            symbol().expect("Failed to get control file information"),
-        );
+        lib.get::<unsafe extern "Rust" fn()>("__pgrx_marker".as_bytes()).wrap_err_with(|| {
+            format!("{} is missing `pgrx::pg_module_magic!()`", lib_so.display())
+        })?;
+        let control_file_entity =
+            pgrx_sql_entity_graph::SqlGraphEntity::ExtensionRoot(control_file_entity);
         entities.push(control_file_entity);
 
         for symbol_to_call in fns_to_call {
@@ -423,12 +521,22 @@ pub(crate) fn generate_schema(
         }
     };
 
-    let pgrx_sql = pgrx_sql_entity_graph::PgrxSql::build(
-        entities.into_iter(),
-        manifest.lib_name()?,
-        versioned_so,
-    )
-    .wrap_err("SQL generation error")?;
+    let mut pgrx_sql =
+        pgrx_sql_entity_graph::PgrxSql::build(entities.into_iter(), extname, versioned_so)
+            .wrap_err("SQL generation error")?;
+    pgrx_sql.qualify_with_extschema = wants_extschema_qualification(&manifest);
+
+    // test builds have `#[pg_test]` functions, usually in a `#[pg_schema] mod tests`, which
+    // aren't part of any release
+    if !is_test {
+        pgrx_sql.check_relocatable().wrap_err_with(|| format!("{}", control_file.display()))?;
+        check_entity_signatures(&package_manifest_path, &pgrx_sql)?;
+    }
+    let schema_info = if wants_schema_info(&manifest) {
+        Some(schema_info_sql(&package_manifest_path, &pgrx_sql, pg_config, profile, features)?)
+    } else {
+        None
+    };
 
     if let Some(out_path) = path {
         let out_path = out_path.as_ref();
@@ -445,11 +553,22 @@ pub(crate) fn generate_schema(
         pgrx_sql
             .to_file(out_path)
             .wrap_err_with(|| eyre!("Could not write SQL to {}", out_path.display()))?;
+        if let Some(schema_info) = &schema_info {
+            use std::io::Write;
+            std::fs::OpenOptions::new()
+                .append(true)
+                .open(out_path)
+                .and_then(|mut out| out.write_all(schema_info.as_bytes()))
+                .wrap_err_with(|| eyre!("Could not write SQL to {}", out_path.display()))?;
+        }
     } else {
         eprintln!("{} SQL entities to {}", "     Writing".bold().green(), "/dev/stdout".cyan(),);
         pgrx_sql
             .write(&mut std::io::stdout())
             .wrap_err_with(|| eyre!("Could not write SQL to stdout"))?;
+        if let Some(schema_info) = &schema_info {
+            print!("{schema_info}");
+        }
     }
 
     if let Some(dot_path) = dot {
@@ -460,6 +579,155 @@ pub(crate) fn generate_schema(
     Ok(())
 }
 
+/// Warns about SQL objects that a previous version of the extension created but this one drops or
+/// defines differently, when there's no script to upgrade from that version.  To compare with,
+/// the objects each version creates are saved next to the upgrade scripts, in
+/// `sql/$EXTNAME--$VERSION.entities`, which should be checked in with them.
+fn check_entity_signatures(
+    package_manifest_path: impl AsRef<Path>,
+    pgrx_sql: &pgrx_sql_entity_graph::PgrxSql,
+) -> eyre::Result<()> {
+    let (_, extname) = find_control_file(&package_manifest_path)?;
+    let version = crate::command::install::get_version(&package_manifest_path)?;
+    let sql_dir = package_manifest_path
+        .as_ref()
+        .parent()
+        .ok_or_else(|| eyre!("no directory for {}", package_manifest_path.as_ref().display()))?
+        .join("sql");
+    let signatures = EntitySignatures::from_pgrx_sql(pgrx_sql)?;
+
+    if let Some(previous) = previous_version(&sql_dir, &extname, &version) {
+        let previous_file = sql_dir.join(format!("{extname}--{previous}.entities"));
+        let previous_signatures = EntitySignatures::parse(
+            &std::fs::read_to_string(&previous_file)
+                .wrap_err_with(|| format!("couldn't read {}", previous_file.display()))?,
+        )
+        .wrap_err_with(|| format!("couldn't parse {}", previous_file.display()))?;
+        let changes = signatures.changes_since(&previous_signatures);
+        let upgrade_script = sql_dir.join(format!("{extname}--{previous}--{version}.sql"));
+        if !changes.is_empty() && !upgrade_script.exists() {
+            eprintln!(
+                "{} version {} of {} changes SQL objects from version {}, but there's no {} to upgrade existing installations:",
+                "     Warning".bold().yellow(),
+                version.cyan(),
+                extname.cyan(),
+                previous.cyan(),
+                format_display_path(&upgrade_script)?.cyan(),
+            );
+            for change in changes {
+                eprintln!("             {change}");
+            }
+        }
+    }
+
+    let signatures_file = sql_dir.join(format!("{extname}--{version}.entities"));
+    let signatures = signatures.to_string();
+    if std::fs::read_to_string(&signatures_file).ok().as_deref() != Some(signatures.as_str()) {
+        std::fs::create_dir_all(&sql_dir)?;
+        std::fs::write(&signatures_file, signatures)
+            .wrap_err_with(|| format!("couldn't write {}", signatures_file.display()))?;
+    }
+    Ok(())
+}
+
+/// Whether the extension's `Cargo.toml` asks for a `$EXTNAME_schema_info()` function with
+///
+/// ```toml
+/// [package.metadata.pgrx]
+/// schema-info = true
+/// ```
+fn wants_schema_info(manifest: &Manifest) -> bool {
+    pgrx_metadata_flag(manifest, "schema-info")
+}
+
+/// Whether the extension's `Cargo.toml` asks for references to its own objects to be qualified
+/// with `@extschema@`, with
+///
+/// ```toml
+/// [package.metadata.pgrx]
+/// qualify-with-extschema = true
+/// ```
+fn wants_extschema_qualification(manifest: &Manifest) -> bool {
+    pgrx_metadata_flag(manifest, "qualify-with-extschema")
+}
+
+fn pgrx_metadata_flag(manifest: &Manifest, key: &str) -> bool {
+    manifest
+        .package
+        .as_ref()
+        .and_then(|package| package.metadata.as_ref())
+        .and_then(|metadata| metadata.get("pgrx"))
+        .and_then(|pgrx| pgrx.get(key))
+        .and_then(|flag| flag.as_bool())
+        .unwrap_or(false)
+}
+
+/// A `$EXTNAME_schema_info()` function that returns, as `jsonb`, what build of the extension is
+/// installed and the SQL objects it created, so they can be checked from SQL
+fn schema_info_sql(
+    package_manifest_path: impl AsRef<Path>,
+    pgrx_sql: &pgrx_sql_entity_graph::PgrxSql,
+    pg_config: &PgConfig,
+    profile: &CargoProfile,
+    features: &clap_cargo::Features,
+) -> eyre::Result<String> {
+    let (_, extname) = find_control_file(&package_manifest_path)?;
+    let entities = EntitySignatures::from_pgrx_sql(pgrx_sql)?
+        .iter()
+        .map(|(kind, name, definition)| {
+            serde_json::json!({ "kind": kind, "name": name, "definition": definition })
+        })
+        .collect::<Vec<_>>();
+    let info = serde_json::json!({
+        "extension": extname,
+        "version": crate::command::install::get_version(&package_manifest_path)?,
+        // not every extension is built from a git checkout
+        "git_hash": get_property(&package_manifest_path, "git_hash").ok().flatten(),
+        "features": features.features,
+        "default_features": !features.no_default_features,
+        "all_features": features.all_features,
+        "profile": profile.name(),
+        "postgres_version": pg_config.major_version()?,
+        "cargo_pgrx_version": env!("CARGO_PKG_VERSION"),
+        "entities": entities,
+    });
+
+    Ok(format!(
+        "\n/* generated by cargo-pgrx, because of `package.metadata.pgrx.schema-info` */\n\
+        CREATE FUNCTION \"{extname}_schema_info\"() RETURNS jsonb\n\
+        IMMUTABLE PARALLEL SAFE\n\
+        LANGUAGE sql\n\
+        AS $pgrx_schema_info$ SELECT '{}'::jsonb $pgrx_schema_info$;\n",
+        serde_json::to_string_pretty(&info)?.replace('\'', "''")
+    ))
+}
+
+/// The latest version before `version` that has its SQL objects saved in `sql_dir`
+fn previous_version(sql_dir: &Path, extname: &str, version: &str) -> Option<String> {
+    let prefix = format!("{extname}--");
+    let versions = std::fs::read_dir(sql_dir)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let filename = entry.file_name().into_string().ok()?;
+            let other = filename.strip_prefix(&prefix)?.strip_suffix(".entities")?;
+            // not an upgrade script's, nor this version's
+            (!other.contains("--") && other != version).then(|| other.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    match semver::Version::parse(version) {
+        Ok(current) => versions
+            .into_iter()
+            .filter_map(|other| Some((semver::Version::parse(&other).ok()?, other)))
+            .filter(|(parsed, _)| *parsed < current)
+            .max()
+            .map(|(_, other)| other),
+        // without semantic versions to go by, the best guess is the one that sorts last
+        Err(_) => versions.into_iter().max(),
+    }
+}
+
 #[tracing::instrument(level = "error", skip_all, fields(
     postmaster_path = %format_display_path(postmaster_path.as_ref())?,
     postmaster_stub_dir = %format_display_path(postmaster_stub_dir.as_ref())?,
@@ -529,6 +797,9 @@ fn create_stub(
         let rustc_flags = rustc_flags_str.split(' ').collect::<Vec<_>>();
         so_rustc_invocation.args(rustc_flags);
     }
+    if cfg!(target_env = "musl") {
+        so_rustc_invocation.args(["-C", "target-feature=-crt-static"]);
+    }
 
     so_rustc_invocation.args([
         "--crate-type",
@@ -560,6 +831,46 @@ fn create_stub(
     Ok(postmaster_stub_built)
 }
 
+/// Finds the `__pgrx_internals` functions which the generator should call.
+///
+/// They're listed in the entity section written by the `pgrx` macros, which we read straight from
+/// the file.  Extensions built against a `pgrx` that predates the section are still scanned for
+/// exports instead.
+fn entity_fns(obj: &object::File) -> eyre::Result<HashSet<String>> {
+    use pgrx_sql_entity_graph::section;
+
+    // Some users reported experiencing duplicate entries if we don't ensure `fns_to_call`
+    // has unique entries.
+    let mut fns_to_call = HashSet::new();
+    let entity_section = obj
+        .section_by_name(section::ELF_SECTION)
+        .or_else(|| obj.section_by_name(section::MACHO_SECTION));
+    if let Some(entity_section) = entity_section {
+        let data = entity_section.uncompressed_data().wrap_err("couldn't read entity section")?;
+        fns_to_call.extend(section::parse_entries(&data).map(String::from));
+        return Ok(fns_to_call);
+    }
+
+    let exports = obj.exports().wrap_err("couldn't get exports from extension shared object")?;
+    for export in exports {
+        let name = std::str::from_utf8(export.name())?.to_string();
+        #[cfg(target_os = "macos")]
+        let name = {
+            // Mac will prefix symbols with `_` automatically, so we remove it to avoid getting
+            // two.
+            let mut name = name;
+            let rename = name.split_off(1);
+            assert_eq!(name, "_");
+            rename
+        };
+
+        if name.starts_with("__pgrx_internals") {
+            fns_to_call.insert(name);
+        }
+    }
+    Ok(fns_to_call)
+}
+
 fn parse_object(data: &[u8]) -> object::Result<object::File> {
     let kind = object::FileKind::parse(&*data)?;
 
@@ -7,7 +7,7 @@
 //LICENSE All rights reserved.
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
-use crate::command::init::initdb;
+use crate::command::init::ensure_data_dir;
 use crate::command::status::status_postgres;
 use crate::manifest::{get_package_manifest, pg_config_and_version};
 use crate::CommandExecute;
@@ -71,9 +71,8 @@ pub(crate) fn start_postgres(pg_config: &PgConfig) -> eyre::Result<()> {
     let bindir = pg_config.bin_dir()?;
     let port = pg_config.port()?;
 
-    if !datadir.try_exists()? {
-        initdb(&bindir, &datadir)?;
-    }
+    let initdb_options = Pgrx::from_config()?.initdb_options(&pg_config.label()?);
+    ensure_data_dir(pg_config, &initdb_options)?;
 
     if status_postgres(pg_config)? {
         tracing::debug!("Already started");
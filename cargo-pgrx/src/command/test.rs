@@ -8,11 +8,16 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 use eyre::Context;
+use owo_colors::OwoColorize;
 use pgrx_pg_config::{get_target_dir, PgConfig, PgConfigSelector, Pgrx};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 
-use crate::manifest::{get_package_manifest, pg_config_and_version};
+use crate::command::init::InitdbArgs;
+use crate::manifest::{
+    display_version_info, get_package_manifest, modify_features_for_version, pg_config_and_version,
+    PgVersionSource,
+};
 use crate::profile::CargoProfile;
 use crate::CommandExecute;
 
@@ -40,6 +45,30 @@ pub(crate) struct Test {
     /// Don't regenerate the schema
     #[clap(long, short)]
     no_schema: bool,
+    /// Build with debuginfo, run tests one at a time, and pause each one until a debugger (see
+    /// `cargo pgrx debug`) attaches to its backend
+    #[clap(long)]
+    wait_for_debugger: bool,
+    /// Run each test on a backend picked at random from a small shared pool, after a random
+    /// delay, instead of on a fresh backend of its own.  This flushes out tests that depend on
+    /// state left behind by other tests.  Use `--shuffle=SEED` with the seed printed by an
+    /// earlier run to repeat it
+    #[clap(long, value_name = "SEED", require_equals = true)]
+    shuffle: Option<Option<u64>>,
+    /// Run each test this many times
+    #[clap(long, value_name = "N", default_value_t = 1)]
+    repeat: usize,
+    /// Write a line of JSON to this file for every test run, with its status, Postgres version,
+    /// duration, and, if it failed, the failure message and the end of its backend's log
+    #[clap(long, value_name = "FILE", value_parser)]
+    json_results: Option<PathBuf>,
+    #[clap(flatten)]
+    initdb: InitdbArgs,
+    /// Run the tests against an existing, already running, Postgres instead of one managed by
+    /// pgrx, installing the extension with this `pg_config`.  The server is found with the usual
+    /// `PGHOST`, `PGPORT`, `PGUSER`, and `PGPASSWORD` environment variables
+    #[clap(long, short = 'c', env = "PGRX_TEST_PG_CONFIG")]
+    pg_config: Option<PathBuf>,
     #[clap(flatten)]
     features: clap_cargo::Features,
     #[clap(from_global, action = clap::ArgAction::Count)]
@@ -54,13 +83,34 @@ impl CommandExecute for Test {
             let mut features = me.features.clone();
             let (package_manifest, _package_manifest_path) =
                 get_package_manifest(&me.features, me.package.as_ref(), me.manifest_path.as_ref())?;
-            let (pg_config, _pg_version) = pg_config_and_version(
-                &pgrx,
-                &package_manifest,
-                me.pg_version.clone(),
-                Some(&mut features),
-                true,
-            )?;
+            let pg_config = match &me.pg_config {
+                Some(path) => {
+                    let pg_config = PgConfig::new_with_defaults(path.clone());
+                    let pg_version = PgVersionSource::PgConfig(pg_config.label()?);
+                    modify_features_for_version(
+                        pgrx,
+                        Some(&mut features),
+                        &package_manifest,
+                        &pg_version,
+                        true,
+                    );
+                    display_version_info(&pg_config, &pg_version);
+                    pg_config
+                }
+                None => {
+                    let (pg_config, _pg_version) = pg_config_and_version(
+                        &pgrx,
+                        &package_manifest,
+                        me.pg_version.clone(),
+                        Some(&mut features),
+                        true,
+                    )?;
+                    me.initdb.save(&pg_config)?;
+                    pg_config
+                }
+            };
+
+            let stress = TestStress::new(me.shuffle, me.repeat);
 
             let profile = CargoProfile::from_flags(
                 me.profile.as_deref(),
@@ -73,6 +123,10 @@ impl CommandExecute for Test {
                 me.package.as_ref(),
                 &profile,
                 me.no_schema,
+                me.wait_for_debugger,
+                &stress,
+                me.json_results.as_deref(),
+                me.pg_config.is_some(),
                 &features,
                 me.testname,
             )?;
@@ -80,6 +134,17 @@ impl CommandExecute for Test {
             Ok(())
         }
 
+        if let Some(path) = &self.json_results {
+            // every Postgres version tested appends to the same file
+            std::fs::write(path, "")
+                .wrap_err_with(|| format!("unable to create `{}`", path.display()))?;
+        }
+
+        if self.pg_config.is_some() {
+            // an external Postgres doesn't need anything from `cargo pgrx init`
+            return perform(self, &Pgrx::default());
+        }
+
         let pgrx = Pgrx::from_config()?;
         if self.pg_version == Some("all".to_string()) {
             // run the tests for **all** the Postgres versions we know about
@@ -97,6 +162,26 @@ impl CommandExecute for Test {
     }
 }
 
+/// How `--shuffle` and `--repeat` ask the test harness to stress the tests
+#[derive(Debug, Clone, Copy)]
+pub struct TestStress {
+    pub shuffle_seed: Option<u64>,
+    pub repeat: usize,
+}
+
+impl TestStress {
+    fn new(shuffle: Option<Option<u64>>, repeat: usize) -> Self {
+        let shuffle_seed = shuffle.map(|seed| {
+            seed.unwrap_or_else(|| {
+                use std::time::{SystemTime, UNIX_EPOCH};
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+                now.as_nanos() as u64 ^ std::process::id() as u64
+            })
+        });
+        TestStress { shuffle_seed, repeat }
+    }
+}
+
 #[tracing::instrument(skip_all, fields(
     pg_version = %pg_config.version()?,
     testname =  tracing::field::Empty,
@@ -108,6 +193,10 @@ pub fn test_extension(
     user_package: Option<&String>,
     profile: &CargoProfile,
     no_schema: bool,
+    wait_for_debugger: bool,
+    stress: &TestStress,
+    json_results: Option<&Path>,
+    external: bool,
     features: &clap_cargo::Features,
     testname: Option<impl AsRef<str>>,
 ) -> eyre::Result<()> {
@@ -139,6 +228,36 @@ pub fn test_extension(
         command.env("RUST_LOG", rust_log);
     }
 
+    command.env("PGRX_TEST_REPEAT", stress.repeat.max(1).to_string());
+    match stress.shuffle_seed {
+        Some(seed) => {
+            eprintln!(
+                "{} tests with seed {seed} (rerun with `--shuffle={seed}`)",
+                "Shuffling".bold().green()
+            );
+            command.env("PGRX_TEST_SHUFFLE_SEED", seed.to_string());
+        }
+        None => {
+            command.env_remove("PGRX_TEST_SHUFFLE_SEED");
+        }
+    }
+
+    match json_results {
+        Some(path) => {
+            // the tests run in the crate's directory, not necessarily the current one
+            command.env("PGRX_TEST_RESULTS", std::env::current_dir()?.join(path));
+        }
+        None => {
+            command.env_remove("PGRX_TEST_RESULTS");
+        }
+    }
+
+    if external {
+        command.env("PGRX_TEST_PG_CONFIG", pg_config.path().expect("no path for pg_config"));
+    } else {
+        command.env_remove("PGRX_TEST_PG_CONFIG");
+    }
+
     if !features_arg.trim().is_empty() {
         command.arg("--features");
         command.arg(&features_arg);
@@ -153,6 +272,7 @@ pub fn test_extension(
     }
 
     command.args(profile.cargo_args());
+    crate::env::set_rustflags(&mut command)?;
 
     if let Some(user_manifest_path) = user_manifest_path {
         command.arg("--manifest-path");
@@ -168,6 +288,18 @@ pub fn test_extension(
         command.arg(testname.as_ref());
     }
 
+    if wait_for_debugger {
+        let profile_env = profile.name().to_uppercase().replace('-', "_");
+        command
+            .env(format!("CARGO_PROFILE_{profile_env}_DEBUG"), "true")
+            .env("PGRX_WAIT_FOR_DEBUGGER", "true")
+            // so the backend PID is printed as soon as each test starts, and only one test waits
+            // at a time
+            .arg("--")
+            .arg("--nocapture")
+            .arg("--test-threads=1");
+    }
+
     eprintln!("{:?}", command);
 
     tracing::debug!(command = ?command, "Running");
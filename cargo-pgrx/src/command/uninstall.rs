@@ -0,0 +1,100 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use crate::command::get::installed_name;
+use crate::command::install::{format_display_path, sha256_file, InstallManifest};
+use crate::CommandExecute;
+use eyre::{eyre, WrapErr};
+use owo_colors::OwoColorize;
+use pgrx_pg_config::PgConfig;
+use std::path::PathBuf;
+
+/// Remove the files `cargo pgrx install` installed for the current crate's extension from the
+/// Postgres specified by whatever `pg_config` is currently on your $PATH
+#[derive(clap::Args, Debug)]
+#[clap(author)]
+pub(crate) struct Uninstall {
+    /// Package to uninstall (see `cargo help pkgid`)
+    #[clap(long, short)]
+    package: Option<String>,
+    /// Path to Cargo.toml
+    #[clap(long, value_parser)]
+    manifest_path: Option<PathBuf>,
+    /// The `pg_config` path (default is first in $PATH)
+    #[clap(long, short = 'c')]
+    pg_config: Option<String>,
+    /// Also remove files that were changed since they were installed
+    #[clap(long)]
+    force: bool,
+    #[clap(from_global, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+impl CommandExecute for Uninstall {
+    #[tracing::instrument(level = "error", skip(self))]
+    fn execute(self) -> eyre::Result<()> {
+        let metadata = crate::metadata::metadata(&Default::default(), self.manifest_path.as_ref())
+            .wrap_err("couldn't get cargo metadata")?;
+        crate::metadata::validate(&metadata)?;
+        let package_manifest_path =
+            crate::manifest::manifest_path(&metadata, self.package.as_ref())
+                .wrap_err("Couldn't get manifest path")?;
+        let extname = installed_name(&package_manifest_path)?;
+
+        let pg_config = match self.pg_config {
+            None => PgConfig::from_path(),
+            Some(config) => PgConfig::new_with_defaults(PathBuf::from(config)),
+        };
+        uninstall_extension(&pg_config, &extname, self.force)
+    }
+}
+
+#[tracing::instrument(level = "error", skip(pg_config), fields(pg_version = %pg_config.version()?))]
+pub(crate) fn uninstall_extension(
+    pg_config: &PgConfig,
+    extname: &str,
+    force: bool,
+) -> eyre::Result<()> {
+    let mut install_manifest_path = pg_config.extension_dir()?;
+    install_manifest_path.push(InstallManifest::filename(extname));
+    let install_manifest = InstallManifest::load(&install_manifest_path)?.ok_or_else(|| {
+        eyre!(
+            "no install manifest at `{}`, was {extname} installed by `cargo pgrx install`?",
+            install_manifest_path.display()
+        )
+    })?;
+
+    println!("{} {extname}", "Uninstalling".bold().green());
+    let mut kept = 0;
+    for (file, hash) in install_manifest.files() {
+        if !file.exists() {
+            continue;
+        }
+        if !force && sha256_file(file)? != hash {
+            println!(
+                "{} {}, it was changed since it was installed (use --force to remove it anyway)",
+                "     Keeping".bold().yellow(),
+                format_display_path(file)?.cyan()
+            );
+            kept += 1;
+            continue;
+        }
+
+        println!("{} {}", "    Removing".bold().green(), format_display_path(file)?.cyan());
+        std::fs::remove_file(file)
+            .wrap_err_with(|| format!("failed to remove `{}`", file.display()))?;
+    }
+
+    if kept == 0 {
+        std::fs::remove_file(&install_manifest_path)
+            .wrap_err_with(|| format!("failed to remove `{}`", install_manifest_path.display()))?;
+    }
+    println!("{} uninstalling {extname}", "    Finished".bold().green());
+    Ok(())
+}
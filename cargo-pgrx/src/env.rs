@@ -12,6 +12,173 @@ pub(crate) fn cargo() -> std::process::Command {
     std::process::Command::new(cargo)
 }
 
+/// Whether `cargo pgrx` was asked, with `--reproducible` or `PGRX_REPRODUCIBLE=true`, to build
+/// extensions so that the same sources build to byte-identical shared libraries
+pub(crate) fn is_reproducible() -> bool {
+    matches!(std::env::var("PGRX_REPRODUCIBLE").as_deref(), Ok("true" | "1"))
+}
+
+/// Add the `rustflags` that building an extension needs to a `cargo` command.
+///
+/// A `cdylib` can't be built for musl targets, like Alpine's, while they statically link the C
+/// runtime, as they do by default, so that's turned off for them.  Reproducible builds also
+/// remap the paths of the machine doing the build and make the ELF build ID content-derived.
+pub(crate) fn set_rustflags(command: &mut std::process::Command) -> eyre::Result<()> {
+    let rustflags = rustflags()?;
+    if is_reproducible() {
+        command.env("CARGO_INCREMENTAL", "0");
+    }
+
+    // cargo ignores `rustflags` from its configuration when either of these are set, so they need
+    // the flags themselves.  Turning off `crt-static` is already the default everywhere but musl
+    let from_env = match std::env::var("CARGO_ENCODED_RUSTFLAGS") {
+        Ok(flags) => Some(flags.split('\x1f').map(String::from).collect::<Vec<_>>()),
+        Err(_) => std::env::var("RUSTFLAGS")
+            .ok()
+            .map(|flags| flags.split_ascii_whitespace().map(String::from).collect()),
+    };
+    match from_env {
+        Some(mut flags) => {
+            flags.retain(|flag| !flag.is_empty());
+            for (cfg, extra) in rustflags {
+                if cfg.contains("musl") && flags.iter().any(|flag| flag.contains("crt-static")) {
+                    continue;
+                }
+                if cfg.contains("linux") && !cfg!(target_os = "linux") {
+                    continue;
+                }
+                flags.extend(extra);
+            }
+            command.env_remove("RUSTFLAGS");
+            command.env("CARGO_ENCODED_RUSTFLAGS", flags.join("\x1f"));
+        }
+        None => {
+            for (cfg, extra) in rustflags {
+                let extra = extra
+                    .iter()
+                    .map(|flag| format!("\"{}\"", flag.replace('\\', "\\\\").replace('"', "\\\"")))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                command.arg("--config");
+                command.arg(format!("target.'{cfg}'.rustflags = [{extra}]"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The `rustflags` [`set_rustflags`] adds, with the `cfg(...)` of the targets each applies to
+fn rustflags() -> eyre::Result<Vec<(&'static str, Vec<String>)>> {
+    let mut rustflags = vec![(
+        r#"cfg(target_env = "musl")"#,
+        vec!["-C".to_string(), "target-feature=-crt-static".to_string()],
+    )];
+    if is_reproducible() {
+        rustflags.push(("cfg(all())", remap_path_prefixes()?));
+        rustflags.push((
+            r#"cfg(target_os = "linux")"#,
+            vec!["-C".to_string(), "link-arg=-Wl,--build-id=sha1".to_string()],
+        ));
+    }
+    Ok(rustflags)
+}
+
+/// The `rustflags` [`set_rustflags`] adds when building for this machine
+pub(crate) fn host_rustflags() -> eyre::Result<Vec<String>> {
+    let applies = |cfg: &str| match cfg {
+        "cfg(all())" => true,
+        r#"cfg(target_env = "musl")"# => cfg!(target_env = "musl"),
+        r#"cfg(target_os = "linux")"# => cfg!(target_os = "linux"),
+        _ => false,
+    };
+    Ok(rustflags()?
+        .into_iter()
+        .filter(|(cfg, _)| applies(cfg))
+        .flat_map(|(_, flags)| flags)
+        .collect())
+}
+
+/// Set the environment variables `pgrx::build_info!()` embeds into an extension, to describe how
+/// `cargo pgrx` built it
+pub(crate) fn set_build_info(
+    command: &mut std::process::Command,
+    manifest_path: Option<&std::path::Path>,
+    profile: &crate::profile::CargoProfile,
+    features: &clap_cargo::Features,
+) {
+    let mut requested = Vec::new();
+    if features.all_features {
+        requested.push("all");
+    } else {
+        if !features.no_default_features {
+            requested.push("default");
+        }
+        requested.extend(features.features.iter().flat_map(|f| f.split_ascii_whitespace()));
+    }
+    command.env("PGRX_BUILD_FEATURES", requested.join(" "));
+    command.env("PGRX_BUILD_PROFILE", profile.name());
+
+    let output_of = |command: &mut std::process::Command| {
+        command
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|stdout| stdout.trim().to_string())
+    };
+    match output_of(rustc().arg("--version")) {
+        Some(version) => command.env("PGRX_BUILD_RUSTC_VERSION", version),
+        None => command.env_remove("PGRX_BUILD_RUSTC_VERSION"),
+    };
+
+    let mut git = std::process::Command::new("git");
+    git.args(["rev-parse", "HEAD"]).stderr(std::process::Stdio::null());
+    if let Some(dir) = manifest_path.and_then(|path| path.parent()) {
+        if !dir.as_os_str().is_empty() {
+            git.current_dir(dir);
+        }
+    }
+    // the extension might not be in a git checkout, or git might not be installed
+    match output_of(&mut git) {
+        Some(hash) => command.env("PGRX_BUILD_GIT_HASH", hash),
+        None => command.env_remove("PGRX_BUILD_GIT_HASH"),
+    };
+}
+
+/// `--remap-path-prefix`es for everywhere the paths of the machine doing the build could leak
+/// into what it builds.  Later ones win when they overlap, so they go from least to most specific.
+fn remap_path_prefixes() -> eyre::Result<Vec<String>> {
+    let mut remaps = Vec::new();
+
+    let sysroot = rustc().arg("--print").arg("sysroot").output()?;
+    if sysroot.status.success() {
+        remaps.push((String::from_utf8(sysroot.stdout)?.trim().to_string(), "/rustc"));
+    }
+
+    let cargo_home = std::env::var("CARGO_HOME")
+        .ok()
+        .or_else(|| std::env::var("HOME").ok().map(|home| format!("{home}/.cargo")));
+    if let Some(cargo_home) = cargo_home {
+        remaps.push((cargo_home, "/cargo"));
+    }
+
+    let workspace_manifest = cargo()
+        .args(["locate-project", "--workspace", "--message-format", "plain"])
+        .stderr(std::process::Stdio::inherit())
+        .output()?;
+    if workspace_manifest.status.success() {
+        let manifest =
+            std::path::PathBuf::from(String::from_utf8(workspace_manifest.stdout)?.trim());
+        if let Some(workspace) = manifest.parent() {
+            remaps.push((workspace.display().to_string(), "/build"));
+        }
+    }
+
+    remaps.push((pgrx_pg_config::get_target_dir()?.display().to_string(), "/build/target"));
+
+    Ok(remaps.into_iter().map(|(from, to)| format!("--remap-path-prefix={from}={to}")).collect())
+}
+
 pub(crate) fn rustc() -> std::process::Command {
     let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
     std::process::Command::new(rustc)
@@ -119,7 +119,7 @@ impl Aggregate for IntegerAvgState {
     //     unimplemented!()
     // }
 
-    // fn deserial(current: Self::State, _buf: Vec<u8>, _internal: PgBox<Self::State>, _fcinfo: pgrx::pg_sys::FunctionCallInfo) -> PgBox<Self::State> {
+    // fn deserial(_buf: Vec<u8>, _fcinfo: pgrx::pg_sys::FunctionCallInfo) -> Self::State {
     //     unimplemented!()
     // }
 
@@ -20,18 +20,50 @@ use syn::{parse_macro_input, Attribute, Data, DeriveInput, Item, ItemImpl};
 use operators::{impl_postgres_eq, impl_postgres_hash, impl_postgres_ord};
 use pgrx_sql_entity_graph::{
     parse_extern_attributes, CodeEnrichment, ExtensionSql, ExtensionSqlFile, ExternArgs,
-    PgAggregate, PgExtern, PostgresEnum, PostgresType, Schema,
+    PgAggregate, PgExtern, PgOpClass, PgTextSearch, PostgresEnum, PostgresType, Schema,
 };
 
 use crate::rewriter::PgGuardRewriter;
+use crate::test_conditions::TestConditions;
 
 mod operators;
 mod rewriter;
+mod sql;
+mod test_conditions;
 
 /// Declare a function as `#[pg_guard]` to indicate that it is called from a Postgres `extern "C"`
 /// function so that Rust `panic!()`s (and Postgres `elog(ERROR)`s) will be properly handled by `pgrx`
+///
+/// Applied to an `extern "C" { }` block, each function in it is wrapped so that an `ERROR` it
+/// raises becomes a Rust panic.  That wrapper costs a `sigsetjmp()` per call, which can show up in
+/// profiles of tiny functions called for every row.  A function that can *never* raise an `ERROR`
+/// can be left unwrapped with `#[pg_guard(skip)]`, which must be justified in a `# Safety`
+/// section of its documentation:
+///
+/// ```rust,ignore
+/// #[pg_guard]
+/// extern "C" {
+///     /// # Safety
+///     ///
+///     /// Only compares bytes: it doesn't allocate, and can't `ereport()`.
+///     #[pg_guard(skip)]
+///     fn pg_strcasecmp(s1: *const c_char, s2: *const c_char) -> c_int;
+/// }
+/// ```
+///
+/// Calling a skipped function that does raise an `ERROR` longjmps straight over Rust frames, which
+/// is undefined behavior.
 #[proc_macro_attribute]
-pub fn pg_guard(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn pg_guard(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            "`#[pg_guard(skip)]` applies to functions inside a `#[pg_guard] extern \"C\" { }` block",
+        )
+        .into_compile_error()
+        .into();
+    }
+
     // get a usable token stream
     let ast = parse_macro_input!(item as syn::Item);
 
@@ -52,12 +84,142 @@ pub fn pg_guard(_attr: TokenStream, item: TokenStream) -> TokenStream {
     res.unwrap_or_else(|e| e.into_compile_error()).into()
 }
 
+/// Mark the function Postgres calls once, right after loading the extension's shared library,
+/// instead of writing `_PG_init()` by hand.
+///
+/// The function takes no arguments and returns nothing.  It runs before anything else in the
+/// library, in every process that loads it: the postmaster, when the library is in
+/// `shared_preload_libraries`, and otherwise each backend that uses the extension.  It's the place
+/// to define GUCs, call `pg_shmem_init!()`, register static background workers, and install hooks.
+/// Calling `pg_shmem_init!()`, loading a static background worker, or defining a `Postmaster` GUC
+/// after it has returned panics.  There is no transaction, so it can't use SPI or look anything up
+/// in the catalogs.
+///
+/// ```rust,ignore
+/// #[pg_init]
+/// fn init() {
+///     GucRegistry::define_bool_guc("my_extension.enabled", "", "", &ENABLED, GucContext::Userset, GucFlags::default());
+/// }
+/// ```
+///
+/// A library can have only one, as only one `_PG_init` symbol can be defined.  See `pgrx::init`.
+#[proc_macro_attribute]
+pub fn pg_init(attr: TokenStream, item: TokenStream) -> TokenStream {
+    lifecycle_function(attr, item, "pg_init", "_PG_init", "run_init")
+}
+
+/// Mark a function to call when the backend exits, if the extension's shared library has a
+/// [`macro@pg_init`] function.
+///
+/// Postgres never unloads a library, so this is the last chance to release what the library holds
+/// outside of Postgres, like files or connections to other services.  It runs while the process
+/// exits, after its last transaction: it can't use SPI, and an `ERROR` from it is only logged.
+/// It isn't called if the process crashes.
+#[proc_macro_attribute]
+pub fn pg_fini(attr: TokenStream, item: TokenStream) -> TokenStream {
+    lifecycle_function(attr, item, "pg_fini", "_PG_fini", "run_fini")
+}
+
+/// Keep `item`, and generate the `symbol` Postgres calls, which hands it to `runner` in `pgrx::init`
+fn lifecycle_function(
+    attr: TokenStream,
+    item: TokenStream,
+    name: &str,
+    symbol: &str,
+    runner: &str,
+) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            format!("#[{name}] takes no arguments"),
+        )
+        .into_compile_error()
+        .into();
+    }
+    let func = parse_macro_input!(item as syn::ItemFn);
+    let sig = &func.sig;
+    if !sig.inputs.is_empty()
+        || !matches!(sig.output, syn::ReturnType::Default)
+        || !sig.generics.params.is_empty()
+        || sig.asyncness.is_some()
+        || sig.abi.is_some()
+    {
+        return syn::Error::new(
+            sig.span(),
+            format!(
+                "#[{name}] applies to a plain `fn()` that takes no arguments and returns nothing"
+            ),
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    let ident = &sig.ident;
+    let symbol = Ident::new(symbol, proc_macro2::Span::call_site());
+    let runner = Ident::new(runner, proc_macro2::Span::call_site());
+    // `#[pg_init]` can't name the `#[pg_fini]` function, so they meet at the `__PgrxLibrary`
+    // that `pg_module_magic!()` declares in the root of the crate
+    let (args, register) = if name == "pg_fini" {
+        let register = quote! {
+            impl ::pgrx::init::PgFini for crate::__PgrxLibrary {
+                fn fini() {
+                    #ident()
+                }
+            }
+        };
+        (quote! { #ident }, register)
+    } else {
+        let args = quote! {
+            #ident,
+            {
+                #[allow(unused_imports)]
+                use ::pgrx::init::{WithFini as _, WithoutFini as _};
+                (&&::pgrx::init::Library::<crate::__PgrxLibrary>::new()).fini()
+            }
+        };
+        (args, quote! {})
+    };
+    quote! {
+        #func
+
+        #[::pgrx::pg_guard]
+        pub extern "C" fn #symbol() {
+            ::pgrx::init::#runner(#args)
+        }
+
+        #register
+    }
+    .into()
+}
+
 /// `#[pg_test]` functions are test functions (akin to `#[test]`), but they run in-process inside
 /// Postgres during `cargo pgrx test`.
+///
+/// Besides the arguments `#[pg_extern]` accepts, these skip the test when they aren't met, so that
+/// one test suite can run cleanly against every supported version of Postgres:
+///
+/// - `min_pg = 14` and `max_pg = 13`: the oldest and newest Postgres major versions to run on
+/// - `skip_if = "path::to::function"`: a `fn() -> bool`, called in the test process, that returns
+///   `true` when the test should be skipped
+/// - `requires_extension = "hstore"`: an extension that must be available, which is created in
+///   the test database before the test runs.  May be given more than once
+///
+/// ```rust,ignore
+/// #[pg_test(min_pg = 14, requires_extension = "hstore")]
+/// fn test_hstore_subscripting() {
+///     assert_eq!(Spi::get_one("SELECT ('a=>1'::hstore)['a']"), Ok(Some("1")));
+/// }
+/// ```
+///
+/// Skipped tests pass, and say why they were skipped on stderr.
 #[proc_macro_attribute]
 pub fn pg_test(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut stream = proc_macro2::TokenStream::new();
-    let args = parse_extern_attributes(proc_macro2::TokenStream::from(attr.clone()));
+    let (attr, conditions) = match TestConditions::extract(attr.into()) {
+        Ok(extracted) => extracted,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let args = parse_extern_attributes(attr.clone());
 
     let mut expected_error = None;
     args.into_iter().for_each(|v| {
@@ -92,7 +254,7 @@ pub fn pg_test(attr: TokenStream, item: TokenStream) -> TokenStream {
             func.attrs = non_test_attributes;
 
             stream.extend(proc_macro2::TokenStream::from(pg_extern(
-                attr,
+                attr.into(),
                 Item::Fn(func.clone()).to_token_stream().into(),
             )));
 
@@ -115,6 +277,16 @@ pub fn pg_test(attr: TokenStream, item: TokenStream) -> TokenStream {
                 });
             }
 
+            let run_test = if conditions.is_empty() {
+                quote! {
+                    pgrx_tests::run_test(#sql_funcname, #expected_error, crate::pg_test::postgresql_conf_options())
+                }
+            } else {
+                quote! {
+                    pgrx_tests::run_test_with_conditions(#sql_funcname, #expected_error, crate::pg_test::postgresql_conf_options(), #conditions)
+                }
+            };
+
             stream.extend(quote! {
                 #[test]
                 #(#test_attributes)*
@@ -123,7 +295,7 @@ pub fn pg_test(attr: TokenStream, item: TokenStream) -> TokenStream {
                     #att_stream
 
                     crate::pg_test::setup(options);
-                    let res = pgrx_tests::run_test(#sql_funcname, #expected_error, crate::pg_test::postgresql_conf_options());
+                    let res = #run_test;
                     match res {
                         Ok(()) => (),
                         Err(e) => panic!("{:?}", e)
@@ -177,13 +349,17 @@ pub fn negator(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
-/// Used with `#[pg_operator]`.  1 value which is the function name
+/// Used with `#[pg_operator]`.  1 value which is the function name, either a built-in estimator
+/// like `eqsel` or a `#[pg_extern]` function taking `(Internal, pg_sys::Oid, Internal, i32)` and
+/// returning `f64`
 #[proc_macro_attribute]
 pub fn restrict(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
-/// Used with `#[pg_operator]`.  1 value which is the function name
+/// Used with `#[pg_operator]`.  1 value which is the function name, either a built-in estimator
+/// like `eqjoinsel` or a `#[pg_extern]` function taking `(Internal, pg_sys::Oid, Internal, i16,
+/// Internal)` and returning `f64`
 #[proc_macro_attribute]
 pub fn join(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
@@ -326,6 +502,20 @@ extension_sql!(r#"
 );
 ```
 
+To refer to the extension's own objects no matter the `search_path`, qualify them with
+`@extschema@`, which Postgres replaces with the extension's schema while running `CREATE EXTENSION`
+(only for extensions that aren't `relocatable`, which `cargo pgrx schema` checks):
+
+```rust,ignore
+use pgrx_macros::extension_sql;
+
+extension_sql!(r#"
+    CREATE VIEW answers AS SELECT @extschema@.answer() AS answer;
+    "#,
+    name = "answers",
+);
+```
+
 To declare the SQL defines some entity (**Caution:** This is not recommended usage):
 
 ```rust,ignore
@@ -434,6 +624,82 @@ pub fn extension_sql_file(input: TokenStream) -> TokenStream {
     }
 }
 
+/**
+Build an SQL statement from a template, quoting each interpolated value according to its placeholder:
+
+* `{ident}` quotes the value as an identifier, with `pgrx::quote::quote_ident()`
+* `{literal}` converts the value `to_string()` and quotes it as a literal, with
+  `pgrx::quote::quote_literal()`
+* `{nullable}` does the same for an `Option`, giving `NULL` for `None`
+
+Placeholders take the next positional argument, or name a variable in scope like
+`{table:ident}`.  Use `{{` and `}}` for literal braces.  There's deliberately no way to interpolate a
+value unquoted.  The template is checked when compiling, and this evaluates to a `String`.
+
+```rust,ignore
+use pgrx::prelude::*;
+
+let schema = "public";
+let table = "my table";
+let query = sql!("SELECT * FROM {schema:ident}.{table:ident} WHERE name = {literal}", "O'Brien");
+assert_eq!(query, r#"SELECT * FROM public."my table" WHERE name = 'O''Brien'"#);
+```
+
+```rust,ignore
+use pgrx::prelude::*;
+
+let table = "my table";
+let min_id: Option<i64> = None;
+let query = sql!("DELETE FROM {table:ident} WHERE id > {nullable}", min_id);
+assert_eq!(query, r#"DELETE FROM "my table" WHERE id > NULL"#);
+```
+*/
+#[proc_macro]
+pub fn sql(input: TokenStream) -> TokenStream {
+    let template = parse_macro_input!(input as sql::SqlTemplate);
+    match template.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/**
+Create SQL functions that report how the extension was built, named after its crate:
+
+* `$CRATE_version()`, returning the crate's version as `text`
+* `$CRATE_build_info()`, returning `jsonb` with the crate's version, the versions of `pgrx`,
+  `rustc`, and Postgres it was built with, and, when it was built by `cargo pgrx`, the git commit,
+  features, and profile.  See `pgrx::build_info::BuildInfo`.
+
+```rust,ignore
+use pgrx::prelude::*;
+
+pg_module_magic!();
+pg_build_info!();
+```
+*/
+#[proc_macro]
+pub fn pg_build_info(input: TokenStream) -> TokenStream {
+    if !input.is_empty() {
+        return quote! { compile_error!("`pg_build_info!()` takes no arguments"); }.into();
+    }
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| "extension".into());
+    let version_name = format!("{crate_name}_version");
+    let build_info_name = format!("{crate_name}_build_info");
+    quote! {
+        #[::pgrx::pg_extern(name = #version_name, immutable, parallel_safe)]
+        fn __pgrx_build_info_version() -> &'static str {
+            env!("CARGO_PKG_VERSION")
+        }
+
+        #[::pgrx::pg_extern(name = #build_info_name, immutable, parallel_safe)]
+        fn __pgrx_build_info() -> ::pgrx::JsonB {
+            ::pgrx::JsonB(::pgrx::build_info!().to_json())
+        }
+    }
+    .into()
+}
+
 /// Associated macro for `#[pg_extern]` or `#[macro@pg_operator]`.  Used to set the `SEARCH_PATH` option
 /// on the `CREATE FUNCTION` statement.
 #[proc_macro_attribute]
@@ -449,6 +715,9 @@ Optionally accepts the following attributes:
 * `immutable`: Corresponds to [`IMMUTABLE`](https://www.postgresql.org/docs/current/sql-createfunction.html).
 * `strict`: Corresponds to [`STRICT`](https://www.postgresql.org/docs/current/sql-createfunction.html).
   + In most cases, `#[pg_extern]` can detect when no `Option<T>`s are used, and automatically set this.
+  + Declaring it also lets the generated wrapper read non-`Option<T>` arguments without checking whether they're NULL.
+    Postgres only enforces `STRICT` when it calls the function itself, so calling the wrapper with a NULL argument
+    through `DirectFunctionCall`, or from a `sql = ...` definition that leaves out `STRICT`, is undefined behavior.
 * `stable`: Corresponds to [`STABLE`](https://www.postgresql.org/docs/current/sql-createfunction.html).
 * `volatile`: Corresponds to [`VOLATILE`](https://www.postgresql.org/docs/current/sql-createfunction.html).
 * `raw`: Corresponds to [`RAW`](https://www.postgresql.org/docs/current/sql-createfunction.html).
@@ -460,6 +729,17 @@ Optionally accepts the following attributes:
 * `no_guard`: Do not use `#[pg_guard]` with the function.
 * `sql`: Same arguments as [`#[pgrx(sql = ..)]`](macro@pgrx).
 * `name`: Specifies target function name. Defaults to Rust function name.
+* `blocking_on`: For an `async fn`, the path of a function like `fn<F: Future>(F) -> F::Output` that
+  runs its body to completion, such as `pgrx::async_support::block_on`.
+* `memory_quota`: The number of bytes of Postgres memory the function may allocate, such as
+  `memory_quota = 16 * 1024 * 1024`.  Exceeding it raises an "out of memory" `ERROR`.
+  See `pgrx::memory_quota`.
+* `support`: Corresponds to [`SUPPORT`](https://www.postgresql.org/docs/current/sql-createfunction.html) (Postgres 12 and later).
+  Either the name of another `#[pg_extern]` function, such as `support = my_support_fn`, or a string naming any function.
+  See `pgrx::planner_support` for writing one.
+* `fold_constants`: Generates a `support` function (Postgres 12 and later) that calls the function at plan time when
+  all its arguments are constants, so `my_fn(3)` is planned as its result.  Only use it for functions that always
+  return the same result for the same arguments.  See `pgrx::planner_support::fold_constants`.
 
 Functions can accept and return any type which `pgrx` supports. `pgrx` supports many PostgreSQL types by default.
 New types can be defined via [`macro@PostgresType`] or [`macro@PostgresEnum`].
@@ -559,6 +839,44 @@ It accepts 2 arguments:
 * A name, such as `example`
 * A type
 
+Borrowed return values, such as a `&str` or `&[u8]`, are always copied into a new datum, even when
+they were borrowed from an argument.  To hand an unmodified `text` argument straight back to
+Postgres instead, take and return a `BorrowedText`:
+
+```rust,ignore
+use pgrx::*;
+#[pg_extern]
+fn first_or_self<'a>(s: BorrowedText<'a>) -> BorrowedText<'a> {
+    // only copied when a shorter substring is returned
+    s.substr(s.as_str().split(',').next().unwrap())
+}
+```
+
+# Calling from Rust
+
+Each `#[pg_extern]` function also gets a plain Rust companion, named with an `_rs` suffix, that calls
+it directly.  Functions exposed to SQL by anything, including other extensions, can be called by
+name through the function manager with `pgrx::call_pg_function!()`, which caches their OIDs:
+
+```rust,ignore
+use pgrx::*;
+#[pg_extern]
+fn scritch(dog: &str) -> String {
+    format!("{dog} is happy")
+}
+
+#[pg_extern]
+fn scritch_all(dogs: Vec<String>) -> Vec<String> {
+    dogs.iter().map(|dog| scritch_rs(dog)).collect()
+}
+
+#[pg_extern]
+fn scritch_loudly(dog: &str) -> Option<String> {
+    let scritched: Option<String> = call_pg_function!("scritch", dog);
+    scritched.map(|s| s.to_uppercase())
+}
+```
+
 # Special Cases
 
 `pg_sys::Oid` is a special cased type alias, in order to use it as an argument or return it must be
@@ -673,7 +991,7 @@ fn impl_postgres_enum(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream>
             }
 
             fn type_oid() -> ::pgrx::pg_sys::Oid {
-                ::pgrx::wrappers::regtypein(#enum_name)
+                ::pgrx::enum_helper::enum_type_oid(#enum_name)
             }
 
         }
@@ -960,6 +1278,76 @@ fn parse_postgres_type_args(attributes: &[Attribute]) -> HashSet<PostgresTypeAtt
     categorized_attributes
 }
 
+/**
+Derives `pgrx::temp_table::TempTableRow` for a struct with named fields, so its values can be
+loaded into a temporary table with `Spi::create_temp_table()`.
+
+Each field becomes a column of the same name, of the SQL type the field's type maps to.  Columns
+are `NOT NULL` unless the field is an `Option`.
+
+```rust,ignore
+use pgrx::prelude::*;
+
+#[derive(TempTableRow)]
+struct Score {
+    id: i64,
+    score: f64,
+    note: Option<String>,
+}
+```
+*/
+#[proc_macro_derive(TempTableRow)]
+pub fn temp_table_row(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    impl_temp_table_row(ast).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn impl_temp_table_row(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &ast.data {
+        Data::Struct(syn::DataStruct { fields: syn::Fields::Named(fields), .. }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new(
+                ast.span(),
+                "#[derive(TempTableRow)] can only be applied to structs with named fields",
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    let mut datums = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let name = ident.to_string();
+        let ty = &field.ty;
+        let is_option = match ty {
+            syn::Type::Path(path) => {
+                path.path.segments.last().map_or(false, |segment| segment.ident == "Option")
+            }
+            _ => false,
+        };
+        columns.push(if is_option {
+            quote! { ::pgrx::ddl::Column::new::<#ty>(#name) }
+        } else {
+            quote! { ::pgrx::ddl::Column::new::<#ty>(#name).not_null() }
+        });
+        datums.push(quote! { ::pgrx::IntoDatum::into_datum(self.#ident) });
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::pgrx::temp_table::TempTableRow for #name #ty_generics #where_clause {
+            fn columns() -> ::std::vec::Vec<::pgrx::ddl::Column> {
+                ::std::vec![#(#columns),*]
+            }
+
+            fn into_datums(self) -> ::std::vec::Vec<::core::option::Option<::pgrx::pg_sys::Datum>> {
+                ::std::vec![#(#datums),*]
+            }
+        }
+    })
+}
+
 /**
 Generate necessary code using the type in operators like `==` and `!=`.
 
@@ -986,6 +1374,11 @@ pub fn postgres_eq(input: TokenStream) -> TokenStream {
 /**
 Generate necessary code using the type in operators like `>`, `<`, `<=`, and `>=`.
 
+This also creates the type's default btree operator class, with a comparison support function built
+on the type's Rust [`Ord`] implementation, so it can be used in `ORDER BY`, merge joins, and btree
+indexes.  If the type doesn't also derive [`macro@PostgresEq`], the `=` and `<>` operators the
+operator class needs are created from the comparison function.
+
 ```rust,ignore
 # use pgrx_pg_sys as pg_sys;
 use pgrx::*;
@@ -1012,6 +1405,11 @@ pub fn postgres_ord(input: TokenStream) -> TokenStream {
 /**
 Generate necessary code for stable hashing the type so it can be used with `USING hash` indexes.
 
+Along with [`macro@PostgresEq`], this creates the default hash operator class for the type, which
+lets Postgres use it in `GROUP BY`, `DISTINCT`, `UNION`, hash joins, and `PARTITION BY HASH`.  The
+hash is computed from the type's Rust [`Hash`](std::hash::Hash) implementation, so it must agree
+with its [`Eq`] implementation.
+
 ```rust,ignore
 # use pgrx_pg_sys as pg_sys;
 use pgrx::*;
@@ -1058,6 +1456,82 @@ pub fn pg_aggregate(_attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/**
+Declare a `pgrx::spgist::SpGistOpClass` or `pgrx::brin::BrinOpClass` implementation on a type as the
+default SP-GiST or BRIN operator class for it.
+
+This creates a `#[pg_extern]` wrapper for each of the access method's support functions, named
+like `{type}_spgist_config` or `{type}_brin_add_value`, and a `CREATE OPERATOR CLASS` statement
+with the trait's `OPERATORS`.  As with [`macro@PostgresHash`], the type's Rust name is used as its
+SQL name, so this only works for types the extension itself creates.
+
+```rust,ignore
+use pgrx::brin::{BrinOpClass, BrinSummary};
+use pgrx::prelude::*;
+
+#[pg_opclass]
+impl BrinOpClass for Reading {
+    type Value = Reading;
+    const OPERATORS: &'static [(u16, &'static str)] =
+        &[(1, "<"), (2, "<="), (3, "="), (4, ">="), (5, ">")];
+    // ...
+}
+```
+*/
+#[proc_macro_attribute]
+pub fn pg_opclass(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_impl = parse_macro_input!(item as syn::ItemImpl);
+    match PgOpClass::new(item_impl) {
+        Ok(opclass) => opclass.to_token_stream().into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/**
+Declare a `pgrx::text_search::TextSearchDictionary` or `pgrx::text_search::TextSearchParser`
+implementation on a type as a Postgres text search dictionary or parser.
+
+For a dictionary this creates `#[pg_extern]` wrappers for its `init` and `lexize` functions, a
+`CREATE TEXT SEARCH TEMPLATE` using them, and a `CREATE TEXT SEARCH DICTIONARY` with the same
+name using the template.  For a parser it creates wrappers for the `start`, `gettoken`, `end`, and
+`lextypes` functions and a `CREATE TEXT SEARCH PARSER`.
+
+`pgrx::text_search` is only available with pgrx's `cshim` feature, which is on by default.
+
+```rust,ignore
+use pgrx::prelude::*;
+use pgrx::text_search::{Lexeme, TextSearchDictionary};
+
+struct Unaccent;
+
+#[pg_text_search(name = "my_unaccent", options = "keep_case = 'false'")]
+impl TextSearchDictionary for Unaccent {
+    fn init(_options: Vec<(String, String)>) -> Self {
+        Unaccent
+    }
+
+    fn lexize(&self, token: &str) -> Option<Vec<Lexeme>> {
+        Some(vec![Lexeme::new(unaccent(token))])
+    }
+}
+```
+
+Optionally accepts the following arguments:
+
+* `name = "..."`: The name of the SQL objects, and the prefix of the support functions' names.
+  Defaults to the type's name, in `snake_case`.
+* `options = "..."`: Options for the `CREATE TEXT SEARCH DICTIONARY` statement, passed to the
+  dictionary's `init` function.
+*/
+#[proc_macro_attribute]
+pub fn pg_text_search(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_impl = parse_macro_input!(item as syn::ItemImpl);
+    match PgTextSearch::new(attr.into(), item_impl) {
+        Ok(text_search) => text_search.to_token_stream().into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
 /**
 A helper attribute for various contexts.
 
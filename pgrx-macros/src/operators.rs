@@ -55,6 +55,7 @@ pub(crate) fn impl_postgres_hash(ast: DeriveInput) -> syn::Result<proc_macro2::T
     let (ident, type_path) = ident_and_type_path(&ast);
 
     stream.extend(hash(ident, &type_path));
+    stream.extend(hash_extended(ident, &type_path));
 
     let sql_graph_entity_item = PostgresHash::from_derive_input(ast)?;
     sql_graph_entity_item.to_tokens(&mut stream);
@@ -180,3 +181,18 @@ pub fn hash(type_name: &Ident, type_path: &proc_macro2::TokenStream) -> proc_mac
         }
     }
 }
+
+pub fn hash_extended(
+    type_name: &Ident,
+    type_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let pg_name =
+        Ident::new(&format!("{}_hash_extended", type_name).to_lowercase(), type_name.span());
+    quote! {
+        #[allow(non_snake_case)]
+        #[::pgrx::pgrx_macros::pg_extern(immutable, parallel_safe)]
+        fn #pg_name(value: #type_path, seed: i64) -> i64 {
+            ::pgrx::misc::pgrx_seahash_extended(&value, seed as u64) as i64
+        }
+    }
+}
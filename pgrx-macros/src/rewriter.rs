@@ -16,8 +16,8 @@ use std::str::FromStr;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    FnArg, ForeignItem, ForeignItemFn, GenericParam, ItemFn, ItemForeignMod, Pat, ReturnType,
-    Signature, Token, Visibility,
+    FnArg, ForeignItem, ForeignItemFn, GenericParam, ItemFn, ItemForeignMod, Lit, Meta,
+    MetaNameValue, Pat, ReturnType, Signature, Token, Visibility,
 };
 
 pub struct PgGuardRewriter();
@@ -125,7 +125,11 @@ impl PgGuardRewriter {
         abi: &syn::Abi,
     ) -> syn::Result<proc_macro2::TokenStream> {
         match item {
-            ForeignItem::Fn(func) => {
+            ForeignItem::Fn(mut func) => {
+                if PgGuardRewriter::take_skip(&mut func)? {
+                    return Ok(quote! { #abi { #func } });
+                }
+
                 if func.sig.variadic.is_some() {
                     return Ok(quote! { #abi { #func } });
                 }
@@ -136,6 +140,47 @@ impl PgGuardRewriter {
         }
     }
 
+    /// Removes a `#[pg_guard(skip)]` from `func`, returning whether there was one.
+    ///
+    /// Skipping the guard is only sound when the function can't raise an ERROR, so it must be
+    /// documented with a `# Safety` section saying why.
+    fn take_skip(func: &mut ForeignItemFn) -> syn::Result<bool> {
+        let mut skip = None;
+        let mut attrs = Vec::with_capacity(func.attrs.len());
+        for attr in func.attrs.drain(..) {
+            if !attr.path.is_ident("pg_guard") {
+                attrs.push(attr);
+                continue;
+            }
+            let arg = attr.parse_args::<Ident>()?;
+            if arg != "skip" {
+                return Err(syn::Error::new(arg.span(), "expected `#[pg_guard(skip)]`"));
+            }
+            skip = Some(attr);
+        }
+        func.attrs = attrs;
+
+        let Some(skip) = skip else { return Ok(false) };
+        let documented = func.attrs.iter().filter(|attr| attr.path.is_ident("doc")).any(|attr| {
+            match attr.parse_meta() {
+                Ok(Meta::NameValue(MetaNameValue { lit: Lit::Str(doc), .. })) => {
+                    doc.value().trim() == "# Safety"
+                }
+                _ => false,
+            }
+        });
+        if !documented {
+            return Err(syn::Error::new(
+                skip.span(),
+                format!(
+                    "`#[pg_guard(skip)]` requires a `/// # Safety` section explaining why `{}` can never raise an ERROR",
+                    func.sig.ident
+                ),
+            ));
+        }
+        Ok(true)
+    }
+
     pub fn foreign_item_fn(
         &self,
         func: &ForeignItemFn,
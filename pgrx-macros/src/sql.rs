@@ -0,0 +1,138 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, LitStr, Token};
+
+/// The input to `sql!()`: a template and its positional arguments
+pub(crate) struct SqlTemplate {
+    template: LitStr,
+    args: Vec<Expr>,
+}
+
+impl Parse for SqlTemplate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let template = input.parse()?;
+        let args = if input.is_empty() {
+            Vec::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::<Expr, Token![,]>::parse_terminated(input)?.into_iter().collect()
+        };
+        Ok(SqlTemplate { template, args })
+    }
+}
+
+impl SqlTemplate {
+    pub(crate) fn expand(self) -> syn::Result<TokenStream2> {
+        let span = self.template.span();
+        let template = self.template.value();
+        let mut args = self.args.into_iter();
+        let mut pieces = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '}' => return Err(syn::Error::new(span, "unmatched `}` in sql!() template")),
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => {
+                                return Err(syn::Error::new(
+                                    span,
+                                    "unmatched `{` in sql!() template",
+                                ))
+                            }
+                        }
+                    }
+                    let (name, mode) = match placeholder.split_once(':') {
+                        Some((name, mode)) => (Some(name.trim()), mode.trim()),
+                        None => (None, placeholder.trim()),
+                    };
+                    let value = match name {
+                        Some(name) => {
+                            let ident = syn::parse_str::<syn::Ident>(name).map_err(|_| {
+                                syn::Error::new(span, format!("`{name}` is not an identifier"))
+                            })?;
+                            let ident = syn::Ident::new(&ident.to_string(), span);
+                            quote! { #ident }
+                        }
+                        None => {
+                            let arg = args.next().ok_or_else(|| {
+                                syn::Error::new(
+                                    span,
+                                    "sql!() template has more placeholders than arguments",
+                                )
+                            })?;
+                            quote! { #arg }
+                        }
+                    };
+                    let quoted = match mode {
+                        "ident" => quote! { ::pgrx::quote::quote_ident(&(#value)) },
+                        "literal" => {
+                            quote! { ::pgrx::quote::quote_literal(&::std::string::ToString::to_string(&(#value))) }
+                        }
+                        "nullable" => quote! {
+                            ::pgrx::quote::quote_nullable(
+                                ::core::option::Option::as_ref(&(#value))
+                                    .map(::std::string::ToString::to_string)
+                            )
+                        },
+                        mode => {
+                            return Err(syn::Error::new(
+                                span,
+                                format!(
+                                    "unknown sql!() placeholder `{{{mode}}}`, expected `{{ident}}`, `{{literal}}`, or `{{nullable}}`"
+                                ),
+                            ))
+                        }
+                    };
+                    if !text.is_empty() {
+                        let piece = std::mem::take(&mut text);
+                        pieces.push(quote! { __sql.push_str(#piece); });
+                    }
+                    pieces.push(quote! { __sql.push_str(&#quoted); });
+                }
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            pieces.push(quote! { __sql.push_str(#text); });
+        }
+        if let Some(extra) = args.next() {
+            return Err(syn::Error::new_spanned(
+                extra,
+                "sql!() has more arguments than the template has placeholders",
+            ));
+        }
+
+        Ok(quote! {
+            {
+                let mut __sql = ::std::string::String::new();
+                #(#pieces)*
+                __sql
+            }
+        })
+    }
+}
@@ -0,0 +1,124 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! The arguments of `#[pg_test]` that decide whether a test runs at all, rather than describing
+//! the `#[pg_extern]` function it's built from
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+#[derive(Default)]
+pub(crate) struct TestConditions {
+    min_pg: Option<u32>,
+    max_pg: Option<u32>,
+    skip_if: Option<syn::Path>,
+    requires_extensions: Vec<String>,
+}
+
+impl TestConditions {
+    /// Split the conditions out of `#[pg_test]`'s arguments, returning them along with the
+    /// arguments that remain for `#[pg_extern]`
+    pub(crate) fn extract(attr: TokenStream) -> syn::Result<(TokenStream, Self)> {
+        let mut conditions = TestConditions::default();
+        let mut remaining = Vec::new();
+
+        let mut segments = vec![Vec::new()];
+        for token in attr {
+            match &token {
+                TokenTree::Punct(p) if p.as_char() == ',' => segments.push(Vec::new()),
+                _ => segments.last_mut().unwrap().push(token),
+            }
+        }
+
+        for segment in segments.into_iter().filter(|segment| !segment.is_empty()) {
+            let name = match &segment[0] {
+                TokenTree::Ident(ident) => ident.to_string(),
+                _ => String::new(),
+            };
+            if !matches!(name.as_str(), "min_pg" | "max_pg" | "skip_if" | "requires_extension") {
+                remaining.push(segment.into_iter().collect::<TokenStream>());
+                continue;
+            }
+
+            let span = segment[0].span();
+            let value = match &segment[1..] {
+                [TokenTree::Punct(eq), value @ ..] if eq.as_char() == '=' && !value.is_empty() => {
+                    value.iter().cloned().collect::<TokenStream>()
+                }
+                _ => return Err(syn::Error::new(span, format!("expected `{name} = ...`"))),
+            };
+            match name.as_str() {
+                "min_pg" => conditions.min_pg = Some(parse_version(value)?),
+                "max_pg" => conditions.max_pg = Some(parse_version(value)?),
+                "skip_if" => {
+                    let function = syn::parse2::<syn::LitStr>(value)?;
+                    conditions.skip_if = Some(function.parse()?);
+                }
+                _ => {
+                    let extension = syn::parse2::<syn::LitStr>(value)?;
+                    conditions.requires_extensions.push(extension.value());
+                }
+            }
+        }
+
+        if let (Some(min_pg), Some(max_pg)) = (conditions.min_pg, conditions.max_pg) {
+            if min_pg > max_pg {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("`min_pg = {min_pg}` is greater than `max_pg = {max_pg}`"),
+                ));
+            }
+        }
+
+        Ok((quote! { #(#remaining),* }, conditions))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.min_pg.is_none()
+            && self.max_pg.is_none()
+            && self.skip_if.is_none()
+            && self.requires_extensions.is_empty()
+    }
+}
+
+fn parse_version(value: TokenStream) -> syn::Result<u32> {
+    let version = syn::parse2::<syn::LitInt>(value)?;
+    match version.base10_parse::<u32>()? {
+        major @ 10..=99 => Ok(major),
+        _ => {
+            Err(syn::Error::new(version.span(), "expected a Postgres major version, such as `14`"))
+        }
+    }
+}
+
+impl ToTokens for TestConditions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let option = |version: Option<u32>| match version {
+            Some(version) => quote! { Some(#version) },
+            None => quote! { None },
+        };
+        let min_pg = option(self.min_pg);
+        let max_pg = option(self.max_pg);
+        let skip_if = match &self.skip_if {
+            Some(function) => {
+                let name = function.to_token_stream().to_string().replace(' ', "");
+                quote! { Some((#name, #function as fn() -> bool)) }
+            }
+            None => quote! { None },
+        };
+        let requires_extensions = &self.requires_extensions;
+        tokens.extend(quote! {
+            pgrx_tests::TestConditions {
+                min_pg: #min_pg,
+                max_pg: #max_pg,
+                skip_if: #skip_if,
+                requires_extensions: &[#(#requires_extensions),*],
+            }
+        });
+    }
+}
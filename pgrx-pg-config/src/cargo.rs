@@ -27,6 +27,30 @@ pub trait PgrxManifestExt {
     /// https://doc.rust-lang.org/cargo/reference/cargo-targets.html#the-name-field
     fn lib_name(&self) -> eyre::Result<String>;
 
+    /// Name of the Postgres extension, which names its control file and the installed shared
+    /// library: the `extension-name` from
+    ///
+    /// ```toml
+    /// [package.metadata.pgrx]
+    /// extension-name = "my_extension"
+    /// ```
+    ///
+    /// if there is one, otherwise the [`lib_name`](PgrxManifestExt::lib_name).
+    fn extension_name(&self) -> eyre::Result<String>;
+
+    /// Whether the crate is a plain loadable module, like an auditing or planner hook, rather than
+    /// an extension: it has no control file or SQL, only a shared library, loaded through
+    /// `shared_preload_libraries` (or `session_preload_libraries`, or `LOAD`) instead of
+    /// `CREATE EXTENSION`.
+    ///
+    /// ```toml
+    /// [package.metadata.pgrx]
+    /// module-only = true
+    /// ```
+    ///
+    /// The library is installed under the [`extension_name`](PgrxManifestExt::extension_name).
+    fn is_module_only(&self) -> bool;
+
     /// Resolved string for target artifact name, used for matching on
     /// `cargo_metadata::message::Artifact`.
     fn target_name(&self) -> eyre::Result<String>;
@@ -77,6 +101,34 @@ impl PgrxManifestExt for Manifest {
         }
     }
 
+    fn extension_name(&self) -> eyre::Result<String> {
+        let configured = self
+            .package
+            .as_ref()
+            .and_then(|package| package.metadata.as_ref())
+            .and_then(|metadata| metadata.get("pgrx"))
+            .and_then(|pgrx| pgrx.get("extension-name"));
+        match configured {
+            Some(name) => match name.as_str() {
+                Some(name) if !name.is_empty() => Ok(name.to_owned()),
+                _ => {
+                    Err(eyre!("`package.metadata.pgrx.extension-name` must be a non-empty string"))
+                }
+            },
+            None => self.lib_name(),
+        }
+    }
+
+    fn is_module_only(&self) -> bool {
+        self.package
+            .as_ref()
+            .and_then(|package| package.metadata.as_ref())
+            .and_then(|metadata| metadata.get("pgrx"))
+            .and_then(|pgrx| pgrx.get("module-only"))
+            .and_then(|flag| flag.as_bool())
+            .unwrap_or(false)
+    }
+
     fn target_name(&self) -> eyre::Result<String> {
         let package = self.package_name()?;
         let lib = self.lib_name()?;
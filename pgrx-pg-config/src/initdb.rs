@@ -0,0 +1,148 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use crate::{get_c_locale_flags, PgConfig};
+use eyre::{eyre, WrapErr};
+use serde_derive::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// The file, inside a data directory, recording the [`InitdbOptions`] it was created with
+const INITDB_OPTIONS_FILE: &str = "pgrx_initdb_options.toml";
+
+/// Options given to `initdb` when pgrx creates a data directory, configured per Postgres version
+/// in `$PGRX_HOME/config.toml`:
+///
+/// ```toml
+/// [initdb.pg15]
+/// locale = "en_US.UTF-8"
+/// encoding = "UTF8"
+/// data_checksums = true
+/// auth = "scram-sha-256"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InitdbOptions {
+    /// The locale of the new cluster.  Defaults to "C.UTF-8", or "C" on systems without it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// The encoding of the template databases.  Defaults to the locale's encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    /// Enable data page checksums
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_checksums: Option<bool>,
+    /// The authentication method for local and host connections.  Defaults to "trust".  Methods
+    /// that need a password, such as "scram-sha-256", take it from `$PGPASSWORD`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<String>,
+}
+
+impl InitdbOptions {
+    /// Are all the options unset?
+    pub fn is_default(&self) -> bool {
+        self == &InitdbOptions::default()
+    }
+
+    /// Overwrite our options with those that are set in `other`
+    pub fn merge(&mut self, other: InitdbOptions) {
+        if other.locale.is_some() {
+            self.locale = other.locale;
+        }
+        if other.encoding.is_some() {
+            self.encoding = other.encoding;
+        }
+        if other.data_checksums.is_some() {
+            self.data_checksums = other.data_checksums;
+        }
+        if other.auth.is_some() {
+            self.auth = other.auth;
+        }
+    }
+
+    /// The arguments to give `initdb`, not including any `--pwfile`
+    pub fn args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        match &self.locale {
+            Some(locale) => args.push(format!("--locale={locale}")),
+            None => args.extend(get_c_locale_flags().iter().map(|flag| flag.to_string())),
+        }
+        if let Some(encoding) = &self.encoding {
+            args.push(format!("--encoding={encoding}"));
+        }
+        if self.data_checksums == Some(true) {
+            args.push("--data-checksums".into());
+        }
+        if let Some(auth) = &self.auth {
+            args.push(format!("--auth={auth}"));
+        }
+        args
+    }
+
+    /// Does the configured authentication method require the superuser to have a password?
+    pub fn requires_password(&self) -> bool {
+        matches!(self.auth.as_deref(), Some("password" | "md5" | "scram-sha-256"))
+    }
+
+    /// Was the data directory at `datadir` created with these options?
+    ///
+    /// Data directories created before pgrx recorded their options are assumed to have been
+    /// created with the defaults.
+    pub fn matches_data_dir(&self, datadir: &Path) -> bool {
+        let recorded = match std::fs::read_to_string(datadir.join(INITDB_OPTIONS_FILE)) {
+            Ok(contents) => toml::from_str::<InitdbOptions>(&contents).ok(),
+            Err(_) => Some(InitdbOptions::default()),
+        };
+        recorded.as_ref() == Some(self)
+    }
+}
+
+/// Create a new data directory at `datadir` using `pg_config`'s `initdb` and the given `options`
+pub fn initdb(pg_config: &PgConfig, datadir: &Path, options: &InitdbOptions) -> eyre::Result<()> {
+    let mut command = Command::new(pg_config.initdb_path()?);
+    command.stdout(Stdio::piped()).stderr(Stdio::piped()).args(options.args());
+
+    // initdb reads the superuser's password from a file, which we only keep for as long as it runs
+    let pwfile = if options.requires_password() {
+        let password = std::env::var("PGPASSWORD").map_err(|_| {
+            eyre!(
+                "the `{}` authentication method requires a password, but $PGPASSWORD is not set",
+                options.auth.as_deref().unwrap_or_default()
+            )
+        })?;
+        let mut pwfile = datadir.as_os_str().to_owned();
+        pwfile.push(".pgrx-pwfile");
+        let pwfile = PathBuf::from(pwfile);
+        std::fs::write(&pwfile, password)?;
+        command.arg(format!("--pwfile={}", pwfile.display()));
+        Some(pwfile)
+    } else {
+        None
+    };
+
+    command.arg("-D").arg(datadir);
+
+    let command_str = format!("{:?}", command);
+    let output = command.output().wrap_err_with(|| eyre!("unable to execute: {}", command_str));
+    if let Some(pwfile) = pwfile {
+        std::fs::remove_file(pwfile).ok();
+    }
+    let output = output?;
+
+    if !output.status.success() {
+        return Err(eyre!(
+            "problem running initdb: {}\n{}{}",
+            command_str,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    std::fs::write(datadir.join(INITDB_OPTIONS_FILE), toml::to_string(options)?)?;
+    Ok(())
+}
@@ -16,13 +16,19 @@ use std::error::Error;
 use std::ffi::OsString;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::io::ErrorKind;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::str::FromStr;
 use url::Url;
 
 pub mod cargo;
 
+mod initdb;
+pub use initdb::{initdb, InitdbOptions};
+
+mod variant;
+pub use variant::PgVariant;
+
 pub static BASE_POSTGRES_PORT_NO: u16 = 28800;
 pub static BASE_POSTGRES_TESTING_PORT_NO: u16 = 32200;
 
@@ -109,6 +115,9 @@ impl Display for PgVersion {
     }
 }
 
+/// Parses the output of `pg_config --version` into a major and minor version
+pub type VersionParser = fn(&str) -> eyre::Result<(u16, PgMinorVersion)>;
+
 #[derive(Clone, Debug)]
 pub struct PgConfig {
     version: Option<PgVersion>,
@@ -116,6 +125,8 @@ pub struct PgConfig {
     known_props: Option<BTreeMap<String, String>>,
     base_port: u16,
     base_testing_port: u16,
+    test_port: Option<u16>,
+    version_parser: Option<VersionParser>,
 }
 
 impl Display for PgConfig {
@@ -132,6 +143,8 @@ impl Default for PgConfig {
             known_props: None,
             base_port: BASE_POSTGRES_PORT_NO,
             base_testing_port: BASE_POSTGRES_TESTING_PORT_NO,
+            test_port: None,
+            version_parser: None,
         }
     }
 }
@@ -150,6 +163,8 @@ impl PgConfig {
             known_props: None,
             base_port,
             base_testing_port,
+            test_port: None,
+            version_parser: None,
         }
     }
 
@@ -160,6 +175,8 @@ impl PgConfig {
             known_props: None,
             base_port: BASE_POSTGRES_PORT_NO,
             base_testing_port: BASE_POSTGRES_TESTING_PORT_NO,
+            test_port: None,
+            version_parser: None,
         }
     }
 
@@ -195,6 +212,8 @@ impl PgConfig {
                 known_props: Some(known_props),
                 base_port: 0,
                 base_testing_port: 0,
+                test_port: None,
+                version_parser: None,
             })
         }
     }
@@ -222,10 +241,21 @@ impl PgConfig {
         self.path().unwrap().parent().unwrap().to_path_buf()
     }
 
-    fn parse_version_str(version_str: &str) -> eyre::Result<(u16, PgMinorVersion)> {
-        let version_parts = version_str.split_whitespace().collect::<Vec<&str>>();
-        let mut version = version_parts
-            .get(1)
+    /// Parse `pg_config --version` with `parser` instead of [`PgConfig::parse_version_str`], for
+    /// forks whose version strings it can't make sense of
+    pub fn with_version_parser(mut self, parser: VersionParser) -> Self {
+        self.version_parser = Some(parser);
+        self
+    }
+
+    /// Parse the output of `pg_config --version`, such as `PostgreSQL 15.3`, or
+    /// `PostgreSQL 11.2-YB-2.17.0.0-b0` from a fork.  The version is the first word after the
+    /// product name that starts with a digit.
+    pub fn parse_version_str(version_str: &str) -> eyre::Result<(u16, PgMinorVersion)> {
+        let mut version = version_str
+            .split_whitespace()
+            .skip(1)
+            .find(|part| part.starts_with(|c: char| c.is_ascii_digit()))
             .ok_or_else(|| eyre!("invalid version string: {}", version_str))?
             .split('.')
             .collect::<Vec<&str>>();
@@ -273,7 +303,8 @@ impl PgConfig {
 
     fn get_version(&self) -> eyre::Result<(u16, PgMinorVersion)> {
         let version_string = self.run("--version")?;
-        Self::parse_version_str(&version_string)
+        let parser = self.version_parser.unwrap_or(Self::parse_version_str);
+        parser(&version_string)
     }
 
     pub fn major_version(&self) -> eyre::Result<u16> {
@@ -314,7 +345,16 @@ impl PgConfig {
     }
 
     pub fn test_port(&self) -> eyre::Result<u16> {
-        Ok(self.base_testing_port + self.major_version()?)
+        match self.test_port {
+            Some(port) => Ok(port),
+            None => Ok(self.base_testing_port + self.major_version()?),
+        }
+    }
+
+    /// Use `port` for the test instance instead of the one derived from the base testing port,
+    /// such as when that's already taken by another project's tests
+    pub fn set_test_port(&mut self, port: u16) {
+        self.test_port = Some(port);
     }
 
     pub fn host(&self) -> &'static str {
@@ -368,8 +408,51 @@ impl PgConfig {
         Ok(path)
     }
 
+    /// Which Postgres fork, if any, this `pg_config` belongs to.  The `PGRX_PG_VARIANT`
+    /// environment variable overrides what's detected, for forks that can't be told apart.
+    pub fn variant(&self) -> eyre::Result<PgVariant> {
+        if let Ok(variant) = std::env::var("PGRX_PG_VARIANT") {
+            return Ok(match variant.to_lowercase().as_str() {
+                "postgres" | "postgresql" => PgVariant::Postgres,
+                "edb" | "epas" | "enterprisedb" => PgVariant::EnterpriseDb,
+                "greenplum" | "gpdb" => PgVariant::Greenplum,
+                "yugabyte" | "yugabytedb" => PgVariant::YugabyteDb,
+                _ => PgVariant::Other(variant),
+            });
+        }
+        let version = self.run("--version")?;
+        // only Greenplum's pg_config knows this option.  The others print an error and nothing
+        // to stdout, and a `PgConfig` described by the environment won't have it at all
+        let gp_version = self.run("--gp_version").ok();
+        Ok(PgVariant::detect(&version, gp_version.as_deref()))
+    }
+
+    /// The directory with the server's headers.  Some forks install them somewhere other than
+    /// where `pg_config --includedir-server` says, so if `postgres.h` isn't there, the usual
+    /// places under `--includedir` and `--pkgincludedir` are tried before settling for it.
     pub fn includedir_server(&self) -> eyre::Result<PathBuf> {
-        Ok(self.run("--includedir-server")?.into())
+        let reported = PathBuf::from(self.run("--includedir-server")?);
+        if self.known_props.is_some() || reported.join("postgres.h").exists() {
+            return Ok(reported);
+        }
+
+        let candidates = [
+            ("--includedir", &["server", "postgresql/server"][..]),
+            ("--pkgincludedir", &["server"][..]),
+        ];
+        for (arg, subdirs) in candidates {
+            let Ok(base) = self.run(arg) else { continue };
+            if base.is_empty() {
+                continue;
+            }
+            for subdir in subdirs {
+                let dir = Path::new(&base).join(subdir);
+                if dir.join("postgres.h").exists() {
+                    return Ok(dir);
+                }
+            }
+        }
+        Ok(reported)
     }
 
     pub fn pkglibdir(&self) -> eyre::Result<PathBuf> {
@@ -429,6 +512,7 @@ impl PgConfig {
 #[derive(Debug)]
 pub struct Pgrx {
     pg_configs: Vec<PgConfig>,
+    initdb_options: HashMap<String, InitdbOptions>,
     base_port: u16,
     base_testing_port: u16,
 }
@@ -437,6 +521,7 @@ impl Default for Pgrx {
     fn default() -> Self {
         Self {
             pg_configs: vec![],
+            initdb_options: HashMap::new(),
             base_port: BASE_POSTGRES_PORT_NO,
             base_testing_port: BASE_POSTGRES_TESTING_PORT_NO,
         }
@@ -450,6 +535,8 @@ struct ConfigToml {
     base_port: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
     base_testing_port: Option<u16>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    initdb: HashMap<String, InitdbOptions>,
 }
 
 pub enum PgConfigSelector<'a> {
@@ -509,7 +596,7 @@ impl Error for PgrxHomeError {}
 
 impl Pgrx {
     pub fn new(base_port: u16, base_testing_port: u16) -> Self {
-        Pgrx { pg_configs: vec![], base_port, base_testing_port }
+        Pgrx { pg_configs: vec![], initdb_options: HashMap::new(), base_port, base_testing_port }
     }
 
     pub fn from_config() -> eyre::Result<Self> {
@@ -541,6 +628,7 @@ impl Pgrx {
                         for (_, v) in configs.configs {
                             pgrx.push(PgConfig::new(v, pgrx.base_port, pgrx.base_testing_port));
                         }
+                        pgrx.initdb_options = configs.initdb;
                         Ok(pgrx)
                     }
                     Err(e) => {
@@ -596,6 +684,36 @@ impl Pgrx {
         Err(eyre!("Postgres `{}` is not managed by pgrx", label))
     }
 
+    /// The [`InitdbOptions`] configured for the Postgres version with the given `label`, such as `pg15`
+    pub fn initdb_options(&self, label: &str) -> InitdbOptions {
+        self.initdb_options.get(label).cloned().unwrap_or_default()
+    }
+
+    /// Merge `options` into the [`InitdbOptions`] saved in `$PGRX_HOME/config.toml` for the
+    /// Postgres version with the given `label`, and return the result
+    pub fn save_initdb_options(label: &str, options: InitdbOptions) -> eyre::Result<InitdbOptions> {
+        let path = Pgrx::config_toml()?;
+        let mut config = toml::from_str::<toml::Table>(&std::fs::read_to_string(&path)?)
+            .wrap_err_with(|| format!("Could not read `{}`", path.display()))?;
+
+        let initdb = config
+            .entry("initdb")
+            .or_insert_with(|| toml::Value::Table(Default::default()))
+            .as_table_mut()
+            .ok_or_else(|| eyre!("`initdb` in `{}` is not a table", path.display()))?;
+        let mut merged = match initdb.remove(label) {
+            Some(existing) => existing.try_into::<InitdbOptions>()?,
+            None => InitdbOptions::default(),
+        };
+        merged.merge(options);
+        if !merged.is_default() {
+            initdb.insert(label.to_string(), toml::Value::try_from(&merged)?);
+        }
+
+        std::fs::write(&path, toml::to_string(&config)?)?;
+        Ok(merged)
+    }
+
     /// Returns true if the specified `label` represents a Postgres version number feature flag,
     /// such as `pg14` or `pg15`
     pub fn is_feature_flag(&self, label: &str) -> bool {
@@ -772,6 +890,7 @@ fn parse_version() {
         ("PostgreSQL 14.5", 14, 5),
         ("PostgreSQL 11.2-FOO-BAR+", 11, 2),
         ("PostgreSQL 10.22-", 10, 22),
+        ("PostgreSQL (Fork Database) 12.12", 12, 12),
     ];
     for (s, major_expected, minor_expected) in versions {
         let (major, minor) =
@@ -792,6 +911,27 @@ fn parse_version() {
         PgConfig::parse_version_str("PostgresSQL .53").expect_err("Parsed invalid version string");
 }
 
+#[test]
+fn initdb_options_merge() {
+    let mut options = InitdbOptions {
+        locale: Some("en_US.UTF-8".into()),
+        data_checksums: Some(true),
+        ..Default::default()
+    };
+    options.merge(InitdbOptions {
+        encoding: Some("UTF8".into()),
+        data_checksums: Some(false),
+        ..Default::default()
+    });
+    assert_eq!(options.locale.as_deref(), Some("en_US.UTF-8"));
+    assert_eq!(options.encoding.as_deref(), Some("UTF8"));
+    assert_eq!(options.args(), vec!["--locale=en_US.UTF-8", "--encoding=UTF8"]);
+
+    let toml = toml::to_string(&options).unwrap();
+    assert_eq!(toml::from_str::<InitdbOptions>(&toml).unwrap(), options);
+    assert!(InitdbOptions::default().is_default());
+}
+
 #[test]
 fn from_empty_env() -> eyre::Result<()> {
     // without "PGRX_PG_CONFIG_AS_ENV" we can't get one of these
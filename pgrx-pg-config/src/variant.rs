@@ -0,0 +1,62 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Recognizing the Postgres forks a `pg_config` might belong to
+use std::fmt::{self, Display, Formatter};
+
+/// Which flavor of Postgres a `pg_config` belongs to.  Forks are supported on a best-effort basis:
+/// pgrx builds against their headers as if they were the Postgres version they're based on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PgVariant {
+    /// Postgres itself, including distributions' builds of it
+    Postgres,
+    /// EnterpriseDB's Postgres Advanced Server
+    EnterpriseDb,
+    Greenplum,
+    YugabyteDb,
+    /// Some other fork, named by the first word of its `pg_config --version`
+    Other(String),
+}
+
+impl PgVariant {
+    /// Recognize the fork from the output of `pg_config --version`, and of `pg_config --gp_version`,
+    /// which only Greenplum's `pg_config` has
+    pub fn detect(version: &str, gp_version: Option<&str>) -> PgVariant {
+        let lowercase = version.to_lowercase();
+        let has_gp_version = matches!(gp_version, Some(v) if !v.trim().is_empty());
+        if has_gp_version || lowercase.contains("greenplum") {
+            PgVariant::Greenplum
+        } else if lowercase.contains("enterprisedb") || lowercase.contains("advanced server") {
+            PgVariant::EnterpriseDb
+        } else if lowercase.contains("-yb-") {
+            PgVariant::YugabyteDb
+        } else {
+            match version.split_whitespace().next() {
+                Some("PostgreSQL") | None => PgVariant::Postgres,
+                Some(name) => PgVariant::Other(name.to_string()),
+            }
+        }
+    }
+
+    pub fn is_postgres(&self) -> bool {
+        *self == PgVariant::Postgres
+    }
+}
+
+impl Display for PgVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PgVariant::Postgres => write!(f, "PostgreSQL"),
+            PgVariant::EnterpriseDb => write!(f, "EDB Postgres Advanced Server"),
+            PgVariant::Greenplum => write!(f, "Greenplum"),
+            PgVariant::YugabyteDb => write!(f, "YugabyteDB"),
+            PgVariant::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
@@ -23,6 +23,7 @@ use syn::{ForeignItem, Item, ItemConst};
 const BLOCKLISTED_TYPES: [&str; 3] = ["Datum", "NullableDatum", "Oid"];
 
 mod build {
+    pub(super) mod bindgen_filter;
     pub(super) mod sym_blocklist;
 }
 
@@ -177,6 +178,9 @@ fn main() -> eyre::Result<()> {
             vec![(found_ver.major, specific)]
         }
     };
+    for (pg_major_ver, pg_config) in &pg_configs {
+        check_variant(*pg_major_ver, pg_config)?;
+    }
     std::thread::scope(|scope| {
         // This is pretty much either always 1 (normally) or 5 (for releases),
         // but in the future if we ever have way more, we should consider
@@ -208,10 +212,39 @@ fn main() -> eyre::Result<()> {
     Ok(())
 }
 
+/// Forks of Postgres are built against as whichever Postgres they're based on, which must be the
+/// one the bindings are being generated for
+fn check_variant(major_version: u16, pg_config: &PgConfig) -> eyre::Result<()> {
+    let variant = pg_config.variant()?;
+    if variant.is_postgres() {
+        return Ok(());
+    }
+
+    let location =
+        pg_config.path().map(|path| format!(" at {}", path.display())).unwrap_or_default();
+    let based_on = pg_config.major_version()?;
+    if !is_supported_major_version(based_on) {
+        return Err(eyre!(
+            "{variant}{location} is based on Postgres {based_on}, which pgrx does not support"
+        ));
+    }
+    if based_on != major_version {
+        return Err(eyre!(
+            "{variant}{location} is based on Postgres {based_on}, not pg{major_version}"
+        ));
+    }
+    println!(
+        "cargo:warning=building pg{major_version} bindings for {variant}{location}. \
+        Postgres forks are supported on a best-effort basis"
+    );
+    Ok(())
+}
+
 fn emit_rerun_if_changed() {
     // `pgrx-pg-config` doesn't emit one for this.
     println!("cargo:rerun-if-env-changed=PGRX_PG_CONFIG_PATH");
     println!("cargo:rerun-if-env-changed=PGRX_PG_CONFIG_AS_ENV");
+    println!("cargo:rerun-if-env-changed=PGRX_PG_VARIANT");
     // Bindgen's behavior depends on these vars, but it doesn't emit them
     // directly because the output would cause issue with `bindgen-cli`. Do it
     // on bindgen's behalf.
@@ -231,6 +264,7 @@ fn emit_rerun_if_changed() {
 
     // don't want to get stuck always generating bindings
     println!("cargo:rerun-if-env-changed=PGRX_PG_SYS_GENERATE_BINDINGS_FOR_RELEASE");
+    println!("cargo:rerun-if-env-changed=PGRX_PG_SYS_PRUNE_BINDINGS");
 
     println!("cargo:rerun-if-changed=include");
     println!("cargo:rerun-if-changed=cshim");
@@ -250,7 +284,11 @@ fn generate_bindings(
     include_h.push("include");
     include_h.push(format!("pg{}.h", major_version));
 
-    let bindgen_output = get_bindings(major_version, &pg_config, &include_h)
+    // the bindings published for docs.rs are always pruned, so that they never show a symbol
+    // that a pruned local build doesn't have
+    let prune = is_for_release || env_tracked("PGRX_PG_SYS_PRUNE_BINDINGS").as_deref() == Some("1");
+
+    let bindgen_output = get_bindings(major_version, &pg_config, &include_h, prune)
         .wrap_err_with(|| format!("bindgen failed for pg{}", major_version))?;
 
     let oids = extract_oids(&bindgen_output);
@@ -347,8 +385,9 @@ fn rewrite_items(
     is_for_release: bool,
 ) -> eyre::Result<proc_macro2::TokenStream> {
     let items_vec = rewrite_oid_consts(&file.items, oids);
+    let (pgnode_impls, node_names) = impl_pg_node(&items_vec, is_for_release)?;
+    let items_vec = gate_node_debug_derives(items_vec, &node_names);
     let mut items = apply_pg_guard(&items_vec)?;
-    let pgnode_impls = impl_pg_node(&items_vec, is_for_release)?;
 
     // append the pgnodes to the set of items
     items.extend(pgnode_impls);
@@ -441,11 +480,53 @@ fn format_builtin_oid_impl<'a>(
     }
 }
 
+/// Swap the derived `Debug` of each `Node` struct for one that is only derived without the
+/// `node-debug` feature, which instead has `impl_pg_node()` print them with `nodeToString()`
+fn gate_node_debug_derives(
+    mut items: Vec<syn::Item>,
+    node_names: &HashSet<syn::Ident>,
+) -> Vec<syn::Item> {
+    for item in items.iter_mut() {
+        let Item::Struct(struct_) = item else { continue };
+        if !node_names.contains(&struct_.ident) {
+            continue;
+        }
+        let mut gated = false;
+        let mut attrs = Vec::with_capacity(struct_.attrs.len() + 1);
+        for attr in struct_.attrs.drain(..) {
+            let list = match attr.parse_meta() {
+                Ok(syn::Meta::List(list)) if list.path.is_ident("derive") => list,
+                _ => {
+                    attrs.push(attr);
+                    continue;
+                }
+            };
+            let is_debug = |derive: &syn::NestedMeta| matches!(derive, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("Debug"));
+            let derives = list.nested.iter().filter(|derive| !is_debug(derive)).collect::<Vec<_>>();
+            if derives.len() == list.nested.len() {
+                attrs.push(attr);
+                continue;
+            }
+            gated = true;
+            if !derives.is_empty() {
+                attrs.push(syn::parse_quote! { #[derive(#(#derives),*)] });
+            }
+        }
+        struct_.attrs = attrs;
+        if gated {
+            struct_.attrs.push(
+                syn::parse_quote! { #[cfg_attr(not(feature = "node-debug"), derive(Debug))] },
+            );
+        }
+    }
+    items
+}
+
 /// Implement our `PgNode` marker trait for `pg_sys::Node` and its "subclasses"
 fn impl_pg_node(
     items: &Vec<syn::Item>,
     is_for_release: bool,
-) -> eyre::Result<proc_macro2::TokenStream> {
+) -> eyre::Result<(proc_macro2::TokenStream, HashSet<syn::Ident>)> {
     let mut pgnode_impls = proc_macro2::TokenStream::new();
 
     // we scope must of the computation so we can borrow `items` and then
@@ -511,8 +592,10 @@ fn impl_pg_node(
     };
 
     // now we can finally iterate the Nodes and emit out Display impl
+    let mut node_names = HashSet::new();
     for node_struct in nodes {
         let struct_name = &node_struct.struct_.ident;
+        node_names.insert(struct_name.clone());
 
         // impl the PgNode trait for all nodes
         pgnode_impls.extend(quote! {
@@ -528,9 +611,20 @@ fn impl_pg_node(
                 }
             }
         });
+
+        // and, with the `node-debug` feature, Debug too
+        let struct_name_str = struct_name.to_string();
+        pgnode_impls.extend(quote! {
+            #[cfg(feature = "node-debug")]
+            impl std::fmt::Debug for #struct_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(&pg_sys::debug_node(self, #struct_name_str))
+                }
+            }
+        });
     }
 
-    Ok(pgnode_impls)
+    Ok((pgnode_impls, node_names))
 }
 
 /// Given a root node, dfs_find_nodes adds all its children nodes to `node_set`.
@@ -680,6 +774,7 @@ fn get_bindings(
     major_version: u16,
     pg_config: &PgConfig,
     include_h: &PathBuf,
+    prune: bool,
 ) -> eyre::Result<syn::File> {
     let bindings = if let Some(info_dir) =
         target_env_tracked(&format!("PGRX_TARGET_INFO_PATH_PG{major_version}"))
@@ -688,7 +783,7 @@ fn get_bindings(
         std::fs::read_to_string(&bindings_file)
             .wrap_err_with(|| format!("failed to read raw bindings from {bindings_file}"))?
     } else {
-        let bindings = run_bindgen(major_version, pg_config, include_h)?;
+        let bindings = run_bindgen(major_version, pg_config, include_h, prune)?;
         if let Some(path) = env_tracked("PGRX_PG_SYS_EXTRA_OUTPUT_PATH") {
             std::fs::write(&path, &bindings)?;
         }
@@ -699,13 +794,37 @@ fn get_bindings(
 
 /// Given a specific postgres version, `run_bindgen` generates bindings for the given
 /// postgres version and returns them as a token stream.
+///
+/// With `prune`, only what's declared in Postgres' own headers is kept, along with whatever
+/// those declarations refer to.
 fn run_bindgen(
     major_version: u16,
     pg_config: &PgConfig,
     include_h: &PathBuf,
+    prune: bool,
 ) -> eyre::Result<String> {
+    use build::bindgen_filter;
+
     eprintln!("Generating bindings for pg{major_version}");
-    let bindings = bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default();
+    for function in bindgen_filter::BLOCKLISTED_FUNCTIONS
+        .iter()
+        .chain(bindgen_filter::blocklisted_functions_for(major_version))
+    {
+        builder = builder.blocklist_function(function);
+    }
+    if prune {
+        builder = builder
+            .allowlist_file(format!(
+                "{}/.*",
+                bindgen_filter::path_regex(&pg_config.includedir_server()?)
+            ))
+            .allowlist_file(bindgen_filter::path_regex(include_h));
+        for var in bindgen_filter::PRUNED_ALLOWED_VARS {
+            builder = builder.allowlist_var(var);
+        }
+    }
+    let bindings = builder
         .header(include_h.display().to_string())
         .clang_args(&extra_bindgen_clang_args(pg_config)?)
         .clang_args(pg_target_include_flags(major_version, pg_config)?)
@@ -0,0 +1,59 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Submodule of `build.rs`
+//!
+//! Maintained lists of what bindgen is told to leave out of the bindings, and of what survives
+//! when they're pruned down to Postgres' own headers.  Pruning happens with
+//! `PGRX_PG_SYS_PRUNE_BINDINGS=1`, and always when generating the bindings published for docs.rs,
+//! so those show the same symbols as a pruned local build.
+//!
+//! Only functions are blocklisted here.  Nothing else in the bindings refers to a function by
+//! name, so removing one can't leave a dangling reference the way removing a type can.
+
+/// Functions, for every Postgres version, that no extension can usefully call
+pub(crate) const BLOCKLISTED_FUNCTIONS: &[&str] = &[
+    // bison generated parser internals, which only work on the parser's own state
+    ".*_yy(?:lex|parse|error)",
+    // entry points of processes the postmaster starts, which never return
+    "(?:PostgresMain|PostgresSingleUserMain|PgArchiverMain|WalReceiverMain)",
+    // prompts on the terminal, which a backend doesn't have
+    "simple_prompt.*",
+];
+
+/// Functions that are only blocklisted for `major_version`, on top of [`BLOCKLISTED_FUNCTIONS`]
+pub(crate) fn blocklisted_functions_for(major_version: u16) -> &'static [&'static str] {
+    match major_version {
+        // the implementation of the elog() macro before pg13.  pgrx reports through ereport()
+        11 | 12 => &["elog_(?:start|finish)"],
+        _ => &[],
+    }
+}
+
+/// Items from system headers that survive pruning even though no Postgres declaration refers to
+/// them.  Types used by Postgres' declarations always survive, since bindgen follows references.
+pub(crate) const PRUNED_ALLOWED_VARS: &[&str] = &[
+    // signals, for background workers and `pg_signal_backend`-like things
+    "SIG[A-Z0-9]+",
+    // errno values, for checking what a failed Postgres function left in `errno`
+    "E(?:INTR|AGAIN|WOULDBLOCK|NOENT|EXIST|ACCES|NOSPC|INVAL)",
+];
+
+/// A regex matching exactly `path`, for `allowlist_file()`
+pub(crate) fn path_regex(path: &std::path::Path) -> String {
+    let path = path.display().to_string();
+    let mut regex = String::with_capacity(path.len());
+    for c in path.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            regex.push('\\');
+        }
+        regex.push(c);
+    }
+    regex
+}
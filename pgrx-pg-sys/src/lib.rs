@@ -226,6 +226,23 @@ pub(crate) unsafe fn display_node_impl(node: NonNull<crate::Node>) -> std::strin
     }
 }
 
+/// implementation function for `impl Debug for $NodeType`, with the `node-debug` feature
+///
+/// Postgres can't print every node, such as the executor's state nodes, nor a node that's still
+/// zeroed, so those are printed with just their name rather than raising an error
+#[cfg(feature = "node-debug")]
+#[doc(hidden)]
+pub fn debug_node<T: PgNode>(node: &T, name: &str) -> std::string::String {
+    // SAFETY: every PgNode starts with its NodeTag
+    let tag = unsafe { (*NonNull::from(node).cast::<crate::Node>().as_ptr()).type_ };
+    if tag == crate::NodeTag_T_Invalid {
+        return format!("{name} {{ <invalid> }}");
+    }
+    crate::PgTryBuilder::new(std::panic::AssertUnwindSafe(|| node.display_node()))
+        .catch_others(|_| format!("{name} {{ .. }}"))
+        .execute()
+}
+
 /// A trait for converting a thing into a `char *` that is allocated by Postgres' palloc
 pub trait AsPgCStr {
     /// Consumes `self` and converts it into a Postgres-allocated `char *`
@@ -587,6 +604,16 @@ mod all_versions {
         ) -> bool;
     }
 
+    /// this comes from `libpq/auth.h`, which we don't generate bindings for because it brings
+    /// along `libpq/libpq-be.h`, whose `Port` depends on how Postgres was configured
+    pub type ClientAuthentication_hook_type = ::std::option::Option<
+        unsafe extern "C" fn(port: *mut super::Port, status: ::std::os::raw::c_int),
+    >;
+
+    extern "C" {
+        pub static mut ClientAuthentication_hook: ClientAuthentication_hook_type;
+    }
+
     #[pg_guard]
     extern "C" {
         pub fn expression_tree_walker(
@@ -7403,7 +7403,8 @@ pub const NodeTag_T_ForeignKeyCacheInfo: NodeTag = 408;
 pub const NodeTag_T_CallContext: NodeTag = 409;
 pub type NodeTag = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Node {
     pub type_: NodeTag,
 }
@@ -7522,7 +7523,8 @@ pub const OnConflictAction_ONCONFLICT_NOTHING: OnConflictAction = 1;
 pub const OnConflictAction_ONCONFLICT_UPDATE: OnConflictAction = 2;
 pub type OnConflictAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct List {
     pub type_: NodeTag,
     pub length: ::std::os::raw::c_int,
@@ -9326,7 +9328,8 @@ pub const LockWaitPolicy_LockWaitSkip: LockWaitPolicy = 1;
 pub const LockWaitPolicy_LockWaitError: LockWaitPolicy = 2;
 pub type LockWaitPolicy = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Alias {
     pub type_: NodeTag,
     pub aliasname: *mut ::std::os::raw::c_char,
@@ -9347,7 +9350,8 @@ pub const OnCommitAction_ONCOMMIT_DELETE_ROWS: OnCommitAction = 2;
 pub const OnCommitAction_ONCOMMIT_DROP: OnCommitAction = 3;
 pub type OnCommitAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeVar {
     pub type_: NodeTag,
     pub catalogname: *mut ::std::os::raw::c_char,
@@ -9368,7 +9372,8 @@ impl Default for RangeVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFunc {
     pub type_: NodeTag,
     pub ns_uris: *mut List,
@@ -9395,7 +9400,8 @@ impl Default for TableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IntoClause {
     pub type_: NodeTag,
     pub rel: *mut RangeVar,
@@ -9416,7 +9422,8 @@ impl Default for IntoClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Expr {
     pub type_: NodeTag,
 }
@@ -9430,7 +9437,8 @@ impl Default for Expr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Var {
     pub xpr: Expr,
     pub varno: Index,
@@ -9453,7 +9461,8 @@ impl Default for Var {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Const {
     pub xpr: Expr,
     pub consttype: Oid,
@@ -9480,7 +9489,8 @@ pub const ParamKind_PARAM_SUBLINK: ParamKind = 2;
 pub const ParamKind_PARAM_MULTIEXPR: ParamKind = 3;
 pub type ParamKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Param {
     pub xpr: Expr,
     pub paramkind: ParamKind,
@@ -9500,7 +9510,8 @@ impl Default for Param {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Aggref {
     pub xpr: Expr,
     pub aggfnoid: Oid,
@@ -9531,7 +9542,8 @@ impl Default for Aggref {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingFunc {
     pub xpr: Expr,
     pub args: *mut List,
@@ -9550,7 +9562,8 @@ impl Default for GroupingFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFunc {
     pub xpr: Expr,
     pub winfnoid: Oid,
@@ -9574,7 +9587,8 @@ impl Default for WindowFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayRef {
     pub xpr: Expr,
     pub refarraytype: Oid,
@@ -9604,7 +9618,8 @@ pub const CoercionForm_COERCE_EXPLICIT_CAST: CoercionForm = 1;
 pub const CoercionForm_COERCE_IMPLICIT_CAST: CoercionForm = 2;
 pub type CoercionForm = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncExpr {
     pub xpr: Expr,
     pub funcid: Oid,
@@ -9627,7 +9642,8 @@ impl Default for FuncExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedArgExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9645,7 +9661,8 @@ impl Default for NamedArgExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -9669,7 +9686,8 @@ impl Default for OpExpr {
 pub type DistinctExpr = OpExpr;
 pub type NullIfExpr = OpExpr;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScalarArrayOpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -9693,7 +9711,8 @@ pub const BoolExprType_OR_EXPR: BoolExprType = 1;
 pub const BoolExprType_NOT_EXPR: BoolExprType = 2;
 pub type BoolExprType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BoolExpr {
     pub xpr: Expr,
     pub boolop: BoolExprType,
@@ -9719,7 +9738,8 @@ pub const SubLinkType_ARRAY_SUBLINK: SubLinkType = 6;
 pub const SubLinkType_CTE_SUBLINK: SubLinkType = 7;
 pub type SubLinkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubLink {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -9739,7 +9759,8 @@ impl Default for SubLink {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlan {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -9770,7 +9791,8 @@ impl Default for SubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlan {
     pub xpr: Expr,
     pub subplans: *mut List,
@@ -9785,7 +9807,8 @@ impl Default for AlternativeSubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldSelect {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9804,7 +9827,8 @@ impl Default for FieldSelect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldStore {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9822,7 +9846,8 @@ impl Default for FieldStore {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelabelType {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9842,7 +9867,8 @@ impl Default for RelabelType {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceViaIO {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9861,7 +9887,8 @@ impl Default for CoerceViaIO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayCoerceExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9882,7 +9909,8 @@ impl Default for ArrayCoerceExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConvertRowtypeExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9900,7 +9928,8 @@ impl Default for ConvertRowtypeExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9917,7 +9946,8 @@ impl Default for CollateExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseExpr {
     pub xpr: Expr,
     pub casetype: Oid,
@@ -9937,7 +9967,8 @@ impl Default for CaseExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseWhen {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -9954,7 +9985,8 @@ impl Default for CaseWhen {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseTestExpr {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -9971,7 +10003,8 @@ impl Default for CaseTestExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayExpr {
     pub xpr: Expr,
     pub array_typeid: Oid,
@@ -9991,7 +10024,8 @@ impl Default for ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowExpr {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10017,7 +10051,8 @@ pub const RowCompareType_ROWCOMPARE_GT: RowCompareType = 5;
 pub const RowCompareType_ROWCOMPARE_NE: RowCompareType = 6;
 pub type RowCompareType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowCompareExpr {
     pub xpr: Expr,
     pub rctype: RowCompareType,
@@ -10037,7 +10072,8 @@ impl Default for RowCompareExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoalesceExpr {
     pub xpr: Expr,
     pub coalescetype: Oid,
@@ -10058,7 +10094,8 @@ pub const MinMaxOp_IS_GREATEST: MinMaxOp = 0;
 pub const MinMaxOp_IS_LEAST: MinMaxOp = 1;
 pub type MinMaxOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxExpr {
     pub xpr: Expr,
     pub minmaxtype: Oid,
@@ -10094,7 +10131,8 @@ pub const SQLValueFunctionOp_SVFOP_CURRENT_CATALOG: SQLValueFunctionOp = 13;
 pub const SQLValueFunctionOp_SVFOP_CURRENT_SCHEMA: SQLValueFunctionOp = 14;
 pub type SQLValueFunctionOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLValueFunction {
     pub xpr: Expr,
     pub op: SQLValueFunctionOp,
@@ -10124,7 +10162,8 @@ pub const XmlOptionType_XMLOPTION_DOCUMENT: XmlOptionType = 0;
 pub const XmlOptionType_XMLOPTION_CONTENT: XmlOptionType = 1;
 pub type XmlOptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlExpr {
     pub xpr: Expr,
     pub op: XmlExprOp,
@@ -10150,7 +10189,8 @@ pub const NullTestType_IS_NULL: NullTestType = 0;
 pub const NullTestType_IS_NOT_NULL: NullTestType = 1;
 pub type NullTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NullTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10175,7 +10215,8 @@ pub const BoolTestType_IS_UNKNOWN: BoolTestType = 4;
 pub const BoolTestType_IS_NOT_UNKNOWN: BoolTestType = 5;
 pub type BoolTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BooleanTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10192,7 +10233,8 @@ impl Default for BooleanTest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomain {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10212,7 +10254,8 @@ impl Default for CoerceToDomain {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomainValue {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10230,7 +10273,8 @@ impl Default for CoerceToDomainValue {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetToDefault {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10248,7 +10292,8 @@ impl Default for SetToDefault {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CurrentOfExpr {
     pub xpr: Expr,
     pub cvarno: Index,
@@ -10265,7 +10310,8 @@ impl Default for CurrentOfExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NextValueExpr {
     pub xpr: Expr,
     pub seqid: Oid,
@@ -10281,7 +10327,8 @@ impl Default for NextValueExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferenceElem {
     pub xpr: Expr,
     pub expr: *mut Node,
@@ -10298,7 +10345,8 @@ impl Default for InferenceElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TargetEntry {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10319,7 +10367,8 @@ impl Default for TargetEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblRef {
     pub type_: NodeTag,
     pub rtindex: ::std::os::raw::c_int,
@@ -10334,7 +10383,8 @@ impl Default for RangeTblRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinExpr {
     pub type_: NodeTag,
     pub jointype: JoinType,
@@ -10356,7 +10406,8 @@ impl Default for JoinExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FromExpr {
     pub type_: NodeTag,
     pub fromlist: *mut List,
@@ -10372,7 +10423,8 @@ impl Default for FromExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictExpr {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -11156,7 +11208,8 @@ extern "C" {
     pub fn RestoreParamList(start_address: *mut *mut ::std::os::raw::c_char) -> ParamListInfo;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannedStmt {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -11193,7 +11246,8 @@ impl Default for PlannedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Plan {
     pub type_: NodeTag,
     pub startup_cost: Cost,
@@ -11221,7 +11275,8 @@ impl Default for Plan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Result {
     pub plan: Plan,
     pub resconstantqual: *mut Node,
@@ -11236,7 +11291,8 @@ impl Default for Result {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSet {
     pub plan: Plan,
 }
@@ -11250,7 +11306,8 @@ impl Default for ProjectSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTable {
     pub plan: Plan,
     pub operation: CmdType,
@@ -11285,7 +11342,8 @@ impl Default for ModifyTable {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Append {
     pub plan: Plan,
     pub appendplans: *mut List,
@@ -11303,7 +11361,8 @@ impl Default for Append {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppend {
     pub plan: Plan,
     pub partitioned_rels: *mut List,
@@ -11324,7 +11383,8 @@ impl Default for MergeAppend {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnion {
     pub plan: Plan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11343,7 +11403,8 @@ impl Default for RecursiveUnion {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAnd {
     pub plan: Plan,
     pub bitmapplans: *mut List,
@@ -11358,7 +11419,8 @@ impl Default for BitmapAnd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOr {
     pub plan: Plan,
     pub isshared: bool,
@@ -11374,7 +11436,8 @@ impl Default for BitmapOr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Scan {
     pub plan: Plan,
     pub scanrelid: Index,
@@ -11390,7 +11453,8 @@ impl Default for Scan {
 }
 pub type SeqScan = Scan;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScan {
     pub scan: Scan,
     pub tablesample: *mut TableSampleClause,
@@ -11405,7 +11469,8 @@ impl Default for SampleScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11426,7 +11491,8 @@ impl Default for IndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11446,7 +11512,8 @@ impl Default for IndexOnlyScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11464,7 +11531,8 @@ impl Default for BitmapIndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScan {
     pub scan: Scan,
     pub bitmapqualorig: *mut List,
@@ -11479,7 +11547,8 @@ impl Default for BitmapHeapScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScan {
     pub scan: Scan,
     pub tidquals: *mut List,
@@ -11494,7 +11563,8 @@ impl Default for TidScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScan {
     pub scan: Scan,
     pub subplan: *mut Plan,
@@ -11509,7 +11579,8 @@ impl Default for SubqueryScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScan {
     pub scan: Scan,
     pub functions: *mut List,
@@ -11525,7 +11596,8 @@ impl Default for FunctionScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScan {
     pub scan: Scan,
     pub values_lists: *mut List,
@@ -11540,7 +11612,8 @@ impl Default for ValuesScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScan {
     pub scan: Scan,
     pub tablefunc: *mut TableFunc,
@@ -11555,7 +11628,8 @@ impl Default for TableFuncScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScan {
     pub scan: Scan,
     pub ctePlanId: ::std::os::raw::c_int,
@@ -11571,7 +11645,8 @@ impl Default for CteScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScan {
     pub scan: Scan,
     pub enrname: *mut ::std::os::raw::c_char,
@@ -11586,7 +11661,8 @@ impl Default for NamedTuplestoreScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScan {
     pub scan: Scan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11601,7 +11677,8 @@ impl Default for WorkTableScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScan {
     pub scan: Scan,
     pub operation: CmdType,
@@ -11623,7 +11700,8 @@ impl Default for ForeignScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScan {
     pub scan: Scan,
     pub flags: uint32,
@@ -11644,7 +11722,8 @@ impl Default for CustomScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Join {
     pub plan: Plan,
     pub jointype: JoinType,
@@ -11661,7 +11740,8 @@ impl Default for Join {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoop {
     pub join: Join,
     pub nestParams: *mut List,
@@ -11676,7 +11756,8 @@ impl Default for NestLoop {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopParam {
     pub type_: NodeTag,
     pub paramno: ::std::os::raw::c_int,
@@ -11692,7 +11773,8 @@ impl Default for NestLoopParam {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoin {
     pub join: Join,
     pub skip_mark_restore: bool,
@@ -11712,7 +11794,8 @@ impl Default for MergeJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoin {
     pub join: Join,
     pub hashclauses: *mut List,
@@ -11727,7 +11810,8 @@ impl Default for HashJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Material {
     pub plan: Plan,
 }
@@ -11741,7 +11825,8 @@ impl Default for Material {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Sort {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11760,7 +11845,8 @@ impl Default for Sort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Group {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11777,7 +11863,8 @@ impl Default for Group {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Agg {
     pub plan: Plan,
     pub aggstrategy: AggStrategy,
@@ -11800,7 +11887,8 @@ impl Default for Agg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAgg {
     pub plan: Plan,
     pub winref: Index,
@@ -11829,7 +11917,8 @@ impl Default for WindowAgg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Unique {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11846,7 +11935,8 @@ impl Default for Unique {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Gather {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11865,7 +11955,8 @@ impl Default for Gather {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMerge {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11887,7 +11978,8 @@ impl Default for GatherMerge {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Hash {
     pub plan: Plan,
     pub skewTable: Oid,
@@ -11905,7 +11997,8 @@ impl Default for Hash {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOp {
     pub plan: Plan,
     pub cmd: SetOpCmd,
@@ -11927,7 +12020,8 @@ impl Default for SetOp {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRows {
     pub plan: Plan,
     pub rowMarks: *mut List,
@@ -11943,7 +12037,8 @@ impl Default for LockRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Limit {
     pub plan: Plan,
     pub limitOffset: *mut Node,
@@ -11966,7 +12061,8 @@ pub const RowMarkType_ROW_MARK_REFERENCE: RowMarkType = 4;
 pub const RowMarkType_ROW_MARK_COPY: RowMarkType = 5;
 pub type RowMarkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanRowMark {
     pub type_: NodeTag,
     pub rti: Index,
@@ -11988,7 +12084,8 @@ impl Default for PlanRowMark {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneInfo {
     pub type_: NodeTag,
     pub prune_infos: *mut List,
@@ -12004,7 +12101,8 @@ impl Default for PartitionPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionedRelPruneInfo {
     pub type_: NodeTag,
     pub reloid: Oid,
@@ -12031,7 +12129,8 @@ impl Default for PartitionedRelPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStep {
     pub type_: NodeTag,
     pub step_id: ::std::os::raw::c_int,
@@ -12046,7 +12145,8 @@ impl Default for PartitionPruneStep {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepOp {
     pub step: PartitionPruneStep,
     pub opstrategy: StrategyNumber,
@@ -12067,7 +12167,8 @@ pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_UNION: PartitionPruneCombine
 pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_INTERSECT: PartitionPruneCombineOp = 1;
 pub type PartitionPruneCombineOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepCombine {
     pub step: PartitionPruneStep,
     pub combineOp: PartitionPruneCombineOp,
@@ -12083,7 +12184,8 @@ impl Default for PartitionPruneStepCombine {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanInvalItem {
     pub type_: NodeTag,
     pub cacheId: ::std::os::raw::c_int,
@@ -13010,7 +13112,8 @@ extern "C" {
     pub fn PrepareSortSupportFromIndexRel(indexRel: Relation, strategy: int16, ssup: SortSupport);
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TupleTableSlot {
     pub type_: NodeTag,
     pub tts_isempty: bool,
@@ -13599,7 +13702,8 @@ pub type ExprStateEvalFunc = ::std::option::Option<
     ) -> Datum,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprState {
     pub tag: Node,
     pub flags: uint8,
@@ -13629,7 +13733,8 @@ impl Default for ExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexInfo {
     pub type_: NodeTag,
     pub ii_NumIndexAttrs: ::std::os::raw::c_int,
@@ -13681,7 +13786,8 @@ impl Default for ExprContext_CB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprContext {
     pub type_: NodeTag,
     pub ecxt_scantuple: *mut TupleTableSlot,
@@ -13719,7 +13825,8 @@ pub const SetFunctionReturnMode_SFRM_Materialize_Random: SetFunctionReturnMode =
 pub const SetFunctionReturnMode_SFRM_Materialize_Preferred: SetFunctionReturnMode = 8;
 pub type SetFunctionReturnMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnSetInfo {
     pub type_: NodeTag,
     pub econtext: *mut ExprContext,
@@ -13740,7 +13847,8 @@ impl Default for ReturnSetInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionInfo {
     pub type_: NodeTag,
     pub pi_state: ExprState,
@@ -13756,7 +13864,8 @@ impl Default for ProjectionInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JunkFilter {
     pub type_: NodeTag,
     pub jf_targetList: *mut List,
@@ -13775,7 +13884,8 @@ impl Default for JunkFilter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictSetState {
     pub type_: NodeTag,
     pub oc_ProjInfo: *mut ProjectionInfo,
@@ -13792,7 +13902,8 @@ impl Default for OnConflictSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultRelInfo {
     pub type_: NodeTag,
     pub ri_RangeTableIndex: Index,
@@ -13830,7 +13941,8 @@ impl Default for ResultRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EState {
     pub type_: NodeTag,
     pub es_direction: ScanDirection,
@@ -14062,7 +14174,8 @@ impl Default for TupleHashTableData {
 }
 pub type TupleHashIterator = tuplehash_iterator;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggrefExprState {
     pub type_: NodeTag,
     pub aggref: *mut Aggref,
@@ -14078,7 +14191,8 @@ impl Default for AggrefExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFuncExprState {
     pub type_: NodeTag,
     pub wfunc: *mut WindowFunc,
@@ -14096,7 +14210,8 @@ impl Default for WindowFuncExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetExprState {
     pub type_: NodeTag,
     pub expr: *mut Expr,
@@ -14122,7 +14237,8 @@ impl Default for SetExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut SubPlan,
@@ -14161,7 +14277,8 @@ impl Default for SubPlanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut AlternativeSubPlan,
@@ -14181,7 +14298,8 @@ pub const DomainConstraintType_DOM_CONSTRAINT_NOTNULL: DomainConstraintType = 0;
 pub const DomainConstraintType_DOM_CONSTRAINT_CHECK: DomainConstraintType = 1;
 pub type DomainConstraintType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DomainConstraintState {
     pub type_: NodeTag,
     pub constrainttype: DomainConstraintType,
@@ -14201,7 +14319,8 @@ impl Default for DomainConstraintState {
 pub type ExecProcNodeMtd =
     ::std::option::Option<unsafe extern "C" fn(pstate: *mut PlanState) -> *mut TupleTableSlot>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanState {
     pub type_: NodeTag,
     pub plan: *mut Plan,
@@ -14251,7 +14370,8 @@ impl Default for EPQState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultState {
     pub ps: PlanState,
     pub resconstantqual: *mut ExprState,
@@ -14268,7 +14388,8 @@ impl Default for ResultState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetState {
     pub ps: PlanState,
     pub elems: *mut *mut Node,
@@ -14287,7 +14408,8 @@ impl Default for ProjectSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTableState {
     pub ps: PlanState,
     pub operation: CmdType,
@@ -14324,7 +14446,8 @@ pub struct ParallelAppendState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendState {
     pub ps: PlanState,
     pub appendplans: *mut *mut PlanState,
@@ -14348,7 +14471,8 @@ impl Default for AppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendState {
     pub ps: PlanState,
     pub mergeplans: *mut *mut PlanState,
@@ -14369,7 +14493,8 @@ impl Default for MergeAppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionState {
     pub ps: PlanState,
     pub recursing: bool,
@@ -14392,7 +14517,8 @@ impl Default for RecursiveUnionState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -14408,7 +14534,8 @@ impl Default for BitmapAndState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -14424,7 +14551,8 @@ impl Default for BitmapOrState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScanState {
     pub ps: PlanState,
     pub ss_currentRelation: Relation,
@@ -14441,7 +14569,8 @@ impl Default for ScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SeqScanState {
     pub ss: ScanState,
     pub pscan_len: Size,
@@ -14456,7 +14585,8 @@ impl Default for SeqScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScanState {
     pub ss: ScanState,
     pub args: *mut List,
@@ -14513,7 +14643,8 @@ impl Default for IndexArrayKeyInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScanState {
     pub ss: ScanState,
     pub indexqualorig: *mut ExprState,
@@ -14547,7 +14678,8 @@ impl Default for IndexScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScanState {
     pub ss: ScanState,
     pub recheckqual: *mut ExprState,
@@ -14574,7 +14706,8 @@ impl Default for IndexOnlyScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScanState {
     pub ss: ScanState,
     pub biss_result: *mut TIDBitmap,
@@ -14624,7 +14757,8 @@ impl Default for ParallelBitmapHeapState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScanState {
     pub ss: ScanState,
     pub bitmapqualorig: *mut ExprState,
@@ -14657,7 +14791,8 @@ impl Default for BitmapHeapScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScanState {
     pub ss: ScanState,
     pub tss_tidexprs: *mut List,
@@ -14677,7 +14812,8 @@ impl Default for TidScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanState {
     pub ss: ScanState,
     pub subplan: *mut PlanState,
@@ -14697,7 +14833,8 @@ pub struct FunctionScanPerFuncState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -14718,7 +14855,8 @@ impl Default for FunctionScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScanState {
     pub ss: ScanState,
     pub rowcontext: *mut ExprContext,
@@ -14737,7 +14875,8 @@ impl Default for ValuesScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScanState {
     pub ss: ScanState,
     pub docexpr: *mut ExprState,
@@ -14765,7 +14904,8 @@ impl Default for TableFuncScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -14785,7 +14925,8 @@ impl Default for CteScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScanState {
     pub ss: ScanState,
     pub readptr: ::std::os::raw::c_int,
@@ -14802,7 +14943,8 @@ impl Default for NamedTuplestoreScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScanState {
     pub ss: ScanState,
     pub rustate: *mut RecursiveUnionState,
@@ -14817,7 +14959,8 @@ impl Default for WorkTableScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScanState {
     pub ss: ScanState,
     pub fdw_recheck_quals: *mut ExprState,
@@ -14835,7 +14978,8 @@ impl Default for ForeignScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScanState {
     pub ss: ScanState,
     pub flags: uint32,
@@ -14853,7 +14997,8 @@ impl Default for CustomScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinState {
     pub ps: PlanState,
     pub jointype: JoinType,
@@ -14870,7 +15015,8 @@ impl Default for JoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopState {
     pub js: JoinState,
     pub nl_NeedNewOuter: bool,
@@ -14893,7 +15039,8 @@ pub struct MergeJoinClauseData {
 }
 pub type MergeJoinClause = *mut MergeJoinClauseData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoinState {
     pub js: JoinState,
     pub mj_NumClauses: ::std::os::raw::c_int,
@@ -14936,7 +15083,8 @@ pub struct HashJoinTableData {
 }
 pub type HashJoinTable = *mut HashJoinTableData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoinState {
     pub js: JoinState,
     pub hashclauses: *mut ExprState,
@@ -14967,7 +15115,8 @@ impl Default for HashJoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -14999,7 +15148,8 @@ impl Default for SharedSortInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortState {
     pub ss: ScanState,
     pub randomAccess: bool,
@@ -15022,7 +15172,8 @@ impl Default for SortState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupState {
     pub ss: ScanState,
     pub eqfunction: *mut ExprState,
@@ -15068,7 +15219,8 @@ pub struct AggStatePerHashData {
 }
 pub type AggStatePerHash = *mut AggStatePerHashData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggState {
     pub ss: ScanState,
     pub aggs: *mut List,
@@ -15129,7 +15281,8 @@ pub struct WindowStatePerAggData {
 }
 pub type WindowStatePerAgg = *mut WindowStatePerAggData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggState {
     pub ss: ScanState,
     pub funcs: *mut List,
@@ -15194,7 +15347,8 @@ impl Default for WindowAggState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniqueState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -15209,7 +15363,8 @@ impl Default for UniqueState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -15237,7 +15392,8 @@ pub struct GMReaderTupleBuffer {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergeState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -15280,7 +15436,8 @@ pub struct SharedHashInfo {
     pub hinstrument: __IncompleteArrayField<HashInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashState {
     pub ps: PlanState,
     pub hashtable: HashJoinTable,
@@ -15305,7 +15462,8 @@ pub struct SetOpStatePerGroupData {
 }
 pub type SetOpStatePerGroup = *mut SetOpStatePerGroupData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -15330,7 +15488,8 @@ impl Default for SetOpState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsState {
     pub ps: PlanState,
     pub lr_arowMarks: *mut List,
@@ -15356,7 +15515,8 @@ pub const LimitStateCond_LIMIT_WINDOWEND: LimitStateCond = 5;
 pub const LimitStateCond_LIMIT_WINDOWSTART: LimitStateCond = 6;
 pub type LimitStateCond = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitState {
     pub ps: PlanState,
     pub limitOffset: *mut ExprState,
@@ -15570,7 +15730,8 @@ pub type SortByNulls = ::std::os::raw::c_uint;
 pub type AclMode = uint32;
 #[doc = "\tQuery Tree"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Query {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -15621,7 +15782,8 @@ impl Default for Query {
 }
 #[doc = "\tSupporting data structures for Parse Trees\n\n\tMost of these node types appear in raw parsetrees output by the grammar,\n\tand get transformed to something else by the analyzer.  A few of them\n\tare used as-is in transformed querytrees."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeName {
     pub type_: NodeTag,
     pub names: *mut List,
@@ -15643,7 +15805,8 @@ impl Default for TypeName {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnRef {
     pub type_: NodeTag,
     pub fields: *mut List,
@@ -15659,7 +15822,8 @@ impl Default for ColumnRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamRef {
     pub type_: NodeTag,
     pub number: ::std::os::raw::c_int,
@@ -15692,7 +15856,8 @@ pub const A_Expr_Kind_AEXPR_NOT_BETWEEN_SYM: A_Expr_Kind = 14;
 pub const A_Expr_Kind_AEXPR_PAREN: A_Expr_Kind = 15;
 pub type A_Expr_Kind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Expr {
     pub type_: NodeTag,
     pub kind: A_Expr_Kind,
@@ -15727,7 +15892,8 @@ impl Default for A_Const {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeCast {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -15744,7 +15910,8 @@ impl Default for TypeCast {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateClause {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -15766,7 +15933,8 @@ pub const RoleSpecType_ROLESPEC_SESSION_USER: RoleSpecType = 2;
 pub const RoleSpecType_ROLESPEC_PUBLIC: RoleSpecType = 3;
 pub type RoleSpecType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RoleSpec {
     pub type_: NodeTag,
     pub roletype: RoleSpecType,
@@ -15783,7 +15951,8 @@ impl Default for RoleSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncCall {
     pub type_: NodeTag,
     pub funcname: *mut List,
@@ -15807,7 +15976,8 @@ impl Default for FuncCall {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Star {
     pub type_: NodeTag,
 }
@@ -15821,7 +15991,8 @@ impl Default for A_Star {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indices {
     pub type_: NodeTag,
     pub is_slice: bool,
@@ -15838,7 +16009,8 @@ impl Default for A_Indices {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indirection {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -15854,7 +16026,8 @@ impl Default for A_Indirection {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_ArrayExpr {
     pub type_: NodeTag,
     pub elements: *mut List,
@@ -15870,7 +16043,8 @@ impl Default for A_ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResTarget {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -15888,7 +16062,8 @@ impl Default for ResTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MultiAssignRef {
     pub type_: NodeTag,
     pub source: *mut Node,
@@ -15905,7 +16080,8 @@ impl Default for MultiAssignRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortBy {
     pub type_: NodeTag,
     pub node: *mut Node,
@@ -15924,7 +16100,8 @@ impl Default for SortBy {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowDef {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -15946,7 +16123,8 @@ impl Default for WindowDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeSubselect {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -15963,7 +16141,8 @@ impl Default for RangeSubselect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeFunction {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -15983,7 +16162,8 @@ impl Default for RangeFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFunc {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -16004,7 +16184,8 @@ impl Default for RangeTableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFuncCol {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -16025,7 +16206,8 @@ impl Default for RangeTableFuncCol {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableSample {
     pub type_: NodeTag,
     pub relation: *mut Node,
@@ -16044,7 +16226,8 @@ impl Default for RangeTableSample {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnDef {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -16075,7 +16258,8 @@ impl Default for ColumnDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableLikeClause {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16101,7 +16285,8 @@ pub const TableLikeOption_CREATE_TABLE_LIKE_STORAGE: TableLikeOption = 64;
 pub const TableLikeOption_CREATE_TABLE_LIKE_ALL: TableLikeOption = 2147483647;
 pub type TableLikeOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16127,7 +16312,8 @@ pub const DefElemAction_DEFELEM_ADD: DefElemAction = 2;
 pub const DefElemAction_DEFELEM_DROP: DefElemAction = 3;
 pub type DefElemAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefElem {
     pub type_: NodeTag,
     pub defnamespace: *mut ::std::os::raw::c_char,
@@ -16146,7 +16332,8 @@ impl Default for DefElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockingClause {
     pub type_: NodeTag,
     pub lockedRels: *mut List,
@@ -16163,7 +16350,8 @@ impl Default for LockingClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlSerialize {
     pub type_: NodeTag,
     pub xmloption: XmlOptionType,
@@ -16181,7 +16369,8 @@ impl Default for XmlSerialize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16200,7 +16389,8 @@ impl Default for PartitionElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionSpec {
     pub type_: NodeTag,
     pub strategy: *mut ::std::os::raw::c_char,
@@ -16217,7 +16407,8 @@ impl Default for PartitionSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionBoundSpec {
     pub type_: NodeTag,
     pub strategy: ::std::os::raw::c_char,
@@ -16243,7 +16434,8 @@ pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_VALUE: PartitionRangeDat
 pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_MAXVALUE: PartitionRangeDatumKind = 1;
 pub type PartitionRangeDatumKind = ::std::os::raw::c_int;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionRangeDatum {
     pub type_: NodeTag,
     pub kind: PartitionRangeDatumKind,
@@ -16260,7 +16452,8 @@ impl Default for PartitionRangeDatum {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionCmd {
     pub type_: NodeTag,
     pub name: *mut RangeVar,
@@ -16285,7 +16478,8 @@ pub const RTEKind_RTE_CTE: RTEKind = 6;
 pub const RTEKind_RTE_NAMEDTUPLESTORE: RTEKind = 7;
 pub type RTEKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblEntry {
     pub type_: NodeTag,
     pub rtekind: RTEKind,
@@ -16330,7 +16524,8 @@ impl Default for RangeTblEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblFunction {
     pub type_: NodeTag,
     pub funcexpr: *mut Node,
@@ -16351,7 +16546,8 @@ impl Default for RangeTblFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableSampleClause {
     pub type_: NodeTag,
     pub tsmhandler: Oid,
@@ -16373,7 +16569,8 @@ pub const WCOKind_WCO_RLS_UPDATE_CHECK: WCOKind = 2;
 pub const WCOKind_WCO_RLS_CONFLICT_CHECK: WCOKind = 3;
 pub type WCOKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithCheckOption {
     pub type_: NodeTag,
     pub kind: WCOKind,
@@ -16392,7 +16589,8 @@ impl Default for WithCheckOption {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortGroupClause {
     pub type_: NodeTag,
     pub tleSortGroupRef: Index,
@@ -16417,7 +16615,8 @@ pub const GroupingSetKind_GROUPING_SET_CUBE: GroupingSetKind = 3;
 pub const GroupingSetKind_GROUPING_SET_SETS: GroupingSetKind = 4;
 pub type GroupingSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSet {
     pub type_: NodeTag,
     pub kind: GroupingSetKind,
@@ -16434,7 +16633,8 @@ impl Default for GroupingSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowClause {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16462,7 +16662,8 @@ impl Default for WindowClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowMarkClause {
     pub type_: NodeTag,
     pub rti: Index,
@@ -16480,7 +16681,8 @@ impl Default for RowMarkClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithClause {
     pub type_: NodeTag,
     pub ctes: *mut List,
@@ -16497,7 +16699,8 @@ impl Default for WithClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferClause {
     pub type_: NodeTag,
     pub indexElems: *mut List,
@@ -16515,7 +16718,8 @@ impl Default for InferClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictClause {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -16534,7 +16738,8 @@ impl Default for OnConflictClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommonTableExpr {
     pub type_: NodeTag,
     pub ctename: *mut ::std::os::raw::c_char,
@@ -16558,7 +16763,8 @@ impl Default for CommonTableExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerTransition {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16576,7 +16782,8 @@ impl Default for TriggerTransition {
 }
 #[doc = "\t\tRaw Grammar Output Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RawStmt {
     pub type_: NodeTag,
     pub stmt: *mut Node,
@@ -16594,7 +16801,8 @@ impl Default for RawStmt {
 }
 #[doc = "\t\tOptimizable Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InsertStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16615,7 +16823,8 @@ impl Default for InsertStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeleteStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16634,7 +16843,8 @@ impl Default for DeleteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpdateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16659,7 +16869,8 @@ pub const SetOperation_SETOP_INTERSECT: SetOperation = 2;
 pub const SetOperation_SETOP_EXCEPT: SetOperation = 3;
 pub type SetOperation = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SelectStmt {
     pub type_: NodeTag,
     pub distinctClause: *mut List,
@@ -16691,7 +16902,8 @@ impl Default for SelectStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOperationStmt {
     pub type_: NodeTag,
     pub op: SetOperation,
@@ -16765,7 +16977,8 @@ pub const ObjectType_OBJECT_VIEW: ObjectType = 49;
 #[doc = "\t\tOther Statements (no optimizations required)\n\n\t\tThese are not touched by parser/analyze.c except to put them into\n\t\tthe utilityStmt field of a Query.  This is eventually passed to\n\t\tProcessUtility (by-passing rewriting and planning).  Some of the\n\t\tstatements do need attention from parse analysis, and this is\n\t\tdone by routines in parser/parse_utilcmd.c after ProcessUtility\n\t\treceives the command for execution.\n\t\tDECLARE CURSOR, EXPLAIN, and CREATE TABLE AS are special cases:\n\t\tthey contain optimizable statements, which get processed normally\n\t\tby parser/analyze.c."]
 pub type ObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSchemaStmt {
     pub type_: NodeTag,
     pub schemaname: *mut ::std::os::raw::c_char,
@@ -16786,7 +16999,8 @@ pub const DropBehavior_DROP_RESTRICT: DropBehavior = 0;
 pub const DropBehavior_DROP_CASCADE: DropBehavior = 1;
 pub type DropBehavior = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16872,7 +17086,8 @@ pub const AlterTableType_AT_SetIdentity: AlterTableType = 65;
 pub const AlterTableType_AT_DropIdentity: AlterTableType = 66;
 pub type AlterTableType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReplicaIdentityStmt {
     pub type_: NodeTag,
     pub identity_type: ::std::os::raw::c_char,
@@ -16888,7 +17103,8 @@ impl Default for ReplicaIdentityStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableCmd {
     pub type_: NodeTag,
     pub subtype: AlterTableType,
@@ -16910,7 +17126,8 @@ impl Default for AlterTableCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterCollationStmt {
     pub type_: NodeTag,
     pub collname: *mut List,
@@ -16925,7 +17142,8 @@ impl Default for AlterCollationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDomainStmt {
     pub type_: NodeTag,
     pub subtype: ::std::os::raw::c_char,
@@ -16949,7 +17167,8 @@ pub const GrantTargetType_ACL_TARGET_ALL_IN_SCHEMA: GrantTargetType = 1;
 pub const GrantTargetType_ACL_TARGET_DEFAULTS: GrantTargetType = 2;
 pub type GrantTargetType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantStmt {
     pub type_: NodeTag,
     pub is_grant: bool,
@@ -16971,7 +17190,8 @@ impl Default for GrantStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ObjectWithArgs {
     pub type_: NodeTag,
     pub objname: *mut List,
@@ -16988,7 +17208,8 @@ impl Default for ObjectWithArgs {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AccessPriv {
     pub type_: NodeTag,
     pub priv_name: *mut ::std::os::raw::c_char,
@@ -17004,7 +17225,8 @@ impl Default for AccessPriv {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantRoleStmt {
     pub type_: NodeTag,
     pub granted_roles: *mut List,
@@ -17024,7 +17246,8 @@ impl Default for GrantRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDefaultPrivilegesStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -17040,7 +17263,8 @@ impl Default for AlterDefaultPrivilegesStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CopyStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17068,7 +17292,8 @@ pub const VariableSetKind_VAR_RESET: VariableSetKind = 4;
 pub const VariableSetKind_VAR_RESET_ALL: VariableSetKind = 5;
 pub type VariableSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableSetStmt {
     pub type_: NodeTag,
     pub kind: VariableSetKind,
@@ -17086,7 +17311,8 @@ impl Default for VariableSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableShowStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17101,7 +17327,8 @@ impl Default for VariableShowStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17140,7 +17367,8 @@ pub const ConstrType_CONSTR_ATTR_DEFERRED: ConstrType = 11;
 pub const ConstrType_CONSTR_ATTR_IMMEDIATE: ConstrType = 12;
 pub type ConstrType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Constraint {
     pub type_: NodeTag,
     pub contype: ConstrType,
@@ -17181,7 +17409,8 @@ impl Default for Constraint {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17199,7 +17428,8 @@ impl Default for CreateTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17215,7 +17445,8 @@ impl Default for DropTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableSpaceOptionsStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17232,7 +17463,8 @@ impl Default for AlterTableSpaceOptionsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableMoveAllStmt {
     pub type_: NodeTag,
     pub orig_tablespacename: *mut ::std::os::raw::c_char,
@@ -17251,7 +17483,8 @@ impl Default for AlterTableMoveAllStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17268,7 +17501,8 @@ impl Default for CreateExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17284,7 +17518,8 @@ impl Default for AlterExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionContentsStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17302,7 +17537,8 @@ impl Default for AlterExtensionContentsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -17319,7 +17555,8 @@ impl Default for CreateFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -17336,7 +17573,8 @@ impl Default for AlterFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -17356,7 +17594,8 @@ impl Default for CreateForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -17374,7 +17613,8 @@ impl Default for AlterForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignTableStmt {
     pub base: CreateStmt,
     pub servername: *mut ::std::os::raw::c_char,
@@ -17390,7 +17630,8 @@ impl Default for CreateForeignTableStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -17408,7 +17649,8 @@ impl Default for CreateUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -17425,7 +17667,8 @@ impl Default for AlterUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -17446,7 +17689,8 @@ pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_LIMIT_TO: ImportForeignSchem
 pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_EXCEPT: ImportForeignSchemaType = 2;
 pub type ImportForeignSchemaType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ImportForeignSchemaStmt {
     pub type_: NodeTag,
     pub server_name: *mut ::std::os::raw::c_char,
@@ -17466,7 +17710,8 @@ impl Default for ImportForeignSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -17487,7 +17732,8 @@ impl Default for CreatePolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -17506,7 +17752,8 @@ impl Default for AlterPolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateAmStmt {
     pub type_: NodeTag,
     pub amname: *mut ::std::os::raw::c_char,
@@ -17523,7 +17770,8 @@ impl Default for CreateAmStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -17551,7 +17799,8 @@ impl Default for CreateTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -17569,7 +17818,8 @@ impl Default for CreateEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -17585,7 +17835,8 @@ impl Default for AlterEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePLangStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -17609,7 +17860,8 @@ pub const RoleStmtType_ROLESTMT_USER: RoleStmtType = 1;
 pub const RoleStmtType_ROLESTMT_GROUP: RoleStmtType = 2;
 pub type RoleStmtType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRoleStmt {
     pub type_: NodeTag,
     pub stmt_type: RoleStmtType,
@@ -17626,7 +17878,8 @@ impl Default for CreateRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -17643,7 +17896,8 @@ impl Default for AlterRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleSetStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -17660,7 +17914,8 @@ impl Default for AlterRoleSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropRoleStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -17676,7 +17931,8 @@ impl Default for DropRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -17695,7 +17951,8 @@ impl Default for CreateSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -17713,7 +17970,8 @@ impl Default for AlterSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefineStmt {
     pub type_: NodeTag,
     pub kind: ObjectType,
@@ -17733,7 +17991,8 @@ impl Default for DefineStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateDomainStmt {
     pub type_: NodeTag,
     pub domainname: *mut List,
@@ -17751,7 +18010,8 @@ impl Default for CreateDomainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassStmt {
     pub type_: NodeTag,
     pub opclassname: *mut List,
@@ -17771,7 +18031,8 @@ impl Default for CreateOpClassStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassItem {
     pub type_: NodeTag,
     pub itemtype: ::std::os::raw::c_int,
@@ -17791,7 +18052,8 @@ impl Default for CreateOpClassItem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -17807,7 +18069,8 @@ impl Default for CreateOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -17825,7 +18088,8 @@ impl Default for AlterOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropStmt {
     pub type_: NodeTag,
     pub objects: *mut List,
@@ -17844,7 +18108,8 @@ impl Default for DropStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TruncateStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -17861,7 +18126,8 @@ impl Default for TruncateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommentStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -17878,7 +18144,8 @@ impl Default for CommentStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SecLabelStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -17896,7 +18163,8 @@ impl Default for SecLabelStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeclareCursorStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -17913,7 +18181,8 @@ impl Default for DeclareCursorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClosePortalStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -17933,7 +18202,8 @@ pub const FetchDirection_FETCH_ABSOLUTE: FetchDirection = 2;
 pub const FetchDirection_FETCH_RELATIVE: FetchDirection = 3;
 pub type FetchDirection = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FetchStmt {
     pub type_: NodeTag,
     pub direction: FetchDirection,
@@ -17951,7 +18221,8 @@ impl Default for FetchStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexStmt {
     pub type_: NodeTag,
     pub idxname: *mut ::std::os::raw::c_char,
@@ -17986,7 +18257,8 @@ impl Default for IndexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -18006,7 +18278,8 @@ impl Default for CreateStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFunctionStmt {
     pub type_: NodeTag,
     pub is_procedure: bool,
@@ -18032,7 +18305,8 @@ pub const FunctionParameterMode_FUNC_PARAM_VARIADIC: FunctionParameterMode = 118
 pub const FunctionParameterMode_FUNC_PARAM_TABLE: FunctionParameterMode = 116;
 pub type FunctionParameterMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionParameter {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18050,7 +18324,8 @@ impl Default for FunctionParameter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFunctionStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -18067,7 +18342,8 @@ impl Default for AlterFunctionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DoStmt {
     pub type_: NodeTag,
     pub args: *mut List,
@@ -18082,7 +18358,8 @@ impl Default for DoStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InlineCodeBlock {
     pub type_: NodeTag,
     pub source_text: *mut ::std::os::raw::c_char,
@@ -18100,7 +18377,8 @@ impl Default for InlineCodeBlock {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallStmt {
     pub type_: NodeTag,
     pub funccall: *mut FuncCall,
@@ -18116,7 +18394,8 @@ impl Default for CallStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallContext {
     pub type_: NodeTag,
     pub atomic: bool,
@@ -18131,7 +18410,8 @@ impl Default for CallContext {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RenameStmt {
     pub type_: NodeTag,
     pub renameType: ObjectType,
@@ -18153,7 +18433,8 @@ impl Default for RenameStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectDependsStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18171,7 +18452,8 @@ impl Default for AlterObjectDependsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectSchemaStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18190,7 +18472,8 @@ impl Default for AlterObjectSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOwnerStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18208,7 +18491,8 @@ impl Default for AlterOwnerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOperatorStmt {
     pub type_: NodeTag,
     pub opername: *mut ObjectWithArgs,
@@ -18224,7 +18508,8 @@ impl Default for AlterOperatorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RuleStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18245,7 +18530,8 @@ impl Default for RuleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NotifyStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -18261,7 +18547,8 @@ impl Default for NotifyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ListenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -18276,7 +18563,8 @@ impl Default for ListenStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UnlistenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -18302,7 +18590,8 @@ pub const TransactionStmtKind_TRANS_STMT_COMMIT_PREPARED: TransactionStmtKind =
 pub const TransactionStmtKind_TRANS_STMT_ROLLBACK_PREPARED: TransactionStmtKind = 9;
 pub type TransactionStmtKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TransactionStmt {
     pub type_: NodeTag,
     pub kind: TransactionStmtKind,
@@ -18320,7 +18609,8 @@ impl Default for TransactionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CompositeTypeStmt {
     pub type_: NodeTag,
     pub typevar: *mut RangeVar,
@@ -18336,7 +18626,8 @@ impl Default for CompositeTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18352,7 +18643,8 @@ impl Default for CreateEnumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRangeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18368,7 +18660,8 @@ impl Default for CreateRangeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18392,7 +18685,8 @@ pub const ViewCheckOption_LOCAL_CHECK_OPTION: ViewCheckOption = 1;
 pub const ViewCheckOption_CASCADED_CHECK_OPTION: ViewCheckOption = 2;
 pub type ViewCheckOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ViewStmt {
     pub type_: NodeTag,
     pub view: *mut RangeVar,
@@ -18412,7 +18706,8 @@ impl Default for ViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LoadStmt {
     pub type_: NodeTag,
     pub filename: *mut ::std::os::raw::c_char,
@@ -18427,7 +18722,8 @@ impl Default for LoadStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatedbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18443,7 +18739,8 @@ impl Default for CreatedbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18459,7 +18756,8 @@ impl Default for AlterDatabaseStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseSetStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18475,7 +18773,8 @@ impl Default for AlterDatabaseSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropdbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18491,7 +18790,8 @@ impl Default for DropdbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSystemStmt {
     pub type_: NodeTag,
     pub setstmt: *mut VariableSetStmt,
@@ -18506,7 +18806,8 @@ impl Default for AlterSystemStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClusterStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18532,7 +18833,8 @@ pub const VacuumOption_VACOPT_SKIPTOAST: VacuumOption = 64;
 pub const VacuumOption_VACOPT_DISABLE_PAGE_SKIPPING: VacuumOption = 128;
 pub type VacuumOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumRelation {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18549,7 +18851,8 @@ impl Default for VacuumRelation {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumStmt {
     pub type_: NodeTag,
     pub options: ::std::os::raw::c_int,
@@ -18565,7 +18868,8 @@ impl Default for VacuumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExplainStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -18581,7 +18885,8 @@ impl Default for ExplainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableAsStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -18600,7 +18905,8 @@ impl Default for CreateTableAsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RefreshMatViewStmt {
     pub type_: NodeTag,
     pub concurrent: bool,
@@ -18617,7 +18923,8 @@ impl Default for RefreshMatViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CheckPointStmt {
     pub type_: NodeTag,
 }
@@ -18636,7 +18943,8 @@ pub const DiscardMode_DISCARD_SEQUENCES: DiscardMode = 2;
 pub const DiscardMode_DISCARD_TEMP: DiscardMode = 3;
 pub type DiscardMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DiscardStmt {
     pub type_: NodeTag,
     pub target: DiscardMode,
@@ -18651,7 +18959,8 @@ impl Default for DiscardStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -18668,7 +18977,8 @@ impl Default for LockStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConstraintsSetStmt {
     pub type_: NodeTag,
     pub constraints: *mut List,
@@ -18690,7 +19000,8 @@ pub const ReindexObjectType_REINDEX_OBJECT_SYSTEM: ReindexObjectType = 3;
 pub const ReindexObjectType_REINDEX_OBJECT_DATABASE: ReindexObjectType = 4;
 pub type ReindexObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReindexStmt {
     pub type_: NodeTag,
     pub kind: ReindexObjectType,
@@ -18708,7 +19019,8 @@ impl Default for ReindexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateConversionStmt {
     pub type_: NodeTag,
     pub conversion_name: *mut List,
@@ -18727,7 +19039,8 @@ impl Default for CreateConversionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateCastStmt {
     pub type_: NodeTag,
     pub sourcetype: *mut TypeName,
@@ -18746,7 +19059,8 @@ impl Default for CreateCastStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTransformStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -18765,7 +19079,8 @@ impl Default for CreateTransformStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PrepareStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18782,7 +19097,8 @@ impl Default for PrepareStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExecuteStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18798,7 +19114,8 @@ impl Default for ExecuteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeallocateStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18813,7 +19130,8 @@ impl Default for DeallocateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -18829,7 +19147,8 @@ impl Default for DropOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReassignOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -18845,7 +19164,8 @@ impl Default for ReassignOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSDictionaryStmt {
     pub type_: NodeTag,
     pub dictname: *mut List,
@@ -18867,7 +19187,8 @@ pub const AlterTSConfigType_ALTER_TSCONFIG_REPLACE_DICT_FOR_TOKEN: AlterTSConfig
 pub const AlterTSConfigType_ALTER_TSCONFIG_DROP_MAPPING: AlterTSConfigType = 4;
 pub type AlterTSConfigType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSConfigurationStmt {
     pub type_: NodeTag,
     pub kind: AlterTSConfigType,
@@ -18888,7 +19209,8 @@ impl Default for AlterTSConfigurationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -18906,7 +19228,8 @@ impl Default for CreatePublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -18925,7 +19248,8 @@ impl Default for AlterPublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -18949,7 +19273,8 @@ pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_REFRESH: AlterSubscriptionTyp
 pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_ENABLED: AlterSubscriptionType = 4;
 pub type AlterSubscriptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSubscriptionStmt {
     pub type_: NodeTag,
     pub kind: AlterSubscriptionType,
@@ -18968,7 +19293,8 @@ impl Default for AlterSubscriptionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -19018,7 +19344,8 @@ pub const InheritanceKind_INHKIND_INHERITED: InheritanceKind = 1;
 pub const InheritanceKind_INHKIND_PARTITIONED: InheritanceKind = 2;
 pub type InheritanceKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerGlobal {
     pub type_: NodeTag,
     pub boundParams: ParamListInfo,
@@ -19052,7 +19379,8 @@ impl Default for PlannerGlobal {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerInfo {
     pub type_: NodeTag,
     pub parse: *mut Query,
@@ -19155,7 +19483,8 @@ pub const RelOptKind_RELOPT_OTHER_UPPER_REL: RelOptKind = 5;
 pub const RelOptKind_RELOPT_DEADREL: RelOptKind = 6;
 pub type RelOptKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelOptInfo {
     pub type_: NodeTag,
     pub reloptkind: RelOptKind,
@@ -19224,7 +19553,8 @@ impl Default for RelOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOptInfo {
     pub type_: NodeTag,
     pub indexoid: Oid,
@@ -19272,7 +19602,8 @@ impl Default for IndexOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyOptInfo {
     pub type_: NodeTag,
     pub con_relid: Index,
@@ -19297,7 +19628,8 @@ impl Default for ForeignKeyOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatisticExtInfo {
     pub type_: NodeTag,
     pub statOid: Oid,
@@ -19315,7 +19647,8 @@ impl Default for StatisticExtInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceClass {
     pub type_: NodeTag,
     pub ec_opfamilies: *mut List,
@@ -19343,7 +19676,8 @@ impl Default for EquivalenceClass {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceMember {
     pub type_: NodeTag,
     pub em_expr: *mut Expr,
@@ -19363,7 +19697,8 @@ impl Default for EquivalenceMember {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathKey {
     pub type_: NodeTag,
     pub pk_eclass: *mut EquivalenceClass,
@@ -19381,7 +19716,8 @@ impl Default for PathKey {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathTarget {
     pub type_: NodeTag,
     pub exprs: *mut List,
@@ -19399,7 +19735,8 @@ impl Default for PathTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamPathInfo {
     pub type_: NodeTag,
     pub ppi_req_outer: Relids,
@@ -19416,7 +19753,8 @@ impl Default for ParamPathInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Path {
     pub type_: NodeTag,
     pub pathtype: NodeTag,
@@ -19441,7 +19779,8 @@ impl Default for Path {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexPath {
     pub path: Path,
     pub indexinfo: *mut IndexOptInfo,
@@ -19464,7 +19803,8 @@ impl Default for IndexPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapPath {
     pub path: Path,
     pub bitmapqual: *mut Path,
@@ -19479,7 +19819,8 @@ impl Default for BitmapHeapPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -19495,7 +19836,8 @@ impl Default for BitmapAndPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -19511,7 +19853,8 @@ impl Default for BitmapOrPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidPath {
     pub path: Path,
     pub tidquals: *mut List,
@@ -19526,7 +19869,8 @@ impl Default for TidPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19541,7 +19885,8 @@ impl Default for SubqueryScanPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignPath {
     pub path: Path,
     pub fdw_outerpath: *mut Path,
@@ -19557,7 +19902,8 @@ impl Default for ForeignPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomPath {
     pub path: Path,
     pub flags: uint32,
@@ -19575,7 +19921,8 @@ impl Default for CustomPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendPath {
     pub path: Path,
     pub partitioned_rels: *mut List,
@@ -19596,7 +19943,8 @@ extern "C" {
     pub fn is_dummy_rel(rel: *mut RelOptInfo) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendPath {
     pub path: Path,
     pub partitioned_rels: *mut List,
@@ -19613,7 +19961,8 @@ impl Default for MergeAppendPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultPath {
     pub path: Path,
     pub quals: *mut List,
@@ -19628,7 +19977,8 @@ impl Default for ResultPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19647,7 +19997,8 @@ pub const UniquePathMethod_UNIQUE_PATH_HASH: UniquePathMethod = 1;
 pub const UniquePathMethod_UNIQUE_PATH_SORT: UniquePathMethod = 2;
 pub type UniquePathMethod = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19665,7 +20016,8 @@ impl Default for UniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19682,7 +20034,8 @@ impl Default for GatherPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19698,7 +20051,8 @@ impl Default for GatherMergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinPath {
     pub path: Path,
     pub jointype: JoinType,
@@ -19718,7 +20072,8 @@ impl Default for JoinPath {
 }
 pub type NestPath = JoinPath;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergePath {
     pub jpath: JoinPath,
     pub path_mergeclauses: *mut List,
@@ -19737,7 +20092,8 @@ impl Default for MergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashPath {
     pub jpath: JoinPath,
     pub path_hashclauses: *mut List,
@@ -19754,7 +20110,8 @@ impl Default for HashPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19770,7 +20127,8 @@ impl Default for ProjectionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19785,7 +20143,8 @@ impl Default for ProjectSetPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19800,7 +20159,8 @@ impl Default for SortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19817,7 +20177,8 @@ impl Default for GroupPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpperUniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19833,7 +20194,8 @@ impl Default for UpperUniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19853,7 +20215,8 @@ impl Default for AggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetData {
     pub type_: NodeTag,
     pub set: *mut List,
@@ -19869,7 +20232,8 @@ impl Default for GroupingSetData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RollupData {
     pub type_: NodeTag,
     pub groupClause: *mut List,
@@ -19889,7 +20253,8 @@ impl Default for RollupData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19907,7 +20272,8 @@ impl Default for GroupingSetsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggPath {
     pub path: Path,
     pub mmaggregates: *mut List,
@@ -19923,7 +20289,8 @@ impl Default for MinMaxAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19939,7 +20306,8 @@ impl Default for WindowAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19960,7 +20328,8 @@ impl Default for SetOpPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionPath {
     pub path: Path,
     pub leftpath: *mut Path,
@@ -19979,7 +20348,8 @@ impl Default for RecursiveUnionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -19996,7 +20366,8 @@ impl Default for LockRowsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTablePath {
     pub path: Path,
     pub operation: CmdType,
@@ -20023,7 +20394,8 @@ impl Default for ModifyTablePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -20040,7 +20412,8 @@ impl Default for LimitPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RestrictInfo {
     pub type_: NodeTag,
     pub clause: *mut Expr,
@@ -20105,7 +20478,8 @@ impl Default for MergeScanSelCache {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderVar {
     pub xpr: Expr,
     pub phexpr: *mut Expr,
@@ -20123,7 +20497,8 @@ impl Default for PlaceHolderVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SpecialJoinInfo {
     pub type_: NodeTag,
     pub min_lefthand: Relids,
@@ -20148,7 +20523,8 @@ impl Default for SpecialJoinInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendRelInfo {
     pub type_: NodeTag,
     pub parent_relid: Index,
@@ -20168,7 +20544,8 @@ impl Default for AppendRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderInfo {
     pub type_: NodeTag,
     pub phid: Index,
@@ -20188,7 +20565,8 @@ impl Default for PlaceHolderInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggInfo {
     pub type_: NodeTag,
     pub aggfnoid: Oid,
@@ -20209,7 +20587,8 @@ impl Default for MinMaxAggInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerParamItem {
     pub type_: NodeTag,
     pub item: *mut Node,
@@ -20557,7 +20936,8 @@ pub struct MemoryContextMethods {
     pub check: ::std::option::Option<unsafe extern "C" fn(context: MemoryContext)>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoryContextData {
     pub type_: NodeTag,
     pub isReset: bool,
@@ -25862,7 +26242,8 @@ pub type aminitparallelscan_function =
 pub type amparallelrescan_function =
     ::std::option::Option<unsafe extern "C" fn(scan: IndexScanDesc)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexAmRoutine {
     pub type_: NodeTag,
     pub amstrategies: uint16,
@@ -28538,7 +28919,8 @@ impl Default for RelationData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyCacheInfo {
     pub type_: NodeTag,
     pub conoid: Oid,
@@ -32704,7 +33086,8 @@ impl Default for CollectedCommand {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EventTriggerData {
     pub type_: NodeTag,
     pub event: *const ::std::os::raw::c_char,
@@ -33411,7 +33794,8 @@ extern "C" {
 }
 pub type TriggerEvent = uint32;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerData {
     pub type_: NodeTag,
     pub tg_event: TriggerEvent,
@@ -35446,7 +35830,8 @@ pub type ReparameterizeForeignPathByChild_function = ::std::option::Option<
     ) -> *mut List,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FdwRoutine {
     pub type_: NodeTag,
     pub GetForeignRelSize: GetForeignRelSize_function,
@@ -36285,7 +36670,8 @@ extern "C" {
     ) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExtensibleNode {
     pub type_: NodeTag,
     pub extnodename: *const ::std::os::raw::c_char,
@@ -36825,7 +37211,8 @@ pub const ReplicationKind_REPLICATION_KIND_PHYSICAL: ReplicationKind = 0;
 pub const ReplicationKind_REPLICATION_KIND_LOGICAL: ReplicationKind = 1;
 pub type ReplicationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IdentifySystemCmd {
     pub type_: NodeTag,
 }
@@ -36839,7 +37226,8 @@ impl Default for IdentifySystemCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BaseBackupCmd {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -36854,7 +37242,8 @@ impl Default for BaseBackupCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -36873,7 +37262,8 @@ impl Default for CreateReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -36889,7 +37279,8 @@ impl Default for DropReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StartReplicationCmd {
     pub type_: NodeTag,
     pub kind: ReplicationKind,
@@ -36908,7 +37299,8 @@ impl Default for StartReplicationCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TimeLineHistoryCmd {
     pub type_: NodeTag,
     pub timeline: TimeLineID,
@@ -36923,7 +37315,8 @@ impl Default for TimeLineHistoryCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLCmd {
     pub type_: NodeTag,
 }
@@ -57947,6 +58340,12 @@ impl std::fmt::Display for A_ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Const {}
 impl pg_sys::PgNode for A_Const {}
 impl std::fmt::Display for A_Const {
@@ -57954,6 +58353,12 @@ impl std::fmt::Display for A_Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Const"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Expr {}
 impl pg_sys::PgNode for A_Expr {}
 impl std::fmt::Display for A_Expr {
@@ -57961,6 +58366,12 @@ impl std::fmt::Display for A_Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indices {}
 impl pg_sys::PgNode for A_Indices {}
 impl std::fmt::Display for A_Indices {
@@ -57968,6 +58379,12 @@ impl std::fmt::Display for A_Indices {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indices"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indirection {}
 impl pg_sys::PgNode for A_Indirection {}
 impl std::fmt::Display for A_Indirection {
@@ -57975,6 +58392,12 @@ impl std::fmt::Display for A_Indirection {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indirection"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Star {}
 impl pg_sys::PgNode for A_Star {}
 impl std::fmt::Display for A_Star {
@@ -57982,6 +58405,12 @@ impl std::fmt::Display for A_Star {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Star {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Star"))
+    }
+}
 impl pg_sys::seal::Sealed for AccessPriv {}
 impl pg_sys::PgNode for AccessPriv {}
 impl std::fmt::Display for AccessPriv {
@@ -57989,6 +58418,12 @@ impl std::fmt::Display for AccessPriv {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AccessPriv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AccessPriv"))
+    }
+}
 impl pg_sys::seal::Sealed for Agg {}
 impl pg_sys::PgNode for Agg {}
 impl std::fmt::Display for Agg {
@@ -57996,6 +58431,12 @@ impl std::fmt::Display for Agg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Agg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Agg"))
+    }
+}
 impl pg_sys::seal::Sealed for AggPath {}
 impl pg_sys::PgNode for AggPath {}
 impl std::fmt::Display for AggPath {
@@ -58003,6 +58444,12 @@ impl std::fmt::Display for AggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AggState {}
 impl pg_sys::PgNode for AggState {}
 impl std::fmt::Display for AggState {
@@ -58010,6 +58457,12 @@ impl std::fmt::Display for AggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggState"))
+    }
+}
 impl pg_sys::seal::Sealed for Aggref {}
 impl pg_sys::PgNode for Aggref {}
 impl std::fmt::Display for Aggref {
@@ -58017,6 +58470,12 @@ impl std::fmt::Display for Aggref {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Aggref {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Aggref"))
+    }
+}
 impl pg_sys::seal::Sealed for AggrefExprState {}
 impl pg_sys::PgNode for AggrefExprState {}
 impl std::fmt::Display for AggrefExprState {
@@ -58024,6 +58483,12 @@ impl std::fmt::Display for AggrefExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggrefExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggrefExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for Alias {}
 impl pg_sys::PgNode for Alias {}
 impl std::fmt::Display for Alias {
@@ -58031,6 +58496,12 @@ impl std::fmt::Display for Alias {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Alias"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterCollationStmt {}
 impl pg_sys::PgNode for AlterCollationStmt {}
 impl std::fmt::Display for AlterCollationStmt {
@@ -58038,6 +58509,12 @@ impl std::fmt::Display for AlterCollationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterCollationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterCollationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseSetStmt {}
 impl pg_sys::PgNode for AlterDatabaseSetStmt {}
 impl std::fmt::Display for AlterDatabaseSetStmt {
@@ -58045,6 +58522,12 @@ impl std::fmt::Display for AlterDatabaseSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseStmt {}
 impl pg_sys::PgNode for AlterDatabaseStmt {}
 impl std::fmt::Display for AlterDatabaseStmt {
@@ -58052,6 +58535,12 @@ impl std::fmt::Display for AlterDatabaseStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDefaultPrivilegesStmt {}
 impl pg_sys::PgNode for AlterDefaultPrivilegesStmt {}
 impl std::fmt::Display for AlterDefaultPrivilegesStmt {
@@ -58059,6 +58548,12 @@ impl std::fmt::Display for AlterDefaultPrivilegesStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDefaultPrivilegesStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDefaultPrivilegesStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDomainStmt {}
 impl pg_sys::PgNode for AlterDomainStmt {}
 impl std::fmt::Display for AlterDomainStmt {
@@ -58066,6 +58561,12 @@ impl std::fmt::Display for AlterDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEnumStmt {}
 impl pg_sys::PgNode for AlterEnumStmt {}
 impl std::fmt::Display for AlterEnumStmt {
@@ -58073,6 +58574,12 @@ impl std::fmt::Display for AlterEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEventTrigStmt {}
 impl pg_sys::PgNode for AlterEventTrigStmt {}
 impl std::fmt::Display for AlterEventTrigStmt {
@@ -58080,6 +58587,12 @@ impl std::fmt::Display for AlterEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionContentsStmt {}
 impl pg_sys::PgNode for AlterExtensionContentsStmt {}
 impl std::fmt::Display for AlterExtensionContentsStmt {
@@ -58087,6 +58600,12 @@ impl std::fmt::Display for AlterExtensionContentsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionContentsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionContentsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionStmt {}
 impl pg_sys::PgNode for AlterExtensionStmt {}
 impl std::fmt::Display for AlterExtensionStmt {
@@ -58094,6 +58613,12 @@ impl std::fmt::Display for AlterExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFdwStmt {}
 impl pg_sys::PgNode for AlterFdwStmt {}
 impl std::fmt::Display for AlterFdwStmt {
@@ -58101,6 +58626,12 @@ impl std::fmt::Display for AlterFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterForeignServerStmt {}
 impl pg_sys::PgNode for AlterForeignServerStmt {}
 impl std::fmt::Display for AlterForeignServerStmt {
@@ -58108,6 +58639,12 @@ impl std::fmt::Display for AlterForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFunctionStmt {}
 impl pg_sys::PgNode for AlterFunctionStmt {}
 impl std::fmt::Display for AlterFunctionStmt {
@@ -58115,6 +58652,12 @@ impl std::fmt::Display for AlterFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectDependsStmt {}
 impl pg_sys::PgNode for AlterObjectDependsStmt {}
 impl std::fmt::Display for AlterObjectDependsStmt {
@@ -58122,6 +58665,12 @@ impl std::fmt::Display for AlterObjectDependsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectDependsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectDependsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectSchemaStmt {}
 impl pg_sys::PgNode for AlterObjectSchemaStmt {}
 impl std::fmt::Display for AlterObjectSchemaStmt {
@@ -58129,6 +58678,12 @@ impl std::fmt::Display for AlterObjectSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOpFamilyStmt {}
 impl pg_sys::PgNode for AlterOpFamilyStmt {}
 impl std::fmt::Display for AlterOpFamilyStmt {
@@ -58136,6 +58691,12 @@ impl std::fmt::Display for AlterOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOperatorStmt {}
 impl pg_sys::PgNode for AlterOperatorStmt {}
 impl std::fmt::Display for AlterOperatorStmt {
@@ -58143,6 +58704,12 @@ impl std::fmt::Display for AlterOperatorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOperatorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOperatorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOwnerStmt {}
 impl pg_sys::PgNode for AlterOwnerStmt {}
 impl std::fmt::Display for AlterOwnerStmt {
@@ -58150,6 +58717,12 @@ impl std::fmt::Display for AlterOwnerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOwnerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOwnerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPolicyStmt {}
 impl pg_sys::PgNode for AlterPolicyStmt {}
 impl std::fmt::Display for AlterPolicyStmt {
@@ -58157,6 +58730,12 @@ impl std::fmt::Display for AlterPolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPublicationStmt {}
 impl pg_sys::PgNode for AlterPublicationStmt {}
 impl std::fmt::Display for AlterPublicationStmt {
@@ -58164,6 +58743,12 @@ impl std::fmt::Display for AlterPublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleSetStmt {}
 impl pg_sys::PgNode for AlterRoleSetStmt {}
 impl std::fmt::Display for AlterRoleSetStmt {
@@ -58171,6 +58756,12 @@ impl std::fmt::Display for AlterRoleSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleStmt {}
 impl pg_sys::PgNode for AlterRoleStmt {}
 impl std::fmt::Display for AlterRoleStmt {
@@ -58178,6 +58769,12 @@ impl std::fmt::Display for AlterRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSeqStmt {}
 impl pg_sys::PgNode for AlterSeqStmt {}
 impl std::fmt::Display for AlterSeqStmt {
@@ -58185,6 +58782,12 @@ impl std::fmt::Display for AlterSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSubscriptionStmt {}
 impl pg_sys::PgNode for AlterSubscriptionStmt {}
 impl std::fmt::Display for AlterSubscriptionStmt {
@@ -58192,6 +58795,12 @@ impl std::fmt::Display for AlterSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSystemStmt {}
 impl pg_sys::PgNode for AlterSystemStmt {}
 impl std::fmt::Display for AlterSystemStmt {
@@ -58199,6 +58808,12 @@ impl std::fmt::Display for AlterSystemStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSystemStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSystemStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSConfigurationStmt {}
 impl pg_sys::PgNode for AlterTSConfigurationStmt {}
 impl std::fmt::Display for AlterTSConfigurationStmt {
@@ -58206,6 +58821,12 @@ impl std::fmt::Display for AlterTSConfigurationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSConfigurationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSConfigurationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSDictionaryStmt {}
 impl pg_sys::PgNode for AlterTSDictionaryStmt {}
 impl std::fmt::Display for AlterTSDictionaryStmt {
@@ -58213,6 +58834,12 @@ impl std::fmt::Display for AlterTSDictionaryStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSDictionaryStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSDictionaryStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableCmd {}
 impl pg_sys::PgNode for AlterTableCmd {}
 impl std::fmt::Display for AlterTableCmd {
@@ -58220,6 +58847,12 @@ impl std::fmt::Display for AlterTableCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableMoveAllStmt {}
 impl pg_sys::PgNode for AlterTableMoveAllStmt {}
 impl std::fmt::Display for AlterTableMoveAllStmt {
@@ -58227,6 +58860,12 @@ impl std::fmt::Display for AlterTableMoveAllStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableMoveAllStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableMoveAllStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableSpaceOptionsStmt {}
 impl pg_sys::PgNode for AlterTableSpaceOptionsStmt {}
 impl std::fmt::Display for AlterTableSpaceOptionsStmt {
@@ -58234,6 +58873,12 @@ impl std::fmt::Display for AlterTableSpaceOptionsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableSpaceOptionsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableSpaceOptionsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableStmt {}
 impl pg_sys::PgNode for AlterTableStmt {}
 impl std::fmt::Display for AlterTableStmt {
@@ -58241,6 +58886,12 @@ impl std::fmt::Display for AlterTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterUserMappingStmt {}
 impl pg_sys::PgNode for AlterUserMappingStmt {}
 impl std::fmt::Display for AlterUserMappingStmt {
@@ -58248,6 +58899,12 @@ impl std::fmt::Display for AlterUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlan {}
 impl pg_sys::PgNode for AlternativeSubPlan {}
 impl std::fmt::Display for AlternativeSubPlan {
@@ -58255,6 +58912,12 @@ impl std::fmt::Display for AlternativeSubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlanState {}
 impl pg_sys::PgNode for AlternativeSubPlanState {}
 impl std::fmt::Display for AlternativeSubPlanState {
@@ -58262,6 +58925,12 @@ impl std::fmt::Display for AlternativeSubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Append {}
 impl pg_sys::PgNode for Append {}
 impl std::fmt::Display for Append {
@@ -58269,6 +58938,12 @@ impl std::fmt::Display for Append {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Append {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Append"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendPath {}
 impl pg_sys::PgNode for AppendPath {}
 impl std::fmt::Display for AppendPath {
@@ -58276,6 +58951,12 @@ impl std::fmt::Display for AppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendRelInfo {}
 impl pg_sys::PgNode for AppendRelInfo {}
 impl std::fmt::Display for AppendRelInfo {
@@ -58283,6 +58964,12 @@ impl std::fmt::Display for AppendRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendState {}
 impl pg_sys::PgNode for AppendState {}
 impl std::fmt::Display for AppendState {
@@ -58290,6 +58977,12 @@ impl std::fmt::Display for AppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayCoerceExpr {}
 impl pg_sys::PgNode for ArrayCoerceExpr {}
 impl std::fmt::Display for ArrayCoerceExpr {
@@ -58297,6 +58990,12 @@ impl std::fmt::Display for ArrayCoerceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayCoerceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayCoerceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayExpr {}
 impl pg_sys::PgNode for ArrayExpr {}
 impl std::fmt::Display for ArrayExpr {
@@ -58304,6 +59003,12 @@ impl std::fmt::Display for ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayRef {}
 impl pg_sys::PgNode for ArrayRef {}
 impl std::fmt::Display for ArrayRef {
@@ -58311,6 +59016,12 @@ impl std::fmt::Display for ArrayRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayRef"))
+    }
+}
 impl pg_sys::seal::Sealed for BaseBackupCmd {}
 impl pg_sys::PgNode for BaseBackupCmd {}
 impl std::fmt::Display for BaseBackupCmd {
@@ -58318,6 +59029,12 @@ impl std::fmt::Display for BaseBackupCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BaseBackupCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BaseBackupCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAnd {}
 impl pg_sys::PgNode for BitmapAnd {}
 impl std::fmt::Display for BitmapAnd {
@@ -58325,6 +59042,12 @@ impl std::fmt::Display for BitmapAnd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAnd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndPath {}
 impl pg_sys::PgNode for BitmapAndPath {}
 impl std::fmt::Display for BitmapAndPath {
@@ -58332,6 +59055,12 @@ impl std::fmt::Display for BitmapAndPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndState {}
 impl pg_sys::PgNode for BitmapAndState {}
 impl std::fmt::Display for BitmapAndState {
@@ -58339,6 +59068,12 @@ impl std::fmt::Display for BitmapAndState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapPath {}
 impl pg_sys::PgNode for BitmapHeapPath {}
 impl std::fmt::Display for BitmapHeapPath {
@@ -58346,6 +59081,12 @@ impl std::fmt::Display for BitmapHeapPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScan {}
 impl pg_sys::PgNode for BitmapHeapScan {}
 impl std::fmt::Display for BitmapHeapScan {
@@ -58353,6 +59094,12 @@ impl std::fmt::Display for BitmapHeapScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScanState {}
 impl pg_sys::PgNode for BitmapHeapScanState {}
 impl std::fmt::Display for BitmapHeapScanState {
@@ -58360,6 +59107,12 @@ impl std::fmt::Display for BitmapHeapScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScan {}
 impl pg_sys::PgNode for BitmapIndexScan {}
 impl std::fmt::Display for BitmapIndexScan {
@@ -58367,6 +59120,12 @@ impl std::fmt::Display for BitmapIndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScanState {}
 impl pg_sys::PgNode for BitmapIndexScanState {}
 impl std::fmt::Display for BitmapIndexScanState {
@@ -58374,6 +59133,12 @@ impl std::fmt::Display for BitmapIndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOr {}
 impl pg_sys::PgNode for BitmapOr {}
 impl std::fmt::Display for BitmapOr {
@@ -58381,6 +59146,12 @@ impl std::fmt::Display for BitmapOr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOr"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrPath {}
 impl pg_sys::PgNode for BitmapOrPath {}
 impl std::fmt::Display for BitmapOrPath {
@@ -58388,6 +59159,12 @@ impl std::fmt::Display for BitmapOrPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrState {}
 impl pg_sys::PgNode for BitmapOrState {}
 impl std::fmt::Display for BitmapOrState {
@@ -58395,6 +59172,12 @@ impl std::fmt::Display for BitmapOrState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrState"))
+    }
+}
 impl pg_sys::seal::Sealed for BoolExpr {}
 impl pg_sys::PgNode for BoolExpr {}
 impl std::fmt::Display for BoolExpr {
@@ -58402,6 +59185,12 @@ impl std::fmt::Display for BoolExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BoolExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BoolExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BooleanTest {}
 impl pg_sys::PgNode for BooleanTest {}
 impl std::fmt::Display for BooleanTest {
@@ -58409,6 +59198,12 @@ impl std::fmt::Display for BooleanTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BooleanTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BooleanTest"))
+    }
+}
 impl pg_sys::seal::Sealed for CallContext {}
 impl pg_sys::PgNode for CallContext {}
 impl std::fmt::Display for CallContext {
@@ -58416,6 +59211,12 @@ impl std::fmt::Display for CallContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallContext"))
+    }
+}
 impl pg_sys::seal::Sealed for CallStmt {}
 impl pg_sys::PgNode for CallStmt {}
 impl std::fmt::Display for CallStmt {
@@ -58423,6 +59224,12 @@ impl std::fmt::Display for CallStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseExpr {}
 impl pg_sys::PgNode for CaseExpr {}
 impl std::fmt::Display for CaseExpr {
@@ -58430,6 +59237,12 @@ impl std::fmt::Display for CaseExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseTestExpr {}
 impl pg_sys::PgNode for CaseTestExpr {}
 impl std::fmt::Display for CaseTestExpr {
@@ -58437,6 +59250,12 @@ impl std::fmt::Display for CaseTestExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseTestExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseTestExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseWhen {}
 impl pg_sys::PgNode for CaseWhen {}
 impl std::fmt::Display for CaseWhen {
@@ -58444,6 +59263,12 @@ impl std::fmt::Display for CaseWhen {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseWhen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseWhen"))
+    }
+}
 impl pg_sys::seal::Sealed for CheckPointStmt {}
 impl pg_sys::PgNode for CheckPointStmt {}
 impl std::fmt::Display for CheckPointStmt {
@@ -58451,6 +59276,12 @@ impl std::fmt::Display for CheckPointStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CheckPointStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CheckPointStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClosePortalStmt {}
 impl pg_sys::PgNode for ClosePortalStmt {}
 impl std::fmt::Display for ClosePortalStmt {
@@ -58458,6 +59289,12 @@ impl std::fmt::Display for ClosePortalStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClosePortalStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClosePortalStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClusterStmt {}
 impl pg_sys::PgNode for ClusterStmt {}
 impl std::fmt::Display for ClusterStmt {
@@ -58465,6 +59302,12 @@ impl std::fmt::Display for ClusterStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClusterStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClusterStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CoalesceExpr {}
 impl pg_sys::PgNode for CoalesceExpr {}
 impl std::fmt::Display for CoalesceExpr {
@@ -58472,6 +59315,12 @@ impl std::fmt::Display for CoalesceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoalesceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoalesceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomain {}
 impl pg_sys::PgNode for CoerceToDomain {}
 impl std::fmt::Display for CoerceToDomain {
@@ -58479,6 +59328,12 @@ impl std::fmt::Display for CoerceToDomain {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomain"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomainValue {}
 impl pg_sys::PgNode for CoerceToDomainValue {}
 impl std::fmt::Display for CoerceToDomainValue {
@@ -58486,6 +59341,12 @@ impl std::fmt::Display for CoerceToDomainValue {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomainValue"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceViaIO {}
 impl pg_sys::PgNode for CoerceViaIO {}
 impl std::fmt::Display for CoerceViaIO {
@@ -58493,6 +59354,12 @@ impl std::fmt::Display for CoerceViaIO {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceViaIO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceViaIO"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateClause {}
 impl pg_sys::PgNode for CollateClause {}
 impl std::fmt::Display for CollateClause {
@@ -58500,6 +59367,12 @@ impl std::fmt::Display for CollateClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateExpr {}
 impl pg_sys::PgNode for CollateExpr {}
 impl std::fmt::Display for CollateExpr {
@@ -58507,6 +59380,12 @@ impl std::fmt::Display for CollateExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnDef {}
 impl pg_sys::PgNode for ColumnDef {}
 impl std::fmt::Display for ColumnDef {
@@ -58514,6 +59393,12 @@ impl std::fmt::Display for ColumnDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnDef"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnRef {}
 impl pg_sys::PgNode for ColumnRef {}
 impl std::fmt::Display for ColumnRef {
@@ -58521,6 +59406,12 @@ impl std::fmt::Display for ColumnRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnRef"))
+    }
+}
 impl pg_sys::seal::Sealed for CommentStmt {}
 impl pg_sys::PgNode for CommentStmt {}
 impl std::fmt::Display for CommentStmt {
@@ -58528,6 +59419,12 @@ impl std::fmt::Display for CommentStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommentStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommentStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CommonTableExpr {}
 impl pg_sys::PgNode for CommonTableExpr {}
 impl std::fmt::Display for CommonTableExpr {
@@ -58535,6 +59432,12 @@ impl std::fmt::Display for CommonTableExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommonTableExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommonTableExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CompositeTypeStmt {}
 impl pg_sys::PgNode for CompositeTypeStmt {}
 impl std::fmt::Display for CompositeTypeStmt {
@@ -58542,6 +59445,12 @@ impl std::fmt::Display for CompositeTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CompositeTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CompositeTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Const {}
 impl pg_sys::PgNode for Const {}
 impl std::fmt::Display for Const {
@@ -58549,6 +59458,12 @@ impl std::fmt::Display for Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Const"))
+    }
+}
 impl pg_sys::seal::Sealed for Constraint {}
 impl pg_sys::PgNode for Constraint {}
 impl std::fmt::Display for Constraint {
@@ -58556,6 +59471,12 @@ impl std::fmt::Display for Constraint {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Constraint"))
+    }
+}
 impl pg_sys::seal::Sealed for ConstraintsSetStmt {}
 impl pg_sys::PgNode for ConstraintsSetStmt {}
 impl std::fmt::Display for ConstraintsSetStmt {
@@ -58563,6 +59484,12 @@ impl std::fmt::Display for ConstraintsSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConstraintsSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConstraintsSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ConvertRowtypeExpr {}
 impl pg_sys::PgNode for ConvertRowtypeExpr {}
 impl std::fmt::Display for ConvertRowtypeExpr {
@@ -58570,13 +59497,25 @@ impl std::fmt::Display for ConvertRowtypeExpr {
         write!(f, "{}", self.display_node())
     }
 }
-impl pg_sys::seal::Sealed for CopyStmt {}
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConvertRowtypeExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConvertRowtypeExpr"))
+    }
+}
+impl pg_sys::seal::Sealed for CopyStmt {}
 impl pg_sys::PgNode for CopyStmt {}
 impl std::fmt::Display for CopyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CopyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CopyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateAmStmt {}
 impl pg_sys::PgNode for CreateAmStmt {}
 impl std::fmt::Display for CreateAmStmt {
@@ -58584,6 +59523,12 @@ impl std::fmt::Display for CreateAmStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateAmStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateAmStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateCastStmt {}
 impl pg_sys::PgNode for CreateCastStmt {}
 impl std::fmt::Display for CreateCastStmt {
@@ -58591,6 +59536,12 @@ impl std::fmt::Display for CreateCastStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateCastStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateCastStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateConversionStmt {}
 impl pg_sys::PgNode for CreateConversionStmt {}
 impl std::fmt::Display for CreateConversionStmt {
@@ -58598,6 +59549,12 @@ impl std::fmt::Display for CreateConversionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateConversionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateConversionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateDomainStmt {}
 impl pg_sys::PgNode for CreateDomainStmt {}
 impl std::fmt::Display for CreateDomainStmt {
@@ -58605,6 +59562,12 @@ impl std::fmt::Display for CreateDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEnumStmt {}
 impl pg_sys::PgNode for CreateEnumStmt {}
 impl std::fmt::Display for CreateEnumStmt {
@@ -58612,6 +59575,12 @@ impl std::fmt::Display for CreateEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEventTrigStmt {}
 impl pg_sys::PgNode for CreateEventTrigStmt {}
 impl std::fmt::Display for CreateEventTrigStmt {
@@ -58619,6 +59588,12 @@ impl std::fmt::Display for CreateEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateExtensionStmt {}
 impl pg_sys::PgNode for CreateExtensionStmt {}
 impl std::fmt::Display for CreateExtensionStmt {
@@ -58626,6 +59601,12 @@ impl std::fmt::Display for CreateExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFdwStmt {}
 impl pg_sys::PgNode for CreateFdwStmt {}
 impl std::fmt::Display for CreateFdwStmt {
@@ -58633,6 +59614,12 @@ impl std::fmt::Display for CreateFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignServerStmt {}
 impl pg_sys::PgNode for CreateForeignServerStmt {}
 impl std::fmt::Display for CreateForeignServerStmt {
@@ -58640,6 +59627,12 @@ impl std::fmt::Display for CreateForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignTableStmt {}
 impl pg_sys::PgNode for CreateForeignTableStmt {}
 impl std::fmt::Display for CreateForeignTableStmt {
@@ -58647,6 +59640,12 @@ impl std::fmt::Display for CreateForeignTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFunctionStmt {}
 impl pg_sys::PgNode for CreateFunctionStmt {}
 impl std::fmt::Display for CreateFunctionStmt {
@@ -58654,6 +59653,12 @@ impl std::fmt::Display for CreateFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassItem {}
 impl pg_sys::PgNode for CreateOpClassItem {}
 impl std::fmt::Display for CreateOpClassItem {
@@ -58661,6 +59666,12 @@ impl std::fmt::Display for CreateOpClassItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassItem"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassStmt {}
 impl pg_sys::PgNode for CreateOpClassStmt {}
 impl std::fmt::Display for CreateOpClassStmt {
@@ -58668,6 +59679,12 @@ impl std::fmt::Display for CreateOpClassStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpFamilyStmt {}
 impl pg_sys::PgNode for CreateOpFamilyStmt {}
 impl std::fmt::Display for CreateOpFamilyStmt {
@@ -58675,6 +59692,12 @@ impl std::fmt::Display for CreateOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePLangStmt {}
 impl pg_sys::PgNode for CreatePLangStmt {}
 impl std::fmt::Display for CreatePLangStmt {
@@ -58682,6 +59705,12 @@ impl std::fmt::Display for CreatePLangStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePLangStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePLangStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePolicyStmt {}
 impl pg_sys::PgNode for CreatePolicyStmt {}
 impl std::fmt::Display for CreatePolicyStmt {
@@ -58689,6 +59718,12 @@ impl std::fmt::Display for CreatePolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePublicationStmt {}
 impl pg_sys::PgNode for CreatePublicationStmt {}
 impl std::fmt::Display for CreatePublicationStmt {
@@ -58696,6 +59731,12 @@ impl std::fmt::Display for CreatePublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRangeStmt {}
 impl pg_sys::PgNode for CreateRangeStmt {}
 impl std::fmt::Display for CreateRangeStmt {
@@ -58703,6 +59744,12 @@ impl std::fmt::Display for CreateRangeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRangeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRangeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateReplicationSlotCmd {}
 impl pg_sys::PgNode for CreateReplicationSlotCmd {}
 impl std::fmt::Display for CreateReplicationSlotCmd {
@@ -58710,6 +59757,12 @@ impl std::fmt::Display for CreateReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRoleStmt {}
 impl pg_sys::PgNode for CreateRoleStmt {}
 impl std::fmt::Display for CreateRoleStmt {
@@ -58717,6 +59770,12 @@ impl std::fmt::Display for CreateRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSchemaStmt {}
 impl pg_sys::PgNode for CreateSchemaStmt {}
 impl std::fmt::Display for CreateSchemaStmt {
@@ -58724,6 +59783,12 @@ impl std::fmt::Display for CreateSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSeqStmt {}
 impl pg_sys::PgNode for CreateSeqStmt {}
 impl std::fmt::Display for CreateSeqStmt {
@@ -58731,6 +59796,12 @@ impl std::fmt::Display for CreateSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStatsStmt {}
 impl pg_sys::PgNode for CreateStatsStmt {}
 impl std::fmt::Display for CreateStatsStmt {
@@ -58738,6 +59809,12 @@ impl std::fmt::Display for CreateStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStmt {}
 impl pg_sys::PgNode for CreateStmt {}
 impl std::fmt::Display for CreateStmt {
@@ -58745,6 +59822,12 @@ impl std::fmt::Display for CreateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSubscriptionStmt {}
 impl pg_sys::PgNode for CreateSubscriptionStmt {}
 impl std::fmt::Display for CreateSubscriptionStmt {
@@ -58752,6 +59835,12 @@ impl std::fmt::Display for CreateSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableAsStmt {}
 impl pg_sys::PgNode for CreateTableAsStmt {}
 impl std::fmt::Display for CreateTableAsStmt {
@@ -58759,6 +59848,12 @@ impl std::fmt::Display for CreateTableAsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableAsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableAsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableSpaceStmt {}
 impl pg_sys::PgNode for CreateTableSpaceStmt {}
 impl std::fmt::Display for CreateTableSpaceStmt {
@@ -58766,6 +59861,12 @@ impl std::fmt::Display for CreateTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTransformStmt {}
 impl pg_sys::PgNode for CreateTransformStmt {}
 impl std::fmt::Display for CreateTransformStmt {
@@ -58773,6 +59874,12 @@ impl std::fmt::Display for CreateTransformStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTransformStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTransformStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTrigStmt {}
 impl pg_sys::PgNode for CreateTrigStmt {}
 impl std::fmt::Display for CreateTrigStmt {
@@ -58780,6 +59887,12 @@ impl std::fmt::Display for CreateTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateUserMappingStmt {}
 impl pg_sys::PgNode for CreateUserMappingStmt {}
 impl std::fmt::Display for CreateUserMappingStmt {
@@ -58787,6 +59900,12 @@ impl std::fmt::Display for CreateUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatedbStmt {}
 impl pg_sys::PgNode for CreatedbStmt {}
 impl std::fmt::Display for CreatedbStmt {
@@ -58794,6 +59913,12 @@ impl std::fmt::Display for CreatedbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatedbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatedbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScan {}
 impl pg_sys::PgNode for CteScan {}
 impl std::fmt::Display for CteScan {
@@ -58801,6 +59926,12 @@ impl std::fmt::Display for CteScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScanState {}
 impl pg_sys::PgNode for CteScanState {}
 impl std::fmt::Display for CteScanState {
@@ -58808,6 +59939,12 @@ impl std::fmt::Display for CteScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for CurrentOfExpr {}
 impl pg_sys::PgNode for CurrentOfExpr {}
 impl std::fmt::Display for CurrentOfExpr {
@@ -58815,6 +59952,12 @@ impl std::fmt::Display for CurrentOfExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CurrentOfExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CurrentOfExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomPath {}
 impl pg_sys::PgNode for CustomPath {}
 impl std::fmt::Display for CustomPath {
@@ -58822,6 +59965,12 @@ impl std::fmt::Display for CustomPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomPath"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScan {}
 impl pg_sys::PgNode for CustomScan {}
 impl std::fmt::Display for CustomScan {
@@ -58829,6 +59978,12 @@ impl std::fmt::Display for CustomScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScanState {}
 impl pg_sys::PgNode for CustomScanState {}
 impl std::fmt::Display for CustomScanState {
@@ -58836,6 +59991,12 @@ impl std::fmt::Display for CustomScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for DeallocateStmt {}
 impl pg_sys::PgNode for DeallocateStmt {}
 impl std::fmt::Display for DeallocateStmt {
@@ -58843,6 +60004,12 @@ impl std::fmt::Display for DeallocateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeallocateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeallocateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeclareCursorStmt {}
 impl pg_sys::PgNode for DeclareCursorStmt {}
 impl std::fmt::Display for DeclareCursorStmt {
@@ -58850,6 +60017,12 @@ impl std::fmt::Display for DeclareCursorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeclareCursorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeclareCursorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DefElem {}
 impl pg_sys::PgNode for DefElem {}
 impl std::fmt::Display for DefElem {
@@ -58857,6 +60030,12 @@ impl std::fmt::Display for DefElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefElem"))
+    }
+}
 impl pg_sys::seal::Sealed for DefineStmt {}
 impl pg_sys::PgNode for DefineStmt {}
 impl std::fmt::Display for DefineStmt {
@@ -58864,6 +60043,12 @@ impl std::fmt::Display for DefineStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefineStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefineStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeleteStmt {}
 impl pg_sys::PgNode for DeleteStmt {}
 impl std::fmt::Display for DeleteStmt {
@@ -58871,6 +60056,12 @@ impl std::fmt::Display for DeleteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeleteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeleteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DiscardStmt {}
 impl pg_sys::PgNode for DiscardStmt {}
 impl std::fmt::Display for DiscardStmt {
@@ -58878,6 +60069,12 @@ impl std::fmt::Display for DiscardStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DiscardStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DiscardStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DoStmt {}
 impl pg_sys::PgNode for DoStmt {}
 impl std::fmt::Display for DoStmt {
@@ -58885,6 +60082,12 @@ impl std::fmt::Display for DoStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DoStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DoStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DomainConstraintState {}
 impl pg_sys::PgNode for DomainConstraintState {}
 impl std::fmt::Display for DomainConstraintState {
@@ -58892,6 +60095,12 @@ impl std::fmt::Display for DomainConstraintState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DomainConstraintState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DomainConstraintState"))
+    }
+}
 impl pg_sys::seal::Sealed for DropOwnedStmt {}
 impl pg_sys::PgNode for DropOwnedStmt {}
 impl std::fmt::Display for DropOwnedStmt {
@@ -58899,6 +60108,12 @@ impl std::fmt::Display for DropOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropReplicationSlotCmd {}
 impl pg_sys::PgNode for DropReplicationSlotCmd {}
 impl std::fmt::Display for DropReplicationSlotCmd {
@@ -58906,6 +60121,12 @@ impl std::fmt::Display for DropReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for DropRoleStmt {}
 impl pg_sys::PgNode for DropRoleStmt {}
 impl std::fmt::Display for DropRoleStmt {
@@ -58913,6 +60134,12 @@ impl std::fmt::Display for DropRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropStmt {}
 impl pg_sys::PgNode for DropStmt {}
 impl std::fmt::Display for DropStmt {
@@ -58920,6 +60147,12 @@ impl std::fmt::Display for DropStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropSubscriptionStmt {}
 impl pg_sys::PgNode for DropSubscriptionStmt {}
 impl std::fmt::Display for DropSubscriptionStmt {
@@ -58927,6 +60160,12 @@ impl std::fmt::Display for DropSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropTableSpaceStmt {}
 impl pg_sys::PgNode for DropTableSpaceStmt {}
 impl std::fmt::Display for DropTableSpaceStmt {
@@ -58934,6 +60173,12 @@ impl std::fmt::Display for DropTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropUserMappingStmt {}
 impl pg_sys::PgNode for DropUserMappingStmt {}
 impl std::fmt::Display for DropUserMappingStmt {
@@ -58941,6 +60186,12 @@ impl std::fmt::Display for DropUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropdbStmt {}
 impl pg_sys::PgNode for DropdbStmt {}
 impl std::fmt::Display for DropdbStmt {
@@ -58948,6 +60199,12 @@ impl std::fmt::Display for DropdbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropdbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropdbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for EState {}
 impl pg_sys::PgNode for EState {}
 impl std::fmt::Display for EState {
@@ -58955,6 +60212,12 @@ impl std::fmt::Display for EState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EState"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceClass {}
 impl pg_sys::PgNode for EquivalenceClass {}
 impl std::fmt::Display for EquivalenceClass {
@@ -58962,6 +60225,12 @@ impl std::fmt::Display for EquivalenceClass {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceClass"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceMember {}
 impl pg_sys::PgNode for EquivalenceMember {}
 impl std::fmt::Display for EquivalenceMember {
@@ -58969,6 +60238,12 @@ impl std::fmt::Display for EquivalenceMember {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceMember"))
+    }
+}
 impl pg_sys::seal::Sealed for EventTriggerData {}
 impl pg_sys::PgNode for EventTriggerData {}
 impl std::fmt::Display for EventTriggerData {
@@ -58976,6 +60251,12 @@ impl std::fmt::Display for EventTriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EventTriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EventTriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for ExecuteStmt {}
 impl pg_sys::PgNode for ExecuteStmt {}
 impl std::fmt::Display for ExecuteStmt {
@@ -58983,6 +60264,12 @@ impl std::fmt::Display for ExecuteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExecuteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExecuteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ExplainStmt {}
 impl pg_sys::PgNode for ExplainStmt {}
 impl std::fmt::Display for ExplainStmt {
@@ -58990,6 +60277,12 @@ impl std::fmt::Display for ExplainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExplainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExplainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Expr {}
 impl pg_sys::PgNode for Expr {}
 impl std::fmt::Display for Expr {
@@ -58997,6 +60290,12 @@ impl std::fmt::Display for Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprContext {}
 impl pg_sys::PgNode for ExprContext {}
 impl std::fmt::Display for ExprContext {
@@ -59004,6 +60303,12 @@ impl std::fmt::Display for ExprContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprContext"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprState {}
 impl pg_sys::PgNode for ExprState {}
 impl std::fmt::Display for ExprState {
@@ -59011,6 +60316,12 @@ impl std::fmt::Display for ExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for ExtensibleNode {}
 impl pg_sys::PgNode for ExtensibleNode {}
 impl std::fmt::Display for ExtensibleNode {
@@ -59018,6 +60329,12 @@ impl std::fmt::Display for ExtensibleNode {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExtensibleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExtensibleNode"))
+    }
+}
 impl pg_sys::seal::Sealed for FdwRoutine {}
 impl pg_sys::PgNode for FdwRoutine {}
 impl std::fmt::Display for FdwRoutine {
@@ -59025,6 +60342,12 @@ impl std::fmt::Display for FdwRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FdwRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FdwRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for FetchStmt {}
 impl pg_sys::PgNode for FetchStmt {}
 impl std::fmt::Display for FetchStmt {
@@ -59032,6 +60355,12 @@ impl std::fmt::Display for FetchStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FetchStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FetchStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldSelect {}
 impl pg_sys::PgNode for FieldSelect {}
 impl std::fmt::Display for FieldSelect {
@@ -59039,6 +60368,12 @@ impl std::fmt::Display for FieldSelect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldSelect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldSelect"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldStore {}
 impl pg_sys::PgNode for FieldStore {}
 impl std::fmt::Display for FieldStore {
@@ -59046,6 +60381,12 @@ impl std::fmt::Display for FieldStore {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldStore"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyCacheInfo {}
 impl pg_sys::PgNode for ForeignKeyCacheInfo {}
 impl std::fmt::Display for ForeignKeyCacheInfo {
@@ -59053,6 +60394,12 @@ impl std::fmt::Display for ForeignKeyCacheInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyCacheInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyCacheInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyOptInfo {}
 impl pg_sys::PgNode for ForeignKeyOptInfo {}
 impl std::fmt::Display for ForeignKeyOptInfo {
@@ -59060,6 +60407,12 @@ impl std::fmt::Display for ForeignKeyOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignPath {}
 impl pg_sys::PgNode for ForeignPath {}
 impl std::fmt::Display for ForeignPath {
@@ -59067,6 +60420,12 @@ impl std::fmt::Display for ForeignPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScan {}
 impl pg_sys::PgNode for ForeignScan {}
 impl std::fmt::Display for ForeignScan {
@@ -59074,6 +60433,12 @@ impl std::fmt::Display for ForeignScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScanState {}
 impl pg_sys::PgNode for ForeignScanState {}
 impl std::fmt::Display for ForeignScanState {
@@ -59081,6 +60446,12 @@ impl std::fmt::Display for ForeignScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for FromExpr {}
 impl pg_sys::PgNode for FromExpr {}
 impl std::fmt::Display for FromExpr {
@@ -59088,6 +60459,12 @@ impl std::fmt::Display for FromExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FromExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FromExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncCall {}
 impl pg_sys::PgNode for FuncCall {}
 impl std::fmt::Display for FuncCall {
@@ -59095,6 +60472,12 @@ impl std::fmt::Display for FuncCall {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncCall"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncExpr {}
 impl pg_sys::PgNode for FuncExpr {}
 impl std::fmt::Display for FuncExpr {
@@ -59102,6 +60485,12 @@ impl std::fmt::Display for FuncExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionParameter {}
 impl pg_sys::PgNode for FunctionParameter {}
 impl std::fmt::Display for FunctionParameter {
@@ -59109,6 +60498,12 @@ impl std::fmt::Display for FunctionParameter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionParameter"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScan {}
 impl pg_sys::PgNode for FunctionScan {}
 impl std::fmt::Display for FunctionScan {
@@ -59116,6 +60511,12 @@ impl std::fmt::Display for FunctionScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScan"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScanState {}
 impl pg_sys::PgNode for FunctionScanState {}
 impl std::fmt::Display for FunctionScanState {
@@ -59123,6 +60524,12 @@ impl std::fmt::Display for FunctionScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Gather {}
 impl pg_sys::PgNode for Gather {}
 impl std::fmt::Display for Gather {
@@ -59130,6 +60537,12 @@ impl std::fmt::Display for Gather {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Gather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Gather"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMerge {}
 impl pg_sys::PgNode for GatherMerge {}
 impl std::fmt::Display for GatherMerge {
@@ -59137,6 +60550,12 @@ impl std::fmt::Display for GatherMerge {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMerge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMerge"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergePath {}
 impl pg_sys::PgNode for GatherMergePath {}
 impl std::fmt::Display for GatherMergePath {
@@ -59144,6 +60563,12 @@ impl std::fmt::Display for GatherMergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergeState {}
 impl pg_sys::PgNode for GatherMergeState {}
 impl std::fmt::Display for GatherMergeState {
@@ -59151,6 +60576,12 @@ impl std::fmt::Display for GatherMergeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergeState"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherPath {}
 impl pg_sys::PgNode for GatherPath {}
 impl std::fmt::Display for GatherPath {
@@ -59158,6 +60589,12 @@ impl std::fmt::Display for GatherPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherState {}
 impl pg_sys::PgNode for GatherState {}
 impl std::fmt::Display for GatherState {
@@ -59165,6 +60602,12 @@ impl std::fmt::Display for GatherState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherState"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantRoleStmt {}
 impl pg_sys::PgNode for GrantRoleStmt {}
 impl std::fmt::Display for GrantRoleStmt {
@@ -59172,6 +60615,12 @@ impl std::fmt::Display for GrantRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantStmt {}
 impl pg_sys::PgNode for GrantStmt {}
 impl std::fmt::Display for GrantStmt {
@@ -59179,6 +60628,12 @@ impl std::fmt::Display for GrantStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Group {}
 impl pg_sys::PgNode for Group {}
 impl std::fmt::Display for Group {
@@ -59186,6 +60641,12 @@ impl std::fmt::Display for Group {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Group"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupPath {}
 impl pg_sys::PgNode for GroupPath {}
 impl std::fmt::Display for GroupPath {
@@ -59193,6 +60654,12 @@ impl std::fmt::Display for GroupPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupState {}
 impl pg_sys::PgNode for GroupState {}
 impl std::fmt::Display for GroupState {
@@ -59200,6 +60667,12 @@ impl std::fmt::Display for GroupState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupState"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingFunc {}
 impl pg_sys::PgNode for GroupingFunc {}
 impl std::fmt::Display for GroupingFunc {
@@ -59207,6 +60680,12 @@ impl std::fmt::Display for GroupingFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSet {}
 impl pg_sys::PgNode for GroupingSet {}
 impl std::fmt::Display for GroupingSet {
@@ -59214,6 +60693,12 @@ impl std::fmt::Display for GroupingSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSet"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetData {}
 impl pg_sys::PgNode for GroupingSetData {}
 impl std::fmt::Display for GroupingSetData {
@@ -59221,6 +60706,12 @@ impl std::fmt::Display for GroupingSetData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetData"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetsPath {}
 impl pg_sys::PgNode for GroupingSetsPath {}
 impl std::fmt::Display for GroupingSetsPath {
@@ -59228,6 +60719,12 @@ impl std::fmt::Display for GroupingSetsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Hash {}
 impl pg_sys::PgNode for Hash {}
 impl std::fmt::Display for Hash {
@@ -59235,6 +60732,12 @@ impl std::fmt::Display for Hash {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Hash"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoin {}
 impl pg_sys::PgNode for HashJoin {}
 impl std::fmt::Display for HashJoin {
@@ -59242,6 +60745,12 @@ impl std::fmt::Display for HashJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoinState {}
 impl pg_sys::PgNode for HashJoinState {}
 impl std::fmt::Display for HashJoinState {
@@ -59249,6 +60758,12 @@ impl std::fmt::Display for HashJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for HashPath {}
 impl pg_sys::PgNode for HashPath {}
 impl std::fmt::Display for HashPath {
@@ -59256,6 +60771,12 @@ impl std::fmt::Display for HashPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashPath"))
+    }
+}
 impl pg_sys::seal::Sealed for HashState {}
 impl pg_sys::PgNode for HashState {}
 impl std::fmt::Display for HashState {
@@ -59263,6 +60784,12 @@ impl std::fmt::Display for HashState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashState"))
+    }
+}
 impl pg_sys::seal::Sealed for IdentifySystemCmd {}
 impl pg_sys::PgNode for IdentifySystemCmd {}
 impl std::fmt::Display for IdentifySystemCmd {
@@ -59270,6 +60797,12 @@ impl std::fmt::Display for IdentifySystemCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IdentifySystemCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IdentifySystemCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for ImportForeignSchemaStmt {}
 impl pg_sys::PgNode for ImportForeignSchemaStmt {}
 impl std::fmt::Display for ImportForeignSchemaStmt {
@@ -59277,6 +60810,12 @@ impl std::fmt::Display for ImportForeignSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ImportForeignSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ImportForeignSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexAmRoutine {}
 impl pg_sys::PgNode for IndexAmRoutine {}
 impl std::fmt::Display for IndexAmRoutine {
@@ -59284,6 +60823,12 @@ impl std::fmt::Display for IndexAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexElem {}
 impl pg_sys::PgNode for IndexElem {}
 impl std::fmt::Display for IndexElem {
@@ -59291,6 +60836,12 @@ impl std::fmt::Display for IndexElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexElem"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexInfo {}
 impl pg_sys::PgNode for IndexInfo {}
 impl std::fmt::Display for IndexInfo {
@@ -59298,6 +60849,12 @@ impl std::fmt::Display for IndexInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScan {}
 impl pg_sys::PgNode for IndexOnlyScan {}
 impl std::fmt::Display for IndexOnlyScan {
@@ -59305,6 +60862,12 @@ impl std::fmt::Display for IndexOnlyScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScanState {}
 impl pg_sys::PgNode for IndexOnlyScanState {}
 impl std::fmt::Display for IndexOnlyScanState {
@@ -59312,6 +60875,12 @@ impl std::fmt::Display for IndexOnlyScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOptInfo {}
 impl pg_sys::PgNode for IndexOptInfo {}
 impl std::fmt::Display for IndexOptInfo {
@@ -59319,6 +60888,12 @@ impl std::fmt::Display for IndexOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexPath {}
 impl pg_sys::PgNode for IndexPath {}
 impl std::fmt::Display for IndexPath {
@@ -59326,6 +60901,12 @@ impl std::fmt::Display for IndexPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScan {}
 impl pg_sys::PgNode for IndexScan {}
 impl std::fmt::Display for IndexScan {
@@ -59333,6 +60914,12 @@ impl std::fmt::Display for IndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScanState {}
 impl pg_sys::PgNode for IndexScanState {}
 impl std::fmt::Display for IndexScanState {
@@ -59340,6 +60927,12 @@ impl std::fmt::Display for IndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexStmt {}
 impl pg_sys::PgNode for IndexStmt {}
 impl std::fmt::Display for IndexStmt {
@@ -59347,6 +60940,12 @@ impl std::fmt::Display for IndexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for InferClause {}
 impl pg_sys::PgNode for InferClause {}
 impl std::fmt::Display for InferClause {
@@ -59354,6 +60953,12 @@ impl std::fmt::Display for InferClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferClause"))
+    }
+}
 impl pg_sys::seal::Sealed for InferenceElem {}
 impl pg_sys::PgNode for InferenceElem {}
 impl std::fmt::Display for InferenceElem {
@@ -59361,6 +60966,12 @@ impl std::fmt::Display for InferenceElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferenceElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferenceElem"))
+    }
+}
 impl pg_sys::seal::Sealed for InlineCodeBlock {}
 impl pg_sys::PgNode for InlineCodeBlock {}
 impl std::fmt::Display for InlineCodeBlock {
@@ -59368,6 +60979,12 @@ impl std::fmt::Display for InlineCodeBlock {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InlineCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InlineCodeBlock"))
+    }
+}
 impl pg_sys::seal::Sealed for InsertStmt {}
 impl pg_sys::PgNode for InsertStmt {}
 impl std::fmt::Display for InsertStmt {
@@ -59375,6 +60992,12 @@ impl std::fmt::Display for InsertStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InsertStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InsertStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IntoClause {}
 impl pg_sys::PgNode for IntoClause {}
 impl std::fmt::Display for IntoClause {
@@ -59382,6 +61005,12 @@ impl std::fmt::Display for IntoClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IntoClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IntoClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Join {}
 impl pg_sys::PgNode for Join {}
 impl std::fmt::Display for Join {
@@ -59389,6 +61018,12 @@ impl std::fmt::Display for Join {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Join {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Join"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinExpr {}
 impl pg_sys::PgNode for JoinExpr {}
 impl std::fmt::Display for JoinExpr {
@@ -59396,6 +61031,12 @@ impl std::fmt::Display for JoinExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinPath {}
 impl pg_sys::PgNode for JoinPath {}
 impl std::fmt::Display for JoinPath {
@@ -59403,6 +61044,12 @@ impl std::fmt::Display for JoinPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinPath"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinState {}
 impl pg_sys::PgNode for JoinState {}
 impl std::fmt::Display for JoinState {
@@ -59410,6 +61057,12 @@ impl std::fmt::Display for JoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for JunkFilter {}
 impl pg_sys::PgNode for JunkFilter {}
 impl std::fmt::Display for JunkFilter {
@@ -59417,6 +61070,12 @@ impl std::fmt::Display for JunkFilter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JunkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JunkFilter"))
+    }
+}
 impl pg_sys::seal::Sealed for Limit {}
 impl pg_sys::PgNode for Limit {}
 impl std::fmt::Display for Limit {
@@ -59424,6 +61083,12 @@ impl std::fmt::Display for Limit {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Limit"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitPath {}
 impl pg_sys::PgNode for LimitPath {}
 impl std::fmt::Display for LimitPath {
@@ -59431,6 +61096,12 @@ impl std::fmt::Display for LimitPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitState {}
 impl pg_sys::PgNode for LimitState {}
 impl std::fmt::Display for LimitState {
@@ -59438,6 +61109,12 @@ impl std::fmt::Display for LimitState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitState"))
+    }
+}
 impl pg_sys::seal::Sealed for List {}
 impl pg_sys::PgNode for List {}
 impl std::fmt::Display for List {
@@ -59445,6 +61122,12 @@ impl std::fmt::Display for List {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "List"))
+    }
+}
 impl pg_sys::seal::Sealed for ListenStmt {}
 impl pg_sys::PgNode for ListenStmt {}
 impl std::fmt::Display for ListenStmt {
@@ -59452,6 +61135,12 @@ impl std::fmt::Display for ListenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ListenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ListenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LoadStmt {}
 impl pg_sys::PgNode for LoadStmt {}
 impl std::fmt::Display for LoadStmt {
@@ -59459,6 +61148,12 @@ impl std::fmt::Display for LoadStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LoadStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LoadStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRows {}
 impl pg_sys::PgNode for LockRows {}
 impl std::fmt::Display for LockRows {
@@ -59466,6 +61161,12 @@ impl std::fmt::Display for LockRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRows"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsPath {}
 impl pg_sys::PgNode for LockRowsPath {}
 impl std::fmt::Display for LockRowsPath {
@@ -59473,6 +61174,12 @@ impl std::fmt::Display for LockRowsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsState {}
 impl pg_sys::PgNode for LockRowsState {}
 impl std::fmt::Display for LockRowsState {
@@ -59480,6 +61187,12 @@ impl std::fmt::Display for LockRowsState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsState"))
+    }
+}
 impl pg_sys::seal::Sealed for LockStmt {}
 impl pg_sys::PgNode for LockStmt {}
 impl std::fmt::Display for LockStmt {
@@ -59487,6 +61200,12 @@ impl std::fmt::Display for LockStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockingClause {}
 impl pg_sys::PgNode for LockingClause {}
 impl std::fmt::Display for LockingClause {
@@ -59494,6 +61213,12 @@ impl std::fmt::Display for LockingClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockingClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockingClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Material {}
 impl pg_sys::PgNode for Material {}
 impl std::fmt::Display for Material {
@@ -59501,6 +61226,12 @@ impl std::fmt::Display for Material {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Material"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialPath {}
 impl pg_sys::PgNode for MaterialPath {}
 impl std::fmt::Display for MaterialPath {
@@ -59508,6 +61239,12 @@ impl std::fmt::Display for MaterialPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialState {}
 impl pg_sys::PgNode for MaterialState {}
 impl std::fmt::Display for MaterialState {
@@ -59515,6 +61252,12 @@ impl std::fmt::Display for MaterialState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialState"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoryContextData {}
 impl pg_sys::PgNode for MemoryContextData {}
 impl std::fmt::Display for MemoryContextData {
@@ -59522,6 +61265,12 @@ impl std::fmt::Display for MemoryContextData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoryContextData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoryContextData"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppend {}
 impl pg_sys::PgNode for MergeAppend {}
 impl std::fmt::Display for MergeAppend {
@@ -59529,6 +61278,12 @@ impl std::fmt::Display for MergeAppend {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppend"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendPath {}
 impl pg_sys::PgNode for MergeAppendPath {}
 impl std::fmt::Display for MergeAppendPath {
@@ -59536,6 +61291,12 @@ impl std::fmt::Display for MergeAppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendState {}
 impl pg_sys::PgNode for MergeAppendState {}
 impl std::fmt::Display for MergeAppendState {
@@ -59543,6 +61304,12 @@ impl std::fmt::Display for MergeAppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoin {}
 impl pg_sys::PgNode for MergeJoin {}
 impl std::fmt::Display for MergeJoin {
@@ -59550,6 +61317,12 @@ impl std::fmt::Display for MergeJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoinState {}
 impl pg_sys::PgNode for MergeJoinState {}
 impl std::fmt::Display for MergeJoinState {
@@ -59557,6 +61330,12 @@ impl std::fmt::Display for MergeJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergePath {}
 impl pg_sys::PgNode for MergePath {}
 impl std::fmt::Display for MergePath {
@@ -59564,6 +61343,12 @@ impl std::fmt::Display for MergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggInfo {}
 impl pg_sys::PgNode for MinMaxAggInfo {}
 impl std::fmt::Display for MinMaxAggInfo {
@@ -59571,6 +61356,12 @@ impl std::fmt::Display for MinMaxAggInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggPath {}
 impl pg_sys::PgNode for MinMaxAggPath {}
 impl std::fmt::Display for MinMaxAggPath {
@@ -59578,6 +61369,12 @@ impl std::fmt::Display for MinMaxAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxExpr {}
 impl pg_sys::PgNode for MinMaxExpr {}
 impl std::fmt::Display for MinMaxExpr {
@@ -59585,6 +61382,12 @@ impl std::fmt::Display for MinMaxExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTable {}
 impl pg_sys::PgNode for ModifyTable {}
 impl std::fmt::Display for ModifyTable {
@@ -59592,6 +61395,12 @@ impl std::fmt::Display for ModifyTable {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTable"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTablePath {}
 impl pg_sys::PgNode for ModifyTablePath {}
 impl std::fmt::Display for ModifyTablePath {
@@ -59599,6 +61408,12 @@ impl std::fmt::Display for ModifyTablePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTablePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTablePath"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTableState {}
 impl pg_sys::PgNode for ModifyTableState {}
 impl std::fmt::Display for ModifyTableState {
@@ -59606,6 +61421,12 @@ impl std::fmt::Display for ModifyTableState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTableState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTableState"))
+    }
+}
 impl pg_sys::seal::Sealed for MultiAssignRef {}
 impl pg_sys::PgNode for MultiAssignRef {}
 impl std::fmt::Display for MultiAssignRef {
@@ -59613,6 +61434,12 @@ impl std::fmt::Display for MultiAssignRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MultiAssignRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MultiAssignRef"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedArgExpr {}
 impl pg_sys::PgNode for NamedArgExpr {}
 impl std::fmt::Display for NamedArgExpr {
@@ -59620,6 +61447,12 @@ impl std::fmt::Display for NamedArgExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedArgExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedArgExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScan {}
 impl pg_sys::PgNode for NamedTuplestoreScan {}
 impl std::fmt::Display for NamedTuplestoreScan {
@@ -59627,6 +61460,12 @@ impl std::fmt::Display for NamedTuplestoreScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScan"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScanState {}
 impl pg_sys::PgNode for NamedTuplestoreScanState {}
 impl std::fmt::Display for NamedTuplestoreScanState {
@@ -59634,6 +61473,12 @@ impl std::fmt::Display for NamedTuplestoreScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoop {}
 impl pg_sys::PgNode for NestLoop {}
 impl std::fmt::Display for NestLoop {
@@ -59641,6 +61486,12 @@ impl std::fmt::Display for NestLoop {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoop"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopParam {}
 impl pg_sys::PgNode for NestLoopParam {}
 impl std::fmt::Display for NestLoopParam {
@@ -59648,6 +61499,12 @@ impl std::fmt::Display for NestLoopParam {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopParam"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopState {}
 impl pg_sys::PgNode for NestLoopState {}
 impl std::fmt::Display for NestLoopState {
@@ -59655,6 +61512,12 @@ impl std::fmt::Display for NestLoopState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopState"))
+    }
+}
 impl pg_sys::seal::Sealed for NextValueExpr {}
 impl pg_sys::PgNode for NextValueExpr {}
 impl std::fmt::Display for NextValueExpr {
@@ -59662,6 +61525,12 @@ impl std::fmt::Display for NextValueExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NextValueExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NextValueExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Node {}
 impl pg_sys::PgNode for Node {}
 impl std::fmt::Display for Node {
@@ -59669,6 +61538,12 @@ impl std::fmt::Display for Node {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Node"))
+    }
+}
 impl pg_sys::seal::Sealed for NotifyStmt {}
 impl pg_sys::PgNode for NotifyStmt {}
 impl std::fmt::Display for NotifyStmt {
@@ -59676,6 +61551,12 @@ impl std::fmt::Display for NotifyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NotifyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NotifyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for NullTest {}
 impl pg_sys::PgNode for NullTest {}
 impl std::fmt::Display for NullTest {
@@ -59683,6 +61564,12 @@ impl std::fmt::Display for NullTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NullTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NullTest"))
+    }
+}
 impl pg_sys::seal::Sealed for ObjectWithArgs {}
 impl pg_sys::PgNode for ObjectWithArgs {}
 impl std::fmt::Display for ObjectWithArgs {
@@ -59690,6 +61577,12 @@ impl std::fmt::Display for ObjectWithArgs {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ObjectWithArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ObjectWithArgs"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictClause {}
 impl pg_sys::PgNode for OnConflictClause {}
 impl std::fmt::Display for OnConflictClause {
@@ -59697,6 +61590,12 @@ impl std::fmt::Display for OnConflictClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictClause"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictExpr {}
 impl pg_sys::PgNode for OnConflictExpr {}
 impl std::fmt::Display for OnConflictExpr {
@@ -59704,6 +61603,12 @@ impl std::fmt::Display for OnConflictExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictSetState {}
 impl pg_sys::PgNode for OnConflictSetState {}
 impl std::fmt::Display for OnConflictSetState {
@@ -59711,6 +61616,12 @@ impl std::fmt::Display for OnConflictSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for OpExpr {}
 impl pg_sys::PgNode for OpExpr {}
 impl std::fmt::Display for OpExpr {
@@ -59718,6 +61629,12 @@ impl std::fmt::Display for OpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Param {}
 impl pg_sys::PgNode for Param {}
 impl std::fmt::Display for Param {
@@ -59725,6 +61642,12 @@ impl std::fmt::Display for Param {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Param"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamPathInfo {}
 impl pg_sys::PgNode for ParamPathInfo {}
 impl std::fmt::Display for ParamPathInfo {
@@ -59732,6 +61655,12 @@ impl std::fmt::Display for ParamPathInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamPathInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamPathInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamRef {}
 impl pg_sys::PgNode for ParamRef {}
 impl std::fmt::Display for ParamRef {
@@ -59739,6 +61668,12 @@ impl std::fmt::Display for ParamRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamRef"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionBoundSpec {}
 impl pg_sys::PgNode for PartitionBoundSpec {}
 impl std::fmt::Display for PartitionBoundSpec {
@@ -59746,6 +61681,12 @@ impl std::fmt::Display for PartitionBoundSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionBoundSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionBoundSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionCmd {}
 impl pg_sys::PgNode for PartitionCmd {}
 impl std::fmt::Display for PartitionCmd {
@@ -59753,6 +61694,12 @@ impl std::fmt::Display for PartitionCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionElem {}
 impl pg_sys::PgNode for PartitionElem {}
 impl std::fmt::Display for PartitionElem {
@@ -59760,6 +61707,12 @@ impl std::fmt::Display for PartitionElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionElem"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneInfo {}
 impl pg_sys::PgNode for PartitionPruneInfo {}
 impl std::fmt::Display for PartitionPruneInfo {
@@ -59767,6 +61720,12 @@ impl std::fmt::Display for PartitionPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStep {}
 impl pg_sys::PgNode for PartitionPruneStep {}
 impl std::fmt::Display for PartitionPruneStep {
@@ -59774,6 +61733,12 @@ impl std::fmt::Display for PartitionPruneStep {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStep"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepCombine {}
 impl pg_sys::PgNode for PartitionPruneStepCombine {}
 impl std::fmt::Display for PartitionPruneStepCombine {
@@ -59781,6 +61746,12 @@ impl std::fmt::Display for PartitionPruneStepCombine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepCombine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepCombine"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepOp {}
 impl pg_sys::PgNode for PartitionPruneStepOp {}
 impl std::fmt::Display for PartitionPruneStepOp {
@@ -59788,6 +61759,12 @@ impl std::fmt::Display for PartitionPruneStepOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepOp"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionRangeDatum {}
 impl pg_sys::PgNode for PartitionRangeDatum {}
 impl std::fmt::Display for PartitionRangeDatum {
@@ -59795,6 +61772,12 @@ impl std::fmt::Display for PartitionRangeDatum {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionRangeDatum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionRangeDatum"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionSpec {}
 impl pg_sys::PgNode for PartitionSpec {}
 impl std::fmt::Display for PartitionSpec {
@@ -59802,6 +61785,12 @@ impl std::fmt::Display for PartitionSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionedRelPruneInfo {}
 impl pg_sys::PgNode for PartitionedRelPruneInfo {}
 impl std::fmt::Display for PartitionedRelPruneInfo {
@@ -59809,6 +61798,12 @@ impl std::fmt::Display for PartitionedRelPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionedRelPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionedRelPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Path {}
 impl pg_sys::PgNode for Path {}
 impl std::fmt::Display for Path {
@@ -59816,6 +61811,12 @@ impl std::fmt::Display for Path {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Path"))
+    }
+}
 impl pg_sys::seal::Sealed for PathKey {}
 impl pg_sys::PgNode for PathKey {}
 impl std::fmt::Display for PathKey {
@@ -59823,6 +61824,12 @@ impl std::fmt::Display for PathKey {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathKey"))
+    }
+}
 impl pg_sys::seal::Sealed for PathTarget {}
 impl pg_sys::PgNode for PathTarget {}
 impl std::fmt::Display for PathTarget {
@@ -59830,6 +61837,12 @@ impl std::fmt::Display for PathTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderInfo {}
 impl pg_sys::PgNode for PlaceHolderInfo {}
 impl std::fmt::Display for PlaceHolderInfo {
@@ -59837,6 +61850,12 @@ impl std::fmt::Display for PlaceHolderInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderVar {}
 impl pg_sys::PgNode for PlaceHolderVar {}
 impl std::fmt::Display for PlaceHolderVar {
@@ -59844,6 +61863,12 @@ impl std::fmt::Display for PlaceHolderVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderVar"))
+    }
+}
 impl pg_sys::seal::Sealed for Plan {}
 impl pg_sys::PgNode for Plan {}
 impl std::fmt::Display for Plan {
@@ -59851,6 +61876,12 @@ impl std::fmt::Display for Plan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Plan"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanInvalItem {}
 impl pg_sys::PgNode for PlanInvalItem {}
 impl std::fmt::Display for PlanInvalItem {
@@ -59858,6 +61889,12 @@ impl std::fmt::Display for PlanInvalItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanInvalItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanInvalItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanRowMark {}
 impl pg_sys::PgNode for PlanRowMark {}
 impl std::fmt::Display for PlanRowMark {
@@ -59865,6 +61902,12 @@ impl std::fmt::Display for PlanRowMark {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanRowMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanRowMark"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanState {}
 impl pg_sys::PgNode for PlanState {}
 impl std::fmt::Display for PlanState {
@@ -59872,6 +61915,12 @@ impl std::fmt::Display for PlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannedStmt {}
 impl pg_sys::PgNode for PlannedStmt {}
 impl std::fmt::Display for PlannedStmt {
@@ -59879,6 +61928,12 @@ impl std::fmt::Display for PlannedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerGlobal {}
 impl pg_sys::PgNode for PlannerGlobal {}
 impl std::fmt::Display for PlannerGlobal {
@@ -59886,6 +61941,12 @@ impl std::fmt::Display for PlannerGlobal {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerGlobal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerGlobal"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerInfo {}
 impl pg_sys::PgNode for PlannerInfo {}
 impl std::fmt::Display for PlannerInfo {
@@ -59893,6 +61954,12 @@ impl std::fmt::Display for PlannerInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerParamItem {}
 impl pg_sys::PgNode for PlannerParamItem {}
 impl std::fmt::Display for PlannerParamItem {
@@ -59900,6 +61967,12 @@ impl std::fmt::Display for PlannerParamItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerParamItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerParamItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PrepareStmt {}
 impl pg_sys::PgNode for PrepareStmt {}
 impl std::fmt::Display for PrepareStmt {
@@ -59907,6 +61980,12 @@ impl std::fmt::Display for PrepareStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PrepareStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PrepareStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSet {}
 impl pg_sys::PgNode for ProjectSet {}
 impl std::fmt::Display for ProjectSet {
@@ -59914,6 +61993,12 @@ impl std::fmt::Display for ProjectSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSet"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetPath {}
 impl pg_sys::PgNode for ProjectSetPath {}
 impl std::fmt::Display for ProjectSetPath {
@@ -59921,6 +62006,12 @@ impl std::fmt::Display for ProjectSetPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetState {}
 impl pg_sys::PgNode for ProjectSetState {}
 impl std::fmt::Display for ProjectSetState {
@@ -59928,6 +62019,12 @@ impl std::fmt::Display for ProjectSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionInfo {}
 impl pg_sys::PgNode for ProjectionInfo {}
 impl std::fmt::Display for ProjectionInfo {
@@ -59935,6 +62032,12 @@ impl std::fmt::Display for ProjectionInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionPath {}
 impl pg_sys::PgNode for ProjectionPath {}
 impl std::fmt::Display for ProjectionPath {
@@ -59942,6 +62045,12 @@ impl std::fmt::Display for ProjectionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Query {}
 impl pg_sys::PgNode for Query {}
 impl std::fmt::Display for Query {
@@ -59949,6 +62058,12 @@ impl std::fmt::Display for Query {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Query"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeFunction {}
 impl pg_sys::PgNode for RangeFunction {}
 impl std::fmt::Display for RangeFunction {
@@ -59956,6 +62071,12 @@ impl std::fmt::Display for RangeFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeSubselect {}
 impl pg_sys::PgNode for RangeSubselect {}
 impl std::fmt::Display for RangeSubselect {
@@ -59963,6 +62084,12 @@ impl std::fmt::Display for RangeSubselect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeSubselect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeSubselect"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFunc {}
 impl pg_sys::PgNode for RangeTableFunc {}
 impl std::fmt::Display for RangeTableFunc {
@@ -59970,6 +62097,12 @@ impl std::fmt::Display for RangeTableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFuncCol {}
 impl pg_sys::PgNode for RangeTableFuncCol {}
 impl std::fmt::Display for RangeTableFuncCol {
@@ -59977,6 +62110,12 @@ impl std::fmt::Display for RangeTableFuncCol {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFuncCol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFuncCol"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableSample {}
 impl pg_sys::PgNode for RangeTableSample {}
 impl std::fmt::Display for RangeTableSample {
@@ -59984,6 +62123,12 @@ impl std::fmt::Display for RangeTableSample {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableSample"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblEntry {}
 impl pg_sys::PgNode for RangeTblEntry {}
 impl std::fmt::Display for RangeTblEntry {
@@ -59991,6 +62136,12 @@ impl std::fmt::Display for RangeTblEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblFunction {}
 impl pg_sys::PgNode for RangeTblFunction {}
 impl std::fmt::Display for RangeTblFunction {
@@ -59998,6 +62149,12 @@ impl std::fmt::Display for RangeTblFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblRef {}
 impl pg_sys::PgNode for RangeTblRef {}
 impl std::fmt::Display for RangeTblRef {
@@ -60005,6 +62162,12 @@ impl std::fmt::Display for RangeTblRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblRef"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeVar {}
 impl pg_sys::PgNode for RangeVar {}
 impl std::fmt::Display for RangeVar {
@@ -60012,6 +62175,12 @@ impl std::fmt::Display for RangeVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeVar"))
+    }
+}
 impl pg_sys::seal::Sealed for RawStmt {}
 impl pg_sys::PgNode for RawStmt {}
 impl std::fmt::Display for RawStmt {
@@ -60019,6 +62188,12 @@ impl std::fmt::Display for RawStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RawStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RawStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReassignOwnedStmt {}
 impl pg_sys::PgNode for ReassignOwnedStmt {}
 impl std::fmt::Display for ReassignOwnedStmt {
@@ -60026,6 +62201,12 @@ impl std::fmt::Display for ReassignOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReassignOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReassignOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnion {}
 impl pg_sys::PgNode for RecursiveUnion {}
 impl std::fmt::Display for RecursiveUnion {
@@ -60033,6 +62214,12 @@ impl std::fmt::Display for RecursiveUnion {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnion"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionPath {}
 impl pg_sys::PgNode for RecursiveUnionPath {}
 impl std::fmt::Display for RecursiveUnionPath {
@@ -60040,6 +62227,12 @@ impl std::fmt::Display for RecursiveUnionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionState {}
 impl pg_sys::PgNode for RecursiveUnionState {}
 impl std::fmt::Display for RecursiveUnionState {
@@ -60047,6 +62240,12 @@ impl std::fmt::Display for RecursiveUnionState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionState"))
+    }
+}
 impl pg_sys::seal::Sealed for RefreshMatViewStmt {}
 impl pg_sys::PgNode for RefreshMatViewStmt {}
 impl std::fmt::Display for RefreshMatViewStmt {
@@ -60054,6 +62253,12 @@ impl std::fmt::Display for RefreshMatViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RefreshMatViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RefreshMatViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReindexStmt {}
 impl pg_sys::PgNode for ReindexStmt {}
 impl std::fmt::Display for ReindexStmt {
@@ -60061,6 +62266,12 @@ impl std::fmt::Display for ReindexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReindexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReindexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RelOptInfo {}
 impl pg_sys::PgNode for RelOptInfo {}
 impl std::fmt::Display for RelOptInfo {
@@ -60068,6 +62279,12 @@ impl std::fmt::Display for RelOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RelabelType {}
 impl pg_sys::PgNode for RelabelType {}
 impl std::fmt::Display for RelabelType {
@@ -60075,6 +62292,12 @@ impl std::fmt::Display for RelabelType {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelabelType"))
+    }
+}
 impl pg_sys::seal::Sealed for RenameStmt {}
 impl pg_sys::PgNode for RenameStmt {}
 impl std::fmt::Display for RenameStmt {
@@ -60082,6 +62305,12 @@ impl std::fmt::Display for RenameStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RenameStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RenameStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReplicaIdentityStmt {}
 impl pg_sys::PgNode for ReplicaIdentityStmt {}
 impl std::fmt::Display for ReplicaIdentityStmt {
@@ -60089,6 +62318,12 @@ impl std::fmt::Display for ReplicaIdentityStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReplicaIdentityStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReplicaIdentityStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ResTarget {}
 impl pg_sys::PgNode for ResTarget {}
 impl std::fmt::Display for ResTarget {
@@ -60096,6 +62331,12 @@ impl std::fmt::Display for ResTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for RestrictInfo {}
 impl pg_sys::PgNode for RestrictInfo {}
 impl std::fmt::Display for RestrictInfo {
@@ -60103,6 +62344,12 @@ impl std::fmt::Display for RestrictInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RestrictInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RestrictInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Result {}
 impl pg_sys::PgNode for Result {}
 impl std::fmt::Display for Result {
@@ -60110,6 +62357,12 @@ impl std::fmt::Display for Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Result"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultPath {}
 impl pg_sys::PgNode for ResultPath {}
 impl std::fmt::Display for ResultPath {
@@ -60117,6 +62370,12 @@ impl std::fmt::Display for ResultPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultRelInfo {}
 impl pg_sys::PgNode for ResultRelInfo {}
 impl std::fmt::Display for ResultRelInfo {
@@ -60124,6 +62383,12 @@ impl std::fmt::Display for ResultRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultState {}
 impl pg_sys::PgNode for ResultState {}
 impl std::fmt::Display for ResultState {
@@ -60131,6 +62396,12 @@ impl std::fmt::Display for ResultState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultState"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnSetInfo {}
 impl pg_sys::PgNode for ReturnSetInfo {}
 impl std::fmt::Display for ReturnSetInfo {
@@ -60138,6 +62409,12 @@ impl std::fmt::Display for ReturnSetInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnSetInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnSetInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RoleSpec {}
 impl pg_sys::PgNode for RoleSpec {}
 impl std::fmt::Display for RoleSpec {
@@ -60145,6 +62422,12 @@ impl std::fmt::Display for RoleSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RoleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RoleSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for RollupData {}
 impl pg_sys::PgNode for RollupData {}
 impl std::fmt::Display for RollupData {
@@ -60152,6 +62435,12 @@ impl std::fmt::Display for RollupData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RollupData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RollupData"))
+    }
+}
 impl pg_sys::seal::Sealed for RowCompareExpr {}
 impl pg_sys::PgNode for RowCompareExpr {}
 impl std::fmt::Display for RowCompareExpr {
@@ -60159,6 +62448,12 @@ impl std::fmt::Display for RowCompareExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowCompareExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowCompareExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowExpr {}
 impl pg_sys::PgNode for RowExpr {}
 impl std::fmt::Display for RowExpr {
@@ -60166,6 +62461,12 @@ impl std::fmt::Display for RowExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowMarkClause {}
 impl pg_sys::PgNode for RowMarkClause {}
 impl std::fmt::Display for RowMarkClause {
@@ -60173,6 +62474,12 @@ impl std::fmt::Display for RowMarkClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowMarkClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowMarkClause"))
+    }
+}
 impl pg_sys::seal::Sealed for RuleStmt {}
 impl pg_sys::PgNode for RuleStmt {}
 impl std::fmt::Display for RuleStmt {
@@ -60180,6 +62487,12 @@ impl std::fmt::Display for RuleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RuleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RuleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLCmd {}
 impl pg_sys::PgNode for SQLCmd {}
 impl std::fmt::Display for SQLCmd {
@@ -60187,6 +62500,12 @@ impl std::fmt::Display for SQLCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLValueFunction {}
 impl pg_sys::PgNode for SQLValueFunction {}
 impl std::fmt::Display for SQLValueFunction {
@@ -60194,6 +62513,12 @@ impl std::fmt::Display for SQLValueFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLValueFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLValueFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScan {}
 impl pg_sys::PgNode for SampleScan {}
 impl std::fmt::Display for SampleScan {
@@ -60201,6 +62526,12 @@ impl std::fmt::Display for SampleScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScanState {}
 impl pg_sys::PgNode for SampleScanState {}
 impl std::fmt::Display for SampleScanState {
@@ -60208,6 +62539,12 @@ impl std::fmt::Display for SampleScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for ScalarArrayOpExpr {}
 impl pg_sys::PgNode for ScalarArrayOpExpr {}
 impl std::fmt::Display for ScalarArrayOpExpr {
@@ -60215,6 +62552,12 @@ impl std::fmt::Display for ScalarArrayOpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScalarArrayOpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScalarArrayOpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Scan {}
 impl pg_sys::PgNode for Scan {}
 impl std::fmt::Display for Scan {
@@ -60222,6 +62565,12 @@ impl std::fmt::Display for Scan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Scan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Scan"))
+    }
+}
 impl pg_sys::seal::Sealed for ScanState {}
 impl pg_sys::PgNode for ScanState {}
 impl std::fmt::Display for ScanState {
@@ -60229,6 +62578,12 @@ impl std::fmt::Display for ScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SecLabelStmt {}
 impl pg_sys::PgNode for SecLabelStmt {}
 impl std::fmt::Display for SecLabelStmt {
@@ -60236,6 +62591,12 @@ impl std::fmt::Display for SecLabelStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SecLabelStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SecLabelStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SelectStmt {}
 impl pg_sys::PgNode for SelectStmt {}
 impl std::fmt::Display for SelectStmt {
@@ -60243,6 +62604,12 @@ impl std::fmt::Display for SelectStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SelectStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SelectStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SeqScanState {}
 impl pg_sys::PgNode for SeqScanState {}
 impl std::fmt::Display for SeqScanState {
@@ -60250,6 +62617,12 @@ impl std::fmt::Display for SeqScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SeqScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SeqScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetExprState {}
 impl pg_sys::PgNode for SetExprState {}
 impl std::fmt::Display for SetExprState {
@@ -60257,6 +62630,12 @@ impl std::fmt::Display for SetExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOp {}
 impl pg_sys::PgNode for SetOp {}
 impl std::fmt::Display for SetOp {
@@ -60264,6 +62643,12 @@ impl std::fmt::Display for SetOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOp"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpPath {}
 impl pg_sys::PgNode for SetOpPath {}
 impl std::fmt::Display for SetOpPath {
@@ -60271,6 +62656,12 @@ impl std::fmt::Display for SetOpPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpState {}
 impl pg_sys::PgNode for SetOpState {}
 impl std::fmt::Display for SetOpState {
@@ -60278,6 +62669,12 @@ impl std::fmt::Display for SetOpState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOperationStmt {}
 impl pg_sys::PgNode for SetOperationStmt {}
 impl std::fmt::Display for SetOperationStmt {
@@ -60285,6 +62682,12 @@ impl std::fmt::Display for SetOperationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOperationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOperationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SetToDefault {}
 impl pg_sys::PgNode for SetToDefault {}
 impl std::fmt::Display for SetToDefault {
@@ -60292,6 +62695,12 @@ impl std::fmt::Display for SetToDefault {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetToDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetToDefault"))
+    }
+}
 impl pg_sys::seal::Sealed for Sort {}
 impl pg_sys::PgNode for Sort {}
 impl std::fmt::Display for Sort {
@@ -60299,6 +62708,12 @@ impl std::fmt::Display for Sort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Sort"))
+    }
+}
 impl pg_sys::seal::Sealed for SortBy {}
 impl pg_sys::PgNode for SortBy {}
 impl std::fmt::Display for SortBy {
@@ -60306,6 +62721,12 @@ impl std::fmt::Display for SortBy {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortBy"))
+    }
+}
 impl pg_sys::seal::Sealed for SortGroupClause {}
 impl pg_sys::PgNode for SortGroupClause {}
 impl std::fmt::Display for SortGroupClause {
@@ -60313,6 +62734,12 @@ impl std::fmt::Display for SortGroupClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortGroupClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortGroupClause"))
+    }
+}
 impl pg_sys::seal::Sealed for SortPath {}
 impl pg_sys::PgNode for SortPath {}
 impl std::fmt::Display for SortPath {
@@ -60320,6 +62747,12 @@ impl std::fmt::Display for SortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SortState {}
 impl pg_sys::PgNode for SortState {}
 impl std::fmt::Display for SortState {
@@ -60327,6 +62760,12 @@ impl std::fmt::Display for SortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortState"))
+    }
+}
 impl pg_sys::seal::Sealed for SpecialJoinInfo {}
 impl pg_sys::PgNode for SpecialJoinInfo {}
 impl std::fmt::Display for SpecialJoinInfo {
@@ -60334,6 +62773,12 @@ impl std::fmt::Display for SpecialJoinInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SpecialJoinInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SpecialJoinInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StartReplicationCmd {}
 impl pg_sys::PgNode for StartReplicationCmd {}
 impl std::fmt::Display for StartReplicationCmd {
@@ -60341,6 +62786,12 @@ impl std::fmt::Display for StartReplicationCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StartReplicationCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StartReplicationCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for StatisticExtInfo {}
 impl pg_sys::PgNode for StatisticExtInfo {}
 impl std::fmt::Display for StatisticExtInfo {
@@ -60348,6 +62799,12 @@ impl std::fmt::Display for StatisticExtInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatisticExtInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatisticExtInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for SubLink {}
 impl pg_sys::PgNode for SubLink {}
 impl std::fmt::Display for SubLink {
@@ -60355,6 +62812,12 @@ impl std::fmt::Display for SubLink {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubLink"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlan {}
 impl pg_sys::PgNode for SubPlan {}
 impl std::fmt::Display for SubPlan {
@@ -60362,6 +62825,12 @@ impl std::fmt::Display for SubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlanState {}
 impl pg_sys::PgNode for SubPlanState {}
 impl std::fmt::Display for SubPlanState {
@@ -60369,6 +62838,12 @@ impl std::fmt::Display for SubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScan {}
 impl pg_sys::PgNode for SubqueryScan {}
 impl std::fmt::Display for SubqueryScan {
@@ -60376,6 +62851,12 @@ impl std::fmt::Display for SubqueryScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanPath {}
 impl pg_sys::PgNode for SubqueryScanPath {}
 impl std::fmt::Display for SubqueryScanPath {
@@ -60383,6 +62864,12 @@ impl std::fmt::Display for SubqueryScanPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanState {}
 impl pg_sys::PgNode for SubqueryScanState {}
 impl std::fmt::Display for SubqueryScanState {
@@ -60390,6 +62877,12 @@ impl std::fmt::Display for SubqueryScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFunc {}
 impl pg_sys::PgNode for TableFunc {}
 impl std::fmt::Display for TableFunc {
@@ -60397,6 +62890,12 @@ impl std::fmt::Display for TableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScan {}
 impl pg_sys::PgNode for TableFuncScan {}
 impl std::fmt::Display for TableFuncScan {
@@ -60404,6 +62903,12 @@ impl std::fmt::Display for TableFuncScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScanState {}
 impl pg_sys::PgNode for TableFuncScanState {}
 impl std::fmt::Display for TableFuncScanState {
@@ -60411,6 +62916,12 @@ impl std::fmt::Display for TableFuncScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TableLikeClause {}
 impl pg_sys::PgNode for TableLikeClause {}
 impl std::fmt::Display for TableLikeClause {
@@ -60418,6 +62929,12 @@ impl std::fmt::Display for TableLikeClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableLikeClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableLikeClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TableSampleClause {}
 impl pg_sys::PgNode for TableSampleClause {}
 impl std::fmt::Display for TableSampleClause {
@@ -60425,6 +62942,12 @@ impl std::fmt::Display for TableSampleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableSampleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableSampleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TargetEntry {}
 impl pg_sys::PgNode for TargetEntry {}
 impl std::fmt::Display for TargetEntry {
@@ -60432,6 +62955,12 @@ impl std::fmt::Display for TargetEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TargetEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TargetEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for TidPath {}
 impl pg_sys::PgNode for TidPath {}
 impl std::fmt::Display for TidPath {
@@ -60439,6 +62968,12 @@ impl std::fmt::Display for TidPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidPath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScan {}
 impl pg_sys::PgNode for TidScan {}
 impl std::fmt::Display for TidScan {
@@ -60446,6 +62981,12 @@ impl std::fmt::Display for TidScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScanState {}
 impl pg_sys::PgNode for TidScanState {}
 impl std::fmt::Display for TidScanState {
@@ -60453,6 +62994,12 @@ impl std::fmt::Display for TidScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TimeLineHistoryCmd {}
 impl pg_sys::PgNode for TimeLineHistoryCmd {}
 impl std::fmt::Display for TimeLineHistoryCmd {
@@ -60460,6 +63007,12 @@ impl std::fmt::Display for TimeLineHistoryCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TimeLineHistoryCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TimeLineHistoryCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for TransactionStmt {}
 impl pg_sys::PgNode for TransactionStmt {}
 impl std::fmt::Display for TransactionStmt {
@@ -60467,6 +63020,12 @@ impl std::fmt::Display for TransactionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TransactionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TransactionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerData {}
 impl pg_sys::PgNode for TriggerData {}
 impl std::fmt::Display for TriggerData {
@@ -60474,6 +63033,12 @@ impl std::fmt::Display for TriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerTransition {}
 impl pg_sys::PgNode for TriggerTransition {}
 impl std::fmt::Display for TriggerTransition {
@@ -60481,6 +63046,12 @@ impl std::fmt::Display for TriggerTransition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerTransition"))
+    }
+}
 impl pg_sys::seal::Sealed for TruncateStmt {}
 impl pg_sys::PgNode for TruncateStmt {}
 impl std::fmt::Display for TruncateStmt {
@@ -60488,6 +63059,12 @@ impl std::fmt::Display for TruncateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TruncateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TruncateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TupleTableSlot {}
 impl pg_sys::PgNode for TupleTableSlot {}
 impl std::fmt::Display for TupleTableSlot {
@@ -60495,6 +63072,12 @@ impl std::fmt::Display for TupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeCast {}
 impl pg_sys::PgNode for TypeCast {}
 impl std::fmt::Display for TypeCast {
@@ -60502,6 +63085,12 @@ impl std::fmt::Display for TypeCast {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeCast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeCast"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeName {}
 impl pg_sys::PgNode for TypeName {}
 impl std::fmt::Display for TypeName {
@@ -60509,6 +63098,12 @@ impl std::fmt::Display for TypeName {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeName"))
+    }
+}
 impl pg_sys::seal::Sealed for Unique {}
 impl pg_sys::PgNode for Unique {}
 impl std::fmt::Display for Unique {
@@ -60516,6 +63111,12 @@ impl std::fmt::Display for Unique {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Unique"))
+    }
+}
 impl pg_sys::seal::Sealed for UniquePath {}
 impl pg_sys::PgNode for UniquePath {}
 impl std::fmt::Display for UniquePath {
@@ -60523,6 +63124,12 @@ impl std::fmt::Display for UniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for UniqueState {}
 impl pg_sys::PgNode for UniqueState {}
 impl std::fmt::Display for UniqueState {
@@ -60530,6 +63137,12 @@ impl std::fmt::Display for UniqueState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniqueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniqueState"))
+    }
+}
 impl pg_sys::seal::Sealed for UnlistenStmt {}
 impl pg_sys::PgNode for UnlistenStmt {}
 impl std::fmt::Display for UnlistenStmt {
@@ -60537,6 +63150,12 @@ impl std::fmt::Display for UnlistenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UnlistenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UnlistenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpdateStmt {}
 impl pg_sys::PgNode for UpdateStmt {}
 impl std::fmt::Display for UpdateStmt {
@@ -60544,6 +63163,12 @@ impl std::fmt::Display for UpdateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpdateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpdateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpperUniquePath {}
 impl pg_sys::PgNode for UpperUniquePath {}
 impl std::fmt::Display for UpperUniquePath {
@@ -60551,6 +63176,12 @@ impl std::fmt::Display for UpperUniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpperUniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpperUniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumRelation {}
 impl pg_sys::PgNode for VacuumRelation {}
 impl std::fmt::Display for VacuumRelation {
@@ -60558,6 +63189,12 @@ impl std::fmt::Display for VacuumRelation {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumRelation"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumStmt {}
 impl pg_sys::PgNode for VacuumStmt {}
 impl std::fmt::Display for VacuumStmt {
@@ -60565,6 +63202,12 @@ impl std::fmt::Display for VacuumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Value {}
 impl pg_sys::PgNode for Value {}
 impl std::fmt::Display for Value {
@@ -60572,6 +63215,12 @@ impl std::fmt::Display for Value {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Value"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScan {}
 impl pg_sys::PgNode for ValuesScan {}
 impl std::fmt::Display for ValuesScan {
@@ -60579,6 +63228,12 @@ impl std::fmt::Display for ValuesScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScanState {}
 impl pg_sys::PgNode for ValuesScanState {}
 impl std::fmt::Display for ValuesScanState {
@@ -60586,6 +63241,12 @@ impl std::fmt::Display for ValuesScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Var {}
 impl pg_sys::PgNode for Var {}
 impl std::fmt::Display for Var {
@@ -60593,6 +63254,12 @@ impl std::fmt::Display for Var {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Var"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableSetStmt {}
 impl pg_sys::PgNode for VariableSetStmt {}
 impl std::fmt::Display for VariableSetStmt {
@@ -60600,6 +63267,12 @@ impl std::fmt::Display for VariableSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableShowStmt {}
 impl pg_sys::PgNode for VariableShowStmt {}
 impl std::fmt::Display for VariableShowStmt {
@@ -60607,6 +63280,12 @@ impl std::fmt::Display for VariableShowStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableShowStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableShowStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ViewStmt {}
 impl pg_sys::PgNode for ViewStmt {}
 impl std::fmt::Display for ViewStmt {
@@ -60614,6 +63293,12 @@ impl std::fmt::Display for ViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAgg {}
 impl pg_sys::PgNode for WindowAgg {}
 impl std::fmt::Display for WindowAgg {
@@ -60621,6 +63306,12 @@ impl std::fmt::Display for WindowAgg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAgg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAgg"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggPath {}
 impl pg_sys::PgNode for WindowAggPath {}
 impl std::fmt::Display for WindowAggPath {
@@ -60628,6 +63319,12 @@ impl std::fmt::Display for WindowAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggState {}
 impl pg_sys::PgNode for WindowAggState {}
 impl std::fmt::Display for WindowAggState {
@@ -60635,6 +63332,12 @@ impl std::fmt::Display for WindowAggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggState"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowClause {}
 impl pg_sys::PgNode for WindowClause {}
 impl std::fmt::Display for WindowClause {
@@ -60642,6 +63345,12 @@ impl std::fmt::Display for WindowClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowDef {}
 impl pg_sys::PgNode for WindowDef {}
 impl std::fmt::Display for WindowDef {
@@ -60649,6 +63358,12 @@ impl std::fmt::Display for WindowDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowDef"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFunc {}
 impl pg_sys::PgNode for WindowFunc {}
 impl std::fmt::Display for WindowFunc {
@@ -60656,6 +63371,12 @@ impl std::fmt::Display for WindowFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFuncExprState {}
 impl pg_sys::PgNode for WindowFuncExprState {}
 impl std::fmt::Display for WindowFuncExprState {
@@ -60663,6 +63384,12 @@ impl std::fmt::Display for WindowFuncExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFuncExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFuncExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for WithCheckOption {}
 impl pg_sys::PgNode for WithCheckOption {}
 impl std::fmt::Display for WithCheckOption {
@@ -60670,6 +63397,12 @@ impl std::fmt::Display for WithCheckOption {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithCheckOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithCheckOption"))
+    }
+}
 impl pg_sys::seal::Sealed for WithClause {}
 impl pg_sys::PgNode for WithClause {}
 impl std::fmt::Display for WithClause {
@@ -60677,6 +63410,12 @@ impl std::fmt::Display for WithClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScan {}
 impl pg_sys::PgNode for WorkTableScan {}
 impl std::fmt::Display for WorkTableScan {
@@ -60684,6 +63423,12 @@ impl std::fmt::Display for WorkTableScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScan"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScanState {}
 impl pg_sys::PgNode for WorkTableScanState {}
 impl std::fmt::Display for WorkTableScanState {
@@ -60691,6 +63436,12 @@ impl std::fmt::Display for WorkTableScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlExpr {}
 impl pg_sys::PgNode for XmlExpr {}
 impl std::fmt::Display for XmlExpr {
@@ -60698,6 +63449,12 @@ impl std::fmt::Display for XmlExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlSerialize {}
 impl pg_sys::PgNode for XmlSerialize {}
 impl std::fmt::Display for XmlSerialize {
@@ -60705,3 +63462,9 @@ impl std::fmt::Display for XmlSerialize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlSerialize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlSerialize"))
+    }
+}
@@ -7529,7 +7529,8 @@ pub const NodeTag_T_SupportRequestRows: NodeTag = 415;
 pub const NodeTag_T_SupportRequestIndexCondition: NodeTag = 416;
 pub type NodeTag = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Node {
     pub type_: NodeTag,
 }
@@ -7648,7 +7649,8 @@ pub const OnConflictAction_ONCONFLICT_NOTHING: OnConflictAction = 1;
 pub const OnConflictAction_ONCONFLICT_UPDATE: OnConflictAction = 2;
 pub type OnConflictAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct List {
     pub type_: NodeTag,
     pub length: ::std::os::raw::c_int,
@@ -8719,7 +8721,8 @@ pub struct BufferAccessStrategyData {
 }
 pub type BufferAccessStrategy = *mut BufferAccessStrategyData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TupleTableSlot {
     pub type_: NodeTag,
     pub tts_flags: uint16,
@@ -8784,7 +8787,8 @@ extern "C" {
     pub static TTSOpsBufferHeapTuple: TupleTableSlotOps;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VirtualTupleTableSlot {
     pub base: TupleTableSlot,
     pub data: *mut ::std::os::raw::c_char,
@@ -8799,7 +8803,8 @@ impl Default for VirtualTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HeapTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -8816,7 +8821,8 @@ impl Default for HeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BufferHeapTupleTableSlot {
     pub base: HeapTupleTableSlot,
     pub buffer: Buffer,
@@ -8831,7 +8837,8 @@ impl Default for BufferHeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinimalTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -9569,7 +9576,8 @@ pub const LockTupleMode_LockTupleNoKeyExclusive: LockTupleMode = 2;
 pub const LockTupleMode_LockTupleExclusive: LockTupleMode = 3;
 pub type LockTupleMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Alias {
     pub type_: NodeTag,
     pub aliasname: *mut ::std::os::raw::c_char,
@@ -9590,7 +9598,8 @@ pub const OnCommitAction_ONCOMMIT_DELETE_ROWS: OnCommitAction = 2;
 pub const OnCommitAction_ONCOMMIT_DROP: OnCommitAction = 3;
 pub type OnCommitAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeVar {
     pub type_: NodeTag,
     pub catalogname: *mut ::std::os::raw::c_char,
@@ -9611,7 +9620,8 @@ impl Default for RangeVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFunc {
     pub type_: NodeTag,
     pub ns_uris: *mut List,
@@ -9638,7 +9648,8 @@ impl Default for TableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IntoClause {
     pub type_: NodeTag,
     pub rel: *mut RangeVar,
@@ -9660,7 +9671,8 @@ impl Default for IntoClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Expr {
     pub type_: NodeTag,
 }
@@ -9674,7 +9686,8 @@ impl Default for Expr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Var {
     pub xpr: Expr,
     pub varno: Index,
@@ -9697,7 +9710,8 @@ impl Default for Var {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Const {
     pub xpr: Expr,
     pub consttype: Oid,
@@ -9724,7 +9738,8 @@ pub const ParamKind_PARAM_SUBLINK: ParamKind = 2;
 pub const ParamKind_PARAM_MULTIEXPR: ParamKind = 3;
 pub type ParamKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Param {
     pub xpr: Expr,
     pub paramkind: ParamKind,
@@ -9744,7 +9759,8 @@ impl Default for Param {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Aggref {
     pub xpr: Expr,
     pub aggfnoid: Oid,
@@ -9775,7 +9791,8 @@ impl Default for Aggref {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingFunc {
     pub xpr: Expr,
     pub args: *mut List,
@@ -9794,7 +9811,8 @@ impl Default for GroupingFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFunc {
     pub xpr: Expr,
     pub winfnoid: Oid,
@@ -9818,7 +9836,8 @@ impl Default for WindowFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubscriptingRef {
     pub xpr: Expr,
     pub refcontainertype: Oid,
@@ -9848,7 +9867,8 @@ pub const CoercionForm_COERCE_EXPLICIT_CAST: CoercionForm = 1;
 pub const CoercionForm_COERCE_IMPLICIT_CAST: CoercionForm = 2;
 pub type CoercionForm = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncExpr {
     pub xpr: Expr,
     pub funcid: Oid,
@@ -9871,7 +9891,8 @@ impl Default for FuncExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedArgExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9889,7 +9910,8 @@ impl Default for NamedArgExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -9913,7 +9935,8 @@ impl Default for OpExpr {
 pub type DistinctExpr = OpExpr;
 pub type NullIfExpr = OpExpr;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScalarArrayOpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -9937,7 +9960,8 @@ pub const BoolExprType_OR_EXPR: BoolExprType = 1;
 pub const BoolExprType_NOT_EXPR: BoolExprType = 2;
 pub type BoolExprType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BoolExpr {
     pub xpr: Expr,
     pub boolop: BoolExprType,
@@ -9963,7 +9987,8 @@ pub const SubLinkType_ARRAY_SUBLINK: SubLinkType = 6;
 pub const SubLinkType_CTE_SUBLINK: SubLinkType = 7;
 pub type SubLinkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubLink {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -9983,7 +10008,8 @@ impl Default for SubLink {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlan {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10014,7 +10040,8 @@ impl Default for SubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlan {
     pub xpr: Expr,
     pub subplans: *mut List,
@@ -10029,7 +10056,8 @@ impl Default for AlternativeSubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldSelect {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10048,7 +10076,8 @@ impl Default for FieldSelect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldStore {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10066,7 +10095,8 @@ impl Default for FieldStore {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelabelType {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10086,7 +10116,8 @@ impl Default for RelabelType {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceViaIO {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10105,7 +10136,8 @@ impl Default for CoerceViaIO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayCoerceExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10126,7 +10158,8 @@ impl Default for ArrayCoerceExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConvertRowtypeExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10144,7 +10177,8 @@ impl Default for ConvertRowtypeExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10161,7 +10195,8 @@ impl Default for CollateExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseExpr {
     pub xpr: Expr,
     pub casetype: Oid,
@@ -10181,7 +10216,8 @@ impl Default for CaseExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseWhen {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10198,7 +10234,8 @@ impl Default for CaseWhen {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseTestExpr {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10215,7 +10252,8 @@ impl Default for CaseTestExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayExpr {
     pub xpr: Expr,
     pub array_typeid: Oid,
@@ -10235,7 +10273,8 @@ impl Default for ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowExpr {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10261,7 +10300,8 @@ pub const RowCompareType_ROWCOMPARE_GT: RowCompareType = 5;
 pub const RowCompareType_ROWCOMPARE_NE: RowCompareType = 6;
 pub type RowCompareType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowCompareExpr {
     pub xpr: Expr,
     pub rctype: RowCompareType,
@@ -10281,7 +10321,8 @@ impl Default for RowCompareExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoalesceExpr {
     pub xpr: Expr,
     pub coalescetype: Oid,
@@ -10302,7 +10343,8 @@ pub const MinMaxOp_IS_GREATEST: MinMaxOp = 0;
 pub const MinMaxOp_IS_LEAST: MinMaxOp = 1;
 pub type MinMaxOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxExpr {
     pub xpr: Expr,
     pub minmaxtype: Oid,
@@ -10338,7 +10380,8 @@ pub const SQLValueFunctionOp_SVFOP_CURRENT_CATALOG: SQLValueFunctionOp = 13;
 pub const SQLValueFunctionOp_SVFOP_CURRENT_SCHEMA: SQLValueFunctionOp = 14;
 pub type SQLValueFunctionOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLValueFunction {
     pub xpr: Expr,
     pub op: SQLValueFunctionOp,
@@ -10368,7 +10411,8 @@ pub const XmlOptionType_XMLOPTION_DOCUMENT: XmlOptionType = 0;
 pub const XmlOptionType_XMLOPTION_CONTENT: XmlOptionType = 1;
 pub type XmlOptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlExpr {
     pub xpr: Expr,
     pub op: XmlExprOp,
@@ -10394,7 +10438,8 @@ pub const NullTestType_IS_NULL: NullTestType = 0;
 pub const NullTestType_IS_NOT_NULL: NullTestType = 1;
 pub type NullTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NullTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10419,7 +10464,8 @@ pub const BoolTestType_IS_UNKNOWN: BoolTestType = 4;
 pub const BoolTestType_IS_NOT_UNKNOWN: BoolTestType = 5;
 pub type BoolTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BooleanTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10436,7 +10482,8 @@ impl Default for BooleanTest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomain {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10456,7 +10503,8 @@ impl Default for CoerceToDomain {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomainValue {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10474,7 +10522,8 @@ impl Default for CoerceToDomainValue {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetToDefault {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10492,7 +10541,8 @@ impl Default for SetToDefault {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CurrentOfExpr {
     pub xpr: Expr,
     pub cvarno: Index,
@@ -10509,7 +10559,8 @@ impl Default for CurrentOfExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NextValueExpr {
     pub xpr: Expr,
     pub seqid: Oid,
@@ -10525,7 +10576,8 @@ impl Default for NextValueExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferenceElem {
     pub xpr: Expr,
     pub expr: *mut Node,
@@ -10542,7 +10594,8 @@ impl Default for InferenceElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TargetEntry {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10563,7 +10616,8 @@ impl Default for TargetEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblRef {
     pub type_: NodeTag,
     pub rtindex: ::std::os::raw::c_int,
@@ -10578,7 +10632,8 @@ impl Default for RangeTblRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinExpr {
     pub type_: NodeTag,
     pub jointype: JoinType,
@@ -10600,7 +10655,8 @@ impl Default for JoinExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FromExpr {
     pub type_: NodeTag,
     pub fromlist: *mut List,
@@ -10616,7 +10672,8 @@ impl Default for FromExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictExpr {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -10638,7 +10695,8 @@ impl Default for OnConflictExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannedStmt {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -10674,7 +10732,8 @@ impl Default for PlannedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Plan {
     pub type_: NodeTag,
     pub startup_cost: Cost,
@@ -10702,7 +10761,8 @@ impl Default for Plan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Result {
     pub plan: Plan,
     pub resconstantqual: *mut Node,
@@ -10717,7 +10777,8 @@ impl Default for Result {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSet {
     pub plan: Plan,
 }
@@ -10731,7 +10792,8 @@ impl Default for ProjectSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTable {
     pub plan: Plan,
     pub operation: CmdType,
@@ -10766,7 +10828,8 @@ impl Default for ModifyTable {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Append {
     pub plan: Plan,
     pub appendplans: *mut List,
@@ -10783,7 +10846,8 @@ impl Default for Append {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppend {
     pub plan: Plan,
     pub mergeplans: *mut List,
@@ -10804,7 +10868,8 @@ impl Default for MergeAppend {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnion {
     pub plan: Plan,
     pub wtParam: ::std::os::raw::c_int,
@@ -10824,7 +10889,8 @@ impl Default for RecursiveUnion {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAnd {
     pub plan: Plan,
     pub bitmapplans: *mut List,
@@ -10839,7 +10905,8 @@ impl Default for BitmapAnd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOr {
     pub plan: Plan,
     pub isshared: bool,
@@ -10855,7 +10922,8 @@ impl Default for BitmapOr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Scan {
     pub plan: Plan,
     pub scanrelid: Index,
@@ -10871,7 +10939,8 @@ impl Default for Scan {
 }
 pub type SeqScan = Scan;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScan {
     pub scan: Scan,
     pub tablesample: *mut TableSampleClause,
@@ -10886,7 +10955,8 @@ impl Default for SampleScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -10907,7 +10977,8 @@ impl Default for IndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -10927,7 +10998,8 @@ impl Default for IndexOnlyScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -10945,7 +11017,8 @@ impl Default for BitmapIndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScan {
     pub scan: Scan,
     pub bitmapqualorig: *mut List,
@@ -10960,7 +11033,8 @@ impl Default for BitmapHeapScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScan {
     pub scan: Scan,
     pub tidquals: *mut List,
@@ -10975,7 +11049,8 @@ impl Default for TidScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScan {
     pub scan: Scan,
     pub subplan: *mut Plan,
@@ -10990,7 +11065,8 @@ impl Default for SubqueryScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScan {
     pub scan: Scan,
     pub functions: *mut List,
@@ -11006,7 +11082,8 @@ impl Default for FunctionScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScan {
     pub scan: Scan,
     pub values_lists: *mut List,
@@ -11021,7 +11098,8 @@ impl Default for ValuesScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScan {
     pub scan: Scan,
     pub tablefunc: *mut TableFunc,
@@ -11036,7 +11114,8 @@ impl Default for TableFuncScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScan {
     pub scan: Scan,
     pub ctePlanId: ::std::os::raw::c_int,
@@ -11052,7 +11131,8 @@ impl Default for CteScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScan {
     pub scan: Scan,
     pub enrname: *mut ::std::os::raw::c_char,
@@ -11067,7 +11147,8 @@ impl Default for NamedTuplestoreScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScan {
     pub scan: Scan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11082,7 +11163,8 @@ impl Default for WorkTableScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScan {
     pub scan: Scan,
     pub operation: CmdType,
@@ -11104,7 +11186,8 @@ impl Default for ForeignScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScan {
     pub scan: Scan,
     pub flags: uint32,
@@ -11125,7 +11208,8 @@ impl Default for CustomScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Join {
     pub plan: Plan,
     pub jointype: JoinType,
@@ -11142,7 +11226,8 @@ impl Default for Join {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoop {
     pub join: Join,
     pub nestParams: *mut List,
@@ -11157,7 +11242,8 @@ impl Default for NestLoop {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopParam {
     pub type_: NodeTag,
     pub paramno: ::std::os::raw::c_int,
@@ -11173,7 +11259,8 @@ impl Default for NestLoopParam {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoin {
     pub join: Join,
     pub skip_mark_restore: bool,
@@ -11193,7 +11280,8 @@ impl Default for MergeJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoin {
     pub join: Join,
     pub hashclauses: *mut List,
@@ -11211,7 +11299,8 @@ impl Default for HashJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Material {
     pub plan: Plan,
 }
@@ -11225,7 +11314,8 @@ impl Default for Material {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Sort {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11244,7 +11334,8 @@ impl Default for Sort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Group {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11262,7 +11353,8 @@ impl Default for Group {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Agg {
     pub plan: Plan,
     pub aggstrategy: AggStrategy,
@@ -11286,7 +11378,8 @@ impl Default for Agg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAgg {
     pub plan: Plan,
     pub winref: Index,
@@ -11317,7 +11410,8 @@ impl Default for WindowAgg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Unique {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11335,7 +11429,8 @@ impl Default for Unique {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Gather {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11354,7 +11449,8 @@ impl Default for Gather {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMerge {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11376,7 +11472,8 @@ impl Default for GatherMerge {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Hash {
     pub plan: Plan,
     pub hashkeys: *mut List,
@@ -11395,7 +11492,8 @@ impl Default for Hash {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOp {
     pub plan: Plan,
     pub cmd: SetOpCmd,
@@ -11418,7 +11516,8 @@ impl Default for SetOp {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRows {
     pub plan: Plan,
     pub rowMarks: *mut List,
@@ -11434,7 +11533,8 @@ impl Default for LockRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Limit {
     pub plan: Plan,
     pub limitOffset: *mut Node,
@@ -11457,7 +11557,8 @@ pub const RowMarkType_ROW_MARK_REFERENCE: RowMarkType = 4;
 pub const RowMarkType_ROW_MARK_COPY: RowMarkType = 5;
 pub type RowMarkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanRowMark {
     pub type_: NodeTag,
     pub rti: Index,
@@ -11479,7 +11580,8 @@ impl Default for PlanRowMark {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneInfo {
     pub type_: NodeTag,
     pub prune_infos: *mut List,
@@ -11495,7 +11597,8 @@ impl Default for PartitionPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionedRelPruneInfo {
     pub type_: NodeTag,
     pub rtindex: Index,
@@ -11518,7 +11621,8 @@ impl Default for PartitionedRelPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStep {
     pub type_: NodeTag,
     pub step_id: ::std::os::raw::c_int,
@@ -11533,7 +11637,8 @@ impl Default for PartitionPruneStep {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepOp {
     pub step: PartitionPruneStep,
     pub opstrategy: StrategyNumber,
@@ -11554,7 +11659,8 @@ pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_UNION: PartitionPruneCombine
 pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_INTERSECT: PartitionPruneCombineOp = 1;
 pub type PartitionPruneCombineOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepCombine {
     pub step: PartitionPruneStep,
     pub combineOp: PartitionPruneCombineOp,
@@ -11570,7 +11676,8 @@ impl Default for PartitionPruneStepCombine {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanInvalItem {
     pub type_: NodeTag,
     pub cacheId: ::std::os::raw::c_int,
@@ -13533,7 +13640,8 @@ pub type ExprStateEvalFunc = ::std::option::Option<
     ) -> Datum,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprState {
     pub tag: Node,
     pub flags: uint8,
@@ -13563,7 +13671,8 @@ impl Default for ExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexInfo {
     pub type_: NodeTag,
     pub ii_NumIndexAttrs: ::std::os::raw::c_int,
@@ -13615,7 +13724,8 @@ impl Default for ExprContext_CB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprContext {
     pub type_: NodeTag,
     pub ecxt_scantuple: *mut TupleTableSlot,
@@ -13653,7 +13763,8 @@ pub const SetFunctionReturnMode_SFRM_Materialize_Random: SetFunctionReturnMode =
 pub const SetFunctionReturnMode_SFRM_Materialize_Preferred: SetFunctionReturnMode = 8;
 pub type SetFunctionReturnMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnSetInfo {
     pub type_: NodeTag,
     pub econtext: *mut ExprContext,
@@ -13674,7 +13785,8 @@ impl Default for ReturnSetInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionInfo {
     pub type_: NodeTag,
     pub pi_state: ExprState,
@@ -13690,7 +13802,8 @@ impl Default for ProjectionInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JunkFilter {
     pub type_: NodeTag,
     pub jf_targetList: *mut List,
@@ -13709,7 +13822,8 @@ impl Default for JunkFilter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictSetState {
     pub type_: NodeTag,
     pub oc_Existing: *mut TupleTableSlot,
@@ -13727,7 +13841,8 @@ impl Default for OnConflictSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultRelInfo {
     pub type_: NodeTag,
     pub ri_RangeTableIndex: Index,
@@ -13770,7 +13885,8 @@ impl Default for ResultRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EState {
     pub type_: NodeTag,
     pub es_direction: ScanDirection,
@@ -14001,7 +14117,8 @@ impl Default for TupleHashTableData {
 }
 pub type TupleHashIterator = tuplehash_iterator;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggrefExprState {
     pub type_: NodeTag,
     pub aggref: *mut Aggref,
@@ -14017,7 +14134,8 @@ impl Default for AggrefExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFuncExprState {
     pub type_: NodeTag,
     pub wfunc: *mut WindowFunc,
@@ -14035,7 +14153,8 @@ impl Default for WindowFuncExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetExprState {
     pub type_: NodeTag,
     pub expr: *mut Expr,
@@ -14061,7 +14180,8 @@ impl Default for SetExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut SubPlan,
@@ -14101,7 +14221,8 @@ impl Default for SubPlanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut AlternativeSubPlan,
@@ -14121,7 +14242,8 @@ pub const DomainConstraintType_DOM_CONSTRAINT_NOTNULL: DomainConstraintType = 0;
 pub const DomainConstraintType_DOM_CONSTRAINT_CHECK: DomainConstraintType = 1;
 pub type DomainConstraintType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DomainConstraintState {
     pub type_: NodeTag,
     pub constrainttype: DomainConstraintType,
@@ -14141,7 +14263,8 @@ impl Default for DomainConstraintState {
 pub type ExecProcNodeMtd =
     ::std::option::Option<unsafe extern "C" fn(pstate: *mut PlanState) -> *mut TupleTableSlot>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanState {
     pub type_: NodeTag,
     pub plan: *mut Plan,
@@ -14209,7 +14332,8 @@ impl Default for EPQState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultState {
     pub ps: PlanState,
     pub resconstantqual: *mut ExprState,
@@ -14226,7 +14350,8 @@ impl Default for ResultState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetState {
     pub ps: PlanState,
     pub elems: *mut *mut Node,
@@ -14245,7 +14370,8 @@ impl Default for ProjectSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTableState {
     pub ps: PlanState,
     pub operation: CmdType,
@@ -14287,7 +14413,8 @@ pub struct PartitionPruneState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendState {
     pub ps: PlanState,
     pub appendplans: *mut *mut PlanState,
@@ -14311,7 +14438,8 @@ impl Default for AppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendState {
     pub ps: PlanState,
     pub mergeplans: *mut *mut PlanState,
@@ -14335,7 +14463,8 @@ impl Default for MergeAppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionState {
     pub ps: PlanState,
     pub recursing: bool,
@@ -14358,7 +14487,8 @@ impl Default for RecursiveUnionState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -14374,7 +14504,8 @@ impl Default for BitmapAndState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -14390,7 +14521,8 @@ impl Default for BitmapOrState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScanState {
     pub ps: PlanState,
     pub ss_currentRelation: Relation,
@@ -14407,7 +14539,8 @@ impl Default for ScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SeqScanState {
     pub ss: ScanState,
     pub pscan_len: Size,
@@ -14422,7 +14555,8 @@ impl Default for SeqScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScanState {
     pub ss: ScanState,
     pub args: *mut List,
@@ -14482,7 +14616,8 @@ impl Default for IndexArrayKeyInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScanState {
     pub ss: ScanState,
     pub indexqualorig: *mut ExprState,
@@ -14516,7 +14651,8 @@ impl Default for IndexScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScanState {
     pub ss: ScanState,
     pub recheckqual: *mut ExprState,
@@ -14544,7 +14680,8 @@ impl Default for IndexOnlyScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScanState {
     pub ss: ScanState,
     pub biss_result: *mut TIDBitmap,
@@ -14594,7 +14731,8 @@ impl Default for ParallelBitmapHeapState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScanState {
     pub ss: ScanState,
     pub bitmapqualorig: *mut ExprState,
@@ -14627,7 +14765,8 @@ impl Default for BitmapHeapScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScanState {
     pub ss: ScanState,
     pub tss_tidexprs: *mut List,
@@ -14647,7 +14786,8 @@ impl Default for TidScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanState {
     pub ss: ScanState,
     pub subplan: *mut PlanState,
@@ -14667,7 +14807,8 @@ pub struct FunctionScanPerFuncState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -14688,7 +14829,8 @@ impl Default for FunctionScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScanState {
     pub ss: ScanState,
     pub rowcontext: *mut ExprContext,
@@ -14707,7 +14849,8 @@ impl Default for ValuesScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScanState {
     pub ss: ScanState,
     pub docexpr: *mut ExprState,
@@ -14735,7 +14878,8 @@ impl Default for TableFuncScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -14755,7 +14899,8 @@ impl Default for CteScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScanState {
     pub ss: ScanState,
     pub readptr: ::std::os::raw::c_int,
@@ -14772,7 +14917,8 @@ impl Default for NamedTuplestoreScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScanState {
     pub ss: ScanState,
     pub rustate: *mut RecursiveUnionState,
@@ -14787,7 +14933,8 @@ impl Default for WorkTableScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScanState {
     pub ss: ScanState,
     pub fdw_recheck_quals: *mut ExprState,
@@ -14805,7 +14952,8 @@ impl Default for ForeignScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScanState {
     pub ss: ScanState,
     pub flags: uint32,
@@ -14823,7 +14971,8 @@ impl Default for CustomScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinState {
     pub ps: PlanState,
     pub jointype: JoinType,
@@ -14840,7 +14989,8 @@ impl Default for JoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopState {
     pub js: JoinState,
     pub nl_NeedNewOuter: bool,
@@ -14863,7 +15013,8 @@ pub struct MergeJoinClauseData {
 }
 pub type MergeJoinClause = *mut MergeJoinClauseData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoinState {
     pub js: JoinState,
     pub mj_NumClauses: ::std::os::raw::c_int,
@@ -14906,7 +15057,8 @@ pub struct HashJoinTableData {
 }
 pub type HashJoinTable = *mut HashJoinTableData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoinState {
     pub js: JoinState,
     pub hashclauses: *mut ExprState,
@@ -14937,7 +15089,8 @@ impl Default for HashJoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -14969,7 +15122,8 @@ impl Default for SharedSortInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortState {
     pub ss: ScanState,
     pub randomAccess: bool,
@@ -14992,7 +15146,8 @@ impl Default for SortState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupState {
     pub ss: ScanState,
     pub eqfunction: *mut ExprState,
@@ -15038,7 +15193,8 @@ pub struct AggStatePerHashData {
 }
 pub type AggStatePerHash = *mut AggStatePerHashData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggState {
     pub ss: ScanState,
     pub aggs: *mut List,
@@ -15099,7 +15255,8 @@ pub struct WindowStatePerAggData {
 }
 pub type WindowStatePerAgg = *mut WindowStatePerAggData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggState {
     pub ss: ScanState,
     pub funcs: *mut List,
@@ -15164,7 +15321,8 @@ impl Default for WindowAggState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniqueState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -15179,7 +15337,8 @@ impl Default for UniqueState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -15207,7 +15366,8 @@ pub struct GMReaderTupleBuffer {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergeState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -15250,7 +15410,8 @@ pub struct SharedHashInfo {
     pub hinstrument: __IncompleteArrayField<HashInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashState {
     pub ps: PlanState,
     pub hashtable: HashJoinTable,
@@ -15275,7 +15436,8 @@ pub struct SetOpStatePerGroupData {
 }
 pub type SetOpStatePerGroup = *mut SetOpStatePerGroupData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -15300,7 +15462,8 @@ impl Default for SetOpState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsState {
     pub ps: PlanState,
     pub lr_arowMarks: *mut List,
@@ -15324,7 +15487,8 @@ pub const LimitStateCond_LIMIT_WINDOWEND: LimitStateCond = 5;
 pub const LimitStateCond_LIMIT_WINDOWSTART: LimitStateCond = 6;
 pub type LimitStateCond = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitState {
     pub ps: PlanState,
     pub limitOffset: *mut ExprState,
@@ -15520,7 +15684,8 @@ pub type SortByNulls = ::std::os::raw::c_uint;
 pub type AclMode = uint32;
 #[doc = "\tQuery Tree"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Query {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -15571,7 +15736,8 @@ impl Default for Query {
 }
 #[doc = "\tSupporting data structures for Parse Trees\n\n\tMost of these node types appear in raw parsetrees output by the grammar,\n\tand get transformed to something else by the analyzer.  A few of them\n\tare used as-is in transformed querytrees."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeName {
     pub type_: NodeTag,
     pub names: *mut List,
@@ -15593,7 +15759,8 @@ impl Default for TypeName {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnRef {
     pub type_: NodeTag,
     pub fields: *mut List,
@@ -15609,7 +15776,8 @@ impl Default for ColumnRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamRef {
     pub type_: NodeTag,
     pub number: ::std::os::raw::c_int,
@@ -15642,7 +15810,8 @@ pub const A_Expr_Kind_AEXPR_NOT_BETWEEN_SYM: A_Expr_Kind = 14;
 pub const A_Expr_Kind_AEXPR_PAREN: A_Expr_Kind = 15;
 pub type A_Expr_Kind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Expr {
     pub type_: NodeTag,
     pub kind: A_Expr_Kind,
@@ -15677,7 +15846,8 @@ impl Default for A_Const {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeCast {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -15694,7 +15864,8 @@ impl Default for TypeCast {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateClause {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -15716,7 +15887,8 @@ pub const RoleSpecType_ROLESPEC_SESSION_USER: RoleSpecType = 2;
 pub const RoleSpecType_ROLESPEC_PUBLIC: RoleSpecType = 3;
 pub type RoleSpecType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RoleSpec {
     pub type_: NodeTag,
     pub roletype: RoleSpecType,
@@ -15733,7 +15905,8 @@ impl Default for RoleSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncCall {
     pub type_: NodeTag,
     pub funcname: *mut List,
@@ -15757,7 +15930,8 @@ impl Default for FuncCall {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Star {
     pub type_: NodeTag,
 }
@@ -15771,7 +15945,8 @@ impl Default for A_Star {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indices {
     pub type_: NodeTag,
     pub is_slice: bool,
@@ -15788,7 +15963,8 @@ impl Default for A_Indices {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indirection {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -15804,7 +15980,8 @@ impl Default for A_Indirection {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_ArrayExpr {
     pub type_: NodeTag,
     pub elements: *mut List,
@@ -15820,7 +15997,8 @@ impl Default for A_ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResTarget {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -15838,7 +16016,8 @@ impl Default for ResTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MultiAssignRef {
     pub type_: NodeTag,
     pub source: *mut Node,
@@ -15855,7 +16034,8 @@ impl Default for MultiAssignRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortBy {
     pub type_: NodeTag,
     pub node: *mut Node,
@@ -15874,7 +16054,8 @@ impl Default for SortBy {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowDef {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -15896,7 +16077,8 @@ impl Default for WindowDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeSubselect {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -15913,7 +16095,8 @@ impl Default for RangeSubselect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeFunction {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -15933,7 +16116,8 @@ impl Default for RangeFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFunc {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -15954,7 +16138,8 @@ impl Default for RangeTableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFuncCol {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -15975,7 +16160,8 @@ impl Default for RangeTableFuncCol {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableSample {
     pub type_: NodeTag,
     pub relation: *mut Node,
@@ -15994,7 +16180,8 @@ impl Default for RangeTableSample {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnDef {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -16025,7 +16212,8 @@ impl Default for ColumnDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableLikeClause {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16052,7 +16240,8 @@ pub const TableLikeOption_CREATE_TABLE_LIKE_STORAGE: TableLikeOption = 128;
 pub const TableLikeOption_CREATE_TABLE_LIKE_ALL: TableLikeOption = 2147483647;
 pub type TableLikeOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16078,7 +16267,8 @@ pub const DefElemAction_DEFELEM_ADD: DefElemAction = 2;
 pub const DefElemAction_DEFELEM_DROP: DefElemAction = 3;
 pub type DefElemAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefElem {
     pub type_: NodeTag,
     pub defnamespace: *mut ::std::os::raw::c_char,
@@ -16097,7 +16287,8 @@ impl Default for DefElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockingClause {
     pub type_: NodeTag,
     pub lockedRels: *mut List,
@@ -16114,7 +16305,8 @@ impl Default for LockingClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlSerialize {
     pub type_: NodeTag,
     pub xmloption: XmlOptionType,
@@ -16132,7 +16324,8 @@ impl Default for XmlSerialize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16151,7 +16344,8 @@ impl Default for PartitionElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionSpec {
     pub type_: NodeTag,
     pub strategy: *mut ::std::os::raw::c_char,
@@ -16168,7 +16362,8 @@ impl Default for PartitionSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionBoundSpec {
     pub type_: NodeTag,
     pub strategy: ::std::os::raw::c_char,
@@ -16194,7 +16389,8 @@ pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_VALUE: PartitionRangeDat
 pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_MAXVALUE: PartitionRangeDatumKind = 1;
 pub type PartitionRangeDatumKind = ::std::os::raw::c_int;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionRangeDatum {
     pub type_: NodeTag,
     pub kind: PartitionRangeDatumKind,
@@ -16211,7 +16407,8 @@ impl Default for PartitionRangeDatum {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionCmd {
     pub type_: NodeTag,
     pub name: *mut RangeVar,
@@ -16237,7 +16434,8 @@ pub const RTEKind_RTE_NAMEDTUPLESTORE: RTEKind = 7;
 pub const RTEKind_RTE_RESULT: RTEKind = 8;
 pub type RTEKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblEntry {
     pub type_: NodeTag,
     pub rtekind: RTEKind,
@@ -16284,7 +16482,8 @@ impl Default for RangeTblEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblFunction {
     pub type_: NodeTag,
     pub funcexpr: *mut Node,
@@ -16305,7 +16504,8 @@ impl Default for RangeTblFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableSampleClause {
     pub type_: NodeTag,
     pub tsmhandler: Oid,
@@ -16327,7 +16527,8 @@ pub const WCOKind_WCO_RLS_UPDATE_CHECK: WCOKind = 2;
 pub const WCOKind_WCO_RLS_CONFLICT_CHECK: WCOKind = 3;
 pub type WCOKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithCheckOption {
     pub type_: NodeTag,
     pub kind: WCOKind,
@@ -16346,7 +16547,8 @@ impl Default for WithCheckOption {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortGroupClause {
     pub type_: NodeTag,
     pub tleSortGroupRef: Index,
@@ -16371,7 +16573,8 @@ pub const GroupingSetKind_GROUPING_SET_CUBE: GroupingSetKind = 3;
 pub const GroupingSetKind_GROUPING_SET_SETS: GroupingSetKind = 4;
 pub type GroupingSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSet {
     pub type_: NodeTag,
     pub kind: GroupingSetKind,
@@ -16388,7 +16591,8 @@ impl Default for GroupingSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowClause {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16416,7 +16620,8 @@ impl Default for WindowClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowMarkClause {
     pub type_: NodeTag,
     pub rti: Index,
@@ -16434,7 +16639,8 @@ impl Default for RowMarkClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithClause {
     pub type_: NodeTag,
     pub ctes: *mut List,
@@ -16451,7 +16657,8 @@ impl Default for WithClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferClause {
     pub type_: NodeTag,
     pub indexElems: *mut List,
@@ -16469,7 +16676,8 @@ impl Default for InferClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictClause {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -16492,7 +16700,8 @@ pub const CTEMaterialize_CTEMaterializeAlways: CTEMaterialize = 1;
 pub const CTEMaterialize_CTEMaterializeNever: CTEMaterialize = 2;
 pub type CTEMaterialize = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommonTableExpr {
     pub type_: NodeTag,
     pub ctename: *mut ::std::os::raw::c_char,
@@ -16517,7 +16726,8 @@ impl Default for CommonTableExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerTransition {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16535,7 +16745,8 @@ impl Default for TriggerTransition {
 }
 #[doc = "\t\tRaw Grammar Output Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RawStmt {
     pub type_: NodeTag,
     pub stmt: *mut Node,
@@ -16553,7 +16764,8 @@ impl Default for RawStmt {
 }
 #[doc = "\t\tOptimizable Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InsertStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16574,7 +16786,8 @@ impl Default for InsertStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeleteStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16593,7 +16806,8 @@ impl Default for DeleteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpdateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16618,7 +16832,8 @@ pub const SetOperation_SETOP_INTERSECT: SetOperation = 2;
 pub const SetOperation_SETOP_EXCEPT: SetOperation = 3;
 pub type SetOperation = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SelectStmt {
     pub type_: NodeTag,
     pub distinctClause: *mut List,
@@ -16650,7 +16865,8 @@ impl Default for SelectStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOperationStmt {
     pub type_: NodeTag,
     pub op: SetOperation,
@@ -16724,7 +16940,8 @@ pub const ObjectType_OBJECT_VIEW: ObjectType = 49;
 #[doc = "\t\tOther Statements (no optimizations required)\n\n\t\tThese are not touched by parser/analyze.c except to put them into\n\t\tthe utilityStmt field of a Query.  This is eventually passed to\n\t\tProcessUtility (by-passing rewriting and planning).  Some of the\n\t\tstatements do need attention from parse analysis, and this is\n\t\tdone by routines in parser/parse_utilcmd.c after ProcessUtility\n\t\treceives the command for execution.\n\t\tDECLARE CURSOR, EXPLAIN, and CREATE TABLE AS are special cases:\n\t\tthey contain optimizable statements, which get processed normally\n\t\tby parser/analyze.c."]
 pub type ObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSchemaStmt {
     pub type_: NodeTag,
     pub schemaname: *mut ::std::os::raw::c_char,
@@ -16745,7 +16962,8 @@ pub const DropBehavior_DROP_RESTRICT: DropBehavior = 0;
 pub const DropBehavior_DROP_CASCADE: DropBehavior = 1;
 pub type DropBehavior = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16831,7 +17049,8 @@ pub const AlterTableType_AT_DropIdentity: AlterTableType = 65;
 pub const AlterTableType_AT_CookedColumnDefault: AlterTableType = 66;
 pub type AlterTableType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReplicaIdentityStmt {
     pub type_: NodeTag,
     pub identity_type: ::std::os::raw::c_char,
@@ -16847,7 +17066,8 @@ impl Default for ReplicaIdentityStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableCmd {
     pub type_: NodeTag,
     pub subtype: AlterTableType,
@@ -16869,7 +17089,8 @@ impl Default for AlterTableCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterCollationStmt {
     pub type_: NodeTag,
     pub collname: *mut List,
@@ -16884,7 +17105,8 @@ impl Default for AlterCollationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDomainStmt {
     pub type_: NodeTag,
     pub subtype: ::std::os::raw::c_char,
@@ -16908,7 +17130,8 @@ pub const GrantTargetType_ACL_TARGET_ALL_IN_SCHEMA: GrantTargetType = 1;
 pub const GrantTargetType_ACL_TARGET_DEFAULTS: GrantTargetType = 2;
 pub type GrantTargetType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantStmt {
     pub type_: NodeTag,
     pub is_grant: bool,
@@ -16930,7 +17153,8 @@ impl Default for GrantStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ObjectWithArgs {
     pub type_: NodeTag,
     pub objname: *mut List,
@@ -16947,7 +17171,8 @@ impl Default for ObjectWithArgs {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AccessPriv {
     pub type_: NodeTag,
     pub priv_name: *mut ::std::os::raw::c_char,
@@ -16963,7 +17188,8 @@ impl Default for AccessPriv {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantRoleStmt {
     pub type_: NodeTag,
     pub granted_roles: *mut List,
@@ -16983,7 +17209,8 @@ impl Default for GrantRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDefaultPrivilegesStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -16999,7 +17226,8 @@ impl Default for AlterDefaultPrivilegesStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CopyStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17028,7 +17256,8 @@ pub const VariableSetKind_VAR_RESET: VariableSetKind = 4;
 pub const VariableSetKind_VAR_RESET_ALL: VariableSetKind = 5;
 pub type VariableSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableSetStmt {
     pub type_: NodeTag,
     pub kind: VariableSetKind,
@@ -17046,7 +17275,8 @@ impl Default for VariableSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableShowStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17061,7 +17291,8 @@ impl Default for VariableShowStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17102,7 +17333,8 @@ pub const ConstrType_CONSTR_ATTR_DEFERRED: ConstrType = 12;
 pub const ConstrType_CONSTR_ATTR_IMMEDIATE: ConstrType = 13;
 pub type ConstrType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Constraint {
     pub type_: NodeTag,
     pub contype: ConstrType,
@@ -17144,7 +17376,8 @@ impl Default for Constraint {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17162,7 +17395,8 @@ impl Default for CreateTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17178,7 +17412,8 @@ impl Default for DropTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableSpaceOptionsStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17195,7 +17430,8 @@ impl Default for AlterTableSpaceOptionsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableMoveAllStmt {
     pub type_: NodeTag,
     pub orig_tablespacename: *mut ::std::os::raw::c_char,
@@ -17214,7 +17450,8 @@ impl Default for AlterTableMoveAllStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17231,7 +17468,8 @@ impl Default for CreateExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17247,7 +17485,8 @@ impl Default for AlterExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionContentsStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17265,7 +17504,8 @@ impl Default for AlterExtensionContentsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -17282,7 +17522,8 @@ impl Default for CreateFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -17299,7 +17540,8 @@ impl Default for AlterFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -17319,7 +17561,8 @@ impl Default for CreateForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -17337,7 +17580,8 @@ impl Default for AlterForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignTableStmt {
     pub base: CreateStmt,
     pub servername: *mut ::std::os::raw::c_char,
@@ -17353,7 +17597,8 @@ impl Default for CreateForeignTableStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -17371,7 +17616,8 @@ impl Default for CreateUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -17388,7 +17634,8 @@ impl Default for AlterUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -17409,7 +17656,8 @@ pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_LIMIT_TO: ImportForeignSchem
 pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_EXCEPT: ImportForeignSchemaType = 2;
 pub type ImportForeignSchemaType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ImportForeignSchemaStmt {
     pub type_: NodeTag,
     pub server_name: *mut ::std::os::raw::c_char,
@@ -17429,7 +17677,8 @@ impl Default for ImportForeignSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -17450,7 +17699,8 @@ impl Default for CreatePolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -17469,7 +17719,8 @@ impl Default for AlterPolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateAmStmt {
     pub type_: NodeTag,
     pub amname: *mut ::std::os::raw::c_char,
@@ -17486,7 +17737,8 @@ impl Default for CreateAmStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -17514,7 +17766,8 @@ impl Default for CreateTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -17532,7 +17785,8 @@ impl Default for CreateEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -17548,7 +17802,8 @@ impl Default for AlterEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePLangStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -17572,7 +17827,8 @@ pub const RoleStmtType_ROLESTMT_USER: RoleStmtType = 1;
 pub const RoleStmtType_ROLESTMT_GROUP: RoleStmtType = 2;
 pub type RoleStmtType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRoleStmt {
     pub type_: NodeTag,
     pub stmt_type: RoleStmtType,
@@ -17589,7 +17845,8 @@ impl Default for CreateRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -17606,7 +17863,8 @@ impl Default for AlterRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleSetStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -17623,7 +17881,8 @@ impl Default for AlterRoleSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropRoleStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -17639,7 +17898,8 @@ impl Default for DropRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -17658,7 +17918,8 @@ impl Default for CreateSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -17676,7 +17937,8 @@ impl Default for AlterSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefineStmt {
     pub type_: NodeTag,
     pub kind: ObjectType,
@@ -17697,7 +17959,8 @@ impl Default for DefineStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateDomainStmt {
     pub type_: NodeTag,
     pub domainname: *mut List,
@@ -17715,7 +17978,8 @@ impl Default for CreateDomainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassStmt {
     pub type_: NodeTag,
     pub opclassname: *mut List,
@@ -17735,7 +17999,8 @@ impl Default for CreateOpClassStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassItem {
     pub type_: NodeTag,
     pub itemtype: ::std::os::raw::c_int,
@@ -17755,7 +18020,8 @@ impl Default for CreateOpClassItem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -17771,7 +18037,8 @@ impl Default for CreateOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -17789,7 +18056,8 @@ impl Default for AlterOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropStmt {
     pub type_: NodeTag,
     pub objects: *mut List,
@@ -17808,7 +18076,8 @@ impl Default for DropStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TruncateStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -17825,7 +18094,8 @@ impl Default for TruncateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommentStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -17842,7 +18112,8 @@ impl Default for CommentStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SecLabelStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -17860,7 +18131,8 @@ impl Default for SecLabelStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeclareCursorStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -17877,7 +18149,8 @@ impl Default for DeclareCursorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClosePortalStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -17897,7 +18170,8 @@ pub const FetchDirection_FETCH_ABSOLUTE: FetchDirection = 2;
 pub const FetchDirection_FETCH_RELATIVE: FetchDirection = 3;
 pub type FetchDirection = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FetchStmt {
     pub type_: NodeTag,
     pub direction: FetchDirection,
@@ -17915,7 +18189,8 @@ impl Default for FetchStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexStmt {
     pub type_: NodeTag,
     pub idxname: *mut ::std::os::raw::c_char,
@@ -17950,7 +18225,8 @@ impl Default for IndexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -17970,7 +18246,8 @@ impl Default for CreateStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFunctionStmt {
     pub type_: NodeTag,
     pub is_procedure: bool,
@@ -17996,7 +18273,8 @@ pub const FunctionParameterMode_FUNC_PARAM_VARIADIC: FunctionParameterMode = 118
 pub const FunctionParameterMode_FUNC_PARAM_TABLE: FunctionParameterMode = 116;
 pub type FunctionParameterMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionParameter {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18014,7 +18292,8 @@ impl Default for FunctionParameter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFunctionStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -18031,7 +18310,8 @@ impl Default for AlterFunctionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DoStmt {
     pub type_: NodeTag,
     pub args: *mut List,
@@ -18046,7 +18326,8 @@ impl Default for DoStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InlineCodeBlock {
     pub type_: NodeTag,
     pub source_text: *mut ::std::os::raw::c_char,
@@ -18064,7 +18345,8 @@ impl Default for InlineCodeBlock {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallStmt {
     pub type_: NodeTag,
     pub funccall: *mut FuncCall,
@@ -18080,7 +18362,8 @@ impl Default for CallStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallContext {
     pub type_: NodeTag,
     pub atomic: bool,
@@ -18095,7 +18378,8 @@ impl Default for CallContext {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RenameStmt {
     pub type_: NodeTag,
     pub renameType: ObjectType,
@@ -18117,7 +18401,8 @@ impl Default for RenameStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectDependsStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18135,7 +18420,8 @@ impl Default for AlterObjectDependsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectSchemaStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18154,7 +18440,8 @@ impl Default for AlterObjectSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOwnerStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18172,7 +18459,8 @@ impl Default for AlterOwnerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOperatorStmt {
     pub type_: NodeTag,
     pub opername: *mut ObjectWithArgs,
@@ -18188,7 +18476,8 @@ impl Default for AlterOperatorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RuleStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18209,7 +18498,8 @@ impl Default for RuleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NotifyStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -18225,7 +18515,8 @@ impl Default for NotifyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ListenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -18240,7 +18531,8 @@ impl Default for ListenStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UnlistenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -18266,7 +18558,8 @@ pub const TransactionStmtKind_TRANS_STMT_COMMIT_PREPARED: TransactionStmtKind =
 pub const TransactionStmtKind_TRANS_STMT_ROLLBACK_PREPARED: TransactionStmtKind = 9;
 pub type TransactionStmtKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TransactionStmt {
     pub type_: NodeTag,
     pub kind: TransactionStmtKind,
@@ -18285,7 +18578,8 @@ impl Default for TransactionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CompositeTypeStmt {
     pub type_: NodeTag,
     pub typevar: *mut RangeVar,
@@ -18301,7 +18595,8 @@ impl Default for CompositeTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18317,7 +18612,8 @@ impl Default for CreateEnumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRangeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18333,7 +18629,8 @@ impl Default for CreateRangeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18357,7 +18654,8 @@ pub const ViewCheckOption_LOCAL_CHECK_OPTION: ViewCheckOption = 1;
 pub const ViewCheckOption_CASCADED_CHECK_OPTION: ViewCheckOption = 2;
 pub type ViewCheckOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ViewStmt {
     pub type_: NodeTag,
     pub view: *mut RangeVar,
@@ -18377,7 +18675,8 @@ impl Default for ViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LoadStmt {
     pub type_: NodeTag,
     pub filename: *mut ::std::os::raw::c_char,
@@ -18392,7 +18691,8 @@ impl Default for LoadStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatedbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18408,7 +18708,8 @@ impl Default for CreatedbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18424,7 +18725,8 @@ impl Default for AlterDatabaseStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseSetStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18440,7 +18742,8 @@ impl Default for AlterDatabaseSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropdbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -18456,7 +18759,8 @@ impl Default for DropdbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSystemStmt {
     pub type_: NodeTag,
     pub setstmt: *mut VariableSetStmt,
@@ -18474,7 +18778,8 @@ pub const ClusterOption_CLUOPT_RECHECK: ClusterOption = 1;
 pub const ClusterOption_CLUOPT_VERBOSE: ClusterOption = 2;
 pub type ClusterOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClusterStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18491,7 +18796,8 @@ impl Default for ClusterStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -18508,7 +18814,8 @@ impl Default for VacuumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumRelation {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18525,7 +18832,8 @@ impl Default for VacuumRelation {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExplainStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -18541,7 +18849,8 @@ impl Default for ExplainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableAsStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -18560,7 +18869,8 @@ impl Default for CreateTableAsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RefreshMatViewStmt {
     pub type_: NodeTag,
     pub concurrent: bool,
@@ -18577,7 +18887,8 @@ impl Default for RefreshMatViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CheckPointStmt {
     pub type_: NodeTag,
 }
@@ -18596,7 +18907,8 @@ pub const DiscardMode_DISCARD_SEQUENCES: DiscardMode = 2;
 pub const DiscardMode_DISCARD_TEMP: DiscardMode = 3;
 pub type DiscardMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DiscardStmt {
     pub type_: NodeTag,
     pub target: DiscardMode,
@@ -18611,7 +18923,8 @@ impl Default for DiscardStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -18628,7 +18941,8 @@ impl Default for LockStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConstraintsSetStmt {
     pub type_: NodeTag,
     pub constraints: *mut List,
@@ -18650,7 +18964,8 @@ pub const ReindexObjectType_REINDEX_OBJECT_SYSTEM: ReindexObjectType = 3;
 pub const ReindexObjectType_REINDEX_OBJECT_DATABASE: ReindexObjectType = 4;
 pub type ReindexObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReindexStmt {
     pub type_: NodeTag,
     pub kind: ReindexObjectType,
@@ -18669,7 +18984,8 @@ impl Default for ReindexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateConversionStmt {
     pub type_: NodeTag,
     pub conversion_name: *mut List,
@@ -18688,7 +19004,8 @@ impl Default for CreateConversionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateCastStmt {
     pub type_: NodeTag,
     pub sourcetype: *mut TypeName,
@@ -18707,7 +19024,8 @@ impl Default for CreateCastStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTransformStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -18726,7 +19044,8 @@ impl Default for CreateTransformStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PrepareStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18743,7 +19062,8 @@ impl Default for PrepareStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExecuteStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18759,7 +19079,8 @@ impl Default for ExecuteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeallocateStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18774,7 +19095,8 @@ impl Default for DeallocateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -18790,7 +19112,8 @@ impl Default for DropOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReassignOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -18806,7 +19129,8 @@ impl Default for ReassignOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSDictionaryStmt {
     pub type_: NodeTag,
     pub dictname: *mut List,
@@ -18828,7 +19152,8 @@ pub const AlterTSConfigType_ALTER_TSCONFIG_REPLACE_DICT_FOR_TOKEN: AlterTSConfig
 pub const AlterTSConfigType_ALTER_TSCONFIG_DROP_MAPPING: AlterTSConfigType = 4;
 pub type AlterTSConfigType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSConfigurationStmt {
     pub type_: NodeTag,
     pub kind: AlterTSConfigType,
@@ -18849,7 +19174,8 @@ impl Default for AlterTSConfigurationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -18867,7 +19193,8 @@ impl Default for CreatePublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -18886,7 +19213,8 @@ impl Default for AlterPublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -18910,7 +19238,8 @@ pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_REFRESH: AlterSubscriptionTyp
 pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_ENABLED: AlterSubscriptionType = 4;
 pub type AlterSubscriptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSubscriptionStmt {
     pub type_: NodeTag,
     pub kind: AlterSubscriptionType,
@@ -18929,7 +19258,8 @@ impl Default for AlterSubscriptionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -18993,7 +19323,8 @@ pub struct MemoryContextMethods {
     pub check: ::std::option::Option<unsafe extern "C" fn(context: MemoryContext)>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoryContextData {
     pub type_: NodeTag,
     pub isReset: bool,
@@ -24762,7 +25093,8 @@ pub type aminitparallelscan_function =
 pub type amparallelrescan_function =
     ::std::option::Option<unsafe extern "C" fn(scan: IndexScanDesc)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexAmRoutine {
     pub type_: NodeTag,
     pub amstrategies: uint16,
@@ -27388,7 +27720,8 @@ impl Default for RelationData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyCacheInfo {
     pub type_: NodeTag,
     pub conoid: Oid,
@@ -28416,7 +28749,8 @@ pub type IndexBuildCallback = ::std::option::Option<
     ),
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableAmRoutine {
     pub type_: NodeTag,
     pub slot_callbacks:
@@ -32751,7 +33085,8 @@ impl Default for CollectedCommand {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EventTriggerData {
     pub type_: NodeTag,
     pub event: *const ::std::os::raw::c_char,
@@ -33445,7 +33780,8 @@ extern "C" {
 }
 pub type TriggerEvent = uint32;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerData {
     pub type_: NodeTag,
     pub tg_event: TriggerEvent,
@@ -35402,7 +35738,8 @@ pub const InheritanceKind_INHKIND_INHERITED: InheritanceKind = 1;
 pub const InheritanceKind_INHKIND_PARTITIONED: InheritanceKind = 2;
 pub type InheritanceKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerGlobal {
     pub type_: NodeTag,
     pub boundParams: ParamListInfo,
@@ -35436,7 +35773,8 @@ impl Default for PlannerGlobal {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerInfo {
     pub type_: NodeTag,
     pub parse: *mut Query,
@@ -35538,7 +35876,8 @@ pub const RelOptKind_RELOPT_OTHER_UPPER_REL: RelOptKind = 5;
 pub const RelOptKind_RELOPT_DEADREL: RelOptKind = 6;
 pub type RelOptKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelOptInfo {
     pub type_: NodeTag,
     pub reloptkind: RelOptKind,
@@ -35607,7 +35946,8 @@ impl Default for RelOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOptInfo {
     pub type_: NodeTag,
     pub indexoid: Oid,
@@ -35655,7 +35995,8 @@ impl Default for IndexOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyOptInfo {
     pub type_: NodeTag,
     pub con_relid: Index,
@@ -35680,7 +36021,8 @@ impl Default for ForeignKeyOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatisticExtInfo {
     pub type_: NodeTag,
     pub statOid: Oid,
@@ -35698,7 +36040,8 @@ impl Default for StatisticExtInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceClass {
     pub type_: NodeTag,
     pub ec_opfamilies: *mut List,
@@ -35726,7 +36069,8 @@ impl Default for EquivalenceClass {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceMember {
     pub type_: NodeTag,
     pub em_expr: *mut Expr,
@@ -35746,7 +36090,8 @@ impl Default for EquivalenceMember {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathKey {
     pub type_: NodeTag,
     pub pk_eclass: *mut EquivalenceClass,
@@ -35764,7 +36109,8 @@ impl Default for PathKey {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathTarget {
     pub type_: NodeTag,
     pub exprs: *mut List,
@@ -35782,7 +36128,8 @@ impl Default for PathTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamPathInfo {
     pub type_: NodeTag,
     pub ppi_req_outer: Relids,
@@ -35799,7 +36146,8 @@ impl Default for ParamPathInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Path {
     pub type_: NodeTag,
     pub pathtype: NodeTag,
@@ -35824,7 +36172,8 @@ impl Default for Path {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexPath {
     pub path: Path,
     pub indexinfo: *mut IndexOptInfo,
@@ -35845,7 +36194,8 @@ impl Default for IndexPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexClause {
     pub type_: NodeTag,
     pub rinfo: *mut RestrictInfo,
@@ -35864,7 +36214,8 @@ impl Default for IndexClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapPath {
     pub path: Path,
     pub bitmapqual: *mut Path,
@@ -35879,7 +36230,8 @@ impl Default for BitmapHeapPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -35895,7 +36247,8 @@ impl Default for BitmapAndPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -35911,7 +36264,8 @@ impl Default for BitmapOrPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidPath {
     pub path: Path,
     pub tidquals: *mut List,
@@ -35926,7 +36280,8 @@ impl Default for TidPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -35941,7 +36296,8 @@ impl Default for SubqueryScanPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignPath {
     pub path: Path,
     pub fdw_outerpath: *mut Path,
@@ -35957,7 +36313,8 @@ impl Default for ForeignPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomPath {
     pub path: Path,
     pub flags: uint32,
@@ -35975,7 +36332,8 @@ impl Default for CustomPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendPath {
     pub path: Path,
     pub partitioned_rels: *mut List,
@@ -35997,7 +36355,8 @@ extern "C" {
     pub fn is_dummy_rel(rel: *mut RelOptInfo) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendPath {
     pub path: Path,
     pub partitioned_rels: *mut List,
@@ -36014,7 +36373,8 @@ impl Default for MergeAppendPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupResultPath {
     pub path: Path,
     pub quals: *mut List,
@@ -36029,7 +36389,8 @@ impl Default for GroupResultPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36048,7 +36409,8 @@ pub const UniquePathMethod_UNIQUE_PATH_HASH: UniquePathMethod = 1;
 pub const UniquePathMethod_UNIQUE_PATH_SORT: UniquePathMethod = 2;
 pub type UniquePathMethod = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36066,7 +36428,8 @@ impl Default for UniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36083,7 +36446,8 @@ impl Default for GatherPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36099,7 +36463,8 @@ impl Default for GatherMergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinPath {
     pub path: Path,
     pub jointype: JoinType,
@@ -36119,7 +36484,8 @@ impl Default for JoinPath {
 }
 pub type NestPath = JoinPath;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergePath {
     pub jpath: JoinPath,
     pub path_mergeclauses: *mut List,
@@ -36138,7 +36504,8 @@ impl Default for MergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashPath {
     pub jpath: JoinPath,
     pub path_hashclauses: *mut List,
@@ -36155,7 +36522,8 @@ impl Default for HashPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36171,7 +36539,8 @@ impl Default for ProjectionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36186,7 +36555,8 @@ impl Default for ProjectSetPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36201,7 +36571,8 @@ impl Default for SortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36218,7 +36589,8 @@ impl Default for GroupPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpperUniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36234,7 +36606,8 @@ impl Default for UpperUniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36254,7 +36627,8 @@ impl Default for AggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetData {
     pub type_: NodeTag,
     pub set: *mut List,
@@ -36270,7 +36644,8 @@ impl Default for GroupingSetData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RollupData {
     pub type_: NodeTag,
     pub groupClause: *mut List,
@@ -36290,7 +36665,8 @@ impl Default for RollupData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36308,7 +36684,8 @@ impl Default for GroupingSetsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggPath {
     pub path: Path,
     pub mmaggregates: *mut List,
@@ -36324,7 +36701,8 @@ impl Default for MinMaxAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36340,7 +36718,8 @@ impl Default for WindowAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36361,7 +36740,8 @@ impl Default for SetOpPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionPath {
     pub path: Path,
     pub leftpath: *mut Path,
@@ -36380,7 +36760,8 @@ impl Default for RecursiveUnionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36397,7 +36778,8 @@ impl Default for LockRowsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTablePath {
     pub path: Path,
     pub operation: CmdType,
@@ -36424,7 +36806,8 @@ impl Default for ModifyTablePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -36441,7 +36824,8 @@ impl Default for LimitPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RestrictInfo {
     pub type_: NodeTag,
     pub clause: *mut Expr,
@@ -36506,7 +36890,8 @@ impl Default for MergeScanSelCache {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderVar {
     pub xpr: Expr,
     pub phexpr: *mut Expr,
@@ -36524,7 +36909,8 @@ impl Default for PlaceHolderVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SpecialJoinInfo {
     pub type_: NodeTag,
     pub min_lefthand: Relids,
@@ -36549,7 +36935,8 @@ impl Default for SpecialJoinInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendRelInfo {
     pub type_: NodeTag,
     pub parent_relid: Index,
@@ -36569,7 +36956,8 @@ impl Default for AppendRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderInfo {
     pub type_: NodeTag,
     pub phid: Index,
@@ -36589,7 +36977,8 @@ impl Default for PlaceHolderInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggInfo {
     pub type_: NodeTag,
     pub aggfnoid: Oid,
@@ -36610,7 +36999,8 @@ impl Default for MinMaxAggInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerParamItem {
     pub type_: NodeTag,
     pub item: *mut Node,
@@ -36906,7 +37296,8 @@ pub type ReparameterizeForeignPathByChild_function = ::std::option::Option<
     ) -> *mut List,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FdwRoutine {
     pub type_: NodeTag,
     pub GetForeignRelSize: GetForeignRelSize_function,
@@ -37753,7 +38144,8 @@ extern "C" {
     ) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExtensibleNode {
     pub type_: NodeTag,
     pub extnodename: *const ::std::os::raw::c_char,
@@ -38363,7 +38755,8 @@ pub const ReplicationKind_REPLICATION_KIND_PHYSICAL: ReplicationKind = 0;
 pub const ReplicationKind_REPLICATION_KIND_LOGICAL: ReplicationKind = 1;
 pub type ReplicationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IdentifySystemCmd {
     pub type_: NodeTag,
 }
@@ -38377,7 +38770,8 @@ impl Default for IdentifySystemCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BaseBackupCmd {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -38392,7 +38786,8 @@ impl Default for BaseBackupCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -38411,7 +38806,8 @@ impl Default for CreateReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -38427,7 +38823,8 @@ impl Default for DropReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StartReplicationCmd {
     pub type_: NodeTag,
     pub kind: ReplicationKind,
@@ -38446,7 +38843,8 @@ impl Default for StartReplicationCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TimeLineHistoryCmd {
     pub type_: NodeTag,
     pub timeline: TimeLineID,
@@ -38461,7 +38859,8 @@ impl Default for TimeLineHistoryCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLCmd {
     pub type_: NodeTag,
 }
@@ -38475,7 +38874,8 @@ impl Default for SQLCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSimplify {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -38491,7 +38891,8 @@ impl Default for SupportRequestSimplify {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSelectivity {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -38514,7 +38915,8 @@ impl Default for SupportRequestSelectivity {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestCost {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -38533,7 +38935,8 @@ impl Default for SupportRequestCost {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestRows {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -38551,7 +38954,8 @@ impl Default for SupportRequestRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestIndexCondition {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -59317,6 +59721,12 @@ impl std::fmt::Display for A_ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Const {}
 impl pg_sys::PgNode for A_Const {}
 impl std::fmt::Display for A_Const {
@@ -59324,6 +59734,12 @@ impl std::fmt::Display for A_Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Const"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Expr {}
 impl pg_sys::PgNode for A_Expr {}
 impl std::fmt::Display for A_Expr {
@@ -59331,6 +59747,12 @@ impl std::fmt::Display for A_Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indices {}
 impl pg_sys::PgNode for A_Indices {}
 impl std::fmt::Display for A_Indices {
@@ -59338,6 +59760,12 @@ impl std::fmt::Display for A_Indices {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indices"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indirection {}
 impl pg_sys::PgNode for A_Indirection {}
 impl std::fmt::Display for A_Indirection {
@@ -59345,6 +59773,12 @@ impl std::fmt::Display for A_Indirection {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indirection"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Star {}
 impl pg_sys::PgNode for A_Star {}
 impl std::fmt::Display for A_Star {
@@ -59352,6 +59786,12 @@ impl std::fmt::Display for A_Star {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Star {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Star"))
+    }
+}
 impl pg_sys::seal::Sealed for AccessPriv {}
 impl pg_sys::PgNode for AccessPriv {}
 impl std::fmt::Display for AccessPriv {
@@ -59359,6 +59799,12 @@ impl std::fmt::Display for AccessPriv {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AccessPriv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AccessPriv"))
+    }
+}
 impl pg_sys::seal::Sealed for Agg {}
 impl pg_sys::PgNode for Agg {}
 impl std::fmt::Display for Agg {
@@ -59366,6 +59812,12 @@ impl std::fmt::Display for Agg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Agg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Agg"))
+    }
+}
 impl pg_sys::seal::Sealed for AggPath {}
 impl pg_sys::PgNode for AggPath {}
 impl std::fmt::Display for AggPath {
@@ -59373,6 +59825,12 @@ impl std::fmt::Display for AggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AggState {}
 impl pg_sys::PgNode for AggState {}
 impl std::fmt::Display for AggState {
@@ -59380,6 +59838,12 @@ impl std::fmt::Display for AggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggState"))
+    }
+}
 impl pg_sys::seal::Sealed for Aggref {}
 impl pg_sys::PgNode for Aggref {}
 impl std::fmt::Display for Aggref {
@@ -59387,6 +59851,12 @@ impl std::fmt::Display for Aggref {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Aggref {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Aggref"))
+    }
+}
 impl pg_sys::seal::Sealed for AggrefExprState {}
 impl pg_sys::PgNode for AggrefExprState {}
 impl std::fmt::Display for AggrefExprState {
@@ -59394,6 +59864,12 @@ impl std::fmt::Display for AggrefExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggrefExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggrefExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for Alias {}
 impl pg_sys::PgNode for Alias {}
 impl std::fmt::Display for Alias {
@@ -59401,6 +59877,12 @@ impl std::fmt::Display for Alias {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Alias"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterCollationStmt {}
 impl pg_sys::PgNode for AlterCollationStmt {}
 impl std::fmt::Display for AlterCollationStmt {
@@ -59408,6 +59890,12 @@ impl std::fmt::Display for AlterCollationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterCollationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterCollationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseSetStmt {}
 impl pg_sys::PgNode for AlterDatabaseSetStmt {}
 impl std::fmt::Display for AlterDatabaseSetStmt {
@@ -59415,6 +59903,12 @@ impl std::fmt::Display for AlterDatabaseSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseStmt {}
 impl pg_sys::PgNode for AlterDatabaseStmt {}
 impl std::fmt::Display for AlterDatabaseStmt {
@@ -59422,6 +59916,12 @@ impl std::fmt::Display for AlterDatabaseStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDefaultPrivilegesStmt {}
 impl pg_sys::PgNode for AlterDefaultPrivilegesStmt {}
 impl std::fmt::Display for AlterDefaultPrivilegesStmt {
@@ -59429,6 +59929,12 @@ impl std::fmt::Display for AlterDefaultPrivilegesStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDefaultPrivilegesStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDefaultPrivilegesStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDomainStmt {}
 impl pg_sys::PgNode for AlterDomainStmt {}
 impl std::fmt::Display for AlterDomainStmt {
@@ -59436,6 +59942,12 @@ impl std::fmt::Display for AlterDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEnumStmt {}
 impl pg_sys::PgNode for AlterEnumStmt {}
 impl std::fmt::Display for AlterEnumStmt {
@@ -59443,6 +59955,12 @@ impl std::fmt::Display for AlterEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEventTrigStmt {}
 impl pg_sys::PgNode for AlterEventTrigStmt {}
 impl std::fmt::Display for AlterEventTrigStmt {
@@ -59450,6 +59968,12 @@ impl std::fmt::Display for AlterEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionContentsStmt {}
 impl pg_sys::PgNode for AlterExtensionContentsStmt {}
 impl std::fmt::Display for AlterExtensionContentsStmt {
@@ -59457,6 +59981,12 @@ impl std::fmt::Display for AlterExtensionContentsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionContentsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionContentsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionStmt {}
 impl pg_sys::PgNode for AlterExtensionStmt {}
 impl std::fmt::Display for AlterExtensionStmt {
@@ -59464,6 +59994,12 @@ impl std::fmt::Display for AlterExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFdwStmt {}
 impl pg_sys::PgNode for AlterFdwStmt {}
 impl std::fmt::Display for AlterFdwStmt {
@@ -59471,6 +60007,12 @@ impl std::fmt::Display for AlterFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterForeignServerStmt {}
 impl pg_sys::PgNode for AlterForeignServerStmt {}
 impl std::fmt::Display for AlterForeignServerStmt {
@@ -59478,6 +60020,12 @@ impl std::fmt::Display for AlterForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFunctionStmt {}
 impl pg_sys::PgNode for AlterFunctionStmt {}
 impl std::fmt::Display for AlterFunctionStmt {
@@ -59485,6 +60033,12 @@ impl std::fmt::Display for AlterFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectDependsStmt {}
 impl pg_sys::PgNode for AlterObjectDependsStmt {}
 impl std::fmt::Display for AlterObjectDependsStmt {
@@ -59492,6 +60046,12 @@ impl std::fmt::Display for AlterObjectDependsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectDependsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectDependsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectSchemaStmt {}
 impl pg_sys::PgNode for AlterObjectSchemaStmt {}
 impl std::fmt::Display for AlterObjectSchemaStmt {
@@ -59499,6 +60059,12 @@ impl std::fmt::Display for AlterObjectSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOpFamilyStmt {}
 impl pg_sys::PgNode for AlterOpFamilyStmt {}
 impl std::fmt::Display for AlterOpFamilyStmt {
@@ -59506,6 +60072,12 @@ impl std::fmt::Display for AlterOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOperatorStmt {}
 impl pg_sys::PgNode for AlterOperatorStmt {}
 impl std::fmt::Display for AlterOperatorStmt {
@@ -59513,6 +60085,12 @@ impl std::fmt::Display for AlterOperatorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOperatorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOperatorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOwnerStmt {}
 impl pg_sys::PgNode for AlterOwnerStmt {}
 impl std::fmt::Display for AlterOwnerStmt {
@@ -59520,6 +60098,12 @@ impl std::fmt::Display for AlterOwnerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOwnerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOwnerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPolicyStmt {}
 impl pg_sys::PgNode for AlterPolicyStmt {}
 impl std::fmt::Display for AlterPolicyStmt {
@@ -59527,6 +60111,12 @@ impl std::fmt::Display for AlterPolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPublicationStmt {}
 impl pg_sys::PgNode for AlterPublicationStmt {}
 impl std::fmt::Display for AlterPublicationStmt {
@@ -59534,6 +60124,12 @@ impl std::fmt::Display for AlterPublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleSetStmt {}
 impl pg_sys::PgNode for AlterRoleSetStmt {}
 impl std::fmt::Display for AlterRoleSetStmt {
@@ -59541,6 +60137,12 @@ impl std::fmt::Display for AlterRoleSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleStmt {}
 impl pg_sys::PgNode for AlterRoleStmt {}
 impl std::fmt::Display for AlterRoleStmt {
@@ -59548,6 +60150,12 @@ impl std::fmt::Display for AlterRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSeqStmt {}
 impl pg_sys::PgNode for AlterSeqStmt {}
 impl std::fmt::Display for AlterSeqStmt {
@@ -59555,6 +60163,12 @@ impl std::fmt::Display for AlterSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSubscriptionStmt {}
 impl pg_sys::PgNode for AlterSubscriptionStmt {}
 impl std::fmt::Display for AlterSubscriptionStmt {
@@ -59562,6 +60176,12 @@ impl std::fmt::Display for AlterSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSystemStmt {}
 impl pg_sys::PgNode for AlterSystemStmt {}
 impl std::fmt::Display for AlterSystemStmt {
@@ -59569,6 +60189,12 @@ impl std::fmt::Display for AlterSystemStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSystemStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSystemStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSConfigurationStmt {}
 impl pg_sys::PgNode for AlterTSConfigurationStmt {}
 impl std::fmt::Display for AlterTSConfigurationStmt {
@@ -59576,6 +60202,12 @@ impl std::fmt::Display for AlterTSConfigurationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSConfigurationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSConfigurationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSDictionaryStmt {}
 impl pg_sys::PgNode for AlterTSDictionaryStmt {}
 impl std::fmt::Display for AlterTSDictionaryStmt {
@@ -59583,6 +60215,12 @@ impl std::fmt::Display for AlterTSDictionaryStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSDictionaryStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSDictionaryStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableCmd {}
 impl pg_sys::PgNode for AlterTableCmd {}
 impl std::fmt::Display for AlterTableCmd {
@@ -59590,6 +60228,12 @@ impl std::fmt::Display for AlterTableCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableMoveAllStmt {}
 impl pg_sys::PgNode for AlterTableMoveAllStmt {}
 impl std::fmt::Display for AlterTableMoveAllStmt {
@@ -59597,6 +60241,12 @@ impl std::fmt::Display for AlterTableMoveAllStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableMoveAllStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableMoveAllStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableSpaceOptionsStmt {}
 impl pg_sys::PgNode for AlterTableSpaceOptionsStmt {}
 impl std::fmt::Display for AlterTableSpaceOptionsStmt {
@@ -59604,6 +60254,12 @@ impl std::fmt::Display for AlterTableSpaceOptionsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableSpaceOptionsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableSpaceOptionsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableStmt {}
 impl pg_sys::PgNode for AlterTableStmt {}
 impl std::fmt::Display for AlterTableStmt {
@@ -59611,6 +60267,12 @@ impl std::fmt::Display for AlterTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterUserMappingStmt {}
 impl pg_sys::PgNode for AlterUserMappingStmt {}
 impl std::fmt::Display for AlterUserMappingStmt {
@@ -59618,6 +60280,12 @@ impl std::fmt::Display for AlterUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlan {}
 impl pg_sys::PgNode for AlternativeSubPlan {}
 impl std::fmt::Display for AlternativeSubPlan {
@@ -59625,6 +60293,12 @@ impl std::fmt::Display for AlternativeSubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlanState {}
 impl pg_sys::PgNode for AlternativeSubPlanState {}
 impl std::fmt::Display for AlternativeSubPlanState {
@@ -59632,6 +60306,12 @@ impl std::fmt::Display for AlternativeSubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Append {}
 impl pg_sys::PgNode for Append {}
 impl std::fmt::Display for Append {
@@ -59639,6 +60319,12 @@ impl std::fmt::Display for Append {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Append {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Append"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendPath {}
 impl pg_sys::PgNode for AppendPath {}
 impl std::fmt::Display for AppendPath {
@@ -59646,6 +60332,12 @@ impl std::fmt::Display for AppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendRelInfo {}
 impl pg_sys::PgNode for AppendRelInfo {}
 impl std::fmt::Display for AppendRelInfo {
@@ -59653,6 +60345,12 @@ impl std::fmt::Display for AppendRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendState {}
 impl pg_sys::PgNode for AppendState {}
 impl std::fmt::Display for AppendState {
@@ -59660,6 +60358,12 @@ impl std::fmt::Display for AppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayCoerceExpr {}
 impl pg_sys::PgNode for ArrayCoerceExpr {}
 impl std::fmt::Display for ArrayCoerceExpr {
@@ -59667,6 +60371,12 @@ impl std::fmt::Display for ArrayCoerceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayCoerceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayCoerceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayExpr {}
 impl pg_sys::PgNode for ArrayExpr {}
 impl std::fmt::Display for ArrayExpr {
@@ -59674,6 +60384,12 @@ impl std::fmt::Display for ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BaseBackupCmd {}
 impl pg_sys::PgNode for BaseBackupCmd {}
 impl std::fmt::Display for BaseBackupCmd {
@@ -59681,6 +60397,12 @@ impl std::fmt::Display for BaseBackupCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BaseBackupCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BaseBackupCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAnd {}
 impl pg_sys::PgNode for BitmapAnd {}
 impl std::fmt::Display for BitmapAnd {
@@ -59688,6 +60410,12 @@ impl std::fmt::Display for BitmapAnd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAnd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndPath {}
 impl pg_sys::PgNode for BitmapAndPath {}
 impl std::fmt::Display for BitmapAndPath {
@@ -59695,6 +60423,12 @@ impl std::fmt::Display for BitmapAndPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndState {}
 impl pg_sys::PgNode for BitmapAndState {}
 impl std::fmt::Display for BitmapAndState {
@@ -59702,6 +60436,12 @@ impl std::fmt::Display for BitmapAndState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapPath {}
 impl pg_sys::PgNode for BitmapHeapPath {}
 impl std::fmt::Display for BitmapHeapPath {
@@ -59709,6 +60449,12 @@ impl std::fmt::Display for BitmapHeapPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScan {}
 impl pg_sys::PgNode for BitmapHeapScan {}
 impl std::fmt::Display for BitmapHeapScan {
@@ -59716,6 +60462,12 @@ impl std::fmt::Display for BitmapHeapScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScanState {}
 impl pg_sys::PgNode for BitmapHeapScanState {}
 impl std::fmt::Display for BitmapHeapScanState {
@@ -59723,6 +60475,12 @@ impl std::fmt::Display for BitmapHeapScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScan {}
 impl pg_sys::PgNode for BitmapIndexScan {}
 impl std::fmt::Display for BitmapIndexScan {
@@ -59730,6 +60488,12 @@ impl std::fmt::Display for BitmapIndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScanState {}
 impl pg_sys::PgNode for BitmapIndexScanState {}
 impl std::fmt::Display for BitmapIndexScanState {
@@ -59737,6 +60501,12 @@ impl std::fmt::Display for BitmapIndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOr {}
 impl pg_sys::PgNode for BitmapOr {}
 impl std::fmt::Display for BitmapOr {
@@ -59744,6 +60514,12 @@ impl std::fmt::Display for BitmapOr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOr"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrPath {}
 impl pg_sys::PgNode for BitmapOrPath {}
 impl std::fmt::Display for BitmapOrPath {
@@ -59751,6 +60527,12 @@ impl std::fmt::Display for BitmapOrPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrState {}
 impl pg_sys::PgNode for BitmapOrState {}
 impl std::fmt::Display for BitmapOrState {
@@ -59758,6 +60540,12 @@ impl std::fmt::Display for BitmapOrState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrState"))
+    }
+}
 impl pg_sys::seal::Sealed for BoolExpr {}
 impl pg_sys::PgNode for BoolExpr {}
 impl std::fmt::Display for BoolExpr {
@@ -59765,6 +60553,12 @@ impl std::fmt::Display for BoolExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BoolExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BoolExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BooleanTest {}
 impl pg_sys::PgNode for BooleanTest {}
 impl std::fmt::Display for BooleanTest {
@@ -59772,6 +60566,12 @@ impl std::fmt::Display for BooleanTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BooleanTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BooleanTest"))
+    }
+}
 impl pg_sys::seal::Sealed for BufferHeapTupleTableSlot {}
 impl pg_sys::PgNode for BufferHeapTupleTableSlot {}
 impl std::fmt::Display for BufferHeapTupleTableSlot {
@@ -59779,6 +60579,12 @@ impl std::fmt::Display for BufferHeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BufferHeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BufferHeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for CallContext {}
 impl pg_sys::PgNode for CallContext {}
 impl std::fmt::Display for CallContext {
@@ -59786,6 +60592,12 @@ impl std::fmt::Display for CallContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallContext"))
+    }
+}
 impl pg_sys::seal::Sealed for CallStmt {}
 impl pg_sys::PgNode for CallStmt {}
 impl std::fmt::Display for CallStmt {
@@ -59793,6 +60605,12 @@ impl std::fmt::Display for CallStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseExpr {}
 impl pg_sys::PgNode for CaseExpr {}
 impl std::fmt::Display for CaseExpr {
@@ -59800,6 +60618,12 @@ impl std::fmt::Display for CaseExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseTestExpr {}
 impl pg_sys::PgNode for CaseTestExpr {}
 impl std::fmt::Display for CaseTestExpr {
@@ -59807,6 +60631,12 @@ impl std::fmt::Display for CaseTestExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseTestExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseTestExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseWhen {}
 impl pg_sys::PgNode for CaseWhen {}
 impl std::fmt::Display for CaseWhen {
@@ -59814,6 +60644,12 @@ impl std::fmt::Display for CaseWhen {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseWhen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseWhen"))
+    }
+}
 impl pg_sys::seal::Sealed for CheckPointStmt {}
 impl pg_sys::PgNode for CheckPointStmt {}
 impl std::fmt::Display for CheckPointStmt {
@@ -59821,6 +60657,12 @@ impl std::fmt::Display for CheckPointStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CheckPointStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CheckPointStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClosePortalStmt {}
 impl pg_sys::PgNode for ClosePortalStmt {}
 impl std::fmt::Display for ClosePortalStmt {
@@ -59828,6 +60670,12 @@ impl std::fmt::Display for ClosePortalStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClosePortalStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClosePortalStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClusterStmt {}
 impl pg_sys::PgNode for ClusterStmt {}
 impl std::fmt::Display for ClusterStmt {
@@ -59835,6 +60683,12 @@ impl std::fmt::Display for ClusterStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClusterStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClusterStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CoalesceExpr {}
 impl pg_sys::PgNode for CoalesceExpr {}
 impl std::fmt::Display for CoalesceExpr {
@@ -59842,6 +60696,12 @@ impl std::fmt::Display for CoalesceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoalesceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoalesceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomain {}
 impl pg_sys::PgNode for CoerceToDomain {}
 impl std::fmt::Display for CoerceToDomain {
@@ -59849,6 +60709,12 @@ impl std::fmt::Display for CoerceToDomain {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomain"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomainValue {}
 impl pg_sys::PgNode for CoerceToDomainValue {}
 impl std::fmt::Display for CoerceToDomainValue {
@@ -59856,6 +60722,12 @@ impl std::fmt::Display for CoerceToDomainValue {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomainValue"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceViaIO {}
 impl pg_sys::PgNode for CoerceViaIO {}
 impl std::fmt::Display for CoerceViaIO {
@@ -59863,6 +60735,12 @@ impl std::fmt::Display for CoerceViaIO {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceViaIO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceViaIO"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateClause {}
 impl pg_sys::PgNode for CollateClause {}
 impl std::fmt::Display for CollateClause {
@@ -59870,6 +60748,12 @@ impl std::fmt::Display for CollateClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateExpr {}
 impl pg_sys::PgNode for CollateExpr {}
 impl std::fmt::Display for CollateExpr {
@@ -59877,6 +60761,12 @@ impl std::fmt::Display for CollateExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnDef {}
 impl pg_sys::PgNode for ColumnDef {}
 impl std::fmt::Display for ColumnDef {
@@ -59884,6 +60774,12 @@ impl std::fmt::Display for ColumnDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnDef"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnRef {}
 impl pg_sys::PgNode for ColumnRef {}
 impl std::fmt::Display for ColumnRef {
@@ -59891,6 +60787,12 @@ impl std::fmt::Display for ColumnRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnRef"))
+    }
+}
 impl pg_sys::seal::Sealed for CommentStmt {}
 impl pg_sys::PgNode for CommentStmt {}
 impl std::fmt::Display for CommentStmt {
@@ -59898,6 +60800,12 @@ impl std::fmt::Display for CommentStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommentStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommentStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CommonTableExpr {}
 impl pg_sys::PgNode for CommonTableExpr {}
 impl std::fmt::Display for CommonTableExpr {
@@ -59905,6 +60813,12 @@ impl std::fmt::Display for CommonTableExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommonTableExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommonTableExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CompositeTypeStmt {}
 impl pg_sys::PgNode for CompositeTypeStmt {}
 impl std::fmt::Display for CompositeTypeStmt {
@@ -59912,6 +60826,12 @@ impl std::fmt::Display for CompositeTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CompositeTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CompositeTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Const {}
 impl pg_sys::PgNode for Const {}
 impl std::fmt::Display for Const {
@@ -59919,6 +60839,12 @@ impl std::fmt::Display for Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Const"))
+    }
+}
 impl pg_sys::seal::Sealed for Constraint {}
 impl pg_sys::PgNode for Constraint {}
 impl std::fmt::Display for Constraint {
@@ -59926,6 +60852,12 @@ impl std::fmt::Display for Constraint {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Constraint"))
+    }
+}
 impl pg_sys::seal::Sealed for ConstraintsSetStmt {}
 impl pg_sys::PgNode for ConstraintsSetStmt {}
 impl std::fmt::Display for ConstraintsSetStmt {
@@ -59933,6 +60865,12 @@ impl std::fmt::Display for ConstraintsSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConstraintsSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConstraintsSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ConvertRowtypeExpr {}
 impl pg_sys::PgNode for ConvertRowtypeExpr {}
 impl std::fmt::Display for ConvertRowtypeExpr {
@@ -59940,6 +60878,12 @@ impl std::fmt::Display for ConvertRowtypeExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConvertRowtypeExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConvertRowtypeExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CopyStmt {}
 impl pg_sys::PgNode for CopyStmt {}
 impl std::fmt::Display for CopyStmt {
@@ -59947,6 +60891,12 @@ impl std::fmt::Display for CopyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CopyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CopyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateAmStmt {}
 impl pg_sys::PgNode for CreateAmStmt {}
 impl std::fmt::Display for CreateAmStmt {
@@ -59954,6 +60904,12 @@ impl std::fmt::Display for CreateAmStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateAmStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateAmStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateCastStmt {}
 impl pg_sys::PgNode for CreateCastStmt {}
 impl std::fmt::Display for CreateCastStmt {
@@ -59961,6 +60917,12 @@ impl std::fmt::Display for CreateCastStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateCastStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateCastStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateConversionStmt {}
 impl pg_sys::PgNode for CreateConversionStmt {}
 impl std::fmt::Display for CreateConversionStmt {
@@ -59968,6 +60930,12 @@ impl std::fmt::Display for CreateConversionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateConversionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateConversionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateDomainStmt {}
 impl pg_sys::PgNode for CreateDomainStmt {}
 impl std::fmt::Display for CreateDomainStmt {
@@ -59975,6 +60943,12 @@ impl std::fmt::Display for CreateDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEnumStmt {}
 impl pg_sys::PgNode for CreateEnumStmt {}
 impl std::fmt::Display for CreateEnumStmt {
@@ -59982,6 +60956,12 @@ impl std::fmt::Display for CreateEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEventTrigStmt {}
 impl pg_sys::PgNode for CreateEventTrigStmt {}
 impl std::fmt::Display for CreateEventTrigStmt {
@@ -59989,6 +60969,12 @@ impl std::fmt::Display for CreateEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateExtensionStmt {}
 impl pg_sys::PgNode for CreateExtensionStmt {}
 impl std::fmt::Display for CreateExtensionStmt {
@@ -59996,6 +60982,12 @@ impl std::fmt::Display for CreateExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFdwStmt {}
 impl pg_sys::PgNode for CreateFdwStmt {}
 impl std::fmt::Display for CreateFdwStmt {
@@ -60003,6 +60995,12 @@ impl std::fmt::Display for CreateFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignServerStmt {}
 impl pg_sys::PgNode for CreateForeignServerStmt {}
 impl std::fmt::Display for CreateForeignServerStmt {
@@ -60010,6 +61008,12 @@ impl std::fmt::Display for CreateForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignTableStmt {}
 impl pg_sys::PgNode for CreateForeignTableStmt {}
 impl std::fmt::Display for CreateForeignTableStmt {
@@ -60017,6 +61021,12 @@ impl std::fmt::Display for CreateForeignTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFunctionStmt {}
 impl pg_sys::PgNode for CreateFunctionStmt {}
 impl std::fmt::Display for CreateFunctionStmt {
@@ -60024,6 +61034,12 @@ impl std::fmt::Display for CreateFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassItem {}
 impl pg_sys::PgNode for CreateOpClassItem {}
 impl std::fmt::Display for CreateOpClassItem {
@@ -60031,6 +61047,12 @@ impl std::fmt::Display for CreateOpClassItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassItem"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassStmt {}
 impl pg_sys::PgNode for CreateOpClassStmt {}
 impl std::fmt::Display for CreateOpClassStmt {
@@ -60038,6 +61060,12 @@ impl std::fmt::Display for CreateOpClassStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpFamilyStmt {}
 impl pg_sys::PgNode for CreateOpFamilyStmt {}
 impl std::fmt::Display for CreateOpFamilyStmt {
@@ -60045,6 +61073,12 @@ impl std::fmt::Display for CreateOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePLangStmt {}
 impl pg_sys::PgNode for CreatePLangStmt {}
 impl std::fmt::Display for CreatePLangStmt {
@@ -60052,6 +61086,12 @@ impl std::fmt::Display for CreatePLangStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePLangStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePLangStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePolicyStmt {}
 impl pg_sys::PgNode for CreatePolicyStmt {}
 impl std::fmt::Display for CreatePolicyStmt {
@@ -60059,6 +61099,12 @@ impl std::fmt::Display for CreatePolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePublicationStmt {}
 impl pg_sys::PgNode for CreatePublicationStmt {}
 impl std::fmt::Display for CreatePublicationStmt {
@@ -60066,6 +61112,12 @@ impl std::fmt::Display for CreatePublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRangeStmt {}
 impl pg_sys::PgNode for CreateRangeStmt {}
 impl std::fmt::Display for CreateRangeStmt {
@@ -60073,6 +61125,12 @@ impl std::fmt::Display for CreateRangeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRangeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRangeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateReplicationSlotCmd {}
 impl pg_sys::PgNode for CreateReplicationSlotCmd {}
 impl std::fmt::Display for CreateReplicationSlotCmd {
@@ -60080,6 +61138,12 @@ impl std::fmt::Display for CreateReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRoleStmt {}
 impl pg_sys::PgNode for CreateRoleStmt {}
 impl std::fmt::Display for CreateRoleStmt {
@@ -60087,6 +61151,12 @@ impl std::fmt::Display for CreateRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSchemaStmt {}
 impl pg_sys::PgNode for CreateSchemaStmt {}
 impl std::fmt::Display for CreateSchemaStmt {
@@ -60094,6 +61164,12 @@ impl std::fmt::Display for CreateSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSeqStmt {}
 impl pg_sys::PgNode for CreateSeqStmt {}
 impl std::fmt::Display for CreateSeqStmt {
@@ -60101,6 +61177,12 @@ impl std::fmt::Display for CreateSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStatsStmt {}
 impl pg_sys::PgNode for CreateStatsStmt {}
 impl std::fmt::Display for CreateStatsStmt {
@@ -60108,6 +61190,12 @@ impl std::fmt::Display for CreateStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStmt {}
 impl pg_sys::PgNode for CreateStmt {}
 impl std::fmt::Display for CreateStmt {
@@ -60115,6 +61203,12 @@ impl std::fmt::Display for CreateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSubscriptionStmt {}
 impl pg_sys::PgNode for CreateSubscriptionStmt {}
 impl std::fmt::Display for CreateSubscriptionStmt {
@@ -60122,6 +61216,12 @@ impl std::fmt::Display for CreateSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableAsStmt {}
 impl pg_sys::PgNode for CreateTableAsStmt {}
 impl std::fmt::Display for CreateTableAsStmt {
@@ -60129,6 +61229,12 @@ impl std::fmt::Display for CreateTableAsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableAsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableAsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableSpaceStmt {}
 impl pg_sys::PgNode for CreateTableSpaceStmt {}
 impl std::fmt::Display for CreateTableSpaceStmt {
@@ -60136,6 +61242,12 @@ impl std::fmt::Display for CreateTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTransformStmt {}
 impl pg_sys::PgNode for CreateTransformStmt {}
 impl std::fmt::Display for CreateTransformStmt {
@@ -60143,6 +61255,12 @@ impl std::fmt::Display for CreateTransformStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTransformStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTransformStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTrigStmt {}
 impl pg_sys::PgNode for CreateTrigStmt {}
 impl std::fmt::Display for CreateTrigStmt {
@@ -60150,6 +61268,12 @@ impl std::fmt::Display for CreateTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateUserMappingStmt {}
 impl pg_sys::PgNode for CreateUserMappingStmt {}
 impl std::fmt::Display for CreateUserMappingStmt {
@@ -60157,6 +61281,12 @@ impl std::fmt::Display for CreateUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatedbStmt {}
 impl pg_sys::PgNode for CreatedbStmt {}
 impl std::fmt::Display for CreatedbStmt {
@@ -60164,6 +61294,12 @@ impl std::fmt::Display for CreatedbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatedbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatedbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScan {}
 impl pg_sys::PgNode for CteScan {}
 impl std::fmt::Display for CteScan {
@@ -60171,6 +61307,12 @@ impl std::fmt::Display for CteScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScanState {}
 impl pg_sys::PgNode for CteScanState {}
 impl std::fmt::Display for CteScanState {
@@ -60178,6 +61320,12 @@ impl std::fmt::Display for CteScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for CurrentOfExpr {}
 impl pg_sys::PgNode for CurrentOfExpr {}
 impl std::fmt::Display for CurrentOfExpr {
@@ -60185,6 +61333,12 @@ impl std::fmt::Display for CurrentOfExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CurrentOfExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CurrentOfExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomPath {}
 impl pg_sys::PgNode for CustomPath {}
 impl std::fmt::Display for CustomPath {
@@ -60192,6 +61346,12 @@ impl std::fmt::Display for CustomPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomPath"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScan {}
 impl pg_sys::PgNode for CustomScan {}
 impl std::fmt::Display for CustomScan {
@@ -60199,6 +61359,12 @@ impl std::fmt::Display for CustomScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScanState {}
 impl pg_sys::PgNode for CustomScanState {}
 impl std::fmt::Display for CustomScanState {
@@ -60206,6 +61372,12 @@ impl std::fmt::Display for CustomScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for DeallocateStmt {}
 impl pg_sys::PgNode for DeallocateStmt {}
 impl std::fmt::Display for DeallocateStmt {
@@ -60213,6 +61385,12 @@ impl std::fmt::Display for DeallocateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeallocateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeallocateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeclareCursorStmt {}
 impl pg_sys::PgNode for DeclareCursorStmt {}
 impl std::fmt::Display for DeclareCursorStmt {
@@ -60220,6 +61398,12 @@ impl std::fmt::Display for DeclareCursorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeclareCursorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeclareCursorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DefElem {}
 impl pg_sys::PgNode for DefElem {}
 impl std::fmt::Display for DefElem {
@@ -60227,6 +61411,12 @@ impl std::fmt::Display for DefElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefElem"))
+    }
+}
 impl pg_sys::seal::Sealed for DefineStmt {}
 impl pg_sys::PgNode for DefineStmt {}
 impl std::fmt::Display for DefineStmt {
@@ -60234,6 +61424,12 @@ impl std::fmt::Display for DefineStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefineStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefineStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeleteStmt {}
 impl pg_sys::PgNode for DeleteStmt {}
 impl std::fmt::Display for DeleteStmt {
@@ -60241,6 +61437,12 @@ impl std::fmt::Display for DeleteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeleteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeleteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DiscardStmt {}
 impl pg_sys::PgNode for DiscardStmt {}
 impl std::fmt::Display for DiscardStmt {
@@ -60248,6 +61450,12 @@ impl std::fmt::Display for DiscardStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DiscardStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DiscardStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DoStmt {}
 impl pg_sys::PgNode for DoStmt {}
 impl std::fmt::Display for DoStmt {
@@ -60255,6 +61463,12 @@ impl std::fmt::Display for DoStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DoStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DoStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DomainConstraintState {}
 impl pg_sys::PgNode for DomainConstraintState {}
 impl std::fmt::Display for DomainConstraintState {
@@ -60262,6 +61476,12 @@ impl std::fmt::Display for DomainConstraintState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DomainConstraintState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DomainConstraintState"))
+    }
+}
 impl pg_sys::seal::Sealed for DropOwnedStmt {}
 impl pg_sys::PgNode for DropOwnedStmt {}
 impl std::fmt::Display for DropOwnedStmt {
@@ -60269,6 +61489,12 @@ impl std::fmt::Display for DropOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropReplicationSlotCmd {}
 impl pg_sys::PgNode for DropReplicationSlotCmd {}
 impl std::fmt::Display for DropReplicationSlotCmd {
@@ -60276,6 +61502,12 @@ impl std::fmt::Display for DropReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for DropRoleStmt {}
 impl pg_sys::PgNode for DropRoleStmt {}
 impl std::fmt::Display for DropRoleStmt {
@@ -60283,6 +61515,12 @@ impl std::fmt::Display for DropRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropStmt {}
 impl pg_sys::PgNode for DropStmt {}
 impl std::fmt::Display for DropStmt {
@@ -60290,6 +61528,12 @@ impl std::fmt::Display for DropStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropSubscriptionStmt {}
 impl pg_sys::PgNode for DropSubscriptionStmt {}
 impl std::fmt::Display for DropSubscriptionStmt {
@@ -60297,6 +61541,12 @@ impl std::fmt::Display for DropSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropTableSpaceStmt {}
 impl pg_sys::PgNode for DropTableSpaceStmt {}
 impl std::fmt::Display for DropTableSpaceStmt {
@@ -60304,6 +61554,12 @@ impl std::fmt::Display for DropTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropUserMappingStmt {}
 impl pg_sys::PgNode for DropUserMappingStmt {}
 impl std::fmt::Display for DropUserMappingStmt {
@@ -60311,6 +61567,12 @@ impl std::fmt::Display for DropUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropdbStmt {}
 impl pg_sys::PgNode for DropdbStmt {}
 impl std::fmt::Display for DropdbStmt {
@@ -60318,6 +61580,12 @@ impl std::fmt::Display for DropdbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropdbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropdbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for EState {}
 impl pg_sys::PgNode for EState {}
 impl std::fmt::Display for EState {
@@ -60325,6 +61593,12 @@ impl std::fmt::Display for EState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EState"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceClass {}
 impl pg_sys::PgNode for EquivalenceClass {}
 impl std::fmt::Display for EquivalenceClass {
@@ -60332,6 +61606,12 @@ impl std::fmt::Display for EquivalenceClass {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceClass"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceMember {}
 impl pg_sys::PgNode for EquivalenceMember {}
 impl std::fmt::Display for EquivalenceMember {
@@ -60339,6 +61619,12 @@ impl std::fmt::Display for EquivalenceMember {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceMember"))
+    }
+}
 impl pg_sys::seal::Sealed for EventTriggerData {}
 impl pg_sys::PgNode for EventTriggerData {}
 impl std::fmt::Display for EventTriggerData {
@@ -60346,6 +61632,12 @@ impl std::fmt::Display for EventTriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EventTriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EventTriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for ExecuteStmt {}
 impl pg_sys::PgNode for ExecuteStmt {}
 impl std::fmt::Display for ExecuteStmt {
@@ -60353,6 +61645,12 @@ impl std::fmt::Display for ExecuteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExecuteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExecuteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ExplainStmt {}
 impl pg_sys::PgNode for ExplainStmt {}
 impl std::fmt::Display for ExplainStmt {
@@ -60360,6 +61658,12 @@ impl std::fmt::Display for ExplainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExplainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExplainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Expr {}
 impl pg_sys::PgNode for Expr {}
 impl std::fmt::Display for Expr {
@@ -60367,6 +61671,12 @@ impl std::fmt::Display for Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprContext {}
 impl pg_sys::PgNode for ExprContext {}
 impl std::fmt::Display for ExprContext {
@@ -60374,6 +61684,12 @@ impl std::fmt::Display for ExprContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprContext"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprState {}
 impl pg_sys::PgNode for ExprState {}
 impl std::fmt::Display for ExprState {
@@ -60381,6 +61697,12 @@ impl std::fmt::Display for ExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for ExtensibleNode {}
 impl pg_sys::PgNode for ExtensibleNode {}
 impl std::fmt::Display for ExtensibleNode {
@@ -60388,6 +61710,12 @@ impl std::fmt::Display for ExtensibleNode {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExtensibleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExtensibleNode"))
+    }
+}
 impl pg_sys::seal::Sealed for FdwRoutine {}
 impl pg_sys::PgNode for FdwRoutine {}
 impl std::fmt::Display for FdwRoutine {
@@ -60395,6 +61723,12 @@ impl std::fmt::Display for FdwRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FdwRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FdwRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for FetchStmt {}
 impl pg_sys::PgNode for FetchStmt {}
 impl std::fmt::Display for FetchStmt {
@@ -60402,6 +61736,12 @@ impl std::fmt::Display for FetchStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FetchStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FetchStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldSelect {}
 impl pg_sys::PgNode for FieldSelect {}
 impl std::fmt::Display for FieldSelect {
@@ -60409,6 +61749,12 @@ impl std::fmt::Display for FieldSelect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldSelect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldSelect"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldStore {}
 impl pg_sys::PgNode for FieldStore {}
 impl std::fmt::Display for FieldStore {
@@ -60416,6 +61762,12 @@ impl std::fmt::Display for FieldStore {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldStore"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyCacheInfo {}
 impl pg_sys::PgNode for ForeignKeyCacheInfo {}
 impl std::fmt::Display for ForeignKeyCacheInfo {
@@ -60423,6 +61775,12 @@ impl std::fmt::Display for ForeignKeyCacheInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyCacheInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyCacheInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyOptInfo {}
 impl pg_sys::PgNode for ForeignKeyOptInfo {}
 impl std::fmt::Display for ForeignKeyOptInfo {
@@ -60430,6 +61788,12 @@ impl std::fmt::Display for ForeignKeyOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignPath {}
 impl pg_sys::PgNode for ForeignPath {}
 impl std::fmt::Display for ForeignPath {
@@ -60437,6 +61801,12 @@ impl std::fmt::Display for ForeignPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScan {}
 impl pg_sys::PgNode for ForeignScan {}
 impl std::fmt::Display for ForeignScan {
@@ -60444,6 +61814,12 @@ impl std::fmt::Display for ForeignScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScanState {}
 impl pg_sys::PgNode for ForeignScanState {}
 impl std::fmt::Display for ForeignScanState {
@@ -60451,6 +61827,12 @@ impl std::fmt::Display for ForeignScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for FromExpr {}
 impl pg_sys::PgNode for FromExpr {}
 impl std::fmt::Display for FromExpr {
@@ -60458,6 +61840,12 @@ impl std::fmt::Display for FromExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FromExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FromExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncCall {}
 impl pg_sys::PgNode for FuncCall {}
 impl std::fmt::Display for FuncCall {
@@ -60465,6 +61853,12 @@ impl std::fmt::Display for FuncCall {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncCall"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncExpr {}
 impl pg_sys::PgNode for FuncExpr {}
 impl std::fmt::Display for FuncExpr {
@@ -60472,6 +61866,12 @@ impl std::fmt::Display for FuncExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionParameter {}
 impl pg_sys::PgNode for FunctionParameter {}
 impl std::fmt::Display for FunctionParameter {
@@ -60479,6 +61879,12 @@ impl std::fmt::Display for FunctionParameter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionParameter"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScan {}
 impl pg_sys::PgNode for FunctionScan {}
 impl std::fmt::Display for FunctionScan {
@@ -60486,6 +61892,12 @@ impl std::fmt::Display for FunctionScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScan"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScanState {}
 impl pg_sys::PgNode for FunctionScanState {}
 impl std::fmt::Display for FunctionScanState {
@@ -60493,6 +61905,12 @@ impl std::fmt::Display for FunctionScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Gather {}
 impl pg_sys::PgNode for Gather {}
 impl std::fmt::Display for Gather {
@@ -60500,6 +61918,12 @@ impl std::fmt::Display for Gather {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Gather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Gather"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMerge {}
 impl pg_sys::PgNode for GatherMerge {}
 impl std::fmt::Display for GatherMerge {
@@ -60507,6 +61931,12 @@ impl std::fmt::Display for GatherMerge {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMerge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMerge"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergePath {}
 impl pg_sys::PgNode for GatherMergePath {}
 impl std::fmt::Display for GatherMergePath {
@@ -60514,6 +61944,12 @@ impl std::fmt::Display for GatherMergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergeState {}
 impl pg_sys::PgNode for GatherMergeState {}
 impl std::fmt::Display for GatherMergeState {
@@ -60521,6 +61957,12 @@ impl std::fmt::Display for GatherMergeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergeState"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherPath {}
 impl pg_sys::PgNode for GatherPath {}
 impl std::fmt::Display for GatherPath {
@@ -60528,6 +61970,12 @@ impl std::fmt::Display for GatherPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherState {}
 impl pg_sys::PgNode for GatherState {}
 impl std::fmt::Display for GatherState {
@@ -60535,6 +61983,12 @@ impl std::fmt::Display for GatherState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherState"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantRoleStmt {}
 impl pg_sys::PgNode for GrantRoleStmt {}
 impl std::fmt::Display for GrantRoleStmt {
@@ -60542,6 +61996,12 @@ impl std::fmt::Display for GrantRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantStmt {}
 impl pg_sys::PgNode for GrantStmt {}
 impl std::fmt::Display for GrantStmt {
@@ -60549,6 +62009,12 @@ impl std::fmt::Display for GrantStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Group {}
 impl pg_sys::PgNode for Group {}
 impl std::fmt::Display for Group {
@@ -60556,6 +62022,12 @@ impl std::fmt::Display for Group {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Group"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupPath {}
 impl pg_sys::PgNode for GroupPath {}
 impl std::fmt::Display for GroupPath {
@@ -60563,6 +62035,12 @@ impl std::fmt::Display for GroupPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupResultPath {}
 impl pg_sys::PgNode for GroupResultPath {}
 impl std::fmt::Display for GroupResultPath {
@@ -60570,6 +62048,12 @@ impl std::fmt::Display for GroupResultPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupResultPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupResultPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupState {}
 impl pg_sys::PgNode for GroupState {}
 impl std::fmt::Display for GroupState {
@@ -60577,6 +62061,12 @@ impl std::fmt::Display for GroupState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupState"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingFunc {}
 impl pg_sys::PgNode for GroupingFunc {}
 impl std::fmt::Display for GroupingFunc {
@@ -60584,6 +62074,12 @@ impl std::fmt::Display for GroupingFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSet {}
 impl pg_sys::PgNode for GroupingSet {}
 impl std::fmt::Display for GroupingSet {
@@ -60591,6 +62087,12 @@ impl std::fmt::Display for GroupingSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSet"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetData {}
 impl pg_sys::PgNode for GroupingSetData {}
 impl std::fmt::Display for GroupingSetData {
@@ -60598,6 +62100,12 @@ impl std::fmt::Display for GroupingSetData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetData"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetsPath {}
 impl pg_sys::PgNode for GroupingSetsPath {}
 impl std::fmt::Display for GroupingSetsPath {
@@ -60605,6 +62113,12 @@ impl std::fmt::Display for GroupingSetsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Hash {}
 impl pg_sys::PgNode for Hash {}
 impl std::fmt::Display for Hash {
@@ -60612,6 +62126,12 @@ impl std::fmt::Display for Hash {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Hash"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoin {}
 impl pg_sys::PgNode for HashJoin {}
 impl std::fmt::Display for HashJoin {
@@ -60619,6 +62139,12 @@ impl std::fmt::Display for HashJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoinState {}
 impl pg_sys::PgNode for HashJoinState {}
 impl std::fmt::Display for HashJoinState {
@@ -60626,6 +62152,12 @@ impl std::fmt::Display for HashJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for HashPath {}
 impl pg_sys::PgNode for HashPath {}
 impl std::fmt::Display for HashPath {
@@ -60633,6 +62165,12 @@ impl std::fmt::Display for HashPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashPath"))
+    }
+}
 impl pg_sys::seal::Sealed for HashState {}
 impl pg_sys::PgNode for HashState {}
 impl std::fmt::Display for HashState {
@@ -60640,6 +62178,12 @@ impl std::fmt::Display for HashState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashState"))
+    }
+}
 impl pg_sys::seal::Sealed for HeapTupleTableSlot {}
 impl pg_sys::PgNode for HeapTupleTableSlot {}
 impl std::fmt::Display for HeapTupleTableSlot {
@@ -60647,6 +62191,12 @@ impl std::fmt::Display for HeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for IdentifySystemCmd {}
 impl pg_sys::PgNode for IdentifySystemCmd {}
 impl std::fmt::Display for IdentifySystemCmd {
@@ -60654,6 +62204,12 @@ impl std::fmt::Display for IdentifySystemCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IdentifySystemCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IdentifySystemCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for ImportForeignSchemaStmt {}
 impl pg_sys::PgNode for ImportForeignSchemaStmt {}
 impl std::fmt::Display for ImportForeignSchemaStmt {
@@ -60661,6 +62217,12 @@ impl std::fmt::Display for ImportForeignSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ImportForeignSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ImportForeignSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexAmRoutine {}
 impl pg_sys::PgNode for IndexAmRoutine {}
 impl std::fmt::Display for IndexAmRoutine {
@@ -60668,6 +62230,12 @@ impl std::fmt::Display for IndexAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexClause {}
 impl pg_sys::PgNode for IndexClause {}
 impl std::fmt::Display for IndexClause {
@@ -60675,6 +62243,12 @@ impl std::fmt::Display for IndexClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexClause"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexElem {}
 impl pg_sys::PgNode for IndexElem {}
 impl std::fmt::Display for IndexElem {
@@ -60682,6 +62256,12 @@ impl std::fmt::Display for IndexElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexElem"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexInfo {}
 impl pg_sys::PgNode for IndexInfo {}
 impl std::fmt::Display for IndexInfo {
@@ -60689,6 +62269,12 @@ impl std::fmt::Display for IndexInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScan {}
 impl pg_sys::PgNode for IndexOnlyScan {}
 impl std::fmt::Display for IndexOnlyScan {
@@ -60696,6 +62282,12 @@ impl std::fmt::Display for IndexOnlyScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScanState {}
 impl pg_sys::PgNode for IndexOnlyScanState {}
 impl std::fmt::Display for IndexOnlyScanState {
@@ -60703,6 +62295,12 @@ impl std::fmt::Display for IndexOnlyScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOptInfo {}
 impl pg_sys::PgNode for IndexOptInfo {}
 impl std::fmt::Display for IndexOptInfo {
@@ -60710,6 +62308,12 @@ impl std::fmt::Display for IndexOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexPath {}
 impl pg_sys::PgNode for IndexPath {}
 impl std::fmt::Display for IndexPath {
@@ -60717,6 +62321,12 @@ impl std::fmt::Display for IndexPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScan {}
 impl pg_sys::PgNode for IndexScan {}
 impl std::fmt::Display for IndexScan {
@@ -60724,6 +62334,12 @@ impl std::fmt::Display for IndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScanState {}
 impl pg_sys::PgNode for IndexScanState {}
 impl std::fmt::Display for IndexScanState {
@@ -60731,6 +62347,12 @@ impl std::fmt::Display for IndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexStmt {}
 impl pg_sys::PgNode for IndexStmt {}
 impl std::fmt::Display for IndexStmt {
@@ -60738,6 +62360,12 @@ impl std::fmt::Display for IndexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for InferClause {}
 impl pg_sys::PgNode for InferClause {}
 impl std::fmt::Display for InferClause {
@@ -60745,6 +62373,12 @@ impl std::fmt::Display for InferClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferClause"))
+    }
+}
 impl pg_sys::seal::Sealed for InferenceElem {}
 impl pg_sys::PgNode for InferenceElem {}
 impl std::fmt::Display for InferenceElem {
@@ -60752,6 +62386,12 @@ impl std::fmt::Display for InferenceElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferenceElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferenceElem"))
+    }
+}
 impl pg_sys::seal::Sealed for InlineCodeBlock {}
 impl pg_sys::PgNode for InlineCodeBlock {}
 impl std::fmt::Display for InlineCodeBlock {
@@ -60759,6 +62399,12 @@ impl std::fmt::Display for InlineCodeBlock {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InlineCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InlineCodeBlock"))
+    }
+}
 impl pg_sys::seal::Sealed for InsertStmt {}
 impl pg_sys::PgNode for InsertStmt {}
 impl std::fmt::Display for InsertStmt {
@@ -60766,6 +62412,12 @@ impl std::fmt::Display for InsertStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InsertStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InsertStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IntoClause {}
 impl pg_sys::PgNode for IntoClause {}
 impl std::fmt::Display for IntoClause {
@@ -60773,6 +62425,12 @@ impl std::fmt::Display for IntoClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IntoClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IntoClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Join {}
 impl pg_sys::PgNode for Join {}
 impl std::fmt::Display for Join {
@@ -60780,6 +62438,12 @@ impl std::fmt::Display for Join {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Join {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Join"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinExpr {}
 impl pg_sys::PgNode for JoinExpr {}
 impl std::fmt::Display for JoinExpr {
@@ -60787,6 +62451,12 @@ impl std::fmt::Display for JoinExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinPath {}
 impl pg_sys::PgNode for JoinPath {}
 impl std::fmt::Display for JoinPath {
@@ -60794,6 +62464,12 @@ impl std::fmt::Display for JoinPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinPath"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinState {}
 impl pg_sys::PgNode for JoinState {}
 impl std::fmt::Display for JoinState {
@@ -60801,6 +62477,12 @@ impl std::fmt::Display for JoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for JunkFilter {}
 impl pg_sys::PgNode for JunkFilter {}
 impl std::fmt::Display for JunkFilter {
@@ -60808,6 +62490,12 @@ impl std::fmt::Display for JunkFilter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JunkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JunkFilter"))
+    }
+}
 impl pg_sys::seal::Sealed for Limit {}
 impl pg_sys::PgNode for Limit {}
 impl std::fmt::Display for Limit {
@@ -60815,6 +62503,12 @@ impl std::fmt::Display for Limit {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Limit"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitPath {}
 impl pg_sys::PgNode for LimitPath {}
 impl std::fmt::Display for LimitPath {
@@ -60822,6 +62516,12 @@ impl std::fmt::Display for LimitPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitState {}
 impl pg_sys::PgNode for LimitState {}
 impl std::fmt::Display for LimitState {
@@ -60829,6 +62529,12 @@ impl std::fmt::Display for LimitState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitState"))
+    }
+}
 impl pg_sys::seal::Sealed for List {}
 impl pg_sys::PgNode for List {}
 impl std::fmt::Display for List {
@@ -60836,6 +62542,12 @@ impl std::fmt::Display for List {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "List"))
+    }
+}
 impl pg_sys::seal::Sealed for ListenStmt {}
 impl pg_sys::PgNode for ListenStmt {}
 impl std::fmt::Display for ListenStmt {
@@ -60843,6 +62555,12 @@ impl std::fmt::Display for ListenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ListenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ListenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LoadStmt {}
 impl pg_sys::PgNode for LoadStmt {}
 impl std::fmt::Display for LoadStmt {
@@ -60850,6 +62568,12 @@ impl std::fmt::Display for LoadStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LoadStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LoadStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRows {}
 impl pg_sys::PgNode for LockRows {}
 impl std::fmt::Display for LockRows {
@@ -60857,6 +62581,12 @@ impl std::fmt::Display for LockRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRows"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsPath {}
 impl pg_sys::PgNode for LockRowsPath {}
 impl std::fmt::Display for LockRowsPath {
@@ -60864,6 +62594,12 @@ impl std::fmt::Display for LockRowsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsState {}
 impl pg_sys::PgNode for LockRowsState {}
 impl std::fmt::Display for LockRowsState {
@@ -60871,6 +62607,12 @@ impl std::fmt::Display for LockRowsState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsState"))
+    }
+}
 impl pg_sys::seal::Sealed for LockStmt {}
 impl pg_sys::PgNode for LockStmt {}
 impl std::fmt::Display for LockStmt {
@@ -60878,6 +62620,12 @@ impl std::fmt::Display for LockStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockingClause {}
 impl pg_sys::PgNode for LockingClause {}
 impl std::fmt::Display for LockingClause {
@@ -60885,6 +62633,12 @@ impl std::fmt::Display for LockingClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockingClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockingClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Material {}
 impl pg_sys::PgNode for Material {}
 impl std::fmt::Display for Material {
@@ -60892,6 +62646,12 @@ impl std::fmt::Display for Material {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Material"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialPath {}
 impl pg_sys::PgNode for MaterialPath {}
 impl std::fmt::Display for MaterialPath {
@@ -60899,6 +62659,12 @@ impl std::fmt::Display for MaterialPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialState {}
 impl pg_sys::PgNode for MaterialState {}
 impl std::fmt::Display for MaterialState {
@@ -60906,6 +62672,12 @@ impl std::fmt::Display for MaterialState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialState"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoryContextData {}
 impl pg_sys::PgNode for MemoryContextData {}
 impl std::fmt::Display for MemoryContextData {
@@ -60913,6 +62685,12 @@ impl std::fmt::Display for MemoryContextData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoryContextData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoryContextData"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppend {}
 impl pg_sys::PgNode for MergeAppend {}
 impl std::fmt::Display for MergeAppend {
@@ -60920,6 +62698,12 @@ impl std::fmt::Display for MergeAppend {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppend"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendPath {}
 impl pg_sys::PgNode for MergeAppendPath {}
 impl std::fmt::Display for MergeAppendPath {
@@ -60927,6 +62711,12 @@ impl std::fmt::Display for MergeAppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendState {}
 impl pg_sys::PgNode for MergeAppendState {}
 impl std::fmt::Display for MergeAppendState {
@@ -60934,6 +62724,12 @@ impl std::fmt::Display for MergeAppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoin {}
 impl pg_sys::PgNode for MergeJoin {}
 impl std::fmt::Display for MergeJoin {
@@ -60941,6 +62737,12 @@ impl std::fmt::Display for MergeJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoinState {}
 impl pg_sys::PgNode for MergeJoinState {}
 impl std::fmt::Display for MergeJoinState {
@@ -60948,6 +62750,12 @@ impl std::fmt::Display for MergeJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergePath {}
 impl pg_sys::PgNode for MergePath {}
 impl std::fmt::Display for MergePath {
@@ -60955,11 +62763,23 @@ impl std::fmt::Display for MergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggInfo {}
 impl pg_sys::PgNode for MinMaxAggInfo {}
 impl std::fmt::Display for MinMaxAggInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display_node())
+        write!(f, "{}", self.display_node())
+    }
+}
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggInfo"))
     }
 }
 impl pg_sys::seal::Sealed for MinMaxAggPath {}
@@ -60969,6 +62789,12 @@ impl std::fmt::Display for MinMaxAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxExpr {}
 impl pg_sys::PgNode for MinMaxExpr {}
 impl std::fmt::Display for MinMaxExpr {
@@ -60976,6 +62802,12 @@ impl std::fmt::Display for MinMaxExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for MinimalTupleTableSlot {}
 impl pg_sys::PgNode for MinimalTupleTableSlot {}
 impl std::fmt::Display for MinimalTupleTableSlot {
@@ -60983,6 +62815,12 @@ impl std::fmt::Display for MinimalTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinimalTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinimalTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTable {}
 impl pg_sys::PgNode for ModifyTable {}
 impl std::fmt::Display for ModifyTable {
@@ -60990,6 +62828,12 @@ impl std::fmt::Display for ModifyTable {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTable"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTablePath {}
 impl pg_sys::PgNode for ModifyTablePath {}
 impl std::fmt::Display for ModifyTablePath {
@@ -60997,6 +62841,12 @@ impl std::fmt::Display for ModifyTablePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTablePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTablePath"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTableState {}
 impl pg_sys::PgNode for ModifyTableState {}
 impl std::fmt::Display for ModifyTableState {
@@ -61004,6 +62854,12 @@ impl std::fmt::Display for ModifyTableState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTableState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTableState"))
+    }
+}
 impl pg_sys::seal::Sealed for MultiAssignRef {}
 impl pg_sys::PgNode for MultiAssignRef {}
 impl std::fmt::Display for MultiAssignRef {
@@ -61011,6 +62867,12 @@ impl std::fmt::Display for MultiAssignRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MultiAssignRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MultiAssignRef"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedArgExpr {}
 impl pg_sys::PgNode for NamedArgExpr {}
 impl std::fmt::Display for NamedArgExpr {
@@ -61018,6 +62880,12 @@ impl std::fmt::Display for NamedArgExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedArgExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedArgExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScan {}
 impl pg_sys::PgNode for NamedTuplestoreScan {}
 impl std::fmt::Display for NamedTuplestoreScan {
@@ -61025,6 +62893,12 @@ impl std::fmt::Display for NamedTuplestoreScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScan"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScanState {}
 impl pg_sys::PgNode for NamedTuplestoreScanState {}
 impl std::fmt::Display for NamedTuplestoreScanState {
@@ -61032,6 +62906,12 @@ impl std::fmt::Display for NamedTuplestoreScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoop {}
 impl pg_sys::PgNode for NestLoop {}
 impl std::fmt::Display for NestLoop {
@@ -61039,6 +62919,12 @@ impl std::fmt::Display for NestLoop {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoop"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopParam {}
 impl pg_sys::PgNode for NestLoopParam {}
 impl std::fmt::Display for NestLoopParam {
@@ -61046,6 +62932,12 @@ impl std::fmt::Display for NestLoopParam {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopParam"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopState {}
 impl pg_sys::PgNode for NestLoopState {}
 impl std::fmt::Display for NestLoopState {
@@ -61053,6 +62945,12 @@ impl std::fmt::Display for NestLoopState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopState"))
+    }
+}
 impl pg_sys::seal::Sealed for NextValueExpr {}
 impl pg_sys::PgNode for NextValueExpr {}
 impl std::fmt::Display for NextValueExpr {
@@ -61060,6 +62958,12 @@ impl std::fmt::Display for NextValueExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NextValueExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NextValueExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Node {}
 impl pg_sys::PgNode for Node {}
 impl std::fmt::Display for Node {
@@ -61067,6 +62971,12 @@ impl std::fmt::Display for Node {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Node"))
+    }
+}
 impl pg_sys::seal::Sealed for NotifyStmt {}
 impl pg_sys::PgNode for NotifyStmt {}
 impl std::fmt::Display for NotifyStmt {
@@ -61074,6 +62984,12 @@ impl std::fmt::Display for NotifyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NotifyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NotifyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for NullTest {}
 impl pg_sys::PgNode for NullTest {}
 impl std::fmt::Display for NullTest {
@@ -61081,6 +62997,12 @@ impl std::fmt::Display for NullTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NullTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NullTest"))
+    }
+}
 impl pg_sys::seal::Sealed for ObjectWithArgs {}
 impl pg_sys::PgNode for ObjectWithArgs {}
 impl std::fmt::Display for ObjectWithArgs {
@@ -61088,6 +63010,12 @@ impl std::fmt::Display for ObjectWithArgs {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ObjectWithArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ObjectWithArgs"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictClause {}
 impl pg_sys::PgNode for OnConflictClause {}
 impl std::fmt::Display for OnConflictClause {
@@ -61095,6 +63023,12 @@ impl std::fmt::Display for OnConflictClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictClause"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictExpr {}
 impl pg_sys::PgNode for OnConflictExpr {}
 impl std::fmt::Display for OnConflictExpr {
@@ -61102,6 +63036,12 @@ impl std::fmt::Display for OnConflictExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictSetState {}
 impl pg_sys::PgNode for OnConflictSetState {}
 impl std::fmt::Display for OnConflictSetState {
@@ -61109,6 +63049,12 @@ impl std::fmt::Display for OnConflictSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for OpExpr {}
 impl pg_sys::PgNode for OpExpr {}
 impl std::fmt::Display for OpExpr {
@@ -61116,6 +63062,12 @@ impl std::fmt::Display for OpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Param {}
 impl pg_sys::PgNode for Param {}
 impl std::fmt::Display for Param {
@@ -61123,6 +63075,12 @@ impl std::fmt::Display for Param {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Param"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamPathInfo {}
 impl pg_sys::PgNode for ParamPathInfo {}
 impl std::fmt::Display for ParamPathInfo {
@@ -61130,6 +63088,12 @@ impl std::fmt::Display for ParamPathInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamPathInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamPathInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamRef {}
 impl pg_sys::PgNode for ParamRef {}
 impl std::fmt::Display for ParamRef {
@@ -61137,6 +63101,12 @@ impl std::fmt::Display for ParamRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamRef"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionBoundSpec {}
 impl pg_sys::PgNode for PartitionBoundSpec {}
 impl std::fmt::Display for PartitionBoundSpec {
@@ -61144,6 +63114,12 @@ impl std::fmt::Display for PartitionBoundSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionBoundSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionBoundSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionCmd {}
 impl pg_sys::PgNode for PartitionCmd {}
 impl std::fmt::Display for PartitionCmd {
@@ -61151,6 +63127,12 @@ impl std::fmt::Display for PartitionCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionElem {}
 impl pg_sys::PgNode for PartitionElem {}
 impl std::fmt::Display for PartitionElem {
@@ -61158,6 +63140,12 @@ impl std::fmt::Display for PartitionElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionElem"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneInfo {}
 impl pg_sys::PgNode for PartitionPruneInfo {}
 impl std::fmt::Display for PartitionPruneInfo {
@@ -61165,6 +63153,12 @@ impl std::fmt::Display for PartitionPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStep {}
 impl pg_sys::PgNode for PartitionPruneStep {}
 impl std::fmt::Display for PartitionPruneStep {
@@ -61172,6 +63166,12 @@ impl std::fmt::Display for PartitionPruneStep {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStep"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepCombine {}
 impl pg_sys::PgNode for PartitionPruneStepCombine {}
 impl std::fmt::Display for PartitionPruneStepCombine {
@@ -61179,6 +63179,12 @@ impl std::fmt::Display for PartitionPruneStepCombine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepCombine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepCombine"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepOp {}
 impl pg_sys::PgNode for PartitionPruneStepOp {}
 impl std::fmt::Display for PartitionPruneStepOp {
@@ -61186,6 +63192,12 @@ impl std::fmt::Display for PartitionPruneStepOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepOp"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionRangeDatum {}
 impl pg_sys::PgNode for PartitionRangeDatum {}
 impl std::fmt::Display for PartitionRangeDatum {
@@ -61193,6 +63205,12 @@ impl std::fmt::Display for PartitionRangeDatum {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionRangeDatum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionRangeDatum"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionSpec {}
 impl pg_sys::PgNode for PartitionSpec {}
 impl std::fmt::Display for PartitionSpec {
@@ -61200,6 +63218,12 @@ impl std::fmt::Display for PartitionSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionedRelPruneInfo {}
 impl pg_sys::PgNode for PartitionedRelPruneInfo {}
 impl std::fmt::Display for PartitionedRelPruneInfo {
@@ -61207,6 +63231,12 @@ impl std::fmt::Display for PartitionedRelPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionedRelPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionedRelPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Path {}
 impl pg_sys::PgNode for Path {}
 impl std::fmt::Display for Path {
@@ -61214,6 +63244,12 @@ impl std::fmt::Display for Path {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Path"))
+    }
+}
 impl pg_sys::seal::Sealed for PathKey {}
 impl pg_sys::PgNode for PathKey {}
 impl std::fmt::Display for PathKey {
@@ -61221,6 +63257,12 @@ impl std::fmt::Display for PathKey {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathKey"))
+    }
+}
 impl pg_sys::seal::Sealed for PathTarget {}
 impl pg_sys::PgNode for PathTarget {}
 impl std::fmt::Display for PathTarget {
@@ -61228,6 +63270,12 @@ impl std::fmt::Display for PathTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderInfo {}
 impl pg_sys::PgNode for PlaceHolderInfo {}
 impl std::fmt::Display for PlaceHolderInfo {
@@ -61235,6 +63283,12 @@ impl std::fmt::Display for PlaceHolderInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderVar {}
 impl pg_sys::PgNode for PlaceHolderVar {}
 impl std::fmt::Display for PlaceHolderVar {
@@ -61242,6 +63296,12 @@ impl std::fmt::Display for PlaceHolderVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderVar"))
+    }
+}
 impl pg_sys::seal::Sealed for Plan {}
 impl pg_sys::PgNode for Plan {}
 impl std::fmt::Display for Plan {
@@ -61249,6 +63309,12 @@ impl std::fmt::Display for Plan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Plan"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanInvalItem {}
 impl pg_sys::PgNode for PlanInvalItem {}
 impl std::fmt::Display for PlanInvalItem {
@@ -61256,6 +63322,12 @@ impl std::fmt::Display for PlanInvalItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanInvalItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanInvalItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanRowMark {}
 impl pg_sys::PgNode for PlanRowMark {}
 impl std::fmt::Display for PlanRowMark {
@@ -61263,6 +63335,12 @@ impl std::fmt::Display for PlanRowMark {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanRowMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanRowMark"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanState {}
 impl pg_sys::PgNode for PlanState {}
 impl std::fmt::Display for PlanState {
@@ -61270,6 +63348,12 @@ impl std::fmt::Display for PlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannedStmt {}
 impl pg_sys::PgNode for PlannedStmt {}
 impl std::fmt::Display for PlannedStmt {
@@ -61277,6 +63361,12 @@ impl std::fmt::Display for PlannedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerGlobal {}
 impl pg_sys::PgNode for PlannerGlobal {}
 impl std::fmt::Display for PlannerGlobal {
@@ -61284,6 +63374,12 @@ impl std::fmt::Display for PlannerGlobal {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerGlobal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerGlobal"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerInfo {}
 impl pg_sys::PgNode for PlannerInfo {}
 impl std::fmt::Display for PlannerInfo {
@@ -61291,6 +63387,12 @@ impl std::fmt::Display for PlannerInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerParamItem {}
 impl pg_sys::PgNode for PlannerParamItem {}
 impl std::fmt::Display for PlannerParamItem {
@@ -61298,6 +63400,12 @@ impl std::fmt::Display for PlannerParamItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerParamItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerParamItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PrepareStmt {}
 impl pg_sys::PgNode for PrepareStmt {}
 impl std::fmt::Display for PrepareStmt {
@@ -61305,6 +63413,12 @@ impl std::fmt::Display for PrepareStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PrepareStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PrepareStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSet {}
 impl pg_sys::PgNode for ProjectSet {}
 impl std::fmt::Display for ProjectSet {
@@ -61312,6 +63426,12 @@ impl std::fmt::Display for ProjectSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSet"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetPath {}
 impl pg_sys::PgNode for ProjectSetPath {}
 impl std::fmt::Display for ProjectSetPath {
@@ -61319,6 +63439,12 @@ impl std::fmt::Display for ProjectSetPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetState {}
 impl pg_sys::PgNode for ProjectSetState {}
 impl std::fmt::Display for ProjectSetState {
@@ -61326,6 +63452,12 @@ impl std::fmt::Display for ProjectSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionInfo {}
 impl pg_sys::PgNode for ProjectionInfo {}
 impl std::fmt::Display for ProjectionInfo {
@@ -61333,6 +63465,12 @@ impl std::fmt::Display for ProjectionInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionPath {}
 impl pg_sys::PgNode for ProjectionPath {}
 impl std::fmt::Display for ProjectionPath {
@@ -61340,6 +63478,12 @@ impl std::fmt::Display for ProjectionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Query {}
 impl pg_sys::PgNode for Query {}
 impl std::fmt::Display for Query {
@@ -61347,6 +63491,12 @@ impl std::fmt::Display for Query {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Query"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeFunction {}
 impl pg_sys::PgNode for RangeFunction {}
 impl std::fmt::Display for RangeFunction {
@@ -61354,6 +63504,12 @@ impl std::fmt::Display for RangeFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeSubselect {}
 impl pg_sys::PgNode for RangeSubselect {}
 impl std::fmt::Display for RangeSubselect {
@@ -61361,6 +63517,12 @@ impl std::fmt::Display for RangeSubselect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeSubselect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeSubselect"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFunc {}
 impl pg_sys::PgNode for RangeTableFunc {}
 impl std::fmt::Display for RangeTableFunc {
@@ -61368,6 +63530,12 @@ impl std::fmt::Display for RangeTableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFuncCol {}
 impl pg_sys::PgNode for RangeTableFuncCol {}
 impl std::fmt::Display for RangeTableFuncCol {
@@ -61375,6 +63543,12 @@ impl std::fmt::Display for RangeTableFuncCol {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFuncCol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFuncCol"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableSample {}
 impl pg_sys::PgNode for RangeTableSample {}
 impl std::fmt::Display for RangeTableSample {
@@ -61382,6 +63556,12 @@ impl std::fmt::Display for RangeTableSample {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableSample"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblEntry {}
 impl pg_sys::PgNode for RangeTblEntry {}
 impl std::fmt::Display for RangeTblEntry {
@@ -61389,6 +63569,12 @@ impl std::fmt::Display for RangeTblEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblFunction {}
 impl pg_sys::PgNode for RangeTblFunction {}
 impl std::fmt::Display for RangeTblFunction {
@@ -61396,6 +63582,12 @@ impl std::fmt::Display for RangeTblFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblRef {}
 impl pg_sys::PgNode for RangeTblRef {}
 impl std::fmt::Display for RangeTblRef {
@@ -61403,6 +63595,12 @@ impl std::fmt::Display for RangeTblRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblRef"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeVar {}
 impl pg_sys::PgNode for RangeVar {}
 impl std::fmt::Display for RangeVar {
@@ -61410,6 +63608,12 @@ impl std::fmt::Display for RangeVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeVar"))
+    }
+}
 impl pg_sys::seal::Sealed for RawStmt {}
 impl pg_sys::PgNode for RawStmt {}
 impl std::fmt::Display for RawStmt {
@@ -61417,6 +63621,12 @@ impl std::fmt::Display for RawStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RawStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RawStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReassignOwnedStmt {}
 impl pg_sys::PgNode for ReassignOwnedStmt {}
 impl std::fmt::Display for ReassignOwnedStmt {
@@ -61424,6 +63634,12 @@ impl std::fmt::Display for ReassignOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReassignOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReassignOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnion {}
 impl pg_sys::PgNode for RecursiveUnion {}
 impl std::fmt::Display for RecursiveUnion {
@@ -61431,6 +63647,12 @@ impl std::fmt::Display for RecursiveUnion {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnion"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionPath {}
 impl pg_sys::PgNode for RecursiveUnionPath {}
 impl std::fmt::Display for RecursiveUnionPath {
@@ -61438,6 +63660,12 @@ impl std::fmt::Display for RecursiveUnionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionState {}
 impl pg_sys::PgNode for RecursiveUnionState {}
 impl std::fmt::Display for RecursiveUnionState {
@@ -61445,6 +63673,12 @@ impl std::fmt::Display for RecursiveUnionState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionState"))
+    }
+}
 impl pg_sys::seal::Sealed for RefreshMatViewStmt {}
 impl pg_sys::PgNode for RefreshMatViewStmt {}
 impl std::fmt::Display for RefreshMatViewStmt {
@@ -61452,6 +63686,12 @@ impl std::fmt::Display for RefreshMatViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RefreshMatViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RefreshMatViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReindexStmt {}
 impl pg_sys::PgNode for ReindexStmt {}
 impl std::fmt::Display for ReindexStmt {
@@ -61459,6 +63699,12 @@ impl std::fmt::Display for ReindexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReindexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReindexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RelOptInfo {}
 impl pg_sys::PgNode for RelOptInfo {}
 impl std::fmt::Display for RelOptInfo {
@@ -61466,6 +63712,12 @@ impl std::fmt::Display for RelOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RelabelType {}
 impl pg_sys::PgNode for RelabelType {}
 impl std::fmt::Display for RelabelType {
@@ -61473,6 +63725,12 @@ impl std::fmt::Display for RelabelType {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelabelType"))
+    }
+}
 impl pg_sys::seal::Sealed for RenameStmt {}
 impl pg_sys::PgNode for RenameStmt {}
 impl std::fmt::Display for RenameStmt {
@@ -61480,6 +63738,12 @@ impl std::fmt::Display for RenameStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RenameStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RenameStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReplicaIdentityStmt {}
 impl pg_sys::PgNode for ReplicaIdentityStmt {}
 impl std::fmt::Display for ReplicaIdentityStmt {
@@ -61487,6 +63751,12 @@ impl std::fmt::Display for ReplicaIdentityStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReplicaIdentityStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReplicaIdentityStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ResTarget {}
 impl pg_sys::PgNode for ResTarget {}
 impl std::fmt::Display for ResTarget {
@@ -61494,6 +63764,12 @@ impl std::fmt::Display for ResTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for RestrictInfo {}
 impl pg_sys::PgNode for RestrictInfo {}
 impl std::fmt::Display for RestrictInfo {
@@ -61501,6 +63777,12 @@ impl std::fmt::Display for RestrictInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RestrictInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RestrictInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Result {}
 impl pg_sys::PgNode for Result {}
 impl std::fmt::Display for Result {
@@ -61508,6 +63790,12 @@ impl std::fmt::Display for Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Result"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultRelInfo {}
 impl pg_sys::PgNode for ResultRelInfo {}
 impl std::fmt::Display for ResultRelInfo {
@@ -61515,6 +63803,12 @@ impl std::fmt::Display for ResultRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultState {}
 impl pg_sys::PgNode for ResultState {}
 impl std::fmt::Display for ResultState {
@@ -61522,6 +63816,12 @@ impl std::fmt::Display for ResultState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultState"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnSetInfo {}
 impl pg_sys::PgNode for ReturnSetInfo {}
 impl std::fmt::Display for ReturnSetInfo {
@@ -61529,6 +63829,12 @@ impl std::fmt::Display for ReturnSetInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnSetInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnSetInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RoleSpec {}
 impl pg_sys::PgNode for RoleSpec {}
 impl std::fmt::Display for RoleSpec {
@@ -61536,6 +63842,12 @@ impl std::fmt::Display for RoleSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RoleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RoleSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for RollupData {}
 impl pg_sys::PgNode for RollupData {}
 impl std::fmt::Display for RollupData {
@@ -61543,6 +63855,12 @@ impl std::fmt::Display for RollupData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RollupData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RollupData"))
+    }
+}
 impl pg_sys::seal::Sealed for RowCompareExpr {}
 impl pg_sys::PgNode for RowCompareExpr {}
 impl std::fmt::Display for RowCompareExpr {
@@ -61550,6 +63868,12 @@ impl std::fmt::Display for RowCompareExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowCompareExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowCompareExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowExpr {}
 impl pg_sys::PgNode for RowExpr {}
 impl std::fmt::Display for RowExpr {
@@ -61557,6 +63881,12 @@ impl std::fmt::Display for RowExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowMarkClause {}
 impl pg_sys::PgNode for RowMarkClause {}
 impl std::fmt::Display for RowMarkClause {
@@ -61564,6 +63894,12 @@ impl std::fmt::Display for RowMarkClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowMarkClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowMarkClause"))
+    }
+}
 impl pg_sys::seal::Sealed for RuleStmt {}
 impl pg_sys::PgNode for RuleStmt {}
 impl std::fmt::Display for RuleStmt {
@@ -61571,6 +63907,12 @@ impl std::fmt::Display for RuleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RuleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RuleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLCmd {}
 impl pg_sys::PgNode for SQLCmd {}
 impl std::fmt::Display for SQLCmd {
@@ -61578,6 +63920,12 @@ impl std::fmt::Display for SQLCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLValueFunction {}
 impl pg_sys::PgNode for SQLValueFunction {}
 impl std::fmt::Display for SQLValueFunction {
@@ -61585,6 +63933,12 @@ impl std::fmt::Display for SQLValueFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLValueFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLValueFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScan {}
 impl pg_sys::PgNode for SampleScan {}
 impl std::fmt::Display for SampleScan {
@@ -61592,6 +63946,12 @@ impl std::fmt::Display for SampleScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScanState {}
 impl pg_sys::PgNode for SampleScanState {}
 impl std::fmt::Display for SampleScanState {
@@ -61599,6 +63959,12 @@ impl std::fmt::Display for SampleScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for ScalarArrayOpExpr {}
 impl pg_sys::PgNode for ScalarArrayOpExpr {}
 impl std::fmt::Display for ScalarArrayOpExpr {
@@ -61606,6 +63972,12 @@ impl std::fmt::Display for ScalarArrayOpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScalarArrayOpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScalarArrayOpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Scan {}
 impl pg_sys::PgNode for Scan {}
 impl std::fmt::Display for Scan {
@@ -61613,6 +63985,12 @@ impl std::fmt::Display for Scan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Scan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Scan"))
+    }
+}
 impl pg_sys::seal::Sealed for ScanState {}
 impl pg_sys::PgNode for ScanState {}
 impl std::fmt::Display for ScanState {
@@ -61620,6 +63998,12 @@ impl std::fmt::Display for ScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SecLabelStmt {}
 impl pg_sys::PgNode for SecLabelStmt {}
 impl std::fmt::Display for SecLabelStmt {
@@ -61627,6 +64011,12 @@ impl std::fmt::Display for SecLabelStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SecLabelStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SecLabelStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SelectStmt {}
 impl pg_sys::PgNode for SelectStmt {}
 impl std::fmt::Display for SelectStmt {
@@ -61634,6 +64024,12 @@ impl std::fmt::Display for SelectStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SelectStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SelectStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SeqScanState {}
 impl pg_sys::PgNode for SeqScanState {}
 impl std::fmt::Display for SeqScanState {
@@ -61641,6 +64037,12 @@ impl std::fmt::Display for SeqScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SeqScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SeqScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetExprState {}
 impl pg_sys::PgNode for SetExprState {}
 impl std::fmt::Display for SetExprState {
@@ -61648,6 +64050,12 @@ impl std::fmt::Display for SetExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOp {}
 impl pg_sys::PgNode for SetOp {}
 impl std::fmt::Display for SetOp {
@@ -61655,6 +64063,12 @@ impl std::fmt::Display for SetOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOp"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpPath {}
 impl pg_sys::PgNode for SetOpPath {}
 impl std::fmt::Display for SetOpPath {
@@ -61662,6 +64076,12 @@ impl std::fmt::Display for SetOpPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpState {}
 impl pg_sys::PgNode for SetOpState {}
 impl std::fmt::Display for SetOpState {
@@ -61669,6 +64089,12 @@ impl std::fmt::Display for SetOpState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOperationStmt {}
 impl pg_sys::PgNode for SetOperationStmt {}
 impl std::fmt::Display for SetOperationStmt {
@@ -61676,6 +64102,12 @@ impl std::fmt::Display for SetOperationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOperationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOperationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SetToDefault {}
 impl pg_sys::PgNode for SetToDefault {}
 impl std::fmt::Display for SetToDefault {
@@ -61683,6 +64115,12 @@ impl std::fmt::Display for SetToDefault {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetToDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetToDefault"))
+    }
+}
 impl pg_sys::seal::Sealed for Sort {}
 impl pg_sys::PgNode for Sort {}
 impl std::fmt::Display for Sort {
@@ -61690,6 +64128,12 @@ impl std::fmt::Display for Sort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Sort"))
+    }
+}
 impl pg_sys::seal::Sealed for SortBy {}
 impl pg_sys::PgNode for SortBy {}
 impl std::fmt::Display for SortBy {
@@ -61697,6 +64141,12 @@ impl std::fmt::Display for SortBy {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortBy"))
+    }
+}
 impl pg_sys::seal::Sealed for SortGroupClause {}
 impl pg_sys::PgNode for SortGroupClause {}
 impl std::fmt::Display for SortGroupClause {
@@ -61704,6 +64154,12 @@ impl std::fmt::Display for SortGroupClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortGroupClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortGroupClause"))
+    }
+}
 impl pg_sys::seal::Sealed for SortPath {}
 impl pg_sys::PgNode for SortPath {}
 impl std::fmt::Display for SortPath {
@@ -61711,6 +64167,12 @@ impl std::fmt::Display for SortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SortState {}
 impl pg_sys::PgNode for SortState {}
 impl std::fmt::Display for SortState {
@@ -61718,6 +64180,12 @@ impl std::fmt::Display for SortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortState"))
+    }
+}
 impl pg_sys::seal::Sealed for SpecialJoinInfo {}
 impl pg_sys::PgNode for SpecialJoinInfo {}
 impl std::fmt::Display for SpecialJoinInfo {
@@ -61725,6 +64193,12 @@ impl std::fmt::Display for SpecialJoinInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SpecialJoinInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SpecialJoinInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StartReplicationCmd {}
 impl pg_sys::PgNode for StartReplicationCmd {}
 impl std::fmt::Display for StartReplicationCmd {
@@ -61732,6 +64206,12 @@ impl std::fmt::Display for StartReplicationCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StartReplicationCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StartReplicationCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for StatisticExtInfo {}
 impl pg_sys::PgNode for StatisticExtInfo {}
 impl std::fmt::Display for StatisticExtInfo {
@@ -61739,6 +64219,12 @@ impl std::fmt::Display for StatisticExtInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatisticExtInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatisticExtInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for SubLink {}
 impl pg_sys::PgNode for SubLink {}
 impl std::fmt::Display for SubLink {
@@ -61746,6 +64232,12 @@ impl std::fmt::Display for SubLink {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubLink"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlan {}
 impl pg_sys::PgNode for SubPlan {}
 impl std::fmt::Display for SubPlan {
@@ -61753,6 +64245,12 @@ impl std::fmt::Display for SubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlanState {}
 impl pg_sys::PgNode for SubPlanState {}
 impl std::fmt::Display for SubPlanState {
@@ -61760,6 +64258,12 @@ impl std::fmt::Display for SubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScan {}
 impl pg_sys::PgNode for SubqueryScan {}
 impl std::fmt::Display for SubqueryScan {
@@ -61767,6 +64271,12 @@ impl std::fmt::Display for SubqueryScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanPath {}
 impl pg_sys::PgNode for SubqueryScanPath {}
 impl std::fmt::Display for SubqueryScanPath {
@@ -61774,6 +64284,12 @@ impl std::fmt::Display for SubqueryScanPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanState {}
 impl pg_sys::PgNode for SubqueryScanState {}
 impl std::fmt::Display for SubqueryScanState {
@@ -61781,6 +64297,12 @@ impl std::fmt::Display for SubqueryScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubscriptingRef {}
 impl pg_sys::PgNode for SubscriptingRef {}
 impl std::fmt::Display for SubscriptingRef {
@@ -61788,6 +64310,12 @@ impl std::fmt::Display for SubscriptingRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubscriptingRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubscriptingRef"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestCost {}
 impl pg_sys::PgNode for SupportRequestCost {}
 impl std::fmt::Display for SupportRequestCost {
@@ -61795,6 +64323,12 @@ impl std::fmt::Display for SupportRequestCost {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestCost"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestIndexCondition {}
 impl pg_sys::PgNode for SupportRequestIndexCondition {}
 impl std::fmt::Display for SupportRequestIndexCondition {
@@ -61802,6 +64336,12 @@ impl std::fmt::Display for SupportRequestIndexCondition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestIndexCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestIndexCondition"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestRows {}
 impl pg_sys::PgNode for SupportRequestRows {}
 impl std::fmt::Display for SupportRequestRows {
@@ -61809,6 +64349,12 @@ impl std::fmt::Display for SupportRequestRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestRows"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSelectivity {}
 impl pg_sys::PgNode for SupportRequestSelectivity {}
 impl std::fmt::Display for SupportRequestSelectivity {
@@ -61816,6 +64362,12 @@ impl std::fmt::Display for SupportRequestSelectivity {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSelectivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSelectivity"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSimplify {}
 impl pg_sys::PgNode for SupportRequestSimplify {}
 impl std::fmt::Display for SupportRequestSimplify {
@@ -61823,6 +64375,12 @@ impl std::fmt::Display for SupportRequestSimplify {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSimplify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSimplify"))
+    }
+}
 impl pg_sys::seal::Sealed for TableAmRoutine {}
 impl pg_sys::PgNode for TableAmRoutine {}
 impl std::fmt::Display for TableAmRoutine {
@@ -61830,6 +64388,12 @@ impl std::fmt::Display for TableAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFunc {}
 impl pg_sys::PgNode for TableFunc {}
 impl std::fmt::Display for TableFunc {
@@ -61837,6 +64401,12 @@ impl std::fmt::Display for TableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScan {}
 impl pg_sys::PgNode for TableFuncScan {}
 impl std::fmt::Display for TableFuncScan {
@@ -61844,6 +64414,12 @@ impl std::fmt::Display for TableFuncScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScanState {}
 impl pg_sys::PgNode for TableFuncScanState {}
 impl std::fmt::Display for TableFuncScanState {
@@ -61851,6 +64427,12 @@ impl std::fmt::Display for TableFuncScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TableLikeClause {}
 impl pg_sys::PgNode for TableLikeClause {}
 impl std::fmt::Display for TableLikeClause {
@@ -61858,6 +64440,12 @@ impl std::fmt::Display for TableLikeClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableLikeClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableLikeClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TableSampleClause {}
 impl pg_sys::PgNode for TableSampleClause {}
 impl std::fmt::Display for TableSampleClause {
@@ -61865,6 +64453,12 @@ impl std::fmt::Display for TableSampleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableSampleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableSampleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TargetEntry {}
 impl pg_sys::PgNode for TargetEntry {}
 impl std::fmt::Display for TargetEntry {
@@ -61872,6 +64466,12 @@ impl std::fmt::Display for TargetEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TargetEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TargetEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for TidPath {}
 impl pg_sys::PgNode for TidPath {}
 impl std::fmt::Display for TidPath {
@@ -61879,6 +64479,12 @@ impl std::fmt::Display for TidPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidPath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScan {}
 impl pg_sys::PgNode for TidScan {}
 impl std::fmt::Display for TidScan {
@@ -61886,6 +64492,12 @@ impl std::fmt::Display for TidScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScanState {}
 impl pg_sys::PgNode for TidScanState {}
 impl std::fmt::Display for TidScanState {
@@ -61893,6 +64505,12 @@ impl std::fmt::Display for TidScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TimeLineHistoryCmd {}
 impl pg_sys::PgNode for TimeLineHistoryCmd {}
 impl std::fmt::Display for TimeLineHistoryCmd {
@@ -61900,6 +64518,12 @@ impl std::fmt::Display for TimeLineHistoryCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TimeLineHistoryCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TimeLineHistoryCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for TransactionStmt {}
 impl pg_sys::PgNode for TransactionStmt {}
 impl std::fmt::Display for TransactionStmt {
@@ -61907,6 +64531,12 @@ impl std::fmt::Display for TransactionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TransactionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TransactionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerData {}
 impl pg_sys::PgNode for TriggerData {}
 impl std::fmt::Display for TriggerData {
@@ -61914,6 +64544,12 @@ impl std::fmt::Display for TriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerTransition {}
 impl pg_sys::PgNode for TriggerTransition {}
 impl std::fmt::Display for TriggerTransition {
@@ -61921,6 +64557,12 @@ impl std::fmt::Display for TriggerTransition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerTransition"))
+    }
+}
 impl pg_sys::seal::Sealed for TruncateStmt {}
 impl pg_sys::PgNode for TruncateStmt {}
 impl std::fmt::Display for TruncateStmt {
@@ -61928,6 +64570,12 @@ impl std::fmt::Display for TruncateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TruncateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TruncateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TupleTableSlot {}
 impl pg_sys::PgNode for TupleTableSlot {}
 impl std::fmt::Display for TupleTableSlot {
@@ -61935,6 +64583,12 @@ impl std::fmt::Display for TupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeCast {}
 impl pg_sys::PgNode for TypeCast {}
 impl std::fmt::Display for TypeCast {
@@ -61942,6 +64596,12 @@ impl std::fmt::Display for TypeCast {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeCast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeCast"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeName {}
 impl pg_sys::PgNode for TypeName {}
 impl std::fmt::Display for TypeName {
@@ -61949,6 +64609,12 @@ impl std::fmt::Display for TypeName {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeName"))
+    }
+}
 impl pg_sys::seal::Sealed for Unique {}
 impl pg_sys::PgNode for Unique {}
 impl std::fmt::Display for Unique {
@@ -61956,6 +64622,12 @@ impl std::fmt::Display for Unique {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Unique"))
+    }
+}
 impl pg_sys::seal::Sealed for UniquePath {}
 impl pg_sys::PgNode for UniquePath {}
 impl std::fmt::Display for UniquePath {
@@ -61963,6 +64635,12 @@ impl std::fmt::Display for UniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for UniqueState {}
 impl pg_sys::PgNode for UniqueState {}
 impl std::fmt::Display for UniqueState {
@@ -61970,6 +64648,12 @@ impl std::fmt::Display for UniqueState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniqueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniqueState"))
+    }
+}
 impl pg_sys::seal::Sealed for UnlistenStmt {}
 impl pg_sys::PgNode for UnlistenStmt {}
 impl std::fmt::Display for UnlistenStmt {
@@ -61977,6 +64661,12 @@ impl std::fmt::Display for UnlistenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UnlistenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UnlistenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpdateStmt {}
 impl pg_sys::PgNode for UpdateStmt {}
 impl std::fmt::Display for UpdateStmt {
@@ -61984,6 +64674,12 @@ impl std::fmt::Display for UpdateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpdateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpdateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpperUniquePath {}
 impl pg_sys::PgNode for UpperUniquePath {}
 impl std::fmt::Display for UpperUniquePath {
@@ -61991,6 +64687,12 @@ impl std::fmt::Display for UpperUniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpperUniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpperUniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumRelation {}
 impl pg_sys::PgNode for VacuumRelation {}
 impl std::fmt::Display for VacuumRelation {
@@ -61998,6 +64700,12 @@ impl std::fmt::Display for VacuumRelation {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumRelation"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumStmt {}
 impl pg_sys::PgNode for VacuumStmt {}
 impl std::fmt::Display for VacuumStmt {
@@ -62005,6 +64713,12 @@ impl std::fmt::Display for VacuumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Value {}
 impl pg_sys::PgNode for Value {}
 impl std::fmt::Display for Value {
@@ -62012,6 +64726,12 @@ impl std::fmt::Display for Value {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Value"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScan {}
 impl pg_sys::PgNode for ValuesScan {}
 impl std::fmt::Display for ValuesScan {
@@ -62019,6 +64739,12 @@ impl std::fmt::Display for ValuesScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScanState {}
 impl pg_sys::PgNode for ValuesScanState {}
 impl std::fmt::Display for ValuesScanState {
@@ -62026,6 +64752,12 @@ impl std::fmt::Display for ValuesScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Var {}
 impl pg_sys::PgNode for Var {}
 impl std::fmt::Display for Var {
@@ -62033,6 +64765,12 @@ impl std::fmt::Display for Var {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Var"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableSetStmt {}
 impl pg_sys::PgNode for VariableSetStmt {}
 impl std::fmt::Display for VariableSetStmt {
@@ -62040,6 +64778,12 @@ impl std::fmt::Display for VariableSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableShowStmt {}
 impl pg_sys::PgNode for VariableShowStmt {}
 impl std::fmt::Display for VariableShowStmt {
@@ -62047,6 +64791,12 @@ impl std::fmt::Display for VariableShowStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableShowStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableShowStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ViewStmt {}
 impl pg_sys::PgNode for ViewStmt {}
 impl std::fmt::Display for ViewStmt {
@@ -62054,6 +64804,12 @@ impl std::fmt::Display for ViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VirtualTupleTableSlot {}
 impl pg_sys::PgNode for VirtualTupleTableSlot {}
 impl std::fmt::Display for VirtualTupleTableSlot {
@@ -62061,6 +64817,12 @@ impl std::fmt::Display for VirtualTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VirtualTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VirtualTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAgg {}
 impl pg_sys::PgNode for WindowAgg {}
 impl std::fmt::Display for WindowAgg {
@@ -62068,6 +64830,12 @@ impl std::fmt::Display for WindowAgg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAgg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAgg"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggPath {}
 impl pg_sys::PgNode for WindowAggPath {}
 impl std::fmt::Display for WindowAggPath {
@@ -62075,6 +64843,12 @@ impl std::fmt::Display for WindowAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggState {}
 impl pg_sys::PgNode for WindowAggState {}
 impl std::fmt::Display for WindowAggState {
@@ -62082,6 +64856,12 @@ impl std::fmt::Display for WindowAggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggState"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowClause {}
 impl pg_sys::PgNode for WindowClause {}
 impl std::fmt::Display for WindowClause {
@@ -62089,6 +64869,12 @@ impl std::fmt::Display for WindowClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowDef {}
 impl pg_sys::PgNode for WindowDef {}
 impl std::fmt::Display for WindowDef {
@@ -62096,6 +64882,12 @@ impl std::fmt::Display for WindowDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowDef"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFunc {}
 impl pg_sys::PgNode for WindowFunc {}
 impl std::fmt::Display for WindowFunc {
@@ -62103,6 +64895,12 @@ impl std::fmt::Display for WindowFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFuncExprState {}
 impl pg_sys::PgNode for WindowFuncExprState {}
 impl std::fmt::Display for WindowFuncExprState {
@@ -62110,6 +64908,12 @@ impl std::fmt::Display for WindowFuncExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFuncExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFuncExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for WithCheckOption {}
 impl pg_sys::PgNode for WithCheckOption {}
 impl std::fmt::Display for WithCheckOption {
@@ -62117,6 +64921,12 @@ impl std::fmt::Display for WithCheckOption {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithCheckOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithCheckOption"))
+    }
+}
 impl pg_sys::seal::Sealed for WithClause {}
 impl pg_sys::PgNode for WithClause {}
 impl std::fmt::Display for WithClause {
@@ -62124,6 +64934,12 @@ impl std::fmt::Display for WithClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScan {}
 impl pg_sys::PgNode for WorkTableScan {}
 impl std::fmt::Display for WorkTableScan {
@@ -62131,6 +64947,12 @@ impl std::fmt::Display for WorkTableScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScan"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScanState {}
 impl pg_sys::PgNode for WorkTableScanState {}
 impl std::fmt::Display for WorkTableScanState {
@@ -62138,6 +64960,12 @@ impl std::fmt::Display for WorkTableScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlExpr {}
 impl pg_sys::PgNode for XmlExpr {}
 impl std::fmt::Display for XmlExpr {
@@ -62145,6 +64973,12 @@ impl std::fmt::Display for XmlExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlSerialize {}
 impl pg_sys::PgNode for XmlSerialize {}
 impl std::fmt::Display for XmlSerialize {
@@ -62152,3 +64986,9 @@ impl std::fmt::Display for XmlSerialize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlSerialize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlSerialize"))
+    }
+}
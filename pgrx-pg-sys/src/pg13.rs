@@ -6972,7 +6972,8 @@ pub const NodeTag_T_SupportRequestRows: NodeTag = 420;
 pub const NodeTag_T_SupportRequestIndexCondition: NodeTag = 421;
 pub type NodeTag = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Node {
     pub type_: NodeTag,
 }
@@ -8327,7 +8328,8 @@ pub struct BufferAccessStrategyData {
 }
 pub type BufferAccessStrategy = *mut BufferAccessStrategyData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TupleTableSlot {
     pub type_: NodeTag,
     pub tts_flags: uint16,
@@ -8392,7 +8394,8 @@ extern "C" {
     pub static TTSOpsBufferHeapTuple: TupleTableSlotOps;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VirtualTupleTableSlot {
     pub base: TupleTableSlot,
     pub data: *mut ::std::os::raw::c_char,
@@ -8407,7 +8410,8 @@ impl Default for VirtualTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HeapTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -8424,7 +8428,8 @@ impl Default for HeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BufferHeapTupleTableSlot {
     pub base: HeapTupleTableSlot,
     pub buffer: Buffer,
@@ -8439,7 +8444,8 @@ impl Default for BufferHeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinimalTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -9849,7 +9855,8 @@ pub const LockTupleMode_LockTupleNoKeyExclusive: LockTupleMode = 2;
 pub const LockTupleMode_LockTupleExclusive: LockTupleMode = 3;
 pub type LockTupleMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Alias {
     pub type_: NodeTag,
     pub aliasname: *mut ::std::os::raw::c_char,
@@ -9870,7 +9877,8 @@ pub const OnCommitAction_ONCOMMIT_DELETE_ROWS: OnCommitAction = 2;
 pub const OnCommitAction_ONCOMMIT_DROP: OnCommitAction = 3;
 pub type OnCommitAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeVar {
     pub type_: NodeTag,
     pub catalogname: *mut ::std::os::raw::c_char,
@@ -9891,7 +9899,8 @@ impl Default for RangeVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFunc {
     pub type_: NodeTag,
     pub ns_uris: *mut List,
@@ -9918,7 +9927,8 @@ impl Default for TableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IntoClause {
     pub type_: NodeTag,
     pub rel: *mut RangeVar,
@@ -9940,7 +9950,8 @@ impl Default for IntoClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Expr {
     pub type_: NodeTag,
 }
@@ -9954,7 +9965,8 @@ impl Default for Expr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Var {
     pub xpr: Expr,
     pub varno: Index,
@@ -9977,7 +9989,8 @@ impl Default for Var {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Const {
     pub xpr: Expr,
     pub consttype: Oid,
@@ -10004,7 +10017,8 @@ pub const ParamKind_PARAM_SUBLINK: ParamKind = 2;
 pub const ParamKind_PARAM_MULTIEXPR: ParamKind = 3;
 pub type ParamKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Param {
     pub xpr: Expr,
     pub paramkind: ParamKind,
@@ -10024,7 +10038,8 @@ impl Default for Param {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Aggref {
     pub xpr: Expr,
     pub aggfnoid: Oid,
@@ -10055,7 +10070,8 @@ impl Default for Aggref {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingFunc {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10074,7 +10090,8 @@ impl Default for GroupingFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFunc {
     pub xpr: Expr,
     pub winfnoid: Oid,
@@ -10098,7 +10115,8 @@ impl Default for WindowFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubscriptingRef {
     pub xpr: Expr,
     pub refcontainertype: Oid,
@@ -10128,7 +10146,8 @@ pub const CoercionForm_COERCE_EXPLICIT_CAST: CoercionForm = 1;
 pub const CoercionForm_COERCE_IMPLICIT_CAST: CoercionForm = 2;
 pub type CoercionForm = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncExpr {
     pub xpr: Expr,
     pub funcid: Oid,
@@ -10151,7 +10170,8 @@ impl Default for FuncExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedArgExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10169,7 +10189,8 @@ impl Default for NamedArgExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -10193,7 +10214,8 @@ impl Default for OpExpr {
 pub type DistinctExpr = OpExpr;
 pub type NullIfExpr = OpExpr;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScalarArrayOpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -10217,7 +10239,8 @@ pub const BoolExprType_OR_EXPR: BoolExprType = 1;
 pub const BoolExprType_NOT_EXPR: BoolExprType = 2;
 pub type BoolExprType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BoolExpr {
     pub xpr: Expr,
     pub boolop: BoolExprType,
@@ -10243,7 +10266,8 @@ pub const SubLinkType_ARRAY_SUBLINK: SubLinkType = 6;
 pub const SubLinkType_CTE_SUBLINK: SubLinkType = 7;
 pub type SubLinkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubLink {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10263,7 +10287,8 @@ impl Default for SubLink {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlan {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10294,7 +10319,8 @@ impl Default for SubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlan {
     pub xpr: Expr,
     pub subplans: *mut List,
@@ -10309,7 +10335,8 @@ impl Default for AlternativeSubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldSelect {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10328,7 +10355,8 @@ impl Default for FieldSelect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldStore {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10346,7 +10374,8 @@ impl Default for FieldStore {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelabelType {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10366,7 +10395,8 @@ impl Default for RelabelType {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceViaIO {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10385,7 +10415,8 @@ impl Default for CoerceViaIO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayCoerceExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10406,7 +10437,8 @@ impl Default for ArrayCoerceExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConvertRowtypeExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10424,7 +10456,8 @@ impl Default for ConvertRowtypeExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10441,7 +10474,8 @@ impl Default for CollateExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseExpr {
     pub xpr: Expr,
     pub casetype: Oid,
@@ -10461,7 +10495,8 @@ impl Default for CaseExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseWhen {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10478,7 +10513,8 @@ impl Default for CaseWhen {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseTestExpr {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10495,7 +10531,8 @@ impl Default for CaseTestExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayExpr {
     pub xpr: Expr,
     pub array_typeid: Oid,
@@ -10515,7 +10552,8 @@ impl Default for ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowExpr {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10541,7 +10579,8 @@ pub const RowCompareType_ROWCOMPARE_GT: RowCompareType = 5;
 pub const RowCompareType_ROWCOMPARE_NE: RowCompareType = 6;
 pub type RowCompareType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowCompareExpr {
     pub xpr: Expr,
     pub rctype: RowCompareType,
@@ -10561,7 +10600,8 @@ impl Default for RowCompareExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoalesceExpr {
     pub xpr: Expr,
     pub coalescetype: Oid,
@@ -10582,7 +10622,8 @@ pub const MinMaxOp_IS_GREATEST: MinMaxOp = 0;
 pub const MinMaxOp_IS_LEAST: MinMaxOp = 1;
 pub type MinMaxOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxExpr {
     pub xpr: Expr,
     pub minmaxtype: Oid,
@@ -10618,7 +10659,8 @@ pub const SQLValueFunctionOp_SVFOP_CURRENT_CATALOG: SQLValueFunctionOp = 13;
 pub const SQLValueFunctionOp_SVFOP_CURRENT_SCHEMA: SQLValueFunctionOp = 14;
 pub type SQLValueFunctionOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLValueFunction {
     pub xpr: Expr,
     pub op: SQLValueFunctionOp,
@@ -10648,7 +10690,8 @@ pub const XmlOptionType_XMLOPTION_DOCUMENT: XmlOptionType = 0;
 pub const XmlOptionType_XMLOPTION_CONTENT: XmlOptionType = 1;
 pub type XmlOptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlExpr {
     pub xpr: Expr,
     pub op: XmlExprOp,
@@ -10674,7 +10717,8 @@ pub const NullTestType_IS_NULL: NullTestType = 0;
 pub const NullTestType_IS_NOT_NULL: NullTestType = 1;
 pub type NullTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NullTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10699,7 +10743,8 @@ pub const BoolTestType_IS_UNKNOWN: BoolTestType = 4;
 pub const BoolTestType_IS_NOT_UNKNOWN: BoolTestType = 5;
 pub type BoolTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BooleanTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10716,7 +10761,8 @@ impl Default for BooleanTest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomain {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10736,7 +10782,8 @@ impl Default for CoerceToDomain {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomainValue {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10754,7 +10801,8 @@ impl Default for CoerceToDomainValue {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetToDefault {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10772,7 +10820,8 @@ impl Default for SetToDefault {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CurrentOfExpr {
     pub xpr: Expr,
     pub cvarno: Index,
@@ -10789,7 +10838,8 @@ impl Default for CurrentOfExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NextValueExpr {
     pub xpr: Expr,
     pub seqid: Oid,
@@ -10805,7 +10855,8 @@ impl Default for NextValueExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferenceElem {
     pub xpr: Expr,
     pub expr: *mut Node,
@@ -10822,7 +10873,8 @@ impl Default for InferenceElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TargetEntry {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10843,7 +10895,8 @@ impl Default for TargetEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblRef {
     pub type_: NodeTag,
     pub rtindex: ::std::os::raw::c_int,
@@ -10858,7 +10911,8 @@ impl Default for RangeTblRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinExpr {
     pub type_: NodeTag,
     pub jointype: JoinType,
@@ -10880,7 +10934,8 @@ impl Default for JoinExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FromExpr {
     pub type_: NodeTag,
     pub fromlist: *mut List,
@@ -10896,7 +10951,8 @@ impl Default for FromExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictExpr {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -10918,7 +10974,8 @@ impl Default for OnConflictExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannedStmt {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -10955,7 +11012,8 @@ impl Default for PlannedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Plan {
     pub type_: NodeTag,
     pub startup_cost: Cost,
@@ -10983,7 +11041,8 @@ impl Default for Plan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Result {
     pub plan: Plan,
     pub resconstantqual: *mut Node,
@@ -10998,7 +11057,8 @@ impl Default for Result {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSet {
     pub plan: Plan,
 }
@@ -11012,7 +11072,8 @@ impl Default for ProjectSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTable {
     pub plan: Plan,
     pub operation: CmdType,
@@ -11047,7 +11108,8 @@ impl Default for ModifyTable {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Append {
     pub plan: Plan,
     pub apprelids: *mut Bitmapset,
@@ -11065,7 +11127,8 @@ impl Default for Append {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppend {
     pub plan: Plan,
     pub apprelids: *mut Bitmapset,
@@ -11087,7 +11150,8 @@ impl Default for MergeAppend {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnion {
     pub plan: Plan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11107,7 +11171,8 @@ impl Default for RecursiveUnion {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAnd {
     pub plan: Plan,
     pub bitmapplans: *mut List,
@@ -11122,7 +11187,8 @@ impl Default for BitmapAnd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOr {
     pub plan: Plan,
     pub isshared: bool,
@@ -11138,7 +11204,8 @@ impl Default for BitmapOr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Scan {
     pub plan: Plan,
     pub scanrelid: Index,
@@ -11154,7 +11221,8 @@ impl Default for Scan {
 }
 pub type SeqScan = Scan;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScan {
     pub scan: Scan,
     pub tablesample: *mut TableSampleClause,
@@ -11169,7 +11237,8 @@ impl Default for SampleScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11190,7 +11259,8 @@ impl Default for IndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11210,7 +11280,8 @@ impl Default for IndexOnlyScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11228,7 +11299,8 @@ impl Default for BitmapIndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScan {
     pub scan: Scan,
     pub bitmapqualorig: *mut List,
@@ -11243,7 +11315,8 @@ impl Default for BitmapHeapScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScan {
     pub scan: Scan,
     pub tidquals: *mut List,
@@ -11258,7 +11331,8 @@ impl Default for TidScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScan {
     pub scan: Scan,
     pub subplan: *mut Plan,
@@ -11273,7 +11347,8 @@ impl Default for SubqueryScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScan {
     pub scan: Scan,
     pub functions: *mut List,
@@ -11289,7 +11364,8 @@ impl Default for FunctionScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScan {
     pub scan: Scan,
     pub values_lists: *mut List,
@@ -11304,7 +11380,8 @@ impl Default for ValuesScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScan {
     pub scan: Scan,
     pub tablefunc: *mut TableFunc,
@@ -11319,7 +11396,8 @@ impl Default for TableFuncScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScan {
     pub scan: Scan,
     pub ctePlanId: ::std::os::raw::c_int,
@@ -11335,7 +11413,8 @@ impl Default for CteScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScan {
     pub scan: Scan,
     pub enrname: *mut ::std::os::raw::c_char,
@@ -11350,7 +11429,8 @@ impl Default for NamedTuplestoreScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScan {
     pub scan: Scan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11365,7 +11445,8 @@ impl Default for WorkTableScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScan {
     pub scan: Scan,
     pub operation: CmdType,
@@ -11387,7 +11468,8 @@ impl Default for ForeignScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScan {
     pub scan: Scan,
     pub flags: uint32,
@@ -11408,7 +11490,8 @@ impl Default for CustomScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Join {
     pub plan: Plan,
     pub jointype: JoinType,
@@ -11425,7 +11508,8 @@ impl Default for Join {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoop {
     pub join: Join,
     pub nestParams: *mut List,
@@ -11440,7 +11524,8 @@ impl Default for NestLoop {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopParam {
     pub type_: NodeTag,
     pub paramno: ::std::os::raw::c_int,
@@ -11456,7 +11541,8 @@ impl Default for NestLoopParam {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoin {
     pub join: Join,
     pub skip_mark_restore: bool,
@@ -11476,7 +11562,8 @@ impl Default for MergeJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoin {
     pub join: Join,
     pub hashclauses: *mut List,
@@ -11494,7 +11581,8 @@ impl Default for HashJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Material {
     pub plan: Plan,
 }
@@ -11508,7 +11596,8 @@ impl Default for Material {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Sort {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11527,7 +11616,8 @@ impl Default for Sort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSort {
     pub sort: Sort,
     pub nPresortedCols: ::std::os::raw::c_int,
@@ -11542,7 +11632,8 @@ impl Default for IncrementalSort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Group {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11560,7 +11651,8 @@ impl Default for Group {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Agg {
     pub plan: Plan,
     pub aggstrategy: AggStrategy,
@@ -11585,7 +11677,8 @@ impl Default for Agg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAgg {
     pub plan: Plan,
     pub winref: Index,
@@ -11616,7 +11709,8 @@ impl Default for WindowAgg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Unique {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11634,7 +11728,8 @@ impl Default for Unique {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Gather {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11653,7 +11748,8 @@ impl Default for Gather {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMerge {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11675,7 +11771,8 @@ impl Default for GatherMerge {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Hash {
     pub plan: Plan,
     pub hashkeys: *mut List,
@@ -11694,7 +11791,8 @@ impl Default for Hash {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOp {
     pub plan: Plan,
     pub cmd: SetOpCmd,
@@ -11717,7 +11815,8 @@ impl Default for SetOp {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRows {
     pub plan: Plan,
     pub rowMarks: *mut List,
@@ -11733,7 +11832,8 @@ impl Default for LockRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Limit {
     pub plan: Plan,
     pub limitOffset: *mut Node,
@@ -11761,7 +11861,8 @@ pub const RowMarkType_ROW_MARK_REFERENCE: RowMarkType = 4;
 pub const RowMarkType_ROW_MARK_COPY: RowMarkType = 5;
 pub type RowMarkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanRowMark {
     pub type_: NodeTag,
     pub rti: Index,
@@ -11783,7 +11884,8 @@ impl Default for PlanRowMark {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneInfo {
     pub type_: NodeTag,
     pub prune_infos: *mut List,
@@ -11799,7 +11901,8 @@ impl Default for PartitionPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionedRelPruneInfo {
     pub type_: NodeTag,
     pub rtindex: Index,
@@ -11822,7 +11925,8 @@ impl Default for PartitionedRelPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStep {
     pub type_: NodeTag,
     pub step_id: ::std::os::raw::c_int,
@@ -11837,7 +11941,8 @@ impl Default for PartitionPruneStep {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepOp {
     pub step: PartitionPruneStep,
     pub opstrategy: StrategyNumber,
@@ -11858,7 +11963,8 @@ pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_UNION: PartitionPruneCombine
 pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_INTERSECT: PartitionPruneCombineOp = 1;
 pub type PartitionPruneCombineOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepCombine {
     pub step: PartitionPruneStep,
     pub combineOp: PartitionPruneCombineOp,
@@ -11874,7 +11980,8 @@ impl Default for PartitionPruneStepCombine {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanInvalItem {
     pub type_: NodeTag,
     pub cacheId: ::std::os::raw::c_int,
@@ -13871,7 +13978,8 @@ pub type ExprStateEvalFunc = ::std::option::Option<
     ) -> Datum,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprState {
     pub tag: NodeTag,
     pub flags: uint8,
@@ -13901,7 +14009,8 @@ impl Default for ExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexInfo {
     pub type_: NodeTag,
     pub ii_NumIndexAttrs: ::std::os::raw::c_int,
@@ -13954,7 +14063,8 @@ impl Default for ExprContext_CB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprContext {
     pub type_: NodeTag,
     pub ecxt_scantuple: *mut TupleTableSlot,
@@ -13992,7 +14102,8 @@ pub const SetFunctionReturnMode_SFRM_Materialize_Random: SetFunctionReturnMode =
 pub const SetFunctionReturnMode_SFRM_Materialize_Preferred: SetFunctionReturnMode = 8;
 pub type SetFunctionReturnMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnSetInfo {
     pub type_: NodeTag,
     pub econtext: *mut ExprContext,
@@ -14013,7 +14124,8 @@ impl Default for ReturnSetInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionInfo {
     pub type_: NodeTag,
     pub pi_state: ExprState,
@@ -14029,7 +14141,8 @@ impl Default for ProjectionInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JunkFilter {
     pub type_: NodeTag,
     pub jf_targetList: *mut List,
@@ -14048,7 +14161,8 @@ impl Default for JunkFilter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictSetState {
     pub type_: NodeTag,
     pub oc_Existing: *mut TupleTableSlot,
@@ -14066,7 +14180,8 @@ impl Default for OnConflictSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultRelInfo {
     pub type_: NodeTag,
     pub ri_RangeTableIndex: Index,
@@ -14125,7 +14240,8 @@ impl Default for ResultRelInfoExtra {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EState {
     pub type_: NodeTag,
     pub es_direction: ScanDirection,
@@ -14394,7 +14510,8 @@ impl Default for TupleHashTableData {
 }
 pub type TupleHashIterator = tuplehash_iterator;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggrefExprState {
     pub type_: NodeTag,
     pub aggref: *mut Aggref,
@@ -14410,7 +14527,8 @@ impl Default for AggrefExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFuncExprState {
     pub type_: NodeTag,
     pub wfunc: *mut WindowFunc,
@@ -14428,7 +14546,8 @@ impl Default for WindowFuncExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetExprState {
     pub type_: NodeTag,
     pub expr: *mut Expr,
@@ -14454,7 +14573,8 @@ impl Default for SetExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut SubPlan,
@@ -14494,7 +14614,8 @@ impl Default for SubPlanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut AlternativeSubPlan,
@@ -14514,7 +14635,8 @@ pub const DomainConstraintType_DOM_CONSTRAINT_NOTNULL: DomainConstraintType = 0;
 pub const DomainConstraintType_DOM_CONSTRAINT_CHECK: DomainConstraintType = 1;
 pub type DomainConstraintType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DomainConstraintState {
     pub type_: NodeTag,
     pub constrainttype: DomainConstraintType,
@@ -14534,7 +14656,8 @@ impl Default for DomainConstraintState {
 pub type ExecProcNodeMtd =
     ::std::option::Option<unsafe extern "C" fn(pstate: *mut PlanState) -> *mut TupleTableSlot>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanState {
     pub type_: NodeTag,
     pub plan: *mut Plan,
@@ -14602,7 +14725,8 @@ impl Default for EPQState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultState {
     pub ps: PlanState,
     pub resconstantqual: *mut ExprState,
@@ -14619,7 +14743,8 @@ impl Default for ResultState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetState {
     pub ps: PlanState,
     pub elems: *mut *mut Node,
@@ -14638,7 +14763,8 @@ impl Default for ProjectSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTableState {
     pub ps: PlanState,
     pub operation: CmdType,
@@ -14679,7 +14805,8 @@ pub struct PartitionPruneState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendState {
     pub ps: PlanState,
     pub appendplans: *mut *mut PlanState,
@@ -14703,7 +14830,8 @@ impl Default for AppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendState {
     pub ps: PlanState,
     pub mergeplans: *mut *mut PlanState,
@@ -14726,7 +14854,8 @@ impl Default for MergeAppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionState {
     pub ps: PlanState,
     pub recursing: bool,
@@ -14749,7 +14878,8 @@ impl Default for RecursiveUnionState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -14765,7 +14895,8 @@ impl Default for BitmapAndState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -14781,7 +14912,8 @@ impl Default for BitmapOrState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScanState {
     pub ps: PlanState,
     pub ss_currentRelation: Relation,
@@ -14798,7 +14930,8 @@ impl Default for ScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SeqScanState {
     pub ss: ScanState,
     pub pscan_len: Size,
@@ -14813,7 +14946,8 @@ impl Default for SeqScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScanState {
     pub ss: ScanState,
     pub args: *mut List,
@@ -14873,7 +15007,8 @@ impl Default for IndexArrayKeyInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScanState {
     pub ss: ScanState,
     pub indexqualorig: *mut ExprState,
@@ -14907,7 +15042,8 @@ impl Default for IndexScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScanState {
     pub ss: ScanState,
     pub recheckqual: *mut ExprState,
@@ -14935,7 +15071,8 @@ impl Default for IndexOnlyScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScanState {
     pub ss: ScanState,
     pub biss_result: *mut TIDBitmap,
@@ -14985,7 +15122,8 @@ impl Default for ParallelBitmapHeapState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScanState {
     pub ss: ScanState,
     pub bitmapqualorig: *mut ExprState,
@@ -15018,7 +15156,8 @@ impl Default for BitmapHeapScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScanState {
     pub ss: ScanState,
     pub tss_tidexprs: *mut List,
@@ -15038,7 +15177,8 @@ impl Default for TidScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanState {
     pub ss: ScanState,
     pub subplan: *mut PlanState,
@@ -15058,7 +15198,8 @@ pub struct FunctionScanPerFuncState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -15079,7 +15220,8 @@ impl Default for FunctionScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScanState {
     pub ss: ScanState,
     pub rowcontext: *mut ExprContext,
@@ -15098,7 +15240,8 @@ impl Default for ValuesScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScanState {
     pub ss: ScanState,
     pub docexpr: *mut ExprState,
@@ -15126,7 +15269,8 @@ impl Default for TableFuncScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -15146,7 +15290,8 @@ impl Default for CteScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScanState {
     pub ss: ScanState,
     pub readptr: ::std::os::raw::c_int,
@@ -15163,7 +15308,8 @@ impl Default for NamedTuplestoreScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScanState {
     pub ss: ScanState,
     pub rustate: *mut RecursiveUnionState,
@@ -15178,7 +15324,8 @@ impl Default for WorkTableScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScanState {
     pub ss: ScanState,
     pub fdw_recheck_quals: *mut ExprState,
@@ -15196,7 +15343,8 @@ impl Default for ForeignScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScanState {
     pub ss: ScanState,
     pub flags: uint32,
@@ -15214,7 +15362,8 @@ impl Default for CustomScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinState {
     pub ps: PlanState,
     pub jointype: JoinType,
@@ -15231,7 +15380,8 @@ impl Default for JoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopState {
     pub js: JoinState,
     pub nl_NeedNewOuter: bool,
@@ -15254,7 +15404,8 @@ pub struct MergeJoinClauseData {
 }
 pub type MergeJoinClause = *mut MergeJoinClauseData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoinState {
     pub js: JoinState,
     pub mj_NumClauses: ::std::os::raw::c_int,
@@ -15297,7 +15448,8 @@ pub struct HashJoinTableData {
 }
 pub type HashJoinTable = *mut HashJoinTableData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoinState {
     pub js: JoinState,
     pub hashclauses: *mut ExprState,
@@ -15328,7 +15480,8 @@ impl Default for HashJoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -15376,7 +15529,8 @@ impl Default for SharedSortInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortState {
     pub ss: ScanState,
     pub randomAccess: bool,
@@ -15426,7 +15580,8 @@ pub const IncrementalSortExecutionStatus_INCSORT_READFULLSORT: IncrementalSortEx
 pub const IncrementalSortExecutionStatus_INCSORT_READPREFIXSORT: IncrementalSortExecutionStatus = 3;
 pub type IncrementalSortExecutionStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSortState {
     pub ss: ScanState,
     pub bounded: bool,
@@ -15454,7 +15609,8 @@ impl Default for IncrementalSortState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupState {
     pub ss: ScanState,
     pub eqfunction: *mut ExprState,
@@ -15513,7 +15669,8 @@ pub struct AggStatePerHashData {
 }
 pub type AggStatePerHash = *mut AggStatePerHashData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggState {
     pub ss: ScanState,
     pub aggs: *mut List,
@@ -15594,7 +15751,8 @@ pub struct WindowStatePerAggData {
 }
 pub type WindowStatePerAgg = *mut WindowStatePerAggData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggState {
     pub ss: ScanState,
     pub funcs: *mut List,
@@ -15659,7 +15817,8 @@ impl Default for WindowAggState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniqueState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -15674,7 +15833,8 @@ impl Default for UniqueState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -15702,7 +15862,8 @@ pub struct GMReaderTupleBuffer {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergeState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -15745,7 +15906,8 @@ pub struct SharedHashInfo {
     pub hinstrument: __IncompleteArrayField<HashInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashState {
     pub ps: PlanState,
     pub hashtable: HashJoinTable,
@@ -15770,7 +15932,8 @@ pub struct SetOpStatePerGroupData {
 }
 pub type SetOpStatePerGroup = *mut SetOpStatePerGroupData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -15795,7 +15958,8 @@ impl Default for SetOpState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsState {
     pub ps: PlanState,
     pub lr_arowMarks: *mut List,
@@ -15820,7 +15984,8 @@ pub const LimitStateCond_LIMIT_WINDOWEND: LimitStateCond = 6;
 pub const LimitStateCond_LIMIT_WINDOWSTART: LimitStateCond = 7;
 pub type LimitStateCond = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitState {
     pub ps: PlanState,
     pub limitOffset: *mut ExprState,
@@ -16259,7 +16424,8 @@ pub type SortByNulls = ::std::os::raw::c_uint;
 pub type AclMode = uint32;
 #[doc = "\tQuery Tree"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Query {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -16311,7 +16477,8 @@ impl Default for Query {
 }
 #[doc = "\tSupporting data structures for Parse Trees\n\n\tMost of these node types appear in raw parsetrees output by the grammar,\n\tand get transformed to something else by the analyzer.  A few of them\n\tare used as-is in transformed querytrees."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeName {
     pub type_: NodeTag,
     pub names: *mut List,
@@ -16333,7 +16500,8 @@ impl Default for TypeName {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnRef {
     pub type_: NodeTag,
     pub fields: *mut List,
@@ -16349,7 +16517,8 @@ impl Default for ColumnRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamRef {
     pub type_: NodeTag,
     pub number: ::std::os::raw::c_int,
@@ -16382,7 +16551,8 @@ pub const A_Expr_Kind_AEXPR_NOT_BETWEEN_SYM: A_Expr_Kind = 14;
 pub const A_Expr_Kind_AEXPR_PAREN: A_Expr_Kind = 15;
 pub type A_Expr_Kind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Expr {
     pub type_: NodeTag,
     pub kind: A_Expr_Kind,
@@ -16417,7 +16587,8 @@ impl Default for A_Const {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeCast {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -16434,7 +16605,8 @@ impl Default for TypeCast {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateClause {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -16456,7 +16628,8 @@ pub const RoleSpecType_ROLESPEC_SESSION_USER: RoleSpecType = 2;
 pub const RoleSpecType_ROLESPEC_PUBLIC: RoleSpecType = 3;
 pub type RoleSpecType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RoleSpec {
     pub type_: NodeTag,
     pub roletype: RoleSpecType,
@@ -16473,7 +16646,8 @@ impl Default for RoleSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncCall {
     pub type_: NodeTag,
     pub funcname: *mut List,
@@ -16497,7 +16671,8 @@ impl Default for FuncCall {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Star {
     pub type_: NodeTag,
 }
@@ -16511,7 +16686,8 @@ impl Default for A_Star {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indices {
     pub type_: NodeTag,
     pub is_slice: bool,
@@ -16528,7 +16704,8 @@ impl Default for A_Indices {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indirection {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -16544,7 +16721,8 @@ impl Default for A_Indirection {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_ArrayExpr {
     pub type_: NodeTag,
     pub elements: *mut List,
@@ -16560,7 +16738,8 @@ impl Default for A_ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResTarget {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16578,7 +16757,8 @@ impl Default for ResTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MultiAssignRef {
     pub type_: NodeTag,
     pub source: *mut Node,
@@ -16595,7 +16775,8 @@ impl Default for MultiAssignRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortBy {
     pub type_: NodeTag,
     pub node: *mut Node,
@@ -16614,7 +16795,8 @@ impl Default for SortBy {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowDef {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16636,7 +16818,8 @@ impl Default for WindowDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeSubselect {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -16653,7 +16836,8 @@ impl Default for RangeSubselect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeFunction {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -16673,7 +16857,8 @@ impl Default for RangeFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFunc {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -16694,7 +16879,8 @@ impl Default for RangeTableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFuncCol {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -16715,7 +16901,8 @@ impl Default for RangeTableFuncCol {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableSample {
     pub type_: NodeTag,
     pub relation: *mut Node,
@@ -16734,7 +16921,8 @@ impl Default for RangeTableSample {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnDef {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -16765,7 +16953,8 @@ impl Default for ColumnDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableLikeClause {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -16792,7 +16981,8 @@ pub const TableLikeOption_CREATE_TABLE_LIKE_STORAGE: TableLikeOption = 128;
 pub const TableLikeOption_CREATE_TABLE_LIKE_ALL: TableLikeOption = 2147483647;
 pub type TableLikeOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16819,7 +17009,8 @@ pub const DefElemAction_DEFELEM_ADD: DefElemAction = 2;
 pub const DefElemAction_DEFELEM_DROP: DefElemAction = 3;
 pub type DefElemAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefElem {
     pub type_: NodeTag,
     pub defnamespace: *mut ::std::os::raw::c_char,
@@ -16838,7 +17029,8 @@ impl Default for DefElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockingClause {
     pub type_: NodeTag,
     pub lockedRels: *mut List,
@@ -16855,7 +17047,8 @@ impl Default for LockingClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlSerialize {
     pub type_: NodeTag,
     pub xmloption: XmlOptionType,
@@ -16873,7 +17066,8 @@ impl Default for XmlSerialize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -16892,7 +17086,8 @@ impl Default for PartitionElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionSpec {
     pub type_: NodeTag,
     pub strategy: *mut ::std::os::raw::c_char,
@@ -16909,7 +17104,8 @@ impl Default for PartitionSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionBoundSpec {
     pub type_: NodeTag,
     pub strategy: ::std::os::raw::c_char,
@@ -16935,7 +17131,8 @@ pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_VALUE: PartitionRangeDat
 pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_MAXVALUE: PartitionRangeDatumKind = 1;
 pub type PartitionRangeDatumKind = ::std::os::raw::c_int;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionRangeDatum {
     pub type_: NodeTag,
     pub kind: PartitionRangeDatumKind,
@@ -16952,7 +17149,8 @@ impl Default for PartitionRangeDatum {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionCmd {
     pub type_: NodeTag,
     pub name: *mut RangeVar,
@@ -16978,7 +17176,8 @@ pub const RTEKind_RTE_NAMEDTUPLESTORE: RTEKind = 7;
 pub const RTEKind_RTE_RESULT: RTEKind = 8;
 pub type RTEKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblEntry {
     pub type_: NodeTag,
     pub rtekind: RTEKind,
@@ -17028,7 +17227,8 @@ impl Default for RangeTblEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblFunction {
     pub type_: NodeTag,
     pub funcexpr: *mut Node,
@@ -17049,7 +17249,8 @@ impl Default for RangeTblFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableSampleClause {
     pub type_: NodeTag,
     pub tsmhandler: Oid,
@@ -17071,7 +17272,8 @@ pub const WCOKind_WCO_RLS_UPDATE_CHECK: WCOKind = 2;
 pub const WCOKind_WCO_RLS_CONFLICT_CHECK: WCOKind = 3;
 pub type WCOKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithCheckOption {
     pub type_: NodeTag,
     pub kind: WCOKind,
@@ -17090,7 +17292,8 @@ impl Default for WithCheckOption {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortGroupClause {
     pub type_: NodeTag,
     pub tleSortGroupRef: Index,
@@ -17115,7 +17318,8 @@ pub const GroupingSetKind_GROUPING_SET_CUBE: GroupingSetKind = 3;
 pub const GroupingSetKind_GROUPING_SET_SETS: GroupingSetKind = 4;
 pub type GroupingSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSet {
     pub type_: NodeTag,
     pub kind: GroupingSetKind,
@@ -17132,7 +17336,8 @@ impl Default for GroupingSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowClause {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17160,7 +17365,8 @@ impl Default for WindowClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowMarkClause {
     pub type_: NodeTag,
     pub rti: Index,
@@ -17178,7 +17384,8 @@ impl Default for RowMarkClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithClause {
     pub type_: NodeTag,
     pub ctes: *mut List,
@@ -17195,7 +17402,8 @@ impl Default for WithClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferClause {
     pub type_: NodeTag,
     pub indexElems: *mut List,
@@ -17213,7 +17421,8 @@ impl Default for InferClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictClause {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -17236,7 +17445,8 @@ pub const CTEMaterialize_CTEMaterializeAlways: CTEMaterialize = 1;
 pub const CTEMaterialize_CTEMaterializeNever: CTEMaterialize = 2;
 pub type CTEMaterialize = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommonTableExpr {
     pub type_: NodeTag,
     pub ctename: *mut ::std::os::raw::c_char,
@@ -17261,7 +17471,8 @@ impl Default for CommonTableExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerTransition {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17279,7 +17490,8 @@ impl Default for TriggerTransition {
 }
 #[doc = "\t\tRaw Grammar Output Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RawStmt {
     pub type_: NodeTag,
     pub stmt: *mut Node,
@@ -17297,7 +17509,8 @@ impl Default for RawStmt {
 }
 #[doc = "\t\tOptimizable Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InsertStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17318,7 +17531,8 @@ impl Default for InsertStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeleteStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17337,7 +17551,8 @@ impl Default for DeleteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpdateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17362,7 +17577,8 @@ pub const SetOperation_SETOP_INTERSECT: SetOperation = 2;
 pub const SetOperation_SETOP_EXCEPT: SetOperation = 3;
 pub type SetOperation = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SelectStmt {
     pub type_: NodeTag,
     pub distinctClause: *mut List,
@@ -17395,7 +17611,8 @@ impl Default for SelectStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOperationStmt {
     pub type_: NodeTag,
     pub op: SetOperation,
@@ -17469,7 +17686,8 @@ pub const ObjectType_OBJECT_VIEW: ObjectType = 49;
 #[doc = "\t\tOther Statements (no optimizations required)\n\n\t\tThese are not touched by parser/analyze.c except to put them into\n\t\tthe utilityStmt field of a Query.  This is eventually passed to\n\t\tProcessUtility (by-passing rewriting and planning).  Some of the\n\t\tstatements do need attention from parse analysis, and this is\n\t\tdone by routines in parser/parse_utilcmd.c after ProcessUtility\n\t\treceives the command for execution.\n\t\tDECLARE CURSOR, EXPLAIN, and CREATE TABLE AS are special cases:\n\t\tthey contain optimizable statements, which get processed normally\n\t\tby parser/analyze.c."]
 pub type ObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSchemaStmt {
     pub type_: NodeTag,
     pub schemaname: *mut ::std::os::raw::c_char,
@@ -17490,7 +17708,8 @@ pub const DropBehavior_DROP_RESTRICT: DropBehavior = 0;
 pub const DropBehavior_DROP_CASCADE: DropBehavior = 1;
 pub type DropBehavior = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17576,7 +17795,8 @@ pub const AlterTableType_AT_SetIdentity: AlterTableType = 65;
 pub const AlterTableType_AT_DropIdentity: AlterTableType = 66;
 pub type AlterTableType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReplicaIdentityStmt {
     pub type_: NodeTag,
     pub identity_type: ::std::os::raw::c_char,
@@ -17592,7 +17812,8 @@ impl Default for ReplicaIdentityStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableCmd {
     pub type_: NodeTag,
     pub subtype: AlterTableType,
@@ -17614,7 +17835,8 @@ impl Default for AlterTableCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterCollationStmt {
     pub type_: NodeTag,
     pub collname: *mut List,
@@ -17629,7 +17851,8 @@ impl Default for AlterCollationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDomainStmt {
     pub type_: NodeTag,
     pub subtype: ::std::os::raw::c_char,
@@ -17653,7 +17876,8 @@ pub const GrantTargetType_ACL_TARGET_ALL_IN_SCHEMA: GrantTargetType = 1;
 pub const GrantTargetType_ACL_TARGET_DEFAULTS: GrantTargetType = 2;
 pub type GrantTargetType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantStmt {
     pub type_: NodeTag,
     pub is_grant: bool,
@@ -17675,7 +17899,8 @@ impl Default for GrantStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ObjectWithArgs {
     pub type_: NodeTag,
     pub objname: *mut List,
@@ -17692,7 +17917,8 @@ impl Default for ObjectWithArgs {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AccessPriv {
     pub type_: NodeTag,
     pub priv_name: *mut ::std::os::raw::c_char,
@@ -17708,7 +17934,8 @@ impl Default for AccessPriv {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantRoleStmt {
     pub type_: NodeTag,
     pub granted_roles: *mut List,
@@ -17728,7 +17955,8 @@ impl Default for GrantRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDefaultPrivilegesStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -17744,7 +17972,8 @@ impl Default for AlterDefaultPrivilegesStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CopyStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17773,7 +18002,8 @@ pub const VariableSetKind_VAR_RESET: VariableSetKind = 4;
 pub const VariableSetKind_VAR_RESET_ALL: VariableSetKind = 5;
 pub type VariableSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableSetStmt {
     pub type_: NodeTag,
     pub kind: VariableSetKind,
@@ -17791,7 +18021,8 @@ impl Default for VariableSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableShowStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17806,7 +18037,8 @@ impl Default for VariableShowStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17847,7 +18079,8 @@ pub const ConstrType_CONSTR_ATTR_DEFERRED: ConstrType = 12;
 pub const ConstrType_CONSTR_ATTR_IMMEDIATE: ConstrType = 13;
 pub type ConstrType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Constraint {
     pub type_: NodeTag,
     pub contype: ConstrType,
@@ -17889,7 +18122,8 @@ impl Default for Constraint {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17907,7 +18141,8 @@ impl Default for CreateTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17923,7 +18158,8 @@ impl Default for DropTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableSpaceOptionsStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -17940,7 +18176,8 @@ impl Default for AlterTableSpaceOptionsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableMoveAllStmt {
     pub type_: NodeTag,
     pub orig_tablespacename: *mut ::std::os::raw::c_char,
@@ -17959,7 +18196,8 @@ impl Default for AlterTableMoveAllStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17976,7 +18214,8 @@ impl Default for CreateExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -17992,7 +18231,8 @@ impl Default for AlterExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionContentsStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -18010,7 +18250,8 @@ impl Default for AlterExtensionContentsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -18027,7 +18268,8 @@ impl Default for CreateFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -18044,7 +18286,8 @@ impl Default for AlterFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -18064,7 +18307,8 @@ impl Default for CreateForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -18082,7 +18326,8 @@ impl Default for AlterForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignTableStmt {
     pub base: CreateStmt,
     pub servername: *mut ::std::os::raw::c_char,
@@ -18098,7 +18343,8 @@ impl Default for CreateForeignTableStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -18116,7 +18362,8 @@ impl Default for CreateUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -18133,7 +18380,8 @@ impl Default for AlterUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -18154,7 +18402,8 @@ pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_LIMIT_TO: ImportForeignSchem
 pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_EXCEPT: ImportForeignSchemaType = 2;
 pub type ImportForeignSchemaType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ImportForeignSchemaStmt {
     pub type_: NodeTag,
     pub server_name: *mut ::std::os::raw::c_char,
@@ -18174,7 +18423,8 @@ impl Default for ImportForeignSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -18195,7 +18445,8 @@ impl Default for CreatePolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -18214,7 +18465,8 @@ impl Default for AlterPolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateAmStmt {
     pub type_: NodeTag,
     pub amname: *mut ::std::os::raw::c_char,
@@ -18231,7 +18483,8 @@ impl Default for CreateAmStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -18259,7 +18512,8 @@ impl Default for CreateTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -18277,7 +18531,8 @@ impl Default for CreateEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -18293,7 +18548,8 @@ impl Default for AlterEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePLangStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -18317,7 +18573,8 @@ pub const RoleStmtType_ROLESTMT_USER: RoleStmtType = 1;
 pub const RoleStmtType_ROLESTMT_GROUP: RoleStmtType = 2;
 pub type RoleStmtType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRoleStmt {
     pub type_: NodeTag,
     pub stmt_type: RoleStmtType,
@@ -18334,7 +18591,8 @@ impl Default for CreateRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -18351,7 +18609,8 @@ impl Default for AlterRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleSetStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -18368,7 +18627,8 @@ impl Default for AlterRoleSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropRoleStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -18384,7 +18644,8 @@ impl Default for DropRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -18403,7 +18664,8 @@ impl Default for CreateSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -18421,7 +18683,8 @@ impl Default for AlterSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefineStmt {
     pub type_: NodeTag,
     pub kind: ObjectType,
@@ -18442,7 +18705,8 @@ impl Default for DefineStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateDomainStmt {
     pub type_: NodeTag,
     pub domainname: *mut List,
@@ -18460,7 +18724,8 @@ impl Default for CreateDomainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassStmt {
     pub type_: NodeTag,
     pub opclassname: *mut List,
@@ -18480,7 +18745,8 @@ impl Default for CreateOpClassStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassItem {
     pub type_: NodeTag,
     pub itemtype: ::std::os::raw::c_int,
@@ -18500,7 +18766,8 @@ impl Default for CreateOpClassItem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -18516,7 +18783,8 @@ impl Default for CreateOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -18534,7 +18802,8 @@ impl Default for AlterOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropStmt {
     pub type_: NodeTag,
     pub objects: *mut List,
@@ -18553,7 +18822,8 @@ impl Default for DropStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TruncateStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -18570,7 +18840,8 @@ impl Default for TruncateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommentStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -18587,7 +18858,8 @@ impl Default for CommentStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SecLabelStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -18605,7 +18877,8 @@ impl Default for SecLabelStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeclareCursorStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -18622,7 +18895,8 @@ impl Default for DeclareCursorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClosePortalStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -18642,7 +18916,8 @@ pub const FetchDirection_FETCH_ABSOLUTE: FetchDirection = 2;
 pub const FetchDirection_FETCH_RELATIVE: FetchDirection = 3;
 pub type FetchDirection = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FetchStmt {
     pub type_: NodeTag,
     pub direction: FetchDirection,
@@ -18660,7 +18935,8 @@ impl Default for FetchStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexStmt {
     pub type_: NodeTag,
     pub idxname: *mut ::std::os::raw::c_char,
@@ -18697,7 +18973,8 @@ impl Default for IndexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -18717,7 +18994,8 @@ impl Default for CreateStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -18734,7 +19012,8 @@ impl Default for AlterStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFunctionStmt {
     pub type_: NodeTag,
     pub is_procedure: bool,
@@ -18760,7 +19039,8 @@ pub const FunctionParameterMode_FUNC_PARAM_VARIADIC: FunctionParameterMode = 118
 pub const FunctionParameterMode_FUNC_PARAM_TABLE: FunctionParameterMode = 116;
 pub type FunctionParameterMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionParameter {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18778,7 +19058,8 @@ impl Default for FunctionParameter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFunctionStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -18795,7 +19076,8 @@ impl Default for AlterFunctionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DoStmt {
     pub type_: NodeTag,
     pub args: *mut List,
@@ -18810,7 +19092,8 @@ impl Default for DoStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InlineCodeBlock {
     pub type_: NodeTag,
     pub source_text: *mut ::std::os::raw::c_char,
@@ -18828,7 +19111,8 @@ impl Default for InlineCodeBlock {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallStmt {
     pub type_: NodeTag,
     pub funccall: *mut FuncCall,
@@ -18844,7 +19128,8 @@ impl Default for CallStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallContext {
     pub type_: NodeTag,
     pub atomic: bool,
@@ -18859,7 +19144,8 @@ impl Default for CallContext {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RenameStmt {
     pub type_: NodeTag,
     pub renameType: ObjectType,
@@ -18881,7 +19167,8 @@ impl Default for RenameStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectDependsStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18900,7 +19187,8 @@ impl Default for AlterObjectDependsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectSchemaStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18919,7 +19207,8 @@ impl Default for AlterObjectSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOwnerStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -18937,7 +19226,8 @@ impl Default for AlterOwnerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOperatorStmt {
     pub type_: NodeTag,
     pub opername: *mut ObjectWithArgs,
@@ -18953,7 +19243,8 @@ impl Default for AlterOperatorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTypeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -18969,7 +19260,8 @@ impl Default for AlterTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RuleStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18990,7 +19282,8 @@ impl Default for RuleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NotifyStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -19006,7 +19299,8 @@ impl Default for NotifyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ListenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -19021,7 +19315,8 @@ impl Default for ListenStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UnlistenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -19047,7 +19342,8 @@ pub const TransactionStmtKind_TRANS_STMT_COMMIT_PREPARED: TransactionStmtKind =
 pub const TransactionStmtKind_TRANS_STMT_ROLLBACK_PREPARED: TransactionStmtKind = 9;
 pub type TransactionStmtKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TransactionStmt {
     pub type_: NodeTag,
     pub kind: TransactionStmtKind,
@@ -19066,7 +19362,8 @@ impl Default for TransactionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CompositeTypeStmt {
     pub type_: NodeTag,
     pub typevar: *mut RangeVar,
@@ -19082,7 +19379,8 @@ impl Default for CompositeTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19098,7 +19396,8 @@ impl Default for CreateEnumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRangeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19114,7 +19413,8 @@ impl Default for CreateRangeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19138,7 +19438,8 @@ pub const ViewCheckOption_LOCAL_CHECK_OPTION: ViewCheckOption = 1;
 pub const ViewCheckOption_CASCADED_CHECK_OPTION: ViewCheckOption = 2;
 pub type ViewCheckOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ViewStmt {
     pub type_: NodeTag,
     pub view: *mut RangeVar,
@@ -19158,7 +19459,8 @@ impl Default for ViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LoadStmt {
     pub type_: NodeTag,
     pub filename: *mut ::std::os::raw::c_char,
@@ -19173,7 +19475,8 @@ impl Default for LoadStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatedbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19189,7 +19492,8 @@ impl Default for CreatedbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19205,7 +19509,8 @@ impl Default for AlterDatabaseStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseSetStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19221,7 +19526,8 @@ impl Default for AlterDatabaseSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropdbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19238,7 +19544,8 @@ impl Default for DropdbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSystemStmt {
     pub type_: NodeTag,
     pub setstmt: *mut VariableSetStmt,
@@ -19256,7 +19563,8 @@ pub const ClusterOption_CLUOPT_RECHECK: ClusterOption = 1;
 pub const ClusterOption_CLUOPT_VERBOSE: ClusterOption = 2;
 pub type ClusterOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClusterStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -19273,7 +19581,8 @@ impl Default for ClusterStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -19290,7 +19599,8 @@ impl Default for VacuumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumRelation {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -19307,7 +19617,8 @@ impl Default for VacuumRelation {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExplainStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -19323,7 +19634,8 @@ impl Default for ExplainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableAsStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -19342,7 +19654,8 @@ impl Default for CreateTableAsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RefreshMatViewStmt {
     pub type_: NodeTag,
     pub concurrent: bool,
@@ -19359,7 +19672,8 @@ impl Default for RefreshMatViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CheckPointStmt {
     pub type_: NodeTag,
 }
@@ -19378,7 +19692,8 @@ pub const DiscardMode_DISCARD_SEQUENCES: DiscardMode = 2;
 pub const DiscardMode_DISCARD_TEMP: DiscardMode = 3;
 pub type DiscardMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DiscardStmt {
     pub type_: NodeTag,
     pub target: DiscardMode,
@@ -19393,7 +19708,8 @@ impl Default for DiscardStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -19410,7 +19726,8 @@ impl Default for LockStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConstraintsSetStmt {
     pub type_: NodeTag,
     pub constraints: *mut List,
@@ -19432,7 +19749,8 @@ pub const ReindexObjectType_REINDEX_OBJECT_SYSTEM: ReindexObjectType = 3;
 pub const ReindexObjectType_REINDEX_OBJECT_DATABASE: ReindexObjectType = 4;
 pub type ReindexObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReindexStmt {
     pub type_: NodeTag,
     pub kind: ReindexObjectType,
@@ -19451,7 +19769,8 @@ impl Default for ReindexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateConversionStmt {
     pub type_: NodeTag,
     pub conversion_name: *mut List,
@@ -19470,7 +19789,8 @@ impl Default for CreateConversionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateCastStmt {
     pub type_: NodeTag,
     pub sourcetype: *mut TypeName,
@@ -19489,7 +19809,8 @@ impl Default for CreateCastStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTransformStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -19508,7 +19829,8 @@ impl Default for CreateTransformStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PrepareStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -19525,7 +19847,8 @@ impl Default for PrepareStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExecuteStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -19541,7 +19864,8 @@ impl Default for ExecuteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeallocateStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -19556,7 +19880,8 @@ impl Default for DeallocateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -19572,7 +19897,8 @@ impl Default for DropOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReassignOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -19588,7 +19914,8 @@ impl Default for ReassignOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSDictionaryStmt {
     pub type_: NodeTag,
     pub dictname: *mut List,
@@ -19610,7 +19937,8 @@ pub const AlterTSConfigType_ALTER_TSCONFIG_REPLACE_DICT_FOR_TOKEN: AlterTSConfig
 pub const AlterTSConfigType_ALTER_TSCONFIG_DROP_MAPPING: AlterTSConfigType = 4;
 pub type AlterTSConfigType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSConfigurationStmt {
     pub type_: NodeTag,
     pub kind: AlterTSConfigType,
@@ -19631,7 +19959,8 @@ impl Default for AlterTSConfigurationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -19649,7 +19978,8 @@ impl Default for CreatePublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -19668,7 +19998,8 @@ impl Default for AlterPublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -19692,7 +20023,8 @@ pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_REFRESH: AlterSubscriptionTyp
 pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_ENABLED: AlterSubscriptionType = 4;
 pub type AlterSubscriptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSubscriptionStmt {
     pub type_: NodeTag,
     pub kind: AlterSubscriptionType,
@@ -19711,7 +20043,8 @@ impl Default for AlterSubscriptionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -19775,7 +20108,8 @@ pub struct MemoryContextMethods {
     pub check: ::std::option::Option<unsafe extern "C" fn(context: MemoryContext)>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoryContextData {
     pub type_: NodeTag,
     pub isReset: bool,
@@ -25767,7 +26101,8 @@ pub type aminitparallelscan_function =
 pub type amparallelrescan_function =
     ::std::option::Option<unsafe extern "C" fn(scan: IndexScanDesc)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexAmRoutine {
     pub type_: NodeTag,
     pub amstrategies: uint16,
@@ -27122,7 +27457,8 @@ impl Default for RelationData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyCacheInfo {
     pub type_: NodeTag,
     pub conoid: Oid,
@@ -28791,7 +29127,8 @@ pub type IndexBuildCallback = ::std::option::Option<
     ),
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableAmRoutine {
     pub type_: NodeTag,
     pub slot_callbacks:
@@ -34096,7 +34433,8 @@ impl Default for CollectedCommand {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EventTriggerData {
     pub type_: NodeTag,
     pub event: *const ::std::os::raw::c_char,
@@ -34807,7 +35145,8 @@ extern "C" {
 }
 pub type TriggerEvent = uint32;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerData {
     pub type_: NodeTag,
     pub tg_event: TriggerEvent,
@@ -36757,7 +37096,8 @@ pub const InheritanceKind_INHKIND_INHERITED: InheritanceKind = 1;
 pub const InheritanceKind_INHKIND_PARTITIONED: InheritanceKind = 2;
 pub type InheritanceKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerGlobal {
     pub type_: NodeTag,
     pub boundParams: ParamListInfo,
@@ -36792,7 +37132,8 @@ impl Default for PlannerGlobal {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerInfo {
     pub type_: NodeTag,
     pub parse: *mut Query,
@@ -36895,7 +37236,8 @@ pub const RelOptKind_RELOPT_OTHER_UPPER_REL: RelOptKind = 5;
 pub const RelOptKind_RELOPT_DEADREL: RelOptKind = 6;
 pub type RelOptKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelOptInfo {
     pub type_: NodeTag,
     pub reloptkind: RelOptKind,
@@ -36967,7 +37309,8 @@ impl Default for RelOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOptInfo {
     pub type_: NodeTag,
     pub indexoid: Oid,
@@ -37016,7 +37359,8 @@ impl Default for IndexOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyOptInfo {
     pub type_: NodeTag,
     pub con_relid: Index,
@@ -37041,7 +37385,8 @@ impl Default for ForeignKeyOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatisticExtInfo {
     pub type_: NodeTag,
     pub statOid: Oid,
@@ -37059,7 +37404,8 @@ impl Default for StatisticExtInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceClass {
     pub type_: NodeTag,
     pub ec_opfamilies: *mut List,
@@ -37087,7 +37433,8 @@ impl Default for EquivalenceClass {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceMember {
     pub type_: NodeTag,
     pub em_expr: *mut Expr,
@@ -37107,7 +37454,8 @@ impl Default for EquivalenceMember {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathKey {
     pub type_: NodeTag,
     pub pk_eclass: *mut EquivalenceClass,
@@ -37125,7 +37473,8 @@ impl Default for PathKey {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathTarget {
     pub type_: NodeTag,
     pub exprs: *mut List,
@@ -37143,7 +37492,8 @@ impl Default for PathTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamPathInfo {
     pub type_: NodeTag,
     pub ppi_req_outer: Relids,
@@ -37160,7 +37510,8 @@ impl Default for ParamPathInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Path {
     pub type_: NodeTag,
     pub pathtype: NodeTag,
@@ -37185,7 +37536,8 @@ impl Default for Path {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexPath {
     pub path: Path,
     pub indexinfo: *mut IndexOptInfo,
@@ -37206,7 +37558,8 @@ impl Default for IndexPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexClause {
     pub type_: NodeTag,
     pub rinfo: *mut RestrictInfo,
@@ -37225,7 +37578,8 @@ impl Default for IndexClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapPath {
     pub path: Path,
     pub bitmapqual: *mut Path,
@@ -37240,7 +37594,8 @@ impl Default for BitmapHeapPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -37256,7 +37611,8 @@ impl Default for BitmapAndPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -37272,7 +37628,8 @@ impl Default for BitmapOrPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidPath {
     pub path: Path,
     pub tidquals: *mut List,
@@ -37287,7 +37644,8 @@ impl Default for TidPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37302,7 +37660,8 @@ impl Default for SubqueryScanPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignPath {
     pub path: Path,
     pub fdw_outerpath: *mut Path,
@@ -37318,7 +37677,8 @@ impl Default for ForeignPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomPath {
     pub path: Path,
     pub flags: uint32,
@@ -37336,7 +37696,8 @@ impl Default for CustomPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendPath {
     pub path: Path,
     pub partitioned_rels: *mut List,
@@ -37358,7 +37719,8 @@ extern "C" {
     pub fn is_dummy_rel(rel: *mut RelOptInfo) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendPath {
     pub path: Path,
     pub partitioned_rels: *mut List,
@@ -37375,7 +37737,8 @@ impl Default for MergeAppendPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupResultPath {
     pub path: Path,
     pub quals: *mut List,
@@ -37390,7 +37753,8 @@ impl Default for GroupResultPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37409,7 +37773,8 @@ pub const UniquePathMethod_UNIQUE_PATH_HASH: UniquePathMethod = 1;
 pub const UniquePathMethod_UNIQUE_PATH_SORT: UniquePathMethod = 2;
 pub type UniquePathMethod = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37427,7 +37792,8 @@ impl Default for UniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37444,7 +37810,8 @@ impl Default for GatherPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37460,7 +37827,8 @@ impl Default for GatherMergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinPath {
     pub path: Path,
     pub jointype: JoinType,
@@ -37480,7 +37848,8 @@ impl Default for JoinPath {
 }
 pub type NestPath = JoinPath;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergePath {
     pub jpath: JoinPath,
     pub path_mergeclauses: *mut List,
@@ -37499,7 +37868,8 @@ impl Default for MergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashPath {
     pub jpath: JoinPath,
     pub path_hashclauses: *mut List,
@@ -37516,7 +37886,8 @@ impl Default for HashPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37532,7 +37903,8 @@ impl Default for ProjectionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37547,7 +37919,8 @@ impl Default for ProjectSetPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37562,7 +37935,8 @@ impl Default for SortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSortPath {
     pub spath: SortPath,
     pub nPresortedCols: ::std::os::raw::c_int,
@@ -37577,7 +37951,8 @@ impl Default for IncrementalSortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37594,7 +37969,8 @@ impl Default for GroupPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpperUniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37610,7 +37986,8 @@ impl Default for UpperUniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37631,7 +38008,8 @@ impl Default for AggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetData {
     pub type_: NodeTag,
     pub set: *mut List,
@@ -37647,7 +38025,8 @@ impl Default for GroupingSetData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RollupData {
     pub type_: NodeTag,
     pub groupClause: *mut List,
@@ -37667,7 +38046,8 @@ impl Default for RollupData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37686,7 +38066,8 @@ impl Default for GroupingSetsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggPath {
     pub path: Path,
     pub mmaggregates: *mut List,
@@ -37702,7 +38083,8 @@ impl Default for MinMaxAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37718,7 +38100,8 @@ impl Default for WindowAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37739,7 +38122,8 @@ impl Default for SetOpPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionPath {
     pub path: Path,
     pub leftpath: *mut Path,
@@ -37758,7 +38142,8 @@ impl Default for RecursiveUnionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37775,7 +38160,8 @@ impl Default for LockRowsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTablePath {
     pub path: Path,
     pub operation: CmdType,
@@ -37802,7 +38188,8 @@ impl Default for ModifyTablePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -37820,7 +38207,8 @@ impl Default for LimitPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RestrictInfo {
     pub type_: NodeTag,
     pub clause: *mut Expr,
@@ -37885,7 +38273,8 @@ impl Default for MergeScanSelCache {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderVar {
     pub xpr: Expr,
     pub phexpr: *mut Expr,
@@ -37903,7 +38292,8 @@ impl Default for PlaceHolderVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SpecialJoinInfo {
     pub type_: NodeTag,
     pub min_lefthand: Relids,
@@ -37928,7 +38318,8 @@ impl Default for SpecialJoinInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendRelInfo {
     pub type_: NodeTag,
     pub parent_relid: Index,
@@ -37950,7 +38341,8 @@ impl Default for AppendRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderInfo {
     pub type_: NodeTag,
     pub phid: Index,
@@ -37970,7 +38362,8 @@ impl Default for PlaceHolderInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggInfo {
     pub type_: NodeTag,
     pub aggfnoid: Oid,
@@ -37991,7 +38384,8 @@ impl Default for MinMaxAggInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerParamItem {
     pub type_: NodeTag,
     pub item: *mut Node,
@@ -38287,7 +38681,8 @@ pub type ReparameterizeForeignPathByChild_function = ::std::option::Option<
     ) -> *mut List,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FdwRoutine {
     pub type_: NodeTag,
     pub GetForeignRelSize: GetForeignRelSize_function,
@@ -39121,7 +39516,8 @@ extern "C" {
     );
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExtensibleNode {
     pub type_: NodeTag,
     pub extnodename: *const ::std::os::raw::c_char,
@@ -39731,7 +40127,8 @@ pub const ReplicationKind_REPLICATION_KIND_PHYSICAL: ReplicationKind = 0;
 pub const ReplicationKind_REPLICATION_KIND_LOGICAL: ReplicationKind = 1;
 pub type ReplicationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IdentifySystemCmd {
     pub type_: NodeTag,
 }
@@ -39745,7 +40142,8 @@ impl Default for IdentifySystemCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BaseBackupCmd {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -39760,7 +40158,8 @@ impl Default for BaseBackupCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -39779,7 +40178,8 @@ impl Default for CreateReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -39795,7 +40195,8 @@ impl Default for DropReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StartReplicationCmd {
     pub type_: NodeTag,
     pub kind: ReplicationKind,
@@ -39814,7 +40215,8 @@ impl Default for StartReplicationCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TimeLineHistoryCmd {
     pub type_: NodeTag,
     pub timeline: TimeLineID,
@@ -39829,7 +40231,8 @@ impl Default for TimeLineHistoryCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLCmd {
     pub type_: NodeTag,
 }
@@ -39843,7 +40246,8 @@ impl Default for SQLCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSimplify {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -39859,7 +40263,8 @@ impl Default for SupportRequestSimplify {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSelectivity {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -39882,7 +40287,8 @@ impl Default for SupportRequestSelectivity {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestCost {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -39901,7 +40307,8 @@ impl Default for SupportRequestCost {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestRows {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -39919,7 +40326,8 @@ impl Default for SupportRequestRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestIndexCondition {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -61041,6 +61449,12 @@ impl std::fmt::Display for A_ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Const {}
 impl pg_sys::PgNode for A_Const {}
 impl std::fmt::Display for A_Const {
@@ -61048,6 +61462,12 @@ impl std::fmt::Display for A_Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Const"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Expr {}
 impl pg_sys::PgNode for A_Expr {}
 impl std::fmt::Display for A_Expr {
@@ -61055,6 +61475,12 @@ impl std::fmt::Display for A_Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indices {}
 impl pg_sys::PgNode for A_Indices {}
 impl std::fmt::Display for A_Indices {
@@ -61062,6 +61488,12 @@ impl std::fmt::Display for A_Indices {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indices"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indirection {}
 impl pg_sys::PgNode for A_Indirection {}
 impl std::fmt::Display for A_Indirection {
@@ -61069,6 +61501,12 @@ impl std::fmt::Display for A_Indirection {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indirection"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Star {}
 impl pg_sys::PgNode for A_Star {}
 impl std::fmt::Display for A_Star {
@@ -61076,6 +61514,12 @@ impl std::fmt::Display for A_Star {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Star {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Star"))
+    }
+}
 impl pg_sys::seal::Sealed for AccessPriv {}
 impl pg_sys::PgNode for AccessPriv {}
 impl std::fmt::Display for AccessPriv {
@@ -61083,6 +61527,12 @@ impl std::fmt::Display for AccessPriv {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AccessPriv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AccessPriv"))
+    }
+}
 impl pg_sys::seal::Sealed for Agg {}
 impl pg_sys::PgNode for Agg {}
 impl std::fmt::Display for Agg {
@@ -61090,6 +61540,12 @@ impl std::fmt::Display for Agg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Agg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Agg"))
+    }
+}
 impl pg_sys::seal::Sealed for AggPath {}
 impl pg_sys::PgNode for AggPath {}
 impl std::fmt::Display for AggPath {
@@ -61097,6 +61553,12 @@ impl std::fmt::Display for AggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AggState {}
 impl pg_sys::PgNode for AggState {}
 impl std::fmt::Display for AggState {
@@ -61104,6 +61566,12 @@ impl std::fmt::Display for AggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggState"))
+    }
+}
 impl pg_sys::seal::Sealed for Aggref {}
 impl pg_sys::PgNode for Aggref {}
 impl std::fmt::Display for Aggref {
@@ -61111,6 +61579,12 @@ impl std::fmt::Display for Aggref {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Aggref {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Aggref"))
+    }
+}
 impl pg_sys::seal::Sealed for AggrefExprState {}
 impl pg_sys::PgNode for AggrefExprState {}
 impl std::fmt::Display for AggrefExprState {
@@ -61118,6 +61592,12 @@ impl std::fmt::Display for AggrefExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggrefExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggrefExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for Alias {}
 impl pg_sys::PgNode for Alias {}
 impl std::fmt::Display for Alias {
@@ -61125,6 +61605,12 @@ impl std::fmt::Display for Alias {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Alias"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterCollationStmt {}
 impl pg_sys::PgNode for AlterCollationStmt {}
 impl std::fmt::Display for AlterCollationStmt {
@@ -61132,6 +61618,12 @@ impl std::fmt::Display for AlterCollationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterCollationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterCollationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseSetStmt {}
 impl pg_sys::PgNode for AlterDatabaseSetStmt {}
 impl std::fmt::Display for AlterDatabaseSetStmt {
@@ -61139,6 +61631,12 @@ impl std::fmt::Display for AlterDatabaseSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseStmt {}
 impl pg_sys::PgNode for AlterDatabaseStmt {}
 impl std::fmt::Display for AlterDatabaseStmt {
@@ -61146,6 +61644,12 @@ impl std::fmt::Display for AlterDatabaseStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDefaultPrivilegesStmt {}
 impl pg_sys::PgNode for AlterDefaultPrivilegesStmt {}
 impl std::fmt::Display for AlterDefaultPrivilegesStmt {
@@ -61153,6 +61657,12 @@ impl std::fmt::Display for AlterDefaultPrivilegesStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDefaultPrivilegesStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDefaultPrivilegesStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDomainStmt {}
 impl pg_sys::PgNode for AlterDomainStmt {}
 impl std::fmt::Display for AlterDomainStmt {
@@ -61160,6 +61670,12 @@ impl std::fmt::Display for AlterDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEnumStmt {}
 impl pg_sys::PgNode for AlterEnumStmt {}
 impl std::fmt::Display for AlterEnumStmt {
@@ -61167,6 +61683,12 @@ impl std::fmt::Display for AlterEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEventTrigStmt {}
 impl pg_sys::PgNode for AlterEventTrigStmt {}
 impl std::fmt::Display for AlterEventTrigStmt {
@@ -61174,6 +61696,12 @@ impl std::fmt::Display for AlterEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionContentsStmt {}
 impl pg_sys::PgNode for AlterExtensionContentsStmt {}
 impl std::fmt::Display for AlterExtensionContentsStmt {
@@ -61181,6 +61709,12 @@ impl std::fmt::Display for AlterExtensionContentsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionContentsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionContentsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionStmt {}
 impl pg_sys::PgNode for AlterExtensionStmt {}
 impl std::fmt::Display for AlterExtensionStmt {
@@ -61188,6 +61722,12 @@ impl std::fmt::Display for AlterExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFdwStmt {}
 impl pg_sys::PgNode for AlterFdwStmt {}
 impl std::fmt::Display for AlterFdwStmt {
@@ -61195,6 +61735,12 @@ impl std::fmt::Display for AlterFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterForeignServerStmt {}
 impl pg_sys::PgNode for AlterForeignServerStmt {}
 impl std::fmt::Display for AlterForeignServerStmt {
@@ -61202,6 +61748,12 @@ impl std::fmt::Display for AlterForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFunctionStmt {}
 impl pg_sys::PgNode for AlterFunctionStmt {}
 impl std::fmt::Display for AlterFunctionStmt {
@@ -61209,6 +61761,12 @@ impl std::fmt::Display for AlterFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectDependsStmt {}
 impl pg_sys::PgNode for AlterObjectDependsStmt {}
 impl std::fmt::Display for AlterObjectDependsStmt {
@@ -61216,6 +61774,12 @@ impl std::fmt::Display for AlterObjectDependsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectDependsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectDependsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectSchemaStmt {}
 impl pg_sys::PgNode for AlterObjectSchemaStmt {}
 impl std::fmt::Display for AlterObjectSchemaStmt {
@@ -61223,6 +61787,12 @@ impl std::fmt::Display for AlterObjectSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOpFamilyStmt {}
 impl pg_sys::PgNode for AlterOpFamilyStmt {}
 impl std::fmt::Display for AlterOpFamilyStmt {
@@ -61230,6 +61800,12 @@ impl std::fmt::Display for AlterOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOperatorStmt {}
 impl pg_sys::PgNode for AlterOperatorStmt {}
 impl std::fmt::Display for AlterOperatorStmt {
@@ -61237,6 +61813,12 @@ impl std::fmt::Display for AlterOperatorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOperatorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOperatorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOwnerStmt {}
 impl pg_sys::PgNode for AlterOwnerStmt {}
 impl std::fmt::Display for AlterOwnerStmt {
@@ -61244,6 +61826,12 @@ impl std::fmt::Display for AlterOwnerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOwnerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOwnerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPolicyStmt {}
 impl pg_sys::PgNode for AlterPolicyStmt {}
 impl std::fmt::Display for AlterPolicyStmt {
@@ -61251,6 +61839,12 @@ impl std::fmt::Display for AlterPolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPublicationStmt {}
 impl pg_sys::PgNode for AlterPublicationStmt {}
 impl std::fmt::Display for AlterPublicationStmt {
@@ -61258,6 +61852,12 @@ impl std::fmt::Display for AlterPublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleSetStmt {}
 impl pg_sys::PgNode for AlterRoleSetStmt {}
 impl std::fmt::Display for AlterRoleSetStmt {
@@ -61265,6 +61865,12 @@ impl std::fmt::Display for AlterRoleSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleStmt {}
 impl pg_sys::PgNode for AlterRoleStmt {}
 impl std::fmt::Display for AlterRoleStmt {
@@ -61272,6 +61878,12 @@ impl std::fmt::Display for AlterRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSeqStmt {}
 impl pg_sys::PgNode for AlterSeqStmt {}
 impl std::fmt::Display for AlterSeqStmt {
@@ -61279,6 +61891,12 @@ impl std::fmt::Display for AlterSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterStatsStmt {}
 impl pg_sys::PgNode for AlterStatsStmt {}
 impl std::fmt::Display for AlterStatsStmt {
@@ -61286,6 +61904,12 @@ impl std::fmt::Display for AlterStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSubscriptionStmt {}
 impl pg_sys::PgNode for AlterSubscriptionStmt {}
 impl std::fmt::Display for AlterSubscriptionStmt {
@@ -61293,6 +61917,12 @@ impl std::fmt::Display for AlterSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSystemStmt {}
 impl pg_sys::PgNode for AlterSystemStmt {}
 impl std::fmt::Display for AlterSystemStmt {
@@ -61300,6 +61930,12 @@ impl std::fmt::Display for AlterSystemStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSystemStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSystemStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSConfigurationStmt {}
 impl pg_sys::PgNode for AlterTSConfigurationStmt {}
 impl std::fmt::Display for AlterTSConfigurationStmt {
@@ -61307,6 +61943,12 @@ impl std::fmt::Display for AlterTSConfigurationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSConfigurationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSConfigurationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSDictionaryStmt {}
 impl pg_sys::PgNode for AlterTSDictionaryStmt {}
 impl std::fmt::Display for AlterTSDictionaryStmt {
@@ -61314,6 +61956,12 @@ impl std::fmt::Display for AlterTSDictionaryStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSDictionaryStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSDictionaryStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableCmd {}
 impl pg_sys::PgNode for AlterTableCmd {}
 impl std::fmt::Display for AlterTableCmd {
@@ -61321,6 +61969,12 @@ impl std::fmt::Display for AlterTableCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableMoveAllStmt {}
 impl pg_sys::PgNode for AlterTableMoveAllStmt {}
 impl std::fmt::Display for AlterTableMoveAllStmt {
@@ -61328,6 +61982,12 @@ impl std::fmt::Display for AlterTableMoveAllStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableMoveAllStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableMoveAllStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableSpaceOptionsStmt {}
 impl pg_sys::PgNode for AlterTableSpaceOptionsStmt {}
 impl std::fmt::Display for AlterTableSpaceOptionsStmt {
@@ -61335,6 +61995,12 @@ impl std::fmt::Display for AlterTableSpaceOptionsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableSpaceOptionsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableSpaceOptionsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableStmt {}
 impl pg_sys::PgNode for AlterTableStmt {}
 impl std::fmt::Display for AlterTableStmt {
@@ -61342,6 +62008,12 @@ impl std::fmt::Display for AlterTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTypeStmt {}
 impl pg_sys::PgNode for AlterTypeStmt {}
 impl std::fmt::Display for AlterTypeStmt {
@@ -61349,6 +62021,12 @@ impl std::fmt::Display for AlterTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterUserMappingStmt {}
 impl pg_sys::PgNode for AlterUserMappingStmt {}
 impl std::fmt::Display for AlterUserMappingStmt {
@@ -61356,6 +62034,12 @@ impl std::fmt::Display for AlterUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlan {}
 impl pg_sys::PgNode for AlternativeSubPlan {}
 impl std::fmt::Display for AlternativeSubPlan {
@@ -61363,6 +62047,12 @@ impl std::fmt::Display for AlternativeSubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlanState {}
 impl pg_sys::PgNode for AlternativeSubPlanState {}
 impl std::fmt::Display for AlternativeSubPlanState {
@@ -61370,6 +62060,12 @@ impl std::fmt::Display for AlternativeSubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Append {}
 impl pg_sys::PgNode for Append {}
 impl std::fmt::Display for Append {
@@ -61377,6 +62073,12 @@ impl std::fmt::Display for Append {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Append {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Append"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendPath {}
 impl pg_sys::PgNode for AppendPath {}
 impl std::fmt::Display for AppendPath {
@@ -61384,6 +62086,12 @@ impl std::fmt::Display for AppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendRelInfo {}
 impl pg_sys::PgNode for AppendRelInfo {}
 impl std::fmt::Display for AppendRelInfo {
@@ -61391,6 +62099,12 @@ impl std::fmt::Display for AppendRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendState {}
 impl pg_sys::PgNode for AppendState {}
 impl std::fmt::Display for AppendState {
@@ -61398,6 +62112,12 @@ impl std::fmt::Display for AppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayCoerceExpr {}
 impl pg_sys::PgNode for ArrayCoerceExpr {}
 impl std::fmt::Display for ArrayCoerceExpr {
@@ -61405,6 +62125,12 @@ impl std::fmt::Display for ArrayCoerceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayCoerceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayCoerceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayExpr {}
 impl pg_sys::PgNode for ArrayExpr {}
 impl std::fmt::Display for ArrayExpr {
@@ -61412,6 +62138,12 @@ impl std::fmt::Display for ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BaseBackupCmd {}
 impl pg_sys::PgNode for BaseBackupCmd {}
 impl std::fmt::Display for BaseBackupCmd {
@@ -61419,6 +62151,12 @@ impl std::fmt::Display for BaseBackupCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BaseBackupCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BaseBackupCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAnd {}
 impl pg_sys::PgNode for BitmapAnd {}
 impl std::fmt::Display for BitmapAnd {
@@ -61426,6 +62164,12 @@ impl std::fmt::Display for BitmapAnd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAnd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndPath {}
 impl pg_sys::PgNode for BitmapAndPath {}
 impl std::fmt::Display for BitmapAndPath {
@@ -61433,6 +62177,12 @@ impl std::fmt::Display for BitmapAndPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndState {}
 impl pg_sys::PgNode for BitmapAndState {}
 impl std::fmt::Display for BitmapAndState {
@@ -61440,6 +62190,12 @@ impl std::fmt::Display for BitmapAndState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapPath {}
 impl pg_sys::PgNode for BitmapHeapPath {}
 impl std::fmt::Display for BitmapHeapPath {
@@ -61447,6 +62203,12 @@ impl std::fmt::Display for BitmapHeapPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScan {}
 impl pg_sys::PgNode for BitmapHeapScan {}
 impl std::fmt::Display for BitmapHeapScan {
@@ -61454,6 +62216,12 @@ impl std::fmt::Display for BitmapHeapScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScanState {}
 impl pg_sys::PgNode for BitmapHeapScanState {}
 impl std::fmt::Display for BitmapHeapScanState {
@@ -61461,6 +62229,12 @@ impl std::fmt::Display for BitmapHeapScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScan {}
 impl pg_sys::PgNode for BitmapIndexScan {}
 impl std::fmt::Display for BitmapIndexScan {
@@ -61468,6 +62242,12 @@ impl std::fmt::Display for BitmapIndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScanState {}
 impl pg_sys::PgNode for BitmapIndexScanState {}
 impl std::fmt::Display for BitmapIndexScanState {
@@ -61475,6 +62255,12 @@ impl std::fmt::Display for BitmapIndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOr {}
 impl pg_sys::PgNode for BitmapOr {}
 impl std::fmt::Display for BitmapOr {
@@ -61482,6 +62268,12 @@ impl std::fmt::Display for BitmapOr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOr"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrPath {}
 impl pg_sys::PgNode for BitmapOrPath {}
 impl std::fmt::Display for BitmapOrPath {
@@ -61489,6 +62281,12 @@ impl std::fmt::Display for BitmapOrPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrState {}
 impl pg_sys::PgNode for BitmapOrState {}
 impl std::fmt::Display for BitmapOrState {
@@ -61496,6 +62294,12 @@ impl std::fmt::Display for BitmapOrState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrState"))
+    }
+}
 impl pg_sys::seal::Sealed for BoolExpr {}
 impl pg_sys::PgNode for BoolExpr {}
 impl std::fmt::Display for BoolExpr {
@@ -61503,6 +62307,12 @@ impl std::fmt::Display for BoolExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BoolExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BoolExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BooleanTest {}
 impl pg_sys::PgNode for BooleanTest {}
 impl std::fmt::Display for BooleanTest {
@@ -61510,6 +62320,12 @@ impl std::fmt::Display for BooleanTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BooleanTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BooleanTest"))
+    }
+}
 impl pg_sys::seal::Sealed for BufferHeapTupleTableSlot {}
 impl pg_sys::PgNode for BufferHeapTupleTableSlot {}
 impl std::fmt::Display for BufferHeapTupleTableSlot {
@@ -61517,6 +62333,12 @@ impl std::fmt::Display for BufferHeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BufferHeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BufferHeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for CallContext {}
 impl pg_sys::PgNode for CallContext {}
 impl std::fmt::Display for CallContext {
@@ -61524,6 +62346,12 @@ impl std::fmt::Display for CallContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallContext"))
+    }
+}
 impl pg_sys::seal::Sealed for CallStmt {}
 impl pg_sys::PgNode for CallStmt {}
 impl std::fmt::Display for CallStmt {
@@ -61531,6 +62359,12 @@ impl std::fmt::Display for CallStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseExpr {}
 impl pg_sys::PgNode for CaseExpr {}
 impl std::fmt::Display for CaseExpr {
@@ -61538,6 +62372,12 @@ impl std::fmt::Display for CaseExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseTestExpr {}
 impl pg_sys::PgNode for CaseTestExpr {}
 impl std::fmt::Display for CaseTestExpr {
@@ -61545,6 +62385,12 @@ impl std::fmt::Display for CaseTestExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseTestExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseTestExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseWhen {}
 impl pg_sys::PgNode for CaseWhen {}
 impl std::fmt::Display for CaseWhen {
@@ -61552,6 +62398,12 @@ impl std::fmt::Display for CaseWhen {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseWhen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseWhen"))
+    }
+}
 impl pg_sys::seal::Sealed for CheckPointStmt {}
 impl pg_sys::PgNode for CheckPointStmt {}
 impl std::fmt::Display for CheckPointStmt {
@@ -61559,6 +62411,12 @@ impl std::fmt::Display for CheckPointStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CheckPointStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CheckPointStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClosePortalStmt {}
 impl pg_sys::PgNode for ClosePortalStmt {}
 impl std::fmt::Display for ClosePortalStmt {
@@ -61566,6 +62424,12 @@ impl std::fmt::Display for ClosePortalStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClosePortalStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClosePortalStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClusterStmt {}
 impl pg_sys::PgNode for ClusterStmt {}
 impl std::fmt::Display for ClusterStmt {
@@ -61573,6 +62437,12 @@ impl std::fmt::Display for ClusterStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClusterStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClusterStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CoalesceExpr {}
 impl pg_sys::PgNode for CoalesceExpr {}
 impl std::fmt::Display for CoalesceExpr {
@@ -61580,6 +62450,12 @@ impl std::fmt::Display for CoalesceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoalesceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoalesceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomain {}
 impl pg_sys::PgNode for CoerceToDomain {}
 impl std::fmt::Display for CoerceToDomain {
@@ -61587,6 +62463,12 @@ impl std::fmt::Display for CoerceToDomain {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomain"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomainValue {}
 impl pg_sys::PgNode for CoerceToDomainValue {}
 impl std::fmt::Display for CoerceToDomainValue {
@@ -61594,6 +62476,12 @@ impl std::fmt::Display for CoerceToDomainValue {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomainValue"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceViaIO {}
 impl pg_sys::PgNode for CoerceViaIO {}
 impl std::fmt::Display for CoerceViaIO {
@@ -61601,6 +62489,12 @@ impl std::fmt::Display for CoerceViaIO {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceViaIO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceViaIO"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateClause {}
 impl pg_sys::PgNode for CollateClause {}
 impl std::fmt::Display for CollateClause {
@@ -61608,6 +62502,12 @@ impl std::fmt::Display for CollateClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateExpr {}
 impl pg_sys::PgNode for CollateExpr {}
 impl std::fmt::Display for CollateExpr {
@@ -61615,6 +62515,12 @@ impl std::fmt::Display for CollateExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnDef {}
 impl pg_sys::PgNode for ColumnDef {}
 impl std::fmt::Display for ColumnDef {
@@ -61622,6 +62528,12 @@ impl std::fmt::Display for ColumnDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnDef"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnRef {}
 impl pg_sys::PgNode for ColumnRef {}
 impl std::fmt::Display for ColumnRef {
@@ -61629,6 +62541,12 @@ impl std::fmt::Display for ColumnRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnRef"))
+    }
+}
 impl pg_sys::seal::Sealed for CommentStmt {}
 impl pg_sys::PgNode for CommentStmt {}
 impl std::fmt::Display for CommentStmt {
@@ -61636,6 +62554,12 @@ impl std::fmt::Display for CommentStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommentStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommentStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CommonTableExpr {}
 impl pg_sys::PgNode for CommonTableExpr {}
 impl std::fmt::Display for CommonTableExpr {
@@ -61643,6 +62567,12 @@ impl std::fmt::Display for CommonTableExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommonTableExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommonTableExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CompositeTypeStmt {}
 impl pg_sys::PgNode for CompositeTypeStmt {}
 impl std::fmt::Display for CompositeTypeStmt {
@@ -61650,6 +62580,12 @@ impl std::fmt::Display for CompositeTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CompositeTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CompositeTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Const {}
 impl pg_sys::PgNode for Const {}
 impl std::fmt::Display for Const {
@@ -61657,6 +62593,12 @@ impl std::fmt::Display for Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Const"))
+    }
+}
 impl pg_sys::seal::Sealed for Constraint {}
 impl pg_sys::PgNode for Constraint {}
 impl std::fmt::Display for Constraint {
@@ -61664,6 +62606,12 @@ impl std::fmt::Display for Constraint {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Constraint"))
+    }
+}
 impl pg_sys::seal::Sealed for ConstraintsSetStmt {}
 impl pg_sys::PgNode for ConstraintsSetStmt {}
 impl std::fmt::Display for ConstraintsSetStmt {
@@ -61671,6 +62619,12 @@ impl std::fmt::Display for ConstraintsSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConstraintsSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConstraintsSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ConvertRowtypeExpr {}
 impl pg_sys::PgNode for ConvertRowtypeExpr {}
 impl std::fmt::Display for ConvertRowtypeExpr {
@@ -61678,6 +62632,12 @@ impl std::fmt::Display for ConvertRowtypeExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConvertRowtypeExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConvertRowtypeExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CopyStmt {}
 impl pg_sys::PgNode for CopyStmt {}
 impl std::fmt::Display for CopyStmt {
@@ -61685,6 +62645,12 @@ impl std::fmt::Display for CopyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CopyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CopyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateAmStmt {}
 impl pg_sys::PgNode for CreateAmStmt {}
 impl std::fmt::Display for CreateAmStmt {
@@ -61692,6 +62658,12 @@ impl std::fmt::Display for CreateAmStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateAmStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateAmStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateCastStmt {}
 impl pg_sys::PgNode for CreateCastStmt {}
 impl std::fmt::Display for CreateCastStmt {
@@ -61699,6 +62671,12 @@ impl std::fmt::Display for CreateCastStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateCastStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateCastStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateConversionStmt {}
 impl pg_sys::PgNode for CreateConversionStmt {}
 impl std::fmt::Display for CreateConversionStmt {
@@ -61706,6 +62684,12 @@ impl std::fmt::Display for CreateConversionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateConversionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateConversionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateDomainStmt {}
 impl pg_sys::PgNode for CreateDomainStmt {}
 impl std::fmt::Display for CreateDomainStmt {
@@ -61713,6 +62697,12 @@ impl std::fmt::Display for CreateDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEnumStmt {}
 impl pg_sys::PgNode for CreateEnumStmt {}
 impl std::fmt::Display for CreateEnumStmt {
@@ -61720,6 +62710,12 @@ impl std::fmt::Display for CreateEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEventTrigStmt {}
 impl pg_sys::PgNode for CreateEventTrigStmt {}
 impl std::fmt::Display for CreateEventTrigStmt {
@@ -61727,6 +62723,12 @@ impl std::fmt::Display for CreateEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateExtensionStmt {}
 impl pg_sys::PgNode for CreateExtensionStmt {}
 impl std::fmt::Display for CreateExtensionStmt {
@@ -61734,6 +62736,12 @@ impl std::fmt::Display for CreateExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFdwStmt {}
 impl pg_sys::PgNode for CreateFdwStmt {}
 impl std::fmt::Display for CreateFdwStmt {
@@ -61741,6 +62749,12 @@ impl std::fmt::Display for CreateFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignServerStmt {}
 impl pg_sys::PgNode for CreateForeignServerStmt {}
 impl std::fmt::Display for CreateForeignServerStmt {
@@ -61748,6 +62762,12 @@ impl std::fmt::Display for CreateForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignTableStmt {}
 impl pg_sys::PgNode for CreateForeignTableStmt {}
 impl std::fmt::Display for CreateForeignTableStmt {
@@ -61755,6 +62775,12 @@ impl std::fmt::Display for CreateForeignTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFunctionStmt {}
 impl pg_sys::PgNode for CreateFunctionStmt {}
 impl std::fmt::Display for CreateFunctionStmt {
@@ -61762,6 +62788,12 @@ impl std::fmt::Display for CreateFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassItem {}
 impl pg_sys::PgNode for CreateOpClassItem {}
 impl std::fmt::Display for CreateOpClassItem {
@@ -61769,6 +62801,12 @@ impl std::fmt::Display for CreateOpClassItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassItem"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassStmt {}
 impl pg_sys::PgNode for CreateOpClassStmt {}
 impl std::fmt::Display for CreateOpClassStmt {
@@ -61776,6 +62814,12 @@ impl std::fmt::Display for CreateOpClassStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpFamilyStmt {}
 impl pg_sys::PgNode for CreateOpFamilyStmt {}
 impl std::fmt::Display for CreateOpFamilyStmt {
@@ -61783,6 +62827,12 @@ impl std::fmt::Display for CreateOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePLangStmt {}
 impl pg_sys::PgNode for CreatePLangStmt {}
 impl std::fmt::Display for CreatePLangStmt {
@@ -61790,6 +62840,12 @@ impl std::fmt::Display for CreatePLangStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePLangStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePLangStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePolicyStmt {}
 impl pg_sys::PgNode for CreatePolicyStmt {}
 impl std::fmt::Display for CreatePolicyStmt {
@@ -61797,6 +62853,12 @@ impl std::fmt::Display for CreatePolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePublicationStmt {}
 impl pg_sys::PgNode for CreatePublicationStmt {}
 impl std::fmt::Display for CreatePublicationStmt {
@@ -61804,6 +62866,12 @@ impl std::fmt::Display for CreatePublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRangeStmt {}
 impl pg_sys::PgNode for CreateRangeStmt {}
 impl std::fmt::Display for CreateRangeStmt {
@@ -61811,6 +62879,12 @@ impl std::fmt::Display for CreateRangeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRangeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRangeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateReplicationSlotCmd {}
 impl pg_sys::PgNode for CreateReplicationSlotCmd {}
 impl std::fmt::Display for CreateReplicationSlotCmd {
@@ -61818,6 +62892,12 @@ impl std::fmt::Display for CreateReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRoleStmt {}
 impl pg_sys::PgNode for CreateRoleStmt {}
 impl std::fmt::Display for CreateRoleStmt {
@@ -61825,6 +62905,12 @@ impl std::fmt::Display for CreateRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSchemaStmt {}
 impl pg_sys::PgNode for CreateSchemaStmt {}
 impl std::fmt::Display for CreateSchemaStmt {
@@ -61832,6 +62918,12 @@ impl std::fmt::Display for CreateSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSeqStmt {}
 impl pg_sys::PgNode for CreateSeqStmt {}
 impl std::fmt::Display for CreateSeqStmt {
@@ -61839,6 +62931,12 @@ impl std::fmt::Display for CreateSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStatsStmt {}
 impl pg_sys::PgNode for CreateStatsStmt {}
 impl std::fmt::Display for CreateStatsStmt {
@@ -61846,6 +62944,12 @@ impl std::fmt::Display for CreateStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStmt {}
 impl pg_sys::PgNode for CreateStmt {}
 impl std::fmt::Display for CreateStmt {
@@ -61853,6 +62957,12 @@ impl std::fmt::Display for CreateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSubscriptionStmt {}
 impl pg_sys::PgNode for CreateSubscriptionStmt {}
 impl std::fmt::Display for CreateSubscriptionStmt {
@@ -61860,6 +62970,12 @@ impl std::fmt::Display for CreateSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableAsStmt {}
 impl pg_sys::PgNode for CreateTableAsStmt {}
 impl std::fmt::Display for CreateTableAsStmt {
@@ -61867,6 +62983,12 @@ impl std::fmt::Display for CreateTableAsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableAsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableAsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableSpaceStmt {}
 impl pg_sys::PgNode for CreateTableSpaceStmt {}
 impl std::fmt::Display for CreateTableSpaceStmt {
@@ -61874,6 +62996,12 @@ impl std::fmt::Display for CreateTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTransformStmt {}
 impl pg_sys::PgNode for CreateTransformStmt {}
 impl std::fmt::Display for CreateTransformStmt {
@@ -61881,6 +63009,12 @@ impl std::fmt::Display for CreateTransformStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTransformStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTransformStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTrigStmt {}
 impl pg_sys::PgNode for CreateTrigStmt {}
 impl std::fmt::Display for CreateTrigStmt {
@@ -61888,6 +63022,12 @@ impl std::fmt::Display for CreateTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateUserMappingStmt {}
 impl pg_sys::PgNode for CreateUserMappingStmt {}
 impl std::fmt::Display for CreateUserMappingStmt {
@@ -61895,6 +63035,12 @@ impl std::fmt::Display for CreateUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatedbStmt {}
 impl pg_sys::PgNode for CreatedbStmt {}
 impl std::fmt::Display for CreatedbStmt {
@@ -61902,6 +63048,12 @@ impl std::fmt::Display for CreatedbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatedbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatedbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScan {}
 impl pg_sys::PgNode for CteScan {}
 impl std::fmt::Display for CteScan {
@@ -61909,6 +63061,12 @@ impl std::fmt::Display for CteScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScanState {}
 impl pg_sys::PgNode for CteScanState {}
 impl std::fmt::Display for CteScanState {
@@ -61916,6 +63074,12 @@ impl std::fmt::Display for CteScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for CurrentOfExpr {}
 impl pg_sys::PgNode for CurrentOfExpr {}
 impl std::fmt::Display for CurrentOfExpr {
@@ -61923,6 +63087,12 @@ impl std::fmt::Display for CurrentOfExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CurrentOfExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CurrentOfExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomPath {}
 impl pg_sys::PgNode for CustomPath {}
 impl std::fmt::Display for CustomPath {
@@ -61930,6 +63100,12 @@ impl std::fmt::Display for CustomPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomPath"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScan {}
 impl pg_sys::PgNode for CustomScan {}
 impl std::fmt::Display for CustomScan {
@@ -61937,6 +63113,12 @@ impl std::fmt::Display for CustomScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScanState {}
 impl pg_sys::PgNode for CustomScanState {}
 impl std::fmt::Display for CustomScanState {
@@ -61944,6 +63126,12 @@ impl std::fmt::Display for CustomScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for DeallocateStmt {}
 impl pg_sys::PgNode for DeallocateStmt {}
 impl std::fmt::Display for DeallocateStmt {
@@ -61951,6 +63139,12 @@ impl std::fmt::Display for DeallocateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeallocateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeallocateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeclareCursorStmt {}
 impl pg_sys::PgNode for DeclareCursorStmt {}
 impl std::fmt::Display for DeclareCursorStmt {
@@ -61958,6 +63152,12 @@ impl std::fmt::Display for DeclareCursorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeclareCursorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeclareCursorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DefElem {}
 impl pg_sys::PgNode for DefElem {}
 impl std::fmt::Display for DefElem {
@@ -61965,6 +63165,12 @@ impl std::fmt::Display for DefElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefElem"))
+    }
+}
 impl pg_sys::seal::Sealed for DefineStmt {}
 impl pg_sys::PgNode for DefineStmt {}
 impl std::fmt::Display for DefineStmt {
@@ -61972,6 +63178,12 @@ impl std::fmt::Display for DefineStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefineStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefineStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeleteStmt {}
 impl pg_sys::PgNode for DeleteStmt {}
 impl std::fmt::Display for DeleteStmt {
@@ -61979,6 +63191,12 @@ impl std::fmt::Display for DeleteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeleteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeleteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DiscardStmt {}
 impl pg_sys::PgNode for DiscardStmt {}
 impl std::fmt::Display for DiscardStmt {
@@ -61986,6 +63204,12 @@ impl std::fmt::Display for DiscardStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DiscardStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DiscardStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DoStmt {}
 impl pg_sys::PgNode for DoStmt {}
 impl std::fmt::Display for DoStmt {
@@ -61993,6 +63217,12 @@ impl std::fmt::Display for DoStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DoStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DoStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DomainConstraintState {}
 impl pg_sys::PgNode for DomainConstraintState {}
 impl std::fmt::Display for DomainConstraintState {
@@ -62000,6 +63230,12 @@ impl std::fmt::Display for DomainConstraintState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DomainConstraintState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DomainConstraintState"))
+    }
+}
 impl pg_sys::seal::Sealed for DropOwnedStmt {}
 impl pg_sys::PgNode for DropOwnedStmt {}
 impl std::fmt::Display for DropOwnedStmt {
@@ -62007,6 +63243,12 @@ impl std::fmt::Display for DropOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropReplicationSlotCmd {}
 impl pg_sys::PgNode for DropReplicationSlotCmd {}
 impl std::fmt::Display for DropReplicationSlotCmd {
@@ -62014,6 +63256,12 @@ impl std::fmt::Display for DropReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for DropRoleStmt {}
 impl pg_sys::PgNode for DropRoleStmt {}
 impl std::fmt::Display for DropRoleStmt {
@@ -62021,6 +63269,12 @@ impl std::fmt::Display for DropRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropStmt {}
 impl pg_sys::PgNode for DropStmt {}
 impl std::fmt::Display for DropStmt {
@@ -62028,6 +63282,12 @@ impl std::fmt::Display for DropStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropSubscriptionStmt {}
 impl pg_sys::PgNode for DropSubscriptionStmt {}
 impl std::fmt::Display for DropSubscriptionStmt {
@@ -62035,6 +63295,12 @@ impl std::fmt::Display for DropSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropTableSpaceStmt {}
 impl pg_sys::PgNode for DropTableSpaceStmt {}
 impl std::fmt::Display for DropTableSpaceStmt {
@@ -62042,6 +63308,12 @@ impl std::fmt::Display for DropTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropUserMappingStmt {}
 impl pg_sys::PgNode for DropUserMappingStmt {}
 impl std::fmt::Display for DropUserMappingStmt {
@@ -62049,6 +63321,12 @@ impl std::fmt::Display for DropUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropdbStmt {}
 impl pg_sys::PgNode for DropdbStmt {}
 impl std::fmt::Display for DropdbStmt {
@@ -62056,6 +63334,12 @@ impl std::fmt::Display for DropdbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropdbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropdbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for EState {}
 impl pg_sys::PgNode for EState {}
 impl std::fmt::Display for EState {
@@ -62063,6 +63347,12 @@ impl std::fmt::Display for EState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EState"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceClass {}
 impl pg_sys::PgNode for EquivalenceClass {}
 impl std::fmt::Display for EquivalenceClass {
@@ -62070,6 +63360,12 @@ impl std::fmt::Display for EquivalenceClass {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceClass"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceMember {}
 impl pg_sys::PgNode for EquivalenceMember {}
 impl std::fmt::Display for EquivalenceMember {
@@ -62077,6 +63373,12 @@ impl std::fmt::Display for EquivalenceMember {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceMember"))
+    }
+}
 impl pg_sys::seal::Sealed for EventTriggerData {}
 impl pg_sys::PgNode for EventTriggerData {}
 impl std::fmt::Display for EventTriggerData {
@@ -62084,6 +63386,12 @@ impl std::fmt::Display for EventTriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EventTriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EventTriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for ExecuteStmt {}
 impl pg_sys::PgNode for ExecuteStmt {}
 impl std::fmt::Display for ExecuteStmt {
@@ -62091,6 +63399,12 @@ impl std::fmt::Display for ExecuteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExecuteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExecuteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ExplainStmt {}
 impl pg_sys::PgNode for ExplainStmt {}
 impl std::fmt::Display for ExplainStmt {
@@ -62098,6 +63412,12 @@ impl std::fmt::Display for ExplainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExplainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExplainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Expr {}
 impl pg_sys::PgNode for Expr {}
 impl std::fmt::Display for Expr {
@@ -62105,6 +63425,12 @@ impl std::fmt::Display for Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprContext {}
 impl pg_sys::PgNode for ExprContext {}
 impl std::fmt::Display for ExprContext {
@@ -62112,6 +63438,12 @@ impl std::fmt::Display for ExprContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprContext"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprState {}
 impl pg_sys::PgNode for ExprState {}
 impl std::fmt::Display for ExprState {
@@ -62119,6 +63451,12 @@ impl std::fmt::Display for ExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for ExtensibleNode {}
 impl pg_sys::PgNode for ExtensibleNode {}
 impl std::fmt::Display for ExtensibleNode {
@@ -62126,6 +63464,12 @@ impl std::fmt::Display for ExtensibleNode {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExtensibleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExtensibleNode"))
+    }
+}
 impl pg_sys::seal::Sealed for FdwRoutine {}
 impl pg_sys::PgNode for FdwRoutine {}
 impl std::fmt::Display for FdwRoutine {
@@ -62133,6 +63477,12 @@ impl std::fmt::Display for FdwRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FdwRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FdwRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for FetchStmt {}
 impl pg_sys::PgNode for FetchStmt {}
 impl std::fmt::Display for FetchStmt {
@@ -62140,6 +63490,12 @@ impl std::fmt::Display for FetchStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FetchStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FetchStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldSelect {}
 impl pg_sys::PgNode for FieldSelect {}
 impl std::fmt::Display for FieldSelect {
@@ -62147,6 +63503,12 @@ impl std::fmt::Display for FieldSelect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldSelect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldSelect"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldStore {}
 impl pg_sys::PgNode for FieldStore {}
 impl std::fmt::Display for FieldStore {
@@ -62154,6 +63516,12 @@ impl std::fmt::Display for FieldStore {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldStore"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyCacheInfo {}
 impl pg_sys::PgNode for ForeignKeyCacheInfo {}
 impl std::fmt::Display for ForeignKeyCacheInfo {
@@ -62161,6 +63529,12 @@ impl std::fmt::Display for ForeignKeyCacheInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyCacheInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyCacheInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyOptInfo {}
 impl pg_sys::PgNode for ForeignKeyOptInfo {}
 impl std::fmt::Display for ForeignKeyOptInfo {
@@ -62168,6 +63542,12 @@ impl std::fmt::Display for ForeignKeyOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignPath {}
 impl pg_sys::PgNode for ForeignPath {}
 impl std::fmt::Display for ForeignPath {
@@ -62175,6 +63555,12 @@ impl std::fmt::Display for ForeignPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScan {}
 impl pg_sys::PgNode for ForeignScan {}
 impl std::fmt::Display for ForeignScan {
@@ -62182,6 +63568,12 @@ impl std::fmt::Display for ForeignScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScanState {}
 impl pg_sys::PgNode for ForeignScanState {}
 impl std::fmt::Display for ForeignScanState {
@@ -62189,6 +63581,12 @@ impl std::fmt::Display for ForeignScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for FromExpr {}
 impl pg_sys::PgNode for FromExpr {}
 impl std::fmt::Display for FromExpr {
@@ -62196,6 +63594,12 @@ impl std::fmt::Display for FromExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FromExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FromExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncCall {}
 impl pg_sys::PgNode for FuncCall {}
 impl std::fmt::Display for FuncCall {
@@ -62203,6 +63607,12 @@ impl std::fmt::Display for FuncCall {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncCall"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncExpr {}
 impl pg_sys::PgNode for FuncExpr {}
 impl std::fmt::Display for FuncExpr {
@@ -62210,6 +63620,12 @@ impl std::fmt::Display for FuncExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionParameter {}
 impl pg_sys::PgNode for FunctionParameter {}
 impl std::fmt::Display for FunctionParameter {
@@ -62217,13 +63633,25 @@ impl std::fmt::Display for FunctionParameter {
         write!(f, "{}", self.display_node())
     }
 }
-impl pg_sys::seal::Sealed for FunctionScan {}
-impl pg_sys::PgNode for FunctionScan {}
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionParameter"))
+    }
+}
+impl pg_sys::seal::Sealed for FunctionScan {}
+impl pg_sys::PgNode for FunctionScan {}
 impl std::fmt::Display for FunctionScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScan"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScanState {}
 impl pg_sys::PgNode for FunctionScanState {}
 impl std::fmt::Display for FunctionScanState {
@@ -62231,6 +63659,12 @@ impl std::fmt::Display for FunctionScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Gather {}
 impl pg_sys::PgNode for Gather {}
 impl std::fmt::Display for Gather {
@@ -62238,6 +63672,12 @@ impl std::fmt::Display for Gather {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Gather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Gather"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMerge {}
 impl pg_sys::PgNode for GatherMerge {}
 impl std::fmt::Display for GatherMerge {
@@ -62245,6 +63685,12 @@ impl std::fmt::Display for GatherMerge {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMerge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMerge"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergePath {}
 impl pg_sys::PgNode for GatherMergePath {}
 impl std::fmt::Display for GatherMergePath {
@@ -62252,6 +63698,12 @@ impl std::fmt::Display for GatherMergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergeState {}
 impl pg_sys::PgNode for GatherMergeState {}
 impl std::fmt::Display for GatherMergeState {
@@ -62259,6 +63711,12 @@ impl std::fmt::Display for GatherMergeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergeState"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherPath {}
 impl pg_sys::PgNode for GatherPath {}
 impl std::fmt::Display for GatherPath {
@@ -62266,6 +63724,12 @@ impl std::fmt::Display for GatherPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherState {}
 impl pg_sys::PgNode for GatherState {}
 impl std::fmt::Display for GatherState {
@@ -62273,6 +63737,12 @@ impl std::fmt::Display for GatherState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherState"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantRoleStmt {}
 impl pg_sys::PgNode for GrantRoleStmt {}
 impl std::fmt::Display for GrantRoleStmt {
@@ -62280,6 +63750,12 @@ impl std::fmt::Display for GrantRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantStmt {}
 impl pg_sys::PgNode for GrantStmt {}
 impl std::fmt::Display for GrantStmt {
@@ -62287,6 +63763,12 @@ impl std::fmt::Display for GrantStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Group {}
 impl pg_sys::PgNode for Group {}
 impl std::fmt::Display for Group {
@@ -62294,6 +63776,12 @@ impl std::fmt::Display for Group {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Group"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupPath {}
 impl pg_sys::PgNode for GroupPath {}
 impl std::fmt::Display for GroupPath {
@@ -62301,6 +63789,12 @@ impl std::fmt::Display for GroupPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupResultPath {}
 impl pg_sys::PgNode for GroupResultPath {}
 impl std::fmt::Display for GroupResultPath {
@@ -62308,6 +63802,12 @@ impl std::fmt::Display for GroupResultPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupResultPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupResultPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupState {}
 impl pg_sys::PgNode for GroupState {}
 impl std::fmt::Display for GroupState {
@@ -62315,6 +63815,12 @@ impl std::fmt::Display for GroupState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupState"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingFunc {}
 impl pg_sys::PgNode for GroupingFunc {}
 impl std::fmt::Display for GroupingFunc {
@@ -62322,6 +63828,12 @@ impl std::fmt::Display for GroupingFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSet {}
 impl pg_sys::PgNode for GroupingSet {}
 impl std::fmt::Display for GroupingSet {
@@ -62329,6 +63841,12 @@ impl std::fmt::Display for GroupingSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSet"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetData {}
 impl pg_sys::PgNode for GroupingSetData {}
 impl std::fmt::Display for GroupingSetData {
@@ -62336,6 +63854,12 @@ impl std::fmt::Display for GroupingSetData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetData"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetsPath {}
 impl pg_sys::PgNode for GroupingSetsPath {}
 impl std::fmt::Display for GroupingSetsPath {
@@ -62343,6 +63867,12 @@ impl std::fmt::Display for GroupingSetsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Hash {}
 impl pg_sys::PgNode for Hash {}
 impl std::fmt::Display for Hash {
@@ -62350,6 +63880,12 @@ impl std::fmt::Display for Hash {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Hash"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoin {}
 impl pg_sys::PgNode for HashJoin {}
 impl std::fmt::Display for HashJoin {
@@ -62357,6 +63893,12 @@ impl std::fmt::Display for HashJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoinState {}
 impl pg_sys::PgNode for HashJoinState {}
 impl std::fmt::Display for HashJoinState {
@@ -62364,6 +63906,12 @@ impl std::fmt::Display for HashJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for HashPath {}
 impl pg_sys::PgNode for HashPath {}
 impl std::fmt::Display for HashPath {
@@ -62371,6 +63919,12 @@ impl std::fmt::Display for HashPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashPath"))
+    }
+}
 impl pg_sys::seal::Sealed for HashState {}
 impl pg_sys::PgNode for HashState {}
 impl std::fmt::Display for HashState {
@@ -62378,6 +63932,12 @@ impl std::fmt::Display for HashState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashState"))
+    }
+}
 impl pg_sys::seal::Sealed for HeapTupleTableSlot {}
 impl pg_sys::PgNode for HeapTupleTableSlot {}
 impl std::fmt::Display for HeapTupleTableSlot {
@@ -62385,6 +63945,12 @@ impl std::fmt::Display for HeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for IdentifySystemCmd {}
 impl pg_sys::PgNode for IdentifySystemCmd {}
 impl std::fmt::Display for IdentifySystemCmd {
@@ -62392,6 +63958,12 @@ impl std::fmt::Display for IdentifySystemCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IdentifySystemCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IdentifySystemCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for ImportForeignSchemaStmt {}
 impl pg_sys::PgNode for ImportForeignSchemaStmt {}
 impl std::fmt::Display for ImportForeignSchemaStmt {
@@ -62399,6 +63971,12 @@ impl std::fmt::Display for ImportForeignSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ImportForeignSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ImportForeignSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSort {}
 impl pg_sys::PgNode for IncrementalSort {}
 impl std::fmt::Display for IncrementalSort {
@@ -62406,6 +63984,12 @@ impl std::fmt::Display for IncrementalSort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSort"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSortPath {}
 impl pg_sys::PgNode for IncrementalSortPath {}
 impl std::fmt::Display for IncrementalSortPath {
@@ -62413,6 +63997,12 @@ impl std::fmt::Display for IncrementalSortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSortState {}
 impl pg_sys::PgNode for IncrementalSortState {}
 impl std::fmt::Display for IncrementalSortState {
@@ -62420,6 +64010,12 @@ impl std::fmt::Display for IncrementalSortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSortState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexAmRoutine {}
 impl pg_sys::PgNode for IndexAmRoutine {}
 impl std::fmt::Display for IndexAmRoutine {
@@ -62427,6 +64023,12 @@ impl std::fmt::Display for IndexAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexClause {}
 impl pg_sys::PgNode for IndexClause {}
 impl std::fmt::Display for IndexClause {
@@ -62434,6 +64036,12 @@ impl std::fmt::Display for IndexClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexClause"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexElem {}
 impl pg_sys::PgNode for IndexElem {}
 impl std::fmt::Display for IndexElem {
@@ -62441,6 +64049,12 @@ impl std::fmt::Display for IndexElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexElem"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexInfo {}
 impl pg_sys::PgNode for IndexInfo {}
 impl std::fmt::Display for IndexInfo {
@@ -62448,6 +64062,12 @@ impl std::fmt::Display for IndexInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScan {}
 impl pg_sys::PgNode for IndexOnlyScan {}
 impl std::fmt::Display for IndexOnlyScan {
@@ -62455,6 +64075,12 @@ impl std::fmt::Display for IndexOnlyScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScanState {}
 impl pg_sys::PgNode for IndexOnlyScanState {}
 impl std::fmt::Display for IndexOnlyScanState {
@@ -62462,6 +64088,12 @@ impl std::fmt::Display for IndexOnlyScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOptInfo {}
 impl pg_sys::PgNode for IndexOptInfo {}
 impl std::fmt::Display for IndexOptInfo {
@@ -62469,6 +64101,12 @@ impl std::fmt::Display for IndexOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexPath {}
 impl pg_sys::PgNode for IndexPath {}
 impl std::fmt::Display for IndexPath {
@@ -62476,6 +64114,12 @@ impl std::fmt::Display for IndexPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScan {}
 impl pg_sys::PgNode for IndexScan {}
 impl std::fmt::Display for IndexScan {
@@ -62483,6 +64127,12 @@ impl std::fmt::Display for IndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScanState {}
 impl pg_sys::PgNode for IndexScanState {}
 impl std::fmt::Display for IndexScanState {
@@ -62490,6 +64140,12 @@ impl std::fmt::Display for IndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexStmt {}
 impl pg_sys::PgNode for IndexStmt {}
 impl std::fmt::Display for IndexStmt {
@@ -62497,6 +64153,12 @@ impl std::fmt::Display for IndexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for InferClause {}
 impl pg_sys::PgNode for InferClause {}
 impl std::fmt::Display for InferClause {
@@ -62504,6 +64166,12 @@ impl std::fmt::Display for InferClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferClause"))
+    }
+}
 impl pg_sys::seal::Sealed for InferenceElem {}
 impl pg_sys::PgNode for InferenceElem {}
 impl std::fmt::Display for InferenceElem {
@@ -62511,6 +64179,12 @@ impl std::fmt::Display for InferenceElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferenceElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferenceElem"))
+    }
+}
 impl pg_sys::seal::Sealed for InlineCodeBlock {}
 impl pg_sys::PgNode for InlineCodeBlock {}
 impl std::fmt::Display for InlineCodeBlock {
@@ -62518,6 +64192,12 @@ impl std::fmt::Display for InlineCodeBlock {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InlineCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InlineCodeBlock"))
+    }
+}
 impl pg_sys::seal::Sealed for InsertStmt {}
 impl pg_sys::PgNode for InsertStmt {}
 impl std::fmt::Display for InsertStmt {
@@ -62525,6 +64205,12 @@ impl std::fmt::Display for InsertStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InsertStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InsertStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IntoClause {}
 impl pg_sys::PgNode for IntoClause {}
 impl std::fmt::Display for IntoClause {
@@ -62532,6 +64218,12 @@ impl std::fmt::Display for IntoClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IntoClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IntoClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Join {}
 impl pg_sys::PgNode for Join {}
 impl std::fmt::Display for Join {
@@ -62539,6 +64231,12 @@ impl std::fmt::Display for Join {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Join {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Join"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinExpr {}
 impl pg_sys::PgNode for JoinExpr {}
 impl std::fmt::Display for JoinExpr {
@@ -62546,6 +64244,12 @@ impl std::fmt::Display for JoinExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinPath {}
 impl pg_sys::PgNode for JoinPath {}
 impl std::fmt::Display for JoinPath {
@@ -62553,6 +64257,12 @@ impl std::fmt::Display for JoinPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinPath"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinState {}
 impl pg_sys::PgNode for JoinState {}
 impl std::fmt::Display for JoinState {
@@ -62560,6 +64270,12 @@ impl std::fmt::Display for JoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for JunkFilter {}
 impl pg_sys::PgNode for JunkFilter {}
 impl std::fmt::Display for JunkFilter {
@@ -62567,6 +64283,12 @@ impl std::fmt::Display for JunkFilter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JunkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JunkFilter"))
+    }
+}
 impl pg_sys::seal::Sealed for Limit {}
 impl pg_sys::PgNode for Limit {}
 impl std::fmt::Display for Limit {
@@ -62574,6 +64296,12 @@ impl std::fmt::Display for Limit {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Limit"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitPath {}
 impl pg_sys::PgNode for LimitPath {}
 impl std::fmt::Display for LimitPath {
@@ -62581,6 +64309,12 @@ impl std::fmt::Display for LimitPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitState {}
 impl pg_sys::PgNode for LimitState {}
 impl std::fmt::Display for LimitState {
@@ -62588,6 +64322,12 @@ impl std::fmt::Display for LimitState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitState"))
+    }
+}
 impl pg_sys::seal::Sealed for List {}
 impl pg_sys::PgNode for List {}
 impl std::fmt::Display for List {
@@ -62595,6 +64335,12 @@ impl std::fmt::Display for List {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "List"))
+    }
+}
 impl pg_sys::seal::Sealed for ListenStmt {}
 impl pg_sys::PgNode for ListenStmt {}
 impl std::fmt::Display for ListenStmt {
@@ -62602,6 +64348,12 @@ impl std::fmt::Display for ListenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ListenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ListenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LoadStmt {}
 impl pg_sys::PgNode for LoadStmt {}
 impl std::fmt::Display for LoadStmt {
@@ -62609,6 +64361,12 @@ impl std::fmt::Display for LoadStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LoadStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LoadStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRows {}
 impl pg_sys::PgNode for LockRows {}
 impl std::fmt::Display for LockRows {
@@ -62616,6 +64374,12 @@ impl std::fmt::Display for LockRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRows"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsPath {}
 impl pg_sys::PgNode for LockRowsPath {}
 impl std::fmt::Display for LockRowsPath {
@@ -62623,6 +64387,12 @@ impl std::fmt::Display for LockRowsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsState {}
 impl pg_sys::PgNode for LockRowsState {}
 impl std::fmt::Display for LockRowsState {
@@ -62630,6 +64400,12 @@ impl std::fmt::Display for LockRowsState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsState"))
+    }
+}
 impl pg_sys::seal::Sealed for LockStmt {}
 impl pg_sys::PgNode for LockStmt {}
 impl std::fmt::Display for LockStmt {
@@ -62637,6 +64413,12 @@ impl std::fmt::Display for LockStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockingClause {}
 impl pg_sys::PgNode for LockingClause {}
 impl std::fmt::Display for LockingClause {
@@ -62644,6 +64426,12 @@ impl std::fmt::Display for LockingClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockingClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockingClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Material {}
 impl pg_sys::PgNode for Material {}
 impl std::fmt::Display for Material {
@@ -62651,6 +64439,12 @@ impl std::fmt::Display for Material {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Material"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialPath {}
 impl pg_sys::PgNode for MaterialPath {}
 impl std::fmt::Display for MaterialPath {
@@ -62658,6 +64452,12 @@ impl std::fmt::Display for MaterialPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialState {}
 impl pg_sys::PgNode for MaterialState {}
 impl std::fmt::Display for MaterialState {
@@ -62665,6 +64465,12 @@ impl std::fmt::Display for MaterialState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialState"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoryContextData {}
 impl pg_sys::PgNode for MemoryContextData {}
 impl std::fmt::Display for MemoryContextData {
@@ -62672,6 +64478,12 @@ impl std::fmt::Display for MemoryContextData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoryContextData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoryContextData"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppend {}
 impl pg_sys::PgNode for MergeAppend {}
 impl std::fmt::Display for MergeAppend {
@@ -62679,6 +64491,12 @@ impl std::fmt::Display for MergeAppend {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppend"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendPath {}
 impl pg_sys::PgNode for MergeAppendPath {}
 impl std::fmt::Display for MergeAppendPath {
@@ -62686,6 +64504,12 @@ impl std::fmt::Display for MergeAppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendState {}
 impl pg_sys::PgNode for MergeAppendState {}
 impl std::fmt::Display for MergeAppendState {
@@ -62693,6 +64517,12 @@ impl std::fmt::Display for MergeAppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoin {}
 impl pg_sys::PgNode for MergeJoin {}
 impl std::fmt::Display for MergeJoin {
@@ -62700,6 +64530,12 @@ impl std::fmt::Display for MergeJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoinState {}
 impl pg_sys::PgNode for MergeJoinState {}
 impl std::fmt::Display for MergeJoinState {
@@ -62707,6 +64543,12 @@ impl std::fmt::Display for MergeJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergePath {}
 impl pg_sys::PgNode for MergePath {}
 impl std::fmt::Display for MergePath {
@@ -62714,6 +64556,12 @@ impl std::fmt::Display for MergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggInfo {}
 impl pg_sys::PgNode for MinMaxAggInfo {}
 impl std::fmt::Display for MinMaxAggInfo {
@@ -62721,6 +64569,12 @@ impl std::fmt::Display for MinMaxAggInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggPath {}
 impl pg_sys::PgNode for MinMaxAggPath {}
 impl std::fmt::Display for MinMaxAggPath {
@@ -62728,6 +64582,12 @@ impl std::fmt::Display for MinMaxAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxExpr {}
 impl pg_sys::PgNode for MinMaxExpr {}
 impl std::fmt::Display for MinMaxExpr {
@@ -62735,6 +64595,12 @@ impl std::fmt::Display for MinMaxExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for MinimalTupleTableSlot {}
 impl pg_sys::PgNode for MinimalTupleTableSlot {}
 impl std::fmt::Display for MinimalTupleTableSlot {
@@ -62742,6 +64608,12 @@ impl std::fmt::Display for MinimalTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinimalTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinimalTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTable {}
 impl pg_sys::PgNode for ModifyTable {}
 impl std::fmt::Display for ModifyTable {
@@ -62749,6 +64621,12 @@ impl std::fmt::Display for ModifyTable {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTable"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTablePath {}
 impl pg_sys::PgNode for ModifyTablePath {}
 impl std::fmt::Display for ModifyTablePath {
@@ -62756,6 +64634,12 @@ impl std::fmt::Display for ModifyTablePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTablePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTablePath"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTableState {}
 impl pg_sys::PgNode for ModifyTableState {}
 impl std::fmt::Display for ModifyTableState {
@@ -62763,6 +64647,12 @@ impl std::fmt::Display for ModifyTableState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTableState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTableState"))
+    }
+}
 impl pg_sys::seal::Sealed for MultiAssignRef {}
 impl pg_sys::PgNode for MultiAssignRef {}
 impl std::fmt::Display for MultiAssignRef {
@@ -62770,6 +64660,12 @@ impl std::fmt::Display for MultiAssignRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MultiAssignRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MultiAssignRef"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedArgExpr {}
 impl pg_sys::PgNode for NamedArgExpr {}
 impl std::fmt::Display for NamedArgExpr {
@@ -62777,6 +64673,12 @@ impl std::fmt::Display for NamedArgExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedArgExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedArgExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScan {}
 impl pg_sys::PgNode for NamedTuplestoreScan {}
 impl std::fmt::Display for NamedTuplestoreScan {
@@ -62784,6 +64686,12 @@ impl std::fmt::Display for NamedTuplestoreScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScan"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScanState {}
 impl pg_sys::PgNode for NamedTuplestoreScanState {}
 impl std::fmt::Display for NamedTuplestoreScanState {
@@ -62791,6 +64699,12 @@ impl std::fmt::Display for NamedTuplestoreScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoop {}
 impl pg_sys::PgNode for NestLoop {}
 impl std::fmt::Display for NestLoop {
@@ -62798,6 +64712,12 @@ impl std::fmt::Display for NestLoop {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoop"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopParam {}
 impl pg_sys::PgNode for NestLoopParam {}
 impl std::fmt::Display for NestLoopParam {
@@ -62805,6 +64725,12 @@ impl std::fmt::Display for NestLoopParam {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopParam"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopState {}
 impl pg_sys::PgNode for NestLoopState {}
 impl std::fmt::Display for NestLoopState {
@@ -62812,6 +64738,12 @@ impl std::fmt::Display for NestLoopState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopState"))
+    }
+}
 impl pg_sys::seal::Sealed for NextValueExpr {}
 impl pg_sys::PgNode for NextValueExpr {}
 impl std::fmt::Display for NextValueExpr {
@@ -62819,6 +64751,12 @@ impl std::fmt::Display for NextValueExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NextValueExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NextValueExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Node {}
 impl pg_sys::PgNode for Node {}
 impl std::fmt::Display for Node {
@@ -62826,6 +64764,12 @@ impl std::fmt::Display for Node {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Node"))
+    }
+}
 impl pg_sys::seal::Sealed for NotifyStmt {}
 impl pg_sys::PgNode for NotifyStmt {}
 impl std::fmt::Display for NotifyStmt {
@@ -62833,6 +64777,12 @@ impl std::fmt::Display for NotifyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NotifyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NotifyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for NullTest {}
 impl pg_sys::PgNode for NullTest {}
 impl std::fmt::Display for NullTest {
@@ -62840,6 +64790,12 @@ impl std::fmt::Display for NullTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NullTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NullTest"))
+    }
+}
 impl pg_sys::seal::Sealed for ObjectWithArgs {}
 impl pg_sys::PgNode for ObjectWithArgs {}
 impl std::fmt::Display for ObjectWithArgs {
@@ -62847,6 +64803,12 @@ impl std::fmt::Display for ObjectWithArgs {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ObjectWithArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ObjectWithArgs"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictClause {}
 impl pg_sys::PgNode for OnConflictClause {}
 impl std::fmt::Display for OnConflictClause {
@@ -62854,6 +64816,12 @@ impl std::fmt::Display for OnConflictClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictClause"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictExpr {}
 impl pg_sys::PgNode for OnConflictExpr {}
 impl std::fmt::Display for OnConflictExpr {
@@ -62861,6 +64829,12 @@ impl std::fmt::Display for OnConflictExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictSetState {}
 impl pg_sys::PgNode for OnConflictSetState {}
 impl std::fmt::Display for OnConflictSetState {
@@ -62868,6 +64842,12 @@ impl std::fmt::Display for OnConflictSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for OpExpr {}
 impl pg_sys::PgNode for OpExpr {}
 impl std::fmt::Display for OpExpr {
@@ -62875,6 +64855,12 @@ impl std::fmt::Display for OpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Param {}
 impl pg_sys::PgNode for Param {}
 impl std::fmt::Display for Param {
@@ -62882,6 +64868,12 @@ impl std::fmt::Display for Param {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Param"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamPathInfo {}
 impl pg_sys::PgNode for ParamPathInfo {}
 impl std::fmt::Display for ParamPathInfo {
@@ -62889,6 +64881,12 @@ impl std::fmt::Display for ParamPathInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamPathInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamPathInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamRef {}
 impl pg_sys::PgNode for ParamRef {}
 impl std::fmt::Display for ParamRef {
@@ -62896,6 +64894,12 @@ impl std::fmt::Display for ParamRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamRef"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionBoundSpec {}
 impl pg_sys::PgNode for PartitionBoundSpec {}
 impl std::fmt::Display for PartitionBoundSpec {
@@ -62903,6 +64907,12 @@ impl std::fmt::Display for PartitionBoundSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionBoundSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionBoundSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionCmd {}
 impl pg_sys::PgNode for PartitionCmd {}
 impl std::fmt::Display for PartitionCmd {
@@ -62910,6 +64920,12 @@ impl std::fmt::Display for PartitionCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionElem {}
 impl pg_sys::PgNode for PartitionElem {}
 impl std::fmt::Display for PartitionElem {
@@ -62917,6 +64933,12 @@ impl std::fmt::Display for PartitionElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionElem"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneInfo {}
 impl pg_sys::PgNode for PartitionPruneInfo {}
 impl std::fmt::Display for PartitionPruneInfo {
@@ -62924,6 +64946,12 @@ impl std::fmt::Display for PartitionPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStep {}
 impl pg_sys::PgNode for PartitionPruneStep {}
 impl std::fmt::Display for PartitionPruneStep {
@@ -62931,6 +64959,12 @@ impl std::fmt::Display for PartitionPruneStep {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStep"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepCombine {}
 impl pg_sys::PgNode for PartitionPruneStepCombine {}
 impl std::fmt::Display for PartitionPruneStepCombine {
@@ -62938,6 +64972,12 @@ impl std::fmt::Display for PartitionPruneStepCombine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepCombine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepCombine"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepOp {}
 impl pg_sys::PgNode for PartitionPruneStepOp {}
 impl std::fmt::Display for PartitionPruneStepOp {
@@ -62945,6 +64985,12 @@ impl std::fmt::Display for PartitionPruneStepOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepOp"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionRangeDatum {}
 impl pg_sys::PgNode for PartitionRangeDatum {}
 impl std::fmt::Display for PartitionRangeDatum {
@@ -62952,6 +64998,12 @@ impl std::fmt::Display for PartitionRangeDatum {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionRangeDatum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionRangeDatum"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionSpec {}
 impl pg_sys::PgNode for PartitionSpec {}
 impl std::fmt::Display for PartitionSpec {
@@ -62959,6 +65011,12 @@ impl std::fmt::Display for PartitionSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionedRelPruneInfo {}
 impl pg_sys::PgNode for PartitionedRelPruneInfo {}
 impl std::fmt::Display for PartitionedRelPruneInfo {
@@ -62966,6 +65024,12 @@ impl std::fmt::Display for PartitionedRelPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionedRelPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionedRelPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Path {}
 impl pg_sys::PgNode for Path {}
 impl std::fmt::Display for Path {
@@ -62973,6 +65037,12 @@ impl std::fmt::Display for Path {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Path"))
+    }
+}
 impl pg_sys::seal::Sealed for PathKey {}
 impl pg_sys::PgNode for PathKey {}
 impl std::fmt::Display for PathKey {
@@ -62980,6 +65050,12 @@ impl std::fmt::Display for PathKey {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathKey"))
+    }
+}
 impl pg_sys::seal::Sealed for PathTarget {}
 impl pg_sys::PgNode for PathTarget {}
 impl std::fmt::Display for PathTarget {
@@ -62987,6 +65063,12 @@ impl std::fmt::Display for PathTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderInfo {}
 impl pg_sys::PgNode for PlaceHolderInfo {}
 impl std::fmt::Display for PlaceHolderInfo {
@@ -62994,6 +65076,12 @@ impl std::fmt::Display for PlaceHolderInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderVar {}
 impl pg_sys::PgNode for PlaceHolderVar {}
 impl std::fmt::Display for PlaceHolderVar {
@@ -63001,6 +65089,12 @@ impl std::fmt::Display for PlaceHolderVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderVar"))
+    }
+}
 impl pg_sys::seal::Sealed for Plan {}
 impl pg_sys::PgNode for Plan {}
 impl std::fmt::Display for Plan {
@@ -63008,6 +65102,12 @@ impl std::fmt::Display for Plan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Plan"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanInvalItem {}
 impl pg_sys::PgNode for PlanInvalItem {}
 impl std::fmt::Display for PlanInvalItem {
@@ -63015,6 +65115,12 @@ impl std::fmt::Display for PlanInvalItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanInvalItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanInvalItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanRowMark {}
 impl pg_sys::PgNode for PlanRowMark {}
 impl std::fmt::Display for PlanRowMark {
@@ -63022,6 +65128,12 @@ impl std::fmt::Display for PlanRowMark {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanRowMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanRowMark"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanState {}
 impl pg_sys::PgNode for PlanState {}
 impl std::fmt::Display for PlanState {
@@ -63029,6 +65141,12 @@ impl std::fmt::Display for PlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannedStmt {}
 impl pg_sys::PgNode for PlannedStmt {}
 impl std::fmt::Display for PlannedStmt {
@@ -63036,6 +65154,12 @@ impl std::fmt::Display for PlannedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerGlobal {}
 impl pg_sys::PgNode for PlannerGlobal {}
 impl std::fmt::Display for PlannerGlobal {
@@ -63043,6 +65167,12 @@ impl std::fmt::Display for PlannerGlobal {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerGlobal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerGlobal"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerInfo {}
 impl pg_sys::PgNode for PlannerInfo {}
 impl std::fmt::Display for PlannerInfo {
@@ -63050,6 +65180,12 @@ impl std::fmt::Display for PlannerInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerParamItem {}
 impl pg_sys::PgNode for PlannerParamItem {}
 impl std::fmt::Display for PlannerParamItem {
@@ -63057,6 +65193,12 @@ impl std::fmt::Display for PlannerParamItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerParamItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerParamItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PrepareStmt {}
 impl pg_sys::PgNode for PrepareStmt {}
 impl std::fmt::Display for PrepareStmt {
@@ -63064,6 +65206,12 @@ impl std::fmt::Display for PrepareStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PrepareStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PrepareStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSet {}
 impl pg_sys::PgNode for ProjectSet {}
 impl std::fmt::Display for ProjectSet {
@@ -63071,6 +65219,12 @@ impl std::fmt::Display for ProjectSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSet"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetPath {}
 impl pg_sys::PgNode for ProjectSetPath {}
 impl std::fmt::Display for ProjectSetPath {
@@ -63078,6 +65232,12 @@ impl std::fmt::Display for ProjectSetPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetState {}
 impl pg_sys::PgNode for ProjectSetState {}
 impl std::fmt::Display for ProjectSetState {
@@ -63085,6 +65245,12 @@ impl std::fmt::Display for ProjectSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionInfo {}
 impl pg_sys::PgNode for ProjectionInfo {}
 impl std::fmt::Display for ProjectionInfo {
@@ -63092,6 +65258,12 @@ impl std::fmt::Display for ProjectionInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionPath {}
 impl pg_sys::PgNode for ProjectionPath {}
 impl std::fmt::Display for ProjectionPath {
@@ -63099,6 +65271,12 @@ impl std::fmt::Display for ProjectionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Query {}
 impl pg_sys::PgNode for Query {}
 impl std::fmt::Display for Query {
@@ -63106,6 +65284,12 @@ impl std::fmt::Display for Query {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Query"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeFunction {}
 impl pg_sys::PgNode for RangeFunction {}
 impl std::fmt::Display for RangeFunction {
@@ -63113,6 +65297,12 @@ impl std::fmt::Display for RangeFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeSubselect {}
 impl pg_sys::PgNode for RangeSubselect {}
 impl std::fmt::Display for RangeSubselect {
@@ -63120,6 +65310,12 @@ impl std::fmt::Display for RangeSubselect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeSubselect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeSubselect"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFunc {}
 impl pg_sys::PgNode for RangeTableFunc {}
 impl std::fmt::Display for RangeTableFunc {
@@ -63127,6 +65323,12 @@ impl std::fmt::Display for RangeTableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFuncCol {}
 impl pg_sys::PgNode for RangeTableFuncCol {}
 impl std::fmt::Display for RangeTableFuncCol {
@@ -63134,6 +65336,12 @@ impl std::fmt::Display for RangeTableFuncCol {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFuncCol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFuncCol"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableSample {}
 impl pg_sys::PgNode for RangeTableSample {}
 impl std::fmt::Display for RangeTableSample {
@@ -63141,6 +65349,12 @@ impl std::fmt::Display for RangeTableSample {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableSample"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblEntry {}
 impl pg_sys::PgNode for RangeTblEntry {}
 impl std::fmt::Display for RangeTblEntry {
@@ -63148,6 +65362,12 @@ impl std::fmt::Display for RangeTblEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblFunction {}
 impl pg_sys::PgNode for RangeTblFunction {}
 impl std::fmt::Display for RangeTblFunction {
@@ -63155,6 +65375,12 @@ impl std::fmt::Display for RangeTblFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblRef {}
 impl pg_sys::PgNode for RangeTblRef {}
 impl std::fmt::Display for RangeTblRef {
@@ -63162,6 +65388,12 @@ impl std::fmt::Display for RangeTblRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblRef"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeVar {}
 impl pg_sys::PgNode for RangeVar {}
 impl std::fmt::Display for RangeVar {
@@ -63169,6 +65401,12 @@ impl std::fmt::Display for RangeVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeVar"))
+    }
+}
 impl pg_sys::seal::Sealed for RawStmt {}
 impl pg_sys::PgNode for RawStmt {}
 impl std::fmt::Display for RawStmt {
@@ -63176,6 +65414,12 @@ impl std::fmt::Display for RawStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RawStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RawStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReassignOwnedStmt {}
 impl pg_sys::PgNode for ReassignOwnedStmt {}
 impl std::fmt::Display for ReassignOwnedStmt {
@@ -63183,6 +65427,12 @@ impl std::fmt::Display for ReassignOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReassignOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReassignOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnion {}
 impl pg_sys::PgNode for RecursiveUnion {}
 impl std::fmt::Display for RecursiveUnion {
@@ -63190,6 +65440,12 @@ impl std::fmt::Display for RecursiveUnion {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnion"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionPath {}
 impl pg_sys::PgNode for RecursiveUnionPath {}
 impl std::fmt::Display for RecursiveUnionPath {
@@ -63197,6 +65453,12 @@ impl std::fmt::Display for RecursiveUnionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionState {}
 impl pg_sys::PgNode for RecursiveUnionState {}
 impl std::fmt::Display for RecursiveUnionState {
@@ -63204,6 +65466,12 @@ impl std::fmt::Display for RecursiveUnionState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionState"))
+    }
+}
 impl pg_sys::seal::Sealed for RefreshMatViewStmt {}
 impl pg_sys::PgNode for RefreshMatViewStmt {}
 impl std::fmt::Display for RefreshMatViewStmt {
@@ -63211,6 +65479,12 @@ impl std::fmt::Display for RefreshMatViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RefreshMatViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RefreshMatViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReindexStmt {}
 impl pg_sys::PgNode for ReindexStmt {}
 impl std::fmt::Display for ReindexStmt {
@@ -63218,6 +65492,12 @@ impl std::fmt::Display for ReindexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReindexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReindexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RelOptInfo {}
 impl pg_sys::PgNode for RelOptInfo {}
 impl std::fmt::Display for RelOptInfo {
@@ -63225,6 +65505,12 @@ impl std::fmt::Display for RelOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RelabelType {}
 impl pg_sys::PgNode for RelabelType {}
 impl std::fmt::Display for RelabelType {
@@ -63232,6 +65518,12 @@ impl std::fmt::Display for RelabelType {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelabelType"))
+    }
+}
 impl pg_sys::seal::Sealed for RenameStmt {}
 impl pg_sys::PgNode for RenameStmt {}
 impl std::fmt::Display for RenameStmt {
@@ -63239,6 +65531,12 @@ impl std::fmt::Display for RenameStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RenameStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RenameStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReplicaIdentityStmt {}
 impl pg_sys::PgNode for ReplicaIdentityStmt {}
 impl std::fmt::Display for ReplicaIdentityStmt {
@@ -63246,6 +65544,12 @@ impl std::fmt::Display for ReplicaIdentityStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReplicaIdentityStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReplicaIdentityStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ResTarget {}
 impl pg_sys::PgNode for ResTarget {}
 impl std::fmt::Display for ResTarget {
@@ -63253,6 +65557,12 @@ impl std::fmt::Display for ResTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for RestrictInfo {}
 impl pg_sys::PgNode for RestrictInfo {}
 impl std::fmt::Display for RestrictInfo {
@@ -63260,6 +65570,12 @@ impl std::fmt::Display for RestrictInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RestrictInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RestrictInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Result {}
 impl pg_sys::PgNode for Result {}
 impl std::fmt::Display for Result {
@@ -63267,6 +65583,12 @@ impl std::fmt::Display for Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Result"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultRelInfo {}
 impl pg_sys::PgNode for ResultRelInfo {}
 impl std::fmt::Display for ResultRelInfo {
@@ -63274,6 +65596,12 @@ impl std::fmt::Display for ResultRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultState {}
 impl pg_sys::PgNode for ResultState {}
 impl std::fmt::Display for ResultState {
@@ -63281,6 +65609,12 @@ impl std::fmt::Display for ResultState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultState"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnSetInfo {}
 impl pg_sys::PgNode for ReturnSetInfo {}
 impl std::fmt::Display for ReturnSetInfo {
@@ -63288,6 +65622,12 @@ impl std::fmt::Display for ReturnSetInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnSetInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnSetInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RoleSpec {}
 impl pg_sys::PgNode for RoleSpec {}
 impl std::fmt::Display for RoleSpec {
@@ -63295,6 +65635,12 @@ impl std::fmt::Display for RoleSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RoleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RoleSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for RollupData {}
 impl pg_sys::PgNode for RollupData {}
 impl std::fmt::Display for RollupData {
@@ -63302,6 +65648,12 @@ impl std::fmt::Display for RollupData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RollupData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RollupData"))
+    }
+}
 impl pg_sys::seal::Sealed for RowCompareExpr {}
 impl pg_sys::PgNode for RowCompareExpr {}
 impl std::fmt::Display for RowCompareExpr {
@@ -63309,6 +65661,12 @@ impl std::fmt::Display for RowCompareExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowCompareExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowCompareExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowExpr {}
 impl pg_sys::PgNode for RowExpr {}
 impl std::fmt::Display for RowExpr {
@@ -63316,6 +65674,12 @@ impl std::fmt::Display for RowExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowMarkClause {}
 impl pg_sys::PgNode for RowMarkClause {}
 impl std::fmt::Display for RowMarkClause {
@@ -63323,6 +65687,12 @@ impl std::fmt::Display for RowMarkClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowMarkClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowMarkClause"))
+    }
+}
 impl pg_sys::seal::Sealed for RuleStmt {}
 impl pg_sys::PgNode for RuleStmt {}
 impl std::fmt::Display for RuleStmt {
@@ -63330,6 +65700,12 @@ impl std::fmt::Display for RuleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RuleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RuleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLCmd {}
 impl pg_sys::PgNode for SQLCmd {}
 impl std::fmt::Display for SQLCmd {
@@ -63337,6 +65713,12 @@ impl std::fmt::Display for SQLCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLValueFunction {}
 impl pg_sys::PgNode for SQLValueFunction {}
 impl std::fmt::Display for SQLValueFunction {
@@ -63344,6 +65726,12 @@ impl std::fmt::Display for SQLValueFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLValueFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLValueFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScan {}
 impl pg_sys::PgNode for SampleScan {}
 impl std::fmt::Display for SampleScan {
@@ -63351,6 +65739,12 @@ impl std::fmt::Display for SampleScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScanState {}
 impl pg_sys::PgNode for SampleScanState {}
 impl std::fmt::Display for SampleScanState {
@@ -63358,6 +65752,12 @@ impl std::fmt::Display for SampleScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for ScalarArrayOpExpr {}
 impl pg_sys::PgNode for ScalarArrayOpExpr {}
 impl std::fmt::Display for ScalarArrayOpExpr {
@@ -63365,6 +65765,12 @@ impl std::fmt::Display for ScalarArrayOpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScalarArrayOpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScalarArrayOpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Scan {}
 impl pg_sys::PgNode for Scan {}
 impl std::fmt::Display for Scan {
@@ -63372,6 +65778,12 @@ impl std::fmt::Display for Scan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Scan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Scan"))
+    }
+}
 impl pg_sys::seal::Sealed for ScanState {}
 impl pg_sys::PgNode for ScanState {}
 impl std::fmt::Display for ScanState {
@@ -63379,6 +65791,12 @@ impl std::fmt::Display for ScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SecLabelStmt {}
 impl pg_sys::PgNode for SecLabelStmt {}
 impl std::fmt::Display for SecLabelStmt {
@@ -63386,6 +65804,12 @@ impl std::fmt::Display for SecLabelStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SecLabelStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SecLabelStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SelectStmt {}
 impl pg_sys::PgNode for SelectStmt {}
 impl std::fmt::Display for SelectStmt {
@@ -63393,6 +65817,12 @@ impl std::fmt::Display for SelectStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SelectStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SelectStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SeqScanState {}
 impl pg_sys::PgNode for SeqScanState {}
 impl std::fmt::Display for SeqScanState {
@@ -63400,6 +65830,12 @@ impl std::fmt::Display for SeqScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SeqScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SeqScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetExprState {}
 impl pg_sys::PgNode for SetExprState {}
 impl std::fmt::Display for SetExprState {
@@ -63407,6 +65843,12 @@ impl std::fmt::Display for SetExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOp {}
 impl pg_sys::PgNode for SetOp {}
 impl std::fmt::Display for SetOp {
@@ -63414,6 +65856,12 @@ impl std::fmt::Display for SetOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOp"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpPath {}
 impl pg_sys::PgNode for SetOpPath {}
 impl std::fmt::Display for SetOpPath {
@@ -63421,6 +65869,12 @@ impl std::fmt::Display for SetOpPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpState {}
 impl pg_sys::PgNode for SetOpState {}
 impl std::fmt::Display for SetOpState {
@@ -63428,6 +65882,12 @@ impl std::fmt::Display for SetOpState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOperationStmt {}
 impl pg_sys::PgNode for SetOperationStmt {}
 impl std::fmt::Display for SetOperationStmt {
@@ -63435,6 +65895,12 @@ impl std::fmt::Display for SetOperationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOperationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOperationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SetToDefault {}
 impl pg_sys::PgNode for SetToDefault {}
 impl std::fmt::Display for SetToDefault {
@@ -63442,6 +65908,12 @@ impl std::fmt::Display for SetToDefault {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetToDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetToDefault"))
+    }
+}
 impl pg_sys::seal::Sealed for Sort {}
 impl pg_sys::PgNode for Sort {}
 impl std::fmt::Display for Sort {
@@ -63449,6 +65921,12 @@ impl std::fmt::Display for Sort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Sort"))
+    }
+}
 impl pg_sys::seal::Sealed for SortBy {}
 impl pg_sys::PgNode for SortBy {}
 impl std::fmt::Display for SortBy {
@@ -63456,6 +65934,12 @@ impl std::fmt::Display for SortBy {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortBy"))
+    }
+}
 impl pg_sys::seal::Sealed for SortGroupClause {}
 impl pg_sys::PgNode for SortGroupClause {}
 impl std::fmt::Display for SortGroupClause {
@@ -63463,6 +65947,12 @@ impl std::fmt::Display for SortGroupClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortGroupClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortGroupClause"))
+    }
+}
 impl pg_sys::seal::Sealed for SortPath {}
 impl pg_sys::PgNode for SortPath {}
 impl std::fmt::Display for SortPath {
@@ -63470,6 +65960,12 @@ impl std::fmt::Display for SortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SortState {}
 impl pg_sys::PgNode for SortState {}
 impl std::fmt::Display for SortState {
@@ -63477,6 +65973,12 @@ impl std::fmt::Display for SortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortState"))
+    }
+}
 impl pg_sys::seal::Sealed for SpecialJoinInfo {}
 impl pg_sys::PgNode for SpecialJoinInfo {}
 impl std::fmt::Display for SpecialJoinInfo {
@@ -63484,6 +65986,12 @@ impl std::fmt::Display for SpecialJoinInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SpecialJoinInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SpecialJoinInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StartReplicationCmd {}
 impl pg_sys::PgNode for StartReplicationCmd {}
 impl std::fmt::Display for StartReplicationCmd {
@@ -63491,6 +65999,12 @@ impl std::fmt::Display for StartReplicationCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StartReplicationCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StartReplicationCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for StatisticExtInfo {}
 impl pg_sys::PgNode for StatisticExtInfo {}
 impl std::fmt::Display for StatisticExtInfo {
@@ -63498,6 +66012,12 @@ impl std::fmt::Display for StatisticExtInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatisticExtInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatisticExtInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for SubLink {}
 impl pg_sys::PgNode for SubLink {}
 impl std::fmt::Display for SubLink {
@@ -63505,6 +66025,12 @@ impl std::fmt::Display for SubLink {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubLink"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlan {}
 impl pg_sys::PgNode for SubPlan {}
 impl std::fmt::Display for SubPlan {
@@ -63512,6 +66038,12 @@ impl std::fmt::Display for SubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlanState {}
 impl pg_sys::PgNode for SubPlanState {}
 impl std::fmt::Display for SubPlanState {
@@ -63519,6 +66051,12 @@ impl std::fmt::Display for SubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScan {}
 impl pg_sys::PgNode for SubqueryScan {}
 impl std::fmt::Display for SubqueryScan {
@@ -63526,6 +66064,12 @@ impl std::fmt::Display for SubqueryScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanPath {}
 impl pg_sys::PgNode for SubqueryScanPath {}
 impl std::fmt::Display for SubqueryScanPath {
@@ -63533,6 +66077,12 @@ impl std::fmt::Display for SubqueryScanPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanState {}
 impl pg_sys::PgNode for SubqueryScanState {}
 impl std::fmt::Display for SubqueryScanState {
@@ -63540,6 +66090,12 @@ impl std::fmt::Display for SubqueryScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubscriptingRef {}
 impl pg_sys::PgNode for SubscriptingRef {}
 impl std::fmt::Display for SubscriptingRef {
@@ -63547,6 +66103,12 @@ impl std::fmt::Display for SubscriptingRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubscriptingRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubscriptingRef"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestCost {}
 impl pg_sys::PgNode for SupportRequestCost {}
 impl std::fmt::Display for SupportRequestCost {
@@ -63554,6 +66116,12 @@ impl std::fmt::Display for SupportRequestCost {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestCost"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestIndexCondition {}
 impl pg_sys::PgNode for SupportRequestIndexCondition {}
 impl std::fmt::Display for SupportRequestIndexCondition {
@@ -63561,6 +66129,12 @@ impl std::fmt::Display for SupportRequestIndexCondition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestIndexCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestIndexCondition"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestRows {}
 impl pg_sys::PgNode for SupportRequestRows {}
 impl std::fmt::Display for SupportRequestRows {
@@ -63568,6 +66142,12 @@ impl std::fmt::Display for SupportRequestRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestRows"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSelectivity {}
 impl pg_sys::PgNode for SupportRequestSelectivity {}
 impl std::fmt::Display for SupportRequestSelectivity {
@@ -63575,6 +66155,12 @@ impl std::fmt::Display for SupportRequestSelectivity {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSelectivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSelectivity"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSimplify {}
 impl pg_sys::PgNode for SupportRequestSimplify {}
 impl std::fmt::Display for SupportRequestSimplify {
@@ -63582,6 +66168,12 @@ impl std::fmt::Display for SupportRequestSimplify {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSimplify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSimplify"))
+    }
+}
 impl pg_sys::seal::Sealed for TableAmRoutine {}
 impl pg_sys::PgNode for TableAmRoutine {}
 impl std::fmt::Display for TableAmRoutine {
@@ -63589,6 +66181,12 @@ impl std::fmt::Display for TableAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFunc {}
 impl pg_sys::PgNode for TableFunc {}
 impl std::fmt::Display for TableFunc {
@@ -63596,6 +66194,12 @@ impl std::fmt::Display for TableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScan {}
 impl pg_sys::PgNode for TableFuncScan {}
 impl std::fmt::Display for TableFuncScan {
@@ -63603,6 +66207,12 @@ impl std::fmt::Display for TableFuncScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScanState {}
 impl pg_sys::PgNode for TableFuncScanState {}
 impl std::fmt::Display for TableFuncScanState {
@@ -63610,6 +66220,12 @@ impl std::fmt::Display for TableFuncScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TableLikeClause {}
 impl pg_sys::PgNode for TableLikeClause {}
 impl std::fmt::Display for TableLikeClause {
@@ -63617,6 +66233,12 @@ impl std::fmt::Display for TableLikeClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableLikeClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableLikeClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TableSampleClause {}
 impl pg_sys::PgNode for TableSampleClause {}
 impl std::fmt::Display for TableSampleClause {
@@ -63624,6 +66246,12 @@ impl std::fmt::Display for TableSampleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableSampleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableSampleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TargetEntry {}
 impl pg_sys::PgNode for TargetEntry {}
 impl std::fmt::Display for TargetEntry {
@@ -63631,6 +66259,12 @@ impl std::fmt::Display for TargetEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TargetEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TargetEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for TidPath {}
 impl pg_sys::PgNode for TidPath {}
 impl std::fmt::Display for TidPath {
@@ -63638,6 +66272,12 @@ impl std::fmt::Display for TidPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidPath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScan {}
 impl pg_sys::PgNode for TidScan {}
 impl std::fmt::Display for TidScan {
@@ -63645,6 +66285,12 @@ impl std::fmt::Display for TidScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScanState {}
 impl pg_sys::PgNode for TidScanState {}
 impl std::fmt::Display for TidScanState {
@@ -63652,6 +66298,12 @@ impl std::fmt::Display for TidScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TimeLineHistoryCmd {}
 impl pg_sys::PgNode for TimeLineHistoryCmd {}
 impl std::fmt::Display for TimeLineHistoryCmd {
@@ -63659,6 +66311,12 @@ impl std::fmt::Display for TimeLineHistoryCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TimeLineHistoryCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TimeLineHistoryCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for TransactionStmt {}
 impl pg_sys::PgNode for TransactionStmt {}
 impl std::fmt::Display for TransactionStmt {
@@ -63666,6 +66324,12 @@ impl std::fmt::Display for TransactionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TransactionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TransactionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerData {}
 impl pg_sys::PgNode for TriggerData {}
 impl std::fmt::Display for TriggerData {
@@ -63673,6 +66337,12 @@ impl std::fmt::Display for TriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerTransition {}
 impl pg_sys::PgNode for TriggerTransition {}
 impl std::fmt::Display for TriggerTransition {
@@ -63680,6 +66350,12 @@ impl std::fmt::Display for TriggerTransition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerTransition"))
+    }
+}
 impl pg_sys::seal::Sealed for TruncateStmt {}
 impl pg_sys::PgNode for TruncateStmt {}
 impl std::fmt::Display for TruncateStmt {
@@ -63687,6 +66363,12 @@ impl std::fmt::Display for TruncateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TruncateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TruncateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TupleTableSlot {}
 impl pg_sys::PgNode for TupleTableSlot {}
 impl std::fmt::Display for TupleTableSlot {
@@ -63694,6 +66376,12 @@ impl std::fmt::Display for TupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeCast {}
 impl pg_sys::PgNode for TypeCast {}
 impl std::fmt::Display for TypeCast {
@@ -63701,6 +66389,12 @@ impl std::fmt::Display for TypeCast {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeCast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeCast"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeName {}
 impl pg_sys::PgNode for TypeName {}
 impl std::fmt::Display for TypeName {
@@ -63708,6 +66402,12 @@ impl std::fmt::Display for TypeName {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeName"))
+    }
+}
 impl pg_sys::seal::Sealed for Unique {}
 impl pg_sys::PgNode for Unique {}
 impl std::fmt::Display for Unique {
@@ -63715,6 +66415,12 @@ impl std::fmt::Display for Unique {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Unique"))
+    }
+}
 impl pg_sys::seal::Sealed for UniquePath {}
 impl pg_sys::PgNode for UniquePath {}
 impl std::fmt::Display for UniquePath {
@@ -63722,6 +66428,12 @@ impl std::fmt::Display for UniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for UniqueState {}
 impl pg_sys::PgNode for UniqueState {}
 impl std::fmt::Display for UniqueState {
@@ -63729,6 +66441,12 @@ impl std::fmt::Display for UniqueState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniqueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniqueState"))
+    }
+}
 impl pg_sys::seal::Sealed for UnlistenStmt {}
 impl pg_sys::PgNode for UnlistenStmt {}
 impl std::fmt::Display for UnlistenStmt {
@@ -63736,6 +66454,12 @@ impl std::fmt::Display for UnlistenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UnlistenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UnlistenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpdateStmt {}
 impl pg_sys::PgNode for UpdateStmt {}
 impl std::fmt::Display for UpdateStmt {
@@ -63743,6 +66467,12 @@ impl std::fmt::Display for UpdateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpdateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpdateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpperUniquePath {}
 impl pg_sys::PgNode for UpperUniquePath {}
 impl std::fmt::Display for UpperUniquePath {
@@ -63750,6 +66480,12 @@ impl std::fmt::Display for UpperUniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpperUniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpperUniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumRelation {}
 impl pg_sys::PgNode for VacuumRelation {}
 impl std::fmt::Display for VacuumRelation {
@@ -63757,6 +66493,12 @@ impl std::fmt::Display for VacuumRelation {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumRelation"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumStmt {}
 impl pg_sys::PgNode for VacuumStmt {}
 impl std::fmt::Display for VacuumStmt {
@@ -63764,6 +66506,12 @@ impl std::fmt::Display for VacuumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Value {}
 impl pg_sys::PgNode for Value {}
 impl std::fmt::Display for Value {
@@ -63771,6 +66519,12 @@ impl std::fmt::Display for Value {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Value"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScan {}
 impl pg_sys::PgNode for ValuesScan {}
 impl std::fmt::Display for ValuesScan {
@@ -63778,6 +66532,12 @@ impl std::fmt::Display for ValuesScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScanState {}
 impl pg_sys::PgNode for ValuesScanState {}
 impl std::fmt::Display for ValuesScanState {
@@ -63785,6 +66545,12 @@ impl std::fmt::Display for ValuesScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Var {}
 impl pg_sys::PgNode for Var {}
 impl std::fmt::Display for Var {
@@ -63792,6 +66558,12 @@ impl std::fmt::Display for Var {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Var"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableSetStmt {}
 impl pg_sys::PgNode for VariableSetStmt {}
 impl std::fmt::Display for VariableSetStmt {
@@ -63799,6 +66571,12 @@ impl std::fmt::Display for VariableSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableShowStmt {}
 impl pg_sys::PgNode for VariableShowStmt {}
 impl std::fmt::Display for VariableShowStmt {
@@ -63806,6 +66584,12 @@ impl std::fmt::Display for VariableShowStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableShowStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableShowStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ViewStmt {}
 impl pg_sys::PgNode for ViewStmt {}
 impl std::fmt::Display for ViewStmt {
@@ -63813,6 +66597,12 @@ impl std::fmt::Display for ViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VirtualTupleTableSlot {}
 impl pg_sys::PgNode for VirtualTupleTableSlot {}
 impl std::fmt::Display for VirtualTupleTableSlot {
@@ -63820,6 +66610,12 @@ impl std::fmt::Display for VirtualTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VirtualTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VirtualTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAgg {}
 impl pg_sys::PgNode for WindowAgg {}
 impl std::fmt::Display for WindowAgg {
@@ -63827,6 +66623,12 @@ impl std::fmt::Display for WindowAgg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAgg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAgg"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggPath {}
 impl pg_sys::PgNode for WindowAggPath {}
 impl std::fmt::Display for WindowAggPath {
@@ -63834,6 +66636,12 @@ impl std::fmt::Display for WindowAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggState {}
 impl pg_sys::PgNode for WindowAggState {}
 impl std::fmt::Display for WindowAggState {
@@ -63841,6 +66649,12 @@ impl std::fmt::Display for WindowAggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggState"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowClause {}
 impl pg_sys::PgNode for WindowClause {}
 impl std::fmt::Display for WindowClause {
@@ -63848,6 +66662,12 @@ impl std::fmt::Display for WindowClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowDef {}
 impl pg_sys::PgNode for WindowDef {}
 impl std::fmt::Display for WindowDef {
@@ -63855,6 +66675,12 @@ impl std::fmt::Display for WindowDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowDef"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFunc {}
 impl pg_sys::PgNode for WindowFunc {}
 impl std::fmt::Display for WindowFunc {
@@ -63862,6 +66688,12 @@ impl std::fmt::Display for WindowFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFuncExprState {}
 impl pg_sys::PgNode for WindowFuncExprState {}
 impl std::fmt::Display for WindowFuncExprState {
@@ -63869,6 +66701,12 @@ impl std::fmt::Display for WindowFuncExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFuncExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFuncExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for WithCheckOption {}
 impl pg_sys::PgNode for WithCheckOption {}
 impl std::fmt::Display for WithCheckOption {
@@ -63876,6 +66714,12 @@ impl std::fmt::Display for WithCheckOption {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithCheckOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithCheckOption"))
+    }
+}
 impl pg_sys::seal::Sealed for WithClause {}
 impl pg_sys::PgNode for WithClause {}
 impl std::fmt::Display for WithClause {
@@ -63883,6 +66727,12 @@ impl std::fmt::Display for WithClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScan {}
 impl pg_sys::PgNode for WorkTableScan {}
 impl std::fmt::Display for WorkTableScan {
@@ -63890,6 +66740,12 @@ impl std::fmt::Display for WorkTableScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScan"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScanState {}
 impl pg_sys::PgNode for WorkTableScanState {}
 impl std::fmt::Display for WorkTableScanState {
@@ -63897,6 +66753,12 @@ impl std::fmt::Display for WorkTableScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlExpr {}
 impl pg_sys::PgNode for XmlExpr {}
 impl std::fmt::Display for XmlExpr {
@@ -63904,6 +66766,12 @@ impl std::fmt::Display for XmlExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlSerialize {}
 impl pg_sys::PgNode for XmlSerialize {}
 impl std::fmt::Display for XmlSerialize {
@@ -63911,3 +66779,9 @@ impl std::fmt::Display for XmlSerialize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlSerialize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlSerialize"))
+    }
+}
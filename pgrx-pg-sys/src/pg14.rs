@@ -7041,7 +7041,8 @@ pub const NodeTag_T_SupportRequestRows: NodeTag = 430;
 pub const NodeTag_T_SupportRequestIndexCondition: NodeTag = 431;
 pub type NodeTag = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Node {
     pub type_: NodeTag,
 }
@@ -8424,7 +8425,8 @@ pub struct BufferAccessStrategyData {
 }
 pub type BufferAccessStrategy = *mut BufferAccessStrategyData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TupleTableSlot {
     pub type_: NodeTag,
     pub tts_flags: uint16,
@@ -8489,7 +8491,8 @@ extern "C" {
     pub static TTSOpsBufferHeapTuple: TupleTableSlotOps;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VirtualTupleTableSlot {
     pub base: TupleTableSlot,
     pub data: *mut ::std::os::raw::c_char,
@@ -8504,7 +8507,8 @@ impl Default for VirtualTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HeapTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -8521,7 +8525,8 @@ impl Default for HeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BufferHeapTupleTableSlot {
     pub base: HeapTupleTableSlot,
     pub buffer: Buffer,
@@ -8536,7 +8541,8 @@ impl Default for BufferHeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinimalTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -10074,7 +10080,8 @@ pub const LockTupleMode_LockTupleNoKeyExclusive: LockTupleMode = 2;
 pub const LockTupleMode_LockTupleExclusive: LockTupleMode = 3;
 pub type LockTupleMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Alias {
     pub type_: NodeTag,
     pub aliasname: *mut ::std::os::raw::c_char,
@@ -10095,7 +10102,8 @@ pub const OnCommitAction_ONCOMMIT_DELETE_ROWS: OnCommitAction = 2;
 pub const OnCommitAction_ONCOMMIT_DROP: OnCommitAction = 3;
 pub type OnCommitAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeVar {
     pub type_: NodeTag,
     pub catalogname: *mut ::std::os::raw::c_char,
@@ -10116,7 +10124,8 @@ impl Default for RangeVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFunc {
     pub type_: NodeTag,
     pub ns_uris: *mut List,
@@ -10143,7 +10152,8 @@ impl Default for TableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IntoClause {
     pub type_: NodeTag,
     pub rel: *mut RangeVar,
@@ -10165,7 +10175,8 @@ impl Default for IntoClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Expr {
     pub type_: NodeTag,
 }
@@ -10179,7 +10190,8 @@ impl Default for Expr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Var {
     pub xpr: Expr,
     pub varno: Index,
@@ -10202,7 +10214,8 @@ impl Default for Var {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Const {
     pub xpr: Expr,
     pub consttype: Oid,
@@ -10229,7 +10242,8 @@ pub const ParamKind_PARAM_SUBLINK: ParamKind = 2;
 pub const ParamKind_PARAM_MULTIEXPR: ParamKind = 3;
 pub type ParamKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Param {
     pub xpr: Expr,
     pub paramkind: ParamKind,
@@ -10249,7 +10263,8 @@ impl Default for Param {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Aggref {
     pub xpr: Expr,
     pub aggfnoid: Oid,
@@ -10282,7 +10297,8 @@ impl Default for Aggref {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingFunc {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10301,7 +10317,8 @@ impl Default for GroupingFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFunc {
     pub xpr: Expr,
     pub winfnoid: Oid,
@@ -10325,7 +10342,8 @@ impl Default for WindowFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubscriptingRef {
     pub xpr: Expr,
     pub refcontainertype: Oid,
@@ -10358,7 +10376,8 @@ pub const CoercionForm_COERCE_IMPLICIT_CAST: CoercionForm = 2;
 pub const CoercionForm_COERCE_SQL_SYNTAX: CoercionForm = 3;
 pub type CoercionForm = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncExpr {
     pub xpr: Expr,
     pub funcid: Oid,
@@ -10381,7 +10400,8 @@ impl Default for FuncExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedArgExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10399,7 +10419,8 @@ impl Default for NamedArgExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -10423,7 +10444,8 @@ impl Default for OpExpr {
 pub type DistinctExpr = OpExpr;
 pub type NullIfExpr = OpExpr;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScalarArrayOpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -10448,7 +10470,8 @@ pub const BoolExprType_OR_EXPR: BoolExprType = 1;
 pub const BoolExprType_NOT_EXPR: BoolExprType = 2;
 pub type BoolExprType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BoolExpr {
     pub xpr: Expr,
     pub boolop: BoolExprType,
@@ -10474,7 +10497,8 @@ pub const SubLinkType_ARRAY_SUBLINK: SubLinkType = 6;
 pub const SubLinkType_CTE_SUBLINK: SubLinkType = 7;
 pub type SubLinkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubLink {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10494,7 +10518,8 @@ impl Default for SubLink {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlan {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10524,7 +10549,8 @@ impl Default for SubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlan {
     pub xpr: Expr,
     pub subplans: *mut List,
@@ -10539,7 +10565,8 @@ impl Default for AlternativeSubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldSelect {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10558,7 +10585,8 @@ impl Default for FieldSelect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldStore {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10576,7 +10604,8 @@ impl Default for FieldStore {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelabelType {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10596,7 +10625,8 @@ impl Default for RelabelType {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceViaIO {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10615,7 +10645,8 @@ impl Default for CoerceViaIO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayCoerceExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10636,7 +10667,8 @@ impl Default for ArrayCoerceExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConvertRowtypeExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10654,7 +10686,8 @@ impl Default for ConvertRowtypeExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10671,7 +10704,8 @@ impl Default for CollateExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseExpr {
     pub xpr: Expr,
     pub casetype: Oid,
@@ -10691,7 +10725,8 @@ impl Default for CaseExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseWhen {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10708,7 +10743,8 @@ impl Default for CaseWhen {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseTestExpr {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10725,7 +10761,8 @@ impl Default for CaseTestExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayExpr {
     pub xpr: Expr,
     pub array_typeid: Oid,
@@ -10745,7 +10782,8 @@ impl Default for ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowExpr {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10771,7 +10809,8 @@ pub const RowCompareType_ROWCOMPARE_GT: RowCompareType = 5;
 pub const RowCompareType_ROWCOMPARE_NE: RowCompareType = 6;
 pub type RowCompareType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowCompareExpr {
     pub xpr: Expr,
     pub rctype: RowCompareType,
@@ -10791,7 +10830,8 @@ impl Default for RowCompareExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoalesceExpr {
     pub xpr: Expr,
     pub coalescetype: Oid,
@@ -10812,7 +10852,8 @@ pub const MinMaxOp_IS_GREATEST: MinMaxOp = 0;
 pub const MinMaxOp_IS_LEAST: MinMaxOp = 1;
 pub type MinMaxOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxExpr {
     pub xpr: Expr,
     pub minmaxtype: Oid,
@@ -10848,7 +10889,8 @@ pub const SQLValueFunctionOp_SVFOP_CURRENT_CATALOG: SQLValueFunctionOp = 13;
 pub const SQLValueFunctionOp_SVFOP_CURRENT_SCHEMA: SQLValueFunctionOp = 14;
 pub type SQLValueFunctionOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLValueFunction {
     pub xpr: Expr,
     pub op: SQLValueFunctionOp,
@@ -10878,7 +10920,8 @@ pub const XmlOptionType_XMLOPTION_DOCUMENT: XmlOptionType = 0;
 pub const XmlOptionType_XMLOPTION_CONTENT: XmlOptionType = 1;
 pub type XmlOptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlExpr {
     pub xpr: Expr,
     pub op: XmlExprOp,
@@ -10904,7 +10947,8 @@ pub const NullTestType_IS_NULL: NullTestType = 0;
 pub const NullTestType_IS_NOT_NULL: NullTestType = 1;
 pub type NullTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NullTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10929,7 +10973,8 @@ pub const BoolTestType_IS_UNKNOWN: BoolTestType = 4;
 pub const BoolTestType_IS_NOT_UNKNOWN: BoolTestType = 5;
 pub type BoolTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BooleanTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10946,7 +10991,8 @@ impl Default for BooleanTest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomain {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10966,7 +11012,8 @@ impl Default for CoerceToDomain {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomainValue {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10984,7 +11031,8 @@ impl Default for CoerceToDomainValue {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetToDefault {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -11002,7 +11050,8 @@ impl Default for SetToDefault {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CurrentOfExpr {
     pub xpr: Expr,
     pub cvarno: Index,
@@ -11019,7 +11068,8 @@ impl Default for CurrentOfExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NextValueExpr {
     pub xpr: Expr,
     pub seqid: Oid,
@@ -11035,7 +11085,8 @@ impl Default for NextValueExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferenceElem {
     pub xpr: Expr,
     pub expr: *mut Node,
@@ -11052,7 +11103,8 @@ impl Default for InferenceElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TargetEntry {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -11073,7 +11125,8 @@ impl Default for TargetEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblRef {
     pub type_: NodeTag,
     pub rtindex: ::std::os::raw::c_int,
@@ -11088,7 +11141,8 @@ impl Default for RangeTblRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinExpr {
     pub type_: NodeTag,
     pub jointype: JoinType,
@@ -11111,7 +11165,8 @@ impl Default for JoinExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FromExpr {
     pub type_: NodeTag,
     pub fromlist: *mut List,
@@ -11127,7 +11182,8 @@ impl Default for FromExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictExpr {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -11149,7 +11205,8 @@ impl Default for OnConflictExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannedStmt {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -11185,7 +11242,8 @@ impl Default for PlannedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Plan {
     pub type_: NodeTag,
     pub startup_cost: Cost,
@@ -11214,7 +11272,8 @@ impl Default for Plan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Result {
     pub plan: Plan,
     pub resconstantqual: *mut Node,
@@ -11229,7 +11288,8 @@ impl Default for Result {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSet {
     pub plan: Plan,
 }
@@ -11243,7 +11303,8 @@ impl Default for ProjectSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTable {
     pub plan: Plan,
     pub operation: CmdType,
@@ -11277,7 +11338,8 @@ impl Default for ModifyTable {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Append {
     pub plan: Plan,
     pub apprelids: *mut Bitmapset,
@@ -11296,7 +11358,8 @@ impl Default for Append {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppend {
     pub plan: Plan,
     pub apprelids: *mut Bitmapset,
@@ -11318,7 +11381,8 @@ impl Default for MergeAppend {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnion {
     pub plan: Plan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11338,7 +11402,8 @@ impl Default for RecursiveUnion {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAnd {
     pub plan: Plan,
     pub bitmapplans: *mut List,
@@ -11353,7 +11418,8 @@ impl Default for BitmapAnd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOr {
     pub plan: Plan,
     pub isshared: bool,
@@ -11369,7 +11435,8 @@ impl Default for BitmapOr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Scan {
     pub plan: Plan,
     pub scanrelid: Index,
@@ -11385,7 +11452,8 @@ impl Default for Scan {
 }
 pub type SeqScan = Scan;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScan {
     pub scan: Scan,
     pub tablesample: *mut TableSampleClause,
@@ -11400,7 +11468,8 @@ impl Default for SampleScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11421,7 +11490,8 @@ impl Default for IndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11441,7 +11511,8 @@ impl Default for IndexOnlyScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -11459,7 +11530,8 @@ impl Default for BitmapIndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScan {
     pub scan: Scan,
     pub bitmapqualorig: *mut List,
@@ -11474,7 +11546,8 @@ impl Default for BitmapHeapScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScan {
     pub scan: Scan,
     pub tidquals: *mut List,
@@ -11489,7 +11562,8 @@ impl Default for TidScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidRangeScan {
     pub scan: Scan,
     pub tidrangequals: *mut List,
@@ -11504,7 +11578,8 @@ impl Default for TidRangeScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScan {
     pub scan: Scan,
     pub subplan: *mut Plan,
@@ -11519,7 +11594,8 @@ impl Default for SubqueryScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScan {
     pub scan: Scan,
     pub functions: *mut List,
@@ -11535,7 +11611,8 @@ impl Default for FunctionScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScan {
     pub scan: Scan,
     pub values_lists: *mut List,
@@ -11550,7 +11627,8 @@ impl Default for ValuesScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScan {
     pub scan: Scan,
     pub tablefunc: *mut TableFunc,
@@ -11565,7 +11643,8 @@ impl Default for TableFuncScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScan {
     pub scan: Scan,
     pub ctePlanId: ::std::os::raw::c_int,
@@ -11581,7 +11660,8 @@ impl Default for CteScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScan {
     pub scan: Scan,
     pub enrname: *mut ::std::os::raw::c_char,
@@ -11596,7 +11676,8 @@ impl Default for NamedTuplestoreScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScan {
     pub scan: Scan,
     pub wtParam: ::std::os::raw::c_int,
@@ -11611,7 +11692,8 @@ impl Default for WorkTableScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScan {
     pub scan: Scan,
     pub operation: CmdType,
@@ -11634,7 +11716,8 @@ impl Default for ForeignScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScan {
     pub scan: Scan,
     pub flags: uint32,
@@ -11655,7 +11738,8 @@ impl Default for CustomScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Join {
     pub plan: Plan,
     pub jointype: JoinType,
@@ -11672,7 +11756,8 @@ impl Default for Join {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoop {
     pub join: Join,
     pub nestParams: *mut List,
@@ -11687,7 +11772,8 @@ impl Default for NestLoop {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopParam {
     pub type_: NodeTag,
     pub paramno: ::std::os::raw::c_int,
@@ -11703,7 +11789,8 @@ impl Default for NestLoopParam {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoin {
     pub join: Join,
     pub skip_mark_restore: bool,
@@ -11723,7 +11810,8 @@ impl Default for MergeJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoin {
     pub join: Join,
     pub hashclauses: *mut List,
@@ -11741,7 +11829,8 @@ impl Default for HashJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Material {
     pub plan: Plan,
 }
@@ -11755,7 +11844,8 @@ impl Default for Material {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Memoize {
     pub plan: Plan,
     pub numKeys: ::std::os::raw::c_int,
@@ -11777,7 +11867,8 @@ impl Default for Memoize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Sort {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11796,7 +11887,8 @@ impl Default for Sort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSort {
     pub sort: Sort,
     pub nPresortedCols: ::std::os::raw::c_int,
@@ -11811,7 +11903,8 @@ impl Default for IncrementalSort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Group {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11829,7 +11922,8 @@ impl Default for Group {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Agg {
     pub plan: Plan,
     pub aggstrategy: AggStrategy,
@@ -11854,7 +11948,8 @@ impl Default for Agg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAgg {
     pub plan: Plan,
     pub winref: Index,
@@ -11885,7 +11980,8 @@ impl Default for WindowAgg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Unique {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -11903,7 +11999,8 @@ impl Default for Unique {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Gather {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11922,7 +12019,8 @@ impl Default for Gather {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMerge {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -11944,7 +12042,8 @@ impl Default for GatherMerge {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Hash {
     pub plan: Plan,
     pub hashkeys: *mut List,
@@ -11963,7 +12062,8 @@ impl Default for Hash {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOp {
     pub plan: Plan,
     pub cmd: SetOpCmd,
@@ -11986,7 +12086,8 @@ impl Default for SetOp {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRows {
     pub plan: Plan,
     pub rowMarks: *mut List,
@@ -12002,7 +12103,8 @@ impl Default for LockRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Limit {
     pub plan: Plan,
     pub limitOffset: *mut Node,
@@ -12030,7 +12132,8 @@ pub const RowMarkType_ROW_MARK_REFERENCE: RowMarkType = 4;
 pub const RowMarkType_ROW_MARK_COPY: RowMarkType = 5;
 pub type RowMarkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanRowMark {
     pub type_: NodeTag,
     pub rti: Index,
@@ -12052,7 +12155,8 @@ impl Default for PlanRowMark {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneInfo {
     pub type_: NodeTag,
     pub prune_infos: *mut List,
@@ -12068,7 +12172,8 @@ impl Default for PartitionPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionedRelPruneInfo {
     pub type_: NodeTag,
     pub rtindex: Index,
@@ -12091,7 +12196,8 @@ impl Default for PartitionedRelPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStep {
     pub type_: NodeTag,
     pub step_id: ::std::os::raw::c_int,
@@ -12106,7 +12212,8 @@ impl Default for PartitionPruneStep {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepOp {
     pub step: PartitionPruneStep,
     pub opstrategy: StrategyNumber,
@@ -12127,7 +12234,8 @@ pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_UNION: PartitionPruneCombine
 pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_INTERSECT: PartitionPruneCombineOp = 1;
 pub type PartitionPruneCombineOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepCombine {
     pub step: PartitionPruneStep,
     pub combineOp: PartitionPruneCombineOp,
@@ -12143,7 +12251,8 @@ impl Default for PartitionPruneStepCombine {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanInvalItem {
     pub type_: NodeTag,
     pub cacheId: ::std::os::raw::c_int,
@@ -14219,7 +14328,8 @@ pub type ExprStateEvalFunc = ::std::option::Option<
     ) -> Datum,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprState {
     pub tag: NodeTag,
     pub flags: uint8,
@@ -14249,7 +14359,8 @@ impl Default for ExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexInfo {
     pub type_: NodeTag,
     pub ii_NumIndexAttrs: ::std::os::raw::c_int,
@@ -14302,7 +14413,8 @@ impl Default for ExprContext_CB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprContext {
     pub type_: NodeTag,
     pub ecxt_scantuple: *mut TupleTableSlot,
@@ -14340,7 +14452,8 @@ pub const SetFunctionReturnMode_SFRM_Materialize_Random: SetFunctionReturnMode =
 pub const SetFunctionReturnMode_SFRM_Materialize_Preferred: SetFunctionReturnMode = 8;
 pub type SetFunctionReturnMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnSetInfo {
     pub type_: NodeTag,
     pub econtext: *mut ExprContext,
@@ -14361,7 +14474,8 @@ impl Default for ReturnSetInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionInfo {
     pub type_: NodeTag,
     pub pi_state: ExprState,
@@ -14377,7 +14491,8 @@ impl Default for ProjectionInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JunkFilter {
     pub type_: NodeTag,
     pub jf_targetList: *mut List,
@@ -14395,7 +14510,8 @@ impl Default for JunkFilter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictSetState {
     pub type_: NodeTag,
     pub oc_Existing: *mut TupleTableSlot,
@@ -14413,7 +14529,8 @@ impl Default for OnConflictSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultRelInfo {
     pub type_: NodeTag,
     pub ri_RangeTableIndex: Index,
@@ -14502,7 +14619,8 @@ impl Default for AsyncRequest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EState {
     pub type_: NodeTag,
     pub es_direction: ScanDirection,
@@ -14774,7 +14892,8 @@ impl Default for TupleHashTableData {
 }
 pub type TupleHashIterator = tuplehash_iterator;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFuncExprState {
     pub type_: NodeTag,
     pub wfunc: *mut WindowFunc,
@@ -14792,7 +14911,8 @@ impl Default for WindowFuncExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetExprState {
     pub type_: NodeTag,
     pub expr: *mut Expr,
@@ -14818,7 +14938,8 @@ impl Default for SetExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut SubPlan,
@@ -14861,7 +14982,8 @@ pub const DomainConstraintType_DOM_CONSTRAINT_NOTNULL: DomainConstraintType = 0;
 pub const DomainConstraintType_DOM_CONSTRAINT_CHECK: DomainConstraintType = 1;
 pub type DomainConstraintType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DomainConstraintState {
     pub type_: NodeTag,
     pub constrainttype: DomainConstraintType,
@@ -14881,7 +15003,8 @@ impl Default for DomainConstraintState {
 pub type ExecProcNodeMtd =
     ::std::option::Option<unsafe extern "C" fn(pstate: *mut PlanState) -> *mut TupleTableSlot>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanState {
     pub type_: NodeTag,
     pub plan: *mut Plan,
@@ -14950,7 +15073,8 @@ impl Default for EPQState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultState {
     pub ps: PlanState,
     pub resconstantqual: *mut ExprState,
@@ -14967,7 +15091,8 @@ impl Default for ResultState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetState {
     pub ps: PlanState,
     pub elems: *mut *mut Node,
@@ -14986,7 +15111,8 @@ impl Default for ProjectSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTableState {
     pub ps: PlanState,
     pub operation: CmdType,
@@ -15026,7 +15152,8 @@ pub struct PartitionPruneState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendState {
     pub ps: PlanState,
     pub appendplans: *mut *mut PlanState,
@@ -15061,7 +15188,8 @@ impl Default for AppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendState {
     pub ps: PlanState,
     pub mergeplans: *mut *mut PlanState,
@@ -15084,7 +15212,8 @@ impl Default for MergeAppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionState {
     pub ps: PlanState,
     pub recursing: bool,
@@ -15107,7 +15236,8 @@ impl Default for RecursiveUnionState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -15123,7 +15253,8 @@ impl Default for BitmapAndState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -15139,7 +15270,8 @@ impl Default for BitmapOrState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScanState {
     pub ps: PlanState,
     pub ss_currentRelation: Relation,
@@ -15156,7 +15288,8 @@ impl Default for ScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SeqScanState {
     pub ss: ScanState,
     pub pscan_len: Size,
@@ -15171,7 +15304,8 @@ impl Default for SeqScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScanState {
     pub ss: ScanState,
     pub args: *mut List,
@@ -15231,7 +15365,8 @@ impl Default for IndexArrayKeyInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScanState {
     pub ss: ScanState,
     pub indexqualorig: *mut ExprState,
@@ -15265,7 +15400,8 @@ impl Default for IndexScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScanState {
     pub ss: ScanState,
     pub recheckqual: *mut ExprState,
@@ -15293,7 +15429,8 @@ impl Default for IndexOnlyScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScanState {
     pub ss: ScanState,
     pub biss_result: *mut TIDBitmap,
@@ -15343,7 +15480,8 @@ impl Default for ParallelBitmapHeapState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScanState {
     pub ss: ScanState,
     pub bitmapqualorig: *mut ExprState,
@@ -15376,7 +15514,8 @@ impl Default for BitmapHeapScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScanState {
     pub ss: ScanState,
     pub tss_tidexprs: *mut List,
@@ -15396,7 +15535,8 @@ impl Default for TidScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidRangeScanState {
     pub ss: ScanState,
     pub trss_tidexprs: *mut List,
@@ -15414,7 +15554,8 @@ impl Default for TidRangeScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanState {
     pub ss: ScanState,
     pub subplan: *mut PlanState,
@@ -15434,7 +15575,8 @@ pub struct FunctionScanPerFuncState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -15455,7 +15597,8 @@ impl Default for FunctionScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScanState {
     pub ss: ScanState,
     pub rowcontext: *mut ExprContext,
@@ -15474,7 +15617,8 @@ impl Default for ValuesScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScanState {
     pub ss: ScanState,
     pub docexpr: *mut ExprState,
@@ -15502,7 +15646,8 @@ impl Default for TableFuncScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -15522,7 +15667,8 @@ impl Default for CteScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScanState {
     pub ss: ScanState,
     pub readptr: ::std::os::raw::c_int,
@@ -15539,7 +15685,8 @@ impl Default for NamedTuplestoreScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScanState {
     pub ss: ScanState,
     pub rustate: *mut RecursiveUnionState,
@@ -15554,7 +15701,8 @@ impl Default for WorkTableScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScanState {
     pub ss: ScanState,
     pub fdw_recheck_quals: *mut ExprState,
@@ -15573,7 +15721,8 @@ impl Default for ForeignScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScanState {
     pub ss: ScanState,
     pub flags: uint32,
@@ -15591,7 +15740,8 @@ impl Default for CustomScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinState {
     pub ps: PlanState,
     pub jointype: JoinType,
@@ -15608,7 +15758,8 @@ impl Default for JoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopState {
     pub js: JoinState,
     pub nl_NeedNewOuter: bool,
@@ -15631,7 +15782,8 @@ pub struct MergeJoinClauseData {
 }
 pub type MergeJoinClause = *mut MergeJoinClauseData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoinState {
     pub js: JoinState,
     pub mj_NumClauses: ::std::os::raw::c_int,
@@ -15674,7 +15826,8 @@ pub struct HashJoinTableData {
 }
 pub type HashJoinTable = *mut HashJoinTableData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoinState {
     pub js: JoinState,
     pub hashclauses: *mut ExprState,
@@ -15705,7 +15858,8 @@ impl Default for HashJoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -15752,7 +15906,8 @@ pub struct SharedMemoizeInfo {
     pub sinstrument: __IncompleteArrayField<MemoizeInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoizeState {
     pub ss: ScanState,
     pub mstatus: ::std::os::raw::c_int,
@@ -15818,7 +15973,8 @@ impl Default for SharedSortInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortState {
     pub ss: ScanState,
     pub randomAccess: bool,
@@ -15868,7 +16024,8 @@ pub const IncrementalSortExecutionStatus_INCSORT_READFULLSORT: IncrementalSortEx
 pub const IncrementalSortExecutionStatus_INCSORT_READPREFIXSORT: IncrementalSortExecutionStatus = 3;
 pub type IncrementalSortExecutionStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSortState {
     pub ss: ScanState,
     pub bounded: bool,
@@ -15896,7 +16053,8 @@ impl Default for IncrementalSortState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupState {
     pub ss: ScanState,
     pub eqfunction: *mut ExprState,
@@ -15955,7 +16113,8 @@ pub struct AggStatePerHashData {
 }
 pub type AggStatePerHash = *mut AggStatePerHashData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggState {
     pub ss: ScanState,
     pub aggs: *mut List,
@@ -16036,7 +16195,8 @@ pub struct WindowStatePerAggData {
 }
 pub type WindowStatePerAgg = *mut WindowStatePerAggData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggState {
     pub ss: ScanState,
     pub funcs: *mut List,
@@ -16101,7 +16261,8 @@ impl Default for WindowAggState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniqueState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -16116,7 +16277,8 @@ impl Default for UniqueState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -16144,7 +16306,8 @@ pub struct GMReaderTupleBuffer {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergeState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -16187,7 +16350,8 @@ pub struct SharedHashInfo {
     pub hinstrument: __IncompleteArrayField<HashInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashState {
     pub ps: PlanState,
     pub hashtable: HashJoinTable,
@@ -16212,7 +16376,8 @@ pub struct SetOpStatePerGroupData {
 }
 pub type SetOpStatePerGroup = *mut SetOpStatePerGroupData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -16237,7 +16402,8 @@ impl Default for SetOpState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsState {
     pub ps: PlanState,
     pub lr_arowMarks: *mut List,
@@ -16262,7 +16428,8 @@ pub const LimitStateCond_LIMIT_WINDOWEND: LimitStateCond = 6;
 pub const LimitStateCond_LIMIT_WINDOWSTART: LimitStateCond = 7;
 pub type LimitStateCond = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitState {
     pub ps: PlanState,
     pub limitOffset: *mut ExprState,
@@ -16705,7 +16872,8 @@ pub type SetQuantifier = ::std::os::raw::c_uint;
 pub type AclMode = uint32;
 #[doc = "\tQuery Tree"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Query {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -16759,7 +16927,8 @@ impl Default for Query {
 }
 #[doc = "\tSupporting data structures for Parse Trees\n\n\tMost of these node types appear in raw parsetrees output by the grammar,\n\tand get transformed to something else by the analyzer.  A few of them\n\tare used as-is in transformed querytrees."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeName {
     pub type_: NodeTag,
     pub names: *mut List,
@@ -16781,7 +16950,8 @@ impl Default for TypeName {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnRef {
     pub type_: NodeTag,
     pub fields: *mut List,
@@ -16797,7 +16967,8 @@ impl Default for ColumnRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamRef {
     pub type_: NodeTag,
     pub number: ::std::os::raw::c_int,
@@ -16828,7 +16999,8 @@ pub const A_Expr_Kind_AEXPR_BETWEEN_SYM: A_Expr_Kind = 12;
 pub const A_Expr_Kind_AEXPR_NOT_BETWEEN_SYM: A_Expr_Kind = 13;
 pub type A_Expr_Kind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Expr {
     pub type_: NodeTag,
     pub kind: A_Expr_Kind,
@@ -16863,7 +17035,8 @@ impl Default for A_Const {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeCast {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -16880,7 +17053,8 @@ impl Default for TypeCast {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateClause {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -16903,7 +17077,8 @@ pub const RoleSpecType_ROLESPEC_SESSION_USER: RoleSpecType = 3;
 pub const RoleSpecType_ROLESPEC_PUBLIC: RoleSpecType = 4;
 pub type RoleSpecType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RoleSpec {
     pub type_: NodeTag,
     pub roletype: RoleSpecType,
@@ -16920,7 +17095,8 @@ impl Default for RoleSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncCall {
     pub type_: NodeTag,
     pub funcname: *mut List,
@@ -16945,7 +17121,8 @@ impl Default for FuncCall {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Star {
     pub type_: NodeTag,
 }
@@ -16959,7 +17136,8 @@ impl Default for A_Star {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indices {
     pub type_: NodeTag,
     pub is_slice: bool,
@@ -16976,7 +17154,8 @@ impl Default for A_Indices {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indirection {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -16992,7 +17171,8 @@ impl Default for A_Indirection {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_ArrayExpr {
     pub type_: NodeTag,
     pub elements: *mut List,
@@ -17008,7 +17188,8 @@ impl Default for A_ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResTarget {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17026,7 +17207,8 @@ impl Default for ResTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MultiAssignRef {
     pub type_: NodeTag,
     pub source: *mut Node,
@@ -17043,7 +17225,8 @@ impl Default for MultiAssignRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortBy {
     pub type_: NodeTag,
     pub node: *mut Node,
@@ -17062,7 +17245,8 @@ impl Default for SortBy {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowDef {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17084,7 +17268,8 @@ impl Default for WindowDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeSubselect {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -17101,7 +17286,8 @@ impl Default for RangeSubselect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeFunction {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -17121,7 +17307,8 @@ impl Default for RangeFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFunc {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -17142,7 +17329,8 @@ impl Default for RangeTableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFuncCol {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -17163,7 +17351,8 @@ impl Default for RangeTableFuncCol {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableSample {
     pub type_: NodeTag,
     pub relation: *mut Node,
@@ -17182,7 +17371,8 @@ impl Default for RangeTableSample {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnDef {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -17214,7 +17404,8 @@ impl Default for ColumnDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableLikeClause {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17242,7 +17433,8 @@ pub const TableLikeOption_CREATE_TABLE_LIKE_STORAGE: TableLikeOption = 256;
 pub const TableLikeOption_CREATE_TABLE_LIKE_ALL: TableLikeOption = 2147483647;
 pub type TableLikeOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17269,7 +17461,8 @@ pub const DefElemAction_DEFELEM_ADD: DefElemAction = 2;
 pub const DefElemAction_DEFELEM_DROP: DefElemAction = 3;
 pub type DefElemAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefElem {
     pub type_: NodeTag,
     pub defnamespace: *mut ::std::os::raw::c_char,
@@ -17288,7 +17481,8 @@ impl Default for DefElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockingClause {
     pub type_: NodeTag,
     pub lockedRels: *mut List,
@@ -17305,7 +17499,8 @@ impl Default for LockingClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlSerialize {
     pub type_: NodeTag,
     pub xmloption: XmlOptionType,
@@ -17323,7 +17518,8 @@ impl Default for XmlSerialize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17342,7 +17538,8 @@ impl Default for PartitionElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionSpec {
     pub type_: NodeTag,
     pub strategy: *mut ::std::os::raw::c_char,
@@ -17359,7 +17556,8 @@ impl Default for PartitionSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionBoundSpec {
     pub type_: NodeTag,
     pub strategy: ::std::os::raw::c_char,
@@ -17385,7 +17583,8 @@ pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_VALUE: PartitionRangeDat
 pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_MAXVALUE: PartitionRangeDatumKind = 1;
 pub type PartitionRangeDatumKind = ::std::os::raw::c_int;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionRangeDatum {
     pub type_: NodeTag,
     pub kind: PartitionRangeDatumKind,
@@ -17402,7 +17601,8 @@ impl Default for PartitionRangeDatum {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionCmd {
     pub type_: NodeTag,
     pub name: *mut RangeVar,
@@ -17429,7 +17629,8 @@ pub const RTEKind_RTE_NAMEDTUPLESTORE: RTEKind = 7;
 pub const RTEKind_RTE_RESULT: RTEKind = 8;
 pub type RTEKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblEntry {
     pub type_: NodeTag,
     pub rtekind: RTEKind,
@@ -17480,7 +17681,8 @@ impl Default for RangeTblEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblFunction {
     pub type_: NodeTag,
     pub funcexpr: *mut Node,
@@ -17501,7 +17703,8 @@ impl Default for RangeTblFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableSampleClause {
     pub type_: NodeTag,
     pub tsmhandler: Oid,
@@ -17523,7 +17726,8 @@ pub const WCOKind_WCO_RLS_UPDATE_CHECK: WCOKind = 2;
 pub const WCOKind_WCO_RLS_CONFLICT_CHECK: WCOKind = 3;
 pub type WCOKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithCheckOption {
     pub type_: NodeTag,
     pub kind: WCOKind,
@@ -17542,7 +17746,8 @@ impl Default for WithCheckOption {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortGroupClause {
     pub type_: NodeTag,
     pub tleSortGroupRef: Index,
@@ -17567,7 +17772,8 @@ pub const GroupingSetKind_GROUPING_SET_CUBE: GroupingSetKind = 3;
 pub const GroupingSetKind_GROUPING_SET_SETS: GroupingSetKind = 4;
 pub type GroupingSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSet {
     pub type_: NodeTag,
     pub kind: GroupingSetKind,
@@ -17584,7 +17790,8 @@ impl Default for GroupingSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowClause {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17612,7 +17819,8 @@ impl Default for WindowClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowMarkClause {
     pub type_: NodeTag,
     pub rti: Index,
@@ -17630,7 +17838,8 @@ impl Default for RowMarkClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithClause {
     pub type_: NodeTag,
     pub ctes: *mut List,
@@ -17647,7 +17856,8 @@ impl Default for WithClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferClause {
     pub type_: NodeTag,
     pub indexElems: *mut List,
@@ -17665,7 +17875,8 @@ impl Default for InferClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictClause {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -17688,7 +17899,8 @@ pub const CTEMaterialize_CTEMaterializeAlways: CTEMaterialize = 1;
 pub const CTEMaterialize_CTEMaterializeNever: CTEMaterialize = 2;
 pub type CTEMaterialize = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CTESearchClause {
     pub type_: NodeTag,
     pub search_col_list: *mut List,
@@ -17706,7 +17918,8 @@ impl Default for CTESearchClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CTECycleClause {
     pub type_: NodeTag,
     pub cycle_col_list: *mut List,
@@ -17730,7 +17943,8 @@ impl Default for CTECycleClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommonTableExpr {
     pub type_: NodeTag,
     pub ctename: *mut ::std::os::raw::c_char,
@@ -17757,7 +17971,8 @@ impl Default for CommonTableExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerTransition {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -17775,7 +17990,8 @@ impl Default for TriggerTransition {
 }
 #[doc = "\t\tRaw Grammar Output Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RawStmt {
     pub type_: NodeTag,
     pub stmt: *mut Node,
@@ -17793,7 +18009,8 @@ impl Default for RawStmt {
 }
 #[doc = "\t\tOptimizable Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InsertStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17814,7 +18031,8 @@ impl Default for InsertStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeleteStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17833,7 +18051,8 @@ impl Default for DeleteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpdateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -17858,7 +18077,8 @@ pub const SetOperation_SETOP_INTERSECT: SetOperation = 2;
 pub const SetOperation_SETOP_EXCEPT: SetOperation = 3;
 pub type SetOperation = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SelectStmt {
     pub type_: NodeTag,
     pub distinctClause: *mut List,
@@ -17892,7 +18112,8 @@ impl Default for SelectStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOperationStmt {
     pub type_: NodeTag,
     pub op: SetOperation,
@@ -17914,7 +18135,8 @@ impl Default for SetOperationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnStmt {
     pub type_: NodeTag,
     pub returnval: *mut Node,
@@ -17929,7 +18151,8 @@ impl Default for ReturnStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PLAssignStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18000,7 +18223,8 @@ pub const ObjectType_OBJECT_VIEW: ObjectType = 49;
 #[doc = "\t\tOther Statements (no optimizations required)\n\n\t\tThese are not touched by parser/analyze.c except to put them into\n\t\tthe utilityStmt field of a Query.  This is eventually passed to\n\t\tProcessUtility (by-passing rewriting and planning).  Some of the\n\t\tstatements do need attention from parse analysis, and this is\n\t\tdone by routines in parser/parse_utilcmd.c after ProcessUtility\n\t\treceives the command for execution.\n\t\tDECLARE CURSOR, EXPLAIN, and CREATE TABLE AS are special cases:\n\t\tthey contain optimizable statements, which get processed normally\n\t\tby parser/analyze.c."]
 pub type ObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSchemaStmt {
     pub type_: NodeTag,
     pub schemaname: *mut ::std::os::raw::c_char,
@@ -18021,7 +18245,8 @@ pub const DropBehavior_DROP_RESTRICT: DropBehavior = 0;
 pub const DropBehavior_DROP_CASCADE: DropBehavior = 1;
 pub type DropBehavior = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18110,7 +18335,8 @@ pub const AlterTableType_AT_DropIdentity: AlterTableType = 68;
 pub const AlterTableType_AT_ReAddStatistics: AlterTableType = 69;
 pub type AlterTableType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReplicaIdentityStmt {
     pub type_: NodeTag,
     pub identity_type: ::std::os::raw::c_char,
@@ -18126,7 +18352,8 @@ impl Default for ReplicaIdentityStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableCmd {
     pub type_: NodeTag,
     pub subtype: AlterTableType,
@@ -18148,7 +18375,8 @@ impl Default for AlterTableCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterCollationStmt {
     pub type_: NodeTag,
     pub collname: *mut List,
@@ -18163,7 +18391,8 @@ impl Default for AlterCollationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDomainStmt {
     pub type_: NodeTag,
     pub subtype: ::std::os::raw::c_char,
@@ -18187,7 +18416,8 @@ pub const GrantTargetType_ACL_TARGET_ALL_IN_SCHEMA: GrantTargetType = 1;
 pub const GrantTargetType_ACL_TARGET_DEFAULTS: GrantTargetType = 2;
 pub type GrantTargetType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantStmt {
     pub type_: NodeTag,
     pub is_grant: bool,
@@ -18210,7 +18440,8 @@ impl Default for GrantStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ObjectWithArgs {
     pub type_: NodeTag,
     pub objname: *mut List,
@@ -18228,7 +18459,8 @@ impl Default for ObjectWithArgs {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AccessPriv {
     pub type_: NodeTag,
     pub priv_name: *mut ::std::os::raw::c_char,
@@ -18244,7 +18476,8 @@ impl Default for AccessPriv {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantRoleStmt {
     pub type_: NodeTag,
     pub granted_roles: *mut List,
@@ -18264,7 +18497,8 @@ impl Default for GrantRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDefaultPrivilegesStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -18280,7 +18514,8 @@ impl Default for AlterDefaultPrivilegesStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CopyStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18309,7 +18544,8 @@ pub const VariableSetKind_VAR_RESET: VariableSetKind = 4;
 pub const VariableSetKind_VAR_RESET_ALL: VariableSetKind = 5;
 pub type VariableSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableSetStmt {
     pub type_: NodeTag,
     pub kind: VariableSetKind,
@@ -18327,7 +18563,8 @@ impl Default for VariableSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableShowStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -18342,7 +18579,8 @@ impl Default for VariableShowStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -18383,7 +18621,8 @@ pub const ConstrType_CONSTR_ATTR_DEFERRED: ConstrType = 12;
 pub const ConstrType_CONSTR_ATTR_IMMEDIATE: ConstrType = 13;
 pub type ConstrType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Constraint {
     pub type_: NodeTag,
     pub contype: ConstrType,
@@ -18425,7 +18664,8 @@ impl Default for Constraint {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -18443,7 +18683,8 @@ impl Default for CreateTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -18459,7 +18700,8 @@ impl Default for DropTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableSpaceOptionsStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -18476,7 +18718,8 @@ impl Default for AlterTableSpaceOptionsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableMoveAllStmt {
     pub type_: NodeTag,
     pub orig_tablespacename: *mut ::std::os::raw::c_char,
@@ -18495,7 +18738,8 @@ impl Default for AlterTableMoveAllStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -18512,7 +18756,8 @@ impl Default for CreateExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -18528,7 +18773,8 @@ impl Default for AlterExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionContentsStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -18546,7 +18792,8 @@ impl Default for AlterExtensionContentsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -18563,7 +18810,8 @@ impl Default for CreateFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -18580,7 +18828,8 @@ impl Default for AlterFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -18600,7 +18849,8 @@ impl Default for CreateForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -18618,7 +18868,8 @@ impl Default for AlterForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignTableStmt {
     pub base: CreateStmt,
     pub servername: *mut ::std::os::raw::c_char,
@@ -18634,7 +18885,8 @@ impl Default for CreateForeignTableStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -18652,7 +18904,8 @@ impl Default for CreateUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -18669,7 +18922,8 @@ impl Default for AlterUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -18690,7 +18944,8 @@ pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_LIMIT_TO: ImportForeignSchem
 pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_EXCEPT: ImportForeignSchemaType = 2;
 pub type ImportForeignSchemaType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ImportForeignSchemaStmt {
     pub type_: NodeTag,
     pub server_name: *mut ::std::os::raw::c_char,
@@ -18710,7 +18965,8 @@ impl Default for ImportForeignSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -18731,7 +18987,8 @@ impl Default for CreatePolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -18750,7 +19007,8 @@ impl Default for AlterPolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateAmStmt {
     pub type_: NodeTag,
     pub amname: *mut ::std::os::raw::c_char,
@@ -18767,7 +19025,8 @@ impl Default for CreateAmStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTrigStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -18796,7 +19055,8 @@ impl Default for CreateTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -18814,7 +19074,8 @@ impl Default for CreateEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -18830,7 +19091,8 @@ impl Default for AlterEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePLangStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -18854,7 +19116,8 @@ pub const RoleStmtType_ROLESTMT_USER: RoleStmtType = 1;
 pub const RoleStmtType_ROLESTMT_GROUP: RoleStmtType = 2;
 pub type RoleStmtType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRoleStmt {
     pub type_: NodeTag,
     pub stmt_type: RoleStmtType,
@@ -18871,7 +19134,8 @@ impl Default for CreateRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -18888,7 +19152,8 @@ impl Default for AlterRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleSetStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -18905,7 +19170,8 @@ impl Default for AlterRoleSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropRoleStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -18921,7 +19187,8 @@ impl Default for DropRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -18940,7 +19207,8 @@ impl Default for CreateSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -18958,7 +19226,8 @@ impl Default for AlterSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefineStmt {
     pub type_: NodeTag,
     pub kind: ObjectType,
@@ -18979,7 +19248,8 @@ impl Default for DefineStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateDomainStmt {
     pub type_: NodeTag,
     pub domainname: *mut List,
@@ -18997,7 +19267,8 @@ impl Default for CreateDomainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassStmt {
     pub type_: NodeTag,
     pub opclassname: *mut List,
@@ -19017,7 +19288,8 @@ impl Default for CreateOpClassStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassItem {
     pub type_: NodeTag,
     pub itemtype: ::std::os::raw::c_int,
@@ -19037,7 +19309,8 @@ impl Default for CreateOpClassItem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -19053,7 +19326,8 @@ impl Default for CreateOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -19071,7 +19345,8 @@ impl Default for AlterOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropStmt {
     pub type_: NodeTag,
     pub objects: *mut List,
@@ -19090,7 +19365,8 @@ impl Default for DropStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TruncateStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -19107,7 +19383,8 @@ impl Default for TruncateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommentStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -19124,7 +19401,8 @@ impl Default for CommentStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SecLabelStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -19142,7 +19420,8 @@ impl Default for SecLabelStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeclareCursorStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -19159,7 +19438,8 @@ impl Default for DeclareCursorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClosePortalStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -19179,7 +19459,8 @@ pub const FetchDirection_FETCH_ABSOLUTE: FetchDirection = 2;
 pub const FetchDirection_FETCH_RELATIVE: FetchDirection = 3;
 pub type FetchDirection = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FetchStmt {
     pub type_: NodeTag,
     pub direction: FetchDirection,
@@ -19197,7 +19478,8 @@ impl Default for FetchStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexStmt {
     pub type_: NodeTag,
     pub idxname: *mut ::std::os::raw::c_char,
@@ -19234,7 +19516,8 @@ impl Default for IndexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -19255,7 +19538,8 @@ impl Default for CreateStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatsElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -19271,7 +19555,8 @@ impl Default for StatsElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -19288,7 +19573,8 @@ impl Default for AlterStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFunctionStmt {
     pub type_: NodeTag,
     pub is_procedure: bool,
@@ -19316,7 +19602,8 @@ pub const FunctionParameterMode_FUNC_PARAM_TABLE: FunctionParameterMode = 116;
 pub const FunctionParameterMode_FUNC_PARAM_DEFAULT: FunctionParameterMode = 100;
 pub type FunctionParameterMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionParameter {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -19334,7 +19621,8 @@ impl Default for FunctionParameter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFunctionStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -19351,7 +19639,8 @@ impl Default for AlterFunctionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DoStmt {
     pub type_: NodeTag,
     pub args: *mut List,
@@ -19366,7 +19655,8 @@ impl Default for DoStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InlineCodeBlock {
     pub type_: NodeTag,
     pub source_text: *mut ::std::os::raw::c_char,
@@ -19384,7 +19674,8 @@ impl Default for InlineCodeBlock {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallStmt {
     pub type_: NodeTag,
     pub funccall: *mut FuncCall,
@@ -19401,7 +19692,8 @@ impl Default for CallStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallContext {
     pub type_: NodeTag,
     pub atomic: bool,
@@ -19416,7 +19708,8 @@ impl Default for CallContext {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RenameStmt {
     pub type_: NodeTag,
     pub renameType: ObjectType,
@@ -19438,7 +19731,8 @@ impl Default for RenameStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectDependsStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -19457,7 +19751,8 @@ impl Default for AlterObjectDependsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectSchemaStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -19476,7 +19771,8 @@ impl Default for AlterObjectSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOwnerStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -19494,7 +19790,8 @@ impl Default for AlterOwnerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOperatorStmt {
     pub type_: NodeTag,
     pub opername: *mut ObjectWithArgs,
@@ -19510,7 +19807,8 @@ impl Default for AlterOperatorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTypeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19526,7 +19824,8 @@ impl Default for AlterTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RuleStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -19547,7 +19846,8 @@ impl Default for RuleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NotifyStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -19563,7 +19863,8 @@ impl Default for NotifyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ListenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -19578,7 +19879,8 @@ impl Default for ListenStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UnlistenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -19604,7 +19906,8 @@ pub const TransactionStmtKind_TRANS_STMT_COMMIT_PREPARED: TransactionStmtKind =
 pub const TransactionStmtKind_TRANS_STMT_ROLLBACK_PREPARED: TransactionStmtKind = 9;
 pub type TransactionStmtKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TransactionStmt {
     pub type_: NodeTag,
     pub kind: TransactionStmtKind,
@@ -19623,7 +19926,8 @@ impl Default for TransactionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CompositeTypeStmt {
     pub type_: NodeTag,
     pub typevar: *mut RangeVar,
@@ -19639,7 +19943,8 @@ impl Default for CompositeTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19655,7 +19960,8 @@ impl Default for CreateEnumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRangeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19671,7 +19977,8 @@ impl Default for CreateRangeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -19695,7 +20002,8 @@ pub const ViewCheckOption_LOCAL_CHECK_OPTION: ViewCheckOption = 1;
 pub const ViewCheckOption_CASCADED_CHECK_OPTION: ViewCheckOption = 2;
 pub type ViewCheckOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ViewStmt {
     pub type_: NodeTag,
     pub view: *mut RangeVar,
@@ -19715,7 +20023,8 @@ impl Default for ViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LoadStmt {
     pub type_: NodeTag,
     pub filename: *mut ::std::os::raw::c_char,
@@ -19730,7 +20039,8 @@ impl Default for LoadStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatedbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19746,7 +20056,8 @@ impl Default for CreatedbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19762,7 +20073,8 @@ impl Default for AlterDatabaseStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseSetStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19778,7 +20090,8 @@ impl Default for AlterDatabaseSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropdbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -19795,7 +20108,8 @@ impl Default for DropdbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSystemStmt {
     pub type_: NodeTag,
     pub setstmt: *mut VariableSetStmt,
@@ -19810,7 +20124,8 @@ impl Default for AlterSystemStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClusterStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -19827,7 +20142,8 @@ impl Default for ClusterStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -19844,7 +20160,8 @@ impl Default for VacuumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumRelation {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -19861,7 +20178,8 @@ impl Default for VacuumRelation {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExplainStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -19877,7 +20195,8 @@ impl Default for ExplainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableAsStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -19896,7 +20215,8 @@ impl Default for CreateTableAsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RefreshMatViewStmt {
     pub type_: NodeTag,
     pub concurrent: bool,
@@ -19913,7 +20233,8 @@ impl Default for RefreshMatViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CheckPointStmt {
     pub type_: NodeTag,
 }
@@ -19932,7 +20253,8 @@ pub const DiscardMode_DISCARD_SEQUENCES: DiscardMode = 2;
 pub const DiscardMode_DISCARD_TEMP: DiscardMode = 3;
 pub type DiscardMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DiscardStmt {
     pub type_: NodeTag,
     pub target: DiscardMode,
@@ -19947,7 +20269,8 @@ impl Default for DiscardStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -19964,7 +20287,8 @@ impl Default for LockStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConstraintsSetStmt {
     pub type_: NodeTag,
     pub constraints: *mut List,
@@ -19986,7 +20310,8 @@ pub const ReindexObjectType_REINDEX_OBJECT_SYSTEM: ReindexObjectType = 3;
 pub const ReindexObjectType_REINDEX_OBJECT_DATABASE: ReindexObjectType = 4;
 pub type ReindexObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReindexStmt {
     pub type_: NodeTag,
     pub kind: ReindexObjectType,
@@ -20004,7 +20329,8 @@ impl Default for ReindexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateConversionStmt {
     pub type_: NodeTag,
     pub conversion_name: *mut List,
@@ -20023,7 +20349,8 @@ impl Default for CreateConversionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateCastStmt {
     pub type_: NodeTag,
     pub sourcetype: *mut TypeName,
@@ -20042,7 +20369,8 @@ impl Default for CreateCastStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTransformStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -20061,7 +20389,8 @@ impl Default for CreateTransformStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PrepareStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -20078,7 +20407,8 @@ impl Default for PrepareStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExecuteStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -20094,7 +20424,8 @@ impl Default for ExecuteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeallocateStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -20109,7 +20440,8 @@ impl Default for DeallocateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -20125,7 +20457,8 @@ impl Default for DropOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReassignOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -20141,7 +20474,8 @@ impl Default for ReassignOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSDictionaryStmt {
     pub type_: NodeTag,
     pub dictname: *mut List,
@@ -20163,7 +20497,8 @@ pub const AlterTSConfigType_ALTER_TSCONFIG_REPLACE_DICT_FOR_TOKEN: AlterTSConfig
 pub const AlterTSConfigType_ALTER_TSCONFIG_DROP_MAPPING: AlterTSConfigType = 4;
 pub type AlterTSConfigType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSConfigurationStmt {
     pub type_: NodeTag,
     pub kind: AlterTSConfigType,
@@ -20184,7 +20519,8 @@ impl Default for AlterTSConfigurationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -20202,7 +20538,8 @@ impl Default for CreatePublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -20221,7 +20558,8 @@ impl Default for AlterPublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -20247,7 +20585,8 @@ pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_REFRESH: AlterSubscriptionTyp
 pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_ENABLED: AlterSubscriptionType = 6;
 pub type AlterSubscriptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSubscriptionStmt {
     pub type_: NodeTag,
     pub kind: AlterSubscriptionType,
@@ -20266,7 +20605,8 @@ impl Default for AlterSubscriptionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -20332,7 +20672,8 @@ pub struct MemoryContextMethods {
     pub check: ::std::option::Option<unsafe extern "C" fn(context: MemoryContext)>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoryContextData {
     pub type_: NodeTag,
     pub isReset: bool,
@@ -24912,7 +25253,8 @@ pub type aminitparallelscan_function =
 pub type amparallelrescan_function =
     ::std::option::Option<unsafe extern "C" fn(scan: IndexScanDesc)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexAmRoutine {
     pub type_: NodeTag,
     pub amstrategies: uint16,
@@ -26680,7 +27022,8 @@ impl Default for RelationData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyCacheInfo {
     pub type_: NodeTag,
     pub conoid: Oid,
@@ -29133,7 +29476,8 @@ pub type IndexBuildCallback = ::std::option::Option<
     ),
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableAmRoutine {
     pub type_: NodeTag,
     pub slot_callbacks:
@@ -34228,7 +34572,8 @@ impl Default for CollectedCommand {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EventTriggerData {
     pub type_: NodeTag,
     pub event: *const ::std::os::raw::c_char,
@@ -34939,7 +35284,8 @@ extern "C" {
 }
 pub type TriggerEvent = uint32;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerData {
     pub type_: NodeTag,
     pub tg_event: TriggerEvent,
@@ -37537,7 +37883,8 @@ pub const UpperRelationKind_UPPERREL_ORDERED: UpperRelationKind = 5;
 pub const UpperRelationKind_UPPERREL_FINAL: UpperRelationKind = 6;
 pub type UpperRelationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerGlobal {
     pub type_: NodeTag,
     pub boundParams: ParamListInfo,
@@ -37571,7 +37918,8 @@ impl Default for PlannerGlobal {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerInfo {
     pub type_: NodeTag,
     pub parse: *mut Query,
@@ -37685,7 +38033,8 @@ pub const RelOptKind_RELOPT_OTHER_UPPER_REL: RelOptKind = 5;
 pub const RelOptKind_RELOPT_DEADREL: RelOptKind = 6;
 pub type RelOptKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelOptInfo {
     pub type_: NodeTag,
     pub reloptkind: RelOptKind,
@@ -37757,7 +38106,8 @@ impl Default for RelOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOptInfo {
     pub type_: NodeTag,
     pub indexoid: Oid,
@@ -37806,7 +38156,8 @@ impl Default for IndexOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyOptInfo {
     pub type_: NodeTag,
     pub con_relid: Index,
@@ -37833,7 +38184,8 @@ impl Default for ForeignKeyOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatisticExtInfo {
     pub type_: NodeTag,
     pub statOid: Oid,
@@ -37852,7 +38204,8 @@ impl Default for StatisticExtInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceClass {
     pub type_: NodeTag,
     pub ec_opfamilies: *mut List,
@@ -37880,7 +38233,8 @@ impl Default for EquivalenceClass {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceMember {
     pub type_: NodeTag,
     pub em_expr: *mut Expr,
@@ -37900,7 +38254,8 @@ impl Default for EquivalenceMember {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathKey {
     pub type_: NodeTag,
     pub pk_eclass: *mut EquivalenceClass,
@@ -37922,7 +38277,8 @@ pub const VolatileFunctionStatus_VOLATILITY_VOLATILE: VolatileFunctionStatus = 1
 pub const VolatileFunctionStatus_VOLATILITY_NOVOLATILE: VolatileFunctionStatus = 2;
 pub type VolatileFunctionStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathTarget {
     pub type_: NodeTag,
     pub exprs: *mut List,
@@ -37941,7 +38297,8 @@ impl Default for PathTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamPathInfo {
     pub type_: NodeTag,
     pub ppi_req_outer: Relids,
@@ -37958,7 +38315,8 @@ impl Default for ParamPathInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Path {
     pub type_: NodeTag,
     pub pathtype: NodeTag,
@@ -37983,7 +38341,8 @@ impl Default for Path {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexPath {
     pub path: Path,
     pub indexinfo: *mut IndexOptInfo,
@@ -38004,7 +38363,8 @@ impl Default for IndexPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexClause {
     pub type_: NodeTag,
     pub rinfo: *mut RestrictInfo,
@@ -38023,7 +38383,8 @@ impl Default for IndexClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapPath {
     pub path: Path,
     pub bitmapqual: *mut Path,
@@ -38038,7 +38399,8 @@ impl Default for BitmapHeapPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -38054,7 +38416,8 @@ impl Default for BitmapAndPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -38070,7 +38433,8 @@ impl Default for BitmapOrPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidPath {
     pub path: Path,
     pub tidquals: *mut List,
@@ -38085,7 +38449,8 @@ impl Default for TidPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidRangePath {
     pub path: Path,
     pub tidrangequals: *mut List,
@@ -38100,7 +38465,8 @@ impl Default for TidRangePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38115,7 +38481,8 @@ impl Default for SubqueryScanPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignPath {
     pub path: Path,
     pub fdw_outerpath: *mut Path,
@@ -38131,7 +38498,8 @@ impl Default for ForeignPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomPath {
     pub path: Path,
     pub flags: uint32,
@@ -38149,7 +38517,8 @@ impl Default for CustomPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendPath {
     pub path: Path,
     pub subpaths: *mut List,
@@ -38170,7 +38539,8 @@ extern "C" {
     pub fn is_dummy_rel(rel: *mut RelOptInfo) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendPath {
     pub path: Path,
     pub subpaths: *mut List,
@@ -38186,7 +38556,8 @@ impl Default for MergeAppendPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupResultPath {
     pub path: Path,
     pub quals: *mut List,
@@ -38201,7 +38572,8 @@ impl Default for GroupResultPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38216,7 +38588,8 @@ impl Default for MaterialPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoizePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38241,7 +38614,8 @@ pub const UniquePathMethod_UNIQUE_PATH_HASH: UniquePathMethod = 1;
 pub const UniquePathMethod_UNIQUE_PATH_SORT: UniquePathMethod = 2;
 pub type UniquePathMethod = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38259,7 +38633,8 @@ impl Default for UniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38276,7 +38651,8 @@ impl Default for GatherPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38292,7 +38668,8 @@ impl Default for GatherMergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinPath {
     pub path: Path,
     pub jointype: JoinType,
@@ -38312,7 +38689,8 @@ impl Default for JoinPath {
 }
 pub type NestPath = JoinPath;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergePath {
     pub jpath: JoinPath,
     pub path_mergeclauses: *mut List,
@@ -38331,7 +38709,8 @@ impl Default for MergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashPath {
     pub jpath: JoinPath,
     pub path_hashclauses: *mut List,
@@ -38348,7 +38727,8 @@ impl Default for HashPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38364,7 +38744,8 @@ impl Default for ProjectionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38379,7 +38760,8 @@ impl Default for ProjectSetPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38394,7 +38776,8 @@ impl Default for SortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSortPath {
     pub spath: SortPath,
     pub nPresortedCols: ::std::os::raw::c_int,
@@ -38409,7 +38792,8 @@ impl Default for IncrementalSortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38426,7 +38810,8 @@ impl Default for GroupPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpperUniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38442,7 +38827,8 @@ impl Default for UpperUniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38463,7 +38849,8 @@ impl Default for AggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetData {
     pub type_: NodeTag,
     pub set: *mut List,
@@ -38479,7 +38866,8 @@ impl Default for GroupingSetData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RollupData {
     pub type_: NodeTag,
     pub groupClause: *mut List,
@@ -38499,7 +38887,8 @@ impl Default for RollupData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38518,7 +38907,8 @@ impl Default for GroupingSetsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggPath {
     pub path: Path,
     pub mmaggregates: *mut List,
@@ -38534,7 +38924,8 @@ impl Default for MinMaxAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38550,7 +38941,8 @@ impl Default for WindowAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38571,7 +38963,8 @@ impl Default for SetOpPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionPath {
     pub path: Path,
     pub leftpath: *mut Path,
@@ -38590,7 +38983,8 @@ impl Default for RecursiveUnionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38607,7 +39001,8 @@ impl Default for LockRowsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTablePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38634,7 +39029,8 @@ impl Default for ModifyTablePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38652,7 +39048,8 @@ impl Default for LimitPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RestrictInfo {
     pub type_: NodeTag,
     pub clause: *mut Expr,
@@ -38719,7 +39116,8 @@ impl Default for MergeScanSelCache {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderVar {
     pub xpr: Expr,
     pub phexpr: *mut Expr,
@@ -38737,7 +39135,8 @@ impl Default for PlaceHolderVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SpecialJoinInfo {
     pub type_: NodeTag,
     pub min_lefthand: Relids,
@@ -38762,7 +39161,8 @@ impl Default for SpecialJoinInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendRelInfo {
     pub type_: NodeTag,
     pub parent_relid: Index,
@@ -38784,7 +39184,8 @@ impl Default for AppendRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowIdentityVarInfo {
     pub type_: NodeTag,
     pub rowidvar: *mut Var,
@@ -38802,7 +39203,8 @@ impl Default for RowIdentityVarInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderInfo {
     pub type_: NodeTag,
     pub phid: Index,
@@ -38822,7 +39224,8 @@ impl Default for PlaceHolderInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggInfo {
     pub type_: NodeTag,
     pub aggfnoid: Oid,
@@ -38843,7 +39246,8 @@ impl Default for MinMaxAggInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerParamItem {
     pub type_: NodeTag,
     pub item: *mut Node,
@@ -39205,7 +39609,8 @@ pub type ForeignAsyncConfigureWait_function =
 pub type ForeignAsyncNotify_function =
     ::std::option::Option<unsafe extern "C" fn(areq: *mut AsyncRequest)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FdwRoutine {
     pub type_: NodeTag,
     pub GetForeignRelSize: GetForeignRelSize_function,
@@ -40081,7 +40486,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExtensibleNode {
     pub type_: NodeTag,
     pub extnodename: *const ::std::os::raw::c_char,
@@ -40692,7 +41098,8 @@ pub const ReplicationKind_REPLICATION_KIND_PHYSICAL: ReplicationKind = 0;
 pub const ReplicationKind_REPLICATION_KIND_LOGICAL: ReplicationKind = 1;
 pub type ReplicationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IdentifySystemCmd {
     pub type_: NodeTag,
 }
@@ -40706,7 +41113,8 @@ impl Default for IdentifySystemCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BaseBackupCmd {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -40721,7 +41129,8 @@ impl Default for BaseBackupCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -40740,7 +41149,8 @@ impl Default for CreateReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -40756,7 +41166,8 @@ impl Default for DropReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StartReplicationCmd {
     pub type_: NodeTag,
     pub kind: ReplicationKind,
@@ -40775,7 +41186,8 @@ impl Default for StartReplicationCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TimeLineHistoryCmd {
     pub type_: NodeTag,
     pub timeline: TimeLineID,
@@ -40790,7 +41202,8 @@ impl Default for TimeLineHistoryCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLCmd {
     pub type_: NodeTag,
 }
@@ -40804,7 +41217,8 @@ impl Default for SQLCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSimplify {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -40820,7 +41234,8 @@ impl Default for SupportRequestSimplify {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSelectivity {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -40843,7 +41258,8 @@ impl Default for SupportRequestSelectivity {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestCost {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -40862,7 +41278,8 @@ impl Default for SupportRequestCost {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestRows {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -40880,7 +41297,8 @@ impl Default for SupportRequestRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestIndexCondition {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -64057,6 +64475,12 @@ impl std::fmt::Display for A_ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Const {}
 impl pg_sys::PgNode for A_Const {}
 impl std::fmt::Display for A_Const {
@@ -64064,6 +64488,12 @@ impl std::fmt::Display for A_Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Const"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Expr {}
 impl pg_sys::PgNode for A_Expr {}
 impl std::fmt::Display for A_Expr {
@@ -64071,6 +64501,12 @@ impl std::fmt::Display for A_Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indices {}
 impl pg_sys::PgNode for A_Indices {}
 impl std::fmt::Display for A_Indices {
@@ -64078,6 +64514,12 @@ impl std::fmt::Display for A_Indices {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indices"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indirection {}
 impl pg_sys::PgNode for A_Indirection {}
 impl std::fmt::Display for A_Indirection {
@@ -64085,6 +64527,12 @@ impl std::fmt::Display for A_Indirection {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indirection"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Star {}
 impl pg_sys::PgNode for A_Star {}
 impl std::fmt::Display for A_Star {
@@ -64092,6 +64540,12 @@ impl std::fmt::Display for A_Star {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Star {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Star"))
+    }
+}
 impl pg_sys::seal::Sealed for AccessPriv {}
 impl pg_sys::PgNode for AccessPriv {}
 impl std::fmt::Display for AccessPriv {
@@ -64099,6 +64553,12 @@ impl std::fmt::Display for AccessPriv {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AccessPriv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AccessPriv"))
+    }
+}
 impl pg_sys::seal::Sealed for Agg {}
 impl pg_sys::PgNode for Agg {}
 impl std::fmt::Display for Agg {
@@ -64106,6 +64566,12 @@ impl std::fmt::Display for Agg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Agg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Agg"))
+    }
+}
 impl pg_sys::seal::Sealed for AggPath {}
 impl pg_sys::PgNode for AggPath {}
 impl std::fmt::Display for AggPath {
@@ -64113,6 +64579,12 @@ impl std::fmt::Display for AggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AggState {}
 impl pg_sys::PgNode for AggState {}
 impl std::fmt::Display for AggState {
@@ -64120,6 +64592,12 @@ impl std::fmt::Display for AggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggState"))
+    }
+}
 impl pg_sys::seal::Sealed for Aggref {}
 impl pg_sys::PgNode for Aggref {}
 impl std::fmt::Display for Aggref {
@@ -64127,6 +64605,12 @@ impl std::fmt::Display for Aggref {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Aggref {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Aggref"))
+    }
+}
 impl pg_sys::seal::Sealed for Alias {}
 impl pg_sys::PgNode for Alias {}
 impl std::fmt::Display for Alias {
@@ -64134,6 +64618,12 @@ impl std::fmt::Display for Alias {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Alias"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterCollationStmt {}
 impl pg_sys::PgNode for AlterCollationStmt {}
 impl std::fmt::Display for AlterCollationStmt {
@@ -64141,6 +64631,12 @@ impl std::fmt::Display for AlterCollationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterCollationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterCollationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseSetStmt {}
 impl pg_sys::PgNode for AlterDatabaseSetStmt {}
 impl std::fmt::Display for AlterDatabaseSetStmt {
@@ -64148,6 +64644,12 @@ impl std::fmt::Display for AlterDatabaseSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseStmt {}
 impl pg_sys::PgNode for AlterDatabaseStmt {}
 impl std::fmt::Display for AlterDatabaseStmt {
@@ -64155,6 +64657,12 @@ impl std::fmt::Display for AlterDatabaseStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDefaultPrivilegesStmt {}
 impl pg_sys::PgNode for AlterDefaultPrivilegesStmt {}
 impl std::fmt::Display for AlterDefaultPrivilegesStmt {
@@ -64162,6 +64670,12 @@ impl std::fmt::Display for AlterDefaultPrivilegesStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDefaultPrivilegesStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDefaultPrivilegesStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDomainStmt {}
 impl pg_sys::PgNode for AlterDomainStmt {}
 impl std::fmt::Display for AlterDomainStmt {
@@ -64169,6 +64683,12 @@ impl std::fmt::Display for AlterDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEnumStmt {}
 impl pg_sys::PgNode for AlterEnumStmt {}
 impl std::fmt::Display for AlterEnumStmt {
@@ -64176,6 +64696,12 @@ impl std::fmt::Display for AlterEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEventTrigStmt {}
 impl pg_sys::PgNode for AlterEventTrigStmt {}
 impl std::fmt::Display for AlterEventTrigStmt {
@@ -64183,6 +64709,12 @@ impl std::fmt::Display for AlterEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionContentsStmt {}
 impl pg_sys::PgNode for AlterExtensionContentsStmt {}
 impl std::fmt::Display for AlterExtensionContentsStmt {
@@ -64190,6 +64722,12 @@ impl std::fmt::Display for AlterExtensionContentsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionContentsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionContentsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionStmt {}
 impl pg_sys::PgNode for AlterExtensionStmt {}
 impl std::fmt::Display for AlterExtensionStmt {
@@ -64197,6 +64735,12 @@ impl std::fmt::Display for AlterExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFdwStmt {}
 impl pg_sys::PgNode for AlterFdwStmt {}
 impl std::fmt::Display for AlterFdwStmt {
@@ -64204,6 +64748,12 @@ impl std::fmt::Display for AlterFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterForeignServerStmt {}
 impl pg_sys::PgNode for AlterForeignServerStmt {}
 impl std::fmt::Display for AlterForeignServerStmt {
@@ -64211,6 +64761,12 @@ impl std::fmt::Display for AlterForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFunctionStmt {}
 impl pg_sys::PgNode for AlterFunctionStmt {}
 impl std::fmt::Display for AlterFunctionStmt {
@@ -64218,6 +64774,12 @@ impl std::fmt::Display for AlterFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectDependsStmt {}
 impl pg_sys::PgNode for AlterObjectDependsStmt {}
 impl std::fmt::Display for AlterObjectDependsStmt {
@@ -64225,6 +64787,12 @@ impl std::fmt::Display for AlterObjectDependsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectDependsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectDependsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectSchemaStmt {}
 impl pg_sys::PgNode for AlterObjectSchemaStmt {}
 impl std::fmt::Display for AlterObjectSchemaStmt {
@@ -64232,6 +64800,12 @@ impl std::fmt::Display for AlterObjectSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOpFamilyStmt {}
 impl pg_sys::PgNode for AlterOpFamilyStmt {}
 impl std::fmt::Display for AlterOpFamilyStmt {
@@ -64239,6 +64813,12 @@ impl std::fmt::Display for AlterOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOperatorStmt {}
 impl pg_sys::PgNode for AlterOperatorStmt {}
 impl std::fmt::Display for AlterOperatorStmt {
@@ -64246,6 +64826,12 @@ impl std::fmt::Display for AlterOperatorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOperatorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOperatorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOwnerStmt {}
 impl pg_sys::PgNode for AlterOwnerStmt {}
 impl std::fmt::Display for AlterOwnerStmt {
@@ -64253,6 +64839,12 @@ impl std::fmt::Display for AlterOwnerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOwnerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOwnerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPolicyStmt {}
 impl pg_sys::PgNode for AlterPolicyStmt {}
 impl std::fmt::Display for AlterPolicyStmt {
@@ -64260,6 +64852,12 @@ impl std::fmt::Display for AlterPolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPublicationStmt {}
 impl pg_sys::PgNode for AlterPublicationStmt {}
 impl std::fmt::Display for AlterPublicationStmt {
@@ -64267,6 +64865,12 @@ impl std::fmt::Display for AlterPublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleSetStmt {}
 impl pg_sys::PgNode for AlterRoleSetStmt {}
 impl std::fmt::Display for AlterRoleSetStmt {
@@ -64274,6 +64878,12 @@ impl std::fmt::Display for AlterRoleSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleStmt {}
 impl pg_sys::PgNode for AlterRoleStmt {}
 impl std::fmt::Display for AlterRoleStmt {
@@ -64281,6 +64891,12 @@ impl std::fmt::Display for AlterRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSeqStmt {}
 impl pg_sys::PgNode for AlterSeqStmt {}
 impl std::fmt::Display for AlterSeqStmt {
@@ -64288,6 +64904,12 @@ impl std::fmt::Display for AlterSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterStatsStmt {}
 impl pg_sys::PgNode for AlterStatsStmt {}
 impl std::fmt::Display for AlterStatsStmt {
@@ -64295,6 +64917,12 @@ impl std::fmt::Display for AlterStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSubscriptionStmt {}
 impl pg_sys::PgNode for AlterSubscriptionStmt {}
 impl std::fmt::Display for AlterSubscriptionStmt {
@@ -64302,6 +64930,12 @@ impl std::fmt::Display for AlterSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSystemStmt {}
 impl pg_sys::PgNode for AlterSystemStmt {}
 impl std::fmt::Display for AlterSystemStmt {
@@ -64309,6 +64943,12 @@ impl std::fmt::Display for AlterSystemStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSystemStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSystemStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSConfigurationStmt {}
 impl pg_sys::PgNode for AlterTSConfigurationStmt {}
 impl std::fmt::Display for AlterTSConfigurationStmt {
@@ -64316,6 +64956,12 @@ impl std::fmt::Display for AlterTSConfigurationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSConfigurationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSConfigurationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSDictionaryStmt {}
 impl pg_sys::PgNode for AlterTSDictionaryStmt {}
 impl std::fmt::Display for AlterTSDictionaryStmt {
@@ -64323,6 +64969,12 @@ impl std::fmt::Display for AlterTSDictionaryStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSDictionaryStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSDictionaryStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableCmd {}
 impl pg_sys::PgNode for AlterTableCmd {}
 impl std::fmt::Display for AlterTableCmd {
@@ -64330,6 +64982,12 @@ impl std::fmt::Display for AlterTableCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableMoveAllStmt {}
 impl pg_sys::PgNode for AlterTableMoveAllStmt {}
 impl std::fmt::Display for AlterTableMoveAllStmt {
@@ -64337,6 +64995,12 @@ impl std::fmt::Display for AlterTableMoveAllStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableMoveAllStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableMoveAllStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableSpaceOptionsStmt {}
 impl pg_sys::PgNode for AlterTableSpaceOptionsStmt {}
 impl std::fmt::Display for AlterTableSpaceOptionsStmt {
@@ -64344,6 +65008,12 @@ impl std::fmt::Display for AlterTableSpaceOptionsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableSpaceOptionsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableSpaceOptionsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableStmt {}
 impl pg_sys::PgNode for AlterTableStmt {}
 impl std::fmt::Display for AlterTableStmt {
@@ -64351,6 +65021,12 @@ impl std::fmt::Display for AlterTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTypeStmt {}
 impl pg_sys::PgNode for AlterTypeStmt {}
 impl std::fmt::Display for AlterTypeStmt {
@@ -64358,6 +65034,12 @@ impl std::fmt::Display for AlterTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterUserMappingStmt {}
 impl pg_sys::PgNode for AlterUserMappingStmt {}
 impl std::fmt::Display for AlterUserMappingStmt {
@@ -64365,6 +65047,12 @@ impl std::fmt::Display for AlterUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlan {}
 impl pg_sys::PgNode for AlternativeSubPlan {}
 impl std::fmt::Display for AlternativeSubPlan {
@@ -64372,6 +65060,12 @@ impl std::fmt::Display for AlternativeSubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for Append {}
 impl pg_sys::PgNode for Append {}
 impl std::fmt::Display for Append {
@@ -64379,6 +65073,12 @@ impl std::fmt::Display for Append {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Append {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Append"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendPath {}
 impl pg_sys::PgNode for AppendPath {}
 impl std::fmt::Display for AppendPath {
@@ -64386,6 +65086,12 @@ impl std::fmt::Display for AppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendRelInfo {}
 impl pg_sys::PgNode for AppendRelInfo {}
 impl std::fmt::Display for AppendRelInfo {
@@ -64393,6 +65099,12 @@ impl std::fmt::Display for AppendRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendState {}
 impl pg_sys::PgNode for AppendState {}
 impl std::fmt::Display for AppendState {
@@ -64400,6 +65112,12 @@ impl std::fmt::Display for AppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayCoerceExpr {}
 impl pg_sys::PgNode for ArrayCoerceExpr {}
 impl std::fmt::Display for ArrayCoerceExpr {
@@ -64407,6 +65125,12 @@ impl std::fmt::Display for ArrayCoerceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayCoerceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayCoerceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayExpr {}
 impl pg_sys::PgNode for ArrayExpr {}
 impl std::fmt::Display for ArrayExpr {
@@ -64414,6 +65138,12 @@ impl std::fmt::Display for ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BaseBackupCmd {}
 impl pg_sys::PgNode for BaseBackupCmd {}
 impl std::fmt::Display for BaseBackupCmd {
@@ -64421,6 +65151,12 @@ impl std::fmt::Display for BaseBackupCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BaseBackupCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BaseBackupCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAnd {}
 impl pg_sys::PgNode for BitmapAnd {}
 impl std::fmt::Display for BitmapAnd {
@@ -64428,6 +65164,12 @@ impl std::fmt::Display for BitmapAnd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAnd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndPath {}
 impl pg_sys::PgNode for BitmapAndPath {}
 impl std::fmt::Display for BitmapAndPath {
@@ -64435,6 +65177,12 @@ impl std::fmt::Display for BitmapAndPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndState {}
 impl pg_sys::PgNode for BitmapAndState {}
 impl std::fmt::Display for BitmapAndState {
@@ -64442,6 +65190,12 @@ impl std::fmt::Display for BitmapAndState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapPath {}
 impl pg_sys::PgNode for BitmapHeapPath {}
 impl std::fmt::Display for BitmapHeapPath {
@@ -64449,6 +65203,12 @@ impl std::fmt::Display for BitmapHeapPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScan {}
 impl pg_sys::PgNode for BitmapHeapScan {}
 impl std::fmt::Display for BitmapHeapScan {
@@ -64456,6 +65216,12 @@ impl std::fmt::Display for BitmapHeapScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScanState {}
 impl pg_sys::PgNode for BitmapHeapScanState {}
 impl std::fmt::Display for BitmapHeapScanState {
@@ -64463,6 +65229,12 @@ impl std::fmt::Display for BitmapHeapScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScan {}
 impl pg_sys::PgNode for BitmapIndexScan {}
 impl std::fmt::Display for BitmapIndexScan {
@@ -64470,6 +65242,12 @@ impl std::fmt::Display for BitmapIndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScanState {}
 impl pg_sys::PgNode for BitmapIndexScanState {}
 impl std::fmt::Display for BitmapIndexScanState {
@@ -64477,6 +65255,12 @@ impl std::fmt::Display for BitmapIndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOr {}
 impl pg_sys::PgNode for BitmapOr {}
 impl std::fmt::Display for BitmapOr {
@@ -64484,6 +65268,12 @@ impl std::fmt::Display for BitmapOr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOr"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrPath {}
 impl pg_sys::PgNode for BitmapOrPath {}
 impl std::fmt::Display for BitmapOrPath {
@@ -64491,6 +65281,12 @@ impl std::fmt::Display for BitmapOrPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrState {}
 impl pg_sys::PgNode for BitmapOrState {}
 impl std::fmt::Display for BitmapOrState {
@@ -64498,6 +65294,12 @@ impl std::fmt::Display for BitmapOrState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrState"))
+    }
+}
 impl pg_sys::seal::Sealed for BoolExpr {}
 impl pg_sys::PgNode for BoolExpr {}
 impl std::fmt::Display for BoolExpr {
@@ -64505,6 +65307,12 @@ impl std::fmt::Display for BoolExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BoolExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BoolExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BooleanTest {}
 impl pg_sys::PgNode for BooleanTest {}
 impl std::fmt::Display for BooleanTest {
@@ -64512,6 +65320,12 @@ impl std::fmt::Display for BooleanTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BooleanTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BooleanTest"))
+    }
+}
 impl pg_sys::seal::Sealed for BufferHeapTupleTableSlot {}
 impl pg_sys::PgNode for BufferHeapTupleTableSlot {}
 impl std::fmt::Display for BufferHeapTupleTableSlot {
@@ -64519,6 +65333,12 @@ impl std::fmt::Display for BufferHeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BufferHeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BufferHeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for CTECycleClause {}
 impl pg_sys::PgNode for CTECycleClause {}
 impl std::fmt::Display for CTECycleClause {
@@ -64526,6 +65346,12 @@ impl std::fmt::Display for CTECycleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CTECycleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CTECycleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CTESearchClause {}
 impl pg_sys::PgNode for CTESearchClause {}
 impl std::fmt::Display for CTESearchClause {
@@ -64533,6 +65359,12 @@ impl std::fmt::Display for CTESearchClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CTESearchClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CTESearchClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CallContext {}
 impl pg_sys::PgNode for CallContext {}
 impl std::fmt::Display for CallContext {
@@ -64540,6 +65372,12 @@ impl std::fmt::Display for CallContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallContext"))
+    }
+}
 impl pg_sys::seal::Sealed for CallStmt {}
 impl pg_sys::PgNode for CallStmt {}
 impl std::fmt::Display for CallStmt {
@@ -64547,6 +65385,12 @@ impl std::fmt::Display for CallStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseExpr {}
 impl pg_sys::PgNode for CaseExpr {}
 impl std::fmt::Display for CaseExpr {
@@ -64554,6 +65398,12 @@ impl std::fmt::Display for CaseExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseTestExpr {}
 impl pg_sys::PgNode for CaseTestExpr {}
 impl std::fmt::Display for CaseTestExpr {
@@ -64561,6 +65411,12 @@ impl std::fmt::Display for CaseTestExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseTestExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseTestExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseWhen {}
 impl pg_sys::PgNode for CaseWhen {}
 impl std::fmt::Display for CaseWhen {
@@ -64568,6 +65424,12 @@ impl std::fmt::Display for CaseWhen {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseWhen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseWhen"))
+    }
+}
 impl pg_sys::seal::Sealed for CheckPointStmt {}
 impl pg_sys::PgNode for CheckPointStmt {}
 impl std::fmt::Display for CheckPointStmt {
@@ -64575,6 +65437,12 @@ impl std::fmt::Display for CheckPointStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CheckPointStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CheckPointStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClosePortalStmt {}
 impl pg_sys::PgNode for ClosePortalStmt {}
 impl std::fmt::Display for ClosePortalStmt {
@@ -64582,6 +65450,12 @@ impl std::fmt::Display for ClosePortalStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClosePortalStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClosePortalStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClusterStmt {}
 impl pg_sys::PgNode for ClusterStmt {}
 impl std::fmt::Display for ClusterStmt {
@@ -64589,6 +65463,12 @@ impl std::fmt::Display for ClusterStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClusterStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClusterStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CoalesceExpr {}
 impl pg_sys::PgNode for CoalesceExpr {}
 impl std::fmt::Display for CoalesceExpr {
@@ -64596,6 +65476,12 @@ impl std::fmt::Display for CoalesceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoalesceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoalesceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomain {}
 impl pg_sys::PgNode for CoerceToDomain {}
 impl std::fmt::Display for CoerceToDomain {
@@ -64603,6 +65489,12 @@ impl std::fmt::Display for CoerceToDomain {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomain"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomainValue {}
 impl pg_sys::PgNode for CoerceToDomainValue {}
 impl std::fmt::Display for CoerceToDomainValue {
@@ -64610,6 +65502,12 @@ impl std::fmt::Display for CoerceToDomainValue {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomainValue"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceViaIO {}
 impl pg_sys::PgNode for CoerceViaIO {}
 impl std::fmt::Display for CoerceViaIO {
@@ -64617,6 +65515,12 @@ impl std::fmt::Display for CoerceViaIO {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceViaIO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceViaIO"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateClause {}
 impl pg_sys::PgNode for CollateClause {}
 impl std::fmt::Display for CollateClause {
@@ -64624,6 +65528,12 @@ impl std::fmt::Display for CollateClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateExpr {}
 impl pg_sys::PgNode for CollateExpr {}
 impl std::fmt::Display for CollateExpr {
@@ -64631,6 +65541,12 @@ impl std::fmt::Display for CollateExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnDef {}
 impl pg_sys::PgNode for ColumnDef {}
 impl std::fmt::Display for ColumnDef {
@@ -64638,6 +65554,12 @@ impl std::fmt::Display for ColumnDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnDef"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnRef {}
 impl pg_sys::PgNode for ColumnRef {}
 impl std::fmt::Display for ColumnRef {
@@ -64645,6 +65567,12 @@ impl std::fmt::Display for ColumnRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnRef"))
+    }
+}
 impl pg_sys::seal::Sealed for CommentStmt {}
 impl pg_sys::PgNode for CommentStmt {}
 impl std::fmt::Display for CommentStmt {
@@ -64652,6 +65580,12 @@ impl std::fmt::Display for CommentStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommentStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommentStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CommonTableExpr {}
 impl pg_sys::PgNode for CommonTableExpr {}
 impl std::fmt::Display for CommonTableExpr {
@@ -64659,6 +65593,12 @@ impl std::fmt::Display for CommonTableExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommonTableExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommonTableExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CompositeTypeStmt {}
 impl pg_sys::PgNode for CompositeTypeStmt {}
 impl std::fmt::Display for CompositeTypeStmt {
@@ -64666,6 +65606,12 @@ impl std::fmt::Display for CompositeTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CompositeTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CompositeTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Const {}
 impl pg_sys::PgNode for Const {}
 impl std::fmt::Display for Const {
@@ -64673,6 +65619,12 @@ impl std::fmt::Display for Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Const"))
+    }
+}
 impl pg_sys::seal::Sealed for Constraint {}
 impl pg_sys::PgNode for Constraint {}
 impl std::fmt::Display for Constraint {
@@ -64680,6 +65632,12 @@ impl std::fmt::Display for Constraint {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Constraint"))
+    }
+}
 impl pg_sys::seal::Sealed for ConstraintsSetStmt {}
 impl pg_sys::PgNode for ConstraintsSetStmt {}
 impl std::fmt::Display for ConstraintsSetStmt {
@@ -64687,6 +65645,12 @@ impl std::fmt::Display for ConstraintsSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConstraintsSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConstraintsSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ConvertRowtypeExpr {}
 impl pg_sys::PgNode for ConvertRowtypeExpr {}
 impl std::fmt::Display for ConvertRowtypeExpr {
@@ -64694,6 +65658,12 @@ impl std::fmt::Display for ConvertRowtypeExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConvertRowtypeExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConvertRowtypeExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CopyStmt {}
 impl pg_sys::PgNode for CopyStmt {}
 impl std::fmt::Display for CopyStmt {
@@ -64701,6 +65671,12 @@ impl std::fmt::Display for CopyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CopyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CopyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateAmStmt {}
 impl pg_sys::PgNode for CreateAmStmt {}
 impl std::fmt::Display for CreateAmStmt {
@@ -64708,6 +65684,12 @@ impl std::fmt::Display for CreateAmStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateAmStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateAmStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateCastStmt {}
 impl pg_sys::PgNode for CreateCastStmt {}
 impl std::fmt::Display for CreateCastStmt {
@@ -64715,6 +65697,12 @@ impl std::fmt::Display for CreateCastStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateCastStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateCastStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateConversionStmt {}
 impl pg_sys::PgNode for CreateConversionStmt {}
 impl std::fmt::Display for CreateConversionStmt {
@@ -64722,6 +65710,12 @@ impl std::fmt::Display for CreateConversionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateConversionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateConversionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateDomainStmt {}
 impl pg_sys::PgNode for CreateDomainStmt {}
 impl std::fmt::Display for CreateDomainStmt {
@@ -64729,6 +65723,12 @@ impl std::fmt::Display for CreateDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEnumStmt {}
 impl pg_sys::PgNode for CreateEnumStmt {}
 impl std::fmt::Display for CreateEnumStmt {
@@ -64736,6 +65736,12 @@ impl std::fmt::Display for CreateEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEventTrigStmt {}
 impl pg_sys::PgNode for CreateEventTrigStmt {}
 impl std::fmt::Display for CreateEventTrigStmt {
@@ -64743,6 +65749,12 @@ impl std::fmt::Display for CreateEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateExtensionStmt {}
 impl pg_sys::PgNode for CreateExtensionStmt {}
 impl std::fmt::Display for CreateExtensionStmt {
@@ -64750,6 +65762,12 @@ impl std::fmt::Display for CreateExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFdwStmt {}
 impl pg_sys::PgNode for CreateFdwStmt {}
 impl std::fmt::Display for CreateFdwStmt {
@@ -64757,6 +65775,12 @@ impl std::fmt::Display for CreateFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignServerStmt {}
 impl pg_sys::PgNode for CreateForeignServerStmt {}
 impl std::fmt::Display for CreateForeignServerStmt {
@@ -64764,6 +65788,12 @@ impl std::fmt::Display for CreateForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignTableStmt {}
 impl pg_sys::PgNode for CreateForeignTableStmt {}
 impl std::fmt::Display for CreateForeignTableStmt {
@@ -64771,6 +65801,12 @@ impl std::fmt::Display for CreateForeignTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFunctionStmt {}
 impl pg_sys::PgNode for CreateFunctionStmt {}
 impl std::fmt::Display for CreateFunctionStmt {
@@ -64778,6 +65814,12 @@ impl std::fmt::Display for CreateFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassItem {}
 impl pg_sys::PgNode for CreateOpClassItem {}
 impl std::fmt::Display for CreateOpClassItem {
@@ -64785,6 +65827,12 @@ impl std::fmt::Display for CreateOpClassItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassItem"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassStmt {}
 impl pg_sys::PgNode for CreateOpClassStmt {}
 impl std::fmt::Display for CreateOpClassStmt {
@@ -64792,6 +65840,12 @@ impl std::fmt::Display for CreateOpClassStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpFamilyStmt {}
 impl pg_sys::PgNode for CreateOpFamilyStmt {}
 impl std::fmt::Display for CreateOpFamilyStmt {
@@ -64799,6 +65853,12 @@ impl std::fmt::Display for CreateOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePLangStmt {}
 impl pg_sys::PgNode for CreatePLangStmt {}
 impl std::fmt::Display for CreatePLangStmt {
@@ -64806,6 +65866,12 @@ impl std::fmt::Display for CreatePLangStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePLangStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePLangStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePolicyStmt {}
 impl pg_sys::PgNode for CreatePolicyStmt {}
 impl std::fmt::Display for CreatePolicyStmt {
@@ -64813,6 +65879,12 @@ impl std::fmt::Display for CreatePolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePublicationStmt {}
 impl pg_sys::PgNode for CreatePublicationStmt {}
 impl std::fmt::Display for CreatePublicationStmt {
@@ -64820,6 +65892,12 @@ impl std::fmt::Display for CreatePublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRangeStmt {}
 impl pg_sys::PgNode for CreateRangeStmt {}
 impl std::fmt::Display for CreateRangeStmt {
@@ -64827,6 +65905,12 @@ impl std::fmt::Display for CreateRangeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRangeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRangeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateReplicationSlotCmd {}
 impl pg_sys::PgNode for CreateReplicationSlotCmd {}
 impl std::fmt::Display for CreateReplicationSlotCmd {
@@ -64834,6 +65918,12 @@ impl std::fmt::Display for CreateReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRoleStmt {}
 impl pg_sys::PgNode for CreateRoleStmt {}
 impl std::fmt::Display for CreateRoleStmt {
@@ -64841,6 +65931,12 @@ impl std::fmt::Display for CreateRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSchemaStmt {}
 impl pg_sys::PgNode for CreateSchemaStmt {}
 impl std::fmt::Display for CreateSchemaStmt {
@@ -64848,6 +65944,12 @@ impl std::fmt::Display for CreateSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSeqStmt {}
 impl pg_sys::PgNode for CreateSeqStmt {}
 impl std::fmt::Display for CreateSeqStmt {
@@ -64855,6 +65957,12 @@ impl std::fmt::Display for CreateSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStatsStmt {}
 impl pg_sys::PgNode for CreateStatsStmt {}
 impl std::fmt::Display for CreateStatsStmt {
@@ -64862,6 +65970,12 @@ impl std::fmt::Display for CreateStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStmt {}
 impl pg_sys::PgNode for CreateStmt {}
 impl std::fmt::Display for CreateStmt {
@@ -64869,6 +65983,12 @@ impl std::fmt::Display for CreateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSubscriptionStmt {}
 impl pg_sys::PgNode for CreateSubscriptionStmt {}
 impl std::fmt::Display for CreateSubscriptionStmt {
@@ -64876,6 +65996,12 @@ impl std::fmt::Display for CreateSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableAsStmt {}
 impl pg_sys::PgNode for CreateTableAsStmt {}
 impl std::fmt::Display for CreateTableAsStmt {
@@ -64883,6 +66009,12 @@ impl std::fmt::Display for CreateTableAsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableAsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableAsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableSpaceStmt {}
 impl pg_sys::PgNode for CreateTableSpaceStmt {}
 impl std::fmt::Display for CreateTableSpaceStmt {
@@ -64890,6 +66022,12 @@ impl std::fmt::Display for CreateTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTransformStmt {}
 impl pg_sys::PgNode for CreateTransformStmt {}
 impl std::fmt::Display for CreateTransformStmt {
@@ -64897,6 +66035,12 @@ impl std::fmt::Display for CreateTransformStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTransformStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTransformStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTrigStmt {}
 impl pg_sys::PgNode for CreateTrigStmt {}
 impl std::fmt::Display for CreateTrigStmt {
@@ -64904,6 +66048,12 @@ impl std::fmt::Display for CreateTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateUserMappingStmt {}
 impl pg_sys::PgNode for CreateUserMappingStmt {}
 impl std::fmt::Display for CreateUserMappingStmt {
@@ -64911,6 +66061,12 @@ impl std::fmt::Display for CreateUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatedbStmt {}
 impl pg_sys::PgNode for CreatedbStmt {}
 impl std::fmt::Display for CreatedbStmt {
@@ -64918,6 +66074,12 @@ impl std::fmt::Display for CreatedbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatedbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatedbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScan {}
 impl pg_sys::PgNode for CteScan {}
 impl std::fmt::Display for CteScan {
@@ -64925,6 +66087,12 @@ impl std::fmt::Display for CteScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScanState {}
 impl pg_sys::PgNode for CteScanState {}
 impl std::fmt::Display for CteScanState {
@@ -64932,6 +66100,12 @@ impl std::fmt::Display for CteScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for CurrentOfExpr {}
 impl pg_sys::PgNode for CurrentOfExpr {}
 impl std::fmt::Display for CurrentOfExpr {
@@ -64939,6 +66113,12 @@ impl std::fmt::Display for CurrentOfExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CurrentOfExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CurrentOfExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomPath {}
 impl pg_sys::PgNode for CustomPath {}
 impl std::fmt::Display for CustomPath {
@@ -64946,6 +66126,12 @@ impl std::fmt::Display for CustomPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomPath"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScan {}
 impl pg_sys::PgNode for CustomScan {}
 impl std::fmt::Display for CustomScan {
@@ -64953,6 +66139,12 @@ impl std::fmt::Display for CustomScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScanState {}
 impl pg_sys::PgNode for CustomScanState {}
 impl std::fmt::Display for CustomScanState {
@@ -64960,6 +66152,12 @@ impl std::fmt::Display for CustomScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for DeallocateStmt {}
 impl pg_sys::PgNode for DeallocateStmt {}
 impl std::fmt::Display for DeallocateStmt {
@@ -64967,6 +66165,12 @@ impl std::fmt::Display for DeallocateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeallocateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeallocateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeclareCursorStmt {}
 impl pg_sys::PgNode for DeclareCursorStmt {}
 impl std::fmt::Display for DeclareCursorStmt {
@@ -64974,6 +66178,12 @@ impl std::fmt::Display for DeclareCursorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeclareCursorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeclareCursorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DefElem {}
 impl pg_sys::PgNode for DefElem {}
 impl std::fmt::Display for DefElem {
@@ -64981,6 +66191,12 @@ impl std::fmt::Display for DefElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefElem"))
+    }
+}
 impl pg_sys::seal::Sealed for DefineStmt {}
 impl pg_sys::PgNode for DefineStmt {}
 impl std::fmt::Display for DefineStmt {
@@ -64988,6 +66204,12 @@ impl std::fmt::Display for DefineStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefineStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefineStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeleteStmt {}
 impl pg_sys::PgNode for DeleteStmt {}
 impl std::fmt::Display for DeleteStmt {
@@ -64995,6 +66217,12 @@ impl std::fmt::Display for DeleteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeleteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeleteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DiscardStmt {}
 impl pg_sys::PgNode for DiscardStmt {}
 impl std::fmt::Display for DiscardStmt {
@@ -65002,6 +66230,12 @@ impl std::fmt::Display for DiscardStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DiscardStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DiscardStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DoStmt {}
 impl pg_sys::PgNode for DoStmt {}
 impl std::fmt::Display for DoStmt {
@@ -65009,6 +66243,12 @@ impl std::fmt::Display for DoStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DoStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DoStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DomainConstraintState {}
 impl pg_sys::PgNode for DomainConstraintState {}
 impl std::fmt::Display for DomainConstraintState {
@@ -65016,6 +66256,12 @@ impl std::fmt::Display for DomainConstraintState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DomainConstraintState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DomainConstraintState"))
+    }
+}
 impl pg_sys::seal::Sealed for DropOwnedStmt {}
 impl pg_sys::PgNode for DropOwnedStmt {}
 impl std::fmt::Display for DropOwnedStmt {
@@ -65023,6 +66269,12 @@ impl std::fmt::Display for DropOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropReplicationSlotCmd {}
 impl pg_sys::PgNode for DropReplicationSlotCmd {}
 impl std::fmt::Display for DropReplicationSlotCmd {
@@ -65030,6 +66282,12 @@ impl std::fmt::Display for DropReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for DropRoleStmt {}
 impl pg_sys::PgNode for DropRoleStmt {}
 impl std::fmt::Display for DropRoleStmt {
@@ -65037,6 +66295,12 @@ impl std::fmt::Display for DropRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropStmt {}
 impl pg_sys::PgNode for DropStmt {}
 impl std::fmt::Display for DropStmt {
@@ -65044,6 +66308,12 @@ impl std::fmt::Display for DropStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropSubscriptionStmt {}
 impl pg_sys::PgNode for DropSubscriptionStmt {}
 impl std::fmt::Display for DropSubscriptionStmt {
@@ -65051,6 +66321,12 @@ impl std::fmt::Display for DropSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropTableSpaceStmt {}
 impl pg_sys::PgNode for DropTableSpaceStmt {}
 impl std::fmt::Display for DropTableSpaceStmt {
@@ -65058,6 +66334,12 @@ impl std::fmt::Display for DropTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropUserMappingStmt {}
 impl pg_sys::PgNode for DropUserMappingStmt {}
 impl std::fmt::Display for DropUserMappingStmt {
@@ -65065,6 +66347,12 @@ impl std::fmt::Display for DropUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropdbStmt {}
 impl pg_sys::PgNode for DropdbStmt {}
 impl std::fmt::Display for DropdbStmt {
@@ -65072,6 +66360,12 @@ impl std::fmt::Display for DropdbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropdbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropdbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for EState {}
 impl pg_sys::PgNode for EState {}
 impl std::fmt::Display for EState {
@@ -65079,6 +66373,12 @@ impl std::fmt::Display for EState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EState"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceClass {}
 impl pg_sys::PgNode for EquivalenceClass {}
 impl std::fmt::Display for EquivalenceClass {
@@ -65086,6 +66386,12 @@ impl std::fmt::Display for EquivalenceClass {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceClass"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceMember {}
 impl pg_sys::PgNode for EquivalenceMember {}
 impl std::fmt::Display for EquivalenceMember {
@@ -65093,6 +66399,12 @@ impl std::fmt::Display for EquivalenceMember {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceMember"))
+    }
+}
 impl pg_sys::seal::Sealed for EventTriggerData {}
 impl pg_sys::PgNode for EventTriggerData {}
 impl std::fmt::Display for EventTriggerData {
@@ -65100,6 +66412,12 @@ impl std::fmt::Display for EventTriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EventTriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EventTriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for ExecuteStmt {}
 impl pg_sys::PgNode for ExecuteStmt {}
 impl std::fmt::Display for ExecuteStmt {
@@ -65107,6 +66425,12 @@ impl std::fmt::Display for ExecuteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExecuteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExecuteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ExplainStmt {}
 impl pg_sys::PgNode for ExplainStmt {}
 impl std::fmt::Display for ExplainStmt {
@@ -65114,6 +66438,12 @@ impl std::fmt::Display for ExplainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExplainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExplainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Expr {}
 impl pg_sys::PgNode for Expr {}
 impl std::fmt::Display for Expr {
@@ -65121,6 +66451,12 @@ impl std::fmt::Display for Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprContext {}
 impl pg_sys::PgNode for ExprContext {}
 impl std::fmt::Display for ExprContext {
@@ -65128,6 +66464,12 @@ impl std::fmt::Display for ExprContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprContext"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprState {}
 impl pg_sys::PgNode for ExprState {}
 impl std::fmt::Display for ExprState {
@@ -65135,6 +66477,12 @@ impl std::fmt::Display for ExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for ExtensibleNode {}
 impl pg_sys::PgNode for ExtensibleNode {}
 impl std::fmt::Display for ExtensibleNode {
@@ -65142,13 +66490,25 @@ impl std::fmt::Display for ExtensibleNode {
         write!(f, "{}", self.display_node())
     }
 }
-impl pg_sys::seal::Sealed for FdwRoutine {}
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExtensibleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExtensibleNode"))
+    }
+}
+impl pg_sys::seal::Sealed for FdwRoutine {}
 impl pg_sys::PgNode for FdwRoutine {}
 impl std::fmt::Display for FdwRoutine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FdwRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FdwRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for FetchStmt {}
 impl pg_sys::PgNode for FetchStmt {}
 impl std::fmt::Display for FetchStmt {
@@ -65156,6 +66516,12 @@ impl std::fmt::Display for FetchStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FetchStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FetchStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldSelect {}
 impl pg_sys::PgNode for FieldSelect {}
 impl std::fmt::Display for FieldSelect {
@@ -65163,6 +66529,12 @@ impl std::fmt::Display for FieldSelect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldSelect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldSelect"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldStore {}
 impl pg_sys::PgNode for FieldStore {}
 impl std::fmt::Display for FieldStore {
@@ -65170,6 +66542,12 @@ impl std::fmt::Display for FieldStore {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldStore"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyCacheInfo {}
 impl pg_sys::PgNode for ForeignKeyCacheInfo {}
 impl std::fmt::Display for ForeignKeyCacheInfo {
@@ -65177,6 +66555,12 @@ impl std::fmt::Display for ForeignKeyCacheInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyCacheInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyCacheInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyOptInfo {}
 impl pg_sys::PgNode for ForeignKeyOptInfo {}
 impl std::fmt::Display for ForeignKeyOptInfo {
@@ -65184,6 +66568,12 @@ impl std::fmt::Display for ForeignKeyOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignPath {}
 impl pg_sys::PgNode for ForeignPath {}
 impl std::fmt::Display for ForeignPath {
@@ -65191,6 +66581,12 @@ impl std::fmt::Display for ForeignPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScan {}
 impl pg_sys::PgNode for ForeignScan {}
 impl std::fmt::Display for ForeignScan {
@@ -65198,6 +66594,12 @@ impl std::fmt::Display for ForeignScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScanState {}
 impl pg_sys::PgNode for ForeignScanState {}
 impl std::fmt::Display for ForeignScanState {
@@ -65205,6 +66607,12 @@ impl std::fmt::Display for ForeignScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for FromExpr {}
 impl pg_sys::PgNode for FromExpr {}
 impl std::fmt::Display for FromExpr {
@@ -65212,6 +66620,12 @@ impl std::fmt::Display for FromExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FromExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FromExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncCall {}
 impl pg_sys::PgNode for FuncCall {}
 impl std::fmt::Display for FuncCall {
@@ -65219,6 +66633,12 @@ impl std::fmt::Display for FuncCall {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncCall"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncExpr {}
 impl pg_sys::PgNode for FuncExpr {}
 impl std::fmt::Display for FuncExpr {
@@ -65226,6 +66646,12 @@ impl std::fmt::Display for FuncExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionParameter {}
 impl pg_sys::PgNode for FunctionParameter {}
 impl std::fmt::Display for FunctionParameter {
@@ -65233,6 +66659,12 @@ impl std::fmt::Display for FunctionParameter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionParameter"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScan {}
 impl pg_sys::PgNode for FunctionScan {}
 impl std::fmt::Display for FunctionScan {
@@ -65240,6 +66672,12 @@ impl std::fmt::Display for FunctionScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScan"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScanState {}
 impl pg_sys::PgNode for FunctionScanState {}
 impl std::fmt::Display for FunctionScanState {
@@ -65247,6 +66685,12 @@ impl std::fmt::Display for FunctionScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Gather {}
 impl pg_sys::PgNode for Gather {}
 impl std::fmt::Display for Gather {
@@ -65254,6 +66698,12 @@ impl std::fmt::Display for Gather {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Gather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Gather"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMerge {}
 impl pg_sys::PgNode for GatherMerge {}
 impl std::fmt::Display for GatherMerge {
@@ -65261,6 +66711,12 @@ impl std::fmt::Display for GatherMerge {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMerge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMerge"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergePath {}
 impl pg_sys::PgNode for GatherMergePath {}
 impl std::fmt::Display for GatherMergePath {
@@ -65268,6 +66724,12 @@ impl std::fmt::Display for GatherMergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergeState {}
 impl pg_sys::PgNode for GatherMergeState {}
 impl std::fmt::Display for GatherMergeState {
@@ -65275,6 +66737,12 @@ impl std::fmt::Display for GatherMergeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergeState"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherPath {}
 impl pg_sys::PgNode for GatherPath {}
 impl std::fmt::Display for GatherPath {
@@ -65282,6 +66750,12 @@ impl std::fmt::Display for GatherPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherState {}
 impl pg_sys::PgNode for GatherState {}
 impl std::fmt::Display for GatherState {
@@ -65289,6 +66763,12 @@ impl std::fmt::Display for GatherState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherState"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantRoleStmt {}
 impl pg_sys::PgNode for GrantRoleStmt {}
 impl std::fmt::Display for GrantRoleStmt {
@@ -65296,6 +66776,12 @@ impl std::fmt::Display for GrantRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantStmt {}
 impl pg_sys::PgNode for GrantStmt {}
 impl std::fmt::Display for GrantStmt {
@@ -65303,6 +66789,12 @@ impl std::fmt::Display for GrantStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Group {}
 impl pg_sys::PgNode for Group {}
 impl std::fmt::Display for Group {
@@ -65310,6 +66802,12 @@ impl std::fmt::Display for Group {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Group"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupPath {}
 impl pg_sys::PgNode for GroupPath {}
 impl std::fmt::Display for GroupPath {
@@ -65317,6 +66815,12 @@ impl std::fmt::Display for GroupPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupResultPath {}
 impl pg_sys::PgNode for GroupResultPath {}
 impl std::fmt::Display for GroupResultPath {
@@ -65324,6 +66828,12 @@ impl std::fmt::Display for GroupResultPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupResultPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupResultPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupState {}
 impl pg_sys::PgNode for GroupState {}
 impl std::fmt::Display for GroupState {
@@ -65331,6 +66841,12 @@ impl std::fmt::Display for GroupState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupState"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingFunc {}
 impl pg_sys::PgNode for GroupingFunc {}
 impl std::fmt::Display for GroupingFunc {
@@ -65338,6 +66854,12 @@ impl std::fmt::Display for GroupingFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSet {}
 impl pg_sys::PgNode for GroupingSet {}
 impl std::fmt::Display for GroupingSet {
@@ -65345,6 +66867,12 @@ impl std::fmt::Display for GroupingSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSet"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetData {}
 impl pg_sys::PgNode for GroupingSetData {}
 impl std::fmt::Display for GroupingSetData {
@@ -65352,6 +66880,12 @@ impl std::fmt::Display for GroupingSetData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetData"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetsPath {}
 impl pg_sys::PgNode for GroupingSetsPath {}
 impl std::fmt::Display for GroupingSetsPath {
@@ -65359,6 +66893,12 @@ impl std::fmt::Display for GroupingSetsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Hash {}
 impl pg_sys::PgNode for Hash {}
 impl std::fmt::Display for Hash {
@@ -65366,6 +66906,12 @@ impl std::fmt::Display for Hash {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Hash"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoin {}
 impl pg_sys::PgNode for HashJoin {}
 impl std::fmt::Display for HashJoin {
@@ -65373,6 +66919,12 @@ impl std::fmt::Display for HashJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoinState {}
 impl pg_sys::PgNode for HashJoinState {}
 impl std::fmt::Display for HashJoinState {
@@ -65380,6 +66932,12 @@ impl std::fmt::Display for HashJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for HashPath {}
 impl pg_sys::PgNode for HashPath {}
 impl std::fmt::Display for HashPath {
@@ -65387,6 +66945,12 @@ impl std::fmt::Display for HashPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashPath"))
+    }
+}
 impl pg_sys::seal::Sealed for HashState {}
 impl pg_sys::PgNode for HashState {}
 impl std::fmt::Display for HashState {
@@ -65394,6 +66958,12 @@ impl std::fmt::Display for HashState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashState"))
+    }
+}
 impl pg_sys::seal::Sealed for HeapTupleTableSlot {}
 impl pg_sys::PgNode for HeapTupleTableSlot {}
 impl std::fmt::Display for HeapTupleTableSlot {
@@ -65401,6 +66971,12 @@ impl std::fmt::Display for HeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for IdentifySystemCmd {}
 impl pg_sys::PgNode for IdentifySystemCmd {}
 impl std::fmt::Display for IdentifySystemCmd {
@@ -65408,6 +66984,12 @@ impl std::fmt::Display for IdentifySystemCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IdentifySystemCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IdentifySystemCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for ImportForeignSchemaStmt {}
 impl pg_sys::PgNode for ImportForeignSchemaStmt {}
 impl std::fmt::Display for ImportForeignSchemaStmt {
@@ -65415,6 +66997,12 @@ impl std::fmt::Display for ImportForeignSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ImportForeignSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ImportForeignSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSort {}
 impl pg_sys::PgNode for IncrementalSort {}
 impl std::fmt::Display for IncrementalSort {
@@ -65422,6 +67010,12 @@ impl std::fmt::Display for IncrementalSort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSort"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSortPath {}
 impl pg_sys::PgNode for IncrementalSortPath {}
 impl std::fmt::Display for IncrementalSortPath {
@@ -65429,6 +67023,12 @@ impl std::fmt::Display for IncrementalSortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSortState {}
 impl pg_sys::PgNode for IncrementalSortState {}
 impl std::fmt::Display for IncrementalSortState {
@@ -65436,6 +67036,12 @@ impl std::fmt::Display for IncrementalSortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSortState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexAmRoutine {}
 impl pg_sys::PgNode for IndexAmRoutine {}
 impl std::fmt::Display for IndexAmRoutine {
@@ -65443,6 +67049,12 @@ impl std::fmt::Display for IndexAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexClause {}
 impl pg_sys::PgNode for IndexClause {}
 impl std::fmt::Display for IndexClause {
@@ -65450,6 +67062,12 @@ impl std::fmt::Display for IndexClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexClause"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexElem {}
 impl pg_sys::PgNode for IndexElem {}
 impl std::fmt::Display for IndexElem {
@@ -65457,6 +67075,12 @@ impl std::fmt::Display for IndexElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexElem"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexInfo {}
 impl pg_sys::PgNode for IndexInfo {}
 impl std::fmt::Display for IndexInfo {
@@ -65464,6 +67088,12 @@ impl std::fmt::Display for IndexInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScan {}
 impl pg_sys::PgNode for IndexOnlyScan {}
 impl std::fmt::Display for IndexOnlyScan {
@@ -65471,6 +67101,12 @@ impl std::fmt::Display for IndexOnlyScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScanState {}
 impl pg_sys::PgNode for IndexOnlyScanState {}
 impl std::fmt::Display for IndexOnlyScanState {
@@ -65478,6 +67114,12 @@ impl std::fmt::Display for IndexOnlyScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOptInfo {}
 impl pg_sys::PgNode for IndexOptInfo {}
 impl std::fmt::Display for IndexOptInfo {
@@ -65485,6 +67127,12 @@ impl std::fmt::Display for IndexOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexPath {}
 impl pg_sys::PgNode for IndexPath {}
 impl std::fmt::Display for IndexPath {
@@ -65492,6 +67140,12 @@ impl std::fmt::Display for IndexPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScan {}
 impl pg_sys::PgNode for IndexScan {}
 impl std::fmt::Display for IndexScan {
@@ -65499,6 +67153,12 @@ impl std::fmt::Display for IndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScanState {}
 impl pg_sys::PgNode for IndexScanState {}
 impl std::fmt::Display for IndexScanState {
@@ -65506,6 +67166,12 @@ impl std::fmt::Display for IndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexStmt {}
 impl pg_sys::PgNode for IndexStmt {}
 impl std::fmt::Display for IndexStmt {
@@ -65513,6 +67179,12 @@ impl std::fmt::Display for IndexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for InferClause {}
 impl pg_sys::PgNode for InferClause {}
 impl std::fmt::Display for InferClause {
@@ -65520,6 +67192,12 @@ impl std::fmt::Display for InferClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferClause"))
+    }
+}
 impl pg_sys::seal::Sealed for InferenceElem {}
 impl pg_sys::PgNode for InferenceElem {}
 impl std::fmt::Display for InferenceElem {
@@ -65527,6 +67205,12 @@ impl std::fmt::Display for InferenceElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferenceElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferenceElem"))
+    }
+}
 impl pg_sys::seal::Sealed for InlineCodeBlock {}
 impl pg_sys::PgNode for InlineCodeBlock {}
 impl std::fmt::Display for InlineCodeBlock {
@@ -65534,6 +67218,12 @@ impl std::fmt::Display for InlineCodeBlock {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InlineCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InlineCodeBlock"))
+    }
+}
 impl pg_sys::seal::Sealed for InsertStmt {}
 impl pg_sys::PgNode for InsertStmt {}
 impl std::fmt::Display for InsertStmt {
@@ -65541,6 +67231,12 @@ impl std::fmt::Display for InsertStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InsertStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InsertStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IntoClause {}
 impl pg_sys::PgNode for IntoClause {}
 impl std::fmt::Display for IntoClause {
@@ -65548,6 +67244,12 @@ impl std::fmt::Display for IntoClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IntoClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IntoClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Join {}
 impl pg_sys::PgNode for Join {}
 impl std::fmt::Display for Join {
@@ -65555,6 +67257,12 @@ impl std::fmt::Display for Join {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Join {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Join"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinExpr {}
 impl pg_sys::PgNode for JoinExpr {}
 impl std::fmt::Display for JoinExpr {
@@ -65562,6 +67270,12 @@ impl std::fmt::Display for JoinExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinPath {}
 impl pg_sys::PgNode for JoinPath {}
 impl std::fmt::Display for JoinPath {
@@ -65569,6 +67283,12 @@ impl std::fmt::Display for JoinPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinPath"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinState {}
 impl pg_sys::PgNode for JoinState {}
 impl std::fmt::Display for JoinState {
@@ -65576,6 +67296,12 @@ impl std::fmt::Display for JoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for JunkFilter {}
 impl pg_sys::PgNode for JunkFilter {}
 impl std::fmt::Display for JunkFilter {
@@ -65583,6 +67309,12 @@ impl std::fmt::Display for JunkFilter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JunkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JunkFilter"))
+    }
+}
 impl pg_sys::seal::Sealed for Limit {}
 impl pg_sys::PgNode for Limit {}
 impl std::fmt::Display for Limit {
@@ -65590,6 +67322,12 @@ impl std::fmt::Display for Limit {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Limit"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitPath {}
 impl pg_sys::PgNode for LimitPath {}
 impl std::fmt::Display for LimitPath {
@@ -65597,6 +67335,12 @@ impl std::fmt::Display for LimitPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitState {}
 impl pg_sys::PgNode for LimitState {}
 impl std::fmt::Display for LimitState {
@@ -65604,6 +67348,12 @@ impl std::fmt::Display for LimitState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitState"))
+    }
+}
 impl pg_sys::seal::Sealed for List {}
 impl pg_sys::PgNode for List {}
 impl std::fmt::Display for List {
@@ -65611,6 +67361,12 @@ impl std::fmt::Display for List {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "List"))
+    }
+}
 impl pg_sys::seal::Sealed for ListenStmt {}
 impl pg_sys::PgNode for ListenStmt {}
 impl std::fmt::Display for ListenStmt {
@@ -65618,6 +67374,12 @@ impl std::fmt::Display for ListenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ListenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ListenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LoadStmt {}
 impl pg_sys::PgNode for LoadStmt {}
 impl std::fmt::Display for LoadStmt {
@@ -65625,6 +67387,12 @@ impl std::fmt::Display for LoadStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LoadStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LoadStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRows {}
 impl pg_sys::PgNode for LockRows {}
 impl std::fmt::Display for LockRows {
@@ -65632,6 +67400,12 @@ impl std::fmt::Display for LockRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRows"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsPath {}
 impl pg_sys::PgNode for LockRowsPath {}
 impl std::fmt::Display for LockRowsPath {
@@ -65639,6 +67413,12 @@ impl std::fmt::Display for LockRowsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsState {}
 impl pg_sys::PgNode for LockRowsState {}
 impl std::fmt::Display for LockRowsState {
@@ -65646,6 +67426,12 @@ impl std::fmt::Display for LockRowsState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsState"))
+    }
+}
 impl pg_sys::seal::Sealed for LockStmt {}
 impl pg_sys::PgNode for LockStmt {}
 impl std::fmt::Display for LockStmt {
@@ -65653,6 +67439,12 @@ impl std::fmt::Display for LockStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockingClause {}
 impl pg_sys::PgNode for LockingClause {}
 impl std::fmt::Display for LockingClause {
@@ -65660,6 +67452,12 @@ impl std::fmt::Display for LockingClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockingClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockingClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Material {}
 impl pg_sys::PgNode for Material {}
 impl std::fmt::Display for Material {
@@ -65667,6 +67465,12 @@ impl std::fmt::Display for Material {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Material"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialPath {}
 impl pg_sys::PgNode for MaterialPath {}
 impl std::fmt::Display for MaterialPath {
@@ -65674,6 +67478,12 @@ impl std::fmt::Display for MaterialPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialState {}
 impl pg_sys::PgNode for MaterialState {}
 impl std::fmt::Display for MaterialState {
@@ -65681,6 +67491,12 @@ impl std::fmt::Display for MaterialState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialState"))
+    }
+}
 impl pg_sys::seal::Sealed for Memoize {}
 impl pg_sys::PgNode for Memoize {}
 impl std::fmt::Display for Memoize {
@@ -65688,6 +67504,12 @@ impl std::fmt::Display for Memoize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Memoize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Memoize"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoizePath {}
 impl pg_sys::PgNode for MemoizePath {}
 impl std::fmt::Display for MemoizePath {
@@ -65695,6 +67517,12 @@ impl std::fmt::Display for MemoizePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoizePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoizePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoizeState {}
 impl pg_sys::PgNode for MemoizeState {}
 impl std::fmt::Display for MemoizeState {
@@ -65702,6 +67530,12 @@ impl std::fmt::Display for MemoizeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoizeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoizeState"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoryContextData {}
 impl pg_sys::PgNode for MemoryContextData {}
 impl std::fmt::Display for MemoryContextData {
@@ -65709,6 +67543,12 @@ impl std::fmt::Display for MemoryContextData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoryContextData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoryContextData"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppend {}
 impl pg_sys::PgNode for MergeAppend {}
 impl std::fmt::Display for MergeAppend {
@@ -65716,6 +67556,12 @@ impl std::fmt::Display for MergeAppend {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppend"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendPath {}
 impl pg_sys::PgNode for MergeAppendPath {}
 impl std::fmt::Display for MergeAppendPath {
@@ -65723,6 +67569,12 @@ impl std::fmt::Display for MergeAppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendState {}
 impl pg_sys::PgNode for MergeAppendState {}
 impl std::fmt::Display for MergeAppendState {
@@ -65730,6 +67582,12 @@ impl std::fmt::Display for MergeAppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoin {}
 impl pg_sys::PgNode for MergeJoin {}
 impl std::fmt::Display for MergeJoin {
@@ -65737,6 +67595,12 @@ impl std::fmt::Display for MergeJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoinState {}
 impl pg_sys::PgNode for MergeJoinState {}
 impl std::fmt::Display for MergeJoinState {
@@ -65744,6 +67608,12 @@ impl std::fmt::Display for MergeJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergePath {}
 impl pg_sys::PgNode for MergePath {}
 impl std::fmt::Display for MergePath {
@@ -65751,6 +67621,12 @@ impl std::fmt::Display for MergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggInfo {}
 impl pg_sys::PgNode for MinMaxAggInfo {}
 impl std::fmt::Display for MinMaxAggInfo {
@@ -65758,6 +67634,12 @@ impl std::fmt::Display for MinMaxAggInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggPath {}
 impl pg_sys::PgNode for MinMaxAggPath {}
 impl std::fmt::Display for MinMaxAggPath {
@@ -65765,6 +67647,12 @@ impl std::fmt::Display for MinMaxAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxExpr {}
 impl pg_sys::PgNode for MinMaxExpr {}
 impl std::fmt::Display for MinMaxExpr {
@@ -65772,6 +67660,12 @@ impl std::fmt::Display for MinMaxExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for MinimalTupleTableSlot {}
 impl pg_sys::PgNode for MinimalTupleTableSlot {}
 impl std::fmt::Display for MinimalTupleTableSlot {
@@ -65779,6 +67673,12 @@ impl std::fmt::Display for MinimalTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinimalTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinimalTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTable {}
 impl pg_sys::PgNode for ModifyTable {}
 impl std::fmt::Display for ModifyTable {
@@ -65786,6 +67686,12 @@ impl std::fmt::Display for ModifyTable {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTable"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTablePath {}
 impl pg_sys::PgNode for ModifyTablePath {}
 impl std::fmt::Display for ModifyTablePath {
@@ -65793,6 +67699,12 @@ impl std::fmt::Display for ModifyTablePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTablePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTablePath"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTableState {}
 impl pg_sys::PgNode for ModifyTableState {}
 impl std::fmt::Display for ModifyTableState {
@@ -65800,6 +67712,12 @@ impl std::fmt::Display for ModifyTableState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTableState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTableState"))
+    }
+}
 impl pg_sys::seal::Sealed for MultiAssignRef {}
 impl pg_sys::PgNode for MultiAssignRef {}
 impl std::fmt::Display for MultiAssignRef {
@@ -65807,6 +67725,12 @@ impl std::fmt::Display for MultiAssignRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MultiAssignRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MultiAssignRef"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedArgExpr {}
 impl pg_sys::PgNode for NamedArgExpr {}
 impl std::fmt::Display for NamedArgExpr {
@@ -65814,6 +67738,12 @@ impl std::fmt::Display for NamedArgExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedArgExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedArgExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScan {}
 impl pg_sys::PgNode for NamedTuplestoreScan {}
 impl std::fmt::Display for NamedTuplestoreScan {
@@ -65821,6 +67751,12 @@ impl std::fmt::Display for NamedTuplestoreScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScan"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScanState {}
 impl pg_sys::PgNode for NamedTuplestoreScanState {}
 impl std::fmt::Display for NamedTuplestoreScanState {
@@ -65828,6 +67764,12 @@ impl std::fmt::Display for NamedTuplestoreScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoop {}
 impl pg_sys::PgNode for NestLoop {}
 impl std::fmt::Display for NestLoop {
@@ -65835,6 +67777,12 @@ impl std::fmt::Display for NestLoop {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoop"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopParam {}
 impl pg_sys::PgNode for NestLoopParam {}
 impl std::fmt::Display for NestLoopParam {
@@ -65842,6 +67790,12 @@ impl std::fmt::Display for NestLoopParam {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopParam"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopState {}
 impl pg_sys::PgNode for NestLoopState {}
 impl std::fmt::Display for NestLoopState {
@@ -65849,6 +67803,12 @@ impl std::fmt::Display for NestLoopState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopState"))
+    }
+}
 impl pg_sys::seal::Sealed for NextValueExpr {}
 impl pg_sys::PgNode for NextValueExpr {}
 impl std::fmt::Display for NextValueExpr {
@@ -65856,6 +67816,12 @@ impl std::fmt::Display for NextValueExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NextValueExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NextValueExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Node {}
 impl pg_sys::PgNode for Node {}
 impl std::fmt::Display for Node {
@@ -65863,6 +67829,12 @@ impl std::fmt::Display for Node {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Node"))
+    }
+}
 impl pg_sys::seal::Sealed for NotifyStmt {}
 impl pg_sys::PgNode for NotifyStmt {}
 impl std::fmt::Display for NotifyStmt {
@@ -65870,6 +67842,12 @@ impl std::fmt::Display for NotifyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NotifyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NotifyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for NullTest {}
 impl pg_sys::PgNode for NullTest {}
 impl std::fmt::Display for NullTest {
@@ -65877,6 +67855,12 @@ impl std::fmt::Display for NullTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NullTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NullTest"))
+    }
+}
 impl pg_sys::seal::Sealed for ObjectWithArgs {}
 impl pg_sys::PgNode for ObjectWithArgs {}
 impl std::fmt::Display for ObjectWithArgs {
@@ -65884,6 +67868,12 @@ impl std::fmt::Display for ObjectWithArgs {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ObjectWithArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ObjectWithArgs"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictClause {}
 impl pg_sys::PgNode for OnConflictClause {}
 impl std::fmt::Display for OnConflictClause {
@@ -65891,6 +67881,12 @@ impl std::fmt::Display for OnConflictClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictClause"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictExpr {}
 impl pg_sys::PgNode for OnConflictExpr {}
 impl std::fmt::Display for OnConflictExpr {
@@ -65898,6 +67894,12 @@ impl std::fmt::Display for OnConflictExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictSetState {}
 impl pg_sys::PgNode for OnConflictSetState {}
 impl std::fmt::Display for OnConflictSetState {
@@ -65905,6 +67907,12 @@ impl std::fmt::Display for OnConflictSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for OpExpr {}
 impl pg_sys::PgNode for OpExpr {}
 impl std::fmt::Display for OpExpr {
@@ -65912,6 +67920,12 @@ impl std::fmt::Display for OpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for PLAssignStmt {}
 impl pg_sys::PgNode for PLAssignStmt {}
 impl std::fmt::Display for PLAssignStmt {
@@ -65919,6 +67933,12 @@ impl std::fmt::Display for PLAssignStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PLAssignStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PLAssignStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Param {}
 impl pg_sys::PgNode for Param {}
 impl std::fmt::Display for Param {
@@ -65926,6 +67946,12 @@ impl std::fmt::Display for Param {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Param"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamPathInfo {}
 impl pg_sys::PgNode for ParamPathInfo {}
 impl std::fmt::Display for ParamPathInfo {
@@ -65933,6 +67959,12 @@ impl std::fmt::Display for ParamPathInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamPathInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamPathInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamRef {}
 impl pg_sys::PgNode for ParamRef {}
 impl std::fmt::Display for ParamRef {
@@ -65940,6 +67972,12 @@ impl std::fmt::Display for ParamRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamRef"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionBoundSpec {}
 impl pg_sys::PgNode for PartitionBoundSpec {}
 impl std::fmt::Display for PartitionBoundSpec {
@@ -65947,6 +67985,12 @@ impl std::fmt::Display for PartitionBoundSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionBoundSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionBoundSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionCmd {}
 impl pg_sys::PgNode for PartitionCmd {}
 impl std::fmt::Display for PartitionCmd {
@@ -65954,6 +67998,12 @@ impl std::fmt::Display for PartitionCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionElem {}
 impl pg_sys::PgNode for PartitionElem {}
 impl std::fmt::Display for PartitionElem {
@@ -65961,6 +68011,12 @@ impl std::fmt::Display for PartitionElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionElem"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneInfo {}
 impl pg_sys::PgNode for PartitionPruneInfo {}
 impl std::fmt::Display for PartitionPruneInfo {
@@ -65968,6 +68024,12 @@ impl std::fmt::Display for PartitionPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStep {}
 impl pg_sys::PgNode for PartitionPruneStep {}
 impl std::fmt::Display for PartitionPruneStep {
@@ -65975,6 +68037,12 @@ impl std::fmt::Display for PartitionPruneStep {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStep"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepCombine {}
 impl pg_sys::PgNode for PartitionPruneStepCombine {}
 impl std::fmt::Display for PartitionPruneStepCombine {
@@ -65982,6 +68050,12 @@ impl std::fmt::Display for PartitionPruneStepCombine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepCombine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepCombine"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepOp {}
 impl pg_sys::PgNode for PartitionPruneStepOp {}
 impl std::fmt::Display for PartitionPruneStepOp {
@@ -65989,6 +68063,12 @@ impl std::fmt::Display for PartitionPruneStepOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepOp"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionRangeDatum {}
 impl pg_sys::PgNode for PartitionRangeDatum {}
 impl std::fmt::Display for PartitionRangeDatum {
@@ -65996,6 +68076,12 @@ impl std::fmt::Display for PartitionRangeDatum {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionRangeDatum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionRangeDatum"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionSpec {}
 impl pg_sys::PgNode for PartitionSpec {}
 impl std::fmt::Display for PartitionSpec {
@@ -66003,6 +68089,12 @@ impl std::fmt::Display for PartitionSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionedRelPruneInfo {}
 impl pg_sys::PgNode for PartitionedRelPruneInfo {}
 impl std::fmt::Display for PartitionedRelPruneInfo {
@@ -66010,6 +68102,12 @@ impl std::fmt::Display for PartitionedRelPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionedRelPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionedRelPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Path {}
 impl pg_sys::PgNode for Path {}
 impl std::fmt::Display for Path {
@@ -66017,6 +68115,12 @@ impl std::fmt::Display for Path {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Path"))
+    }
+}
 impl pg_sys::seal::Sealed for PathKey {}
 impl pg_sys::PgNode for PathKey {}
 impl std::fmt::Display for PathKey {
@@ -66024,6 +68128,12 @@ impl std::fmt::Display for PathKey {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathKey"))
+    }
+}
 impl pg_sys::seal::Sealed for PathTarget {}
 impl pg_sys::PgNode for PathTarget {}
 impl std::fmt::Display for PathTarget {
@@ -66031,6 +68141,12 @@ impl std::fmt::Display for PathTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderInfo {}
 impl pg_sys::PgNode for PlaceHolderInfo {}
 impl std::fmt::Display for PlaceHolderInfo {
@@ -66038,6 +68154,12 @@ impl std::fmt::Display for PlaceHolderInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderVar {}
 impl pg_sys::PgNode for PlaceHolderVar {}
 impl std::fmt::Display for PlaceHolderVar {
@@ -66045,6 +68167,12 @@ impl std::fmt::Display for PlaceHolderVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderVar"))
+    }
+}
 impl pg_sys::seal::Sealed for Plan {}
 impl pg_sys::PgNode for Plan {}
 impl std::fmt::Display for Plan {
@@ -66052,6 +68180,12 @@ impl std::fmt::Display for Plan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Plan"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanInvalItem {}
 impl pg_sys::PgNode for PlanInvalItem {}
 impl std::fmt::Display for PlanInvalItem {
@@ -66059,6 +68193,12 @@ impl std::fmt::Display for PlanInvalItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanInvalItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanInvalItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanRowMark {}
 impl pg_sys::PgNode for PlanRowMark {}
 impl std::fmt::Display for PlanRowMark {
@@ -66066,6 +68206,12 @@ impl std::fmt::Display for PlanRowMark {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanRowMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanRowMark"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanState {}
 impl pg_sys::PgNode for PlanState {}
 impl std::fmt::Display for PlanState {
@@ -66073,6 +68219,12 @@ impl std::fmt::Display for PlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannedStmt {}
 impl pg_sys::PgNode for PlannedStmt {}
 impl std::fmt::Display for PlannedStmt {
@@ -66080,6 +68232,12 @@ impl std::fmt::Display for PlannedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerGlobal {}
 impl pg_sys::PgNode for PlannerGlobal {}
 impl std::fmt::Display for PlannerGlobal {
@@ -66087,6 +68245,12 @@ impl std::fmt::Display for PlannerGlobal {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerGlobal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerGlobal"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerInfo {}
 impl pg_sys::PgNode for PlannerInfo {}
 impl std::fmt::Display for PlannerInfo {
@@ -66094,6 +68258,12 @@ impl std::fmt::Display for PlannerInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerParamItem {}
 impl pg_sys::PgNode for PlannerParamItem {}
 impl std::fmt::Display for PlannerParamItem {
@@ -66101,6 +68271,12 @@ impl std::fmt::Display for PlannerParamItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerParamItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerParamItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PrepareStmt {}
 impl pg_sys::PgNode for PrepareStmt {}
 impl std::fmt::Display for PrepareStmt {
@@ -66108,6 +68284,12 @@ impl std::fmt::Display for PrepareStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PrepareStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PrepareStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSet {}
 impl pg_sys::PgNode for ProjectSet {}
 impl std::fmt::Display for ProjectSet {
@@ -66115,6 +68297,12 @@ impl std::fmt::Display for ProjectSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSet"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetPath {}
 impl pg_sys::PgNode for ProjectSetPath {}
 impl std::fmt::Display for ProjectSetPath {
@@ -66122,6 +68310,12 @@ impl std::fmt::Display for ProjectSetPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetState {}
 impl pg_sys::PgNode for ProjectSetState {}
 impl std::fmt::Display for ProjectSetState {
@@ -66129,6 +68323,12 @@ impl std::fmt::Display for ProjectSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionInfo {}
 impl pg_sys::PgNode for ProjectionInfo {}
 impl std::fmt::Display for ProjectionInfo {
@@ -66136,6 +68336,12 @@ impl std::fmt::Display for ProjectionInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionPath {}
 impl pg_sys::PgNode for ProjectionPath {}
 impl std::fmt::Display for ProjectionPath {
@@ -66143,6 +68349,12 @@ impl std::fmt::Display for ProjectionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Query {}
 impl pg_sys::PgNode for Query {}
 impl std::fmt::Display for Query {
@@ -66150,6 +68362,12 @@ impl std::fmt::Display for Query {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Query"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeFunction {}
 impl pg_sys::PgNode for RangeFunction {}
 impl std::fmt::Display for RangeFunction {
@@ -66157,6 +68375,12 @@ impl std::fmt::Display for RangeFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeSubselect {}
 impl pg_sys::PgNode for RangeSubselect {}
 impl std::fmt::Display for RangeSubselect {
@@ -66164,6 +68388,12 @@ impl std::fmt::Display for RangeSubselect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeSubselect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeSubselect"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFunc {}
 impl pg_sys::PgNode for RangeTableFunc {}
 impl std::fmt::Display for RangeTableFunc {
@@ -66171,6 +68401,12 @@ impl std::fmt::Display for RangeTableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFuncCol {}
 impl pg_sys::PgNode for RangeTableFuncCol {}
 impl std::fmt::Display for RangeTableFuncCol {
@@ -66178,6 +68414,12 @@ impl std::fmt::Display for RangeTableFuncCol {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFuncCol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFuncCol"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableSample {}
 impl pg_sys::PgNode for RangeTableSample {}
 impl std::fmt::Display for RangeTableSample {
@@ -66185,6 +68427,12 @@ impl std::fmt::Display for RangeTableSample {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableSample"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblEntry {}
 impl pg_sys::PgNode for RangeTblEntry {}
 impl std::fmt::Display for RangeTblEntry {
@@ -66192,6 +68440,12 @@ impl std::fmt::Display for RangeTblEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblFunction {}
 impl pg_sys::PgNode for RangeTblFunction {}
 impl std::fmt::Display for RangeTblFunction {
@@ -66199,6 +68453,12 @@ impl std::fmt::Display for RangeTblFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblRef {}
 impl pg_sys::PgNode for RangeTblRef {}
 impl std::fmt::Display for RangeTblRef {
@@ -66206,6 +68466,12 @@ impl std::fmt::Display for RangeTblRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblRef"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeVar {}
 impl pg_sys::PgNode for RangeVar {}
 impl std::fmt::Display for RangeVar {
@@ -66213,6 +68479,12 @@ impl std::fmt::Display for RangeVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeVar"))
+    }
+}
 impl pg_sys::seal::Sealed for RawStmt {}
 impl pg_sys::PgNode for RawStmt {}
 impl std::fmt::Display for RawStmt {
@@ -66220,6 +68492,12 @@ impl std::fmt::Display for RawStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RawStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RawStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReassignOwnedStmt {}
 impl pg_sys::PgNode for ReassignOwnedStmt {}
 impl std::fmt::Display for ReassignOwnedStmt {
@@ -66227,6 +68505,12 @@ impl std::fmt::Display for ReassignOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReassignOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReassignOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnion {}
 impl pg_sys::PgNode for RecursiveUnion {}
 impl std::fmt::Display for RecursiveUnion {
@@ -66234,6 +68518,12 @@ impl std::fmt::Display for RecursiveUnion {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnion"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionPath {}
 impl pg_sys::PgNode for RecursiveUnionPath {}
 impl std::fmt::Display for RecursiveUnionPath {
@@ -66241,6 +68531,12 @@ impl std::fmt::Display for RecursiveUnionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionState {}
 impl pg_sys::PgNode for RecursiveUnionState {}
 impl std::fmt::Display for RecursiveUnionState {
@@ -66248,6 +68544,12 @@ impl std::fmt::Display for RecursiveUnionState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionState"))
+    }
+}
 impl pg_sys::seal::Sealed for RefreshMatViewStmt {}
 impl pg_sys::PgNode for RefreshMatViewStmt {}
 impl std::fmt::Display for RefreshMatViewStmt {
@@ -66255,6 +68557,12 @@ impl std::fmt::Display for RefreshMatViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RefreshMatViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RefreshMatViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReindexStmt {}
 impl pg_sys::PgNode for ReindexStmt {}
 impl std::fmt::Display for ReindexStmt {
@@ -66262,6 +68570,12 @@ impl std::fmt::Display for ReindexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReindexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReindexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RelOptInfo {}
 impl pg_sys::PgNode for RelOptInfo {}
 impl std::fmt::Display for RelOptInfo {
@@ -66269,6 +68583,12 @@ impl std::fmt::Display for RelOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RelabelType {}
 impl pg_sys::PgNode for RelabelType {}
 impl std::fmt::Display for RelabelType {
@@ -66276,6 +68596,12 @@ impl std::fmt::Display for RelabelType {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelabelType"))
+    }
+}
 impl pg_sys::seal::Sealed for RenameStmt {}
 impl pg_sys::PgNode for RenameStmt {}
 impl std::fmt::Display for RenameStmt {
@@ -66283,6 +68609,12 @@ impl std::fmt::Display for RenameStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RenameStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RenameStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReplicaIdentityStmt {}
 impl pg_sys::PgNode for ReplicaIdentityStmt {}
 impl std::fmt::Display for ReplicaIdentityStmt {
@@ -66290,6 +68622,12 @@ impl std::fmt::Display for ReplicaIdentityStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReplicaIdentityStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReplicaIdentityStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ResTarget {}
 impl pg_sys::PgNode for ResTarget {}
 impl std::fmt::Display for ResTarget {
@@ -66297,6 +68635,12 @@ impl std::fmt::Display for ResTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for RestrictInfo {}
 impl pg_sys::PgNode for RestrictInfo {}
 impl std::fmt::Display for RestrictInfo {
@@ -66304,6 +68648,12 @@ impl std::fmt::Display for RestrictInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RestrictInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RestrictInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Result {}
 impl pg_sys::PgNode for Result {}
 impl std::fmt::Display for Result {
@@ -66311,6 +68661,12 @@ impl std::fmt::Display for Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Result"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultRelInfo {}
 impl pg_sys::PgNode for ResultRelInfo {}
 impl std::fmt::Display for ResultRelInfo {
@@ -66318,6 +68674,12 @@ impl std::fmt::Display for ResultRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultState {}
 impl pg_sys::PgNode for ResultState {}
 impl std::fmt::Display for ResultState {
@@ -66325,6 +68687,12 @@ impl std::fmt::Display for ResultState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultState"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnSetInfo {}
 impl pg_sys::PgNode for ReturnSetInfo {}
 impl std::fmt::Display for ReturnSetInfo {
@@ -66332,6 +68700,12 @@ impl std::fmt::Display for ReturnSetInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnSetInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnSetInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnStmt {}
 impl pg_sys::PgNode for ReturnStmt {}
 impl std::fmt::Display for ReturnStmt {
@@ -66339,6 +68713,12 @@ impl std::fmt::Display for ReturnStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RoleSpec {}
 impl pg_sys::PgNode for RoleSpec {}
 impl std::fmt::Display for RoleSpec {
@@ -66346,6 +68726,12 @@ impl std::fmt::Display for RoleSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RoleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RoleSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for RollupData {}
 impl pg_sys::PgNode for RollupData {}
 impl std::fmt::Display for RollupData {
@@ -66353,6 +68739,12 @@ impl std::fmt::Display for RollupData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RollupData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RollupData"))
+    }
+}
 impl pg_sys::seal::Sealed for RowCompareExpr {}
 impl pg_sys::PgNode for RowCompareExpr {}
 impl std::fmt::Display for RowCompareExpr {
@@ -66360,6 +68752,12 @@ impl std::fmt::Display for RowCompareExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowCompareExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowCompareExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowExpr {}
 impl pg_sys::PgNode for RowExpr {}
 impl std::fmt::Display for RowExpr {
@@ -66367,6 +68765,12 @@ impl std::fmt::Display for RowExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowIdentityVarInfo {}
 impl pg_sys::PgNode for RowIdentityVarInfo {}
 impl std::fmt::Display for RowIdentityVarInfo {
@@ -66374,6 +68778,12 @@ impl std::fmt::Display for RowIdentityVarInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowIdentityVarInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowIdentityVarInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RowMarkClause {}
 impl pg_sys::PgNode for RowMarkClause {}
 impl std::fmt::Display for RowMarkClause {
@@ -66381,6 +68791,12 @@ impl std::fmt::Display for RowMarkClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowMarkClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowMarkClause"))
+    }
+}
 impl pg_sys::seal::Sealed for RuleStmt {}
 impl pg_sys::PgNode for RuleStmt {}
 impl std::fmt::Display for RuleStmt {
@@ -66388,6 +68804,12 @@ impl std::fmt::Display for RuleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RuleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RuleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLCmd {}
 impl pg_sys::PgNode for SQLCmd {}
 impl std::fmt::Display for SQLCmd {
@@ -66395,6 +68817,12 @@ impl std::fmt::Display for SQLCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLValueFunction {}
 impl pg_sys::PgNode for SQLValueFunction {}
 impl std::fmt::Display for SQLValueFunction {
@@ -66402,6 +68830,12 @@ impl std::fmt::Display for SQLValueFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLValueFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLValueFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScan {}
 impl pg_sys::PgNode for SampleScan {}
 impl std::fmt::Display for SampleScan {
@@ -66409,6 +68843,12 @@ impl std::fmt::Display for SampleScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScanState {}
 impl pg_sys::PgNode for SampleScanState {}
 impl std::fmt::Display for SampleScanState {
@@ -66416,6 +68856,12 @@ impl std::fmt::Display for SampleScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for ScalarArrayOpExpr {}
 impl pg_sys::PgNode for ScalarArrayOpExpr {}
 impl std::fmt::Display for ScalarArrayOpExpr {
@@ -66423,6 +68869,12 @@ impl std::fmt::Display for ScalarArrayOpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScalarArrayOpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScalarArrayOpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Scan {}
 impl pg_sys::PgNode for Scan {}
 impl std::fmt::Display for Scan {
@@ -66430,6 +68882,12 @@ impl std::fmt::Display for Scan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Scan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Scan"))
+    }
+}
 impl pg_sys::seal::Sealed for ScanState {}
 impl pg_sys::PgNode for ScanState {}
 impl std::fmt::Display for ScanState {
@@ -66437,6 +68895,12 @@ impl std::fmt::Display for ScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SecLabelStmt {}
 impl pg_sys::PgNode for SecLabelStmt {}
 impl std::fmt::Display for SecLabelStmt {
@@ -66444,6 +68908,12 @@ impl std::fmt::Display for SecLabelStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SecLabelStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SecLabelStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SelectStmt {}
 impl pg_sys::PgNode for SelectStmt {}
 impl std::fmt::Display for SelectStmt {
@@ -66451,6 +68921,12 @@ impl std::fmt::Display for SelectStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SelectStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SelectStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SeqScanState {}
 impl pg_sys::PgNode for SeqScanState {}
 impl std::fmt::Display for SeqScanState {
@@ -66458,6 +68934,12 @@ impl std::fmt::Display for SeqScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SeqScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SeqScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetExprState {}
 impl pg_sys::PgNode for SetExprState {}
 impl std::fmt::Display for SetExprState {
@@ -66465,6 +68947,12 @@ impl std::fmt::Display for SetExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOp {}
 impl pg_sys::PgNode for SetOp {}
 impl std::fmt::Display for SetOp {
@@ -66472,6 +68960,12 @@ impl std::fmt::Display for SetOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOp"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpPath {}
 impl pg_sys::PgNode for SetOpPath {}
 impl std::fmt::Display for SetOpPath {
@@ -66479,6 +68973,12 @@ impl std::fmt::Display for SetOpPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpState {}
 impl pg_sys::PgNode for SetOpState {}
 impl std::fmt::Display for SetOpState {
@@ -66486,6 +68986,12 @@ impl std::fmt::Display for SetOpState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOperationStmt {}
 impl pg_sys::PgNode for SetOperationStmt {}
 impl std::fmt::Display for SetOperationStmt {
@@ -66493,6 +68999,12 @@ impl std::fmt::Display for SetOperationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOperationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOperationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SetToDefault {}
 impl pg_sys::PgNode for SetToDefault {}
 impl std::fmt::Display for SetToDefault {
@@ -66500,6 +69012,12 @@ impl std::fmt::Display for SetToDefault {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetToDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetToDefault"))
+    }
+}
 impl pg_sys::seal::Sealed for Sort {}
 impl pg_sys::PgNode for Sort {}
 impl std::fmt::Display for Sort {
@@ -66507,6 +69025,12 @@ impl std::fmt::Display for Sort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Sort"))
+    }
+}
 impl pg_sys::seal::Sealed for SortBy {}
 impl pg_sys::PgNode for SortBy {}
 impl std::fmt::Display for SortBy {
@@ -66514,6 +69038,12 @@ impl std::fmt::Display for SortBy {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortBy"))
+    }
+}
 impl pg_sys::seal::Sealed for SortGroupClause {}
 impl pg_sys::PgNode for SortGroupClause {}
 impl std::fmt::Display for SortGroupClause {
@@ -66521,6 +69051,12 @@ impl std::fmt::Display for SortGroupClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortGroupClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortGroupClause"))
+    }
+}
 impl pg_sys::seal::Sealed for SortPath {}
 impl pg_sys::PgNode for SortPath {}
 impl std::fmt::Display for SortPath {
@@ -66528,6 +69064,12 @@ impl std::fmt::Display for SortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SortState {}
 impl pg_sys::PgNode for SortState {}
 impl std::fmt::Display for SortState {
@@ -66535,6 +69077,12 @@ impl std::fmt::Display for SortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortState"))
+    }
+}
 impl pg_sys::seal::Sealed for SpecialJoinInfo {}
 impl pg_sys::PgNode for SpecialJoinInfo {}
 impl std::fmt::Display for SpecialJoinInfo {
@@ -66542,6 +69090,12 @@ impl std::fmt::Display for SpecialJoinInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SpecialJoinInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SpecialJoinInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StartReplicationCmd {}
 impl pg_sys::PgNode for StartReplicationCmd {}
 impl std::fmt::Display for StartReplicationCmd {
@@ -66549,6 +69103,12 @@ impl std::fmt::Display for StartReplicationCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StartReplicationCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StartReplicationCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for StatisticExtInfo {}
 impl pg_sys::PgNode for StatisticExtInfo {}
 impl std::fmt::Display for StatisticExtInfo {
@@ -66556,6 +69116,12 @@ impl std::fmt::Display for StatisticExtInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatisticExtInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatisticExtInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StatsElem {}
 impl pg_sys::PgNode for StatsElem {}
 impl std::fmt::Display for StatsElem {
@@ -66563,6 +69129,12 @@ impl std::fmt::Display for StatsElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatsElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatsElem"))
+    }
+}
 impl pg_sys::seal::Sealed for SubLink {}
 impl pg_sys::PgNode for SubLink {}
 impl std::fmt::Display for SubLink {
@@ -66570,6 +69142,12 @@ impl std::fmt::Display for SubLink {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubLink"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlan {}
 impl pg_sys::PgNode for SubPlan {}
 impl std::fmt::Display for SubPlan {
@@ -66577,6 +69155,12 @@ impl std::fmt::Display for SubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlanState {}
 impl pg_sys::PgNode for SubPlanState {}
 impl std::fmt::Display for SubPlanState {
@@ -66584,6 +69168,12 @@ impl std::fmt::Display for SubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScan {}
 impl pg_sys::PgNode for SubqueryScan {}
 impl std::fmt::Display for SubqueryScan {
@@ -66591,6 +69181,12 @@ impl std::fmt::Display for SubqueryScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanPath {}
 impl pg_sys::PgNode for SubqueryScanPath {}
 impl std::fmt::Display for SubqueryScanPath {
@@ -66598,6 +69194,12 @@ impl std::fmt::Display for SubqueryScanPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanState {}
 impl pg_sys::PgNode for SubqueryScanState {}
 impl std::fmt::Display for SubqueryScanState {
@@ -66605,6 +69207,12 @@ impl std::fmt::Display for SubqueryScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubscriptingRef {}
 impl pg_sys::PgNode for SubscriptingRef {}
 impl std::fmt::Display for SubscriptingRef {
@@ -66612,6 +69220,12 @@ impl std::fmt::Display for SubscriptingRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubscriptingRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubscriptingRef"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestCost {}
 impl pg_sys::PgNode for SupportRequestCost {}
 impl std::fmt::Display for SupportRequestCost {
@@ -66619,6 +69233,12 @@ impl std::fmt::Display for SupportRequestCost {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestCost"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestIndexCondition {}
 impl pg_sys::PgNode for SupportRequestIndexCondition {}
 impl std::fmt::Display for SupportRequestIndexCondition {
@@ -66626,6 +69246,12 @@ impl std::fmt::Display for SupportRequestIndexCondition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestIndexCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestIndexCondition"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestRows {}
 impl pg_sys::PgNode for SupportRequestRows {}
 impl std::fmt::Display for SupportRequestRows {
@@ -66633,6 +69259,12 @@ impl std::fmt::Display for SupportRequestRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestRows"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSelectivity {}
 impl pg_sys::PgNode for SupportRequestSelectivity {}
 impl std::fmt::Display for SupportRequestSelectivity {
@@ -66640,6 +69272,12 @@ impl std::fmt::Display for SupportRequestSelectivity {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSelectivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSelectivity"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSimplify {}
 impl pg_sys::PgNode for SupportRequestSimplify {}
 impl std::fmt::Display for SupportRequestSimplify {
@@ -66647,6 +69285,12 @@ impl std::fmt::Display for SupportRequestSimplify {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSimplify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSimplify"))
+    }
+}
 impl pg_sys::seal::Sealed for TableAmRoutine {}
 impl pg_sys::PgNode for TableAmRoutine {}
 impl std::fmt::Display for TableAmRoutine {
@@ -66654,6 +69298,12 @@ impl std::fmt::Display for TableAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFunc {}
 impl pg_sys::PgNode for TableFunc {}
 impl std::fmt::Display for TableFunc {
@@ -66661,6 +69311,12 @@ impl std::fmt::Display for TableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScan {}
 impl pg_sys::PgNode for TableFuncScan {}
 impl std::fmt::Display for TableFuncScan {
@@ -66668,6 +69324,12 @@ impl std::fmt::Display for TableFuncScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScanState {}
 impl pg_sys::PgNode for TableFuncScanState {}
 impl std::fmt::Display for TableFuncScanState {
@@ -66675,6 +69337,12 @@ impl std::fmt::Display for TableFuncScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TableLikeClause {}
 impl pg_sys::PgNode for TableLikeClause {}
 impl std::fmt::Display for TableLikeClause {
@@ -66682,6 +69350,12 @@ impl std::fmt::Display for TableLikeClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableLikeClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableLikeClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TableSampleClause {}
 impl pg_sys::PgNode for TableSampleClause {}
 impl std::fmt::Display for TableSampleClause {
@@ -66689,6 +69363,12 @@ impl std::fmt::Display for TableSampleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableSampleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableSampleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TargetEntry {}
 impl pg_sys::PgNode for TargetEntry {}
 impl std::fmt::Display for TargetEntry {
@@ -66696,6 +69376,12 @@ impl std::fmt::Display for TargetEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TargetEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TargetEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for TidPath {}
 impl pg_sys::PgNode for TidPath {}
 impl std::fmt::Display for TidPath {
@@ -66703,6 +69389,12 @@ impl std::fmt::Display for TidPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidPath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidRangePath {}
 impl pg_sys::PgNode for TidRangePath {}
 impl std::fmt::Display for TidRangePath {
@@ -66710,6 +69402,12 @@ impl std::fmt::Display for TidRangePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidRangePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidRangePath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidRangeScan {}
 impl pg_sys::PgNode for TidRangeScan {}
 impl std::fmt::Display for TidRangeScan {
@@ -66717,6 +69415,12 @@ impl std::fmt::Display for TidRangeScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidRangeScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidRangeScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidRangeScanState {}
 impl pg_sys::PgNode for TidRangeScanState {}
 impl std::fmt::Display for TidRangeScanState {
@@ -66724,6 +69428,12 @@ impl std::fmt::Display for TidRangeScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidRangeScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidRangeScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScan {}
 impl pg_sys::PgNode for TidScan {}
 impl std::fmt::Display for TidScan {
@@ -66731,6 +69441,12 @@ impl std::fmt::Display for TidScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScanState {}
 impl pg_sys::PgNode for TidScanState {}
 impl std::fmt::Display for TidScanState {
@@ -66738,6 +69454,12 @@ impl std::fmt::Display for TidScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TimeLineHistoryCmd {}
 impl pg_sys::PgNode for TimeLineHistoryCmd {}
 impl std::fmt::Display for TimeLineHistoryCmd {
@@ -66745,6 +69467,12 @@ impl std::fmt::Display for TimeLineHistoryCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TimeLineHistoryCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TimeLineHistoryCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for TransactionStmt {}
 impl pg_sys::PgNode for TransactionStmt {}
 impl std::fmt::Display for TransactionStmt {
@@ -66752,6 +69480,12 @@ impl std::fmt::Display for TransactionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TransactionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TransactionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerData {}
 impl pg_sys::PgNode for TriggerData {}
 impl std::fmt::Display for TriggerData {
@@ -66759,6 +69493,12 @@ impl std::fmt::Display for TriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerTransition {}
 impl pg_sys::PgNode for TriggerTransition {}
 impl std::fmt::Display for TriggerTransition {
@@ -66766,6 +69506,12 @@ impl std::fmt::Display for TriggerTransition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerTransition"))
+    }
+}
 impl pg_sys::seal::Sealed for TruncateStmt {}
 impl pg_sys::PgNode for TruncateStmt {}
 impl std::fmt::Display for TruncateStmt {
@@ -66773,6 +69519,12 @@ impl std::fmt::Display for TruncateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TruncateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TruncateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TupleTableSlot {}
 impl pg_sys::PgNode for TupleTableSlot {}
 impl std::fmt::Display for TupleTableSlot {
@@ -66780,6 +69532,12 @@ impl std::fmt::Display for TupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeCast {}
 impl pg_sys::PgNode for TypeCast {}
 impl std::fmt::Display for TypeCast {
@@ -66787,6 +69545,12 @@ impl std::fmt::Display for TypeCast {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeCast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeCast"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeName {}
 impl pg_sys::PgNode for TypeName {}
 impl std::fmt::Display for TypeName {
@@ -66794,6 +69558,12 @@ impl std::fmt::Display for TypeName {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeName"))
+    }
+}
 impl pg_sys::seal::Sealed for Unique {}
 impl pg_sys::PgNode for Unique {}
 impl std::fmt::Display for Unique {
@@ -66801,6 +69571,12 @@ impl std::fmt::Display for Unique {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Unique"))
+    }
+}
 impl pg_sys::seal::Sealed for UniquePath {}
 impl pg_sys::PgNode for UniquePath {}
 impl std::fmt::Display for UniquePath {
@@ -66808,6 +69584,12 @@ impl std::fmt::Display for UniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for UniqueState {}
 impl pg_sys::PgNode for UniqueState {}
 impl std::fmt::Display for UniqueState {
@@ -66815,6 +69597,12 @@ impl std::fmt::Display for UniqueState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniqueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniqueState"))
+    }
+}
 impl pg_sys::seal::Sealed for UnlistenStmt {}
 impl pg_sys::PgNode for UnlistenStmt {}
 impl std::fmt::Display for UnlistenStmt {
@@ -66822,6 +69610,12 @@ impl std::fmt::Display for UnlistenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UnlistenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UnlistenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpdateStmt {}
 impl pg_sys::PgNode for UpdateStmt {}
 impl std::fmt::Display for UpdateStmt {
@@ -66829,6 +69623,12 @@ impl std::fmt::Display for UpdateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpdateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpdateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpperUniquePath {}
 impl pg_sys::PgNode for UpperUniquePath {}
 impl std::fmt::Display for UpperUniquePath {
@@ -66836,6 +69636,12 @@ impl std::fmt::Display for UpperUniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpperUniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpperUniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumRelation {}
 impl pg_sys::PgNode for VacuumRelation {}
 impl std::fmt::Display for VacuumRelation {
@@ -66843,6 +69649,12 @@ impl std::fmt::Display for VacuumRelation {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumRelation"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumStmt {}
 impl pg_sys::PgNode for VacuumStmt {}
 impl std::fmt::Display for VacuumStmt {
@@ -66850,6 +69662,12 @@ impl std::fmt::Display for VacuumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Value {}
 impl pg_sys::PgNode for Value {}
 impl std::fmt::Display for Value {
@@ -66857,6 +69675,12 @@ impl std::fmt::Display for Value {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Value"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScan {}
 impl pg_sys::PgNode for ValuesScan {}
 impl std::fmt::Display for ValuesScan {
@@ -66864,6 +69688,12 @@ impl std::fmt::Display for ValuesScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScanState {}
 impl pg_sys::PgNode for ValuesScanState {}
 impl std::fmt::Display for ValuesScanState {
@@ -66871,6 +69701,12 @@ impl std::fmt::Display for ValuesScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Var {}
 impl pg_sys::PgNode for Var {}
 impl std::fmt::Display for Var {
@@ -66878,6 +69714,12 @@ impl std::fmt::Display for Var {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Var"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableSetStmt {}
 impl pg_sys::PgNode for VariableSetStmt {}
 impl std::fmt::Display for VariableSetStmt {
@@ -66885,6 +69727,12 @@ impl std::fmt::Display for VariableSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableShowStmt {}
 impl pg_sys::PgNode for VariableShowStmt {}
 impl std::fmt::Display for VariableShowStmt {
@@ -66892,6 +69740,12 @@ impl std::fmt::Display for VariableShowStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableShowStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableShowStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ViewStmt {}
 impl pg_sys::PgNode for ViewStmt {}
 impl std::fmt::Display for ViewStmt {
@@ -66899,6 +69753,12 @@ impl std::fmt::Display for ViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VirtualTupleTableSlot {}
 impl pg_sys::PgNode for VirtualTupleTableSlot {}
 impl std::fmt::Display for VirtualTupleTableSlot {
@@ -66906,6 +69766,12 @@ impl std::fmt::Display for VirtualTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VirtualTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VirtualTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAgg {}
 impl pg_sys::PgNode for WindowAgg {}
 impl std::fmt::Display for WindowAgg {
@@ -66913,6 +69779,12 @@ impl std::fmt::Display for WindowAgg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAgg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAgg"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggPath {}
 impl pg_sys::PgNode for WindowAggPath {}
 impl std::fmt::Display for WindowAggPath {
@@ -66920,6 +69792,12 @@ impl std::fmt::Display for WindowAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggState {}
 impl pg_sys::PgNode for WindowAggState {}
 impl std::fmt::Display for WindowAggState {
@@ -66927,6 +69805,12 @@ impl std::fmt::Display for WindowAggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggState"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowClause {}
 impl pg_sys::PgNode for WindowClause {}
 impl std::fmt::Display for WindowClause {
@@ -66934,6 +69818,12 @@ impl std::fmt::Display for WindowClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowDef {}
 impl pg_sys::PgNode for WindowDef {}
 impl std::fmt::Display for WindowDef {
@@ -66941,6 +69831,12 @@ impl std::fmt::Display for WindowDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowDef"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFunc {}
 impl pg_sys::PgNode for WindowFunc {}
 impl std::fmt::Display for WindowFunc {
@@ -66948,6 +69844,12 @@ impl std::fmt::Display for WindowFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFuncExprState {}
 impl pg_sys::PgNode for WindowFuncExprState {}
 impl std::fmt::Display for WindowFuncExprState {
@@ -66955,6 +69857,12 @@ impl std::fmt::Display for WindowFuncExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFuncExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFuncExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for WithCheckOption {}
 impl pg_sys::PgNode for WithCheckOption {}
 impl std::fmt::Display for WithCheckOption {
@@ -66962,6 +69870,12 @@ impl std::fmt::Display for WithCheckOption {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithCheckOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithCheckOption"))
+    }
+}
 impl pg_sys::seal::Sealed for WithClause {}
 impl pg_sys::PgNode for WithClause {}
 impl std::fmt::Display for WithClause {
@@ -66969,6 +69883,12 @@ impl std::fmt::Display for WithClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScan {}
 impl pg_sys::PgNode for WorkTableScan {}
 impl std::fmt::Display for WorkTableScan {
@@ -66976,6 +69896,12 @@ impl std::fmt::Display for WorkTableScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScan"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScanState {}
 impl pg_sys::PgNode for WorkTableScanState {}
 impl std::fmt::Display for WorkTableScanState {
@@ -66983,6 +69909,12 @@ impl std::fmt::Display for WorkTableScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlExpr {}
 impl pg_sys::PgNode for XmlExpr {}
 impl std::fmt::Display for XmlExpr {
@@ -66990,6 +69922,12 @@ impl std::fmt::Display for XmlExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlSerialize {}
 impl pg_sys::PgNode for XmlSerialize {}
 impl std::fmt::Display for XmlSerialize {
@@ -66997,3 +69935,9 @@ impl std::fmt::Display for XmlSerialize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlSerialize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlSerialize"))
+    }
+}
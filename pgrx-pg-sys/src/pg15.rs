@@ -6597,7 +6597,8 @@ pub const NodeTag_T_SupportRequestIndexCondition: NodeTag = 436;
 pub const NodeTag_T_SupportRequestWFuncMonotonic: NodeTag = 437;
 pub type NodeTag = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Node {
     pub type_: NodeTag,
 }
@@ -7986,7 +7987,8 @@ pub struct BufferAccessStrategyData {
 }
 pub type BufferAccessStrategy = *mut BufferAccessStrategyData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TupleTableSlot {
     pub type_: NodeTag,
     pub tts_flags: uint16,
@@ -8051,7 +8053,8 @@ extern "C" {
     pub static TTSOpsBufferHeapTuple: TupleTableSlotOps;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VirtualTupleTableSlot {
     pub base: TupleTableSlot,
     pub data: *mut ::std::os::raw::c_char,
@@ -8066,7 +8069,8 @@ impl Default for VirtualTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HeapTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -8083,7 +8087,8 @@ impl Default for HeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BufferHeapTupleTableSlot {
     pub base: HeapTupleTableSlot,
     pub buffer: Buffer,
@@ -8098,7 +8103,8 @@ impl Default for BufferHeapTupleTableSlot {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinimalTupleTableSlot {
     pub base: TupleTableSlot,
     pub tuple: HeapTuple,
@@ -9635,7 +9641,8 @@ pub const LockTupleMode_LockTupleNoKeyExclusive: LockTupleMode = 2;
 pub const LockTupleMode_LockTupleExclusive: LockTupleMode = 3;
 pub type LockTupleMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Alias {
     pub type_: NodeTag,
     pub aliasname: *mut ::std::os::raw::c_char,
@@ -9656,7 +9663,8 @@ pub const OnCommitAction_ONCOMMIT_DELETE_ROWS: OnCommitAction = 2;
 pub const OnCommitAction_ONCOMMIT_DROP: OnCommitAction = 3;
 pub type OnCommitAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeVar {
     pub type_: NodeTag,
     pub catalogname: *mut ::std::os::raw::c_char,
@@ -9677,7 +9685,8 @@ impl Default for RangeVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFunc {
     pub type_: NodeTag,
     pub ns_uris: *mut List,
@@ -9704,7 +9713,8 @@ impl Default for TableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IntoClause {
     pub type_: NodeTag,
     pub rel: *mut RangeVar,
@@ -9726,7 +9736,8 @@ impl Default for IntoClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Expr {
     pub type_: NodeTag,
 }
@@ -9740,7 +9751,8 @@ impl Default for Expr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Var {
     pub xpr: Expr,
     pub varno: ::std::os::raw::c_int,
@@ -9763,7 +9775,8 @@ impl Default for Var {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Const {
     pub xpr: Expr,
     pub consttype: Oid,
@@ -9790,7 +9803,8 @@ pub const ParamKind_PARAM_SUBLINK: ParamKind = 2;
 pub const ParamKind_PARAM_MULTIEXPR: ParamKind = 3;
 pub type ParamKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Param {
     pub xpr: Expr,
     pub paramkind: ParamKind,
@@ -9810,7 +9824,8 @@ impl Default for Param {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Aggref {
     pub xpr: Expr,
     pub aggfnoid: Oid,
@@ -9843,7 +9858,8 @@ impl Default for Aggref {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingFunc {
     pub xpr: Expr,
     pub args: *mut List,
@@ -9862,7 +9878,8 @@ impl Default for GroupingFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFunc {
     pub xpr: Expr,
     pub winfnoid: Oid,
@@ -9886,7 +9903,8 @@ impl Default for WindowFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubscriptingRef {
     pub xpr: Expr,
     pub refcontainertype: Oid,
@@ -9919,7 +9937,8 @@ pub const CoercionForm_COERCE_IMPLICIT_CAST: CoercionForm = 2;
 pub const CoercionForm_COERCE_SQL_SYNTAX: CoercionForm = 3;
 pub type CoercionForm = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncExpr {
     pub xpr: Expr,
     pub funcid: Oid,
@@ -9942,7 +9961,8 @@ impl Default for FuncExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedArgExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -9960,7 +9980,8 @@ impl Default for NamedArgExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -9984,7 +10005,8 @@ impl Default for OpExpr {
 pub type DistinctExpr = OpExpr;
 pub type NullIfExpr = OpExpr;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScalarArrayOpExpr {
     pub xpr: Expr,
     pub opno: Oid,
@@ -10010,7 +10032,8 @@ pub const BoolExprType_OR_EXPR: BoolExprType = 1;
 pub const BoolExprType_NOT_EXPR: BoolExprType = 2;
 pub type BoolExprType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BoolExpr {
     pub xpr: Expr,
     pub boolop: BoolExprType,
@@ -10036,7 +10059,8 @@ pub const SubLinkType_ARRAY_SUBLINK: SubLinkType = 6;
 pub const SubLinkType_CTE_SUBLINK: SubLinkType = 7;
 pub type SubLinkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubLink {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10056,7 +10080,8 @@ impl Default for SubLink {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlan {
     pub xpr: Expr,
     pub subLinkType: SubLinkType,
@@ -10086,7 +10111,8 @@ impl Default for SubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlternativeSubPlan {
     pub xpr: Expr,
     pub subplans: *mut List,
@@ -10101,7 +10127,8 @@ impl Default for AlternativeSubPlan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldSelect {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10120,7 +10147,8 @@ impl Default for FieldSelect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FieldStore {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10138,7 +10166,8 @@ impl Default for FieldStore {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelabelType {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10158,7 +10187,8 @@ impl Default for RelabelType {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceViaIO {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10177,7 +10207,8 @@ impl Default for CoerceViaIO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayCoerceExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10198,7 +10229,8 @@ impl Default for ArrayCoerceExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConvertRowtypeExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10216,7 +10248,8 @@ impl Default for ConvertRowtypeExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateExpr {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10233,7 +10266,8 @@ impl Default for CollateExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseExpr {
     pub xpr: Expr,
     pub casetype: Oid,
@@ -10253,7 +10287,8 @@ impl Default for CaseExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseWhen {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10270,7 +10305,8 @@ impl Default for CaseWhen {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CaseTestExpr {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10287,7 +10323,8 @@ impl Default for CaseTestExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ArrayExpr {
     pub xpr: Expr,
     pub array_typeid: Oid,
@@ -10307,7 +10344,8 @@ impl Default for ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowExpr {
     pub xpr: Expr,
     pub args: *mut List,
@@ -10333,7 +10371,8 @@ pub const RowCompareType_ROWCOMPARE_GT: RowCompareType = 5;
 pub const RowCompareType_ROWCOMPARE_NE: RowCompareType = 6;
 pub type RowCompareType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowCompareExpr {
     pub xpr: Expr,
     pub rctype: RowCompareType,
@@ -10353,7 +10392,8 @@ impl Default for RowCompareExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoalesceExpr {
     pub xpr: Expr,
     pub coalescetype: Oid,
@@ -10374,7 +10414,8 @@ pub const MinMaxOp_IS_GREATEST: MinMaxOp = 0;
 pub const MinMaxOp_IS_LEAST: MinMaxOp = 1;
 pub type MinMaxOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxExpr {
     pub xpr: Expr,
     pub minmaxtype: Oid,
@@ -10410,7 +10451,8 @@ pub const SQLValueFunctionOp_SVFOP_CURRENT_CATALOG: SQLValueFunctionOp = 13;
 pub const SQLValueFunctionOp_SVFOP_CURRENT_SCHEMA: SQLValueFunctionOp = 14;
 pub type SQLValueFunctionOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SQLValueFunction {
     pub xpr: Expr,
     pub op: SQLValueFunctionOp,
@@ -10440,7 +10482,8 @@ pub const XmlOptionType_XMLOPTION_DOCUMENT: XmlOptionType = 0;
 pub const XmlOptionType_XMLOPTION_CONTENT: XmlOptionType = 1;
 pub type XmlOptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlExpr {
     pub xpr: Expr,
     pub op: XmlExprOp,
@@ -10466,7 +10509,8 @@ pub const NullTestType_IS_NULL: NullTestType = 0;
 pub const NullTestType_IS_NOT_NULL: NullTestType = 1;
 pub type NullTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NullTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10491,7 +10535,8 @@ pub const BoolTestType_IS_UNKNOWN: BoolTestType = 4;
 pub const BoolTestType_IS_NOT_UNKNOWN: BoolTestType = 5;
 pub type BoolTestType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BooleanTest {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10508,7 +10553,8 @@ impl Default for BooleanTest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomain {
     pub xpr: Expr,
     pub arg: *mut Expr,
@@ -10528,7 +10574,8 @@ impl Default for CoerceToDomain {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CoerceToDomainValue {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10546,7 +10593,8 @@ impl Default for CoerceToDomainValue {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetToDefault {
     pub xpr: Expr,
     pub typeId: Oid,
@@ -10564,7 +10612,8 @@ impl Default for SetToDefault {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CurrentOfExpr {
     pub xpr: Expr,
     pub cvarno: Index,
@@ -10581,7 +10630,8 @@ impl Default for CurrentOfExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NextValueExpr {
     pub xpr: Expr,
     pub seqid: Oid,
@@ -10597,7 +10647,8 @@ impl Default for NextValueExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferenceElem {
     pub xpr: Expr,
     pub expr: *mut Node,
@@ -10614,7 +10665,8 @@ impl Default for InferenceElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TargetEntry {
     pub xpr: Expr,
     pub expr: *mut Expr,
@@ -10635,7 +10687,8 @@ impl Default for TargetEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblRef {
     pub type_: NodeTag,
     pub rtindex: ::std::os::raw::c_int,
@@ -10650,7 +10703,8 @@ impl Default for RangeTblRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinExpr {
     pub type_: NodeTag,
     pub jointype: JoinType,
@@ -10673,7 +10727,8 @@ impl Default for JoinExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FromExpr {
     pub type_: NodeTag,
     pub fromlist: *mut List,
@@ -10689,7 +10744,8 @@ impl Default for FromExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictExpr {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -10711,7 +10767,8 @@ impl Default for OnConflictExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Integer {
     pub type_: NodeTag,
     pub ival: ::std::os::raw::c_int,
@@ -10726,7 +10783,8 @@ impl Default for Integer {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Float {
     pub type_: NodeTag,
     pub fval: *mut ::std::os::raw::c_char,
@@ -10741,7 +10799,8 @@ impl Default for Float {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Boolean {
     pub type_: NodeTag,
     pub boolval: bool,
@@ -10756,7 +10815,8 @@ impl Default for Boolean {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct String {
     pub type_: NodeTag,
     pub sval: *mut ::std::os::raw::c_char,
@@ -10771,7 +10831,8 @@ impl Default for String {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitString {
     pub type_: NodeTag,
     pub bsval: *mut ::std::os::raw::c_char,
@@ -10855,7 +10916,8 @@ pub type SetQuantifier = ::std::os::raw::c_uint;
 pub type AclMode = uint32;
 #[doc = "\tQuery Tree"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Query {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -10911,7 +10973,8 @@ impl Default for Query {
 }
 #[doc = "\tSupporting data structures for Parse Trees\n\n\tMost of these node types appear in raw parsetrees output by the grammar,\n\tand get transformed to something else by the analyzer.  A few of them\n\tare used as-is in transformed querytrees."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeName {
     pub type_: NodeTag,
     pub names: *mut List,
@@ -10933,7 +10996,8 @@ impl Default for TypeName {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnRef {
     pub type_: NodeTag,
     pub fields: *mut List,
@@ -10949,7 +11013,8 @@ impl Default for ColumnRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamRef {
     pub type_: NodeTag,
     pub number: ::std::os::raw::c_int,
@@ -10980,7 +11045,8 @@ pub const A_Expr_Kind_AEXPR_BETWEEN_SYM: A_Expr_Kind = 12;
 pub const A_Expr_Kind_AEXPR_NOT_BETWEEN_SYM: A_Expr_Kind = 13;
 pub type A_Expr_Kind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Expr {
     pub type_: NodeTag,
     pub kind: A_Expr_Kind,
@@ -11035,7 +11101,8 @@ impl Default for A_Const {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TypeCast {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -11052,7 +11119,8 @@ impl Default for TypeCast {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CollateClause {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -11075,7 +11143,8 @@ pub const RoleSpecType_ROLESPEC_SESSION_USER: RoleSpecType = 3;
 pub const RoleSpecType_ROLESPEC_PUBLIC: RoleSpecType = 4;
 pub type RoleSpecType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RoleSpec {
     pub type_: NodeTag,
     pub roletype: RoleSpecType,
@@ -11092,7 +11161,8 @@ impl Default for RoleSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FuncCall {
     pub type_: NodeTag,
     pub funcname: *mut List,
@@ -11117,7 +11187,8 @@ impl Default for FuncCall {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Star {
     pub type_: NodeTag,
 }
@@ -11131,7 +11202,8 @@ impl Default for A_Star {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indices {
     pub type_: NodeTag,
     pub is_slice: bool,
@@ -11148,7 +11220,8 @@ impl Default for A_Indices {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_Indirection {
     pub type_: NodeTag,
     pub arg: *mut Node,
@@ -11164,7 +11237,8 @@ impl Default for A_Indirection {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct A_ArrayExpr {
     pub type_: NodeTag,
     pub elements: *mut List,
@@ -11180,7 +11254,8 @@ impl Default for A_ArrayExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResTarget {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -11198,7 +11273,8 @@ impl Default for ResTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MultiAssignRef {
     pub type_: NodeTag,
     pub source: *mut Node,
@@ -11215,7 +11291,8 @@ impl Default for MultiAssignRef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortBy {
     pub type_: NodeTag,
     pub node: *mut Node,
@@ -11234,7 +11311,8 @@ impl Default for SortBy {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowDef {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -11256,7 +11334,8 @@ impl Default for WindowDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeSubselect {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -11273,7 +11352,8 @@ impl Default for RangeSubselect {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeFunction {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -11293,7 +11373,8 @@ impl Default for RangeFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFunc {
     pub type_: NodeTag,
     pub lateral: bool,
@@ -11314,7 +11395,8 @@ impl Default for RangeTableFunc {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableFuncCol {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -11335,7 +11417,8 @@ impl Default for RangeTableFuncCol {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTableSample {
     pub type_: NodeTag,
     pub relation: *mut Node,
@@ -11354,7 +11437,8 @@ impl Default for RangeTableSample {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ColumnDef {
     pub type_: NodeTag,
     pub colname: *mut ::std::os::raw::c_char,
@@ -11386,7 +11470,8 @@ impl Default for ColumnDef {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableLikeClause {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -11414,7 +11499,8 @@ pub const TableLikeOption_CREATE_TABLE_LIKE_STORAGE: TableLikeOption = 256;
 pub const TableLikeOption_CREATE_TABLE_LIKE_ALL: TableLikeOption = 2147483647;
 pub type TableLikeOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -11441,7 +11527,8 @@ pub const DefElemAction_DEFELEM_ADD: DefElemAction = 2;
 pub const DefElemAction_DEFELEM_DROP: DefElemAction = 3;
 pub type DefElemAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefElem {
     pub type_: NodeTag,
     pub defnamespace: *mut ::std::os::raw::c_char,
@@ -11460,7 +11547,8 @@ impl Default for DefElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockingClause {
     pub type_: NodeTag,
     pub lockedRels: *mut List,
@@ -11477,7 +11565,8 @@ impl Default for LockingClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct XmlSerialize {
     pub type_: NodeTag,
     pub xmloption: XmlOptionType,
@@ -11495,7 +11584,8 @@ impl Default for XmlSerialize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -11514,7 +11604,8 @@ impl Default for PartitionElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionSpec {
     pub type_: NodeTag,
     pub strategy: *mut ::std::os::raw::c_char,
@@ -11531,7 +11622,8 @@ impl Default for PartitionSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionBoundSpec {
     pub type_: NodeTag,
     pub strategy: ::std::os::raw::c_char,
@@ -11557,7 +11649,8 @@ pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_VALUE: PartitionRangeDat
 pub const PartitionRangeDatumKind_PARTITION_RANGE_DATUM_MAXVALUE: PartitionRangeDatumKind = 1;
 pub type PartitionRangeDatumKind = ::std::os::raw::c_int;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionRangeDatum {
     pub type_: NodeTag,
     pub kind: PartitionRangeDatumKind,
@@ -11574,7 +11667,8 @@ impl Default for PartitionRangeDatum {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionCmd {
     pub type_: NodeTag,
     pub name: *mut RangeVar,
@@ -11601,7 +11695,8 @@ pub const RTEKind_RTE_NAMEDTUPLESTORE: RTEKind = 7;
 pub const RTEKind_RTE_RESULT: RTEKind = 8;
 pub type RTEKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblEntry {
     pub type_: NodeTag,
     pub rtekind: RTEKind,
@@ -11652,7 +11747,8 @@ impl Default for RangeTblEntry {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RangeTblFunction {
     pub type_: NodeTag,
     pub funcexpr: *mut Node,
@@ -11673,7 +11769,8 @@ impl Default for RangeTblFunction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableSampleClause {
     pub type_: NodeTag,
     pub tsmhandler: Oid,
@@ -11697,7 +11794,8 @@ pub const WCOKind_WCO_RLS_MERGE_UPDATE_CHECK: WCOKind = 4;
 pub const WCOKind_WCO_RLS_MERGE_DELETE_CHECK: WCOKind = 5;
 pub type WCOKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithCheckOption {
     pub type_: NodeTag,
     pub kind: WCOKind,
@@ -11716,7 +11814,8 @@ impl Default for WithCheckOption {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortGroupClause {
     pub type_: NodeTag,
     pub tleSortGroupRef: Index,
@@ -11741,7 +11840,8 @@ pub const GroupingSetKind_GROUPING_SET_CUBE: GroupingSetKind = 3;
 pub const GroupingSetKind_GROUPING_SET_SETS: GroupingSetKind = 4;
 pub type GroupingSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSet {
     pub type_: NodeTag,
     pub kind: GroupingSetKind,
@@ -11758,7 +11858,8 @@ impl Default for GroupingSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowClause {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -11787,7 +11888,8 @@ impl Default for WindowClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowMarkClause {
     pub type_: NodeTag,
     pub rti: Index,
@@ -11805,7 +11907,8 @@ impl Default for RowMarkClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WithClause {
     pub type_: NodeTag,
     pub ctes: *mut List,
@@ -11822,7 +11925,8 @@ impl Default for WithClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InferClause {
     pub type_: NodeTag,
     pub indexElems: *mut List,
@@ -11840,7 +11944,8 @@ impl Default for InferClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictClause {
     pub type_: NodeTag,
     pub action: OnConflictAction,
@@ -11863,7 +11968,8 @@ pub const CTEMaterialize_CTEMaterializeAlways: CTEMaterialize = 1;
 pub const CTEMaterialize_CTEMaterializeNever: CTEMaterialize = 2;
 pub type CTEMaterialize = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CTESearchClause {
     pub type_: NodeTag,
     pub search_col_list: *mut List,
@@ -11881,7 +11987,8 @@ impl Default for CTESearchClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CTECycleClause {
     pub type_: NodeTag,
     pub cycle_col_list: *mut List,
@@ -11905,7 +12012,8 @@ impl Default for CTECycleClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommonTableExpr {
     pub type_: NodeTag,
     pub ctename: *mut ::std::os::raw::c_char,
@@ -11932,7 +12040,8 @@ impl Default for CommonTableExpr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeWhenClause {
     pub type_: NodeTag,
     pub matched: bool,
@@ -11952,7 +12061,8 @@ impl Default for MergeWhenClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAction {
     pub type_: NodeTag,
     pub matched: bool,
@@ -11972,7 +12082,8 @@ impl Default for MergeAction {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerTransition {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -11990,7 +12101,8 @@ impl Default for TriggerTransition {
 }
 #[doc = "\t\tRaw Grammar Output Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RawStmt {
     pub type_: NodeTag,
     pub stmt: *mut Node,
@@ -12008,7 +12120,8 @@ impl Default for RawStmt {
 }
 #[doc = "\t\tOptimizable Statements"]
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InsertStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12029,7 +12142,8 @@ impl Default for InsertStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeleteStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12048,7 +12162,8 @@ impl Default for DeleteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpdateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12068,7 +12183,8 @@ impl Default for UpdateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12092,7 +12208,8 @@ pub const SetOperation_SETOP_INTERSECT: SetOperation = 2;
 pub const SetOperation_SETOP_EXCEPT: SetOperation = 3;
 pub type SetOperation = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SelectStmt {
     pub type_: NodeTag,
     pub distinctClause: *mut List,
@@ -12126,7 +12243,8 @@ impl Default for SelectStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOperationStmt {
     pub type_: NodeTag,
     pub op: SetOperation,
@@ -12148,7 +12266,8 @@ impl Default for SetOperationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnStmt {
     pub type_: NodeTag,
     pub returnval: *mut Node,
@@ -12163,7 +12282,8 @@ impl Default for ReturnStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PLAssignStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -12236,7 +12356,8 @@ pub const ObjectType_OBJECT_VIEW: ObjectType = 51;
 #[doc = "\t\tOther Statements (no optimizations required)\n\n\t\tThese are not touched by parser/analyze.c except to put them into\n\t\tthe utilityStmt field of a Query.  This is eventually passed to\n\t\tProcessUtility (by-passing rewriting and planning).  Some of the\n\t\tstatements do need attention from parse analysis, and this is\n\t\tdone by routines in parser/parse_utilcmd.c after ProcessUtility\n\t\treceives the command for execution.\n\t\tDECLARE CURSOR, EXPLAIN, and CREATE TABLE AS are special cases:\n\t\tthey contain optimizable statements, which get processed normally\n\t\tby parser/analyze.c."]
 pub type ObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSchemaStmt {
     pub type_: NodeTag,
     pub schemaname: *mut ::std::os::raw::c_char,
@@ -12257,7 +12378,8 @@ pub const DropBehavior_DROP_RESTRICT: DropBehavior = 0;
 pub const DropBehavior_DROP_CASCADE: DropBehavior = 1;
 pub type DropBehavior = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12347,7 +12469,8 @@ pub const AlterTableType_AT_DropIdentity: AlterTableType = 69;
 pub const AlterTableType_AT_ReAddStatistics: AlterTableType = 70;
 pub type AlterTableType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReplicaIdentityStmt {
     pub type_: NodeTag,
     pub identity_type: ::std::os::raw::c_char,
@@ -12363,7 +12486,8 @@ impl Default for ReplicaIdentityStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableCmd {
     pub type_: NodeTag,
     pub subtype: AlterTableType,
@@ -12385,7 +12509,8 @@ impl Default for AlterTableCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterCollationStmt {
     pub type_: NodeTag,
     pub collname: *mut List,
@@ -12400,7 +12525,8 @@ impl Default for AlterCollationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDomainStmt {
     pub type_: NodeTag,
     pub subtype: ::std::os::raw::c_char,
@@ -12424,7 +12550,8 @@ pub const GrantTargetType_ACL_TARGET_ALL_IN_SCHEMA: GrantTargetType = 1;
 pub const GrantTargetType_ACL_TARGET_DEFAULTS: GrantTargetType = 2;
 pub type GrantTargetType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantStmt {
     pub type_: NodeTag,
     pub is_grant: bool,
@@ -12447,7 +12574,8 @@ impl Default for GrantStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ObjectWithArgs {
     pub type_: NodeTag,
     pub objname: *mut List,
@@ -12465,7 +12593,8 @@ impl Default for ObjectWithArgs {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AccessPriv {
     pub type_: NodeTag,
     pub priv_name: *mut ::std::os::raw::c_char,
@@ -12481,7 +12610,8 @@ impl Default for AccessPriv {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GrantRoleStmt {
     pub type_: NodeTag,
     pub granted_roles: *mut List,
@@ -12501,7 +12631,8 @@ impl Default for GrantRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDefaultPrivilegesStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -12517,7 +12648,8 @@ impl Default for AlterDefaultPrivilegesStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CopyStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12546,7 +12678,8 @@ pub const VariableSetKind_VAR_RESET: VariableSetKind = 4;
 pub const VariableSetKind_VAR_RESET_ALL: VariableSetKind = 5;
 pub type VariableSetKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableSetStmt {
     pub type_: NodeTag,
     pub kind: VariableSetKind,
@@ -12564,7 +12697,8 @@ impl Default for VariableSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VariableShowStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -12579,7 +12713,8 @@ impl Default for VariableShowStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -12620,7 +12755,8 @@ pub const ConstrType_CONSTR_ATTR_DEFERRED: ConstrType = 12;
 pub const ConstrType_CONSTR_ATTR_IMMEDIATE: ConstrType = 13;
 pub type ConstrType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Constraint {
     pub type_: NodeTag,
     pub contype: ConstrType,
@@ -12664,7 +12800,8 @@ impl Default for Constraint {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -12682,7 +12819,8 @@ impl Default for CreateTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropTableSpaceStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -12698,7 +12836,8 @@ impl Default for DropTableSpaceStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableSpaceOptionsStmt {
     pub type_: NodeTag,
     pub tablespacename: *mut ::std::os::raw::c_char,
@@ -12715,7 +12854,8 @@ impl Default for AlterTableSpaceOptionsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTableMoveAllStmt {
     pub type_: NodeTag,
     pub orig_tablespacename: *mut ::std::os::raw::c_char,
@@ -12734,7 +12874,8 @@ impl Default for AlterTableMoveAllStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -12751,7 +12892,8 @@ impl Default for CreateExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -12767,7 +12909,8 @@ impl Default for AlterExtensionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterExtensionContentsStmt {
     pub type_: NodeTag,
     pub extname: *mut ::std::os::raw::c_char,
@@ -12785,7 +12928,8 @@ impl Default for AlterExtensionContentsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -12802,7 +12946,8 @@ impl Default for CreateFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFdwStmt {
     pub type_: NodeTag,
     pub fdwname: *mut ::std::os::raw::c_char,
@@ -12819,7 +12964,8 @@ impl Default for AlterFdwStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -12839,7 +12985,8 @@ impl Default for CreateForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterForeignServerStmt {
     pub type_: NodeTag,
     pub servername: *mut ::std::os::raw::c_char,
@@ -12857,7 +13004,8 @@ impl Default for AlterForeignServerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateForeignTableStmt {
     pub base: CreateStmt,
     pub servername: *mut ::std::os::raw::c_char,
@@ -12873,7 +13021,8 @@ impl Default for CreateForeignTableStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -12891,7 +13040,8 @@ impl Default for CreateUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -12908,7 +13058,8 @@ impl Default for AlterUserMappingStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropUserMappingStmt {
     pub type_: NodeTag,
     pub user: *mut RoleSpec,
@@ -12929,7 +13080,8 @@ pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_LIMIT_TO: ImportForeignSchem
 pub const ImportForeignSchemaType_FDW_IMPORT_SCHEMA_EXCEPT: ImportForeignSchemaType = 2;
 pub type ImportForeignSchemaType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ImportForeignSchemaStmt {
     pub type_: NodeTag,
     pub server_name: *mut ::std::os::raw::c_char,
@@ -12949,7 +13101,8 @@ impl Default for ImportForeignSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -12970,7 +13123,8 @@ impl Default for CreatePolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPolicyStmt {
     pub type_: NodeTag,
     pub policy_name: *mut ::std::os::raw::c_char,
@@ -12989,7 +13143,8 @@ impl Default for AlterPolicyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateAmStmt {
     pub type_: NodeTag,
     pub amname: *mut ::std::os::raw::c_char,
@@ -13006,7 +13161,8 @@ impl Default for CreateAmStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTrigStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -13035,7 +13191,8 @@ impl Default for CreateTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -13053,7 +13210,8 @@ impl Default for CreateEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEventTrigStmt {
     pub type_: NodeTag,
     pub trigname: *mut ::std::os::raw::c_char,
@@ -13069,7 +13227,8 @@ impl Default for AlterEventTrigStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePLangStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -13093,7 +13252,8 @@ pub const RoleStmtType_ROLESTMT_USER: RoleStmtType = 1;
 pub const RoleStmtType_ROLESTMT_GROUP: RoleStmtType = 2;
 pub type RoleStmtType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRoleStmt {
     pub type_: NodeTag,
     pub stmt_type: RoleStmtType,
@@ -13110,7 +13270,8 @@ impl Default for CreateRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -13127,7 +13288,8 @@ impl Default for AlterRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterRoleSetStmt {
     pub type_: NodeTag,
     pub role: *mut RoleSpec,
@@ -13144,7 +13306,8 @@ impl Default for AlterRoleSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropRoleStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -13160,7 +13323,8 @@ impl Default for DropRoleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -13179,7 +13343,8 @@ impl Default for CreateSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSeqStmt {
     pub type_: NodeTag,
     pub sequence: *mut RangeVar,
@@ -13197,7 +13362,8 @@ impl Default for AlterSeqStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DefineStmt {
     pub type_: NodeTag,
     pub kind: ObjectType,
@@ -13218,7 +13384,8 @@ impl Default for DefineStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateDomainStmt {
     pub type_: NodeTag,
     pub domainname: *mut List,
@@ -13236,7 +13403,8 @@ impl Default for CreateDomainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassStmt {
     pub type_: NodeTag,
     pub opclassname: *mut List,
@@ -13256,7 +13424,8 @@ impl Default for CreateOpClassStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpClassItem {
     pub type_: NodeTag,
     pub itemtype: ::std::os::raw::c_int,
@@ -13276,7 +13445,8 @@ impl Default for CreateOpClassItem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -13292,7 +13462,8 @@ impl Default for CreateOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOpFamilyStmt {
     pub type_: NodeTag,
     pub opfamilyname: *mut List,
@@ -13310,7 +13481,8 @@ impl Default for AlterOpFamilyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropStmt {
     pub type_: NodeTag,
     pub objects: *mut List,
@@ -13329,7 +13501,8 @@ impl Default for DropStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TruncateStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -13346,7 +13519,8 @@ impl Default for TruncateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CommentStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -13363,7 +13537,8 @@ impl Default for CommentStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SecLabelStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -13381,7 +13556,8 @@ impl Default for SecLabelStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeclareCursorStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -13398,7 +13574,8 @@ impl Default for DeclareCursorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClosePortalStmt {
     pub type_: NodeTag,
     pub portalname: *mut ::std::os::raw::c_char,
@@ -13418,7 +13595,8 @@ pub const FetchDirection_FETCH_ABSOLUTE: FetchDirection = 2;
 pub const FetchDirection_FETCH_RELATIVE: FetchDirection = 3;
 pub type FetchDirection = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FetchStmt {
     pub type_: NodeTag,
     pub direction: FetchDirection,
@@ -13436,7 +13614,8 @@ impl Default for FetchStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexStmt {
     pub type_: NodeTag,
     pub idxname: *mut ::std::os::raw::c_char,
@@ -13474,7 +13653,8 @@ impl Default for IndexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -13495,7 +13675,8 @@ impl Default for CreateStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatsElem {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -13511,7 +13692,8 @@ impl Default for StatsElem {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterStatsStmt {
     pub type_: NodeTag,
     pub defnames: *mut List,
@@ -13528,7 +13710,8 @@ impl Default for AlterStatsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateFunctionStmt {
     pub type_: NodeTag,
     pub is_procedure: bool,
@@ -13556,7 +13739,8 @@ pub const FunctionParameterMode_FUNC_PARAM_TABLE: FunctionParameterMode = 116;
 pub const FunctionParameterMode_FUNC_PARAM_DEFAULT: FunctionParameterMode = 100;
 pub type FunctionParameterMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionParameter {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -13574,7 +13758,8 @@ impl Default for FunctionParameter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterFunctionStmt {
     pub type_: NodeTag,
     pub objtype: ObjectType,
@@ -13591,7 +13776,8 @@ impl Default for AlterFunctionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DoStmt {
     pub type_: NodeTag,
     pub args: *mut List,
@@ -13606,7 +13792,8 @@ impl Default for DoStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct InlineCodeBlock {
     pub type_: NodeTag,
     pub source_text: *mut ::std::os::raw::c_char,
@@ -13624,7 +13811,8 @@ impl Default for InlineCodeBlock {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallStmt {
     pub type_: NodeTag,
     pub funccall: *mut FuncCall,
@@ -13641,7 +13829,8 @@ impl Default for CallStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CallContext {
     pub type_: NodeTag,
     pub atomic: bool,
@@ -13656,7 +13845,8 @@ impl Default for CallContext {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RenameStmt {
     pub type_: NodeTag,
     pub renameType: ObjectType,
@@ -13678,7 +13868,8 @@ impl Default for RenameStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectDependsStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -13697,7 +13888,8 @@ impl Default for AlterObjectDependsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterObjectSchemaStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -13716,7 +13908,8 @@ impl Default for AlterObjectSchemaStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOwnerStmt {
     pub type_: NodeTag,
     pub objectType: ObjectType,
@@ -13734,7 +13927,8 @@ impl Default for AlterOwnerStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterOperatorStmt {
     pub type_: NodeTag,
     pub opername: *mut ObjectWithArgs,
@@ -13750,7 +13944,8 @@ impl Default for AlterOperatorStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTypeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -13766,7 +13961,8 @@ impl Default for AlterTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RuleStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -13787,7 +13983,8 @@ impl Default for RuleStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NotifyStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -13803,7 +14000,8 @@ impl Default for NotifyStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ListenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -13818,7 +14016,8 @@ impl Default for ListenStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UnlistenStmt {
     pub type_: NodeTag,
     pub conditionname: *mut ::std::os::raw::c_char,
@@ -13844,7 +14043,8 @@ pub const TransactionStmtKind_TRANS_STMT_COMMIT_PREPARED: TransactionStmtKind =
 pub const TransactionStmtKind_TRANS_STMT_ROLLBACK_PREPARED: TransactionStmtKind = 9;
 pub type TransactionStmtKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TransactionStmt {
     pub type_: NodeTag,
     pub kind: TransactionStmtKind,
@@ -13863,7 +14063,8 @@ impl Default for TransactionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CompositeTypeStmt {
     pub type_: NodeTag,
     pub typevar: *mut RangeVar,
@@ -13879,7 +14080,8 @@ impl Default for CompositeTypeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -13895,7 +14097,8 @@ impl Default for CreateEnumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateRangeStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -13911,7 +14114,8 @@ impl Default for CreateRangeStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterEnumStmt {
     pub type_: NodeTag,
     pub typeName: *mut List,
@@ -13935,7 +14139,8 @@ pub const ViewCheckOption_LOCAL_CHECK_OPTION: ViewCheckOption = 1;
 pub const ViewCheckOption_CASCADED_CHECK_OPTION: ViewCheckOption = 2;
 pub type ViewCheckOption = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ViewStmt {
     pub type_: NodeTag,
     pub view: *mut RangeVar,
@@ -13955,7 +14160,8 @@ impl Default for ViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LoadStmt {
     pub type_: NodeTag,
     pub filename: *mut ::std::os::raw::c_char,
@@ -13970,7 +14176,8 @@ impl Default for LoadStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatedbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -13986,7 +14193,8 @@ impl Default for CreatedbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -14002,7 +14210,8 @@ impl Default for AlterDatabaseStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseRefreshCollStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -14017,7 +14226,8 @@ impl Default for AlterDatabaseRefreshCollStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterDatabaseSetStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -14033,7 +14243,8 @@ impl Default for AlterDatabaseSetStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropdbStmt {
     pub type_: NodeTag,
     pub dbname: *mut ::std::os::raw::c_char,
@@ -14050,7 +14261,8 @@ impl Default for DropdbStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSystemStmt {
     pub type_: NodeTag,
     pub setstmt: *mut VariableSetStmt,
@@ -14065,7 +14277,8 @@ impl Default for AlterSystemStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ClusterStmt {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -14082,7 +14295,8 @@ impl Default for ClusterStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumStmt {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -14099,7 +14313,8 @@ impl Default for VacuumStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct VacuumRelation {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -14116,7 +14331,8 @@ impl Default for VacuumRelation {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExplainStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -14132,7 +14348,8 @@ impl Default for ExplainStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTableAsStmt {
     pub type_: NodeTag,
     pub query: *mut Node,
@@ -14151,7 +14368,8 @@ impl Default for CreateTableAsStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RefreshMatViewStmt {
     pub type_: NodeTag,
     pub concurrent: bool,
@@ -14168,7 +14386,8 @@ impl Default for RefreshMatViewStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CheckPointStmt {
     pub type_: NodeTag,
 }
@@ -14187,7 +14406,8 @@ pub const DiscardMode_DISCARD_SEQUENCES: DiscardMode = 2;
 pub const DiscardMode_DISCARD_TEMP: DiscardMode = 3;
 pub type DiscardMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DiscardStmt {
     pub type_: NodeTag,
     pub target: DiscardMode,
@@ -14202,7 +14422,8 @@ impl Default for DiscardStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockStmt {
     pub type_: NodeTag,
     pub relations: *mut List,
@@ -14219,7 +14440,8 @@ impl Default for LockStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ConstraintsSetStmt {
     pub type_: NodeTag,
     pub constraints: *mut List,
@@ -14241,7 +14463,8 @@ pub const ReindexObjectType_REINDEX_OBJECT_SYSTEM: ReindexObjectType = 3;
 pub const ReindexObjectType_REINDEX_OBJECT_DATABASE: ReindexObjectType = 4;
 pub type ReindexObjectType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReindexStmt {
     pub type_: NodeTag,
     pub kind: ReindexObjectType,
@@ -14259,7 +14482,8 @@ impl Default for ReindexStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateConversionStmt {
     pub type_: NodeTag,
     pub conversion_name: *mut List,
@@ -14278,7 +14502,8 @@ impl Default for CreateConversionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateCastStmt {
     pub type_: NodeTag,
     pub sourcetype: *mut TypeName,
@@ -14297,7 +14522,8 @@ impl Default for CreateCastStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateTransformStmt {
     pub type_: NodeTag,
     pub replace: bool,
@@ -14316,7 +14542,8 @@ impl Default for CreateTransformStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PrepareStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -14333,7 +14560,8 @@ impl Default for PrepareStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExecuteStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -14349,7 +14577,8 @@ impl Default for ExecuteStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DeallocateStmt {
     pub type_: NodeTag,
     pub name: *mut ::std::os::raw::c_char,
@@ -14364,7 +14593,8 @@ impl Default for DeallocateStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -14380,7 +14610,8 @@ impl Default for DropOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReassignOwnedStmt {
     pub type_: NodeTag,
     pub roles: *mut List,
@@ -14396,7 +14627,8 @@ impl Default for ReassignOwnedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSDictionaryStmt {
     pub type_: NodeTag,
     pub dictname: *mut List,
@@ -14418,7 +14650,8 @@ pub const AlterTSConfigType_ALTER_TSCONFIG_REPLACE_DICT_FOR_TOKEN: AlterTSConfig
 pub const AlterTSConfigType_ALTER_TSCONFIG_DROP_MAPPING: AlterTSConfigType = 4;
 pub type AlterTSConfigType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterTSConfigurationStmt {
     pub type_: NodeTag,
     pub kind: AlterTSConfigType,
@@ -14439,7 +14672,8 @@ impl Default for AlterTSConfigurationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PublicationTable {
     pub type_: NodeTag,
     pub relation: *mut RangeVar,
@@ -14461,7 +14695,8 @@ pub const PublicationObjSpecType_PUBLICATIONOBJ_TABLES_IN_CUR_SCHEMA: Publicatio
 pub const PublicationObjSpecType_PUBLICATIONOBJ_CONTINUATION: PublicationObjSpecType = 3;
 pub type PublicationObjSpecType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PublicationObjSpec {
     pub type_: NodeTag,
     pub pubobjtype: PublicationObjSpecType,
@@ -14479,7 +14714,8 @@ impl Default for PublicationObjSpec {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreatePublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -14501,7 +14737,8 @@ pub const AlterPublicationAction_AP_DropObjects: AlterPublicationAction = 1;
 pub const AlterPublicationAction_AP_SetObjects: AlterPublicationAction = 2;
 pub type AlterPublicationAction = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterPublicationStmt {
     pub type_: NodeTag,
     pub pubname: *mut ::std::os::raw::c_char,
@@ -14520,7 +14757,8 @@ impl Default for AlterPublicationStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -14547,7 +14785,8 @@ pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_ENABLED: AlterSubscriptionTyp
 pub const AlterSubscriptionType_ALTER_SUBSCRIPTION_SKIP: AlterSubscriptionType = 7;
 pub type AlterSubscriptionType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AlterSubscriptionStmt {
     pub type_: NodeTag,
     pub kind: AlterSubscriptionType,
@@ -14566,7 +14805,8 @@ impl Default for AlterSubscriptionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropSubscriptionStmt {
     pub type_: NodeTag,
     pub subname: *mut ::std::os::raw::c_char,
@@ -14583,7 +14823,8 @@ impl Default for DropSubscriptionStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannedStmt {
     pub type_: NodeTag,
     pub commandType: CmdType,
@@ -14619,7 +14860,8 @@ impl Default for PlannedStmt {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Plan {
     pub type_: NodeTag,
     pub startup_cost: Cost,
@@ -14648,7 +14890,8 @@ impl Default for Plan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Result {
     pub plan: Plan,
     pub resconstantqual: *mut Node,
@@ -14663,7 +14906,8 @@ impl Default for Result {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSet {
     pub plan: Plan,
 }
@@ -14677,7 +14921,8 @@ impl Default for ProjectSet {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTable {
     pub plan: Plan,
     pub operation: CmdType,
@@ -14712,7 +14957,8 @@ impl Default for ModifyTable {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Append {
     pub plan: Plan,
     pub apprelids: *mut Bitmapset,
@@ -14731,7 +14977,8 @@ impl Default for Append {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppend {
     pub plan: Plan,
     pub apprelids: *mut Bitmapset,
@@ -14753,7 +15000,8 @@ impl Default for MergeAppend {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnion {
     pub plan: Plan,
     pub wtParam: ::std::os::raw::c_int,
@@ -14773,7 +15021,8 @@ impl Default for RecursiveUnion {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAnd {
     pub plan: Plan,
     pub bitmapplans: *mut List,
@@ -14788,7 +15037,8 @@ impl Default for BitmapAnd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOr {
     pub plan: Plan,
     pub isshared: bool,
@@ -14804,7 +15054,8 @@ impl Default for BitmapOr {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Scan {
     pub plan: Plan,
     pub scanrelid: Index,
@@ -14819,7 +15070,8 @@ impl Default for Scan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SeqScan {
     pub scan: Scan,
 }
@@ -14833,7 +15085,8 @@ impl Default for SeqScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScan {
     pub scan: Scan,
     pub tablesample: *mut TableSampleClause,
@@ -14848,7 +15101,8 @@ impl Default for SampleScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -14869,7 +15123,8 @@ impl Default for IndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -14889,7 +15144,8 @@ impl Default for IndexOnlyScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScan {
     pub scan: Scan,
     pub indexid: Oid,
@@ -14907,7 +15163,8 @@ impl Default for BitmapIndexScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScan {
     pub scan: Scan,
     pub bitmapqualorig: *mut List,
@@ -14922,7 +15179,8 @@ impl Default for BitmapHeapScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScan {
     pub scan: Scan,
     pub tidquals: *mut List,
@@ -14937,7 +15195,8 @@ impl Default for TidScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidRangeScan {
     pub scan: Scan,
     pub tidrangequals: *mut List,
@@ -14956,7 +15215,8 @@ pub const SubqueryScanStatus_SUBQUERY_SCAN_TRIVIAL: SubqueryScanStatus = 1;
 pub const SubqueryScanStatus_SUBQUERY_SCAN_NONTRIVIAL: SubqueryScanStatus = 2;
 pub type SubqueryScanStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScan {
     pub scan: Scan,
     pub subplan: *mut Plan,
@@ -14972,7 +15232,8 @@ impl Default for SubqueryScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScan {
     pub scan: Scan,
     pub functions: *mut List,
@@ -14988,7 +15249,8 @@ impl Default for FunctionScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScan {
     pub scan: Scan,
     pub values_lists: *mut List,
@@ -15003,7 +15265,8 @@ impl Default for ValuesScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScan {
     pub scan: Scan,
     pub tablefunc: *mut TableFunc,
@@ -15018,7 +15281,8 @@ impl Default for TableFuncScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScan {
     pub scan: Scan,
     pub ctePlanId: ::std::os::raw::c_int,
@@ -15034,7 +15298,8 @@ impl Default for CteScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScan {
     pub scan: Scan,
     pub enrname: *mut ::std::os::raw::c_char,
@@ -15049,7 +15314,8 @@ impl Default for NamedTuplestoreScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScan {
     pub scan: Scan,
     pub wtParam: ::std::os::raw::c_int,
@@ -15064,7 +15330,8 @@ impl Default for WorkTableScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScan {
     pub scan: Scan,
     pub operation: CmdType,
@@ -15087,7 +15354,8 @@ impl Default for ForeignScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScan {
     pub scan: Scan,
     pub flags: uint32,
@@ -15108,7 +15376,8 @@ impl Default for CustomScan {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Join {
     pub plan: Plan,
     pub jointype: JoinType,
@@ -15125,7 +15394,8 @@ impl Default for Join {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoop {
     pub join: Join,
     pub nestParams: *mut List,
@@ -15140,7 +15410,8 @@ impl Default for NestLoop {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopParam {
     pub type_: NodeTag,
     pub paramno: ::std::os::raw::c_int,
@@ -15156,7 +15427,8 @@ impl Default for NestLoopParam {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoin {
     pub join: Join,
     pub skip_mark_restore: bool,
@@ -15176,7 +15448,8 @@ impl Default for MergeJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoin {
     pub join: Join,
     pub hashclauses: *mut List,
@@ -15194,7 +15467,8 @@ impl Default for HashJoin {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Material {
     pub plan: Plan,
 }
@@ -15208,7 +15482,8 @@ impl Default for Material {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Memoize {
     pub plan: Plan,
     pub numKeys: ::std::os::raw::c_int,
@@ -15230,7 +15505,8 @@ impl Default for Memoize {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Sort {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -15249,7 +15525,8 @@ impl Default for Sort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSort {
     pub sort: Sort,
     pub nPresortedCols: ::std::os::raw::c_int,
@@ -15264,7 +15541,8 @@ impl Default for IncrementalSort {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Group {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -15282,7 +15560,8 @@ impl Default for Group {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Agg {
     pub plan: Plan,
     pub aggstrategy: AggStrategy,
@@ -15307,7 +15586,8 @@ impl Default for Agg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAgg {
     pub plan: Plan,
     pub winref: Index,
@@ -15341,7 +15621,8 @@ impl Default for WindowAgg {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Unique {
     pub plan: Plan,
     pub numCols: ::std::os::raw::c_int,
@@ -15359,7 +15640,8 @@ impl Default for Unique {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Gather {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -15378,7 +15660,8 @@ impl Default for Gather {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMerge {
     pub plan: Plan,
     pub num_workers: ::std::os::raw::c_int,
@@ -15400,7 +15683,8 @@ impl Default for GatherMerge {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Hash {
     pub plan: Plan,
     pub hashkeys: *mut List,
@@ -15419,7 +15703,8 @@ impl Default for Hash {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOp {
     pub plan: Plan,
     pub cmd: SetOpCmd,
@@ -15442,7 +15727,8 @@ impl Default for SetOp {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRows {
     pub plan: Plan,
     pub rowMarks: *mut List,
@@ -15458,7 +15744,8 @@ impl Default for LockRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Limit {
     pub plan: Plan,
     pub limitOffset: *mut Node,
@@ -15486,7 +15773,8 @@ pub const RowMarkType_ROW_MARK_REFERENCE: RowMarkType = 4;
 pub const RowMarkType_ROW_MARK_COPY: RowMarkType = 5;
 pub type RowMarkType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanRowMark {
     pub type_: NodeTag,
     pub rti: Index,
@@ -15508,7 +15796,8 @@ impl Default for PlanRowMark {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneInfo {
     pub type_: NodeTag,
     pub prune_infos: *mut List,
@@ -15524,7 +15813,8 @@ impl Default for PartitionPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionedRelPruneInfo {
     pub type_: NodeTag,
     pub rtindex: Index,
@@ -15547,7 +15837,8 @@ impl Default for PartitionedRelPruneInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStep {
     pub type_: NodeTag,
     pub step_id: ::std::os::raw::c_int,
@@ -15562,7 +15853,8 @@ impl Default for PartitionPruneStep {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepOp {
     pub step: PartitionPruneStep,
     pub opstrategy: StrategyNumber,
@@ -15583,7 +15875,8 @@ pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_UNION: PartitionPruneCombine
 pub const PartitionPruneCombineOp_PARTPRUNE_COMBINE_INTERSECT: PartitionPruneCombineOp = 1;
 pub type PartitionPruneCombineOp = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PartitionPruneStepCombine {
     pub step: PartitionPruneStep,
     pub combineOp: PartitionPruneCombineOp,
@@ -15599,7 +15892,8 @@ impl Default for PartitionPruneStepCombine {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanInvalItem {
     pub type_: NodeTag,
     pub cacheId: ::std::os::raw::c_int,
@@ -17721,7 +18015,8 @@ pub type ExprStateEvalFunc = ::std::option::Option<
     ) -> Datum,
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprState {
     pub type_: NodeTag,
     pub flags: uint8,
@@ -17751,7 +18046,8 @@ impl Default for ExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexInfo {
     pub type_: NodeTag,
     pub ii_NumIndexAttrs: ::std::os::raw::c_int,
@@ -17807,7 +18103,8 @@ impl Default for ExprContext_CB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExprContext {
     pub type_: NodeTag,
     pub ecxt_scantuple: *mut TupleTableSlot,
@@ -17845,7 +18142,8 @@ pub const SetFunctionReturnMode_SFRM_Materialize_Random: SetFunctionReturnMode =
 pub const SetFunctionReturnMode_SFRM_Materialize_Preferred: SetFunctionReturnMode = 8;
 pub type SetFunctionReturnMode = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReturnSetInfo {
     pub type_: NodeTag,
     pub econtext: *mut ExprContext,
@@ -17866,7 +18164,8 @@ impl Default for ReturnSetInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionInfo {
     pub type_: NodeTag,
     pub pi_state: ExprState,
@@ -17882,7 +18181,8 @@ impl Default for ProjectionInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JunkFilter {
     pub type_: NodeTag,
     pub jf_targetList: *mut List,
@@ -17900,7 +18200,8 @@ impl Default for JunkFilter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct OnConflictSetState {
     pub type_: NodeTag,
     pub oc_Existing: *mut TupleTableSlot,
@@ -17918,7 +18219,8 @@ impl Default for OnConflictSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeActionState {
     pub type_: NodeTag,
     pub mas_action: *mut MergeAction,
@@ -17935,7 +18237,8 @@ impl Default for MergeActionState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultRelInfo {
     pub type_: NodeTag,
     pub ri_RangeTableIndex: Index,
@@ -18027,7 +18330,8 @@ impl Default for AsyncRequest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EState {
     pub type_: NodeTag,
     pub es_direction: ScanDirection,
@@ -18299,7 +18603,8 @@ impl Default for TupleHashTableData {
 }
 pub type TupleHashIterator = tuplehash_iterator;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowFuncExprState {
     pub type_: NodeTag,
     pub wfunc: *mut WindowFunc,
@@ -18317,7 +18622,8 @@ impl Default for WindowFuncExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetExprState {
     pub type_: NodeTag,
     pub expr: *mut Expr,
@@ -18343,7 +18649,8 @@ impl Default for SetExprState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubPlanState {
     pub type_: NodeTag,
     pub subplan: *mut SubPlan,
@@ -18386,7 +18693,8 @@ pub const DomainConstraintType_DOM_CONSTRAINT_NOTNULL: DomainConstraintType = 0;
 pub const DomainConstraintType_DOM_CONSTRAINT_CHECK: DomainConstraintType = 1;
 pub type DomainConstraintType = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DomainConstraintState {
     pub type_: NodeTag,
     pub constrainttype: DomainConstraintType,
@@ -18406,7 +18714,8 @@ impl Default for DomainConstraintState {
 pub type ExecProcNodeMtd =
     ::std::option::Option<unsafe extern "C" fn(pstate: *mut PlanState) -> *mut TupleTableSlot>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlanState {
     pub type_: NodeTag,
     pub plan: *mut Plan,
@@ -18475,7 +18784,8 @@ impl Default for EPQState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ResultState {
     pub ps: PlanState,
     pub resconstantqual: *mut ExprState,
@@ -18492,7 +18802,8 @@ impl Default for ResultState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetState {
     pub ps: PlanState,
     pub elems: *mut *mut Node,
@@ -18511,7 +18822,8 @@ impl Default for ProjectSetState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTableState {
     pub ps: PlanState,
     pub operation: CmdType,
@@ -18555,7 +18867,8 @@ pub struct PartitionPruneState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendState {
     pub ps: PlanState,
     pub appendplans: *mut *mut PlanState,
@@ -18590,7 +18903,8 @@ impl Default for AppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendState {
     pub ps: PlanState,
     pub mergeplans: *mut *mut PlanState,
@@ -18613,7 +18927,8 @@ impl Default for MergeAppendState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionState {
     pub ps: PlanState,
     pub recursing: bool,
@@ -18636,7 +18951,8 @@ impl Default for RecursiveUnionState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -18652,7 +18968,8 @@ impl Default for BitmapAndState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrState {
     pub ps: PlanState,
     pub bitmapplans: *mut *mut PlanState,
@@ -18668,7 +18985,8 @@ impl Default for BitmapOrState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ScanState {
     pub ps: PlanState,
     pub ss_currentRelation: Relation,
@@ -18685,7 +19003,8 @@ impl Default for ScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SeqScanState {
     pub ss: ScanState,
     pub pscan_len: Size,
@@ -18700,7 +19019,8 @@ impl Default for SeqScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SampleScanState {
     pub ss: ScanState,
     pub args: *mut List,
@@ -18760,7 +19080,8 @@ impl Default for IndexArrayKeyInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexScanState {
     pub ss: ScanState,
     pub indexqualorig: *mut ExprState,
@@ -18794,7 +19115,8 @@ impl Default for IndexScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOnlyScanState {
     pub ss: ScanState,
     pub recheckqual: *mut ExprState,
@@ -18822,7 +19144,8 @@ impl Default for IndexOnlyScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapIndexScanState {
     pub ss: ScanState,
     pub biss_result: *mut TIDBitmap,
@@ -18872,7 +19195,8 @@ impl Default for ParallelBitmapHeapState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapScanState {
     pub ss: ScanState,
     pub bitmapqualorig: *mut ExprState,
@@ -18905,7 +19229,8 @@ impl Default for BitmapHeapScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidScanState {
     pub ss: ScanState,
     pub tss_tidexprs: *mut List,
@@ -18925,7 +19250,8 @@ impl Default for TidScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidRangeScanState {
     pub ss: ScanState,
     pub trss_tidexprs: *mut List,
@@ -18943,7 +19269,8 @@ impl Default for TidRangeScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanState {
     pub ss: ScanState,
     pub subplan: *mut PlanState,
@@ -18963,7 +19290,8 @@ pub struct FunctionScanPerFuncState {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FunctionScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -18984,7 +19312,8 @@ impl Default for FunctionScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ValuesScanState {
     pub ss: ScanState,
     pub rowcontext: *mut ExprContext,
@@ -19003,7 +19332,8 @@ impl Default for ValuesScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableFuncScanState {
     pub ss: ScanState,
     pub docexpr: *mut ExprState,
@@ -19031,7 +19361,8 @@ impl Default for TableFuncScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CteScanState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -19051,7 +19382,8 @@ impl Default for CteScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NamedTuplestoreScanState {
     pub ss: ScanState,
     pub readptr: ::std::os::raw::c_int,
@@ -19068,7 +19400,8 @@ impl Default for NamedTuplestoreScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WorkTableScanState {
     pub ss: ScanState,
     pub rustate: *mut RecursiveUnionState,
@@ -19083,7 +19416,8 @@ impl Default for WorkTableScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignScanState {
     pub ss: ScanState,
     pub fdw_recheck_quals: *mut ExprState,
@@ -19102,7 +19436,8 @@ impl Default for ForeignScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomScanState {
     pub ss: ScanState,
     pub flags: uint32,
@@ -19120,7 +19455,8 @@ impl Default for CustomScanState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinState {
     pub ps: PlanState,
     pub jointype: JoinType,
@@ -19137,7 +19473,8 @@ impl Default for JoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestLoopState {
     pub js: JoinState,
     pub nl_NeedNewOuter: bool,
@@ -19160,7 +19497,8 @@ pub struct MergeJoinClauseData {
 }
 pub type MergeJoinClause = *mut MergeJoinClauseData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeJoinState {
     pub js: JoinState,
     pub mj_NumClauses: ::std::os::raw::c_int,
@@ -19203,7 +19541,8 @@ pub struct HashJoinTableData {
 }
 pub type HashJoinTable = *mut HashJoinTableData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashJoinState {
     pub js: JoinState,
     pub hashclauses: *mut ExprState,
@@ -19234,7 +19573,8 @@ impl Default for HashJoinState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialState {
     pub ss: ScanState,
     pub eflags: ::std::os::raw::c_int,
@@ -19281,7 +19621,8 @@ pub struct SharedMemoizeInfo {
     pub sinstrument: __IncompleteArrayField<MemoizeInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoizeState {
     pub ss: ScanState,
     pub mstatus: ::std::os::raw::c_int,
@@ -19347,7 +19688,8 @@ impl Default for SharedSortInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortState {
     pub ss: ScanState,
     pub randomAccess: bool,
@@ -19398,7 +19740,8 @@ pub const IncrementalSortExecutionStatus_INCSORT_READFULLSORT: IncrementalSortEx
 pub const IncrementalSortExecutionStatus_INCSORT_READPREFIXSORT: IncrementalSortExecutionStatus = 3;
 pub type IncrementalSortExecutionStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSortState {
     pub ss: ScanState,
     pub bounded: bool,
@@ -19426,7 +19769,8 @@ impl Default for IncrementalSortState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupState {
     pub ss: ScanState,
     pub eqfunction: *mut ExprState,
@@ -19485,7 +19829,8 @@ pub struct AggStatePerHashData {
 }
 pub type AggStatePerHash = *mut AggStatePerHashData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggState {
     pub ss: ScanState,
     pub aggs: *mut List,
@@ -19571,7 +19916,8 @@ pub const WindowAggStatus_WINDOWAGG_PASSTHROUGH: WindowAggStatus = 2;
 pub const WindowAggStatus_WINDOWAGG_PASSTHROUGH_STRICT: WindowAggStatus = 3;
 pub type WindowAggStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggState {
     pub ss: ScanState,
     pub funcs: *mut List,
@@ -19639,7 +19985,8 @@ impl Default for WindowAggState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniqueState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -19654,7 +20001,8 @@ impl Default for UniqueState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -19682,7 +20030,8 @@ pub struct GMReaderTupleBuffer {
     _unused: [u8; 0],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergeState {
     pub ps: PlanState,
     pub initialized: bool,
@@ -19725,7 +20074,8 @@ pub struct SharedHashInfo {
     pub hinstrument: __IncompleteArrayField<HashInstrumentation>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashState {
     pub ps: PlanState,
     pub hashtable: HashJoinTable,
@@ -19750,7 +20100,8 @@ pub struct SetOpStatePerGroupData {
 }
 pub type SetOpStatePerGroup = *mut SetOpStatePerGroupData;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpState {
     pub ps: PlanState,
     pub eqfunction: *mut ExprState,
@@ -19775,7 +20126,8 @@ impl Default for SetOpState {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsState {
     pub ps: PlanState,
     pub lr_arowMarks: *mut List,
@@ -19800,7 +20152,8 @@ pub const LimitStateCond_LIMIT_WINDOWEND: LimitStateCond = 6;
 pub const LimitStateCond_LIMIT_WINDOWSTART: LimitStateCond = 7;
 pub type LimitStateCond = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitState {
     pub ps: PlanState,
     pub limitOffset: *mut ExprState,
@@ -20222,7 +20575,8 @@ pub struct MemoryContextMethods {
     pub check: ::std::option::Option<unsafe extern "C" fn(context: MemoryContext)>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoryContextData {
     pub type_: NodeTag,
     pub isReset: bool,
@@ -24868,7 +25222,8 @@ pub type aminitparallelscan_function =
 pub type amparallelrescan_function =
     ::std::option::Option<unsafe extern "C" fn(scan: IndexScanDesc)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexAmRoutine {
     pub type_: NodeTag,
     pub amstrategies: uint16,
@@ -26723,7 +27078,8 @@ impl Default for RelationData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyCacheInfo {
     pub type_: NodeTag,
     pub conoid: Oid,
@@ -29272,7 +29628,8 @@ pub type IndexBuildCallback = ::std::option::Option<
     ),
 >;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TableAmRoutine {
     pub type_: NodeTag,
     pub slot_callbacks:
@@ -34464,7 +34821,8 @@ impl Default for CollectedCommand {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EventTriggerData {
     pub type_: NodeTag,
     pub event: *const ::std::os::raw::c_char,
@@ -35175,7 +35533,8 @@ extern "C" {
 }
 pub type TriggerEvent = uint32;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TriggerData {
     pub type_: NodeTag,
     pub tg_event: TriggerEvent,
@@ -37893,7 +38252,8 @@ pub const UpperRelationKind_UPPERREL_ORDERED: UpperRelationKind = 6;
 pub const UpperRelationKind_UPPERREL_FINAL: UpperRelationKind = 7;
 pub type UpperRelationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerGlobal {
     pub type_: NodeTag,
     pub boundParams: ParamListInfo,
@@ -37927,7 +38287,8 @@ impl Default for PlannerGlobal {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerInfo {
     pub type_: NodeTag,
     pub parse: *mut Query,
@@ -38041,7 +38402,8 @@ pub const RelOptKind_RELOPT_OTHER_UPPER_REL: RelOptKind = 5;
 pub const RelOptKind_RELOPT_DEADREL: RelOptKind = 6;
 pub type RelOptKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RelOptInfo {
     pub type_: NodeTag,
     pub reloptkind: RelOptKind,
@@ -38114,7 +38476,8 @@ impl Default for RelOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexOptInfo {
     pub type_: NodeTag,
     pub indexoid: Oid,
@@ -38163,7 +38526,8 @@ impl Default for IndexOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignKeyOptInfo {
     pub type_: NodeTag,
     pub con_relid: Index,
@@ -38190,7 +38554,8 @@ impl Default for ForeignKeyOptInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StatisticExtInfo {
     pub type_: NodeTag,
     pub statOid: Oid,
@@ -38210,7 +38575,8 @@ impl Default for StatisticExtInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceClass {
     pub type_: NodeTag,
     pub ec_opfamilies: *mut List,
@@ -38238,7 +38604,8 @@ impl Default for EquivalenceClass {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct EquivalenceMember {
     pub type_: NodeTag,
     pub em_expr: *mut Expr,
@@ -38258,7 +38625,8 @@ impl Default for EquivalenceMember {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathKey {
     pub type_: NodeTag,
     pub pk_eclass: *mut EquivalenceClass,
@@ -38280,7 +38648,8 @@ pub const VolatileFunctionStatus_VOLATILITY_VOLATILE: VolatileFunctionStatus = 1
 pub const VolatileFunctionStatus_VOLATILITY_NOVOLATILE: VolatileFunctionStatus = 2;
 pub type VolatileFunctionStatus = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PathTarget {
     pub type_: NodeTag,
     pub exprs: *mut List,
@@ -38299,7 +38668,8 @@ impl Default for PathTarget {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ParamPathInfo {
     pub type_: NodeTag,
     pub ppi_req_outer: Relids,
@@ -38316,7 +38686,8 @@ impl Default for ParamPathInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct Path {
     pub type_: NodeTag,
     pub pathtype: NodeTag,
@@ -38341,7 +38712,8 @@ impl Default for Path {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexPath {
     pub path: Path,
     pub indexinfo: *mut IndexOptInfo,
@@ -38362,7 +38734,8 @@ impl Default for IndexPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IndexClause {
     pub type_: NodeTag,
     pub rinfo: *mut RestrictInfo,
@@ -38381,7 +38754,8 @@ impl Default for IndexClause {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapHeapPath {
     pub path: Path,
     pub bitmapqual: *mut Path,
@@ -38396,7 +38770,8 @@ impl Default for BitmapHeapPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapAndPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -38412,7 +38787,8 @@ impl Default for BitmapAndPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BitmapOrPath {
     pub path: Path,
     pub bitmapquals: *mut List,
@@ -38428,7 +38804,8 @@ impl Default for BitmapOrPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidPath {
     pub path: Path,
     pub tidquals: *mut List,
@@ -38443,7 +38820,8 @@ impl Default for TidPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TidRangePath {
     pub path: Path,
     pub tidrangequals: *mut List,
@@ -38458,7 +38836,8 @@ impl Default for TidRangePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SubqueryScanPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38473,7 +38852,8 @@ impl Default for SubqueryScanPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ForeignPath {
     pub path: Path,
     pub fdw_outerpath: *mut Path,
@@ -38489,7 +38869,8 @@ impl Default for ForeignPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CustomPath {
     pub path: Path,
     pub flags: uint32,
@@ -38507,7 +38888,8 @@ impl Default for CustomPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendPath {
     pub path: Path,
     pub subpaths: *mut List,
@@ -38528,7 +38910,8 @@ extern "C" {
     pub fn is_dummy_rel(rel: *mut RelOptInfo) -> bool;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergeAppendPath {
     pub path: Path,
     pub subpaths: *mut List,
@@ -38544,7 +38927,8 @@ impl Default for MergeAppendPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupResultPath {
     pub path: Path,
     pub quals: *mut List,
@@ -38559,7 +38943,8 @@ impl Default for GroupResultPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MaterialPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38574,7 +38959,8 @@ impl Default for MaterialPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MemoizePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38599,7 +38985,8 @@ pub const UniquePathMethod_UNIQUE_PATH_HASH: UniquePathMethod = 1;
 pub const UniquePathMethod_UNIQUE_PATH_SORT: UniquePathMethod = 2;
 pub type UniquePathMethod = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38617,7 +39004,8 @@ impl Default for UniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38634,7 +39022,8 @@ impl Default for GatherPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GatherMergePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38650,7 +39039,8 @@ impl Default for GatherMergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct JoinPath {
     pub path: Path,
     pub jointype: JoinType,
@@ -38669,7 +39059,8 @@ impl Default for JoinPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct NestPath {
     pub jpath: JoinPath,
 }
@@ -38683,7 +39074,8 @@ impl Default for NestPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MergePath {
     pub jpath: JoinPath,
     pub path_mergeclauses: *mut List,
@@ -38702,7 +39094,8 @@ impl Default for MergePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct HashPath {
     pub jpath: JoinPath,
     pub path_hashclauses: *mut List,
@@ -38719,7 +39112,8 @@ impl Default for HashPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectionPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38735,7 +39129,8 @@ impl Default for ProjectionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ProjectSetPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38750,7 +39145,8 @@ impl Default for ProjectSetPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SortPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38765,7 +39161,8 @@ impl Default for SortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IncrementalSortPath {
     pub spath: SortPath,
     pub nPresortedCols: ::std::os::raw::c_int,
@@ -38780,7 +39177,8 @@ impl Default for IncrementalSortPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38797,7 +39195,8 @@ impl Default for GroupPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct UpperUniquePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38813,7 +39212,8 @@ impl Default for UpperUniquePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38834,7 +39234,8 @@ impl Default for AggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetData {
     pub type_: NodeTag,
     pub set: *mut List,
@@ -38850,7 +39251,8 @@ impl Default for GroupingSetData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RollupData {
     pub type_: NodeTag,
     pub groupClause: *mut List,
@@ -38870,7 +39272,8 @@ impl Default for RollupData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct GroupingSetsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38889,7 +39292,8 @@ impl Default for GroupingSetsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggPath {
     pub path: Path,
     pub mmaggregates: *mut List,
@@ -38905,7 +39309,8 @@ impl Default for MinMaxAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct WindowAggPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38923,7 +39328,8 @@ impl Default for WindowAggPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SetOpPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38944,7 +39350,8 @@ impl Default for SetOpPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RecursiveUnionPath {
     pub path: Path,
     pub leftpath: *mut Path,
@@ -38963,7 +39370,8 @@ impl Default for RecursiveUnionPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LockRowsPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -38980,7 +39388,8 @@ impl Default for LockRowsPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ModifyTablePath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -39008,7 +39417,8 @@ impl Default for ModifyTablePath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct LimitPath {
     pub path: Path,
     pub subpath: *mut Path,
@@ -39026,7 +39436,8 @@ impl Default for LimitPath {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RestrictInfo {
     pub type_: NodeTag,
     pub clause: *mut Expr,
@@ -39094,7 +39505,8 @@ impl Default for MergeScanSelCache {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderVar {
     pub xpr: Expr,
     pub phexpr: *mut Expr,
@@ -39112,7 +39524,8 @@ impl Default for PlaceHolderVar {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SpecialJoinInfo {
     pub type_: NodeTag,
     pub min_lefthand: Relids,
@@ -39137,7 +39550,8 @@ impl Default for SpecialJoinInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct AppendRelInfo {
     pub type_: NodeTag,
     pub parent_relid: Index,
@@ -39159,7 +39573,8 @@ impl Default for AppendRelInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct RowIdentityVarInfo {
     pub type_: NodeTag,
     pub rowidvar: *mut Var,
@@ -39177,7 +39592,8 @@ impl Default for RowIdentityVarInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlaceHolderInfo {
     pub type_: NodeTag,
     pub phid: Index,
@@ -39197,7 +39613,8 @@ impl Default for PlaceHolderInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct MinMaxAggInfo {
     pub type_: NodeTag,
     pub aggfnoid: Oid,
@@ -39218,7 +39635,8 @@ impl Default for MinMaxAggInfo {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct PlannerParamItem {
     pub type_: NodeTag,
     pub item: *mut Node,
@@ -39580,7 +39998,8 @@ pub type ForeignAsyncConfigureWait_function =
 pub type ForeignAsyncNotify_function =
     ::std::option::Option<unsafe extern "C" fn(areq: *mut AsyncRequest)>;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct FdwRoutine {
     pub type_: NodeTag,
     pub GetForeignRelSize: GetForeignRelSize_function,
@@ -40456,7 +40875,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ExtensibleNode {
     pub type_: NodeTag,
     pub extnodename: *const ::std::os::raw::c_char,
@@ -41068,7 +41488,8 @@ pub const ReplicationKind_REPLICATION_KIND_PHYSICAL: ReplicationKind = 0;
 pub const ReplicationKind_REPLICATION_KIND_LOGICAL: ReplicationKind = 1;
 pub type ReplicationKind = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct IdentifySystemCmd {
     pub type_: NodeTag,
 }
@@ -41082,7 +41503,8 @@ impl Default for IdentifySystemCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct BaseBackupCmd {
     pub type_: NodeTag,
     pub options: *mut List,
@@ -41097,7 +41519,8 @@ impl Default for BaseBackupCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct CreateReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -41116,7 +41539,8 @@ impl Default for CreateReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct DropReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -41132,7 +41556,8 @@ impl Default for DropReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct StartReplicationCmd {
     pub type_: NodeTag,
     pub kind: ReplicationKind,
@@ -41151,7 +41576,8 @@ impl Default for StartReplicationCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct ReadReplicationSlotCmd {
     pub type_: NodeTag,
     pub slotname: *mut ::std::os::raw::c_char,
@@ -41166,7 +41592,8 @@ impl Default for ReadReplicationSlotCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct TimeLineHistoryCmd {
     pub type_: NodeTag,
     pub timeline: TimeLineID,
@@ -41181,7 +41608,8 @@ impl Default for TimeLineHistoryCmd {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSimplify {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -41197,7 +41625,8 @@ impl Default for SupportRequestSimplify {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestSelectivity {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -41220,7 +41649,8 @@ impl Default for SupportRequestSelectivity {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestCost {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -41239,7 +41669,8 @@ impl Default for SupportRequestCost {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestRows {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -41257,7 +41688,8 @@ impl Default for SupportRequestRows {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestIndexCondition {
     pub type_: NodeTag,
     pub root: *mut PlannerInfo,
@@ -41280,7 +41712,8 @@ impl Default for SupportRequestIndexCondition {
     }
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "node-debug"), derive(Debug))]
 pub struct SupportRequestWFuncMonotonic {
     pub type_: NodeTag,
     pub window_func: *mut WindowFunc,
@@ -64923,6 +65356,12 @@ impl std::fmt::Display for A_ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Const {}
 impl pg_sys::PgNode for A_Const {}
 impl std::fmt::Display for A_Const {
@@ -64930,6 +65369,12 @@ impl std::fmt::Display for A_Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Const"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Expr {}
 impl pg_sys::PgNode for A_Expr {}
 impl std::fmt::Display for A_Expr {
@@ -64937,6 +65382,12 @@ impl std::fmt::Display for A_Expr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indices {}
 impl pg_sys::PgNode for A_Indices {}
 impl std::fmt::Display for A_Indices {
@@ -64944,6 +65395,12 @@ impl std::fmt::Display for A_Indices {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indices"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Indirection {}
 impl pg_sys::PgNode for A_Indirection {}
 impl std::fmt::Display for A_Indirection {
@@ -64951,6 +65408,12 @@ impl std::fmt::Display for A_Indirection {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Indirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Indirection"))
+    }
+}
 impl pg_sys::seal::Sealed for A_Star {}
 impl pg_sys::PgNode for A_Star {}
 impl std::fmt::Display for A_Star {
@@ -64958,6 +65421,12 @@ impl std::fmt::Display for A_Star {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for A_Star {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "A_Star"))
+    }
+}
 impl pg_sys::seal::Sealed for AccessPriv {}
 impl pg_sys::PgNode for AccessPriv {}
 impl std::fmt::Display for AccessPriv {
@@ -64965,6 +65434,12 @@ impl std::fmt::Display for AccessPriv {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AccessPriv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AccessPriv"))
+    }
+}
 impl pg_sys::seal::Sealed for Agg {}
 impl pg_sys::PgNode for Agg {}
 impl std::fmt::Display for Agg {
@@ -64972,6 +65447,12 @@ impl std::fmt::Display for Agg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Agg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Agg"))
+    }
+}
 impl pg_sys::seal::Sealed for AggPath {}
 impl pg_sys::PgNode for AggPath {}
 impl std::fmt::Display for AggPath {
@@ -64979,6 +65460,12 @@ impl std::fmt::Display for AggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AggState {}
 impl pg_sys::PgNode for AggState {}
 impl std::fmt::Display for AggState {
@@ -64986,6 +65473,12 @@ impl std::fmt::Display for AggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AggState"))
+    }
+}
 impl pg_sys::seal::Sealed for Aggref {}
 impl pg_sys::PgNode for Aggref {}
 impl std::fmt::Display for Aggref {
@@ -64993,6 +65486,12 @@ impl std::fmt::Display for Aggref {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Aggref {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Aggref"))
+    }
+}
 impl pg_sys::seal::Sealed for Alias {}
 impl pg_sys::PgNode for Alias {}
 impl std::fmt::Display for Alias {
@@ -65000,6 +65499,12 @@ impl std::fmt::Display for Alias {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Alias"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterCollationStmt {}
 impl pg_sys::PgNode for AlterCollationStmt {}
 impl std::fmt::Display for AlterCollationStmt {
@@ -65007,6 +65512,12 @@ impl std::fmt::Display for AlterCollationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterCollationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterCollationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseRefreshCollStmt {}
 impl pg_sys::PgNode for AlterDatabaseRefreshCollStmt {}
 impl std::fmt::Display for AlterDatabaseRefreshCollStmt {
@@ -65014,6 +65525,12 @@ impl std::fmt::Display for AlterDatabaseRefreshCollStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseRefreshCollStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseRefreshCollStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseSetStmt {}
 impl pg_sys::PgNode for AlterDatabaseSetStmt {}
 impl std::fmt::Display for AlterDatabaseSetStmt {
@@ -65021,6 +65538,12 @@ impl std::fmt::Display for AlterDatabaseSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDatabaseStmt {}
 impl pg_sys::PgNode for AlterDatabaseStmt {}
 impl std::fmt::Display for AlterDatabaseStmt {
@@ -65028,6 +65551,12 @@ impl std::fmt::Display for AlterDatabaseStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDatabaseStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDatabaseStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDefaultPrivilegesStmt {}
 impl pg_sys::PgNode for AlterDefaultPrivilegesStmt {}
 impl std::fmt::Display for AlterDefaultPrivilegesStmt {
@@ -65035,6 +65564,12 @@ impl std::fmt::Display for AlterDefaultPrivilegesStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDefaultPrivilegesStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDefaultPrivilegesStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterDomainStmt {}
 impl pg_sys::PgNode for AlterDomainStmt {}
 impl std::fmt::Display for AlterDomainStmt {
@@ -65042,6 +65577,12 @@ impl std::fmt::Display for AlterDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEnumStmt {}
 impl pg_sys::PgNode for AlterEnumStmt {}
 impl std::fmt::Display for AlterEnumStmt {
@@ -65049,6 +65590,12 @@ impl std::fmt::Display for AlterEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterEventTrigStmt {}
 impl pg_sys::PgNode for AlterEventTrigStmt {}
 impl std::fmt::Display for AlterEventTrigStmt {
@@ -65056,6 +65603,12 @@ impl std::fmt::Display for AlterEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionContentsStmt {}
 impl pg_sys::PgNode for AlterExtensionContentsStmt {}
 impl std::fmt::Display for AlterExtensionContentsStmt {
@@ -65063,6 +65616,12 @@ impl std::fmt::Display for AlterExtensionContentsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionContentsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionContentsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterExtensionStmt {}
 impl pg_sys::PgNode for AlterExtensionStmt {}
 impl std::fmt::Display for AlterExtensionStmt {
@@ -65070,6 +65629,12 @@ impl std::fmt::Display for AlterExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFdwStmt {}
 impl pg_sys::PgNode for AlterFdwStmt {}
 impl std::fmt::Display for AlterFdwStmt {
@@ -65077,6 +65642,12 @@ impl std::fmt::Display for AlterFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterForeignServerStmt {}
 impl pg_sys::PgNode for AlterForeignServerStmt {}
 impl std::fmt::Display for AlterForeignServerStmt {
@@ -65084,6 +65655,12 @@ impl std::fmt::Display for AlterForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterFunctionStmt {}
 impl pg_sys::PgNode for AlterFunctionStmt {}
 impl std::fmt::Display for AlterFunctionStmt {
@@ -65091,6 +65668,12 @@ impl std::fmt::Display for AlterFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectDependsStmt {}
 impl pg_sys::PgNode for AlterObjectDependsStmt {}
 impl std::fmt::Display for AlterObjectDependsStmt {
@@ -65098,6 +65681,12 @@ impl std::fmt::Display for AlterObjectDependsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectDependsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectDependsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterObjectSchemaStmt {}
 impl pg_sys::PgNode for AlterObjectSchemaStmt {}
 impl std::fmt::Display for AlterObjectSchemaStmt {
@@ -65105,6 +65694,12 @@ impl std::fmt::Display for AlterObjectSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterObjectSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterObjectSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOpFamilyStmt {}
 impl pg_sys::PgNode for AlterOpFamilyStmt {}
 impl std::fmt::Display for AlterOpFamilyStmt {
@@ -65112,6 +65707,12 @@ impl std::fmt::Display for AlterOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOperatorStmt {}
 impl pg_sys::PgNode for AlterOperatorStmt {}
 impl std::fmt::Display for AlterOperatorStmt {
@@ -65119,6 +65720,12 @@ impl std::fmt::Display for AlterOperatorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOperatorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOperatorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterOwnerStmt {}
 impl pg_sys::PgNode for AlterOwnerStmt {}
 impl std::fmt::Display for AlterOwnerStmt {
@@ -65126,6 +65733,12 @@ impl std::fmt::Display for AlterOwnerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterOwnerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterOwnerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPolicyStmt {}
 impl pg_sys::PgNode for AlterPolicyStmt {}
 impl std::fmt::Display for AlterPolicyStmt {
@@ -65133,6 +65746,12 @@ impl std::fmt::Display for AlterPolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterPublicationStmt {}
 impl pg_sys::PgNode for AlterPublicationStmt {}
 impl std::fmt::Display for AlterPublicationStmt {
@@ -65140,6 +65759,12 @@ impl std::fmt::Display for AlterPublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterPublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterPublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleSetStmt {}
 impl pg_sys::PgNode for AlterRoleSetStmt {}
 impl std::fmt::Display for AlterRoleSetStmt {
@@ -65147,6 +65772,12 @@ impl std::fmt::Display for AlterRoleSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterRoleStmt {}
 impl pg_sys::PgNode for AlterRoleStmt {}
 impl std::fmt::Display for AlterRoleStmt {
@@ -65154,6 +65785,12 @@ impl std::fmt::Display for AlterRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSeqStmt {}
 impl pg_sys::PgNode for AlterSeqStmt {}
 impl std::fmt::Display for AlterSeqStmt {
@@ -65161,6 +65798,12 @@ impl std::fmt::Display for AlterSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterStatsStmt {}
 impl pg_sys::PgNode for AlterStatsStmt {}
 impl std::fmt::Display for AlterStatsStmt {
@@ -65168,6 +65811,12 @@ impl std::fmt::Display for AlterStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSubscriptionStmt {}
 impl pg_sys::PgNode for AlterSubscriptionStmt {}
 impl std::fmt::Display for AlterSubscriptionStmt {
@@ -65175,6 +65824,12 @@ impl std::fmt::Display for AlterSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterSystemStmt {}
 impl pg_sys::PgNode for AlterSystemStmt {}
 impl std::fmt::Display for AlterSystemStmt {
@@ -65182,6 +65837,12 @@ impl std::fmt::Display for AlterSystemStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterSystemStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterSystemStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSConfigurationStmt {}
 impl pg_sys::PgNode for AlterTSConfigurationStmt {}
 impl std::fmt::Display for AlterTSConfigurationStmt {
@@ -65189,6 +65850,12 @@ impl std::fmt::Display for AlterTSConfigurationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSConfigurationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSConfigurationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTSDictionaryStmt {}
 impl pg_sys::PgNode for AlterTSDictionaryStmt {}
 impl std::fmt::Display for AlterTSDictionaryStmt {
@@ -65196,6 +65863,12 @@ impl std::fmt::Display for AlterTSDictionaryStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTSDictionaryStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTSDictionaryStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableCmd {}
 impl pg_sys::PgNode for AlterTableCmd {}
 impl std::fmt::Display for AlterTableCmd {
@@ -65203,6 +65876,12 @@ impl std::fmt::Display for AlterTableCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableMoveAllStmt {}
 impl pg_sys::PgNode for AlterTableMoveAllStmt {}
 impl std::fmt::Display for AlterTableMoveAllStmt {
@@ -65210,6 +65889,12 @@ impl std::fmt::Display for AlterTableMoveAllStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableMoveAllStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableMoveAllStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableSpaceOptionsStmt {}
 impl pg_sys::PgNode for AlterTableSpaceOptionsStmt {}
 impl std::fmt::Display for AlterTableSpaceOptionsStmt {
@@ -65217,6 +65902,12 @@ impl std::fmt::Display for AlterTableSpaceOptionsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableSpaceOptionsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableSpaceOptionsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTableStmt {}
 impl pg_sys::PgNode for AlterTableStmt {}
 impl std::fmt::Display for AlterTableStmt {
@@ -65224,6 +65915,12 @@ impl std::fmt::Display for AlterTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterTypeStmt {}
 impl pg_sys::PgNode for AlterTypeStmt {}
 impl std::fmt::Display for AlterTypeStmt {
@@ -65231,6 +65928,12 @@ impl std::fmt::Display for AlterTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlterUserMappingStmt {}
 impl pg_sys::PgNode for AlterUserMappingStmt {}
 impl std::fmt::Display for AlterUserMappingStmt {
@@ -65238,6 +65941,12 @@ impl std::fmt::Display for AlterUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlterUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlterUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for AlternativeSubPlan {}
 impl pg_sys::PgNode for AlternativeSubPlan {}
 impl std::fmt::Display for AlternativeSubPlan {
@@ -65245,6 +65954,12 @@ impl std::fmt::Display for AlternativeSubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AlternativeSubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AlternativeSubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for Append {}
 impl pg_sys::PgNode for Append {}
 impl std::fmt::Display for Append {
@@ -65252,6 +65967,12 @@ impl std::fmt::Display for Append {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Append {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Append"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendPath {}
 impl pg_sys::PgNode for AppendPath {}
 impl std::fmt::Display for AppendPath {
@@ -65259,6 +65980,12 @@ impl std::fmt::Display for AppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendRelInfo {}
 impl pg_sys::PgNode for AppendRelInfo {}
 impl std::fmt::Display for AppendRelInfo {
@@ -65266,6 +65993,12 @@ impl std::fmt::Display for AppendRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for AppendState {}
 impl pg_sys::PgNode for AppendState {}
 impl std::fmt::Display for AppendState {
@@ -65273,6 +66006,12 @@ impl std::fmt::Display for AppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for AppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "AppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayCoerceExpr {}
 impl pg_sys::PgNode for ArrayCoerceExpr {}
 impl std::fmt::Display for ArrayCoerceExpr {
@@ -65280,6 +66019,12 @@ impl std::fmt::Display for ArrayCoerceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayCoerceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayCoerceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ArrayExpr {}
 impl pg_sys::PgNode for ArrayExpr {}
 impl std::fmt::Display for ArrayExpr {
@@ -65287,6 +66032,12 @@ impl std::fmt::Display for ArrayExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ArrayExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for BaseBackupCmd {}
 impl pg_sys::PgNode for BaseBackupCmd {}
 impl std::fmt::Display for BaseBackupCmd {
@@ -65294,6 +66045,12 @@ impl std::fmt::Display for BaseBackupCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BaseBackupCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BaseBackupCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitString {}
 impl pg_sys::PgNode for BitString {}
 impl std::fmt::Display for BitString {
@@ -65301,6 +66058,12 @@ impl std::fmt::Display for BitString {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitString"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAnd {}
 impl pg_sys::PgNode for BitmapAnd {}
 impl std::fmt::Display for BitmapAnd {
@@ -65308,6 +66071,12 @@ impl std::fmt::Display for BitmapAnd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAnd"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndPath {}
 impl pg_sys::PgNode for BitmapAndPath {}
 impl std::fmt::Display for BitmapAndPath {
@@ -65315,6 +66084,12 @@ impl std::fmt::Display for BitmapAndPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapAndState {}
 impl pg_sys::PgNode for BitmapAndState {}
 impl std::fmt::Display for BitmapAndState {
@@ -65322,6 +66097,12 @@ impl std::fmt::Display for BitmapAndState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapAndState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapAndState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapPath {}
 impl pg_sys::PgNode for BitmapHeapPath {}
 impl std::fmt::Display for BitmapHeapPath {
@@ -65329,6 +66110,12 @@ impl std::fmt::Display for BitmapHeapPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScan {}
 impl pg_sys::PgNode for BitmapHeapScan {}
 impl std::fmt::Display for BitmapHeapScan {
@@ -65336,6 +66123,12 @@ impl std::fmt::Display for BitmapHeapScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapHeapScanState {}
 impl pg_sys::PgNode for BitmapHeapScanState {}
 impl std::fmt::Display for BitmapHeapScanState {
@@ -65343,6 +66136,12 @@ impl std::fmt::Display for BitmapHeapScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapHeapScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapHeapScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScan {}
 impl pg_sys::PgNode for BitmapIndexScan {}
 impl std::fmt::Display for BitmapIndexScan {
@@ -65350,6 +66149,12 @@ impl std::fmt::Display for BitmapIndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapIndexScanState {}
 impl pg_sys::PgNode for BitmapIndexScanState {}
 impl std::fmt::Display for BitmapIndexScanState {
@@ -65357,6 +66162,12 @@ impl std::fmt::Display for BitmapIndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapIndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapIndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOr {}
 impl pg_sys::PgNode for BitmapOr {}
 impl std::fmt::Display for BitmapOr {
@@ -65364,6 +66175,12 @@ impl std::fmt::Display for BitmapOr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOr"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrPath {}
 impl pg_sys::PgNode for BitmapOrPath {}
 impl std::fmt::Display for BitmapOrPath {
@@ -65371,6 +66188,12 @@ impl std::fmt::Display for BitmapOrPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrPath"))
+    }
+}
 impl pg_sys::seal::Sealed for BitmapOrState {}
 impl pg_sys::PgNode for BitmapOrState {}
 impl std::fmt::Display for BitmapOrState {
@@ -65378,6 +66201,12 @@ impl std::fmt::Display for BitmapOrState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BitmapOrState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BitmapOrState"))
+    }
+}
 impl pg_sys::seal::Sealed for BoolExpr {}
 impl pg_sys::PgNode for BoolExpr {}
 impl std::fmt::Display for BoolExpr {
@@ -65385,6 +66214,12 @@ impl std::fmt::Display for BoolExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BoolExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BoolExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Boolean {}
 impl pg_sys::PgNode for Boolean {}
 impl std::fmt::Display for Boolean {
@@ -65392,6 +66227,12 @@ impl std::fmt::Display for Boolean {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Boolean {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Boolean"))
+    }
+}
 impl pg_sys::seal::Sealed for BooleanTest {}
 impl pg_sys::PgNode for BooleanTest {}
 impl std::fmt::Display for BooleanTest {
@@ -65399,6 +66240,12 @@ impl std::fmt::Display for BooleanTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BooleanTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BooleanTest"))
+    }
+}
 impl pg_sys::seal::Sealed for BufferHeapTupleTableSlot {}
 impl pg_sys::PgNode for BufferHeapTupleTableSlot {}
 impl std::fmt::Display for BufferHeapTupleTableSlot {
@@ -65406,6 +66253,12 @@ impl std::fmt::Display for BufferHeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for BufferHeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "BufferHeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for CTECycleClause {}
 impl pg_sys::PgNode for CTECycleClause {}
 impl std::fmt::Display for CTECycleClause {
@@ -65413,6 +66266,12 @@ impl std::fmt::Display for CTECycleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CTECycleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CTECycleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CTESearchClause {}
 impl pg_sys::PgNode for CTESearchClause {}
 impl std::fmt::Display for CTESearchClause {
@@ -65420,6 +66279,12 @@ impl std::fmt::Display for CTESearchClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CTESearchClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CTESearchClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CallContext {}
 impl pg_sys::PgNode for CallContext {}
 impl std::fmt::Display for CallContext {
@@ -65427,6 +66292,12 @@ impl std::fmt::Display for CallContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallContext"))
+    }
+}
 impl pg_sys::seal::Sealed for CallStmt {}
 impl pg_sys::PgNode for CallStmt {}
 impl std::fmt::Display for CallStmt {
@@ -65434,6 +66305,12 @@ impl std::fmt::Display for CallStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CallStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CallStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseExpr {}
 impl pg_sys::PgNode for CaseExpr {}
 impl std::fmt::Display for CaseExpr {
@@ -65441,6 +66318,12 @@ impl std::fmt::Display for CaseExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseTestExpr {}
 impl pg_sys::PgNode for CaseTestExpr {}
 impl std::fmt::Display for CaseTestExpr {
@@ -65448,6 +66331,12 @@ impl std::fmt::Display for CaseTestExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseTestExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseTestExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CaseWhen {}
 impl pg_sys::PgNode for CaseWhen {}
 impl std::fmt::Display for CaseWhen {
@@ -65455,6 +66344,12 @@ impl std::fmt::Display for CaseWhen {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CaseWhen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CaseWhen"))
+    }
+}
 impl pg_sys::seal::Sealed for CheckPointStmt {}
 impl pg_sys::PgNode for CheckPointStmt {}
 impl std::fmt::Display for CheckPointStmt {
@@ -65462,6 +66357,12 @@ impl std::fmt::Display for CheckPointStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CheckPointStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CheckPointStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClosePortalStmt {}
 impl pg_sys::PgNode for ClosePortalStmt {}
 impl std::fmt::Display for ClosePortalStmt {
@@ -65469,6 +66370,12 @@ impl std::fmt::Display for ClosePortalStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClosePortalStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClosePortalStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ClusterStmt {}
 impl pg_sys::PgNode for ClusterStmt {}
 impl std::fmt::Display for ClusterStmt {
@@ -65476,6 +66383,12 @@ impl std::fmt::Display for ClusterStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ClusterStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ClusterStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CoalesceExpr {}
 impl pg_sys::PgNode for CoalesceExpr {}
 impl std::fmt::Display for CoalesceExpr {
@@ -65483,6 +66396,12 @@ impl std::fmt::Display for CoalesceExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoalesceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoalesceExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomain {}
 impl pg_sys::PgNode for CoerceToDomain {}
 impl std::fmt::Display for CoerceToDomain {
@@ -65490,6 +66409,12 @@ impl std::fmt::Display for CoerceToDomain {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomain"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceToDomainValue {}
 impl pg_sys::PgNode for CoerceToDomainValue {}
 impl std::fmt::Display for CoerceToDomainValue {
@@ -65497,6 +66422,12 @@ impl std::fmt::Display for CoerceToDomainValue {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceToDomainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceToDomainValue"))
+    }
+}
 impl pg_sys::seal::Sealed for CoerceViaIO {}
 impl pg_sys::PgNode for CoerceViaIO {}
 impl std::fmt::Display for CoerceViaIO {
@@ -65504,6 +66435,12 @@ impl std::fmt::Display for CoerceViaIO {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CoerceViaIO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CoerceViaIO"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateClause {}
 impl pg_sys::PgNode for CollateClause {}
 impl std::fmt::Display for CollateClause {
@@ -65511,6 +66448,12 @@ impl std::fmt::Display for CollateClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateClause"))
+    }
+}
 impl pg_sys::seal::Sealed for CollateExpr {}
 impl pg_sys::PgNode for CollateExpr {}
 impl std::fmt::Display for CollateExpr {
@@ -65518,6 +66461,12 @@ impl std::fmt::Display for CollateExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CollateExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CollateExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnDef {}
 impl pg_sys::PgNode for ColumnDef {}
 impl std::fmt::Display for ColumnDef {
@@ -65525,6 +66474,12 @@ impl std::fmt::Display for ColumnDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnDef"))
+    }
+}
 impl pg_sys::seal::Sealed for ColumnRef {}
 impl pg_sys::PgNode for ColumnRef {}
 impl std::fmt::Display for ColumnRef {
@@ -65532,6 +66487,12 @@ impl std::fmt::Display for ColumnRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ColumnRef"))
+    }
+}
 impl pg_sys::seal::Sealed for CommentStmt {}
 impl pg_sys::PgNode for CommentStmt {}
 impl std::fmt::Display for CommentStmt {
@@ -65539,6 +66500,12 @@ impl std::fmt::Display for CommentStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommentStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommentStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CommonTableExpr {}
 impl pg_sys::PgNode for CommonTableExpr {}
 impl std::fmt::Display for CommonTableExpr {
@@ -65546,6 +66513,12 @@ impl std::fmt::Display for CommonTableExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CommonTableExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CommonTableExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CompositeTypeStmt {}
 impl pg_sys::PgNode for CompositeTypeStmt {}
 impl std::fmt::Display for CompositeTypeStmt {
@@ -65553,6 +66526,12 @@ impl std::fmt::Display for CompositeTypeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CompositeTypeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CompositeTypeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Const {}
 impl pg_sys::PgNode for Const {}
 impl std::fmt::Display for Const {
@@ -65560,6 +66539,12 @@ impl std::fmt::Display for Const {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Const {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Const"))
+    }
+}
 impl pg_sys::seal::Sealed for Constraint {}
 impl pg_sys::PgNode for Constraint {}
 impl std::fmt::Display for Constraint {
@@ -65567,6 +66552,12 @@ impl std::fmt::Display for Constraint {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Constraint"))
+    }
+}
 impl pg_sys::seal::Sealed for ConstraintsSetStmt {}
 impl pg_sys::PgNode for ConstraintsSetStmt {}
 impl std::fmt::Display for ConstraintsSetStmt {
@@ -65574,6 +66565,12 @@ impl std::fmt::Display for ConstraintsSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConstraintsSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConstraintsSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ConvertRowtypeExpr {}
 impl pg_sys::PgNode for ConvertRowtypeExpr {}
 impl std::fmt::Display for ConvertRowtypeExpr {
@@ -65581,6 +66578,12 @@ impl std::fmt::Display for ConvertRowtypeExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ConvertRowtypeExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ConvertRowtypeExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CopyStmt {}
 impl pg_sys::PgNode for CopyStmt {}
 impl std::fmt::Display for CopyStmt {
@@ -65588,6 +66591,12 @@ impl std::fmt::Display for CopyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CopyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CopyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateAmStmt {}
 impl pg_sys::PgNode for CreateAmStmt {}
 impl std::fmt::Display for CreateAmStmt {
@@ -65595,6 +66604,12 @@ impl std::fmt::Display for CreateAmStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateAmStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateAmStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateCastStmt {}
 impl pg_sys::PgNode for CreateCastStmt {}
 impl std::fmt::Display for CreateCastStmt {
@@ -65602,6 +66617,12 @@ impl std::fmt::Display for CreateCastStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateCastStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateCastStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateConversionStmt {}
 impl pg_sys::PgNode for CreateConversionStmt {}
 impl std::fmt::Display for CreateConversionStmt {
@@ -65609,6 +66630,12 @@ impl std::fmt::Display for CreateConversionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateConversionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateConversionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateDomainStmt {}
 impl pg_sys::PgNode for CreateDomainStmt {}
 impl std::fmt::Display for CreateDomainStmt {
@@ -65616,6 +66643,12 @@ impl std::fmt::Display for CreateDomainStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateDomainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateDomainStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEnumStmt {}
 impl pg_sys::PgNode for CreateEnumStmt {}
 impl std::fmt::Display for CreateEnumStmt {
@@ -65623,6 +66656,12 @@ impl std::fmt::Display for CreateEnumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEnumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEnumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateEventTrigStmt {}
 impl pg_sys::PgNode for CreateEventTrigStmt {}
 impl std::fmt::Display for CreateEventTrigStmt {
@@ -65630,6 +66669,12 @@ impl std::fmt::Display for CreateEventTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateEventTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateEventTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateExtensionStmt {}
 impl pg_sys::PgNode for CreateExtensionStmt {}
 impl std::fmt::Display for CreateExtensionStmt {
@@ -65637,6 +66682,12 @@ impl std::fmt::Display for CreateExtensionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateExtensionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateExtensionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFdwStmt {}
 impl pg_sys::PgNode for CreateFdwStmt {}
 impl std::fmt::Display for CreateFdwStmt {
@@ -65644,6 +66695,12 @@ impl std::fmt::Display for CreateFdwStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFdwStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFdwStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignServerStmt {}
 impl pg_sys::PgNode for CreateForeignServerStmt {}
 impl std::fmt::Display for CreateForeignServerStmt {
@@ -65651,6 +66708,12 @@ impl std::fmt::Display for CreateForeignServerStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignServerStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignServerStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateForeignTableStmt {}
 impl pg_sys::PgNode for CreateForeignTableStmt {}
 impl std::fmt::Display for CreateForeignTableStmt {
@@ -65658,6 +66721,12 @@ impl std::fmt::Display for CreateForeignTableStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateForeignTableStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateForeignTableStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateFunctionStmt {}
 impl pg_sys::PgNode for CreateFunctionStmt {}
 impl std::fmt::Display for CreateFunctionStmt {
@@ -65665,6 +66734,12 @@ impl std::fmt::Display for CreateFunctionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateFunctionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateFunctionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassItem {}
 impl pg_sys::PgNode for CreateOpClassItem {}
 impl std::fmt::Display for CreateOpClassItem {
@@ -65672,6 +66747,12 @@ impl std::fmt::Display for CreateOpClassItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassItem"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpClassStmt {}
 impl pg_sys::PgNode for CreateOpClassStmt {}
 impl std::fmt::Display for CreateOpClassStmt {
@@ -65679,6 +66760,12 @@ impl std::fmt::Display for CreateOpClassStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpClassStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpClassStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateOpFamilyStmt {}
 impl pg_sys::PgNode for CreateOpFamilyStmt {}
 impl std::fmt::Display for CreateOpFamilyStmt {
@@ -65686,6 +66773,12 @@ impl std::fmt::Display for CreateOpFamilyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateOpFamilyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateOpFamilyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePLangStmt {}
 impl pg_sys::PgNode for CreatePLangStmt {}
 impl std::fmt::Display for CreatePLangStmt {
@@ -65693,6 +66786,12 @@ impl std::fmt::Display for CreatePLangStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePLangStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePLangStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePolicyStmt {}
 impl pg_sys::PgNode for CreatePolicyStmt {}
 impl std::fmt::Display for CreatePolicyStmt {
@@ -65700,6 +66799,12 @@ impl std::fmt::Display for CreatePolicyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePolicyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePolicyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatePublicationStmt {}
 impl pg_sys::PgNode for CreatePublicationStmt {}
 impl std::fmt::Display for CreatePublicationStmt {
@@ -65707,6 +66812,12 @@ impl std::fmt::Display for CreatePublicationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatePublicationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatePublicationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRangeStmt {}
 impl pg_sys::PgNode for CreateRangeStmt {}
 impl std::fmt::Display for CreateRangeStmt {
@@ -65714,6 +66825,12 @@ impl std::fmt::Display for CreateRangeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRangeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRangeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateReplicationSlotCmd {}
 impl pg_sys::PgNode for CreateReplicationSlotCmd {}
 impl std::fmt::Display for CreateReplicationSlotCmd {
@@ -65721,6 +66838,12 @@ impl std::fmt::Display for CreateReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateRoleStmt {}
 impl pg_sys::PgNode for CreateRoleStmt {}
 impl std::fmt::Display for CreateRoleStmt {
@@ -65728,6 +66851,12 @@ impl std::fmt::Display for CreateRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSchemaStmt {}
 impl pg_sys::PgNode for CreateSchemaStmt {}
 impl std::fmt::Display for CreateSchemaStmt {
@@ -65735,6 +66864,12 @@ impl std::fmt::Display for CreateSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSeqStmt {}
 impl pg_sys::PgNode for CreateSeqStmt {}
 impl std::fmt::Display for CreateSeqStmt {
@@ -65742,6 +66877,12 @@ impl std::fmt::Display for CreateSeqStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSeqStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSeqStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStatsStmt {}
 impl pg_sys::PgNode for CreateStatsStmt {}
 impl std::fmt::Display for CreateStatsStmt {
@@ -65749,6 +66890,12 @@ impl std::fmt::Display for CreateStatsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStatsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStatsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateStmt {}
 impl pg_sys::PgNode for CreateStmt {}
 impl std::fmt::Display for CreateStmt {
@@ -65756,6 +66903,12 @@ impl std::fmt::Display for CreateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateSubscriptionStmt {}
 impl pg_sys::PgNode for CreateSubscriptionStmt {}
 impl std::fmt::Display for CreateSubscriptionStmt {
@@ -65763,6 +66916,12 @@ impl std::fmt::Display for CreateSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableAsStmt {}
 impl pg_sys::PgNode for CreateTableAsStmt {}
 impl std::fmt::Display for CreateTableAsStmt {
@@ -65770,6 +66929,12 @@ impl std::fmt::Display for CreateTableAsStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableAsStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableAsStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTableSpaceStmt {}
 impl pg_sys::PgNode for CreateTableSpaceStmt {}
 impl std::fmt::Display for CreateTableSpaceStmt {
@@ -65777,6 +66942,12 @@ impl std::fmt::Display for CreateTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTransformStmt {}
 impl pg_sys::PgNode for CreateTransformStmt {}
 impl std::fmt::Display for CreateTransformStmt {
@@ -65784,6 +66955,12 @@ impl std::fmt::Display for CreateTransformStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTransformStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTransformStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateTrigStmt {}
 impl pg_sys::PgNode for CreateTrigStmt {}
 impl std::fmt::Display for CreateTrigStmt {
@@ -65791,6 +66968,12 @@ impl std::fmt::Display for CreateTrigStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateTrigStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateTrigStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreateUserMappingStmt {}
 impl pg_sys::PgNode for CreateUserMappingStmt {}
 impl std::fmt::Display for CreateUserMappingStmt {
@@ -65798,6 +66981,12 @@ impl std::fmt::Display for CreateUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreateUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreateUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CreatedbStmt {}
 impl pg_sys::PgNode for CreatedbStmt {}
 impl std::fmt::Display for CreatedbStmt {
@@ -65805,6 +66994,12 @@ impl std::fmt::Display for CreatedbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CreatedbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CreatedbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScan {}
 impl pg_sys::PgNode for CteScan {}
 impl std::fmt::Display for CteScan {
@@ -65812,6 +67007,12 @@ impl std::fmt::Display for CteScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CteScanState {}
 impl pg_sys::PgNode for CteScanState {}
 impl std::fmt::Display for CteScanState {
@@ -65819,6 +67020,12 @@ impl std::fmt::Display for CteScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CteScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CteScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for CurrentOfExpr {}
 impl pg_sys::PgNode for CurrentOfExpr {}
 impl std::fmt::Display for CurrentOfExpr {
@@ -65826,6 +67033,12 @@ impl std::fmt::Display for CurrentOfExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CurrentOfExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CurrentOfExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomPath {}
 impl pg_sys::PgNode for CustomPath {}
 impl std::fmt::Display for CustomPath {
@@ -65833,6 +67046,12 @@ impl std::fmt::Display for CustomPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomPath"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScan {}
 impl pg_sys::PgNode for CustomScan {}
 impl std::fmt::Display for CustomScan {
@@ -65840,6 +67059,12 @@ impl std::fmt::Display for CustomScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScan"))
+    }
+}
 impl pg_sys::seal::Sealed for CustomScanState {}
 impl pg_sys::PgNode for CustomScanState {}
 impl std::fmt::Display for CustomScanState {
@@ -65847,6 +67072,12 @@ impl std::fmt::Display for CustomScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for CustomScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "CustomScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for DeallocateStmt {}
 impl pg_sys::PgNode for DeallocateStmt {}
 impl std::fmt::Display for DeallocateStmt {
@@ -65854,6 +67085,12 @@ impl std::fmt::Display for DeallocateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeallocateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeallocateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeclareCursorStmt {}
 impl pg_sys::PgNode for DeclareCursorStmt {}
 impl std::fmt::Display for DeclareCursorStmt {
@@ -65861,6 +67098,12 @@ impl std::fmt::Display for DeclareCursorStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeclareCursorStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeclareCursorStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DefElem {}
 impl pg_sys::PgNode for DefElem {}
 impl std::fmt::Display for DefElem {
@@ -65868,6 +67111,12 @@ impl std::fmt::Display for DefElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefElem"))
+    }
+}
 impl pg_sys::seal::Sealed for DefineStmt {}
 impl pg_sys::PgNode for DefineStmt {}
 impl std::fmt::Display for DefineStmt {
@@ -65875,6 +67124,12 @@ impl std::fmt::Display for DefineStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DefineStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DefineStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DeleteStmt {}
 impl pg_sys::PgNode for DeleteStmt {}
 impl std::fmt::Display for DeleteStmt {
@@ -65882,6 +67137,12 @@ impl std::fmt::Display for DeleteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DeleteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DeleteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DiscardStmt {}
 impl pg_sys::PgNode for DiscardStmt {}
 impl std::fmt::Display for DiscardStmt {
@@ -65889,6 +67150,12 @@ impl std::fmt::Display for DiscardStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DiscardStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DiscardStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DoStmt {}
 impl pg_sys::PgNode for DoStmt {}
 impl std::fmt::Display for DoStmt {
@@ -65896,6 +67163,12 @@ impl std::fmt::Display for DoStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DoStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DoStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DomainConstraintState {}
 impl pg_sys::PgNode for DomainConstraintState {}
 impl std::fmt::Display for DomainConstraintState {
@@ -65903,6 +67176,12 @@ impl std::fmt::Display for DomainConstraintState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DomainConstraintState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DomainConstraintState"))
+    }
+}
 impl pg_sys::seal::Sealed for DropOwnedStmt {}
 impl pg_sys::PgNode for DropOwnedStmt {}
 impl std::fmt::Display for DropOwnedStmt {
@@ -65910,6 +67189,12 @@ impl std::fmt::Display for DropOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropReplicationSlotCmd {}
 impl pg_sys::PgNode for DropReplicationSlotCmd {}
 impl std::fmt::Display for DropReplicationSlotCmd {
@@ -65917,6 +67202,12 @@ impl std::fmt::Display for DropReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for DropRoleStmt {}
 impl pg_sys::PgNode for DropRoleStmt {}
 impl std::fmt::Display for DropRoleStmt {
@@ -65924,6 +67215,12 @@ impl std::fmt::Display for DropRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropStmt {}
 impl pg_sys::PgNode for DropStmt {}
 impl std::fmt::Display for DropStmt {
@@ -65931,6 +67228,12 @@ impl std::fmt::Display for DropStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropSubscriptionStmt {}
 impl pg_sys::PgNode for DropSubscriptionStmt {}
 impl std::fmt::Display for DropSubscriptionStmt {
@@ -65938,6 +67241,12 @@ impl std::fmt::Display for DropSubscriptionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropSubscriptionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropSubscriptionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropTableSpaceStmt {}
 impl pg_sys::PgNode for DropTableSpaceStmt {}
 impl std::fmt::Display for DropTableSpaceStmt {
@@ -65945,6 +67254,12 @@ impl std::fmt::Display for DropTableSpaceStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropTableSpaceStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropTableSpaceStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropUserMappingStmt {}
 impl pg_sys::PgNode for DropUserMappingStmt {}
 impl std::fmt::Display for DropUserMappingStmt {
@@ -65952,6 +67267,12 @@ impl std::fmt::Display for DropUserMappingStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropUserMappingStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropUserMappingStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for DropdbStmt {}
 impl pg_sys::PgNode for DropdbStmt {}
 impl std::fmt::Display for DropdbStmt {
@@ -65959,6 +67280,12 @@ impl std::fmt::Display for DropdbStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for DropdbStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "DropdbStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for EState {}
 impl pg_sys::PgNode for EState {}
 impl std::fmt::Display for EState {
@@ -65966,6 +67293,12 @@ impl std::fmt::Display for EState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EState"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceClass {}
 impl pg_sys::PgNode for EquivalenceClass {}
 impl std::fmt::Display for EquivalenceClass {
@@ -65973,6 +67306,12 @@ impl std::fmt::Display for EquivalenceClass {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceClass"))
+    }
+}
 impl pg_sys::seal::Sealed for EquivalenceMember {}
 impl pg_sys::PgNode for EquivalenceMember {}
 impl std::fmt::Display for EquivalenceMember {
@@ -65980,6 +67319,12 @@ impl std::fmt::Display for EquivalenceMember {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EquivalenceMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EquivalenceMember"))
+    }
+}
 impl pg_sys::seal::Sealed for EventTriggerData {}
 impl pg_sys::PgNode for EventTriggerData {}
 impl std::fmt::Display for EventTriggerData {
@@ -65987,6 +67332,12 @@ impl std::fmt::Display for EventTriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for EventTriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "EventTriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for ExecuteStmt {}
 impl pg_sys::PgNode for ExecuteStmt {}
 impl std::fmt::Display for ExecuteStmt {
@@ -65994,6 +67345,12 @@ impl std::fmt::Display for ExecuteStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExecuteStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExecuteStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ExplainStmt {}
 impl pg_sys::PgNode for ExplainStmt {}
 impl std::fmt::Display for ExplainStmt {
@@ -66001,13 +67358,25 @@ impl std::fmt::Display for ExplainStmt {
         write!(f, "{}", self.display_node())
     }
 }
-impl pg_sys::seal::Sealed for Expr {}
-impl pg_sys::PgNode for Expr {}
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExplainStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExplainStmt"))
+    }
+}
+impl pg_sys::seal::Sealed for Expr {}
+impl pg_sys::PgNode for Expr {}
 impl std::fmt::Display for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Expr"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprContext {}
 impl pg_sys::PgNode for ExprContext {}
 impl std::fmt::Display for ExprContext {
@@ -66015,6 +67384,12 @@ impl std::fmt::Display for ExprContext {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprContext"))
+    }
+}
 impl pg_sys::seal::Sealed for ExprState {}
 impl pg_sys::PgNode for ExprState {}
 impl std::fmt::Display for ExprState {
@@ -66022,6 +67397,12 @@ impl std::fmt::Display for ExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for ExtensibleNode {}
 impl pg_sys::PgNode for ExtensibleNode {}
 impl std::fmt::Display for ExtensibleNode {
@@ -66029,6 +67410,12 @@ impl std::fmt::Display for ExtensibleNode {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ExtensibleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ExtensibleNode"))
+    }
+}
 impl pg_sys::seal::Sealed for FdwRoutine {}
 impl pg_sys::PgNode for FdwRoutine {}
 impl std::fmt::Display for FdwRoutine {
@@ -66036,6 +67423,12 @@ impl std::fmt::Display for FdwRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FdwRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FdwRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for FetchStmt {}
 impl pg_sys::PgNode for FetchStmt {}
 impl std::fmt::Display for FetchStmt {
@@ -66043,6 +67436,12 @@ impl std::fmt::Display for FetchStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FetchStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FetchStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldSelect {}
 impl pg_sys::PgNode for FieldSelect {}
 impl std::fmt::Display for FieldSelect {
@@ -66050,6 +67449,12 @@ impl std::fmt::Display for FieldSelect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldSelect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldSelect"))
+    }
+}
 impl pg_sys::seal::Sealed for FieldStore {}
 impl pg_sys::PgNode for FieldStore {}
 impl std::fmt::Display for FieldStore {
@@ -66057,6 +67462,12 @@ impl std::fmt::Display for FieldStore {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FieldStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FieldStore"))
+    }
+}
 impl pg_sys::seal::Sealed for Float {}
 impl pg_sys::PgNode for Float {}
 impl std::fmt::Display for Float {
@@ -66064,6 +67475,12 @@ impl std::fmt::Display for Float {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Float {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Float"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyCacheInfo {}
 impl pg_sys::PgNode for ForeignKeyCacheInfo {}
 impl std::fmt::Display for ForeignKeyCacheInfo {
@@ -66071,6 +67488,12 @@ impl std::fmt::Display for ForeignKeyCacheInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyCacheInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyCacheInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignKeyOptInfo {}
 impl pg_sys::PgNode for ForeignKeyOptInfo {}
 impl std::fmt::Display for ForeignKeyOptInfo {
@@ -66078,6 +67501,12 @@ impl std::fmt::Display for ForeignKeyOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignKeyOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignKeyOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignPath {}
 impl pg_sys::PgNode for ForeignPath {}
 impl std::fmt::Display for ForeignPath {
@@ -66085,6 +67514,12 @@ impl std::fmt::Display for ForeignPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScan {}
 impl pg_sys::PgNode for ForeignScan {}
 impl std::fmt::Display for ForeignScan {
@@ -66092,6 +67527,12 @@ impl std::fmt::Display for ForeignScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ForeignScanState {}
 impl pg_sys::PgNode for ForeignScanState {}
 impl std::fmt::Display for ForeignScanState {
@@ -66099,6 +67540,12 @@ impl std::fmt::Display for ForeignScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ForeignScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ForeignScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for FromExpr {}
 impl pg_sys::PgNode for FromExpr {}
 impl std::fmt::Display for FromExpr {
@@ -66106,6 +67553,12 @@ impl std::fmt::Display for FromExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FromExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FromExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncCall {}
 impl pg_sys::PgNode for FuncCall {}
 impl std::fmt::Display for FuncCall {
@@ -66113,6 +67566,12 @@ impl std::fmt::Display for FuncCall {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncCall"))
+    }
+}
 impl pg_sys::seal::Sealed for FuncExpr {}
 impl pg_sys::PgNode for FuncExpr {}
 impl std::fmt::Display for FuncExpr {
@@ -66120,6 +67579,12 @@ impl std::fmt::Display for FuncExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FuncExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FuncExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionParameter {}
 impl pg_sys::PgNode for FunctionParameter {}
 impl std::fmt::Display for FunctionParameter {
@@ -66127,6 +67592,12 @@ impl std::fmt::Display for FunctionParameter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionParameter"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScan {}
 impl pg_sys::PgNode for FunctionScan {}
 impl std::fmt::Display for FunctionScan {
@@ -66134,6 +67605,12 @@ impl std::fmt::Display for FunctionScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScan"))
+    }
+}
 impl pg_sys::seal::Sealed for FunctionScanState {}
 impl pg_sys::PgNode for FunctionScanState {}
 impl std::fmt::Display for FunctionScanState {
@@ -66141,6 +67618,12 @@ impl std::fmt::Display for FunctionScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for FunctionScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "FunctionScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Gather {}
 impl pg_sys::PgNode for Gather {}
 impl std::fmt::Display for Gather {
@@ -66148,6 +67631,12 @@ impl std::fmt::Display for Gather {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Gather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Gather"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMerge {}
 impl pg_sys::PgNode for GatherMerge {}
 impl std::fmt::Display for GatherMerge {
@@ -66155,6 +67644,12 @@ impl std::fmt::Display for GatherMerge {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMerge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMerge"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergePath {}
 impl pg_sys::PgNode for GatherMergePath {}
 impl std::fmt::Display for GatherMergePath {
@@ -66162,6 +67657,12 @@ impl std::fmt::Display for GatherMergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherMergeState {}
 impl pg_sys::PgNode for GatherMergeState {}
 impl std::fmt::Display for GatherMergeState {
@@ -66169,6 +67670,12 @@ impl std::fmt::Display for GatherMergeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherMergeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherMergeState"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherPath {}
 impl pg_sys::PgNode for GatherPath {}
 impl std::fmt::Display for GatherPath {
@@ -66176,6 +67683,12 @@ impl std::fmt::Display for GatherPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GatherState {}
 impl pg_sys::PgNode for GatherState {}
 impl std::fmt::Display for GatherState {
@@ -66183,6 +67696,12 @@ impl std::fmt::Display for GatherState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GatherState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GatherState"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantRoleStmt {}
 impl pg_sys::PgNode for GrantRoleStmt {}
 impl std::fmt::Display for GrantRoleStmt {
@@ -66190,6 +67709,12 @@ impl std::fmt::Display for GrantRoleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantRoleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantRoleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for GrantStmt {}
 impl pg_sys::PgNode for GrantStmt {}
 impl std::fmt::Display for GrantStmt {
@@ -66197,6 +67722,12 @@ impl std::fmt::Display for GrantStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GrantStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GrantStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Group {}
 impl pg_sys::PgNode for Group {}
 impl std::fmt::Display for Group {
@@ -66204,6 +67735,12 @@ impl std::fmt::Display for Group {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Group"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupPath {}
 impl pg_sys::PgNode for GroupPath {}
 impl std::fmt::Display for GroupPath {
@@ -66211,6 +67748,12 @@ impl std::fmt::Display for GroupPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupResultPath {}
 impl pg_sys::PgNode for GroupResultPath {}
 impl std::fmt::Display for GroupResultPath {
@@ -66218,6 +67761,12 @@ impl std::fmt::Display for GroupResultPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupResultPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupResultPath"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupState {}
 impl pg_sys::PgNode for GroupState {}
 impl std::fmt::Display for GroupState {
@@ -66225,6 +67774,12 @@ impl std::fmt::Display for GroupState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupState"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingFunc {}
 impl pg_sys::PgNode for GroupingFunc {}
 impl std::fmt::Display for GroupingFunc {
@@ -66232,6 +67787,12 @@ impl std::fmt::Display for GroupingFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSet {}
 impl pg_sys::PgNode for GroupingSet {}
 impl std::fmt::Display for GroupingSet {
@@ -66239,6 +67800,12 @@ impl std::fmt::Display for GroupingSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSet"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetData {}
 impl pg_sys::PgNode for GroupingSetData {}
 impl std::fmt::Display for GroupingSetData {
@@ -66246,6 +67813,12 @@ impl std::fmt::Display for GroupingSetData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetData"))
+    }
+}
 impl pg_sys::seal::Sealed for GroupingSetsPath {}
 impl pg_sys::PgNode for GroupingSetsPath {}
 impl std::fmt::Display for GroupingSetsPath {
@@ -66253,6 +67826,12 @@ impl std::fmt::Display for GroupingSetsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for GroupingSetsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "GroupingSetsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for Hash {}
 impl pg_sys::PgNode for Hash {}
 impl std::fmt::Display for Hash {
@@ -66260,6 +67839,12 @@ impl std::fmt::Display for Hash {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Hash"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoin {}
 impl pg_sys::PgNode for HashJoin {}
 impl std::fmt::Display for HashJoin {
@@ -66267,6 +67852,12 @@ impl std::fmt::Display for HashJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for HashJoinState {}
 impl pg_sys::PgNode for HashJoinState {}
 impl std::fmt::Display for HashJoinState {
@@ -66274,6 +67865,12 @@ impl std::fmt::Display for HashJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for HashPath {}
 impl pg_sys::PgNode for HashPath {}
 impl std::fmt::Display for HashPath {
@@ -66281,6 +67878,12 @@ impl std::fmt::Display for HashPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashPath"))
+    }
+}
 impl pg_sys::seal::Sealed for HashState {}
 impl pg_sys::PgNode for HashState {}
 impl std::fmt::Display for HashState {
@@ -66288,6 +67891,12 @@ impl std::fmt::Display for HashState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HashState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HashState"))
+    }
+}
 impl pg_sys::seal::Sealed for HeapTupleTableSlot {}
 impl pg_sys::PgNode for HeapTupleTableSlot {}
 impl std::fmt::Display for HeapTupleTableSlot {
@@ -66295,6 +67904,12 @@ impl std::fmt::Display for HeapTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for HeapTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "HeapTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for IdentifySystemCmd {}
 impl pg_sys::PgNode for IdentifySystemCmd {}
 impl std::fmt::Display for IdentifySystemCmd {
@@ -66302,6 +67917,12 @@ impl std::fmt::Display for IdentifySystemCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IdentifySystemCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IdentifySystemCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for ImportForeignSchemaStmt {}
 impl pg_sys::PgNode for ImportForeignSchemaStmt {}
 impl std::fmt::Display for ImportForeignSchemaStmt {
@@ -66309,6 +67930,12 @@ impl std::fmt::Display for ImportForeignSchemaStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ImportForeignSchemaStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ImportForeignSchemaStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSort {}
 impl pg_sys::PgNode for IncrementalSort {}
 impl std::fmt::Display for IncrementalSort {
@@ -66316,6 +67943,12 @@ impl std::fmt::Display for IncrementalSort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSort"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSortPath {}
 impl pg_sys::PgNode for IncrementalSortPath {}
 impl std::fmt::Display for IncrementalSortPath {
@@ -66323,6 +67956,12 @@ impl std::fmt::Display for IncrementalSortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IncrementalSortState {}
 impl pg_sys::PgNode for IncrementalSortState {}
 impl std::fmt::Display for IncrementalSortState {
@@ -66330,6 +67969,12 @@ impl std::fmt::Display for IncrementalSortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IncrementalSortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IncrementalSortState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexAmRoutine {}
 impl pg_sys::PgNode for IndexAmRoutine {}
 impl std::fmt::Display for IndexAmRoutine {
@@ -66337,6 +67982,12 @@ impl std::fmt::Display for IndexAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexClause {}
 impl pg_sys::PgNode for IndexClause {}
 impl std::fmt::Display for IndexClause {
@@ -66344,6 +67995,12 @@ impl std::fmt::Display for IndexClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexClause"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexElem {}
 impl pg_sys::PgNode for IndexElem {}
 impl std::fmt::Display for IndexElem {
@@ -66351,6 +68008,12 @@ impl std::fmt::Display for IndexElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexElem"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexInfo {}
 impl pg_sys::PgNode for IndexInfo {}
 impl std::fmt::Display for IndexInfo {
@@ -66358,6 +68021,12 @@ impl std::fmt::Display for IndexInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScan {}
 impl pg_sys::PgNode for IndexOnlyScan {}
 impl std::fmt::Display for IndexOnlyScan {
@@ -66365,6 +68034,12 @@ impl std::fmt::Display for IndexOnlyScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOnlyScanState {}
 impl pg_sys::PgNode for IndexOnlyScanState {}
 impl std::fmt::Display for IndexOnlyScanState {
@@ -66372,6 +68047,12 @@ impl std::fmt::Display for IndexOnlyScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOnlyScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOnlyScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexOptInfo {}
 impl pg_sys::PgNode for IndexOptInfo {}
 impl std::fmt::Display for IndexOptInfo {
@@ -66379,6 +68060,12 @@ impl std::fmt::Display for IndexOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexPath {}
 impl pg_sys::PgNode for IndexPath {}
 impl std::fmt::Display for IndexPath {
@@ -66386,6 +68073,12 @@ impl std::fmt::Display for IndexPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexPath"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScan {}
 impl pg_sys::PgNode for IndexScan {}
 impl std::fmt::Display for IndexScan {
@@ -66393,6 +68086,12 @@ impl std::fmt::Display for IndexScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScan"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexScanState {}
 impl pg_sys::PgNode for IndexScanState {}
 impl std::fmt::Display for IndexScanState {
@@ -66400,6 +68099,12 @@ impl std::fmt::Display for IndexScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for IndexStmt {}
 impl pg_sys::PgNode for IndexStmt {}
 impl std::fmt::Display for IndexStmt {
@@ -66407,6 +68112,12 @@ impl std::fmt::Display for IndexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IndexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IndexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for InferClause {}
 impl pg_sys::PgNode for InferClause {}
 impl std::fmt::Display for InferClause {
@@ -66414,6 +68125,12 @@ impl std::fmt::Display for InferClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferClause"))
+    }
+}
 impl pg_sys::seal::Sealed for InferenceElem {}
 impl pg_sys::PgNode for InferenceElem {}
 impl std::fmt::Display for InferenceElem {
@@ -66421,6 +68138,12 @@ impl std::fmt::Display for InferenceElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InferenceElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InferenceElem"))
+    }
+}
 impl pg_sys::seal::Sealed for InlineCodeBlock {}
 impl pg_sys::PgNode for InlineCodeBlock {}
 impl std::fmt::Display for InlineCodeBlock {
@@ -66428,6 +68151,12 @@ impl std::fmt::Display for InlineCodeBlock {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InlineCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InlineCodeBlock"))
+    }
+}
 impl pg_sys::seal::Sealed for InsertStmt {}
 impl pg_sys::PgNode for InsertStmt {}
 impl std::fmt::Display for InsertStmt {
@@ -66435,6 +68164,12 @@ impl std::fmt::Display for InsertStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for InsertStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "InsertStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Integer {}
 impl pg_sys::PgNode for Integer {}
 impl std::fmt::Display for Integer {
@@ -66442,6 +68177,12 @@ impl std::fmt::Display for Integer {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Integer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Integer"))
+    }
+}
 impl pg_sys::seal::Sealed for IntoClause {}
 impl pg_sys::PgNode for IntoClause {}
 impl std::fmt::Display for IntoClause {
@@ -66449,6 +68190,12 @@ impl std::fmt::Display for IntoClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for IntoClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "IntoClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Join {}
 impl pg_sys::PgNode for Join {}
 impl std::fmt::Display for Join {
@@ -66456,6 +68203,12 @@ impl std::fmt::Display for Join {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Join {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Join"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinExpr {}
 impl pg_sys::PgNode for JoinExpr {}
 impl std::fmt::Display for JoinExpr {
@@ -66463,6 +68216,12 @@ impl std::fmt::Display for JoinExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinPath {}
 impl pg_sys::PgNode for JoinPath {}
 impl std::fmt::Display for JoinPath {
@@ -66470,6 +68229,12 @@ impl std::fmt::Display for JoinPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinPath"))
+    }
+}
 impl pg_sys::seal::Sealed for JoinState {}
 impl pg_sys::PgNode for JoinState {}
 impl std::fmt::Display for JoinState {
@@ -66477,6 +68242,12 @@ impl std::fmt::Display for JoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for JunkFilter {}
 impl pg_sys::PgNode for JunkFilter {}
 impl std::fmt::Display for JunkFilter {
@@ -66484,6 +68255,12 @@ impl std::fmt::Display for JunkFilter {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for JunkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "JunkFilter"))
+    }
+}
 impl pg_sys::seal::Sealed for Limit {}
 impl pg_sys::PgNode for Limit {}
 impl std::fmt::Display for Limit {
@@ -66491,6 +68268,12 @@ impl std::fmt::Display for Limit {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Limit"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitPath {}
 impl pg_sys::PgNode for LimitPath {}
 impl std::fmt::Display for LimitPath {
@@ -66498,6 +68281,12 @@ impl std::fmt::Display for LimitPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LimitState {}
 impl pg_sys::PgNode for LimitState {}
 impl std::fmt::Display for LimitState {
@@ -66505,6 +68294,12 @@ impl std::fmt::Display for LimitState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LimitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LimitState"))
+    }
+}
 impl pg_sys::seal::Sealed for List {}
 impl pg_sys::PgNode for List {}
 impl std::fmt::Display for List {
@@ -66512,6 +68307,12 @@ impl std::fmt::Display for List {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "List"))
+    }
+}
 impl pg_sys::seal::Sealed for ListenStmt {}
 impl pg_sys::PgNode for ListenStmt {}
 impl std::fmt::Display for ListenStmt {
@@ -66519,6 +68320,12 @@ impl std::fmt::Display for ListenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ListenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ListenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LoadStmt {}
 impl pg_sys::PgNode for LoadStmt {}
 impl std::fmt::Display for LoadStmt {
@@ -66526,6 +68333,12 @@ impl std::fmt::Display for LoadStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LoadStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LoadStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRows {}
 impl pg_sys::PgNode for LockRows {}
 impl std::fmt::Display for LockRows {
@@ -66533,6 +68346,12 @@ impl std::fmt::Display for LockRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRows"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsPath {}
 impl pg_sys::PgNode for LockRowsPath {}
 impl std::fmt::Display for LockRowsPath {
@@ -66540,6 +68359,12 @@ impl std::fmt::Display for LockRowsPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsPath"))
+    }
+}
 impl pg_sys::seal::Sealed for LockRowsState {}
 impl pg_sys::PgNode for LockRowsState {}
 impl std::fmt::Display for LockRowsState {
@@ -66547,6 +68372,12 @@ impl std::fmt::Display for LockRowsState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockRowsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockRowsState"))
+    }
+}
 impl pg_sys::seal::Sealed for LockStmt {}
 impl pg_sys::PgNode for LockStmt {}
 impl std::fmt::Display for LockStmt {
@@ -66554,6 +68385,12 @@ impl std::fmt::Display for LockStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for LockingClause {}
 impl pg_sys::PgNode for LockingClause {}
 impl std::fmt::Display for LockingClause {
@@ -66561,6 +68398,12 @@ impl std::fmt::Display for LockingClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for LockingClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "LockingClause"))
+    }
+}
 impl pg_sys::seal::Sealed for Material {}
 impl pg_sys::PgNode for Material {}
 impl std::fmt::Display for Material {
@@ -66568,6 +68411,12 @@ impl std::fmt::Display for Material {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Material"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialPath {}
 impl pg_sys::PgNode for MaterialPath {}
 impl std::fmt::Display for MaterialPath {
@@ -66575,6 +68424,12 @@ impl std::fmt::Display for MaterialPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MaterialState {}
 impl pg_sys::PgNode for MaterialState {}
 impl std::fmt::Display for MaterialState {
@@ -66582,6 +68437,12 @@ impl std::fmt::Display for MaterialState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MaterialState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MaterialState"))
+    }
+}
 impl pg_sys::seal::Sealed for Memoize {}
 impl pg_sys::PgNode for Memoize {}
 impl std::fmt::Display for Memoize {
@@ -66589,6 +68450,12 @@ impl std::fmt::Display for Memoize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Memoize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Memoize"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoizePath {}
 impl pg_sys::PgNode for MemoizePath {}
 impl std::fmt::Display for MemoizePath {
@@ -66596,6 +68463,12 @@ impl std::fmt::Display for MemoizePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoizePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoizePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoizeState {}
 impl pg_sys::PgNode for MemoizeState {}
 impl std::fmt::Display for MemoizeState {
@@ -66603,6 +68476,12 @@ impl std::fmt::Display for MemoizeState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoizeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoizeState"))
+    }
+}
 impl pg_sys::seal::Sealed for MemoryContextData {}
 impl pg_sys::PgNode for MemoryContextData {}
 impl std::fmt::Display for MemoryContextData {
@@ -66610,6 +68489,12 @@ impl std::fmt::Display for MemoryContextData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MemoryContextData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MemoryContextData"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAction {}
 impl pg_sys::PgNode for MergeAction {}
 impl std::fmt::Display for MergeAction {
@@ -66617,6 +68502,12 @@ impl std::fmt::Display for MergeAction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAction"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeActionState {}
 impl pg_sys::PgNode for MergeActionState {}
 impl std::fmt::Display for MergeActionState {
@@ -66624,6 +68515,12 @@ impl std::fmt::Display for MergeActionState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeActionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeActionState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppend {}
 impl pg_sys::PgNode for MergeAppend {}
 impl std::fmt::Display for MergeAppend {
@@ -66631,6 +68528,12 @@ impl std::fmt::Display for MergeAppend {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppend"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendPath {}
 impl pg_sys::PgNode for MergeAppendPath {}
 impl std::fmt::Display for MergeAppendPath {
@@ -66638,6 +68541,12 @@ impl std::fmt::Display for MergeAppendPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeAppendState {}
 impl pg_sys::PgNode for MergeAppendState {}
 impl std::fmt::Display for MergeAppendState {
@@ -66645,6 +68554,12 @@ impl std::fmt::Display for MergeAppendState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeAppendState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeAppendState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoin {}
 impl pg_sys::PgNode for MergeJoin {}
 impl std::fmt::Display for MergeJoin {
@@ -66652,6 +68567,12 @@ impl std::fmt::Display for MergeJoin {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoin"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeJoinState {}
 impl pg_sys::PgNode for MergeJoinState {}
 impl std::fmt::Display for MergeJoinState {
@@ -66659,6 +68580,12 @@ impl std::fmt::Display for MergeJoinState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeJoinState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeJoinState"))
+    }
+}
 impl pg_sys::seal::Sealed for MergePath {}
 impl pg_sys::PgNode for MergePath {}
 impl std::fmt::Display for MergePath {
@@ -66666,6 +68593,12 @@ impl std::fmt::Display for MergePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergePath"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeStmt {}
 impl pg_sys::PgNode for MergeStmt {}
 impl std::fmt::Display for MergeStmt {
@@ -66673,6 +68606,12 @@ impl std::fmt::Display for MergeStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for MergeWhenClause {}
 impl pg_sys::PgNode for MergeWhenClause {}
 impl std::fmt::Display for MergeWhenClause {
@@ -66680,6 +68619,12 @@ impl std::fmt::Display for MergeWhenClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MergeWhenClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MergeWhenClause"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggInfo {}
 impl pg_sys::PgNode for MinMaxAggInfo {}
 impl std::fmt::Display for MinMaxAggInfo {
@@ -66687,6 +68632,12 @@ impl std::fmt::Display for MinMaxAggInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxAggPath {}
 impl pg_sys::PgNode for MinMaxAggPath {}
 impl std::fmt::Display for MinMaxAggPath {
@@ -66694,6 +68645,12 @@ impl std::fmt::Display for MinMaxAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for MinMaxExpr {}
 impl pg_sys::PgNode for MinMaxExpr {}
 impl std::fmt::Display for MinMaxExpr {
@@ -66701,6 +68658,12 @@ impl std::fmt::Display for MinMaxExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinMaxExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinMaxExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for MinimalTupleTableSlot {}
 impl pg_sys::PgNode for MinimalTupleTableSlot {}
 impl std::fmt::Display for MinimalTupleTableSlot {
@@ -66708,6 +68671,12 @@ impl std::fmt::Display for MinimalTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MinimalTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MinimalTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTable {}
 impl pg_sys::PgNode for ModifyTable {}
 impl std::fmt::Display for ModifyTable {
@@ -66715,6 +68684,12 @@ impl std::fmt::Display for ModifyTable {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTable"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTablePath {}
 impl pg_sys::PgNode for ModifyTablePath {}
 impl std::fmt::Display for ModifyTablePath {
@@ -66722,6 +68697,12 @@ impl std::fmt::Display for ModifyTablePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTablePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTablePath"))
+    }
+}
 impl pg_sys::seal::Sealed for ModifyTableState {}
 impl pg_sys::PgNode for ModifyTableState {}
 impl std::fmt::Display for ModifyTableState {
@@ -66729,6 +68710,12 @@ impl std::fmt::Display for ModifyTableState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ModifyTableState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ModifyTableState"))
+    }
+}
 impl pg_sys::seal::Sealed for MultiAssignRef {}
 impl pg_sys::PgNode for MultiAssignRef {}
 impl std::fmt::Display for MultiAssignRef {
@@ -66736,6 +68723,12 @@ impl std::fmt::Display for MultiAssignRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for MultiAssignRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "MultiAssignRef"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedArgExpr {}
 impl pg_sys::PgNode for NamedArgExpr {}
 impl std::fmt::Display for NamedArgExpr {
@@ -66743,6 +68736,12 @@ impl std::fmt::Display for NamedArgExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedArgExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedArgExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScan {}
 impl pg_sys::PgNode for NamedTuplestoreScan {}
 impl std::fmt::Display for NamedTuplestoreScan {
@@ -66750,6 +68749,12 @@ impl std::fmt::Display for NamedTuplestoreScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScan"))
+    }
+}
 impl pg_sys::seal::Sealed for NamedTuplestoreScanState {}
 impl pg_sys::PgNode for NamedTuplestoreScanState {}
 impl std::fmt::Display for NamedTuplestoreScanState {
@@ -66757,6 +68762,12 @@ impl std::fmt::Display for NamedTuplestoreScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NamedTuplestoreScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NamedTuplestoreScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoop {}
 impl pg_sys::PgNode for NestLoop {}
 impl std::fmt::Display for NestLoop {
@@ -66764,6 +68775,12 @@ impl std::fmt::Display for NestLoop {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoop"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopParam {}
 impl pg_sys::PgNode for NestLoopParam {}
 impl std::fmt::Display for NestLoopParam {
@@ -66771,6 +68788,12 @@ impl std::fmt::Display for NestLoopParam {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopParam"))
+    }
+}
 impl pg_sys::seal::Sealed for NestLoopState {}
 impl pg_sys::PgNode for NestLoopState {}
 impl std::fmt::Display for NestLoopState {
@@ -66778,6 +68801,12 @@ impl std::fmt::Display for NestLoopState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestLoopState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestLoopState"))
+    }
+}
 impl pg_sys::seal::Sealed for NestPath {}
 impl pg_sys::PgNode for NestPath {}
 impl std::fmt::Display for NestPath {
@@ -66785,6 +68814,12 @@ impl std::fmt::Display for NestPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NestPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NestPath"))
+    }
+}
 impl pg_sys::seal::Sealed for NextValueExpr {}
 impl pg_sys::PgNode for NextValueExpr {}
 impl std::fmt::Display for NextValueExpr {
@@ -66792,6 +68827,12 @@ impl std::fmt::Display for NextValueExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NextValueExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NextValueExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Node {}
 impl pg_sys::PgNode for Node {}
 impl std::fmt::Display for Node {
@@ -66799,6 +68840,12 @@ impl std::fmt::Display for Node {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Node"))
+    }
+}
 impl pg_sys::seal::Sealed for NotifyStmt {}
 impl pg_sys::PgNode for NotifyStmt {}
 impl std::fmt::Display for NotifyStmt {
@@ -66806,6 +68853,12 @@ impl std::fmt::Display for NotifyStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NotifyStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NotifyStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for NullTest {}
 impl pg_sys::PgNode for NullTest {}
 impl std::fmt::Display for NullTest {
@@ -66813,6 +68866,12 @@ impl std::fmt::Display for NullTest {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for NullTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "NullTest"))
+    }
+}
 impl pg_sys::seal::Sealed for ObjectWithArgs {}
 impl pg_sys::PgNode for ObjectWithArgs {}
 impl std::fmt::Display for ObjectWithArgs {
@@ -66820,6 +68879,12 @@ impl std::fmt::Display for ObjectWithArgs {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ObjectWithArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ObjectWithArgs"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictClause {}
 impl pg_sys::PgNode for OnConflictClause {}
 impl std::fmt::Display for OnConflictClause {
@@ -66827,6 +68892,12 @@ impl std::fmt::Display for OnConflictClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictClause"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictExpr {}
 impl pg_sys::PgNode for OnConflictExpr {}
 impl std::fmt::Display for OnConflictExpr {
@@ -66834,6 +68905,12 @@ impl std::fmt::Display for OnConflictExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for OnConflictSetState {}
 impl pg_sys::PgNode for OnConflictSetState {}
 impl std::fmt::Display for OnConflictSetState {
@@ -66841,6 +68918,12 @@ impl std::fmt::Display for OnConflictSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OnConflictSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OnConflictSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for OpExpr {}
 impl pg_sys::PgNode for OpExpr {}
 impl std::fmt::Display for OpExpr {
@@ -66848,6 +68931,12 @@ impl std::fmt::Display for OpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for OpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "OpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for PLAssignStmt {}
 impl pg_sys::PgNode for PLAssignStmt {}
 impl std::fmt::Display for PLAssignStmt {
@@ -66855,6 +68944,12 @@ impl std::fmt::Display for PLAssignStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PLAssignStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PLAssignStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for Param {}
 impl pg_sys::PgNode for Param {}
 impl std::fmt::Display for Param {
@@ -66862,6 +68957,12 @@ impl std::fmt::Display for Param {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Param"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamPathInfo {}
 impl pg_sys::PgNode for ParamPathInfo {}
 impl std::fmt::Display for ParamPathInfo {
@@ -66869,6 +68970,12 @@ impl std::fmt::Display for ParamPathInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamPathInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamPathInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ParamRef {}
 impl pg_sys::PgNode for ParamRef {}
 impl std::fmt::Display for ParamRef {
@@ -66876,6 +68983,12 @@ impl std::fmt::Display for ParamRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ParamRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ParamRef"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionBoundSpec {}
 impl pg_sys::PgNode for PartitionBoundSpec {}
 impl std::fmt::Display for PartitionBoundSpec {
@@ -66883,6 +68996,12 @@ impl std::fmt::Display for PartitionBoundSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionBoundSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionBoundSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionCmd {}
 impl pg_sys::PgNode for PartitionCmd {}
 impl std::fmt::Display for PartitionCmd {
@@ -66890,6 +69009,12 @@ impl std::fmt::Display for PartitionCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionElem {}
 impl pg_sys::PgNode for PartitionElem {}
 impl std::fmt::Display for PartitionElem {
@@ -66897,6 +69022,12 @@ impl std::fmt::Display for PartitionElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionElem"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneInfo {}
 impl pg_sys::PgNode for PartitionPruneInfo {}
 impl std::fmt::Display for PartitionPruneInfo {
@@ -66904,6 +69035,12 @@ impl std::fmt::Display for PartitionPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStep {}
 impl pg_sys::PgNode for PartitionPruneStep {}
 impl std::fmt::Display for PartitionPruneStep {
@@ -66911,6 +69048,12 @@ impl std::fmt::Display for PartitionPruneStep {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStep"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepCombine {}
 impl pg_sys::PgNode for PartitionPruneStepCombine {}
 impl std::fmt::Display for PartitionPruneStepCombine {
@@ -66918,6 +69061,12 @@ impl std::fmt::Display for PartitionPruneStepCombine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepCombine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepCombine"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionPruneStepOp {}
 impl pg_sys::PgNode for PartitionPruneStepOp {}
 impl std::fmt::Display for PartitionPruneStepOp {
@@ -66925,6 +69074,12 @@ impl std::fmt::Display for PartitionPruneStepOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionPruneStepOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionPruneStepOp"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionRangeDatum {}
 impl pg_sys::PgNode for PartitionRangeDatum {}
 impl std::fmt::Display for PartitionRangeDatum {
@@ -66932,6 +69087,12 @@ impl std::fmt::Display for PartitionRangeDatum {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionRangeDatum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionRangeDatum"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionSpec {}
 impl pg_sys::PgNode for PartitionSpec {}
 impl std::fmt::Display for PartitionSpec {
@@ -66939,6 +69100,12 @@ impl std::fmt::Display for PartitionSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PartitionedRelPruneInfo {}
 impl pg_sys::PgNode for PartitionedRelPruneInfo {}
 impl std::fmt::Display for PartitionedRelPruneInfo {
@@ -66946,6 +69113,12 @@ impl std::fmt::Display for PartitionedRelPruneInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PartitionedRelPruneInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PartitionedRelPruneInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Path {}
 impl pg_sys::PgNode for Path {}
 impl std::fmt::Display for Path {
@@ -66953,6 +69126,12 @@ impl std::fmt::Display for Path {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Path"))
+    }
+}
 impl pg_sys::seal::Sealed for PathKey {}
 impl pg_sys::PgNode for PathKey {}
 impl std::fmt::Display for PathKey {
@@ -66960,6 +69139,12 @@ impl std::fmt::Display for PathKey {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathKey"))
+    }
+}
 impl pg_sys::seal::Sealed for PathTarget {}
 impl pg_sys::PgNode for PathTarget {}
 impl std::fmt::Display for PathTarget {
@@ -66967,6 +69152,12 @@ impl std::fmt::Display for PathTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PathTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PathTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderInfo {}
 impl pg_sys::PgNode for PlaceHolderInfo {}
 impl std::fmt::Display for PlaceHolderInfo {
@@ -66974,6 +69165,12 @@ impl std::fmt::Display for PlaceHolderInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlaceHolderVar {}
 impl pg_sys::PgNode for PlaceHolderVar {}
 impl std::fmt::Display for PlaceHolderVar {
@@ -66981,6 +69178,12 @@ impl std::fmt::Display for PlaceHolderVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlaceHolderVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlaceHolderVar"))
+    }
+}
 impl pg_sys::seal::Sealed for Plan {}
 impl pg_sys::PgNode for Plan {}
 impl std::fmt::Display for Plan {
@@ -66988,6 +69191,12 @@ impl std::fmt::Display for Plan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Plan"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanInvalItem {}
 impl pg_sys::PgNode for PlanInvalItem {}
 impl std::fmt::Display for PlanInvalItem {
@@ -66995,6 +69204,12 @@ impl std::fmt::Display for PlanInvalItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanInvalItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanInvalItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanRowMark {}
 impl pg_sys::PgNode for PlanRowMark {}
 impl std::fmt::Display for PlanRowMark {
@@ -67002,6 +69217,12 @@ impl std::fmt::Display for PlanRowMark {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanRowMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanRowMark"))
+    }
+}
 impl pg_sys::seal::Sealed for PlanState {}
 impl pg_sys::PgNode for PlanState {}
 impl std::fmt::Display for PlanState {
@@ -67009,6 +69230,12 @@ impl std::fmt::Display for PlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannedStmt {}
 impl pg_sys::PgNode for PlannedStmt {}
 impl std::fmt::Display for PlannedStmt {
@@ -67016,6 +69243,12 @@ impl std::fmt::Display for PlannedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerGlobal {}
 impl pg_sys::PgNode for PlannerGlobal {}
 impl std::fmt::Display for PlannerGlobal {
@@ -67023,6 +69256,12 @@ impl std::fmt::Display for PlannerGlobal {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerGlobal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerGlobal"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerInfo {}
 impl pg_sys::PgNode for PlannerInfo {}
 impl std::fmt::Display for PlannerInfo {
@@ -67030,6 +69269,12 @@ impl std::fmt::Display for PlannerInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for PlannerParamItem {}
 impl pg_sys::PgNode for PlannerParamItem {}
 impl std::fmt::Display for PlannerParamItem {
@@ -67037,6 +69282,12 @@ impl std::fmt::Display for PlannerParamItem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PlannerParamItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PlannerParamItem"))
+    }
+}
 impl pg_sys::seal::Sealed for PrepareStmt {}
 impl pg_sys::PgNode for PrepareStmt {}
 impl std::fmt::Display for PrepareStmt {
@@ -67044,6 +69295,12 @@ impl std::fmt::Display for PrepareStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PrepareStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PrepareStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSet {}
 impl pg_sys::PgNode for ProjectSet {}
 impl std::fmt::Display for ProjectSet {
@@ -67051,6 +69308,12 @@ impl std::fmt::Display for ProjectSet {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSet"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetPath {}
 impl pg_sys::PgNode for ProjectSetPath {}
 impl std::fmt::Display for ProjectSetPath {
@@ -67058,6 +69321,12 @@ impl std::fmt::Display for ProjectSetPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetPath"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectSetState {}
 impl pg_sys::PgNode for ProjectSetState {}
 impl std::fmt::Display for ProjectSetState {
@@ -67065,6 +69334,12 @@ impl std::fmt::Display for ProjectSetState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectSetState"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionInfo {}
 impl pg_sys::PgNode for ProjectionInfo {}
 impl std::fmt::Display for ProjectionInfo {
@@ -67072,6 +69347,12 @@ impl std::fmt::Display for ProjectionInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ProjectionPath {}
 impl pg_sys::PgNode for ProjectionPath {}
 impl std::fmt::Display for ProjectionPath {
@@ -67079,6 +69360,12 @@ impl std::fmt::Display for ProjectionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ProjectionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ProjectionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for PublicationObjSpec {}
 impl pg_sys::PgNode for PublicationObjSpec {}
 impl std::fmt::Display for PublicationObjSpec {
@@ -67086,6 +69373,12 @@ impl std::fmt::Display for PublicationObjSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PublicationObjSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PublicationObjSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for PublicationTable {}
 impl pg_sys::PgNode for PublicationTable {}
 impl std::fmt::Display for PublicationTable {
@@ -67093,6 +69386,12 @@ impl std::fmt::Display for PublicationTable {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for PublicationTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "PublicationTable"))
+    }
+}
 impl pg_sys::seal::Sealed for Query {}
 impl pg_sys::PgNode for Query {}
 impl std::fmt::Display for Query {
@@ -67100,6 +69399,12 @@ impl std::fmt::Display for Query {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Query"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeFunction {}
 impl pg_sys::PgNode for RangeFunction {}
 impl std::fmt::Display for RangeFunction {
@@ -67107,6 +69412,12 @@ impl std::fmt::Display for RangeFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeSubselect {}
 impl pg_sys::PgNode for RangeSubselect {}
 impl std::fmt::Display for RangeSubselect {
@@ -67114,6 +69425,12 @@ impl std::fmt::Display for RangeSubselect {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeSubselect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeSubselect"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFunc {}
 impl pg_sys::PgNode for RangeTableFunc {}
 impl std::fmt::Display for RangeTableFunc {
@@ -67121,6 +69438,12 @@ impl std::fmt::Display for RangeTableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableFuncCol {}
 impl pg_sys::PgNode for RangeTableFuncCol {}
 impl std::fmt::Display for RangeTableFuncCol {
@@ -67128,6 +69451,12 @@ impl std::fmt::Display for RangeTableFuncCol {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableFuncCol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableFuncCol"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTableSample {}
 impl pg_sys::PgNode for RangeTableSample {}
 impl std::fmt::Display for RangeTableSample {
@@ -67135,6 +69464,12 @@ impl std::fmt::Display for RangeTableSample {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTableSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTableSample"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblEntry {}
 impl pg_sys::PgNode for RangeTblEntry {}
 impl std::fmt::Display for RangeTblEntry {
@@ -67142,6 +69477,12 @@ impl std::fmt::Display for RangeTblEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblFunction {}
 impl pg_sys::PgNode for RangeTblFunction {}
 impl std::fmt::Display for RangeTblFunction {
@@ -67149,6 +69490,12 @@ impl std::fmt::Display for RangeTblFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeTblRef {}
 impl pg_sys::PgNode for RangeTblRef {}
 impl std::fmt::Display for RangeTblRef {
@@ -67156,6 +69503,12 @@ impl std::fmt::Display for RangeTblRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeTblRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeTblRef"))
+    }
+}
 impl pg_sys::seal::Sealed for RangeVar {}
 impl pg_sys::PgNode for RangeVar {}
 impl std::fmt::Display for RangeVar {
@@ -67163,6 +69516,12 @@ impl std::fmt::Display for RangeVar {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RangeVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RangeVar"))
+    }
+}
 impl pg_sys::seal::Sealed for RawStmt {}
 impl pg_sys::PgNode for RawStmt {}
 impl std::fmt::Display for RawStmt {
@@ -67170,6 +69529,12 @@ impl std::fmt::Display for RawStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RawStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RawStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReadReplicationSlotCmd {}
 impl pg_sys::PgNode for ReadReplicationSlotCmd {}
 impl std::fmt::Display for ReadReplicationSlotCmd {
@@ -67177,6 +69542,12 @@ impl std::fmt::Display for ReadReplicationSlotCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReadReplicationSlotCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReadReplicationSlotCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for ReassignOwnedStmt {}
 impl pg_sys::PgNode for ReassignOwnedStmt {}
 impl std::fmt::Display for ReassignOwnedStmt {
@@ -67184,6 +69555,12 @@ impl std::fmt::Display for ReassignOwnedStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReassignOwnedStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReassignOwnedStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnion {}
 impl pg_sys::PgNode for RecursiveUnion {}
 impl std::fmt::Display for RecursiveUnion {
@@ -67191,6 +69568,12 @@ impl std::fmt::Display for RecursiveUnion {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnion"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionPath {}
 impl pg_sys::PgNode for RecursiveUnionPath {}
 impl std::fmt::Display for RecursiveUnionPath {
@@ -67198,6 +69581,12 @@ impl std::fmt::Display for RecursiveUnionPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionPath"))
+    }
+}
 impl pg_sys::seal::Sealed for RecursiveUnionState {}
 impl pg_sys::PgNode for RecursiveUnionState {}
 impl std::fmt::Display for RecursiveUnionState {
@@ -67205,6 +69594,12 @@ impl std::fmt::Display for RecursiveUnionState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RecursiveUnionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RecursiveUnionState"))
+    }
+}
 impl pg_sys::seal::Sealed for RefreshMatViewStmt {}
 impl pg_sys::PgNode for RefreshMatViewStmt {}
 impl std::fmt::Display for RefreshMatViewStmt {
@@ -67212,6 +69607,12 @@ impl std::fmt::Display for RefreshMatViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RefreshMatViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RefreshMatViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReindexStmt {}
 impl pg_sys::PgNode for ReindexStmt {}
 impl std::fmt::Display for ReindexStmt {
@@ -67219,6 +69620,12 @@ impl std::fmt::Display for ReindexStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReindexStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReindexStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RelOptInfo {}
 impl pg_sys::PgNode for RelOptInfo {}
 impl std::fmt::Display for RelOptInfo {
@@ -67226,6 +69633,12 @@ impl std::fmt::Display for RelOptInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelOptInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelOptInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RelabelType {}
 impl pg_sys::PgNode for RelabelType {}
 impl std::fmt::Display for RelabelType {
@@ -67233,6 +69646,12 @@ impl std::fmt::Display for RelabelType {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RelabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RelabelType"))
+    }
+}
 impl pg_sys::seal::Sealed for RenameStmt {}
 impl pg_sys::PgNode for RenameStmt {}
 impl std::fmt::Display for RenameStmt {
@@ -67240,6 +69659,12 @@ impl std::fmt::Display for RenameStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RenameStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RenameStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ReplicaIdentityStmt {}
 impl pg_sys::PgNode for ReplicaIdentityStmt {}
 impl std::fmt::Display for ReplicaIdentityStmt {
@@ -67247,6 +69672,12 @@ impl std::fmt::Display for ReplicaIdentityStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReplicaIdentityStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReplicaIdentityStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ResTarget {}
 impl pg_sys::PgNode for ResTarget {}
 impl std::fmt::Display for ResTarget {
@@ -67254,6 +69685,12 @@ impl std::fmt::Display for ResTarget {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResTarget"))
+    }
+}
 impl pg_sys::seal::Sealed for RestrictInfo {}
 impl pg_sys::PgNode for RestrictInfo {}
 impl std::fmt::Display for RestrictInfo {
@@ -67261,6 +69698,12 @@ impl std::fmt::Display for RestrictInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RestrictInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RestrictInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for Result {}
 impl pg_sys::PgNode for Result {}
 impl std::fmt::Display for Result {
@@ -67268,6 +69711,12 @@ impl std::fmt::Display for Result {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Result"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultRelInfo {}
 impl pg_sys::PgNode for ResultRelInfo {}
 impl std::fmt::Display for ResultRelInfo {
@@ -67275,6 +69724,12 @@ impl std::fmt::Display for ResultRelInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultRelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultRelInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ResultState {}
 impl pg_sys::PgNode for ResultState {}
 impl std::fmt::Display for ResultState {
@@ -67282,6 +69737,12 @@ impl std::fmt::Display for ResultState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ResultState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ResultState"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnSetInfo {}
 impl pg_sys::PgNode for ReturnSetInfo {}
 impl std::fmt::Display for ReturnSetInfo {
@@ -67289,6 +69750,12 @@ impl std::fmt::Display for ReturnSetInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnSetInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnSetInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for ReturnStmt {}
 impl pg_sys::PgNode for ReturnStmt {}
 impl std::fmt::Display for ReturnStmt {
@@ -67296,6 +69763,12 @@ impl std::fmt::Display for ReturnStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ReturnStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ReturnStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for RoleSpec {}
 impl pg_sys::PgNode for RoleSpec {}
 impl std::fmt::Display for RoleSpec {
@@ -67303,6 +69776,12 @@ impl std::fmt::Display for RoleSpec {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RoleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RoleSpec"))
+    }
+}
 impl pg_sys::seal::Sealed for RollupData {}
 impl pg_sys::PgNode for RollupData {}
 impl std::fmt::Display for RollupData {
@@ -67310,6 +69789,12 @@ impl std::fmt::Display for RollupData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RollupData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RollupData"))
+    }
+}
 impl pg_sys::seal::Sealed for RowCompareExpr {}
 impl pg_sys::PgNode for RowCompareExpr {}
 impl std::fmt::Display for RowCompareExpr {
@@ -67317,6 +69802,12 @@ impl std::fmt::Display for RowCompareExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowCompareExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowCompareExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowExpr {}
 impl pg_sys::PgNode for RowExpr {}
 impl std::fmt::Display for RowExpr {
@@ -67324,6 +69815,12 @@ impl std::fmt::Display for RowExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for RowIdentityVarInfo {}
 impl pg_sys::PgNode for RowIdentityVarInfo {}
 impl std::fmt::Display for RowIdentityVarInfo {
@@ -67331,6 +69828,12 @@ impl std::fmt::Display for RowIdentityVarInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowIdentityVarInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowIdentityVarInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for RowMarkClause {}
 impl pg_sys::PgNode for RowMarkClause {}
 impl std::fmt::Display for RowMarkClause {
@@ -67338,6 +69841,12 @@ impl std::fmt::Display for RowMarkClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RowMarkClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RowMarkClause"))
+    }
+}
 impl pg_sys::seal::Sealed for RuleStmt {}
 impl pg_sys::PgNode for RuleStmt {}
 impl std::fmt::Display for RuleStmt {
@@ -67345,6 +69854,12 @@ impl std::fmt::Display for RuleStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for RuleStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "RuleStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SQLValueFunction {}
 impl pg_sys::PgNode for SQLValueFunction {}
 impl std::fmt::Display for SQLValueFunction {
@@ -67352,6 +69867,12 @@ impl std::fmt::Display for SQLValueFunction {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SQLValueFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SQLValueFunction"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScan {}
 impl pg_sys::PgNode for SampleScan {}
 impl std::fmt::Display for SampleScan {
@@ -67359,6 +69880,12 @@ impl std::fmt::Display for SampleScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SampleScanState {}
 impl pg_sys::PgNode for SampleScanState {}
 impl std::fmt::Display for SampleScanState {
@@ -67366,6 +69893,12 @@ impl std::fmt::Display for SampleScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SampleScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SampleScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for ScalarArrayOpExpr {}
 impl pg_sys::PgNode for ScalarArrayOpExpr {}
 impl std::fmt::Display for ScalarArrayOpExpr {
@@ -67373,6 +69906,12 @@ impl std::fmt::Display for ScalarArrayOpExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScalarArrayOpExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScalarArrayOpExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for Scan {}
 impl pg_sys::PgNode for Scan {}
 impl std::fmt::Display for Scan {
@@ -67380,6 +69919,12 @@ impl std::fmt::Display for Scan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Scan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Scan"))
+    }
+}
 impl pg_sys::seal::Sealed for ScanState {}
 impl pg_sys::PgNode for ScanState {}
 impl std::fmt::Display for ScanState {
@@ -67387,6 +69932,12 @@ impl std::fmt::Display for ScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SecLabelStmt {}
 impl pg_sys::PgNode for SecLabelStmt {}
 impl std::fmt::Display for SecLabelStmt {
@@ -67394,6 +69945,12 @@ impl std::fmt::Display for SecLabelStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SecLabelStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SecLabelStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SelectStmt {}
 impl pg_sys::PgNode for SelectStmt {}
 impl std::fmt::Display for SelectStmt {
@@ -67401,6 +69958,12 @@ impl std::fmt::Display for SelectStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SelectStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SelectStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SeqScan {}
 impl pg_sys::PgNode for SeqScan {}
 impl std::fmt::Display for SeqScan {
@@ -67408,6 +69971,12 @@ impl std::fmt::Display for SeqScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SeqScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SeqScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SeqScanState {}
 impl pg_sys::PgNode for SeqScanState {}
 impl std::fmt::Display for SeqScanState {
@@ -67415,6 +69984,12 @@ impl std::fmt::Display for SeqScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SeqScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SeqScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetExprState {}
 impl pg_sys::PgNode for SetExprState {}
 impl std::fmt::Display for SetExprState {
@@ -67422,6 +69997,12 @@ impl std::fmt::Display for SetExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOp {}
 impl pg_sys::PgNode for SetOp {}
 impl std::fmt::Display for SetOp {
@@ -67429,6 +70010,12 @@ impl std::fmt::Display for SetOp {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOp"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpPath {}
 impl pg_sys::PgNode for SetOpPath {}
 impl std::fmt::Display for SetOpPath {
@@ -67436,6 +70023,12 @@ impl std::fmt::Display for SetOpPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOpState {}
 impl pg_sys::PgNode for SetOpState {}
 impl std::fmt::Display for SetOpState {
@@ -67443,6 +70036,12 @@ impl std::fmt::Display for SetOpState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOpState"))
+    }
+}
 impl pg_sys::seal::Sealed for SetOperationStmt {}
 impl pg_sys::PgNode for SetOperationStmt {}
 impl std::fmt::Display for SetOperationStmt {
@@ -67450,6 +70049,12 @@ impl std::fmt::Display for SetOperationStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetOperationStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetOperationStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for SetToDefault {}
 impl pg_sys::PgNode for SetToDefault {}
 impl std::fmt::Display for SetToDefault {
@@ -67457,6 +70062,12 @@ impl std::fmt::Display for SetToDefault {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SetToDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SetToDefault"))
+    }
+}
 impl pg_sys::seal::Sealed for Sort {}
 impl pg_sys::PgNode for Sort {}
 impl std::fmt::Display for Sort {
@@ -67464,6 +70075,12 @@ impl std::fmt::Display for Sort {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Sort"))
+    }
+}
 impl pg_sys::seal::Sealed for SortBy {}
 impl pg_sys::PgNode for SortBy {}
 impl std::fmt::Display for SortBy {
@@ -67471,6 +70088,12 @@ impl std::fmt::Display for SortBy {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortBy"))
+    }
+}
 impl pg_sys::seal::Sealed for SortGroupClause {}
 impl pg_sys::PgNode for SortGroupClause {}
 impl std::fmt::Display for SortGroupClause {
@@ -67478,6 +70101,12 @@ impl std::fmt::Display for SortGroupClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortGroupClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortGroupClause"))
+    }
+}
 impl pg_sys::seal::Sealed for SortPath {}
 impl pg_sys::PgNode for SortPath {}
 impl std::fmt::Display for SortPath {
@@ -67485,6 +70114,12 @@ impl std::fmt::Display for SortPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SortState {}
 impl pg_sys::PgNode for SortState {}
 impl std::fmt::Display for SortState {
@@ -67492,6 +70127,12 @@ impl std::fmt::Display for SortState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SortState"))
+    }
+}
 impl pg_sys::seal::Sealed for SpecialJoinInfo {}
 impl pg_sys::PgNode for SpecialJoinInfo {}
 impl std::fmt::Display for SpecialJoinInfo {
@@ -67499,6 +70140,12 @@ impl std::fmt::Display for SpecialJoinInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SpecialJoinInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SpecialJoinInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StartReplicationCmd {}
 impl pg_sys::PgNode for StartReplicationCmd {}
 impl std::fmt::Display for StartReplicationCmd {
@@ -67506,6 +70153,12 @@ impl std::fmt::Display for StartReplicationCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StartReplicationCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StartReplicationCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for StatisticExtInfo {}
 impl pg_sys::PgNode for StatisticExtInfo {}
 impl std::fmt::Display for StatisticExtInfo {
@@ -67513,6 +70166,12 @@ impl std::fmt::Display for StatisticExtInfo {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatisticExtInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatisticExtInfo"))
+    }
+}
 impl pg_sys::seal::Sealed for StatsElem {}
 impl pg_sys::PgNode for StatsElem {}
 impl std::fmt::Display for StatsElem {
@@ -67520,6 +70179,12 @@ impl std::fmt::Display for StatsElem {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for StatsElem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "StatsElem"))
+    }
+}
 impl pg_sys::seal::Sealed for String {}
 impl pg_sys::PgNode for String {}
 impl std::fmt::Display for String {
@@ -67527,6 +70192,12 @@ impl std::fmt::Display for String {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "String"))
+    }
+}
 impl pg_sys::seal::Sealed for SubLink {}
 impl pg_sys::PgNode for SubLink {}
 impl std::fmt::Display for SubLink {
@@ -67534,6 +70205,12 @@ impl std::fmt::Display for SubLink {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubLink"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlan {}
 impl pg_sys::PgNode for SubPlan {}
 impl std::fmt::Display for SubPlan {
@@ -67541,6 +70218,12 @@ impl std::fmt::Display for SubPlan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubPlanState {}
 impl pg_sys::PgNode for SubPlanState {}
 impl std::fmt::Display for SubPlanState {
@@ -67548,6 +70231,12 @@ impl std::fmt::Display for SubPlanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubPlanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubPlanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScan {}
 impl pg_sys::PgNode for SubqueryScan {}
 impl std::fmt::Display for SubqueryScan {
@@ -67555,6 +70244,12 @@ impl std::fmt::Display for SubqueryScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScan"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanPath {}
 impl pg_sys::PgNode for SubqueryScanPath {}
 impl std::fmt::Display for SubqueryScanPath {
@@ -67562,6 +70257,12 @@ impl std::fmt::Display for SubqueryScanPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanPath"))
+    }
+}
 impl pg_sys::seal::Sealed for SubqueryScanState {}
 impl pg_sys::PgNode for SubqueryScanState {}
 impl std::fmt::Display for SubqueryScanState {
@@ -67569,6 +70270,12 @@ impl std::fmt::Display for SubqueryScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubqueryScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubqueryScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for SubscriptingRef {}
 impl pg_sys::PgNode for SubscriptingRef {}
 impl std::fmt::Display for SubscriptingRef {
@@ -67576,6 +70283,12 @@ impl std::fmt::Display for SubscriptingRef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SubscriptingRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SubscriptingRef"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestCost {}
 impl pg_sys::PgNode for SupportRequestCost {}
 impl std::fmt::Display for SupportRequestCost {
@@ -67583,6 +70296,12 @@ impl std::fmt::Display for SupportRequestCost {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestCost"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestIndexCondition {}
 impl pg_sys::PgNode for SupportRequestIndexCondition {}
 impl std::fmt::Display for SupportRequestIndexCondition {
@@ -67590,6 +70309,12 @@ impl std::fmt::Display for SupportRequestIndexCondition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestIndexCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestIndexCondition"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestRows {}
 impl pg_sys::PgNode for SupportRequestRows {}
 impl std::fmt::Display for SupportRequestRows {
@@ -67597,6 +70322,12 @@ impl std::fmt::Display for SupportRequestRows {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestRows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestRows"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSelectivity {}
 impl pg_sys::PgNode for SupportRequestSelectivity {}
 impl std::fmt::Display for SupportRequestSelectivity {
@@ -67604,6 +70335,12 @@ impl std::fmt::Display for SupportRequestSelectivity {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSelectivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSelectivity"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestSimplify {}
 impl pg_sys::PgNode for SupportRequestSimplify {}
 impl std::fmt::Display for SupportRequestSimplify {
@@ -67611,6 +70348,12 @@ impl std::fmt::Display for SupportRequestSimplify {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestSimplify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestSimplify"))
+    }
+}
 impl pg_sys::seal::Sealed for SupportRequestWFuncMonotonic {}
 impl pg_sys::PgNode for SupportRequestWFuncMonotonic {}
 impl std::fmt::Display for SupportRequestWFuncMonotonic {
@@ -67618,6 +70361,12 @@ impl std::fmt::Display for SupportRequestWFuncMonotonic {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for SupportRequestWFuncMonotonic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "SupportRequestWFuncMonotonic"))
+    }
+}
 impl pg_sys::seal::Sealed for TableAmRoutine {}
 impl pg_sys::PgNode for TableAmRoutine {}
 impl std::fmt::Display for TableAmRoutine {
@@ -67625,6 +70374,12 @@ impl std::fmt::Display for TableAmRoutine {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableAmRoutine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableAmRoutine"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFunc {}
 impl pg_sys::PgNode for TableFunc {}
 impl std::fmt::Display for TableFunc {
@@ -67632,6 +70387,12 @@ impl std::fmt::Display for TableFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScan {}
 impl pg_sys::PgNode for TableFuncScan {}
 impl std::fmt::Display for TableFuncScan {
@@ -67639,6 +70400,12 @@ impl std::fmt::Display for TableFuncScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TableFuncScanState {}
 impl pg_sys::PgNode for TableFuncScanState {}
 impl std::fmt::Display for TableFuncScanState {
@@ -67646,6 +70413,12 @@ impl std::fmt::Display for TableFuncScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableFuncScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableFuncScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TableLikeClause {}
 impl pg_sys::PgNode for TableLikeClause {}
 impl std::fmt::Display for TableLikeClause {
@@ -67653,6 +70426,12 @@ impl std::fmt::Display for TableLikeClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableLikeClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableLikeClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TableSampleClause {}
 impl pg_sys::PgNode for TableSampleClause {}
 impl std::fmt::Display for TableSampleClause {
@@ -67660,6 +70439,12 @@ impl std::fmt::Display for TableSampleClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TableSampleClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TableSampleClause"))
+    }
+}
 impl pg_sys::seal::Sealed for TargetEntry {}
 impl pg_sys::PgNode for TargetEntry {}
 impl std::fmt::Display for TargetEntry {
@@ -67667,6 +70452,12 @@ impl std::fmt::Display for TargetEntry {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TargetEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TargetEntry"))
+    }
+}
 impl pg_sys::seal::Sealed for TidPath {}
 impl pg_sys::PgNode for TidPath {}
 impl std::fmt::Display for TidPath {
@@ -67674,6 +70465,12 @@ impl std::fmt::Display for TidPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidPath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidRangePath {}
 impl pg_sys::PgNode for TidRangePath {}
 impl std::fmt::Display for TidRangePath {
@@ -67681,6 +70478,12 @@ impl std::fmt::Display for TidRangePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidRangePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidRangePath"))
+    }
+}
 impl pg_sys::seal::Sealed for TidRangeScan {}
 impl pg_sys::PgNode for TidRangeScan {}
 impl std::fmt::Display for TidRangeScan {
@@ -67688,6 +70491,12 @@ impl std::fmt::Display for TidRangeScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidRangeScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidRangeScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidRangeScanState {}
 impl pg_sys::PgNode for TidRangeScanState {}
 impl std::fmt::Display for TidRangeScanState {
@@ -67695,6 +70504,12 @@ impl std::fmt::Display for TidRangeScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidRangeScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidRangeScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScan {}
 impl pg_sys::PgNode for TidScan {}
 impl std::fmt::Display for TidScan {
@@ -67702,6 +70517,12 @@ impl std::fmt::Display for TidScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScan"))
+    }
+}
 impl pg_sys::seal::Sealed for TidScanState {}
 impl pg_sys::PgNode for TidScanState {}
 impl std::fmt::Display for TidScanState {
@@ -67709,6 +70530,12 @@ impl std::fmt::Display for TidScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TidScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TidScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for TimeLineHistoryCmd {}
 impl pg_sys::PgNode for TimeLineHistoryCmd {}
 impl std::fmt::Display for TimeLineHistoryCmd {
@@ -67716,6 +70543,12 @@ impl std::fmt::Display for TimeLineHistoryCmd {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TimeLineHistoryCmd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TimeLineHistoryCmd"))
+    }
+}
 impl pg_sys::seal::Sealed for TransactionStmt {}
 impl pg_sys::PgNode for TransactionStmt {}
 impl std::fmt::Display for TransactionStmt {
@@ -67723,6 +70556,12 @@ impl std::fmt::Display for TransactionStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TransactionStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TransactionStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerData {}
 impl pg_sys::PgNode for TriggerData {}
 impl std::fmt::Display for TriggerData {
@@ -67730,6 +70569,12 @@ impl std::fmt::Display for TriggerData {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerData"))
+    }
+}
 impl pg_sys::seal::Sealed for TriggerTransition {}
 impl pg_sys::PgNode for TriggerTransition {}
 impl std::fmt::Display for TriggerTransition {
@@ -67737,6 +70582,12 @@ impl std::fmt::Display for TriggerTransition {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TriggerTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TriggerTransition"))
+    }
+}
 impl pg_sys::seal::Sealed for TruncateStmt {}
 impl pg_sys::PgNode for TruncateStmt {}
 impl std::fmt::Display for TruncateStmt {
@@ -67744,6 +70595,12 @@ impl std::fmt::Display for TruncateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TruncateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TruncateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for TupleTableSlot {}
 impl pg_sys::PgNode for TupleTableSlot {}
 impl std::fmt::Display for TupleTableSlot {
@@ -67751,6 +70608,12 @@ impl std::fmt::Display for TupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeCast {}
 impl pg_sys::PgNode for TypeCast {}
 impl std::fmt::Display for TypeCast {
@@ -67758,6 +70621,12 @@ impl std::fmt::Display for TypeCast {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeCast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeCast"))
+    }
+}
 impl pg_sys::seal::Sealed for TypeName {}
 impl pg_sys::PgNode for TypeName {}
 impl std::fmt::Display for TypeName {
@@ -67765,6 +70634,12 @@ impl std::fmt::Display for TypeName {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "TypeName"))
+    }
+}
 impl pg_sys::seal::Sealed for Unique {}
 impl pg_sys::PgNode for Unique {}
 impl std::fmt::Display for Unique {
@@ -67772,6 +70647,12 @@ impl std::fmt::Display for Unique {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Unique"))
+    }
+}
 impl pg_sys::seal::Sealed for UniquePath {}
 impl pg_sys::PgNode for UniquePath {}
 impl std::fmt::Display for UniquePath {
@@ -67779,6 +70660,12 @@ impl std::fmt::Display for UniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for UniqueState {}
 impl pg_sys::PgNode for UniqueState {}
 impl std::fmt::Display for UniqueState {
@@ -67786,6 +70673,12 @@ impl std::fmt::Display for UniqueState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UniqueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UniqueState"))
+    }
+}
 impl pg_sys::seal::Sealed for UnlistenStmt {}
 impl pg_sys::PgNode for UnlistenStmt {}
 impl std::fmt::Display for UnlistenStmt {
@@ -67793,6 +70686,12 @@ impl std::fmt::Display for UnlistenStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UnlistenStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UnlistenStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpdateStmt {}
 impl pg_sys::PgNode for UpdateStmt {}
 impl std::fmt::Display for UpdateStmt {
@@ -67800,6 +70699,12 @@ impl std::fmt::Display for UpdateStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpdateStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpdateStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for UpperUniquePath {}
 impl pg_sys::PgNode for UpperUniquePath {}
 impl std::fmt::Display for UpperUniquePath {
@@ -67807,6 +70712,12 @@ impl std::fmt::Display for UpperUniquePath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for UpperUniquePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "UpperUniquePath"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumRelation {}
 impl pg_sys::PgNode for VacuumRelation {}
 impl std::fmt::Display for VacuumRelation {
@@ -67814,6 +70725,12 @@ impl std::fmt::Display for VacuumRelation {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumRelation"))
+    }
+}
 impl pg_sys::seal::Sealed for VacuumStmt {}
 impl pg_sys::PgNode for VacuumStmt {}
 impl std::fmt::Display for VacuumStmt {
@@ -67821,6 +70738,12 @@ impl std::fmt::Display for VacuumStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VacuumStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VacuumStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScan {}
 impl pg_sys::PgNode for ValuesScan {}
 impl std::fmt::Display for ValuesScan {
@@ -67828,6 +70751,12 @@ impl std::fmt::Display for ValuesScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScan"))
+    }
+}
 impl pg_sys::seal::Sealed for ValuesScanState {}
 impl pg_sys::PgNode for ValuesScanState {}
 impl std::fmt::Display for ValuesScanState {
@@ -67835,6 +70764,12 @@ impl std::fmt::Display for ValuesScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ValuesScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ValuesScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for Var {}
 impl pg_sys::PgNode for Var {}
 impl std::fmt::Display for Var {
@@ -67842,6 +70777,12 @@ impl std::fmt::Display for Var {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "Var"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableSetStmt {}
 impl pg_sys::PgNode for VariableSetStmt {}
 impl std::fmt::Display for VariableSetStmt {
@@ -67849,6 +70790,12 @@ impl std::fmt::Display for VariableSetStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableSetStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableSetStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VariableShowStmt {}
 impl pg_sys::PgNode for VariableShowStmt {}
 impl std::fmt::Display for VariableShowStmt {
@@ -67856,6 +70803,12 @@ impl std::fmt::Display for VariableShowStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VariableShowStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VariableShowStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for ViewStmt {}
 impl pg_sys::PgNode for ViewStmt {}
 impl std::fmt::Display for ViewStmt {
@@ -67863,6 +70816,12 @@ impl std::fmt::Display for ViewStmt {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for ViewStmt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "ViewStmt"))
+    }
+}
 impl pg_sys::seal::Sealed for VirtualTupleTableSlot {}
 impl pg_sys::PgNode for VirtualTupleTableSlot {}
 impl std::fmt::Display for VirtualTupleTableSlot {
@@ -67870,6 +70829,12 @@ impl std::fmt::Display for VirtualTupleTableSlot {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for VirtualTupleTableSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "VirtualTupleTableSlot"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAgg {}
 impl pg_sys::PgNode for WindowAgg {}
 impl std::fmt::Display for WindowAgg {
@@ -67877,6 +70842,12 @@ impl std::fmt::Display for WindowAgg {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAgg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAgg"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggPath {}
 impl pg_sys::PgNode for WindowAggPath {}
 impl std::fmt::Display for WindowAggPath {
@@ -67884,6 +70855,12 @@ impl std::fmt::Display for WindowAggPath {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggPath"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowAggState {}
 impl pg_sys::PgNode for WindowAggState {}
 impl std::fmt::Display for WindowAggState {
@@ -67891,6 +70868,12 @@ impl std::fmt::Display for WindowAggState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowAggState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowAggState"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowClause {}
 impl pg_sys::PgNode for WindowClause {}
 impl std::fmt::Display for WindowClause {
@@ -67898,6 +70881,12 @@ impl std::fmt::Display for WindowClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowDef {}
 impl pg_sys::PgNode for WindowDef {}
 impl std::fmt::Display for WindowDef {
@@ -67905,6 +70894,12 @@ impl std::fmt::Display for WindowDef {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowDef"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFunc {}
 impl pg_sys::PgNode for WindowFunc {}
 impl std::fmt::Display for WindowFunc {
@@ -67912,6 +70907,12 @@ impl std::fmt::Display for WindowFunc {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFunc"))
+    }
+}
 impl pg_sys::seal::Sealed for WindowFuncExprState {}
 impl pg_sys::PgNode for WindowFuncExprState {}
 impl std::fmt::Display for WindowFuncExprState {
@@ -67919,6 +70920,12 @@ impl std::fmt::Display for WindowFuncExprState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WindowFuncExprState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WindowFuncExprState"))
+    }
+}
 impl pg_sys::seal::Sealed for WithCheckOption {}
 impl pg_sys::PgNode for WithCheckOption {}
 impl std::fmt::Display for WithCheckOption {
@@ -67926,6 +70933,12 @@ impl std::fmt::Display for WithCheckOption {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithCheckOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithCheckOption"))
+    }
+}
 impl pg_sys::seal::Sealed for WithClause {}
 impl pg_sys::PgNode for WithClause {}
 impl std::fmt::Display for WithClause {
@@ -67933,6 +70946,12 @@ impl std::fmt::Display for WithClause {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WithClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WithClause"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScan {}
 impl pg_sys::PgNode for WorkTableScan {}
 impl std::fmt::Display for WorkTableScan {
@@ -67940,6 +70959,12 @@ impl std::fmt::Display for WorkTableScan {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScan"))
+    }
+}
 impl pg_sys::seal::Sealed for WorkTableScanState {}
 impl pg_sys::PgNode for WorkTableScanState {}
 impl std::fmt::Display for WorkTableScanState {
@@ -67947,6 +70972,12 @@ impl std::fmt::Display for WorkTableScanState {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for WorkTableScanState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "WorkTableScanState"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlExpr {}
 impl pg_sys::PgNode for XmlExpr {}
 impl std::fmt::Display for XmlExpr {
@@ -67954,6 +70985,12 @@ impl std::fmt::Display for XmlExpr {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlExpr"))
+    }
+}
 impl pg_sys::seal::Sealed for XmlSerialize {}
 impl pg_sys::PgNode for XmlSerialize {}
 impl std::fmt::Display for XmlSerialize {
@@ -67961,3 +70998,9 @@ impl std::fmt::Display for XmlSerialize {
         write!(f, "{}", self.display_node())
     }
 }
+#[cfg(feature = "node-debug")]
+impl std::fmt::Debug for XmlSerialize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&pg_sys::debug_node(self, "XmlSerialize"))
+    }
+}
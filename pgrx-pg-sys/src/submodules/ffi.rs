@@ -57,7 +57,7 @@ own.
 
 Furthermore, Postgres is a single-threaded runtime.  As such, [`pg_guard_ffi_boundary`] should
 **only** be called from the main thread.  In fact, [`pg_guard_ffi_boundary`] will detect this
-and immediately panic.
+and immediately panic, or in debug builds, abort the process with a message naming the thread.
 
 More generally, Rust cannot guarantee destructors are always run, PGRX is written in Rust code, and
 the implementation of `pg_guard_ffi_boundary` relies on help from Postgres, the OS, and the C runtime;
@@ -41,10 +41,12 @@ pub use datum::NullableDatum;
 pub use oids::*;
 pub use pg_try::*;
 pub use polyfill::*;
+pub use thread_check::{debug_assert_backend_thread, is_active_thread};
 pub use tupdesc::*;
 pub use utils::*;
 
-#[cfg(target_os = "linux")]
+// glibc's `sigsetjmp` is only a macro, but other libcs, like musl, have the function itself
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
 extern "C" {
     #[link_name = "__sigsetjmp"]
     pub(crate) fn sigsetjmp(
@@ -53,7 +55,12 @@ extern "C" {
     ) -> std::os::raw::c_int;
 }
 
-#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd"))]
+#[cfg(any(
+    all(target_os = "linux", not(target_env = "gnu")),
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "openbsd"
+))]
 extern "C" {
     pub(crate) fn sigsetjmp(
         env: *mut crate::sigjmp_buf,
@@ -275,6 +275,14 @@ impl ErrorReport {
         self
     }
 
+    /// Set the source location being reported, whose default is the caller of [`ErrorReport::new`]
+    pub fn set_location<S: Into<String>>(mut self, file: S, line: u32) -> Self {
+        self.location.file = file.into();
+        self.location.line = line;
+        self.location.col = 0;
+        self
+    }
+
     /// Returns the error message of this error report
     pub fn message(&self) -> &str {
         &self.message
@@ -378,10 +386,13 @@ enum GuardAction<R> {
 ///
 /// [trivially-deallocated stack frames](https://github.com/rust-lang/rfcs/blob/master/text/2945-c-unwind-abi.md#plain-old-frames)
 #[doc(hidden)]
+#[track_caller]
 pub unsafe fn pgrx_extern_c_guard<Func, R: Copy>(f: Func) -> R
 where
     Func: FnOnce() -> R + UnwindSafe + RefUnwindSafe,
 {
+    crate::thread_check::debug_assert_backend_thread("a #[pg_guard] function");
+
     match run_guarded(f) {
         GuardAction::Return(r) => r,
         GuardAction::ReThrow => {
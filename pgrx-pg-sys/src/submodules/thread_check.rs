@@ -18,6 +18,11 @@
 //!
 //! This is somewhat heavyhanded, and should be called from fewer places in the
 //! future...
+//!
+//! In debug builds a violation aborts the whole process with a message naming the offending
+//! thread instead of panicking.  A panic on a spawned thread only takes that thread down, and
+//! is easily swallowed by a `JoinHandle` that's never joined, by which point the thread may
+//! already have scribbled over backend-global state.
 
 use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -38,6 +43,15 @@ pub(crate) fn check_active_thread() {
     }
 }
 
+/// Is the current thread allowed to call into Postgres?  It is if it's the first thread that has,
+/// or no thread has yet.
+pub fn is_active_thread() -> bool {
+    match ACTIVE_THREAD.load(Ordering::Relaxed) {
+        0 => true,
+        thread_id => nonzero_thread_id().get() == thread_id,
+    }
+}
+
 #[track_caller]
 fn init_active_thread(tid: NonZeroUsize) {
     match ACTIVE_THREAD.compare_exchange(0, tid.get(), Ordering::Relaxed, Ordering::Relaxed) {
@@ -55,10 +69,41 @@ fn init_active_thread(tid: NonZeroUsize) {
     }
 }
 
+/// In debug builds, abort the process if the current thread isn't the one allowed to call into
+/// Postgres.  `what` names the thing being called, for the message.  Compiles to nothing in
+/// release builds.
+#[inline(always)]
+#[track_caller]
+pub fn debug_assert_backend_thread(what: &str) {
+    if cfg!(debug_assertions) && !is_active_thread() {
+        abort_off_backend_thread(what, std::panic::Location::caller());
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn abort_off_backend_thread(what: &str, location: &std::panic::Location) -> ! {
+    use std::io::Write;
+
+    let thread = std::thread::current();
+    let _ = writeln!(
+        std::io::stderr(),
+        "{location}:  {what} was called from thread {:?} ({:?}), but Postgres is single-threaded and \
+         may only be called from the backend's main thread.  Send results back to the backend thread \
+         instead, for example with `pgrx::backend_channel::BackendChannel`.  Aborting.",
+        thread.name().unwrap_or("<unnamed>"),
+        thread.id(),
+    );
+    std::process::abort()
+}
+
 #[cold]
 #[inline(never)]
 #[track_caller]
 fn thread_id_check_failed() -> ! {
+    if cfg!(debug_assertions) {
+        abort_off_backend_thread("a Postgres function", std::panic::Location::caller());
+    }
     panic!(
         "{}:  postgres FFI may not not be called from multiple threads.",
         std::panic::Location::caller()
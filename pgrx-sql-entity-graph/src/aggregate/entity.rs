@@ -117,7 +117,7 @@ pub struct PgAggregateEntity {
     // pub msspace: &'static str,
     /// The `MFINALFUNC` parameter for [`CREATE AGGREGATE`](https://www.postgresql.org/docs/current/sql-createaggregate.html)
     ///
-    /// Corresponds to `moving_state_finalize` in [`pgrx::aggregate::Aggregate`].
+    /// Corresponds to `moving_finalize` in [`pgrx::aggregate::Aggregate`].
     pub mfinalfunc: Option<&'static str>,
 
     /// The `MFINALFUNC_MODIFY` parameter for [`CREATE AGGREGATE`](https://www.postgresql.org/docs/current/sql-createaggregate.html)
@@ -200,7 +200,7 @@ impl ToSql for PgAggregateEntity {
         }
         if let Some(value) = self.deserialfunc {
             optional_attributes.push((
-                format!("\tDESERIALFUNC ={} \"{}\"", schema, value),
+                format!("\tDESERIALFUNC = {}\"{}\"", schema, value),
                 format!("/* {}::deserial */", self.full_path),
             ));
         }
@@ -380,56 +380,88 @@ impl PgAggregate {
         };
 
         let fn_serial = get_impl_func_by_name(&item_impl_snapshot, "serial");
-        let fn_serial_name = if let Some(found) = fn_serial {
-            let fn_name =
-                Ident::new(&format!("{}_serial", snake_case_target_ident), found.sig.ident.span());
-            let pg_extern_attr = pg_extern_attr(found);
+        let fn_deserial = get_impl_func_by_name(&item_impl_snapshot, "deserial");
+        // A parallel aggregate with an `InternalState<T>` gets `serial` and `deserial` that
+        // (de)serialize `T` with serde
+        let serde_state = fn_serial.is_none()
+            && fn_deserial.is_none()
+            && fn_combine_name.is_some()
+            && is_internal_state(&type_state_without_self);
+
+        let fn_serial_name = if fn_serial.is_some() || serde_state {
+            let fn_name = Ident::new(
+                &format!("{}_serial", snake_case_target_ident),
+                fn_serial.map_or(target_ident.span(), |found| found.sig.ident.span()),
+            );
+            let pg_extern_attr = match fn_serial {
+                Some(found) => pg_extern_attr(found),
+                None => parse_quote! { #[::pgrx::pg_extern(immutable, parallel_safe)] },
+            };
+            let serial = match fn_serial {
+                Some(_) => {
+                    quote! { <#target_path as ::pgrx::aggregate::Aggregate>::serial(this, fcinfo) }
+                }
+                None => quote! { ::pgrx::aggregate::InternalState::serialize(&this) },
+            };
             pg_externs.push(parse_quote! {
                 #[allow(non_snake_case, clippy::too_many_arguments)]
                 #pg_extern_attr
                 fn #fn_name(this: #type_state_without_self, fcinfo: ::pgrx::pg_sys::FunctionCallInfo) -> Vec<u8> {
                     <#target_path as ::pgrx::aggregate::Aggregate>::in_memory_context(
                         fcinfo,
-                        move |_context| <#target_path as ::pgrx::aggregate::Aggregate>::serial(this, fcinfo)
+                        move |_context| #serial
                     )
                 }
             });
             Some(fn_name)
         } else {
+            None
+        };
+        if fn_serial.is_none() {
             item_impl.items.push(parse_quote! {
                 fn serial(current: #type_state_without_self, _fcinfo: ::pgrx::pg_sys::FunctionCallInfo) -> Vec<u8> {
                     unimplemented!("Call to serial on an aggregate which does not support it.")
                 }
             });
-            None
-        };
+        }
 
-        let fn_deserial = get_impl_func_by_name(&item_impl_snapshot, "deserial");
-        let fn_deserial_name = if let Some(found) = fn_deserial {
+        let fn_deserial_name = if fn_deserial.is_some() || serde_state {
             let fn_name = Ident::new(
                 &format!("{}_deserial", snake_case_target_ident),
-                found.sig.ident.span(),
+                fn_deserial.map_or(target_ident.span(), |found| found.sig.ident.span()),
             );
-            let pg_extern_attr = pg_extern_attr(found);
+            let pg_extern_attr = match fn_deserial {
+                Some(found) => pg_extern_attr(found),
+                None => parse_quote! { #[::pgrx::pg_extern(immutable, parallel_safe)] },
+            };
+            let deserial = match fn_deserial {
+                Some(_) => {
+                    quote! { <#target_path as ::pgrx::aggregate::Aggregate>::deserial(buf, fcinfo) }
+                }
+                None => quote! { ::pgrx::aggregate::InternalState::deserialize(&buf) },
+            };
+            // Postgres requires a deserialization function to take an unused `internal` too
             pg_externs.push(parse_quote! {
                 #[allow(non_snake_case, clippy::too_many_arguments)]
                 #pg_extern_attr
-                fn #fn_name(this: #type_state_without_self, buf: Vec<u8>, internal: ::pgrx::pgbox::PgBox<#type_state_without_self>, fcinfo: ::pgrx::pg_sys::FunctionCallInfo) -> ::pgrx::pgbox::PgBox<#type_state_without_self> {
+                fn #fn_name(buf: Vec<u8>, _internal: ::pgrx::datum::Internal, fcinfo: ::pgrx::pg_sys::FunctionCallInfo) -> #type_state_without_self {
                     <#target_path as ::pgrx::aggregate::Aggregate>::in_memory_context(
                         fcinfo,
-                        move |_context| <#target_path as ::pgrx::aggregate::Aggregate>::deserial(this, buf, internal, fcinfo)
+                        move |_context| #deserial
                     )
                 }
             });
             Some(fn_name)
         } else {
+            None
+        };
+        if fn_deserial.is_none() {
             item_impl.items.push(parse_quote! {
-                fn deserial(current: #type_state_without_self, _buf: Vec<u8>, _internal: ::pgrx::pgbox::PgBox<#type_state_without_self>, _fcinfo: ::pgrx::pg_sys::FunctionCallInfo) -> ::pgrx::pgbox::PgBox<#type_state_without_self> {
+                fn deserial(_buf: Vec<u8>, _fcinfo: ::pgrx::pg_sys::FunctionCallInfo) -> #type_state_without_self {
                     unimplemented!("Call to deserial on an aggregate which does not support it.")
                 }
             });
-            None
-        };
+        }
 
         let fn_moving_state = get_impl_func_by_name(&item_impl_snapshot, "moving_state");
         let fn_moving_state_name = if let Some(found) = fn_moving_state {
@@ -533,6 +565,63 @@ impl PgAggregate {
             None
         };
 
+        // Postgres only accepts moving-aggregate mode with all of `MSTYPE`, `MSFUNC`, and
+        // `MINVFUNC`, and the other `M*` parameters only with them
+        let moving = [
+            type_moving_state_value.is_some(),
+            fn_moving_state_name.is_some(),
+            fn_moving_state_inverse_name.is_some(),
+        ];
+        let moving_extras = fn_moving_finalize_name.is_some()
+            || ["MOVING_FINALIZE_MODIFY", "MOVING_INITIAL_CONDITION"]
+                .into_iter()
+                .any(|name| get_impl_const_by_name(&item_impl_snapshot, name).is_some());
+        if moving.contains(&true) && moving.contains(&false) || moving_extras && !moving[0] {
+            return Err(syn::Error::new(
+                item_impl_snapshot.self_ty.span(),
+                "moving-aggregate mode needs all of `MovingState`, `moving_state`, and `moving_state_inverse`",
+            ));
+        }
+
+        let const_ordered_set = get_impl_const_by_name(&item_impl_snapshot, "ORDERED_SET")
+            .and_then(get_const_litbool)
+            .unwrap_or(false);
+        let hypothetical = if let Some(value) =
+            get_impl_const_by_name(&item_impl_snapshot, "HYPOTHETICAL")
+        {
+            match &value.expr {
+                syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+                    syn::Lit::Bool(lit) => lit.value,
+                    _ => return Err(syn::Error::new(value.span(), "`#[pg_aggregate]` required the `HYPOTHETICAL` value to be a literal boolean.")),
+                },
+                _ => return Err(syn::Error::new(value.span(), "`#[pg_aggregate]` required the `HYPOTHETICAL` value to be a literal boolean.")),
+            }
+        } else {
+            false
+        };
+        // Postgres rejects these when creating the aggregate, but they're easier to fix from here
+        if hypothetical && !const_ordered_set {
+            return Err(syn::Error::new(
+                item_impl_snapshot.self_ty.span(),
+                "only ordered-set aggregates, with `ORDERED_SET = true`, can be `HYPOTHETICAL`",
+            ));
+        }
+        if const_ordered_set && moving[0] {
+            return Err(syn::Error::new(
+                item_impl_snapshot.self_ty.span(),
+                "ordered-set aggregates can't have a moving-aggregate mode",
+            ));
+        }
+        if hypothetical
+            && type_ordered_set_args_value.as_ref().map_or(0, |args| args.found.len())
+                < type_args_value.found.len()
+        {
+            return Err(syn::Error::new(
+                item_impl_snapshot.self_ty.span(),
+                "the last `OrderedSetArgs` of a hypothetical-set aggregate must match its `Args`",
+            ));
+        }
+
         Ok(CodeEnrichment(Self {
             item_impl,
             target_ident,
@@ -557,9 +646,7 @@ impl PgAggregate {
             )
             .and_then(|e| get_const_litstr(e).transpose())
             .transpose()?,
-            const_ordered_set: get_impl_const_by_name(&item_impl_snapshot, "ORDERED_SET")
-                .and_then(get_const_litbool)
-                .unwrap_or(false),
+            const_ordered_set,
             const_sort_operator: get_impl_const_by_name(&item_impl_snapshot, "SORT_OPERATOR")
                 .and_then(|e| get_const_litstr(e).transpose())
                 .transpose()?,
@@ -577,19 +664,7 @@ impl PgAggregate {
             fn_moving_state: fn_moving_state_name,
             fn_moving_state_inverse: fn_moving_state_inverse_name,
             fn_moving_finalize: fn_moving_finalize_name,
-            hypothetical: if let Some(value) =
-                get_impl_const_by_name(&item_impl_snapshot, "HYPOTHETICAL")
-            {
-                match &value.expr {
-                    syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
-                        syn::Lit::Bool(lit) => lit.value,
-                        _ => return Err(syn::Error::new(value.span(), "`#[pg_aggregate]` required the `HYPOTHETICAL` value to be a literal boolean.")),
-                    },
-                    _ => return Err(syn::Error::new(value.span(), "`#[pg_aggregate]` required the `HYPOTHETICAL` value to be a literal boolean.")),
-                }
-            } else {
-                false
-            },
+            hypothetical,
             to_sql_config,
         }))
     }
@@ -604,6 +679,7 @@ impl ToEntityGraphTokens for PgAggregate {
             &format!("__pgrx_internals_aggregate_{}", snake_case_target_ident),
             target_ident.span(),
         );
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
 
         let name = &self.name;
         let type_args_iter = &self.type_args.entity_tokens();
@@ -632,6 +708,7 @@ impl ToEntityGraphTokens for PgAggregate {
         let to_sql_config = &self.to_sql_config;
 
         quote! {
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
@@ -741,6 +818,16 @@ fn get_target_path(item_impl: &ItemImpl) -> Result<Path, syn::Error> {
     Ok(target_ident)
 }
 
+/// Is `ty` a `pgrx::aggregate::InternalState<T>`?
+fn is_internal_state(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => {
+            ty.path.segments.last().map_or(false, |segment| segment.ident == "InternalState")
+        }
+        _ => false,
+    }
+}
+
 fn pg_extern_attr(item: &ImplItemMethod) -> syn::Attribute {
     let mut found = None;
     for attr in item.attrs.iter() {
@@ -952,7 +1039,6 @@ mod tests {
                 const MOVING_FINALIZE_MODIFY: Option<FinalizeModify> = Some(FinalizeModify::ReadWrite);
                 const SORT_OPERATOR: Option<&'static str> = Some("sortop");
                 const MOVING_INITIAL_CONDITION: Option<&'static str> = Some("1,1");
-                const HYPOTHETICAL: bool = true;
 
                 fn state(current: Self::State, v: Self::Args) -> Self::State {
                     todo!()
@@ -1013,4 +1099,25 @@ mod tests {
         assert!(agg.is_err());
         Ok(())
     }
+
+    #[test]
+    fn agg_hypothetical_requires_ordered_set() -> Result<()> {
+        // This is not valid as only ordered-set aggregates can be hypothetical.
+        let tokens: ItemImpl = parse_quote! {
+            #[pg_aggregate]
+            impl Aggregate for DemoAgg {
+                type State = i32;
+                type Args = i32;
+                type OrderedSetArgs = i32;
+                const HYPOTHETICAL: bool = true;
+
+                fn state(current: Self::State, v: Self::Args) -> Self::State {
+                    todo!()
+                }
+            }
+        };
+        let agg = PgAggregate::new(tokens);
+        assert!(agg.is_err());
+        Ok(())
+    }
 }
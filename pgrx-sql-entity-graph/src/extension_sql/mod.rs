@@ -96,7 +96,9 @@ impl ToEntityGraphTokens for ExtensionSqlFile {
         let creates_iter = creates.iter();
         let sql_graph_entity_fn_name =
             syn::Ident::new(&format!("__pgrx_internals_sql_{}", name.clone()), Span::call_site());
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
         quote! {
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
@@ -194,7 +196,9 @@ impl ToEntityGraphTokens for ExtensionSql {
 
         let sql_graph_entity_fn_name =
             syn::Ident::new(&format!("__pgrx_internals_sql_{}", name.value()), Span::call_site());
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
         quote! {
+            #section_entry
             #[no_mangle]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
             pub extern "Rust" fn  #sql_graph_entity_fn_name() -> ::pgrx::pgrx_sql_entity_graph::SqlGraphEntity {
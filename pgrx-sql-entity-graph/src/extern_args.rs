@@ -30,6 +30,7 @@ pub enum ExternArgs {
     Schema(String),
     Name(String),
     Cost(String),
+    Support(String),
     Requires(Vec<PositioningRef>),
 }
 
@@ -52,6 +53,7 @@ impl core::fmt::Display for ExternArgs {
             ExternArgs::Schema(_) => Ok(()),
             ExternArgs::Name(_) => Ok(()),
             ExternArgs::Cost(cost) => write!(f, "COST {}", cost),
+            ExternArgs::Support(support) => write!(f, "SUPPORT {}", support),
             ExternArgs::Requires(_) => Ok(()),
         }
     }
@@ -104,6 +106,14 @@ impl ToTokens for ExternArgs {
                     .to_token_stream(),
                 );
             }
+            ExternArgs::Support(_s) => {
+                tokens.append_all(
+                    quote! {
+                        Support(String::from("#_s"))
+                    }
+                    .to_token_stream(),
+                );
+            }
             ExternArgs::Requires(items) => {
                 tokens.append_all(
                     quote! {
@@ -171,7 +181,33 @@ pub fn parse_extern_attributes(attr: TokenStream) -> HashSet<ExternArgs> {
                         let name = name[1..name.len() - 1].to_string();
                         args.insert(ExternArgs::Name(name.to_string()))
                     }
+                    "support" => {
+                        let _punc = itr.next().unwrap();
+                        let value = itr.next().unwrap();
+                        let support = match value {
+                            // a string of SQL naming the function
+                            TokenTree::Literal(literal) => {
+                                let support = literal.to_string();
+                                let support =
+                                    unescape::unescape(&support).expect("failed to unescape");
+                                support[1..support.len() - 1].to_string()
+                            }
+                            // the name of a `#[pg_extern]` function
+                            other => other.to_string(),
+                        };
+                        args.insert(ExternArgs::Support(support))
+                    }
                     // Recognized, but not handled as an extern argument
+                    "blocking_on" | "memory_quota" => {
+                        // skip the `=` and the path or expression that follows it
+                        let _punc = itr.next().unwrap();
+                        for t in itr.by_ref() {
+                            if matches!(&t, TokenTree::Punct(p) if p.as_char() == ',') {
+                                break;
+                            }
+                        }
+                        false
+                    }
                     "sql" => {
                         let _punc = itr.next().unwrap();
                         let _value = itr.next().unwrap();
@@ -201,4 +237,31 @@ mod tests {
         let args = parse_extern_attributes(ts);
         assert!(args.contains(&ExternArgs::Error("syntax error at or near \"THIS\"".to_string())));
     }
+
+    #[test]
+    fn parse_support() {
+        let ts = proc_macro2::TokenStream::from_str("immutable, support = my_support").unwrap();
+        let args = parse_extern_attributes(ts);
+        assert!(args.contains(&ExternArgs::Support("my_support".to_string())));
+
+        let ts = proc_macro2::TokenStream::from_str("support = \"ext.my_support\"").unwrap();
+        let args = parse_extern_attributes(ts);
+        assert!(args.contains(&ExternArgs::Support("ext.my_support".to_string())));
+    }
+
+    #[test]
+    fn parse_blocking_on() {
+        let s = "blocking_on = runtimes::strict::run, immutable";
+        let ts = proc_macro2::TokenStream::from_str(s).unwrap();
+        let args = parse_extern_attributes(ts);
+        assert_eq!(args.into_iter().collect::<Vec<_>>(), vec![ExternArgs::Immutable]);
+    }
+
+    #[test]
+    fn parse_memory_quota() {
+        let s = "memory_quota = 16 * 1024 * 1024, strict";
+        let ts = proc_macro2::TokenStream::from_str(s).unwrap();
+        let args = parse_extern_attributes(ts);
+        assert_eq!(args.into_iter().collect::<Vec<_>>(), vec![ExternArgs::Strict]);
+    }
 }
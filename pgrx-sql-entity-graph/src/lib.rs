@@ -30,10 +30,13 @@ pub use pg_extern::entity::{
     PgOperatorEntity,
 };
 pub use pg_extern::{NameMacro, PgExtern, PgExternArgument, PgOperator};
+pub use pg_opclass::entity::PgOpClassEntity;
+pub use pg_opclass::{OpClassAccessMethod, PgOpClass};
+pub use pg_text_search::{PgTextSearch, TextSearchKind};
 pub use pg_trigger::attribute::PgTriggerAttribute;
 pub use pg_trigger::entity::PgTriggerEntity;
 pub use pg_trigger::PgTrigger;
-pub use pgrx_sql::PgrxSql;
+pub use pgrx_sql::{PgrxSql, EXTSCHEMA};
 pub use positioning_ref::PositioningRef;
 pub use postgres_enum::entity::PostgresEnumEntity;
 pub use postgres_enum::PostgresEnum;
@@ -45,6 +48,7 @@ pub use postgres_type::entity::PostgresTypeEntity;
 pub use postgres_type::PostgresType;
 pub use schema::entity::SchemaEntity;
 pub use schema::Schema;
+pub use signatures::{EntitySignatures, SignatureChange};
 pub use to_sql::entity::ToSqlConfigEntity;
 pub use to_sql::{ToSql, ToSqlConfig};
 pub use used_type::{UsedType, UsedTypeEntity};
@@ -58,6 +62,8 @@ pub mod lifetimes;
 pub(crate) mod mapping;
 pub mod metadata;
 pub(crate) mod pg_extern;
+pub(crate) mod pg_opclass;
+pub(crate) mod pg_text_search;
 pub(crate) mod pg_trigger;
 pub(crate) mod pgrx_attribute;
 pub(crate) mod pgrx_sql;
@@ -67,6 +73,8 @@ pub(crate) mod postgres_hash;
 pub(crate) mod postgres_ord;
 pub(crate) mod postgres_type;
 pub(crate) mod schema;
+pub mod section;
+pub(crate) mod signatures;
 pub(crate) mod to_sql;
 pub(crate) mod used_type;
 
@@ -102,6 +110,7 @@ pub enum SqlGraphEntity {
     Enum(PostgresEnumEntity),
     Ord(PostgresOrdEntity),
     Hash(PostgresHashEntity),
+    OpClass(PgOpClassEntity),
     Aggregate(PgAggregateEntity),
     Trigger(PgTriggerEntity),
 }
@@ -135,6 +144,7 @@ impl SqlGraphIdentifier for SqlGraphEntity {
             SqlGraphEntity::Enum(item) => item.dot_identifier(),
             SqlGraphEntity::Ord(item) => item.dot_identifier(),
             SqlGraphEntity::Hash(item) => item.dot_identifier(),
+            SqlGraphEntity::OpClass(item) => item.dot_identifier(),
             SqlGraphEntity::Aggregate(item) => item.dot_identifier(),
             SqlGraphEntity::Trigger(item) => item.dot_identifier(),
             SqlGraphEntity::ExtensionRoot(item) => item.dot_identifier(),
@@ -151,6 +161,7 @@ impl SqlGraphIdentifier for SqlGraphEntity {
             SqlGraphEntity::Enum(item) => item.rust_identifier(),
             SqlGraphEntity::Ord(item) => item.rust_identifier(),
             SqlGraphEntity::Hash(item) => item.rust_identifier(),
+            SqlGraphEntity::OpClass(item) => item.rust_identifier(),
             SqlGraphEntity::Aggregate(item) => item.rust_identifier(),
             SqlGraphEntity::Trigger(item) => item.rust_identifier(),
             SqlGraphEntity::ExtensionRoot(item) => item.rust_identifier(),
@@ -167,6 +178,7 @@ impl SqlGraphIdentifier for SqlGraphEntity {
             SqlGraphEntity::Enum(item) => item.file(),
             SqlGraphEntity::Ord(item) => item.file(),
             SqlGraphEntity::Hash(item) => item.file(),
+            SqlGraphEntity::OpClass(item) => item.file(),
             SqlGraphEntity::Aggregate(item) => item.file(),
             SqlGraphEntity::Trigger(item) => item.file(),
             SqlGraphEntity::ExtensionRoot(item) => item.file(),
@@ -183,6 +195,7 @@ impl SqlGraphIdentifier for SqlGraphEntity {
             SqlGraphEntity::Enum(item) => item.line(),
             SqlGraphEntity::Ord(item) => item.line(),
             SqlGraphEntity::Hash(item) => item.line(),
+            SqlGraphEntity::OpClass(item) => item.line(),
             SqlGraphEntity::Aggregate(item) => item.line(),
             SqlGraphEntity::Trigger(item) => item.line(),
             SqlGraphEntity::ExtensionRoot(item) => item.line(),
@@ -246,6 +259,9 @@ impl ToSql for SqlGraphEntity {
             SqlGraphEntity::Hash(item) => {
                 item.to_sql_config.to_sql(self, context).unwrap_or_else(|| item.to_sql(context))
             }
+            SqlGraphEntity::OpClass(item) => {
+                item.to_sql_config.to_sql(self, context).unwrap_or_else(|| item.to_sql(context))
+            }
             SqlGraphEntity::Aggregate(item) => {
                 item.to_sql_config.to_sql(self, context).unwrap_or_else(|| item.to_sql(context))
             }
@@ -288,6 +288,24 @@ unsafe impl<'a> SqlTranslatable for &'a [u8] {
     }
 }
 
+/// for `#[pg_extern]` arguments that borrow the elements of an array without copying them
+macro_rules! impl_sql_translatable_for_slice {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl<'a> SqlTranslatable for &'a [$t] {
+                fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+                    Vec::<$t>::argument_sql()
+                }
+                fn return_sql() -> Result<Returns, ReturnsError> {
+                    Vec::<$t>::return_sql()
+                }
+            }
+        )*
+    };
+}
+
+impl_sql_translatable_for_slice!(i8, i16, i32, i64, f32, f64);
+
 unsafe impl SqlTranslatable for i8 {
     fn argument_sql() -> Result<SqlMapping, ArgumentError> {
         Ok(SqlMapping::As(String::from("\"char\"")))
@@ -378,3 +396,41 @@ unsafe impl SqlTranslatable for &'_ core::ffi::CStr {
         Ok(Returns::One(SqlMapping::literal("cstring")))
     }
 }
+
+#[cfg(feature = "rust_decimal")]
+unsafe impl SqlTranslatable for rust_decimal::Decimal {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("NUMERIC"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("NUMERIC")))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+unsafe impl SqlTranslatable for num_bigint::BigInt {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("NUMERIC"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("NUMERIC")))
+    }
+}
+
+unsafe impl SqlTranslatable for std::collections::HashMap<String, Option<String>> {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("hstore"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("hstore")))
+    }
+}
+
+unsafe impl SqlTranslatable for std::collections::BTreeMap<String, Option<String>> {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("hstore"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("hstore")))
+    }
+}
@@ -41,8 +41,12 @@ pub enum Attribute {
     Schema(syn::LitStr),
     Name(syn::LitStr),
     Cost(syn::Expr),
+    Support(syn::LitStr),
     Requires(Punctuated<PositioningRef, Token![,]>),
     Sql(ToSqlConfig),
+    BlockingOn(syn::Path),
+    MemoryQuota(syn::Expr),
+    FoldConstants,
 }
 
 impl Attribute {
@@ -88,12 +92,18 @@ impl Attribute {
             Attribute::Cost(s) => {
                 quote! { ::pgrx::pgrx_sql_entity_graph::ExternArgs::Cost(format!("{}", #s)) }
             }
+            Attribute::Support(s) => {
+                quote! { ::pgrx::pgrx_sql_entity_graph::ExternArgs::Support(String::from(#s)) }
+            }
             Attribute::Requires(items) => {
                 let items_iter = items.iter().map(|x| x.to_token_stream()).collect::<Vec<_>>();
                 quote! { ::pgrx::pgrx_sql_entity_graph::ExternArgs::Requires(vec![#(#items_iter),*],) }
             }
-            // This attribute is handled separately
-            Attribute::Sql(_) => {
+            // These attributes are handled separately
+            Attribute::Sql(_)
+            | Attribute::BlockingOn(_)
+            | Attribute::MemoryQuota(_)
+            | Attribute::FoldConstants => {
                 quote! {}
             }
         }
@@ -137,6 +147,9 @@ impl ToTokens for Attribute {
             Attribute::Cost(s) => {
                 quote! { cost = #s }
             }
+            Attribute::Support(s) => {
+                quote! { support = #s }
+            }
             Attribute::Requires(items) => {
                 let items_iter = items.iter().map(|x| x.to_token_stream()).collect::<Vec<_>>();
                 quote! { requires = [#(#items_iter),*] }
@@ -145,6 +158,15 @@ impl ToTokens for Attribute {
             Attribute::Sql(to_sql_config) => {
                 quote! { sql = #to_sql_config }
             }
+            Attribute::BlockingOn(path) => {
+                quote! { blocking_on = #path }
+            }
+            Attribute::MemoryQuota(quota) => {
+                quote! { memory_quota = #quota }
+            }
+            Attribute::FoldConstants => {
+                quote! { fold_constants }
+            }
         };
         tokens.append_all(quoted);
     }
@@ -166,6 +188,7 @@ impl Parse for Attribute {
             "parallel_safe" => Self::ParallelSafe,
             "parallel_unsafe" => Self::ParallelUnsafe,
             "parallel_restricted" => Self::ParallelRestricted,
+            "fold_constants" => Self::FoldConstants,
             "error" => {
                 let _eq: Token![=] = input.parse()?;
                 let literal: syn::LitStr = input.parse()?;
@@ -186,12 +209,35 @@ impl Parse for Attribute {
                 let literal: syn::Expr = input.parse()?;
                 Self::Cost(literal)
             }
+            "support" => {
+                let _eq: Token![=] = input.parse()?;
+                // either the name of another `#[pg_extern]` function, or a string of SQL naming
+                // any function, perhaps schema-qualified
+                if input.peek(syn::LitStr) {
+                    Self::Support(input.parse()?)
+                } else {
+                    let path: syn::Path = input.parse()?;
+                    let name = path.segments.last().unwrap().ident.to_string();
+                    Self::Support(syn::LitStr::new(
+                        &name,
+                        path.segments.last().unwrap().ident.span(),
+                    ))
+                }
+            }
             "requires" => {
                 let _eq: syn::token::Eq = input.parse()?;
                 let content;
                 let _bracket = syn::bracketed!(content in input);
                 Self::Requires(content.parse_terminated(PositioningRef::parse)?)
             }
+            "blocking_on" => {
+                let _eq: Token![=] = input.parse()?;
+                Self::BlockingOn(input.parse()?)
+            }
+            "memory_quota" => {
+                let _eq: Token![=] = input.parse()?;
+                Self::MemoryQuota(input.parse()?)
+            }
             "sql" => {
                 use crate::pgrx_attribute::ArgValue;
                 use syn::Lit;
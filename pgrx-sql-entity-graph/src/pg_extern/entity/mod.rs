@@ -28,6 +28,7 @@ use crate::pgrx_sql::PgrxSql;
 use crate::to_sql::entity::ToSqlConfigEntity;
 use crate::to_sql::ToSql;
 use crate::ExternArgs;
+use crate::UsedTypeEntity;
 use crate::{SqlGraphEntity, SqlGraphIdentifier};
 
 use eyre::{eyre, WrapErr};
@@ -51,6 +52,39 @@ pub struct PgExternEntity {
     pub to_sql_config: ToSqlConfigEntity,
 }
 
+impl PgExternEntity {
+    /// The names of the functions that this function's SQL refers to, and so must be created
+    /// before it: its planner support function, and its operator's selectivity estimators
+    pub(crate) fn referenced_function_names(&self) -> Vec<&str> {
+        let support = self.extern_attrs.iter().filter_map(|attr| match attr {
+            ExternArgs::Support(support) => Some(support.as_str()),
+            _ => None,
+        });
+        let estimators = self.operator.iter().flat_map(|op| op.restrict.into_iter().chain(op.join));
+        support
+            .chain(estimators)
+            // the name may be schema-qualified
+            .map(|name| name.rsplit('.').next().unwrap().trim_matches('"'))
+            .collect()
+    }
+}
+
+impl PgExternEntity {
+    /// The types of the left and right operands, if this is a binary `#[pg_operator]`
+    pub(crate) fn operand_types(&self) -> Option<(&UsedTypeEntity, &UsedTypeEntity)> {
+        self.operator.as_ref()?;
+        let mut operands = self
+            .fn_args
+            .iter()
+            .filter(|arg| arg.used_ty.metadata.argument_sql != Ok(SqlMapping::Skip))
+            .map(|arg| &arg.used_ty);
+        match (operands.next(), operands.next(), operands.next()) {
+            (Some(left), Some(right), None) => Some((left, right)),
+            _ => None,
+        }
+    }
+}
+
 impl From<PgExternEntity> for SqlGraphEntity {
     fn from(val: PgExternEntity) -> Self {
         SqlGraphEntity::Function(val)
@@ -97,6 +131,26 @@ impl ToSql for PgExternEntity {
         extern_attrs.sort();
         extern_attrs.dedup();
 
+        // Postgres' coding rule for `internal`: a function that returns one must take one, so that
+        // SQL can't call it to make up a pointer
+        let is_internal =
+            |sql: &SqlMapping| matches!(sql, SqlMapping::As(sql) if sql == "internal");
+        let returns_internal = matches!(
+            self.metadata.retval.as_ref().map(|retval| &retval.return_sql),
+            Some(Ok(Returns::One(sql))) if is_internal(sql)
+        );
+        let takes_internal = self
+            .metadata
+            .arguments
+            .iter()
+            .any(|arg| matches!(&arg.argument_sql, Ok(sql) if is_internal(sql)));
+        if returns_internal && !takes_internal {
+            return Err(eyre!(
+                "`{}` returns `internal` without taking an `internal` argument, which would let SQL call it",
+                self.full_path
+            ));
+        }
+
         let module_pathname = &context.get_module_pathname();
 
         let fn_sql = format!(
@@ -400,7 +454,7 @@ impl ToSql for PgExternEntity {
                 let mut retval = extern_attrs
                     .iter()
                     .filter(|attr| **attr != ExternArgs::CreateOrReplace)
-                    .map(|attr| format!("{}", attr).to_uppercase())
+                    .map(|attr| format!("{}", attr))
                     .collect::<Vec<_>>()
                     .join(" ");
                 retval.push('\n');
@@ -35,7 +35,7 @@ use crate::enrich::CodeEnrichment;
 use crate::enrich::ToEntityGraphTokens;
 use crate::enrich::ToRustCodeTokens;
 use crate::lifetimes::staticize_lifetimes;
-use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream as TokenStream2, TokenTree};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream, Parser};
 use syn::punctuated::Punctuated;
@@ -78,12 +78,16 @@ pub struct PgExtern {
     inputs: Vec<PgExternArgument>,
     input_types: Vec<syn::Type>,
     returns: Returning,
+    fold_constants: Option<TokenStream2>,
 }
 
 impl PgExtern {
     pub fn new(attr: TokenStream2, item: TokenStream2) -> Result<CodeEnrichment<Self>, syn::Error> {
         let mut attrs = Vec::new();
         let mut to_sql_config: Option<ToSqlConfig> = None;
+        let mut blocking_on: Option<syn::Path> = None;
+        let mut memory_quota: Option<syn::Expr> = None;
+        let mut fold_constants = false;
 
         let parser = Punctuated::<Attribute, Token![,]>::parse_terminated;
         let punctuated_attrs = parser.parse2(attr)?;
@@ -92,6 +96,15 @@ impl PgExtern {
                 Attribute::Sql(config) => {
                     to_sql_config.get_or_insert(config);
                 }
+                Attribute::BlockingOn(path) => {
+                    blocking_on.get_or_insert(path);
+                }
+                Attribute::MemoryQuota(quota) => {
+                    memory_quota.get_or_insert(quota);
+                }
+                Attribute::FoldConstants => {
+                    fold_constants = true;
+                }
                 attr => {
                     attrs.push(attr);
                 }
@@ -100,7 +113,14 @@ impl PgExtern {
 
         let mut to_sql_config = to_sql_config.unwrap_or_default();
 
-        let func = syn::parse2::<syn::ItemFn>(item)?;
+        let mut func = syn::parse2::<syn::ItemFn>(item)?;
+        init_path_only(func.block.to_token_stream(), &mut false)?;
+        Self::block_on_async(&mut func, blocking_on)?;
+        Self::limit_memory(&mut func, memory_quota);
+        let fold_constants = match fold_constants {
+            true => Some(Self::fold_constants_support(&func, &mut attrs)?),
+            false => None,
+        };
 
         if let Some(ref mut content) = to_sql_config.content {
             let value = content.value();
@@ -127,9 +147,81 @@ impl PgExtern {
             inputs,
             input_types,
             returns,
+            fold_constants,
         }))
     }
 
+    /// Turn an `async fn` into a plain one whose body is run to completion by `blocking_on`, a
+    /// path to a function like `fn<F: Future>(F) -> F::Output`
+    fn block_on_async(func: &mut syn::ItemFn, blocking_on: Option<syn::Path>) -> syn::Result<()> {
+        match (func.sig.asyncness.take(), blocking_on) {
+            (Some(_), Some(blocking_on)) => {
+                let block = &func.block;
+                func.block = Box::new(syn::parse_quote_spanned! { block.span() =>
+                    { #blocking_on(async move #block) }
+                });
+                Ok(())
+            }
+            (Some(asyncness), None) => Err(syn::Error::new(
+                asyncness.span(),
+                "an async #[pg_extern] function needs a `blocking_on = ...` function to run it, \
+                 such as `pgrx::async_support::block_on`",
+            )),
+            (None, Some(blocking_on)) => Err(syn::Error::new(
+                blocking_on.span(),
+                "`blocking_on` is only for async functions",
+            )),
+            (None, None) => Ok(()),
+        }
+    }
+
+    /// Run the function's body under `pgrx::memory_quota::with_memory_quota()`
+    fn limit_memory(func: &mut syn::ItemFn, memory_quota: Option<syn::Expr>) {
+        if let Some(quota) = memory_quota {
+            let block = &func.block;
+            func.block = Box::new(syn::parse_quote_spanned! { block.span() =>
+                { ::pgrx::memory_quota::with_memory_quota(#quota, move || #block) }
+            });
+        }
+    }
+
+    /// Generate the planner support function for `fold_constants`, and make it the function's
+    /// `support`
+    fn fold_constants_support(
+        func: &syn::ItemFn,
+        attrs: &mut Vec<Attribute>,
+    ) -> syn::Result<TokenStream2> {
+        if attrs.iter().any(|attr| matches!(attr, Attribute::Support(_))) {
+            return Err(syn::Error::new(
+                func.sig.span(),
+                "`fold_constants` generates a `support` function, so can't be used with another",
+            ));
+        }
+        let ident = &func.sig.ident;
+        let support_ident = Ident::new(&format!("{}_fold_constants", ident), ident.span());
+        let schema = attrs.iter().find_map(|attr| match attr {
+            Attribute::Schema(schema) => Some(schema.clone()),
+            _ => None,
+        });
+        let (support_name, schema) = match schema {
+            Some(schema) => (
+                format!("{}.{}", schema.value(), support_ident),
+                Some(quote! { , schema = #schema }),
+            ),
+            None => (support_ident.to_string(), None),
+        };
+        attrs.push(Attribute::Support(syn::LitStr::new(&support_name, ident.span())));
+
+        let doc = format!(" Folds calls to `{}` with constant arguments into their result", ident);
+        Ok(quote_spanned! { func.sig.span() =>
+            #[doc = #doc]
+            #[::pgrx::pgrx_macros::pg_extern(immutable, parallel_safe #schema)]
+            fn #support_ident(request: ::pgrx::datum::Internal) -> ::pgrx::datum::Internal {
+                unsafe { ::pgrx::planner_support::fold_constants(request) }
+            }
+        })
+    }
+
     fn input_types(func: &syn::ItemFn) -> syn::Result<Vec<syn::Type>> {
         func.sig
             .inputs
@@ -305,7 +397,9 @@ impl PgExtern {
 
         let sql_graph_entity_fn_name =
             syn::Ident::new(&format!("__pgrx_internals_fn_{}", ident), Span::call_site());
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
         quote_spanned! { self.func.sig.span() =>
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
@@ -354,6 +448,43 @@ impl PgExtern {
         }
     }
 
+    /// A plain Rust function, named `{name}_rs`, which calls the annotated function directly, so
+    /// extension code has a stable way to reuse it without going through SQL
+    fn rust_companion_func(&self) -> TokenStream2 {
+        let func_name = &self.func.sig.ident;
+        let mut sig = self.func.sig.clone();
+        sig.ident = Ident::new(&format!("{}_rs", func_name), func_name.span());
+        // the original patterns may be `mut`, which would be unused here, and an argument with the
+        // same name as the function would shadow it
+        let arg_pats = self
+            .inputs
+            .iter()
+            .map(|arg| match arg.pat == *func_name {
+                true => Ident::new(&format!("{}_", arg.pat), arg.pat.span()),
+                false => arg.pat.clone(),
+            })
+            .collect::<Vec<_>>();
+        for (input, pat) in sig.inputs.iter_mut().zip(&arg_pats) {
+            if let syn::FnArg::Typed(pat_type) = input {
+                *pat_type.pat = syn::parse_quote! { #pat };
+            }
+        }
+        let vis = &self.func.vis;
+        let cfg_attrs = self.func.attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+        let doc = format!("Calls [`{func_name}`] directly, without going through SQL");
+
+        quote_spanned! { self.func.sig.span() =>
+            #(#cfg_attrs)*
+            #[doc = #doc]
+            #[inline]
+            #[allow(dead_code, clippy::too_many_arguments)]
+            #vis #sig {
+                #[allow(unused_unsafe)] // unwrapped fn might be unsafe
+                unsafe { #func_name(#(#arg_pats),*) }
+            }
+        }
+    }
+
     pub fn wrapper_func(&self) -> TokenStream2 {
         let func_name = &self.func.sig.ident;
         let func_name_wrapper = Ident::new(
@@ -370,6 +501,9 @@ impl PgExtern {
             .iter()
             .map(|v| syn::Ident::new(&format!("{}_", &v.pat), self.func.sig.span()))
             .collect::<Vec<_>>();
+        // Postgres never calls a function declared `STRICT` with a NULL argument, so its
+        // non-`Option` arguments are read without looking at their null flags
+        let strict = self.extern_attrs().contains(&Attribute::Strict);
         let arg_fetches = args.iter().enumerate().map(|(idx, arg)| {
             let pat = &arg_pats[idx];
             let resolved_ty = &arg.used_ty.resolved_ty;
@@ -390,6 +524,9 @@ impl PgExtern {
                     (true, None) | (true, Some(_)) => quote_spanned! { pat.span() =>
                         let #pat = unsafe { ::pgrx::fcinfo::pg_getarg_datum_raw(#fcinfo_ident, #idx) as #resolved_ty };
                     },
+                    (false, None) if strict => quote_spanned! { pat.span() =>
+                        let #pat = unsafe { ::pgrx::fcinfo::pg_getarg_strict::<#resolved_ty>(#fcinfo_ident, #idx) };
+                    },
                     (false, None) => quote_spanned! { pat.span() =>
                         let #pat = unsafe { ::pgrx::fcinfo::pg_getarg::<#resolved_ty>(#fcinfo_ident, #idx).unwrap_or_else(|| panic!("{} is null", stringify!{#pat})) };
                     },
@@ -458,6 +595,19 @@ impl PgExtern {
                         ::pgrx::datum::IntoDatum::into_datum(#result_ident).unwrap_or_else(|| panic!("returned Datum was NULL"))
                     }
                 };
+                // A polymorphic return type, like `anyelement`, is resolved per call from the
+                // arguments, so what's returned has to be checked to match it.
+                let retval_check = if retval_ty.resolved_ty == syn::parse_quote!(())
+                    || retval_ty.resolved_ty == syn::parse_quote!(pg_sys::Datum)
+                    || retval_ty.resolved_ty == syn::parse_quote!(pgrx::pg_sys::Datum)
+                    || retval_ty.resolved_ty == syn::parse_quote!(::pgrx::pg_sys::Datum)
+                {
+                    quote! {}
+                } else {
+                    quote_spanned! { self.func.sig.output.span() =>
+                        unsafe { ::pgrx::fcinfo::pg_check_return_type(#fcinfo_ident, &#result_ident) };
+                    }
+                };
 
                 quote_spanned! { self.func.sig.span() =>
                     #[no_mangle]
@@ -470,6 +620,7 @@ impl PgExtern {
 
                         #[allow(unused_unsafe)] // unwrapped fn might be unsafe
                         let #result_ident = unsafe { #func_name(#(#arg_pats),*) };
+                        #retval_check
 
                         #retval_transform
                     }
@@ -602,13 +753,17 @@ impl ToEntityGraphTokens for PgExtern {
 impl ToRustCodeTokens for PgExtern {
     fn to_rust_code_tokens(&self) -> TokenStream2 {
         let original_func = &self.func;
+        let rust_companion_func = self.rust_companion_func();
         let wrapper_func = self.wrapper_func();
         let finfo_tokens = self.finfo_tokens();
+        let fold_constants = &self.fold_constants;
 
         quote_spanned! { self.func.sig.span() =>
             #original_func
+            #rust_companion_func
             #wrapper_func
             #finfo_tokens
+            #fold_constants
         }
     }
 }
@@ -625,3 +780,108 @@ impl Parse for CodeEnrichment<PgExtern> {
         PgExtern::new(quote! {#(#attrs)*}, input.parse()?)
     }
 }
+
+/// Reject registrations in `tokens`, a `#[pg_extern]` function's body, that `pgrx::init` only
+/// allows from the library's `#[pg_init]` function.  Postgres calls that while loading the
+/// library, before any SQL can call a `#[pg_extern]` function, so there they'd always panic.
+/// `builds_worker` is whether a `BackgroundWorkerBuilder` was seen earlier in the body.
+///
+/// This only sees what's written in the body itself, not what the functions it calls do.
+fn init_path_only(tokens: TokenStream2, builds_worker: &mut bool) -> syn::Result<()> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    for (i, token) in tokens.iter().enumerate() {
+        let ident = match token {
+            TokenTree::Group(group) => {
+                init_path_only(group.stream(), builds_worker)?;
+                continue;
+            }
+            TokenTree::Ident(ident) => ident,
+            _ => continue,
+        };
+        let name = ident.to_string();
+        let what = match (name.as_str(), tokens.get(i + 1)) {
+            ("pg_shmem_init", Some(TokenTree::Punct(bang))) if bang.as_char() == '!' => {
+                "`pg_shmem_init!()`"
+            }
+            ("BackgroundWorkerBuilder", _) => {
+                *builds_worker = true;
+                continue;
+            }
+            ("load", Some(TokenTree::Group(args)))
+                if *builds_worker
+                    && args.delimiter() == Delimiter::Parenthesis
+                    && args.stream().is_empty()
+                    && matches!(tokens.get(i.wrapping_sub(1)), Some(TokenTree::Punct(dot)) if dot.as_char() == '.') =>
+            {
+                "loading a static background worker"
+            }
+            (define, Some(TokenTree::Group(args)))
+                if define.starts_with("define_")
+                    && define.ends_with("_guc")
+                    && args
+                        .stream()
+                        .into_iter()
+                        .any(|arg| matches!(arg, TokenTree::Ident(arg) if arg == "Postmaster")) =>
+            {
+                "defining a `Postmaster` GUC"
+            }
+            _ => continue,
+        };
+        return Err(syn::Error::new(
+            ident.span(),
+            format!(
+                "{what} can only be done from the library's `#[pg_init]` function, \
+                 which has returned before a `#[pg_extern]` function can be called"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PgExtern;
+    use quote::quote;
+
+    fn init_path_error(body: proc_macro2::TokenStream) -> Option<String> {
+        let func = quote! { fn example() #body };
+        PgExtern::new(quote! {}, func).err().map(|e| e.to_string())
+    }
+
+    #[test]
+    fn rejects_shmem_init() {
+        let error = init_path_error(quote! {{ pg_shmem_init!(COUNTER); }});
+        assert!(error.unwrap().starts_with("`pg_shmem_init!()` can only be done"));
+    }
+
+    #[test]
+    fn rejects_loading_static_worker() {
+        let error = init_path_error(quote! {{
+            let worker = BackgroundWorkerBuilder::new("late").set_function("bgworker");
+            if true {
+                worker.load();
+            }
+        }});
+        assert!(error.unwrap().starts_with("loading a static background worker can only be done"));
+    }
+
+    #[test]
+    fn rejects_postmaster_guc() {
+        let error = init_path_error(quote! {{
+            GucRegistry::define_bool_guc("x.y", "", "", &GUC, GucContext::Postmaster, GucFlags::default());
+        }});
+        assert!(error.unwrap().starts_with("defining a `Postmaster` GUC can only be done"));
+    }
+
+    #[test]
+    fn allows_runtime_registrations() {
+        assert_eq!(
+            init_path_error(quote! {{
+                GucRegistry::define_bool_guc("x.y", "", "", &GUC, GucContext::Userset, GucFlags::default());
+                BackgroundWorkerBuilder::new("dynamic").load_dynamic();
+                FLAG.load(Ordering::SeqCst);
+            }}),
+            None
+        );
+    }
+}
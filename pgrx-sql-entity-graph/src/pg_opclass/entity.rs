@@ -0,0 +1,96 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+/*!
+
+`#[pg_opclass]` related entities for Rust to SQL translation
+
+> Like all of the [`sql_entity_graph`][crate::pgrx_sql_entity_graph] APIs, this is considered **internal**
+to the `pgrx` framework and very subject to change between versions. While you may use this, please do it with caution.
+
+*/
+use crate::pgrx_sql::PgrxSql;
+use crate::to_sql::entity::ToSqlConfigEntity;
+use crate::to_sql::ToSql;
+use crate::{SqlGraphEntity, SqlGraphIdentifier};
+
+/// The output of a [`PgOpClass`](crate::pg_opclass::PgOpClass) from `quote::ToTokens::to_tokens`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
+pub struct PgOpClassEntity {
+    pub name: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub full_path: &'static str,
+    pub module_path: &'static str,
+    pub id: core::any::TypeId,
+    pub access_method: &'static str,
+    /// The operators, as `(strategy number, operator)`
+    pub operators: Vec<(u16, &'static str)>,
+    /// The support functions, as `(support number, function name, SQL argument types)`
+    pub functions: Vec<(u16, &'static str, &'static str)>,
+    pub to_sql_config: ToSqlConfigEntity,
+}
+
+impl PgOpClassEntity {
+    pub(crate) fn opclass_name(&self) -> String {
+        format!("{}_{}_ops", self.name, self.access_method)
+    }
+}
+
+impl From<PgOpClassEntity> for SqlGraphEntity {
+    fn from(val: PgOpClassEntity) -> Self {
+        SqlGraphEntity::OpClass(val)
+    }
+}
+
+impl SqlGraphIdentifier for PgOpClassEntity {
+    fn dot_identifier(&self) -> String {
+        format!("opclass {} {}", self.access_method, self.full_path)
+    }
+    fn rust_identifier(&self) -> String {
+        format!("{} ({})", self.full_path, self.access_method)
+    }
+
+    fn file(&self) -> Option<&'static str> {
+        Some(self.file)
+    }
+
+    fn line(&self) -> Option<u32> {
+        Some(self.line)
+    }
+}
+
+impl ToSql for PgOpClassEntity {
+    fn to_sql(&self, _context: &PgrxSql) -> eyre::Result<String> {
+        let members = self
+            .operators
+            .iter()
+            .map(|(strategy, operator)| format!("\tOPERATOR    {strategy}   {operator}"))
+            .chain(self.functions.iter().map(|(support, function, args)| {
+                format!("\tFUNCTION    {support}   {function}({args})")
+            }))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let sql = format!(
+            "\n\
+            -- {file}:{line}\n\
+            -- {full_path}\n\
+            CREATE OPERATOR CLASS {opclass_name} DEFAULT FOR TYPE {name} USING {access_method} AS\n\
+            {members};\
+            ",
+            file = self.file,
+            line = self.line,
+            full_path = self.full_path,
+            opclass_name = self.opclass_name(),
+            name = self.name,
+            access_method = self.access_method,
+        );
+        Ok(sql)
+    }
+}
@@ -0,0 +1,260 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+/*!
+
+`#[pg_opclass]` related macro expansion for Rust to SQL translation
+
+> Like all of the [`sql_entity_graph`][crate::pgrx_sql_entity_graph] APIs, this is considered **internal**
+to the `pgrx` framework and very subject to change between versions. While you may use this, please do it with caution.
+
+*/
+pub mod entity;
+
+use crate::enrich::{CodeEnrichment, ToEntityGraphTokens, ToRustCodeTokens};
+use crate::ToSqlConfig;
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{ItemFn, ItemImpl, Path};
+
+/// The index access methods whose operator classes can be implemented with `#[pg_opclass]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpClassAccessMethod {
+    SpGist,
+    Brin,
+}
+
+impl OpClassAccessMethod {
+    /// The access method for an implementation of the named trait
+    pub fn from_trait_name(name: &str) -> Option<Self> {
+        match name {
+            "SpGistOpClass" => Some(OpClassAccessMethod::SpGist),
+            "BrinOpClass" => Some(OpClassAccessMethod::Brin),
+            _ => None,
+        }
+    }
+
+    /// The access method's name in SQL
+    pub fn sql(&self) -> &'static str {
+        match self {
+            OpClassAccessMethod::SpGist => "spgist",
+            OpClassAccessMethod::Brin => "brin",
+        }
+    }
+
+    /// The `pgrx` module implementing the access method's support functions
+    fn module(&self) -> TokenStream2 {
+        match self {
+            OpClassAccessMethod::SpGist => quote! { ::pgrx::spgist },
+            OpClassAccessMethod::Brin => quote! { ::pgrx::brin },
+        }
+    }
+
+    /// The support functions, as `(support number, name, number of internal arguments, returns)`
+    fn support_functions(&self) -> &'static [(u16, &'static str, usize, SupportReturns)] {
+        match self {
+            OpClassAccessMethod::SpGist => &[
+                (1, "config", 2, SupportReturns::Void),
+                (2, "choose", 2, SupportReturns::Void),
+                (3, "picksplit", 2, SupportReturns::Void),
+                (4, "inner_consistent", 2, SupportReturns::Void),
+                (5, "leaf_consistent", 2, SupportReturns::Bool),
+            ],
+            OpClassAccessMethod::Brin => &[
+                (1, "opcinfo", 1, SupportReturns::Internal),
+                (2, "add_value", 4, SupportReturns::Bool),
+                (3, "consistent", 3, SupportReturns::Bool),
+                (4, "union", 3, SupportReturns::Bool),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SupportReturns {
+    Void,
+    Bool,
+    Internal,
+}
+
+/// A parsed `#[pg_opclass]` item.
+///
+/// It should be used with [`syn::parse::Parse`] functions.
+///
+/// Using [`quote::ToTokens`] will output the declaration for a `pgrx::pgrx_sql_entity_graph::PgOpClassEntity`.
+///
+/// ```rust
+/// use syn::{Macro, parse::Parse, parse_quote, parse};
+/// use quote::{quote, ToTokens};
+/// use pgrx_sql_entity_graph::PgOpClass;
+///
+/// # fn main() -> eyre::Result<()> {
+/// use pgrx_sql_entity_graph::CodeEnrichment;
+/// let parsed: CodeEnrichment<PgOpClass> = parse_quote! {
+///     impl BrinOpClass for Example {
+///         const OPERATORS: &'static [(u16, &'static str)] = &[(3, "=")];
+///     }
+/// };
+/// let sql_graph_entity_tokens = parsed.to_token_stream();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PgOpClass {
+    item_impl: ItemImpl,
+    trait_path: Path,
+    target_ident: Ident,
+    access_method: OpClassAccessMethod,
+    support_functions: Vec<ItemFn>,
+    to_sql_config: ToSqlConfig,
+}
+
+impl PgOpClass {
+    pub fn new(item_impl: ItemImpl) -> Result<CodeEnrichment<Self>, syn::Error> {
+        let to_sql_config =
+            ToSqlConfig::from_attributes(item_impl.attrs.as_slice())?.unwrap_or_default();
+
+        let trait_path = match &item_impl.trait_ {
+            Some((_, path, _)) => path.clone(),
+            None => {
+                return Err(syn::Error::new(
+                    item_impl.span(),
+                    "`#[pg_opclass]` only works on implementations of `SpGistOpClass` or `BrinOpClass`.",
+                ))
+            }
+        };
+        let access_method = trait_path
+            .segments
+            .last()
+            .and_then(|last| OpClassAccessMethod::from_trait_name(&last.ident.to_string()))
+            .ok_or_else(|| {
+                syn::Error::new(
+                    trait_path.span(),
+                    "`#[pg_opclass]` only works on implementations of `SpGistOpClass` or `BrinOpClass`.",
+                )
+            })?;
+
+        let target_ident = match &*item_impl.self_ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                type_path.path.segments.last().map(|segment| segment.ident.clone())
+            }
+            _ => None,
+        }
+        .ok_or_else(|| {
+            syn::Error::new(
+                item_impl.self_ty.span(),
+                "`#[pg_opclass]` only works with types whose path have a final segment.",
+            )
+        })?;
+        let snake_case_target_ident = target_ident.to_string().to_case(Case::Snake);
+
+        let module = access_method.module();
+        let self_ty = &item_impl.self_ty;
+        let mut support_functions = Vec::new();
+        for (_, function, nargs, returns) in access_method.support_functions() {
+            let fn_name = Ident::new(
+                &format!("{snake_case_target_ident}_{}_{function}", access_method.sql()),
+                target_ident.span(),
+            );
+            crate::ident_is_acceptable_to_postgres(&fn_name)?;
+            let support_fn = Ident::new(function, Span::call_site());
+            let args = (0..*nargs)
+                .map(|i| Ident::new(&format!("arg{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let returns = match returns {
+                SupportReturns::Void => quote! {},
+                SupportReturns::Bool => quote! { -> bool },
+                SupportReturns::Internal => quote! { -> ::pgrx::datum::Internal },
+            };
+            support_functions.push(syn::parse_quote! {
+                #[doc(hidden)]
+                #[::pgrx::pg_extern(immutable, parallel_safe)]
+                fn #fn_name(#(#args: ::pgrx::datum::Internal),*) #returns {
+                    unsafe { #module::#support_fn::<#self_ty>(#(#args),*) }
+                }
+            });
+        }
+
+        Ok(CodeEnrichment(Self {
+            item_impl,
+            trait_path,
+            target_ident,
+            access_method,
+            support_functions,
+            to_sql_config,
+        }))
+    }
+}
+
+impl ToEntityGraphTokens for PgOpClass {
+    fn to_entity_graph_tokens(&self) -> TokenStream2 {
+        let target_ident = &self.target_ident;
+        let self_ty = &self.item_impl.self_ty;
+        let trait_path = &self.trait_path;
+        let access_method = self.access_method.sql();
+        let snake_case_target_ident = target_ident.to_string().to_case(Case::Snake);
+        let sql_graph_entity_fn_name = syn::Ident::new(
+            &format!("__pgrx_internals_opclass_{snake_case_target_ident}_{access_method}"),
+            target_ident.span(),
+        );
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
+        let support_numbers = self.access_method.support_functions().iter().map(|(n, ..)| n);
+        let support_fn_names = self.support_functions.iter().map(|f| &f.sig.ident);
+        let support_fn_args = self
+            .access_method
+            .support_functions()
+            .iter()
+            .map(|(_, _, nargs, _)| vec!["internal"; *nargs].join(", "));
+        let to_sql_config = &self.to_sql_config;
+        quote! {
+            #section_entry
+            #[no_mangle]
+            #[doc(hidden)]
+            #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
+            pub extern "Rust" fn #sql_graph_entity_fn_name() -> ::pgrx::pgrx_sql_entity_graph::SqlGraphEntity {
+                extern crate alloc;
+                use alloc::vec::Vec;
+                use alloc::vec;
+                let submission = ::pgrx::pgrx_sql_entity_graph::PgOpClassEntity {
+                    name: stringify!(#target_ident),
+                    file: file!(),
+                    line: line!(),
+                    full_path: ::core::any::type_name::<#self_ty>(),
+                    module_path: module_path!(),
+                    id: ::core::any::TypeId::of::<#self_ty>(),
+                    access_method: #access_method,
+                    operators: <#self_ty as #trait_path>::OPERATORS.to_vec(),
+                    functions: vec![#((#support_numbers, stringify!(#support_fn_names), #support_fn_args)),*],
+                    to_sql_config: #to_sql_config,
+                };
+                ::pgrx::pgrx_sql_entity_graph::SqlGraphEntity::OpClass(submission)
+            }
+        }
+    }
+}
+
+impl ToRustCodeTokens for PgOpClass {
+    fn to_rust_code_tokens(&self) -> TokenStream2 {
+        let item_impl = &self.item_impl;
+        let support_functions = &self.support_functions;
+        quote! {
+            #item_impl
+            #(#support_functions)*
+        }
+    }
+}
+
+impl Parse for CodeEnrichment<PgOpClass> {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        PgOpClass::new(input.parse()?)
+    }
+}
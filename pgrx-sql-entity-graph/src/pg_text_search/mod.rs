@@ -0,0 +1,255 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+/*!
+
+`#[pg_text_search]` related macro expansion for Rust to SQL translation
+
+> Like all of the [`sql_entity_graph`][crate::pgrx_sql_entity_graph] APIs, this is considered **internal**
+to the `pgrx` framework and very subject to change between versions. While you may use this, please do it with caution.
+
+*/
+use crate::enrich::{CodeEnrichment, ToEntityGraphTokens, ToRustCodeTokens};
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{ItemFn, ItemImpl, Lit, MetaNameValue, Token};
+
+/// The kinds of text search objects that can be implemented with `#[pg_text_search]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextSearchKind {
+    Dictionary,
+    Parser,
+}
+
+impl TextSearchKind {
+    /// The kind of object an implementation of the named trait creates
+    pub fn from_trait_name(name: &str) -> Option<Self> {
+        match name {
+            "TextSearchDictionary" => Some(TextSearchKind::Dictionary),
+            "TextSearchParser" => Some(TextSearchKind::Parser),
+            _ => None,
+        }
+    }
+
+    /// The support functions, as `(name, number of internal arguments, return type)`
+    fn support_functions(&self) -> &'static [(&'static str, usize, &'static str)] {
+        match self {
+            TextSearchKind::Dictionary => &[("init", 1, "internal"), ("lexize", 4, "internal")],
+            TextSearchKind::Parser => &[
+                ("start", 2, "internal"),
+                ("gettoken", 3, "integer"),
+                ("end", 1, "void"),
+                ("lextypes", 1, "internal"),
+            ],
+        }
+    }
+}
+
+/// A parsed `#[pg_text_search]` item.
+///
+/// It should be used with [`syn::parse::Parse`] functions.
+///
+/// Using [`quote::ToTokens`] will output the declaration for a `pgrx::pgrx_sql_entity_graph::ExtensionSqlEntity`
+/// creating the text search template and dictionary, or parser.
+///
+/// ```rust
+/// use syn::{Macro, parse::Parse, parse_quote, parse};
+/// use quote::{quote, ToTokens};
+/// use pgrx_sql_entity_graph::PgTextSearch;
+///
+/// # fn main() -> eyre::Result<()> {
+/// let parsed = PgTextSearch::new(
+///     quote! { name = "upper", options = "Accent = true" },
+///     parse_quote! {
+///         impl TextSearchDictionary for Upper {
+///             fn init(options: Vec<(String, String)>) -> Self { Upper }
+///             fn lexize(&self, token: &str) -> Option<Vec<Lexeme>> { None }
+///         }
+///     },
+/// )?;
+/// let sql_graph_entity_tokens = parsed.to_token_stream();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PgTextSearch {
+    item_impl: ItemImpl,
+    kind: TextSearchKind,
+    name: String,
+    options: Option<String>,
+    support_functions: Vec<ItemFn>,
+}
+
+impl PgTextSearch {
+    pub fn new(
+        attr: TokenStream2,
+        item_impl: ItemImpl,
+    ) -> Result<CodeEnrichment<Self>, syn::Error> {
+        let kind = match &item_impl.trait_ {
+            Some((_, path, _)) => path
+                .segments
+                .last()
+                .and_then(|last| TextSearchKind::from_trait_name(&last.ident.to_string())),
+            None => None,
+        }
+        .ok_or_else(|| {
+            syn::Error::new(
+                item_impl.span(),
+                "`#[pg_text_search]` only works on implementations of `TextSearchDictionary` or `TextSearchParser`.",
+            )
+        })?;
+
+        let mut name = None;
+        let mut options = None;
+        let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(attr)?;
+        for arg in args {
+            let value = match &arg.lit {
+                Lit::Str(value) => value.value(),
+                other => return Err(syn::Error::new(other.span(), "expected a string")),
+            };
+            if arg.path.is_ident("name") {
+                name = Some(value);
+            } else if arg.path.is_ident("options") && kind == TextSearchKind::Dictionary {
+                options = Some(value);
+            } else {
+                return Err(syn::Error::new(
+                    arg.path.span(),
+                    "unknown `#[pg_text_search]` argument",
+                ));
+            }
+        }
+
+        let name = match name {
+            Some(name) => name,
+            None => match &*item_impl.self_ty {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident.to_string().to_case(Case::Snake)),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                syn::Error::new(
+                    item_impl.self_ty.span(),
+                    "`#[pg_text_search]` needs a `name` for types whose path doesn't have a final segment.",
+                )
+            })?,
+        };
+
+        let self_ty = &item_impl.self_ty;
+        let mut support_functions = Vec::new();
+        for (function, nargs, returns) in kind.support_functions() {
+            let fn_name: Ident =
+                syn::parse_str(&format!("{name}_ts_{function}")).map_err(|_| {
+                    syn::Error::new(
+                        Span::call_site(),
+                        format!("`{name}` is not a valid identifier"),
+                    )
+                })?;
+            crate::ident_is_acceptable_to_postgres(&fn_name)?;
+            let support_fn = Ident::new(function, Span::call_site());
+            let args = (0..*nargs)
+                .map(|i| Ident::new(&format!("arg{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let returns = match *returns {
+                "internal" => quote! { -> ::pgrx::datum::Internal },
+                "integer" => quote! { -> i32 },
+                _ => quote! {},
+            };
+            support_functions.push(syn::parse_quote! {
+                #[doc(hidden)]
+                #[::pgrx::pg_extern]
+                fn #fn_name(#(#args: ::pgrx::datum::Internal),*) #returns {
+                    unsafe { ::pgrx::text_search::#support_fn::<#self_ty>(#(#args),*) }
+                }
+            });
+        }
+
+        Ok(CodeEnrichment(Self { item_impl, kind, name, options, support_functions }))
+    }
+
+    /// The SQL creating the text search objects
+    pub fn sql(&self) -> String {
+        let name = &self.name;
+        match self.kind {
+            TextSearchKind::Dictionary => format!(
+                "CREATE TEXT SEARCH TEMPLATE {name} (INIT = {name}_ts_init, LEXIZE = {name}_ts_lexize);\n\
+                 CREATE TEXT SEARCH DICTIONARY {name} (TEMPLATE = {name}{options});",
+                options = self.options.as_ref().map(|o| format!(", {o}")).unwrap_or_default(),
+            ),
+            TextSearchKind::Parser => format!(
+                "CREATE TEXT SEARCH PARSER {name} (\n\
+                 \tSTART = {name}_ts_start,\n\
+                 \tGETTOKEN = {name}_ts_gettoken,\n\
+                 \tEND = {name}_ts_end,\n\
+                 \tLEXTYPES = {name}_ts_lextypes\n\
+                 );",
+            ),
+        }
+    }
+}
+
+impl ToEntityGraphTokens for PgTextSearch {
+    fn to_entity_graph_tokens(&self) -> TokenStream2 {
+        let kind = match self.kind {
+            TextSearchKind::Dictionary => "dictionary",
+            TextSearchKind::Parser => "parser",
+        };
+        let name = format!("{}_text_search_{kind}", self.name);
+        let sql_graph_entity_fn_name =
+            syn::Ident::new(&format!("__pgrx_internals_sql_{name}"), Span::call_site());
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
+        let sql = self.sql();
+        let requires = self.support_functions.iter().map(|f| &f.sig.ident);
+        quote! {
+            #section_entry
+            #[no_mangle]
+            #[doc(hidden)]
+            #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
+            pub extern "Rust" fn #sql_graph_entity_fn_name() -> ::pgrx::pgrx_sql_entity_graph::SqlGraphEntity {
+                extern crate alloc;
+                use alloc::vec::Vec;
+                use alloc::vec;
+                let submission = ::pgrx::pgrx_sql_entity_graph::ExtensionSqlEntity {
+                    sql: #sql,
+                    module_path: module_path!(),
+                    full_path: concat!(file!(), ':', line!()),
+                    file: file!(),
+                    line: line!(),
+                    name: #name,
+                    bootstrap: false,
+                    finalize: false,
+                    requires: vec![#(
+                        ::pgrx::pgrx_sql_entity_graph::PositioningRef::FullPath(
+                            String::from(concat!(module_path!(), "::", stringify!(#requires)))
+                        )
+                    ),*],
+                    creates: vec![],
+                };
+                ::pgrx::pgrx_sql_entity_graph::SqlGraphEntity::CustomSql(submission)
+            }
+        }
+    }
+}
+
+impl ToRustCodeTokens for PgTextSearch {
+    fn to_rust_code_tokens(&self) -> TokenStream2 {
+        let item_impl = &self.item_impl;
+        let support_functions = &self.support_functions;
+        quote! {
+            #item_impl
+            #(#support_functions)*
+        }
+    }
+}
@@ -125,11 +125,13 @@ impl ToEntityGraphTokens for PgTrigger {
             &format!("__pgrx_internals_trigger_{}", self.func.sig.ident.to_string()),
             self.func.sig.ident.span(),
         );
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
         let func_sig_ident = &self.func.sig.ident;
         let function_name = func_sig_ident.to_string();
         let to_sql_config = &self.to_sql_config;
 
         quote! {
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
@@ -30,6 +30,7 @@ use crate::control_file::ControlFile;
 use crate::extension_sql::entity::{ExtensionSqlEntity, SqlDeclaredEntity};
 use crate::extension_sql::SqlDeclared;
 use crate::pg_extern::entity::PgExternEntity;
+use crate::pg_opclass::entity::PgOpClassEntity;
 use crate::pg_trigger::entity::PgTriggerEntity;
 use crate::positioning_ref::PositioningRef;
 use crate::postgres_enum::entity::PostgresEnumEntity;
@@ -38,7 +39,7 @@ use crate::postgres_ord::entity::PostgresOrdEntity;
 use crate::postgres_type::entity::PostgresTypeEntity;
 use crate::schema::entity::SchemaEntity;
 use crate::to_sql::ToSql;
-use crate::{SqlGraphEntity, SqlGraphIdentifier};
+use crate::{SqlGraphEntity, SqlGraphIdentifier, UsedTypeEntity};
 
 use super::{PgExternReturnEntity, PgExternReturnEntityIteratedItem};
 
@@ -75,12 +76,20 @@ pub struct PgrxSql {
     pub enums: HashMap<PostgresEnumEntity, NodeIndex>,
     pub ords: HashMap<PostgresOrdEntity, NodeIndex>,
     pub hashes: HashMap<PostgresHashEntity, NodeIndex>,
+    pub opclasses: HashMap<PgOpClassEntity, NodeIndex>,
     pub aggregates: HashMap<PgAggregateEntity, NodeIndex>,
     pub triggers: HashMap<PgTriggerEntity, NodeIndex>,
     pub extension_name: String,
     pub versioned_so: bool,
+    /// Whether references to the extension's own objects are qualified with `@extschema@`, so
+    /// they resolve no matter the `search_path`.  Only possible for non-relocatable extensions.
+    pub qualify_with_extschema: bool,
 }
 
+/// The placeholder Postgres replaces with the (quoted) name of a non-relocatable extension's
+/// schema while running its scripts.
+pub const EXTSCHEMA: &str = "@extschema@";
+
 impl PgrxSql {
     pub fn build(
         entities: impl Iterator<Item = SqlGraphEntity>,
@@ -100,6 +109,7 @@ impl PgrxSql {
         let mut enums: Vec<PostgresEnumEntity> = Vec::default();
         let mut ords: Vec<PostgresOrdEntity> = Vec::default();
         let mut hashes: Vec<PostgresHashEntity> = Vec::default();
+        let mut opclasses: Vec<PgOpClassEntity> = Vec::default();
         let mut aggregates: Vec<PgAggregateEntity> = Vec::default();
         let mut triggers: Vec<PgTriggerEntity> = Vec::default();
         for entity in entities {
@@ -129,6 +139,9 @@ impl PgrxSql {
                 SqlGraphEntity::Hash(input_hash) => {
                     hashes.push(input_hash);
                 }
+                SqlGraphEntity::OpClass(input_opclass) => {
+                    opclasses.push(input_opclass);
+                }
                 SqlGraphEntity::Aggregate(input_aggregate) => {
                     aggregates.push(input_aggregate);
                 }
@@ -165,6 +178,8 @@ impl PgrxSql {
         )?;
         let mapped_ords = initialize_ords(&mut graph, root, bootstrap, finalize, ords)?;
         let mapped_hashes = initialize_hashes(&mut graph, root, bootstrap, finalize, hashes)?;
+        let mapped_opclasses =
+            initialize_opclasses(&mut graph, root, bootstrap, finalize, opclasses)?;
         let mapped_aggregates = initialize_aggregates(
             &mut graph,
             root,
@@ -201,6 +216,13 @@ impl PgrxSql {
             &mapped_extension_sqls,
             &mapped_triggers,
         )?;
+        connect_operators(
+            &mut graph,
+            &mapped_externs,
+            &mapped_types,
+            &mapped_enums,
+            &mapped_extension_sqls,
+        )?;
         connect_ords(
             &mut graph,
             &mapped_ords,
@@ -216,6 +238,17 @@ impl PgrxSql {
             &mapped_types,
             &mapped_enums,
             &mapped_externs,
+            &mapped_ords,
+        );
+        connect_opclasses(
+            &mut graph,
+            &mapped_opclasses,
+            &mapped_schemas,
+            &mapped_types,
+            &mapped_enums,
+            &mapped_externs,
+            &mapped_ords,
+            &mapped_hashes,
         );
         connect_aggregates(
             &mut graph,
@@ -238,6 +271,7 @@ impl PgrxSql {
             enums: mapped_enums,
             ords: mapped_ords,
             hashes: mapped_hashes,
+            opclasses: mapped_opclasses,
             aggregates: mapped_aggregates,
             triggers: mapped_triggers,
             graph: graph,
@@ -246,6 +280,7 @@ impl PgrxSql {
             graph_finalize: finalize,
             extension_name: extension_name,
             versioned_so,
+            qualify_with_extschema: false,
         };
         Ok(this)
     }
@@ -363,6 +398,10 @@ impl PgrxSql {
                         "label = \"{}\", penwidth = 0, style = \"filled\", fillcolor = \"#FFE4E0\", weight = 5, shape = \"diamond\"",
                         node.dot_identifier()
                     ),
+                    SqlGraphEntity::OpClass(_item) => format!(
+                        "label = \"{}\", penwidth = 0, style = \"filled\", fillcolor = \"#FFE4E0\", weight = 5, shape = \"diamond\"",
+                        node.dot_identifier()
+                    ),
                     SqlGraphEntity::Aggregate(_item) => format!(
                         "label = \"{}\", penwidth = 0, style = \"filled\", fillcolor = \"#FFE4E0\", weight = 5, shape = \"diamond\"",
                         node.dot_identifier()
@@ -394,20 +433,25 @@ impl PgrxSql {
     }
 
     pub fn schema_alias_of(&self, item_index: &NodeIndex) -> Option<String> {
-        self.graph
-            .neighbors_undirected(*item_index)
-            .flat_map(|neighbor_index| match &self.graph[neighbor_index] {
+        // an item in a `#[pg_schema]` module is also connected to the root, so the module wins
+        let mut neighbors = self.graph.neighbors_undirected(*item_index);
+        if let Some(schema) =
+            neighbors.clone().find_map(|neighbor_index| match &self.graph[neighbor_index] {
                 SqlGraphEntity::Schema(s) => Some(String::from(s.name)),
-                SqlGraphEntity::ExtensionRoot(control) => {
-                    if !control.relocatable {
-                        control.schema.clone()
-                    } else {
-                        Some(String::from("@extname@"))
-                    }
-                }
                 _ => None,
             })
-            .next()
+        {
+            return Some(schema);
+        }
+        neighbors.find_map(|neighbor_index| match &self.graph[neighbor_index] {
+            // a relocatable extension's objects go wherever `CREATE EXTENSION` puts them
+            SqlGraphEntity::ExtensionRoot(control) if control.relocatable => None,
+            SqlGraphEntity::ExtensionRoot(_) if self.qualify_with_extschema => {
+                Some(String::from(EXTSCHEMA))
+            }
+            SqlGraphEntity::ExtensionRoot(control) => control.schema.clone(),
+            _ => None,
+        })
     }
 
     pub fn schema_prefix_for(&self, target: &NodeIndex) -> String {
@@ -452,6 +496,65 @@ impl PgrxSql {
         None
     }
 
+    /// Checks that a relocatable extension's SQL can be moved to another schema: it mustn't name
+    /// schemas for its objects, and it can't use `@extschema@`, which Postgres only substitutes
+    /// for extensions that aren't relocatable.
+    ///
+    /// Otherwise the problem would only surface once somebody ran `CREATE EXTENSION`.
+    pub fn check_relocatable(&self) -> eyre::Result<()> {
+        if !self.control.relocatable {
+            return Ok(());
+        }
+
+        let mut problems = Vec::new();
+        if let Some(schema) = &self.control.schema {
+            problems.push(format!("the control file sets `schema = '{schema}'`"));
+        }
+        if self.qualify_with_extschema {
+            problems.push(String::from(
+                "`package.metadata.pgrx.qualify-with-extschema` qualifies its objects with `@extschema@`",
+            ));
+        }
+        for schema in self.schemas.keys() {
+            problems.push(format!(
+                "{}:{}: `#[pg_schema] mod {}` creates its objects in the schema `{}`",
+                schema.file, schema.line, schema.name, schema.name
+            ));
+        }
+        for function in self.externs.keys() {
+            if let Some(schema) = function.schema {
+                problems.push(format!(
+                    "{}:{}: `{}` is created in the schema `{schema}`",
+                    function.file, function.line, function.full_path
+                ));
+            }
+            if function.search_path.iter().flatten().any(|path| path.contains(EXTSCHEMA)) {
+                problems.push(format!(
+                    "{}:{}: `{}` has `@extschema@` in its `#[search_path]`",
+                    function.file, function.line, function.full_path
+                ));
+            }
+        }
+        for sql in self.extension_sqls.keys() {
+            if sql.sql.contains(EXTSCHEMA) {
+                problems
+                    .push(format!("{}:{}: `{}` uses `@extschema@`", sql.file, sql.line, sql.name));
+            }
+        }
+        if problems.is_empty() && self.to_sql()?.contains(EXTSCHEMA) {
+            problems.push(String::from("the generated SQL uses `@extschema@`"));
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+        problems.sort();
+        Err(eyre!(
+            "The extension is `relocatable`, but its SQL depends on the schema it's created in.  Set `relocatable = false` in the .control file, or:\n{}",
+            problems.iter().map(|problem| format!("  - {problem}")).collect::<Vec<_>>().join("\n")
+        ))
+    }
+
     pub fn get_module_pathname(&self) -> String {
         return if self.versioned_so {
             let extname = &self.extension_name;
@@ -897,6 +1000,15 @@ fn connect_externs(
             );
         }
 
+        // Support functions and selectivity estimators must be defined before the functions and
+        // operators that use them.
+        let referenced_function_names = item.referenced_function_names();
+        for (other_item, &other_index) in externs {
+            if other_index != index && referenced_function_names.contains(&other_item.name) {
+                graph.add_edge(other_index, index, SqlGraphRelationship::RequiredBy);
+            }
+        }
+
         // The hash function must be defined after the {typename}_eq function.
         for (hash_item, &hash_index) in hashes {
             if item.module_path == hash_item.module_path
@@ -1067,6 +1179,110 @@ fn initialize_ords(
     Ok(mapped_ords)
 }
 
+/// Make sure the commutator and negator of every `#[pg_operator]` is declared, and create them
+/// before the operators that refer to them where that's possible
+///
+/// Postgres quietly creates a "shell" for an operator named before it's defined, which is what
+/// lets a pair of operators name each other as commutators.  But a shell that's never defined,
+/// such as because of a typo or mismatched operand types, isn't noticed until the operator is
+/// used.
+fn connect_operators(
+    graph: &mut StableGraph<SqlGraphEntity, SqlGraphRelationship>,
+    externs: &HashMap<PgExternEntity, NodeIndex>,
+    types: &HashMap<PostgresTypeEntity, NodeIndex>,
+    enums: &HashMap<PostgresEnumEntity, NodeIndex>,
+    extension_sqls: &HashMap<ExtensionSqlEntity, NodeIndex>,
+) -> eyre::Result<()> {
+    let is_extension_type = |ty: &UsedTypeEntity| {
+        types.keys().any(|ty_item| ty_item.id_matches(&ty.ty_id))
+            || enums.keys().any(|enum_item| enum_item.id_matches(&ty.ty_id))
+    };
+    let declares = |extern_item: &PgExternEntity, opname: &str, left, right| {
+        extern_item.operator.as_ref().and_then(|op| op.opname) == Some(opname)
+            && extern_item
+                .operand_types()
+                .map_or(false, |(l, r)| same_sql_type(l, left) && same_sql_type(r, right))
+    };
+    let refers_to = |extern_item: &PgExternEntity, opname: &str| {
+        extern_item.operator.as_ref().map_or(false, |op| {
+            op.commutator.map(operator_name) == Some(opname)
+                || op.negator.map(operator_name) == Some(opname)
+        })
+    };
+
+    for (item, &index) in externs {
+        let (Some(op), Some((left, right))) = (&item.operator, item.operand_types()) else {
+            continue;
+        };
+        let Some(opname) = op.opname else { continue };
+
+        let references =
+            [("commutator", op.commutator, (right, left)), ("negator", op.negator, (left, right))];
+        for (kind, referenced, (expected_left, expected_right)) in references {
+            let Some(referenced) = referenced.map(operator_name) else { continue };
+
+            let declared_by = externs.iter().find(|(other_item, _)| {
+                declares(other_item, referenced, expected_left, expected_right)
+            });
+            match declared_by {
+                Some((other_item, &other_index)) => {
+                    // operators that refer to each other rely on Postgres' shell operators
+                    if other_index != index && !refers_to(other_item, opname) {
+                        graph.add_edge(other_index, index, SqlGraphRelationship::RequiredBy);
+                    }
+                }
+                None => {
+                    // operators over built-in types may refer to built-in operators, which we
+                    // can't see, as may hand-written SQL
+                    let in_extension_sql = extension_sqls.keys().any(|sql_item| {
+                        sql_item.sql.contains("CREATE OPERATOR")
+                            && sql_item.sql.contains(referenced)
+                    });
+                    if (is_extension_type(expected_left) || is_extension_type(expected_right))
+                        && !in_extension_sql
+                    {
+                        return Err(eyre!(
+                            "`{}` names operator `{}` ({}, {}) as its {}, but no `#[pg_operator]` declares it",
+                            item.full_path,
+                            referenced,
+                            expected_left.full_path,
+                            expected_right.full_path,
+                            kind,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The bare name of an operator, which may be written as `OPERATOR(schema.name)`
+fn operator_name(name: &str) -> &str {
+    match name.strip_prefix("OPERATOR(").and_then(|name| name.strip_suffix(')')) {
+        Some(qualified) => qualified.rsplit('.').next().unwrap(),
+        None => name,
+    }
+}
+
+/// Do two Rust types map to the same SQL type?
+fn same_sql_type(left: &UsedTypeEntity, right: &UsedTypeEntity) -> bool {
+    use crate::metadata::SqlMapping;
+
+    match (&left.metadata.argument_sql, &right.metadata.argument_sql) {
+        (Ok(SqlMapping::Composite { .. }), Ok(SqlMapping::Composite { .. })) => {
+            left.metadata.argument_sql == right.metadata.argument_sql
+                && left.composite_type == right.composite_type
+        }
+        (Ok(SqlMapping::Source { .. }), Ok(SqlMapping::Source { .. })) => {
+            left.metadata.argument_sql == right.metadata.argument_sql
+                && left.ty_source == right.ty_source
+        }
+        (Ok(l), Ok(r)) => l == r,
+        _ => left.ty_id == right.ty_id,
+    }
+}
+
 fn connect_ords(
     graph: &mut StableGraph<SqlGraphEntity, SqlGraphRelationship>,
     ords: &HashMap<PostgresOrdEntity, NodeIndex>,
@@ -1148,6 +1364,7 @@ fn connect_hashes(
     types: &HashMap<PostgresTypeEntity, NodeIndex>,
     enums: &HashMap<PostgresEnumEntity, NodeIndex>,
     externs: &HashMap<PgExternEntity, NodeIndex>,
+    ords: &HashMap<PostgresOrdEntity, NodeIndex>,
 ) {
     for (item, &index) in hashes {
         make_schema_connection(
@@ -1169,14 +1386,105 @@ fn connect_hashes(
             enums,
         );
 
+        // both hash support functions must be defined before the operator class
+        let hash_fn_names = [item.fn_name(), item.extended_fn_name()];
         for (extern_item, &extern_index) in externs {
-            let hash_fn_name = item.fn_name();
-            let hash_fn_matches =
-                item.module_path == extern_item.module_path && extern_item.name == hash_fn_name;
+            let hash_fn_matches = item.module_path == extern_item.module_path
+                && hash_fn_names.iter().any(|name| extern_item.name == name);
 
             if hash_fn_matches {
                 graph.add_edge(extern_index, index, SqlGraphRelationship::RequiredBy);
-                break;
+            }
+        }
+
+        // without a `#[derive(PostgresEq)]`, the `=` operator is created along with the btree
+        // operator class
+        for (ord_item, &ord_index) in ords {
+            if ord_item.id == item.id {
+                graph.add_edge(ord_index, index, SqlGraphRelationship::RequiredBy);
+            }
+        }
+    }
+}
+
+fn initialize_opclasses(
+    graph: &mut StableGraph<SqlGraphEntity, SqlGraphRelationship>,
+    root: NodeIndex,
+    bootstrap: Option<NodeIndex>,
+    finalize: Option<NodeIndex>,
+    opclasses: Vec<PgOpClassEntity>,
+) -> eyre::Result<HashMap<PgOpClassEntity, NodeIndex>> {
+    let mut mapped_opclasses = HashMap::default();
+    for item in opclasses {
+        let entity: SqlGraphEntity = item.clone().into();
+        let index = graph.add_node(entity);
+        mapped_opclasses.insert(item, index);
+        build_base_edges(graph, index, root, bootstrap, finalize);
+    }
+    Ok(mapped_opclasses)
+}
+
+fn connect_opclasses(
+    graph: &mut StableGraph<SqlGraphEntity, SqlGraphRelationship>,
+    opclasses: &HashMap<PgOpClassEntity, NodeIndex>,
+    schemas: &HashMap<SchemaEntity, NodeIndex>,
+    types: &HashMap<PostgresTypeEntity, NodeIndex>,
+    enums: &HashMap<PostgresEnumEntity, NodeIndex>,
+    externs: &HashMap<PgExternEntity, NodeIndex>,
+    ords: &HashMap<PostgresOrdEntity, NodeIndex>,
+    hashes: &HashMap<PostgresHashEntity, NodeIndex>,
+) {
+    for (item, &index) in opclasses {
+        make_schema_connection(
+            graph,
+            "Operator class",
+            index,
+            &item.rust_identifier(),
+            item.module_path,
+            schemas,
+        );
+
+        make_type_or_enum_connection(
+            graph,
+            "Operator class",
+            index,
+            &item.rust_identifier(),
+            &item.id,
+            types,
+            enums,
+        );
+
+        // the support functions, and the operators, must be defined before the operator class.
+        // Operators may be given along with their operand types, as in `<< (point, point)`
+        let operator_names = item
+            .operators
+            .iter()
+            .map(|(_, operator)| operator_name(operator.split_whitespace().next().unwrap_or("")))
+            .collect::<Vec<_>>();
+        for (extern_item, &extern_index) in externs {
+            let support_fn_matches = item.module_path == extern_item.module_path
+                && item.functions.iter().any(|(_, name, _)| extern_item.name == *name);
+            let operator_matches = extern_item
+                .operator
+                .as_ref()
+                .and_then(|op| op.opname)
+                .map_or(false, |opname| operator_names.contains(&opname))
+                && extern_item.fn_args.iter().any(|arg| arg.used_ty.ty_id == item.id);
+
+            if support_fn_matches || operator_matches {
+                graph.add_edge(extern_index, index, SqlGraphRelationship::RequiredBy);
+            }
+        }
+
+        // `#[derive(PostgresOrd)]` and `#[derive(PostgresHash)]` may create the operators
+        for (ord_item, &ord_index) in ords {
+            if ord_item.id == item.id {
+                graph.add_edge(ord_index, index, SqlGraphRelationship::RequiredBy);
+            }
+        }
+        for (hash_item, &hash_index) in hashes {
+            if hash_item.id == item.id {
+                graph.add_edge(hash_index, index, SqlGraphRelationship::RequiredBy);
             }
         }
     }
@@ -1525,3 +1833,56 @@ fn make_type_or_enum_connection(
 
     found
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(relocatable: bool, sql: &'static str) -> PgrxSql {
+        let control = ControlFile {
+            comment: String::from("test"),
+            default_version: String::from("1.0"),
+            module_pathname: None,
+            relocatable,
+            superuser: false,
+            schema: None,
+        };
+        let entities = vec![
+            SqlGraphEntity::ExtensionRoot(control),
+            SqlGraphEntity::Schema(SchemaEntity {
+                module_path: "test::reports",
+                name: "reports",
+                file: "src/lib.rs",
+                line: 10,
+            }),
+            SqlGraphEntity::CustomSql(ExtensionSqlEntity {
+                module_path: "test",
+                full_path: "src/lib.rs:20",
+                sql,
+                file: "src/lib.rs",
+                line: 20,
+                name: "setup",
+                bootstrap: false,
+                finalize: false,
+                requires: vec![],
+                creates: vec![],
+            }),
+        ];
+        PgrxSql::build(entities.into_iter(), String::from("test"), true).unwrap()
+    }
+
+    #[test]
+    fn check_relocatable() {
+        let sql = "CREATE VIEW v AS SELECT 1 FROM @extschema@.t;";
+        assert!(build(false, sql).check_relocatable().is_ok());
+
+        let err = build(true, sql).check_relocatable().unwrap_err().to_string();
+        assert!(err.contains("src/lib.rs:10: `#[pg_schema] mod reports`"), "{err}");
+        assert!(err.contains("src/lib.rs:20: `setup` uses `@extschema@`"), "{err}");
+
+        let mut pgrx_sql = build(true, "SELECT 1;");
+        pgrx_sql.qualify_with_extschema = true;
+        let err = pgrx_sql.check_relocatable().unwrap_err().to_string();
+        assert!(err.contains("`package.metadata.pgrx.qualify-with-extschema`"), "{err}");
+    }
+}
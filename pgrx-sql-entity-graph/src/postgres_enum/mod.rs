@@ -124,6 +124,7 @@ impl ToEntityGraphTokens for PostgresEnum {
         let variants = self.variants.iter();
         let sql_graph_entity_fn_name =
             syn::Ident::new(&format!("__pgrx_internals_enum_{}", name), Span::call_site());
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
 
         let to_sql_config = &self.to_sql_config;
 
@@ -138,6 +139,7 @@ impl ToEntityGraphTokens for PostgresEnum {
                 }
             }
 
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
@@ -36,6 +36,10 @@ impl PostgresHashEntity {
     pub(crate) fn fn_name(&self) -> String {
         format!("{}_hash", self.name.to_lowercase())
     }
+
+    pub(crate) fn extended_fn_name(&self) -> String {
+        format!("{}_hash_extended", self.name.to_lowercase())
+    }
 }
 
 impl From<PostgresHashEntity> for SqlGraphEntity {
@@ -69,13 +73,15 @@ impl ToSql for PostgresHashEntity {
                             CREATE OPERATOR FAMILY {name}_hash_ops USING hash;\n\
                             CREATE OPERATOR CLASS {name}_hash_ops DEFAULT FOR TYPE {name} USING hash FAMILY {name}_hash_ops AS\n\
                                 \tOPERATOR    1   =  ({name}, {name}),\n\
-                                \tFUNCTION    1   {fn_name}({name});\
+                                \tFUNCTION    1   {fn_name}({name}),\n\
+                                \tFUNCTION    2   {extended_fn_name}({name}, bigint);\
                             ",
                           name = self.name,
                           full_path = self.full_path,
                           file = self.file,
                           line = self.line,
                           fn_name = self.fn_name(),
+                          extended_fn_name = self.extended_fn_name(),
         );
         Ok(sql)
     }
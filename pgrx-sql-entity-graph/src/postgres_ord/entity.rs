@@ -49,6 +49,10 @@ impl PostgresOrdEntity {
         format!("{}_eq", self.name.to_lowercase())
     }
 
+    pub(crate) fn ne_fn_name(&self) -> String {
+        format!("{}_ne", self.name.to_lowercase())
+    }
+
     pub(crate) fn gt_fn_name(&self) -> String {
         format!("{}_gt", self.name.to_lowercase())
     }
@@ -82,10 +86,51 @@ impl SqlGraphIdentifier for PostgresOrdEntity {
 }
 
 impl ToSql for PostgresOrdEntity {
-    fn to_sql(&self, _context: &PgrxSql) -> eyre::Result<String> {
+    fn to_sql(&self, context: &PgrxSql) -> eyre::Result<String> {
+        // `#[derive(PostgresEq)]` provides the `=` and `<>` operators, otherwise they're built on
+        // the comparison function
+        let has_eq = context.externs.keys().any(|extern_item| {
+            extern_item.module_path == self.module_path && extern_item.name == self.eq_fn_name()
+        });
+        let eq_sql = if has_eq {
+            String::new()
+        } else {
+            format!("\n\
+                    CREATE FUNCTION {eq_fn_name}(left_arg {name}, right_arg {name}) RETURNS bool IMMUTABLE STRICT PARALLEL SAFE LANGUAGE sql\n\
+                        \tAS $$ SELECT {cmp_fn_name}(left_arg, right_arg) = 0 $$;\n\
+                    CREATE FUNCTION {ne_fn_name}(left_arg {name}, right_arg {name}) RETURNS bool IMMUTABLE STRICT PARALLEL SAFE LANGUAGE sql\n\
+                        \tAS $$ SELECT {cmp_fn_name}(left_arg, right_arg) <> 0 $$;\n\
+                    CREATE OPERATOR = (\n\
+                        \tPROCEDURE = {eq_fn_name},\n\
+                        \tLEFTARG = {name},\n\
+                        \tRIGHTARG = {name},\n\
+                        \tCOMMUTATOR = =,\n\
+                        \tNEGATOR = <>,\n\
+                        \tRESTRICT = eqsel,\n\
+                        \tJOIN = eqjoinsel,\n\
+                        \tMERGES\n\
+                    );\n\
+                    CREATE OPERATOR <> (\n\
+                        \tPROCEDURE = {ne_fn_name},\n\
+                        \tLEFTARG = {name},\n\
+                        \tRIGHTARG = {name},\n\
+                        \tCOMMUTATOR = <>,\n\
+                        \tNEGATOR = =,\n\
+                        \tRESTRICT = neqsel,\n\
+                        \tJOIN = neqjoinsel\n\
+                    );\
+                ",
+                name = self.name,
+                eq_fn_name = self.eq_fn_name(),
+                ne_fn_name = self.ne_fn_name(),
+                cmp_fn_name = self.cmp_fn_name(),
+            )
+        };
+
         let sql = format!("\n\
                             -- {file}:{line}\n\
-                            -- {full_path}\n\
+                            -- {full_path}\
+                            {eq_sql}\n\
                             CREATE OPERATOR FAMILY {name}_btree_ops USING btree;\n\
                             CREATE OPERATOR CLASS {name}_btree_ops DEFAULT FOR TYPE {name} USING btree FAMILY {name}_btree_ops AS\n\
                                   \tOPERATOR 1 <,\n\
@@ -102,8 +102,10 @@ impl ToEntityGraphTokens for PostgresOrd {
         let name = &self.name;
         let sql_graph_entity_fn_name =
             syn::Ident::new(&format!("__pgrx_internals_ord_{}", self.name), Span::call_site());
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
         let to_sql_config = &self.to_sql_config;
         quote! {
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
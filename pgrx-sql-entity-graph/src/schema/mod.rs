@@ -81,7 +81,9 @@ impl Schema {
             &format!("__pgrx_internals_schema_{}_{}", ident, postfix),
             proc_macro2::Span::call_site(),
         );
+        let section_entry = crate::section::entry_tokens(&sql_graph_entity_fn_name);
         quote! {
+            #section_entry
             #[no_mangle]
             #[doc(hidden)]
             #[allow(unknown_lints, clippy::no_mangle_with_rust_abi)]
@@ -0,0 +1,77 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+/*!
+
+The section of an extension's shared library which lists the functions producing its SQL entities
+
+> Like all of the [`sql_entity_graph`][crate] APIs, this is considered **internal**
+> to the `pgrx` framework and very subject to change between versions. While you may use this, please do it with caution.
+
+Every `__pgrx_internals_*` function generated by the `pgrx` macros is accompanied by a static
+holding its NUL-terminated name, placed in a section of its own.  `cargo pgrx schema` reads that
+section straight out of the compiled library, so finding the entities doesn't depend on how (or
+whether) the linker populated the dynamic symbol table.
+
+Only the names are recorded here.  The entities themselves are still made by calling those
+functions, which `cargo pgrx schema` does by loading the library against a stub of the symbols
+Postgres provides: an entity's SQL comes from trait implementations and type information that
+exist only as code in the library, not as data the macros could write into the section.
+
+*/
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// The name of the section on ELF platforms.
+pub const ELF_SECTION: &str = ".pgrxsql";
+
+/// The name of the section on Mach-O platforms, where it lives in the `__DATA` segment.
+pub const MACHO_SECTION: &str = "__pgrxsql";
+
+/// Generates the static recording `entity_fn` in the entity section.
+///
+/// The static is exported so that `--gc-sections` keeps it around.
+pub fn entry_tokens(entity_fn: &syn::Ident) -> TokenStream2 {
+    let name = format!("{entity_fn}\0");
+    let len = name.len();
+    let bytes = syn::LitByteStr::new(name.as_bytes(), entity_fn.span());
+    let static_ident = syn::Ident::new(&format!("__pgrx_section{entity_fn}"), entity_fn.span());
+    quote! {
+        #[no_mangle]
+        #[used]
+        #[doc(hidden)]
+        #[cfg_attr(target_os = "macos", link_section = "__DATA,__pgrxsql")]
+        #[cfg_attr(not(target_os = "macos"), link_section = ".pgrxsql")]
+        pub static #static_ident: [u8; #len] = *#bytes;
+    }
+}
+
+/// Splits the contents of the entity section back into the names of the functions to call.
+///
+/// Linkers may pad between entries, so empty names are skipped.
+pub fn parse_entries(data: &[u8]) -> impl Iterator<Item = &str> {
+    data.split(|byte| *byte == 0)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| core::str::from_utf8(entry).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_entries;
+
+    #[test]
+    fn parse_padded_entries() {
+        let data =
+            b"__pgrx_internals_fn_a\0__pgrx_internals_type_b\0\0\0\0__pgrx_internals_sql_c\0";
+        assert_eq!(
+            parse_entries(data).collect::<Vec<_>>(),
+            vec!["__pgrx_internals_fn_a", "__pgrx_internals_type_b", "__pgrx_internals_sql_c"]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+/*!
+
+The SQL objects an extension creates, in a form that can be saved with each release and compared
+with the next one's, to catch objects that were dropped or redefined without an upgrade script.
+
+> Like all of the [`sql_entity_graph`][crate::pgrx_sql_entity_graph] APIs, this is considered **internal**
+to the `pgrx` framework and very subject to change between versions. While you may use this, please do it with caution.
+
+*/
+use crate::{PgrxSql, SqlGraphEntity, ToSql, EXTSCHEMA};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+/// The functions, types, and aggregates an extension creates, keyed by what they're named in SQL,
+/// along with the SQL that defines them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntitySignatures(BTreeMap<String, String>);
+
+impl EntitySignatures {
+    pub fn from_pgrx_sql(pgrx_sql: &PgrxSql) -> eyre::Result<Self> {
+        let mut signatures = BTreeMap::<String, Vec<String>>::new();
+        for index in pgrx_sql.graph.node_indices() {
+            let entity = &pgrx_sql.graph[index];
+            let (kind, name) = match entity {
+                SqlGraphEntity::Function(item) => ("function", item.name),
+                SqlGraphEntity::Trigger(item) => ("function", item.function_name),
+                SqlGraphEntity::Type(item) => ("type", item.name),
+                SqlGraphEntity::Enum(item) => ("type", item.name),
+                SqlGraphEntity::Aggregate(item) => ("aggregate", item.name),
+                _ => continue,
+            };
+            // the input and output functions of types are part of the type's SQL instead
+            // qualifying references with `@extschema@` doesn't change what they refer to
+            let sql = entity.to_sql(pgrx_sql)?.replace(&format!("{EXTSCHEMA}."), "");
+            let sql = normalize_sql(&sql);
+            if sql.is_empty() {
+                continue;
+            }
+            let schema = pgrx_sql
+                .schema_alias_of(&index)
+                .filter(|schema| schema != EXTSCHEMA)
+                .unwrap_or_else(|| "public".into());
+            // overloads of a function share a name, so they're compared together
+            signatures.entry(format!("{kind} {schema}.{name}")).or_default().push(sql);
+        }
+        Ok(EntitySignatures(
+            signatures
+                .into_iter()
+                .map(|(key, mut overloads)| {
+                    // in a stable order, rather than whatever order the graph has them in
+                    overloads.sort_unstable();
+                    (key, overloads.join(" "))
+                })
+                .collect(),
+        ))
+    }
+
+    /// Each object as its kind (`function`, `type`, or `aggregate`), its schema-qualified name,
+    /// and the SQL defining it
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+        self.0.iter().map(|(key, signature)| {
+            let (kind, name) = key.split_once(' ').unwrap_or(("", key));
+            (kind, name, signature.as_str())
+        })
+    }
+
+    /// Read signatures written out with [`EntitySignatures::to_string`]
+    pub fn parse(s: &str) -> eyre::Result<Self> {
+        let mut signatures = BTreeMap::new();
+        for line in s.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (key, signature) = line
+                .split_once('\t')
+                .ok_or_else(|| eyre::eyre!("malformed entity signature: {line}"))?;
+            signatures.insert(key.to_string(), signature.to_string());
+        }
+        Ok(EntitySignatures(signatures))
+    }
+
+    /// What about `previous` has been dropped or redefined in `self`.  Objects that are new in
+    /// `self` aren't included, as adding them doesn't break anything.
+    pub fn changes_since(&self, previous: &EntitySignatures) -> Vec<SignatureChange> {
+        previous
+            .0
+            .iter()
+            .filter_map(|(key, before)| match self.0.get(key) {
+                None => Some(SignatureChange::Dropped { object: key.clone() }),
+                Some(after) if after != before => Some(SignatureChange::Redefined {
+                    object: key.clone(),
+                    before: before.clone(),
+                    after: after.clone(),
+                }),
+                Some(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl Display for EntitySignatures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# SQL objects created by this version of the extension, generated by cargo-pgrx"
+        )?;
+        writeln!(f, "# Keep this file, so later versions can be checked for incompatible changes")?;
+        for (key, signature) in &self.0 {
+            writeln!(f, "{key}\t{signature}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureChange {
+    Dropped { object: String },
+    Redefined { object: String, before: String, after: String },
+}
+
+impl Display for SignatureChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureChange::Dropped { object } => write!(f, "{object} was dropped"),
+            SignatureChange::Redefined { object, before, after } => {
+                write!(f, "{object} was redefined\n    from: {before}\n      to: {after}")
+            }
+        }
+    }
+}
+
+/// Remove comments and extra whitespace, so only changes to the SQL itself are noticed
+fn normalize_sql(sql: &str) -> String {
+    let mut normalized = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut quote = None;
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) => {
+                normalized.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            (None, '\'' | '"') => {
+                normalized.push(c);
+                quote = Some(c);
+            }
+            (None, '-') if chars.peek() == Some(&'-') => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+                normalized.push(' ');
+            }
+            (None, '/') if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = None;
+                for c in chars.by_ref() {
+                    if last == Some('*') && c == '/' {
+                        break;
+                    }
+                    last = Some(c);
+                }
+                normalized.push(' ');
+            }
+            (None, c) if c.is_whitespace() => normalized.push(' '),
+            (None, c) => normalized.push(c),
+        }
+    }
+
+    let mut collapsed = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+    for (spaced, tight) in [("( ", "("), (" )", ")"), (" ,", ","), (" ;", ";")] {
+        collapsed = collapsed.replace(spaced, tight);
+    }
+    collapsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_strips_comments_and_whitespace() {
+        let sql = "-- src/lib.rs:10\n-- foo::bar\nCREATE  FUNCTION \"bar\"(\n\t\"x\" INT /* i32 */\n) RETURNS INT /* i32 */\nSTRICT\nLANGUAGE c /* Rust */\nAS 'MODULE_PATHNAME', 'bar_wrapper';";
+        assert_eq!(
+            normalize_sql(sql),
+            "CREATE FUNCTION \"bar\"(\"x\" INT) RETURNS INT STRICT LANGUAGE c AS 'MODULE_PATHNAME', 'bar_wrapper';"
+        );
+        assert_eq!(normalize_sql("SELECT '--not a comment'"), "SELECT '--not a comment'");
+    }
+
+    #[test]
+    fn changes_since() {
+        let previous = EntitySignatures::parse(
+            "# header\nfunction public.a\tCREATE FUNCTION a();\nfunction public.b\tCREATE FUNCTION b();\ntype public.t\tCREATE TYPE t;\n",
+        )
+        .unwrap();
+        let current = EntitySignatures::parse(
+            "function public.a\tCREATE FUNCTION a(\"x\" INT);\ntype public.t\tCREATE TYPE t;\ntype public.u\tCREATE TYPE u;\n",
+        )
+        .unwrap();
+        assert_eq!(
+            current.changes_since(&previous),
+            vec![
+                SignatureChange::Redefined {
+                    object: "function public.a".into(),
+                    before: "CREATE FUNCTION a();".into(),
+                    after: "CREATE FUNCTION a(\"x\" INT);".into(),
+                },
+                SignatureChange::Dropped { object: "function public.b".into() },
+            ]
+        );
+        assert_eq!(EntitySignatures::parse(&current.to_string()).unwrap(), current);
+    }
+}
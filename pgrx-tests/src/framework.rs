@@ -14,19 +14,27 @@ use eyre::{eyre, WrapErr};
 use once_cell::sync::Lazy;
 use owo_colors::OwoColorize;
 use pgrx::prelude::*;
-use pgrx_pg_config::{
-    cargo::PgrxManifestExt, createdb, get_c_locale_flags, get_target_dir, PgConfig, Pgrx,
-};
+use pgrx_pg_config::{cargo::PgrxManifestExt, createdb, get_target_dir, PgConfig, Pgrx};
 use postgres::error::DbError;
 use std::collections::HashMap;
 use std::io::{BufRead, BufReader, Write};
+use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use sysinfo::{Pid, ProcessExt, System, SystemExt};
 
+mod port_registry;
+mod requirements;
+mod results;
 mod shutdown;
+mod sql_examples;
+mod stress;
+pub use requirements::TestConditions;
+use requirements::TestRequirements;
 pub use shutdown::add_shutdown_hook;
+pub use sql_examples::run_sql_examples;
+use stress::StressMode;
 
 type LogLines = Arc<Mutex<HashMap<String, Vec<String>>>>;
 
@@ -34,6 +42,8 @@ struct SetupState {
     installed: bool,
     loglines: LogLines,
     system_session_id: String,
+    /// Why the cluster-level requirements of the crate being tested can't be met, if they can't
+    unmet_requirements: Option<String>,
 }
 
 static TEST_MUTEX: Lazy<Mutex<SetupState>> = Lazy::new(|| {
@@ -41,6 +51,7 @@ static TEST_MUTEX: Lazy<Mutex<SetupState>> = Lazy::new(|| {
         installed: false,
         loglines: Arc::new(Mutex::new(HashMap::new())),
         system_session_id: "NONE".to_string(),
+        unmet_requirements: None,
     })
 });
 
@@ -114,9 +125,84 @@ pub fn run_test(
     expected_error: Option<&str>,
     postgresql_conf: Vec<&'static str>,
 ) -> eyre::Result<()> {
-    let (loglines, system_session_id) = initialize_test_framework(postgresql_conf)?;
+    run_test_with_conditions(
+        sql_funcname,
+        expected_error,
+        postgresql_conf,
+        TestConditions::default(),
+    )
+}
+
+/// Like [`run_test`], but skips the test if its [`TestConditions`] aren't met
+pub fn run_test_with_conditions(
+    sql_funcname: &str,
+    expected_error: Option<&str>,
+    postgresql_conf: Vec<&'static str>,
+    conditions: TestConditions,
+) -> eyre::Result<()> {
+    let (loglines, system_session_id, unmet_requirements) =
+        initialize_test_framework(postgresql_conf)?;
+
+    let unmet = match unmet_requirements {
+        Some(reason) => Some(reason),
+        None => conditions.unmet(&mut client()?.0)?,
+    };
+    if let Some(reason) = unmet {
+        eprintln!("{} `{sql_funcname}`: {reason}", "Skipping".bold().yellow());
+        let details = results::Details { message: Some(reason), log: None };
+        results::record(sql_funcname, 0, results::Status::Skipped, Duration::ZERO, &details)?;
+        return Ok(());
+    }
+
+    let stress = StressMode::from_env()?;
+    for repetition in 0..stress.repeat {
+        let started = Instant::now();
+        let mut details = results::Details::default();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            run_test_once(
+                sql_funcname,
+                expected_error,
+                &loglines,
+                &system_session_id,
+                &stress,
+                repetition,
+                &mut details,
+            )
+        }));
 
-    let (mut client, session_id) = client()?;
+        let status = match &result {
+            Ok(Ok(())) => results::Status::Passed,
+            Ok(Err(e)) => {
+                details.message.get_or_insert_with(|| format!("{e:#}"));
+                results::Status::Failed
+            }
+            Err(_) => results::Status::Failed,
+        };
+        results::record(sql_funcname, repetition, status, started.elapsed(), &details)?;
+
+        match result {
+            Ok(result) => result?,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+    Ok(())
+}
+
+fn run_test_once(
+    sql_funcname: &str,
+    expected_error: Option<&str>,
+    loglines: &LogLines,
+    system_session_id: &str,
+    stress: &StressMode,
+    repetition: usize,
+    details: &mut results::Details,
+) -> eyre::Result<()> {
+    let mut connection = stress.connect(sql_funcname, repetition)?;
+    let (client, session_id) = connection.session();
+
+    if std::env::var("PGRX_WAIT_FOR_DEBUGGER").as_deref() == Ok("true") {
+        wait_for_debugger(client, sql_funcname)?;
+    }
 
     let result = client.transaction().map(|mut tx| {
         let schema = "tests"; // get_extension_schema();
@@ -161,24 +247,47 @@ pub fn run_test(
         // wait a second for Postgres to get log messages written to stderr
         std::thread::sleep(std::time::Duration::from_millis(1000));
 
-        let system_loglines = format_loglines(&system_session_id, &loglines);
-        let session_loglines = format_loglines(&session_id, &loglines);
+        let system_loglines = format_loglines(system_session_id, loglines);
+        let session_loglines = format_loglines(&session_id, loglines);
+        details.failed(&message, &session_loglines);
         panic!(
-            "\n\nPostgres Messages:\n{system_loglines}\n\nTest Function Messages:\n{session_loglines}\n\nClient Error:\n{message}\npostgres location: {pg_location}\nrust location: {rust_location}\n\n",
+            "\n\nPostgres Messages:\n{system_loglines}\n\nTest Function Messages:\n{session_loglines}\n\nClient Error:\n{message}\npostgres location: {pg_location}\nrust location: {rust_location}\n{stress}\n",
                 system_loglines = system_loglines.dimmed().white(),
                 session_loglines = session_loglines.cyan(),
                 message = message.bold().red(),
                 pg_location = pg_location.dimmed().white(),
-                rust_location = rust_location.yellow()
+                rust_location = rust_location.yellow(),
+                stress = stress.describe(repetition).bold().yellow(),
         );
     } else if let Some(message) = expected_error {
         // we expected an ERROR, but didn't get one
-        return Err(eyre!("Expected error: {message}"));
+        std::thread::sleep(std::time::Duration::from_millis(1000));
+        let session_loglines = format_loglines(&session_id, loglines);
+        details.failed(format!("Expected error: {message}"), &session_loglines);
+        return Err(eyre!(
+            "Expected error: {message}\n\nTest Function Messages:\n{session_loglines}\n{stress}",
+            session_loglines = session_loglines.cyan(),
+            stress = stress.describe(repetition).bold().yellow(),
+        ));
     } else {
         Ok(())
     }
 }
 
+/// Session ids, from the `%c` in our `log_line_prefix`, are the backend's start time and PID in hex
+fn session_id_for_pid(loglines: &HashMap<String, Vec<String>>, pid: u32) -> Option<String> {
+    let suffix = format!(".{pid:x}");
+    // PIDs get reused, so pick the most recently started backend
+    loglines
+        .keys()
+        .filter(|session_id| session_id.ends_with(&suffix))
+        .max_by_key(|session_id| {
+            let start_time = session_id.split('.').next().unwrap_or_default();
+            u64::from_str_radix(start_time, 16).unwrap_or_default()
+        })
+        .cloned()
+}
+
 fn format_loglines(session_id: &str, loglines: &LogLines) -> String {
     let mut result = String::new();
 
@@ -192,7 +301,7 @@ fn format_loglines(session_id: &str, loglines: &LogLines) -> String {
 
 fn initialize_test_framework(
     postgresql_conf: Vec<&'static str>,
-) -> eyre::Result<(LogLines, String)> {
+) -> eyre::Result<(LogLines, String, Option<String>)> {
     let mut state = TEST_MUTEX.lock().unwrap_or_else(|_| {
         // This used to immediately throw an std::process::exit(1), but it
         // would consume both stdout and stderr, resulting in error messages
@@ -205,26 +314,78 @@ fn initialize_test_framework(
     if !state.installed {
         shutdown::register_shutdown_hook();
         install_extension()?;
-        initdb(postgresql_conf)?;
 
-        let system_session_id = start_pg(state.loglines.clone())?;
+        let requirements = TestRequirements::from_manifest()?;
         let pg_config = get_pg_config()?;
-        dropdb()?;
-        createdb(&pg_config, get_pg_dbname(), true, false)?;
-        create_extension()?;
+        state.unmet_requirements = requirements.unmet_before_start(&pg_config)?;
+        if get_external_pg_config().is_some() {
+            // somebody else is managing this Postgres, so all we can do is check that it's
+            // configured the way the tests need it to be
+            let mut maintenance_client = maintenance_client()?;
+            if state.unmet_requirements.is_none() {
+                state.unmet_requirements =
+                    requirements.unmet_on_external(&mut maintenance_client, postgresql_conf)?;
+            }
+            if state.unmet_requirements.is_none() {
+                let dbname = get_pg_dbname();
+                // separately, as a multi-statement query runs in one transaction, and neither
+                // command can run inside one
+                maintenance_client.batch_execute(&format!("DROP DATABASE IF EXISTS {dbname}"))?;
+                maintenance_client.batch_execute(&format!("CREATE DATABASE {dbname}"))?;
+                state.unmet_requirements = requirements.unmet_after_start(&mut client()?.0)?;
+            }
+            if state.unmet_requirements.is_none() {
+                create_extension()?;
+            }
+        } else if state.unmet_requirements.is_none() {
+            initdb(requirements.postgresql_conf(postgresql_conf))?;
+
+            let system_session_id = start_pg(state.loglines.clone())?;
+            dropdb()?;
+            createdb(&pg_config, get_pg_dbname(), true, false)?;
+            state.system_session_id = system_session_id;
+
+            state.unmet_requirements = requirements.unmet_after_start(&mut client()?.0)?;
+            if state.unmet_requirements.is_none() {
+                create_extension()?;
+            }
+        }
         state.installed = true;
-        state.system_session_id = system_session_id;
     }
 
-    Ok((state.loglines.clone(), state.system_session_id.clone()))
+    Ok((state.loglines.clone(), state.system_session_id.clone(), state.unmet_requirements.clone()))
 }
 
-fn get_pg_config() -> eyre::Result<PgConfig> {
-    let pgrx = Pgrx::from_config().wrap_err("Unable to get PGRX from config")?;
+/// The `pg_config` of an existing, already running, Postgres to test against instead of one
+/// managed by pgrx
+fn get_external_pg_config() -> Option<PathBuf> {
+    std::env::var_os("PGRX_TEST_PG_CONFIG").map(PathBuf::from)
+}
 
+fn get_pg_config() -> eyre::Result<PgConfig> {
     let pg_version = pg_sys::get_pg_major_version_num();
 
-    let pg_config = pgrx
+    if let Some(path) = get_external_pg_config() {
+        let mut pg_config = PgConfig::new_with_defaults(path);
+        if pg_config.major_version()? != pg_version {
+            return Err(eyre!(
+                "`{}` is for Postgres {}, but the tests were compiled for Postgres {pg_version}",
+                pg_config.path().unwrap().display(),
+                pg_config.major_version()?
+            ));
+        }
+        // connect the way libpq would
+        let port = match std::env::var("PGPORT") {
+            Ok(port) => port.parse().wrap_err("PGPORT is not a valid port number")?,
+            Err(_) => 5432,
+        };
+        pg_config.set_test_port(port);
+        return Ok(pg_config);
+    }
+
+    let pgrx = Pgrx::from_config().wrap_err("Unable to get PGRX from config")?;
+
+    let mut pg_config = pgrx
         .get(&format!("pg{}", pg_version))
         .wrap_err_with(|| {
             format!("Error getting pg_config: {} is not a valid postgres version", pg_version)
@@ -232,18 +393,27 @@ fn get_pg_config() -> eyre::Result<PgConfig> {
         .unwrap()
         .clone();
 
+    // other projects may be running their tests at the same time, so the configured test port is
+    // only where we start looking for a free one
+    let port = port_registry::reserve_test_port(pg_config.test_port()?)?;
+    pg_config.set_test_port(port);
+
     Ok(pg_config)
 }
 
 pub fn client() -> eyre::Result<(postgres::Client, String)> {
     let pg_config = get_pg_config()?;
-    let mut client = postgres::Config::new()
-        .host(pg_config.host())
+    let mut config = postgres::Config::new();
+    config
+        .host(&get_pg_host(&pg_config))
         .port(pg_config.test_port().expect("unable to determine test port"))
         .user(&get_pg_user())
-        .dbname(&get_pg_dbname())
-        .connect(postgres::NoTls)
-        .wrap_err("Error connecting to Postgres")?;
+        .dbname(&get_pg_dbname());
+    // needed if the cluster was created with a password authentication method
+    if let Ok(password) = std::env::var("PGPASSWORD") {
+        config.password(password);
+    }
+    let mut client = config.connect(postgres::NoTls).wrap_err("Error connecting to Postgres")?;
 
     let sid_query_result = query_wrapper(
         Some("SELECT to_hex(trunc(EXTRACT(EPOCH FROM backend_start))::integer) || '.' || to_hex(pid) AS sid FROM pg_stat_activity WHERE pid = pg_backend_pid();".to_string()),
@@ -275,6 +445,21 @@ pub fn client() -> eyre::Result<(postgres::Client, String)> {
     Ok((client, session_id))
 }
 
+/// A connection to the `postgres` database of an external Postgres, for creating the test database
+fn maintenance_client() -> eyre::Result<postgres::Client> {
+    let pg_config = get_pg_config()?;
+    let mut config = postgres::Config::new();
+    config
+        .host(&get_pg_host(&pg_config))
+        .port(pg_config.test_port()?)
+        .user(&get_pg_user())
+        .dbname("postgres");
+    if let Ok(password) = std::env::var("PGPASSWORD") {
+        config.password(password);
+    }
+    config.connect(postgres::NoTls).wrap_err("Error connecting to Postgres")
+}
+
 fn install_extension() -> eyre::Result<()> {
     eprintln!("installing extension");
     let profile = std::env::var("PGRX_BUILD_PROFILE").unwrap_or("debug".into());
@@ -290,9 +475,7 @@ fn install_extension() -> eyre::Result<()> {
         std::env::var("PGRX_NO_DEFAULT_FEATURES").unwrap_or("false".to_string()) == "true";
     let all_features = std::env::var("PGRX_ALL_FEATURES").unwrap_or("false".to_string()) == "true";
 
-    let pg_version = format!("pg{}", pg_sys::get_pg_major_version_string());
-    let pgrx = Pgrx::from_config()?;
-    let pg_config = pgrx.get(&pg_version)?;
+    let pg_config = get_pg_config()?;
     let cargo_test_args = get_cargo_test_features()?;
     println!("detected cargo args: {:?}", cargo_test_args);
 
@@ -374,51 +557,27 @@ fn install_extension() -> eyre::Result<()> {
     Ok(())
 }
 
-fn initdb(postgresql_conf: Vec<&'static str>) -> eyre::Result<()> {
+fn initdb(postgresql_conf: Vec<String>) -> eyre::Result<()> {
     let pgdata = get_pgdata_path()?;
+    let pg_config = get_pg_config()?;
+    let options = Pgrx::from_config()?.initdb_options(&pg_config.label()?);
 
-    if !pgdata.is_dir() {
-        let pg_config = get_pg_config()?;
-        let mut command =
-            Command::new(pg_config.initdb_path().wrap_err("unable to determine initdb path")?);
-
-        command
-            .args(get_c_locale_flags())
-            .arg("-D")
-            .arg(pgdata.to_str().unwrap())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit());
-
-        let command_str = format!("{:?}", command);
-
-        let child = command.spawn().wrap_err_with(|| {
-            format!(
-                "Failed to spawn process for initializing database using command: '{}': ",
-                command_str
-            )
-        })?;
-
-        let output = child.wait_with_output().wrap_err_with(|| {
-            format!(
-                "Failed waiting for spawned process attempting to initialize database using command: '{}': ",
-                command_str
-            )
+    // the test data directory is disposable, so recreate it if the configured options changed
+    if pgdata.is_dir() && !options.matches_data_dir(&pgdata) {
+        std::fs::remove_dir_all(&pgdata).wrap_err_with(|| {
+            format!("unable to remove test data directory `{}`", pgdata.display())
         })?;
+    }
 
-        if !output.status.success() {
-            return Err(eyre!(
-                "Failed to initialize database using command: {}\n\n{}{}",
-                command_str,
-                String::from_utf8(output.stdout).unwrap(),
-                String::from_utf8(output.stderr).unwrap()
-            ));
-        }
+    if !pgdata.is_dir() {
+        pgrx_pg_config::initdb(&pg_config, &pgdata, &options)
+            .wrap_err("Failed to initialize test database")?;
     }
 
     modify_postgresql_conf(pgdata, postgresql_conf)
 }
 
-fn modify_postgresql_conf(pgdata: PathBuf, postgresql_conf: Vec<&'static str>) -> eyre::Result<()> {
+fn modify_postgresql_conf(pgdata: PathBuf, postgresql_conf: Vec<String>) -> eyre::Result<()> {
     let mut postgresql_conf_file = std::fs::OpenOptions::new()
         .write(true)
         .truncate(true)
@@ -515,13 +674,18 @@ fn monitor_pg(mut command: Command, cmd_string: String, loglines: LogLines) -> S
         let reader = BufReader::new(child.stderr.take().expect("couldn't take postmaster stderr"));
 
         let regex = regex::Regex::new(r#"\[.*?\] \[.*?\] \[(?P<session_id>.*?)\]"#).unwrap();
+        let pid_regex = regex::Regex::new(r#"\(PID (?P<pid>\d+)\)"#).unwrap();
         let mut is_started_yet = false;
+        let mut last_session_id = "NONE".to_string();
         let mut lines = reader.lines();
         while let Some(Ok(line)) = lines.next() {
             let session_id = match get_named_capture(&regex, "session_id", &line) {
                 Some(sid) => sid,
-                None => "NONE".to_string(),
+                // lines without a prefix continue a multi-line message (a DETAIL, a CONTEXT, a
+                // backtrace), or are written directly to stderr by the same process
+                None => last_session_id.clone(),
             };
+            last_session_id = session_id.clone();
 
             if line.contains("database system is ready to accept connections") {
                 // Postgres says it's ready to go
@@ -548,6 +712,20 @@ fn monitor_pg(mut command: Command, cmd_string: String, loglines: LogLines) -> S
             // }
 
             let mut loglines = loglines.lock().unwrap();
+
+            // the postmaster reports when a backend crashes, and that belongs with the backend's
+            // own messages too
+            if let Some(pid) = get_named_capture(&pid_regex, "pid", &line) {
+                let backend_session_id = pid
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|pid| session_id_for_pid(&loglines, pid))
+                    .filter(|backend_session_id| *backend_session_id != session_id);
+                if let Some(backend_session_id) = backend_session_id {
+                    loglines.get_mut(&backend_session_id).unwrap().push(line.clone());
+                }
+            }
+
             let session_lines = loglines.entry(session_id).or_insert_with(Vec::new);
             session_lines.push(line);
         }
@@ -631,16 +809,80 @@ fn get_extension_name() -> eyre::Result<String> {
     // Cargo.toml is case sensitive atm so this is ok.
     // https://github.com/rust-lang/cargo/issues/45
     let path = PathBuf::from(dir).join("Cargo.toml");
-    let name = pgrx_pg_config::cargo::read_manifest(path)?.lib_name()?;
+    let name = pgrx_pg_config::cargo::read_manifest(path)?.extension_name()?;
     Ok(name.replace("-", "_"))
 }
 
+/// Where the PID of a test backend that's waiting for a debugger is written, for
+/// `cargo pgrx debug` to find
+fn get_debugger_pid_file() -> eyre::Result<PathBuf> {
+    let mut target_dir = get_target_dir()?;
+    target_dir.push(&format!(
+        "pgrx-test-backend-{}-{:016x}.pid",
+        pg_sys::get_pg_major_version_num(),
+        get_project_hash()?
+    ));
+    Ok(target_dir)
+}
+
+/// Block until a debugger has attached to the backend `client` is connected to
+///
+/// On Linux this is detected by watching the backend's `TracerPid`.  Elsewhere, the user is asked
+/// to press enter once they've attached.
+fn wait_for_debugger(client: &mut postgres::Client, sql_funcname: &str) -> eyre::Result<()> {
+    let pid: i32 = client.query_one("SELECT pg_backend_pid()", &[])?.get(0);
+    let pid_file = get_debugger_pid_file()?;
+    std::fs::write(&pid_file, pid.to_string())
+        .wrap_err_with(|| format!("failed to write `{}`", pid_file.display()))?;
+
+    eprintln!(
+        "{} `{sql_funcname}` in backend PID {pid}, attach with `cargo pgrx debug` or `gdb -p {pid}`",
+        "Waiting for a debugger to run".bold().yellow(),
+    );
+
+    let status_file = PathBuf::from(format!("/proc/{pid}/status"));
+    if status_file.exists() {
+        loop {
+            let status = std::fs::read_to_string(&status_file)?;
+            let tracer_pid = status
+                .lines()
+                .find_map(|line| line.strip_prefix("TracerPid:"))
+                .map(|tracer_pid| tracer_pid.trim() != "0");
+            if tracer_pid.unwrap_or(true) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    } else {
+        eprintln!("Press enter once the debugger is attached");
+        std::io::stdin().read_line(&mut String::new())?;
+    }
+
+    std::fs::remove_file(&pid_file).ok();
+    Ok(())
+}
+
+/// Each project gets its own test data directory, as projects can share a `CARGO_TARGET_DIR` and
+/// may run their tests at the same time
 fn get_pgdata_path() -> eyre::Result<PathBuf> {
     let mut target_dir = get_target_dir()?;
-    target_dir.push(&format!("pgrx-test-data-{}", pg_sys::get_pg_major_version_num()));
+    target_dir.push(&format!(
+        "pgrx-test-data-{}-{:016x}",
+        pg_sys::get_pg_major_version_num(),
+        get_project_hash()?
+    ));
     Ok(target_dir)
 }
 
+/// A hash of the path of the project being tested, stable across runs and toolchains (FNV-1a)
+fn get_project_hash() -> eyre::Result<u64> {
+    let dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| eyre!("CARGO_MANIFEST_DIR environment var is unset or invalid UTF-8"))?;
+    Ok(dir
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3)))
+}
+
 fn get_pid_file() -> eyre::Result<PathBuf> {
     let mut pgdata = get_pgdata_path()?;
     pgdata.push("postmaster.pid");
@@ -652,10 +894,22 @@ pub(crate) fn get_pg_dbname() -> &'static str {
 }
 
 pub(crate) fn get_pg_user() -> String {
+    if get_external_pg_config().is_some() {
+        if let Ok(user) = std::env::var("PGUSER") {
+            return user;
+        }
+    }
     std::env::var("USER")
         .unwrap_or_else(|_| panic!("USER environment var is unset or invalid UTF-8"))
 }
 
+fn get_pg_host(pg_config: &PgConfig) -> String {
+    match std::env::var("PGHOST") {
+        Ok(host) if get_external_pg_config().is_some() => host,
+        _ => pg_config.host().to_string(),
+    }
+}
+
 pub fn get_named_capture(
     regex: &regex::Regex,
     name: &'static str,
@@ -0,0 +1,60 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Hands out test ports so that many projects, or many CI jobs, can run their tests at once
+//!
+//! Every port is guarded by a file in `$PGRX_HOME/test-ports/`, locked with `flock(2)` for as long
+//! as the test process lives.  The kernel releases the lock when the process exits, however it
+//! exits, so a crashed test run never leaves a port reserved.
+use eyre::{eyre, WrapErr};
+use once_cell::sync::OnceCell;
+use pgrx_pg_config::Pgrx;
+use std::fs::File;
+use std::net::TcpListener;
+use std::os::unix::io::AsRawFd;
+
+/// How many ports, starting at the configured test port, to try before giving up
+const MAX_PORTS: u16 = 100;
+
+/// The port this process reserved, and the locked file that reserves it
+static RESERVATION: OnceCell<(u16, File)> = OnceCell::new();
+
+/// The port reserved for this process' test Postgres instance
+///
+/// The first call reserves the first free port from `first_port` onwards.  Later calls return the
+/// same port.
+pub(super) fn reserve_test_port(first_port: u16) -> eyre::Result<u16> {
+    let (port, _) = RESERVATION.get_or_try_init(|| reserve(first_port))?;
+    Ok(*port)
+}
+
+fn reserve(first_port: u16) -> eyre::Result<(u16, File)> {
+    let mut dir = Pgrx::home()?;
+    dir.push("test-ports");
+    std::fs::create_dir_all(&dir)
+        .wrap_err_with(|| format!("unable to create `{}`", dir.display()))?;
+
+    for port in first_port..first_port.saturating_add(MAX_PORTS) {
+        let path = dir.join(format!("{port}.lock"));
+        let file = File::create(&path)
+            .wrap_err_with(|| format!("unable to create `{}`", path.display()))?;
+
+        let locked = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } == 0;
+        // something we don't know about, such as a Postgres left behind by a killed test run,
+        // might already be listening on the port
+        if locked && TcpListener::bind(("127.0.0.1", port)).is_ok() {
+            return Ok((port, file));
+        }
+    }
+
+    Err(eyre!(
+        "no free test port between {first_port} and {}",
+        first_port.saturating_add(MAX_PORTS - 1)
+    ))
+}
@@ -0,0 +1,240 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Cluster-level requirements a test crate declares in its `Cargo.toml`:
+//!
+//! ```toml
+//! [package.metadata.pgrx.test]
+//! # libraries to load with `shared_preload_libraries`, in addition to any set in
+//! # `pg_test::postgresql_conf_options()`
+//! shared_preload_libraries = ["pg_stat_statements", "my_extension"]
+//! # more `postgresql.conf` settings
+//! postgresql_conf = ["max_worker_processes = 16"]
+//! # the tests need Postgres to have been built with ICU support
+//! icu = true
+//! ```
+//!
+//! If the requirements can't be met by the Postgres being tested, the tests are skipped rather
+//! than failed.  Individual tests can be skipped in the same way with [`TestConditions`].
+use eyre::{eyre, WrapErr};
+use once_cell::sync::Lazy;
+use pgrx_pg_config::PgConfig;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+#[derive(Debug, Default)]
+pub(super) struct TestRequirements {
+    pub(super) shared_preload_libraries: Vec<String>,
+    pub(super) postgresql_conf: Vec<String>,
+    pub(super) icu: bool,
+}
+
+impl TestRequirements {
+    /// Read the requirements of the crate being tested
+    pub(super) fn from_manifest() -> eyre::Result<Self> {
+        let dir = std::env::var("CARGO_MANIFEST_DIR")
+            .map_err(|_| eyre!("CARGO_MANIFEST_DIR environment var is unset or invalid UTF-8"))?;
+        let manifest = pgrx_pg_config::cargo::read_manifest(PathBuf::from(dir).join("Cargo.toml"))?;
+
+        let table = manifest
+            .package
+            .as_ref()
+            .and_then(|package| package.metadata.as_ref())
+            .and_then(|metadata| metadata.get("pgrx"))
+            .and_then(|pgrx| pgrx.get("test"));
+        let table = match table {
+            Some(table) => table,
+            None => return Ok(TestRequirements::default()),
+        };
+
+        let strings = |key: &str| -> eyre::Result<Vec<String>> {
+            match table.get(key) {
+                None => Ok(vec![]),
+                Some(value) => value
+                    .as_array()
+                    .and_then(|values| {
+                        values.iter().map(|v| v.as_str().map(str::to_string)).collect()
+                    })
+                    .ok_or_else(|| {
+                        eyre!("`package.metadata.pgrx.test.{key}` must be an array of strings")
+                    }),
+            }
+        };
+
+        Ok(TestRequirements {
+            shared_preload_libraries: strings("shared_preload_libraries")?,
+            postgresql_conf: strings("postgresql_conf")?,
+            icu: match table.get("icu") {
+                None => false,
+                Some(value) => value
+                    .as_bool()
+                    .ok_or_else(|| eyre!("`package.metadata.pgrx.test.icu` must be a boolean"))?,
+            },
+        })
+    }
+
+    /// The settings to add to `postgresql.conf`, merged with those from the test crate's
+    /// `pg_test::postgresql_conf_options()`
+    pub(super) fn postgresql_conf(&self, postgresql_conf: Vec<&'static str>) -> Vec<String> {
+        let mut libraries = Vec::new();
+        let mut settings = Vec::new();
+        for setting in postgresql_conf {
+            match setting.split_once('=') {
+                Some((name, value)) if name.trim() == "shared_preload_libraries" => libraries
+                    .extend(
+                        value
+                            .trim()
+                            .trim_matches('\'')
+                            .split(',')
+                            .map(|library| library.trim().to_string())
+                            .filter(|library| !library.is_empty()),
+                    ),
+                _ => settings.push(setting.to_string()),
+            }
+        }
+
+        for library in &self.shared_preload_libraries {
+            if !libraries.contains(library) {
+                libraries.push(library.clone());
+            }
+        }
+        if !libraries.is_empty() {
+            settings.push(format!("shared_preload_libraries='{}'", libraries.join(",")));
+        }
+        settings.extend(self.postgresql_conf.iter().cloned());
+        settings
+    }
+
+    /// Why the requirements that can be checked before starting Postgres aren't met, if they aren't
+    pub(super) fn unmet_before_start(&self, pg_config: &PgConfig) -> eyre::Result<Option<String>> {
+        let pkglibdir = pg_config.pkglibdir()?;
+        let so_extension = if cfg!(target_os = "macos") { "dylib" } else { "so" };
+        for library in &self.shared_preload_libraries {
+            let library = library.trim_start_matches("$libdir/");
+            if !pkglibdir.join(format!("{library}.{so_extension}")).exists() {
+                return Ok(Some(format!(
+                    "shared_preload_libraries entry `{library}` is not installed in `{}`",
+                    pkglibdir.display()
+                )));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Why an external Postgres, which we can't configure ourselves, doesn't meet the requirements,
+    /// if it doesn't.  Only `shared_preload_libraries` can be checked, so any other settings are
+    /// assumed to have been made
+    pub(super) fn unmet_on_external(
+        &self,
+        client: &mut postgres::Client,
+        postgresql_conf: Vec<&'static str>,
+    ) -> eyre::Result<Option<String>> {
+        let preloaded: String = client.query_one("SHOW shared_preload_libraries", &[])?.get(0);
+        let preloaded = preloaded.split(',').map(|library| library.trim()).collect::<Vec<_>>();
+
+        for setting in self.postgresql_conf(postgresql_conf) {
+            if let Some(("shared_preload_libraries", libraries)) =
+                setting.split_once('=').map(|(name, value)| (name.trim(), value))
+            {
+                for library in libraries.trim_matches('\'').split(',') {
+                    if !preloaded.contains(&library) {
+                        return Ok(Some(format!(
+                            "`{library}` is not in the external Postgres' shared_preload_libraries"
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Why the requirements that can only be checked once Postgres is running aren't met, if
+    /// they aren't
+    pub(super) fn unmet_after_start(
+        &self,
+        client: &mut postgres::Client,
+    ) -> eyre::Result<Option<String>> {
+        if self.icu {
+            let has_icu: bool = client
+                .query_one(
+                    "SELECT EXISTS (SELECT 1 FROM pg_collation WHERE collprovider = 'i')",
+                    &[],
+                )
+                .wrap_err("unable to determine if Postgres supports ICU")?
+                .get(0);
+            if !has_icu {
+                return Ok(Some("Postgres was built without ICU support".to_string()));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The conditions under which a single test runs, from the `min_pg`, `max_pg`, `skip_if`, and
+/// `requires_extension` arguments of `#[pg_test]`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TestConditions {
+    /// The oldest Postgres major version to run on
+    pub min_pg: Option<u32>,
+    /// The newest Postgres major version to run on
+    pub max_pg: Option<u32>,
+    /// The name of a function returning `true` when the test should be skipped, and the function
+    pub skip_if: Option<(&'static str, fn() -> bool)>,
+    /// Extensions to create before the test runs
+    pub requires_extensions: &'static [&'static str],
+}
+
+/// The extensions [`TestConditions::unmet`] has already created
+static CREATED_EXTENSIONS: Lazy<Mutex<HashSet<String>>> = Lazy::new(Default::default);
+
+impl TestConditions {
+    /// Why the test shouldn't run, if it shouldn't.  Creates the required extensions if they're
+    /// available
+    pub(super) fn unmet(&self, client: &mut postgres::Client) -> eyre::Result<Option<String>> {
+        if let Some((name, skip_if)) = self.skip_if {
+            if skip_if() {
+                return Ok(Some(format!("`{name}()` returned true")));
+            }
+        }
+
+        if self.min_pg.is_some() || self.max_pg.is_some() {
+            let version: String = client.query_one("SHOW server_version_num", &[])?.get(0);
+            let major = version.parse::<u32>().wrap_err("invalid server_version_num")? / 10000;
+            if let Some(min_pg) = self.min_pg.filter(|&min_pg| major < min_pg) {
+                return Ok(Some(format!("requires Postgres {min_pg} or newer, not {major}")));
+            }
+            if let Some(max_pg) = self.max_pg.filter(|&max_pg| major > max_pg) {
+                return Ok(Some(format!("requires Postgres {max_pg} or older, not {major}")));
+            }
+        }
+
+        // tests run concurrently, and racing `CREATE EXTENSION IF NOT EXISTS`s can still collide
+        let mut created = CREATED_EXTENSIONS.lock().unwrap_or_else(|e| e.into_inner());
+        for &extension in self.requires_extensions {
+            if created.contains(extension) {
+                continue;
+            }
+            let available: bool = client
+                .query_one(
+                    "SELECT EXISTS (SELECT 1 FROM pg_available_extensions WHERE name = $1)",
+                    &[&extension],
+                )?
+                .get(0);
+            if !available {
+                return Ok(Some(format!("the `{extension}` extension is not available")));
+            }
+            client
+                .batch_execute(&format!("CREATE EXTENSION IF NOT EXISTS \"{extension}\" CASCADE"))
+                .wrap_err_with(|| format!("failed to create the `{extension}` extension"))?;
+            created.insert(extension.to_string());
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,101 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! `cargo pgrx test --json-results FILE` (`PGRX_TEST_RESULTS`): a line of JSON appended to `FILE`
+//! for every test run, so CI can aggregate results across Postgres versions without scraping the
+//! human-readable output:
+//!
+//! ```json
+//! {"test":"test_foo","status":"failed","pg_version":"15.3","repetition":0,"duration_ms":12.5,"message":"...","log":"..."}
+//! ```
+//!
+//! `status` is one of `passed`, `failed`, or `skipped`.  `message` is the failure message or the
+//! reason the test was skipped, and `log` is the end of what the test's backend logged, when
+//! it failed.
+use once_cell::sync::Lazy;
+use pgrx::pg_sys;
+use serde::Serialize;
+use std::ffi::CStr;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How many of the backend's last log lines are included with a failure
+const LOG_LINES: usize = 50;
+
+static RESULTS: Lazy<Option<Mutex<PathBuf>>> =
+    Lazy::new(|| std::env::var_os("PGRX_TEST_RESULTS").map(|path| Mutex::new(path.into())));
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum Status {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// What a test run wants to say about itself beyond whether it passed, filled in as it fails
+#[derive(Debug, Default)]
+pub(super) struct Details {
+    pub(super) message: Option<String>,
+    pub(super) log: Option<String>,
+}
+
+impl Details {
+    pub(super) fn failed(&mut self, message: impl Into<String>, log: &str) {
+        let lines = log.lines().collect::<Vec<_>>();
+        self.message = Some(message.into());
+        self.log = Some(lines[lines.len().saturating_sub(LOG_LINES)..].join("\n"));
+    }
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+    test: &'a str,
+    status: Status,
+    pg_version: &'a str,
+    repetition: usize,
+    duration_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    log: Option<&'a str>,
+}
+
+/// Append a line for one run of `test`, if results were asked for
+pub(super) fn record(
+    test: &str,
+    repetition: usize,
+    status: Status,
+    duration: Duration,
+    details: &Details,
+) -> eyre::Result<()> {
+    let Some(path) = RESULTS.as_ref() else {
+        return Ok(());
+    };
+
+    let pg_version = CStr::from_bytes_with_nul(pg_sys::PG_VERSION)?.to_str()?;
+    let mut line = serde_json::to_vec(&Line {
+        test,
+        status,
+        pg_version,
+        repetition,
+        duration_ms: duration.as_secs_f64() * 1000.0,
+        message: details.message.as_deref(),
+        log: details.log.as_deref(),
+    })?;
+    line.push(b'\n');
+
+    // tests run concurrently, so each line is written whole while holding the lock
+    let path = path.lock().unwrap_or_else(|e| e.into_inner());
+    OpenOptions::new().create(true).append(true).open(&*path)?.write_all(&line)?;
+    Ok(())
+}
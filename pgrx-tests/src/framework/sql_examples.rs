@@ -0,0 +1,158 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Checking files of example SQL, like the `sql/examples.sql` of a new extension:
+//!
+//! ```sql
+//! -- statements end at the end of a line with a `;`
+//! SELECT hello_my_extension();
+//! -- => Hello, my_extension
+//! SELECT 1, 'one' UNION ALL SELECT 2, 'two';
+//! -- => 1 | one
+//! -- => 2 | two
+//! ```
+//!
+//! Each statement is run in order, in one transaction, and one followed by `-- =>` lines must
+//! return exactly those rows, with columns as Postgres writes them as text, separated by ` | `.
+//! `NULL`s are written as nothing.
+use eyre::{eyre, WrapErr};
+use owo_colors::OwoColorize;
+use postgres::SimpleQueryMessage;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Example {
+    /// The line the statement starts on
+    line: usize,
+    sql: String,
+    expected: Option<Vec<String>>,
+}
+
+fn parse_examples(sql: &str) -> Vec<Example> {
+    let mut examples = Vec::<Example>::new();
+    let mut statement: Option<Example> = None;
+    for (number, line) in sql.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(row) = trimmed.strip_prefix("-- =>") {
+            // expectations belong to the last finished statement
+            if let Some(example) = examples.last_mut().filter(|_| statement.is_none()) {
+                example.expected.get_or_insert_with(Vec::new).push(row.trim().to_string());
+            }
+            continue;
+        }
+        if statement.is_none() && (trimmed.is_empty() || trimmed.starts_with("--")) {
+            continue;
+        }
+
+        let example = statement.get_or_insert_with(|| Example {
+            line: number + 1,
+            sql: String::new(),
+            expected: None,
+        });
+        example.sql.push_str(line);
+        example.sql.push('\n');
+        if trimmed.ends_with(';') {
+            examples.extend(statement.take());
+        }
+    }
+    // a last statement without a `;` is still run
+    examples.extend(statement);
+    examples
+}
+
+/// Run the examples in `sql`, from the file named `filename`, against the extension being tested,
+/// and fail if any of them errors or returns something other than what it says it should.  The
+/// transaction they run in is rolled back afterwards.
+///
+/// This is usually called from a `#[test]` in the extension, like:
+///
+/// ```rust,ignore
+/// #[cfg(test)]
+/// mod sql_examples {
+///     #[test]
+///     fn examples() {
+///         pgrx_tests::run_sql_examples(
+///             "sql/examples.sql",
+///             include_str!("../sql/examples.sql"),
+///             crate::pg_test::postgresql_conf_options(),
+///         )
+///         .unwrap();
+///     }
+/// }
+/// ```
+pub fn run_sql_examples(
+    filename: &str,
+    sql: &str,
+    postgresql_conf: Vec<&'static str>,
+) -> eyre::Result<()> {
+    let (_loglines, _system_session_id, unmet_requirements) =
+        super::initialize_test_framework(postgresql_conf)?;
+    if let Some(reason) = unmet_requirements {
+        eprintln!("{} `{filename}`: {reason}", "Skipping".bold().yellow());
+        return Ok(());
+    }
+
+    let (mut client, _session_id) = super::client()?;
+    let mut tx = client.transaction()?;
+    for example in parse_examples(sql) {
+        let location = format!("{filename}:{}", example.line);
+        let messages = tx.simple_query(&example.sql).wrap_err_with(|| {
+            format!("{location}: `{}` failed", example.sql.trim().bold().white())
+        })?;
+        let Some(expected) = example.expected else { continue };
+
+        let returned = messages
+            .iter()
+            .filter_map(|message| match message {
+                SimpleQueryMessage::Row(row) => Some(
+                    (0..row.len())
+                        .map(|column| row.get(column).unwrap_or_default())
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                        // as the expected rows were trimmed
+                        .trim()
+                        .to_string(),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if returned != expected {
+            return Err(eyre!(
+                "{location}: `{}` returned\n{}\nbut the example says it returns\n{}",
+                example.sql.trim().bold().white(),
+                returned.join("\n").red(),
+                expected.join("\n").green()
+            ));
+        }
+    }
+    tx.rollback()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let sql = "-- a comment\nSELECT 1;\n-- => 1\n\nSELECT\n  2,\n  3;\n-- => 2 | 3\n-- =>   \nCREATE TABLE t ();\nSELECT 4";
+        assert_eq!(
+            parse_examples(sql),
+            vec![
+                Example { line: 2, sql: "SELECT 1;\n".into(), expected: Some(vec!["1".into()]) },
+                Example {
+                    line: 5,
+                    sql: "SELECT\n  2,\n  3;\n".into(),
+                    expected: Some(vec!["2 | 3".into(), "".into()]),
+                },
+                Example { line: 10, sql: "CREATE TABLE t ();\n".into(), expected: None },
+                Example { line: 11, sql: "SELECT 4\n".into(), expected: None },
+            ]
+        );
+    }
+}
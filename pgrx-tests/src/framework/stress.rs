@@ -0,0 +1,141 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! `cargo pgrx test --shuffle` and `--repeat`: running `#[pg_test]`s in ways that shake out tests
+//! which only pass because of state some other test left behind.
+//!
+//! - `--repeat N` (`PGRX_TEST_REPEAT`) runs every test `N` times.
+//! - `--shuffle[=SEED]` (`PGRX_TEST_SHUFFLE_SEED`) stops giving each test run a fresh backend.
+//!   Instead, runs check out a backend from a small shared pool, picked at random, so tests see
+//!   each other's session state (GUCs, temp tables, caches, Rust statics) in varying
+//!   combinations.  Each run also starts after a random delay, which reorders tests that the
+//!   harness runs concurrently.
+//!
+//! All the randomness is derived from the seed and the test's name, and the seed is included in
+//! every failure message so a failing combination can be retried.
+use super::client;
+use eyre::eyre;
+use once_cell::sync::Lazy;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// How many backends `--shuffle` spreads test runs across
+const POOL_SIZE: usize = 4;
+
+/// The longest a shuffled test run waits before starting
+const MAX_JITTER: Duration = Duration::from_millis(25);
+
+type Session = (postgres::Client, String);
+
+static POOL: Lazy<Vec<Mutex<Option<Session>>>> =
+    Lazy::new(|| (0..POOL_SIZE).map(|_| Mutex::new(None)).collect());
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct StressMode {
+    pub(super) repeat: usize,
+    pub(super) seed: Option<u64>,
+}
+
+impl StressMode {
+    pub(super) fn from_env() -> eyre::Result<Self> {
+        let repeat = match std::env::var("PGRX_TEST_REPEAT") {
+            Ok(repeat) => repeat
+                .parse::<usize>()
+                .map_err(|e| eyre!("PGRX_TEST_REPEAT=`{repeat}` is not a count: {e}"))?
+                .max(1),
+            Err(_) => 1,
+        };
+        let seed = match std::env::var("PGRX_TEST_SHUFFLE_SEED") {
+            Ok(seed) => Some(
+                seed.parse::<u64>()
+                    .map_err(|e| eyre!("PGRX_TEST_SHUFFLE_SEED=`{seed}` is not a u64: {e}"))?,
+            ),
+            Err(_) => None,
+        };
+        Ok(StressMode { repeat, seed })
+    }
+
+    /// Connect for one run of `test`, waiting out its random delay first if shuffling
+    pub(super) fn connect(&self, test: &str, repetition: usize) -> eyre::Result<Connection> {
+        let Some(mut rng) = self.rng(test, repetition) else {
+            let (client, session_id) = client()?;
+            return Ok(Connection::Fresh(client, session_id));
+        };
+
+        std::thread::sleep(rng.gen_range(Duration::ZERO..=MAX_JITTER));
+
+        // a previous run that panicked while holding this slot may have left its session in any
+        // state, so start that one over
+        let slot = &POOL[rng.gen_range(0..POOL_SIZE)];
+        let mut session = slot.lock().unwrap_or_else(|poisoned| {
+            let mut session = poisoned.into_inner();
+            session.take();
+            session
+        });
+        let connected = matches!(&*session, Some((client, _)) if !client.is_closed());
+        if !connected {
+            *session = Some(client()?);
+        }
+        Ok(Connection::Pooled(session))
+    }
+
+    /// Describes the run, for failure messages
+    pub(super) fn describe(&self, repetition: usize) -> String {
+        let mut description = Vec::new();
+        if self.repeat > 1 {
+            description.push(format!("repetition {} of {}", repetition + 1, self.repeat));
+        }
+        if let Some(seed) = self.seed {
+            description.push(format!("rerun with `cargo pgrx test --shuffle={seed}`"));
+        }
+        description.join(", ")
+    }
+
+    fn rng(&self, test: &str, repetition: usize) -> Option<StdRng> {
+        let seed = self.seed?;
+        // FNV-1a, so the same seed picks the same backends and delays from one build to the next
+        let mut hash = 0xcbf29ce484222325u64 ^ seed;
+        for byte in test.bytes().chain(repetition.to_le_bytes()) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        Some(StdRng::seed_from_u64(hash))
+    }
+}
+
+/// The connection a single test run uses
+pub(super) enum Connection {
+    Fresh(postgres::Client, String),
+    Pooled(MutexGuard<'static, Option<Session>>),
+}
+
+impl Connection {
+    pub(super) fn session(&mut self) -> (&mut postgres::Client, String) {
+        match self {
+            Connection::Fresh(client, session_id) => (client, session_id.clone()),
+            Connection::Pooled(session) => {
+                let (client, session_id) = session.as_mut().expect("pooled session is connected");
+                (client, session_id.clone())
+            }
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // don't hand a backend that's mid-failure to the next test
+        if let Connection::Pooled(session) = self {
+            if std::thread::panicking() {
+                session.take();
+            }
+        }
+    }
+}
@@ -8,6 +8,7 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 mod framework;
+pub mod proptest;
 #[cfg(any(test, feature = "pg_test"))]
 mod tests;
 
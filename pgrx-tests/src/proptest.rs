@@ -0,0 +1,244 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Property-based round-trip checks for [`FromDatum`] and [`IntoDatum`] implementations.
+//!
+//! Custom type authors can get serialization-soundness testing for free by calling
+//! [`assert_datum_roundtrip`] from a `#[pg_test]`:
+//!
+//! ```rust,no_run
+//! # use pgrx::prelude::*;
+//! # #[derive(Clone, Debug, PartialEq, PostgresType, serde::Serialize, serde::Deserialize)]
+//! # struct MyType { value: i32 }
+//! # impl proptest::arbitrary::Arbitrary for MyType {
+//! #     type Parameters = ();
+//! #     type Strategy = proptest::strategy::BoxedStrategy<Self>;
+//! #     fn arbitrary_with(_: ()) -> Self::Strategy {
+//! #         use proptest::strategy::Strategy;
+//! #         proptest::arbitrary::any::<i32>().prop_map(|value| MyType { value }).boxed()
+//! #     }
+//! # }
+//! #[pg_test]
+//! fn my_type_roundtrips() {
+//!     pgrx_tests::proptest::assert_datum_roundtrip::<MyType>();
+//! }
+//! ```
+//!
+//! Every generated value is pushed through:
+//!
+//! - `IntoDatum` followed directly by `FromDatum`, in Rust
+//! - Postgres, as an SPI argument that is selected straight back out
+//! - the type's text input and output functions
+//! - a temporary table, so values large enough to be TOASTed are stored out-of-line and read back
+//! - a Postgres array, with NULL elements mixed in
+//!
+//! NULL handling is checked too: a NULL datum must come back as `None` from every path.
+//!
+//! Failing values are shrunk by [`proptest`](::proptest) before being reported.  If Postgres
+//! itself raises an `ERROR` the run stops at the first failing value and the error is re-raised
+//! as-is, since the transaction cannot be trusted after that.
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::collection::vec;
+use ::proptest::strategy::Strategy;
+use ::proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use ::proptest::{prop_assert, prop_assert_eq};
+use pgrx::prelude::*;
+use pgrx::{FromDatum, IntoDatum};
+
+/// The number of values generated per type, unless overridden by `PROPTEST_CASES`
+pub const DEFAULT_CASES: u32 = 64;
+
+/// Fuzz `T`'s [`FromDatum`] and [`IntoDatum`] implementations with values from its [`Arbitrary`]
+/// strategy, panicking with the smallest failing value if any of them don't survive a round-trip.
+///
+/// Must be called from within a transaction, such as the body of a `#[pg_test]`.
+pub fn assert_datum_roundtrip<T>()
+where
+    T: Arbitrary + IntoDatum + FromDatum + Clone + PartialEq + Debug + 'static,
+{
+    assert_datum_roundtrip_with(any::<T>())
+}
+
+/// Like [`assert_datum_roundtrip`], but generates values from `strategy` instead of `T`'s
+/// [`Arbitrary`] implementation.  See the [`strategies`] module for some useful ones.
+pub fn assert_datum_roundtrip_with<T, S>(strategy: S)
+where
+    T: IntoDatum + FromDatum + Clone + PartialEq + Debug + 'static,
+    S: Strategy<Value = T>,
+{
+    check_datum_null::<T>();
+
+    let mut runner = test_runner();
+    let caught: RefCell<Option<Box<dyn Any + Send>>> = RefCell::new(None);
+    let result = runner.run(&strategy, |value| {
+        if caught.borrow().is_some() {
+            // Postgres raised an ERROR while checking an earlier value.  Report every other
+            // value as passing so that proptest stops shrinking and we can re-raise it below
+            return Ok(());
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| check_datum_roundtrip(value))) {
+            Ok(result) => result,
+            Err(e) => {
+                *caught.borrow_mut() = Some(e);
+                Err(TestCaseError::fail("Postgres raised an ERROR"))
+            }
+        }
+    });
+
+    if let Some(e) = caught.into_inner() {
+        panic::resume_unwind(e);
+    }
+    match result {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, value)) => panic!(
+            "`{}` failed to round-trip through a datum: {reason}\nminimal failing value: {value:#?}",
+            std::any::type_name::<T>()
+        ),
+        Err(TestError::Abort(reason)) => {
+            panic!("round-trip testing `{}` aborted: {reason}", std::any::type_name::<T>())
+        }
+    }
+}
+
+/// Check that a single `value` survives every round-trip [`assert_datum_roundtrip`] performs.
+///
+/// This is the building block for driving the checks from your own [`TestRunner`] or `proptest!`
+/// block.
+pub fn check_datum_roundtrip<T>(value: T) -> Result<(), TestCaseError>
+where
+    T: IntoDatum + FromDatum + Clone + PartialEq + Debug + 'static,
+{
+    let type_oid = T::type_oid();
+    let type_name = type_name(type_oid);
+
+    // straight through Rust
+    let datum = value.clone().into_datum();
+    prop_assert!(datum.is_some(), "into_datum() returned NULL for a non-NULL value");
+    let back = unsafe { T::from_polymorphic_datum(datum.unwrap(), false, type_oid) };
+    prop_assert_eq!(back.as_ref(), Some(&value), "direct round-trip");
+
+    // as an SPI argument
+    let back = Spi::get_one_with_args::<T>(
+        "SELECT $1",
+        vec![(PgOid::from(type_oid), value.clone().into_datum())],
+    )
+    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(back.as_ref(), Some(&value), "SPI argument round-trip");
+
+    // through the type's text output and input functions.  Postgres 11 doesn't print floats with
+    // enough digits to be read back exactly unless asked to
+    Spi::run("SET LOCAL extra_float_digits = 3").map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let back = Spi::get_one_with_args::<T>(
+        &format!("SELECT CAST(CAST($1 AS text) AS {type_name})"),
+        vec![(PgOid::from(type_oid), value.clone().into_datum())],
+    )
+    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(back.as_ref(), Some(&value), "text I/O round-trip");
+
+    // stored in a table, where large values get compressed and/or TOASTed
+    let table = format!("pg_temp.pgrx_roundtrip_{}", u32::from(type_oid));
+    Spi::run(&format!("CREATE TEMPORARY TABLE IF NOT EXISTS {table} (v {type_name})"))
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let back = Spi::get_one_with_args::<T>(
+        &format!("WITH ins AS (INSERT INTO {table} VALUES ($1) RETURNING v) SELECT v FROM ins"),
+        vec![(PgOid::from(type_oid), value.clone().into_datum())],
+    )
+    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(back.as_ref(), Some(&value), "INSERT ... RETURNING round-trip");
+    let back = Spi::get_one::<T>(&format!("SELECT v FROM {table} LIMIT 1"))
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(back.as_ref(), Some(&value), "stored round-trip");
+    Spi::run(&format!("TRUNCATE {table}")).map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+    // as an element of an array, surrounded by NULLs
+    let array = vec![None, Some(value.clone()), None, Some(value)];
+    let back = Spi::get_one_with_args::<Vec<Option<T>>>(
+        "SELECT $1",
+        vec![(PgOid::from(T::array_type_oid()), array.clone().into_datum())],
+    )
+    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(back, Some(array), "array round-trip");
+
+    Ok(())
+}
+
+/// A [`TestRunner`] configured for running inside a Postgres backend: no failure persistence
+/// files are written into the data directory, and [`DEFAULT_CASES`] values are tried per type
+pub fn test_runner() -> TestRunner {
+    let cases = std::env::var("PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    TestRunner::new(Config { cases, failure_persistence: None, ..Config::default() })
+}
+
+fn check_datum_null<T>()
+where
+    T: IntoDatum + FromDatum + Debug + 'static,
+{
+    let type_oid = T::type_oid();
+    let null = unsafe { T::from_polymorphic_datum(pg_sys::Datum::from(0), true, type_oid) };
+    assert!(null.is_none(), "from_datum() of a NULL datum returned {null:?}");
+
+    let null = Spi::get_one_with_args::<T>("SELECT $1", vec![(PgOid::from(type_oid), None)])
+        .expect("NULL SPI argument round-trip failed");
+    assert!(null.is_none(), "NULL SPI argument came back as {null:?}");
+}
+
+fn type_name(type_oid: pg_sys::Oid) -> String {
+    unsafe {
+        let name = pg_sys::format_type_be(type_oid);
+        let owned = CStr::from_ptr(name).to_str().expect("type name is not UTF8").to_owned();
+        pg_sys::pfree(name.cast());
+        owned
+    }
+}
+
+/// Strategies for values that are worth testing but which the standard [`Arbitrary`]
+/// implementations either can't produce or can't be sent to Postgres as-is
+pub mod strategies {
+    use super::*;
+
+    /// Strings Postgres will accept as `text`: anything without a NUL byte
+    pub fn text() -> impl Strategy<Value = String> {
+        any::<String>().prop_map(|s| s.replace('\0', ""))
+    }
+
+    /// Strings long enough to be compressed or moved out-of-line when stored.  Half of them are
+    /// highly repetitive, which compresses well, and half are random, which doesn't
+    pub fn toast_text() -> impl Strategy<Value = String> {
+        ::proptest::prop_oneof![
+            (text(), 2_048usize..65_536).prop_map(|(s, len)| if s.is_empty() {
+                "x".repeat(len)
+            } else {
+                s.repeat(len / s.len() + 1)
+            }),
+            ::proptest::string::string_regex("[^\\x00]{2048,8192}").unwrap(),
+        ]
+    }
+
+    /// Arbitrary `bytea` values
+    pub fn bytea() -> impl Strategy<Value = Vec<u8>> {
+        vec(any::<u8>(), 0..256)
+    }
+
+    /// `bytea` values long enough to be compressed or moved out-of-line when stored
+    pub fn toast_bytea() -> impl Strategy<Value = Vec<u8>> {
+        ::proptest::prop_oneof![
+            (any::<u8>(), 2_048usize..65_536).prop_map(|(b, len)| vec![b; len]),
+            vec(any::<u8>(), 2_048..8_192),
+        ]
+    }
+}
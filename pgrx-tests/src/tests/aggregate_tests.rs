@@ -7,6 +7,7 @@
 //LICENSE All rights reserved.
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::aggregate::InternalState;
 use pgrx::prelude::*;
 use pgrx::{Aggregate, Internal, ParallelOption};
 use serde::{Deserialize, Serialize};
@@ -143,6 +144,113 @@ impl Aggregate for DemoPercentileDisc {
     }
 }
 
+pub struct DemoHypotheticalRank;
+
+#[pg_aggregate]
+impl Aggregate for DemoHypotheticalRank {
+    type Args = name!(input, i32);
+    type State = Internal;
+    type Finalize = i64;
+    const ORDERED_SET: bool = true;
+    const HYPOTHETICAL: bool = true;
+    type OrderedSetArgs = name!(hypothetical, i32);
+
+    fn state(
+        mut current: Self::State,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::State {
+        let inner = unsafe { current.get_or_insert_default::<Vec<i32>>() };
+
+        inner.push(arg);
+        current
+    }
+
+    fn finalize(
+        mut current: Self::State,
+        direct_arg: Self::OrderedSetArgs,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::Finalize {
+        let inner = unsafe { current.get_or_insert_default::<Vec<i32>>() };
+
+        inner.iter().filter(|value| **value < direct_arg).count() as i64 + 1
+    }
+}
+
+pub struct DemoMovingMax;
+
+/// A maximum can't be undone when the maximum leaves the window frame, so the inverse gives up
+/// then and Postgres recomputes it from the rest of the frame
+#[pg_aggregate]
+impl Aggregate for DemoMovingMax {
+    type Args = i32;
+    type State = Option<i32>;
+    type MovingState = Option<i32>;
+
+    fn state(
+        current: Self::State,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::State {
+        Some(current.map_or(arg, |current| current.max(arg)))
+    }
+
+    fn moving_state(
+        current: Self::MovingState,
+        arg: Self::Args,
+        fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::MovingState {
+        Self::state(current, arg, fcinfo)
+    }
+
+    fn moving_state_inverse(
+        current: Self::MovingState,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::MovingState {
+        current.filter(|current| *current != arg)
+    }
+}
+
+pub struct DemoParallelUnique;
+
+/// Like `DemoUnique`, but it can run in parallel: its state is serialized with serde
+#[pg_aggregate]
+impl Aggregate for DemoParallelUnique {
+    const PARALLEL: Option<ParallelOption> = Some(ParallelOption::Safe);
+    type Args = i32;
+    type State = InternalState<HashSet<i32>>;
+    type Finalize = i32;
+
+    fn state(
+        mut current: Self::State,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::State {
+        current.get_or_insert_default().insert(arg);
+        current
+    }
+
+    fn combine(
+        mut first: Self::State,
+        second: Self::State,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::State {
+        if let Some(second) = second.get() {
+            first.get_or_insert_default().extend(second);
+        }
+        first
+    }
+
+    fn finalize(
+        current: Self::State,
+        _direct_args: Self::OrderedSetArgs,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::Finalize {
+        current.get().map_or(0, |set| set.len() as i32)
+    }
+}
+
 #[pgrx::pg_schema]
 mod demo_schema {
     use pgrx::PostgresType;
@@ -213,6 +321,26 @@ mod tests {
         assert_eq!(retval, Ok(Some(vec![1, 21, 320, 4300])));
     }
 
+    #[pg_test]
+    fn aggregate_moving_mode() -> Result<(), spi::Error> {
+        let inverse = Spi::get_one::<bool>(
+            "SELECT aggminvtransfn <> 0 FROM pg_aggregate WHERE aggfnoid = 'demo_sum'::regproc",
+        )?;
+        assert_eq!(inverse, Some(true));
+
+        let retval = Spi::get_one::<Vec<i32>>(
+            "
+            SELECT array_agg(calculated ORDER BY n) FROM (
+                SELECT n, DemoMovingMax(value) OVER (
+                    ORDER BY n ROWS BETWEEN 2 PRECEDING AND CURRENT ROW
+                ) as calculated FROM UNNEST(ARRAY [5, 1, 3, 2, 9, 4, 1, 1]) WITH ORDINALITY as t(value, n)
+            ) as results;
+        ",
+        )?;
+        assert_eq!(retval, Some(vec![5, 5, 5, 3, 9, 9, 9, 4]));
+        Ok(())
+    }
+
     #[pg_test]
     fn aggregate_demo_unique() {
         let retval = Spi::get_one::<i32>(
@@ -221,6 +349,32 @@ mod tests {
         assert_eq!(retval, Ok(Some(2)));
     }
 
+    #[pg_test]
+    fn aggregate_parallel_serde_state() -> Result<(), spi::Error> {
+        let serial = Spi::get_one::<bool>(
+            "SELECT aggserialfn <> 0 AND aggdeserialfn <> 0 FROM pg_aggregate WHERE aggfnoid = 'DemoParallelUnique'::regproc",
+        )?;
+        assert_eq!(serial, Some(true));
+
+        Spi::run(
+            "CREATE TABLE parallel_unique AS SELECT x % 100 AS x FROM generate_series(1, 20000) x",
+        )?;
+        Spi::run("ANALYZE parallel_unique")?;
+        Spi::run("SET LOCAL parallel_setup_cost = 0")?;
+        Spi::run("SET LOCAL parallel_tuple_cost = 0")?;
+        Spi::run("SET LOCAL min_parallel_table_scan_size = 0")?;
+        Spi::run("SET LOCAL max_parallel_workers_per_gather = 2")?;
+
+        // partial aggregates hand their serialized states to the one that finalizes
+        let plan = Spi::explain("SELECT DemoParallelUnique(x) FROM parallel_unique")?;
+        assert!(plan.0.to_string().contains(r#""Partial Mode":"Partial""#), "{}", plan.0);
+        assert_eq!(
+            Spi::get_one::<i32>("SELECT DemoParallelUnique(x) FROM parallel_unique")?,
+            Some(100)
+        );
+        Ok(())
+    }
+
     #[pg_test]
     fn aggregate_demo_percentile_disc() {
         // Example from https://www.postgresql.org/docs/current/xaggr.html#XAGGR-ORDERED-SET-AGGREGATES
@@ -235,6 +389,20 @@ mod tests {
         assert_eq!(retval, Ok(Some(5)));
     }
 
+    #[pg_test]
+    fn aggregate_demo_hypothetical_rank() -> Result<(), spi::Error> {
+        let kind = Spi::get_one::<String>(
+            "SELECT aggkind::text FROM pg_aggregate WHERE aggfnoid = 'DemoHypotheticalRank'::regproc",
+        )?;
+        assert_eq!(kind.as_deref(), Some("h"));
+
+        let retval = Spi::get_one::<i64>(
+            "SELECT DemoHypotheticalRank(4000) WITHIN GROUP (ORDER BY income) FROM UNNEST(ARRAY [6000, 70000, 500]) as income;"
+        )?;
+        assert_eq!(retval, Some(2));
+        Ok(())
+    }
+
     #[pg_test]
     fn aggregate_demo_custom_state() {
         let retval = Spi::get_one::<i32>(
@@ -46,6 +46,28 @@ fn count_nulls(values: Array<i32>) -> i32 {
     values.iter().map(|v| v.is_none()).filter(|v| *v).count() as i32
 }
 
+#[pg_extern]
+fn concat_non_null(values: Array<&str>) -> String {
+    values.iter_non_null().collect()
+}
+
+#[pg_extern]
+fn array_passthrough(values: Array<i32>) -> Array<i32> {
+    values
+}
+
+#[pg_extern]
+fn variadic_array_passthrough<'a>(
+    values: VariadicArray<'a, &'a str>,
+) -> VariadicArray<'a, &'a str> {
+    values
+}
+
+#[pg_extern]
+fn double_non_null(values: Array<i32>) -> Array<i32> {
+    values.iter().map(|v| v.map(|v| v * 2)).collect()
+}
+
 #[pg_extern]
 fn optional_array_arg(values: Option<Array<f32>>) -> f32 {
     values.unwrap().iter().map(|v| v.unwrap_or(0f32)).sum()
@@ -149,6 +171,21 @@ fn arr_sort_uniq(arr: Array<i32>) -> Vec<i32> {
     v
 }
 
+#[pg_extern]
+fn sum_int_slice(values: &[i32]) -> i64 {
+    values.iter().map(|v| *v as i64).sum()
+}
+
+#[pg_extern]
+fn dot_product(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[pg_extern]
+fn bytea_popcount(bytes: &[u8]) -> i64 {
+    bytes.iter().map(|b| b.count_ones() as i64).sum()
+}
+
 #[derive(Debug, Eq, PartialEq, PostgresEnum, Serialize)]
 pub enum ArrayTestEnum {
     One,
@@ -438,6 +475,97 @@ mod tests {
         Ok(())
     }
 
+    #[pg_test]
+    fn test_slices_with_null() -> Result<(), Box<dyn std::error::Error>> {
+        let array = Spi::get_one::<Array<i32>>("SELECT ARRAY[NULL, 1, 2, NULL, 3]::integer[]")?
+            .expect("datum was null");
+        let (values, nulls) = array.as_slices();
+        assert_eq!(values, &[1, 2, 3]);
+        let nulls = nulls.expect("array has no null bitmap");
+        assert_eq!(
+            nulls.iter().map(|valid| *valid).collect::<Vec<_>>(),
+            [false, true, true, false, true]
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_slices_without_null() -> Result<(), Box<dyn std::error::Error>> {
+        let array =
+            Spi::get_one::<Array<i64>>("SELECT ARRAY[1, 2, 3]::bigint[]")?.expect("datum was null");
+        assert_eq!(array.as_slices(), (&[1i64, 2, 3][..], None));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_iter_non_null() -> Result<(), Box<dyn std::error::Error>> {
+        let concat = Spi::get_one::<String>(
+            "SELECT concat_non_null(ARRAY[NULL, 'a', 'bb', NULL, NULL, 'ccc', NULL]::text[])",
+        )?;
+        assert_eq!(concat.as_deref(), Some("abbccc"));
+
+        let array = Spi::get_one::<Array<i32>>("SELECT ARRAY[NULL, NULL]::integer[]")?
+            .expect("datum was null");
+        assert_eq!(array.iter_non_null().count(), 0);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_return_array() -> Result<(), Box<dyn std::error::Error>> {
+        let array = Spi::get_one::<Vec<Option<i32>>>(
+            "SELECT array_passthrough(ARRAY[1, NULL, 3]::integer[])",
+        )?;
+        assert_eq!(array, Some(vec![Some(1), None, Some(3)]));
+
+        let array = Spi::get_one::<Vec<Option<i32>>>(
+            "SELECT double_non_null(ARRAY[1, NULL, 3]::integer[])",
+        )?;
+        assert_eq!(array, Some(vec![Some(2), None, Some(6)]));
+
+        let array =
+            Spi::get_one::<Vec<String>>("SELECT variadic_array_passthrough('a', 'b', 'c')")?;
+        assert_eq!(array, Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_slice_arguments() -> Result<(), Box<dyn std::error::Error>> {
+        let sum = Spi::get_one::<i64>("SELECT sum_int_slice(ARRAY[1, 2, 3, 4]::int[])")?;
+        assert_eq!(sum, Some(10));
+
+        // multidimensional arrays are flattened
+        let sum = Spi::get_one::<i64>("SELECT sum_int_slice('{{1, 2}, {3, 4}}'::int[])")?;
+        assert_eq!(sum, Some(10));
+
+        let sum = Spi::get_one::<i64>("SELECT sum_int_slice('{}'::int[])")?;
+        assert_eq!(sum, Some(0));
+
+        let dot = Spi::get_one::<f64>(
+            "SELECT dot_product(ARRAY[1.0, 2.0, 3.0]::float8[], ARRAY[4.0, 5.0, 6.0]::float8[])",
+        )?;
+        assert_eq!(dot, Some(32.0));
+
+        let ones = Spi::get_one::<i64>("SELECT bytea_popcount('\\x0f0301'::bytea)")?;
+        assert_eq!(ones, Some(7));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_slice_argument_toasted() -> Result<(), Box<dyn std::error::Error>> {
+        Spi::run("CREATE TABLE slice_toasted (a int[])")?;
+        Spi::run(
+            "INSERT INTO slice_toasted SELECT array_agg(x) FROM generate_series(1, 100000) x",
+        )?;
+        let sum = Spi::get_one::<i64>("SELECT sum_int_slice(a) FROM slice_toasted")?;
+        assert_eq!(sum, Some(5000050000));
+        Ok(())
+    }
+
+    #[pg_test(error = "array contains NULL")]
+    fn test_slice_argument_with_null() -> Result<Option<i64>, pgrx::spi::Error> {
+        Spi::get_one::<i64>("SELECT sum_int_slice(ARRAY[1, NULL, 3]::int[])")
+    }
+
     #[pg_test]
     fn test_leak_after_drop() -> Result<(), Box<dyn std::error::Error>> {
         Spi::run("create table test_leak_after_drop (a text[]);")?;
@@ -0,0 +1,202 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{
+        Date32Type, Float64Type, Int32Type, Int64Type, TimestampMicrosecondType,
+    };
+    use arrow_schema::{DataType, TimeUnit};
+    use pgrx::arrow;
+    use pgrx::prelude::*;
+    use pgrx::PgRelation;
+
+    #[pg_extern]
+    fn arrow_roundtrip(
+        query: &str,
+        batch_size: i32,
+    ) -> SetOfIterator<'static, pgrx::composite_type!("record")> {
+        let batches = arrow::query_to_record_batches(query, batch_size as usize).unwrap();
+        SetOfIterator::new(
+            batches.into_iter().flat_map(|batch| arrow::record_batch_into_records(batch).unwrap()),
+        )
+    }
+
+    #[pg_test]
+    fn test_query_to_record_batch() {
+        let batches = arrow::query_to_record_batches(
+            "SELECT i, i::bigint * 10 AS big, i / 2.0::float8 AS half, 'row ' || i AS label,
+                    date '2000-01-01' + i AS day, timestamp '1970-01-01 00:00:01' AS ts,
+                    numeric '1.5' AS amount
+               FROM generate_series(1, 5) i",
+            100,
+        )
+        .unwrap();
+        assert_eq!(batches.len(), 1);
+
+        let batch = &batches[0];
+        let schema = batch.schema();
+        let types: Vec<_> = schema.fields().iter().map(|f| f.data_type().clone()).collect();
+        assert_eq!(
+            types,
+            vec![
+                DataType::Int32,
+                DataType::Int64,
+                DataType::Float64,
+                DataType::Utf8,
+                DataType::Date32,
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                DataType::Utf8,
+            ]
+        );
+        assert_eq!(schema.field(3).name(), "label");
+        assert_eq!(batch.num_rows(), 5);
+
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>().values(), &[1, 2, 3, 4, 5]);
+        assert_eq!(batch.column(1).as_primitive::<Int64Type>().value(4), 50);
+        assert_eq!(batch.column(2).as_primitive::<Float64Type>().value(2), 1.5);
+        assert_eq!(batch.column(3).as_string::<i32>().value(0), "row 1");
+        assert_eq!(batch.column(4).as_primitive::<Date32Type>().value(0), 10_958);
+        assert_eq!(batch.column(5).as_primitive::<TimestampMicrosecondType>().value(0), 1_000_000);
+        assert_eq!(batch.column(6).as_string::<i32>().value(0), "1.5");
+    }
+
+    #[pg_test]
+    fn test_query_to_record_batches_in_batches() {
+        let batches = arrow::query_to_record_batches(
+            "SELECT i, CASE WHEN i % 2 = 0 THEN NULL ELSE i END AS odd FROM generate_series(1, 10) i",
+            4,
+        )
+        .unwrap();
+        let sizes: Vec<_> = batches.iter().map(|batch| batch.num_rows()).collect();
+        assert_eq!(sizes, vec![4, 4, 2]);
+        assert_eq!(batches[0].column(1).null_count(), 2);
+        assert!(batches[0].column(1).is_null(1));
+    }
+
+    #[pg_test]
+    fn test_infinite_timestamp_is_an_error() {
+        let result = arrow::query_to_record_batches("SELECT 'infinity'::timestamptz", 10);
+        assert!(matches!(result, Err(arrow::ArrowQueryError::Arrow(_))));
+    }
+
+    #[pg_test]
+    fn test_record_batch_into_records() -> Result<(), pgrx::spi::Error> {
+        let rows = Spi::connect(|client| {
+            client
+                .select(
+                    "SELECT * FROM tests.arrow_roundtrip(
+                        $$SELECT i, 'row ' || i AS label, i % 2 = 0 AS even, timestamptz '2023-01-01 00:00:00+00' AS ts
+                            FROM generate_series(1, 5) i$$, 2)
+                        AS t(i int, label text, even bool, ts timestamptz)
+                      WHERE even",
+                    None,
+                    None,
+                )?
+                .map(|row| Ok((row.get::<i32>(1)?, row.get::<String>(2)?)))
+                .collect::<Result<Vec<_>, pgrx::spi::Error>>()
+        })?;
+        assert_eq!(
+            rows,
+            vec![(Some(2), Some("row 2".to_string())), (Some(4), Some("row 4".to_string()))]
+        );
+
+        let same = Spi::get_one::<bool>(
+            "SELECT ts = '2023-01-01 00:00:00+00' FROM tests.arrow_roundtrip(
+                $$SELECT timestamptz '2023-01-01 00:00:00+00' AS ts$$, 10) AS t(ts timestamptz)",
+        )?;
+        assert_eq!(same, Some(true));
+        Ok(())
+    }
+
+    fn create_copy_tables() -> Result<(), pgrx::spi::Error> {
+        Spi::run(
+            "CREATE TABLE tests.copy_source (id int NOT NULL, label text, amount numeric, seen date);
+             INSERT INTO tests.copy_source
+                  SELECT i, CASE WHEN i % 3 = 0 THEN NULL ELSE 'row ' || i END, i * 1.25, date '2023-01-01' + i
+                    FROM generate_series(1, 10000) i;
+             CREATE TABLE tests.copy_target (LIKE tests.copy_source);",
+        )
+    }
+
+    #[cfg(any(feature = "parquet", feature = "csv"))]
+    fn copied_rows_match() -> Result<Option<bool>, pgrx::spi::Error> {
+        Spi::get_one(
+            "SELECT count(*) = 10000 AND NOT EXISTS (
+                (SELECT * FROM tests.copy_source EXCEPT SELECT * FROM tests.copy_target)
+                UNION ALL
+                (SELECT * FROM tests.copy_target EXCEPT SELECT * FROM tests.copy_source))
+               FROM tests.copy_target",
+        )
+    }
+
+    fn open_relation(name: &str) -> PgRelation {
+        PgRelation::open_with_name_and_share_lock(name).unwrap()
+    }
+
+    #[cfg(feature = "parquet")]
+    #[pg_test]
+    fn test_parquet_roundtrip() -> Result<(), pgrx::spi::Error> {
+        create_copy_tables()?;
+        let path = std::env::temp_dir().join(format!("pgrx-copy-{}.parquet", std::process::id()));
+
+        let written = arrow::parquet::copy_relation_to_parquet_file(
+            &open_relation("tests.copy_source"),
+            &path,
+        )
+        .unwrap();
+        assert_eq!(written, 10000);
+
+        let inserted = arrow::parquet::copy_parquet_file_to_relation(
+            &path,
+            &open_relation("tests.copy_target"),
+        )
+        .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(inserted, 10000);
+        assert_eq!(copied_rows_match()?, Some(true));
+        Ok(())
+    }
+
+    #[cfg(feature = "csv")]
+    #[pg_test]
+    fn test_csv_roundtrip() -> Result<(), pgrx::spi::Error> {
+        create_copy_tables()?;
+        let mut csv = Vec::new();
+        let written =
+            arrow::csv::copy_relation_to_csv(&open_relation("tests.copy_source"), &mut csv)
+                .unwrap();
+        assert_eq!(written, 10000);
+        assert!(csv.starts_with(b"id,label,amount,seen\n1,row 1,1.25,2023-01-02\n"));
+
+        let inserted =
+            arrow::csv::copy_csv_to_relation(csv.as_slice(), &open_relation("tests.copy_target"))
+                .unwrap();
+        assert_eq!(inserted, 10000);
+        assert_eq!(copied_rows_match()?, Some(true));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_insert_unknown_column() -> Result<(), pgrx::spi::Error> {
+        create_copy_tables()?;
+        let batches = arrow::query_to_record_batches("SELECT 1 AS nope", 10).unwrap();
+        let result = arrow::insert_record_batches(
+            &open_relation("tests.copy_target"),
+            batches.into_iter().map(Ok::<_, arrow::CopyError>),
+        );
+        assert!(matches!(result, Err(arrow::CopyError::Arrow(_))));
+        Ok(())
+    }
+}
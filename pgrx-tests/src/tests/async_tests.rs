@@ -0,0 +1,71 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::async_support::{self, tokio};
+    use pgrx::prelude::*;
+    use std::time::Duration;
+
+    #[pg_extern(blocking_on = pgrx::async_support::block_on)]
+    async fn async_add(a: i32, b: i32) -> i32 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        a + b
+    }
+
+    #[pg_extern(blocking_on = async_support::block_on)]
+    async fn async_shout(text: &str) -> Option<String> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let len = text.len();
+        tokio::spawn(async move { tx.send(len).unwrap() });
+        let len = rx.await.ok()?;
+        Some(format!("{}{}", text.to_uppercase(), "!".repeat(len)))
+    }
+
+    #[pg_test]
+    fn test_async_pg_extern() -> Result<(), pgrx::spi::Error> {
+        assert_eq!(Spi::get_one::<i32>("SELECT tests.async_add(1, 2)")?, Some(3));
+        assert_eq!(
+            Spi::get_one::<String>("SELECT tests.async_shout('hey')")?,
+            Some("HEY!!!".to_string())
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_block_on_runs_timers() {
+        let started = std::time::Instant::now();
+        let slept = async_support::block_on(async {
+            tokio::time::sleep(Duration::from_millis(120)).await;
+            "done"
+        });
+        assert_eq!(slept, "done");
+        assert!(started.elapsed() >= Duration::from_millis(120));
+    }
+
+    #[pg_test(error = "canceling statement due to user request")]
+    fn test_block_on_is_cancellable() {
+        // as if pg_cancel_backend() had been called on us
+        #[cfg(feature = "pg11")]
+        unsafe {
+            pg_sys::QueryCancelPending = true;
+            pg_sys::InterruptPending = true;
+        }
+        #[cfg(not(feature = "pg11"))]
+        unsafe {
+            pg_sys::QueryCancelPending = 1;
+            pg_sys::InterruptPending = 1;
+        }
+        async_support::block_on(std::future::pending::<()>());
+    }
+}
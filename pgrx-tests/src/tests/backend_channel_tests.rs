@@ -0,0 +1,86 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::backend_channel::{BackendChannel, RecvTimeoutError};
+    use pgrx::prelude::*;
+    use std::time::Duration;
+
+    #[pg_test]
+    fn test_backend_channel_collects_from_threads() {
+        let channel = BackendChannel::new();
+        for i in 0..8i64 {
+            let sender = channel.sender();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(10 * i as u64));
+                sender.send(i * i).unwrap();
+            });
+        }
+
+        let mut squares = channel.iter().collect::<Vec<_>>();
+        squares.sort();
+        assert_eq!(squares, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+
+    #[pg_test]
+    fn test_backend_channel_results_usable_with_spi() -> Result<(), pgrx::spi::Error> {
+        let channel = BackendChannel::new();
+        let sender = channel.sender();
+        std::thread::spawn(move || sender.send("from a thread".to_string()).unwrap());
+
+        let text = channel.recv().unwrap();
+        let upper = Spi::get_one_with_args::<String>(
+            "SELECT upper($1)",
+            vec![(PgBuiltInOids::TEXTOID.oid(), text.into_datum())],
+        )?;
+        assert_eq!(upper, Some("FROM A THREAD".to_string()));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_backend_channel_timeout_and_disconnect() {
+        let channel = BackendChannel::<i32>::new();
+        let sender = channel.sender();
+        assert_eq!(channel.try_recv(), None);
+        assert_eq!(channel.recv_timeout(Duration::from_millis(50)), Err(RecvTimeoutError::Timeout));
+
+        sender.send(1).unwrap();
+        drop(sender);
+        assert_eq!(channel.recv_timeout(Duration::from_millis(50)), Ok(1));
+        assert_eq!(
+            channel.recv_timeout(Duration::from_millis(50)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+        assert_eq!(channel.recv(), None);
+    }
+
+    #[pg_test(error = "canceling statement due to user request")]
+    fn test_backend_channel_is_cancellable() {
+        let channel = BackendChannel::<i32>::new();
+        let _sender = channel.sender();
+
+        // as if pg_cancel_backend() had been called on us
+        #[cfg(feature = "pg11")]
+        unsafe {
+            pg_sys::QueryCancelPending = true;
+            pg_sys::InterruptPending = true;
+        }
+        #[cfg(not(feature = "pg11"))]
+        unsafe {
+            pg_sys::QueryCancelPending = 1;
+            pg_sys::InterruptPending = 1;
+        }
+        channel.recv();
+    }
+}
@@ -86,6 +86,104 @@ pub extern "C" fn bgworker_return_value(arg: pg_sys::Datum) {
     .expect("bgworker transaction failed");
 }
 
+#[pg_guard]
+#[no_mangle]
+/// Records every reload, and its shutdown, in `tests.bgworker_reload_log`
+pub extern "C" fn bgworker_reload_shutdown(_arg: pg_sys::Datum) {
+    use pgrx::bgworkers::*;
+    use std::time::Duration;
+    BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+    BackgroundWorker::connect_worker_to_spi(
+        Some(crate::framework::get_pg_dbname()),
+        Some(crate::framework::get_pg_user().as_str()),
+    );
+
+    BackgroundWorker::on_reload(|| {
+        BackgroundWorker::transaction(|| {
+            Spi::run("INSERT INTO tests.bgworker_reload_log VALUES ('reload')")
+        })
+        .expect("bgworker transaction failed")
+    });
+    BackgroundWorker::on_shutdown(|| {
+        BackgroundWorker::transaction(|| {
+            Spi::run("INSERT INTO tests.bgworker_reload_log VALUES ('shutdown')")
+        })
+        .expect("bgworker transaction failed")
+    });
+    BackgroundWorker::transaction(|| {
+        Spi::run("CREATE TABLE tests.bgworker_reload_log (event text)")
+    })
+    .expect("bgworker transaction failed");
+
+    while BackgroundWorker::wait_latch(Some(Duration::from_millis(100))) {}
+    BackgroundWorker::shutdown();
+}
+
+#[pg_guard]
+#[no_mangle]
+/// Never finishes shutting down on its own
+pub extern "C" fn bgworker_shutdown_timeout(_arg: pg_sys::Datum) {
+    use pgrx::bgworkers::*;
+    use std::time::Duration;
+    BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+
+    BackgroundWorker::set_shutdown_timeout(Duration::from_millis(100));
+    BackgroundWorker::on_shutdown(|| loop {
+        std::thread::sleep(Duration::from_millis(10));
+        pg_sys::check_for_interrupts!();
+    });
+
+    while BackgroundWorker::wait_latch(Some(Duration::from_millis(100))) {}
+    BackgroundWorker::shutdown();
+}
+
+#[pg_guard]
+#[no_mangle]
+/// Supervises `bgworker_supervised` in every database
+pub extern "C" fn bgworker_supervisor(_arg: pg_sys::Datum) {
+    use pgrx::bgworkers::*;
+    use std::time::Duration;
+    BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+    BackgroundWorker::connect_worker_to_spi(
+        Some(crate::framework::get_pg_dbname()),
+        Some(crate::framework::get_pg_user().as_str()),
+    );
+    BackgroundWorker::transaction(|| Spi::run("CREATE TABLE tests.bgworker_supervised (pid int)"))
+        .expect("bgworker transaction failed");
+
+    DatabaseSupervisor::new("pgrx_tests supervised", "pgrx_tests", "bgworker_supervised")
+        .poll_interval(Duration::from_millis(100))
+        .restart_delay(Duration::from_millis(10))
+        .run()
+        .expect("supervisor failed");
+}
+
+#[pg_guard]
+#[no_mangle]
+/// In the test database, exits the first time it's started, to be restarted
+pub extern "C" fn bgworker_supervised(_arg: pg_sys::Datum) {
+    use pgrx::bgworkers::*;
+    use std::time::Duration;
+    BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+    let database = BackgroundWorker::get_extra();
+    BackgroundWorker::connect_worker_to_spi(
+        Some(database),
+        Some(crate::framework::get_pg_user().as_str()),
+    );
+
+    if database == crate::framework::get_pg_dbname() {
+        let started = BackgroundWorker::transaction(|| {
+            Spi::run("INSERT INTO tests.bgworker_supervised VALUES (pg_backend_pid())")?;
+            Spi::get_one::<i64>("SELECT count(*) FROM tests.bgworker_supervised")
+        })
+        .expect("bgworker transaction failed");
+        if started < Some(2) {
+            return;
+        }
+    }
+    while BackgroundWorker::wait_latch(Some(Duration::from_millis(100))) {}
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pgrx::pg_schema]
 mod tests {
@@ -159,4 +257,72 @@ mod tests {
 
         assert_eq!(Ok(Some(123)), Spi::get_one::<i32>("SELECT v FROM tests.bgworker_test_return;"));
     }
+
+    /// Poll `query` until it returns `true`, for up to 10 seconds
+    fn wait_until(query: &str) -> bool {
+        for _ in 0..1000 {
+            if Spi::get_one::<bool>(query) == Ok(Some(true)) {
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[pg_test]
+    fn test_background_worker_reload_and_shutdown() {
+        let worker = BackgroundWorkerBuilder::new("dynamic_bgworker")
+            .set_library("pgrx_tests")
+            .set_function("bgworker_reload_shutdown")
+            .enable_spi_access()
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        worker.wait_for_startup().expect("no PID from the worker");
+        assert!(wait_until("SELECT to_regclass('tests.bgworker_reload_log') IS NOT NULL"));
+
+        Spi::run("SELECT pg_reload_conf()").unwrap();
+        assert!(wait_until("SELECT EXISTS (SELECT 1 FROM tests.bgworker_reload_log)"));
+
+        worker.terminate().wait_for_shutdown().expect("aborted shutdown");
+        assert_eq!(
+            Ok(Some(vec![Some("reload".to_string()), Some("shutdown".to_string())])),
+            Spi::get_one::<Vec<Option<String>>>(
+                "SELECT array_agg(DISTINCT event ORDER BY event) FROM tests.bgworker_reload_log"
+            )
+        );
+    }
+
+    #[pg_test]
+    fn test_background_worker_shutdown_timeout() {
+        let worker = BackgroundWorkerBuilder::new("dynamic_bgworker")
+            .set_library("pgrx_tests")
+            .set_function("bgworker_shutdown_timeout")
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        worker.wait_for_startup().expect("no PID from the worker");
+        worker.terminate().wait_for_shutdown().expect("aborted shutdown");
+    }
+
+    #[pg_test]
+    fn test_database_supervisor() {
+        let worker = BackgroundWorkerBuilder::new("dynamic_bgworker")
+            .set_library("pgrx_tests")
+            .set_function("bgworker_supervisor")
+            .enable_spi_access()
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        worker.wait_for_startup().expect("no PID from the worker");
+
+        assert!(wait_until("SELECT to_regclass('tests.bgworker_supervised') IS NOT NULL"));
+
+        // the first worker in this database exits, and the supervisor starts another
+        assert!(wait_until("SELECT count(DISTINCT pid) >= 2 FROM tests.bgworker_supervised"));
+        worker.terminate().wait_for_shutdown().expect("aborted shutdown");
+        assert_eq!(
+            Ok(Some(0)),
+            Spi::get_one::<i64>(
+                "SELECT count(*) FROM pg_stat_activity WHERE backend_type = 'pgrx_tests supervised'"
+            )
+        );
+    }
 }
@@ -0,0 +1,74 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use pgrx::BorrowedText;
+
+#[pg_extern]
+fn borrowed_text_trim(s: BorrowedText<'_>) -> BorrowedText<'_> {
+    s.substr(s.as_str().trim_matches(' '))
+}
+
+#[pg_extern]
+fn borrowed_text_trim_is_original(s: BorrowedText<'_>) -> bool {
+    s.substr(s.as_str().trim_matches(' ')).is_original()
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::BorrowedText;
+
+    #[pg_test]
+    fn test_borrowed_text_pass_through() -> Result<(), pgrx::spi::Error> {
+        assert_eq!(Spi::get_one::<&str>("SELECT borrowed_text_trim('abc')")?, Some("abc"));
+        assert_eq!(
+            Spi::get_one::<bool>("SELECT borrowed_text_trim_is_original('abc')")?,
+            Some(true)
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_borrowed_text_substring_is_copied() -> Result<(), pgrx::spi::Error> {
+        assert_eq!(Spi::get_one::<&str>("SELECT borrowed_text_trim('  abc ')")?, Some("abc"));
+        assert_eq!(
+            Spi::get_one::<bool>("SELECT borrowed_text_trim_is_original('  abc ')")?,
+            Some(false)
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_borrowed_text_toasted() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE TABLE borrowed_text_toasted (t text)")?;
+        Spi::run("INSERT INTO borrowed_text_toasted SELECT repeat('pgrx', 100000)")?;
+        let same =
+            Spi::get_one::<bool>("SELECT borrowed_text_trim(t) = t FROM borrowed_text_toasted")?;
+        assert_eq!(same, Some(true));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_borrowed_text_from_str() {
+        let text = BorrowedText::from("hello");
+        assert_eq!(text, "hello");
+        assert!(!text.is_original());
+    }
+
+    #[pg_test(error = "`part` is not a substring of this text")]
+    fn test_borrowed_text_substr_unrelated() {
+        let text = BorrowedText::from("hello");
+        text.substr("world");
+    }
+}
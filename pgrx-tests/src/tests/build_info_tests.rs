@@ -0,0 +1,44 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+pgrx::pg_build_info!();
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+    use pgrx::JsonB;
+
+    #[pg_test]
+    fn test_version_function() -> Result<(), spi::Error> {
+        let version = Spi::get_one::<String>("SELECT public.pgrx_tests_version()")?;
+        assert_eq!(version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_build_info_function() -> Result<(), spi::Error> {
+        let JsonB(info) = Spi::get_one::<JsonB>("SELECT public.pgrx_tests_build_info()")?.unwrap();
+        assert_eq!(info["name"], "pgrx-tests");
+        assert_eq!(info["pgrx_version"], pgrx::build_info::PGRX_VERSION);
+        assert_eq!(info["pg_version_num"], pg_sys::PG_VERSION_NUM);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_build_info_macro() {
+        let info = pgrx::build_info!();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.features, option_env!("PGRX_BUILD_FEATURES"));
+        assert_eq!(info.to_json()["git_hash"].as_str(), info.git_hash);
+    }
+}
@@ -0,0 +1,105 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::builtins;
+    use pgrx::prelude::*;
+    use pgrx::AnyNumeric;
+
+    #[pg_test]
+    fn test_text_builtins() {
+        assert_eq!(builtins::textcat("pg", "rx"), "pgrx");
+        assert_eq!(builtins::upper("pgrx"), "PGRX");
+        assert_eq!(builtins::lower("PGRX"), "pgrx");
+        assert_eq!(builtins::initcap("hello world"), "Hello World");
+        assert_eq!(builtins::btrim("  pgrx ", " "), "pgrx");
+        assert_eq!(builtins::replace_text("a-b-c", "-", "+"), "a+b+c");
+        assert_eq!(builtins::text_left("pgrx", 2), "pg");
+        assert_eq!(builtins::text_right("pgrx", -1), "grx");
+        assert_eq!(builtins::text_reverse("pgrx"), "xrgp");
+        assert_eq!(
+            builtins::md5_text("pgrx"),
+            Spi::get_one::<String>("SELECT md5('pgrx')").unwrap().unwrap()
+        );
+    }
+
+    #[pg_test]
+    fn test_numeric_builtins() {
+        let a = AnyNumeric::try_from(1.25f64).unwrap();
+        let b = AnyNumeric::from(2);
+        assert_eq!(
+            builtins::numeric_add(a.clone(), b.clone()),
+            AnyNumeric::try_from(3.25f64).unwrap()
+        );
+        assert_eq!(
+            builtins::numeric_sub(a.clone(), b.clone()),
+            AnyNumeric::try_from(-0.75f64).unwrap()
+        );
+        assert_eq!(
+            builtins::numeric_mul(a.clone(), b.clone()),
+            AnyNumeric::try_from(2.5f64).unwrap()
+        );
+        assert_eq!(builtins::numeric_div(a.clone(), b), AnyNumeric::try_from(0.625f64).unwrap());
+        assert_eq!(builtins::numeric_round(a, 1), AnyNumeric::try_from(1.3f64).unwrap());
+    }
+
+    #[pg_test]
+    #[should_panic(expected = "division by zero")]
+    fn test_numeric_div_by_zero() {
+        builtins::numeric_div(AnyNumeric::from(1), AnyNumeric::from(0));
+    }
+
+    #[pg_test]
+    fn test_datetime_builtins() -> Result<(), Box<dyn std::error::Error>> {
+        let day = Interval::new(0, 1, 0)?;
+        let hour = Interval::new(0, 0, 3_600_000_000)?;
+
+        let ts = TimestampWithTimeZone::new(2023, 3, 25, 12, 0, 0.0)?;
+        assert_eq!(
+            builtins::timestamptz_pl_interval(ts, day),
+            TimestampWithTimeZone::new(2023, 3, 26, 12, 0, 0.0)?
+        );
+        assert_eq!(
+            builtins::timestamptz_mi_interval(ts, hour),
+            TimestampWithTimeZone::new(2023, 3, 25, 11, 0, 0.0)?
+        );
+
+        let ts = Timestamp::new(2023, 1, 31, 0, 0, 0.0)?;
+        assert_eq!(
+            builtins::timestamp_pl_interval(ts, Interval::new(1, 0, 0)?),
+            Timestamp::new(2023, 2, 28, 0, 0, 0.0)?
+        );
+
+        assert_eq!(builtins::interval_pl(day, hour), Interval::new(0, 1, 3_600_000_000)?);
+        assert_eq!(builtins::interval_mi(day, day), Interval::new(0, 0, 0)?);
+        assert_eq!(builtins::date_pli(Date::new(2024, 2, 28)?, 2), Date::new(2024, 3, 1)?);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_array_builtins() {
+        assert_eq!(
+            builtins::array_append(vec![Some(1), Some(2)], Some(3)),
+            vec![Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(builtins::array_append(Vec::<Option<i64>>::new(), None), vec![None]);
+        assert_eq!(
+            builtins::array_prepend(Some("a".to_string()), vec![Some("b".to_string())]),
+            vec![Some("a".to_string()), Some("b".to_string())]
+        );
+        assert_eq!(
+            builtins::array_cat(vec![Some(1.5f64)], vec![None, Some(2.5)]),
+            vec![Some(1.5), None, Some(2.5)]
+        );
+    }
+}
@@ -14,6 +14,8 @@ mod tests {
     use crate as pgrx_tests;
 
     use pgrx::prelude::*;
+    use pgrx::{ByteaReader, ByteaWriter};
+    use std::io::{Read, Seek, SeekFrom, Write};
 
     #[pg_extern]
     fn return_bytes() -> &'static [u8] {
@@ -58,4 +60,71 @@ mod tests {
         let vec = Spi::get_one::<Vec<u8>>("SELECT tests.return_vec_subvec('abcdefg'::bytea);");
         assert_eq!(vec, Ok(Some(vec![b'b', b'c', b'd'])));
     }
+
+    #[pg_extern]
+    fn bytea_reader_sum(mut bytes: ByteaReader) -> i64 {
+        let mut buf = [0u8; 1000];
+        let mut sum = 0;
+        loop {
+            let n = bytes.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            sum += buf[..n].iter().map(|b| *b as i64).sum::<i64>();
+        }
+        sum
+    }
+
+    #[pg_extern]
+    fn bytea_reader_tail(mut bytes: ByteaReader, n: i64) -> Vec<u8> {
+        bytes.seek(SeekFrom::End(-n)).unwrap();
+        let mut tail = Vec::new();
+        bytes.read_to_end(&mut tail).unwrap();
+        tail
+    }
+
+    #[pg_extern]
+    fn bytea_writer_repeat(bytes: &[u8], times: i32) -> ByteaWriter {
+        let mut writer = ByteaWriter::with_capacity(bytes.len() * times as usize);
+        for _ in 0..times {
+            writer.write_all(bytes).unwrap();
+        }
+        writer
+    }
+
+    #[pg_test]
+    fn test_bytea_reader_inline() {
+        let sum = Spi::get_one::<i64>("SELECT tests.bytea_reader_sum('\\x010203'::bytea);");
+        assert_eq!(sum, Ok(Some(6)));
+    }
+
+    #[pg_test]
+    fn test_bytea_writer() {
+        let bytes = Spi::get_one::<Vec<u8>>("SELECT tests.bytea_writer_repeat('ab'::bytea, 3);");
+        assert_eq!(bytes, Ok(Some(b"ababab".to_vec())));
+
+        let empty = Spi::get_one::<Vec<u8>>("SELECT tests.bytea_writer_repeat('ab'::bytea, 0);");
+        assert_eq!(empty, Ok(Some(vec![])));
+    }
+
+    #[pg_test]
+    fn test_bytea_reader_external() -> Result<(), spi::Error> {
+        // STORAGE EXTERNAL keeps the value uncompressed in the TOAST table, so it's read in slices
+        Spi::run(
+            "CREATE TABLE bytea_reader_test (b bytea);
+             ALTER TABLE bytea_reader_test ALTER COLUMN b SET STORAGE EXTERNAL;
+             INSERT INTO bytea_reader_test
+                SELECT string_agg(set_byte('\\x00'::bytea, 0, i % 256), '')
+                FROM generate_series(0, 199999) i;",
+        )?;
+
+        let expected: i64 = (0..200000i64).map(|i| i % 256).sum();
+        let sum = Spi::get_one::<i64>("SELECT tests.bytea_reader_sum(b) FROM bytea_reader_test")?;
+        assert_eq!(sum, Some(expected));
+
+        let tail =
+            Spi::get_one::<Vec<u8>>("SELECT tests.bytea_reader_tail(b, 3) FROM bytea_reader_test")?;
+        assert_eq!(tail, Some(vec![253, 254, 255]));
+        Ok(())
+    }
 }
@@ -0,0 +1,108 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::{AuthenticationStatus, ClientAuthenticationHook, ClientInfo, ConnectionRejected};
+
+/// Rejects clients that call themselves `pgrx_tests_rejected`.  Installed by the `#[pg_init]`
+/// function in `shmem_tests`
+pub struct RejectByName;
+
+impl ClientAuthenticationHook for RejectByName {
+    fn authenticate(
+        &mut self,
+        client: &ClientInfo,
+        status: AuthenticationStatus,
+    ) -> Result<(), ConnectionRejected> {
+        let rejected = client
+            .startup_options
+            .iter()
+            .any(|(name, value)| name == "application_name" && value == "pgrx_tests_rejected");
+        if rejected && status == AuthenticationStatus::Ok {
+            Err(ConnectionRejected::new("pgrx_tests_rejected is not welcome here")
+                .with_hint("Try another application_name."))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub static mut REJECT_BY_NAME: RejectByName = RejectByName;
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+    use std::net::IpAddr;
+
+    #[pg_test]
+    fn test_client_info() -> Result<(), pgrx::spi::Error> {
+        let client = pgrx::client_info().expect("no client is connected");
+
+        let addr = Spi::get_one::<String>("SELECT host(inet_client_addr())")?;
+        assert_eq!(client.remote_addr, addr.map(|addr| addr.parse::<IpAddr>().unwrap()));
+        let port = Spi::get_one::<i32>("SELECT inet_client_port()")?;
+        assert_eq!(client.remote_port.map(i32::from), port);
+        if client.remote_addr.is_none() {
+            assert_eq!(client.remote_host, "[local]");
+        }
+
+        assert_eq!(client.user_name, Spi::get_one::<String>("SELECT session_user::text")?);
+        assert_eq!(
+            client.database_name,
+            Spi::get_one::<String>("SELECT current_database()::text")?
+        );
+        assert_eq!(client.protocol_version, (3, 0));
+        assert!(client
+            .startup_options
+            .contains(&("client_encoding".to_string(), "UTF8".to_string())));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_client_ssl_info() -> Result<(), pgrx::spi::Error> {
+        let client = pgrx::client_info().expect("no client is connected");
+        let ssl = Spi::get_one::<bool>("SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()")?;
+        assert_eq!(client.ssl.is_some(), ssl.unwrap());
+        if let Some(ssl) = client.ssl {
+            let version = Spi::get_one::<String>(
+                "SELECT version FROM pg_stat_ssl WHERE pid = pg_backend_pid()",
+            )?;
+            assert_eq!(Some(ssl.version), version);
+        }
+        Ok(())
+    }
+
+    fn connect(application_name: &str) -> Result<postgres::Client, postgres::Error> {
+        let port = Spi::get_one::<String>("SELECT current_setting('port')").unwrap().unwrap();
+        let user = Spi::get_one::<String>("SELECT session_user::text").unwrap().unwrap();
+        let dbname = Spi::get_one::<String>("SELECT current_database()::text").unwrap().unwrap();
+        postgres::Config::new()
+            .host("localhost")
+            .port(port.parse().unwrap())
+            .user(&user)
+            .dbname(&dbname)
+            .application_name(application_name)
+            .connect(postgres::NoTls)
+    }
+
+    #[pg_test]
+    fn test_client_authentication_hook() {
+        let mut client = connect("pgrx_tests_accepted").expect("connection should be accepted");
+        let row = client.query_one("SELECT 1", &[]).unwrap();
+        assert_eq!(row.get::<_, i32>(0), 1);
+
+        let error = connect("pgrx_tests_rejected").err().expect("connection should be rejected");
+        let error = error.as_db_error().expect("rejection should be a database error");
+        assert_eq!(error.message(), "pgrx_tests_rejected is not welcome here");
+        assert_eq!(error.hint(), Some("Try another application_name."));
+        assert_eq!(error.code(), &postgres::error::SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+    }
+}
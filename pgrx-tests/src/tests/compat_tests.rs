@@ -0,0 +1,76 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::compat;
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_table_open() {
+        unsafe {
+            let lockmode = pg_sys::AccessShareLock as pg_sys::LOCKMODE;
+            let rel = compat::table_open(pg_sys::RelationRelationId, lockmode);
+            assert_eq!((*rel).rd_id, pg_sys::RelationRelationId);
+            compat::table_close(rel, lockmode);
+        }
+    }
+
+    #[pg_test]
+    fn test_create_template_tuple_desc() {
+        let tupdesc = compat::create_template_tuple_desc(3);
+        assert_eq!(unsafe { (*tupdesc).natts }, 3);
+    }
+
+    #[pg_test]
+    fn test_string_nodes() {
+        let node = compat::make_string("hello");
+        assert_eq!(unsafe { compat::str_val(node) }.to_str(), Ok("hello"));
+    }
+
+    #[pg_test]
+    fn test_get_sys_cache_oid() -> Result<(), pgrx::spi::Error> {
+        let name = std::ffi::CString::new("pg_catalog").unwrap();
+        let oid = compat::get_sys_cache_oid(
+            pg_sys::SysCacheIdentifier_NAMESPACENAME,
+            1, // Anum_pg_namespace_oid
+            [name.as_c_str().into_datum().unwrap(), 0.into(), 0.into(), 0.into()],
+        );
+        assert_eq!(Some(oid), Spi::get_one("SELECT 'pg_catalog'::regnamespace::oid")?);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_object_aclcheck_and_ownercheck() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE ROLE compat_nobody")?;
+        let nobody = Spi::get_one::<pg_sys::Oid>("SELECT 'compat_nobody'::regrole::oid")?.unwrap();
+        let superuser = unsafe { pg_sys::GetUserId() };
+        let mode = pg_sys::ACL_SELECT as pg_sys::AclMode;
+
+        let table = Spi::get_one::<pg_sys::Oid>("SELECT 'pg_class'::regclass::oid")?.unwrap();
+        assert_eq!(
+            compat::object_aclcheck(pg_sys::RelationRelationId, table, nobody, mode),
+            pg_sys::AclResult_ACLCHECK_OK
+        );
+        Spi::run("CREATE TABLE compat_private ()")?;
+        Spi::run("REVOKE ALL ON compat_private FROM PUBLIC")?;
+        let table = Spi::get_one::<pg_sys::Oid>("SELECT 'compat_private'::regclass::oid")?.unwrap();
+        assert_eq!(
+            compat::object_aclcheck(pg_sys::RelationRelationId, table, nobody, mode),
+            pg_sys::AclResult_ACLCHECK_NO_PRIV
+        );
+
+        assert!(compat::object_ownercheck(pg_sys::RelationRelationId, table, superuser));
+        assert!(!compat::object_ownercheck(pg_sys::RelationRelationId, table, nobody));
+        Ok(())
+    }
+}
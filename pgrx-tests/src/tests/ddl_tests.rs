@@ -0,0 +1,121 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::ddl::{Column, CreateIndexBuilder, CreateTableBuilder};
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_create_table_sql() {
+        let sql = CreateTableBuilder::new("my table")
+            .schema("tests")
+            .if_not_exists()
+            .column(Column::new::<i64>("id").primary_key())
+            .column(Column::new::<String>("kind").not_null().default_literal("it's"))
+            .column(
+                Column::with_type("code", pg_sys::VARCHAROID).typmod(10 + pg_sys::VARHDRSZ as i32),
+            )
+            .column(Column::new::<TimestampWithTimeZone>("at").default_expression("now()"))
+            .unique(&["kind", "code"])
+            .check(Some("kind not empty"), "kind <> ''")
+            .to_sql();
+        assert_eq!(
+            sql,
+            "CREATE TABLE IF NOT EXISTS tests.\"my table\" (id bigint PRIMARY KEY, \
+             kind text NOT NULL DEFAULT 'it''s', code character varying(10), \
+             at timestamp with time zone DEFAULT now(), UNIQUE (kind, code), \
+             CONSTRAINT \"kind not empty\" CHECK (kind <> ''))"
+        );
+    }
+
+    #[pg_test]
+    fn test_create_index_sql() {
+        let sql = CreateIndexBuilder::new("Events")
+            .schema("tests")
+            .name("events_kind_idx")
+            .unique()
+            .if_not_exists()
+            .using("btree")
+            .column("kind")
+            .expression("lower(name)")
+            .include("at")
+            .predicate("deleted IS NULL")
+            .to_sql();
+        assert_eq!(
+            sql,
+            "CREATE UNIQUE INDEX IF NOT EXISTS events_kind_idx ON tests.\"Events\" USING btree \
+             (kind, (lower(name))) INCLUDE (at) WHERE deleted IS NULL"
+        );
+    }
+
+    #[pg_test]
+    fn test_create_tables_and_index() -> Result<(), pgrx::spi::Error> {
+        CreateTableBuilder::new("owners")
+            .schema("tests")
+            .column(Column::new::<i32>("id").primary_key())
+            .column(Column::new::<String>("name; DROP TABLE pg_class").not_null())
+            .execute()?;
+        CreateTableBuilder::new("pets")
+            .schema("tests")
+            .column(Column::new::<i32>("owner").not_null())
+            .column(Column::new::<String>("name").not_null())
+            .column(Column::new::<i32>("legs").default_literal(4))
+            .primary_key(&["owner", "name"])
+            .foreign_key(&["owner"], Some("tests"), "owners", &["id"])
+            .check(None, "legs >= 0")
+            .execute()?;
+        CreateIndexBuilder::new("pets")
+            .schema("tests")
+            .name("pets_legs")
+            .column("legs")
+            .execute()?;
+        // again, which does nothing
+        CreateIndexBuilder::new("pets")
+            .schema("tests")
+            .name("pets_legs")
+            .if_not_exists()
+            .column("legs")
+            .execute()?;
+
+        Spi::run(
+            "INSERT INTO tests.owners VALUES (1, 'Alice');
+             INSERT INTO tests.pets (owner, name) VALUES (1, 'Rex');",
+        )?;
+        assert_eq!(Spi::get_one::<i32>("SELECT legs FROM tests.pets")?, Some(4));
+        assert_eq!(
+            Spi::get_one::<bool>("SELECT to_regclass('tests.pets_legs') IS NOT NULL")?,
+            Some(true)
+        );
+        assert_eq!(
+            Spi::get_one::<String>("SELECT \"name; DROP TABLE pg_class\" FROM tests.owners")?,
+            Some("Alice".to_string())
+        );
+        Ok(())
+    }
+
+    #[pg_test(
+        error = "insert or update on table \"pets\" violates foreign key constraint \"pets_owner_fkey\""
+    )]
+    fn test_foreign_key_is_enforced() -> Result<(), pgrx::spi::Error> {
+        CreateTableBuilder::new("owners")
+            .schema("tests")
+            .column(Column::new::<i32>("id").primary_key())
+            .execute()?;
+        CreateTableBuilder::new("pets")
+            .schema("tests")
+            .column(Column::new::<i32>("owner").references(Some("tests"), "owners", "id"))
+            .execute()?;
+        Spi::run("INSERT INTO tests.pets VALUES (1)")
+    }
+}
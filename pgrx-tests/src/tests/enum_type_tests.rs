@@ -23,6 +23,18 @@ fn take_foo_enum(value: Foo) -> Foo {
     Foo::Three
 }
 
+#[pg_extern]
+fn rotate_foo_enums(values: Vec<Foo>) -> Vec<Foo> {
+    values
+        .into_iter()
+        .map(|value| match value {
+            Foo::One => Foo::Two,
+            Foo::Two => Foo::Three,
+            Foo::Three => Foo::One,
+        })
+        .collect()
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pgrx::pg_schema]
 mod tests {
@@ -40,4 +52,43 @@ mod tests {
         let result = Spi::get_one::<Foo>("SELECT take_foo_enum('One');");
         assert_eq!(Ok(Some(Foo::Three)), result);
     }
+
+    #[pg_test]
+    fn test_foo_enum_array() {
+        let result = Spi::get_one::<Vec<Foo>>(
+            "SELECT rotate_foo_enums(ARRAY['One', 'Three', 'One']::Foo[]);",
+        );
+        assert_eq!(Ok(Some(vec![Foo::Two, Foo::One, Foo::Two])), result);
+
+        let result = Spi::get_one_with_args::<Vec<Option<Foo>>>(
+            "SELECT $1",
+            vec![(
+                PgOid::from(Vec::<Option<Foo>>::type_oid()),
+                vec![Some(Foo::Two), None].into_datum(),
+            )],
+        );
+        assert_eq!(Ok(Some(vec![Some(Foo::Two), None])), result);
+    }
+
+    #[pg_test]
+    fn test_foo_enum_in_composite() {
+        Spi::run("CREATE TYPE FooHolder AS (name text, foo Foo, foos Foo[]);").expect("SPI failed");
+
+        let mut holder = PgHeapTuple::new_composite_type("FooHolder").unwrap();
+        holder.set_by_name("foo", Foo::Two).unwrap();
+        holder.set_by_name("foos", vec![Foo::Three, Foo::One]).unwrap();
+        assert_eq!(holder.get_by_name::<Foo>("foo").unwrap(), Some(Foo::Two));
+        assert_eq!(
+            holder.get_by_name::<Vec<Foo>>("foos").unwrap(),
+            Some(vec![Foo::Three, Foo::One])
+        );
+
+        let holder = Spi::get_one::<PgHeapTuple<'_, pgrx::AllocatedByRust>>(
+            "SELECT ROW('a', 'Three', ARRAY['Two'])::FooHolder",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(holder.get_by_name::<Foo>("foo").unwrap(), Some(Foo::Three));
+        assert_eq!(holder.get_by_name::<Vec<Foo>>("foos").unwrap(), Some(vec![Foo::Two]));
+    }
 }
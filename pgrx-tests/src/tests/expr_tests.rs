@@ -0,0 +1,80 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::{ExprError, ExprEvaluator, PgTupleDesc};
+
+    fn make_point(x: i32, y: Option<i32>) -> PgHeapTuple<'static, AllocatedByRust> {
+        let mut point = PgHeapTuple::new_composite_type("expr_point").unwrap();
+        point.set_by_name("x", x).unwrap();
+        if let Some(y) = y {
+            point.set_by_name("y", y).unwrap();
+        }
+        point
+    }
+
+    #[pg_test]
+    fn test_expr_matches() {
+        Spi::run("CREATE TYPE expr_point AS (x int, y int, label text);").unwrap();
+        let tupdesc = PgTupleDesc::for_composite_type("expr_point").unwrap();
+        let mut filter = ExprEvaluator::compile("x + y > 10", tupdesc).unwrap();
+        assert_eq!(filter.result_type(), pg_sys::BOOLOID);
+
+        unsafe {
+            assert!(filter.matches(&make_point(5, Some(6))).unwrap());
+            assert!(!filter.matches(&make_point(5, Some(5))).unwrap());
+            // NULL is treated as false
+            assert!(!filter.matches(&make_point(5, None)).unwrap());
+        }
+    }
+
+    #[pg_test]
+    fn test_expr_evaluate() {
+        Spi::run("CREATE TYPE expr_point AS (x int, y int, label text);").unwrap();
+        let tupdesc = PgTupleDesc::for_composite_type("expr_point").unwrap();
+        let mut label = ExprEvaluator::compile("x || ',' || coalesce(y, 0)", tupdesc).unwrap();
+
+        unsafe {
+            assert_eq!(
+                label.evaluate::<String, _>(&make_point(1, Some(2))),
+                Ok(Some("1,2".into()))
+            );
+            assert_eq!(label.evaluate::<String, _>(&make_point(3, None)), Ok(Some("3,0".into())));
+            assert_eq!(
+                label.evaluate::<i32, _>(&make_point(3, None)),
+                Err(ExprError::IncompatibleType(pg_sys::TEXTOID, pg_sys::INT4OID))
+            );
+        }
+    }
+
+    #[pg_test]
+    fn test_expr_rejects_non_scalar() {
+        Spi::run("CREATE TYPE expr_point AS (x int, y int, label text);").unwrap();
+        let tupdesc = PgTupleDesc::for_composite_type("expr_point").unwrap();
+        for expr in ["sum(x)", "(SELECT 1)", "generate_series(1, x)", "row_number() OVER ()"] {
+            assert!(matches!(
+                ExprEvaluator::compile(expr, tupdesc.clone()),
+                Err(ExprError::NotAScalarExpression(_))
+            ));
+        }
+    }
+
+    #[pg_test(error = "column \"z\" does not exist")]
+    fn test_expr_unknown_column() {
+        Spi::run("CREATE TYPE expr_point AS (x int, y int, label text);").unwrap();
+        let tupdesc = PgTupleDesc::for_composite_type("expr_point").unwrap();
+        let _ = ExprEvaluator::compile("z > 1", tupdesc);
+    }
+}
@@ -0,0 +1,35 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+
+#[pg_extern]
+fn extschema_answer() -> i32 {
+    42
+}
+
+extension_sql!(
+    r#"CREATE VIEW extschema_view AS SELECT @extschema@.extschema_answer() AS answer;"#,
+    name = "create_extschema_view",
+    requires = [extschema_answer],
+);
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_extschema_in_extension_sql() {
+        assert_eq!(Spi::get_one::<i32>("SELECT answer FROM extschema_view"), Ok(Some(42)));
+    }
+}
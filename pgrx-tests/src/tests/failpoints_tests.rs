@@ -0,0 +1,99 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::failpoints;
+    use pgrx::prelude::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::{Duration, Instant};
+
+    #[pg_test]
+    fn test_unarmed_failpoint_does_nothing() {
+        pgrx::fail_point!("fp_unarmed");
+    }
+
+    #[pg_test(error = "failpoint `fp_error` triggered")]
+    fn test_failpoint_error() {
+        failpoints::enable("fp_error", "error").unwrap();
+        pgrx::fail_point!("fp_error");
+    }
+
+    #[pg_test(error = "failpoint `fp_panic` triggered")]
+    fn test_failpoint_panic() {
+        failpoints::enable("fp_panic", "panic").unwrap();
+        pgrx::fail_point!("fp_panic");
+    }
+
+    #[pg_test]
+    fn test_failpoint_count_and_disable() {
+        failpoints::enable("fp_twice", "2*error").unwrap();
+        for _ in 0..2 {
+            let failed = PgTryBuilder::new(|| {
+                pgrx::fail_point!("fp_twice");
+                false
+            })
+            .catch_others(|_| true)
+            .execute();
+            assert!(failed);
+        }
+        pgrx::fail_point!("fp_twice");
+
+        failpoints::enable("fp_disabled", "error").unwrap();
+        failpoints::disable("fp_disabled");
+        pgrx::fail_point!("fp_disabled");
+    }
+
+    #[pg_test]
+    fn test_failpoint_sleep() {
+        failpoints::enable("fp_sleep", "sleep(50)").unwrap();
+        let started = Instant::now();
+        pgrx::fail_point!("fp_sleep");
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        failpoints::disable_all();
+    }
+
+    #[pg_test]
+    fn test_failpoint_invalid_spec() {
+        assert!(failpoints::enable("fp_bad", "explode").is_err());
+        assert!(failpoints::enable("fp_bad", "x*error").is_err());
+        assert!(failpoints::enable("fp_bad", "sleep(soon)").is_err());
+    }
+
+    #[pg_test(error = "failpoint `fp_guc` triggered")]
+    fn test_failpoint_from_guc() -> Result<(), pgrx::spi::Error> {
+        Spi::run("SET pgrx.failpoints = 'fp_other=sleep(1); fp_guc = error'")?;
+        pgrx::fail_point!("fp_guc");
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_failpoint_exercises_cleanup() {
+        static CLEANED_UP: AtomicBool = AtomicBool::new(false);
+        struct Cleanup;
+        impl Drop for Cleanup {
+            fn drop(&mut self) {
+                CLEANED_UP.store(true, Ordering::SeqCst);
+            }
+        }
+
+        failpoints::enable("fp_cleanup", "1*error").unwrap();
+        PgTryBuilder::new(|| {
+            let _cleanup = Cleanup;
+            pgrx::fail_point!("fp_cleanup");
+        })
+        .catch_others(|_| ())
+        .execute();
+        assert!(CLEANED_UP.load(Ordering::SeqCst));
+    }
+}
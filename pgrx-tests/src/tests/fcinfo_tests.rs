@@ -99,6 +99,28 @@ fn same_name(same_name: &str) -> &str {
     same_name
 }
 
+#[pg_extern(strict)]
+fn strict_count_nulls(label: &str, values: Vec<Option<i32>>) -> String {
+    format!("{label}: {}", values.iter().filter(|v| v.is_none()).count())
+}
+
+#[pg_extern]
+fn not_strict_add(a: i32, b: Option<i32>) -> i32 {
+    a + b.unwrap_or(0)
+}
+
+/// Read through the `STRICT` fast path, without null checks
+#[pg_extern(strict)]
+fn bench_strict_add(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+/// The same function, inferred to be `STRICT`, so its arguments' null flags are still checked
+#[pg_extern]
+fn bench_checked_add(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
 // Tests for regression of https://github.com/pgcentralfoundation/pgrx/issues/432
 #[pg_extern]
 fn fcinfo_renamed_one_arg(
@@ -167,10 +189,10 @@ mod tests {
     #[allow(unused_imports)]
     use crate as pgrx_tests;
 
-    use super::{NullError, NullStrict};
+    use super::{add_two_numbers_rs, same_name_rs, NullError, NullStrict};
     use crate::tests::fcinfo_tests::same_name;
     use pgrx::prelude::*;
-    use pgrx::{direct_pg_extern_function_call, IntoDatum};
+    use pgrx::{call_pg_function, direct_pg_extern_function_call, IntoDatum};
 
     #[test]
     fn make_idea_happy() {
@@ -318,6 +340,102 @@ mod tests {
         assert_eq!("test", same_name("test"));
     }
 
+    #[pg_test]
+    fn test_rust_companion() {
+        assert_eq!(3, add_two_numbers_rs(1, 2));
+        assert_eq!("test", same_name_rs("test"));
+    }
+
+    #[pg_test]
+    fn test_call_pg_function() {
+        let sum: Option<i32> = call_pg_function!("add_two_numbers", 1, 2);
+        assert_eq!(sum, Some(3));
+
+        let len: Option<i32> = call_pg_function!("pg_catalog.length", "hello");
+        assert_eq!(len, Some(5));
+
+        // non-strict functions are given the NULL
+        let result: Option<i32> = call_pg_function!("takes_option", None::<i32>);
+        assert_eq!(result, Some(-1));
+
+        // strict functions aren't called at all
+        let result: Option<i32> = call_pg_function!("add_two_numbers", 1, None::<i32>);
+        assert_eq!(result, None);
+    }
+
+    #[pg_test]
+    fn test_call_pg_function_after_replace() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE FUNCTION tests.answer() RETURNS int LANGUAGE sql AS 'SELECT 41'")?;
+        let answer: Option<i32> = call_pg_function!("tests.answer");
+        assert_eq!(answer, Some(41));
+
+        // the cached OID is forgotten when the function is replaced
+        Spi::run("DROP FUNCTION tests.answer()")?;
+        Spi::run("CREATE FUNCTION tests.answer() RETURNS int LANGUAGE sql AS 'SELECT 42'")?;
+        let answer: Option<i32> = call_pg_function!("tests.answer");
+        assert_eq!(answer, Some(42));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_call_pg_function_follows_search_path() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE SCHEMA answer_a; CREATE SCHEMA answer_b")?;
+        Spi::run("CREATE FUNCTION answer_a.answer() RETURNS int LANGUAGE sql AS 'SELECT 1'")?;
+        Spi::run("CREATE FUNCTION answer_b.answer() RETURNS int LANGUAGE sql AS 'SELECT 2'")?;
+
+        Spi::run("SET LOCAL search_path TO answer_a")?;
+        let answer: Option<i32> = call_pg_function!("answer");
+        assert_eq!(answer, Some(1));
+        Spi::run("SET LOCAL search_path TO answer_b")?;
+        let answer: Option<i32> = call_pg_function!("answer");
+        assert_eq!(answer, Some(2));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_call_pg_function_quoted_name() -> Result<(), pgrx::spi::Error> {
+        Spi::run(r#"CREATE SCHEMA "Odd.Schema""#)?;
+        Spi::run(
+            r#"CREATE FUNCTION "Odd.Schema"."Answer"() RETURNS int LANGUAGE sql AS 'SELECT 42'"#,
+        )?;
+
+        let answer: Option<i32> = call_pg_function!(r#""Odd.Schema"."Answer""#);
+        assert_eq!(answer, Some(42));
+        Ok(())
+    }
+
+    #[pg_test]
+    #[should_panic(expected = "function missing_function(integer) does not exist")]
+    fn test_call_missing_pg_function() {
+        let _: Option<i32> = call_pg_function!("missing_function", 1);
+    }
+
+    #[pg_test]
+    #[should_panic(expected = "is not compatible with type oid")]
+    fn test_call_pg_function_wrong_return_type() {
+        let _: Option<String> = call_pg_function!("add_two_numbers", 1, 2);
+    }
+
+    #[pg_test]
+    fn test_strict_args() -> Result<(), pgrx::spi::Error> {
+        let nulls =
+            Spi::get_one::<String>("SELECT strict_count_nulls('nulls', ARRAY[1, NULL, 3, NULL])")?;
+        assert_eq!(nulls.as_deref(), Some("nulls: 2"));
+        let nulls = Spi::get_one::<String>("SELECT strict_count_nulls(NULL, ARRAY[1])")?;
+        assert_eq!(nulls, None);
+        let sum = Spi::get_one::<i32>("SELECT not_strict_add(40, 2)")?;
+        assert_eq!(sum, Some(42));
+        let sum = Spi::get_one::<i32>("SELECT not_strict_add(42, NULL)")?;
+        assert_eq!(sum, Some(42));
+        Ok(())
+    }
+
+    #[pg_test]
+    #[should_panic(expected = "a_ is null")]
+    fn test_not_strict_null_arg() {
+        Spi::get_one::<i32>("SELECT not_strict_add(NULL, 2)").unwrap();
+    }
+
     #[pg_test]
     fn test_null_strict_type() {
         assert_eq!(Ok(None), Spi::get_one::<NullStrict>("SELECT null::NullStrict"));
@@ -328,4 +446,48 @@ mod tests {
     fn test_null_error_type() {
         Spi::get_one::<NullError>("SELECT null::NullError").unwrap();
     }
+
+    /// Call a two-argument `#[pg_extern]` wrapper `calls` times, reusing one `fcinfo`
+    #[cfg(not(feature = "pg11"))]
+    unsafe fn time_calls(
+        func: unsafe extern "C" fn(pg_sys::FunctionCallInfo) -> pg_sys::Datum,
+        calls: i32,
+    ) -> std::time::Duration {
+        let size = std::mem::size_of::<pg_sys::FunctionCallInfoBaseData>()
+            + 2 * std::mem::size_of::<pg_sys::NullableDatum>();
+        let fcinfo = pg_sys::palloc0(size).cast::<pg_sys::FunctionCallInfoBaseData>();
+        (*fcinfo).nargs = 2;
+        let args = (*fcinfo).args.as_mut_ptr();
+        (*args.add(1)).value = 1.into();
+
+        let start = std::time::Instant::now();
+        for i in 0..calls {
+            (*args).value = i.into();
+            std::hint::black_box(func(std::hint::black_box(fcinfo)));
+        }
+        let elapsed = start.elapsed();
+        pg_sys::pfree(fcinfo.cast());
+        elapsed
+    }
+
+    /// A microbenchmark of reading `STRICT` arguments.  It's ignored by default; run it with
+    /// `cargo pgrx test pg15 bench_strict_argument_reads -- --ignored` and look for the `NOTICE`
+    #[cfg(not(feature = "pg11"))]
+    #[pg_test]
+    #[ignore]
+    fn bench_strict_argument_reads() {
+        const CALLS: i32 = 50_000_000;
+        // warm up
+        unsafe { time_calls(super::bench_strict_add_wrapper, CALLS / 10) };
+        unsafe { time_calls(super::bench_checked_add_wrapper, CALLS / 10) };
+
+        let strict = unsafe { time_calls(super::bench_strict_add_wrapper, CALLS) };
+        let checked = unsafe { time_calls(super::bench_checked_add_wrapper, CALLS) };
+        let per_call = |elapsed: std::time::Duration| elapsed.as_nanos() as f64 / CALLS as f64;
+        notice!(
+            "strict: {:.3} ns/call, checked: {:.3} ns/call",
+            per_call(strict),
+            per_call(checked)
+        );
+    }
 }
@@ -37,6 +37,39 @@ mod tests {
         assert_eq!(GUC.get(), true);
     }
 
+    #[pg_test]
+    fn test_feature_flag() {
+        static FLAG: FeatureFlag = pgrx::pg_feature_flag!("enable_test_flag", default = true);
+        assert_eq!(FLAG.name(), "pgrx_tests.enable_test_flag");
+        assert!(FLAG.is_enabled());
+        FLAG.register();
+
+        Spi::run("SET pgrx_tests.enable_test_flag TO off").expect("SPI failed");
+        assert!(!FLAG.is_enabled());
+
+        let description = Spi::get_one::<String>(
+            "SELECT short_desc FROM pg_settings WHERE name = 'pgrx_tests.enable_test_flag'",
+        );
+        assert_eq!(description, Ok(Some("Enables enable_test_flag".to_string())));
+    }
+
+    #[pg_test]
+    fn test_feature_flag_options() {
+        static FLAG: FeatureFlag = pgrx::pg_feature_flag!(
+            "enable_other_test_flag",
+            default = false,
+            context = GucContext::Userset,
+            description = "A flag for testing",
+        );
+        assert!(!FLAG.is_enabled());
+        FLAG.register();
+
+        let setting = Spi::get_two::<String, String>(
+            "SELECT context, short_desc FROM pg_settings WHERE name = 'pgrx_tests.enable_other_test_flag'",
+        );
+        assert_eq!(setting, Ok((Some("user".to_string()), Some("A flag for testing".to_string()))));
+    }
+
     #[pg_test]
     fn test_int_guc() {
         static GUC: GucSetting<i32> = GucSetting::<i32>::new(42);
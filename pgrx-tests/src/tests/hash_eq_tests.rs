@@ -0,0 +1,120 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    PostgresType,
+    PostgresEq,
+    PostgresHash
+)]
+pub struct HashableThing {
+    id: i32,
+    name: String,
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    fn create_things() -> Result<(), spi::Error> {
+        Spi::run(
+            r#"CREATE TABLE things AS
+                SELECT format('{"id": %s, "name": "thing %s"}', i % 3, i % 3)::HashableThing AS thing
+                  FROM generate_series(1, 10) i"#,
+        )
+    }
+
+    #[pg_test]
+    fn test_hash_group_by_and_distinct() -> Result<(), spi::Error> {
+        create_things()?;
+        // without sorting, grouping can only be done by hashing
+        Spi::run("SET LOCAL enable_sort = off")?;
+
+        let groups = Spi::get_one::<i64>(
+            "SELECT count(*) FROM (SELECT thing, count(*) FROM things GROUP BY thing) g",
+        )?;
+        assert_eq!(groups, Some(3));
+
+        let distinct =
+            Spi::get_one::<i64>("SELECT count(*) FROM (SELECT DISTINCT thing FROM things) d")?;
+        assert_eq!(distinct, Some(3));
+
+        let union = Spi::get_one::<i64>(
+            "SELECT count(*) FROM (SELECT thing FROM things UNION SELECT thing FROM things) u",
+        )?;
+        assert_eq!(union, Some(3));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_hash_join() -> Result<(), spi::Error> {
+        create_things()?;
+        Spi::run("SET LOCAL enable_nestloop = off")?;
+        Spi::run("SET LOCAL enable_mergejoin = off")?;
+
+        let plan = Spi::explain("SELECT * FROM things a JOIN things b ON a.thing = b.thing")?;
+        assert!(plan.0.to_string().contains("Hash Join"));
+
+        let joined = Spi::get_one::<i64>(
+            "SELECT count(*) FROM things a JOIN things b ON a.thing = b.thing",
+        )?;
+        // 4, 3, and 3 of each thing
+        assert_eq!(joined, Some(4 * 4 + 3 * 3 + 3 * 3));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_hash_partitioning() -> Result<(), spi::Error> {
+        Spi::run("CREATE TABLE partitioned (thing HashableThing) PARTITION BY HASH (thing)")?;
+        Spi::run(
+            "CREATE TABLE partitioned_0 PARTITION OF partitioned FOR VALUES WITH (MODULUS 2, REMAINDER 0)",
+        )?;
+        Spi::run(
+            "CREATE TABLE partitioned_1 PARTITION OF partitioned FOR VALUES WITH (MODULUS 2, REMAINDER 1)",
+        )?;
+        create_things()?;
+        Spi::run("INSERT INTO partitioned SELECT thing FROM things")?;
+
+        let total = Spi::get_one::<i64>(
+            "SELECT (SELECT count(*) FROM partitioned_0) + (SELECT count(*) FROM partitioned_1)",
+        )?;
+        assert_eq!(total, Some(10));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_extended_hash_matches_standard_hash() -> Result<(), spi::Error> {
+        let matches = Spi::get_one::<bool>(
+            r#"SELECT (hashablething_hash_extended(t, 0) & 4294967295)
+                    = (hashablething_hash(t)::bigint & 4294967295)
+                 FROM (SELECT '{"id": 42, "name": "forty-two"}'::HashableThing AS t) x"#,
+        )?;
+        assert_eq!(matches, Some(true));
+
+        let differs = Spi::get_one::<bool>(
+            r#"SELECT hashablething_hash_extended(t, 0) <> hashablething_hash_extended(t, 1)
+                 FROM (SELECT '{"id": 42, "name": "forty-two"}'::HashableThing AS t) x"#,
+        )?;
+        assert_eq!(differs, Some(true));
+        Ok(())
+    }
+}
@@ -291,6 +291,26 @@ mod arguments {
             }
             sum_scritches
         }
+
+        #[pg_extern]
+        fn dog_names_and_scritches_by_column(
+            dogs: pgrx::Array<pgrx::composite_type!("Dog")>,
+        ) -> String {
+            let columns = dogs.to_columns();
+            let names = columns.column_by_name::<String>("name").unwrap();
+            let scritches =
+                columns.column_by_index::<i32>(std::num::NonZeroUsize::new(2).unwrap()).unwrap();
+            names
+                .into_iter()
+                .zip(scritches)
+                .enumerate()
+                .map(|(row, (name, scritches))| match columns.is_null(row) {
+                    Some(true) => "NULL".to_string(),
+                    _ => format!("{}={}", name.unwrap_or_default(), scritches.unwrap_or_default()),
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        }
     }
 }
 
@@ -311,6 +331,20 @@ mod returning {
             tuple
         }
 
+        #[pg_extern]
+        fn build_dog_record(name: &str) -> pgrx::composite_type!("record") {
+            RecordBuilder::new()
+                .add("name", name)
+                .add("scritches", 42)
+                .add_null::<bool>("good")
+                .build()
+        }
+
+        #[pg_extern]
+        fn build_owner_record() -> pgrx::composite_type!("record") {
+            RecordBuilder::new().add("dog", build_dog_record("Nami")).add("owner", "Brandy").build()
+        }
+
         #[pg_extern]
         fn scritch(
             maybe_dog: Option<::pgrx::composite_type!("Dog")>,
@@ -398,7 +432,24 @@ mod returning {
         }
     }
 
-    // Returning VariadicArray/Array isn't supported, use a Vec.
+    mod array {
+        use super::*;
+
+        #[pg_extern]
+        fn scritched_dogs<'a>(
+            dogs: pgrx::Array<'a, pgrx::composite_type!("Dog")>,
+        ) -> pgrx::Array<'a, pgrx::composite_type!("Dog")> {
+            // the array is returned as it was passed in
+            dogs
+        }
+
+        #[pg_extern]
+        fn scritched_dogs_variadic<'a>(
+            dogs: VariadicArray<'a, pgrx::composite_type!("Dog")>,
+        ) -> VariadicArray<'a, pgrx::composite_type!("Dog")> {
+            dogs
+        }
+    }
 }
 
 // Just a compile test...
@@ -636,7 +687,7 @@ mod tests {
     use pgrx::datum::TryFromDatumError;
     use pgrx::heap_tuple::PgHeapTupleError;
     use pgrx::prelude::*;
-    use pgrx::AllocatedByRust;
+    use pgrx::{AllocatedByRust, PgTupleDesc};
     use std::num::NonZeroUsize;
 
     #[pg_test]
@@ -765,6 +816,76 @@ mod tests {
         assert_eq!(retval, Ok(Some(43)));
     }
 
+    #[pg_test]
+    fn test_dog_names_and_scritches_by_column() {
+        let retval = Spi::get_one::<String>("
+            SELECT dog_names_and_scritches_by_column(ARRAY[ROW('Nami', 1), NULL, ROW(NULL, 42)]::Dog[])
+        ");
+        assert_eq!(retval, Ok(Some("Nami=1,NULL,=42".to_string())));
+    }
+
+    #[pg_test]
+    fn test_to_columns_errors() -> Result<(), spi::Error> {
+        Spi::connect(|client| {
+            let dogs = client
+                .select("SELECT ARRAY[ROW('Nami', 1)]::Dog[]", None, None)?
+                .first()
+                .get_one::<pgrx::Array<PgHeapTuple<'_, AllocatedByRust>>>()?
+                .expect("datum was null");
+            let columns = dogs.to_columns();
+            assert_eq!(columns.len(), 1);
+            assert_eq!(
+                columns.column_by_name::<i32>("boops"),
+                Err(TryFromDatumError::NoSuchAttributeName("boops".into())),
+            );
+            assert!(matches!(
+                columns.column_by_name::<i32>("name"),
+                Err(TryFromDatumError::IncompatibleTypes { .. })
+            ));
+            Ok(())
+        })
+    }
+
+    #[pg_test]
+    fn test_build_dog_record() {
+        let retval = Spi::get_one::<String>("SELECT build_dog_record('Nami')::text");
+        assert_eq!(retval, Ok(Some("(Nami,42,)".to_string())));
+
+        let retval = Spi::get_one::<i32>(
+            "SELECT scritches FROM build_dog_record('Nami') AS dog(name text, scritches int, good bool)",
+        );
+        assert_eq!(retval, Ok(Some(42)));
+    }
+
+    #[pg_test]
+    fn test_build_nested_record() {
+        let retval = Spi::get_one::<String>("SELECT build_owner_record()::text");
+        assert_eq!(retval, Ok(Some(r#"("(Nami,42,)",Brandy)"#.to_string())));
+    }
+
+    #[pg_test]
+    fn test_bless_tuple_desc() {
+        let mut tupdesc =
+            unsafe { PgTupleDesc::from_pg_is_copy(pgrx::compat::create_template_tuple_desc(0)) };
+        assert!(!tupdesc.is_blessed());
+        tupdesc.bless();
+        assert!(tupdesc.is_blessed());
+        assert_eq!(tupdesc.oid(), pg_sys::RECORDOID);
+    }
+
+    #[pg_test]
+    fn test_scritched_dogs() {
+        let retval = Spi::get_one::<String>(
+            "SELECT scritched_dogs(ARRAY[ROW('Nami', 1), NULL]::Dog[])::text",
+        );
+        assert_eq!(retval, Ok(Some(r#"{"(Nami,1)",NULL}"#.to_string())));
+
+        let retval = Spi::get_one::<String>(
+            "SELECT scritched_dogs_variadic(ROW('Nami', 1)::Dog, ROW('Brandy', 42)::Dog)::text",
+        );
+        assert_eq!(retval, Ok(Some(r#"{"(Nami,1)","(Brandy,42)"}"#.to_string())));
+    }
+
     #[pg_test]
     fn test_create_dog() -> Result<(), pgrx::spi::Error> {
         let retval = Spi::get_one::<PgHeapTuple<'_, AllocatedByRust>>(
@@ -819,6 +940,36 @@ mod tests {
         assert_eq!(heap_tuple.get_by_name("age").unwrap(), Some(42i32));
     }
 
+    #[pg_test]
+    fn test_new_composite_type_after_ddl() {
+        Spi::run("CREATE TYPE DogWithAge AS (name text, age int);").expect("SPI failed");
+        let heap_tuple = PgHeapTuple::new_composite_type("DogWithAge").unwrap();
+        assert_eq!(heap_tuple.len(), 2);
+
+        Spi::run(
+            "DROP TYPE DogWithAge; CREATE TYPE DogWithAge AS (name text, age int, good bool);",
+        )
+        .expect("SPI failed");
+        let mut heap_tuple = PgHeapTuple::new_composite_type("DogWithAge").unwrap();
+        assert_eq!(heap_tuple.len(), 3);
+        heap_tuple.set_by_name("good", true).unwrap();
+        assert_eq!(heap_tuple.get_by_name("good").unwrap(), Some(true));
+    }
+
+    #[pg_test]
+    fn test_new_composite_type_follows_search_path() {
+        Spi::run(
+            "CREATE SCHEMA kennel;
+             CREATE TYPE kennel.DogWithAge AS (name text, age int, kennel text);
+             CREATE TYPE DogWithAge AS (name text, age int);",
+        )
+        .expect("SPI failed");
+        assert_eq!(PgHeapTuple::new_composite_type("DogWithAge").unwrap().len(), 2);
+
+        Spi::run("SET LOCAL search_path TO kennel, public").expect("SPI failed");
+        assert_eq!(PgHeapTuple::new_composite_type("DogWithAge").unwrap().len(), 3);
+    }
+
     #[pg_test]
     fn test_missing_type() {
         const NON_EXISTING_ATTRIBUTE: &str = "DEFINITELY_NOT_EXISTING";
@@ -935,4 +1086,55 @@ mod tests {
         assert_eq!(result, 10_000);
         Ok(())
     }
+
+    #[pg_test]
+    fn test_set_checked_domain_violations() {
+        Spi::run("CREATE DOMAIN dog_name AS text NOT NULL CHECK (length(VALUE) > 0);")
+            .expect("SPI failed");
+        Spi::run("CREATE TYPE NamedDog AS (name dog_name, age int);").expect("SPI failed");
+        let mut heap_tuple = PgHeapTuple::new_composite_type("NamedDog").unwrap();
+
+        assert_eq!(
+            heap_tuple.set_by_name_checked("name", Option::<String>::None),
+            Err(TryFromDatumError::DomainConstraintViolation {
+                attname: "name".to_string(),
+                message: "domain dog_name does not allow null values".to_string(),
+            })
+        );
+        assert!(matches!(
+            heap_tuple.set_by_name_checked("name", ""),
+            Err(TryFromDatumError::DomainConstraintViolation { .. })
+        ));
+        assert!(matches!(
+            heap_tuple.check_domains(),
+            Err(TryFromDatumError::DomainConstraintViolation { .. })
+        ));
+
+        heap_tuple.set_by_name_checked("name", "Brandy").unwrap();
+        heap_tuple.set_by_name_checked("age", 42).unwrap();
+        assert_eq!(heap_tuple.get_by_name::<String>("name").unwrap(), Some("Brandy".to_string()));
+        assert_eq!(heap_tuple.check_domains(), Ok(()));
+
+        // the unchecked setter doesn't consult the domain
+        heap_tuple.set_by_name("name", "").unwrap();
+        assert_eq!(heap_tuple.get_by_name::<String>("name").unwrap(), Some("".to_string()));
+    }
+
+    #[pg_test]
+    fn test_apply_domain_defaults() {
+        Spi::run("CREATE DOMAIN dog_age AS int NOT NULL DEFAULT 1 + 1;").expect("SPI failed");
+        Spi::run("CREATE TYPE AgedDog AS (name text, age dog_age);").expect("SPI failed");
+        let mut heap_tuple = PgHeapTuple::new_composite_type("AgedDog").unwrap();
+        heap_tuple.set_by_name("name", "Brandy").unwrap();
+        heap_tuple.set_by_name("age", 7).unwrap();
+        heap_tuple.apply_domain_defaults();
+        assert_eq!(heap_tuple.get_by_name::<i32>("age").unwrap(), Some(7));
+
+        let mut heap_tuple = PgHeapTuple::new_composite_type("AgedDog").unwrap();
+        assert!(heap_tuple.check_domains().is_err());
+        heap_tuple.apply_domain_defaults();
+        assert_eq!(heap_tuple.get_by_name::<i32>("age").unwrap(), Some(2));
+        assert_eq!(heap_tuple.get_by_name::<String>("name").unwrap(), None);
+        assert_eq!(heap_tuple.check_domains(), Ok(()));
+    }
 }
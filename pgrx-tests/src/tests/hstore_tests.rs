@@ -0,0 +1,59 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[pg_test]
+    fn test_hstore_to_hashmap() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE EXTENSION IF NOT EXISTS hstore")?;
+        let map = Spi::get_one::<HashMap<String, Option<String>>>(
+            r#"SELECT 'a=>1, "b c"=>"quoted \"value\"", d=>NULL'::hstore"#,
+        )?
+        .expect("datum was null");
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["a"], Some("1".to_string()));
+        assert_eq!(map["b c"], Some(r#"quoted "value""#.to_string()));
+        assert_eq!(map["d"], None);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_hstore_roundtrip() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE EXTENSION IF NOT EXISTS hstore")?;
+        let mut map = BTreeMap::new();
+        map.insert("key".to_string(), Some("value".to_string()));
+        map.insert(r#"back\slash"#.to_string(), Some(r#""quoted""#.to_string()));
+        map.insert("null".to_string(), None);
+
+        let result = Spi::get_one_with_args::<BTreeMap<String, Option<String>>>(
+            "SELECT $1",
+            vec![(PgOid::from(pgrx::hstore_type_oid()), map.clone().into_datum())],
+        )?
+        .expect("datum was null");
+        assert_eq!(result, map);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_empty_hstore() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE EXTENSION IF NOT EXISTS hstore")?;
+        let map = Spi::get_one::<HashMap<String, Option<String>>>("SELECT ''::hstore")?
+            .expect("datum was null");
+        assert!(map.is_empty());
+        Ok(())
+    }
+}
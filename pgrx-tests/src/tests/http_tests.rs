@@ -0,0 +1,91 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::http::{HttpClient, HttpError};
+    use pgrx::prelude::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    /// Serve one request with `response`, or with nothing at all if it's `None`, returning the URL
+    fn serve_once(response: Option<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request);
+            match response {
+                Some(response) => stream.write_all(response.as_bytes()).unwrap(),
+                None => std::thread::sleep(Duration::from_secs(5)),
+            }
+        });
+        url
+    }
+
+    #[pg_test]
+    fn test_http_get() {
+        let url = serve_once(Some(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+        ));
+        let response = HttpClient::new().get(&url).header("Accept", "text/plain").send().unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.header("content-type"), Some("text/plain"));
+        assert_eq!(response.text(), Ok("hello"));
+    }
+
+    #[pg_test]
+    fn test_http_error_status_is_a_response() {
+        let url = serve_once(Some(
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 4\r\nConnection: close\r\n\r\nnope",
+        ));
+        let response = HttpClient::new().post(&url).body("{}").send().unwrap();
+        assert_eq!(response.status(), 404);
+        assert_eq!(response.into_body(), b"nope");
+    }
+
+    #[pg_test]
+    fn test_http_response_too_large() {
+        let url = serve_once(Some(
+            "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+        ));
+        let result = HttpClient::new().max_response_size(4).get(&url).send();
+        assert!(matches!(result, Err(HttpError::TooLarge(4))));
+    }
+
+    #[pg_test]
+    fn test_http_timeout() {
+        let url = serve_once(None);
+        let result = HttpClient::new().timeout(Duration::from_millis(100)).get(&url).send();
+        assert!(matches!(result, Err(HttpError::Timeout(_))));
+    }
+
+    #[pg_test(error = "canceling statement due to user request")]
+    fn test_http_cancel() {
+        let url = serve_once(None);
+        // as if pg_cancel_backend() had been called on us
+        #[cfg(feature = "pg11")]
+        unsafe {
+            pg_sys::QueryCancelPending = true;
+            pg_sys::InterruptPending = true;
+        }
+        #[cfg(not(feature = "pg11"))]
+        unsafe {
+            pg_sys::QueryCancelPending = 1;
+            pg_sys::InterruptPending = 1;
+        }
+        let _ = HttpClient::new().get(&url).send();
+    }
+}
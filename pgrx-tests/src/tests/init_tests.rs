@@ -0,0 +1,75 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::bgworkers::BackgroundWorkerBuilder;
+    use pgrx::guc::*;
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_pg_init_has_returned() {
+        assert!(!pgrx::init::in_init());
+    }
+
+    #[pg_fini]
+    fn fini() {
+        // the test library holds nothing outside of Postgres
+    }
+
+    #[pg_test]
+    fn test_pg_init_finds_pg_fini() {
+        use pgrx::init::{Library, WithFini as _, WithoutFini as _};
+
+        struct WithoutPgFini;
+        assert!((&&Library::<crate::__PgrxLibrary>::new()).fini().is_some());
+        assert!((&&Library::<WithoutPgFini>::new()).fini().is_none());
+    }
+
+    // `#[pg_extern]`, and so `#[pg_test]`, rejects these in its own body at build time, so they're
+    // made from ordinary functions to get to the runtime checks
+    fn load_static_bgworker() {
+        BackgroundWorkerBuilder::new("too late")
+            .set_library("pgrx_tests")
+            .set_function("bgworker")
+            .load();
+    }
+
+    fn define_postmaster_guc() {
+        static GUC: GucSetting<bool> = GucSetting::<bool>::new(false);
+        GucRegistry::define_bool_guc(
+            "test.too_late",
+            "",
+            "",
+            &GUC,
+            GucContext::Postmaster,
+            GucFlags::default(),
+        );
+    }
+
+    #[pg_test]
+    #[should_panic(
+        expected = "loading a static background worker can only be done from the library's #[pg_init] function"
+    )]
+    fn test_static_bgworker_outside_pg_init() {
+        load_static_bgworker();
+    }
+
+    #[pg_test]
+    #[should_panic(
+        expected = "defining a Postmaster GUC can only be done from the library's #[pg_init] function"
+    )]
+    fn test_postmaster_guc_outside_pg_init() {
+        define_postmaster_guc();
+    }
+}
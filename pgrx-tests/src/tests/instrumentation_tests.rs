@@ -0,0 +1,60 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::{executor_instrumentation_view, ExecutorInstrumentation};
+
+// initialized and installed by the `#[pg_init]` function in `shmem_tests`
+pub static STATEMENTS: ExecutorInstrumentation<64> = ExecutorInstrumentation::new();
+
+executor_instrumentation_view!(instrumentation_test_statements, STATEMENTS);
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use super::STATEMENTS;
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_executor_instrumentation() {
+        let query = "SELECT g FROM generate_series(1, 42) g /* instrumentation test */";
+        for _ in 0..3 {
+            Spi::connect(|client| client.select(query, None, None).map(|_| ())).unwrap();
+        }
+
+        let stats = STATEMENTS
+            .statements()
+            .into_iter()
+            .find(|stats| stats.query.as_str() == query)
+            .expect("query was not recorded");
+        assert_eq!(stats.calls, 3);
+        assert_eq!(stats.rows, 3 * 42);
+        assert!(stats.total_time_ms >= stats.max_time_ms);
+        assert!(stats.max_time_ms >= stats.min_time_ms);
+    }
+
+    #[pg_test]
+    fn test_executor_instrumentation_view() {
+        Spi::run("SELECT 1 /* instrumentation view test */").unwrap();
+        let calls = Spi::get_one::<i64>(
+            "SELECT calls FROM instrumentation_test_statements() \
+             WHERE query = 'SELECT 1 /* instrumentation view test */'",
+        );
+        assert!(matches!(calls, Ok(Some(n)) if n >= 1));
+
+        STATEMENTS.reset();
+        let count = Spi::get_one::<i64>(
+            "SELECT count(*) FROM instrumentation_test_statements() \
+             WHERE query LIKE '%instrumentation view test%'",
+        );
+        assert_eq!(count, Ok(Some(0)));
+    }
+}
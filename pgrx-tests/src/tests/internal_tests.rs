@@ -7,6 +7,14 @@
 //LICENSE All rights reserved.
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use pgrx::Internal;
+
+#[pg_extern(immutable, parallel_safe)]
+fn internal_state_sum(state: Internal, extra: i64) -> i64 {
+    unsafe { state.get::<Vec<i64>>() }.map_or(0, |state| state.iter().sum()) + extra
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pgrx::pg_schema]
 mod tests {
@@ -53,6 +61,39 @@ mod tests {
         assert_eq!(val.initialized(), true);
     }
 
+    #[pg_test]
+    fn internal_pg_extern_arg() -> Result<(), spi::Error> {
+        assert_eq!(
+            Spi::get_one::<String>(
+                "SELECT pg_get_function_arguments('internal_state_sum'::regproc)"
+            )?,
+            Some("state internal, extra bigint".into())
+        );
+
+        let state = Internal::new(vec![1i64, 2, 3]);
+        let sum = unsafe {
+            pgrx::direct_pg_extern_function_call::<i64>(
+                super::internal_state_sum_wrapper,
+                &[state.into_datum(), 4i64.into_datum()],
+            )
+        };
+        assert_eq!(sum, Some(10));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn internal_ptr() {
+        let mut value = 5i32;
+        let val = Internal::from_ptr(&mut value as *mut i32);
+        assert!(val.initialized());
+        assert_eq!(val.as_ptr::<i32>(), &mut value as *mut i32);
+        assert_eq!(unsafe { val.get::<i32>() }, Some(&5));
+
+        let val = Internal::from_ptr(std::ptr::null_mut::<i32>());
+        assert!(!val.initialized());
+        assert!(val.as_ptr::<i32>().is_null());
+    }
+
     #[pg_test]
     fn internal_get_or_insert_with() {
         let mut val = Internal::default();
@@ -0,0 +1,86 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::jobs::JobQueue;
+    use pgrx::prelude::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn queue() -> JobQueue {
+        let queue = JobQueue::new("jobs test").max_attempts(2);
+        queue.create().unwrap();
+        queue
+    }
+
+    #[pg_test]
+    fn test_jobs_claim_in_order() -> Result<(), spi::Error> {
+        let queue = queue();
+        let first = queue.enqueue(json!({ "n": 1 }))?;
+        let second = queue.enqueue(json!({ "n": 2 }))?;
+        queue.enqueue_after(json!({ "n": 3 }), Duration::from_secs(3600))?;
+
+        let job = queue.claim()?.unwrap();
+        assert_eq!((job.id, job.payload, job.attempts), (first, json!({ "n": 1 }), 0));
+        queue.complete(first)?;
+        assert_eq!(queue.claim()?.map(|job| job.id), Some(second));
+        queue.complete(second)?;
+        // the last one isn't due yet
+        assert_eq!(queue.claim()?, None);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_jobs_work_one() -> Result<(), spi::Error> {
+        let queue = queue().retry_delay(Duration::ZERO);
+        let id = queue.enqueue(json!("payload"))?;
+
+        assert!(queue.work_one(|_| Err("not yet".to_string()))?);
+        let (attempts, error) = Spi::get_two::<i32, String>(&format!(
+            "SELECT attempts, last_error FROM {} WHERE id = {id}",
+            queue.qualified_name()
+        ))?;
+        assert_eq!((attempts, error.as_deref()), (Some(1), Some("not yet")));
+
+        // an ERROR is rolled back, and this is the last attempt
+        assert!(queue.work_one(|job| {
+            Spi::run(&format!("DELETE FROM {}", queue.qualified_name())).unwrap();
+            error!("job {} failed", job.id)
+        })?);
+        let failed = Spi::get_two::<bool, String>(&format!(
+            "SELECT failed, last_error FROM {} WHERE id = {id}",
+            queue.qualified_name()
+        ))?;
+        assert_eq!(failed, (Some(true), Some(format!("job {id} failed"))));
+        assert!(!queue.work_one(|_| Ok(()))?);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_jobs_work_one_completes() -> Result<(), spi::Error> {
+        let queue = queue();
+        queue.enqueue(json!([]))?;
+        let mut seen = Vec::new();
+        assert!(queue.work_one(|job| {
+            seen.push(job.payload.clone());
+            Ok(())
+        })?);
+        assert_eq!(seen, vec![json!([])]);
+        let count =
+            Spi::get_one::<i64>(&format!("SELECT count(*) FROM {}", queue.qualified_name()));
+        assert_eq!(count, Ok(Some(0)));
+        Ok(())
+    }
+}
@@ -15,6 +15,28 @@ mod tests {
 
     use pgrx::prelude::*;
     use pgrx::{Json, JsonB};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[pg_extern]
+    fn json_point_swap(point: Json<Point>) -> Json<Point> {
+        Json(Point { x: point.0.y, y: point.0.x })
+    }
+
+    #[pg_extern]
+    fn jsonb_point(x: i32, y: i32) -> JsonB<Point> {
+        JsonB(Point { x, y })
+    }
+
+    #[pg_extern]
+    fn jsonb_point_sum(point: JsonB<Point>) -> i32 {
+        point.0.x + point.0.y
+    }
 
     #[pg_test]
     fn test_json() -> Result<(), pgrx::spi::Error> {
@@ -61,4 +83,37 @@ mod tests {
         assert_eq!(user.last_name, "McBlahFace");
         Ok(())
     }
+
+    #[pg_test]
+    fn test_jsonb_typed() -> Result<(), pgrx::spi::Error> {
+        let point = Spi::get_one::<JsonB<Point>>("SELECT tests.jsonb_point(1, 2)")?
+            .expect("datum was null");
+        assert_eq!(point.0, Point { x: 1, y: 2 });
+
+        let sum = Spi::get_one::<i32>(r#"SELECT tests.jsonb_point_sum('{"x": 3, "y": 4}')"#)?;
+        assert_eq!(sum, Some(7));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_jsonb_from_hashmap() -> Result<(), pgrx::spi::Error> {
+        let mut map = std::collections::HashMap::new();
+        map.insert("answer".to_string(), 42);
+
+        let text = Spi::get_one_with_args::<String>(
+            "SELECT $1::text",
+            vec![(PgBuiltInOids::JSONBOID.oid(), JsonB(map).into_datum())],
+        )?;
+        assert_eq!(text.as_deref(), Some(r#"{"answer": 42}"#));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_json_typed() -> Result<(), pgrx::spi::Error> {
+        let point =
+            Spi::get_one::<Json<Point>>(r#"SELECT tests.json_point_swap('{"x": 1, "y": 2}')"#)?
+                .expect("datum was null");
+        assert_eq!(point.0, Point { x: 2, y: 1 });
+        Ok(())
+    }
 }
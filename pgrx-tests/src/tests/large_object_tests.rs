@@ -0,0 +1,88 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::{LargeObject, LargeObjectMode};
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    #[pg_test]
+    fn test_large_object_read_write_seek() {
+        let oid = LargeObject::create();
+        let mut lo = LargeObject::open(oid, LargeObjectMode::READ | LargeObjectMode::WRITE);
+        assert_eq!(lo.oid(), oid);
+
+        lo.write_all(b"hello, world").unwrap();
+        assert_eq!(lo.tell(), 12);
+
+        lo.seek(SeekFrom::Start(7)).unwrap();
+        let mut rest = String::new();
+        lo.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "world");
+
+        lo.truncate(5);
+        lo.close();
+        assert_eq!(LargeObject::get(oid), b"hello");
+    }
+
+    #[pg_test]
+    fn test_large_object_chunked_read_write() {
+        let data = (0..3 * 1024 * 1024).map(|i| i as u8).collect::<Vec<_>>();
+        let oid = LargeObject::create();
+        let mut lo = LargeObject::open(oid, LargeObjectMode::READ | LargeObjectMode::WRITE);
+
+        // each call moves at most a megabyte
+        assert_eq!(lo.write(&data).unwrap(), 1024 * 1024);
+        lo.write_all(&data[1024 * 1024..]).unwrap();
+
+        lo.rewind().unwrap();
+        let mut buf = vec![0; data.len()];
+        assert_eq!(lo.read(&mut buf).unwrap(), 1024 * 1024);
+        lo.read_exact(&mut buf[1024 * 1024..]).unwrap();
+        assert!(buf == data);
+    }
+
+    #[pg_test]
+    fn test_large_object_from_bytes() -> Result<(), spi::Error> {
+        let oid = LargeObject::from_bytes(b"some bytes");
+        let mut lo = LargeObject::open(oid, LargeObjectMode::READ);
+        lo.seek(SeekFrom::End(-5)).unwrap();
+        let mut tail = Vec::new();
+        lo.read_to_end(&mut tail).unwrap();
+        assert_eq!(tail, b"bytes");
+        drop(lo);
+
+        let visible = Spi::get_one_with_args::<Vec<u8>>(
+            "SELECT lo_get($1)",
+            vec![(PgBuiltInOids::OIDOID.oid(), oid.into_datum())],
+        )?;
+        assert_eq!(visible.as_deref(), Some(&b"some bytes"[..]));
+
+        LargeObject::unlink(oid);
+        let count = Spi::get_one_with_args::<i64>(
+            "SELECT count(*) FROM pg_largeobject_metadata WHERE oid = $1",
+            vec![(PgBuiltInOids::OIDOID.oid(), oid.into_datum())],
+        )?;
+        assert_eq!(count, Some(0));
+        Ok(())
+    }
+
+    #[pg_test(error = "large object 4294967294 does not exist")]
+    fn test_large_object_missing() {
+        LargeObject::open(
+            unsafe { pg_sys::Oid::from_u32_unchecked(4294967294) },
+            LargeObjectMode::READ,
+        );
+    }
+}
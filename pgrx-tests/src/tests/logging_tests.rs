@@ -0,0 +1,114 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::logging::{EmitLogHook, LogEntry};
+use pgrx::PgLogLevel;
+
+/// Keeps the server log entries whose message starts with `pgrx_tests capture:`, out of the log
+/// and in `entries` instead.  Installed by the `#[pg_init]` function in `shmem_tests`
+pub struct CaptureLog {
+    entries: Vec<(PgLogLevel, String, Option<String>)>,
+}
+
+impl EmitLogHook for CaptureLog {
+    fn emit_log(&mut self, entry: &mut LogEntry) {
+        let Some(message) = entry.message() else { return };
+        if message.starts_with("pgrx_tests capture:") {
+            self.entries.push((
+                entry.level(),
+                message.to_string(),
+                entry.detail().map(str::to_string),
+            ));
+            entry.suppress();
+        }
+    }
+}
+
+pub static mut CAPTURE_LOG: CaptureLog = CaptureLog { entries: Vec::new() };
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+
+    fn captured(message: &str) -> Option<(PgLogLevel, Option<String>)> {
+        let entries = unsafe { &(*std::ptr::addr_of!(super::CAPTURE_LOG)).entries };
+        entries
+            .iter()
+            .find(|(_, m, _)| m == message)
+            .map(|(level, _, detail)| (*level, detail.clone()))
+    }
+
+    #[pg_test]
+    fn test_emit_log_hook() {
+        warning!("pgrx_tests capture: warning");
+        assert_eq!(captured("pgrx_tests capture: warning"), Some((PgLogLevel::WARNING, None)));
+    }
+
+    #[pg_test]
+    fn test_emit_log_hook_skips_client_only_entries() {
+        // INFO isn't written to the server log by default, so the hook never sees it
+        info!("pgrx_tests capture: info");
+        assert_eq!(captured("pgrx_tests capture: info"), None);
+    }
+
+    #[pg_test]
+    fn test_set_min_level() {
+        use pgrx::logging::{internal_log_enabled, set_min_level};
+
+        assert!(internal_log_enabled(PgLogLevel::INFO));
+        set_min_level(PgLogLevel::WARNING);
+        assert!(!internal_log_enabled(PgLogLevel::INFO));
+        assert!(internal_log_enabled(PgLogLevel::WARNING));
+        set_min_level(PgLogLevel::DEBUG5);
+        assert_eq!(pgrx::logging::min_level(), PgLogLevel::DEBUG5);
+    }
+
+    #[pg_test]
+    fn test_log_level_guc() -> Result<(), pgrx::spi::Error> {
+        Spi::run("SET LOCAL pgrx.log_level = 'notice'")?;
+        assert_eq!(pgrx::logging::min_level(), PgLogLevel::NOTICE);
+        assert!(!pgrx::logging::internal_log_enabled(PgLogLevel::LOG));
+
+        // the more severe of the two wins
+        pgrx::logging::set_min_level(PgLogLevel::WARNING);
+        assert_eq!(pgrx::logging::min_level(), PgLogLevel::WARNING);
+        pgrx::logging::set_min_level(PgLogLevel::DEBUG5);
+
+        Spi::run("SET LOCAL pgrx.log_level = 'DEBUG5'")?;
+        assert_eq!(pgrx::logging::min_level(), PgLogLevel::DEBUG5);
+        Ok(())
+    }
+
+    #[cfg(feature = "log")]
+    #[pg_test]
+    fn test_log_bridge() {
+        log::warn!(answer = 42, question = "unknown"; "pgrx_tests capture: {}", "log");
+        assert_eq!(
+            captured("pgrx_tests capture: log"),
+            Some((PgLogLevel::WARNING, Some("answer = 42, question = unknown".into())))
+        );
+
+        // below the bridge's level
+        log::trace!("pgrx_tests capture: log trace");
+        assert_eq!(captured("pgrx_tests capture: log trace"), None);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[pg_test]
+    fn test_tracing_bridge() {
+        tracing::error!(answer = 42, "pgrx_tests capture: {}", "tracing");
+        assert_eq!(
+            captured("pgrx_tests capture: tracing"),
+            Some((PgLogLevel::WARNING, Some("answer = 42".into())))
+        );
+    }
+}
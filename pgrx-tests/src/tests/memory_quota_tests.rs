@@ -0,0 +1,91 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::memory_quota::{memory_quota_used, with_memory_quota};
+    use pgrx::prelude::*;
+
+    #[pg_extern(memory_quota = 64 * 1024)]
+    fn quota_repeat(text: &str, times: i32) -> String {
+        text.repeat(times as usize)
+    }
+
+    #[pg_extern(memory_quota = 64 * 1024)]
+    fn quota_palloc(bytes: i64) -> Result<i64, std::num::TryFromIntError> {
+        let size = usize::try_from(bytes)?;
+        unsafe {
+            pg_sys::palloc(size);
+        }
+        Ok(bytes)
+    }
+
+    #[pg_test]
+    fn test_memory_quota_within_limit() {
+        let result = with_memory_quota(1024 * 1024, || {
+            let ptr = unsafe { pg_sys::palloc0(1000) };
+            assert!(!ptr.is_null());
+            memory_quota_used().unwrap()
+        });
+        assert!(result >= 1000);
+        assert_eq!(memory_quota_used(), None);
+    }
+
+    #[pg_test]
+    fn test_memory_quota_nested() {
+        with_memory_quota(1024 * 1024, || {
+            unsafe { pg_sys::palloc(100) };
+            with_memory_quota(1024, || {
+                unsafe { pg_sys::palloc(512) };
+                assert_eq!(memory_quota_used(), Some(512));
+            });
+            assert_eq!(memory_quota_used(), Some(100));
+        });
+    }
+
+    #[pg_test(error = "out of memory")]
+    fn test_memory_quota_exceeded() {
+        with_memory_quota(4096, || unsafe {
+            pg_sys::palloc(2048);
+            pg_sys::palloc(4096);
+        });
+    }
+
+    #[pg_test]
+    fn test_memory_quota_restores_context_after_error() {
+        let before = unsafe { pg_sys::CurrentMemoryContext };
+        let result = PgTryBuilder::new(|| {
+            with_memory_quota(1024, || unsafe { pg_sys::palloc(4096) });
+            false
+        })
+        .catch_others(|_| true)
+        .execute();
+        assert!(result);
+        assert_eq!(unsafe { pg_sys::CurrentMemoryContext }, before);
+    }
+
+    #[pg_test]
+    fn test_pg_extern_memory_quota() -> Result<(), pgrx::spi::Error> {
+        assert_eq!(Spi::get_one::<i64>("SELECT tests.quota_palloc(1024)")?, Some(1024));
+        assert_eq!(
+            Spi::get_one::<String>("SELECT tests.quota_repeat('ab', 3)")?,
+            Some("ababab".to_string())
+        );
+        Ok(())
+    }
+
+    #[pg_test(error = "out of memory")]
+    fn test_pg_extern_memory_quota_exceeded() -> Result<Option<i64>, pgrx::spi::Error> {
+        Spi::get_one::<i64>("SELECT tests.quota_palloc(1024 * 1024)")
+    }
+}
@@ -10,45 +10,105 @@
 mod aggregate_tests;
 mod anyarray_tests;
 mod array_tests;
+#[cfg(feature = "arrow")]
+mod arrow_tests;
+#[cfg(feature = "async")]
+mod async_tests;
 mod attributes_tests;
+mod backend_channel_tests;
 mod bgworker_tests;
+mod borrowed_text_tests;
+mod build_info_tests;
+#[cfg(feature = "cshim")]
+mod builtins_tests;
 mod bytea_tests;
 mod cfg_tests;
+#[cfg(feature = "cshim")]
+mod client_tests;
+mod compat_tests;
 mod datetime_tests;
+mod ddl_tests;
 mod default_arg_value_tests;
 mod derive_pgtype_lifetimes;
 mod enum_type_tests;
+#[cfg(feature = "cshim")]
+mod expr_tests;
+mod extschema_tests;
+#[cfg(feature = "failpoints")]
+mod failpoints_tests;
 mod fcinfo_tests;
 mod from_into_datum_tests;
 mod geo_tests;
 mod guc_tests;
+mod hash_eq_tests;
 mod heap_tuple;
 #[cfg(feature = "cshim")]
 mod hooks_tests;
+mod hstore_tests;
+#[cfg(feature = "http")]
+mod http_tests;
 mod inet_tests;
+mod init_tests;
+mod instrumentation_tests;
 mod internal_tests;
 mod issue1134;
+mod jobs_tests;
 mod json_tests;
+mod large_object_tests;
 mod lifetime_tests;
 mod log_tests;
+mod logging_tests;
 mod memcxt_tests;
+mod memory_quota_tests;
 mod name_tests;
+#[cfg(feature = "node-debug")]
+mod node_debug_tests;
+#[cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+mod numeric_crate_tests;
 mod numeric_tests;
+mod oid_tests;
+mod opclass_tests;
+mod operator_tests;
+mod ord_tests;
+#[cfg(feature = "cshim")]
+mod parser_tests;
+mod partition_tests;
 mod pg_extern_tests;
 mod pg_guard_tests;
+mod pg_test_conditions_tests;
 mod pg_try_tests;
 mod pgbox_tests;
 mod pgrx_module_qualification;
+mod planner_support_tests;
 mod postgres_type_tests;
+mod proptest_tests;
+#[cfg(feature = "cshim")]
+mod query_rewriter_tests;
+mod quote_tests;
 mod range_tests;
+mod resowner_tests;
 mod result_tests;
 mod roundtrip_tests;
+mod schedule_tests;
 mod schema_tests;
+mod session_cache_tests;
 mod shmem_tests;
+mod spi_retry_tests;
+mod spi_security_tests;
 mod spi_tests;
+mod spi_trace_tests;
+mod sql_examples_tests;
 mod srf_tests;
 mod struct_type_tests;
+mod symbols_tests;
+mod temp_file_tests;
+mod temp_table_tests;
+#[cfg(feature = "cshim")]
+mod text_search_tests;
+mod thread_check_tests;
+mod timeout_tests;
 mod trigger_tests;
+mod tuplesort_tests;
 mod uuid_tests;
 mod variadic_tests;
 mod xact_callback_tests;
@@ -0,0 +1,30 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_node_debug_uses_node_to_string() {
+        let node = unsafe { *pg_sys::makeBoolConst(true, false).cast::<pg_sys::Const>() };
+        let debug = format!("{node:?}");
+        assert!(debug.starts_with("{CONST"), "{debug}");
+        assert_eq!(debug, node.to_string());
+    }
+
+    #[pg_test]
+    fn test_node_debug_of_invalid_node() {
+        let node = unsafe { std::mem::zeroed::<pg_sys::Const>() };
+        assert_eq!(format!("{node:?}"), "Const { <invalid> }");
+    }
+}
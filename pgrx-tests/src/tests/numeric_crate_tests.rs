@@ -0,0 +1,155 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    #[cfg(feature = "num-bigint")]
+    use num_bigint::BigInt;
+    #[cfg(feature = "num-bigint")]
+    use pgrx::numeric::Error;
+    use pgrx::prelude::*;
+    #[cfg(feature = "rust_decimal")]
+    use rust_decimal::Decimal;
+    #[cfg(any(feature = "num-bigint", feature = "rust_decimal"))]
+    use std::str::FromStr;
+
+    #[cfg(feature = "rust_decimal")]
+    const DECIMALS: &[&str] = &[
+        "0",
+        "0.00",
+        "1",
+        "-1",
+        "1.10",
+        "10000",
+        "-0.0001",
+        "12345.678",
+        "123456789.123456789",
+        "0.0000000000000000000000000001",
+        "79228162514264337593543950335",
+        "-7922816251426433759354395033.5",
+    ];
+
+    #[cfg(feature = "num-bigint")]
+    const BIGINTS: &[&str] = &[
+        "0",
+        "1",
+        "-1",
+        "9999",
+        "10000",
+        "-9223372036854775809",
+        "340282366920938463463374607431768211456",
+        "-123456789012345678901234567890123456789012345678901234567890",
+    ];
+
+    #[cfg(feature = "rust_decimal")]
+    #[pg_extern]
+    fn decimal_times_two(value: Decimal) -> Decimal {
+        value * Decimal::TWO
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[pg_extern]
+    fn bigint_plus_one(value: BigInt) -> BigInt {
+        value + 1
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[pg_test]
+    fn test_decimal_into_numeric() -> Result<(), pgrx::spi::Error> {
+        for &text in DECIMALS {
+            let decimal = Decimal::from_str(text).unwrap();
+            let numeric = Spi::get_one_with_args::<String>(
+                "SELECT $1::text",
+                vec![(PgBuiltInOids::NUMERICOID.oid(), decimal.into_datum())],
+            )?;
+            // the scale is kept, too
+            assert_eq!(numeric.as_deref(), Some(text));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[pg_test]
+    fn test_decimal_from_numeric() -> Result<(), pgrx::spi::Error> {
+        for &text in DECIMALS {
+            let decimal = Spi::get_one::<Decimal>(&format!("SELECT '{text}'::numeric"))?
+                .expect("datum was null");
+            assert_eq!(decimal.to_string(), text);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[pg_test]
+    fn test_decimal_out_of_range() -> Result<(), pgrx::spi::Error> {
+        for text in ["79228162514264337593543950336", "0.00000000000000000000000000001", "NaN"] {
+            let numeric = Spi::get_one::<AnyNumeric>(&format!("SELECT '{text}'::numeric"))?
+                .expect("datum was null");
+            assert!(matches!(
+                Decimal::try_from(numeric),
+                Err(Error::OutOfRange(_) | Error::Invalid(_))
+            ));
+        }
+
+        // trailing zeros beyond a Decimal's scale aren't lost precision
+        let numeric = Spi::get_one::<AnyNumeric>("SELECT 1.000000000000000000000000000000")?
+            .expect("datum was null");
+        assert_eq!(Decimal::try_from(numeric), Ok(Decimal::ONE));
+        Ok(())
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[pg_test]
+    fn test_decimal_argument_and_return() -> Result<(), pgrx::spi::Error> {
+        let result = Spi::get_one::<String>("SELECT decimal_times_two(1234.5678)::text")?;
+        assert_eq!(result.as_deref(), Some("2469.1356"));
+        Ok(())
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[pg_test]
+    fn test_bigint_numeric_roundtrip() -> Result<(), pgrx::spi::Error> {
+        for &text in BIGINTS {
+            let bigint = BigInt::from_str(text).unwrap();
+            let numeric = Spi::get_one_with_args::<String>(
+                "SELECT $1::text",
+                vec![(PgBuiltInOids::NUMERICOID.oid(), bigint.clone().into_datum())],
+            )?;
+            assert_eq!(numeric.as_deref(), Some(text));
+
+            let roundtrip = Spi::get_one::<BigInt>(&format!("SELECT '{text}'::numeric"))?;
+            assert_eq!(roundtrip, Some(bigint));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[pg_test]
+    fn test_bigint_from_numeric_with_fraction() -> Result<(), pgrx::spi::Error> {
+        let numeric = Spi::get_one::<AnyNumeric>("SELECT 42.00::numeric")?.expect("datum was null");
+        assert_eq!(BigInt::try_from(numeric), Ok(BigInt::from(42)));
+
+        let numeric = Spi::get_one::<AnyNumeric>("SELECT 42.5::numeric")?.expect("datum was null");
+        assert!(matches!(BigInt::try_from(numeric), Err(Error::OutOfRange(_))));
+        Ok(())
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[pg_test]
+    fn test_bigint_argument_and_return() -> Result<(), pgrx::spi::Error> {
+        let result =
+            Spi::get_one::<String>("SELECT bigint_plus_one(99999999999999999999999999999)::text")?;
+        assert_eq!(result.as_deref(), Some("100000000000000000000000000000"));
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use pgrx::{FunctionOid, PgRelation, RelationOid, TypeOid};
+
+#[pg_extern]
+fn relation_name(rel: RelationOid) -> String {
+    unsafe { PgRelation::open(rel.oid()) }.name().to_string()
+}
+
+#[pg_extern]
+fn type_of_text() -> TypeOid {
+    TypeOid::of::<String>()
+}
+
+#[pg_extern]
+fn same_function(func: FunctionOid) -> FunctionOid {
+    func
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::TypeOid;
+
+    #[pg_test]
+    fn test_typed_oid_sql_types() -> Result<(), spi::Error> {
+        assert_eq!(
+            Spi::get_one::<String>("SELECT relation_name('pg_catalog.pg_class'::regclass)")?,
+            Some("pg_class".into())
+        );
+        assert_eq!(Spi::get_one::<String>("SELECT type_of_text()::text")?, Some("text".into()));
+        assert_eq!(
+            Spi::get_one::<String>("SELECT same_function('pg_catalog.upper(text)')::text")?,
+            Some("upper(text)".into())
+        );
+        assert_eq!(
+            Spi::get_one::<TypeOid>("SELECT 'pg_catalog.int4'::regtype")?,
+            Some(TypeOid::of::<i32>())
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_type_oid_named() -> Result<(), spi::Error> {
+        assert_eq!(TypeOid::named("int4"), Some(TypeOid::of::<i32>()));
+        assert_eq!(TypeOid::named("pg_catalog.text"), Some(TypeOid::of::<String>()));
+        assert_eq!(TypeOid::named("oid_tests_mood"), None);
+
+        // the cached miss is forgotten once the type exists
+        Spi::run("CREATE TYPE tests.oid_tests_mood AS ENUM ('happy', 'sad')")?;
+        let mood = TypeOid::named("tests.oid_tests_mood");
+        assert!(matches!(mood, Some(mood) if mood.is_valid()));
+        assert_eq!(Spi::get_one::<TypeOid>("SELECT 'tests.oid_tests_mood'::regtype")?, mood);
+        Ok(())
+    }
+}
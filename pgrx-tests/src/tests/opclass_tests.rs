@@ -0,0 +1,257 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::brin::{BrinOpClass, BrinSummary};
+use pgrx::prelude::*;
+use pgrx::spgist::{as_slice, palloc_array, scan_keys, SpGistOpClass};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    PostgresType,
+    PostgresOrd
+)]
+pub struct Reading {
+    value: i64,
+}
+
+#[pg_extern]
+fn reading(value: i64) -> Reading {
+    Reading { value }
+}
+
+fn reading_from_datum(datum: pg_sys::Datum) -> Reading {
+    unsafe { Reading::from_datum(datum, false) }.expect("reading should not be NULL")
+}
+
+/// Partitions readings around their median, which each inner tuple keeps as its prefix
+#[pg_opclass]
+impl SpGistOpClass for Reading {
+    const OPERATORS: &'static [(u16, &'static str)] = &[(1, "=")];
+
+    fn config(input: &pg_sys::spgConfigIn, output: &mut pg_sys::spgConfigOut) {
+        output.prefixType = input.attType;
+        output.labelType = pg_sys::VOIDOID;
+        output.canReturnData = true;
+        output.longValuesOK = false;
+    }
+
+    fn choose(input: &pg_sys::spgChooseIn, output: &mut pg_sys::spgChooseOut) {
+        let node = if input.allTheSame
+            || reading_from_datum(input.datum) < reading_from_datum(input.prefixDatum)
+        {
+            0
+        } else {
+            1
+        };
+        output.resultType = pg_sys::spgChooseResultType_spgMatchNode;
+        output.result.matchNode = pg_sys::spgChooseOut__bindgen_ty_1__bindgen_ty_1 {
+            nodeN: node,
+            levelAdd: 0,
+            restDatum: input.datum,
+        };
+    }
+
+    fn picksplit(input: &pg_sys::spgPickSplitIn, output: &mut pg_sys::spgPickSplitOut) {
+        let datums = unsafe { as_slice(input.datums, input.nTuples) };
+        let readings = datums.iter().map(|datum| reading_from_datum(*datum)).collect::<Vec<_>>();
+        let mut sorted = readings.clone();
+        sorted.sort();
+        let median = sorted[sorted.len() / 2].clone();
+        let nodes = readings.iter().map(|r| if *r < median { 0 } else { 1 }).collect::<Vec<_>>();
+
+        output.hasPrefix = true;
+        output.prefixDatum = median.into_datum().unwrap();
+        output.nNodes = 2;
+        output.nodeLabels = std::ptr::null_mut();
+        output.mapTuplesToNodes = palloc_array(&nodes);
+        output.leafTupleDatums = palloc_array(datums);
+    }
+
+    fn inner_consistent(
+        input: &pg_sys::spgInnerConsistentIn,
+        output: &mut pg_sys::spgInnerConsistentOut,
+    ) {
+        let mut visit = [true, true];
+        if !input.allTheSame {
+            let median = reading_from_datum(input.prefixDatum);
+            for key in unsafe { scan_keys(input.scankeys, input.nkeys) } {
+                if reading_from_datum(key.sk_argument) < median {
+                    visit[1] = false;
+                } else {
+                    visit[0] = false;
+                }
+            }
+        }
+        let nodes = (0..input.nNodes)
+            .filter(|node| input.allTheSame || visit[*node as usize])
+            .collect::<Vec<_>>();
+        output.nNodes = nodes.len() as i32;
+        output.nodeNumbers = palloc_array(&nodes);
+    }
+
+    fn leaf_consistent(
+        input: &pg_sys::spgLeafConsistentIn,
+        output: &mut pg_sys::spgLeafConsistentOut,
+    ) -> bool {
+        output.recheck = false;
+        output.leafValue = input.leafDatum;
+        let reading = reading_from_datum(input.leafDatum);
+        unsafe { scan_keys(input.scankeys, input.nkeys) }
+            .iter()
+            .all(|key| reading == reading_from_datum(key.sk_argument))
+    }
+}
+
+/// Summarizes each block range by its smallest and largest readings
+#[pg_opclass]
+impl BrinOpClass for Reading {
+    type Value = Reading;
+    const OPERATORS: &'static [(u16, &'static str)] =
+        &[(1, "<"), (2, "<="), (3, "="), (4, ">="), (5, ">")];
+
+    fn stored_types(column_type: pg_sys::Oid) -> Vec<pg_sys::Oid> {
+        vec![column_type, column_type]
+    }
+
+    fn add_value(summary: &mut BrinSummary, value: Reading) -> bool {
+        let mut changed = false;
+        if summary.is_empty() || value < summary.get::<Reading>(0).unwrap() {
+            summary.set(0, value.clone());
+            changed = true;
+        }
+        if summary.is_empty() || value > summary.get::<Reading>(1).unwrap() {
+            summary.set(1, value);
+            changed = true;
+        }
+        changed
+    }
+
+    fn consistent(summary: &BrinSummary, key: &pg_sys::ScanKeyData) -> bool {
+        let query = reading_from_datum(key.sk_argument);
+        let min = summary.get::<Reading>(0).unwrap();
+        let max = summary.get::<Reading>(1).unwrap();
+        match key.sk_strategy {
+            1 => min < query,
+            2 => min <= query,
+            3 => min <= query && query <= max,
+            4 => max >= query,
+            5 => max > query,
+            _ => true,
+        }
+    }
+
+    fn union(summary: &mut BrinSummary, other: &BrinSummary) {
+        let min = other.get::<Reading>(0).unwrap();
+        let max = other.get::<Reading>(1).unwrap();
+        if min < summary.get::<Reading>(0).unwrap() {
+            summary.set(0, min);
+        }
+        if max > summary.get::<Reading>(1).unwrap() {
+            summary.set(1, max);
+        }
+    }
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_opclasses_created() -> Result<(), spi::Error> {
+        let opclasses = Spi::get_one::<Vec<String>>(
+            "SELECT array_agg(opcname::text || ' ' || amname::text ORDER BY opcname)
+               FROM pg_opclass JOIN pg_am ON pg_am.oid = opcmethod
+              WHERE opcname IN ('reading_brin_ops', 'reading_spgist_ops') AND opcdefault",
+        )?;
+        assert_eq!(
+            opclasses,
+            Some(vec![
+                "reading_brin_ops brin".to_string(),
+                "reading_spgist_ops spgist".to_string()
+            ])
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_spgist_index() -> Result<(), spi::Error> {
+        Spi::run(
+            "CREATE TABLE spgist_readings AS
+                SELECT reading(i % 100) AS r FROM generate_series(1, 5000) i",
+        )?;
+        Spi::run("CREATE INDEX idxspgist_readings ON spgist_readings USING spgist (r)")?;
+        Spi::run("ANALYZE spgist_readings")?;
+        Spi::run("SET LOCAL enable_seqscan = off")?;
+
+        let query = "SELECT count(*) FROM spgist_readings WHERE r = reading(42)";
+        let plan = Spi::explain(query)?;
+        assert!(plan.0.to_string().contains("idxspgist_readings"));
+        assert_eq!(Spi::get_one::<i64>(query)?, Some(50));
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM spgist_readings WHERE r = reading(100)")?,
+            Some(0)
+        );
+
+        // values inserted into the existing index are found too
+        Spi::run("INSERT INTO spgist_readings SELECT reading(42) FROM generate_series(1, 10)")?;
+        assert_eq!(Spi::get_one::<i64>(query)?, Some(60));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_brin_index() -> Result<(), spi::Error> {
+        Spi::run(
+            "CREATE TABLE brin_readings AS
+                SELECT reading(i) AS r FROM generate_series(1, 10000) i",
+        )?;
+        Spi::run(
+            "CREATE INDEX idxbrin_readings ON brin_readings
+                USING brin (r) WITH (pages_per_range = 1)",
+        )?;
+        Spi::run("ANALYZE brin_readings")?;
+        Spi::run("SET LOCAL enable_seqscan = off")?;
+
+        let query = "SELECT count(*) FROM brin_readings WHERE r < reading(100)";
+        let plan = Spi::explain(query)?;
+        assert!(plan.0.to_string().contains("idxbrin_readings"));
+        assert_eq!(Spi::get_one::<i64>(query)?, Some(99));
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM brin_readings WHERE r = reading(5000)")?,
+            Some(1)
+        );
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM brin_readings WHERE r >= reading(9990)")?,
+            Some(11)
+        );
+
+        // rows added to an already-summarized range widen its summary
+        Spi::run("INSERT INTO brin_readings VALUES (reading(20000)), (NULL)")?;
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM brin_readings WHERE r > reading(15000)")?,
+            Some(1)
+        );
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM brin_readings WHERE r IS NULL")?,
+            Some(1)
+        );
+        Ok(())
+    }
+}
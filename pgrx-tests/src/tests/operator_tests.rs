@@ -0,0 +1,65 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+
+#[pg_operator(immutable, parallel_safe)]
+#[opname(<<~)]
+#[commutator(~>>)]
+#[negator(!<<~)]
+fn int_before_bigint(a: i32, b: i64) -> bool {
+    (a as i64) < b
+}
+
+#[pg_operator(immutable, parallel_safe)]
+#[opname(~>>)]
+#[commutator(<<~)]
+fn bigint_after_int(a: i64, b: i32) -> bool {
+    a > (b as i64)
+}
+
+#[pg_operator(immutable, parallel_safe)]
+#[opname(!<<~)]
+#[negator(<<~)]
+fn int_not_before_bigint(a: i32, b: i64) -> bool {
+    (a as i64) >= b
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    /// The name of the function implementing the commutator or negator of `opname`, which would
+    /// be `NULL` if it were left as a shell operator
+    fn related_function(opname: &str, relation: &str) -> Result<Option<String>, spi::Error> {
+        Spi::get_one(&format!(
+            "SELECT proc.proname::text
+               FROM pg_operator op
+               JOIN pg_operator related ON related.oid = op.{relation}
+               LEFT JOIN pg_proc proc ON proc.oid = related.oprcode
+              WHERE op.oprname = '{opname}'"
+        ))
+    }
+
+    #[pg_test]
+    fn test_commutator_and_negator_are_defined() -> Result<(), spi::Error> {
+        assert_eq!(related_function("<<~", "oprcom")?.as_deref(), Some("bigint_after_int"));
+        assert_eq!(related_function("~>>", "oprcom")?.as_deref(), Some("int_before_bigint"));
+        assert_eq!(related_function("<<~", "oprnegate")?.as_deref(), Some("int_not_before_bigint"));
+        assert_eq!(related_function("!<<~", "oprnegate")?.as_deref(), Some("int_before_bigint"));
+
+        assert_eq!(Spi::get_one::<bool>("SELECT 1 <<~ 2::bigint AND 2::bigint ~>> 1")?, Some(true));
+        assert_eq!(Spi::get_one::<bool>("SELECT NOT (1 <<~ 2::bigint)")?, Some(false));
+        Ok(())
+    }
+}
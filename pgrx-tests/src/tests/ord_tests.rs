@@ -0,0 +1,103 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Only derives `PostgresOrd`, so its `=` and `<>` operators come from the btree operator class
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    PostgresType,
+    PostgresOrd
+)]
+pub struct OrderedThing {
+    rank: i32,
+    name: String,
+}
+
+#[pg_extern]
+fn ordered_thing(rank: i32, name: &str) -> OrderedThing {
+    OrderedThing { rank, name: name.to_string() }
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    fn create_things() -> Result<(), spi::Error> {
+        Spi::run(
+            "CREATE TABLE ordered_things AS
+                SELECT ordered_thing(i % 7, 'thing ' || i) AS thing FROM generate_series(1, 1000) i",
+        )
+    }
+
+    #[pg_test]
+    fn test_ord_operators() -> Result<(), spi::Error> {
+        let result = Spi::get_one::<bool>(
+            "SELECT ordered_thing(1, 'a') < ordered_thing(1, 'b')
+                AND ordered_thing(1, 'b') <= ordered_thing(1, 'b')
+                AND ordered_thing(2, 'a') > ordered_thing(1, 'z')
+                AND ordered_thing(2, 'a') >= ordered_thing(2, 'a')
+                AND ordered_thing(2, 'a') = ordered_thing(2, 'a')
+                AND ordered_thing(2, 'a') <> ordered_thing(2, 'b')",
+        )?;
+        assert_eq!(result, Some(true));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_order_by() -> Result<(), spi::Error> {
+        create_things()?;
+        let names = Spi::get_one::<Vec<String>>(
+            "SELECT array_agg(thing::text) FROM (
+                SELECT thing FROM ordered_things ORDER BY thing DESC LIMIT 2
+             ) t",
+        )?
+        .unwrap();
+        assert_eq!(
+            names,
+            vec![
+                r#"{"rank":6,"name":"thing 993"}"#.to_string(),
+                r#"{"rank":6,"name":"thing 986"}"#.to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_btree_index() -> Result<(), spi::Error> {
+        create_things()?;
+        Spi::run("CREATE INDEX idxordered_things ON ordered_things (thing)")?;
+        Spi::run("ANALYZE ordered_things")?;
+        Spi::run("SET LOCAL enable_seqscan = off")?;
+
+        let query = "SELECT count(*) FROM ordered_things WHERE thing = ordered_thing(3, 'thing 3')";
+        let plan = Spi::explain(query)?;
+        assert!(plan.0.to_string().contains("idxordered_things"));
+        assert_eq!(Spi::get_one::<i64>(query)?, Some(1));
+
+        let below = Spi::get_one::<i64>(
+            "SELECT count(*) FROM ordered_things WHERE thing < ordered_thing(1, '')",
+        )?;
+        // every multiple of 7
+        assert_eq!(below, Some(142));
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::parser::{parse, Statement};
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_parse_multiple_statements() {
+        let stmts = parse("SELECT 1;\n  UPDATE t SET x = 1 WHERE y = 2 ;DROP TABLE t").unwrap();
+        assert_eq!(stmts.len(), 3);
+        assert_eq!(stmts[0].sql(), "SELECT 1");
+        assert_eq!(stmts[1].sql(), "UPDATE t SET x = 1 WHERE y = 2");
+        assert_eq!(stmts[2].sql(), "DROP TABLE t");
+
+        assert!(matches!(stmts[0].statement(), Statement::Select(_)));
+        match stmts[1].statement() {
+            Statement::Update(update) => unsafe {
+                assert!(!update.whereClause.is_null());
+                let relname = core::ffi::CStr::from_ptr((*update.relation).relname);
+                assert_eq!(relname.to_str().unwrap(), "t");
+            },
+            _ => panic!("not an UPDATE"),
+        }
+        assert!(matches!(stmts[2].statement(), Statement::Drop(_)));
+        assert_eq!(stmts[2].node_tag(), pg_sys::NodeTag_T_DropStmt);
+    }
+
+    #[pg_test]
+    fn test_parse_other_statement() {
+        let stmts = parse("VACUUM t").unwrap();
+        assert!(matches!(stmts[0].statement(), Statement::Other(_)));
+        assert_eq!(stmts[0].node_tag(), pg_sys::NodeTag_T_VacuumStmt);
+        assert!(stmts[0].to_string().contains("VACUUMSTMT"));
+    }
+
+    #[pg_test]
+    fn test_parse_empty() {
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("  ;  ").unwrap().is_empty());
+    }
+
+    #[pg_test]
+    fn test_parse_syntax_error() {
+        let err = parse("SELEKT 1").unwrap_err();
+        assert_eq!(err.message, "syntax error at or near \"SELEKT\"");
+    }
+}
@@ -0,0 +1,140 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    #[cfg(feature = "cshim")]
+    use pgrx::partition::PartitionRouter;
+    use pgrx::partition::{PartitionBound, RangeBound};
+    use pgrx::prelude::*;
+    use pgrx::PgRelation;
+
+    fn open(name: &str) -> PgRelation {
+        PgRelation::open_with_name_and_share_lock(name).unwrap()
+    }
+
+    fn month(from: &str, to: &str) -> PartitionBound {
+        PartitionBound::Range {
+            from: vec![RangeBound::value(from)],
+            to: vec![RangeBound::value(to)],
+        }
+    }
+
+    #[pg_test]
+    fn test_bound_sql() {
+        assert_eq!(
+            PartitionBound::Range {
+                from: vec![RangeBound::MinValue, RangeBound::value(1)],
+                to: vec![RangeBound::value("it's"), RangeBound::MaxValue],
+            }
+            .to_sql(),
+            "FOR VALUES FROM (MINVALUE, '1') TO ('it''s', MAXVALUE)"
+        );
+        assert_eq!(
+            PartitionBound::List(vec![Some("a".into()), None]).to_sql(),
+            "FOR VALUES IN ('a', NULL)"
+        );
+        assert_eq!(
+            PartitionBound::Hash { modulus: 4, remainder: 1 }.to_sql(),
+            "FOR VALUES WITH (MODULUS 4, REMAINDER 1)"
+        );
+        assert_eq!(PartitionBound::Default.to_sql(), "DEFAULT");
+    }
+
+    #[pg_test]
+    fn test_create_attach_detach_partition() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE TABLE tests.events (at date NOT NULL, id int) PARTITION BY RANGE (at)")?;
+        let events = open("tests.events");
+
+        let january =
+            events.create_partition("events_2023_01", &month("2023-01-01", "2023-02-01"))?;
+        assert_eq!(january.name(), "events_2023_01");
+        assert_eq!(january.namespace(), "tests");
+        assert_eq!(
+            january.partition_bound()?.as_deref(),
+            Some("FOR VALUES FROM ('2023-01-01') TO ('2023-02-01')")
+        );
+        assert_eq!(events.partition_bound()?, None);
+
+        Spi::run("CREATE TABLE tests.events_2023_02 (LIKE tests.events)")?;
+        let february = open("tests.events_2023_02");
+        events.attach_partition(&february, &month("2023-02-01", "2023-03-01"))?;
+        assert_eq!(events.partitions()?, vec![january.oid(), february.oid()]);
+
+        events.detach_partition(&january)?;
+        assert_eq!(events.partitions()?, vec![february.oid()]);
+        assert_eq!(january.partition_bound()?, None);
+        Ok(())
+    }
+
+    #[pg_test(error = "no partition of relation \"events\" found for row")]
+    fn test_partition_bounds_are_enforced() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE TABLE tests.events (at date NOT NULL, id int) PARTITION BY RANGE (at)")?;
+        open("tests.events")
+            .create_partition("events_2023_01", &month("2023-01-01", "2023-02-01"))?;
+        Spi::run("INSERT INTO tests.events VALUES ('2023-03-01', 1)")
+    }
+
+    #[cfg(feature = "cshim")]
+    #[pg_test]
+    fn test_partition_router() -> Result<(), Box<dyn std::error::Error>> {
+        Spi::run(
+            "CREATE TABLE tests.readings (at date NOT NULL, sensor int NOT NULL) PARTITION BY RANGE (at);
+             CREATE TABLE tests.readings_2023_01 PARTITION OF tests.readings
+                 FOR VALUES FROM ('2023-01-01') TO ('2023-02-01') PARTITION BY HASH (sensor);
+             CREATE TABLE tests.readings_2023_01_h0 PARTITION OF tests.readings_2023_01
+                 FOR VALUES WITH (MODULUS 2, REMAINDER 0);
+             CREATE TABLE tests.readings_2023_01_h1 PARTITION OF tests.readings_2023_01
+                 FOR VALUES WITH (MODULUS 2, REMAINDER 1);
+             CREATE TABLE tests.readings_other PARTITION OF tests.readings DEFAULT;",
+        )?;
+        let readings = open("tests.readings");
+        let mut router = PartitionRouter::new(&readings)?;
+
+        for (at, sensor) in [("2023-01-15", 1), ("2023-01-20", 2), ("2023-03-01", 3)] {
+            let mut reading = PgHeapTuple::new_composite_type("tests.readings")?;
+            reading.set_by_name(
+                "at",
+                Spi::get_one::<pgrx::datum::Date>(&format!("SELECT '{at}'::date"))?,
+            )?;
+            reading.set_by_name("sensor", sensor)?;
+            let routed = unsafe { router.route(&reading) }?;
+
+            // agree with where Postgres puts it
+            let expected = Spi::get_one::<pg_sys::Oid>(&format!(
+                "INSERT INTO tests.readings VALUES ('{at}', {sensor}) RETURNING tableoid"
+            ))?;
+            assert_eq!(routed, expected);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cshim")]
+    #[pg_test]
+    fn test_partition_router_without_default() -> Result<(), Box<dyn std::error::Error>> {
+        Spi::run("CREATE TABLE tests.events (at date NOT NULL, id int) PARTITION BY RANGE (at)")?;
+        let events = open("tests.events");
+        let january =
+            events.create_partition("events_2023_01", &month("2023-01-01", "2023-02-01"))?;
+        let mut router = PartitionRouter::new(&events)?;
+
+        let mut event = PgHeapTuple::new_composite_type("tests.events")?;
+        event.set_by_name("at", Spi::get_one::<pgrx::datum::Date>("SELECT '2023-01-31'::date")?)?;
+        assert_eq!(unsafe { router.route(&event) }?, Some(january.oid()));
+
+        event.set_by_name("at", Spi::get_one::<pgrx::datum::Date>("SELECT '2023-02-01'::date")?)?;
+        assert_eq!(unsafe { router.route(&event) }?, None);
+        Ok(())
+    }
+}
@@ -136,6 +136,35 @@ mod tests {
         assert_eq!(interval_type, Ok(Some(pg_sys::INTERVALOID)));
     }
 
+    #[pg_extern]
+    fn anyele_or_default(
+        value: Option<pgrx::AnyElement>,
+        default: pgrx::AnyElement,
+    ) -> pgrx::AnyElement {
+        value.unwrap_or(default)
+    }
+
+    #[pg_extern]
+    fn anyele_forty_two(_value: pgrx::AnyElement) -> Option<pgrx::AnyElement> {
+        pgrx::AnyElement::new(42i32)
+    }
+
+    #[pg_test]
+    fn test_anyele_return() -> Result<(), spi::Error> {
+        let int = Spi::get_one::<i32>(r#"SELECT tests."anyele_or_default"(NULL::integer, 7)"#)?;
+        assert_eq!(int, Some(7));
+        let text = Spi::get_one::<String>(r#"SELECT tests."anyele_or_default"('a'::text, 'b')"#)?;
+        assert_eq!(text.as_deref(), Some("a"));
+        let forty_two = Spi::get_one::<i32>(r#"SELECT tests."anyele_forty_two"(1)"#)?;
+        assert_eq!(forty_two, Some(42));
+        Ok(())
+    }
+
+    #[pg_test(error = "function returned type integer but its return type resolved to text")]
+    fn test_anyele_return_wrong_type() -> Result<Option<String>, spi::Error> {
+        Spi::get_one::<String>(r#"SELECT tests."anyele_forty_two"('a'::text)"#)
+    }
+
     #[pg_extern(name = "custom_name")]
     fn fn_custom() -> bool {
         true
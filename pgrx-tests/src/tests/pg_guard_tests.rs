@@ -38,9 +38,34 @@ extern "C" fn extern_func_impl_2<'a>() -> bool {
     true
 }
 
+// This ensures that a function in an `extern "C"` block can opt out of the guard, and that it's
+// still callable as declared
+#[pg_guard]
+extern "C" {
+    /// # Safety
+    ///
+    /// Only compares bytes: it doesn't allocate, and can't `ereport()`.
+    #[pg_guard(skip)]
+    fn pg_strcasecmp(
+        s1: *const core::ffi::c_char,
+        s2: *const core::ffi::c_char,
+    ) -> core::ffi::c_int;
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pg_schema]
 mod tests {
     #[allow(unused_imports)]
     use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_unguarded_extern() {
+        let strcasecmp = |a: &[u8], b: &[u8]| unsafe {
+            super::pg_strcasecmp(a.as_ptr().cast(), b.as_ptr().cast())
+        };
+        assert_eq!(strcasecmp(b"PgRx\0", b"pgrx\0"), 0);
+        assert!(strcasecmp(b"a\0", b"B\0") < 0);
+    }
 }
@@ -0,0 +1,69 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    fn always() -> bool {
+        true
+    }
+
+    fn never() -> bool {
+        false
+    }
+
+    #[pg_test(min_pg = 99)]
+    fn test_min_pg_skips() {
+        panic!("should have been skipped because Postgres is too old");
+    }
+
+    #[pg_test(max_pg = 10)]
+    fn test_max_pg_skips() {
+        panic!("should have been skipped because Postgres is too new");
+    }
+
+    #[pg_test(min_pg = 11, max_pg = 99)]
+    fn test_version_range_runs() {
+        assert_eq!(Spi::get_one::<i32>("SELECT 1"), Ok(Some(1)));
+    }
+
+    #[pg_test(skip_if = "always")]
+    fn test_skip_if_skips() {
+        panic!("should have been skipped because `always()` returned true");
+    }
+
+    #[pg_test(skip_if = "never")]
+    fn test_skip_if_runs() {
+        assert_eq!(Spi::get_one::<i32>("SELECT 1"), Ok(Some(1)));
+    }
+
+    #[pg_test(requires_extension = "pgrx_no_such_extension")]
+    fn test_requires_missing_extension_skips() {
+        panic!("should have been skipped because the extension doesn't exist");
+    }
+
+    #[pg_test(requires_extension = "plpgsql")]
+    fn test_requires_extension_runs() {
+        let installed = Spi::get_one::<bool>(
+            "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'plpgsql')",
+        );
+        assert_eq!(installed, Ok(Some(true)));
+    }
+
+    #[pg_test(min_pg = 11, requires_extension = "plpgsql", error = "expected")]
+    fn test_conditions_with_extern_args() {
+        error!("expected")
+    }
+}
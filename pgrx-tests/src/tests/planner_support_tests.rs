@@ -0,0 +1,154 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use pgrx::Internal;
+
+/// Estimates that a quarter of all rows match, whatever the arguments
+#[pg_extern(immutable, parallel_safe)]
+fn quarter_sel(_root: Internal, _operator: pg_sys::Oid, _args: Internal, _var_relid: i32) -> f64 {
+    0.25
+}
+
+#[pg_operator(immutable, parallel_safe)]
+#[opname(=%=)]
+#[restrict(quarter_sel)]
+fn same_remainder(a: i32, b: i32) -> bool {
+    a % 10 == b % 10
+}
+
+#[cfg(any(
+    feature = "pg12",
+    feature = "pg13",
+    feature = "pg14",
+    feature = "pg15",
+    feature = "pg16"
+))]
+mod support {
+    use pgrx::prelude::*;
+    #[cfg(feature = "cshim")]
+    use pgrx::{
+        planner_support::{self, SupportRequest},
+        Internal, PgList,
+    };
+
+    #[cfg(feature = "cshim")]
+    #[pg_extern(immutable, parallel_safe, support = plus_support)]
+    fn plus(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    /// `plus(x, 0)` is just `x`
+    #[cfg(feature = "cshim")]
+    #[pg_extern(immutable, parallel_safe)]
+    fn plus_support(request: Internal) -> Internal {
+        unsafe {
+            if let Some(SupportRequest::Simplify(request)) = SupportRequest::from_internal(request)
+            {
+                let args = PgList::<pg_sys::Node>::from_pg((*request.fcall).args);
+                let (x, y) = (args.get_ptr(0).unwrap(), args.get_ptr(1).unwrap());
+                if pgrx::is_a(y, pg_sys::NodeTag_T_Const) {
+                    let y = &*y.cast::<pg_sys::Const>();
+                    if !y.constisnull && i32::from_datum(y.constvalue, false) == Some(0) {
+                        return planner_support::replace_with(x);
+                    }
+                }
+            }
+            planner_support::unsupported()
+        }
+    }
+
+    /// Left `VOLATILE`, so only its support function lets the planner fold it
+    #[pg_extern(fold_constants)]
+    fn times_ten(x: i32) -> i32 {
+        x * 10
+    }
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_restrict_selectivity_estimator() -> Result<(), spi::Error> {
+        Spi::run("CREATE TABLE remainders AS SELECT x FROM generate_series(1, 1000) x")?;
+        Spi::run("ANALYZE remainders")?;
+
+        let plan = Spi::explain("SELECT * FROM remainders WHERE x =%= 3")?;
+        assert_eq!(plan.0[0]["Plan"]["Plan Rows"], 250);
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM remainders WHERE x =%= 3")?,
+            Some(100)
+        );
+        Ok(())
+    }
+
+    #[cfg(any(
+        feature = "pg12",
+        feature = "pg13",
+        feature = "pg14",
+        feature = "pg15",
+        feature = "pg16"
+    ))]
+    #[cfg(feature = "cshim")]
+    #[pg_test]
+    fn test_support_function_simplifies() -> Result<(), spi::Error> {
+        let plan = Spi::connect(|mut client| {
+            client
+                .update(
+                    "EXPLAIN (VERBOSE, FORMAT JSON) SELECT plus(x, 0), plus(x, 1) FROM generate_series(1, 3) x",
+                    None,
+                    None,
+                )?
+                .first()
+                .get_one::<pgrx::Json>()
+        })?
+        .unwrap();
+        let output = plan.0[0]["Plan"]["Output"].to_string();
+        // only the call with a non-zero argument remains
+        assert_eq!(output.matches("plus(").count(), 1, "{output}");
+
+        assert_eq!(Spi::get_one::<i32>("SELECT plus(41, 0)")?, Some(41));
+        assert_eq!(Spi::get_one::<i32>("SELECT plus(41, 1)")?, Some(42));
+        Ok(())
+    }
+
+    #[cfg(any(
+        feature = "pg12",
+        feature = "pg13",
+        feature = "pg14",
+        feature = "pg15",
+        feature = "pg16"
+    ))]
+    #[pg_test]
+    fn test_fold_constants() -> Result<(), spi::Error> {
+        let plan = Spi::connect(|mut client| {
+            client
+                .update(
+                    "EXPLAIN (VERBOSE, FORMAT JSON) SELECT times_ten(4), times_ten(x) FROM generate_series(1, 3) x",
+                    None,
+                    None,
+                )?
+                .first()
+                .get_one::<pgrx::Json>()
+        })?
+        .unwrap();
+        let output = &plan.0[0]["Plan"]["Output"];
+        // the call with a constant argument was replaced by its result
+        assert_eq!(output[0], "40", "{output}");
+        assert_eq!(output.to_string().matches("times_ten(").count(), 1, "{output}");
+
+        assert_eq!(Spi::get_one::<i32>("SELECT times_ten(NULL)")?, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use proptest::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, PostgresType, Serialize, Deserialize)]
+pub struct RoundtripType {
+    id: i32,
+    label: String,
+}
+
+impl Arbitrary for RoundtripType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<i32>(), crate::proptest::strategies::text())
+            .prop_map(|(id, label)| RoundtripType { id, label })
+            .boxed()
+    }
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use super::RoundtripType;
+    use pgrx::prelude::*;
+    use pgrx_tests::proptest::{
+        assert_datum_roundtrip, assert_datum_roundtrip_with, check_datum_roundtrip, strategies,
+    };
+
+    #[pg_test]
+    fn test_roundtrip_integers() {
+        assert_datum_roundtrip::<i16>();
+        assert_datum_roundtrip::<i32>();
+        assert_datum_roundtrip::<i64>();
+    }
+
+    #[pg_test]
+    fn test_roundtrip_bool() {
+        assert_datum_roundtrip::<bool>();
+    }
+
+    #[pg_test]
+    fn test_roundtrip_floats() {
+        use proptest::num::f64;
+        assert_datum_roundtrip_with(f64::NORMAL | f64::SUBNORMAL | f64::ZERO);
+    }
+
+    #[pg_test]
+    fn test_roundtrip_text() {
+        assert_datum_roundtrip_with(strategies::text());
+    }
+
+    #[pg_test]
+    fn test_roundtrip_toasted_text() {
+        assert_datum_roundtrip_with(strategies::toast_text());
+    }
+
+    #[pg_test]
+    fn test_roundtrip_bytea() {
+        assert_datum_roundtrip_with(strategies::bytea());
+        assert_datum_roundtrip_with(strategies::toast_bytea());
+    }
+
+    #[pg_test]
+    fn test_roundtrip_postgres_type() {
+        assert_datum_roundtrip::<RoundtripType>();
+    }
+
+    #[pg_test]
+    fn test_check_single_value() {
+        check_datum_roundtrip(String::from("a string")).unwrap();
+        check_datum_roundtrip(i32::MIN).unwrap();
+    }
+
+    #[pg_test]
+    #[should_panic(expected = "failed to round-trip")]
+    fn test_roundtrip_failure_is_reported() {
+        // NaN is never equal to itself, so every NaN "fails" to round-trip
+        assert_datum_roundtrip_with(proptest::num::f64::QUIET_NAN);
+    }
+}
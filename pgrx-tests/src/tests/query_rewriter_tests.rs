@@ -0,0 +1,92 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::query_rewriter::{copy_query, register_query_rewriter, QueryRewriter};
+    use pgrx::PgList;
+
+    /// Adds `LIMIT 1` to queries that read from one specific table
+    struct LimitOne {
+        relid: pg_sys::Oid,
+        calls: usize,
+    }
+
+    impl QueryRewriter for LimitOne {
+        fn rewrite(&mut self, query: &pg_sys::Query) -> Option<PgBox<pg_sys::Query>> {
+            let rtable = unsafe { PgList::<pg_sys::RangeTblEntry>::from_pg(query.rtable) };
+            if !rtable.iter_ptr().any(|rte| unsafe { (*rte).relid } == self.relid) {
+                return None;
+            }
+            self.calls += 1;
+
+            // this would be a recursive call, but it's planned without being rewritten
+            Spi::get_one::<i32>("SELECT 1").unwrap();
+
+            let mut query = copy_query(query);
+            unsafe {
+                query.limitCount = pg_sys::makeConst(
+                    pg_sys::INT8OID,
+                    -1,
+                    pg_sys::InvalidOid,
+                    8,
+                    1i64.into(),
+                    false,
+                    true,
+                )
+                .cast();
+            }
+            #[cfg(not(any(feature = "pg11", feature = "pg12")))]
+            {
+                query.limitOption = pg_sys::LimitOption_LIMIT_OPTION_COUNT;
+            }
+            Some(query)
+        }
+    }
+
+    #[pg_test]
+    unsafe fn test_query_rewriter() {
+        static mut REWRITER: LimitOne = LimitOne { relid: pg_sys::InvalidOid, calls: 0 };
+        register_query_rewriter(&mut REWRITER);
+
+        Spi::run("CREATE TABLE rewriter_test AS SELECT generate_series(1, 10) AS x").unwrap();
+        REWRITER.relid =
+            Spi::get_one::<pg_sys::Oid>("SELECT 'rewriter_test'::regclass::oid").unwrap().unwrap();
+
+        assert_eq!(
+            Spi::get_one::<i64>("SELECT count(*) FROM generate_series(1, 10)"),
+            Ok(Some(10))
+        );
+        assert_eq!(REWRITER.calls, 0);
+
+        let rows = Spi::get_one::<i64>("SELECT count(*) FROM (SELECT x FROM rewriter_test) t");
+        // the rewriter only sees the outer query, whose only RTE is the subquery
+        assert_eq!(rows, Ok(Some(10)));
+        assert_eq!(REWRITER.calls, 0);
+
+        let sum = Spi::get_one::<i64>("SELECT sum(x) FROM rewriter_test");
+        assert_eq!(sum, Ok(Some(55)));
+        assert_eq!(REWRITER.calls, 1);
+
+        let first = Spi::get_one::<i32>("SELECT x FROM rewriter_test ORDER BY x DESC");
+        assert_eq!(first, Ok(Some(10)));
+        let count = Spi::connect(|client| {
+            client.select("SELECT x FROM rewriter_test", None, None).map(|table| table.len())
+        });
+        assert_eq!(count, Ok(1));
+        assert_eq!(REWRITER.calls, 3);
+
+        REWRITER.relid = pg_sys::InvalidOid;
+    }
+}
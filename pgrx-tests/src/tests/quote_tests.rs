@@ -0,0 +1,54 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::quote::*;
+
+    #[pg_test]
+    fn test_quote_functions() {
+        assert_eq!(quote_ident("plain"), "plain");
+        assert_eq!(quote_ident("select"), "\"select\"");
+        assert_eq!(quote_ident("Mixed \"Case\""), "\"Mixed \"\"Case\"\"\"");
+        assert_eq!(quote_qualified_ident("my schema", String::from("t")), "\"my schema\".t");
+        assert_eq!(quote_literal("it's"), "'it''s'");
+        assert_eq!(quote_literal("back\\slash"), "E'back\\\\slash'");
+        assert_eq!(quote_nullable(Some("x")), "'x'");
+        assert_eq!(quote_nullable(None::<&str>), "NULL");
+    }
+
+    #[pg_test]
+    fn test_sql_macro() {
+        let table = "my table";
+        let id: Option<i64> = None;
+        assert_eq!(
+            sql!("SELECT {ident} FROM {ident}.{table:ident} WHERE name = {literal} AND id = {id:nullable}", "col", "public", "O'Brien"),
+            r#"SELECT col FROM public."my table" WHERE name = 'O''Brien' AND id = NULL"#
+        );
+        assert_eq!(sql!("SELECT {literal}::int", 42), "SELECT '42'::int");
+        assert_eq!(sql!("SELECT '{{}}'::jsonb"), "SELECT '{}'::jsonb");
+        assert_eq!(sql!("SELECT {nullable}", Some(1.5)), "SELECT '1.5'");
+    }
+
+    #[pg_test]
+    fn test_sql_macro_prevents_injection() -> Result<(), pgrx::spi::Error> {
+        Spi::run("CREATE TABLE tests.\"people; DROP TABLE x\" (name text)")?;
+        let table = "people; DROP TABLE x";
+        let name = "'); DROP TABLE x; --";
+        Spi::run(&sql!("INSERT INTO tests.{table:ident} VALUES ({name:literal})"))?;
+        let stored = Spi::get_one::<String>(&sql!("SELECT name FROM tests.{table:ident}"))?;
+        assert_eq!(stored.as_deref(), Some(name));
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+    use pgrx::resowner::{
+        register_resource_release_callback, ResourceOwned, ResourceRelease, ResourceReleasePhase,
+    };
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    struct SetOnDrop(Rc<Cell<bool>>);
+
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    /// Run `f` in a subtransaction, which is committed or rolled back
+    fn in_subtransaction(commit: bool, f: impl FnOnce()) {
+        unsafe {
+            let memcxt = pg_sys::CurrentMemoryContext;
+            let owner = pg_sys::CurrentResourceOwner;
+            pg_sys::BeginInternalSubTransaction(std::ptr::null());
+            f();
+            if commit {
+                pg_sys::ReleaseCurrentSubTransaction();
+            } else {
+                pg_sys::RollbackAndReleaseCurrentSubTransaction();
+            }
+            pg_sys::MemoryContextSwitchTo(memcxt);
+            pg_sys::CurrentResourceOwner = owner;
+        }
+    }
+
+    #[pg_test]
+    fn test_resource_owned_released_on_abort() {
+        let dropped = Rc::new(Cell::new(false));
+        in_subtransaction(false, || {
+            // as if it were kept somewhere Rust won't drop it
+            std::mem::forget(ResourceOwned::new(SetOnDrop(dropped.clone())));
+            assert!(!dropped.get());
+        });
+        assert!(dropped.get());
+    }
+
+    #[pg_test]
+    fn test_resource_owned_moves_to_parent_on_commit() {
+        let dropped = Rc::new(Cell::new(false));
+        let mut owned = None;
+        in_subtransaction(true, || {
+            owned = Some(ResourceOwned::new(SetOnDrop(dropped.clone())));
+        });
+        let owned = owned.unwrap();
+        assert!(!dropped.get());
+        assert!(!owned.is_released());
+        assert_eq!(owned.with(|value| value.0.get()), Some(false));
+    }
+
+    #[pg_test]
+    fn test_resource_owned_drop_and_take() {
+        let dropped = Rc::new(Cell::new(false));
+        drop(ResourceOwned::new(SetOnDrop(dropped.clone())));
+        assert!(dropped.get());
+
+        let dropped = Rc::new(Cell::new(false));
+        let value = ResourceOwned::new(SetOnDrop(dropped.clone())).take();
+        assert!(value.is_some());
+        assert!(!dropped.get());
+    }
+
+    #[pg_test]
+    fn test_resource_release_callback() {
+        let releases = Rc::new(RefCell::new(Vec::<ResourceRelease>::new()));
+        in_subtransaction(false, || {
+            let releases = releases.clone();
+            register_resource_release_callback(ResourceReleasePhase::BeforeLocks, move |release| {
+                releases.borrow_mut().push(*release)
+            });
+        });
+        assert_eq!(
+            *releases.borrow(),
+            vec![ResourceRelease {
+                phase: ResourceReleasePhase::BeforeLocks,
+                is_commit: false,
+                is_top_level: false
+            }]
+        );
+    }
+
+    #[pg_test]
+    fn test_unregister_resource_release_callback() {
+        let called = Rc::new(Cell::new(false));
+        in_subtransaction(false, || {
+            let called = called.clone();
+            let receipt =
+                register_resource_release_callback(ResourceReleasePhase::AfterLocks, move |_| {
+                    called.set(true)
+                });
+            receipt.unregister_callback();
+        });
+        assert!(!called.get());
+    }
+}
@@ -0,0 +1,111 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::schedule::{Schedule, ScheduleError};
+
+    fn timestamptz(value: &str) -> TimestampWithTimeZone {
+        Spi::get_one_with_args(
+            "SELECT $1::timestamptz",
+            vec![(PgBuiltInOids::TEXTOID.oid(), value.into_datum())],
+        )
+        .unwrap()
+        .unwrap()
+    }
+
+    fn next_after(expression: &str, after: &str) -> Option<TimestampWithTimeZone> {
+        expression.parse::<Schedule>().unwrap().next_after(timestamptz(after))
+    }
+
+    #[pg_test]
+    fn test_schedule_parse_errors() {
+        assert_eq!(
+            "* * * *".parse::<Schedule>(),
+            Err(ScheduleError::WrongFieldCount("* * * *".into(), 4))
+        );
+        assert_eq!(
+            "60 * * * *".parse::<Schedule>(),
+            Err(ScheduleError::InvalidField("60".into(), "minute"))
+        );
+        assert_eq!(
+            "* * * * 5-2".parse::<Schedule>(),
+            Err(ScheduleError::InvalidField("5-2".into(), "day of week"))
+        );
+        assert_eq!(
+            "*/0 * * * *".parse::<Schedule>(),
+            Err(ScheduleError::InvalidField("*/0".into(), "minute"))
+        );
+        assert_eq!(
+            "@reboot".parse::<Schedule>(),
+            Err(ScheduleError::UnknownMacro("@reboot".into()))
+        );
+        assert_eq!("@daily".parse::<Schedule>(), "0 0 * * *".parse::<Schedule>());
+        assert_eq!("0 0 * * 7".parse::<Schedule>(), "0 0 * * sun".parse::<Schedule>());
+    }
+
+    #[pg_test]
+    fn test_schedule_next_after() -> Result<(), spi::Error> {
+        Spi::run("SET LOCAL TimeZone = 'UTC'")?;
+        assert_eq!(
+            next_after("*/15 * * * *", "2024-01-31 23:59:30+00"),
+            Some(timestamptz("2024-02-01 00:00:00+00"))
+        );
+        assert_eq!(
+            next_after("30 3 * * mon-fri", "2024-01-05 03:30:00+00"),
+            Some(timestamptz("2024-01-08 03:30:00+00"))
+        );
+        assert_eq!(
+            next_after("0 0 29 feb *", "2024-03-01 00:00:00+00"),
+            Some(timestamptz("2028-02-29 00:00:00+00"))
+        );
+        assert_eq!(next_after("0 0 30 feb *", "2024-01-01 00:00:00+00"), None);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_schedule_day_of_month_or_week() -> Result<(), spi::Error> {
+        Spi::run("SET LOCAL TimeZone = 'UTC'")?;
+        // 2024-01-06 is a Saturday, before the 15th
+        assert_eq!(
+            next_after("0 12 15 * sat", "2024-01-02 00:00:00+00"),
+            Some(timestamptz("2024-01-06 12:00:00+00"))
+        );
+        assert_eq!(
+            next_after("0 12 15 * sat", "2024-01-13 12:00:00+00"),
+            Some(timestamptz("2024-01-15 12:00:00+00"))
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_schedule_uses_server_time_zone() -> Result<(), spi::Error> {
+        Spi::run("SET LOCAL TimeZone = 'America/New_York'")?;
+        assert_eq!(
+            next_after("0 9 * * *", "2024-01-01 00:00:00-05"),
+            Some(timestamptz("2024-01-01 14:00:00+00"))
+        );
+        // clocks went from 02:00 to 03:00
+        assert_eq!(
+            next_after("30 2 * * *", "2024-03-10 00:00:00-05"),
+            Some(timestamptz("2024-03-10 03:30:00-04"))
+        );
+        // 01:30 happened twice
+        assert_eq!(
+            next_after("30 1 * * *", "2024-11-03 00:00:00-04"),
+            Some(timestamptz("2024-11-03 01:30:00-05"))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::{PgRelation, SessionCache};
+
+    static COLUMN_COUNTS: SessionCache<pg_sys::Oid, usize> = SessionCache::new();
+    static TYPE_NAMES: SessionCache<i64, String> =
+        SessionCache::with_syscaches(&[pg_sys::SysCacheIdentifier_TYPEOID]);
+
+    fn column_count(relid: pg_sys::Oid) -> usize {
+        COLUMN_COUNTS.get_or_insert_with(relid, |relid| unsafe {
+            PgRelation::open(*relid).tuple_desc().len()
+        })
+    }
+
+    fn relid(name: &str) -> pg_sys::Oid {
+        Spi::get_one::<pg_sys::Oid>(&format!("SELECT '{name}'::regclass::oid")).unwrap().unwrap()
+    }
+
+    #[pg_test]
+    fn test_session_cache_relcache_invalidation() {
+        Spi::run("CREATE TABLE session_cache_test (a int, b int)").unwrap();
+        let relid = relid("session_cache_test");
+
+        assert_eq!(column_count(relid), 2);
+        assert_eq!(COLUMN_COUNTS.get(&relid), Some(2));
+
+        Spi::run("ALTER TABLE session_cache_test ADD COLUMN c int").unwrap();
+        // invalidations are processed at the next command boundary, which Spi::run() provides
+        Spi::run("SELECT 1").unwrap();
+        assert_eq!(COLUMN_COUNTS.get(&relid), None);
+        assert_eq!(column_count(relid), 3);
+    }
+
+    #[pg_test]
+    fn test_session_cache_unrelated_relation_kept() {
+        Spi::run("CREATE TABLE session_cache_kept (a int)").unwrap();
+        Spi::run("CREATE TABLE session_cache_altered (a int)").unwrap();
+        let kept = relid("session_cache_kept");
+
+        assert_eq!(column_count(kept), 1);
+        Spi::run("ALTER TABLE session_cache_altered ADD COLUMN b int").unwrap();
+        Spi::run("SELECT 1").unwrap();
+        assert_eq!(COLUMN_COUNTS.get(&kept), Some(1));
+    }
+
+    #[pg_test]
+    fn test_session_cache_syscache_invalidation() {
+        TYPE_NAMES.insert(42, "cached".into());
+        assert_eq!(TYPE_NAMES.get(&42).as_deref(), Some("cached"));
+
+        Spi::run("CREATE TYPE session_cache_type AS (a int)").unwrap();
+        Spi::run("ALTER TYPE session_cache_type RENAME TO session_cache_type2").unwrap();
+        Spi::run("SELECT 1").unwrap();
+        assert!(TYPE_NAMES.is_empty());
+    }
+
+    #[pg_test]
+    fn test_session_cache_error_caches_nothing() {
+        let result = PgTryBuilder::new(|| {
+            COLUMN_COUNTS
+                .get_or_insert_with(pg_sys::Oid::INVALID, |_| error!("computing the value failed"))
+        })
+        .catch_others(|_| 0)
+        .execute();
+        assert_eq!(result, 0);
+        assert_eq!(COLUMN_COUNTS.get(&pg_sys::Oid::INVALID), None);
+    }
+}
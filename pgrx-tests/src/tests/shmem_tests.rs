@@ -14,11 +14,32 @@ use std::sync::atomic::AtomicBool;
 static ATOMIC: PgAtomic<AtomicBool> = PgAtomic::new();
 static LWLOCK: PgLwLock<bool> = PgLwLock::new();
 
-#[pg_guard]
-pub extern "C" fn _PG_init() {
+#[pg_init]
+fn init() {
     // This ensures that this functionality works across PostgreSQL versions
     pg_shmem_init!(ATOMIC);
     pg_shmem_init!(LWLOCK);
+    pg_shmem_init!(crate::tests::instrumentation_tests::STATEMENTS);
+    unsafe { crate::tests::instrumentation_tests::STATEMENTS.install() };
+    #[cfg(feature = "cshim")]
+    unsafe {
+        pgrx::register_client_authentication_hook(&mut *std::ptr::addr_of_mut!(
+            crate::tests::client_tests::REJECT_BY_NAME
+        ))
+    };
+    unsafe {
+        pgrx::logging::register_emit_log_hook(&mut *std::ptr::addr_of_mut!(
+            crate::tests::logging_tests::CAPTURE_LOG
+        ))
+    };
+    pgrx::logging::define_log_level_guc();
+    #[cfg(feature = "failpoints")]
+    pgrx::failpoints::define_failpoints_guc();
+    pgrx::spi::define_trace_spi_gucs();
+    #[cfg(feature = "log")]
+    pgrx::logging::init_log_bridge(log::LevelFilter::Info);
+    #[cfg(feature = "tracing")]
+    pgrx::logging::init_tracing_bridge(tracing::level_filters::LevelFilter::INFO);
 }
 #[cfg(any(test, feature = "pg_test"))]
 #[pgrx::pg_schema]
@@ -0,0 +1,72 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::spi::SpiRetry;
+    use std::time::Duration;
+
+    fn insert_then_fail(
+        client: &mut spi::SpiClient<'_>,
+        code: PgSqlErrorCode,
+    ) -> Result<(), spi::Error> {
+        client.update("INSERT INTO spi_retry VALUES (1)", None, None)?;
+        ereport!(ERROR, code, "try again");
+    }
+
+    #[pg_test]
+    fn test_spi_retrying_rolls_back_failed_attempts() -> Result<(), spi::Error> {
+        Spi::run("CREATE TABLE spi_retry (id int)")?;
+        let mut attempts = 0;
+        Spi::retrying(|mut client| {
+            attempts += 1;
+            if attempts < 3 {
+                insert_then_fail(&mut client, PgSqlErrorCode::ERRCODE_T_R_DEADLOCK_DETECTED)?;
+            }
+            client.update("INSERT INTO spi_retry VALUES (2)", None, None)?;
+            Ok::<_, spi::Error>(())
+        })?;
+        assert_eq!(attempts, 3);
+        assert_eq!(Spi::get_one::<Vec<i32>>("SELECT array_agg(id) FROM spi_retry")?, Some(vec![2]));
+        Ok(())
+    }
+
+    #[pg_test(error = "try again")]
+    fn test_spi_retrying_gives_up() -> Result<(), spi::Error> {
+        Spi::run("CREATE TABLE spi_retry (id int)")?;
+        let retry = SpiRetry::new().max_attempts(2).initial_backoff(Duration::ZERO);
+        Spi::retrying_with(retry, |mut client| {
+            insert_then_fail(&mut client, PgSqlErrorCode::ERRCODE_T_R_SERIALIZATION_FAILURE)
+        })
+    }
+
+    #[pg_test]
+    fn test_spi_retrying_only_retries_conflicts() -> Result<(), spi::Error> {
+        Spi::run("CREATE TABLE spi_retry (id int)")?;
+        let mut attempts = 0;
+        let result = PgTryBuilder::new(std::panic::AssertUnwindSafe(|| {
+            Spi::retrying(|mut client| {
+                attempts += 1;
+                insert_then_fail(&mut client, PgSqlErrorCode::ERRCODE_DIVISION_BY_ZERO)
+            })
+        }))
+        .catch_when(PgSqlErrorCode::ERRCODE_DIVISION_BY_ZERO, |_| Ok(()))
+        .execute();
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts, 1);
+        assert_eq!(Spi::get_one::<i64>("SELECT count(*) FROM spi_retry")?, Some(0));
+        Ok(())
+    }
+}
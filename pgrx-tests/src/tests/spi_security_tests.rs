@@ -0,0 +1,90 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::spi::SpiSecurity;
+
+    fn create_role(name: &str) -> pg_sys::Oid {
+        Spi::run(&format!("CREATE ROLE {name}")).unwrap();
+        Spi::get_one::<pg_sys::Oid>(&format!("SELECT '{name}'::regrole::oid")).unwrap().unwrap()
+    }
+
+    fn rls_table(role: &str) {
+        Spi::run("CREATE TABLE spi_security_rls (owner text)").unwrap();
+        Spi::run("INSERT INTO spi_security_rls VALUES ('someone'), ('someone else')").unwrap();
+        Spi::run("ALTER TABLE spi_security_rls ENABLE ROW LEVEL SECURITY").unwrap();
+        Spi::run("CREATE POLICY mine ON spi_security_rls USING (owner = 'someone')").unwrap();
+        Spi::run(&format!("GRANT SELECT ON spi_security_rls TO {role}")).unwrap();
+    }
+
+    #[pg_test]
+    fn test_spi_security_role() {
+        let role = create_role("spi_security_role");
+        let security = SpiSecurity::new().role(role);
+        let user = Spi::connect_with_security(security, |client| {
+            client.select("SELECT current_user::text", None, None)?.first().get_one::<String>()
+        });
+        assert_eq!(user, Ok(Some("spi_security_role".to_string())));
+
+        // and it's put back afterwards
+        let is_superuser =
+            Spi::get_one::<bool>("SELECT rolsuper FROM pg_roles WHERE rolname = current_user");
+        assert_eq!(is_superuser, Ok(Some(true)));
+    }
+
+    #[pg_test]
+    fn test_spi_security_row_security_on() {
+        let role = create_role("spi_security_rls_on");
+        rls_table("spi_security_rls_on");
+        let security = SpiSecurity::new().role(role).row_security(true);
+        let count = Spi::connect_with_security(security, |client| {
+            client
+                .select("SELECT count(*) FROM spi_security_rls", None, None)?
+                .first()
+                .get_one::<i64>()
+        });
+        assert_eq!(count, Ok(Some(1)));
+    }
+
+    #[pg_test(
+        error = "query would be affected by row-level security policy for table \"spi_security_rls\""
+    )]
+    fn test_spi_security_row_security_off() -> Result<Option<i64>, spi::Error> {
+        let role = create_role("spi_security_rls_off");
+        rls_table("spi_security_rls_off");
+        let security = SpiSecurity::new().role(role).row_security(false);
+        Spi::connect_with_security(security, |client| {
+            client
+                .select("SELECT count(*) FROM spi_security_rls", None, None)?
+                .first()
+                .get_one::<i64>()
+        })
+    }
+
+    #[pg_test]
+    fn test_spi_security_row_security_is_put_back() {
+        Spi::connect_with_security(SpiSecurity::new().row_security(false), |_| ());
+        assert_eq!(Spi::get_one::<String>("SHOW row_security"), Ok(Some("on".to_string())));
+    }
+
+    #[pg_test(error = "cannot create temporary table within security-restricted operation")]
+    fn test_spi_security_restricted() -> Result<(), spi::Error> {
+        Spi::connect_with_security(SpiSecurity::new().restricted(), |mut client| {
+            client.update("CREATE TEMPORARY TABLE spi_security_temp ()", None, None)?;
+            Ok(())
+        })
+    }
+}
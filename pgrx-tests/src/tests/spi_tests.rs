@@ -178,6 +178,42 @@ mod tests {
         Spi::run("SELECT tests.do_panic();").expect("SPI failed");
     }
 
+    #[pg_extern]
+    fn spi_returns_str<'a>(query: &str) -> &'a str {
+        Spi::get_one::<&str>(query).unwrap().unwrap()
+    }
+
+    #[pg_extern]
+    fn spi_returns_bytes<'a>(query: &str) -> &'a [u8] {
+        Spi::connect(|client| client.select(query, None, None)?.first().get_one::<&[u8]>())
+            .unwrap()
+            .unwrap()
+    }
+
+    #[pg_extern]
+    fn spi_returns_borrowed_text<'a>(query: &str) -> pgrx::BorrowedText<'a> {
+        Spi::get_one::<pgrx::BorrowedText>(query).unwrap().unwrap()
+    }
+
+    #[pg_test]
+    fn test_returning_references_into_spi_results() -> Result<(), spi::Error> {
+        assert_eq!(
+            Spi::get_one::<&str>("SELECT tests.spi_returns_str('SELECT ''hello''')")?,
+            Some("hello")
+        );
+        assert_eq!(
+            Spi::get_one::<&[u8]>("SELECT tests.spi_returns_bytes('SELECT ''\\x010203''::bytea')")?,
+            Some(&[1u8, 2, 3][..])
+        );
+        assert_eq!(
+            Spi::get_one::<&str>(
+                "SELECT tests.spi_returns_borrowed_text('SELECT repeat(''x'', 10000)')"
+            )?,
+            Some("x".repeat(10000).as_str())
+        );
+        Ok(())
+    }
+
     #[pg_test]
     fn test_inserting_null() -> Result<(), pgrx::spi::Error> {
         Spi::connect(|mut client| {
@@ -0,0 +1,76 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    fn trace_everything() {
+        Spi::run("SET LOCAL pgrx.trace_spi = on").unwrap();
+        Spi::run("SET LOCAL pgrx.trace_spi_explain = on").unwrap();
+        Spi::run("SET LOCAL pgrx.trace_spi_min_duration = 0").unwrap();
+    }
+
+    #[pg_test]
+    fn test_traced_query_results() {
+        trace_everything();
+        assert_eq!(Spi::get_one::<i32>("SELECT 42"), Ok(Some(42)));
+        assert_eq!(
+            Spi::get_one_with_args::<String>(
+                "SELECT $1 || $2",
+                vec![
+                    (PgBuiltInOids::TEXTOID.oid(), "a'b".into_datum()),
+                    (PgBuiltInOids::TEXTOID.oid(), "c".into_datum())
+                ]
+            ),
+            Ok(Some("a'bc".to_string()))
+        );
+        assert_eq!(
+            Spi::get_one_with_args::<i32>(
+                "SELECT coalesce($1, 7)",
+                vec![(PgBuiltInOids::INT4OID.oid(), None)]
+            ),
+            Ok(Some(7))
+        );
+    }
+
+    #[pg_test]
+    fn test_traced_prepared_statement() {
+        trace_everything();
+        Spi::connect(|client| {
+            let statement =
+                client.prepare("SELECT $1 + 1", Some(vec![PgBuiltInOids::INT4OID.oid()]))?;
+            let result = client.select(&statement, None, Some(vec![41.into_datum()]))?;
+            assert_eq!(result.first().get_one::<i32>()?, Some(42));
+            Ok::<_, spi::Error>(())
+        })
+        .unwrap();
+    }
+
+    #[pg_test]
+    fn test_traced_statements_that_cant_be_explained() {
+        trace_everything();
+        Spi::run("CREATE TEMPORARY TABLE trace_spi (id int)").unwrap();
+        Spi::run("INSERT INTO trace_spi VALUES (1); INSERT INTO trace_spi VALUES (2)").unwrap();
+        assert_eq!(Spi::get_one::<i64>("SELECT count(*) FROM trace_spi"), Ok(Some(2)));
+    }
+
+    #[pg_test]
+    fn test_traced_insert_isnt_run_twice() {
+        trace_everything();
+        Spi::run("CREATE TEMPORARY TABLE trace_spi_once (id int)").unwrap();
+        Spi::run("INSERT INTO trace_spi_once VALUES (1)").unwrap();
+        assert_eq!(Spi::get_one::<i64>("SELECT count(*) FROM trace_spi_once"), Ok(Some(1)));
+    }
+}
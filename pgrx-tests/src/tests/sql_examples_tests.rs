@@ -0,0 +1,41 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(test)]
+mod tests {
+    const EXAMPLES: &str = r#"
+-- the extension being tested has been created
+SELECT extname FROM pg_extension WHERE extname = 'pgrx_tests';
+-- => pgrx_tests
+
+CREATE TEMPORARY TABLE sql_examples (id int, name text);
+INSERT INTO sql_examples VALUES (1, 'one'), (2, NULL);
+SELECT id, name
+  FROM sql_examples
+ ORDER BY id;
+-- => 1 | one
+-- => 2 |
+"#;
+
+    #[test]
+    fn run_sql_examples() {
+        crate::run_sql_examples("EXAMPLES", EXAMPLES, crate::pg_test::postgresql_conf_options())
+            .unwrap();
+    }
+
+    #[test]
+    fn wrong_sql_example() {
+        let result = crate::run_sql_examples(
+            "wrong",
+            "SELECT 1 + 1;\n-- => 3\n",
+            crate::pg_test::postgresql_conf_options(),
+        );
+        assert!(result.is_err());
+    }
+}
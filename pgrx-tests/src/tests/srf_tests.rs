@@ -154,6 +154,24 @@ fn one_col_result_option<'a>(
     Ok(Some(TableIterator::new(std::iter::once((42,)))))
 }
 
+#[pg_extern]
+fn many_wide_rows(
+    n: i64,
+) -> TableIterator<
+    'static,
+    (
+        name!(id, i64),
+        name!(label, String),
+        name!(half, f64),
+        name!(even, bool),
+        name!(note, Option<&'static str>),
+    ),
+> {
+    TableIterator::new((0..n).map(|id| {
+        (id, format!("row {id}"), id as f64 / 2.0, id % 2 == 0, (id % 3 == 0).then_some("fizz"))
+    }))
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pgrx::pg_schema]
 mod tests {
@@ -338,6 +356,49 @@ mod tests {
         assert_eq!(result, Err(spi::Error::InvalidPosition));
     }
 
+    #[pg_test]
+    fn test_many_wide_rows() {
+        let (count, labels, fizzes) = Spi::get_three::<i64, i64, i64>(
+            "SELECT count(*), count(DISTINCT label), count(note) FROM many_wide_rows(100000)",
+        )
+        .unwrap();
+        assert_eq!(count, Some(100000));
+        assert_eq!(labels, Some(100000));
+        assert_eq!(fizzes, Some(33334));
+
+        let last = Spi::get_one::<String>(
+            "SELECT label || ' ' || half || ' ' || even FROM many_wide_rows(4) ORDER BY id DESC LIMIT 1",
+        );
+        assert_eq!(last, Ok(Some("row 3 1.5 false".to_string())));
+    }
+
+    #[pg_test]
+    fn test_tuple_writer() {
+        use pgrx::tuple_writer::TupleWriter;
+        use pgrx::PgTupleDesc;
+
+        Spi::run("CREATE TYPE tuple_writer_row AS (a int, b text)").unwrap();
+        let tupdesc = PgTupleDesc::for_composite_type("tuple_writer_row").unwrap();
+
+        let mut writer =
+            unsafe { TupleWriter::new(tupdesc.as_ptr(), pg_sys::CurrentMemoryContext) };
+        assert_eq!(writer.len(), 2);
+        for i in 0..3 {
+            writer.reset();
+            writer.set(0, i);
+            if i != 1 {
+                writer.set(1, format!("value {i}"));
+            }
+            let tuple = unsafe { writer.form_tuple() };
+            let tuple = unsafe {
+                PgHeapTuple::from_heap_tuple(PgTupleDesc::from_pg_copy(tupdesc.as_ptr()), tuple)
+            };
+            assert_eq!(tuple.get_by_index::<i32>(1.try_into().unwrap()), Ok(Some(i)));
+            let expected = (i != 1).then(|| format!("value {i}"));
+            assert_eq!(tuple.get_by_index::<String>(2.try_into().unwrap()), Ok(expected));
+        }
+    }
+
     #[pg_test]
     pub fn test_one_col_table() {
         assert_eq!(Spi::get_one::<i32>("SELECT * from one_col()"), Ok(Some(42)));
@@ -0,0 +1,45 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+    use pgrx::symbols::OptionalSymbol;
+
+    static PALLOC0: OptionalSymbol<unsafe extern "C" fn(pg_sys::Size) -> *mut std::ffi::c_void> =
+        unsafe { OptionalSymbol::new("palloc0") };
+
+    static MISSING: OptionalSymbol<unsafe extern "C" fn()> =
+        unsafe { OptionalSymbol::new("pgrx_tests_no_such_function") };
+
+    static PLPGSQL_CALL_HANDLER: OptionalSymbol<unsafe extern "C" fn()> =
+        unsafe { OptionalSymbol::in_library("$libdir/plpgsql", "plpgsql_call_handler") };
+
+    #[pg_test]
+    fn test_server_symbol() {
+        let palloc0 = PALLOC0.get().expect("palloc0 should be found");
+        let ptr = unsafe { palloc0(8) } as *mut u64;
+        assert_eq!(unsafe { *ptr }, 0);
+    }
+
+    #[pg_test]
+    fn test_missing_symbol() {
+        assert!(!MISSING.is_available());
+        // and it stays missing
+        assert!(MISSING.get().is_none());
+    }
+
+    #[pg_test]
+    fn test_library_symbol() {
+        assert!(PLPGSQL_CALL_HANDLER.is_available());
+    }
+}
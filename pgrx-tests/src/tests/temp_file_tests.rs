@@ -0,0 +1,75 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::{PgMemoryContexts, TempFile};
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    #[pg_test]
+    fn test_temp_file_roundtrip() {
+        let mut file = TempFile::new();
+        assert!(file.is_empty());
+
+        // enough to spill over BufFile's in-memory buffer several times
+        let chunk = (0..=255u8).collect::<Vec<_>>();
+        for _ in 0..1000 {
+            file.write_all(&chunk).unwrap();
+        }
+        assert_eq!(file.len(), 256_000);
+
+        file.seek(SeekFrom::Start(0)).unwrap();
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents).unwrap();
+        assert_eq!(contents.len(), 256_000);
+        assert!(contents.chunks(256).all(|c| c == chunk.as_slice()));
+    }
+
+    #[pg_test]
+    fn test_temp_file_seek() {
+        let mut file = TempFile::new();
+        file.write_all(b"0123456789").unwrap();
+
+        file.seek(SeekFrom::End(-3)).unwrap();
+        let mut tail = String::new();
+        file.read_to_string(&mut tail).unwrap();
+        assert_eq!(tail, "789");
+
+        file.seek(SeekFrom::Start(2)).unwrap();
+        file.write_all(b"ab").unwrap();
+        file.seek(SeekFrom::Current(-4)).unwrap();
+        let mut head = [0u8; 5];
+        file.read_exact(&mut head).unwrap();
+        assert_eq!(&head, b"01ab4");
+        assert_eq!(file.position(), 5);
+    }
+
+    #[pg_test]
+    fn test_temp_file_outlives_memory_context() {
+        // the context is deleted as soon as it's dropped, at the end of each statement
+        let mut file =
+            unsafe { PgMemoryContexts::new("short-lived").switch_to(|_| TempFile::new()) };
+        let mut inter_xact = unsafe {
+            PgMemoryContexts::new("short-lived").switch_to(|_| TempFile::new_inter_xact())
+        };
+
+        for file in [&mut file, &mut inter_xact] {
+            file.write_all(b"still here").unwrap();
+            file.seek(SeekFrom::Start(0)).unwrap();
+            let mut contents = String::new();
+            file.read_to_string(&mut contents).unwrap();
+            assert_eq!(contents, "still here");
+        }
+    }
+}
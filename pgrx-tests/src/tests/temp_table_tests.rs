@@ -0,0 +1,75 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    #[derive(TempTableRow)]
+    struct Score {
+        id: i32,
+        score: f64,
+        note: Option<String>,
+    }
+
+    fn scores() -> impl Iterator<Item = Score> {
+        (1..=100).map(|id| Score {
+            id,
+            score: id as f64 / 10.0,
+            note: (id % 10 == 0).then(|| format!("round {id}")),
+        })
+    }
+
+    #[pg_test]
+    fn test_create_temp_table() -> Result<(), pgrx::spi::Error> {
+        let table = Spi::create_temp_table(scores())?;
+        assert!(table.name().starts_with("pgrx_temp_"));
+
+        let query = format!(
+            "SELECT count(*), sum(score), count(note) FROM {} JOIN generate_series(1, 50) id USING (id)",
+            table.qualified_name()
+        );
+        let (count, sum, notes) = Spi::get_three::<i64, f64, i64>(&query)?;
+        assert_eq!(count, Some(50));
+        assert_eq!(sum, Some(127.5));
+        assert_eq!(notes, Some(5));
+
+        let columns = Spi::get_one::<String>(&format!(
+            "SELECT string_agg(attname || ' ' || format_type(atttypid, atttypmod) || CASE WHEN attnotnull THEN ' not null' ELSE '' END, ', ' ORDER BY attnum)
+               FROM pg_attribute WHERE attrelid = '{}'::regclass AND attnum > 0",
+            table.qualified_name()
+        ))?;
+        assert_eq!(
+            columns.as_deref(),
+            Some("id integer not null, score double precision not null, note text")
+        );
+
+        table.insert([Score { id: 101, score: 0.0, note: None }])?;
+        assert_eq!(
+            Spi::get_one::<i64>(&format!("SELECT count(*) FROM {}", table.qualified_name()))?,
+            Some(101)
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_temp_table_dropped() -> Result<(), pgrx::spi::Error> {
+        let name = {
+            let table = Spi::create_temp_table(scores())?;
+            table.qualified_name()
+        };
+        let exists = Spi::get_one::<bool>(&format!("SELECT to_regclass('{name}') IS NOT NULL"))?;
+        assert_eq!(exists, Some(false));
+        Ok(())
+    }
+}
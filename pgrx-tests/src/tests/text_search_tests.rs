@@ -0,0 +1,135 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use pgrx::prelude::*;
+use pgrx::text_search::{Lexeme, TextSearchDictionary, TextSearchParser};
+
+/// Upper-cases every token, except for the configured stop words
+pub struct Shout {
+    stop_words: Vec<String>,
+}
+
+#[pg_text_search(name = "shout", options = "StopWords = 'the,a'")]
+impl TextSearchDictionary for Shout {
+    fn init(options: Vec<(String, String)>) -> Self {
+        let stop_words = options
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("stopwords"))
+            .flat_map(|(_, value)| value.split(',').map(|word| word.trim().to_lowercase()))
+            .collect();
+        Shout { stop_words }
+    }
+
+    fn lexize(&self, token: &str) -> Option<Vec<Lexeme>> {
+        if token.starts_with('_') {
+            // leave it to the next dictionary
+            None
+        } else if self.stop_words.contains(&token.to_lowercase()) {
+            Some(vec![])
+        } else {
+            Some(vec![Lexeme::new(token.to_uppercase())])
+        }
+    }
+}
+
+/// Splits text on whitespace into numbers and words
+pub struct NumbersAndWords;
+
+#[pg_text_search]
+impl TextSearchParser for NumbersAndWords {
+    const TOKEN_TYPES: &'static [(i32, &'static str, &'static str)] =
+        &[(1, "number", "Sequence of digits"), (2, "word", "Anything else")];
+
+    fn tokenize(text: &str) -> Vec<(i32, &str)> {
+        text.split_whitespace()
+            .map(|token| {
+                let token_type = if token.chars().all(|c| c.is_ascii_digit()) { 1 } else { 2 };
+                (token_type, token)
+            })
+            .collect()
+    }
+}
+
+extension_sql!(
+    r#"
+    CREATE TEXT SEARCH CONFIGURATION numbers_and_words (PARSER = numbers_and_words);
+    ALTER TEXT SEARCH CONFIGURATION numbers_and_words ADD MAPPING FOR word WITH shout, simple;
+    ALTER TEXT SEARCH CONFIGURATION numbers_and_words ADD MAPPING FOR number WITH simple;
+    "#,
+    name = "numbers_and_words_configuration",
+    requires = ["shout_text_search_dictionary", "numbers_and_words_text_search_parser"]
+);
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_dictionary_lexize() -> Result<(), pgrx::spi::Error> {
+        let lexemes = Spi::get_one::<Vec<String>>("SELECT ts_lexize('shout', 'Hello')")?;
+        assert_eq!(lexemes, Some(vec!["HELLO".to_string()]));
+
+        // a stop word
+        let lexemes = Spi::get_one::<Vec<String>>("SELECT ts_lexize('shout', 'The')")?;
+        assert_eq!(lexemes, Some(vec![]));
+
+        // not recognized
+        let lexemes = Spi::get_one::<Vec<String>>("SELECT ts_lexize('shout', '_hidden')")?;
+        assert_eq!(lexemes, None);
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_dictionary_options() -> Result<(), pgrx::spi::Error> {
+        Spi::run("ALTER TEXT SEARCH DICTIONARY shout (StopWords = 'hello')")?;
+        let lexemes = Spi::get_one::<Vec<String>>("SELECT ts_lexize('shout', 'Hello')")?;
+        assert_eq!(lexemes, Some(vec![]));
+        let lexemes = Spi::get_one::<Vec<String>>("SELECT ts_lexize('shout', 'the')")?;
+        assert_eq!(lexemes, Some(vec!["THE".to_string()]));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_parser_token_types() -> Result<(), pgrx::spi::Error> {
+        let aliases = Spi::get_one::<Vec<String>>(
+            "SELECT array_agg(alias ORDER BY tokid) FROM ts_token_type('numbers_and_words')",
+        )?;
+        assert_eq!(aliases, Some(vec!["number".to_string(), "word".to_string()]));
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_parser_parse() -> Result<(), pgrx::spi::Error> {
+        let tokens = Spi::get_one::<Vec<String>>(
+            "SELECT array_agg(tokid || ':' || token) FROM ts_parse('numbers_and_words', 'route  66 east')",
+        )?;
+        assert_eq!(
+            tokens,
+            Some(vec!["2:route".to_string(), "1:66".to_string(), "2:east".to_string()])
+        );
+        Ok(())
+    }
+
+    #[pg_test]
+    fn test_configuration() -> Result<(), pgrx::spi::Error> {
+        let tsvector = Spi::get_one::<String>(
+            "SELECT to_tsvector('numbers_and_words', 'the route 66 _east')::text",
+        )?;
+        assert_eq!(tsvector.as_deref(), Some("'66':3 'ROUTE':2 '_east':4"));
+
+        let matches = Spi::get_one::<bool>(
+            "SELECT to_tsvector('numbers_and_words', 'the route 66') @@ to_tsquery('numbers_and_words', 'route & 66')",
+        )?;
+        assert_eq!(matches, Some(true));
+        Ok(())
+    }
+}
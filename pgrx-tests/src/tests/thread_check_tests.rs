@@ -0,0 +1,26 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+
+    #[pg_test]
+    fn test_only_backend_thread_is_active() {
+        assert!(pg_sys::is_active_thread());
+        pg_sys::debug_assert_backend_thread("test_only_backend_thread_is_active()");
+
+        let spawned = std::thread::spawn(pg_sys::is_active_thread).join().unwrap();
+        assert!(!spawned);
+    }
+}
@@ -0,0 +1,104 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::timeout::{with_timeout, TimeoutError};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    #[pg_extern]
+    fn spin_for_ms(spin_ms: i64, timeout_ms: i64) -> Result<i64, TimeoutError> {
+        with_timeout(Duration::from_millis(timeout_ms as u64), move || {
+            let started = Instant::now();
+            while started.elapsed() < Duration::from_millis(spin_ms as u64) {
+                std::hint::spin_loop();
+            }
+            spin_ms
+        })
+    }
+
+    #[pg_test]
+    fn test_with_timeout_finishes() {
+        assert_eq!(with_timeout(Duration::from_secs(5), || 6 * 7), Ok(42));
+    }
+
+    #[pg_test]
+    fn test_with_timeout_times_out() {
+        let started = Instant::now();
+        let result = with_timeout(Duration::from_millis(100), || {
+            std::thread::sleep(Duration::from_secs(2));
+        });
+        assert_eq!(result, Err(TimeoutError::TimedOut(Duration::from_millis(100))));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[pg_test]
+    fn test_with_timeout_refuses_while_abandoned_thread_runs() {
+        let release = Arc::new(AtomicBool::new(false));
+        let held = Arc::clone(&release);
+        let result = with_timeout(Duration::from_millis(50), move || {
+            while !held.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        });
+        assert_eq!(result, Err(TimeoutError::TimedOut(Duration::from_millis(50))));
+
+        // the abandoned thread is still running, so this closure doesn't run at all
+        assert_eq!(
+            with_timeout(Duration::from_secs(5), || "again"),
+            Err(TimeoutError::StillRunning)
+        );
+
+        // once it finishes, the timeout can be used again
+        release.store(true, Ordering::SeqCst);
+        let started = Instant::now();
+        loop {
+            match with_timeout(Duration::from_secs(5), || "again") {
+                Ok(again) => break assert_eq!(again, "again"),
+                Err(TimeoutError::StillRunning) if started.elapsed() < Duration::from_secs(5) => {
+                    std::thread::sleep(Duration::from_millis(5))
+                }
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+
+    #[pg_test(error = "boom")]
+    fn test_with_timeout_resumes_panics() {
+        let _ = with_timeout::<(), _>(Duration::from_secs(5), || panic!("boom"));
+    }
+
+    #[pg_test(error = "with_timeout() can't be nested")]
+    fn test_with_timeout_rejects_nesting() {
+        let _ = with_timeout(Duration::from_secs(5), || {
+            with_timeout(Duration::from_secs(1), || 42).unwrap()
+        });
+    }
+
+    #[pg_test]
+    fn test_with_timeout_error_is_catchable() -> Result<(), pgrx::spi::Error> {
+        assert_eq!(Spi::get_one::<i64>("SELECT tests.spin_for_ms(10, 5000)")?, Some(10));
+
+        let caught = PgTryBuilder::new(|| {
+            Spi::run("SELECT tests.spin_for_ms(1000, 50)").ok();
+            false
+        })
+        .catch_others(|_| true)
+        .execute();
+        assert!(caught);
+        Ok(())
+    }
+}
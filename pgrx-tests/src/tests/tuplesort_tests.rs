@@ -0,0 +1,91 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+#[cfg(any(test, feature = "pg_test"))]
+#[pgrx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgrx_tests;
+
+    use pgrx::prelude::*;
+    use pgrx::{PgTupleDesc, Tuplesort, Tuplestore};
+
+    #[pg_test]
+    fn test_tuplesort_ints() {
+        let mut sort = Tuplesort::<i32>::new();
+        sort.extend([Some(3), None, Some(1), Some(2)]);
+        let sorted = sort.sort().collect::<Vec<_>>();
+        assert_eq!(sorted, vec![Some(1), Some(2), Some(3), None]);
+    }
+
+    #[pg_test]
+    fn test_tuplesort_descending() {
+        let mut sort = Tuplesort::<String>::builder().descending().build();
+        sort.extend(["b", "c", "a"].map(|s| Some(s.to_string())));
+        sort.push(None);
+        let sorted = sort.sort().collect::<Vec<_>>();
+        assert_eq!(
+            sorted,
+            vec![None, Some("c".to_string()), Some("b".to_string()), Some("a".to_string())]
+        );
+    }
+
+    #[pg_test]
+    fn test_tuplesort_spills() {
+        // 64kB is the smallest allowed, and this is far more than that
+        let mut sort = Tuplesort::<i64>::builder().work_mem(64).build();
+        sort.extend((0..100_000i64).rev().map(Some));
+        assert!(sort.sort().map(|v| v.unwrap()).eq(0..100_000i64));
+    }
+
+    #[pg_test]
+    fn test_tuplestore_roundtrip() {
+        Spi::run("CREATE TYPE tuplestore_dog AS (name text, age int);").unwrap();
+        let tupdesc = PgTupleDesc::for_composite_type("tuplestore_dog").unwrap();
+        let mut store = Tuplestore::with_work_mem(tupdesc, 64);
+        assert!(store.is_empty());
+
+        for age in 0..10_000i32 {
+            unsafe { store.put_datums([format!("dog {age}").into_datum(), age.into_datum()]) };
+        }
+        assert_eq!(store.len(), 10_000);
+
+        // reading it twice should rescan from the beginning each time
+        for _ in 0..2 {
+            let ages = store
+                .iter()
+                .map(|dog| dog.get_by_name::<i32>("age").unwrap().unwrap())
+                .collect::<Vec<_>>();
+            assert!(ages.into_iter().eq(0..10_000));
+        }
+
+        let last = store.iter().last().unwrap();
+        assert_eq!(last.get_by_name::<String>("name").unwrap(), Some("dog 9999".to_string()));
+    }
+
+    #[pg_test]
+    fn test_tuplestore_put_tuple() {
+        Spi::run("CREATE TYPE tuplestore_cat AS (name text, lives int);").unwrap();
+        let mut cat = PgHeapTuple::new_composite_type("tuplestore_cat").unwrap();
+        cat.set_by_name("name", "Garfield").unwrap();
+        cat.set_by_name("lives", 9).unwrap();
+
+        let tupdesc = PgTupleDesc::for_composite_type("tuplestore_cat").unwrap();
+        let mut store = Tuplestore::new(tupdesc);
+        unsafe {
+            store.put_tuple(&cat);
+            store.put_tuple(&cat);
+        }
+
+        let cats = store.iter().collect::<Vec<_>>();
+        assert_eq!(cats.len(), 2);
+        assert_eq!(cats[1].get_by_name::<&str>("name").unwrap(), Some("Garfield"));
+        assert_eq!(cats[1].get_by_name::<i32>("lives").unwrap(), Some(9));
+    }
+}
@@ -267,12 +267,126 @@ CREATE AGGREGATE DemoSum (
 );
 ```
 
+## Moving-Aggregate Mode
+
+Used as a window function over a frame that slides, such as `OVER (ROWS 2 PRECEDING)`, an aggregate
+is normally recomputed from scratch for every row.  Implementing `MovingState`, `moving_state`, and
+`moving_state_inverse` (which removes a row the frame no longer covers) lets Postgres update it
+as the frame moves instead.  All three are needed, and `moving_finalize`,
+`MOVING_INITIAL_CONDITION`, and `MOVING_FINALIZE_MODIFY` can only be used with them.
+
+```rust
+# use pgrx::prelude::*;
+pub struct MovingSum;
+
+#[pg_aggregate]
+impl Aggregate for MovingSum {
+    const INITIAL_CONDITION: Option<&'static str> = Some("0");
+    const MOVING_INITIAL_CONDITION: Option<&'static str> = Some("0");
+    type Args = i64;
+    type State = i64;
+    type MovingState = i64;
+
+    fn state(current: Self::State, arg: Self::Args, _fcinfo: pg_sys::FunctionCallInfo) -> Self::State {
+        current + arg
+    }
+
+    fn moving_state(
+        current: Self::MovingState,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::MovingState {
+        current + arg
+    }
+
+    fn moving_state_inverse(
+        current: Self::MovingState,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::MovingState {
+        current - arg
+    }
+}
+```
+
+This adds `MSFUNC`, `MINVFUNC`, `MSTYPE`, and `MINITCOND` to the `CREATE AGGREGATE`.  If the
+inverse can't always be computed, use an `Option` for `MovingState` and return `None` from
+`moving_state_inverse`: Postgres then recomputes the aggregate over the current frame.
+
+## Ordered-Set Aggregates
+
+With `ORDERED_SET = true` the aggregate is called like `agg(0.5) WITHIN GROUP (ORDER BY col)`.
+`state` still sees every row of `Args`, in the order given, while the `OrderedSetArgs` are the
+*direct* arguments, evaluated once and passed only to `finalize`.
+
+```rust
+# use pgrx::prelude::*;
+pub struct PercentileDisc;
+
+#[pg_aggregate]
+impl Aggregate for PercentileDisc {
+    const ORDERED_SET: bool = true;
+    type Args = name!(input, i32);
+    type OrderedSetArgs = name!(percentile, f64);
+    type State = Internal;
+    type Finalize = Option<i32>;
+
+    fn state(
+        mut current: Self::State,
+        arg: Self::Args,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::State {
+        unsafe { current.get_or_insert_default::<Vec<i32>>() }.push(arg);
+        current
+    }
+
+    fn finalize(
+        mut current: Self::State,
+        percentile: Self::OrderedSetArgs,
+        _fcinfo: pg_sys::FunctionCallInfo,
+    ) -> Self::Finalize {
+        let inner = unsafe { current.get_or_insert_default::<Vec<i32>>() };
+        let index = (inner.len() as f64 * percentile).ceil() as usize;
+        inner.get(index.saturating_sub(1)).copied()
+    }
+}
+```
+
+Creates:
+
+```sql
+CREATE AGGREGATE PercentileDisc (
+    "percentile" double precision /* f64 */
+    ORDER BY
+    "input" integer /* i32 */
+)
+(
+    SFUNC = "percentile_disc_state", /* aggregate::PercentileDisc::state */
+    STYPE = internal, /* pgrx::datum::internal::Internal */
+    FINALFUNC = "percentile_disc_finalize" /* aggregate::PercentileDisc::final */
+);
+```
+
+Postgres hands `state` the rows in the `ORDER BY` order of the call, but this isn't guaranteed
+if the aggregate is used some other way, so sort them in `finalize` when that matters.
+
+Setting `HYPOTHETICAL = true` as well makes a hypothetical-set aggregate, like `rank(3) WITHIN
+GROUP (ORDER BY col)`: the last `OrderedSetArgs` are the hypothetical row, and must have the same
+types as the `Args` they're compared against.  Ordered-set aggregates can't have a moving-aggregate
+mode.
+
 */
 
 use crate::error;
 use crate::memcxt::PgMemoryContexts;
 use crate::pg_sys::{AggCheckCallContext, CurrentMemoryContext, FunctionCallInfo, MemoryContext};
-use crate::pgbox::PgBox;
+use crate::{pg_sys, FromDatum, Internal, IntoDatum};
+use core::marker::PhantomData;
+use pgrx_sql_entity_graph::metadata::{
+    ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 pub use pgrx_sql_entity_graph::{FinalizeModify, ParallelOption};
 
@@ -331,6 +445,9 @@ where
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
     type Finalize;
 
+    /// The state of the aggregate in moving-aggregate mode, which may differ from `State`, such
+    /// as to keep extra information that `moving_state_inverse` needs.
+    ///
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
     type MovingState;
 
@@ -366,6 +483,9 @@ where
     /// **Optional:** This const can be skipped, `#[pg_aggregate]` will create a stub.
     const MOVING_INITIAL_CONDITION: Option<&'static str> = None;
 
+    /// Set to true if this is a hypothetical-set aggregate, an ordered-set aggregate whose last
+    /// `OrderedSetArgs` give a hypothetical row to compare to the `Args`, and so must match them.
+    ///
     /// **Optional:** This const can be skipped, `#[pg_aggregate]` will create a stub.
     const HYPOTHETICAL: bool = false;
 
@@ -383,17 +503,24 @@ where
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
     fn combine(current: Self::State, _other: Self::State, fcinfo: FunctionCallInfo) -> Self::State;
 
+    /// Encode an `internal` state to send it between the processes of a parallel query.
+    ///
+    /// Generated for an [`InternalState`] of a type that implements serde's `Serialize` and
+    /// `Deserialize`, if the aggregate implements `combine`.
+    ///
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
     fn serial(current: Self::State, fcinfo: FunctionCallInfo) -> Vec<u8>;
 
+    /// Decode an `internal` state encoded by `serial`.
+    ///
+    /// Generated for an [`InternalState`] of a type that implements serde's `Serialize` and
+    /// `Deserialize`, if the aggregate implements `combine`.
+    ///
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
-    fn deserial(
-        current: Self::State,
-        _buf: Vec<u8>,
-        _internal: PgBox<Self::State>,
-        fcinfo: FunctionCallInfo,
-    ) -> PgBox<Self::State>;
+    fn deserial(buf: Vec<u8>, fcinfo: FunctionCallInfo) -> Self::State;
 
+    /// Add a row entering the window frame to the moving-aggregate state.
+    ///
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
     fn moving_state(
         _mstate: Self::MovingState,
@@ -401,6 +528,10 @@ where
         fcinfo: FunctionCallInfo,
     ) -> Self::MovingState;
 
+    /// Remove a row leaving the window frame from the moving-aggregate state, undoing
+    /// `moving_state`.  Returning an SQL `NULL`, from a `MovingState` that is an `Option`, makes
+    /// Postgres recompute the state from the rows left in the frame.
+    ///
     /// **Optional:** This function can be skipped, `#[pg_aggregate]` will create a stub.
     fn moving_state_inverse(
         _mstate: Self::MovingState,
@@ -451,3 +582,142 @@ where
         }
     }
 }
+
+/// The `internal` state of an aggregate, a `T` kept in the aggregate's memory context
+///
+/// Unlike [`Internal`] it knows what it holds, so it can be used without `unsafe`.  An aggregate
+/// whose `State` is an [`InternalState`] of a type that implements serde's `Serialize` and
+/// `Deserialize`, and which implements `combine`, gets `serial` and `deserial` functions generated
+/// by `#[pg_aggregate]`, so it can run in parallel without writing them.
+///
+/// ```rust,no_run
+/// use pgrx::aggregate::InternalState;
+/// use pgrx::prelude::*;
+/// use std::collections::BTreeSet;
+///
+/// pub struct CountDistinct;
+///
+/// #[pg_aggregate]
+/// impl Aggregate for CountDistinct {
+///     const PARALLEL: Option<ParallelOption> = Some(ParallelOption::Safe);
+///     type Args = i64;
+///     type State = InternalState<BTreeSet<i64>>;
+///     type Finalize = i64;
+///
+///     fn state(mut current: Self::State, arg: Self::Args, _: pg_sys::FunctionCallInfo) -> Self::State {
+///         current.get_or_insert_default().insert(arg);
+///         current
+///     }
+///
+///     fn combine(mut first: Self::State, second: Self::State, _: pg_sys::FunctionCallInfo) -> Self::State {
+///         if let Some(second) = second.get() {
+///             first.get_or_insert_default().extend(second);
+///         }
+///         first
+///     }
+///
+///     fn finalize(current: Self::State, _: (), _: pg_sys::FunctionCallInfo) -> Self::Finalize {
+///         current.get().map_or(0, |set| set.len() as i64)
+///     }
+/// }
+/// ```
+pub struct InternalState<T> {
+    internal: Internal,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Default for InternalState<T> {
+    fn default() -> Self {
+        InternalState { internal: Internal::default(), _marker: PhantomData }
+    }
+}
+
+impl<T> InternalState<T> {
+    /// A state holding `value`, which is dropped when the
+    /// [`CurrentMemoryContext`](PgMemoryContexts::CurrentMemoryContext) is deleted.  The functions
+    /// `#[pg_aggregate]` generates run in the aggregate's memory context.
+    pub fn new(value: T) -> Self {
+        InternalState { internal: Internal::new(value), _marker: PhantomData }
+    }
+
+    /// The value, unless the state hasn't been initialized yet
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY:  an `InternalState<T>` only ever holds a `T`
+        unsafe { self.internal.get::<T>() }
+    }
+
+    /// The value, unless the state hasn't been initialized yet
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        // SAFETY:  an `InternalState<T>` only ever holds a `T`
+        unsafe { self.internal.get_mut::<T>() }
+    }
+
+    /// The value, initializing the state with `f` if it hasn't been yet
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        // SAFETY:  an `InternalState<T>` only ever holds a `T`
+        unsafe { self.internal.get_or_insert_with(f) }
+    }
+
+    /// The value, initializing the state with `T::default()` if it hasn't been yet
+    pub fn get_or_insert_default(&mut self) -> &mut T
+    where
+        T: Default,
+    {
+        self.get_or_insert_with(T::default)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> InternalState<T> {
+    /// Encode the state as CBOR, for the `serial` function `#[pg_aggregate]` generates
+    pub fn serialize(&self) -> Vec<u8> {
+        serde_cbor::to_vec(&self.get()).expect("failed to encode aggregate state as CBOR")
+    }
+
+    /// Decode a state encoded by [`InternalState::serialize`], for the `deserial` function
+    /// `#[pg_aggregate]` generates
+    pub fn deserialize(buf: &[u8]) -> Self {
+        match serde_cbor::from_slice::<Option<T>>(buf)
+            .expect("failed to decode aggregate state from CBOR")
+        {
+            Some(value) => InternalState::new(value),
+            None => InternalState::default(),
+        }
+    }
+}
+
+impl<T> FromDatum for InternalState<T> {
+    #[inline]
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self> {
+        let internal = Internal::from_polymorphic_datum(datum, is_null, typoid)?;
+        Some(InternalState { internal, _marker: PhantomData })
+    }
+}
+
+impl<T> IntoDatum for InternalState<T> {
+    #[inline]
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        self.internal.into_datum()
+    }
+
+    #[inline]
+    fn type_oid() -> pg_sys::Oid {
+        pg_sys::INTERNALOID
+    }
+}
+
+unsafe impl<T> SqlTranslatable for InternalState<T> {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Internal::argument_sql()
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Internal::return_sql()
+    }
+    // like `Internal`, an uninitialized state is NULL
+    fn optional() -> bool {
+        true
+    }
+}
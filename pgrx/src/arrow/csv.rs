@@ -0,0 +1,68 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Copy relations to and from CSV files, through Arrow
+//!
+//! Postgres' own `COPY` is usually the better choice for CSV, but it can't write to an arbitrary
+//! [`Write`] such as a network stream, and needs superuser or `pg_write_server_files` for files.
+//! Files have a header row naming the columns.
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use arrow_csv::{ReaderBuilder, WriterBuilder};
+
+use super::{for_each_record_batch, insert_record_batches, select_all, CopyError, COPY_BATCH_SIZE};
+use crate::PgRelation;
+
+/// Write all the rows of `relation` to `writer` as CSV, returning how many were written
+///
+/// Column types are mapped as described in the [`arrow`](super) module docs.
+pub fn copy_relation_to_csv<W: Write>(relation: &PgRelation, writer: W) -> Result<u64, CopyError> {
+    let mut writer = WriterBuilder::new().has_headers(true).build(writer);
+    let mut written = 0;
+    for_each_record_batch(&select_all(relation), COPY_BATCH_SIZE, |batch| {
+        written += batch.num_rows() as u64;
+        Ok::<_, CopyError>(writer.write(&batch)?)
+    })?;
+    Ok(written)
+}
+
+/// Write all the rows of `relation` to a new CSV file at `path`, replacing any file already there,
+/// and return how many were written
+///
+/// The file is written by the Postgres server process, so `path` is on the database server and
+/// relative paths are relative to its data directory.
+pub fn copy_relation_to_csv_file(
+    relation: &PgRelation,
+    path: impl AsRef<Path>,
+) -> Result<u64, CopyError> {
+    copy_relation_to_csv(relation, File::create(path)?)
+}
+
+/// Insert the rows of the CSV data in `reader` into `relation`, returning how many were inserted
+///
+/// The columns must be in the relation's order, and are parsed as the Arrow types the relation's
+/// columns map to.
+pub fn copy_csv_to_relation<R: Read>(reader: R, relation: &PgRelation) -> Result<u64, CopyError> {
+    let schema = super::RecordBatchBuilder::new(&relation.tuple_desc()).schema();
+    let batches = ReaderBuilder::new(schema)
+        .has_header(true)
+        .with_batch_size(COPY_BATCH_SIZE)
+        .build(reader)?;
+    insert_record_batches(relation, batches)
+}
+
+/// Insert the rows of the CSV file at `path` into `relation`, returning how many were inserted
+pub fn copy_csv_file_to_relation(
+    path: impl AsRef<Path>,
+    relation: &PgRelation,
+) -> Result<u64, CopyError> {
+    copy_csv_to_relation(File::open(path)?, relation)
+}
@@ -0,0 +1,568 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Columnar interchange with [Apache Arrow](https://arrow.apache.org)
+//!
+//! Query results are converted into Arrow [`RecordBatch`]es column by column, straight from their
+//! Datums, for handing to analytics libraries like DataFusion or writing out as Parquet.  Going the
+//! other way, a [`RecordBatch`] can be returned from a `RETURNS SETOF record` function.
+//!
+//! | Postgres                             | Arrow                              |
+//! |--------------------------------------|------------------------------------|
+//! | `bool`                               | `Boolean`                          |
+//! | `smallint`, `integer`, `bigint`      | `Int16`, `Int32`, `Int64`          |
+//! | `real`, `double precision`           | `Float32`, `Float64`               |
+//! | `oid`                                | `UInt32`                           |
+//! | `text`, `varchar`, `bpchar`, `name`  | `Utf8`                             |
+//! | `bytea`                              | `Binary`                           |
+//! | `date`                               | `Date32`                           |
+//! | `timestamp`                          | `Timestamp(Microsecond, None)`     |
+//! | `timestamptz`                        | `Timestamp(Microsecond, "+00:00")` |
+//! | anything else                        | `Utf8`, using the type's output function |
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::arrow;
+//!
+//! #[pg_extern]
+//! fn count_arrow_rows(query: &str) -> i64 {
+//!     let batches = arrow::query_to_record_batches(query, 10_000).unwrap();
+//!     batches.iter().map(|batch| batch.num_rows() as i64).sum()
+//! }
+//! ```
+//!
+//! With the `parquet` and `csv` features, the [`parquet`] and [`csv`] modules copy whole relations
+//! to and from files in those formats.
+use std::ffi::CStr;
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, Date32Builder, Float32Builder, Float64Builder, Int16Builder,
+    Int32Builder, Int64Builder, StringBuilder, TimestampMicrosecondBuilder, UInt32Builder,
+};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    TimestampMicrosecondType, UInt32Type,
+};
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+
+use crate::heap_tuple::PgHeapTuple;
+use crate::spi::{self, quote_identifier, quote_qualified_identifier, SpiClient, SpiTupleTable};
+use crate::{pg_sys, AllocatedByRust, FromDatum, IntoDatum, PgOid, PgRelation, PgTupleDesc, Spi};
+
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+/// How many rows the `copy_*` functions convert at a time
+pub const COPY_BATCH_SIZE: usize = 8192;
+
+/// Days from the Unix epoch to the Postgres epoch, 2000-01-01
+const POSTGRES_EPOCH_DAYS: i32 = 10_957;
+/// Microseconds from the Unix epoch to the Postgres epoch
+const POSTGRES_EPOCH_MICROS: i64 = POSTGRES_EPOCH_DAYS as i64 * 86_400_000_000;
+/// The timezone of `timestamptz` columns, which are always UTC
+const UTC: &str = "+00:00";
+
+/// Accumulates rows described by a tuple descriptor into Arrow columns
+pub struct RecordBatchBuilder {
+    schema: SchemaRef,
+    columns: Vec<ColumnBuilder>,
+    rows: usize,
+}
+
+enum ColumnBuilder {
+    Boolean(BooleanBuilder),
+    Int16(Int16Builder),
+    Int32(Int32Builder),
+    Int64(Int64Builder),
+    Float32(Float32Builder),
+    Float64(Float64Builder),
+    UInt32(UInt32Builder),
+    Utf8(StringBuilder),
+    Binary(BinaryBuilder),
+    Date32(Date32Builder),
+    Timestamp(TimestampMicrosecondBuilder),
+    TimestampTz(TimestampMicrosecondBuilder),
+    /// Any other type, formatted by its output function
+    Output {
+        output_func: pg_sys::Oid,
+        builder: StringBuilder,
+    },
+}
+
+impl ColumnBuilder {
+    fn new(typoid: pg_sys::Oid) -> (ColumnBuilder, DataType) {
+        match typoid {
+            pg_sys::BOOLOID => (ColumnBuilder::Boolean(Default::default()), DataType::Boolean),
+            pg_sys::INT2OID => (ColumnBuilder::Int16(Default::default()), DataType::Int16),
+            pg_sys::INT4OID => (ColumnBuilder::Int32(Default::default()), DataType::Int32),
+            pg_sys::INT8OID => (ColumnBuilder::Int64(Default::default()), DataType::Int64),
+            pg_sys::FLOAT4OID => (ColumnBuilder::Float32(Default::default()), DataType::Float32),
+            pg_sys::FLOAT8OID => (ColumnBuilder::Float64(Default::default()), DataType::Float64),
+            pg_sys::OIDOID => (ColumnBuilder::UInt32(Default::default()), DataType::UInt32),
+            pg_sys::TEXTOID | pg_sys::VARCHAROID | pg_sys::BPCHAROID | pg_sys::NAMEOID => {
+                (ColumnBuilder::Utf8(Default::default()), DataType::Utf8)
+            }
+            pg_sys::BYTEAOID => (ColumnBuilder::Binary(Default::default()), DataType::Binary),
+            pg_sys::DATEOID => (ColumnBuilder::Date32(Default::default()), DataType::Date32),
+            pg_sys::TIMESTAMPOID => (
+                ColumnBuilder::Timestamp(Default::default()),
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+            ),
+            pg_sys::TIMESTAMPTZOID => (
+                ColumnBuilder::TimestampTz(TimestampMicrosecondBuilder::new().with_timezone(UTC)),
+                DataType::Timestamp(TimeUnit::Microsecond, Some(UTC.into())),
+            ),
+            typoid => {
+                let mut output_func = pg_sys::InvalidOid;
+                let mut is_varlena = false;
+                unsafe { pg_sys::getTypeOutputInfo(typoid, &mut output_func, &mut is_varlena) };
+                (ColumnBuilder::Output { output_func, builder: Default::default() }, DataType::Utf8)
+            }
+        }
+    }
+
+    /// ## Safety
+    ///
+    /// `datum` must be of the type this column was created for
+    unsafe fn append(&mut self, datum: Option<pg_sys::Datum>) -> Result<(), ArrowError> {
+        let Some(datum) = datum else {
+            self.append_null();
+            return Ok(());
+        };
+
+        match self {
+            ColumnBuilder::Boolean(b) => b.append_value(datum.value() != 0),
+            ColumnBuilder::Int16(b) => b.append_value(datum.value() as i16),
+            ColumnBuilder::Int32(b) => b.append_value(datum.value() as i32),
+            ColumnBuilder::Int64(b) => b.append_value(datum.value() as i64),
+            ColumnBuilder::Float32(b) => b.append_value(f32::from_bits(datum.value() as u32)),
+            ColumnBuilder::Float64(b) => b.append_value(f64::from_bits(datum.value() as u64)),
+            ColumnBuilder::UInt32(b) => b.append_value(datum.value() as u32),
+            ColumnBuilder::Utf8(b) => {
+                // `name` isn't a varlena, but it's a NUL-terminated string all the same
+                b.append_value(
+                    <&str>::from_polymorphic_datum(datum, false, pg_sys::InvalidOid).unwrap(),
+                )
+            }
+            ColumnBuilder::Binary(b) => b.append_value(
+                <&[u8]>::from_polymorphic_datum(datum, false, pg_sys::InvalidOid).unwrap(),
+            ),
+            ColumnBuilder::Date32(b) => {
+                let date = datum.value() as i32;
+                if date == i32::MIN || date == i32::MAX {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "infinite dates can't be represented in Arrow".into(),
+                    ));
+                }
+                b.append_value(date + POSTGRES_EPOCH_DAYS)
+            }
+            ColumnBuilder::Timestamp(b) | ColumnBuilder::TimestampTz(b) => {
+                let micros = datum.value() as i64;
+                if micros == i64::MIN || micros == i64::MAX {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "infinite timestamps can't be represented in Arrow".into(),
+                    ));
+                }
+                b.append_value(micros + POSTGRES_EPOCH_MICROS)
+            }
+            ColumnBuilder::Output { output_func, builder } => {
+                let cstr = pg_sys::OidOutputFunctionCall(*output_func, datum);
+                builder.append_value(std::ffi::CStr::from_ptr(cstr).to_string_lossy());
+                pg_sys::pfree(cstr.cast());
+            }
+        }
+        Ok(())
+    }
+
+    fn append_null(&mut self) {
+        match self {
+            ColumnBuilder::Boolean(b) => b.append_null(),
+            ColumnBuilder::Int16(b) => b.append_null(),
+            ColumnBuilder::Int32(b) => b.append_null(),
+            ColumnBuilder::Int64(b) => b.append_null(),
+            ColumnBuilder::Float32(b) => b.append_null(),
+            ColumnBuilder::Float64(b) => b.append_null(),
+            ColumnBuilder::UInt32(b) => b.append_null(),
+            ColumnBuilder::Utf8(b) => b.append_null(),
+            ColumnBuilder::Binary(b) => b.append_null(),
+            ColumnBuilder::Date32(b) => b.append_null(),
+            ColumnBuilder::Timestamp(b) | ColumnBuilder::TimestampTz(b) => b.append_null(),
+            ColumnBuilder::Output { builder, .. } => builder.append_null(),
+        }
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            ColumnBuilder::Boolean(b) => Arc::new(b.finish()),
+            ColumnBuilder::Int16(b) => Arc::new(b.finish()),
+            ColumnBuilder::Int32(b) => Arc::new(b.finish()),
+            ColumnBuilder::Int64(b) => Arc::new(b.finish()),
+            ColumnBuilder::Float32(b) => Arc::new(b.finish()),
+            ColumnBuilder::Float64(b) => Arc::new(b.finish()),
+            ColumnBuilder::UInt32(b) => Arc::new(b.finish()),
+            ColumnBuilder::Utf8(b) => Arc::new(b.finish()),
+            ColumnBuilder::Binary(b) => Arc::new(b.finish()),
+            ColumnBuilder::Date32(b) => Arc::new(b.finish()),
+            ColumnBuilder::Timestamp(b) => Arc::new(b.finish()),
+            ColumnBuilder::TimestampTz(b) => Arc::new(b.finish().with_timezone(UTC)),
+            ColumnBuilder::Output { builder, .. } => Arc::new(builder.finish()),
+        }
+    }
+}
+
+impl RecordBatchBuilder {
+    /// A builder for rows described by `tupdesc`, whose attributes become the batch's columns
+    pub fn new(tupdesc: &PgTupleDesc) -> Self {
+        let (columns, fields): (Vec<_>, Vec<_>) = tupdesc
+            .iter()
+            .filter(|att| !att.is_dropped())
+            .map(|att| {
+                let (column, data_type) = ColumnBuilder::new(att.type_oid().value());
+                (column, Field::new(att.name(), data_type, !att.attnotnull))
+            })
+            .unzip();
+        RecordBatchBuilder { schema: Arc::new(Schema::new(fields)), columns, rows: 0 }
+    }
+
+    /// The schema of the batches this builds
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// The number of rows appended since the last batch was finished
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Append a row, deforming `tuple` with the `tupdesc` this builder was created with
+    ///
+    /// ## Safety
+    ///
+    /// `tuple` must be a valid heap tuple described by `tupdesc`, which must be the descriptor
+    /// this builder was created with
+    pub unsafe fn append_heap_tuple(
+        &mut self,
+        tupdesc: &PgTupleDesc,
+        tuple: *mut pg_sys::HeapTupleData,
+    ) -> Result<(), ArrowError> {
+        let natts = tupdesc.len();
+        let mut datums = vec![pg_sys::Datum::from(0); natts];
+        let mut nulls = vec![true; natts];
+        pg_sys::heap_deform_tuple(tuple, tupdesc.as_ptr(), datums.as_mut_ptr(), nulls.as_mut_ptr());
+
+        let values = tupdesc
+            .iter()
+            .zip(datums.into_iter().zip(nulls))
+            .filter(|(att, _)| !att.is_dropped())
+            .map(|(_, (datum, is_null))| (!is_null).then_some(datum));
+        for (column, datum) in self.columns.iter_mut().zip(values) {
+            column.append(datum)?;
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Finish the rows appended so far into a [`RecordBatch`], and start a new one
+    pub fn finish(&mut self) -> Result<RecordBatch, ArrowError> {
+        let columns = self.columns.iter_mut().map(ColumnBuilder::finish).collect();
+        self.rows = 0;
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+}
+
+/// Convert the rows of an SPI result into a [`RecordBatch`]
+pub fn spi_to_record_batch(table: &SpiTupleTable) -> Result<RecordBatch, ArrowError> {
+    let Some((tupdesc, tuples)) = table.raw_tuples() else {
+        return Ok(RecordBatch::new_empty(Arc::new(Schema::empty())));
+    };
+    let tupdesc = unsafe { PgTupleDesc::from_pg_unchecked(tupdesc) };
+    let mut builder = RecordBatchBuilder::new(&tupdesc);
+    for &tuple in tuples {
+        unsafe { builder.append_heap_tuple(&tupdesc, tuple)? };
+    }
+    builder.finish()
+}
+
+/// Run `query`, fetching and converting its rows `batch_size` at a time through a cursor, so the
+/// whole result needn't be materialized as tuples first
+pub fn query_to_record_batches(
+    query: &str,
+    batch_size: usize,
+) -> Result<Vec<RecordBatch>, ArrowQueryError> {
+    let mut batches = Vec::new();
+    for_each_record_batch(query, batch_size, |batch| {
+        batches.push(batch);
+        Ok::<_, ArrowQueryError>(())
+    })?;
+    Ok(batches)
+}
+
+/// Run `query`, passing its rows to `f` as they're fetched, `batch_size` at a time
+///
+/// Unlike [`query_to_record_batches()`] only one batch is in memory at a time, so this suits
+/// streaming a large result out to a file or socket.
+pub fn for_each_record_batch<E>(
+    query: &str,
+    batch_size: usize,
+    mut f: impl FnMut(RecordBatch) -> Result<(), E>,
+) -> Result<(), E>
+where
+    E: From<spi::Error> + From<ArrowError>,
+{
+    Spi::connect(|client| {
+        let mut cursor = client.open_cursor(query, None);
+        loop {
+            let table = cursor.fetch(batch_size as _)?;
+            if table.is_empty() {
+                return Ok(());
+            }
+            f(spi_to_record_batch(&table)?)?;
+        }
+    })
+}
+
+/// Insert the rows of `batches` into `relation`, returning how many were inserted
+///
+/// Columns are matched up by name, and each value is cast to its column's type, so a `Utf8` column
+/// can be inserted into any column whose type can be cast from `text`.  Columns of the relation
+/// missing from the batches get their default.  Every batch must have the same schema.
+pub fn insert_record_batches<E>(
+    relation: &PgRelation,
+    batches: impl IntoIterator<Item = Result<RecordBatch, E>>,
+) -> Result<u64, CopyError>
+where
+    CopyError: From<E>,
+{
+    Spi::connect(|mut client| {
+        let mut statement = None;
+        let mut inserted = 0;
+        for batch in batches {
+            let batch = batch?;
+            let statement = match statement {
+                Some(ref statement) => statement,
+                None => statement.insert(prepare_insert(&client, relation, &batch.schema())?),
+            };
+            for row in 0..batch.num_rows() {
+                let args = batch.columns().iter().map(|column| datum_at(column, row)).collect();
+                client.update(&*statement, None, Some(args))?;
+                inserted += 1;
+            }
+        }
+        Ok(inserted)
+    })
+}
+
+fn prepare_insert<'conn>(
+    client: &SpiClient<'conn>,
+    relation: &PgRelation,
+    schema: &Schema,
+) -> Result<spi::PreparedStatement<'conn>, CopyError> {
+    let tupdesc = relation.tuple_desc();
+    let mut columns = Vec::with_capacity(schema.fields().len());
+    let mut values = Vec::with_capacity(schema.fields().len());
+    let mut types = Vec::with_capacity(schema.fields().len());
+    for (i, field) in schema.fields().iter().enumerate() {
+        let att = tupdesc
+            .iter()
+            .find(|att| !att.is_dropped() && att.name() == field.name())
+            .ok_or_else(|| {
+                ArrowError::SchemaError(format!(
+                    "relation \"{}\" has no column \"{}\"",
+                    relation.name(),
+                    field.name()
+                ))
+            })?;
+        let typname = unsafe { CStr::from_ptr(pg_sys::format_type_be(att.atttypid)) };
+        columns.push(quote_identifier(field.name()));
+        values.push(format!("${}::{}", i + 1, typname.to_string_lossy()));
+        types.push(PgOid::from(postgres_type_of(field.data_type())?));
+    }
+
+    let query = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        qualified_name(relation),
+        columns.join(", "),
+        values.join(", ")
+    );
+    Ok(client.prepare(&query, Some(types))?)
+}
+
+/// The quoted, schema-qualified name of `relation`
+fn qualified_name(relation: &PgRelation) -> String {
+    quote_qualified_identifier(relation.namespace(), relation.name())
+}
+
+/// The query the `copy_relation_to_*` functions read `relation` with
+fn select_all(relation: &PgRelation) -> String {
+    format!("SELECT * FROM {}", qualified_name(relation))
+}
+
+/// An error running a query with [`query_to_record_batches()`]
+#[derive(thiserror::Error, Debug)]
+pub enum ArrowQueryError {
+    #[error(transparent)]
+    Spi(#[from] spi::Error),
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+}
+
+/// An error copying a relation to or from a file
+#[derive(thiserror::Error, Debug)]
+pub enum CopyError {
+    #[error(transparent)]
+    Spi(#[from] spi::Error),
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "parquet")]
+    #[error(transparent)]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+}
+
+impl From<ArrowQueryError> for CopyError {
+    fn from(error: ArrowQueryError) -> Self {
+        match error {
+            ArrowQueryError::Spi(e) => CopyError::Spi(e),
+            ArrowQueryError::Arrow(e) => CopyError::Arrow(e),
+        }
+    }
+}
+
+/// The Postgres type of an Arrow [`DataType`], for the types listed in the [module docs](self)
+pub fn postgres_type_of(data_type: &DataType) -> Result<pg_sys::Oid, ArrowError> {
+    match data_type {
+        DataType::Boolean => Ok(pg_sys::BOOLOID),
+        DataType::Int16 => Ok(pg_sys::INT2OID),
+        DataType::Int32 => Ok(pg_sys::INT4OID),
+        DataType::Int64 => Ok(pg_sys::INT8OID),
+        DataType::Float32 => Ok(pg_sys::FLOAT4OID),
+        DataType::Float64 => Ok(pg_sys::FLOAT8OID),
+        DataType::UInt32 => Ok(pg_sys::OIDOID),
+        DataType::Utf8 => Ok(pg_sys::TEXTOID),
+        DataType::Binary => Ok(pg_sys::BYTEAOID),
+        DataType::Date32 => Ok(pg_sys::DATEOID),
+        DataType::Timestamp(TimeUnit::Microsecond, None) => Ok(pg_sys::TIMESTAMPOID),
+        DataType::Timestamp(TimeUnit::Microsecond, Some(_)) => Ok(pg_sys::TIMESTAMPTZOID),
+        other => {
+            Err(ArrowError::NotYetImplemented(format!("no Postgres type for Arrow type {other}")))
+        }
+    }
+}
+
+/// The Datum for row `row` of `array`, allocated in the `CurrentMemoryContext`
+fn datum_at(array: &dyn Array, row: usize) -> Option<pg_sys::Datum> {
+    if array.is_null(row) {
+        return None;
+    }
+    match array.data_type() {
+        DataType::Boolean => array.as_boolean().value(row).into_datum(),
+        DataType::Int16 => array.as_primitive::<Int16Type>().value(row).into_datum(),
+        DataType::Int32 => array.as_primitive::<Int32Type>().value(row).into_datum(),
+        DataType::Int64 => array.as_primitive::<Int64Type>().value(row).into_datum(),
+        DataType::Float32 => array.as_primitive::<Float32Type>().value(row).into_datum(),
+        DataType::Float64 => array.as_primitive::<Float64Type>().value(row).into_datum(),
+        DataType::UInt32 => {
+            Some(pg_sys::Datum::from(array.as_primitive::<UInt32Type>().value(row)))
+        }
+        DataType::Utf8 => array.as_string::<i32>().value(row).into_datum(),
+        DataType::Binary => array.as_binary::<i32>().value(row).into_datum(),
+        DataType::Date32 => Some(pg_sys::Datum::from(
+            array.as_primitive::<Date32Type>().value(row) - POSTGRES_EPOCH_DAYS,
+        )),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => Some(pg_sys::Datum::from(
+            array.as_primitive::<TimestampMicrosecondType>().value(row) - POSTGRES_EPOCH_MICROS,
+        )),
+        other => unreachable!("Arrow type {other} was accepted by postgres_type_of()"),
+    }
+}
+
+/// The rows of a [`RecordBatch`] as anonymous records, made by [`record_batch_into_records()`]
+pub struct RecordBatchRecords {
+    batch: RecordBatch,
+    tupdesc: PgTupleDesc<'static>,
+    row: usize,
+}
+
+impl Iterator for RecordBatchRecords {
+    type Item = PgHeapTuple<'static, AllocatedByRust>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+
+        let datums = self.batch.columns().iter().map(|column| datum_at(column, row));
+        let record = unsafe { PgHeapTuple::from_datums(self.tupdesc.clone(), datums) };
+        Some(record.expect("the record has exactly one datum per column"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.batch.num_rows() - self.row;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for RecordBatchRecords {}
+
+/// Convert the rows of `batch` into anonymous records, such as for a function that
+/// `RETURNS SETOF record`
+///
+/// Rows are converted as they're iterated over.
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::arrow;
+///
+/// #[pg_extern]
+/// fn roundtrip(query: &str) -> SetOfIterator<'static, pgrx::composite_type!("record")> {
+///     let batches = arrow::query_to_record_batches(query, 10_000).unwrap();
+///     let records = batches.into_iter().flat_map(|batch| arrow::record_batch_into_records(batch).unwrap());
+///     SetOfIterator::new(records)
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns [`ArrowError::NotYetImplemented`] if a column has a type without a Postgres type, see
+/// [`postgres_type_of()`].
+pub fn record_batch_into_records(batch: RecordBatch) -> Result<RecordBatchRecords, ArrowError> {
+    let schema = batch.schema();
+    let tupdesc = crate::compat::create_template_tuple_desc(schema.fields().len() as _);
+    for (i, field) in schema.fields().iter().enumerate() {
+        let typoid = postgres_type_of(field.data_type())?;
+        let name = std::ffi::CString::new(field.name().as_str())
+            .map_err(|e| ArrowError::InvalidArgumentError(e.to_string()))?;
+        unsafe {
+            pg_sys::TupleDescInitEntry(
+                tupdesc,
+                (i + 1) as pg_sys::AttrNumber,
+                name.as_ptr(),
+                typoid,
+                -1,
+                0,
+            );
+        }
+    }
+
+    let mut tupdesc = unsafe { PgTupleDesc::from_pg_is_copy(tupdesc) };
+    tupdesc.bless();
+    Ok(RecordBatchRecords { batch, tupdesc, row: 0 })
+}
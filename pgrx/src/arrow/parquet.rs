@@ -0,0 +1,88 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Copy relations to and from [Apache Parquet](https://parquet.apache.org) files
+//!
+//! Rows are converted [`COPY_BATCH_SIZE`] at a time, each batch becoming a row group, so memory
+//! use doesn't grow with the size of the relation.  These run over SPI, so they can be called from
+//! a `#[pg_extern]` function or from a background worker inside a transaction.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::arrow::parquet;
+//! use pgrx::PgRelation;
+//!
+//! #[pg_extern]
+//! fn export_parquet(relation: pg_sys::Oid, path: &str) -> i64 {
+//!     let relation = unsafe { PgRelation::with_lock(relation, pg_sys::AccessShareLock as _) };
+//!     parquet::copy_relation_to_parquet_file(&relation, path).unwrap() as i64
+//! }
+//! ```
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::file::reader::ChunkReader;
+
+use super::{for_each_record_batch, insert_record_batches, select_all, CopyError, COPY_BATCH_SIZE};
+use crate::PgRelation;
+
+/// Write all the rows of `relation` to `writer` as Parquet, returning how many were written
+///
+/// Column types are mapped as described in the [`arrow`](super) module docs.
+pub fn copy_relation_to_parquet<W: Write + Send>(
+    relation: &PgRelation,
+    writer: W,
+) -> Result<u64, CopyError> {
+    let schema = super::RecordBatchBuilder::new(&relation.tuple_desc()).schema();
+    let mut writer = ArrowWriter::try_new(writer, schema, None)?;
+    let mut written = 0;
+    for_each_record_batch(&select_all(relation), COPY_BATCH_SIZE, |batch| {
+        written += batch.num_rows() as u64;
+        Ok::<_, CopyError>(writer.write(&batch)?)
+    })?;
+    writer.close()?;
+    Ok(written)
+}
+
+/// Write all the rows of `relation` to a new Parquet file at `path`, replacing any file already
+/// there, and return how many were written
+///
+/// The file is written by the Postgres server process, so `path` is on the database server and
+/// relative paths are relative to its data directory.
+pub fn copy_relation_to_parquet_file(
+    relation: &PgRelation,
+    path: impl AsRef<Path>,
+) -> Result<u64, CopyError> {
+    copy_relation_to_parquet(relation, File::create(path)?)
+}
+
+/// Insert the rows of the Parquet data in `reader` into `relation`, returning how many were
+/// inserted
+///
+/// Columns are matched up by name, see [`insert_record_batches()`](super::insert_record_batches).
+pub fn copy_parquet_to_relation<R: ChunkReader + 'static>(
+    reader: R,
+    relation: &PgRelation,
+) -> Result<u64, CopyError> {
+    let batches = ParquetRecordBatchReaderBuilder::try_new(reader)?
+        .with_batch_size(COPY_BATCH_SIZE)
+        .build()?;
+    insert_record_batches(relation, batches)
+}
+
+/// Insert the rows of the Parquet file at `path` into `relation`, returning how many were inserted
+pub fn copy_parquet_file_to_relation(
+    path: impl AsRef<Path>,
+    relation: &PgRelation,
+) -> Result<u64, CopyError> {
+    copy_parquet_to_relation(File::open(path)?, relation)
+}
@@ -0,0 +1,80 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Run `async` code, such as async client libraries, from SQL functions
+//!
+//! A backend is single threaded and can't return to Postgres until a function's result is ready,
+//! so async code has to be run to completion before returning.  [`block_on()`] does that on a
+//! per-backend [tokio](https://tokio.rs) runtime, created the first time it's needed, and checks for
+//! query cancellation while it waits.  When the query is cancelled (or `statement_timeout` expires)
+//! the future is dropped, cancelling whatever it was doing, and the usual `ERROR` is raised.
+//!
+//! `#[pg_extern(blocking_on = ...)]` wraps an `async fn` in it:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::async_support::tokio;
+//!
+//! #[pg_extern(blocking_on = pgrx::async_support::block_on)]
+//! async fn slow_add(a: i32, b: i32) -> i32 {
+//!     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+//!     a + b
+//! }
+//! ```
+//!
+//! Any function like `fn<F: Future>(F) -> F::Output` can be used instead, to run futures some other
+//! way.
+//!
+//! The runtime only runs while [`block_on()`] is waiting, and only on the backend's thread, so
+//! futures may use Postgres as long as they don't hold anything across an `.await` that Postgres
+//! could free in the meantime.  Don't call [`block_on()`] from inside a future it's running.
+use std::future::Future;
+use std::time::Duration;
+
+use once_cell::sync::OnceCell;
+
+use crate::pg_sys;
+
+pub use tokio;
+
+/// How often to check for interrupts while waiting for a future
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+static RUNTIME: OnceCell<tokio::runtime::Runtime> = OnceCell::new();
+
+/// This backend's tokio runtime, a current-thread runtime with IO and timers enabled
+///
+/// It's created the first time it's used.  That mustn't be in the postmaster, such as from the
+/// `_PG_init()` of a library in `shared_preload_libraries`, because its backends would inherit a
+/// runtime that doesn't work across `fork()`.
+pub fn runtime() -> &'static tokio::runtime::Runtime {
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create the tokio runtime")
+    })
+}
+
+/// Run `future` to completion on this backend's [`runtime()`], raising an `ERROR` if the query is
+/// cancelled first
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let runtime = runtime();
+    tokio::pin!(future);
+    loop {
+        // interrupts are checked outside of the runtime, so that the `ERROR` they might raise
+        // doesn't unwind through it
+        match runtime.block_on(tokio::time::timeout(POLL_INTERVAL, future.as_mut())) {
+            Ok(output) => return output,
+            Err(_elapsed) => {
+                pg_sys::check_for_interrupts!();
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! A channel for handing results from worker OS threads back to the Postgres backend thread.
+//!
+//! Postgres is not thread-safe.  Threads spawned by an extension must never call into Postgres,
+//! not even to allocate memory or raise an `ERROR`, because every such call assumes it's running
+//! on the backend's main thread.  The one pattern that is safe is for worker threads to do pure
+//! Rust work and send their results back to the backend thread, which then does all the
+//! Postgres-facing work itself.
+//!
+//! [`BackendChannel`] codifies that pattern.  The receiving end lives on the backend thread and
+//! blocks on the backend's latch, so while it's waiting it still responds to query cancellation,
+//! `statement_timeout`, and postmaster death.  The sending end, [`BackendSender`], can be moved
+//! to any thread and never touches Postgres -- it wakes the backend by signalling its thread
+//! with `SIGUSR1`, whose Postgres handler sets the latch.
+//!
+//! ```rust,no_run
+//! use pgrx::backend_channel::BackendChannel;
+//!
+//! let channel = BackendChannel::new();
+//! for i in 0..4 {
+//!     let sender = channel.sender();
+//!     std::thread::spawn(move || {
+//!         // pure Rust work only -- no `pg_sys` calls in here
+//!         sender.send(i * i).ok();
+//!     });
+//! }
+//!
+//! // back on the backend thread, where Postgres calls are fine
+//! let total: i32 = channel.iter().sum();
+//! ```
+use crate::pg_sys;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+// wake up periodically even without a signal, in case one was lost to another SIGUSR1 handler
+const MAX_WAIT: Duration = Duration::from_secs(1);
+
+/// Errors returned when receiving from a [`BackendChannel`] with a timeout
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    #[error("timed out waiting on the channel")]
+    Timeout,
+
+    #[error("all senders have been dropped")]
+    Disconnected,
+}
+
+/// The error returned by [`BackendSender::send`] when the [`BackendChannel`] has been dropped.
+/// It hands the unsent value back.
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq)]
+#[error("the receiving backend channel has been dropped")]
+pub struct SendError<T>(pub T);
+
+/// The receiving end of a channel whose senders live on worker threads.
+///
+/// It must be created, and received from, on the backend's main thread.  It's deliberately not
+/// `Send`.
+pub struct BackendChannel<T> {
+    sender: mpsc::Sender<T>,
+    receiver: mpsc::Receiver<T>,
+    senders: Arc<()>,
+    backend: libc::pthread_t,
+    backend_thread: std::thread::ThreadId,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+/// The sending end of a [`BackendChannel`].  It can be cloned and moved to any thread.
+pub struct BackendSender<T> {
+    sender: mpsc::Sender<T>,
+    _alive: Arc<()>,
+    backend: libc::pthread_t,
+}
+
+impl<T> Clone for BackendSender<T> {
+    fn clone(&self) -> Self {
+        Self { sender: self.sender.clone(), _alive: self._alive.clone(), backend: self.backend }
+    }
+}
+
+impl<T: Send> BackendSender<T> {
+    /// Send `value` to the backend and wake it up if it's waiting.
+    ///
+    /// This never calls into Postgres and is safe to use from any thread.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        self.sender.send(value).map_err(|mpsc::SendError(value)| SendError(value))?;
+        wake_backend(self.backend);
+        Ok(())
+    }
+}
+
+impl<T: Send> Default for BackendChannel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Send> BackendChannel<T> {
+    /// Create a new channel.  This must be called on the backend's main thread.
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        BackendChannel {
+            sender,
+            receiver,
+            senders: Arc::new(()),
+            backend: unsafe { libc::pthread_self() },
+            backend_thread: std::thread::current().id(),
+            _not_send: std::marker::PhantomData,
+        }
+    }
+
+    /// Create a new [`BackendSender`] for this channel, to be moved into a worker thread
+    pub fn sender(&self) -> BackendSender<T> {
+        BackendSender {
+            sender: self.sender.clone(),
+            _alive: self.senders.clone(),
+            backend: self.backend,
+        }
+    }
+
+    /// Receive a value without waiting, returning `None` if none is ready
+    pub fn try_recv(&self) -> Option<T> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Wait for the next value.  Returns `None` once every [`BackendSender`] has been dropped
+    /// and the channel is empty.
+    ///
+    /// While waiting, pending interrupts are serviced, so a query cancel or
+    /// `statement_timeout` raises its usual `ERROR` from here.
+    pub fn recv(&self) -> Option<T> {
+        match self.wait(None, &|| false) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Like [`BackendChannel::recv`], but gives up after `timeout`
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.wait(Some(Instant::now() + timeout), &|| false)
+    }
+
+    /// Like [`BackendChannel::recv`], but gives up with [`RecvTimeoutError::Timeout`] as soon as
+    /// `give_up` returns true.  It's checked every time the backend's latch is set.
+    pub(crate) fn recv_until(&self, give_up: impl Fn() -> bool) -> Result<T, RecvTimeoutError> {
+        self.wait(None, &give_up)
+    }
+
+    /// An iterator that receives values until every [`BackendSender`] has been dropped
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.recv())
+    }
+
+    fn wait(
+        &self,
+        deadline: Option<Instant>,
+        give_up: &dyn Fn() -> bool,
+    ) -> Result<T, RecvTimeoutError> {
+        assert_eq!(
+            self.backend_thread,
+            std::thread::current().id(),
+            "BackendChannel must be received from on the backend thread that created it"
+        );
+
+        loop {
+            if let Ok(value) = self.receiver.try_recv() {
+                return Ok(value);
+            }
+
+            // our own `sender` keeps the mpsc channel connected, so track the others ourselves
+            if self.is_orphaned() {
+                // a sender may have sent its last value between the two checks
+                return self.receiver.try_recv().map_err(|_| RecvTimeoutError::Disconnected);
+            }
+
+            if give_up() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+
+            let wait_for = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(RecvTimeoutError::Timeout);
+                    }
+                    (deadline - now).min(MAX_WAIT)
+                }
+                None => MAX_WAIT,
+            };
+
+            unsafe {
+                let rc = pg_sys::WaitLatch(
+                    pg_sys::MyLatch,
+                    (pg_sys::WL_LATCH_SET | pg_sys::WL_TIMEOUT | pg_sys::WL_POSTMASTER_DEATH) as _,
+                    wait_for.as_millis().max(1) as _,
+                    pg_sys::PG_WAIT_EXTENSION,
+                );
+                pg_sys::ResetLatch(pg_sys::MyLatch);
+
+                if rc & pg_sys::WL_POSTMASTER_DEATH as i32 != 0 {
+                    pg_sys::proc_exit(1);
+                }
+            }
+            pg_sys::check_for_interrupts!();
+        }
+    }
+
+    fn is_orphaned(&self) -> bool {
+        // the channel itself holds one reference, every live `BackendSender` holds another
+        Arc::strong_count(&self.senders) == 1
+    }
+}
+
+fn wake_backend(backend: libc::pthread_t) {
+    // Postgres' SIGUSR1 handler sets `MyLatch`, and it runs on the backend thread, so this is the
+    // one way to wake the backend without calling into Postgres from this thread
+    unsafe {
+        libc::pthread_kill(backend, libc::SIGUSR1);
+    }
+}
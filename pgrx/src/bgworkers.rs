@@ -11,6 +11,8 @@
 //!
 //! See: [https://www.postgresql.org/docs/current/bgworker.html](https://www.postgresql.org/docs/current/bgworker.html)
 use crate::pg_sys;
+use crate::schedule::Schedule;
+use once_cell::sync::OnceCell;
 use pgrx_pg_sys::PgTryBuilder;
 use std::convert::TryInto;
 use std::ffi::{CStr, CString};
@@ -19,9 +21,18 @@ use std::ptr::null_mut;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+mod supervisor;
+pub use supervisor::{DatabaseSupervisor, SupervisedDatabases};
+
 pub static mut PREV_SHMEM_STARTUP_HOOK: Option<unsafe extern "C" fn()> = None;
 static GOT_SIGHUP: AtomicBool = AtomicBool::new(false);
 static GOT_SIGTERM: AtomicBool = AtomicBool::new(false);
+static RELOAD_PENDING: AtomicBool = AtomicBool::new(false);
+
+static mut RELOAD_CALLBACKS: Option<Vec<Box<dyn FnMut()>>> = None;
+static mut SHUTDOWN_CALLBACKS: Option<Vec<Box<dyn FnOnce()>>> = None;
+static mut SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+static SHUTDOWN_TIMEOUT_ID: OnceCell<pg_sys::TimeoutId> = OnceCell::new();
 
 bitflags! {
     struct BGWflags: i32 {
@@ -104,10 +115,13 @@ impl BackgroundWorker {
     }
 
     /// Have we received a SIGUP?
+    ///
+    /// The configuration file has been reloaded by the time this returns `true`.
     pub fn sighup_received() -> bool {
         unsafe {
             assert!(!pg_sys::MyBgworkerEntry.is_null(), "BackgroundWorker associated functions can only be called from a registered background worker");
         }
+        BackgroundWorker::reload_if_requested();
         // toggle the bool to false, returning whatever it was
         GOT_SIGHUP.swap(false, Ordering::SeqCst)
     }
@@ -135,9 +149,163 @@ impl BackgroundWorker {
             ),
             None => wait_latch(0, WLflags::WL_LATCH_SET | WLflags::WL_POSTMASTER_DEATH),
         };
+        BackgroundWorker::reload_if_requested();
         !BackgroundWorker::sigterm_received()
     }
 
+    /// Call `f` every time the configuration file is reloaded because of a SIGHUP, such as to
+    /// re-read GUCs the worker caches.  Callbacks are called in the order they were registered.
+    ///
+    /// Reloads happen in [`BackgroundWorker::wait_latch`], [`BackgroundWorker::sighup_received`],
+    /// and [`BackgroundWorker::reload_if_requested`], never in the middle of other work, so `f`
+    /// may do anything the worker's main loop can.  The SIGHUP handler must be
+    /// [attached](BackgroundWorker::attach_signal_handlers).
+    pub fn on_reload<F: FnMut() + 'static>(f: F) {
+        unsafe {
+            assert!(!pg_sys::MyBgworkerEntry.is_null(), "BackgroundWorker associated functions can only be called from a registered background worker");
+            let callbacks = &mut *std::ptr::addr_of_mut!(RELOAD_CALLBACKS);
+            callbacks.get_or_insert_with(Vec::new).push(Box::new(f));
+        }
+    }
+
+    /// If a SIGHUP arrived since the last reload, reload the configuration file and call the
+    /// [`BackgroundWorker::on_reload`] callbacks.  Returns whether it did.
+    ///
+    /// Workers that don't wait on their latch should call this in their main loop.
+    pub fn reload_if_requested() -> bool {
+        if !RELOAD_PENDING.swap(false, Ordering::SeqCst) {
+            return false;
+        }
+        unsafe {
+            pg_sys::ProcessConfigFile(pg_sys::GucContext_PGC_SIGHUP);
+            if let Some(callbacks) = &mut *std::ptr::addr_of_mut!(RELOAD_CALLBACKS) {
+                for callback in callbacks.iter_mut() {
+                    callback();
+                }
+            }
+        }
+        true
+    }
+
+    /// Call `f` when the worker [shuts down](BackgroundWorker::shutdown).  Callbacks are called in
+    /// the reverse of the order they were registered, so what was set up last is torn down first.
+    pub fn on_shutdown<F: FnOnce() + 'static>(f: F) {
+        unsafe {
+            assert!(!pg_sys::MyBgworkerEntry.is_null(), "BackgroundWorker associated functions can only be called from a registered background worker");
+            let callbacks = &mut *std::ptr::addr_of_mut!(SHUTDOWN_CALLBACKS);
+            callbacks.get_or_insert_with(Vec::new).push(Box::new(f));
+        }
+    }
+
+    /// How long [`BackgroundWorker::shutdown`] gives the [`BackgroundWorker::on_shutdown`]
+    /// callbacks before the worker is terminated.  Defaults to 10 seconds.
+    pub fn set_shutdown_timeout(timeout: Duration) {
+        unsafe {
+            assert!(!pg_sys::MyBgworkerEntry.is_null(), "BackgroundWorker associated functions can only be called from a registered background worker");
+            SHUTDOWN_TIMEOUT = timeout;
+        }
+    }
+
+    /// Shut the worker down in an orderly way: call the [`BackgroundWorker::on_shutdown`]
+    /// callbacks, then return so the worker's main function can too.
+    ///
+    /// If the callbacks are still running once the [shutdown
+    /// timeout](BackgroundWorker::set_shutdown_timeout) passes, the worker is terminated with a
+    /// FATAL error at its next `check_for_interrupts!()`, which SPI queries and waiting on the
+    /// latch both do.  A callback that does neither can't be stopped.
+    ///
+    /// ```rust,no_run
+    /// use pgrx::bgworkers::*;
+    /// use pgrx::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[pg_guard]
+    /// #[no_mangle]
+    /// pub extern "C" fn my_worker(_arg: pg_sys::Datum) {
+    ///     BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+    ///     BackgroundWorker::connect_worker_to_spi(Some("postgres"), None);
+    ///     BackgroundWorker::set_shutdown_timeout(Duration::from_secs(5));
+    ///     BackgroundWorker::on_reload(|| log!("configuration reloaded"));
+    ///     BackgroundWorker::on_shutdown(|| {
+    ///         BackgroundWorker::transaction(|| Spi::run("UPDATE my_worker_state SET running = false"))
+    ///             .unwrap()
+    ///     });
+    ///
+    ///     while BackgroundWorker::wait_latch(Some(Duration::from_secs(1))) {
+    ///         // ... do some work ...
+    ///     }
+    ///     BackgroundWorker::shutdown();
+    /// }
+    /// ```
+    pub fn shutdown() {
+        unsafe {
+            assert!(!pg_sys::MyBgworkerEntry.is_null(), "BackgroundWorker associated functions can only be called from a registered background worker");
+        }
+        let callbacks =
+            unsafe { (*std::ptr::addr_of_mut!(SHUTDOWN_CALLBACKS)).take() }.unwrap_or_default();
+        if callbacks.is_empty() {
+            return;
+        }
+
+        let id = *SHUTDOWN_TIMEOUT_ID.get_or_init(|| unsafe {
+            pg_sys::RegisterTimeout(pg_sys::TimeoutId_USER_TIMEOUT, Some(shutdown_timed_out))
+        });
+        unsafe {
+            let delay_ms = SHUTDOWN_TIMEOUT.as_millis().clamp(1, i32::MAX as u128) as i32;
+            pg_sys::enable_timeout_after(id, delay_ms);
+        }
+        for callback in callbacks.into_iter().rev() {
+            callback();
+        }
+        unsafe {
+            pg_sys::disable_timeout(id, false);
+        }
+    }
+
+    /// Run `f` every time `schedule` fires, in the server's `TimeZone`, sleeping on the
+    /// background worker's latch in between, until the worker gets a SIGTERM.  Returns if the
+    /// schedule never fires again.
+    ///
+    /// Runs missed while `f` was still running are skipped, not made up.  `f` runs outside of
+    /// a transaction: use [`BackgroundWorker::transaction`] inside it to use SPI.
+    ///
+    /// ```rust,no_run
+    /// use pgrx::bgworkers::*;
+    /// use pgrx::prelude::*;
+    /// use pgrx::schedule::Schedule;
+    ///
+    /// #[pg_guard]
+    /// #[no_mangle]
+    /// pub extern "C" fn nightly_worker(_arg: pg_sys::Datum) {
+    ///     BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+    ///     BackgroundWorker::connect_worker_to_spi(Some("postgres"), None);
+    ///     let schedule: Schedule = "0 2 * * *".parse().unwrap();
+    ///     BackgroundWorker::run_on_schedule(&schedule, || {
+    ///         BackgroundWorker::transaction(|| Spi::run("DELETE FROM sessions WHERE expires < now()").unwrap());
+    ///     });
+    /// }
+    /// ```
+    pub fn run_on_schedule<F: FnMut()>(schedule: &Schedule, mut f: F) {
+        let mut after = crate::clock_timestamp();
+        loop {
+            let Some(next) = schedule.next_after(after) else { return };
+            loop {
+                let now = pg_sys::TimestampTz::from(crate::clock_timestamp());
+                let left = pg_sys::TimestampTz::from(next) - now;
+                if left <= 0 {
+                    break;
+                }
+                // round up, so we don't wake just before it's time
+                let timeout = Duration::from_millis((left as u64 + 999) / 1000);
+                if !BackgroundWorker::wait_latch(Some(timeout)) {
+                    return;
+                }
+            }
+            f();
+            after = crate::clock_timestamp();
+        }
+    }
+
     /// Is this `BackgroundWorker` allowed to continue?
     pub fn worker_continue() -> bool {
         unsafe {
@@ -180,6 +348,13 @@ impl BackgroundWorker {
     /// use pgrx::bgworkers::{BackgroundWorker, SignalWakeFlags};
     /// BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
     /// ```
+    ///
+    /// The SIGHUP handler doesn't reload the configuration file itself, as that isn't safe to do
+    /// in a signal handler.  The reload happens the next time the worker calls
+    /// [`BackgroundWorker::wait_latch`], [`BackgroundWorker::sighup_received`], or
+    /// [`BackgroundWorker::reload_if_requested`].  A worker that waits with `pg_sys::WaitLatch`
+    /// directly, and used to see new settings without doing anything, must now call
+    /// `reload_if_requested()` after each wait.
     pub fn attach_signal_handlers(wake: SignalWakeFlags) {
         unsafe {
             assert!(!pg_sys::MyBgworkerEntry.is_null(), "BackgroundWorker associated functions can only be called from a registered background worker");
@@ -215,7 +390,8 @@ impl BackgroundWorker {
 
 unsafe extern "C" fn worker_spi_sighup(_signal_args: i32) {
     GOT_SIGHUP.store(true, Ordering::SeqCst);
-    pg_sys::ProcessConfigFile(pg_sys::GucContext_PGC_SIGHUP);
+    // reloading isn't safe in a signal handler, so `BackgroundWorker::reload_if_requested()` does it
+    RELOAD_PENDING.store(true, Ordering::SeqCst);
     pg_sys::SetLatch(pg_sys::MyLatch);
 }
 
@@ -224,6 +400,14 @@ unsafe extern "C" fn worker_spi_sigterm(_signal_args: i32) {
     pg_sys::SetLatch(pg_sys::MyLatch);
 }
 
+/// Runs in Postgres' `SIGALRM` handler once `BackgroundWorker::shutdown()` has taken too long.
+/// This is what Postgres' own SIGTERM handler does, so the next `CHECK_FOR_INTERRUPTS()` exits.
+unsafe extern "C" fn shutdown_timed_out() {
+    pg_sys::ProcDiePending = true.into();
+    pg_sys::InterruptPending = true.into();
+    pg_sys::SetLatch(pg_sys::MyLatch);
+}
+
 /// Dynamic background worker handle
 pub struct DynamicBackgroundWorker {
     handle: *mut pg_sys::BackgroundWorkerHandle,
@@ -535,6 +719,7 @@ impl BackgroundWorkerBuilder {
     /// Once properly configured, call `load()` to get the BackgroundWorker registered and
     /// started at the proper time by Postgres.
     pub fn load(self: Self) {
+        crate::init::check_init_path("loading a static background worker");
         let mut bgw: pg_sys::BackgroundWorker = (&self).into();
 
         unsafe {
@@ -559,6 +744,16 @@ impl BackgroundWorkerBuilder {
 
         DynamicBackgroundWorker { handle, notify_pid: bgw.bgw_notify_pid }
     }
+
+    /// Like `load_dynamic()`, but returns `None` if there's no free background worker slot
+    /// (see `max_worker_processes`) instead of a handle that can't be used.
+    pub fn try_load_dynamic(self: Self) -> Option<DynamicBackgroundWorker> {
+        let mut bgw: pg_sys::BackgroundWorker = (&self).into();
+        let mut handle: *mut pg_sys::BackgroundWorkerHandle = null_mut();
+
+        unsafe { pg_sys::RegisterDynamicBackgroundWorker(&mut bgw, &mut handle) }
+            .then(|| DynamicBackgroundWorker { handle, notify_pid: bgw.bgw_notify_pid })
+    }
 }
 
 /// This conversion is useful only in limited context outside of pgrx, such as when this structure is required
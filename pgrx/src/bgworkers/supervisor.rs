@@ -0,0 +1,264 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Running one dynamic background worker per database, from a launcher worker.
+use super::{
+    BackgroundWorker, BackgroundWorkerBuilder, BackgroundWorkerStatus, DynamicBackgroundWorker,
+};
+use crate::guc::GucSetting;
+use crate::spi::{self, Spi};
+use crate::{log, pg_sys, warning};
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+
+/// Which databases a [`DatabaseSupervisor`] runs a worker in
+#[derive(Clone, Copy)]
+pub enum SupervisedDatabases {
+    /// Every database that allows connections, except templates
+    All,
+    /// The databases named in a comma-separated string GUC, which is re-read when the
+    /// configuration is reloaded
+    Guc(&'static GucSetting<Option<&'static CStr>>),
+}
+
+/// Keeps one dynamic background worker running in each of a set of databases.
+///
+/// This is the launcher half of what every extension that does background work in more than one
+/// database needs: a static background worker, registered in `_PG_init()`, that starts a worker
+/// for each database, notices databases coming and going, and restarts workers that exit, waiting
+/// longer after each restart so one that keeps failing doesn't spin.
+///
+/// Each worker gets the name of its database as its
+/// [`extra`](BackgroundWorkerBuilder::set_extra) data:
+///
+/// ```rust,no_run
+/// use pgrx::bgworkers::*;
+/// use pgrx::prelude::*;
+/// use std::time::Duration;
+///
+/// #[pg_guard]
+/// pub extern "C" fn _PG_init() {
+///     BackgroundWorkerBuilder::new("my_extension launcher")
+///         .set_library("my_extension")
+///         .set_function("launcher_main")
+///         .enable_spi_access()
+///         .load();
+/// }
+///
+/// #[pg_guard]
+/// #[no_mangle]
+/// pub extern "C" fn launcher_main(_arg: pg_sys::Datum) {
+///     BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+///     BackgroundWorker::connect_worker_to_spi(Some("postgres"), None);
+///     DatabaseSupervisor::new("my_extension worker", "my_extension", "worker_main").run().unwrap();
+/// }
+///
+/// #[pg_guard]
+/// #[no_mangle]
+/// pub extern "C" fn worker_main(_arg: pg_sys::Datum) {
+///     BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+///     BackgroundWorker::connect_worker_to_spi(Some(BackgroundWorker::get_extra()), None);
+///     while BackgroundWorker::wait_latch(Some(Duration::from_secs(10))) {
+///         // ... do this database's work ...
+///     }
+/// }
+/// ```
+///
+/// Workers a supervisor starts are never restarted by the postmaster: the supervisor restarts
+/// them itself, and stops them all when it gets a SIGTERM.
+pub struct DatabaseSupervisor {
+    worker_type: String,
+    library: String,
+    function: String,
+    databases: SupervisedDatabases,
+    poll_interval: Duration,
+    restart_delay: Duration,
+    max_restart_delay: Duration,
+}
+
+/// A database's worker, and when it may be started again
+struct Supervised {
+    worker: Option<DynamicBackgroundWorker>,
+    started_at: Instant,
+    next_start: Instant,
+    delay: Duration,
+    restarts: u32,
+}
+
+impl DatabaseSupervisor {
+    /// Supervise workers of type `worker_type` that run `function` from `library`, in
+    /// [every database](SupervisedDatabases::All)
+    pub fn new(worker_type: &str, library: &str, function: &str) -> Self {
+        DatabaseSupervisor {
+            worker_type: worker_type.to_string(),
+            library: library.to_string(),
+            function: function.to_string(),
+            databases: SupervisedDatabases::All,
+            poll_interval: Duration::from_secs(10),
+            restart_delay: Duration::from_secs(1),
+            max_restart_delay: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// Which databases to run workers in
+    pub fn databases(mut self, databases: SupervisedDatabases) -> Self {
+        self.databases = databases;
+        self
+    }
+
+    /// How often to look for databases that were created or dropped.  Defaults to 10 seconds.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Wait this long to restart a worker that exited, and twice as long each time it exits
+    /// again, up to [`max_restart_delay()`](DatabaseSupervisor::max_restart_delay).  Defaults to
+    /// 1 second.
+    pub fn restart_delay(mut self, restart_delay: Duration) -> Self {
+        self.restart_delay = restart_delay;
+        self
+    }
+
+    /// The longest to wait to restart a worker.  A worker that ran at least this long before
+    /// exiting is restarted after [`restart_delay()`](DatabaseSupervisor::restart_delay) again.
+    /// Defaults to 5 minutes.
+    pub fn max_restart_delay(mut self, max_restart_delay: Duration) -> Self {
+        self.max_restart_delay = max_restart_delay;
+        self
+    }
+
+    /// The names of the databases that should have a worker
+    fn wanted_databases(&self) -> Result<Vec<String>, spi::Error> {
+        match self.databases {
+            SupervisedDatabases::All => BackgroundWorker::transaction(AssertUnwindSafe(|| {
+                Spi::connect(|client| {
+                    client
+                        .select(
+                            "SELECT datname::text FROM pg_database \
+                            WHERE datallowconn AND NOT datistemplate ORDER BY datname",
+                            None,
+                            None,
+                        )?
+                        .map(|row| row.get::<String>(1).map(|name| name.unwrap_or_default()))
+                        .collect()
+                })
+            })),
+            SupervisedDatabases::Guc(guc) => Ok(guc
+                .get()
+                .map(|list| {
+                    list.to_string_lossy()
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()),
+        }
+    }
+
+    fn start(&self, database: &str) -> Option<DynamicBackgroundWorker> {
+        BackgroundWorkerBuilder::new(&format!("{} for {}", self.worker_type, database))
+            .set_type(&self.worker_type)
+            .set_library(&self.library)
+            .set_function(&self.function)
+            .set_extra(database)
+            .enable_spi_access()
+            .set_restart_time(None)
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .try_load_dynamic()
+    }
+
+    /// The launcher's main loop: start and restart workers until the launcher gets a SIGTERM,
+    /// then stop them all and wait for them to exit.
+    ///
+    /// The launcher must have [connected to SPI](BackgroundWorker::connect_worker_to_spi), to
+    /// list the databases, and attached the SIGTERM handler.
+    pub fn run(&self) -> Result<(), spi::Error> {
+        let mut supervised = BTreeMap::<String, Supervised>::new();
+        loop {
+            let wanted = self.wanted_databases()?;
+            supervised.retain(|database, entry| {
+                let keep = wanted.contains(database);
+                if let (false, Some(worker)) = (keep, entry.worker.take()) {
+                    log!("stopping {} for dropped database {}", self.worker_type, database);
+                    worker.terminate();
+                }
+                keep
+            });
+
+            let now = Instant::now();
+            let mut wake_at = now + self.poll_interval;
+            for database in wanted {
+                let entry = supervised.entry(database.clone()).or_insert_with(|| Supervised {
+                    worker: None,
+                    started_at: now,
+                    next_start: now,
+                    delay: self.restart_delay,
+                    restarts: 0,
+                });
+
+                if let Some(worker) = &entry.worker {
+                    match worker.pid() {
+                        Ok(_) | Err(BackgroundWorkerStatus::NotYetStarted) => continue,
+                        Err(BackgroundWorkerStatus::PostmasterDied) => return Ok(()),
+                        Err(_) => {
+                            // it exited: back off, unless it had been running for a while
+                            if now.duration_since(entry.started_at) >= self.max_restart_delay {
+                                entry.delay = self.restart_delay;
+                            }
+                            entry.worker = None;
+                            entry.next_start = now + entry.delay;
+                            entry.delay = (entry.delay * 2).min(self.max_restart_delay);
+                            entry.restarts += 1;
+                            log!(
+                                "{} for {} exited, restarting it in {:?} (restart {})",
+                                self.worker_type,
+                                database,
+                                entry.next_start - now,
+                                entry.restarts
+                            );
+                        }
+                    }
+                }
+
+                if entry.next_start <= now {
+                    entry.started_at = now;
+                    entry.worker = self.start(&database);
+                    if entry.worker.is_none() {
+                        warning!(
+                            "no background worker slot for {} for {}, see max_worker_processes",
+                            self.worker_type,
+                            database
+                        );
+                        entry.next_start = now + entry.delay;
+                    }
+                }
+                if entry.worker.is_none() {
+                    wake_at = wake_at.min(entry.next_start);
+                }
+            }
+
+            // a worker exiting sets our latch too, as we're its notify_pid
+            if !BackgroundWorker::wait_latch(Some(wake_at.saturating_duration_since(now))) {
+                let stopping = supervised
+                    .into_values()
+                    .filter_map(|entry| entry.worker.map(DynamicBackgroundWorker::terminate))
+                    .collect::<Vec<_>>();
+                for worker in stopping {
+                    worker.wait_for_shutdown().ok();
+                }
+                return Ok(());
+            }
+        }
+    }
+}
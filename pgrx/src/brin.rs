@@ -0,0 +1,268 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! BRIN operator classes implemented in Rust
+//!
+//! Implement [`BrinOpClass`] for a type created by the extension and annotate the `impl` with
+//! [`#[pg_opclass]`](macro@crate::pg_opclass) to make it the type's default BRIN operator class.
+//! Each block range of the table is summarized by a [`BrinSummary`], a fixed number of stored
+//! values, which the operator class widens as values are added and checks against the scan keys
+//! of a search.  `NULL`s, and searches for `IS NULL` or `IS NOT NULL`, are handled by pgrx.
+//!
+//! A "minmax" operator class, which is what BRIN's built-in operator classes do for ordered
+//! types, looks like:
+//!
+//! ```rust,ignore
+//! use pgrx::brin::{BrinOpClass, BrinSummary};
+//! use pgrx::prelude::*;
+//!
+//! #[pg_opclass]
+//! impl BrinOpClass for Reading {
+//!     type Value = Reading;
+//!     const OPERATORS: &'static [(u16, &'static str)] =
+//!         &[(1, "<"), (2, "<="), (3, "="), (4, ">="), (5, ">")];
+//!
+//!     fn stored_types(column_type: pg_sys::Oid) -> Vec<pg_sys::Oid> {
+//!         vec![column_type, column_type]
+//!     }
+//!
+//!     fn add_value(summary: &mut BrinSummary, value: Reading) -> bool {
+//!         let mut changed = false;
+//!         if summary.is_empty() || value < summary.get::<Reading>(0).unwrap() {
+//!             summary.set(0, value.clone());
+//!             changed = true;
+//!         }
+//!         if summary.is_empty() || value > summary.get::<Reading>(1).unwrap() {
+//!             summary.set(1, value);
+//!             changed = true;
+//!         }
+//!         changed
+//!     }
+//!     // ...
+//! }
+//! ```
+use crate::datum::Internal;
+use crate::{pg_sys, FromDatum, IntoDatum, PgTupleDesc};
+use std::marker::PhantomData;
+
+/// The support functions of a BRIN operator class
+pub trait BrinOpClass {
+    /// The Rust type of the indexed column
+    type Value: FromDatum;
+
+    /// The operators the operator class supports, as `(strategy number, operator)` pairs.  The
+    /// operator may be followed by its operand types, as in `"< (reading, int8)"`, which otherwise
+    /// default to the indexed type
+    const OPERATORS: &'static [(u16, &'static str)];
+
+    /// The types of the values kept in each block range's [`BrinSummary`], given the type of the
+    /// indexed column
+    fn stored_types(column_type: pg_sys::Oid) -> Vec<pg_sys::Oid>;
+
+    /// Widen `summary` to include `value`, and return whether it changed.  If the summary
+    /// [is empty](BrinSummary::is_empty), every stored value must be set
+    fn add_value(summary: &mut BrinSummary, value: Self::Value) -> bool;
+
+    /// Might the block range described by `summary` contain values satisfying `key`?  The
+    /// operator is given by `key.sk_strategy`, and its argument by `key.sk_argument`
+    fn consistent(summary: &BrinSummary, key: &pg_sys::ScanKeyData) -> bool;
+
+    /// Widen `summary` to also describe the block range summarized by `other`.  Neither is empty
+    fn union(summary: &mut BrinSummary, other: &BrinSummary);
+}
+
+/// The summary of one column over a block range: the values stored by a [`BrinOpClass`], and
+/// whether the range contains `NULL`s
+pub struct BrinSummary<'a> {
+    bdesc: *mut pg_sys::BrinDesc,
+    column: *mut pg_sys::BrinValues,
+    _marker: PhantomData<&'a mut pg_sys::BrinValues>,
+}
+
+impl<'a> BrinSummary<'a> {
+    /// ## Safety
+    ///
+    /// `bdesc` and `column` must be the valid pointers Postgres gave a BRIN support function
+    unsafe fn from_pg(bdesc: *mut pg_sys::BrinDesc, column: *mut pg_sys::BrinValues) -> Self {
+        BrinSummary { bdesc, column, _marker: PhantomData }
+    }
+
+    /// Has no non-`NULL` value been added to the block range yet?  If so, the stored values are
+    /// uninitialized
+    pub fn is_empty(&self) -> bool {
+        unsafe { (*self.column).bv_allnulls }
+    }
+
+    /// Does the block range contain any `NULL`s?
+    pub fn has_nulls(&self) -> bool {
+        unsafe { (*self.column).bv_hasnulls }
+    }
+
+    /// The number of values stored in the summary, as given by [`BrinOpClass::stored_types`]
+    pub fn num_stored(&self) -> usize {
+        self.typcache().len()
+    }
+
+    /// The stored value at `index`, as a `Datum`
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is not less than [`BrinSummary::num_stored`]
+    pub fn datum(&self, index: usize) -> pg_sys::Datum {
+        assert!(index < self.num_stored(), "BRIN summary index {index} out of bounds");
+        unsafe { *(*self.column).bv_values.add(index) }
+    }
+
+    /// The stored value at `index`, or `None` if the summary [is empty](BrinSummary::is_empty)
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is not less than [`BrinSummary::num_stored`]
+    pub fn get<T: FromDatum>(&self, index: usize) -> Option<T> {
+        let datum = self.datum(index);
+        if self.is_empty() {
+            return None;
+        }
+        unsafe { T::from_polymorphic_datum(datum, false, (*self.typcache()[index]).type_id) }
+    }
+
+    /// Replace the stored value at `index`
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is not less than [`BrinSummary::num_stored`], or `value` is `NULL`
+    pub fn set<T: IntoDatum>(&mut self, index: usize, value: T) {
+        let datum = value.into_datum().expect("a BRIN summary cannot store NULL");
+        self.set_datum(index, datum);
+    }
+
+    /// Replace the stored value at `index` with a copy of `datum`
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is not less than [`BrinSummary::num_stored`]
+    pub fn set_datum(&mut self, index: usize, datum: pg_sys::Datum) {
+        assert!(index < self.num_stored(), "BRIN summary index {index} out of bounds");
+        unsafe {
+            let typcache = &*self.typcache()[index];
+            *(*self.column).bv_values.add(index) =
+                pg_sys::datumCopy(datum, typcache.typbyval, typcache.typlen as _);
+        }
+    }
+
+    fn typcache(&self) -> &[*mut pg_sys::TypeCacheEntry] {
+        unsafe {
+            let attno = (*self.column).bv_attno as usize;
+            let opcinfo = &**(*self.bdesc).bd_info.as_slice(attno).last().unwrap();
+            opcinfo.oi_typcache.as_slice(opcinfo.oi_nstored as usize)
+        }
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn opcinfo<T: BrinOpClass>(column_type: Internal) -> Internal {
+    // the argument is really an `Oid`, not a pointer
+    let column_type = pg_sys::Oid::from_u32_unchecked(
+        column_type.unwrap().map_or(0, |datum| datum.value() as u32),
+    );
+    let stored_types = T::stored_types(column_type);
+
+    let size = std::mem::size_of::<pg_sys::BrinOpcInfo>()
+        + std::mem::size_of::<*mut pg_sys::TypeCacheEntry>() * stored_types.len();
+    let info = pg_sys::palloc0(size).cast::<pg_sys::BrinOpcInfo>();
+    (*info).oi_nstored = stored_types.len() as _;
+    let typcache = (*info).oi_typcache.as_mut_slice(stored_types.len());
+    for (entry, typoid) in typcache.iter_mut().zip(stored_types) {
+        *entry = pg_sys::lookup_type_cache(typoid, 0);
+    }
+    Internal::from(Some(pg_sys::Datum::from(info)))
+}
+
+#[doc(hidden)]
+pub unsafe fn add_value<T: BrinOpClass>(
+    bdesc: Internal,
+    column: Internal,
+    value: Internal,
+    is_null: Internal,
+) -> bool {
+    let bdesc = bdesc.get_mut::<pg_sys::BrinDesc>().expect("no BrinDesc");
+    let column = column.get_mut::<pg_sys::BrinValues>().expect("no BrinValues");
+    // these arguments are really a `Datum` of the column's type and a `bool`
+    let value = value.unwrap().unwrap_or(pg_sys::Datum::from(0));
+    let is_null = is_null.unwrap().map_or(false, |datum| datum.value() != 0);
+
+    if is_null {
+        let changed = !column.bv_hasnulls;
+        column.bv_hasnulls = true;
+        return changed;
+    }
+
+    let column_type = PgTupleDesc::from_pg_unchecked(bdesc.bd_tupdesc)
+        .get(column.bv_attno as usize - 1)
+        .map_or(pg_sys::InvalidOid, |att| att.atttypid);
+    let value = T::Value::from_polymorphic_datum(value, false, column_type)
+        .expect("BRIN value should not be NULL");
+
+    let mut summary = BrinSummary::from_pg(bdesc, column);
+    let changed = T::add_value(&mut summary, value);
+    if changed {
+        column.bv_allnulls = false;
+    }
+    changed
+}
+
+#[doc(hidden)]
+pub unsafe fn consistent<T: BrinOpClass>(bdesc: Internal, column: Internal, key: Internal) -> bool {
+    let bdesc = bdesc.get_mut::<pg_sys::BrinDesc>().expect("no BrinDesc");
+    let column = column.get_mut::<pg_sys::BrinValues>().expect("no BrinValues");
+    let key = key.get::<pg_sys::ScanKeyData>().expect("no ScanKeyData");
+
+    if key.sk_flags & pg_sys::SK_ISNULL as i32 != 0 {
+        return if key.sk_flags & pg_sys::SK_SEARCHNULL as i32 != 0 {
+            column.bv_allnulls || column.bv_hasnulls
+        } else if key.sk_flags & pg_sys::SK_SEARCHNOTNULL as i32 != 0 {
+            !column.bv_allnulls
+        } else {
+            // a comparison with NULL is never true
+            false
+        };
+    }
+    if column.bv_allnulls {
+        return false;
+    }
+
+    T::consistent(&BrinSummary::from_pg(bdesc, column), key)
+}
+
+#[doc(hidden)]
+pub unsafe fn union<T: BrinOpClass>(bdesc: Internal, a: Internal, b: Internal) -> bool {
+    let bdesc = bdesc.get_mut::<pg_sys::BrinDesc>().expect("no BrinDesc");
+    let a = a.get_mut::<pg_sys::BrinValues>().expect("no BrinValues");
+    let b = b.get_mut::<pg_sys::BrinValues>().expect("no BrinValues");
+
+    if b.bv_hasnulls {
+        a.bv_hasnulls = true;
+    }
+    if b.bv_allnulls {
+        return true;
+    }
+
+    let mut summary = BrinSummary::from_pg(bdesc, a);
+    let other = BrinSummary::from_pg(bdesc, b);
+    if summary.is_empty() {
+        // there's nothing in `a` to merge with, so it becomes a copy of `b`
+        for i in 0..other.num_stored() {
+            summary.set_datum(i, other.datum(i));
+        }
+        a.bv_allnulls = false;
+    } else {
+        T::union(&mut summary, &other);
+    }
+    true
+}
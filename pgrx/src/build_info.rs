@@ -0,0 +1,75 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Information about how an extension was built, embedded into its shared library
+//!
+//! [`build_info!`] collects it, and [`pg_build_info!`] also creates SQL functions that return it,
+//! so what's installed can be checked from SQL.  Some of it comes from the environment
+//! `cargo pgrx` builds extensions in, so it's missing when they're built some other way.
+//!
+//! [`build_info!`]: crate::build_info!
+//! [`pg_build_info!`]: crate::pg_build_info!
+/// The version of `pgrx` the extension was built with
+pub const PGRX_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// The name of the extension's crate
+    pub name: &'static str,
+    /// The version of the extension's crate
+    pub version: &'static str,
+    pub pgrx_version: &'static str,
+    /// `PG_VERSION_NUM` of the Postgres the extension was built for, like `150003`
+    pub pg_version_num: u32,
+    /// What `rustc --version` said, when built by `cargo pgrx`
+    pub rustc_version: Option<&'static str>,
+    /// The git commit the extension was built from, when built by `cargo pgrx` in a git checkout
+    pub git_hash: Option<&'static str>,
+    /// The features `cargo pgrx` asked for, separated by spaces.  `default` is among them unless
+    /// the build had `--no-default-features`, and `--all-features` is just `all`.
+    pub features: Option<&'static str>,
+    /// The cargo profile the extension was built with, when built by `cargo pgrx`
+    pub profile: Option<&'static str>,
+}
+
+impl BuildInfo {
+    pub fn features(&self) -> impl Iterator<Item = &'static str> {
+        self.features.unwrap_or_default().split_ascii_whitespace()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name,
+            "version": self.version,
+            "pgrx_version": self.pgrx_version,
+            "pg_version_num": self.pg_version_num,
+            "rustc_version": self.rustc_version,
+            "git_hash": self.git_hash,
+            "features": self.features.map(|_| self.features().collect::<Vec<_>>()),
+            "profile": self.profile,
+        })
+    }
+}
+
+/// The [`BuildInfo`] of the crate this is used in
+#[macro_export]
+macro_rules! build_info {
+    () => {
+        $crate::build_info::BuildInfo {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            pgrx_version: $crate::build_info::PGRX_VERSION,
+            pg_version_num: $crate::pg_sys::PG_VERSION_NUM,
+            rustc_version: option_env!("PGRX_BUILD_RUSTC_VERSION"),
+            git_hash: option_env!("PGRX_BUILD_GIT_HASH"),
+            features: option_env!("PGRX_BUILD_FEATURES"),
+            profile: option_env!("PGRX_BUILD_PROFILE"),
+        }
+    };
+}
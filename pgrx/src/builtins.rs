@@ -0,0 +1,201 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Typed wrappers for calling common Postgres builtin functions directly
+//!
+//! Reusing a builtin from Rust means calling it through the "V1" calling convention, with the right
+//! argument types, the right collation, and, for polymorphic functions such as `array_append`, an
+//! expression that tells it what those types are.  These functions take care of all that.
+//!
+//! They're called with the database's default collation, as if by an expression on values without
+//! a more specific one, and raise the same ERRORs they would from SQL.
+//!
+//! ```rust,no_run
+//! use pgrx::builtins;
+//!
+//! assert_eq!(builtins::textcat("pg", "rx"), "pgrx");
+//! assert_eq!(builtins::array_append(vec![Some(1), Some(2)], Some(3)), vec![Some(1), Some(2), Some(3)]);
+//! ```
+use crate::datum::{AnyNumeric, Date, Interval, Timestamp, TimestampWithTimeZone};
+use crate::fcinfo::{direct_function_call_as_datum_internal, FunctionArgument};
+use crate::list::PgList;
+use crate::{pg_sys, FromDatum, IntoDatum, PgMemoryContexts};
+
+/// Call `func` with `args`, describing their types in an `fn_expr` so that polymorphic functions
+/// can find them, and convert its result, which these builtins never make `NULL` for non-`NULL`
+/// arguments
+fn call<R: FromDatum>(
+    func: unsafe fn(pg_sys::FunctionCallInfo) -> pg_sys::Datum,
+    args: &[FunctionArgument],
+) -> R {
+    unsafe {
+        let mut exprargs = PgList::<pg_sys::Const>::new();
+        for arg in args {
+            exprargs.push(pg_sys::makeNullConst(arg.type_oid, -1, pg_sys::InvalidOid));
+        }
+
+        let mut flinfo = pg_sys::FmgrInfo::default();
+        flinfo.fn_oid = pg_sys::InvalidOid;
+        flinfo.fn_nargs = args.len() as _;
+        flinfo.fn_mcxt = PgMemoryContexts::CurrentMemoryContext.value();
+        flinfo.fn_expr = pg_sys::makeFuncExpr(
+            pg_sys::InvalidOid,
+            pg_sys::InvalidOid,
+            exprargs.into_pg(),
+            pg_sys::InvalidOid,
+            pg_sys::DEFAULT_COLLATION_OID,
+            pg_sys::CoercionForm_COERCE_EXPLICIT_CALL,
+        )
+        .cast();
+
+        let datums = args.iter().map(|arg| arg.datum).collect::<Vec<_>>();
+        direct_function_call_as_datum_internal(
+            |fcinfo| {
+                (*fcinfo).flinfo = &mut flinfo;
+                (*fcinfo).fncollation = pg_sys::DEFAULT_COLLATION_OID;
+                func(fcinfo)
+            },
+            &datums,
+        )
+        .and_then(|datum| R::from_datum(datum, false))
+        .expect("builtin function returned NULL")
+    }
+}
+
+/// `a || b`
+pub fn textcat(a: &str, b: &str) -> String {
+    call(pg_sys::textcat, &[a.into(), b.into()])
+}
+
+/// `upper(s)`, according to the default collation
+pub fn upper(s: &str) -> String {
+    call(pg_sys::upper, &[s.into()])
+}
+
+/// `lower(s)`, according to the default collation
+pub fn lower(s: &str) -> String {
+    call(pg_sys::lower, &[s.into()])
+}
+
+/// `initcap(s)`, according to the default collation
+pub fn initcap(s: &str) -> String {
+    call(pg_sys::initcap, &[s.into()])
+}
+
+/// `btrim(s, characters)`
+pub fn btrim(s: &str, characters: &str) -> String {
+    call(pg_sys::btrim, &[s.into(), characters.into()])
+}
+
+/// `replace(s, from, to)`
+pub fn replace_text(s: &str, from: &str, to: &str) -> String {
+    call(pg_sys::replace_text, &[s.into(), from.into(), to.into()])
+}
+
+/// `left(s, n)`
+pub fn text_left(s: &str, n: i32) -> String {
+    call(pg_sys::text_left, &[s.into(), n.into()])
+}
+
+/// `right(s, n)`
+pub fn text_right(s: &str, n: i32) -> String {
+    call(pg_sys::text_right, &[s.into(), n.into()])
+}
+
+/// `reverse(s)`
+pub fn text_reverse(s: &str) -> String {
+    call(pg_sys::text_reverse, &[s.into()])
+}
+
+/// `md5(s)`, as a hex string
+pub fn md5_text(s: &str) -> String {
+    call(pg_sys::md5_text, &[s.into()])
+}
+
+/// `a + b`
+pub fn numeric_add(a: AnyNumeric, b: AnyNumeric) -> AnyNumeric {
+    call(pg_sys::numeric_add, &[a.into(), b.into()])
+}
+
+/// `a - b`
+pub fn numeric_sub(a: AnyNumeric, b: AnyNumeric) -> AnyNumeric {
+    call(pg_sys::numeric_sub, &[a.into(), b.into()])
+}
+
+/// `a * b`
+pub fn numeric_mul(a: AnyNumeric, b: AnyNumeric) -> AnyNumeric {
+    call(pg_sys::numeric_mul, &[a.into(), b.into()])
+}
+
+/// `a / b`, which raises an ERROR for division by zero
+pub fn numeric_div(a: AnyNumeric, b: AnyNumeric) -> AnyNumeric {
+    call(pg_sys::numeric_div, &[a.into(), b.into()])
+}
+
+/// `round(a, scale)`
+pub fn numeric_round(a: AnyNumeric, scale: i32) -> AnyNumeric {
+    call(pg_sys::numeric_round, &[a.into(), scale.into()])
+}
+
+/// `ts + interval`, in the session's time zone
+pub fn timestamptz_pl_interval(
+    ts: TimestampWithTimeZone,
+    interval: Interval,
+) -> TimestampWithTimeZone {
+    call(pg_sys::timestamptz_pl_interval, &[ts.into(), interval.into()])
+}
+
+/// `ts - interval`, in the session's time zone
+pub fn timestamptz_mi_interval(
+    ts: TimestampWithTimeZone,
+    interval: Interval,
+) -> TimestampWithTimeZone {
+    call(pg_sys::timestamptz_mi_interval, &[ts.into(), interval.into()])
+}
+
+/// `ts + interval`
+pub fn timestamp_pl_interval(ts: Timestamp, interval: Interval) -> Timestamp {
+    call(pg_sys::timestamp_pl_interval, &[ts.into(), interval.into()])
+}
+
+/// `a + b`
+pub fn interval_pl(a: Interval, b: Interval) -> Interval {
+    call(pg_sys::interval_pl, &[a.into(), b.into()])
+}
+
+/// `a - b`
+pub fn interval_mi(a: Interval, b: Interval) -> Interval {
+    call(pg_sys::interval_mi, &[a.into(), b.into()])
+}
+
+/// `date + days`
+pub fn date_pli(date: Date, days: i32) -> Date {
+    call(pg_sys::date_pli, &[date.into(), days.into()])
+}
+
+/// `array || element`
+pub fn array_append<T: IntoDatum + FromDatum>(
+    array: Vec<Option<T>>,
+    element: Option<T>,
+) -> Vec<Option<T>> {
+    call(pg_sys::array_append, &[array.into(), element.into()])
+}
+
+/// `element || array`
+pub fn array_prepend<T: IntoDatum + FromDatum>(
+    element: Option<T>,
+    array: Vec<Option<T>>,
+) -> Vec<Option<T>> {
+    call(pg_sys::array_prepend, &[element.into(), array.into()])
+}
+
+/// `a || b`
+pub fn array_cat<T: IntoDatum + FromDatum>(a: Vec<Option<T>>, b: Vec<Option<T>>) -> Vec<Option<T>> {
+    call(pg_sys::array_cat, &[a.into(), b.into()])
+}
@@ -0,0 +1,351 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Streaming [`std::io`] adapters over Postgres `bytea` values.
+//!
+//! [`ByteaReader`] reads a `bytea` Datum without first copying it into a `Vec<u8>`.  If the value
+//! is stored out-of-line and uncompressed, it's fetched from TOAST a chunk at a time, so
+//! only a small window of a potentially very large value is ever resident in memory.
+//!
+//! [`ByteaWriter`] builds a `bytea` directly in a Postgres-allocated buffer, which is then returned
+//! to Postgres as-is.
+//!
+//! Both can be used directly as `#[pg_extern]` arguments and return types:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{ByteaReader, ByteaWriter};
+//!
+//! #[pg_extern]
+//! fn uppercase_bytes(mut input: ByteaReader) -> ByteaWriter {
+//!     let mut output = ByteaWriter::new();
+//!     let mut buf = [0u8; 8192];
+//!     loop {
+//!         let n = std::io::Read::read(&mut input, &mut buf).unwrap();
+//!         if n == 0 {
+//!             break;
+//!         }
+//!         buf[..n].make_ascii_uppercase();
+//!         std::io::Write::write_all(&mut output, &buf[..n]).unwrap();
+//!     }
+//!     output
+//! }
+//! ```
+use crate::{
+    pg_sys, set_varsize, varatt_is_1b_e, varlena_to_byte_slice, varsize_any_exhdr, vartag_external,
+    FromDatum, IntoDatum, StringInfo,
+};
+use pgrx_sql_entity_graph::metadata::{
+    ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
+};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+/// How many bytes to fetch from TOAST at a time when reading an external `bytea`.  This is a
+/// multiple of the usual TOAST chunk size so that each fetch lines up with whole chunks.
+const TOAST_FETCH_SIZE: usize = 32 * 1996;
+
+/// Postgres' `MaxAllocSize`, which is also the largest a varlena can be
+const MAX_ALLOC_SIZE: usize = 0x3fffffff;
+
+/// A [`Read`] + [`Seek`] implementation over a `bytea` Datum
+///
+/// Like any other Datum, the `bytea` being read belongs to Postgres, so a `ByteaReader` must not
+/// outlive the memory context that owns it -- typically the current function call.
+pub struct ByteaReader {
+    source: Source,
+    pos: usize,
+}
+
+enum Source {
+    /// The value was inline, compressed, or otherwise needed to be detoasted all at once
+    Detoasted(*const pg_sys::varlena),
+
+    /// The value lives, uncompressed, in a TOAST table and is fetched in slices
+    External { varlena: *mut pg_sys::varlena, len: usize, window: Window },
+}
+
+/// The most recently fetched slice of an external value
+struct Window {
+    start: usize,
+    varlena: *mut pg_sys::varlena,
+}
+
+impl Window {
+    fn bytes(&self) -> &[u8] {
+        if self.varlena.is_null() {
+            &[]
+        } else {
+            // SAFETY:  `fetch()` set this to a valid, detoasted varlena
+            unsafe { varlena_to_byte_slice(self.varlena) }
+        }
+    }
+}
+
+impl ByteaReader {
+    /// Create a reader over the specified `bytea` Datum
+    ///
+    /// ## Safety
+    ///
+    /// The caller must ensure the Datum is a valid, non-null `bytea` (or other varlena) that
+    /// lives at least as long as the returned `ByteaReader`
+    pub unsafe fn from_datum(datum: pg_sys::Datum) -> ByteaReader {
+        let varlena = datum.cast_mut_ptr::<pg_sys::varlena>();
+        let source = match external_uncompressed_len(varlena) {
+            Some(len) => Source::External {
+                varlena,
+                len,
+                window: Window { start: 0, varlena: std::ptr::null_mut() },
+            },
+            None => Source::Detoasted(pg_sys::pg_detoast_datum_packed(varlena)),
+        };
+
+        ByteaReader { source, pos: 0 }
+    }
+
+    /// The total length, in bytes, of the underlying `bytea`
+    pub fn len(&self) -> usize {
+        match &self.source {
+            Source::Detoasted(varlena) => unsafe { varsize_any_exhdr(*varlena) },
+            Source::External { len, .. } => *len,
+        }
+    }
+
+    /// Is the underlying `bytea` zero bytes long?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The current read position
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl Read for ByteaReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let pos = self.pos;
+        let available = match &mut self.source {
+            Source::Detoasted(varlena) => {
+                // SAFETY:  we detoasted this varlena ourselves in `from_datum()`
+                let bytes = unsafe { varlena_to_byte_slice(*varlena) };
+                bytes.get(pos..).unwrap_or_default()
+            }
+            Source::External { varlena, len, window } => {
+                if pos >= *len {
+                    &[]
+                } else {
+                    if pos < window.start || pos >= window.start + window.bytes().len() {
+                        window.fetch(*varlena, pos, TOAST_FETCH_SIZE.min(*len - pos));
+                    }
+                    &window.bytes()[pos - window.start..]
+                }
+            }
+        };
+
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl Seek for ByteaReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset as i64),
+            SeekFrom::End(offset) => (self.len() as i64).checked_add(offset),
+            SeekFrom::Current(offset) => (self.pos as i64).checked_add(offset),
+        };
+
+        match new_pos {
+            Some(new_pos) if new_pos >= 0 => {
+                self.pos = new_pos as usize;
+                Ok(self.pos as u64)
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+impl Window {
+    fn fetch(&mut self, varlena: *mut pg_sys::varlena, start: usize, count: usize) {
+        unsafe {
+            if !self.varlena.is_null() {
+                pg_sys::pfree(self.varlena.cast());
+            }
+
+            // SAFETY:  `start` and `count` are bounded by the value's length, which itself must
+            // fit in a varlena, so they can't overflow an i32
+            self.varlena = pg_sys::pg_detoast_datum_slice(varlena, start as i32, count as i32);
+            self.start = start;
+        }
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        if !self.varlena.is_null() {
+            unsafe {
+                // SAFETY:  we allocated this slice in `fetch()` and nobody else has a reference to it
+                pg_sys::pfree(self.varlena.cast());
+            }
+        }
+    }
+}
+
+/// If the varlena is an on-disk TOAST pointer to an uncompressed value, returns the length of that
+/// value.  Compressed values can't be sliced without decompressing from the beginning each time,
+/// so for those it's cheaper to just detoast the whole thing once.
+unsafe fn external_uncompressed_len(varlena: *const pg_sys::varlena) -> Option<usize> {
+    if !varatt_is_1b_e(varlena)
+        || vartag_external(varlena) as pg_sys::vartag_external
+            != pg_sys::vartag_external_VARTAG_ONDISK
+    {
+        return None;
+    }
+
+    // the TOAST pointer isn't necessarily aligned, so copy it out, like VARATT_EXTERNAL_GET_POINTER
+    let toast_pointer = (varlena as *const u8)
+        .add(pg_sys::VARHDRSZ_EXTERNAL())
+        .cast::<pg_sys::varatt_external>()
+        .read_unaligned();
+
+    #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+    let extsize = toast_pointer.va_extsize as usize;
+    #[cfg(any(feature = "pg14", feature = "pg15", feature = "pg16"))]
+    let extsize = (toast_pointer.va_extinfo & ((1 << 30) - 1)) as usize;
+
+    let len = toast_pointer.va_rawsize as usize - pg_sys::VARHDRSZ;
+    if extsize < len {
+        // it's compressed
+        None
+    } else {
+        Some(len)
+    }
+}
+
+impl FromDatum for ByteaReader {
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        _typoid: pg_sys::Oid,
+    ) -> Option<ByteaReader> {
+        if is_null || datum.is_null() {
+            None
+        } else {
+            Some(ByteaReader::from_datum(datum))
+        }
+    }
+}
+
+unsafe impl SqlTranslatable for ByteaReader {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("bytea"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("bytea")))
+    }
+}
+
+/// A [`Write`] implementation that builds a `bytea` directly in Postgres-allocated memory
+///
+/// The buffer is allocated in the `CurrentMemoryContext` and grows as needed, up to the 1GB
+/// varlena limit.  Converting it into a Datum, either directly or by returning it from a
+/// `#[pg_extern]` function, hands that buffer to Postgres without copying it.
+pub struct ByteaWriter {
+    buffer: StringInfo,
+}
+
+impl ByteaWriter {
+    /// Create a new, empty, writer
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new, empty, writer with space for at least `capacity` bytes
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut buffer = StringInfo::with_capacity(
+            (capacity + pg_sys::VARHDRSZ).try_into().expect("capacity is too large for a bytea"),
+        );
+        // reserve room for the varlena header, which we'll fill in when we're done
+        buffer.push_bytes(&[0; pg_sys::VARHDRSZ]);
+        ByteaWriter { buffer }
+    }
+
+    /// The number of bytes written so far
+    pub fn len(&self) -> usize {
+        self.buffer.len() - pg_sys::VARHDRSZ
+    }
+
+    /// Have no bytes been written yet?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bytes written so far
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer.as_bytes()[pg_sys::VARHDRSZ..]
+    }
+
+    /// Finish writing and return the `bytea` as a raw Postgres-allocated pointer
+    pub fn into_bytea(self) -> *mut pg_sys::bytea {
+        let len = self.buffer.len();
+        let varlena = self.buffer.into_char_ptr() as *mut pg_sys::varlena;
+        unsafe {
+            // SAFETY:  the buffer starts with the VARHDRSZ bytes we reserved in `with_capacity()`
+            set_varsize(varlena, len as i32);
+        }
+        varlena
+    }
+}
+
+impl Default for ByteaWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for ByteaWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.buffer.len() + buf.len() > MAX_ALLOC_SIZE {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::OutOfMemory,
+                "bytea would exceed the maximum varlena size",
+            ));
+        }
+        self.buffer.push_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl IntoDatum for ByteaWriter {
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        Some(self.into_bytea().into())
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        pg_sys::BYTEAOID
+    }
+}
+
+unsafe impl SqlTranslatable for ByteaWriter {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Err(ArgumentError::NotValidAsArgument("ByteaWriter"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("bytea")))
+    }
+}
@@ -0,0 +1,336 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Information about the client connected to the current backend, and a hook for deciding whether
+//! clients may connect at all
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//!
+//! if let Some(client) = pgrx::client_info() {
+//!     if client.ssl.is_none() && client.remote_addr.is_some() {
+//!         warning!("{} connected from {} without SSL", client.user_name.unwrap_or_default(), client.remote_host);
+//!     }
+//! }
+//! ```
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::list::PgList;
+use crate::pg_sys;
+use crate::pg_sys::panic::ErrorReport;
+use crate::{PgLogLevel, PgSqlErrorCode};
+use core::ffi::CStr;
+use pgrx_macros::pg_guard;
+use std::net::IpAddr;
+use std::os::raw::{c_char, c_int};
+
+/// The SSL state of a connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SslInfo {
+    /// Such as "TLSv1.3"
+    pub version: String,
+    pub cipher: String,
+    pub bits: i32,
+    /// The distinguished name of the client certificate, if one was given
+    pub client_dn: Option<String>,
+}
+
+/// The GSSAPI state of a connection (Postgres 12 and later)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GssInfo {
+    /// The principal the client authenticated as, if it used GSSAPI authentication
+    pub principal: Option<String>,
+    /// Is the connection encrypted with GSSAPI?
+    pub encrypted: bool,
+}
+
+/// The client connected to a backend, as described by the backend's `Port`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// The client's IP address as text, or `"[local]"` for Unix-domain sockets
+    pub remote_host: String,
+    /// The client's IP address, if it connected over TCP
+    pub remote_addr: Option<IpAddr>,
+    /// The client's port, if it connected over TCP
+    pub remote_port: Option<u16>,
+    /// The client's host name, if `log_hostname` resolved it
+    pub remote_hostname: Option<String>,
+    /// The database named in the startup packet
+    pub database_name: Option<String>,
+    /// The user named in the startup packet
+    pub user_name: Option<String>,
+    /// The frontend/backend protocol version, as `(major, minor)`
+    pub protocol_version: (u16, u16),
+    /// The run-time parameters set in the startup packet, such as `application_name`, in the order
+    /// they were given
+    pub startup_options: Vec<(String, String)>,
+    /// The connection's SSL state, or `None` if it isn't using SSL
+    pub ssl: Option<SslInfo>,
+    /// The connection's GSSAPI state, or `None` if it isn't using GSSAPI
+    pub gss: Option<GssInfo>,
+}
+
+/// The leading fields of `libpq/libpq-be.h`'s `Port`, which `pg_sys::Port` leaves opaque.  They're
+/// the same in every supported version of Postgres and don't depend on how it was configured, unlike
+/// the fields that follow them
+#[repr(C)]
+struct PortPrefix {
+    sock: pg_sys::pgsocket,
+    noblock: bool,
+    proto: pg_sys::ProtocolVersion,
+    laddr: pg_sys::SockAddr,
+    raddr: pg_sys::SockAddr,
+    remote_host: *mut c_char,
+    remote_hostname: *mut c_char,
+    remote_hostname_resolv: c_int,
+    remote_hostname_errcode: c_int,
+    remote_port: *mut c_char,
+    can_accept_connections: c_int,
+    database_name: *mut c_char,
+    user_name: *mut c_char,
+    cmdline_options: *mut c_char,
+    guc_options: *mut pg_sys::List,
+}
+
+unsafe fn string(s: *const c_char) -> Option<String> {
+    (!s.is_null()).then(|| CStr::from_ptr(s).to_string_lossy().into_owned())
+}
+
+/// Like [`string`], for the fixed-size buffers of `PgBackendStatus`, which are empty when unset
+fn buffer_string(buffer: &[c_char]) -> Option<String> {
+    let bytes = buffer.iter().map(|c| *c as u8).take_while(|c| *c != 0).collect::<Vec<_>>();
+    (!bytes.is_empty()).then(|| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+impl ClientInfo {
+    /// Describe the client connected through `port`.  The SSL and GSSAPI state are only known
+    /// once the backend has finished starting up, and are `None` before then
+    ///
+    /// ## Safety
+    ///
+    /// `port` must be a valid `Port`, such as `pg_sys::MyProcPort`
+    pub unsafe fn from_port(port: *const pg_sys::Port) -> Self {
+        Self::describe(port, true)
+    }
+
+    /// The backend status can't be looked at until the backend has started up, which it hasn't
+    /// while the client is being authenticated
+    unsafe fn describe(port: *const pg_sys::Port, started: bool) -> Self {
+        let port = &*port.cast::<PortPrefix>();
+        let remote_host = string(port.remote_host).unwrap_or_default();
+        let remote_addr = remote_host.parse::<IpAddr>().ok();
+        let remote_port =
+            remote_addr.and_then(|_| string(port.remote_port)).and_then(|p| p.parse().ok());
+        // a failed lookup is remembered as a NULL `remote_hostname`, so there's no need to check
+        // `remote_hostname_resolv` too
+        let remote_hostname = string(port.remote_hostname);
+
+        // alternating names and values
+        let guc_options = PgList::<c_char>::from_pg(port.guc_options)
+            .iter_ptr()
+            .map(|s| string(s).unwrap_or_default())
+            .collect::<Vec<_>>();
+        let startup_options =
+            guc_options.chunks_exact(2).map(|pair| (pair[0].clone(), pair[1].clone())).collect();
+
+        let (ssl, gss) = started
+            .then(|| own_backend_status())
+            .flatten()
+            .map(|status| (ssl_info(&status), gss_info(&status)))
+            .unwrap_or_default();
+
+        ClientInfo {
+            remote_host,
+            remote_addr,
+            remote_port,
+            remote_hostname,
+            database_name: string(port.database_name),
+            user_name: string(port.user_name),
+            protocol_version: ((port.proto >> 16) as u16, (port.proto & 0xffff) as u16),
+            startup_options,
+            ssl,
+            gss,
+        }
+    }
+}
+
+/// Information about the client connected to this backend, or `None` if this backend doesn't have
+/// one, such as in a background worker
+pub fn client_info() -> Option<ClientInfo> {
+    unsafe {
+        let port = pg_sys::MyProcPort;
+        (!port.is_null()).then(|| ClientInfo::from_port(port))
+    }
+}
+
+/// This backend's entry in the backend status snapshot, which `pg_stat_activity` is built from
+unsafe fn own_backend_status() -> Option<pg_sys::PgBackendStatus> {
+    for i in 1..=pg_sys::pgstat_fetch_stat_numbackends() {
+        let entry = pg_sys::pgstat_fetch_stat_local_beentry(i);
+        if !entry.is_null() && (*entry).backendStatus.st_procpid == pg_sys::MyProcPid {
+            return Some((*entry).backendStatus);
+        }
+    }
+    None
+}
+
+unsafe fn ssl_info(status: &pg_sys::PgBackendStatus) -> Option<SslInfo> {
+    if !status.st_ssl || status.st_sslstatus.is_null() {
+        return None;
+    }
+    let ssl = &*status.st_sslstatus;
+    #[cfg(feature = "pg11")]
+    let client_dn = buffer_string(&ssl.ssl_clientdn);
+    #[cfg(not(feature = "pg11"))]
+    let client_dn = buffer_string(&ssl.ssl_client_dn);
+    Some(SslInfo {
+        version: buffer_string(&ssl.ssl_version).unwrap_or_default(),
+        cipher: buffer_string(&ssl.ssl_cipher).unwrap_or_default(),
+        bits: ssl.ssl_bits,
+        client_dn,
+    })
+}
+
+#[cfg(feature = "pg11")]
+unsafe fn gss_info(_status: &pg_sys::PgBackendStatus) -> Option<GssInfo> {
+    None
+}
+
+#[cfg(not(feature = "pg11"))]
+unsafe fn gss_info(status: &pg_sys::PgBackendStatus) -> Option<GssInfo> {
+    if !status.st_gss || status.st_gssstatus.is_null() {
+        return None;
+    }
+    let gss = &*status.st_gssstatus;
+    Some(GssInfo {
+        principal: if gss.gss_auth { buffer_string(&gss.gss_princ) } else { None },
+        encrypted: gss.gss_enc,
+    })
+}
+
+/// The result of Postgres' own authentication of a client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthenticationStatus {
+    /// The client authenticated successfully
+    Ok,
+    /// The client failed to authenticate, and the connection will be rejected
+    Failed,
+    /// The client disconnected before authenticating, as `psql` does when it finds out it needs
+    /// a password.  The connection will be rejected without a message
+    Eof,
+}
+
+impl AuthenticationStatus {
+    fn from_pg(status: std::os::raw::c_int) -> Self {
+        match status {
+            pg_sys::STATUS_EOF => AuthenticationStatus::Eof,
+            status if status == pg_sys::STATUS_OK as std::os::raw::c_int => {
+                AuthenticationStatus::Ok
+            }
+            _ => AuthenticationStatus::Failed,
+        }
+    }
+}
+
+/// Why a [`ClientAuthenticationHook`] rejected a connection, which is reported to the client
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct ConnectionRejected {
+    pub message: String,
+    pub detail: Option<String>,
+    pub hint: Option<String>,
+}
+
+impl ConnectionRejected {
+    pub fn new(message: impl Into<String>) -> Self {
+        ConnectionRejected { message: message.into(), detail: None, hint: None }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// Reject the connection being authenticated by reporting `rejection` as a `FATAL` error, which
+/// ends the backend
+pub fn reject_connection(rejection: ConnectionRejected) -> ! {
+    let mut report = ErrorReport::new(
+        PgSqlErrorCode::ERRCODE_INVALID_AUTHORIZATION_SPECIFICATION,
+        rejection.message,
+        "reject_connection",
+    );
+    if let Some(detail) = rejection.detail {
+        report = report.set_detail(detail);
+    }
+    if let Some(hint) = rejection.hint {
+        report = report.set_hint(hint);
+    }
+    report.report(PgLogLevel::FATAL);
+    unreachable!("FATAL errors end the backend")
+}
+
+/// Decides whether clients may connect, after Postgres has authenticated them
+pub trait ClientAuthenticationHook {
+    /// Called once Postgres has authenticated `client`, whether it succeeded or not.  Returning an
+    /// error rejects the connection with its message.  Connections that Postgres failed to
+    /// authenticate are rejected regardless, with Postgres' own message if this returns `Ok`.
+    ///
+    /// This runs before the backend has started a transaction, so it can't look at the catalogs,
+    /// use [`Spi`][crate::Spi], or read the SSL and GSSAPI state of `client`.  Anything that must
+    /// outlive the backend, such as a count of failed logins, has to be kept in shared memory.
+    fn authenticate(
+        &mut self,
+        client: &ClientInfo,
+        status: AuthenticationStatus,
+    ) -> Result<(), ConnectionRejected>;
+}
+
+static mut CLIENT_AUTHENTICATION_HOOK: Option<&'static mut dyn ClientAuthenticationHook> = None;
+static mut PREV_CLIENT_AUTHENTICATION_HOOK: pg_sys::ClientAuthentication_hook_type = None;
+
+/// Install `hook` as the `ClientAuthentication_hook`, chained after whatever hook was installed
+/// before it.  This must be called from `_PG_init()`, and the extension must be loaded through
+/// `shared_preload_libraries`, for it to see every connection.
+///
+/// ## Panics
+///
+/// If a [`ClientAuthenticationHook`] has already been registered
+pub unsafe fn register_client_authentication_hook(hook: &'static mut dyn ClientAuthenticationHook) {
+    let registration = &mut *std::ptr::addr_of_mut!(CLIENT_AUTHENTICATION_HOOK);
+    if registration.is_some() {
+        panic!("ClientAuthenticationHook already registered");
+    }
+    *registration = Some(hook);
+
+    PREV_CLIENT_AUTHENTICATION_HOOK = std::mem::replace(
+        &mut *std::ptr::addr_of_mut!(pg_sys::ClientAuthentication_hook),
+        Some(pgrx_client_authentication),
+    );
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgrx_client_authentication(
+    port: *mut pg_sys::Port,
+    status: std::os::raw::c_int,
+) {
+    if let Some(prev) = PREV_CLIENT_AUTHENTICATION_HOOK {
+        prev(port, status);
+    }
+
+    let client = ClientInfo::describe(port, false);
+    let hook = (*std::ptr::addr_of_mut!(CLIENT_AUTHENTICATION_HOOK)).as_mut().unwrap();
+    if let Err(rejection) = hook.authenticate(&client, AuthenticationStatus::from_pg(status)) {
+        reject_connection(rejection);
+    }
+}
@@ -0,0 +1,165 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Functions whose signatures differ across the supported Postgres versions, with one signature
+//!
+//! Each is named after the function in the newest Postgres version, in snake case, and does
+//! what that does on older versions too, so extension code needs fewer
+//! `#[cfg(feature = "pgXX")]` blocks.  Helpers that were already version-independent live with
+//! the rest of their kind, such as [`heap_getattr`][crate::heap_getattr] and
+//! [`PgTupleDesc`][crate::PgTupleDesc].
+use crate::pg_sys::{self, AsPgCStr};
+use std::ffi::CStr;
+
+/// Open the table `relid`, taking `lockmode` on it.  This is `heap_open()` on pg11.
+///
+/// ## Safety
+///
+/// The returned relation must be closed with [`table_close`] before the end of the transaction
+pub unsafe fn table_open(relid: pg_sys::Oid, lockmode: pg_sys::LOCKMODE) -> pg_sys::Relation {
+    #[cfg(feature = "pg11")]
+    {
+        pg_sys::heap_open(relid, lockmode)
+    }
+    #[cfg(not(feature = "pg11"))]
+    {
+        pg_sys::table_open(relid, lockmode)
+    }
+}
+
+/// Close a relation opened by [`table_open`], releasing `lockmode` unless it's `NoLock`
+///
+/// ## Safety
+///
+/// `relation` must be open, and can't be used afterwards
+pub unsafe fn table_close(relation: pg_sys::Relation, lockmode: pg_sys::LOCKMODE) {
+    // table_close() and pg11's heap_close() are both just relation_close()
+    pg_sys::relation_close(relation, lockmode)
+}
+
+/// An empty tuple descriptor for `natts` attributes, to be filled in with
+/// `pg_sys::TupleDescInitEntry()`.  Tuple descriptors can't have OIDs on pg11 either.
+pub fn create_template_tuple_desc(natts: i32) -> pg_sys::TupleDesc {
+    unsafe {
+        #[cfg(feature = "pg11")]
+        {
+            pg_sys::CreateTemplateTupleDesc(natts, false)
+        }
+        #[cfg(not(feature = "pg11"))]
+        {
+            pg_sys::CreateTemplateTupleDesc(natts)
+        }
+    }
+}
+
+/// A string node, palloc'd in the current memory context.  This is a `String` since pg15, and a
+/// `Value` before then.
+pub fn make_string(s: &str) -> *mut pg_sys::Node {
+    unsafe { pg_sys::makeString(s.as_pg_cstr()).cast() }
+}
+
+/// The contents of a string node, as made by [`make_string`] or found in a parse tree, like
+/// Postgres' `strVal()`
+///
+/// ## Safety
+///
+/// `node` must be a valid string node, and the result must not outlive it
+pub unsafe fn str_val<'a>(node: *mut pg_sys::Node) -> &'a CStr {
+    #[cfg(any(feature = "pg15", feature = "pg16"))]
+    let s = (*node.cast::<pg_sys::String>()).sval;
+    #[cfg(not(any(feature = "pg15", feature = "pg16")))]
+    let s = (*node.cast::<pg_sys::Value>()).val.str_;
+    CStr::from_ptr(s)
+}
+
+/// The OID of the catalog row found in `cache_id` by `keys`, or `InvalidOid`.  `oidcol` is the
+/// number of the row's OID column, which pg11 doesn't need, as its OIDs aren't a column.
+pub fn get_sys_cache_oid(
+    cache_id: pg_sys::SysCacheIdentifier,
+    oidcol: pg_sys::AttrNumber,
+    keys: [pg_sys::Datum; 4],
+) -> pg_sys::Oid {
+    let [key1, key2, key3, key4] = keys;
+    unsafe {
+        #[cfg(feature = "pg11")]
+        {
+            let _ = oidcol;
+            pg_sys::GetSysCacheOid(cache_id as _, key1, key2, key3, key4)
+        }
+        #[cfg(not(feature = "pg11"))]
+        {
+            pg_sys::GetSysCacheOid(cache_id as _, oidcol, key1, key2, key3, key4)
+        }
+    }
+}
+
+/// Does `roleid` have the privileges in `mode` on the object `objectid` in the catalog `classid`?
+///
+/// Before pg16 this dispatches to the `pg_*_aclcheck()` function for `classid`, and supports
+/// relations, schemas, functions, types, databases and tablespaces.  Other catalogs raise an
+/// `ERROR`.
+pub fn object_aclcheck(
+    classid: pg_sys::Oid,
+    objectid: pg_sys::Oid,
+    roleid: pg_sys::Oid,
+    mode: pg_sys::AclMode,
+) -> pg_sys::AclResult {
+    unsafe {
+        #[cfg(feature = "pg16")]
+        {
+            pg_sys::object_aclcheck(classid, objectid, roleid, mode)
+        }
+        #[cfg(not(feature = "pg16"))]
+        {
+            match classid {
+                pg_sys::RelationRelationId => pg_sys::pg_class_aclcheck(objectid, roleid, mode),
+                pg_sys::NamespaceRelationId => {
+                    pg_sys::pg_namespace_aclcheck(objectid, roleid, mode)
+                }
+                pg_sys::ProcedureRelationId => pg_sys::pg_proc_aclcheck(objectid, roleid, mode),
+                pg_sys::TypeRelationId => pg_sys::pg_type_aclcheck(objectid, roleid, mode),
+                pg_sys::DatabaseRelationId => pg_sys::pg_database_aclcheck(objectid, roleid, mode),
+                pg_sys::TableSpaceRelationId => {
+                    pg_sys::pg_tablespace_aclcheck(objectid, roleid, mode)
+                }
+                _ => crate::error!("object_aclcheck() doesn't support class {classid:?}"),
+            }
+        }
+    }
+}
+
+/// Does `roleid` own the object `objectid` in the catalog `classid`, or is it a superuser?
+///
+/// Before pg16 this dispatches to the `pg_*_ownercheck()` function for `classid`, and supports
+/// relations, schemas, functions, types, databases, tablespaces, extensions and event
+/// triggers.  Other catalogs raise an `ERROR`.
+pub fn object_ownercheck(classid: pg_sys::Oid, objectid: pg_sys::Oid, roleid: pg_sys::Oid) -> bool {
+    unsafe {
+        #[cfg(feature = "pg16")]
+        {
+            pg_sys::object_ownercheck(classid, objectid, roleid)
+        }
+        #[cfg(not(feature = "pg16"))]
+        {
+            match classid {
+                pg_sys::RelationRelationId => pg_sys::pg_class_ownercheck(objectid, roleid),
+                pg_sys::NamespaceRelationId => pg_sys::pg_namespace_ownercheck(objectid, roleid),
+                pg_sys::ProcedureRelationId => pg_sys::pg_proc_ownercheck(objectid, roleid),
+                pg_sys::TypeRelationId => pg_sys::pg_type_ownercheck(objectid, roleid),
+                pg_sys::DatabaseRelationId => pg_sys::pg_database_ownercheck(objectid, roleid),
+                pg_sys::TableSpaceRelationId => pg_sys::pg_tablespace_ownercheck(objectid, roleid),
+                pg_sys::ExtensionRelationId => pg_sys::pg_extension_ownercheck(objectid, roleid),
+                pg_sys::EventTriggerRelationId => {
+                    pg_sys::pg_event_trigger_ownercheck(objectid, roleid)
+                }
+                _ => crate::error!("object_ownercheck() doesn't support class {classid:?}"),
+            }
+        }
+    }
+}
@@ -74,6 +74,11 @@ impl IntoDatum for AnyArray {
     fn type_oid() -> pg_sys::Oid {
         pg_sys::ANYARRAYOID
     }
+
+    #[inline]
+    fn polymorphic_type_oid(&self) -> Option<pg_sys::Oid> {
+        Some(self.typoid)
+    }
 }
 
 unsafe impl SqlTranslatable for AnyArray {
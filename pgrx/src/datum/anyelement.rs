@@ -18,6 +18,22 @@ use pgrx_sql_entity_graph::metadata::{
 /// **Warning**: Calling [`FromDatum::from_datum`] with this type will unconditonally panic. Call
 /// [`FromDatum::from_polymorphic_datum`] with a type ID instead.
 ///
+/// Returning an `AnyElement` makes a function whose return type is the type of its `anyelement`
+/// arguments in each call, so one Rust function serves every type:
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::AnyElement;
+///
+/// #[pg_extern]
+/// fn or_default(value: Option<AnyElement>, default: AnyElement) -> AnyElement {
+///     value.unwrap_or(default)
+/// }
+/// ```
+///
+/// An `AnyElement` returned this way must have the type Postgres resolved the call to, or the
+/// function raises an ERROR rather than hand back a Datum the caller would misread.
+///
 /// [anyelement]: https://www.postgresql.org/docs/current/extend-type-system.html#EXTEND-TYPES-POLYMORPHIC
 #[derive(Debug, Clone, Copy)]
 pub struct AnyElement {
@@ -34,6 +50,16 @@ impl AnyElement {
         self.typoid
     }
 
+    /// Wrap a value of a specific type, such as to return it from a function declared to return
+    /// `anyelement`.  Returns `None` if the value is SQL `NULL`.
+    ///
+    /// `#[pg_extern]` raises an ERROR if `T` isn't the type the call resolved `anyelement` to.
+    #[inline]
+    pub fn new<T: IntoDatum>(value: T) -> Option<AnyElement> {
+        let typoid = value.composite_type_oid().unwrap_or_else(T::type_oid);
+        value.into_datum().map(|datum| AnyElement { datum, typoid })
+    }
+
     /// Convert this element into a specific type.
     ///
     /// # Safety
@@ -80,6 +106,11 @@ impl IntoDatum for AnyElement {
     fn type_oid() -> pg_sys::Oid {
         pg_sys::ANYELEMENTOID
     }
+
+    #[inline]
+    fn polymorphic_type_oid(&self) -> Option<pg_sys::Oid> {
+        Some(self.typoid)
+    }
 }
 
 unsafe impl SqlTranslatable for AnyElement {
@@ -9,9 +9,10 @@
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 use crate::array::RawArray;
 use crate::datum::array::casper::ChaChaSlide;
+use crate::heap_tuple::{CompositeColumns, PgHeapTuple};
 use crate::layout::*;
 use crate::toast::Toast;
-use crate::{pg_sys, FromDatum, IntoDatum, PgMemoryContexts};
+use crate::{pg_sys, AllocatedByRust, FromDatum, IntoDatum, PgMemoryContexts};
 use bitvec::slice::BitSlice;
 use core::fmt::{Debug, Formatter};
 use core::ops::DerefMut;
@@ -191,6 +192,16 @@ impl<'a, T: FromDatum> Array<'a, T> {
         ArrayTypedIterator { array: self, curr: 0, ptr }
     }
 
+    /// Return an iterator over only the Array's non-NULL elements.
+    ///
+    /// NULL elements take up no space in the array's data, so skipping them is cheaper than
+    /// filtering the `None`s out of [`Array::iter()`].
+    #[inline]
+    pub fn iter_non_null(&self) -> ArrayNonNullIterator<'_, T> {
+        let ptr = self.raw.data_ptr();
+        ArrayNonNullIterator { array: self, curr: 0, ptr }
+    }
+
     /// Returns `true` if this [`Array`] contains one or more SQL "NULL" values
     #[inline]
     pub fn contains_nulls(&self) -> bool {
@@ -275,6 +286,41 @@ impl<'a, T: FromDatum> Array<'a, T> {
     }
 }
 
+impl<'a, 'mcx> Array<'a, PgHeapTuple<'mcx, AllocatedByRust>> {
+    /// Convert every composite in this [`Array`] into columns of their attributes, at once.
+    ///
+    /// The array is walked once and each composite deformed once, with one tuple descriptor for
+    /// all of them, which is much quicker than reading wide composites one attribute at a time.
+    ///
+    /// ```rust,no_run
+    /// use pgrx::prelude::*;
+    ///
+    /// #[pg_extern]
+    /// fn total_age(dogs: Array<pgrx::composite_type!("Dog")>) -> i64 {
+    ///     let columns = dogs.to_columns();
+    ///     let ages = columns.column_by_name::<i32>("age").unwrap();
+    ///     ages.into_iter().flatten().map(i64::from).sum()
+    /// }
+    /// ```
+    pub fn to_columns(&self) -> CompositeColumns<'_> {
+        let mut ptr = self.raw.data_ptr();
+        let mut composites = Vec::with_capacity(self.len());
+        for index in 0..self.len() {
+            match self.null_slice.get(index) {
+                Some(false) => {
+                    composites.push(Some(Datum::from(ptr)));
+                    // SAFETY: only non-null elements are in the data buffer, and we hop once each
+                    ptr = unsafe { self.one_hop_this_time(ptr) };
+                }
+                _ => composites.push(None),
+            }
+        }
+
+        // SAFETY: the array's elements are composites of its element type, borrowed from it
+        unsafe { CompositeColumns::from_composite_datums(self.raw.oid(), composites) }
+    }
+}
+
 #[derive(thiserror::Error, Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ArraySliceError {
     #[error("Cannot create a slice of an Array that contains nulls")]
@@ -293,6 +339,14 @@ impl<'a> Array<'a, f64> {
     pub fn as_slice(&self) -> Result<&[f64], ArraySliceError> {
         as_slice(self)
     }
+
+    /// Returns a slice of the non-NULL `f64`s in this [`Array`], and its null bitmap if it has one.
+    ///
+    /// See `Array<i32>::as_slices()` for how to line the values up with the bitmap.
+    #[inline]
+    pub fn as_slices(&self) -> (&[f64], Option<&BitSlice<u8>>) {
+        as_slices(self)
+    }
 }
 
 impl<'a> Array<'a, f32> {
@@ -306,6 +360,14 @@ impl<'a> Array<'a, f32> {
     pub fn as_slice(&self) -> Result<&[f32], ArraySliceError> {
         as_slice(self)
     }
+
+    /// Returns a slice of the non-NULL `f32`s in this [`Array`], and its null bitmap if it has one.
+    ///
+    /// See `Array<i32>::as_slices()` for how to line the values up with the bitmap.
+    #[inline]
+    pub fn as_slices(&self) -> (&[f32], Option<&BitSlice<u8>>) {
+        as_slices(self)
+    }
 }
 
 #[cfg(target_pointer_width = "64")]
@@ -320,6 +382,14 @@ impl<'a> Array<'a, i64> {
     pub fn as_slice(&self) -> Result<&[i64], ArraySliceError> {
         as_slice(self)
     }
+
+    /// Returns a slice of the non-NULL `i64`s in this [`Array`], and its null bitmap if it has one.
+    ///
+    /// See `Array<i32>::as_slices()` for how to line the values up with the bitmap.
+    #[inline]
+    pub fn as_slices(&self) -> (&[i64], Option<&BitSlice<u8>>) {
+        as_slices(self)
+    }
 }
 
 impl<'a> Array<'a, i32> {
@@ -333,6 +403,26 @@ impl<'a> Array<'a, i32> {
     pub fn as_slice(&self) -> Result<&[i32], ArraySliceError> {
         as_slice(self)
     }
+
+    /// Returns a slice of the non-NULL `i32`s in this [`Array`], and its null bitmap if it has one.
+    ///
+    /// NULL elements take up no space in an array's data, so the slice only holds as many values as
+    /// there are non-NULL elements, in order.  Following Postgres, a bit in the bitmap is set when
+    /// its element is **not** NULL, and the bitmap is `None` when there aren't any NULLs.
+    ///
+    /// ```rust,no_run
+    /// use pgrx::prelude::*;
+    ///
+    /// #[pg_extern]
+    /// fn sum_non_null(values: Array<i32>) -> i64 {
+    ///     let (values, _nulls) = values.as_slices();
+    ///     values.iter().map(|&v| v as i64).sum()
+    /// }
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[i32], Option<&BitSlice<u8>>) {
+        as_slices(self)
+    }
 }
 
 impl<'a> Array<'a, i16> {
@@ -346,6 +436,14 @@ impl<'a> Array<'a, i16> {
     pub fn as_slice(&self) -> Result<&[i16], ArraySliceError> {
         as_slice(self)
     }
+
+    /// Returns a slice of the non-NULL `i16`s in this [`Array`], and its null bitmap if it has one.
+    ///
+    /// See `Array<i32>::as_slices()` for how to line the values up with the bitmap.
+    #[inline]
+    pub fn as_slices(&self) -> (&[i16], Option<&BitSlice<u8>>) {
+        as_slices(self)
+    }
 }
 
 impl<'a> Array<'a, i8> {
@@ -359,6 +457,26 @@ impl<'a> Array<'a, i8> {
     pub fn as_slice(&self) -> Result<&[i8], ArraySliceError> {
         as_slice(self)
     }
+
+    /// Returns a slice of the non-NULL `i8`s in this [`Array`], and its null bitmap if it has one.
+    ///
+    /// See `Array<i32>::as_slices()` for how to line the values up with the bitmap.
+    #[inline]
+    pub fn as_slices(&self) -> (&[i8], Option<&BitSlice<u8>>) {
+        as_slices(self)
+    }
+}
+
+#[inline(always)]
+fn as_slices<'a, T: Sized + FromDatum>(
+    array: &'a Array<'_, T>,
+) -> (&'a [T], Option<&'a BitSlice<u8>>) {
+    let (nulls, non_null) = match array.null_slice {
+        NullKind::Bits(bits) => (Some(bits), bits.count_ones()),
+        NullKind::Strict(len) => (None, len),
+    };
+    let values = unsafe { std::slice::from_raw_parts(array.raw.data_ptr() as *const _, non_null) };
+    (values, nulls)
 }
 
 #[inline(always)]
@@ -372,6 +490,59 @@ fn as_slice<'a, T: Sized + FromDatum>(array: &'a Array<'_, T>) -> Result<&'a [T]
     Ok(slice)
 }
 
+/// Borrows the elements of a fixed-size, pass-by-value, array directly from the detoasted datum,
+/// which lives in the `CurrentMemoryContext`, without copying them.
+///
+/// ## Panics
+///
+/// If the array's element type isn't `T`, or if it contains any SQL `NULL` values.  Use an
+/// [`Array`] to accept arrays with nulls.
+macro_rules! impl_slice_from_datum {
+    ($($(#[$attr:meta])* $t:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<'a> FromDatum for &'a [$t] {
+                #[inline]
+                unsafe fn from_polymorphic_datum(
+                    datum: pg_sys::Datum,
+                    is_null: bool,
+                    _typoid: pg_sys::Oid,
+                ) -> Option<&'a [$t]> {
+                    if is_null || datum.is_null() {
+                        return None;
+                    }
+
+                    // an uncompressed, non-external, array is used in place
+                    let array = pg_sys::pg_detoast_datum(datum.cast_mut_ptr());
+                    let raw = RawArray::from_ptr(NonNull::new_unchecked(array.cast()));
+                    if raw.oid() != <$t as IntoDatum>::type_oid() {
+                        panic!(
+                            "array element type oid `{}` cannot be borrowed as a slice of `{}`",
+                            raw.oid(),
+                            stringify!($t)
+                        );
+                    }
+                    if raw.any_nulls() {
+                        panic!("array contains NULL");
+                    }
+                    Some(std::slice::from_raw_parts(raw.data_ptr().cast::<$t>(), raw.len()))
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_from_datum!(
+    i8,
+    i16,
+    i32,
+    #[cfg(target_pointer_width = "64")]
+    i64,
+    f32,
+    #[cfg(target_pointer_width = "64")]
+    f64,
+);
+
 mod casper {
     use crate::layout::Align;
     use crate::{pg_sys, varlena, Array, FromDatum};
@@ -589,6 +760,40 @@ impl<'a, T: FromDatum + serde::Serialize> serde::Serialize for ArrayTypedIterato
     }
 }
 
+pub struct ArrayNonNullIterator<'a, T: 'a + FromDatum> {
+    array: &'a Array<'a, T>,
+    curr: usize,
+    ptr: *const u8,
+}
+
+impl<'a, T: FromDatum> Iterator for ArrayNonNullIterator<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { array, curr, ptr } = self;
+        loop {
+            let is_null = array.null_slice.get(*curr)?;
+            *curr += 1;
+            if is_null {
+                // NULLs occupy no data bytes, so there's nothing to hop over
+                continue;
+            }
+
+            let element = unsafe { array.bring_it_back_now(*ptr, false) };
+            *ptr = unsafe { array.one_hop_this_time(*ptr) };
+            return element;
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.array.raw.len().saturating_sub(self.curr)))
+    }
+}
+
+impl<'a, T: FromDatum> core::iter::FusedIterator for ArrayNonNullIterator<'a, T> {}
+
 pub struct ArrayIterator<'a, T: 'a + FromDatum> {
     array: &'a Array<'a, T>,
     curr: usize,
@@ -729,6 +934,8 @@ impl<'a, T: FromDatum> FromDatum for Array<'a, T> {
     }
 }
 
+/// Returning an [`Array`] hands Postgres its `ArrayType` as-is, so a function that returns (one of)
+/// its array arguments, perhaps after looking at it, doesn't copy it.
 impl<T: IntoDatum + FromDatum> IntoDatum for Array<'_, T> {
     #[inline]
     fn into_datum(self) -> Option<Datum> {
@@ -743,6 +950,51 @@ impl<T: IntoDatum + FromDatum> IntoDatum for Array<'_, T> {
     }
 }
 
+impl<T: IntoDatum + FromDatum> IntoDatum for VariadicArray<'_, T> {
+    #[inline]
+    fn into_datum(self) -> Option<Datum> {
+        self.0.into_datum()
+    }
+
+    #[inline]
+    fn type_oid() -> Oid {
+        T::array_type_oid()
+    }
+}
+
+/// Build an [`Array`] directly, in the `CurrentMemoryContext`, where `None` is a SQL `NULL` element
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+///
+/// #[pg_extern]
+/// fn evens(values: Array<i32>) -> Array<i32> {
+///     values.iter().map(|v| v.filter(|v| v % 2 == 0)).collect()
+/// }
+/// ```
+impl<'a, T: IntoDatum + FromDatum> FromIterator<Option<T>> for Array<'a, T> {
+    fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
+        unsafe {
+            let memcxt = PgMemoryContexts::CurrentMemoryContext.value();
+            let mut state = pg_sys::initArrayResult(T::type_oid(), memcxt, false);
+            for element in iter {
+                let datum = element.and_then(|element| element.into_datum());
+                state = pg_sys::accumArrayResult(
+                    state,
+                    datum.unwrap_or(0.into()),
+                    datum.is_none(),
+                    T::type_oid(),
+                    memcxt,
+                );
+            }
+
+            let array = pg_sys::makeArrayResult(state, memcxt);
+            let raw = RawArray::detoast_from_varlena(NonNull::new_unchecked(array.cast_mut_ptr()));
+            Array::deconstruct_from(raw)
+        }
+    }
+}
+
 impl<T: FromDatum> FromDatum for Vec<T> {
     #[inline]
     unsafe fn from_polymorphic_datum(
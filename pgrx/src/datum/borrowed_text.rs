@@ -0,0 +1,185 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+use crate::datum::from::convert_varlena_to_str_memoized;
+use crate::{pg_sys, FromDatum, IntoDatum, PgMemoryContexts};
+use pgrx_sql_entity_graph::metadata::{
+    ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
+};
+use std::fmt;
+use std::ops::Deref;
+
+/// A `text` argument that can be returned without copying it
+///
+/// Returning a `&str` from a `#[pg_extern]` function always copies it into a new `text` datum,
+/// because the wrapper can't know where the string came from.  A [`BorrowedText`] remembers the
+/// datum it was read from, so when a function returns it unchanged, or returns a
+/// [`BorrowedText::substr`] that still spans all of it, the original datum is handed back to
+/// Postgres as-is.  Anything else is copied, just as a `&str` would be.
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::BorrowedText;
+///
+/// #[pg_extern]
+/// fn trim_spaces(s: BorrowedText<'_>) -> BorrowedText<'_> {
+///     // most values have no surrounding spaces, and are returned without a copy
+///     let trimmed = s.as_str().trim_matches(' ');
+///     s.substr(trimmed)
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct BorrowedText<'a> {
+    /// The detoasted datum, if this still spans all of it
+    varlena: Option<*const pg_sys::varlena>,
+    text: &'a str,
+}
+
+impl<'a> BorrowedText<'a> {
+    /// Narrow this to `part`, which must be a substring borrowed from it, such as the result of
+    /// `self.as_str().trim()`
+    ///
+    /// ## Panics
+    ///
+    /// If `part` doesn't lie within this text
+    pub fn substr(&self, part: &'a str) -> BorrowedText<'a> {
+        let start = self.text.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+        assert!(
+            part_start >= start && part_start + part.len() <= start + self.text.len(),
+            "`part` is not a substring of this text"
+        );
+
+        let whole = part_start == start && part.len() == self.text.len();
+        BorrowedText { varlena: if whole { self.varlena } else { None }, text: part }
+    }
+
+    /// Will this be returned to Postgres without being copied?
+    pub fn is_original(&self) -> bool {
+        self.varlena.is_some()
+    }
+
+    /// The text, borrowed for as long as the datum it came from rather than for as long as `self`
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+}
+
+impl<'a> From<&'a str> for BorrowedText<'a> {
+    /// A [`BorrowedText`] that will be copied when returned
+    fn from(text: &'a str) -> Self {
+        BorrowedText { varlena: None, text }
+    }
+}
+
+impl<'a> Deref for BorrowedText<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.text
+    }
+}
+
+impl<'a> fmt::Debug for BorrowedText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.text, f)
+    }
+}
+
+impl<'a> fmt::Display for BorrowedText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+impl<'a> PartialEq for BorrowedText<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl<'a> Eq for BorrowedText<'a> {}
+
+impl<'a> PartialEq<str> for BorrowedText<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.text == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for BorrowedText<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.text == *other
+    }
+}
+
+impl<'a> FromDatum for BorrowedText<'a> {
+    #[inline]
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        _typoid: pg_sys::Oid,
+    ) -> Option<BorrowedText<'a>> {
+        if is_null || datum.is_null() {
+            None
+        } else {
+            // a packed (short header) varlena is used in place, and is fine to return as-is
+            let varlena = pg_sys::pg_detoast_datum_packed(datum.cast_mut_ptr());
+            let text = convert_varlena_to_str_memoized(varlena);
+            Some(BorrowedText { varlena: Some(varlena), text })
+        }
+    }
+
+    unsafe fn from_datum_in_memory_context(
+        mut memory_context: PgMemoryContexts,
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if is_null || datum.is_null() {
+            None
+        } else {
+            memory_context.switch_to(|_| {
+                let copy = pg_sys::pg_detoast_datum_copy(datum.cast_mut_ptr());
+                BorrowedText::from_polymorphic_datum(pg_sys::Datum::from(copy), false, typoid)
+            })
+        }
+    }
+}
+
+impl<'a> IntoDatum for BorrowedText<'a> {
+    #[inline]
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        match self.varlena {
+            // it's still the whole datum we were given, which lives at least as long as the call
+            Some(varlena) => Some(pg_sys::Datum::from(varlena)),
+            None => self.text.into_datum(),
+        }
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        pg_sys::TEXTOID
+    }
+
+    #[inline]
+    fn is_compatible_with(other: pg_sys::Oid) -> bool {
+        Self::type_oid() == other || other == pg_sys::VARCHAROID
+    }
+}
+
+unsafe impl<'a> SqlTranslatable for BorrowedText<'a> {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("TEXT"))
+    }
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("TEXT")))
+    }
+}
@@ -630,7 +630,11 @@ pub fn get_timezone_offset<Tz: AsRef<str>>(zone: Tz) -> Result<i32, DateTimeConv
             tz = pg_sys::DetermineTimeZoneAbbrevOffsetTS(now, tzname.as_ptr(), tzp, &mut isdst);
         } else {
             /* try it as a full zone name */
-            crate::warning!("tzname={}", tzname.to_str().unwrap());
+            crate::logging::internal_log!(
+                pgrx_pg_sys::elog::PgLogLevel::DEBUG1,
+                "tzname={}",
+                tzname.to_string_lossy()
+            );
             tzp = pg_sys::pg_tzset(tzname.as_ptr());
             if !tzp.is_null() {
                 /* Get the offset-from-GMT that is valid now for the zone */
@@ -0,0 +1,171 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Conversions between Rust string maps and the `hstore` type from Postgres' contrib
+//!
+//! `hstore` is an extension type, so its OID isn't known until runtime.  It's looked up by name,
+//! through the active `search_path`, and these conversions raise an ERROR if `CREATE EXTENSION hstore`
+//! hasn't happened in the current database.
+//!
+//! Conversions go through `hstore`'s own text input/output functions so that we don't depend on
+//! its on-disk layout.
+use crate::{pg_sys, FromDatum, IntoDatum};
+use core::ffi::CStr;
+use std::collections::{BTreeMap, HashMap};
+
+/// Looks up the OID of the `hstore` type, once per session, raising an ERROR if it isn't installed
+#[inline]
+pub fn hstore_type_oid() -> pg_sys::Oid {
+    crate::wrappers::type_oid_or_error("hstore")
+}
+
+impl FromDatum for HashMap<String, Option<String>> {
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self> {
+        if is_null {
+            None
+        } else {
+            Some(hstore_from_datum(datum, typoid).into_iter().collect())
+        }
+    }
+}
+
+impl FromDatum for BTreeMap<String, Option<String>> {
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self> {
+        if is_null {
+            None
+        } else {
+            Some(hstore_from_datum(datum, typoid).into_iter().collect())
+        }
+    }
+}
+
+impl IntoDatum for HashMap<String, Option<String>> {
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        hstore_into_datum(self.iter())
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        hstore_type_oid()
+    }
+}
+
+impl IntoDatum for BTreeMap<String, Option<String>> {
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        hstore_into_datum(self.iter())
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        hstore_type_oid()
+    }
+}
+
+unsafe fn hstore_from_datum(
+    datum: pg_sys::Datum,
+    typoid: pg_sys::Oid,
+) -> Vec<(String, Option<String>)> {
+    let typoid = if typoid == pg_sys::InvalidOid { hstore_type_oid() } else { typoid };
+    let mut output_func = pg_sys::InvalidOid;
+    let mut is_varlena = false;
+    pg_sys::getTypeOutputInfo(typoid, &mut output_func, &mut is_varlena);
+
+    let cstr = pg_sys::OidOutputFunctionCall(output_func, datum);
+    let text = CStr::from_ptr(cstr).to_str().expect("text version of hstore is not valid UTF8");
+    let pairs = parse_hstore(text);
+    pg_sys::pfree(cstr.cast());
+    pairs
+}
+
+fn hstore_into_datum<'a>(
+    pairs: impl Iterator<Item = (&'a String, &'a Option<String>)>,
+) -> Option<pg_sys::Datum> {
+    let mut text = String::new();
+    for (i, (key, value)) in pairs.enumerate() {
+        if i > 0 {
+            text.push_str(", ");
+        }
+        push_quoted(&mut text, key);
+        text.push_str("=>");
+        match value {
+            Some(value) => push_quoted(&mut text, value),
+            None => text.push_str("NULL"),
+        }
+    }
+    let cstring =
+        alloc::ffi::CString::new(text).expect("hstore keys and values cannot contain NULL bytes");
+
+    unsafe {
+        let mut input_func = pg_sys::InvalidOid;
+        let mut typioparam = pg_sys::InvalidOid;
+        pg_sys::getTypeInputInfo(hstore_type_oid(), &mut input_func, &mut typioparam);
+        Some(pg_sys::OidInputFunctionCall(input_func, cstring.as_ptr().cast_mut(), typioparam, -1))
+    }
+}
+
+fn push_quoted(text: &mut String, s: &str) {
+    text.push('"');
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            text.push('\\');
+        }
+        text.push(c);
+    }
+    text.push('"');
+}
+
+/// Parses the canonical text form produced by `hstore_out`: `"key"=>"value", "key"=>NULL`
+fn parse_hstore(text: &str) -> Vec<(String, Option<String>)> {
+    let mut pairs = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let key = parse_quoted(&mut chars);
+        assert!(
+            chars.next() == Some('=') && chars.next() == Some('>'),
+            "malformed hstore output: expected `=>`"
+        );
+
+        let value = if chars.peek() == Some(&'"') {
+            Some(parse_quoted(&mut chars))
+        } else {
+            let null: String = chars.by_ref().take(4).collect();
+            assert_eq!(null, "NULL", "malformed hstore output: expected a quoted value or NULL");
+            None
+        };
+
+        pairs.push((key, value));
+    }
+
+    pairs
+}
+
+fn parse_quoted(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    assert_eq!(chars.next(), Some('"'), "malformed hstore output: expected `\"`");
+    let mut s = String::new();
+    loop {
+        match chars.next().expect("malformed hstore output: unterminated string") {
+            '\\' => s.push(chars.next().expect("malformed hstore output: dangling escape")),
+            '"' => break,
+            c => s.push(c),
+        }
+    }
+    s
+}
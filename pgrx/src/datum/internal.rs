@@ -21,6 +21,26 @@ use pgrx_sql_entity_graph::metadata::{
 ///    not create any function that is declared to return internal unless it has at least one
 ///    internal argument.
 ///
+/// `#[pg_extern]` functions, such as the support functions of index access methods, operator
+/// classes, and aggregates, can take and return [Internal], which is declared as `internal` in
+/// their SQL.  Following the rule above, generating the schema fails for a function that returns
+/// `internal` without taking one.
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::Internal;
+///
+/// /// The number of elements in the `Vec<i64>` an aggregate keeps as its state
+/// #[pg_extern(immutable, parallel_safe)]
+/// fn state_len(state: Internal) -> i64 {
+///     unsafe { state.get::<Vec<i64>>() }.map_or(0, |state| state.len() as i64)
+/// }
+/// ```
+///
+/// What Postgres passes as `internal` is usually a pointer to one of its own structs rather than
+/// something made with [`Internal::new`], which [`Internal::as_ptr`] and [`Internal::from_ptr`]
+/// convert to and from.
+///
 /// ## Implementation Notes
 ///
 /// [Internal] is a wrapper around an `Option<pg_sys::Datum>`, which when retrieved via
@@ -44,6 +64,13 @@ impl Internal {
         )))
     }
 
+    /// Wrap a pointer, such as one to a Postgres struct, to be passed as `internal`.  A null
+    /// pointer is an uninitialized [`Internal`].
+    #[inline(always)]
+    pub fn from_ptr<T>(ptr: *mut T) -> Self {
+        Self((!ptr.is_null()).then(|| pg_sys::Datum::from(ptr)))
+    }
+
     /// Returns true if the internal value is initialized. If false, this is a null pointer.
     #[inline(always)]
     pub fn initialized(&self) -> bool {
@@ -62,6 +89,16 @@ impl Internal {
         self.0.and_then(|datum| (datum.cast_mut_ptr::<T>() as *const T).as_ref())
     }
 
+    /// The pointer this [`Internal`] holds, such as to the Postgres struct a support function is
+    /// given, or null if it isn't initialized.
+    ///
+    /// Getting the pointer is safe, but dereferencing it means asserting that it really points to
+    /// a `T`, which is your responsibility.
+    #[inline(always)]
+    pub fn as_ptr<T>(&self) -> *mut T {
+        self.0.map_or(core::ptr::null_mut(), |datum| datum.cast_mut_ptr::<T>())
+    }
+
     /// Initializes the internal with `value`, then returns a mutable reference to it.
     ///
     /// If the Internal is already initialized with a value, the old value is dropped.
@@ -38,6 +38,16 @@ pub trait IntoDatum {
         unsafe { pg_sys::get_array_type(Self::type_oid()) }
     }
 
+    /// The actual type of this value, if `Self` is a polymorphic pseudo-type such as
+    /// [`AnyElement`](crate::AnyElement), whose [`IntoDatum::type_oid`] can't say what it holds.
+    ///
+    /// `#[pg_extern]` checks this against the type Postgres resolved the call to return, so a
+    /// function can't return a Datum of some other type than the caller expects.
+    #[inline]
+    fn polymorphic_type_oid(&self) -> Option<pg_sys::Oid> {
+        None
+    }
+
     /// Is a Datum of this type compatible with another Postgres type?
     ///
     /// An example of this are the Postgres `text` and `varchar` types, which are both
@@ -89,6 +99,10 @@ where
     fn type_oid() -> pg_sys::Oid {
         T::type_oid()
     }
+
+    fn polymorphic_type_oid(&self) -> Option<pg_sys::Oid> {
+        self.as_ref().and_then(|t| t.polymorphic_type_oid())
+    }
 }
 
 impl<T, E> IntoDatum for Result<T, E>
@@ -115,6 +129,11 @@ where
     fn type_oid() -> pg_sys::Oid {
         T::type_oid()
     }
+
+    #[inline]
+    fn polymorphic_type_oid(&self) -> Option<pg_sys::Oid> {
+        self.as_ref().ok().and_then(|t| t.polymorphic_type_oid())
+    }
 }
 
 /// for bool
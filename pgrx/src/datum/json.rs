@@ -8,35 +8,67 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 use crate::{
-    direct_function_call, direct_function_call_as_datum, pg_sys, vardata_any, varsize_any_exhdr,
-    void_mut_ptr, FromDatum, IntoDatum,
+    direct_function_call, direct_function_call_as_datum, pg_sys, set_varsize, vardata_any,
+    varsize_any_exhdr, void_mut_ptr, FromDatum, IntoDatum, StringInfo,
 };
 use pgrx_sql_entity_graph::metadata::{
     ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
 };
+use serde::de::DeserializeOwned;
 use serde::{Serialize, Serializer};
 use serde_json::Value;
 
 /// A `json` type from PostgreSQL
+///
+/// By default this holds a [`serde_json::Value`], but any type implementing [`Serialize`] and
+/// [`DeserializeOwned`] can be used instead.  This lets request/response-style functions take and
+/// return plain Rust structs without declaring a [`PostgresType`](crate::PostgresType) for each:
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::Json;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Request {
+///     name: String,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Response {
+///     greeting: String,
+/// }
+///
+/// #[pg_extern]
+/// fn greet(request: Json<Request>) -> Json<Response> {
+///     Json(Response { greeting: format!("Hello, {}", request.0.name) })
+/// }
+/// ```
+///
+/// Use [`JsonB`] in the same way for `jsonb`.
 #[derive(Debug)]
-pub struct Json(pub Value);
+pub struct Json<T = Value>(pub T);
 
 /// A `jsonb` type from PostgreSQL
+///
+/// By default this holds a [`serde_json::Value`], but any type implementing [`Serialize`] and
+/// [`DeserializeOwned`] can be used instead, such as `JsonB<HashMap<String, i64>>` or a plain
+/// Rust struct, which is then converted directly to and from the `jsonb` Datum.
 #[derive(Debug)]
-pub struct JsonB(pub Value);
+pub struct JsonB<T = Value>(pub T);
 
 /// A wholly Rust-[`String`][std::string::String] owned copy of a `json` type from PostgreSQL
 #[derive(Debug)]
 pub struct JsonString(pub String);
 
 /// for json
-impl FromDatum for Json {
+impl<T: DeserializeOwned> FromDatum for Json<T> {
     #[inline]
     unsafe fn from_polymorphic_datum(
         datum: pg_sys::Datum,
         is_null: bool,
         _: pg_sys::Oid,
-    ) -> Option<Json> {
+    ) -> Option<Json<T>> {
         if is_null {
             None
         } else {
@@ -51,12 +83,12 @@ impl FromDatum for Json {
 }
 
 /// for jsonb
-impl FromDatum for JsonB {
+impl<T: DeserializeOwned> FromDatum for JsonB<T> {
     unsafe fn from_polymorphic_datum(
         datum: pg_sys::Datum,
         is_null: bool,
         _: pg_sys::Oid,
-    ) -> Option<JsonB> {
+    ) -> Option<JsonB<T>> {
         if is_null {
             None
         } else {
@@ -82,7 +114,7 @@ impl FromDatum for JsonB {
                 pg_sys::pfree(detoasted as void_mut_ptr);
             }
 
-            // return the parsed serde_json::Value
+            // return the parsed value
             Some(JsonB(value))
         }
     }
@@ -120,10 +152,20 @@ impl FromDatum for JsonString {
 }
 
 /// for json
-impl IntoDatum for Json {
+impl<T: Serialize> IntoDatum for Json<T> {
     fn into_datum(self) -> Option<pg_sys::Datum> {
-        let string = serde_json::to_string(&self.0).expect("failed to serialize Json value");
-        string.into_datum()
+        // serialize straight into the `json` varlena, after room for its header
+        let mut buffer = StringInfo::new();
+        buffer.push_bytes(&[0; pg_sys::VARHDRSZ]);
+        serde_json::to_writer(&mut buffer, &self.0).expect("failed to serialize Json value");
+
+        let len = buffer.len();
+        let varlena = buffer.into_char_ptr() as *mut pg_sys::varlena;
+        unsafe {
+            // SAFETY:  the buffer starts with the VARHDRSZ bytes we reserved above
+            set_varsize(varlena, len as i32);
+        }
+        Some(varlena.into())
     }
 
     fn type_oid() -> pg_sys::Oid {
@@ -132,13 +174,13 @@ impl IntoDatum for Json {
 }
 
 /// for jsonb
-impl IntoDatum for JsonB {
+impl<T: Serialize> IntoDatum for JsonB<T> {
     fn into_datum(self) -> Option<pg_sys::Datum> {
-        let string = serde_json::to_string(&self.0).expect("failed to serialize JsonB value");
-        let cstring =
-            alloc::ffi::CString::new(string).expect("string version of jsonb is not valid UTF8");
+        // serialize straight into a Postgres-allocated buffer rather than an intermediate String
+        let mut buffer = StringInfo::new();
+        serde_json::to_writer(&mut buffer, &self.0).expect("failed to serialize JsonB value");
 
-        unsafe { direct_function_call_as_datum(pg_sys::jsonb_in, &[Some(cstring.as_ptr().into())]) }
+        unsafe { direct_function_call_as_datum(pg_sys::jsonb_in, &[Some(buffer.as_ptr().into())]) }
     }
 
     fn type_oid() -> pg_sys::Oid {
@@ -157,7 +199,7 @@ impl IntoDatum for JsonString {
     }
 }
 
-impl Serialize for Json {
+impl<T: Serialize> Serialize for Json<T> {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
         S: Serializer,
@@ -166,7 +208,7 @@ impl Serialize for Json {
     }
 }
 
-impl Serialize for JsonB {
+impl<T: Serialize> Serialize for JsonB<T> {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
         S: Serializer,
@@ -186,7 +228,7 @@ impl Serialize for JsonString {
     }
 }
 
-unsafe impl SqlTranslatable for Json {
+unsafe impl<T> SqlTranslatable for Json<T> {
     fn argument_sql() -> Result<SqlMapping, ArgumentError> {
         Ok(SqlMapping::literal("json"))
     }
@@ -195,7 +237,7 @@ unsafe impl SqlTranslatable for Json {
     }
 }
 
-unsafe impl SqlTranslatable for crate::datum::JsonB {
+unsafe impl<T> SqlTranslatable for crate::datum::JsonB<T> {
     fn argument_sql() -> Result<SqlMapping, ArgumentError> {
         Ok(SqlMapping::literal("jsonb"))
     }
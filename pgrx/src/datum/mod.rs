@@ -12,10 +12,12 @@
 mod anyarray;
 mod anyelement;
 mod array;
+mod borrowed_text;
 mod date;
 pub mod datetime_support;
 mod from;
 mod geo;
+mod hstore;
 mod inet;
 mod internal;
 mod interval;
@@ -39,10 +41,12 @@ pub use self::uuid::*;
 pub use anyarray::*;
 pub use anyelement::*;
 pub use array::*;
+pub use borrowed_text::*;
 pub use date::*;
 pub use datetime_support::*;
 pub use from::*;
 pub use geo::*;
+pub use hstore::*;
 pub use inet::*;
 pub use internal::*;
 pub use interval::*;
@@ -0,0 +1,81 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Exact conversions between [`num_bigint::BigInt`] and Postgres `NUMERIC`
+use num_bigint::{BigInt, Sign};
+
+use crate::numeric_support::digits::NumericDigits;
+use crate::numeric_support::error::Error;
+use crate::{ereport, pg_sys, AnyNumeric, FromDatum, IntoDatum, PgSqlErrorCode};
+
+impl TryFrom<BigInt> for AnyNumeric {
+    type Error = Error;
+
+    /// ## Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the value has more than the 131072 digits a `NUMERIC` can
+    /// hold before its decimal point.
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        let (sign, words) = value.to_u32_digits();
+        Ok(NumericDigits::from_words(sign == Sign::Minus, &words, 0)?.into())
+    }
+}
+
+impl TryFrom<AnyNumeric> for BigInt {
+    type Error = Error;
+
+    /// ## Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the value has a fractional part, and [`Error::Invalid`] for
+    /// `NaN` and infinities.  A value with only zeros after its decimal point, like `42.00`,
+    /// converts.
+    fn try_from(value: AnyNumeric) -> Result<Self, Self::Error> {
+        let digits = NumericDigits::try_from(&value)?;
+        let words = digits.to_words(0)?;
+        let sign = if digits.is_negative() { Sign::Minus } else { Sign::Plus };
+        Ok(BigInt::from_slice(sign, &words))
+    }
+}
+
+impl FromDatum for BigInt {
+    /// ## Panics
+    ///
+    /// Raises an ERROR if the `NUMERIC` isn't an integer
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self> {
+        let numeric = AnyNumeric::from_polymorphic_datum(datum, is_null, typoid)?;
+        match BigInt::try_from(numeric) {
+            Ok(bigint) => Some(bigint),
+            Err(e) => {
+                ereport!(ERROR, PgSqlErrorCode::ERRCODE_NUMERIC_VALUE_OUT_OF_RANGE, e.to_string());
+            }
+        }
+    }
+}
+
+impl IntoDatum for BigInt {
+    /// ## Panics
+    ///
+    /// Raises an ERROR if the value is too large for a `NUMERIC`
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        match AnyNumeric::try_from(self) {
+            Ok(numeric) => numeric.into_datum(),
+            Err(e) => {
+                ereport!(ERROR, PgSqlErrorCode::ERRCODE_NUMERIC_VALUE_OUT_OF_RANGE, e.to_string());
+            }
+        }
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        pg_sys::NUMERICOID
+    }
+}
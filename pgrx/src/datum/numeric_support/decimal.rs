@@ -0,0 +1,81 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Exact conversions between [`rust_decimal::Decimal`] and Postgres `NUMERIC`
+use rust_decimal::Decimal;
+
+use crate::numeric_support::digits::NumericDigits;
+use crate::numeric_support::error::Error;
+use crate::{ereport, pg_sys, AnyNumeric, FromDatum, IntoDatum, PgSqlErrorCode};
+
+/// The largest scale a [`Decimal`] can have
+const DECIMAL_MAX_SCALE: u32 = 28;
+
+impl From<Decimal> for AnyNumeric {
+    /// Every [`Decimal`] is exactly representable as a `NUMERIC`, with the same scale
+    fn from(value: Decimal) -> Self {
+        let mantissa = value.mantissa().unsigned_abs();
+        let words = [mantissa as u32, (mantissa >> 32) as u32, (mantissa >> 64) as u32];
+        NumericDigits::from_words(value.is_sign_negative(), &words, value.scale())
+            .expect("a Decimal always fits in a numeric")
+            .into()
+    }
+}
+
+impl TryFrom<AnyNumeric> for Decimal {
+    type Error = Error;
+
+    /// Convert a `NUMERIC` into a [`Decimal`], keeping its scale where it can
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the value doesn't fit in 96 bits, or has more than 28
+    /// significant digits after the decimal point, and [`Error::Invalid`] for `NaN` and infinities.
+    fn try_from(value: AnyNumeric) -> Result<Self, Self::Error> {
+        let digits = NumericDigits::try_from(&value)?;
+        let scale = digits.dscale().min(DECIMAL_MAX_SCALE);
+        let words = digits.to_words(scale)?;
+        match words[..] {
+            [] => Ok(Decimal::from_parts(0, 0, 0, false, scale)),
+            [lo] => Ok(Decimal::from_parts(lo, 0, 0, digits.is_negative(), scale)),
+            [lo, mid] => Ok(Decimal::from_parts(lo, mid, 0, digits.is_negative(), scale)),
+            [lo, mid, hi] => Ok(Decimal::from_parts(lo, mid, hi, digits.is_negative(), scale)),
+            _ => Err(Error::OutOfRange(format!("{value} is out of range for a Decimal"))),
+        }
+    }
+}
+
+impl FromDatum for Decimal {
+    /// ## Panics
+    ///
+    /// Raises an ERROR if the `NUMERIC` can't be represented exactly as a [`Decimal`]
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self> {
+        let numeric = AnyNumeric::from_polymorphic_datum(datum, is_null, typoid)?;
+        match Decimal::try_from(numeric) {
+            Ok(decimal) => Some(decimal),
+            Err(e) => {
+                ereport!(ERROR, PgSqlErrorCode::ERRCODE_NUMERIC_VALUE_OUT_OF_RANGE, e.to_string());
+            }
+        }
+    }
+}
+
+impl IntoDatum for Decimal {
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        AnyNumeric::from(self).into_datum()
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        pg_sys::NUMERICOID
+    }
+}
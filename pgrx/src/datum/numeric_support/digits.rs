@@ -0,0 +1,255 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Postgres' own representation of a `NUMERIC`, so Rust's decimal and big integer types can be
+//! converted to and from it exactly, without formatting and parsing strings
+//!
+//! A finite numeric is a sign, a display scale, and base-10000 digits along with the weight of the
+//! first one.  See `src/backend/utils/adt/numeric.c`, whose layout this mirrors.
+use crate::numeric_support::error::Error;
+use crate::{pg_sys, set_varsize_4b, varsize, AnyNumeric};
+
+const NBASE: u32 = 10000;
+const DEC_DIGITS: u32 = 4;
+
+const NUMERIC_SIGN_MASK: u16 = 0xC000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_SHORT: u16 = 0x8000;
+const NUMERIC_SPECIAL: u16 = 0xC000;
+const NUMERIC_DSCALE_MASK: u16 = 0x3FFF;
+
+const NUMERIC_SHORT_SIGN_MASK: u16 = 0x2000;
+const NUMERIC_SHORT_DSCALE_MASK: u16 = 0x1F80;
+const NUMERIC_SHORT_DSCALE_SHIFT: u16 = 7;
+const NUMERIC_SHORT_WEIGHT_SIGN_MASK: u16 = 0x0040;
+const NUMERIC_SHORT_WEIGHT_MASK: u16 = 0x003F;
+
+const NUMERIC_SHORT_DSCALE_MAX: u32 =
+    (NUMERIC_SHORT_DSCALE_MASK >> NUMERIC_SHORT_DSCALE_SHIFT) as u32;
+const NUMERIC_SHORT_WEIGHT_MAX: i32 = NUMERIC_SHORT_WEIGHT_MASK as i32;
+const NUMERIC_SHORT_WEIGHT_MIN: i32 = -(NUMERIC_SHORT_WEIGHT_MASK as i32 + 1);
+
+/// The largest power of ten that fits in a `u32` word
+const WORD_POW10: (u32, u32) = (1_000_000_000, 9);
+
+/// A finite numeric, normalized the way Postgres normalizes its results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct NumericDigits {
+    negative: bool,
+    /// The weight of the first digit, in powers of [`NBASE`]
+    weight: i32,
+    /// The number of decimal digits after the decimal point to display
+    dscale: u32,
+    /// Base-[`NBASE`] digits, most significant first, without leading or trailing zeros
+    digits: Vec<u16>,
+}
+
+impl NumericDigits {
+    /// The numeric `(-1 if negative) * magnitude * 10^-scale`, where `magnitude` is an unsigned
+    /// integer as little-endian `u32` words
+    pub(super) fn from_words(negative: bool, magnitude: &[u32], scale: u32) -> Result<Self, Error> {
+        if scale > NUMERIC_DSCALE_MASK as u32 {
+            return Err(Error::OutOfRange(format!("numeric display scale {scale} is too large")));
+        }
+
+        // line the decimal point up with a digit boundary
+        let mut words = magnitude.to_vec();
+        let pad = (DEC_DIGITS - scale % DEC_DIGITS) % DEC_DIGITS;
+        mul_small(&mut words, 10u32.pow(pad));
+        let fractional_digits = ((scale + pad) / DEC_DIGITS) as i32;
+
+        // least significant first, until it's reversed
+        let mut digits = Vec::new();
+        while words.iter().any(|&word| word != 0) {
+            digits.push(div_small(&mut words, NBASE) as u16);
+        }
+        let mut weight = digits.len() as i32 - 1 - fractional_digits;
+
+        let trailing_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..trailing_zeros);
+        while digits.last() == Some(&0) {
+            digits.pop();
+            weight -= 1;
+        }
+        digits.reverse();
+
+        if digits.is_empty() {
+            return Ok(NumericDigits { negative: false, weight: 0, dscale: scale, digits });
+        }
+        if weight > i16::MAX as i32 || weight < i16::MIN as i32 {
+            return Err(Error::OutOfRange("value overflows numeric format".to_string()));
+        }
+        Ok(NumericDigits { negative, weight, dscale: scale, digits })
+    }
+
+    /// The magnitude of this numeric, multiplied by `10^scale`, as little-endian `u32` words
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if that isn't an integer, so the value can't be represented
+    /// exactly with `scale` decimal digits.
+    pub(super) fn to_words(&self, scale: u32) -> Result<Vec<u32>, Error> {
+        let mut words = vec![];
+        for &digit in &self.digits {
+            mul_small(&mut words, NBASE);
+            add_small(&mut words, digit as u32);
+        }
+
+        // the weight of the last digit, in decimal digits, is where the value's decimal point is
+        let last_weight = self.weight - (self.digits.len() as i32 - 1);
+        let mut exponent = last_weight as i64 * DEC_DIGITS as i64 + scale as i64;
+        while exponent > 0 {
+            let step = exponent.min(WORD_POW10.1 as i64) as u32;
+            mul_small(&mut words, 10u32.pow(step));
+            exponent -= step as i64;
+        }
+        while exponent < 0 {
+            let step = (-exponent).min(WORD_POW10.1 as i64) as u32;
+            if div_small(&mut words, 10u32.pow(step)) != 0 {
+                return Err(Error::OutOfRange(format!(
+                    "numeric value can't be represented exactly with {scale} decimal digits"
+                )));
+            }
+            exponent += step as i64;
+        }
+
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+        Ok(words)
+    }
+
+    pub(super) fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub(super) fn dscale(&self) -> u32 {
+        self.dscale
+    }
+
+    /// Read a numeric's data, which follows its varlena header
+    pub(super) fn decode(data: &[u8]) -> Result<Self, Error> {
+        let read = |index: usize| u16::from_ne_bytes([data[index * 2], data[index * 2 + 1]]);
+        let header = read(0);
+        let (negative, weight, dscale, first_digit) = match header & NUMERIC_SIGN_MASK {
+            NUMERIC_SPECIAL => {
+                return Err(Error::Invalid("NaN and infinite numerics can't be converted".into()))
+            }
+            NUMERIC_SHORT => {
+                let weight_sign = match header & NUMERIC_SHORT_WEIGHT_SIGN_MASK {
+                    0 => 0,
+                    _ => !(NUMERIC_SHORT_WEIGHT_MASK as i32),
+                };
+                (
+                    header & NUMERIC_SHORT_SIGN_MASK != 0,
+                    weight_sign | (header & NUMERIC_SHORT_WEIGHT_MASK) as i32,
+                    ((header & NUMERIC_SHORT_DSCALE_MASK) >> NUMERIC_SHORT_DSCALE_SHIFT) as u32,
+                    1,
+                )
+            }
+            sign => (
+                sign == NUMERIC_NEG,
+                read(1) as i16 as i32,
+                (header & NUMERIC_DSCALE_MASK) as u32,
+                2,
+            ),
+        };
+        let digits = (first_digit..data.len() / 2).map(read).collect();
+        Ok(NumericDigits { negative, weight, dscale, digits })
+    }
+
+    /// Write a numeric's data, to follow its varlena header, in the short format when it fits
+    pub(super) fn encode(&self) -> Vec<u8> {
+        let mut words = Vec::with_capacity(self.digits.len() + 2);
+        if self.dscale <= NUMERIC_SHORT_DSCALE_MAX
+            && (NUMERIC_SHORT_WEIGHT_MIN..=NUMERIC_SHORT_WEIGHT_MAX).contains(&self.weight)
+        {
+            let mut header = NUMERIC_SHORT | (self.dscale as u16) << NUMERIC_SHORT_DSCALE_SHIFT;
+            if self.negative {
+                header |= NUMERIC_SHORT_SIGN_MASK;
+            }
+            if self.weight < 0 {
+                header |= NUMERIC_SHORT_WEIGHT_SIGN_MASK;
+            }
+            words.push(header | (self.weight as u16 & NUMERIC_SHORT_WEIGHT_MASK));
+        } else {
+            let sign = if self.negative { NUMERIC_NEG } else { 0 };
+            words.push(sign | self.dscale as u16 & NUMERIC_DSCALE_MASK);
+            words.push(self.weight as i16 as u16);
+        }
+        words.extend(&self.digits);
+        words.into_iter().flat_map(u16::to_ne_bytes).collect()
+    }
+}
+
+impl TryFrom<&AnyNumeric> for NumericDigits {
+    type Error = Error;
+
+    fn try_from(value: &AnyNumeric) -> Result<Self, Self::Error> {
+        unsafe {
+            // SAFETY: an AnyNumeric is always detoasted
+            let len = varsize(value.inner.cast()) - pg_sys::VARHDRSZ;
+            let data = value.inner.cast::<u8>().add(pg_sys::VARHDRSZ);
+            NumericDigits::decode(std::slice::from_raw_parts(data, len))
+        }
+    }
+}
+
+impl From<NumericDigits> for AnyNumeric {
+    fn from(value: NumericDigits) -> Self {
+        let data = value.encode();
+        unsafe {
+            let len = pg_sys::VARHDRSZ + data.len();
+            let numeric = pg_sys::palloc(len).cast::<u8>();
+            set_varsize_4b(numeric.cast(), len as i32);
+            std::ptr::copy_nonoverlapping(data.as_ptr(), numeric.add(pg_sys::VARHDRSZ), data.len());
+            AnyNumeric { inner: numeric.cast(), need_pfree: true }
+        }
+    }
+}
+
+/// `words *= factor`
+fn mul_small(words: &mut Vec<u32>, factor: u32) {
+    let mut carry = 0u64;
+    for word in words.iter_mut() {
+        let product = *word as u64 * factor as u64 + carry;
+        *word = product as u32;
+        carry = product >> 32;
+    }
+    if carry != 0 {
+        words.push(carry as u32);
+    }
+}
+
+/// `words += addend`
+fn add_small(words: &mut Vec<u32>, addend: u32) {
+    let mut carry = addend as u64;
+    for word in words.iter_mut() {
+        if carry == 0 {
+            return;
+        }
+        let sum = *word as u64 + carry;
+        *word = sum as u32;
+        carry = sum >> 32;
+    }
+    if carry != 0 {
+        words.push(carry as u32);
+    }
+}
+
+/// `words /= divisor`, returning the remainder
+fn div_small(words: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for word in words.iter_mut().rev() {
+        let dividend = remainder << 32 | *word as u64;
+        *word = (dividend / divisor as u64) as u32;
+        remainder = dividend % divisor as u64;
+    }
+    remainder as u32
+}
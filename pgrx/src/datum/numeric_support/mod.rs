@@ -9,12 +9,18 @@
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 use crate::{direct_function_call_as_datum, pg_sys, AnyNumeric};
 
+#[cfg(feature = "num-bigint")]
+mod bigint;
 pub mod cmp;
 pub mod convert;
 pub(super) mod convert_anynumeric;
 pub(super) mod convert_numeric;
 pub(super) mod convert_primitive;
 pub mod datum;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(any(feature = "rust_decimal", feature = "num-bigint"))]
+mod digits;
 pub mod error;
 pub mod hash;
 pub mod ops;
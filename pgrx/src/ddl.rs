@@ -0,0 +1,440 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Builders for `CREATE TABLE` and `CREATE INDEX` statements
+//!
+//! Names are always quoted as identifiers, values as literals, and column types are given as type
+//! OIDs or Rust types rather than text, so a builder can't be made to run SQL nobody intended.
+//! The exceptions are the methods that take an `expression`, such as
+//! [`Column::default_expression()`], whose argument is SQL and is used as is.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::ddl::{Column, CreateIndexBuilder, CreateTableBuilder};
+//!
+//! CreateTableBuilder::new("events")
+//!     .schema("analytics")
+//!     .if_not_exists()
+//!     .column(Column::new::<i64>("id").primary_key())
+//!     .column(Column::new::<String>("kind").not_null().default_literal("click"))
+//!     .column(Column::new::<TimestampWithTimeZone>("at").default_expression("now()"))
+//!     .execute()
+//!     .unwrap();
+//!
+//! CreateIndexBuilder::new("events").schema("analytics").column("kind").column("at").execute().unwrap();
+//! ```
+use core::ffi::CStr;
+
+use crate::spi::{self, quote_identifier, quote_literal, quote_qualified_identifier};
+use crate::{pg_sys, IntoDatum, Spi};
+
+fn qualified_name(schema: &Option<String>, name: &str) -> String {
+    match schema {
+        Some(schema) => quote_qualified_identifier(schema.as_str(), name),
+        None => quote_identifier(name),
+    }
+}
+
+fn column_list<S: AsRef<str>>(columns: &[S]) -> String {
+    columns.iter().map(quote_identifier).collect::<Vec<_>>().join(", ")
+}
+
+/// A column of a [`CreateTableBuilder`]
+#[derive(Debug, Clone)]
+pub struct Column {
+    name: String,
+    type_oid: pg_sys::Oid,
+    typmod: i32,
+    not_null: bool,
+    primary_key: bool,
+    unique: bool,
+    default: Option<String>,
+    references: Option<(Option<String>, String, String)>,
+}
+
+impl Column {
+    /// A column of the SQL type `T` maps to, such as `bigint` for `i64`
+    pub fn new<T: IntoDatum>(name: &str) -> Self {
+        Self::with_type(name, T::type_oid())
+    }
+
+    /// A column of the type with OID `type_oid`
+    pub fn with_type(name: &str, type_oid: pg_sys::Oid) -> Self {
+        Column {
+            name: name.to_string(),
+            type_oid,
+            typmod: -1,
+            not_null: false,
+            primary_key: false,
+            unique: false,
+            default: None,
+            references: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_oid(&self) -> pg_sys::Oid {
+        self.type_oid
+    }
+
+    /// The type modifier of the column's type, such as the `10` of `varchar(10)`, as Postgres
+    /// stores it in `pg_attribute.atttypmod`
+    pub fn typmod(mut self, typmod: i32) -> Self {
+        self.typmod = typmod;
+        self
+    }
+
+    pub fn not_null(mut self) -> Self {
+        self.not_null = true;
+        self
+    }
+
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Default the column to `value`, quoted as a literal and converted to the column's type
+    pub fn default_literal(mut self, value: impl ToString) -> Self {
+        self.default = Some(quote_literal(value.to_string()));
+        self
+    }
+
+    /// Default the column to the SQL expression `expression`, which is not escaped
+    pub fn default_expression(mut self, expression: &str) -> Self {
+        self.default = Some(expression.to_string());
+        self
+    }
+
+    /// Make the column a foreign key referencing `column` of `table`, in `schema` if given
+    pub fn references(mut self, schema: Option<&str>, table: &str, column: &str) -> Self {
+        self.references = Some((schema.map(str::to_string), table.to_string(), column.to_string()));
+        self
+    }
+
+    fn to_sql(&self) -> String {
+        let type_name = unsafe {
+            let type_name = pg_sys::format_type_with_typemod(self.type_oid, self.typmod);
+            let s = CStr::from_ptr(type_name).to_str().unwrap().to_string();
+            pg_sys::pfree(type_name.cast());
+            s
+        };
+        let mut sql = format!("{} {type_name}", quote_identifier(&self.name));
+        if self.not_null {
+            sql.push_str(" NOT NULL");
+        }
+        if let Some(default) = &self.default {
+            sql.push_str(&format!(" DEFAULT {default}"));
+        }
+        if self.primary_key {
+            sql.push_str(" PRIMARY KEY");
+        }
+        if self.unique {
+            sql.push_str(" UNIQUE");
+        }
+        if let Some((schema, table, column)) = &self.references {
+            sql.push_str(&format!(
+                " REFERENCES {} ({})",
+                qualified_name(schema, table),
+                quote_identifier(column)
+            ));
+        }
+        sql
+    }
+}
+
+/// A table constraint of a [`CreateTableBuilder`]
+#[derive(Debug, Clone)]
+enum TableConstraint {
+    PrimaryKey(Vec<String>),
+    Unique(Vec<String>),
+    Check {
+        name: Option<String>,
+        expression: String,
+    },
+    ForeignKey {
+        columns: Vec<String>,
+        schema: Option<String>,
+        table: String,
+        references: Vec<String>,
+    },
+}
+
+impl TableConstraint {
+    fn to_sql(&self) -> String {
+        match self {
+            TableConstraint::PrimaryKey(columns) => {
+                format!("PRIMARY KEY ({})", column_list(columns))
+            }
+            TableConstraint::Unique(columns) => format!("UNIQUE ({})", column_list(columns)),
+            TableConstraint::Check { name: Some(name), expression } => {
+                format!("CONSTRAINT {} CHECK ({expression})", quote_identifier(name))
+            }
+            TableConstraint::Check { name: None, expression } => format!("CHECK ({expression})"),
+            TableConstraint::ForeignKey { columns, schema, table, references } => format!(
+                "FOREIGN KEY ({}) REFERENCES {} ({})",
+                column_list(columns),
+                qualified_name(schema, table),
+                column_list(references)
+            ),
+        }
+    }
+}
+
+/// Builds and runs a `CREATE TABLE` statement
+#[derive(Debug, Clone)]
+#[must_use = "a CreateTableBuilder does nothing until it's executed"]
+pub struct CreateTableBuilder {
+    schema: Option<String>,
+    name: String,
+    temporary: bool,
+    unlogged: bool,
+    if_not_exists: bool,
+    columns: Vec<Column>,
+    constraints: Vec<TableConstraint>,
+}
+
+impl CreateTableBuilder {
+    pub fn new(name: &str) -> Self {
+        CreateTableBuilder {
+            schema: None,
+            name: name.to_string(),
+            temporary: false,
+            unlogged: false,
+            if_not_exists: false,
+            columns: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Create the table in `schema` rather than the first schema of the `search_path`
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    pub fn temporary(mut self) -> Self {
+        self.temporary = true;
+        self
+    }
+
+    pub fn unlogged(mut self) -> Self {
+        self.unlogged = true;
+        self
+    }
+
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// A primary key made of several columns.  Use [`Column::primary_key()`] for just one
+    pub fn primary_key<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.constraints.push(TableConstraint::PrimaryKey(to_strings(columns)));
+        self
+    }
+
+    pub fn unique<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.constraints.push(TableConstraint::Unique(to_strings(columns)));
+        self
+    }
+
+    /// A `CHECK` constraint on the SQL expression `expression`, which is not escaped
+    pub fn check(mut self, name: Option<&str>, expression: &str) -> Self {
+        self.constraints.push(TableConstraint::Check {
+            name: name.map(str::to_string),
+            expression: expression.to_string(),
+        });
+        self
+    }
+
+    /// A foreign key from `columns` to `references` of `table`, in `schema` if given
+    pub fn foreign_key<S: AsRef<str>>(
+        mut self,
+        columns: &[S],
+        schema: Option<&str>,
+        table: &str,
+        references: &[S],
+    ) -> Self {
+        self.constraints.push(TableConstraint::ForeignKey {
+            columns: to_strings(columns),
+            schema: schema.map(str::to_string),
+            table: table.to_string(),
+            references: to_strings(references),
+        });
+        self
+    }
+
+    /// The statement this builds
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::from("CREATE ");
+        if self.temporary {
+            sql.push_str("TEMPORARY ");
+        } else if self.unlogged {
+            sql.push_str("UNLOGGED ");
+        }
+        sql.push_str("TABLE ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        let elements: Vec<_> = self
+            .columns
+            .iter()
+            .map(Column::to_sql)
+            .chain(self.constraints.iter().map(TableConstraint::to_sql))
+            .collect();
+        sql.push_str(&format!(
+            "{} ({})",
+            qualified_name(&self.schema, &self.name),
+            elements.join(", ")
+        ));
+        sql
+    }
+
+    /// Run the statement through SPI
+    pub fn execute(&self) -> Result<(), spi::Error> {
+        Spi::run(&self.to_sql())
+    }
+}
+
+/// Builds and runs a `CREATE INDEX` statement
+#[derive(Debug, Clone)]
+#[must_use = "a CreateIndexBuilder does nothing until it's executed"]
+pub struct CreateIndexBuilder {
+    name: Option<String>,
+    schema: Option<String>,
+    table: String,
+    unique: bool,
+    if_not_exists: bool,
+    method: Option<String>,
+    keys: Vec<String>,
+    include: Vec<String>,
+    predicate: Option<String>,
+}
+
+impl CreateIndexBuilder {
+    /// An index on `table`.  The index is created in the table's schema
+    pub fn new(table: &str) -> Self {
+        CreateIndexBuilder {
+            name: None,
+            schema: None,
+            table: table.to_string(),
+            unique: false,
+            if_not_exists: false,
+            method: None,
+            keys: Vec::new(),
+            include: Vec::new(),
+            predicate: None,
+        }
+    }
+
+    /// The schema of the table, rather than whichever the `search_path` finds it in
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Name the index, rather than letting Postgres choose a name
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Do nothing if an index of the same name exists.  Requires a [`name()`](Self::name)
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// The index access method, such as `btree` (the default), `hash`, `gin`, or `gist`
+    pub fn using(mut self, method: &str) -> Self {
+        self.method = Some(method.to_string());
+        self
+    }
+
+    /// Index `column`
+    pub fn column(mut self, column: &str) -> Self {
+        self.keys.push(quote_identifier(column));
+        self
+    }
+
+    /// Index the SQL expression `expression`, which is not escaped
+    pub fn expression(mut self, expression: &str) -> Self {
+        self.keys.push(format!("({expression})"));
+        self
+    }
+
+    /// Store `column` in the index without indexing it, for index-only scans
+    pub fn include(mut self, column: &str) -> Self {
+        self.include.push(column.to_string());
+        self
+    }
+
+    /// Make this a partial index of the rows where the SQL expression `predicate`, which is not
+    /// escaped, is true
+    pub fn predicate(mut self, predicate: &str) -> Self {
+        self.predicate = Some(predicate.to_string());
+        self
+    }
+
+    /// The statement this builds
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::from("CREATE ");
+        if self.unique {
+            sql.push_str("UNIQUE ");
+        }
+        sql.push_str("INDEX ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        if let Some(name) = &self.name {
+            sql.push_str(&quote_identifier(name));
+            sql.push(' ');
+        }
+        sql.push_str(&format!("ON {}", qualified_name(&self.schema, &self.table)));
+        if let Some(method) = &self.method {
+            sql.push_str(&format!(" USING {}", quote_identifier(method)));
+        }
+        sql.push_str(&format!(" ({})", self.keys.join(", ")));
+        if !self.include.is_empty() {
+            sql.push_str(&format!(" INCLUDE ({})", column_list(&self.include)));
+        }
+        if let Some(predicate) = &self.predicate {
+            sql.push_str(&format!(" WHERE {predicate}"));
+        }
+        sql
+    }
+
+    /// Run the statement through SPI
+    pub fn execute(&self) -> Result<(), spi::Error> {
+        Spi::run(&self.to_sql())
+    }
+}
+
+fn to_strings<S: AsRef<str>>(strings: &[S]) -> Vec<String> {
+    strings.iter().map(|s| s.as_ref().to_string()).collect()
+}
@@ -8,11 +8,35 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 //! Helper functions for working with Postgres `enum` types
+//!
+//! Type and label lookups are cached for the rest of the session, and flushed whenever `pg_type` or
+//! `pg_enum` change, so that converting every element of an array, or every attribute of a
+//! composite, doesn't repeat the same catalog lookups.
 
 use crate::pg_sys::GETSTRUCT;
+use crate::session_cache::SessionCache;
 use crate::{ereport, pg_sys, PgLogLevel, PgSqlErrorCode};
 
+const ENUM_SYSCACHES: &[pg_sys::SysCacheIdentifier] =
+    &[pg_sys::SysCacheIdentifier_TYPEOID, pg_sys::SysCacheIdentifier_ENUMOID];
+
+static ENUM_LABELS: SessionCache<(pg_sys::Oid, String), pg_sys::Oid> =
+    SessionCache::with_syscaches(ENUM_SYSCACHES);
+static ENUM_VALUES: SessionCache<pg_sys::Oid, (String, pg_sys::Oid, f32)> =
+    SessionCache::with_syscaches(ENUM_SYSCACHES);
+
+/// The oid of the enum type named `typname`, as resolved by `regtypein` against the current
+/// `search_path`, once per session
+pub fn enum_type_oid(typname: &str) -> pg_sys::Oid {
+    crate::wrappers::type_oid_or_error(typname)
+}
+
+/// The label, type oid, and sort order of the enum value `enumval`
 pub fn lookup_enum_by_oid(enumval: pg_sys::Oid) -> (String, pg_sys::Oid, f32) {
+    ENUM_VALUES.get_or_insert_with(enumval, |enumval| lookup_enum_by_oid_uncached(*enumval))
+}
+
+fn lookup_enum_by_oid_uncached(enumval: pg_sys::Oid) -> (String, pg_sys::Oid, f32) {
     let tup = unsafe {
         pg_sys::SearchSysCache(
             pg_sys::SysCacheIdentifier_ENUMOID as i32,
@@ -50,13 +74,26 @@ pub fn lookup_enum_by_oid(enumval: pg_sys::Oid) -> (String, pg_sys::Oid, f32) {
     result
 }
 
+/// The enum value, as a `Datum`, of `label` in the enum type named `typname`
 pub fn lookup_enum_by_label(typname: &str, label: &str) -> pg_sys::Datum {
-    let enumtypoid = crate::regtypein(typname);
+    let enumtypoid = enum_type_oid(typname);
 
     if enumtypoid == pg_sys::InvalidOid {
         panic!("could not locate type oid for type: {}", typname);
     }
 
+    pg_sys::Datum::from(
+        ENUM_LABELS.get_or_insert_with((enumtypoid, label.to_string()), |(enumtypoid, label)| {
+            lookup_enum_by_label_uncached(typname, *enumtypoid, label)
+        }),
+    )
+}
+
+fn lookup_enum_by_label_uncached(
+    typname: &str,
+    enumtypoid: pg_sys::Oid,
+    label: &str,
+) -> pg_sys::Oid {
     let tup = unsafe {
         let label =
             alloc::ffi::CString::new(label).expect("failed to convert enum typname to a CString");
@@ -80,7 +117,7 @@ pub fn lookup_enum_by_label(typname: &str, label: &str) -> pg_sys::Datum {
     unsafe {
         let oid = extract_enum_oid(tup);
         pg_sys::ReleaseSysCache(tup);
-        pg_sys::Datum::from(oid)
+        oid
     }
 }
 
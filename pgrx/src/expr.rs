@@ -0,0 +1,237 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Compile a SQL expression once and evaluate it against many tuples, without going through SPI
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{ExprEvaluator, PgTupleDesc};
+//!
+//! let tupdesc = PgTupleDesc::for_composite_type("point3d").unwrap();
+//! let mut filter = ExprEvaluator::compile("x + y > 10 AND z IS NOT NULL", tupdesc).unwrap();
+//!
+//! let mut point = PgHeapTuple::new_composite_type("point3d").unwrap();
+//! point.set_by_name("x", 7).unwrap();
+//! point.set_by_name("y", 8).unwrap();
+//! point.set_by_name("z", 0).unwrap();
+//! assert!(unsafe { filter.matches(&point) }.unwrap());
+//! ```
+use crate::heap_tuple::PgHeapTuple;
+use crate::list::PgList;
+use crate::spi::quote_identifier;
+use crate::{pg_sys, FromDatum, IntoDatum, PgMemoryContexts, PgTupleDesc, WhoAllocated};
+use core::ffi::CStr;
+
+/// Reasons an [`ExprEvaluator`] can't compile or evaluate an expression
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExprError {
+    /// The text wasn't a single scalar expression.  Aggregates, window functions, set-returning
+    /// functions, and sub-selects aren't allowed
+    #[error("not a scalar expression: {0}")]
+    NotAScalarExpression(&'static str),
+
+    /// The expression's result type isn't compatible with the requested Rust type
+    #[error("expression returns type oid `{0}`, which is not compatible with type oid `{1}`")]
+    IncompatibleType(pg_sys::Oid, pg_sys::Oid),
+}
+
+/// A SQL expression compiled against a [`PgTupleDesc`], whose attributes can be referenced by name
+///
+/// The expression is parsed, planned (which folds constants), and compiled into an `ExprState`
+/// once, in the `CurrentMemoryContext`.  Evaluating it is then about as cheap as it is for the
+/// executor to evaluate a `WHERE` clause.
+///
+/// Syntax and type errors in the expression, as well as errors raised while evaluating it (such as
+/// division by zero), are raised as normal Postgres ERRORs.
+pub struct ExprEvaluator<'a> {
+    state: *mut pg_sys::ExprState,
+    econtext: *mut pg_sys::ExprContext,
+    slot: *mut pg_sys::TupleTableSlot,
+    result_type: pg_sys::Oid,
+    tupdesc: PgTupleDesc<'a>,
+}
+
+impl<'a> ExprEvaluator<'a> {
+    /// Compile `expr` so that it can be evaluated against tuples described by `tupdesc`
+    pub fn compile(expr: &str, tupdesc: PgTupleDesc<'a>) -> Result<Self, ExprError> {
+        // Let the parser resolve attribute names by selecting the expression from a subquery with
+        // the same shape as the tuple.  The resulting Vars reference that subquery's attributes by
+        // number, which are exactly the attributes of the tuples we'll put in the scan slot
+        let columns = tupdesc
+            .iter()
+            .enumerate()
+            .map(|(i, att)| {
+                if att.is_dropped() {
+                    format!("NULL::int4 AS \"........pg.dropped.{}........\"", i + 1)
+                } else {
+                    let typname = unsafe {
+                        let typname = pg_sys::format_type_with_typemod(att.atttypid, att.atttypmod);
+                        let s = CStr::from_ptr(typname).to_str().unwrap().to_string();
+                        pg_sys::pfree(typname.cast());
+                        s
+                    };
+                    format!("NULL::{typname} AS {}", quote_identifier(att.name()))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = alloc::ffi::CString::new(format!("SELECT ({expr}) FROM (SELECT {columns}) AS t"))
+            .expect("expression cannot contain NULL bytes");
+
+        unsafe {
+            let raw_stmts =
+                PgList::<pg_sys::RawStmt>::from_pg(pg_sys::pg_parse_query(sql.as_ptr()));
+            if raw_stmts.len() != 1 {
+                return Err(ExprError::NotAScalarExpression("multiple statements"));
+            }
+
+            #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13", feature = "pg14"))]
+            let query = pg_sys::parse_analyze(
+                raw_stmts.get_ptr(0).unwrap(),
+                sql.as_ptr(),
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+            );
+            #[cfg(any(feature = "pg15", feature = "pg16"))]
+            let query = pg_sys::parse_analyze_fixedparams(
+                raw_stmts.get_ptr(0).unwrap(),
+                sql.as_ptr(),
+                std::ptr::null(),
+                0,
+                std::ptr::null_mut(),
+            );
+
+            let target_list = validate(&*query)?;
+            let target = target_list.get_ptr(0).unwrap();
+            let expr = pg_sys::expression_planner((*target).expr);
+            let result_type = pg_sys::exprType(expr.cast());
+            let state = pg_sys::ExecInitExpr(expr, std::ptr::null_mut());
+
+            #[cfg(feature = "pg11")]
+            let slot = pg_sys::MakeSingleTupleTableSlot(tupdesc.as_ptr());
+            #[cfg(not(feature = "pg11"))]
+            let slot = pg_sys::MakeSingleTupleTableSlot(tupdesc.as_ptr(), &pg_sys::TTSOpsHeapTuple);
+
+            let econtext = pg_sys::CreateStandaloneExprContext();
+            (*econtext).ecxt_scantuple = slot;
+
+            Ok(ExprEvaluator { state, econtext, slot, result_type, tupdesc })
+        }
+    }
+
+    /// The OID of the type the expression evaluates to
+    pub fn result_type(&self) -> pg_sys::Oid {
+        self.result_type
+    }
+
+    /// The [`PgTupleDesc`] this expression was compiled against
+    pub fn tupdesc(&self) -> &PgTupleDesc<'a> {
+        &self.tupdesc
+    }
+
+    /// Evaluate the expression against `tuple`
+    ///
+    /// Pass-by-reference results are allocated in memory that's reset at the start of the next
+    /// evaluation, so `T` must not borrow from them beyond that point.
+    ///
+    /// ## Safety
+    ///
+    /// `tuple` must be described by the [`PgTupleDesc`] this expression was compiled against
+    pub unsafe fn evaluate<T: FromDatum + IntoDatum, AllocatedBy: WhoAllocated>(
+        &mut self,
+        tuple: &PgHeapTuple<'_, AllocatedBy>,
+    ) -> Result<Option<T>, ExprError> {
+        if !T::is_compatible_with(self.result_type) {
+            return Err(ExprError::IncompatibleType(self.result_type, T::type_oid()));
+        }
+
+        let per_tuple_memory = (*self.econtext).ecxt_per_tuple_memory;
+        pg_sys::MemoryContextReset(per_tuple_memory);
+
+        #[cfg(feature = "pg11")]
+        pg_sys::ExecStoreTuple(tuple.as_ptr(), self.slot, pg_sys::InvalidBuffer as _, false);
+        #[cfg(not(feature = "pg11"))]
+        pg_sys::ExecStoreHeapTuple(tuple.as_ptr(), self.slot, false);
+
+        // this is what the `ExecEvalExprSwitchContext()` static inline function does
+        let mut is_null = false;
+        let datum = PgMemoryContexts::For(per_tuple_memory).switch_to(|_| {
+            let evalfunc = (*self.state).evalfunc.expect("ExprState has no evalfunc");
+            pg_sys::ffi::pg_guard_ffi_boundary(|| evalfunc(self.state, self.econtext, &mut is_null))
+        });
+
+        Ok(T::from_polymorphic_datum(datum, is_null, self.result_type))
+    }
+
+    /// Evaluate a boolean expression against `tuple`, treating `NULL` as `false`, like a `WHERE`
+    /// clause does
+    ///
+    /// ## Safety
+    ///
+    /// `tuple` must be described by the [`PgTupleDesc`] this expression was compiled against
+    pub unsafe fn matches<AllocatedBy: WhoAllocated>(
+        &mut self,
+        tuple: &PgHeapTuple<'_, AllocatedBy>,
+    ) -> Result<bool, ExprError> {
+        Ok(self.evaluate::<bool, _>(tuple)?.unwrap_or(false))
+    }
+}
+
+impl<'a> Drop for ExprEvaluator<'a> {
+    fn drop(&mut self) {
+        // if we're unwinding then the transaction is going to abort and Postgres will release
+        // everything along with the memory context it was allocated in
+        if !std::thread::panicking() {
+            unsafe {
+                pg_sys::FreeExprContext(self.econtext, true);
+                pg_sys::ExecDropSingleTupleTableSlot(self.slot);
+            }
+        }
+    }
+}
+
+/// Make sure that the only thing the user-provided text contributed to the query is a single,
+/// scalar, expression.  Returns the query's target list
+unsafe fn validate(query: &pg_sys::Query) -> Result<PgList<pg_sys::TargetEntry>, ExprError> {
+    if query.commandType != pg_sys::CmdType_CMD_SELECT {
+        return Err(ExprError::NotAScalarExpression("not a SELECT"));
+    }
+    if query.hasAggs {
+        return Err(ExprError::NotAScalarExpression("aggregate functions are not allowed"));
+    }
+    if query.hasWindowFuncs {
+        return Err(ExprError::NotAScalarExpression("window functions are not allowed"));
+    }
+    if query.hasTargetSRFs {
+        return Err(ExprError::NotAScalarExpression("set-returning functions are not allowed"));
+    }
+    if query.hasSubLinks {
+        return Err(ExprError::NotAScalarExpression("sub-selects are not allowed"));
+    }
+
+    let target_list = PgList::<pg_sys::TargetEntry>::from_pg(query.targetList);
+    if target_list.len() != 1
+        || PgList::<pg_sys::RangeTblEntry>::from_pg(query.rtable).len() != 1
+        || !(*query.jointree).quals.is_null()
+        || !query.cteList.is_null()
+        || !query.groupClause.is_null()
+        || !query.groupingSets.is_null()
+        || !query.havingQual.is_null()
+        || !query.distinctClause.is_null()
+        || !query.sortClause.is_null()
+        || !query.limitCount.is_null()
+        || !query.limitOffset.is_null()
+        || !query.setOperations.is_null()
+    {
+        return Err(ExprError::NotAScalarExpression("unexpected query clauses"));
+    }
+
+    Ok(target_list)
+}
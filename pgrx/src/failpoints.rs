@@ -0,0 +1,245 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Deterministic failure injection for testing error paths.
+//!
+//! Extension code marks the places where a failure could happen with [`fail_point!`]:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//!
+//! fn flush_buffer() {
+//!     pgrx::fail_point!("before_flush");
+//!     // ...
+//! }
+//! ```
+//!
+//! A failpoint does nothing until a test arms it, either from Rust with [`enable`] or from SQL
+//! with the `pgrx.failpoints` setting (see [`define_failpoints_guc`]):
+//!
+//! ```sql
+//! SET pgrx.failpoints = 'before_flush=error; after_flush=1*sleep(500)';
+//! ```
+//!
+//! An armed failpoint performs one of these actions when it's reached:
+//!
+//! | action       | effect                                                        |
+//! |--------------|---------------------------------------------------------------|
+//! | `off`        | nothing                                                       |
+//! | `error`      | raise an `ERROR`                                              |
+//! | `panic`      | `panic!()`, which pgrx turns into an `ERROR` after unwinding  |
+//! | `sleep(MS)`  | sleep for `MS` milliseconds, still servicing interrupts       |
+//!
+//! Prefixing an action with `N*`, as in `2*error`, performs it only the first `N` times the
+//! failpoint is reached, after which it's `off` again.  That makes it possible to exercise the
+//! cleanup of transaction callbacks, resource owners, and the like on exactly the path under
+//! test.
+//!
+//! All of this needs pgrx's `failpoints` feature.  Without it, [`fail_point!`] compiles to nothing,
+//! so failpoints can stay in production code at no cost.
+use crate::guc::{GucContext, GucFlags, GucRegistry, GucSetting};
+use crate::pg_sys;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Mark a failpoint named `name`, which tests can arm to fail here.  See the
+/// [module documentation](crate::failpoints).
+#[macro_export]
+macro_rules! fail_point {
+    ($name:expr) => {
+        $crate::failpoints::eval($name)
+    };
+}
+
+/// What an armed failpoint does when it's reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailAction {
+    Off,
+    Error,
+    Panic,
+    Sleep(Duration),
+}
+
+/// A failpoint specification that couldn't be parsed
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid failpoint specification `{0}`")]
+pub struct InvalidFailpoint(pub String);
+
+#[derive(Debug, Clone, Copy)]
+struct Failpoint {
+    action: FailAction,
+    remaining: Option<u32>,
+}
+
+impl Failpoint {
+    /// Parse `[N*]ACTION`
+    fn parse(spec: &str) -> Result<Self, InvalidFailpoint> {
+        let invalid = || InvalidFailpoint(spec.to_string());
+        let (remaining, action) = match spec.trim().split_once('*') {
+            Some((count, action)) => (Some(count.trim().parse().map_err(|_| invalid())?), action),
+            None => (None, spec),
+        };
+        let action = match action.trim() {
+            "off" => FailAction::Off,
+            "error" => FailAction::Error,
+            "panic" => FailAction::Panic,
+            other => {
+                let millis = other
+                    .strip_prefix("sleep(")
+                    .and_then(|rest| rest.strip_suffix(')'))
+                    .and_then(|millis| millis.trim().parse().ok())
+                    .ok_or_else(invalid)?;
+                FailAction::Sleep(Duration::from_millis(millis))
+            }
+        };
+        Ok(Failpoint { action, remaining })
+    }
+
+    /// The action to perform this time the failpoint is reached
+    fn fire(&mut self) -> FailAction {
+        match &mut self.remaining {
+            None => self.action,
+            Some(0) => FailAction::Off,
+            Some(remaining) => {
+                *remaining -= 1;
+                self.action
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Failpoints {
+    /// armed with [`enable`], which take precedence over the GUC
+    enabled: HashMap<String, Failpoint>,
+    /// the last value of `pgrx.failpoints` that was parsed, and what it armed
+    guc: Option<(CString, HashMap<String, Failpoint>)>,
+}
+
+static FAILPOINTS: Lazy<Mutex<Failpoints>> = Lazy::new(Default::default);
+static ANY_ENABLED: AtomicBool = AtomicBool::new(false);
+static FAILPOINTS_GUC: GucSetting<Option<&'static CStr>> =
+    GucSetting::<Option<&'static CStr>>::new(None);
+static FAILPOINTS_GUC_DEFINED: AtomicBool = AtomicBool::new(false);
+
+/// Define the `pgrx.failpoints` setting, a `;`-separated list of `name=[N*]ACTION` pairs arming
+/// failpoints from SQL.  Only superusers may change it.
+///
+/// This should be called from `_PG_init()`, and only one extension loaded into a backend can
+/// define it.
+pub fn define_failpoints_guc() {
+    GucRegistry::define_string_guc(
+        "pgrx.failpoints",
+        "Failpoints to arm, as `name=action` pairs separated by `;`",
+        "An action is `off`, `error`, `panic`, or `sleep(MS)`, optionally prefixed by `N*` to \
+         perform it only the first N times.",
+        &FAILPOINTS_GUC,
+        GucContext::Suset,
+        GucFlags::default(),
+    );
+    FAILPOINTS_GUC_DEFINED.store(true, Ordering::Relaxed);
+}
+
+/// Arm the failpoint `name` with `[N*]ACTION`, overriding anything `pgrx.failpoints` says about
+/// it
+pub fn enable(name: &str, spec: &str) -> Result<(), InvalidFailpoint> {
+    let failpoint = Failpoint::parse(spec)?;
+    lock().enabled.insert(name.to_string(), failpoint);
+    ANY_ENABLED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Disarm the failpoint `name` that was armed with [`enable`]
+pub fn disable(name: &str) {
+    let mut failpoints = lock();
+    failpoints.enabled.remove(name);
+    ANY_ENABLED.store(!failpoints.enabled.is_empty(), Ordering::Relaxed);
+}
+
+/// Disarm every failpoint that was armed with [`enable`]
+pub fn disable_all() {
+    lock().enabled.clear();
+    ANY_ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Reach the failpoint `name`, performing its action if it's armed.  This is what
+/// [`fail_point!`] calls.
+pub fn eval(name: &str) {
+    if !ANY_ENABLED.load(Ordering::Relaxed) && guc_value().is_none() {
+        return;
+    }
+
+    let action = {
+        let mut failpoints = lock();
+        failpoints.refresh_guc(guc_value());
+        let Failpoints { enabled, guc } = &mut *failpoints;
+        let failpoint = enabled
+            .get_mut(name)
+            .or_else(|| guc.as_mut().and_then(|(_, armed)| armed.get_mut(name)));
+        match failpoint {
+            Some(failpoint) => failpoint.fire(),
+            None => return,
+        }
+    };
+
+    match action {
+        FailAction::Off => {}
+        FailAction::Error => crate::error!("failpoint `{name}` triggered"),
+        FailAction::Panic => panic!("failpoint `{name}` triggered"),
+        FailAction::Sleep(duration) => {
+            let until = Instant::now() + duration;
+            while let Some(left) = until.checked_duration_since(Instant::now()) {
+                std::thread::sleep(left.min(Duration::from_millis(10)));
+                pg_sys::check_for_interrupts!();
+            }
+        }
+    }
+}
+
+impl Failpoints {
+    fn refresh_guc(&mut self, value: Option<&CStr>) {
+        let Some(value) = value else {
+            self.guc = None;
+            return;
+        };
+        if matches!(&self.guc, Some((last, _)) if last.as_c_str() == value) {
+            return;
+        }
+
+        let mut armed = HashMap::new();
+        for entry in value.to_string_lossy().split(';').filter(|entry| !entry.trim().is_empty()) {
+            let parsed = entry
+                .split_once('=')
+                .ok_or_else(|| InvalidFailpoint(entry.to_string()))
+                .and_then(|(name, spec)| Ok((name.trim().to_string(), Failpoint::parse(spec)?)));
+            match parsed {
+                Ok((name, failpoint)) => {
+                    armed.insert(name, failpoint);
+                }
+                Err(e) => crate::warning!("ignoring pgrx.failpoints entry: {e}"),
+            }
+        }
+        self.guc = Some((value.to_owned(), armed));
+    }
+}
+
+fn guc_value() -> Option<&'static CStr> {
+    if !FAILPOINTS_GUC_DEFINED.load(Ordering::Relaxed) {
+        return None;
+    }
+    FAILPOINTS_GUC.get().filter(|value| !value.to_bytes().is_empty())
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Failpoints> {
+    FAILPOINTS.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
@@ -11,7 +11,11 @@
 //!
 //! Typically these functions are not necessary to call directly as they're used behind
 //! the scenes by the code generated by the `#[pg_extern]` macro.
-use crate::{pg_sys, void_mut_ptr, FromDatum, PgBox, PgMemoryContexts};
+use crate::datum::lookup_type_name;
+use crate::{
+    ereport, pg_sys, void_mut_ptr, FromDatum, IntoDatum, PgBox, PgLogLevel, PgMemoryContexts,
+    PgSqlErrorCode, SessionCache,
+};
 
 /// A macro for specifying default argument values so they get properly translated to SQL in
 /// `CREATE FUNCTION` statements
@@ -119,6 +123,47 @@ mod pg_11 {
         }
     }
 
+    /// Get a numbered argument of a `STRICT` function as the specified Rust type.
+    ///
+    /// Postgres never calls a `STRICT` function with a NULL argument, so unlike [`pg_getarg`] this
+    /// neither checks the argument's null flag nor wraps the value in an [`Option`].  It's what
+    /// `#[pg_extern(strict)]` uses for non-`Option` arguments.
+    ///
+    /// Only the executor enforces `STRICT`, though.  Calling the function's wrapper directly, as
+    /// `DirectFunctionCall` and [`direct_pg_extern_function_call`] do, or through a hand-written
+    /// `CREATE FUNCTION` that leaves out `STRICT`, hands it whatever arguments it's given, and a
+    /// NULL one is read as if it were a value.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as we cannot ensure the `fcinfo` argument is a valid
+    /// [`pg_sys::FunctionCallInfo`] pointer, that it has at least `num + 1` arguments, nor that
+    /// the argument is not NULL.  This is your responsibility.
+    ///
+    /// We also cannot ensure that the specified Rust type `T` is compatible with whatever the
+    /// underlying datum is at the argument `num` position.  This too, is your responsibility
+    #[inline(always)]
+    pub unsafe fn pg_getarg_strict<T: FromDatum>(
+        fcinfo: pg_sys::FunctionCallInfo,
+        num: usize,
+    ) -> T {
+        // SAFETY:  User has asserted that `fcinfo` is valid and the argument isn't NULL
+        let datum = unsafe { (*fcinfo).arg[num] };
+        debug_assert!(!pg_arg_is_null(fcinfo, num), "argument {num} is NULL");
+        let value = unsafe {
+            // SAFETY:  User has asserted that the desired Rust type `T` is compatible with the
+            // underlying Datum
+            if T::GET_TYPOID {
+                T::from_polymorphic_datum(datum, false, super::pg_getarg_type(fcinfo, num))
+            } else {
+                T::from_datum(datum, false)
+            }
+        };
+        // SAFETY:  `from_datum` only returns `None` for a NULL, and the caller has asserted that
+        // this argument isn't one
+        unsafe { value.unwrap_unchecked() }
+    }
+
     /// Is the specified argument for a `PG_FUNCTION_INFO_V1` function NULL?
     ///
     /// # Safety
@@ -230,6 +275,47 @@ mod pg_12_13_14_15 {
         }
     }
 
+    /// Get a numbered argument of a `STRICT` function as the specified Rust type.
+    ///
+    /// Postgres never calls a `STRICT` function with a NULL argument, so unlike [`pg_getarg`] this
+    /// neither checks the argument's null flag nor wraps the value in an [`Option`].  It's what
+    /// `#[pg_extern(strict)]` uses for non-`Option` arguments.
+    ///
+    /// Only the executor enforces `STRICT`, though.  Calling the function's wrapper directly, as
+    /// `DirectFunctionCall` and [`direct_pg_extern_function_call`] do, or through a hand-written
+    /// `CREATE FUNCTION` that leaves out `STRICT`, hands it whatever arguments it's given, and a
+    /// NULL one is read as if it were a value.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as we cannot ensure the `fcinfo` argument is a valid
+    /// [`pg_sys::FunctionCallInfo`] pointer, that it has at least `num + 1` arguments, nor that
+    /// the argument is not NULL.  This is your responsibility.
+    ///
+    /// We also cannot ensure that the specified Rust type `T` is compatible with whatever the
+    /// underlying datum is at the argument `num` position.  This too, is your responsibility
+    #[inline(always)]
+    pub unsafe fn pg_getarg_strict<T: FromDatum>(
+        fcinfo: pg_sys::FunctionCallInfo,
+        num: usize,
+    ) -> T {
+        // SAFETY:  User has asserted that `fcinfo` is valid, has the argument, and it isn't NULL
+        let datum = unsafe { (*(*fcinfo).args.as_ptr().add(num)).value };
+        debug_assert!(!pg_arg_is_null(fcinfo, num), "argument {num} is NULL");
+        let value = unsafe {
+            // SAFETY:  User has asserted that the desired Rust type `T` is compatible with the
+            // underlying Datum
+            if T::GET_TYPOID {
+                T::from_polymorphic_datum(datum, false, super::pg_getarg_type(fcinfo, num))
+            } else {
+                T::from_datum(datum, false)
+            }
+        };
+        // SAFETY:  `from_datum` only returns `None` for a NULL, and the caller has asserted that
+        // this argument isn't one
+        unsafe { value.unwrap_unchecked() }
+    }
+
     /// Is the specified argument for a `PG_FUNCTION_INFO_V1` function NULL?
     ///
     /// # Safety
@@ -366,6 +452,50 @@ pub unsafe fn pg_getarg_type(fcinfo: pg_sys::FunctionCallInfo, num: usize) -> pg
     pg_sys::get_fn_expr_argtype(fcinfo.as_ref().unwrap().flinfo, num as std::os::raw::c_int)
 }
 
+/// The type Postgres resolved the return type of this call to, which is only useful if the
+/// function is declared to return a polymorphic type such as `anyelement`.
+///
+/// Returns [`pg_sys::InvalidOid`] if it can't be determined, such as for a direct call through
+/// `DirectFunctionCall`.
+///
+/// # Safety
+///
+/// This function is unsafe as we cannot ensure the `fcinfo` argument is a valid
+/// [`pg_sys::FunctionCallInfo`] pointer.  This is your responsibility.
+#[inline]
+pub unsafe fn pg_return_type(fcinfo: pg_sys::FunctionCallInfo) -> pg_sys::Oid {
+    pg_sys::get_fn_expr_rettype(fcinfo.as_ref().unwrap().flinfo)
+}
+
+/// Raise an ERROR if `value` is of a polymorphic type, such as [`AnyElement`](crate::AnyElement),
+/// and holds a Datum of another type than this call was resolved to return.
+///
+/// `#[pg_extern]` calls this on the values its functions return.
+///
+/// # Safety
+///
+/// This function is unsafe as we cannot ensure the `fcinfo` argument is a valid
+/// [`pg_sys::FunctionCallInfo`] pointer.  This is your responsibility.
+#[inline]
+pub unsafe fn pg_check_return_type<T: IntoDatum>(fcinfo: pg_sys::FunctionCallInfo, value: &T) {
+    let Some(typoid) = value.polymorphic_type_oid() else { return };
+    let expected = pg_return_type(fcinfo);
+    // Postgres passes a domain to a polymorphic argument as its base type
+    if expected != pg_sys::InvalidOid
+        && pg_sys::getBaseType(typoid) != pg_sys::getBaseType(expected)
+    {
+        ereport!(
+            PgLogLevel::ERROR,
+            PgSqlErrorCode::ERRCODE_DATATYPE_MISMATCH,
+            format!(
+                "function returned type {} but its return type resolved to {}",
+                lookup_type_name(typoid),
+                lookup_type_name(expected)
+            )
+        );
+    }
+}
+
 /// This is intended for Postgres functions that take an actual `cstring` argument, not for getting
 /// a varlena argument type as a CStr.
 ///
@@ -509,7 +639,7 @@ pub unsafe fn direct_function_call_as_datum(
 }
 
 #[cfg(feature = "pg11")]
-unsafe fn direct_function_call_as_datum_internal(
+pub(crate) unsafe fn direct_function_call_as_datum_internal(
     func: impl FnOnce(pg_sys::FunctionCallInfo) -> pg_sys::Datum,
     args: &[Option<pg_sys::Datum>],
 ) -> Option<pg_sys::Datum> {
@@ -537,7 +667,7 @@ unsafe fn direct_function_call_as_datum_internal(
 }
 
 #[cfg(not(feature = "pg11"))]
-unsafe fn direct_function_call_as_datum_internal(
+pub(crate) unsafe fn direct_function_call_as_datum_internal(
     func: impl FnOnce(pg_sys::FunctionCallInfo) -> pg_sys::Datum,
     args: &[Option<pg_sys::Datum>],
 ) -> Option<pg_sys::Datum> {
@@ -582,6 +712,125 @@ pub unsafe fn direct_pg_extern_function_call_as_datum(
     direct_function_call_as_datum_internal(|fcinfo| func(fcinfo), args)
 }
 
+/// Call the SQL function `$name` with the given arguments, which can be anything that implements
+/// [`IntoDatum`], including `Option`s for `NULL`s
+///
+/// This is a shortcut for [`call_function`] that works out the argument types for you.  Like it, the
+/// result type must be given, usually by annotating the variable it's assigned to.
+///
+/// ## Examples
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::call_pg_function;
+///
+/// let len: Option<i32> = call_pg_function!("pg_catalog.length", "hello");
+/// assert_eq!(len, Some(5));
+///
+/// // strict functions return NULL when given a NULL
+/// let len: Option<i32> = call_pg_function!("length", None::<&str>);
+/// assert_eq!(len, None);
+/// ```
+#[macro_export]
+macro_rules! call_pg_function {
+    ($name:expr $(, $arg:expr)* $(,)?) => {
+        $crate::fcinfo::call_function($name, &[$($crate::fcinfo::FunctionArgument::from($arg)),*])
+    };
+}
+
+/// An argument to [`call_function`], which remembers its type so the function can be found
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionArgument {
+    pub type_oid: pg_sys::Oid,
+    pub datum: Option<pg_sys::Datum>,
+}
+
+impl<T: IntoDatum> From<T> for FunctionArgument {
+    fn from(value: T) -> Self {
+        FunctionArgument { type_oid: T::type_oid(), datum: value.into_datum() }
+    }
+}
+
+/// The OIDs of the schema-qualified functions [`call_function`] has looked up, keyed by their name
+/// and argument types, which are forgotten whenever `pg_proc` changes
+static FUNCTION_OIDS: SessionCache<String, pg_sys::Oid> = SessionCache::with_syscaches(&[
+    pg_sys::SysCacheIdentifier_PROCOID,
+    pg_sys::SysCacheIdentifier_PROCNAMEARGSNSP,
+]);
+
+/// Call the SQL function named `name`, which may be schema-qualified, through the function manager,
+/// without going through SPI or the planner
+///
+/// `name` is parsed as it would be in SQL, so unquoted parts are folded to lower case and a
+/// mixed-case name or one containing a dot must be double-quoted, as in `r#""MySchema".my_fn"#`.
+///
+/// This is how extension code can reuse the functions it (or anything else) exposes to SQL, such
+/// as those created by `#[pg_extern]`.  The function is found by its exact argument types, as
+/// [`FunctionArgument`]s record them, so there is no implicit casting and polymorphic or variadic
+/// functions can't be called this way.  An ERROR is raised if there is no such function.  An
+/// unqualified name is looked up in the current `search_path` on every call, while the OID of a
+/// schema-qualified one is cached for the rest of the session, until `pg_proc` changes.
+///
+/// Strict functions aren't called if any of the arguments are `NULL`, and return `None` instead.
+/// Set-returning functions can't be called this way.
+///
+/// ## Panics
+///
+/// If the function's return type isn't compatible with `R`
+pub fn call_function<R: FromDatum + IntoDatum>(name: &str, args: &[FunctionArgument]) -> Option<R> {
+    let arg_types = args.iter().map(|arg| arg.type_oid).collect::<Vec<_>>();
+    let cname = alloc::ffi::CString::new(name).expect("function name cannot contain NULL bytes");
+    // parsed as SQL would, so quoted parts keep their case and may contain dots
+    #[cfg(not(feature = "pg16"))]
+    let names = unsafe { pg_sys::stringToQualifiedNameList(cname.as_ptr()) };
+    #[cfg(feature = "pg16")]
+    let names = unsafe { pg_sys::stringToQualifiedNameList(cname.as_ptr(), std::ptr::null_mut()) };
+    let lookup = || unsafe {
+        pg_sys::LookupFuncName(names, arg_types.len() as _, arg_types.as_ptr(), false)
+    };
+    // which function an unqualified name means depends on the `search_path` at the time
+    let func_oid = if unsafe { (*names).length } > 1 {
+        let key = format!(
+            "{name}({})",
+            arg_types.iter().map(|oid| oid.as_u32().to_string()).collect::<Vec<_>>().join(",")
+        );
+        FUNCTION_OIDS.get_or_insert_with(key, |_| lookup())
+    } else {
+        lookup()
+    };
+
+    unsafe {
+        let rettype = pg_sys::get_func_rettype(func_oid);
+        if !R::is_compatible_with(rettype) {
+            panic!(
+                "function `{name}` returns type oid `{rettype}`, which is not compatible with type oid `{}`",
+                R::type_oid()
+            );
+        }
+
+        let mut flinfo = pg_sys::FmgrInfo::default();
+        pg_sys::fmgr_info(func_oid, &mut flinfo);
+        if flinfo.fn_retset {
+            panic!("function `{name}` returns a set, which `call_function` can't do");
+        }
+        if flinfo.fn_strict && args.iter().any(|arg| arg.datum.is_none()) {
+            return None;
+        }
+
+        let func = flinfo.fn_addr.expect("function has no address");
+        let datums = args.iter().map(|arg| arg.datum).collect::<Vec<_>>();
+        let result = direct_function_call_as_datum_internal(
+            |fcinfo| {
+                (*fcinfo).flinfo = &mut flinfo;
+                (*fcinfo).fncollation = pg_sys::DEFAULT_COLLATION_OID;
+                func(fcinfo)
+            },
+            &datums,
+        );
+        result.and_then(|datum| R::from_polymorphic_datum(datum, false, rettype))
+    }
+}
+
 #[inline]
 pub unsafe fn srf_is_first_call(fcinfo: pg_sys::FunctionCallInfo) -> bool {
     (*(*fcinfo).flinfo).fn_extra.is_null()
@@ -14,6 +14,7 @@ pub use pgrx_macros::PostgresGucEnum;
 use std::cell::Cell;
 
 /// Defines at what level this GUC can be set
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GucContext {
     /// cannot be set by the user at all, but only through
     /// internal processes ("server_version" is an example).  These are GUC
@@ -207,6 +208,109 @@ where
     }
 }
 
+/// A runtime switch for some behavior of an extension, backed by a boolean GUC
+///
+/// Declare one with [`pg_feature_flag!`](crate::pg_feature_flag), which names the GUC after the
+/// extension, and [`register()`](FeatureFlag::register) it in `_PG_init()`.  Operators can then turn
+/// risky behavior off with `SET` or in `postgresql.conf` without a rebuild.
+pub struct FeatureFlag {
+    name: &'static str,
+    description: &'static str,
+    context: GucContext,
+    setting: GucSetting<bool>,
+}
+
+impl FeatureFlag {
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        description: &'static str,
+        default: bool,
+        context: GucContext,
+    ) -> Self {
+        FeatureFlag { name, description, context, setting: GucSetting::<bool>::new(default) }
+    }
+
+    /// The name of this flag's GUC, such as `my_extension.enable_fast_path`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Is this flag currently on?
+    ///
+    /// Before [`register()`](FeatureFlag::register) is called, this is the flag's default.
+    pub fn is_enabled(&self) -> bool {
+        self.setting.get()
+    }
+
+    /// Define this flag's GUC, which must be done in `_PG_init()`
+    pub fn register(&'static self) {
+        GucRegistry::define_bool_guc(
+            self.name,
+            self.description,
+            self.description,
+            &self.setting,
+            self.context,
+            GucFlags::default(),
+        );
+    }
+}
+
+/// Declare a [`FeatureFlag`], whose GUC is named `<crate name>.<name>`
+///
+/// Flags can only be changed by superusers unless another `context` is given.
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::guc::FeatureFlag;
+///
+/// static FAST_PATH: FeatureFlag = pgrx::pg_feature_flag!("enable_fast_path", default = true);
+/// static PREFETCH: FeatureFlag = pgrx::pg_feature_flag!(
+///     "enable_prefetch",
+///     default = false,
+///     context = pgrx::guc::GucContext::Userset,
+///     description = "Prefetch the next page while scanning",
+/// );
+///
+/// #[pg_guard]
+/// pub extern "C" fn _PG_init() {
+///     FAST_PATH.register();
+///     PREFETCH.register();
+/// }
+///
+/// #[pg_extern]
+/// fn lookup(key: i64) -> i64 {
+///     if FAST_PATH.is_enabled() {
+///         key // the clever way
+///     } else {
+///         key // the safe way
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! pg_feature_flag {
+    ($name:literal, default = $default:expr $(, context = $context:expr)? $(, description = $description:literal)? $(,)?) => {
+        $crate::guc::FeatureFlag::new(
+            concat!(env!("CARGO_CRATE_NAME"), ".", $name),
+            $crate::pg_feature_flag!(@description $name $(, $description)?),
+            $default,
+            $crate::pg_feature_flag!(@context $($context)?),
+        )
+    };
+    (@description $name:literal) => { concat!("Enables ", $name) };
+    (@description $name:literal, $description:literal) => { $description };
+    (@context) => { $crate::guc::GucContext::Suset };
+    (@context $context:expr) => { $context };
+}
+
+/// A `Postmaster` GUC can only be set when the server starts, so it has to be defined in the
+/// postmaster, by a `shared_preload_libraries` library's `#[pg_init]` function
+fn check_init_path(context: GucContext) {
+    if matches!(context, GucContext::Postmaster) {
+        crate::init::check_init_path("defining a Postmaster GUC");
+    }
+}
+
 /// A struct that has associated functions to register new GUCs
 pub struct GucRegistry {}
 impl GucRegistry {
@@ -218,6 +322,7 @@ impl GucRegistry {
         context: GucContext,
         flags: GucFlags,
     ) {
+        check_init_path(context);
         unsafe {
             pg_sys::DefineCustomBoolVariable(
                 PgMemoryContexts::TopMemoryContext.pstrdup(name),
@@ -244,6 +349,7 @@ impl GucRegistry {
         context: GucContext,
         flags: GucFlags,
     ) {
+        check_init_path(context);
         unsafe {
             pg_sys::DefineCustomIntVariable(
                 PgMemoryContexts::TopMemoryContext.pstrdup(name),
@@ -270,6 +376,7 @@ impl GucRegistry {
         context: GucContext,
         flags: GucFlags,
     ) {
+        check_init_path(context);
         unsafe {
             let boot_val = setting.boot_val.map_or(std::ptr::null(), |s| s.as_ptr());
             *setting.as_ptr() = boot_val as *mut _;
@@ -298,6 +405,7 @@ impl GucRegistry {
         context: GucContext,
         flags: GucFlags,
     ) {
+        check_init_path(context);
         unsafe {
             pg_sys::DefineCustomRealVariable(
                 PgMemoryContexts::TopMemoryContext.pstrdup(name),
@@ -326,6 +434,7 @@ impl GucRegistry {
     ) where
         T: GucEnum<T> + Copy,
     {
+        check_init_path(context);
         unsafe {
             let boot_val = setting.boot_val.to_ordinal();
             (*setting.as_ptr()) = boot_val;
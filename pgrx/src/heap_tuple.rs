@@ -19,6 +19,7 @@ use crate::{
     WhoAllocated,
 };
 use pgrx_pg_sys::errcodes::PgSqlErrorCode;
+use pgrx_pg_sys::panic::CaughtError;
 use pgrx_pg_sys::PgTryBuilder;
 use pgrx_sql_entity_graph::metadata::{
     ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
@@ -188,6 +189,18 @@ impl<'a> PgHeapTuple<'a, AllocatedByPostgres> {
 }
 
 impl<'a> PgHeapTuple<'a, AllocatedByRust> {
+    /// Takes ownership of a [pg_sys::HeapTuple] pointer that we allocated ourselves, such as with
+    /// [pg_sys::heap_copytuple].  It'll be freed when the returned [PgHeapTuple] is dropped.
+    pub(crate) unsafe fn from_owned_heap_tuple(
+        tupdesc: PgTupleDesc<'a>,
+        heap_tuple: pg_sys::HeapTuple,
+    ) -> Self {
+        Self {
+            tuple: PgBox::<pg_sys::HeapTupleData, AllocatedByRust>::from_rust(heap_tuple),
+            tupdesc,
+        }
+    }
+
     /** Create a new heap tuple in the shape of a defined composite type
 
     ```rust,no_run
@@ -214,10 +227,10 @@ impl<'a> PgHeapTuple<'a, AllocatedByRust> {
     pub fn new_composite_type(
         type_name: &str,
     ) -> Result<PgHeapTuple<'a, AllocatedByRust>, PgHeapTupleError> {
-        let tuple_desc = PgTupleDesc::for_composite_type(type_name)
+        let typoid = crate::tupdesc::composite_type_oid(type_name)
             .ok_or_else(|| PgHeapTupleError::NoSuchType(type_name.to_string()))?;
 
-        Self::new_composite_type_by_oid(tuple_desc.oid())
+        Self::new_composite_type_by_oid(typoid)
     }
 
     pub fn new_composite_type_by_oid(
@@ -348,51 +361,107 @@ impl<'a> PgHeapTuple<'a, AllocatedByRust> {
         attno: NonZeroUsize,
         value: T,
     ) -> Result<(), TryFromDatumError> {
+        self.check_type(attno, &value)?;
         unsafe {
-            match self.get_attribute_by_index(attno) {
-                None => return Err(TryFromDatumError::NoSuchAttributeNumber(attno)),
-                Some(att) => {
-                    let type_oid = T::type_oid();
-                    let composite_type_oid = value.composite_type_oid();
-                    let is_compatible_composite_types =
-                        type_oid == pg_sys::RECORDOID && composite_type_oid == Some(att.atttypid);
-                    if !is_compatible_composite_types && !T::is_compatible_with(att.atttypid) {
-                        return Err(TryFromDatumError::IncompatibleTypes {
-                            rust_type: std::any::type_name::<T>(),
-                            rust_oid: att.atttypid,
-                            datum_type: lookup_type_name(type_oid),
-                            datum_oid: type_oid,
-                        });
-                    }
-                }
+            self.replace_datum(attno, value.into_datum());
+        }
+        Ok(())
+    }
+
+    /// Like [`PgHeapTuple::set_by_name`], but if the attribute's type is a domain then `value` must
+    /// also satisfy the domain's `NOT NULL` and `CHECK` constraints.
+    ///
+    /// ## Errors
+    ///
+    /// Those of [`PgHeapTuple::set_by_name`], and
+    /// - return [TryFromDatumError::DomainConstraintViolation] if `value` violates the domain's
+    /// constraints, in which case the tuple is left unchanged
+    pub fn set_by_name_checked<T: IntoDatum>(
+        &mut self,
+        attname: &str,
+        value: T,
+    ) -> Result<(), TryFromDatumError> {
+        match self.get_attribute_by_name(attname) {
+            None => Err(TryFromDatumError::NoSuchAttributeName(attname.to_string())),
+            Some((attnum, _)) => self.set_by_index_checked(attnum, value),
+        }
+    }
+
+    /// Like [`PgHeapTuple::set_by_index`], but if the attribute's type is a domain then `value` must
+    /// also satisfy the domain's `NOT NULL` and `CHECK` constraints.
+    ///
+    /// ## Errors
+    ///
+    /// Those of [`PgHeapTuple::set_by_index`], and
+    /// - return [TryFromDatumError::DomainConstraintViolation] if `value` violates the domain's
+    /// constraints, in which case the tuple is left unchanged
+    pub fn set_by_index_checked<T: IntoDatum>(
+        &mut self,
+        attno: NonZeroUsize,
+        value: T,
+    ) -> Result<(), TryFromDatumError> {
+        self.check_type(attno, &value)?;
+        let datum = value.into_datum();
+        self.check_domain(attno, datum)?;
+        unsafe {
+            self.replace_datum(attno, datum);
+        }
+        Ok(())
+    }
+
+    /// Set every `NULL` attribute whose type is a domain with a `DEFAULT` to that default, as
+    /// Postgres would when the attribute is omitted from an `INSERT`
+    ///
+    /// [`PgHeapTuple::new_composite_type`] creates tuples whose attributes are all `NULL`, which
+    /// would otherwise violate any `NOT NULL` domains.
+    pub fn apply_domain_defaults(&mut self) {
+        for i in 0..self.len() {
+            let attno = NonZeroUsize::new(i + 1).unwrap();
+            let att = self.tupdesc.get(i).unwrap();
+            if att.is_dropped() || !is_domain(att.atttypid) {
+                continue;
             }
 
-            let mut datums =
-                (0..self.tupdesc.len()).map(|i| pg_sys::Datum::from(i)).collect::<Vec<_>>();
-            let mut nulls = (0..self.tupdesc.len()).map(|_| false).collect::<Vec<_>>();
-            let mut do_replace = (0..self.tupdesc.len()).map(|_| false).collect::<Vec<_>>();
-
-            let datum = value.into_datum();
-            let attno = attno.get() - 1;
-
-            nulls[attno] = datum.is_none();
-            datums[attno] = datum.unwrap_or(0.into());
-            do_replace[attno] = true;
-
-            let new_tuple = PgBox::<pg_sys::HeapTupleData, AllocatedByRust>::from_rust(
-                pg_sys::heap_modify_tuple(
-                    self.tuple.as_ptr(),
-                    self.tupdesc.as_ptr(),
-                    datums.as_mut_ptr(),
-                    nulls.as_mut_ptr(),
-                    do_replace.as_mut_ptr(),
-                ),
-            );
-            let old_tuple = std::mem::replace(&mut self.tuple, new_tuple);
-            drop(old_tuple);
-            Ok(())
+            unsafe {
+                if heap_getattr_raw(self.tuple.as_ptr(), attno, self.tupdesc.as_ptr()).is_some() {
+                    continue;
+                }
+
+                let default = pg_sys::get_typdefault(att.atttypid);
+                if default.is_null() {
+                    continue;
+                }
+
+                let datum = evaluate_default(default);
+                self.replace_datum(attno, datum);
+            }
         }
     }
+
+    /// Replace the attribute `attno` with `datum`, whose type has already been checked
+    unsafe fn replace_datum(&mut self, attno: NonZeroUsize, datum: Option<pg_sys::Datum>) {
+        let mut datums =
+            (0..self.tupdesc.len()).map(|i| pg_sys::Datum::from(i)).collect::<Vec<_>>();
+        let mut nulls = (0..self.tupdesc.len()).map(|_| false).collect::<Vec<_>>();
+        let mut do_replace = (0..self.tupdesc.len()).map(|_| false).collect::<Vec<_>>();
+
+        let attno = attno.get() - 1;
+
+        nulls[attno] = datum.is_none();
+        datums[attno] = datum.unwrap_or(0.into());
+        do_replace[attno] = true;
+
+        let new_tuple =
+            PgBox::<pg_sys::HeapTupleData, AllocatedByRust>::from_rust(pg_sys::heap_modify_tuple(
+                self.tuple.as_ptr(),
+                self.tupdesc.as_ptr(),
+                datums.as_mut_ptr(),
+                nulls.as_mut_ptr(),
+                do_replace.as_mut_ptr(),
+            ));
+        let old_tuple = std::mem::replace(&mut self.tuple, new_tuple);
+        drop(old_tuple);
+    }
 }
 
 impl<'a, AllocatedBy: WhoAllocated> IntoDatum for PgHeapTuple<'a, AllocatedBy> {
@@ -424,7 +493,10 @@ impl<'a, AllocatedBy: WhoAllocated> IntoDatum for PgHeapTuple<'a, AllocatedBy> {
 impl<'a, AllocatedBy: WhoAllocated> PgHeapTuple<'a, AllocatedBy> {
     /// Consume this [`PgHeapTuple`] and return a composite Datum representation, containing the tuple
     /// data and the corresponding tuple descriptor information.
-    pub fn into_composite_datum(self) -> Option<pg_sys::Datum> {
+    ///
+    /// An anonymous `RECORD` is [blessed](PgTupleDesc::bless) first, so Postgres can decode it.
+    pub fn into_composite_datum(mut self) -> Option<pg_sys::Datum> {
+        self.tupdesc.bless();
         unsafe {
             Some(pg_sys::heap_copy_tuple_as_datum(self.tuple.as_ptr(), self.tupdesc.as_ptr()))
         }
@@ -444,12 +516,97 @@ impl<'a, AllocatedBy: WhoAllocated> PgHeapTuple<'a, AllocatedBy> {
         self.tuple.into_pg()
     }
 
+    /// Returns a pointer to the underlying [`pg_sys::HeapTupleData`], which remains owned by this
+    /// [`PgHeapTuple`]
+    #[inline]
+    pub fn as_ptr(&self) -> *mut pg_sys::HeapTupleData {
+        self.tuple.as_ptr()
+    }
+
     /// Returns the number of attributes in this [`PgHeapTuple`].
     #[inline]
     pub fn len(&self) -> usize {
         self.tupdesc.len()
     }
 
+    /// Check every attribute whose type is a domain against the domain's `NOT NULL` and `CHECK`
+    /// constraints, such as before returning a tuple built with [`PgHeapTuple::from_datums`] or
+    /// [`PgHeapTuple::new_composite_type`]
+    ///
+    /// ## Errors
+    ///
+    /// - return [TryFromDatumError::DomainConstraintViolation] for the first attribute that
+    /// violates its domain's constraints
+    pub fn check_domains(&self) -> Result<(), TryFromDatumError> {
+        for i in 0..self.len() {
+            let attno = NonZeroUsize::new(i + 1).unwrap();
+            let datum =
+                unsafe { heap_getattr_raw(self.tuple.as_ptr(), attno, self.tupdesc.as_ptr()) };
+            self.check_domain(attno, datum)?;
+        }
+        Ok(())
+    }
+
+    /// Ensure `value` can be stored in the attribute `attno`
+    fn check_type<T: IntoDatum>(
+        &self,
+        attno: NonZeroUsize,
+        value: &T,
+    ) -> Result<(), TryFromDatumError> {
+        match self.tupdesc.get(attno.get() - 1) {
+            None => Err(TryFromDatumError::NoSuchAttributeNumber(attno)),
+            Some(att) => {
+                let type_oid = T::type_oid();
+                let composite_type_oid = value.composite_type_oid();
+                let is_compatible_composite_types =
+                    type_oid == pg_sys::RECORDOID && composite_type_oid == Some(att.atttypid);
+                if !is_compatible_composite_types
+                    && !T::is_compatible_with(att.atttypid)
+                    && !T::is_compatible_with(base_type(att.atttypid))
+                {
+                    return Err(TryFromDatumError::IncompatibleTypes {
+                        rust_type: std::any::type_name::<T>(),
+                        rust_oid: att.atttypid,
+                        datum_type: lookup_type_name(type_oid),
+                        datum_oid: type_oid,
+                    });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// If the attribute `attno` is of a domain type, ensure `datum` satisfies its constraints
+    fn check_domain(
+        &self,
+        attno: NonZeroUsize,
+        datum: Option<pg_sys::Datum>,
+    ) -> Result<(), TryFromDatumError> {
+        let att = match self.tupdesc.get(attno.get() - 1) {
+            None => return Err(TryFromDatumError::NoSuchAttributeNumber(attno)),
+            Some(att) => att,
+        };
+        if att.is_dropped() || !is_domain(att.atttypid) {
+            return Ok(());
+        }
+
+        PgTryBuilder::new(|| {
+            unsafe {
+                pg_sys::domain_check(
+                    datum.unwrap_or(0.into()),
+                    datum.is_none(),
+                    att.atttypid,
+                    std::ptr::null_mut(),
+                    pg_sys::CurrentMemoryContext,
+                );
+            }
+            Ok(())
+        })
+        .catch_when(PgSqlErrorCode::ERRCODE_NOT_NULL_VIOLATION, |e| domain_violation(att, e))
+        .catch_when(PgSqlErrorCode::ERRCODE_CHECK_VIOLATION, |e| domain_violation(att, e))
+        .execute()
+    }
+
     /// Returns an iterator over the attributes in this [`PgHeapTuple`].
     ///
     /// The return value is `(attribute_number: NonZeroUsize, attribute_info: &pg_sys::FormData_pg_attribute)`.
@@ -540,7 +697,7 @@ impl<'a, AllocatedBy: WhoAllocated> PgHeapTuple<'a, AllocatedBy> {
                         record @ pg_sys::RECORDOID => {
                             T::try_from_datum(datum.unwrap(), false, record)
                         }
-                        _ => T::try_from_datum(datum.unwrap(), false, att.type_oid().value()),
+                        _ => T::try_from_datum(datum.unwrap(), false, base_type(att.atttypid)),
                     }
                 }
             }
@@ -548,6 +705,269 @@ impl<'a, AllocatedBy: WhoAllocated> PgHeapTuple<'a, AllocatedBy> {
     }
 }
 
+fn is_domain(typoid: pg_sys::Oid) -> bool {
+    unsafe { pg_sys::get_typtype(typoid) as u8 == pg_sys::TYPTYPE_DOMAIN }
+}
+
+/// The type a domain is ultimately based on, or `typoid` itself if it isn't a domain
+/// Builds an anonymous `RECORD` whose shape is only known at runtime, such as for a function that
+/// `RETURNS record` or a `record` nested in another composite
+///
+/// Each attribute's type comes from the Rust type of its value.  The record's tuple descriptor is
+/// [blessed](PgTupleDesc::bless), so Postgres can decode it wherever it's returned to.
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::heap_tuple::RecordBuilder;
+///
+/// #[pg_extern]
+/// fn dog(name: &str) -> pgrx::composite_type!("record") {
+///     RecordBuilder::new().add("name", name).add("scritches", 42).add_null::<bool>("good").build()
+/// }
+/// ```
+#[derive(Default)]
+pub struct RecordBuilder {
+    attributes: Vec<(String, pg_sys::Oid, Option<pg_sys::Datum>)>,
+}
+
+impl RecordBuilder {
+    pub fn new() -> Self {
+        RecordBuilder::default()
+    }
+
+    /// Add an attribute named `name`, set to `value`
+    ///
+    /// The value is converted to a Datum right away, in the `CurrentMemoryContext`.
+    pub fn add<T: IntoDatum>(mut self, name: &str, value: T) -> Self {
+        let typoid = value.composite_type_oid().unwrap_or_else(T::type_oid);
+        self.attributes.push((name.to_string(), typoid, value.into_datum()));
+        self
+    }
+
+    /// Add an attribute named `name`, of the type of `T`, set to `NULL`
+    pub fn add_null<T: IntoDatum>(mut self, name: &str) -> Self {
+        self.attributes.push((name.to_string(), T::type_oid(), None));
+        self
+    }
+
+    /// The number of attributes added so far
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    /// Form the record, in the `CurrentMemoryContext`
+    pub fn build<'a>(self) -> PgHeapTuple<'a, AllocatedByRust> {
+        unsafe {
+            let tupdesc = crate::compat::create_template_tuple_desc(self.attributes.len() as _);
+            for (i, (name, typoid, _)) in self.attributes.iter().enumerate() {
+                let name = std::ffi::CString::new(name.as_str())
+                    .expect("attribute names can't contain NUL bytes");
+                pg_sys::TupleDescInitEntry(
+                    tupdesc,
+                    (i + 1) as pg_sys::AttrNumber,
+                    name.as_ptr(),
+                    *typoid,
+                    -1,
+                    0,
+                );
+            }
+
+            let mut tupdesc = PgTupleDesc::from_pg_is_copy(tupdesc);
+            tupdesc.bless();
+            PgHeapTuple::from_datums(
+                tupdesc,
+                self.attributes.into_iter().map(|(_, _, datum)| datum),
+            )
+            .expect("the record has exactly one datum per attribute")
+        }
+    }
+}
+
+/// The attributes of many composite values of the same type, such as the elements of an
+/// `Array<pgrx::composite_type!(..)>`, column by column
+///
+/// Each composite is deformed once, in a single pass over its attributes, with one tuple
+/// descriptor shared by all of them, where reading them one at a time with
+/// [`PgHeapTuple::get_by_name()`] walks each composite again for every attribute.  See
+/// [`Array::to_columns()`](crate::Array::to_columns).
+pub struct CompositeColumns<'a> {
+    tupdesc: Option<PgTupleDesc<'a>>,
+    natts: usize,
+    rows: Vec<bool>,
+    datums: Vec<pg_sys::Datum>,
+    nulls: Vec<bool>,
+}
+
+impl<'a> CompositeColumns<'a> {
+    /// Deform each of `composites`, of the composite type `typoid`, or `NULL` where it's `None`
+    ///
+    /// ## Safety
+    ///
+    /// Each datum must be a valid composite of type `typoid`, which for `RECORD` must be the same
+    /// row type for all of them, and live as long as `'a`.
+    pub(crate) unsafe fn from_composite_datums(
+        typoid: pg_sys::Oid,
+        composites: Vec<Option<pg_sys::Datum>>,
+    ) -> Self {
+        let headers = composites
+            .into_iter()
+            .map(|composite| {
+                composite.map(|datum| {
+                    pg_sys::pg_detoast_datum(datum.cast_mut_ptr()) as pg_sys::HeapTupleHeader
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // an anonymous `RECORD`'s row type is only known from a value of it
+        let rowtype = match typoid {
+            pg_sys::RECORDOID => headers.iter().flatten().next().map(|&header| {
+                (
+                    crate::heap_tuple_header_get_type_id(header),
+                    crate::heap_tuple_header_get_typmod(header),
+                )
+            }),
+            typoid => Some((typoid, -1)),
+        };
+        let tupdesc = rowtype.map(|(typoid, typmod)| {
+            PgTupleDesc::from_pg(pg_sys::lookup_rowtype_tupdesc(typoid, typmod))
+        });
+        let natts = tupdesc.as_ref().map(|tupdesc| tupdesc.len()).unwrap_or_default();
+
+        let mut datums = vec![pg_sys::Datum::from(0); headers.len() * natts];
+        let mut nulls = vec![true; headers.len() * natts];
+        let mut rows = Vec::with_capacity(headers.len());
+        for (row, header) in headers.into_iter().enumerate() {
+            rows.push(header.is_none());
+            let (Some(header), Some(tupdesc)) = (header, tupdesc.as_ref()) else { continue };
+
+            let mut tuple = pg_sys::HeapTupleData {
+                t_len: crate::heap_tuple_header_get_datum_length(header) as u32,
+                t_data: header,
+                ..std::mem::zeroed()
+            };
+            let range = row * natts..(row + 1) * natts;
+            pg_sys::heap_deform_tuple(
+                &mut tuple,
+                tupdesc.as_ptr(),
+                datums[range.clone()].as_mut_ptr(),
+                nulls[range].as_mut_ptr(),
+            );
+        }
+
+        CompositeColumns { tupdesc, natts, rows, datums, nulls }
+    }
+
+    /// The number of composites, including `NULL`s
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The tuple descriptor of the composites, or `None` if they're `RECORD`s and all `NULL`
+    pub fn tupdesc(&self) -> Option<&PgTupleDesc<'a>> {
+        self.tupdesc.as_ref()
+    }
+
+    /// Is the composite at `row`, starting from zero, itself `NULL`?
+    pub fn is_null(&self, row: usize) -> Option<bool> {
+        self.rows.get(row).copied()
+    }
+
+    /// Retrieve the values of the specified attribute, by name, one for each composite.  The
+    /// composites that are themselves `NULL` have a `NULL` value.
+    ///
+    /// ## Errors
+    /// - return [`TryFromDatumError::NoSuchAttributeName`] if the attribute does not exist
+    /// - return [`TryFromDatumError::IncompatibleTypes`] if the Rust type of the `value` is not
+    /// compatible with the attribute's Postgres type
+    pub fn column_by_name<T: FromDatum + IntoDatum + 'static>(
+        &self,
+        attname: &str,
+    ) -> Result<Vec<Option<T>>, TryFromDatumError> {
+        let Some(tupdesc) = &self.tupdesc else {
+            return Ok((0..self.len()).map(|_| None).collect());
+        };
+        match tupdesc.iter().position(|att| att.name() == attname) {
+            Some(index) => self.column_by_index(NonZeroUsize::new(index + 1).unwrap()),
+            None => Err(TryFromDatumError::NoSuchAttributeName(attname.to_owned())),
+        }
+    }
+
+    /// Retrieve the values of the specified attribute, by index, one for each composite.  The
+    /// composites that are themselves `NULL` have a `NULL` value.
+    ///
+    /// Attribute numbers start at 1, not 0.
+    ///
+    /// ## Errors
+    /// - return [`TryFromDatumError::NoSuchAttributeNumber`] if the attribute does not exist
+    /// - return [`TryFromDatumError::IncompatibleTypes`] if the Rust type of the `value` is not
+    /// compatible with the attribute's Postgres type
+    pub fn column_by_index<T: FromDatum + IntoDatum + 'static>(
+        &self,
+        attno: NonZeroUsize,
+    ) -> Result<Vec<Option<T>>, TryFromDatumError> {
+        let Some(tupdesc) = &self.tupdesc else {
+            return Ok((0..self.len()).map(|_| None).collect());
+        };
+        let att =
+            tupdesc.get(attno.get() - 1).ok_or(TryFromDatumError::NoSuchAttributeNumber(attno))?;
+        let typoid = match T::type_oid() {
+            record @ pg_sys::RECORDOID => record,
+            _ => base_type(att.atttypid),
+        };
+
+        (0..self.len())
+            .map(|row| {
+                let index = row * self.natts + attno.get() - 1;
+                if self.nulls[index] {
+                    Ok(None)
+                } else {
+                    unsafe { T::try_from_datum(self.datums[index], false, typoid) }
+                }
+            })
+            .collect()
+    }
+}
+
+fn base_type(typoid: pg_sys::Oid) -> pg_sys::Oid {
+    unsafe { pg_sys::getBaseType(typoid) }
+}
+
+/// Evaluate a type's `DEFAULT` expression, as returned by `get_typdefault()`, in the
+/// `CurrentMemoryContext`
+unsafe fn evaluate_default(default: *mut pg_sys::Node) -> Option<pg_sys::Datum> {
+    let expr = pg_sys::expression_planner(default.cast());
+    let state = pg_sys::ExecInitExpr(expr, std::ptr::null_mut());
+    let econtext = pg_sys::CreateStandaloneExprContext();
+
+    let mut is_null = false;
+    let evalfunc = (*state).evalfunc.expect("ExprState has no evalfunc");
+    let datum = pg_sys::ffi::pg_guard_ffi_boundary(|| evalfunc(state, econtext, &mut is_null));
+    pg_sys::FreeExprContext(econtext, true);
+
+    (!is_null).then_some(datum)
+}
+
+fn domain_violation(
+    att: &pg_sys::FormData_pg_attribute,
+    error: CaughtError,
+) -> Result<(), TryFromDatumError> {
+    match error {
+        CaughtError::PostgresError(report) => Err(TryFromDatumError::DomainConstraintViolation {
+            attname: att.name().to_string(),
+            message: report.message().to_string(),
+        }),
+        e => e.rethrow(),
+    }
+}
+
 /** Composite type support
 
 Support for working with types defined by SQL statements like:
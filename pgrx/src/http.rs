@@ -0,0 +1,279 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! A blocking HTTP client that's safe to use from inside a Postgres backend
+//!
+//! Calling an HTTP client directly from a `#[pg_extern]` function blocks the backend in a socket
+//! read where Postgres can't reach it: `pg_cancel_backend()`, `statement_timeout`, and even
+//! `pg_terminate_backend()` do nothing until the server answers.  [`HttpClient`] instead performs
+//! each request on its own thread and, while waiting for it, checks for interrupts every few
+//! milliseconds, so a cancelled query is cancelled promptly.
+//!
+//! Each request's timeout is also capped at what remains of the statement's `statement_timeout`,
+//! so a request thread doesn't outlive the statement that started it by much.
+//!
+//! ## Memory
+//!
+//! Requests and responses are held in Rust's heap, not in a Postgres `MemoryContext`, and the
+//! response body is read completely before [`HttpRequest::send()`] returns.  Returning it from a
+//! function, such as `Vec<u8>` as `bytea`, copies it into the `CurrentMemoryContext` as usual.  The
+//! request thread never calls into Postgres, so nothing allocated by Postgres may be handed to it;
+//! everything the builders accept is copied into owned Rust types for that reason.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::http::HttpClient;
+//!
+//! #[pg_extern]
+//! fn http_get(url: &str) -> String {
+//!     let response = HttpClient::new().get(url).send().unwrap_or_else(|e| error!("{e}"));
+//!     response.text().unwrap_or_else(|e| error!("{e}")).to_string()
+//! }
+//! ```
+use std::io::Read;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use crate::pg_sys;
+
+/// How often the backend checks for interrupts while waiting for a response
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// The timeout of a new [`HttpClient`]
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// The largest response body a new [`HttpClient`] accepts, 64MB
+pub const DEFAULT_MAX_RESPONSE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Makes HTTP requests without making the backend uncancellable
+///
+/// Clones share a connection pool.
+#[derive(Clone)]
+pub struct HttpClient {
+    agent: ureq::Agent,
+    timeout: Duration,
+    max_response_size: u64,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpClient {
+    pub fn new() -> Self {
+        HttpClient {
+            agent: ureq::Agent::new(),
+            timeout: DEFAULT_TIMEOUT,
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+        }
+    }
+
+    /// The longest a whole request, from connecting to reading the last byte of the response,
+    /// may take
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The largest response body to accept, in bytes
+    pub fn max_response_size(mut self, bytes: u64) -> Self {
+        self.max_response_size = bytes;
+        self
+    }
+
+    pub fn get(&self, url: &str) -> HttpRequest {
+        self.request("GET", url)
+    }
+
+    pub fn post(&self, url: &str) -> HttpRequest {
+        self.request("POST", url)
+    }
+
+    pub fn put(&self, url: &str) -> HttpRequest {
+        self.request("PUT", url)
+    }
+
+    pub fn delete(&self, url: &str) -> HttpRequest {
+        self.request("DELETE", url)
+    }
+
+    pub fn request(&self, method: &str, url: &str) -> HttpRequest {
+        HttpRequest {
+            client: self.clone(),
+            method: method.to_string(),
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+}
+
+/// A request built by [`HttpClient`], made by [`HttpRequest::send()`]
+pub struct HttpRequest {
+    client: HttpClient,
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Make the request, waiting for the whole response
+    ///
+    /// An HTTP error status isn't an `Err`; check [`HttpResponse::status()`].
+    ///
+    /// ## Errors
+    ///
+    /// If the query is cancelled or times out while waiting, this raises the usual Postgres
+    /// `ERROR` rather than returning.
+    pub fn send(self) -> Result<HttpResponse, HttpError> {
+        let timeout = statement_time_remaining()
+            .map_or(self.client.timeout, |remaining| remaining.min(self.client.timeout));
+        let (tx, rx) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("pgrx-http".into())
+            .spawn(move || {
+                // the backend has stopped waiting if the receiver is gone, which is fine
+                let _ = tx.send(self.perform(timeout));
+            })
+            .map_err(HttpError::Io)?;
+
+        loop {
+            match rx.recv_timeout(POLL_INTERVAL) {
+                Ok(result) => return result,
+                Err(RecvTimeoutError::Timeout) => {
+                    pg_sys::check_for_interrupts!();
+                }
+                Err(RecvTimeoutError::Disconnected) => return Err(HttpError::Panicked),
+            }
+        }
+    }
+
+    /// Runs on the request thread, so mustn't touch Postgres
+    fn perform(self, timeout: Duration) -> Result<HttpResponse, HttpError> {
+        let mut request = self.client.agent.request(&self.method, &self.url).timeout(timeout);
+        for (name, value) in &self.headers {
+            request = request.set(name, value);
+        }
+        let result = match self.body {
+            Some(body) => request.send_bytes(&body),
+            None => request.call(),
+        };
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(transport)) => {
+                let timed_out = std::error::Error::source(&transport)
+                    .and_then(|source| source.downcast_ref::<std::io::Error>())
+                    .map_or(false, |e| {
+                        matches!(
+                            e.kind(),
+                            std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+                        )
+                    });
+                return Err(if timed_out {
+                    HttpError::Timeout(timeout)
+                } else {
+                    HttpError::Transport(transport.to_string())
+                });
+            }
+        };
+
+        let status = response.status();
+        let headers = response
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = response.header(&name)?.to_string();
+                Some((name, value))
+            })
+            .collect();
+        let limit = self.client.max_response_size;
+        let mut body = Vec::new();
+        response.into_reader().take(limit + 1).read_to_end(&mut body).map_err(HttpError::Io)?;
+        if body.len() as u64 > limit {
+            return Err(HttpError::TooLarge(limit));
+        }
+        Ok(HttpResponse { status, headers, body })
+    }
+}
+
+/// How long until `statement_timeout` cancels the current statement, if it's set
+fn statement_time_remaining() -> Option<Duration> {
+    unsafe {
+        if pg_sys::StatementTimeout <= 0 {
+            return None;
+        }
+        let elapsed_micros =
+            pg_sys::GetCurrentTimestamp() - pg_sys::GetCurrentStatementStartTimestamp();
+        let remaining_micros = pg_sys::StatementTimeout as i64 * 1000 - elapsed_micros;
+        Some(Duration::from_micros(remaining_micros.max(0) as u64))
+    }
+}
+
+/// A response to an [`HttpRequest`], read in full
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The value of the first header called `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+
+    pub fn text(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(&self.body)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HttpError {
+    #[error("HTTP request failed: {0}")]
+    Transport(String),
+    #[error("HTTP request timed out after {0:?}")]
+    Timeout(Duration),
+    #[error("HTTP response is larger than {0} bytes")]
+    TooLarge(u64),
+    #[error("HTTP request thread panicked")]
+    Panicked,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
@@ -8,6 +8,7 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 //! Utility functions for working with [`pg_sys::HeapTuple`][crate::pg_sys::HeapTuple] and [`pg_sys::HeapTupleHeader`][crate::pg_sys::HeapTupleHeader] structs
+use crate::tuple_writer::TupleWriter;
 use crate::*;
 use seq_macro::seq;
 use std::num::NonZeroUsize;
@@ -186,6 +187,21 @@ pub trait IntoHeapTuple {
         self,
         tupdesc: *mut pg_sys::TupleDescData,
     ) -> *mut pg_sys::HeapTupleData;
+
+    /// Convert `Self` into a tuple formed by `writer`, which is valid until `writer` forms the
+    /// next one.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as it cannot guarantee `writer`'s tuple descriptor describes `Self`.
+    unsafe fn into_tuple_writer(self, writer: &mut TupleWriter) -> *mut pg_sys::HeapTupleData
+    where
+        Self: Sized,
+    {
+        writer.reset();
+        let tupdesc = writer.tupdesc();
+        PgMemoryContexts::For(writer.memory_context()).switch_to(|_| self.into_heap_tuple(tupdesc))
+    }
 }
 
 seq!(I in 0..32 {
@@ -210,6 +226,14 @@ seq!(I in 0..32 {
                         pg_sys::heap_form_tuple(tupdesc, datums.as_mut_ptr(), nulls.as_mut_ptr())
                     }
                 }
+
+                unsafe fn into_tuple_writer(self, writer: &mut TupleWriter) -> *mut pg_sys::HeapTupleData {
+                    writer.reset();
+                    #(
+                        writer.set(N, self.N);
+                    )*
+                    writer.form_tuple()
+                }
             }
         });
     )*
@@ -0,0 +1,147 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Support for [`#[pg_init]`](macro@crate::pg_init) and [`#[pg_fini]`](macro@crate::pg_fini)
+//!
+//! Postgres calls a library's `_PG_init()` once, right after loading it into a backend (or into
+//! the postmaster, for `shared_preload_libraries`).  That is the only time a library can define
+//! GUCs, request shared memory, register static background workers, and install hooks.  Instead
+//! of writing `_PG_init()` by hand, mark an ordinary function `#[pg_init]`:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{pg_shmem_init, PgLwLock};
+//!
+//! static COUNTER: PgLwLock<i64> = PgLwLock::new();
+//!
+//! #[pg_init]
+//! fn init() {
+//!     pg_shmem_init!(COUNTER);
+//! }
+//!
+//! #[pg_fini]
+//! fn fini() {
+//!     // release anything outside of Postgres the library still holds
+//! }
+//! ```
+//!
+//! Once a `#[pg_init]` function has returned, [`pg_shmem_init!()`],
+//! [loading](crate::bgworkers::BackgroundWorkerBuilder::load) a static background worker, or
+//! defining a [`Postmaster`](crate::guc::GucContext::Postmaster) GUC panics, rather than taking
+//! effect in only some backends, or not at all.  A `#[pg_extern]` function never runs during
+//! `#[pg_init]`, so one that does any of these in its own body doesn't compile.  Otherwise these
+//! are checks made at runtime, when the code runs in a backend.  A library with a hand-written
+//! `_PG_init()` isn't checked.
+//!
+//! Postgres never unloads a library, so it never calls `_PG_fini()` on its own.  A library with a
+//! `#[pg_init]` function has its `#[pg_fini]` function called when the backend exits instead.
+use crate::pg_sys;
+use once_cell::sync::OnceCell;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const UNMANAGED: u8 = 0;
+const IN_INIT: u8 = 1;
+const INITIALIZED: u8 = 2;
+
+/// Where this library is in its `#[pg_init]` lifecycle.  Every pgrx extension is its own shared
+/// library, with its own copy of this.
+static STATE: AtomicU8 = AtomicU8::new(UNMANAGED);
+
+/// This library's [`#[pg_fini]`](macro@crate::pg_fini) function, if it has one
+static FINI: OnceCell<fn()> = OnceCell::new();
+
+/// Is this library's `#[pg_init]` function running?
+pub fn in_init() -> bool {
+    STATE.load(Ordering::SeqCst) == IN_INIT
+}
+
+/// Panic if `what` is being done after this library's `#[pg_init]` function returned
+#[doc(hidden)]
+pub fn check_init_path(what: &str) {
+    if STATE.load(Ordering::SeqCst) == INITIALIZED {
+        panic!("{what} can only be done from the library's #[pg_init] function");
+    }
+}
+
+/// Called by the `_PG_init()` that [`#[pg_init]`](macro@crate::pg_init) generates, with the
+/// library's [`#[pg_fini]`](macro@crate::pg_fini) function if it has one
+#[doc(hidden)]
+pub fn run_init(init: fn(), fini: Option<fn()>) {
+    if STATE.swap(IN_INIT, Ordering::SeqCst) != UNMANAGED {
+        panic!("_PG_init() was called twice");
+    }
+    init();
+    STATE.store(INITIALIZED, Ordering::SeqCst);
+    if let Some(fini) = fini {
+        FINI.set(fini).ok();
+        unsafe {
+            // SAFETY: Postgres calls `_PG_init()` from the main thread, where this is always allowed
+            pg_sys::on_proc_exit(Some(call_fini), pg_sys::Datum::from(0));
+        }
+    }
+}
+
+/// Called by the `_PG_fini()` that [`#[pg_fini]`](macro@crate::pg_fini) generates
+#[doc(hidden)]
+pub fn run_fini(fini: fn()) {
+    // it may be called both by Postgres and at exit, but only needs to run once
+    if STATE.swap(UNMANAGED, Ordering::SeqCst) == INITIALIZED {
+        fini();
+    }
+}
+
+/// Implemented by [`#[pg_fini]`](macro@crate::pg_fini) for the `__PgrxLibrary` type that
+/// [`pg_module_magic!()`](crate::pg_module_magic) declares in the root of the library
+#[doc(hidden)]
+pub trait PgFini {
+    fn fini();
+}
+
+/// Lets the `_PG_init()` [`#[pg_init]`](macro@crate::pg_init) generates find the library's
+/// `#[pg_fini]` function when there is one, and `None` otherwise:
+/// `(&&Library::<crate::__PgrxLibrary>::new()).fini()` picks [`WithFini`] when `__PgrxLibrary`
+/// implements [`PgFini`], and falls back to [`WithoutFini`] when it doesn't.
+#[doc(hidden)]
+pub struct Library<T>(PhantomData<T>);
+
+impl<T> Library<T> {
+    pub const fn new() -> Self {
+        Library(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithFini {
+    fn fini(&self) -> Option<fn()>;
+}
+
+impl<T: PgFini> WithFini for &Library<T> {
+    fn fini(&self) -> Option<fn()> {
+        Some(T::fini)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithoutFini {
+    fn fini(&self) -> Option<fn()>;
+}
+
+impl<T> WithoutFini for Library<T> {
+    fn fini(&self) -> Option<fn()> {
+        None
+    }
+}
+
+/// Call this library's [`#[pg_fini]`](macro@crate::pg_fini) function, if it has one
+unsafe extern "C" fn call_fini(_code: i32, _arg: pg_sys::Datum) {
+    if let Some(fini) = FINI.get() {
+        run_fini(*fini);
+    }
+}
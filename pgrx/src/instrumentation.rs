@@ -0,0 +1,296 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Per-query execution statistics, kept in shared memory, in the style of `pg_stat_statements`
+//!
+//! [`ExecutorInstrumentation`] installs `ExecutorStart` and `ExecutorEnd` hooks that time every
+//! query and count the rows it processed and the shared buffers it touched.  The
+//! [`executor_instrumentation_view!`][crate::executor_instrumentation_view] macro generates a
+//! set-returning function that reports them.
+//!
+//! Like anything else using shared memory, the extension must be loaded through
+//! `shared_preload_libraries`.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{executor_instrumentation_view, pg_shmem_init, ExecutorInstrumentation};
+//!
+//! // the capacity must be a power of two
+//! static STATEMENTS: ExecutorInstrumentation<1024> = ExecutorInstrumentation::new();
+//!
+//! #[pg_guard]
+//! pub extern "C" fn _PG_init() {
+//!     pg_shmem_init!(STATEMENTS);
+//!     unsafe { STATEMENTS.install() };
+//! }
+//!
+//! // creates `my_ext_statements() RETURNS TABLE (queryid bigint, query text, calls bigint, ...)`
+//! executor_instrumentation_view!(my_ext_statements, STATEMENTS);
+//! ```
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::lwlock::PgLwLock;
+use crate::prelude::*;
+use crate::shmem::{PGRXSharedMemory, PgSharedMemoryInitialization};
+use crate::PgMemoryContexts;
+use std::hash::{Hash, Hasher};
+
+/// How many bytes of each query's text are kept
+pub const QUERY_TEXT_LEN: usize = 256;
+
+/// Accumulated statistics for one query
+#[derive(Debug, Clone, Default)]
+pub struct QueryStats {
+    /// The query identifier computed by Postgres, or a hash of the query text if there isn't one
+    pub query_id: i64,
+    /// The query text, truncated to [`QUERY_TEXT_LEN`] bytes
+    pub query: heapless::String<QUERY_TEXT_LEN>,
+    pub calls: i64,
+    pub total_time_ms: f64,
+    pub min_time_ms: f64,
+    pub max_time_ms: f64,
+    pub rows: i64,
+    pub shared_blks_hit: i64,
+    pub shared_blks_read: i64,
+    pub shared_blks_written: i64,
+}
+
+unsafe impl PGRXSharedMemory for QueryStats {}
+
+impl QueryStats {
+    /// The statistics as a tuple, in the column order used by
+    /// [`executor_instrumentation_view!`][crate::executor_instrumentation_view]
+    #[allow(clippy::type_complexity)]
+    pub fn into_tuple(self) -> (i64, String, i64, f64, f64, f64, f64, i64, i64, i64, i64) {
+        let mean_time_ms =
+            if self.calls > 0 { self.total_time_ms / self.calls as f64 } else { 0.0 };
+        (
+            self.query_id,
+            self.query.as_str().to_string(),
+            self.calls,
+            self.total_time_ms,
+            self.min_time_ms,
+            self.max_time_ms,
+            mean_time_ms,
+            self.rows,
+            self.shared_blks_hit,
+            self.shared_blks_read,
+            self.shared_blks_written,
+        )
+    }
+}
+
+/// Records [`QueryStats`] for up to `N` distinct queries in shared memory
+///
+/// `N` must be a power of two.  Once full, the least-called query is evicted to make room for a
+/// new one.
+pub struct ExecutorInstrumentation<const N: usize> {
+    stats: PgLwLock<heapless::FnvIndexMap<i64, QueryStats, N>>,
+}
+
+impl<const N: usize> ExecutorInstrumentation<N> {
+    pub const fn new() -> Self {
+        ExecutorInstrumentation { stats: PgLwLock::new() }
+    }
+
+    /// Install the executor hooks.  This must be called from `_PG_init()`, after
+    /// [`pg_shmem_init!()`][crate::pg_shmem_init], and only once per extension.
+    ///
+    /// ## Panics
+    ///
+    /// If an [`ExecutorInstrumentation`] has already been installed
+    pub unsafe fn install(&'static self) {
+        let instance = &mut *std::ptr::addr_of_mut!(INSTANCE);
+        if instance.is_some() {
+            panic!("ExecutorInstrumentation already installed");
+        }
+        *instance = Some(self);
+
+        PREV_EXECUTOR_START = std::mem::replace(
+            &mut *std::ptr::addr_of_mut!(pg_sys::ExecutorStart_hook),
+            Some(pgrx_instrumentation_executor_start),
+        );
+        PREV_EXECUTOR_END = std::mem::replace(
+            &mut *std::ptr::addr_of_mut!(pg_sys::ExecutorEnd_hook),
+            Some(pgrx_instrumentation_executor_end),
+        );
+    }
+
+    /// A snapshot of the statistics for every recorded query
+    pub fn statements(&self) -> Vec<QueryStats> {
+        self.stats.share().values().cloned().collect()
+    }
+
+    /// Discard all recorded statistics
+    pub fn reset(&self) {
+        self.stats.exclusive().clear();
+    }
+}
+
+impl<const N: usize> Default for ExecutorInstrumentation<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> PgSharedMemoryInitialization for ExecutorInstrumentation<N> {
+    fn pg_init(&'static self) {
+        self.stats.pg_init();
+    }
+
+    fn shmem_init(&'static self) {
+        self.stats.shmem_init();
+    }
+}
+
+/// Type-erases the `N` of an [`ExecutorInstrumentation`] so the hooks can find it
+trait Recorder {
+    fn record(
+        &self,
+        query_id: i64,
+        query: &str,
+        time_ms: f64,
+        rows: u64,
+        usage: &pg_sys::BufferUsage,
+    );
+}
+
+impl<const N: usize> Recorder for ExecutorInstrumentation<N> {
+    fn record(
+        &self,
+        query_id: i64,
+        query: &str,
+        time_ms: f64,
+        rows: u64,
+        usage: &pg_sys::BufferUsage,
+    ) {
+        let mut stats = self.stats.exclusive();
+        if !stats.contains_key(&query_id) && stats.len() == stats.capacity() {
+            let least_called = stats.iter().min_by_key(|(_, entry)| entry.calls).map(|(k, _)| *k);
+            if let Some(least_called) = least_called {
+                stats.remove(&least_called);
+            }
+        }
+
+        if !stats.contains_key(&query_id) {
+            let mut entry = QueryStats { query_id, min_time_ms: time_ms, ..Default::default() };
+            for c in query.chars() {
+                if entry.query.push(c).is_err() {
+                    break;
+                }
+            }
+            // we just made room for it, if necessary
+            stats.insert(query_id, entry).ok();
+        }
+
+        let entry = stats.get_mut(&query_id).unwrap();
+        entry.calls += 1;
+        entry.total_time_ms += time_ms;
+        entry.min_time_ms = entry.min_time_ms.min(time_ms);
+        entry.max_time_ms = entry.max_time_ms.max(time_ms);
+        entry.rows += rows as i64;
+        entry.shared_blks_hit += usage.shared_blks_hit;
+        entry.shared_blks_read += usage.shared_blks_read;
+        entry.shared_blks_written += usage.shared_blks_written;
+    }
+}
+
+static mut INSTANCE: Option<&'static dyn Recorder> = None;
+static mut PREV_EXECUTOR_START: pg_sys::ExecutorStart_hook_type = None;
+static mut PREV_EXECUTOR_END: pg_sys::ExecutorEnd_hook_type = None;
+
+#[pg_guard]
+unsafe extern "C" fn pgrx_instrumentation_executor_start(
+    query_desc: *mut pg_sys::QueryDesc,
+    eflags: i32,
+) {
+    match PREV_EXECUTOR_START {
+        Some(prev) => prev(query_desc, eflags),
+        None => pg_sys::standard_ExecutorStart(query_desc, eflags),
+    }
+
+    // ask the executor to track the time and buffer usage of the whole query, allocated in the
+    // query's own memory context so it's released along with it
+    if (*query_desc).totaltime.is_null() {
+        PgMemoryContexts::For((*(*query_desc).estate).es_query_cxt).switch_to(|_| {
+            #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+            let totaltime = pg_sys::InstrAlloc(1, pg_sys::InstrumentOption_INSTRUMENT_ALL as _);
+            #[cfg(any(feature = "pg14", feature = "pg15", feature = "pg16"))]
+            let totaltime =
+                pg_sys::InstrAlloc(1, pg_sys::InstrumentOption_INSTRUMENT_ALL as _, false);
+            (*query_desc).totaltime = totaltime;
+        });
+    }
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgrx_instrumentation_executor_end(query_desc: *mut pg_sys::QueryDesc) {
+    let totaltime = (*query_desc).totaltime;
+    if !totaltime.is_null() {
+        // make sure the totals are final
+        pg_sys::InstrEndLoop(totaltime);
+
+        let query = if (*query_desc).sourceText.is_null() {
+            ""
+        } else {
+            core::ffi::CStr::from_ptr((*query_desc).sourceText).to_str().unwrap_or_default()
+        };
+
+        let mut query_id = (*(*query_desc).plannedstmt).queryId as i64;
+        if query_id == 0 {
+            // Postgres didn't compute one, perhaps because `compute_query_id` is off
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            query.hash(&mut hasher);
+            query_id = hasher.finish() as i64;
+        }
+
+        if let Some(instance) = *std::ptr::addr_of!(INSTANCE) {
+            instance.record(
+                query_id,
+                query,
+                (*totaltime).total * 1000.0,
+                (*(*query_desc).estate).es_processed,
+                &(*totaltime).bufusage,
+            );
+        }
+    }
+
+    match PREV_EXECUTOR_END {
+        Some(prev) => prev(query_desc),
+        None => pg_sys::standard_ExecutorEnd(query_desc),
+    }
+}
+
+/// Creates a `#[pg_extern]` set-returning function, named `$fn_name`, reporting the statistics
+/// recorded by the [`ExecutorInstrumentation`] static `$instrumentation`
+#[macro_export]
+macro_rules! executor_instrumentation_view {
+    ($fn_name:ident, $instrumentation:expr) => {
+        #[::pgrx::pg_extern]
+        fn $fn_name() -> ::pgrx::iter::TableIterator<
+            'static,
+            (
+                ::pgrx::name!(queryid, i64),
+                ::pgrx::name!(query, String),
+                ::pgrx::name!(calls, i64),
+                ::pgrx::name!(total_time, f64),
+                ::pgrx::name!(min_time, f64),
+                ::pgrx::name!(max_time, f64),
+                ::pgrx::name!(mean_time, f64),
+                ::pgrx::name!(rows, i64),
+                ::pgrx::name!(shared_blks_hit, i64),
+                ::pgrx::name!(shared_blks_read, i64),
+                ::pgrx::name!(shared_blks_written, i64),
+            ),
+        > {
+            ::pgrx::iter::TableIterator::new(
+                $instrumentation.statements().into_iter().map(|stats| stats.into_tuple()),
+            )
+        }
+    };
+}
@@ -0,0 +1,291 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! A job queue kept in a table, for extensions that do work in the background
+//!
+//! Jobs are rows of a queue table holding a `jsonb` payload.  Any backend can
+//! [`enqueue()`](JobQueue::enqueue) one, and workers [`claim()`](JobQueue::claim) them with
+//! `FOR UPDATE SKIP LOCKED`, so concurrent workers never get the same job, and a job whose worker
+//! dies is simply claimed again once its transaction is rolled back.
+//!
+//! ```rust,no_run
+//! use pgrx::bgworkers::*;
+//! use pgrx::jobs::JobQueue;
+//! use pgrx::prelude::*;
+//! use std::time::Duration;
+//!
+//! const QUEUE: &str = "emails";
+//!
+//! #[pg_extern]
+//! fn create_email_queue() -> Result<(), spi::Error> {
+//!     JobQueue::new(QUEUE).create()
+//! }
+//!
+//! #[pg_extern]
+//! fn send_email(to: &str) -> Result<i64, spi::Error> {
+//!     JobQueue::new(QUEUE).enqueue(serde_json::json!({ "to": to }))
+//! }
+//!
+//! #[pg_guard]
+//! #[no_mangle]
+//! pub extern "C" fn email_worker(_arg: pg_sys::Datum) {
+//!     BackgroundWorker::attach_signal_handlers(SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM);
+//!     BackgroundWorker::connect_worker_to_spi(Some("postgres"), None);
+//!     JobQueue::new(QUEUE)
+//!         .run_worker(Duration::from_secs(1), |job| {
+//!             let to = job.payload["to"].as_str().ok_or("no address")?;
+//!             // ... send it ...
+//!             Ok(())
+//!         })
+//!         .unwrap();
+//! }
+//! ```
+//!
+//! A job whose handler returns an `Err`, or raises an ERROR, is rolled back and tried again later,
+//! waiting twice as long after each failure, until it has failed
+//! [`max_attempts()`](JobQueue::max_attempts) times.  Then it's marked `failed` and kept, with
+//! its `last_error`, for someone to look at.
+//!
+//! [`enqueue()`](JobQueue::enqueue) also sends a `NOTIFY` on the queue's
+//! [`channel()`](JobQueue::channel), with the job's id as the payload, once the enqueuing
+//! transaction commits.  Background workers can't `LISTEN`, which is why
+//! [`run_worker()`](JobQueue::run_worker) polls, but clients outside Postgres can.
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+use crate::bgworkers::BackgroundWorker;
+use crate::quote::{quote_ident, quote_literal, quote_qualified_ident};
+use crate::spi::{self, in_subtransaction, Spi};
+use crate::{IntoDatum, JsonB, PgBuiltInOids};
+use pgrx_pg_sys::panic::CaughtError;
+
+/// The longest [`JobQueue`] waits to retry a failed job
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60 * 60);
+
+/// A job claimed from a [`JobQueue`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+    pub id: i64,
+    pub payload: serde_json::Value,
+    /// How many times the job failed before
+    pub attempts: i32,
+}
+
+/// A queue of [`Job`]s in a table
+#[derive(Debug, Clone)]
+pub struct JobQueue {
+    schema: Option<String>,
+    table: String,
+    channel: String,
+    max_attempts: i32,
+    retry_delay: Duration,
+}
+
+impl JobQueue {
+    /// The queue in the table `table`, which [`create()`](JobQueue::create) creates.  Its
+    /// `NOTIFY` channel is also named `table`.
+    pub fn new(table: &str) -> Self {
+        JobQueue {
+            schema: None,
+            table: table.to_string(),
+            channel: table.to_string(),
+            max_attempts: 5,
+            retry_delay: Duration::from_secs(10),
+        }
+    }
+
+    /// The schema of the queue table, instead of the first one in the `search_path`
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// The channel [`enqueue()`](JobQueue::enqueue) notifies
+    pub fn channel(mut self, channel: &str) -> Self {
+        self.channel = channel.to_string();
+        self
+    }
+
+    /// Mark a job `failed` once it has failed this many times.  Defaults to 5.
+    pub fn max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Wait this long to retry a job the first time it fails, and twice as long after each
+    /// failure after that, up to an hour.  Defaults to 10 seconds.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// The queue table's name, quoted and schema-qualified as needed
+    pub fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => quote_qualified_ident(schema, &self.table),
+            None => quote_ident(&self.table),
+        }
+    }
+
+    /// The `CREATE TABLE` and `CREATE INDEX` statements for the queue table, such as to include
+    /// in an [`extension_sql!`](crate::extension_sql)
+    pub fn to_sql(&self) -> String {
+        let name = self.qualified_name();
+        format!(
+            "CREATE TABLE {name} (\n\
+            \tid bigint GENERATED ALWAYS AS IDENTITY PRIMARY KEY,\n\
+            \tpayload jsonb NOT NULL,\n\
+            \trun_at timestamp with time zone NOT NULL DEFAULT now(),\n\
+            \tattempts integer NOT NULL DEFAULT 0,\n\
+            \tfailed boolean NOT NULL DEFAULT false,\n\
+            \tlast_error text\n\
+            );\n\
+            CREATE INDEX {index} ON {name} (run_at, id) WHERE NOT failed;\n",
+            index = quote_ident(format!("{}_run_at_idx", self.table)),
+        )
+    }
+
+    /// Create the queue table
+    pub fn create(&self) -> Result<(), spi::Error> {
+        Spi::run(&self.to_sql())
+    }
+
+    /// Add a job to run as soon as possible, returning its id
+    pub fn enqueue(&self, payload: serde_json::Value) -> Result<i64, spi::Error> {
+        self.enqueue_after(payload, Duration::ZERO)
+    }
+
+    /// Add a job not to run until `delay` from now, returning its id
+    pub fn enqueue_after(
+        &self,
+        payload: serde_json::Value,
+        delay: Duration,
+    ) -> Result<i64, spi::Error> {
+        let id = Spi::get_one_with_args::<i64>(
+            &format!(
+                "INSERT INTO {} (payload, run_at) VALUES ($1, now() + make_interval(secs => $2)) \
+                RETURNING id",
+                self.qualified_name()
+            ),
+            vec![
+                (PgBuiltInOids::JSONBOID.oid(), JsonB(payload).into_datum()),
+                (PgBuiltInOids::FLOAT8OID.oid(), delay.as_secs_f64().into_datum()),
+            ],
+        )?
+        .expect("INSERT RETURNING returned no id");
+        Spi::run(&format!(
+            "NOTIFY {}, {}",
+            quote_ident(&self.channel),
+            quote_literal(id.to_string())
+        ))?;
+        Ok(id)
+    }
+
+    /// Lock the next job that's due, if any, until the current transaction ends.  It's gone
+    /// from the queue once [`complete()`](JobQueue::complete) is called and the transaction
+    /// commits.
+    pub fn claim(&self) -> Result<Option<Job>, spi::Error> {
+        Spi::connect(|mut client| {
+            let table = client.update(
+                &format!(
+                    "SELECT id, payload, attempts FROM {} WHERE NOT failed AND run_at <= now() \
+                    ORDER BY run_at, id LIMIT 1 FOR UPDATE SKIP LOCKED",
+                    self.qualified_name()
+                ),
+                None,
+                None,
+            )?;
+            if table.is_empty() {
+                return Ok(None);
+            }
+            let (id, payload, attempts) = table.first().get_three::<i64, JsonB, i32>()?;
+            Ok(Some(Job {
+                id: id.expect("job id is NULL"),
+                payload: payload.expect("job payload is NULL").0,
+                attempts: attempts.expect("job attempts is NULL"),
+            }))
+        })
+    }
+
+    /// Remove a finished job from the queue
+    pub fn complete(&self, id: i64) -> Result<(), spi::Error> {
+        Spi::run_with_args(
+            &format!("DELETE FROM {} WHERE id = $1", self.qualified_name()),
+            Some(vec![(PgBuiltInOids::INT8OID.oid(), id.into_datum())]),
+        )
+    }
+
+    /// Record that `job` failed with `error`, to retry it later or, once it has failed
+    /// [`max_attempts()`](JobQueue::max_attempts) times, mark it `failed`
+    pub fn fail(&self, job: &Job, error: &str) -> Result<(), spi::Error> {
+        let delay = self
+            .retry_delay
+            .checked_mul(2u32.saturating_pow(job.attempts.max(0) as u32))
+            .map_or(MAX_RETRY_DELAY, |delay| delay.min(MAX_RETRY_DELAY));
+        Spi::run_with_args(
+            &format!(
+                "UPDATE {} SET attempts = attempts + 1, last_error = $2, \
+                run_at = now() + make_interval(secs => $3), failed = attempts + 1 >= $4 \
+                WHERE id = $1",
+                self.qualified_name()
+            ),
+            Some(vec![
+                (PgBuiltInOids::INT8OID.oid(), job.id.into_datum()),
+                (PgBuiltInOids::TEXTOID.oid(), error.into_datum()),
+                (PgBuiltInOids::FLOAT8OID.oid(), delay.as_secs_f64().into_datum()),
+                (PgBuiltInOids::INT4OID.oid(), self.max_attempts.into_datum()),
+            ]),
+        )
+    }
+
+    /// Claim the next job that's due, if any, and run `handler` on it in a subtransaction, then
+    /// [`complete()`](JobQueue::complete) or [`fail()`](JobQueue::fail) it.  Returns whether
+    /// there was a job.
+    pub fn work_one<F: FnMut(&Job) -> Result<(), String>>(
+        &self,
+        mut handler: F,
+    ) -> Result<bool, spi::Error> {
+        let Some(job) = self.claim()? else { return Ok(false) };
+        match in_subtransaction(|| handler(&job)) {
+            Ok(Ok(())) => self.complete(job.id)?,
+            Ok(Err(error)) => self.fail(&job, &error)?,
+            Err(caught) => {
+                let ereport = match &caught {
+                    CaughtError::PostgresError(ereport)
+                    | CaughtError::ErrorReport(ereport)
+                    | CaughtError::RustPanic { ereport, .. } => ereport,
+                };
+                self.fail(&job, ereport.message())?
+            }
+        }
+        Ok(true)
+    }
+
+    /// A background worker's main loop: run every job that's due, each in its own transaction,
+    /// then wait `poll_interval` and look again, until the worker gets a `SIGTERM`
+    ///
+    /// The worker must have [connected to SPI](BackgroundWorker::connect_worker_to_spi) and
+    /// attached the `SIGTERM` handler.
+    pub fn run_worker<F: FnMut(&Job) -> Result<(), String>>(
+        &self,
+        poll_interval: Duration,
+        mut handler: F,
+    ) -> Result<(), spi::Error> {
+        loop {
+            while BackgroundWorker::transaction(AssertUnwindSafe(|| self.work_one(&mut handler)))? {
+                if BackgroundWorker::sigterm_received() {
+                    return Ok(());
+                }
+            }
+            if !BackgroundWorker::wait_latch(Some(poll_interval)) {
+                return Ok(());
+            }
+        }
+    }
+}
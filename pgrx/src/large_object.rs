@@ -0,0 +1,206 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Safe wrappers around Postgres' server-side large object (`pg_largeobject`) functions
+//!
+//! These call the same backend functions that implement the `lo_*` SQL functions, without the
+//! overhead of going through SPI.  Large object descriptors are only valid for the duration of
+//! the transaction in which they were opened.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{LargeObject, LargeObjectMode};
+//! use std::io::{Read, Write};
+//!
+//! #[pg_extern]
+//! fn lo_roundtrip(data: &[u8]) -> Vec<u8> {
+//!     let oid = LargeObject::create();
+//!
+//!     let mut lo = LargeObject::open(oid, LargeObjectMode::READ | LargeObjectMode::WRITE);
+//!     lo.write_all(data).unwrap();
+//!     lo.rewind().unwrap();
+//!
+//!     let mut contents = Vec::new();
+//!     lo.read_to_end(&mut contents).unwrap();
+//!     contents
+//! }
+//! ```
+use crate::{direct_function_call, pg_sys, IntoDatum};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+bitflags! {
+    /// The mode in which to open a [`LargeObject`]
+    pub struct LargeObjectMode: i32 {
+        const READ = 0x00040000;
+        const WRITE = 0x00020000;
+    }
+}
+
+/// The most [`LargeObject`] reads or writes in one call.  `loread` returns a `bytea`, and both
+/// copy the data into one, which can be at most `MaxAllocSize - VARHDRSZ` bytes.
+const MAX_CHUNK: usize = 1024 * 1024;
+
+/// An open large object descriptor
+///
+/// The descriptor is closed when this is dropped.  It implements [`Read`], [`Write`], and [`Seek`].
+/// Errors raised by Postgres, such as insufficient privileges, are raised as normal Postgres ERRORs.
+pub struct LargeObject {
+    fd: i32,
+    oid: pg_sys::Oid,
+}
+
+impl LargeObject {
+    /// Create a new, empty, large object, returning its assigned OID
+    pub fn create() -> pg_sys::Oid {
+        LargeObject::create_with_oid(pg_sys::InvalidOid)
+    }
+
+    /// Create a new, empty, large object with the specified OID.  Passing [`pg_sys::InvalidOid`]
+    /// lets Postgres assign one.
+    pub fn create_with_oid(oid: pg_sys::Oid) -> pg_sys::Oid {
+        unsafe {
+            direct_function_call::<pg_sys::Oid>(pg_sys::be_lo_create, &[oid.into_datum()])
+                .expect("lo_create returned NULL")
+        }
+    }
+
+    /// Create a new large object containing `bytes`, returning its assigned OID
+    pub fn from_bytes(bytes: &[u8]) -> pg_sys::Oid {
+        unsafe {
+            direct_function_call::<pg_sys::Oid>(
+                pg_sys::be_lo_from_bytea,
+                &[pg_sys::InvalidOid.into_datum(), bytes.into_datum()],
+            )
+            .expect("lo_from_bytea returned NULL")
+        }
+    }
+
+    /// Read the entire contents of the large object with the specified OID
+    pub fn get(oid: pg_sys::Oid) -> Vec<u8> {
+        unsafe {
+            direct_function_call::<Vec<u8>>(pg_sys::be_lo_get, &[oid.into_datum()])
+                .expect("lo_get returned NULL")
+        }
+    }
+
+    /// Delete the large object with the specified OID
+    pub fn unlink(oid: pg_sys::Oid) {
+        unsafe {
+            direct_function_call::<i32>(pg_sys::be_lo_unlink, &[oid.into_datum()]);
+        }
+    }
+
+    /// Open the large object with the specified OID
+    pub fn open(oid: pg_sys::Oid, mode: LargeObjectMode) -> LargeObject {
+        let fd = unsafe {
+            direct_function_call::<i32>(
+                pg_sys::be_lo_open,
+                &[oid.into_datum(), mode.bits().into_datum()],
+            )
+            .expect("lo_open returned NULL")
+        };
+        LargeObject { fd, oid }
+    }
+
+    /// The OID of this large object
+    pub fn oid(&self) -> pg_sys::Oid {
+        self.oid
+    }
+
+    /// The current read/write position
+    pub fn tell(&self) -> i64 {
+        unsafe {
+            direct_function_call::<i64>(pg_sys::be_lo_tell64, &[self.fd.into_datum()])
+                .expect("lo_tell64 returned NULL")
+        }
+    }
+
+    /// Truncate (or extend, with zeros) the large object to `len` bytes
+    pub fn truncate(&mut self, len: i64) {
+        unsafe {
+            direct_function_call::<i32>(
+                pg_sys::be_lo_truncate64,
+                &[self.fd.into_datum(), len.into_datum()],
+            );
+        }
+    }
+
+    /// Explicitly close this large object descriptor
+    pub fn close(self) {
+        // our Drop impl does the work
+    }
+}
+
+impl Read for LargeObject {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(MAX_CHUNK) as i32;
+        let bytes = unsafe {
+            direct_function_call::<&[u8]>(
+                pg_sys::be_loread,
+                &[self.fd.into_datum(), len.into_datum()],
+            )
+            .expect("loread returned NULL")
+        };
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Ok(bytes.len())
+    }
+}
+
+impl Write for LargeObject {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = unsafe {
+            direct_function_call::<i32>(
+                pg_sys::be_lowrite,
+                &[self.fd.into_datum(), buf[..buf.len().min(MAX_CHUNK)].into_datum()],
+            )
+            .expect("lowrite returned NULL")
+        };
+        Ok(written as usize)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for LargeObject {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (
+                offset.try_into().map_err(|_| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidInput, "seek offset overflow")
+                })?,
+                pg_sys::SEEK_SET,
+            ),
+            SeekFrom::Current(offset) => (offset, pg_sys::SEEK_CUR),
+            SeekFrom::End(offset) => (offset, pg_sys::SEEK_END),
+        };
+
+        let position = unsafe {
+            direct_function_call::<i64>(
+                pg_sys::be_lo_lseek64,
+                &[self.fd.into_datum(), offset.into_datum(), (whence as i32).into_datum()],
+            )
+            .expect("lo_lseek64 returned NULL")
+        };
+        Ok(position as u64)
+    }
+}
+
+impl Drop for LargeObject {
+    fn drop(&mut self) {
+        // if we're unwinding then the transaction is going to abort, and Postgres will close the
+        // descriptor for us.  Trying to do it here could raise another ERROR in the middle of a panic
+        if !std::thread::panicking() {
+            unsafe {
+                direct_function_call::<i32>(pg_sys::be_lo_close, &[self.fd.into_datum()]);
+            }
+        }
+    }
+}
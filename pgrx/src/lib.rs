@@ -39,39 +39,92 @@ pub mod prelude;
 
 pub mod aggregate;
 pub mod array;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "async")]
+pub mod async_support;
 pub mod atomics;
+pub mod backend_channel;
 pub mod bgworkers;
+pub mod brin;
+pub mod build_info;
+#[cfg(feature = "cshim")]
+pub mod builtins;
+pub mod bytea;
 pub mod callbacks;
+#[cfg(feature = "cshim")]
+pub mod client;
+pub mod compat;
 pub mod datum;
+pub mod ddl;
 pub mod enum_helper;
+#[cfg(feature = "cshim")]
+pub mod expr;
+#[cfg(feature = "failpoints")]
+pub mod failpoints;
 pub mod fcinfo;
 pub mod ffi;
 pub mod guc;
 pub mod heap_tuple;
 #[cfg(feature = "cshim")]
 pub mod hooks;
+#[cfg(feature = "http")]
+pub mod http;
 pub mod htup;
+pub mod init;
 pub mod inoutfuncs;
+pub mod instrumentation;
 pub mod itemptr;
 pub mod iter;
+pub mod jobs;
+pub mod large_object;
 #[cfg(feature = "cshim")]
 pub mod list;
+pub mod logging;
 pub mod lwlock;
 pub mod memcxt;
+pub mod memory_quota;
 pub mod misc;
 #[cfg(feature = "cshim")]
 pub mod namespace;
 pub mod nodes;
+pub mod oids;
+#[cfg(feature = "cshim")]
+pub mod parser;
+pub mod partition;
 pub mod pgbox;
+#[cfg(any(
+    feature = "pg12",
+    feature = "pg13",
+    feature = "pg14",
+    feature = "pg15",
+    feature = "pg16"
+))]
+pub mod planner_support;
+pub mod query_rewriter;
+pub mod quote;
 pub mod rel;
+pub mod resowner;
+pub mod schedule;
+pub mod session_cache;
 pub mod shmem;
+pub mod spgist;
 pub mod spi;
 #[cfg(feature = "cshim")]
 pub mod spinlock;
 pub mod srf;
 pub mod stringinfo;
+pub mod symbols;
+pub mod temp_file;
+pub mod temp_table;
+#[cfg(feature = "cshim")]
+pub mod text_search;
+pub mod timeout;
 pub mod trigger_support;
 pub mod tupdesc;
+pub mod tuple_writer;
+pub mod tuplesort;
+pub mod tuplestore;
 pub mod varlena;
 pub mod wrappers;
 pub mod xid;
@@ -86,16 +139,23 @@ mod toast;
 
 pub use aggregate::*;
 pub use atomics::*;
+pub use bytea::*;
 pub use callbacks::*;
+#[cfg(feature = "cshim")]
+pub use client::*;
 pub use datum::*;
 pub use enum_helper::*;
+#[cfg(feature = "cshim")]
+pub use expr::*;
 pub use fcinfo::*;
 pub use guc::*;
 #[cfg(feature = "cshim")]
 pub use hooks::*;
 pub use htup::*;
 pub use inoutfuncs::*;
+pub use instrumentation::*;
 pub use itemptr::*;
+pub use large_object::*;
 #[cfg(feature = "cshim")]
 pub use list::*;
 pub use lwlock::*;
@@ -103,13 +163,18 @@ pub use memcxt::*;
 #[cfg(feature = "cshim")]
 pub use namespace::*;
 pub use nodes::*;
+pub use oids::*;
 pub use pgbox::*;
 pub use rel::*;
+pub use session_cache::*;
 pub use shmem::*;
 pub use spi::Spi; // only Spi.  We don't want the top-level namespace polluted with spi::Result and spi::Error
 pub use stringinfo::*;
+pub use temp_file::*;
 pub use trigger_support::*;
 pub use tupdesc::*;
+pub use tuplesort::*;
+pub use tuplestore::*;
 pub use varlena::*;
 pub use wrappers::*;
 pub use xid::*;
@@ -168,12 +233,27 @@ const _: () = {
 /// </pre></div>
 ///
 /// This calls both [`pg_magic_func!()`](pg_magic_func) and [`pg_sql_graph_magic!()`](pg_sql_graph_magic).
+/// `pg_module_magic!(abi_extra = "...")` declares another ABI name, as described for
+/// [`pg_magic_func!()`](pg_magic_func).
+/// Without pgrx's `failpoints` feature, failpoints compile to nothing.  See `pgrx::failpoints`.
+#[cfg(not(feature = "failpoints"))]
+#[macro_export]
+macro_rules! fail_point {
+    ($name:expr) => {
+        ()
+    };
+}
+
 #[macro_export]
 macro_rules! pg_module_magic {
     () => {
         $crate::pg_magic_func!();
         $crate::pg_sql_graph_magic!();
     };
+    (abi_extra = $abi_extra:literal) => {
+        $crate::pg_magic_func!(abi_extra = $abi_extra);
+        $crate::pg_sql_graph_magic!();
+    };
 }
 
 /// Create the `Pg_magic_func` required by PGRX in extensions.
@@ -205,12 +285,29 @@ macro_rules! pg_module_magic {
 /// This macro was initially inspired from the `pg_module` macro by [Daniel Fagnan]
 /// and expanded by [Benjamin Fry].
 ///
+/// ## Declaring Another ABI
+///
+/// Every field of the magic block but one has to match the server exactly, so they're taken from
+/// the bindings.  From Postgres 15, the block also names the server's ABI, `"PostgreSQL"` unless
+/// it's a fork that changed it, and that name can be declared instead with
+/// `pg_magic_func!(abi_extra = "...")`.  That's for a fork known to be compatible with the
+/// Postgres the extension is built against, and Postgres won't load the library into any server
+/// with a different ABI name.
+///
 /// [Benjamin Fry]: https://github.com/bluejekyll/pg-extend-rs
 /// [Daniel Fagnan]: https://github.com/thehydroimpulse/postgres-extension.rs
 /// [Dynamic Loading]: https://www.postgresql.org/docs/current/xfunc-c.html#XFUNC-C-DYNLOAD
 #[macro_export]
 macro_rules! pg_magic_func {
     () => {
+        $crate::pg_magic_func!(@abi_extra pgrx::pg_sys::FMGR_ABI_EXTRA);
+    };
+    (abi_extra = $abi_extra:literal) => {
+        #[cfg(not(any(feature = "pg15", feature = "pg16")))]
+        ::core::compile_error!("the magic block only names an ABI from Postgres 15");
+        $crate::pg_magic_func!(@abi_extra ::core::concat!($abi_extra, "\0").as_bytes());
+    };
+    (@abi_extra $abi_extra:expr) => {
         #[no_mangle]
         #[allow(non_snake_case)]
         #[allow(unused)]
@@ -250,9 +347,11 @@ macro_rules! pg_magic_func {
                 namedatalen: pgrx::pg_sys::NAMEDATALEN as i32,
                 float8byval: cfg!(target_pointer_width = "64") as i32,
                 abi_extra: {
-                    // we'll use what the bindings tell us, but if it ain't "PostgreSQL" then we'll
-                    // raise a compilation error unless the `unsafe-postgres` feature is set
-                    let magic = pgrx::pg_sys::FMGR_ABI_EXTRA;
+                    // we'll use what the bindings tell us, unless another was declared, but if it
+                    // ain't "PostgreSQL" then we'll raise a compilation error unless the
+                    // `unsafe-postgres` feature is set
+                    let magic: &[u8] = $abi_extra;
+                    assert!(magic.len() <= 32, "`abi_extra` can be at most 31 bytes");
                     let mut abi = [0 as ::pgrx::ffi::c_char; 32];
                     let mut i = 0;
                     while i < magic.len() {
@@ -267,13 +366,17 @@ macro_rules! pg_magic_func {
             // calls this function first
             pgrx::initialize();
 
+            // then make sure the library fits the server, beyond what Postgres checks of the magic
+            pgrx::check_server_compatibility(env!("CARGO_CRATE_NAME"));
+
             // return the magic
             &MY_MAGIC
         }
     };
 }
 
-/// Create necessary extension-local internal marker for use with SQL generation.
+/// Create necessary extension-local internal markers for use with SQL generation and
+/// [`#[pg_init]`](macro@pg_init).
 ///
 /// <div class="example-wrap" style="display:inline-block">
 /// <pre class="ignore" style="white-space:normal;font:inherit;">
@@ -285,28 +388,16 @@ macro_rules! pg_magic_func {
 #[macro_export]
 macro_rules! pg_sql_graph_magic {
     () => {
-        // A marker which must exist in the root of the extension.
+        // A marker which must exist in the root of the extension.  `cargo pgrx schema` reads the
+        // control file itself, as it's named after the extension, not necessarily this crate.
 #[no_mangle]
         #[doc(hidden)]
         #[rustfmt::skip] // explicit extern "Rust" is more clear here
-        pub extern "Rust" fn __pgrx_marker(
-            _: (),
-        ) -> $crate::pgrx_sql_entity_graph::ControlFile {
-            use ::core::convert::TryFrom;
-            let package_version = env!("CARGO_PKG_VERSION");
-            let context = include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/",
-                env!("CARGO_CRATE_NAME"),
-                ".control"
-            ))
-            .replace("@CARGO_VERSION@", package_version);
+        pub extern "Rust" fn __pgrx_marker() {}
 
-            let control_file =
-                $crate::pgrx_sql_entity_graph::ControlFile::try_from(context.as_str())
-                    .expect("Could not parse control file, is it valid?");
-            control_file
-        }
+        // This library, which `#[pg_fini]` hands its function to `#[pg_init]` through
+        #[doc(hidden)]
+        pub struct __PgrxLibrary;
     };
 }
 
@@ -353,3 +444,82 @@ pub(crate) enum Utf8Compat {
 pub fn initialize() {
     pg_sys::panic::register_pg_guard_panic_hook();
 }
+
+/// Check that the running server is one the extension `library` can be loaded into
+///
+/// Postgres itself rejects a library whose magic block is for another major version, but not one
+/// built against a server configured with another block size, which the bindings have baked into
+/// the layout of pages and WAL records.  That's raised as an `ERROR` here, rather than left to be
+/// undefined behavior.  A library built against a newer minor release than the server's may use
+/// things the server doesn't have yet, which is a `WARNING`.
+///
+/// ## Note
+///
+/// This is called automatically by the [`pg_magic_func!()`] macro, before Postgres compares the
+/// magic block, and need not be called directly.
+#[doc(hidden)]
+pub fn check_server_compatibility(library: &str) {
+    fn setting(name: &str) -> Option<u32> {
+        let name = std::ffi::CString::new(name).ok()?;
+        // `GetConfigOption()` is the same in every supported major version, so it's safe to call
+        // before knowing the server is the major version the library was built for
+        let value = unsafe { pg_sys::GetConfigOption(name.as_ptr(), true, false) };
+        if value.is_null() {
+            return None;
+        }
+        unsafe { std::ffi::CStr::from_ptr(value) }.to_str().ok()?.parse().ok()
+    }
+
+    let Some(server_version) = setting("server_version_num") else { return };
+    if server_version / 10000 != pg_sys::PG_VERSION_NUM / 10000 {
+        // Postgres will refuse the magic block, naming both versions.  Nothing else of the
+        // server's can be safely called until then
+        return;
+    }
+
+    unsafe {
+        pg_sys::panic::pgrx_extern_c_guard(|| {
+            let sizes = [
+                ("block_size", pg_sys::BLCKSZ),
+                ("wal_block_size", pg_sys::XLOG_BLCKSZ),
+                ("segment_size", pg_sys::RELSEG_SIZE),
+            ];
+            for (name, built) in sizes {
+                match setting(name) {
+                    Some(server) if server != built => {
+                        pg_sys::panic::ErrorReport::new(
+                            PgSqlErrorCode::ERRCODE_FEATURE_NOT_SUPPORTED,
+                            format!(
+                                "incompatible library \"{library}\": {name} mismatch: server's is \
+                                 {server}, library was built for {built}"
+                            ),
+                            "check_server_compatibility",
+                        )
+                        .set_hint(
+                            "Rebuild the library with the `pg_config` of the Postgres it's loaded into.",
+                        )
+                        .report(PgLogLevel::ERROR);
+                    }
+                    _ => {}
+                }
+            }
+
+            if server_version < pg_sys::PG_VERSION_NUM {
+                pg_sys::panic::ErrorReport::new(
+                    PgSqlErrorCode::ERRCODE_WARNING,
+                    format!(
+                        "library \"{library}\" was built for Postgres {}.{}, newer than the \
+                         server's {}.{}",
+                        pg_sys::PG_VERSION_NUM / 10000,
+                        pg_sys::PG_VERSION_NUM % 10000,
+                        server_version / 10000,
+                        server_version % 10000,
+                    ),
+                    "check_server_compatibility",
+                )
+                .set_hint("Update the server, or rebuild the library against its minor release.")
+                .report(PgLogLevel::WARNING);
+            }
+        })
+    }
+}
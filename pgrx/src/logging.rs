@@ -0,0 +1,495 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Intercepting the server log, and routing Rust's `log` and `tracing` macros into it
+//!
+//! An [`EmitLogHook`] sees every entry on its way to the server log, and can rewrite or suppress
+//! it.  It's installed with [`register_emit_log_hook`] from `_PG_init()`.
+//!
+//! With the `log` feature, [`init_log_bridge`] installs a [`log::Log`] that reports the `log`
+//! crate's macros through `ereport()`, and with the `tracing` feature [`init_tracing_bridge`] does
+//! the same for `tracing` events.  Structured key-value fields are kept, as the entry's
+//! `DETAIL`.  Rust's levels are mapped to Postgres' by [`pg_log_level`]; none of them raise an
+//! `ERROR`, so logging never aborts the transaction.
+//!
+//! pgrx's own messages, below `ERROR`, can be quietened with [`set_min_level`] or the
+//! `pgrx.log_level` setting, which [`define_log_level_guc`] defines.  The `no-internal-debug-log`
+//! feature compiles its `DEBUG` messages out entirely.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//!
+//! #[pg_guard]
+//! pub extern "C" fn _PG_init() {
+//!     pgrx::logging::set_min_level(PgLogLevel::WARNING);
+//!     pgrx::logging::define_log_level_guc();
+//!     # #[cfg(feature = "log")]
+//!     pgrx::logging::init_log_bridge(log::LevelFilter::Info);
+//! }
+//! ```
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::guc::{GucContext, GucEnum, GucFlags, GucRegistry, GucSetting};
+use crate::pg_sys::panic::ErrorReport;
+use crate::pg_sys::AsPgCStr;
+use crate::prelude::*;
+use crate::PgMemoryContexts;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+/// Report one of pgrx's own messages at `level`, unless [`internal_log_enabled`] says it shouldn't
+/// be.  Never use this for `ERROR` and above, which can't be dropped.
+macro_rules! internal_log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::logging::internal_log_enabled($level) {
+            $crate::pg_sys::panic::ErrorReport::new(
+                $crate::logging::sql_error_code_for($level),
+                format!($($arg)*),
+                $crate::pg_sys::function_name!(),
+            )
+            .report($level);
+        }
+    };
+}
+pub(crate) use internal_log;
+
+static MIN_LEVEL: AtomicIsize = AtomicIsize::new(PgLogLevel::DEBUG5 as isize);
+static LOG_LEVEL_GUC_DEFINED: AtomicBool = AtomicBool::new(false);
+static LOG_LEVEL_GUC: GucSetting<LogLevel> = GucSetting::<LogLevel>::new(LogLevel::Debug5);
+
+/// The least severe level of pgrx's own messages that are reported.  The default, `DEBUG5`,
+/// leaves it to Postgres' `log_min_messages` and `client_min_messages` to decide.
+pub fn set_min_level(level: PgLogLevel) {
+    MIN_LEVEL.store(level as isize, Ordering::Relaxed);
+}
+
+/// The least severe level of pgrx's own messages that are reported: the more severe of what was
+/// given to [`set_min_level`] and the `pgrx.log_level` setting, if it's been defined
+pub fn min_level() -> PgLogLevel {
+    let level = PgLogLevel::from(MIN_LEVEL.load(Ordering::Relaxed));
+    if LOG_LEVEL_GUC_DEFINED.load(Ordering::Relaxed) {
+        level.max(LOG_LEVEL_GUC.get().into())
+    } else {
+        level
+    }
+}
+
+/// Would one of pgrx's own messages at `level` be reported?  `DEBUG` messages never are when
+/// compiled with the `no-internal-debug-log` feature.
+#[inline]
+pub fn internal_log_enabled(level: PgLogLevel) -> bool {
+    if cfg!(feature = "no-internal-debug-log") && level <= PgLogLevel::DEBUG1 {
+        return false;
+    }
+    level >= min_level()
+}
+
+#[doc(hidden)]
+pub fn sql_error_code_for(level: PgLogLevel) -> PgSqlErrorCode {
+    if level == PgLogLevel::WARNING {
+        PgSqlErrorCode::ERRCODE_WARNING
+    } else {
+        PgSqlErrorCode::ERRCODE_SUCCESSFUL_COMPLETION
+    }
+}
+
+/// The values of `pgrx.log_level`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Debug5,
+    Debug4,
+    Debug3,
+    Debug2,
+    Debug1,
+    Log,
+    Info,
+    Notice,
+    Warning,
+}
+
+impl LogLevel {
+    const ALL: [(LogLevel, &'static str); 9] = [
+        (LogLevel::Debug5, "debug5"),
+        (LogLevel::Debug4, "debug4"),
+        (LogLevel::Debug3, "debug3"),
+        (LogLevel::Debug2, "debug2"),
+        (LogLevel::Debug1, "debug1"),
+        (LogLevel::Log, "log"),
+        (LogLevel::Info, "info"),
+        (LogLevel::Notice, "notice"),
+        (LogLevel::Warning, "warning"),
+    ];
+}
+
+impl From<LogLevel> for PgLogLevel {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Debug5 => PgLogLevel::DEBUG5,
+            LogLevel::Debug4 => PgLogLevel::DEBUG4,
+            LogLevel::Debug3 => PgLogLevel::DEBUG3,
+            LogLevel::Debug2 => PgLogLevel::DEBUG2,
+            LogLevel::Debug1 => PgLogLevel::DEBUG1,
+            LogLevel::Log => PgLogLevel::LOG,
+            LogLevel::Info => PgLogLevel::INFO,
+            LogLevel::Notice => PgLogLevel::NOTICE,
+            LogLevel::Warning => PgLogLevel::WARNING,
+        }
+    }
+}
+
+impl GucEnum<LogLevel> for LogLevel {
+    fn from_ordinal(ordinal: i32) -> LogLevel {
+        LogLevel::ALL[ordinal as usize].0
+    }
+
+    fn to_ordinal(&self) -> i32 {
+        LogLevel::ALL.iter().position(|(level, _)| level == self).unwrap() as i32
+    }
+
+    unsafe fn config_matrix(&self) -> *const pg_sys::config_enum_entry {
+        let entries = PgMemoryContexts::TopMemoryContext
+            .palloc0_slice::<pg_sys::config_enum_entry>(LogLevel::ALL.len() + 1);
+        for (ordinal, (_, name)) in LogLevel::ALL.iter().enumerate() {
+            entries[ordinal].name = PgMemoryContexts::TopMemoryContext.pstrdup(name);
+            entries[ordinal].val = ordinal as i32;
+        }
+        entries.as_ptr()
+    }
+}
+
+/// Define the `pgrx.log_level` setting, which any user can raise to quieten pgrx's own messages.
+/// This should be called from `_PG_init()`, and only one extension loaded into a backend can
+/// define it.
+pub fn define_log_level_guc() {
+    GucRegistry::define_enum_guc(
+        "pgrx.log_level",
+        "The least severe level of pgrx's own messages that are reported",
+        "Messages below this level, and below any set by the extension, are dropped.",
+        &LOG_LEVEL_GUC,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+    LOG_LEVEL_GUC_DEFINED.store(true, Ordering::Relaxed);
+}
+
+/// A server log entry, as seen by an [`EmitLogHook`]
+pub struct LogEntry<'a> {
+    edata: &'a mut pg_sys::ErrorData,
+}
+
+unsafe fn str_from_pg<'a>(s: *const c_char) -> Option<&'a str> {
+    (!s.is_null()).then(|| CStr::from_ptr(s).to_str().ok()).flatten()
+}
+
+impl<'a> LogEntry<'a> {
+    /// ## Safety
+    ///
+    /// `edata` must be a valid `ErrorData`, such as the one given to `emit_log_hook`
+    pub unsafe fn from_pg(edata: *mut pg_sys::ErrorData) -> Self {
+        LogEntry { edata: &mut *edata }
+    }
+
+    pub fn level(&self) -> PgLogLevel {
+        self.edata.elevel.into()
+    }
+
+    pub fn sql_error_code(&self) -> PgSqlErrorCode {
+        self.edata.sqlerrcode.into()
+    }
+
+    /// The primary message, which is `None` if it isn't valid UTF-8
+    pub fn message(&self) -> Option<&str> {
+        unsafe { str_from_pg(self.edata.message) }
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        unsafe { str_from_pg(self.edata.detail) }
+    }
+
+    pub fn hint(&self) -> Option<&str> {
+        unsafe { str_from_pg(self.edata.hint) }
+    }
+
+    pub fn context(&self) -> Option<&str> {
+        unsafe { str_from_pg(self.edata.context) }
+    }
+
+    /// The source file that reported this entry
+    pub fn filename(&self) -> Option<&str> {
+        unsafe { str_from_pg(self.edata.filename) }
+    }
+
+    pub fn line_number(&self) -> i32 {
+        self.edata.lineno
+    }
+
+    /// The function that reported this entry
+    pub fn funcname(&self) -> Option<&str> {
+        unsafe { str_from_pg(self.edata.funcname) }
+    }
+
+    /// Will this entry be sent to the client?
+    pub fn output_to_client(&self) -> bool {
+        self.edata.output_to_client
+    }
+
+    /// Will this entry be written to the server log?
+    pub fn output_to_server(&self) -> bool {
+        self.edata.output_to_server
+    }
+
+    /// Keep this entry out of the server log.  It's still sent to the client, if it would have been
+    pub fn suppress(&mut self) {
+        self.edata.output_to_server = false;
+    }
+
+    /// Replace the primary message written to the server log, and sent to the client
+    pub fn set_message(&mut self, message: &str) {
+        self.edata.message = message.as_pg_cstr();
+    }
+
+    /// Replace the detail written to the server log.  The client sees this too, unless the entry
+    /// has a separate detail just for the log
+    pub fn set_detail(&mut self, detail: &str) {
+        self.edata.detail = detail.as_pg_cstr();
+    }
+
+    /// The underlying `ErrorData`
+    pub fn as_pg(&mut self) -> &mut pg_sys::ErrorData {
+        self.edata
+    }
+}
+
+/// Sees every entry on its way to the server log
+pub trait EmitLogHook {
+    /// Called for each entry that will be written to the server log.  This runs inside Postgres'
+    /// error reporting, so it must not raise an error itself, nor log anything: a panic here
+    /// becomes an `ERROR` raised while reporting another, which Postgres escalates to a `PANIC`
+    /// if it keeps happening.
+    fn emit_log(&mut self, entry: &mut LogEntry);
+}
+
+static mut EMIT_LOG_HOOK: Option<&'static mut dyn EmitLogHook> = None;
+static mut PREV_EMIT_LOG_HOOK: pg_sys::emit_log_hook_type = None;
+
+/// Install `hook` as the `emit_log_hook`, chained after whatever hook was installed before it.
+/// This should be called from `_PG_init()`.
+///
+/// ## Panics
+///
+/// If an [`EmitLogHook`] has already been registered
+pub unsafe fn register_emit_log_hook(hook: &'static mut dyn EmitLogHook) {
+    let registration = &mut *std::ptr::addr_of_mut!(EMIT_LOG_HOOK);
+    if registration.is_some() {
+        panic!("EmitLogHook already registered");
+    }
+    *registration = Some(hook);
+
+    PREV_EMIT_LOG_HOOK =
+        std::mem::replace(&mut *std::ptr::addr_of_mut!(pg_sys::emit_log_hook), Some(pgrx_emit_log));
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgrx_emit_log(edata: *mut pg_sys::ErrorData) {
+    if let Some(prev) = PREV_EMIT_LOG_HOOK {
+        prev(edata);
+    }
+
+    let hook = (*std::ptr::addr_of_mut!(EMIT_LOG_HOOK)).as_mut().unwrap();
+    hook.emit_log(&mut LogEntry::from_pg(edata));
+}
+
+/// The Postgres level Rust's `log` and `tracing` levels are reported at.  `error` is reported as a
+/// `WARNING`, since raising an `ERROR` would abort the transaction, and `info` as a `LOG`, which
+/// only goes to the server log, so that it doesn't reach the client by default.
+pub fn pg_log_level(level: RustLogLevel) -> PgLogLevel {
+    match level {
+        RustLogLevel::Error | RustLogLevel::Warn => PgLogLevel::WARNING,
+        RustLogLevel::Info => PgLogLevel::LOG,
+        RustLogLevel::Debug => PgLogLevel::DEBUG1,
+        RustLogLevel::Trace => PgLogLevel::DEBUG5,
+    }
+}
+
+/// The levels shared by the `log` and `tracing` crates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RustLogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+/// Report a message from the `log` or `tracing` bridge.  Messages from threads other than the one
+/// talking to Postgres are dropped, as there's nowhere safe to send them.
+fn report(
+    level: RustLogLevel,
+    message: String,
+    fields: Vec<String>,
+    module_path: Option<&'static str>,
+    location: Option<(&str, u32)>,
+) {
+    if !pg_sys::is_active_thread() {
+        return;
+    }
+
+    let level = pg_log_level(level);
+    let mut report =
+        ErrorReport::new(sql_error_code_for(level), message, module_path.unwrap_or_default());
+    if !fields.is_empty() {
+        report = report.set_detail(fields.join(", "));
+    }
+    if let Some((file, line)) = location {
+        report = report.set_location(file, line);
+    }
+    report.report(level);
+}
+
+#[cfg(feature = "log")]
+mod log_bridge {
+    use super::{report, RustLogLevel};
+
+    impl From<log::Level> for RustLogLevel {
+        fn from(level: log::Level) -> Self {
+            match level {
+                log::Level::Error => RustLogLevel::Error,
+                log::Level::Warn => RustLogLevel::Warn,
+                log::Level::Info => RustLogLevel::Info,
+                log::Level::Debug => RustLogLevel::Debug,
+                log::Level::Trace => RustLogLevel::Trace,
+            }
+        }
+    }
+
+    /// A [`log::Log`] that reports through `ereport()`
+    pub struct PgLogger;
+
+    struct Fields(Vec<String>);
+
+    impl<'kvs> log::kv::VisitSource<'kvs> for Fields {
+        fn visit_pair(
+            &mut self,
+            key: log::kv::Key<'kvs>,
+            value: log::kv::Value<'kvs>,
+        ) -> Result<(), log::kv::Error> {
+            self.0.push(format!("{key} = {value}"));
+            Ok(())
+        }
+    }
+
+    impl log::Log for PgLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::max_level()
+        }
+
+        fn log(&self, record: &log::Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+            let mut fields = Fields(Vec::new());
+            let _ = record.key_values().visit(&mut fields);
+            report(
+                record.level().into(),
+                record.args().to_string(),
+                fields.0,
+                record.module_path_static(),
+                record.file().zip(record.line()),
+            );
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Route the `log` crate's macros through `ereport()`, for records at or above `max_level`.
+    /// This should be called from `_PG_init()`, and does nothing if a logger is already installed.
+    pub fn init_log_bridge(max_level: log::LevelFilter) {
+        if log::set_logger(&PgLogger).is_ok() {
+            log::set_max_level(max_level);
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+pub use log_bridge::*;
+
+#[cfg(feature = "tracing")]
+mod tracing_bridge {
+    use super::{report, RustLogLevel};
+    use tracing::field::{Field, Visit};
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::Layer;
+
+    impl From<tracing::Level> for RustLogLevel {
+        fn from(level: tracing::Level) -> Self {
+            match level {
+                tracing::Level::ERROR => RustLogLevel::Error,
+                tracing::Level::WARN => RustLogLevel::Warn,
+                tracing::Level::INFO => RustLogLevel::Info,
+                tracing::Level::DEBUG => RustLogLevel::Debug,
+                tracing::Level::TRACE => RustLogLevel::Trace,
+            }
+        }
+    }
+
+    /// A [`tracing_subscriber::Layer`] that reports events through `ereport()`.  Spans aren't
+    /// reported.
+    pub struct PgTracingLayer;
+
+    #[derive(Default)]
+    struct Fields {
+        message: String,
+        fields: Vec<String>,
+    }
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message = value.to_string();
+            } else {
+                self.fields.push(format!("{} = {value}", field.name()));
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.message = format!("{value:?}");
+            } else {
+                self.fields.push(format!("{} = {value:?}", field.name()));
+            }
+        }
+    }
+
+    impl<S: Subscriber> tracing_subscriber::Layer<S> for PgTracingLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            report(
+                (*metadata.level()).into(),
+                fields.message,
+                fields.fields,
+                metadata.module_path(),
+                metadata.file().zip(metadata.line()),
+            );
+        }
+    }
+
+    /// Route `tracing` events at or above `max_level` through `ereport()`, by installing a
+    /// [`PgTracingLayer`] as the global default subscriber.  This should be called from
+    /// `_PG_init()`, and does nothing if a global subscriber is already installed.
+    pub fn init_tracing_bridge(max_level: tracing::level_filters::LevelFilter) {
+        let subscriber = tracing_subscriber::registry().with(PgTracingLayer.with_filter(max_level));
+        let _ = tracing::subscriber::set_global_default(subscriber);
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub use tracing_bridge::*;
@@ -266,7 +266,9 @@ impl PgMemoryContexts {
     }
 
     /// Set this MemoryContext as the `CurrentMemoryContext, returning whatever `CurrentMemoryContext` is
+    #[track_caller]
     pub unsafe fn set_as_current(&mut self) -> PgMemoryContexts {
+        pg_sys::debug_assert_backend_thread("PgMemoryContexts::set_as_current()");
         let old_context = pg_sys::CurrentMemoryContext;
 
         match self {
@@ -374,7 +376,9 @@ impl PgMemoryContexts {
     ///
     /// We also cannot ensure that the result of this function will stay allocated as long as Rust's
     /// borrow checker thinks it will.
+    #[track_caller]
     pub unsafe fn switch_to<R, F: FnOnce(&mut PgMemoryContexts) -> R>(&mut self, f: F) -> R {
+        pg_sys::debug_assert_backend_thread("PgMemoryContexts::switch_to()");
         match self {
             PgMemoryContexts::Transient {
                 parent,
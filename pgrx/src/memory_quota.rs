@@ -0,0 +1,183 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Bounding how much Postgres memory a function may allocate.
+//!
+//! [`with_memory_quota`] runs a closure with `CurrentMemoryContext` switched to a fresh memory
+//! context whose allocator checks every request against a limit.  When the limit would be
+//! exceeded, the request fails with a regular `ERROR` (`SQLSTATE 53200`, "out of memory"), which
+//! aborts the transaction cleanly instead of letting the backend grow until the OOM killer
+//! takes out the whole cluster.
+//!
+//! The same limit can be applied to an entire `#[pg_extern]` function with its `memory_quota`
+//! attribute:
+//!
+//! ```rust,ignore
+//! #[pg_extern(memory_quota = 16 * 1024 * 1024)]
+//! fn summarize(input: &str) -> String {
+//!     // palloc()s made while this runs are limited to 16MB
+//! }
+//! ```
+//!
+//! The quota is a budget on the bytes *requested* from the context while the closure runs.
+//! Memory that's freed along the way isn't credited back, so the quota is an upper bound on the
+//! context's peak size.  What is **not** counted:
+//!
+//! - Rust heap allocations (`Box`, `Vec`, `String`, ...), which don't go through Postgres
+//! - allocations in other memory contexts, including child contexts created by the closure and
+//!   the contexts SPI creates for itself
+//! - on Postgres 16, growth of an existing allocation with `repalloc()`, which no longer
+//!   dispatches through the context
+//! - rows a set-returning function's iterator produces after the function itself has returned
+//!
+//! The quota context is a child of the caller's `CurrentMemoryContext` and is released along with
+//! it, so anything allocated inside the closure stays valid for as long as it would have without
+//! a quota.
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::pg_sys;
+use crate::pg_sys::panic::ErrorReport;
+use crate::prelude::*;
+use crate::PgMemoryContexts;
+use std::cell::Cell;
+use std::ffi::c_void;
+use std::ffi::CStr;
+
+/// The methods installed on a quota context.  `methods` must stay the first field: Postgres only
+/// knows about it, and we get back to the rest by casting `context->methods`.
+#[repr(C)]
+struct QuotaMethods {
+    methods: pg_sys::MemoryContextMethods,
+    inner: pg_sys::MemoryContextMethods,
+    quota: usize,
+    used: Cell<usize>,
+}
+
+/// Run `f` with Postgres allocations in `CurrentMemoryContext` limited to `quota` bytes.
+///
+/// An allocation that would exceed the quota raises an `ERROR` with
+/// [`PgSqlErrorCode::ERRCODE_OUT_OF_MEMORY`].  See the [module documentation](self) for exactly
+/// what is counted.
+pub fn with_memory_quota<R, F: FnOnce() -> R>(quota: usize, f: F) -> R {
+    unsafe {
+        let context = create_quota_context(quota);
+        // restore the caller's context however `f` exits, including by unwinding from an ERROR
+        let _restore = RestoreContext(pg_sys::CurrentMemoryContext);
+        pg_sys::CurrentMemoryContext = context;
+        f()
+    }
+}
+
+/// Returns how many bytes have been counted against the quota of the innermost
+/// [`with_memory_quota`] call, or `None` if `CurrentMemoryContext` isn't a quota context.
+pub fn memory_quota_used() -> Option<usize> {
+    unsafe { quota_methods(pg_sys::CurrentMemoryContext).map(|methods| methods.used.get()) }
+}
+
+struct RestoreContext(pg_sys::MemoryContext);
+
+impl Drop for RestoreContext {
+    fn drop(&mut self) {
+        unsafe {
+            pg_sys::CurrentMemoryContext = self.0;
+        }
+    }
+}
+
+unsafe fn create_quota_context(quota: usize) -> pg_sys::MemoryContext {
+    let parent = pg_sys::CurrentMemoryContext;
+    let context = pg_sys::AllocSetContextCreateExtended(
+        parent,
+        b"pgrx memory quota\0".as_ptr().cast(),
+        pg_sys::ALLOCSET_SMALL_MINSIZE as usize,
+        pg_sys::ALLOCSET_SMALL_INITSIZE as usize,
+        pg_sys::ALLOCSET_DEFAULT_MAXSIZE as usize,
+    );
+
+    // the methods live in the parent so they outlive the quota context, which Postgres deletes
+    // before resetting the parent itself
+    let inner = *(*context).methods;
+    let mut methods = inner;
+    methods.alloc = Some(quota_alloc);
+    #[cfg(any(
+        feature = "pg11",
+        feature = "pg12",
+        feature = "pg13",
+        feature = "pg14",
+        feature = "pg15"
+    ))]
+    {
+        methods.realloc = Some(quota_realloc);
+    }
+
+    let quota_methods = PgMemoryContexts::For(parent).palloc_struct::<QuotaMethods>();
+    quota_methods.write(QuotaMethods { methods, inner, quota, used: Cell::new(0) });
+    (*context).methods = &(*quota_methods).methods;
+    context
+}
+
+unsafe fn quota_methods<'a>(context: pg_sys::MemoryContext) -> Option<&'a QuotaMethods> {
+    if context.is_null() {
+        return None;
+    }
+    let methods = (*context).methods;
+    let is_quota_context =
+        (*methods).alloc.map(|alloc| alloc as *const ()) == Some(quota_alloc as *const ());
+    is_quota_context.then(|| &*methods.cast::<QuotaMethods>())
+}
+
+/// Count `size` more bytes against the quota, raising an `ERROR` if that exceeds it
+unsafe fn charge(context: pg_sys::MemoryContext, methods: &QuotaMethods, size: usize) {
+    let used = methods.used.get().saturating_add(size);
+    if used > methods.quota {
+        let name = CStr::from_ptr((*context).name).to_string_lossy();
+        ErrorReport::new(PgSqlErrorCode::ERRCODE_OUT_OF_MEMORY, "out of memory", function_name!())
+            .set_detail(format!(
+                "Failed on request of size {size} in memory context \"{name}\", \
+                 which has a quota of {} bytes and has already used {}.",
+                methods.quota,
+                methods.used.get()
+            ))
+            .report(PgLogLevel::ERROR);
+    }
+    methods.used.set(used);
+}
+
+#[pg_guard]
+unsafe extern "C" fn quota_alloc(
+    context: pg_sys::MemoryContext,
+    size: pg_sys::Size,
+) -> *mut c_void {
+    let methods = &*(*context).methods.cast::<QuotaMethods>();
+    charge(context, methods, size);
+
+    let alloc = methods.inner.alloc.unwrap();
+    pg_sys::ffi::pg_guard_ffi_boundary(|| alloc(context, size))
+}
+
+#[cfg(any(
+    feature = "pg11",
+    feature = "pg12",
+    feature = "pg13",
+    feature = "pg14",
+    feature = "pg15"
+))]
+#[pg_guard]
+unsafe extern "C" fn quota_realloc(
+    context: pg_sys::MemoryContext,
+    pointer: *mut c_void,
+    size: pg_sys::Size,
+) -> *mut c_void {
+    let methods = &*(*context).methods.cast::<QuotaMethods>();
+    let current = methods.inner.get_chunk_space.unwrap();
+    let current = pg_sys::ffi::pg_guard_ffi_boundary(|| current(context, pointer));
+    charge(context, methods, size.saturating_sub(current));
+
+    let realloc = methods.inner.realloc.unwrap();
+    pg_sys::ffi::pg_guard_ffi_boundary(|| realloc(context, pointer, size))
+}
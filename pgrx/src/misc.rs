@@ -13,18 +13,38 @@ use std::hash::{Hash, Hasher};
 ///
 /// Primarily used by `pgrx`'s `#[derive(PostgresHash)]` macro.
 pub fn pgrx_seahash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = seahasher();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Like [`pgrx_seahash`], but mixes in a `seed`, as Postgres' "extended" hash support functions do.
+///
+/// A `seed` of zero produces the same hash as [`pgrx_seahash`], which Postgres requires of the
+/// two hash support functions of a hash operator class.  Primarily used by `pgrx`'s
+/// `#[derive(PostgresHash)]` macro.
+pub fn pgrx_seahash_extended<T: Hash>(value: &T, seed: u64) -> u64 {
+    if seed == 0 {
+        return pgrx_seahash(value);
+    }
+
+    let mut hasher = seahasher();
+    hasher.write_u64(seed);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn seahasher() -> seahash::SeaHasher {
     // taken from sources of "SeaHasher, v4.0.1" [Seahash](https://crates.io/crates/seahash)
     // assuming the underlying implementation doesn't change, we
     // also want to ensure however we seed it doesn't change either
     //
     // these hash values might be stored on disk by Postgres, so we can't afford
     // to have them changing over time
-    let mut hasher = seahash::SeaHasher::with_seeds(
+    seahash::SeaHasher::with_seeds(
         0x16f11fe89b0d677c,
         0xb480a793d8e6c86c,
         0x6fe2e5aaf078ebc9,
         0x14f994a4c5259381,
-    );
-    value.hash(&mut hasher);
-    hasher.finish()
+    )
 }
@@ -0,0 +1,223 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Object identifiers that say what kind of object they identify, and a per-session registry of
+//! type oids looked up by name
+//!
+//! A bare [`pg_sys::Oid`] could name a type, a relation, a function, or anything else Postgres
+//! keeps in a catalog.  [`TypeOid`], [`RelationOid`], and [`FunctionOid`] can only be mixed up
+//! on purpose, and they map to the SQL types `regtype`, `regclass`, and `regprocedure`, so a
+//! `#[pg_extern]` taking one is declared as taking an object of the right kind.
+//!
+//! Looking a type up by name means parsing the name and searching the catalogs for it along the
+//! `search_path`.  [`TypeOid::named()`] does that once per session for each name, and remembers
+//! the result until a type or schema changes.  Types created by extensions, including the
+//! `#[derive(PostgresType)]` ones of this extension, are found through it.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{PgRelation, RelationOid, TypeOid};
+//!
+//! #[pg_extern]
+//! fn column_type(table: RelationOid, column: &str) -> Option<TypeOid> {
+//!     let rel = unsafe { PgRelation::open(table.oid()) };
+//!     let att = rel.tuple_desc().iter().find(|att| att.name() == column)?;
+//!     Some(unsafe { TypeOid::from_oid_unchecked(att.type_oid().value()) })
+//! }
+//!
+//! fn hstore_oid() -> Option<TypeOid> {
+//!     TypeOid::named("hstore")
+//! }
+//! ```
+use crate::{pg_sys, PgOid};
+use crate::{
+    regtypein, FromDatum, IntoDatum, PgRelation, PgSqlErrorCode, PgTryBuilder, SessionCache,
+    SessionCacheKey,
+};
+use core::ffi::CStr;
+use core::fmt;
+use pgrx_sql_entity_graph::metadata::{
+    ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
+};
+
+macro_rules! typed_oid {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, $sql:literal, $typoid:ident) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name(pg_sys::Oid);
+
+        impl $name {
+            #[doc = concat!("The invalid oid, which identifies no ", $kind)]
+            pub const INVALID: $name = $name(pg_sys::InvalidOid);
+
+            #[doc = concat!("Treat `oid` as identifying a ", $kind)]
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("`oid` must be [`pg_sys::InvalidOid`] or identify a ", $kind, ".  Postgres trusts that")]
+            /// oids it is given identify objects of the kind it expects.
+            pub const unsafe fn from_oid_unchecked(oid: pg_sys::Oid) -> $name {
+                $name(oid)
+            }
+
+            /// The untyped oid
+            pub const fn oid(self) -> pg_sys::Oid {
+                self.0
+            }
+
+            /// Is this not [`Self::INVALID`]?
+            pub const fn is_valid(self) -> bool {
+                self.0.as_u32() != pg_sys::InvalidOid.as_u32()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> $name {
+                $name::INVALID
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl From<$name> for pg_sys::Oid {
+            fn from(oid: $name) -> pg_sys::Oid {
+                oid.0
+            }
+        }
+
+        impl From<$name> for PgOid {
+            fn from(oid: $name) -> PgOid {
+                PgOid::from(oid.0)
+            }
+        }
+
+        impl FromDatum for $name {
+            #[inline]
+            unsafe fn from_polymorphic_datum(
+                datum: pg_sys::Datum,
+                is_null: bool,
+                typoid: pg_sys::Oid,
+            ) -> Option<$name> {
+                pg_sys::Oid::from_polymorphic_datum(datum, is_null, typoid).map($name)
+            }
+        }
+
+        impl IntoDatum for $name {
+            #[inline]
+            fn into_datum(self) -> Option<pg_sys::Datum> {
+                self.0.into_datum()
+            }
+
+            #[inline]
+            fn type_oid() -> pg_sys::Oid {
+                pg_sys::$typoid
+            }
+
+            fn is_compatible_with(other: pg_sys::Oid) -> bool {
+                other == pg_sys::$typoid || other == pg_sys::OIDOID
+            }
+        }
+
+        unsafe impl SqlTranslatable for $name {
+            fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+                Ok(SqlMapping::literal($sql))
+            }
+            fn return_sql() -> Result<Returns, ReturnsError> {
+                Ok(Returns::One(SqlMapping::literal($sql)))
+            }
+        }
+    };
+}
+
+typed_oid!(
+    /// The oid of a type, a row of `pg_type`, which is a `regtype` in SQL
+    TypeOid,
+    "type",
+    "regtype",
+    REGTYPEOID
+);
+
+typed_oid!(
+    /// The oid of a relation, a row of `pg_class`, which is a `regclass` in SQL
+    RelationOid,
+    "relation",
+    "regclass",
+    REGCLASSOID
+);
+
+typed_oid!(
+    /// The oid of a function, a row of `pg_proc`, which is a `regprocedure` in SQL
+    FunctionOid,
+    "function",
+    "regprocedure",
+    REGPROCEDUREOID
+);
+
+impl TypeOid {
+    /// The type Postgres knows the Rust type `T` as
+    pub fn of<T: IntoDatum>() -> TypeOid {
+        TypeOid(T::type_oid())
+    }
+
+    /// The type named by the (possibly schema-qualified) `name`, as `regtype` would find it with
+    /// the current `search_path`, or `None` if there isn't one
+    ///
+    /// Each name is only looked up once per session, unless a type or schema is created, altered,
+    /// or dropped in the meantime.
+    pub fn named(name: &str) -> Option<TypeOid> {
+        let key = unsafe {
+            TypeName {
+                name: name.to_string(),
+                search_path: match pg_sys::namespace_search_path.is_null() {
+                    true => String::new(),
+                    false => CStr::from_ptr(pg_sys::namespace_search_path).to_string_lossy().into(),
+                },
+                user: pg_sys::GetUserId(),
+            }
+        };
+
+        TYPE_OIDS
+            .get_or_insert_with(key, |key| {
+                PgTryBuilder::new(|| Some(regtypein(&key.name)))
+                    .catch_when(PgSqlErrorCode::ERRCODE_UNDEFINED_OBJECT, |_| None)
+                    .execute()
+            })
+            .map(TypeOid)
+    }
+}
+
+impl From<&PgRelation> for RelationOid {
+    fn from(rel: &PgRelation) -> RelationOid {
+        RelationOid(rel.oid())
+    }
+}
+
+/// The type names resolved by [`TypeOid::named()`], which are flushed by any change to a type or
+/// a schema
+static TYPE_OIDS: SessionCache<TypeName, Option<pg_sys::Oid>> = SessionCache::with_syscaches(&[
+    pg_sys::SysCacheIdentifier_TYPEOID,
+    pg_sys::SysCacheIdentifier_TYPENAMENSP,
+    pg_sys::SysCacheIdentifier_NAMESPACEOID,
+]);
+
+/// An unqualified type name resolves differently with a different `search_path`, and `"$user"`
+/// in it depends on the current user
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct TypeName {
+    name: String,
+    search_path: String,
+    user: pg_sys::Oid,
+}
+
+impl SessionCacheKey for TypeName {}
@@ -0,0 +1,136 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Access to the running server's own SQL parser
+//!
+//! Unlike linking an external SQL parser, this always understands exactly the grammar of the
+//! Postgres version the extension was compiled for.  The result is Postgres' "raw" parse tree,
+//! which hasn't yet been through parse analysis, so names haven't been resolved against the catalog.
+//!
+//! ```rust,no_run
+//! use pgrx::parser::{parse, Statement};
+//!
+//! let stmts = parse("SELECT 1; DELETE FROM users").unwrap();
+//! assert_eq!(stmts.len(), 2);
+//! assert_eq!(stmts[1].sql(), "DELETE FROM users");
+//! match stmts[1].statement() {
+//!     Statement::Delete(delete) => assert!(delete.whereClause.is_null()),
+//!     _ => unreachable!(),
+//! }
+//! ```
+use crate::list::PgList;
+use crate::{pg_sys, PgSqlErrorCode, PgTryBuilder};
+use pg_sys::panic::CaughtError;
+
+/// A syntax error reported by the parser
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct ParseError {
+    pub message: String,
+}
+
+/// Parse `sql`, which may contain multiple semicolon-separated statements
+///
+/// Syntax errors are returned as a [`ParseError`].  The parse tree is allocated in the
+/// `CurrentMemoryContext`.
+pub fn parse(sql: &str) -> Result<Vec<RawStatement<'_>>, ParseError> {
+    let cstring = alloc::ffi::CString::new(sql)
+        .map_err(|_| ParseError { message: "SQL cannot contain NULL bytes".to_string() })?;
+
+    let list = PgTryBuilder::new(|| Ok(unsafe { pg_sys::pg_parse_query(cstring.as_ptr()) }))
+        .catch_when(PgSqlErrorCode::ERRCODE_SYNTAX_ERROR, |e| match e {
+            CaughtError::PostgresError(report) => {
+                Err(ParseError { message: report.message().to_string() })
+            }
+            e => e.rethrow(),
+        })
+        .execute()?;
+
+    let stmts = unsafe { PgList::<pg_sys::RawStmt>::from_pg(list) };
+    Ok(stmts.iter_ptr().map(|stmt| RawStatement { stmt: unsafe { &*stmt }, sql }).collect())
+}
+
+/// A single statement from the string given to [`parse`]
+#[derive(Debug, Clone, Copy)]
+pub struct RawStatement<'a> {
+    stmt: &'a pg_sys::RawStmt,
+    sql: &'a str,
+}
+
+impl<'a> RawStatement<'a> {
+    /// The text of just this statement, without any surrounding whitespace or semicolons
+    pub fn sql(&self) -> &'a str {
+        // a length of zero means "the rest of the string"
+        let start = self.stmt.stmt_location.max(0) as usize;
+        let end = match self.stmt.stmt_len {
+            0 => self.sql.len(),
+            len => start + len as usize,
+        };
+        self.sql[start..end].trim()
+    }
+
+    /// The `NodeTag` of the statement's parse tree
+    pub fn node_tag(&self) -> pg_sys::NodeTag {
+        unsafe { (*self.stmt.stmt).type_ }
+    }
+
+    /// The root of the statement's parse tree
+    pub fn node(&self) -> &'a pg_sys::Node {
+        unsafe { &*self.stmt.stmt }
+    }
+
+    /// The statement's parse tree, typed for the most common kinds of statements
+    pub fn statement(&self) -> Statement<'a> {
+        let node = self.stmt.stmt;
+        unsafe {
+            match (*node).type_ {
+                pg_sys::NodeTag_T_SelectStmt => Statement::Select(&*node.cast()),
+                pg_sys::NodeTag_T_InsertStmt => Statement::Insert(&*node.cast()),
+                pg_sys::NodeTag_T_UpdateStmt => Statement::Update(&*node.cast()),
+                pg_sys::NodeTag_T_DeleteStmt => Statement::Delete(&*node.cast()),
+                pg_sys::NodeTag_T_CreateStmt => Statement::Create(&*node.cast()),
+                pg_sys::NodeTag_T_AlterTableStmt => Statement::AlterTable(&*node.cast()),
+                pg_sys::NodeTag_T_DropStmt => Statement::Drop(&*node.cast()),
+                pg_sys::NodeTag_T_IndexStmt => Statement::Index(&*node.cast()),
+                pg_sys::NodeTag_T_ExplainStmt => Statement::Explain(&*node.cast()),
+                pg_sys::NodeTag_T_TransactionStmt => Statement::Transaction(&*node.cast()),
+                _ => Statement::Other(&*node),
+            }
+        }
+    }
+
+    /// Get the raw `RawStmt` pointer
+    pub fn as_ptr(&self) -> *mut pg_sys::RawStmt {
+        self.stmt as *const _ as *mut _
+    }
+}
+
+impl<'a> std::fmt::Display for RawStatement<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.stmt.to_string())
+    }
+}
+
+/// The parse tree of a [`RawStatement`]
+#[derive(Debug, Clone, Copy)]
+pub enum Statement<'a> {
+    Select(&'a pg_sys::SelectStmt),
+    Insert(&'a pg_sys::InsertStmt),
+    Update(&'a pg_sys::UpdateStmt),
+    Delete(&'a pg_sys::DeleteStmt),
+    Create(&'a pg_sys::CreateStmt),
+    AlterTable(&'a pg_sys::AlterTableStmt),
+    Drop(&'a pg_sys::DropStmt),
+    Index(&'a pg_sys::IndexStmt),
+    Explain(&'a pg_sys::ExplainStmt),
+    Transaction(&'a pg_sys::TransactionStmt),
+
+    /// Any other kind of statement.  Use [`RawStatement::node_tag`] to determine what it is
+    Other(&'a pg_sys::Node),
+}
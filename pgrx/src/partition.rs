@@ -0,0 +1,280 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Manage the partitions of a declaratively partitioned table, and route tuples to them
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::partition::{PartitionBound, RangeBound};
+//! use pgrx::PgRelation;
+//!
+//! let events = PgRelation::open_with_name_and_share_lock("events").unwrap();
+//! let january = events
+//!     .create_partition(
+//!         "events_2023_01",
+//!         &PartitionBound::Range {
+//!             from: vec![RangeBound::value("2023-01-01")],
+//!             to: vec![RangeBound::value("2023-02-01")],
+//!         },
+//!     )
+//!     .unwrap();
+//! events.detach_partition(&january).unwrap();
+//! ```
+use crate::spi::{self, quote_literal, quote_qualified_identifier};
+#[cfg(feature = "cshim")]
+use crate::{heap_tuple::PgHeapTuple, ExprError, ExprEvaluator, PgTupleDesc, WhoAllocated};
+use crate::{pg_sys, IntoDatum, PgBuiltInOids, PgRelation, Spi};
+
+/// The values of a partition, as in `FOR VALUES ...`
+///
+/// Values are given as text and quoted as literals, so Postgres converts them to the type of the
+/// partition key column they're for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartitionBound {
+    /// `FOR VALUES FROM (from) TO (to)`, with one bound per partition key column
+    Range { from: Vec<RangeBound>, to: Vec<RangeBound> },
+    /// `FOR VALUES IN (values)`, where `None` is `NULL`
+    List(Vec<Option<String>>),
+    /// `FOR VALUES WITH (MODULUS modulus, REMAINDER remainder)`
+    Hash { modulus: u32, remainder: u32 },
+    /// `DEFAULT`, for rows no other partition accepts
+    Default,
+}
+
+/// One column's lower or upper bound of a [`PartitionBound::Range`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeBound {
+    MinValue,
+    Value(String),
+    MaxValue,
+}
+
+impl RangeBound {
+    pub fn value(value: impl ToString) -> Self {
+        RangeBound::Value(value.to_string())
+    }
+}
+
+impl PartitionBound {
+    /// This bound as SQL, as it follows `PARTITION OF parent` or `ATTACH PARTITION name`
+    pub fn to_sql(&self) -> String {
+        fn range(bounds: &[RangeBound]) -> String {
+            let bounds: Vec<_> = bounds
+                .iter()
+                .map(|bound| match bound {
+                    RangeBound::MinValue => "MINVALUE".to_string(),
+                    RangeBound::Value(value) => quote_literal(value),
+                    RangeBound::MaxValue => "MAXVALUE".to_string(),
+                })
+                .collect();
+            bounds.join(", ")
+        }
+
+        match self {
+            PartitionBound::Range { from, to } => {
+                format!("FOR VALUES FROM ({}) TO ({})", range(from), range(to))
+            }
+            PartitionBound::List(values) => {
+                let values: Vec<_> = values
+                    .iter()
+                    .map(|value| value.as_ref().map_or("NULL".to_string(), quote_literal))
+                    .collect();
+                format!("FOR VALUES IN ({})", values.join(", "))
+            }
+            PartitionBound::Hash { modulus, remainder } => {
+                format!("FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})")
+            }
+            PartitionBound::Default => "DEFAULT".to_string(),
+        }
+    }
+}
+
+impl PgRelation {
+    fn qualified_name(&self) -> String {
+        quote_qualified_identifier(self.namespace(), self.name())
+    }
+
+    /// Create a new partition of this partitioned table called `name`, in the same schema, and
+    /// return it opened with an `AccessShareLock`
+    pub fn create_partition(
+        &self,
+        name: &str,
+        bound: &PartitionBound,
+    ) -> Result<PgRelation, spi::Error> {
+        let partition = quote_qualified_identifier(self.namespace(), name);
+        Spi::run(&format!(
+            "CREATE TABLE {partition} PARTITION OF {} {}",
+            self.qualified_name(),
+            bound.to_sql()
+        ))?;
+        Ok(PgRelation::open_with_name_and_share_lock(&partition)
+            .expect("the partition was just created"))
+    }
+
+    /// Attach an existing table as a partition of this partitioned table
+    ///
+    /// Postgres scans `partition` to check its rows fit `bound`, unless it has a `CHECK`
+    /// constraint proving they do.
+    pub fn attach_partition(
+        &self,
+        partition: &PgRelation,
+        bound: &PartitionBound,
+    ) -> Result<(), spi::Error> {
+        Spi::run(&format!(
+            "ALTER TABLE {} ATTACH PARTITION {} {}",
+            self.qualified_name(),
+            partition.qualified_name(),
+            bound.to_sql()
+        ))
+    }
+
+    /// Detach `partition` from this partitioned table, leaving it a standalone table
+    pub fn detach_partition(&self, partition: &PgRelation) -> Result<(), spi::Error> {
+        Spi::run(&format!(
+            "ALTER TABLE {} DETACH PARTITION {}",
+            self.qualified_name(),
+            partition.qualified_name()
+        ))
+    }
+
+    /// The OIDs of this table's immediate partitions
+    pub fn partitions(&self) -> Result<Vec<pg_sys::Oid>, spi::Error> {
+        Ok(Partition::children_of(self.oid())?.into_iter().map(|child| child.oid).collect())
+    }
+
+    /// The bound of this relation, if it's a partition
+    pub fn partition_bound(&self) -> Result<Option<String>, spi::Error> {
+        Spi::get_one_with_args(
+            "SELECT pg_get_expr(relpartbound, oid) FROM pg_class WHERE oid = $1",
+            vec![(PgBuiltInOids::OIDOID.oid(), self.oid().into_datum())],
+        )
+    }
+}
+
+/// A partition as [`PartitionRouter`] finds it in the catalogs
+#[cfg_attr(not(feature = "cshim"), allow(dead_code))]
+struct Partition {
+    oid: pg_sys::Oid,
+    is_partitioned: bool,
+    /// `None` for a default partition without siblings, which accepts anything
+    constraint: Option<String>,
+}
+
+impl Partition {
+    /// The partitions of `parent`, with the default partition, if any, last
+    fn children_of(parent: pg_sys::Oid) -> Result<Vec<Partition>, spi::Error> {
+        Spi::connect(|client| {
+            client
+                .select(
+                    "SELECT c.oid, c.relkind = 'p', pg_get_partition_constraintdef(c.oid)
+                       FROM pg_inherits i JOIN pg_class c ON c.oid = i.inhrelid
+                      WHERE i.inhparent = $1
+                      ORDER BY pg_get_expr(c.relpartbound, c.oid) = 'DEFAULT', c.oid",
+                    None,
+                    Some(vec![(PgBuiltInOids::OIDOID.oid(), parent.into_datum())]),
+                )?
+                .map(|row| {
+                    Ok(Partition {
+                        oid: row.get(1)?.expect("pg_class.oid is not null"),
+                        is_partitioned: row.get(2)?.unwrap_or(false),
+                        constraint: row.get(3)?,
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+/// Finds the partition a tuple belongs in, without inserting it
+///
+/// Each partition's constraint is compiled once, when the router is created.  Routing then
+/// evaluates them in turn, in memory and without SPI, descending into sub-partitions as needed.
+/// Create a new router after partitions are created, attached, or detached.
+///
+/// Only available with the `cshim` feature, which is on by default.
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::partition::PartitionRouter;
+/// use pgrx::PgRelation;
+///
+/// let events = PgRelation::open_with_name_and_share_lock("events").unwrap();
+/// let mut router = PartitionRouter::new(&events).unwrap();
+/// let mut event = PgHeapTuple::new_composite_type("events").unwrap();
+/// event.set_by_name("id", 42_i64).unwrap();
+/// let partition = unsafe { router.route(&event) }.unwrap();
+/// ```
+#[cfg(feature = "cshim")]
+pub struct PartitionRouter {
+    partitions: Vec<RoutedPartition>,
+}
+
+#[cfg(feature = "cshim")]
+struct RoutedPartition {
+    oid: pg_sys::Oid,
+    /// `None` if the partition accepts anything
+    constraint: Option<ExprEvaluator<'static>>,
+    /// Set if the partition is itself partitioned
+    subpartitions: Option<PartitionRouter>,
+}
+
+#[cfg(feature = "cshim")]
+impl PartitionRouter {
+    /// A router for the partitions of the partitioned table `parent`, compiled in the
+    /// `CurrentMemoryContext`
+    pub fn new(parent: &PgRelation) -> Result<Self, spi::Error> {
+        // every partition's constraint is in terms of the root's columns, by name
+        let tupdesc = unsafe { PgTupleDesc::from_pg_copy(parent.rd_att) };
+        Self::for_partitions_of(parent.oid(), &tupdesc)
+    }
+
+    fn for_partitions_of(
+        parent: pg_sys::Oid,
+        tupdesc: &PgTupleDesc<'static>,
+    ) -> Result<Self, spi::Error> {
+        let mut partitions = Vec::new();
+        for partition in Partition::children_of(parent)? {
+            let constraint = partition.constraint.map(|constraint| {
+                ExprEvaluator::compile(&constraint, tupdesc.clone())
+                    .expect("a partition constraint is a boolean expression")
+            });
+            let subpartitions = if partition.is_partitioned {
+                Some(Self::for_partitions_of(partition.oid, tupdesc)?)
+            } else {
+                None
+            };
+            partitions.push(RoutedPartition { oid: partition.oid, constraint, subpartitions });
+        }
+        Ok(PartitionRouter { partitions })
+    }
+
+    /// The OID of the leaf partition `tuple` belongs in, or `None` if no partition accepts it
+    ///
+    /// ## Safety
+    ///
+    /// `tuple` must be described by the tuple descriptor of the table this router was created for
+    pub unsafe fn route<AllocatedBy: WhoAllocated>(
+        &mut self,
+        tuple: &PgHeapTuple<'_, AllocatedBy>,
+    ) -> Result<Option<pg_sys::Oid>, ExprError> {
+        for partition in &mut self.partitions {
+            let accepts = match &mut partition.constraint {
+                Some(constraint) => constraint.matches(tuple)?,
+                None => true,
+            };
+            if accepts {
+                return match &mut partition.subpartitions {
+                    Some(subpartitions) => subpartitions.route(tuple),
+                    None => Ok(Some(partition.oid)),
+                };
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,176 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Helpers for writing planner support functions, attached to a `#[pg_extern]` function with its
+//! `support = ...` attribute
+//!
+//! The planner calls a function's support function with requests, such as
+//! [`pg_sys::SupportRequestSimplify`], that let it replace the call with something cheaper or
+//! estimate its selectivity, cost, and number of rows more accurately.  Support functions require
+//! Postgres 12 or later.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::planner_support::{self, SupportRequest};
+//! use pgrx::{Internal, PgList};
+//!
+//! #[pg_extern(immutable, parallel_safe, support = plus_support)]
+//! fn plus(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! /// `plus(x, 0)` is just `x`
+//! #[pg_extern(immutable, parallel_safe)]
+//! fn plus_support(request: Internal) -> Internal {
+//!     unsafe {
+//!         if let Some(SupportRequest::Simplify(request)) = SupportRequest::from_internal(request) {
+//!             let args = PgList::<pg_sys::Node>::from_pg((*request.fcall).args);
+//!             let (x, y) = (args.get_ptr(0).unwrap(), args.get_ptr(1).unwrap());
+//!             if pgrx::is_a(y, pg_sys::NodeTag_T_Const) {
+//!                 let y = &*y.cast::<pg_sys::Const>();
+//!                 if !y.constisnull && i32::from_datum(y.constvalue, false) == Some(0) {
+//!                     return planner_support::replace_with(x);
+//!                 }
+//!             }
+//!         }
+//!         planner_support::unsupported()
+//!     }
+//! }
+//! ```
+//!
+//! A function that always returns the same result for the same arguments can instead be given
+//! `#[pg_extern(fold_constants)]`, which generates a support function that calls it at plan time,
+//! using [`fold_constants`], so that calls such as `my_fn(3)` are replaced by their result.
+use crate::datum::Internal;
+use crate::{is_a, pg_sys};
+
+/// A request from the planner to a support function
+pub enum SupportRequest<'a> {
+    /// Replace the function call, `request.fcall`, with a simpler expression
+    Simplify(&'a mut pg_sys::SupportRequestSimplify),
+    /// Estimate the selectivity of the function, used as a boolean condition, by setting
+    /// `request.selectivity`
+    Selectivity(&'a mut pg_sys::SupportRequestSelectivity),
+    /// Estimate the cost of evaluating the function by setting `request.startup` and
+    /// `request.per_tuple`
+    Cost(&'a mut pg_sys::SupportRequestCost),
+    /// Estimate how many rows the set-returning function returns by setting `request.rows`
+    Rows(&'a mut pg_sys::SupportRequestRows),
+    /// Derive an index condition from the function's use in a `WHERE` clause
+    IndexCondition(&'a mut pg_sys::SupportRequestIndexCondition),
+    /// A request added by a later version of Postgres
+    Other(&'a mut pg_sys::Node),
+}
+
+impl<'a> SupportRequest<'a> {
+    /// Interpret the `internal` argument given to a support function
+    ///
+    /// ## Safety
+    ///
+    /// `request` must be the argument the planner gave to a support function
+    pub unsafe fn from_internal(request: Internal) -> Option<SupportRequest<'a>> {
+        let node = request.unwrap()?.cast_mut_ptr::<pg_sys::Node>();
+        let node = node.as_mut()?;
+        Some(match node.type_ {
+            pg_sys::NodeTag_T_SupportRequestSimplify => {
+                SupportRequest::Simplify(&mut *(node as *mut pg_sys::Node).cast())
+            }
+            pg_sys::NodeTag_T_SupportRequestSelectivity => {
+                SupportRequest::Selectivity(&mut *(node as *mut pg_sys::Node).cast())
+            }
+            pg_sys::NodeTag_T_SupportRequestCost => {
+                SupportRequest::Cost(&mut *(node as *mut pg_sys::Node).cast())
+            }
+            pg_sys::NodeTag_T_SupportRequestRows => {
+                SupportRequest::Rows(&mut *(node as *mut pg_sys::Node).cast())
+            }
+            pg_sys::NodeTag_T_SupportRequestIndexCondition => {
+                SupportRequest::IndexCondition(&mut *(node as *mut pg_sys::Node).cast())
+            }
+            _ => SupportRequest::Other(node),
+        })
+    }
+}
+
+/// The result of a support function that doesn't handle the request, telling the planner to
+/// carry on as if there were no support function
+pub fn unsupported() -> Internal {
+    // the planner treats a NULL pointer as "unsupported", but raises an ERROR if the function
+    // returns an SQL NULL
+    Internal::from(Some(pg_sys::Datum::from(0usize)))
+}
+
+/// The result of a support function that handled a [`SupportRequest::Simplify`] request by
+/// replacing the function call with `expr`
+pub fn replace_with(expr: *mut pg_sys::Node) -> Internal {
+    Internal::from(Some(pg_sys::Datum::from(expr)))
+}
+
+/// The result of a support function that handled any other request by filling in its fields
+pub fn handled<T>(request: &mut T) -> Internal {
+    Internal::from(Some(pg_sys::Datum::from(request as *mut T)))
+}
+
+/// A support function for a function that always returns the same result for the same
+/// arguments: it handles a [`SupportRequest::Simplify`] request for a call whose arguments are all
+/// constants by calling the function at plan time and replacing the call with its result.
+///
+/// This is what `#[pg_extern(fold_constants)]` generates a support function around.  Postgres
+/// already does this for `IMMUTABLE` functions, but using a support function lets the planner fold
+/// calls to a function whatever its volatility, so only use it for functions without side effects.
+///
+/// ## Safety
+///
+/// `request` must be the argument the planner gave to a support function
+pub unsafe fn fold_constants(request: Internal) -> Internal {
+    let Some(SupportRequest::Simplify(request)) = SupportRequest::from_internal(request) else {
+        return unsupported();
+    };
+    let fcall = request.fcall;
+    if (*fcall).funcretset {
+        return unsupported();
+    }
+    if !all_const((*fcall).args) {
+        return unsupported();
+    }
+    let folded = pg_sys::evaluate_expr(
+        fcall.cast(),
+        (*fcall).funcresulttype,
+        pg_sys::exprTypmod(fcall.cast()),
+        (*fcall).funccollid,
+    );
+    replace_with(folded.cast())
+}
+
+/// Are all of the expressions in `args` constants?  The list is walked directly, rather than
+/// through `PgList`, so that this works without the `cshim` feature.
+#[cfg(feature = "pg12")]
+unsafe fn all_const(args: *mut pg_sys::List) -> bool {
+    if args.is_null() {
+        return true;
+    }
+    let mut cell = (*args).head;
+    while !cell.is_null() {
+        if !is_a((*cell).data.ptr_value.cast(), pg_sys::NodeTag_T_Const) {
+            return false;
+        }
+        cell = (*cell).next;
+    }
+    true
+}
+
+/// Are all of the expressions in `args` constants?
+#[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15", feature = "pg16"))]
+unsafe fn all_const(args: *mut pg_sys::List) -> bool {
+    if args.is_null() {
+        return true;
+    }
+    let elements = std::slice::from_raw_parts((*args).elements, (*args).length as usize);
+    elements.iter().all(|cell| is_a(cell.ptr_value.cast(), pg_sys::NodeTag_T_Const))
+}
@@ -21,7 +21,7 @@ pub use crate::{default, name};
 pub use crate::iter::{SetOfIterator, TableIterator};
 
 // Needed for complex returns and Triggers
-pub use crate::heap_tuple::{PgHeapTuple, PgHeapTupleError};
+pub use crate::heap_tuple::{PgHeapTuple, PgHeapTupleError, RecordBuilder};
 pub use crate::pgbox::{AllocatedByPostgres, AllocatedByRust, PgBox, WhoAllocated};
 
 // These could be factored into a temporal type module that could be easily imported for code which works with them.
@@ -0,0 +1,177 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! A higher-level alternative to the raw `planner_hook` for extensions that want to rewrite queries
+//! before they're planned
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::query_rewriter::{copy_query, register_query_rewriter, QueryRewriter};
+//!
+//! struct NoOrderBy;
+//!
+//! impl QueryRewriter for NoOrderBy {
+//!     fn rewrite(&mut self, query: &pg_sys::Query) -> Option<PgBox<pg_sys::Query>> {
+//!         if query.sortClause.is_null() {
+//!             // nothing to do, plan the query as-is
+//!             return None;
+//!         }
+//!
+//!         let mut query = copy_query(query);
+//!         query.sortClause = std::ptr::null_mut();
+//!         Some(query)
+//!     }
+//! }
+//!
+//! #[pg_guard]
+//! pub extern "C" fn _PG_init() {
+//!     static mut REWRITER: NoOrderBy = NoOrderBy;
+//!     unsafe { register_query_rewriter(&mut REWRITER) };
+//! }
+//! ```
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::prelude::*;
+
+/// Receives every query before it's planned, and may replace it with a different one
+pub trait QueryRewriter {
+    /// Return `None` to plan `query` unchanged, or a replacement query to plan instead.
+    ///
+    /// `query` may be part of a cached plan, so it must not be modified in place.  Use [`copy_query`]
+    /// to make a copy that's safe to modify.
+    ///
+    /// Any queries planned while this function is running, such as through [`Spi`], are not
+    /// themselves passed to the rewriter.
+    fn rewrite(&mut self, query: &pg_sys::Query) -> Option<PgBox<pg_sys::Query>>;
+}
+
+/// Make a deep copy of `query`, allocated in the `CurrentMemoryContext`, which can then be freely
+/// modified
+pub fn copy_query(query: &pg_sys::Query) -> PgBox<pg_sys::Query> {
+    unsafe {
+        PgBox::from_pg(
+            pg_sys::copyObjectImpl((query as *const pg_sys::Query).cast()).cast::<pg_sys::Query>(),
+        )
+    }
+}
+
+struct Registration {
+    rewriter: &'static mut dyn QueryRewriter,
+    prev_planner_hook: pg_sys::planner_hook_type,
+}
+
+static mut REGISTRATION: Option<Registration> = None;
+
+/// Are we currently inside [`QueryRewriter::rewrite`]?
+static mut REWRITING: bool = false;
+
+/// Install `rewriter` as a `planner_hook`, chained to whatever hook was installed before it.  This
+/// should be called from `_PG_init()`.
+///
+/// ## Panics
+///
+/// If a [`QueryRewriter`] has already been registered
+pub unsafe fn register_query_rewriter(rewriter: &'static mut dyn QueryRewriter) {
+    let registration = &mut *std::ptr::addr_of_mut!(REGISTRATION);
+    if registration.is_some() {
+        panic!("QueryRewriter instance already registered");
+    }
+
+    let prev_planner_hook = std::mem::replace(
+        &mut *std::ptr::addr_of_mut!(pg_sys::planner_hook),
+        Some(pgrx_rewriter_planner),
+    );
+    *registration = Some(Registration {
+        rewriter,
+        prev_planner_hook: prev_planner_hook.or(Some(pgrx_standard_planner_wrapper)),
+    });
+}
+
+/// Resets [`REWRITING`] when dropped, even if the rewriter raised an ERROR
+struct RecursionGuard;
+
+impl RecursionGuard {
+    unsafe fn enter() -> Self {
+        REWRITING = true;
+        RecursionGuard
+    }
+}
+
+impl Drop for RecursionGuard {
+    fn drop(&mut self) {
+        unsafe {
+            REWRITING = false;
+        }
+    }
+}
+
+#[cfg(any(feature = "pg11", feature = "pg12"))]
+#[pg_guard]
+unsafe extern "C" fn pgrx_rewriter_planner(
+    parse: *mut pg_sys::Query,
+    cursor_options: i32,
+    bound_params: pg_sys::ParamListInfo,
+) -> *mut pg_sys::PlannedStmt {
+    let parse = rewrite(parse);
+    ((*std::ptr::addr_of!(REGISTRATION)).as_ref().unwrap().prev_planner_hook.unwrap())(
+        parse,
+        cursor_options,
+        bound_params,
+    )
+}
+
+#[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15", feature = "pg16"))]
+#[pg_guard]
+unsafe extern "C" fn pgrx_rewriter_planner(
+    parse: *mut pg_sys::Query,
+    query_string: *const ::std::os::raw::c_char,
+    cursor_options: i32,
+    bound_params: pg_sys::ParamListInfo,
+) -> *mut pg_sys::PlannedStmt {
+    let parse = rewrite(parse);
+    ((*std::ptr::addr_of!(REGISTRATION)).as_ref().unwrap().prev_planner_hook.unwrap())(
+        parse,
+        query_string,
+        cursor_options,
+        bound_params,
+    )
+}
+
+unsafe fn rewrite(parse: *mut pg_sys::Query) -> *mut pg_sys::Query {
+    if REWRITING {
+        return parse;
+    }
+
+    let _guard = RecursionGuard::enter();
+    let rewriter = &mut (*std::ptr::addr_of_mut!(REGISTRATION)).as_mut().unwrap().rewriter;
+    match rewriter.rewrite(&*parse) {
+        Some(rewritten) => rewritten.into_pg(),
+        None => parse,
+    }
+}
+
+#[cfg(any(feature = "pg11", feature = "pg12"))]
+#[pg_guard]
+unsafe extern "C" fn pgrx_standard_planner_wrapper(
+    parse: *mut pg_sys::Query,
+    cursor_options: i32,
+    bound_params: pg_sys::ParamListInfo,
+) -> *mut pg_sys::PlannedStmt {
+    pg_sys::standard_planner(parse, cursor_options, bound_params)
+}
+
+#[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15", feature = "pg16"))]
+#[pg_guard]
+unsafe extern "C" fn pgrx_standard_planner_wrapper(
+    parse: *mut pg_sys::Query,
+    query_string: *const ::std::os::raw::c_char,
+    cursor_options: i32,
+    bound_params: pg_sys::ParamListInfo,
+) -> *mut pg_sys::PlannedStmt {
+    pg_sys::standard_planner(parse, query_string, cursor_options, bound_params)
+}
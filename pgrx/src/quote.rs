@@ -0,0 +1,85 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Quote identifiers and literals for dynamically built SQL, using the server's own routines
+//!
+//! Anything interpolated into a query that came from outside the extension must be quoted, or
+//! passed as a query argument, else it's an SQL injection.  The [`sql!`](crate::sql) macro quotes
+//! each interpolated value according to how it's used:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//!
+//! let table = "my table";
+//! let name = "O'Brien";
+//! let query = sql!("SELECT * FROM {ident}.{table:ident} WHERE name = {literal}", "public", name);
+//! assert_eq!(query, r#"SELECT * FROM public."my table" WHERE name = 'O''Brien'"#);
+//! ```
+use core::ffi::CStr;
+use std::ffi::CString;
+
+use crate::pg_sys;
+
+/// Quote `ident` as an identifier, if it needs quoting, as the SQL function `quote_ident()` does
+///
+/// ## Panics
+///
+/// If `ident` contains a NUL byte
+pub fn quote_ident(ident: impl AsRef<str>) -> String {
+    let ident = CString::new(ident.as_ref()).expect("identifier contains a NUL byte");
+    // SAFETY: quote_identifier expects a null terminated string and returns one
+    unsafe { CStr::from_ptr(pg_sys::quote_identifier(ident.as_ptr())) }
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+/// Quote `qualifier` and `ident` as identifiers and join them with a `.`, such as for a
+/// schema-qualified table name like `"my schema"."my table"`
+///
+/// ## Panics
+///
+/// If `qualifier` or `ident` contains a NUL byte
+pub fn quote_qualified_ident(qualifier: impl AsRef<str>, ident: impl AsRef<str>) -> String {
+    let qualifier = CString::new(qualifier.as_ref()).expect("qualifier contains a NUL byte");
+    let ident = CString::new(ident.as_ref()).expect("identifier contains a NUL byte");
+    // SAFETY: quote_qualified_identifier expects null terminated strings and returns one
+    unsafe {
+        CStr::from_ptr(pg_sys::quote_qualified_identifier(qualifier.as_ptr(), ident.as_ptr()))
+    }
+    .to_str()
+    .unwrap()
+    .to_string()
+}
+
+/// Quote `literal` as a string literal, as the SQL function `quote_literal()` does
+///
+/// Postgres converts the literal to whatever type the context it's used in requires, so this is
+/// suitable for numbers, dates, and so on, as well as text.
+///
+/// ## Panics
+///
+/// If `literal` contains a NUL byte
+pub fn quote_literal(literal: impl AsRef<str>) -> String {
+    let literal = CString::new(literal.as_ref()).expect("literal contains a NUL byte");
+    // SAFETY: quote_literal_cstr expects a null terminated string and returns one
+    unsafe { CStr::from_ptr(pg_sys::quote_literal_cstr(literal.as_ptr())) }
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+/// Quote `literal` as [`quote_literal()`] does, or return `NULL` if it's `None`, as the SQL
+/// function `quote_nullable()` does
+pub fn quote_nullable(literal: Option<impl AsRef<str>>) -> String {
+    match literal {
+        Some(literal) => quote_literal(literal),
+        None => "NULL".to_string(),
+    }
+}
@@ -0,0 +1,251 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Cleanup tied to Postgres' resource owners
+//!
+//! Rust values that outlive the stack frame that created them, such as those kept in `fn_extra` or
+//! in a set-returning function's state, are never dropped when the query that was using them is
+//! aborted by an ERROR.  Postgres tracks its own resources (buffer pins, open files, locks) with
+//! `ResourceOwner`s, which are released at the end of every transaction, subtransaction, and portal.
+//! This module lets Rust code be released along with them.
+//!
+//! ```rust,no_run
+//! use pgrx::resowner::ResourceOwned;
+//! use std::net::TcpStream;
+//!
+//! // closed when the current query finishes or is aborted, if it hasn't been dropped by then
+//! let connection = ResourceOwned::new(TcpStream::connect("localhost:8080").unwrap());
+//! connection.with(|stream| stream.set_nodelay(true).unwrap());
+//! ```
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::logging::internal_log;
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The phases in which a `ResourceOwner` is released
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceReleasePhase {
+    /// Before locks are released, while the buffer pins, relcache references, and the like that
+    /// still need them are being released
+    BeforeLocks,
+    /// While locks are being released
+    Locks,
+    /// After locks have been released, along with catcache references, plan cache references,
+    /// temporary files, and other purely backend-local resources
+    AfterLocks,
+}
+
+impl ResourceReleasePhase {
+    fn from_pg(phase: pg_sys::ResourceReleasePhase) -> Self {
+        match phase {
+            pg_sys::ResourceReleasePhase_RESOURCE_RELEASE_BEFORE_LOCKS => {
+                ResourceReleasePhase::BeforeLocks
+            }
+            pg_sys::ResourceReleasePhase_RESOURCE_RELEASE_LOCKS => ResourceReleasePhase::Locks,
+            pg_sys::ResourceReleasePhase_RESOURCE_RELEASE_AFTER_LOCKS => {
+                ResourceReleasePhase::AfterLocks
+            }
+            unknown => panic!("Unrecognized ResourceReleasePhase: {}", unknown),
+        }
+    }
+}
+
+/// Why a `ResourceOwner` is being released
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceRelease {
+    pub phase: ResourceReleasePhase,
+    /// Is the (sub)transaction committing?  Otherwise it's aborting, or the owner belongs to a
+    /// portal that's being dropped
+    pub is_commit: bool,
+    /// Is this the top-level transaction's owner, or one of its children?
+    pub is_top_level: bool,
+}
+
+type ReleaseCallback = Box<dyn FnOnce(&ResourceRelease)>;
+
+struct Registration {
+    owner: pg_sys::ResourceOwner,
+    phase: ResourceReleasePhase,
+    /// Registrations made by [`ResourceOwned`] move to the parent owner when a subtransaction
+    /// commits, the way Postgres' own resources do
+    follows_parent: bool,
+    callback: Rc<RefCell<Option<ReleaseCallback>>>,
+}
+
+static mut REGISTRATIONS: Option<Vec<Registration>> = None;
+
+/// Registering a resource release callback returns a `ResourceReleaseReceipt` that can be used to
+/// unregister the callback if it becomes unnecessary before the owner is released
+pub struct ResourceReleaseReceipt(Rc<RefCell<Option<ReleaseCallback>>>);
+
+impl ResourceReleaseReceipt {
+    /// Consumes this `ResourceReleaseReceipt` and unregisters the callback it represents
+    pub fn unregister_callback(self) {
+        self.0.replace(None);
+    }
+}
+
+/// Register a closure to be called when the `CurrentResourceOwner` is released, during `phase`
+///
+/// The current resource owner is usually that of the running query's portal, so the closure is
+/// called when the query finishes or is aborted.  Callbacks registered with the same owner and phase
+/// are called in the reverse of the order in which they were registered.
+///
+/// ## Panics
+///
+/// If there is no `CurrentResourceOwner`, such as outside of a transaction
+///
+/// ## Safety
+///
+/// The closure is called while the (sub)transaction is aborting, so it must only do noncritical,
+/// backend-local cleanup.  A Rust `panic!()` or Postgres `ereport(ERROR)` from it is reported as a
+/// WARNING and otherwise ignored.
+pub fn register_resource_release_callback<F>(
+    phase: ResourceReleasePhase,
+    f: F,
+) -> ResourceReleaseReceipt
+where
+    F: FnOnce(&ResourceRelease) + 'static,
+{
+    ResourceReleaseReceipt(register(phase, false, Box::new(f)))
+}
+
+fn register(
+    phase: ResourceReleasePhase,
+    follows_parent: bool,
+    callback: ReleaseCallback,
+) -> Rc<RefCell<Option<ReleaseCallback>>> {
+    let owner = unsafe { pg_sys::CurrentResourceOwner };
+    if owner.is_null() {
+        panic!("there is no CurrentResourceOwner to register a resource release callback with");
+    }
+
+    let registrations = unsafe { &mut *std::ptr::addr_of_mut!(REGISTRATIONS) };
+    let registrations = registrations.get_or_insert_with(|| {
+        // we only need one callback for the life of the backend
+        unsafe { pg_sys::RegisterResourceReleaseCallback(Some(release), std::ptr::null_mut()) };
+        Vec::new()
+    });
+
+    let callback = Rc::new(RefCell::new(Some(callback)));
+    registrations.push(Registration { owner, phase, follows_parent, callback: callback.clone() });
+    callback
+}
+
+#[pg_guard]
+unsafe extern "C" fn release(
+    phase: pg_sys::ResourceReleasePhase,
+    is_commit: bool,
+    is_top_level: bool,
+    _arg: *mut std::os::raw::c_void,
+) {
+    let release =
+        ResourceRelease { phase: ResourceReleasePhase::from_pg(phase), is_commit, is_top_level };
+    // `ResourceOwnerRelease()` makes the owner being released the current one
+    let owner = pg_sys::CurrentResourceOwner;
+    let registrations = match (*std::ptr::addr_of_mut!(REGISTRATIONS)).as_mut() {
+        Some(registrations) => registrations,
+        None => return,
+    };
+
+    // committing subtransactions hand their resources to their parent
+    if is_commit && !is_top_level {
+        let parent = pg_sys::ResourceOwnerGetParent(owner);
+        for registration in registrations.iter_mut() {
+            if registration.owner == owner && registration.follows_parent && !parent.is_null() {
+                registration.owner = parent;
+            }
+        }
+    }
+
+    // take ours out before calling any of them, in case they register more
+    let mut released = Vec::new();
+    registrations.retain(|registration| {
+        if registration.callback.borrow().is_none() {
+            // unregistered
+            false
+        } else if registration.owner == owner && registration.phase == release.phase {
+            released.push(registration.callback.clone());
+            false
+        } else {
+            true
+        }
+    });
+
+    for callback in released.into_iter().rev() {
+        let callback = callback.borrow_mut().take();
+        if let Some(callback) = callback {
+            PgTryBuilder::new(std::panic::AssertUnwindSafe(|| callback(&release)))
+                .catch_others(|e| {
+                    internal_log!(PgLogLevel::WARNING, "resource release callback failed: {:?}", e);
+                })
+                .execute();
+        }
+    }
+}
+
+/// A value that's dropped when the `CurrentResourceOwner` at the time it was created is released,
+/// if this handle isn't dropped first
+///
+/// This is for values that must be cleaned up even when the query using them is aborted but that
+/// live somewhere Rust won't drop them, such as a network connection kept in `fn_extra`.  Like
+/// Postgres' own resources, the value moves to the parent owner when a subtransaction commits.
+///
+/// The value is dropped during [`ResourceReleasePhase::AfterLocks`], so its `Drop` impl must only do
+/// noncritical, backend-local cleanup.
+pub struct ResourceOwned<T: 'static> {
+    value: Rc<RefCell<Option<T>>>,
+    receipt: Option<ResourceReleaseReceipt>,
+}
+
+impl<T: 'static> ResourceOwned<T> {
+    /// Hand `value` to the `CurrentResourceOwner`
+    ///
+    /// ## Panics
+    ///
+    /// If there is no `CurrentResourceOwner`, such as outside of a transaction
+    pub fn new(value: T) -> Self {
+        let value = Rc::new(RefCell::new(Some(value)));
+        let owned = value.clone();
+        let callback = register(
+            ResourceReleasePhase::AfterLocks,
+            true,
+            Box::new(move |_: &ResourceRelease| drop(owned.borrow_mut().take())),
+        );
+        ResourceOwned { value, receipt: Some(ResourceReleaseReceipt(callback)) }
+    }
+
+    /// Has the value's resource owner been released, dropping it?
+    pub fn is_released(&self) -> bool {
+        self.value.borrow().is_none()
+    }
+
+    /// Call `f` with the value, or return `None` if it has already been released
+    pub fn with<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> Option<R> {
+        self.value.borrow_mut().as_mut().map(f)
+    }
+
+    /// Take the value back from its resource owner, if it hasn't already been released
+    pub fn take(mut self) -> Option<T> {
+        if let Some(receipt) = self.receipt.take() {
+            receipt.unregister_callback();
+        }
+        self.value.borrow_mut().take()
+    }
+}
+
+impl<T: 'static> Drop for ResourceOwned<T> {
+    fn drop(&mut self) {
+        if let Some(receipt) = self.receipt.take() {
+            receipt.unregister_callback();
+        }
+        drop(self.value.borrow_mut().take());
+    }
+}
@@ -0,0 +1,293 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Cron schedules, for background workers that do something periodically
+//!
+//! A [`Schedule`] is parsed from a standard five-field cron expression, and says when it next
+//! fires in the server's `TimeZone`:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::schedule::Schedule;
+//!
+//! // at 03:30 every weekday
+//! let schedule: Schedule = "30 3 * * mon-fri".parse().unwrap();
+//! let next = schedule.next_after(pgrx::clock_timestamp()).unwrap();
+//! ```
+//!
+//! [`BackgroundWorker::run_on_schedule`](crate::bgworkers::BackgroundWorker::run_on_schedule)
+//! runs a closure whenever a schedule fires.
+//!
+//! The fields are the minute (0-59), hour (0-23), day of the month (1-31), month (1-12 or
+//! `jan`-`dec`), and day of the week (0-7 or `sun`-`sat`, where both 0 and 7 are Sunday).  Each
+//! can be `*`, a value, a range like `1-5`, or a list of them like `1,15,20-25`, and `*` and
+//! ranges can take a step like `*/15`.  As in cron, when both the day of the month and the day of
+//! the week are restricted, a day matching either one matches.  `@yearly` (or `@annually`),
+//! `@monthly`, `@weekly`, `@daily` (or `@midnight`), and `@hourly` are also accepted.
+//!
+//! A local time that a daylight saving time change skips is read the way Postgres reads one, so
+//! `30 2 * * *` fires at 03:30 on the day clocks go from 02:00 to 03:00.  A local time that
+//! happens twice fires once, the second time.
+use std::str::FromStr;
+
+use crate::{pg_sys, TimestampWithTimeZone};
+
+/// Why a cron expression couldn't be parsed
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleError {
+    #[error("a cron expression has 5 fields, but `{0}` has {1}")]
+    WrongFieldCount(String, usize),
+
+    #[error("`{0}` is not valid for the {1} field")]
+    InvalidField(String, &'static str),
+
+    #[error("unknown cron macro `{0}`")]
+    UnknownMacro(String),
+}
+
+/// A cron schedule.  See the [module documentation](self) for the syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    /// Was the day of the month `*`?
+    any_day: bool,
+    /// Was the day of the week `*`?
+    any_weekday: bool,
+}
+
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const MINUTE: Field = Field { name: "minute", min: 0, max: 59, names: &[] };
+const HOUR: Field = Field { name: "hour", min: 0, max: 23, names: &[] };
+const DAY: Field = Field { name: "day of month", min: 1, max: 31, names: &[] };
+const MONTH: Field = Field {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"],
+};
+const WEEKDAY: Field = Field {
+    name: "day of week",
+    min: 0,
+    max: 7,
+    names: &["sun", "mon", "tue", "wed", "thu", "fri", "sat"],
+};
+
+impl Field {
+    fn value(&self, value: &str) -> Option<u32> {
+        let value = match self.names.iter().position(|name| name.eq_ignore_ascii_case(value)) {
+            Some(index) => index as u32 + self.min,
+            None => value.parse().ok()?,
+        };
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+
+    /// The values `expression` matches, as a bit set
+    fn parse(&self, expression: &str) -> Result<u64, ScheduleError> {
+        let invalid = || ScheduleError::InvalidField(expression.to_string(), self.name);
+        let mut bits = 0u64;
+        for item in expression.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+                None => (item, 1),
+            };
+            let (first, last) = match range.split_once('-') {
+                _ if range == "*" => (self.min, self.max),
+                Some((first, last)) => {
+                    (self.value(first).ok_or_else(invalid)?, self.value(last).ok_or_else(invalid)?)
+                }
+                // `5/10` means from 5 to the end, every 10
+                None if step != 1 || item.contains('/') => {
+                    (self.value(range).ok_or_else(invalid)?, self.max)
+                }
+                None => {
+                    let value = self.value(range).ok_or_else(invalid)?;
+                    (value, value)
+                }
+            };
+            if step == 0 || first > last {
+                return Err(invalid());
+            }
+            for value in (first..=last).step_by(step as usize) {
+                bits |= 1 << value;
+            }
+        }
+        Ok(bits)
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = ScheduleError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = expression.trim();
+        let expression = match expression {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ if expression.starts_with('@') => {
+                return Err(ScheduleError::UnknownMacro(expression.to_string()))
+            }
+            _ => expression,
+        };
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(ScheduleError::WrongFieldCount(expression.to_string(), fields.len()));
+        };
+
+        let mut weekdays = WEEKDAY.parse(weekday)?;
+        // 7 is Sunday too
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Ok(Schedule {
+            minutes: MINUTE.parse(minute)?,
+            hours: HOUR.parse(hour)? as u32,
+            days: DAY.parse(day)? as u32,
+            months: MONTH.parse(month)? as u16,
+            weekdays: weekdays as u8,
+            any_day: day == "*",
+            any_weekday: weekday == "*",
+        })
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 0 is Sunday
+fn weekday(year: i32, month: i32, day: i32) -> i32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day).rem_euclid(7)
+}
+
+impl Schedule {
+    fn matches_day(&self, year: i32, month: i32, day: i32) -> bool {
+        let by_day = self.days & (1 << day) != 0;
+        let by_weekday = self.weekdays & (1 << weekday(year, month, day)) != 0;
+        match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (false, true) => by_day,
+            (true, false) => by_weekday,
+            (false, false) => by_day || by_weekday,
+        }
+    }
+
+    /// The first time after `after` that this schedule fires, in the server's `TimeZone`, or
+    /// `None` if it never does, like on the 30th of February
+    pub fn next_after(&self, after: TimestampWithTimeZone) -> Option<TimestampWithTimeZone> {
+        let after = pg_sys::TimestampTz::from(after);
+        let mut tm = pg_sys::pg_tm::default();
+        let mut tz = 0;
+        let mut fsec = 0;
+        unsafe {
+            // SAFETY: the pointers are all valid, and a finite timestamp can't fail to convert
+            if pg_sys::timestamp2tm(
+                after,
+                &mut tz,
+                &mut tm,
+                &mut fsec,
+                std::ptr::null_mut(),
+                pg_sys::session_timezone,
+            ) != 0
+            {
+                return None;
+            }
+        }
+
+        // start from the next whole minute
+        let (mut year, mut month, mut day) = (tm.tm_year, tm.tm_mon, tm.tm_mday);
+        let (mut hour, mut minute) = (tm.tm_hour, tm.tm_min + 1);
+        // a schedule that fires at all does within 28 years, when the calendar repeats
+        let last_year = year + 28;
+        loop {
+            if minute > 59 {
+                minute = 0;
+                hour += 1;
+            }
+            if hour > 23 {
+                hour = 0;
+                day += 1;
+            }
+            if day > days_in_month(year, month) {
+                day = 1;
+                month += 1;
+            }
+            if month > 12 {
+                month = 1;
+                year += 1;
+            }
+            if year > last_year {
+                return None;
+            }
+
+            if self.months & (1 << month) == 0 {
+                (day, hour, minute) = (days_in_month(year, month) + 1, 0, 0);
+            } else if !self.matches_day(year, month, day) {
+                (hour, minute) = (24, 0);
+            } else if self.hours & (1 << hour) == 0 {
+                minute = 60;
+            } else if self.minutes & (1 << minute) == 0 {
+                minute += 1;
+            } else {
+                let fires = local_time(year, month, day, hour, minute)?;
+                if fires > after {
+                    return TimestampWithTimeZone::try_from(fires).ok();
+                }
+                minute += 1;
+            }
+        }
+    }
+}
+
+/// The instant the local time is reached in the server's `TimeZone`
+fn local_time(
+    year: i32,
+    month: i32,
+    day: i32,
+    hour: i32,
+    minute: i32,
+) -> Option<pg_sys::TimestampTz> {
+    let mut tm = pg_sys::pg_tm {
+        tm_year: year,
+        tm_mon: month,
+        tm_mday: day,
+        tm_hour: hour,
+        tm_min: minute,
+        ..Default::default()
+    };
+    let mut result = 0;
+    unsafe {
+        // SAFETY: the pointers are all valid
+        let mut tz = pg_sys::DetermineTimeZoneOffset(&mut tm, pg_sys::session_timezone);
+        (pg_sys::tm2timestamp(&mut tm, 0, &mut tz, &mut result) == 0).then_some(result)
+    }
+}
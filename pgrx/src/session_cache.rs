@@ -0,0 +1,217 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Per-backend caches that are automatically flushed when the catalog changes
+//!
+//! Extensions often want to remember metadata they derived from a relation (its columns, its
+//! indexes, its options) rather than looking it up on every call.  Doing so correctly means
+//! registering with Postgres' cache invalidation machinery, otherwise the cached value silently
+//! goes stale after an `ALTER TABLE`, or is left behind by a transaction that aborted.
+//! [`SessionCache`] does that registration for you.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{PgRelation, SessionCache};
+//!
+//! static COLUMN_NAMES: SessionCache<pg_sys::Oid, Vec<String>> = SessionCache::new();
+//!
+//! fn column_names(relid: pg_sys::Oid) -> Vec<String> {
+//!     COLUMN_NAMES.get_or_insert_with(relid, |relid| unsafe {
+//!         let rel = PgRelation::open(*relid);
+//!         rel.tuple_desc().iter().map(|att| att.name().to_string()).collect()
+//!     })
+//! }
+//! ```
+use crate as pgrx; // for #[pg_guard] support from within ourself
+use crate::pg_sys;
+use pgrx_macros::pg_guard;
+use std::cell::{Cell, UnsafeCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A key for a [`SessionCache`]
+///
+/// Entries whose key names a relation are flushed when that relation's relcache entry is
+/// invalidated.  All entries are flushed when any of the cache's syscaches are invalidated.
+pub trait SessionCacheKey: Eq + Hash + Clone {
+    /// The relation this key's cached value was derived from, if any
+    fn relid(&self) -> Option<pg_sys::Oid> {
+        None
+    }
+}
+
+impl SessionCacheKey for pg_sys::Oid {
+    fn relid(&self) -> Option<pg_sys::Oid> {
+        Some(*self)
+    }
+}
+
+impl<T: Eq + Hash + Clone> SessionCacheKey for (pg_sys::Oid, T) {
+    fn relid(&self) -> Option<pg_sys::Oid> {
+        Some(self.0)
+    }
+}
+
+impl SessionCacheKey for String {}
+impl SessionCacheKey for i32 {}
+impl SessionCacheKey for i64 {}
+
+/// A backend-local map from `K` to `V` that drops entries when Postgres invalidates the relations
+/// (or, optionally, the system caches) they were derived from
+///
+/// Invalidation callbacks are registered with Postgres the first time an entry is added.  Postgres
+/// allows a small, fixed, number of callbacks per backend, so a [`SessionCache`] should always be a
+/// `static` rather than something created on demand.
+///
+/// Values are owned by Rust and live for the rest of the session, so they must not contain
+/// pointers into Postgres memory contexts.
+pub struct SessionCache<K, V> {
+    entries: UnsafeCell<Option<HashMap<K, V>>>,
+    syscaches: &'static [pg_sys::SysCacheIdentifier],
+    registered: Cell<bool>,
+    /// Incremented on every invalidation, so a value computed while one happened isn't cached
+    generation: Cell<u64>,
+}
+
+// Postgres backends are single-threaded
+unsafe impl<K, V> Sync for SessionCache<K, V> {}
+
+impl<K: SessionCacheKey, V: Clone> SessionCache<K, V> {
+    /// A cache whose entries are flushed by relcache invalidations of the relation named by their key
+    pub const fn new() -> Self {
+        SessionCache::with_syscaches(&[])
+    }
+
+    /// A cache whose entries are also all flushed by invalidations of any of the given system
+    /// caches, such as `pg_sys::SysCacheIdentifier_TYPEOID`
+    pub const fn with_syscaches(syscaches: &'static [pg_sys::SysCacheIdentifier]) -> Self {
+        SessionCache {
+            entries: UnsafeCell::new(None),
+            syscaches,
+            registered: Cell::new(false),
+            generation: Cell::new(0),
+        }
+    }
+
+    /// Get a copy of the value cached for `key`
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.with_entries(|entries| entries.get(key).cloned())
+    }
+
+    /// Get a copy of the value cached for `key`, calling `f` to compute and cache it if there isn't
+    /// one
+    ///
+    /// If `f` raises an ERROR nothing is cached.  If a relevant invalidation arrives while `f` is
+    /// running, such as when it opens the relation and accepts pending invalidation messages, its
+    /// result is returned but not cached, since it may already be out of date.
+    pub fn get_or_insert_with<F: FnOnce(&K) -> V>(&'static self, key: K, f: F) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+
+        self.register();
+        let generation = self.generation.get();
+        let value = f(&key);
+        if self.generation.get() == generation {
+            self.with_entries(|entries| entries.insert(key, value.clone()));
+        }
+        value
+    }
+
+    /// Cache `value` for `key`, returning the value previously cached for it
+    pub fn insert(&'static self, key: K, value: V) -> Option<V> {
+        self.register();
+        self.with_entries(|entries| entries.insert(key, value))
+    }
+
+    /// Remove the value cached for `key`
+    pub fn remove(&self, key: &K) -> Option<V> {
+        self.with_entries(|entries| entries.remove(key))
+    }
+
+    /// The number of cached entries
+    pub fn len(&self) -> usize {
+        self.with_entries(|entries| entries.len())
+    }
+
+    /// Is the cache empty?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The entries are never borrowed across a call into user code or Postgres, so an invalidation
+    /// callback can't find them already borrowed
+    fn with_entries<R, F: FnOnce(&mut HashMap<K, V>) -> R>(&self, f: F) -> R {
+        unsafe { f((*self.entries.get()).get_or_insert_with(HashMap::new)) }
+    }
+
+    fn register(&'static self) {
+        if self.registered.replace(true) {
+            return;
+        }
+
+        // the callbacks need a thin pointer to pass through a Datum
+        let invalidate: &'static &'static dyn Invalidate = Box::leak(Box::new(self as _));
+        let arg = pg_sys::Datum::from(invalidate as *const &'static dyn Invalidate);
+        unsafe {
+            pg_sys::CacheRegisterRelcacheCallback(Some(relcache_callback), arg);
+            for cacheid in self.syscaches {
+                pg_sys::CacheRegisterSyscacheCallback(*cacheid as _, Some(syscache_callback), arg);
+            }
+        }
+    }
+}
+
+impl<K: SessionCacheKey, V: Clone> Default for SessionCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type-erases a [`SessionCache`] so the invalidation callbacks needn't be generic
+trait Invalidate {
+    /// Flush the entries derived from `relid`, or everything if it's `None`
+    fn invalidate(&self, relid: Option<pg_sys::Oid>);
+}
+
+impl<K: SessionCacheKey, V: Clone> Invalidate for SessionCache<K, V> {
+    fn invalidate(&self, relid: Option<pg_sys::Oid>) {
+        self.generation.set(self.generation.get() + 1);
+        // values are dropped after the map is released, in case they have interesting Drop impls
+        let flushed = self.with_entries(|entries| match relid {
+            None => std::mem::take(entries),
+            Some(relid) => {
+                let keys = entries
+                    .keys()
+                    .filter(|key| key.relid() == Some(relid))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                keys.into_iter().filter_map(|key| entries.remove_entry(&key)).collect()
+            }
+        });
+        drop(flushed);
+    }
+}
+
+#[pg_guard]
+unsafe extern "C" fn relcache_callback(arg: pg_sys::Datum, relid: pg_sys::Oid) {
+    let cache = *arg.cast_mut_ptr::<&'static dyn Invalidate>();
+    // an invalid oid means every relation was invalidated
+    cache.invalidate(if relid == pg_sys::InvalidOid { None } else { Some(relid) });
+}
+
+#[pg_guard]
+unsafe extern "C" fn syscache_callback(
+    arg: pg_sys::Datum,
+    _cacheid: ::std::os::raw::c_int,
+    _hashvalue: u32,
+) {
+    let cache = *arg.cast_mut_ptr::<&'static dyn Invalidate>();
+    cache.invalidate(None);
+}
@@ -52,6 +52,7 @@ pub unsafe trait PGRXSharedMemory {}
 #[macro_export]
 macro_rules! pg_shmem_init {
     ($thing:expr) => {
+        $crate::init::check_init_path("pg_shmem_init!()");
         $thing.pg_init();
 
         unsafe {
@@ -76,6 +77,7 @@ macro_rules! pg_shmem_init {
 #[macro_export]
 macro_rules! pg_shmem_init {
     ($thing:expr) => {
+        $crate::init::check_init_path("pg_shmem_init!()");
         unsafe {
             static mut PREV_SHMEM_REQUEST_HOOK: Option<unsafe extern "C" fn()> = None;
             PREV_SHMEM_REQUEST_HOOK = pg_sys::shmem_request_hook;
@@ -0,0 +1,131 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! SP-GiST operator classes implemented in Rust
+//!
+//! Implement [`SpGistOpClass`] for a type created by the extension and annotate the `impl` with
+//! [`#[pg_opclass]`](macro@crate::pg_opclass) to make it the type's default SP-GiST operator class.
+//! The trait's methods are the five support functions described in the
+//! [SP-GiST documentation](https://www.postgresql.org/docs/current/spgist-extensibility.html), and
+//! are given Postgres' own input and output structs to read and fill in.  Arrays in the output
+//! structs must be `palloc`'d, which [`palloc_array`] does.
+//!
+//! ```rust,ignore
+//! use pgrx::prelude::*;
+//! use pgrx::spgist::{palloc_array, SpGistOpClass};
+//!
+//! #[pg_opclass]
+//! impl SpGistOpClass for Reading {
+//!     const OPERATORS: &'static [(u16, &'static str)] = &[(1, "=")];
+//!
+//!     fn config(input: &pg_sys::spgConfigIn, output: &mut pg_sys::spgConfigOut) {
+//!         output.prefixType = input.attType;
+//!         output.labelType = pg_sys::VOIDOID;
+//!     }
+//!     // ...
+//! }
+//! ```
+use crate::datum::Internal;
+use crate::pg_sys;
+
+/// The support functions of an SP-GiST operator class
+pub trait SpGistOpClass {
+    /// The operators the operator class supports, as `(strategy number, operator)` pairs.  The
+    /// operator may be followed by its operand types, as in `"<< (point, point)"`, which otherwise
+    /// default to the indexed type
+    const OPERATORS: &'static [(u16, &'static str)];
+
+    /// Describe the data types of the inner tuples' prefixes and node labels
+    fn config(input: &pg_sys::spgConfigIn, output: &mut pg_sys::spgConfigOut);
+
+    /// Choose how to insert a new value into an inner tuple
+    fn choose(input: &pg_sys::spgChooseIn, output: &mut pg_sys::spgChooseOut);
+
+    /// Decide how to create a new inner tuple over a set of leaf tuples
+    fn picksplit(input: &pg_sys::spgPickSplitIn, output: &mut pg_sys::spgPickSplitOut);
+
+    /// Return the set of nodes (branches) to follow during a search
+    fn inner_consistent(
+        input: &pg_sys::spgInnerConsistentIn,
+        output: &mut pg_sys::spgInnerConsistentOut,
+    );
+
+    /// Does the leaf tuple satisfy all of the search's scan keys?
+    fn leaf_consistent(
+        input: &pg_sys::spgLeafConsistentIn,
+        output: &mut pg_sys::spgLeafConsistentOut,
+    ) -> bool;
+}
+
+/// View an array from one of the input structs, such as `spgPickSplitIn.datums`, as a slice
+///
+/// ## Safety
+///
+/// `ptr` must be null, or point to at least `len` initialized values that outlive `'a`
+pub unsafe fn as_slice<'a, T>(ptr: *const T, len: i32) -> &'a [T] {
+    if ptr.is_null() || len <= 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+/// The scan keys of a search, from the `scankeys` and `nkeys` of an `spgInnerConsistentIn` or
+/// `spgLeafConsistentIn`
+///
+/// ## Safety
+///
+/// `scankeys` must be null, or point to at least `nkeys` scan keys
+pub unsafe fn scan_keys<'a>(scankeys: pg_sys::ScanKey, nkeys: i32) -> &'a [pg_sys::ScanKeyData] {
+    as_slice(scankeys, nkeys)
+}
+
+/// Copy `values` into an array allocated in the `CurrentMemoryContext`, as the output structs'
+/// arrays must be
+pub fn palloc_array<T: Copy>(values: &[T]) -> *mut T {
+    unsafe {
+        let array = pg_sys::palloc(std::mem::size_of::<T>() * values.len().max(1)).cast::<T>();
+        std::ptr::copy_nonoverlapping(values.as_ptr(), array, values.len());
+        array
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn config<T: SpGistOpClass>(input: Internal, output: Internal) {
+    T::config(input.get().expect("no spgConfigIn"), output.get_mut().expect("no spgConfigOut"))
+}
+
+#[doc(hidden)]
+pub unsafe fn choose<T: SpGistOpClass>(input: Internal, output: Internal) {
+    T::choose(input.get().expect("no spgChooseIn"), output.get_mut().expect("no spgChooseOut"))
+}
+
+#[doc(hidden)]
+pub unsafe fn picksplit<T: SpGistOpClass>(input: Internal, output: Internal) {
+    T::picksplit(
+        input.get().expect("no spgPickSplitIn"),
+        output.get_mut().expect("no spgPickSplitOut"),
+    )
+}
+
+#[doc(hidden)]
+pub unsafe fn inner_consistent<T: SpGistOpClass>(input: Internal, output: Internal) {
+    T::inner_consistent(
+        input.get().expect("no spgInnerConsistentIn"),
+        output.get_mut().expect("no spgInnerConsistentOut"),
+    )
+}
+
+#[doc(hidden)]
+pub unsafe fn leaf_consistent<T: SpGistOpClass>(input: Internal, output: Internal) -> bool {
+    T::leaf_consistent(
+        input.get().expect("no spgLeafConsistentIn"),
+        output.get_mut().expect("no spgLeafConsistentOut"),
+    )
+}
@@ -19,6 +19,15 @@ use std::mem;
 use std::ops::{Deref, Index};
 use std::ptr::NonNull;
 
+mod retry;
+mod security;
+mod trace;
+pub(crate) use retry::in_subtransaction;
+pub use retry::SpiRetry;
+pub use security::SpiSecurity;
+pub use trace::define_trace_spi_gucs;
+use trace::Trace;
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// These match the Postgres `#define`d constants prefixed `SPI_OK_*` that you can find in `pg_sys`.
@@ -77,44 +86,30 @@ impl std::fmt::Display for SpiErrorCodes {
 
 /// A safe wrapper around [`pg_sys::quote_identifier`]. Returns a properly quoted identifier. For
 /// instance for a column or table name such as `"my-table-name"`
+///
+/// The same as [`crate::quote::quote_ident()`].
 pub fn quote_identifier<StringLike: AsRef<str>>(ident: StringLike) -> String {
-    let ident_cstr = CString::new(ident.as_ref()).unwrap();
-    // SAFETY: quote_identifier expects a null terminated string and returns one.
-    let quoted_cstr = unsafe {
-        let quoted_ptr = pg_sys::quote_identifier(ident_cstr.as_ptr());
-        CStr::from_ptr(quoted_ptr)
-    };
-    quoted_cstr.to_str().unwrap().to_string()
+    crate::quote::quote_ident(ident)
 }
 
 /// A safe wrapper around [`pg_sys::quote_qualified_identifier`]. Returns a properly quoted name of
 /// the following format qualifier.ident. A common usecase is to qualify a table_name for example
 /// `"my schema"."my table"`
+///
+/// The same as [`crate::quote::quote_qualified_ident()`].
 pub fn quote_qualified_identifier<StringLike: AsRef<str>>(
     qualifier: StringLike,
     ident: StringLike,
 ) -> String {
-    let qualifier_cstr = CString::new(qualifier.as_ref()).unwrap();
-    let ident_cstr = CString::new(ident.as_ref()).unwrap();
-    // SAFETY: quote_qualified_identifier expects null terminated strings and returns one.
-    let quoted_cstr = unsafe {
-        let quoted_ptr =
-            pg_sys::quote_qualified_identifier(qualifier_cstr.as_ptr(), ident_cstr.as_ptr());
-        CStr::from_ptr(quoted_ptr)
-    };
-    quoted_cstr.to_str().unwrap().to_string()
+    crate::quote::quote_qualified_ident(qualifier, ident)
 }
 
 /// A safe wrapper around [`pg_sys::quote_literal_cstr`]. Returns a properly quoted literal such as
 /// a `TEXT` literal like `'my string with spaces'`.
+///
+/// The same as [`crate::quote::quote_literal()`].
 pub fn quote_literal<StringLike: AsRef<str>>(literal: StringLike) -> String {
-    let literal_cstr = CString::new(literal.as_ref()).unwrap();
-    // SAFETY: quote_literal_cstr expects a null terminated string and returns one.
-    let quoted_cstr = unsafe {
-        let quoted_ptr = pg_sys::quote_literal_cstr(literal_cstr.as_ptr());
-        CStr::from_ptr(quoted_ptr)
-    };
-    quoted_cstr.to_str().unwrap().to_string()
+    crate::quote::quote_literal(literal)
 }
 
 #[derive(Debug)]
@@ -238,24 +233,42 @@ pub struct SpiClient<'conn> {
 }
 
 /// a struct to manage our SPI connection lifetime
-struct SpiConnection(PhantomData<*mut ()>);
+struct SpiConnection {
+    /// the [`UPPER_MEMORY_CONTEXT`] of the connection we're nested inside, if any
+    prev_upper_context: pg_sys::MemoryContext,
+    __marker: PhantomData<*mut ()>,
+}
+
+/// The `CurrentMemoryContext` at the time the innermost [`SpiConnection`] was made.  This is where
+/// values read from SPI results are copied, so that they outlive the connection for exactly as long
+/// as anything else the caller allocated.  It's the same context `SPI_palloc()` uses.
+static mut UPPER_MEMORY_CONTEXT: pg_sys::MemoryContext = std::ptr::null_mut();
 
 impl SpiConnection {
     /// Connect to Postgres' SPI system
     fn connect() -> Result<Self> {
+        let upper_context = PgMemoryContexts::CurrentMemoryContext.value();
+
         // connect to SPI
         //
         // SPI_connect() is documented as being able to return SPI_ERROR_CONNECT, so we have to
         // assume it could.  The truth seems to be that it never actually does.  The one user
         // of SpiConnection::connect() returns `spi::Result` anyways, so it's no big deal
         Spi::check_status(unsafe { pg_sys::SPI_connect() })?;
-        Ok(SpiConnection(PhantomData))
+        let prev_upper_context = unsafe {
+            mem::replace(&mut *std::ptr::addr_of_mut!(UPPER_MEMORY_CONTEXT), upper_context)
+        };
+        Ok(SpiConnection { prev_upper_context, __marker: PhantomData })
     }
 }
 
 impl Drop for SpiConnection {
     /// when SpiConnection is dropped, we make sure to disconnect from SPI
     fn drop(&mut self) {
+        unsafe {
+            UPPER_MEMORY_CONTEXT = self.prev_upper_context;
+        }
+
         // best efforts to disconnect from SPI
         // SPI_finish() would only complain if we hadn't previously called SPI_connect() and
         // SpiConnection should prevent that from happening (assuming users don't go unsafe{})
@@ -263,6 +276,22 @@ impl Drop for SpiConnection {
     }
 }
 
+/// The memory context values read from SPI results should be copied into so they outlive the
+/// current connection
+///
+/// ## Panics
+///
+/// If SPI was connected to outside of [`Spi::connect`] and the current context has no parent,
+/// which is an incredibly unlikely situation
+unsafe fn upper_memory_context() -> PgMemoryContexts {
+    let upper_context = *std::ptr::addr_of!(UPPER_MEMORY_CONTEXT);
+    if upper_context.is_null() {
+        PgMemoryContexts::CurrentMemoryContext.parent().expect("parent memory context is absent")
+    } else {
+        PgMemoryContexts::For(upper_context)
+    }
+}
+
 impl SpiConnection {
     /// Return a client that with a lifetime scoped to this connection.
     fn client(&self) -> SpiClient<'_> {
@@ -335,6 +364,14 @@ impl<'conn> Query<'conn> for &str {
         }
 
         let src = CString::new(self).expect("query contained a null byte");
+        let trace = Trace::sql(
+            self,
+            &arguments
+                .iter()
+                .flatten()
+                .map(|(oid, datum)| (oid.value(), *datum))
+                .collect::<Vec<_>>(),
+        );
         let status_code = match arguments {
             Some(args) => {
                 let nargs = args.len();
@@ -365,6 +402,9 @@ impl<'conn> Query<'conn> for &str {
                 )
             },
         };
+        if let Some(trace) = trace {
+            trace.finish();
+        }
 
         Ok(SpiClient::prepare_tuple_table(status_code)?)
     }
@@ -518,7 +558,10 @@ impl Spi {
     ///
     /// pgrx' SPI API endeavors to return Datum values from functions like `::get_one()` that are
     /// automatically copied into the into the `CurrentMemoryContext` at the time of this
-    /// function call.
+    /// function call.  They're therefore valid for as long as anything else allocated there, and
+    /// borrowed values such as `&str` can be returned from a `#[pg_extern]` function without first
+    /// being converted to an owned `String`.  Use a [`BorrowedText`][crate::BorrowedText] to avoid
+    /// copying the value a second time when it's returned.
     ///
     /// # Examples
     ///
@@ -546,7 +589,10 @@ impl Spi {
     /// This function will panic if for some reason it's unable to "connect" to Postgres' SPI
     /// system.  At the time of this writing, that's actually impossible as the underlying function
     /// ([`pg_sys::SPI_connect()`]) **always** returns a successful response.
+    #[track_caller]
     pub fn connect<R, F: FnOnce(SpiClient<'_>) -> R>(f: F) -> R {
+        pg_sys::debug_assert_backend_thread("Spi::connect()");
+
         // connect to SPI
         //
         // Postgres documents (https://www.postgresql.org/docs/current/spi-spi-connect.html) that
@@ -878,6 +924,7 @@ impl<'conn: 'stmt, 'stmt> Query<'conn> for &'stmt PreparedStatement<'conn> {
             return Err(Error::PreparedStatementArgumentMismatch { expected, got: nargs });
         }
 
+        let trace = Trace::prepared(self.plan.as_ptr(), &args);
         let (mut datums, mut nulls): (Vec<_>, Vec<_>) = args.into_iter().map(prepare_datum).unzip();
 
         // SAFETY: all arguments are prepared above
@@ -890,6 +937,9 @@ impl<'conn: 'stmt, 'stmt> Query<'conn> for &'stmt PreparedStatement<'conn> {
                 limit.unwrap_or(0),
             )
         };
+        if let Some(trace) = trace {
+            trace.finish();
+        }
 
         Ok(SpiClient::prepare_tuple_table(status_code)?)
     }
@@ -1028,6 +1078,17 @@ impl<'conn> SpiTupleTable<'conn> {
         Ok((table as *const _ as *mut _, table.tupdesc))
     }
 
+    /// The tuple descriptor and all the tuples of this table, regardless of the current position
+    #[cfg_attr(not(feature = "arrow"), allow(dead_code))]
+    pub(crate) fn raw_tuples(&self) -> Option<(pg_sys::TupleDesc, &[*mut pg_sys::HeapTupleData])> {
+        let table = self.table.as_deref()?;
+        if self.size == 0 {
+            return Some((table.tupdesc, &[]));
+        }
+        // SAFETY:  `vals` holds the `size` tuples Postgres returned
+        Some((table.tupdesc, unsafe { std::slice::from_raw_parts(table.vals, self.size) }))
+    }
+
     pub fn get_heap_tuple(&self) -> Result<Option<SpiHeapTupleData<'conn>>> {
         if self.size == 0 || self.table.is_none() {
             // a query like "SELECT 1 LIMIT 0" is a valid "select"-style query that will not produce
@@ -1059,10 +1120,8 @@ impl<'conn> SpiTupleTable<'conn> {
     /// If the specified ordinal is out of bounds a [`Error::SpiError(SpiError::NoAttribute)`] is returned
     /// If we have no backing tuple table a [`Error::NoTupleTable`] is returned
     ///
-    /// # Panics
-    ///
-    /// This function will panic there is no parent MemoryContext.  This is an incredibly unlikely
-    /// situation.
+    /// The value is copied into the `CurrentMemoryContext` at the time [`Spi::connect`] was called,
+    /// so borrowed values such as `&str` or `&[u8]` can be returned from the enclosing function.
     pub fn get<T: IntoDatum + FromDatum>(&self, ordinal: usize) -> Result<Option<T>> {
         let (_, tupdesc) = self.get_spi_tuptable()?;
         let datum = self.get_datum_by_ordinal(ordinal)?;
@@ -1073,9 +1132,7 @@ impl<'conn> SpiTupleTable<'conn> {
             // SAFETY:  we know the constraints around `datum` and `is_null` match because we
             // just got them from the underlying heap tuple
             Ok(T::try_from_datum_in_memory_context(
-                PgMemoryContexts::CurrentMemoryContext
-                    .parent()
-                    .expect("parent memory context is absent"),
+                upper_memory_context(),
                 datum,
                 is_null,
                 // SAFETY:  we know `self.tupdesc.is_some()` because an Ok return from
@@ -1389,9 +1446,7 @@ impl<'conn> SpiHeapTupleDataEntry<'conn> {
         match self.datum.as_ref() {
             Some(datum) => unsafe {
                 T::try_from_datum_in_memory_context(
-                    PgMemoryContexts::CurrentMemoryContext
-                        .parent()
-                        .expect("parent memory context is absent"),
+                    upper_memory_context(),
                     *datum,
                     false,
                     self.type_oid,
@@ -0,0 +1,167 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Retrying [`Spi`] work that lost a deadlock or a serialization conflict.
+use super::{Spi, SpiClient};
+use crate::{pg_sys, PgMemoryContexts, PgSqlErrorCode, PgTryBuilder};
+use pgrx_pg_sys::panic::CaughtError;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+
+/// How [`Spi::retrying_with`] retries.  By default, three attempts are made, waiting 10ms before
+/// the second and twice as long before each one after that, up to a second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpiRetry {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for SpiRetry {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl SpiRetry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up, raising the last error, after this many attempts.  At least one is always made.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Wait this long before the first retry, doubling it before each retry after that
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Never wait longer than this between attempts
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+}
+
+/// Can the work be retried in a new subtransaction after this error?
+///
+/// A deadlock's victim releases its locks when the subtransaction rolls back, so it can simply
+/// try again.  A serialization failure can only be retried if the next attempt gets a new
+/// snapshot, which under `REPEATABLE READ` and `SERIALIZABLE` it won't: the transaction as a whole
+/// has to be retried then.
+fn is_retryable(error: &CaughtError) -> bool {
+    let ereport = match error {
+        CaughtError::PostgresError(ereport)
+        | CaughtError::ErrorReport(ereport)
+        | CaughtError::RustPanic { ereport, .. } => ereport,
+    };
+    match ereport.sql_error_code() {
+        PgSqlErrorCode::ERRCODE_T_R_DEADLOCK_DETECTED => true,
+        PgSqlErrorCode::ERRCODE_T_R_SERIALIZATION_FAILURE => {
+            // SAFETY: `XactIsoLevel` is only ever set by this backend
+            unsafe { pg_sys::XactIsoLevel < pg_sys::XACT_REPEATABLE_READ as i32 }
+        }
+        _ => false,
+    }
+}
+
+/// Run `f` in a subtransaction, committing it if `f` returns and rolling it back if `f` raises
+/// an error, which is then returned
+pub(crate) fn in_subtransaction<R>(f: impl FnOnce() -> R) -> Result<R, CaughtError> {
+    let oldcontext = PgMemoryContexts::CurrentMemoryContext.value();
+    // SAFETY: this is how plpgsql runs an exception block, keeping the caller's memory context
+    // and resource owner current
+    let oldowner = unsafe { pg_sys::CurrentResourceOwner };
+    let restore = move || unsafe {
+        pg_sys::MemoryContextSwitchTo(oldcontext);
+        pg_sys::CurrentResourceOwner = oldowner;
+    };
+
+    unsafe {
+        pg_sys::BeginInternalSubTransaction(std::ptr::null());
+        pg_sys::MemoryContextSwitchTo(oldcontext);
+    }
+    PgTryBuilder::new(AssertUnwindSafe(|| {
+        let result = f();
+        unsafe { pg_sys::ReleaseCurrentSubTransaction() };
+        restore();
+        Ok(result)
+    }))
+    .catch_others(|error| {
+        unsafe {
+            pg_sys::FlushErrorState();
+            pg_sys::RollbackAndReleaseCurrentSubTransaction();
+        }
+        restore();
+        Err(error)
+    })
+    .execute()
+}
+
+impl Spi {
+    /// Run `f` with a [`SpiClient`] in a subtransaction, trying again if it's chosen as the victim
+    /// of a deadlock or, under `READ COMMITTED`, fails to serialize with a concurrent transaction.
+    ///
+    /// See [`Spi::retrying_with`].
+    pub fn retrying<R, F: FnMut(SpiClient<'_>) -> R>(f: F) -> R {
+        Spi::retrying_with(SpiRetry::default(), f)
+    }
+
+    /// Run `f` with a [`SpiClient`] in a subtransaction, trying again, as `retry` says, if it
+    /// raises a `deadlock_detected` or `serialization_failure` error.
+    ///
+    /// Each failed attempt is rolled back before the next, so `f` starts over with a clean slate,
+    /// but anything it did outside the database, or to values it captured, stays done.  Other
+    /// errors, and the last one once out of attempts, are raised as usual.
+    ///
+    /// Under `REPEATABLE READ` and `SERIALIZABLE`, a `serialization_failure` isn't retried, as the
+    /// transaction's snapshot stays the same: only retrying the whole transaction can help then.
+    ///
+    /// ```rust,no_run
+    /// use pgrx::prelude::*;
+    /// use pgrx::spi::SpiRetry;
+    ///
+    /// # fn transfer() -> spi::Result<()> {
+    /// Spi::retrying_with(SpiRetry::new().max_attempts(5), |mut client| {
+    ///     client.update("UPDATE accounts SET balance = balance - 10 WHERE id = 1", None, None)?;
+    ///     client.update("UPDATE accounts SET balance = balance + 10 WHERE id = 2", None, None)?;
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn retrying_with<R, F: FnMut(SpiClient<'_>) -> R>(retry: SpiRetry, mut f: F) -> R {
+        let mut backoff = retry.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            let error = match in_subtransaction(|| Spi::connect(&mut f)) {
+                Ok(result) => return result,
+                Err(error) => error,
+            };
+            if attempt >= retry.max_attempts || !is_retryable(&error) {
+                error.rethrow();
+            }
+
+            let until = Instant::now() + backoff;
+            while let Some(left) = until.checked_duration_since(Instant::now()) {
+                std::thread::sleep(left.min(Duration::from_millis(10)));
+                pg_sys::check_for_interrupts!();
+            }
+            backoff = (backoff * 2).min(retry.max_backoff);
+            attempt += 1;
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Running [`Spi`] queries under an explicit role, row-level security setting, and restrictions.
+use super::{Spi, SpiClient};
+use crate::{debug1, pg_sys};
+
+/// The role, row-level security, and restrictions [`Spi::connect_with_security`] runs queries
+/// with.  By default, nothing is changed.
+///
+/// Maintenance functions often need to query tables as some other role than whoever called them,
+/// the way `VACUUM` and `REFRESH MATERIALIZED VIEW` run as the table's owner, or to be sure
+/// row-level security isn't silently hiding rows from them.  This spells that out at the call
+/// site:
+///
+/// ```rust,no_run
+/// use pgrx::prelude::*;
+/// use pgrx::spi::SpiSecurity;
+///
+/// # fn purge(owner: pg_sys::Oid) -> spi::Result<()> {
+/// let security = SpiSecurity::new().role(owner).row_security(false).restricted();
+/// Spi::connect_with_security(security, |mut client| {
+///     client.update("DELETE FROM audit_log WHERE at < now() - '90 days'::interval", None, None)?;
+///     Ok(())
+/// })
+/// # }
+/// ```
+///
+/// Everything is put back once the closure returns, or if it raises an ERROR.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpiSecurity {
+    role: Option<pg_sys::Oid>,
+    row_security: Option<bool>,
+    restricted: bool,
+}
+
+impl SpiSecurity {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run as `role`, with its privileges instead of the current user's.
+    ///
+    /// Like a `SECURITY DEFINER` function, this doesn't check that the current user may act as
+    /// `role`: that's up to the caller.  `SET ROLE` and `SET SESSION AUTHORIZATION` can't be used
+    /// while it's in effect.
+    pub fn role(mut self, role: pg_sys::Oid) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Set `row_security` for the queries.
+    ///
+    /// With `false`, a query that row-level security would filter raises an ERROR instead, so
+    /// it either sees every row or fails.  Only superusers, roles with `BYPASSRLS`, and table
+    /// owners (unless the table has `FORCE ROW LEVEL SECURITY`) can query such tables then.
+    pub fn row_security(mut self, enabled: bool) -> Self {
+        self.row_security = Some(enabled);
+        self
+    }
+
+    /// Run as a security-restricted operation, as Postgres does for code a table's owner
+    /// controls, like index expressions, during maintenance.  Queries then can't change the
+    /// session's role or authorization, create temporary tables, or otherwise affect what runs
+    /// after them.
+    pub fn restricted(mut self) -> Self {
+        self.restricted = true;
+        self
+    }
+
+    /// Switch to this security context until the returned guard is dropped
+    fn enter(&self) -> SecurityGuard {
+        let mut guard = SecurityGuard {
+            save_userid: pg_sys::InvalidOid,
+            save_sec_context: 0,
+            guc_nest_level: None,
+        };
+        unsafe {
+            // SAFETY: these only read and write backend globals
+            pg_sys::GetUserIdAndSecContext(&mut guard.save_userid, &mut guard.save_sec_context);
+
+            let mut sec_context = guard.save_sec_context;
+            if self.role.is_some() {
+                sec_context |= pg_sys::SECURITY_LOCAL_USERID_CHANGE as i32;
+            }
+            if self.restricted {
+                sec_context |= pg_sys::SECURITY_RESTRICTED_OPERATION as i32;
+            }
+            pg_sys::SetUserIdAndSecContext(self.role.unwrap_or(guard.save_userid), sec_context);
+
+            if let Some(enabled) = self.row_security {
+                guard.guc_nest_level = Some(pg_sys::NewGUCNestLevel());
+                let value: &[u8] = if enabled { b"on\0" } else { b"off\0" };
+                pg_sys::set_config_option(
+                    b"row_security\0".as_ptr().cast(),
+                    value.as_ptr().cast(),
+                    pg_sys::GucContext_PGC_USERSET,
+                    pg_sys::GucSource_PGC_S_SESSION,
+                    pg_sys::GucAction_GUC_ACTION_SAVE,
+                    true,
+                    pg_sys::ERROR as i32,
+                    false,
+                );
+            }
+        }
+
+        debug1!(
+            "SPI running as role {}{}{}",
+            self.role.unwrap_or(guard.save_userid),
+            match self.row_security {
+                Some(true) => ", with row_security on",
+                Some(false) => ", with row_security off",
+                None => "",
+            },
+            if self.restricted { ", security-restricted" } else { "" }
+        );
+        guard
+    }
+}
+
+/// Restores the security context that [`SpiSecurity::enter`] replaced
+struct SecurityGuard {
+    save_userid: pg_sys::Oid,
+    save_sec_context: i32,
+    guc_nest_level: Option<i32>,
+}
+
+impl Drop for SecurityGuard {
+    fn drop(&mut self) {
+        unsafe {
+            // SAFETY: this only restores what `SpiSecurity::enter` saved
+            if let Some(nest_level) = self.guc_nest_level {
+                pg_sys::AtEOXact_GUC(false, nest_level);
+            }
+            pg_sys::SetUserIdAndSecContext(self.save_userid, self.save_sec_context);
+        }
+    }
+}
+
+impl Spi {
+    /// Like [`Spi::connect`], but running the queries with `security`'s role, row-level security,
+    /// and restrictions.
+    ///
+    /// See [`SpiSecurity`] for an example.
+    pub fn connect_with_security<R, F: FnOnce(SpiClient<'_>) -> R>(
+        security: SpiSecurity,
+        f: F,
+    ) -> R {
+        let _guard = security.enter();
+        Spi::connect(f)
+    }
+}
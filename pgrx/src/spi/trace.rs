@@ -0,0 +1,260 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! `pgrx.trace_spi`: logging the statements an extension runs through [`Spi`], with their
+//! parameters, how long they took, and optionally their plans, to find slow queries hidden
+//! inside extension code.
+//!
+//! The settings are defined by [`define_trace_spi_gucs`], which an extension calls from its
+//! `_PG_init()`.  Then, as a superuser:
+//!
+//! ```sql
+//! SET pgrx.trace_spi = on;                    -- log every SPI statement
+//! SET pgrx.trace_spi_min_duration = '10ms';   -- ...that takes at least this long
+//! SET pgrx.trace_spi_explain = on;            -- ...along with its plan
+//! ```
+//!
+//! Statements are logged at `LOG` level once they've finished, in the same format as
+//! `log_min_duration_statement`, so the usual log tooling understands them:
+//!
+//! ```text
+//! LOG:  duration: 12.345 ms  rows: 1  SPI statement: SELECT * FROM foo WHERE id = $1
+//! DETAIL:  parameters: $1 = '42'
+//! ```
+//!
+//! Plans come from an `EXPLAIN` of the statement, run just before it with the same parameters.
+//! That doubles the planning work, so `pgrx.trace_spi_explain` is best left off except while
+//! diagnosing.  Only single `SELECT`, `INSERT`, `UPDATE`, `DELETE`, `MERGE`, `VALUES`, `TABLE`,
+//! and `WITH` statements are explained.
+use super::Spi;
+use crate::guc::{GucContext, GucFlags, GucRegistry, GucSetting};
+use crate::pg_sys;
+use crate::pg_sys::panic::ErrorReport;
+use crate::{PgLogLevel, PgSqlErrorCode};
+use std::ffi::{CStr, CString};
+use std::time::Instant;
+
+static TRACE_SPI: GucSetting<bool> = GucSetting::<bool>::new(false);
+static TRACE_SPI_EXPLAIN: GucSetting<bool> = GucSetting::<bool>::new(false);
+static TRACE_SPI_MIN_DURATION: GucSetting<i32> = GucSetting::<i32>::new(0);
+
+/// Parameter values longer than this are truncated in the log
+const MAX_PARAMETER_LEN: usize = 1024;
+
+/// Define the `pgrx.trace_spi`, `pgrx.trace_spi_min_duration`, and `pgrx.trace_spi_explain`
+/// settings.  Only superusers may change them.
+///
+/// This should be called from `_PG_init()`, and only one extension loaded into a backend can
+/// define them.
+pub fn define_trace_spi_gucs() {
+    GucRegistry::define_bool_guc(
+        "pgrx.trace_spi",
+        "Log the statements run through pgrx's SPI interface",
+        "Each statement is logged once it finishes, with its parameters and duration.",
+        &TRACE_SPI,
+        GucContext::Suset,
+        GucFlags::default(),
+    );
+    GucRegistry::define_int_guc(
+        "pgrx.trace_spi_min_duration",
+        "Only log SPI statements that take at least this long",
+        "Zero logs every statement.",
+        &TRACE_SPI_MIN_DURATION,
+        0,
+        i32::MAX,
+        GucContext::Suset,
+        GucFlags::UNIT_MS,
+    );
+    GucRegistry::define_bool_guc(
+        "pgrx.trace_spi_explain",
+        "Log the plans of traced SPI statements",
+        "The plans come from an EXPLAIN run just before each statement.",
+        &TRACE_SPI_EXPLAIN,
+        GucContext::Suset,
+        GucFlags::default(),
+    );
+}
+
+/// A statement being traced, from just before it's run
+pub(super) struct Trace {
+    query: String,
+    parameters: Vec<String>,
+    plan: Option<String>,
+    started: Instant,
+}
+
+impl Trace {
+    /// Start tracing the `query` that's about to run with `args`, if tracing is on
+    pub(super) fn sql(query: &str, args: &[(pg_sys::Oid, Option<pg_sys::Datum>)]) -> Option<Self> {
+        if !TRACE_SPI.get() {
+            return None;
+        }
+        Some(Trace::start(query.to_string(), args))
+    }
+
+    /// Start tracing the prepared statement `plan` that's about to run with `args`, if tracing is
+    /// on
+    pub(super) fn prepared(
+        plan: pg_sys::SPIPlanPtr,
+        args: &[Option<pg_sys::Datum>],
+    ) -> Option<Self> {
+        if !TRACE_SPI.get() {
+            return None;
+        }
+        let args = args
+            .iter()
+            .enumerate()
+            .map(|(i, datum)| (unsafe { pg_sys::SPI_getargtypeid(plan, i as _) }, *datum))
+            .collect::<Vec<_>>();
+        Some(Trace::start(unsafe { query_string(plan) }, &args))
+    }
+
+    fn start(query: String, args: &[(pg_sys::Oid, Option<pg_sys::Datum>)]) -> Self {
+        let parameters = args
+            .iter()
+            .enumerate()
+            .map(|(i, (oid, datum))| format!("${} = {}", i + 1, unsafe { output(*oid, *datum) }))
+            .collect();
+        let plan = TRACE_SPI_EXPLAIN.get().then(|| unsafe { explain(&query, args) }).flatten();
+        Trace { query, parameters, plan, started: Instant::now() }
+    }
+
+    /// Log the statement, now that it's finished
+    pub(super) fn finish(self) {
+        let elapsed = self.started.elapsed();
+        if elapsed.as_millis() < TRACE_SPI_MIN_DURATION.get() as u128 {
+            return;
+        }
+
+        let duration = elapsed.as_secs_f64() * 1000.0;
+        // SAFETY: no concurrent access
+        let rows = unsafe { pg_sys::SPI_processed };
+        let mut report = ErrorReport::new(
+            PgSqlErrorCode::ERRCODE_SUCCESSFUL_COMPLETION,
+            format!("duration: {duration:.3} ms  rows: {rows}  SPI statement: {}", self.query),
+            "pgrx::spi::trace",
+        );
+        if !self.parameters.is_empty() {
+            report = report.set_detail(format!("parameters: {}", self.parameters.join(", ")));
+        }
+        report.report(PgLogLevel::LOG);
+
+        if let Some(plan) = self.plan {
+            ErrorReport::new(
+                PgSqlErrorCode::ERRCODE_SUCCESSFUL_COMPLETION,
+                format!("duration: {duration:.3} ms  plan:\n{plan}"),
+                "pgrx::spi::trace",
+            )
+            .report(PgLogLevel::LOG);
+        }
+    }
+}
+
+/// The text a prepared statement was made from
+unsafe fn query_string(plan: pg_sys::SPIPlanPtr) -> String {
+    let source = first_plan_source(pg_sys::SPI_plan_get_plan_sources(plan));
+    match source {
+        Some(source) if !(*source).query_string.is_null() => {
+            CStr::from_ptr((*source).query_string).to_string_lossy().into_owned()
+        }
+        _ => "<unknown prepared statement>".to_string(),
+    }
+}
+
+/// The first `CachedPlanSource` in `sources`, read directly rather than through `PgList`, which
+/// needs the `cshim` feature
+#[cfg(any(feature = "pg11", feature = "pg12"))]
+unsafe fn first_plan_source(sources: *mut pg_sys::List) -> Option<*mut pg_sys::CachedPlanSource> {
+    if sources.is_null() || (*sources).length == 0 {
+        return None;
+    }
+    Some((*(*sources).head).data.ptr_value.cast())
+}
+
+/// The first `CachedPlanSource` in `sources`, read directly rather than through `PgList`, which
+/// needs the `cshim` feature
+#[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15", feature = "pg16"))]
+unsafe fn first_plan_source(sources: *mut pg_sys::List) -> Option<*mut pg_sys::CachedPlanSource> {
+    if sources.is_null() || (*sources).length == 0 {
+        return None;
+    }
+    Some((*(*sources).elements).ptr_value.cast())
+}
+
+/// A parameter's value, quoted the way Postgres logs them
+unsafe fn output(oid: pg_sys::Oid, datum: Option<pg_sys::Datum>) -> String {
+    let Some(datum) = datum else {
+        return "NULL".to_string();
+    };
+
+    let mut output_func = pg_sys::InvalidOid;
+    let mut is_varlena = false;
+    pg_sys::getTypeOutputInfo(oid, &mut output_func, &mut is_varlena);
+    let text = pg_sys::OidOutputFunctionCall(output_func, datum);
+    let mut value = CStr::from_ptr(text).to_string_lossy().into_owned();
+    pg_sys::pfree(text.cast());
+
+    if value.len() > MAX_PARAMETER_LEN {
+        let mut end = MAX_PARAMETER_LEN;
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        value.truncate(end);
+        value.push_str("...");
+    }
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Can `query` be given to `EXPLAIN` without running anything?
+fn is_explainable(query: &str) -> bool {
+    // a second statement after the `EXPLAIN`ed one would actually be run
+    if query.trim_end().trim_end_matches(';').contains(';') {
+        return false;
+    }
+    let keyword = query.trim_start().split(|c: char| !c.is_ascii_alphabetic()).next();
+    matches!(
+        keyword.map(str::to_ascii_uppercase).as_deref(),
+        Some("SELECT" | "INSERT" | "UPDATE" | "DELETE" | "MERGE" | "VALUES" | "TABLE" | "WITH")
+    )
+}
+
+/// The plan of `query`, from `EXPLAIN`
+unsafe fn explain(query: &str, args: &[(pg_sys::Oid, Option<pg_sys::Datum>)]) -> Option<String> {
+    if !is_explainable(query) {
+        return None;
+    }
+
+    let explain = CString::new(format!("EXPLAIN {query}")).ok()?;
+    let mut types = args.iter().map(|(oid, _)| *oid).collect::<Vec<_>>();
+    let (mut datums, nulls): (Vec<_>, Vec<_>) =
+        args.iter().map(|(_, datum)| super::prepare_datum(*datum)).unzip();
+    let status = pg_sys::SPI_execute_with_args(
+        explain.as_ptr(),
+        args.len() as i32,
+        types.as_mut_ptr(),
+        datums.as_mut_ptr(),
+        nulls.as_ptr(),
+        Spi::is_xact_still_immutable(),
+        0,
+    );
+
+    let tuptable = pg_sys::SPI_tuptable;
+    if Spi::check_status(status).is_err() || tuptable.is_null() {
+        return None;
+    }
+    let lines = (0..pg_sys::SPI_processed as usize)
+        .filter_map(|i| {
+            let line = pg_sys::SPI_getvalue(*(*tuptable).vals.add(i), (*tuptable).tupdesc, 1);
+            (!line.is_null()).then(|| CStr::from_ptr(line).to_string_lossy().into_owned())
+        })
+        .collect::<Vec<_>>();
+    pg_sys::SPI_freetuptable(tuptable);
+    pg_sys::SPI_tuptable = std::ptr::null_mut();
+    Some(lines.join("\n"))
+}
@@ -10,6 +10,7 @@
 #![doc(hidden)]
 //! Helper implementations for returning sets and tables from `#[pg_extern]`-style functions
 use crate::iter::{SetOfIterator, TableIterator};
+use crate::tuple_writer::TupleWriter;
 use crate::{
     pg_return_null, pg_sys, srf_first_call_init, srf_is_first_call, srf_per_call_setup,
     srf_return_done, srf_return_next, IntoDatum, IntoHeapTuple, PgMemoryContexts,
@@ -104,7 +105,7 @@ impl<'a, T: IntoHeapTuple> TableIterator<'a, T> {
                     (table_iterator, tupdesc, (*funcctx).multi_call_memory_ctx)
                 });
 
-            let table_iterator = match table_iterator {
+            let table_state = match table_iterator {
                 // user's function returned None, so there's nothing for us to later iterate
                 None => {
                     srf_return_done(fcinfo, funcctx);
@@ -112,25 +113,33 @@ impl<'a, T: IntoHeapTuple> TableIterator<'a, T> {
                 }
 
                 // user's function returned Some(TableIterator), so we need to leak it into the
-                // memory context Postgres has decided is to be used for multi-call SRF functions
-                Some(iter) => PgMemoryContexts::For(memcxt).leak_and_drop_on_delete(iter),
+                // memory context Postgres has decided is to be used for multi-call SRF functions,
+                // along with the writer that forms each row, reusing the memory of the last one
+                Some(iter) => {
+                    let writer = TupleWriter::new(tupdesc, memcxt);
+                    PgMemoryContexts::For(memcxt).leak_and_drop_on_delete((iter, writer))
+                }
             };
 
             // it's the first call so we need to finish setting up `funcctx`
             (*funcctx).tuple_desc = tupdesc;
-            (*funcctx).user_fctx = table_iterator.cast();
+            (*funcctx).user_fctx = table_state.cast();
         }
 
         let funcctx = srf_per_call_setup(fcinfo);
 
         // SAFETY: we created `funcctx.user_fctx` on the first call into this function so
         // we know it's valid
-        let table_iterator =
-            (*funcctx).user_fctx.cast::<TableIterator<T>>().as_mut().unwrap_unchecked();
+        let (table_iterator, writer) = (*funcctx)
+            .user_fctx
+            .cast::<(TableIterator<T>, TupleWriter)>()
+            .as_mut()
+            .unwrap_unchecked();
 
         match table_iterator.next() {
             Some(tuple) => {
-                let heap_tuple = tuple.into_heap_tuple((*funcctx).tuple_desc);
+                // the executor is done with the previous row by the time it asks for this one
+                let heap_tuple = tuple.into_tuple_writer(writer);
                 srf_return_next(fcinfo, funcctx);
                 pg_sys::HeapTupleHeaderGetDatum((*heap_tuple).t_data)
             }
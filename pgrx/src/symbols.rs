@@ -0,0 +1,117 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Functions that may or may not exist in the running server, resolved when they're first used
+//!
+//! Linking to a function the server doesn't have fails when the library is loaded, so one that
+//! only some minor releases or builds of a major version have can be declared as an
+//! [`OptionalSymbol`] instead, and the extension can do without it where it's missing:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::symbols::OptionalSymbol;
+//!
+//! static PG_STAT_EXTRA: OptionalSymbol<unsafe extern "C" fn(pg_sys::Oid) -> i64> =
+//!     unsafe { OptionalSymbol::new("pg_stat_extra") };
+//!
+//! fn stat_extra(relid: pg_sys::Oid) -> Option<i64> {
+//!     PG_STAT_EXTRA.get().map(|func| unsafe { func(relid) })
+//! }
+//! ```
+use crate::pg_sys;
+use once_cell::sync::OnceCell;
+use std::ffi::{c_void, CString};
+use std::marker::PhantomData;
+
+/// A function, of type `F`, looked up by name with Postgres' own lookup when first used
+pub struct OptionalSymbol<F: Copy> {
+    library: Option<&'static str>,
+    name: &'static str,
+    address: OnceCell<usize>,
+    _function: PhantomData<F>,
+}
+
+// it only holds the address, the same for every thread
+unsafe impl<F: Copy> Sync for OptionalSymbol<F> {}
+unsafe impl<F: Copy> Send for OptionalSymbol<F> {}
+
+impl<F: Copy> OptionalSymbol<F> {
+    /// The function `name` in the server itself
+    ///
+    /// # Safety
+    ///
+    /// `F` must be a function pointer type matching the function's signature, where it exists.
+    pub const unsafe fn new(name: &'static str) -> Self {
+        Self { library: None, name, address: OnceCell::new(), _function: PhantomData }
+    }
+
+    /// The function `name` in the shared library `library`, named as for `LOAD`, such as
+    /// `"$libdir/plpgsql"`.  The library is loaded, if it isn't already, when the function is
+    /// first looked up, and not being able to load it is an `ERROR`.
+    ///
+    /// # Safety
+    ///
+    /// `F` must be a function pointer type matching the function's signature, where it exists.
+    pub const unsafe fn in_library(library: &'static str, name: &'static str) -> Self {
+        Self { library: Some(library), name, address: OnceCell::new(), _function: PhantomData }
+    }
+
+    /// The function, or `None` if the server doesn't have it
+    pub fn get(&self) -> Option<F> {
+        assert_eq!(
+            std::mem::size_of::<F>(),
+            std::mem::size_of::<usize>(),
+            "an `OptionalSymbol` must be a function pointer"
+        );
+        let address = *self.address.get_or_init(|| self.lookup() as usize);
+        (address != 0).then(|| unsafe { std::mem::transmute_copy::<usize, F>(&address) })
+    }
+
+    /// Does the server have the function?
+    pub fn is_available(&self) -> bool {
+        self.get().is_some()
+    }
+
+    fn lookup(&self) -> *mut c_void {
+        let name = CString::new(self.name).expect("symbol name contains a NUL byte");
+        unsafe {
+            match self.library {
+                Some(library) => {
+                    let library = CString::new(library).expect("library name contains a NUL byte");
+                    symbol_ptr(pg_sys::load_external_function(
+                        library.as_ptr(),
+                        name.as_ptr(),
+                        false,
+                        std::ptr::null_mut(),
+                    ))
+                }
+                None => {
+                    // the server's own symbols, through the handle of the main program
+                    let server = libc::dlopen(std::ptr::null(), libc::RTLD_NOW);
+                    if server.is_null() {
+                        return std::ptr::null_mut();
+                    }
+                    symbol_ptr(pg_sys::lookup_external_function(server, name.as_ptr()))
+                }
+            }
+        }
+    }
+}
+
+/// Before Postgres 14, `load_external_function` and `lookup_external_function` return the symbol
+/// as a `PGFunction`
+#[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+fn symbol_ptr(symbol: pg_sys::PGFunction) -> *mut c_void {
+    symbol.map_or(std::ptr::null_mut(), |symbol| symbol as *mut c_void)
+}
+
+#[cfg(any(feature = "pg14", feature = "pg15", feature = "pg16"))]
+fn symbol_ptr(symbol: *mut c_void) -> *mut c_void {
+    symbol
+}
@@ -0,0 +1,227 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Temporary files backed by Postgres' `BufFile` API
+//!
+//! Unlike using [`std::fs`] directly, a [`TempFile`] is created in one of the `temp_tablespaces`,
+//! counts against `temp_file_limit`, shows up in `log_temp_files`, and is automatically removed by
+//! Postgres when the owning transaction ends (or the backend exits), even if the extension errors.
+//!
+//! Files larger than 1GB are transparently split into multiple segments.
+use crate::callbacks::{register_xact_callback, PgXactCallbackEvent, XactCallbackReceipt};
+use crate::{pg_sys, PgMemoryContexts};
+use std::cell::Cell;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::panic::AssertUnwindSafe;
+use std::rc::Rc;
+
+/// The size of each physical segment of a `BufFile`, `MAX_PHYSICAL_FILESIZE` in `buffile.c`
+const SEGMENT_SIZE: u64 = 0x40000000;
+
+/// The events that end a transaction, and with it a [`TempFile::new`] file
+const XACT_END_EVENTS: [PgXactCallbackEvent; 5] = [
+    PgXactCallbackEvent::Commit,
+    PgXactCallbackEvent::Abort,
+    PgXactCallbackEvent::ParallelCommit,
+    PgXactCallbackEvent::ParallelAbort,
+    PgXactCallbackEvent::Prepare,
+];
+
+/// A temporary file implementing [`Read`], [`Write`], and [`Seek`]
+///
+/// ```rust,no_run
+/// use pgrx::TempFile;
+/// use std::io::{Read, Seek, SeekFrom, Write};
+///
+/// let mut file = TempFile::new();
+/// file.write_all(b"spilled to disk").unwrap();
+/// file.seek(SeekFrom::Start(0)).unwrap();
+///
+/// let mut contents = String::new();
+/// file.read_to_string(&mut contents).unwrap();
+/// assert_eq!(contents, "spilled to disk");
+/// ```
+pub struct TempFile {
+    /// Set to null once Postgres has deleted the file, at the end of its transaction
+    file: Rc<Cell<*mut pg_sys::BufFile>>,
+    pos: u64,
+    len: u64,
+    /// The callbacks that notice the end of the file's transaction, if it has one
+    receipts: Vec<XactCallbackReceipt>,
+}
+
+impl TempFile {
+    /// Create a new temporary file that's deleted when the current transaction ends
+    ///
+    /// Postgres frees the underlying `BufFile` then, even if this `TempFile` is kept around, such
+    /// as in a `static`.  After that, reading, writing, and seeking return an error.  A file created
+    /// in a subtransaction is also closed if that subtransaction aborts, and can't be used after.
+    pub fn new() -> Self {
+        let mut file = unsafe {
+            // SAFETY: the `BufFile` lives as long as the transaction, and so does its memory
+            PgMemoryContexts::TopTransactionContext.switch_to(|_| TempFile::create(false))
+        };
+        file.receipts = XACT_END_EVENTS
+            .iter()
+            .map(|&event| {
+                let file = AssertUnwindSafe(Rc::clone(&file.file));
+                register_xact_callback(event, move || file.set(std::ptr::null_mut()))
+            })
+            .collect();
+        file
+    }
+
+    /// Create a new temporary file that survives the end of the current transaction.  It's deleted
+    /// when dropped or when the backend exits.
+    ///
+    /// The underlying `BufFile` is allocated in the `TopMemoryContext`.
+    pub fn new_inter_xact() -> Self {
+        unsafe {
+            // SAFETY: the `TopMemoryContext` lives as long as the backend
+            PgMemoryContexts::TopMemoryContext.switch_to(|_| TempFile::create(true))
+        }
+    }
+
+    fn create(inter_xact: bool) -> Self {
+        let file = unsafe {
+            // makes sure the file is created in one of the `temp_tablespaces`
+            pg_sys::PrepareTempTablespaces();
+            pg_sys::BufFileCreateTemp(inter_xact)
+        };
+        TempFile { file: Rc::new(Cell::new(file)), pos: 0, len: 0, receipts: Vec::new() }
+    }
+
+    /// The `BufFile`, unless its transaction has ended
+    fn buf_file(&self) -> std::io::Result<*mut pg_sys::BufFile> {
+        let file = self.file.get();
+        if file.is_null() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "the temporary file was deleted at the end of its transaction",
+            ));
+        }
+        Ok(file)
+    }
+
+    /// The number of bytes written to the file
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Is the file empty?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The current read/write position
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Get the raw `BufFile` pointer, which is null once the file's transaction has ended
+    pub fn as_ptr(&self) -> *mut pg_sys::BufFile {
+        self.file.get()
+    }
+}
+
+impl Default for TempFile {
+    fn default() -> Self {
+        TempFile::new()
+    }
+}
+
+impl Read for TempFile {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let file = self.buf_file()?;
+        let n = unsafe { pg_sys::BufFileRead(file, buf.as_mut_ptr().cast(), buf.len()) };
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Write for TempFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let file = self.buf_file()?;
+        // before pg14 short writes were reported through the return value rather than an ERROR
+        #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+        let n = unsafe { pg_sys::BufFileWrite(file, buf.as_ptr().cast_mut().cast(), buf.len()) };
+        #[cfg(any(feature = "pg14", feature = "pg15"))]
+        let n = unsafe {
+            pg_sys::BufFileWrite(file, buf.as_ptr().cast_mut().cast(), buf.len());
+            buf.len()
+        };
+        #[cfg(feature = "pg16")]
+        let n = unsafe {
+            pg_sys::BufFileWrite(file, buf.as_ptr().cast(), buf.len());
+            buf.len()
+        };
+
+        if n != buf.len() {
+            return Err(std::io::Error::last_os_error());
+        }
+        self.pos += n as u64;
+        self.len = self.len.max(self.pos);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // BufFile flushes its buffer as needed, and a temp file has no durability guarantees
+        Ok(())
+    }
+}
+
+impl Seek for TempFile {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        let file = self.buf_file()?;
+        let fileno = (new_pos / SEGMENT_SIZE) as i32;
+        let offset = (new_pos % SEGMENT_SIZE) as pg_sys::off_t;
+        let result = unsafe { pg_sys::BufFileSeek(file, fileno, offset, pg_sys::SEEK_SET as _) };
+        if result != 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "could not seek in temporary file",
+            ));
+        }
+
+        self.pos = new_pos;
+        Ok(new_pos)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        // if we're unwinding then the transaction is going to abort and Postgres will clean up
+        // the file itself, which also avoids the risk of raising another ERROR mid-panic
+        if std::thread::panicking() {
+            return;
+        }
+        for receipt in self.receipts.drain(..) {
+            receipt.unregister_callback();
+        }
+        // and once the transaction has ended, it already has
+        let file = self.file.replace(std::ptr::null_mut());
+        if !file.is_null() {
+            unsafe {
+                pg_sys::BufFileClose(file);
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Load Rust values into a temporary table, to join against them in SQL
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//!
+//! #[derive(TempTableRow)]
+//! struct Score {
+//!     id: i64,
+//!     score: f64,
+//! }
+//!
+//! let scores = (1..=1000).map(|id| Score { id, score: (id as f64).sqrt() });
+//! let scores = Spi::create_temp_table(scores).unwrap();
+//! let best = Spi::get_one::<String>(&format!(
+//!     "SELECT name FROM products JOIN {} s USING (id) ORDER BY s.score DESC LIMIT 1",
+//!     scores.qualified_name()
+//! ));
+//! // the table is dropped here
+//! ```
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::ddl::{Column, CreateTableBuilder};
+use crate::pg_sys;
+use crate::quote::{quote_ident, quote_qualified_ident};
+use crate::spi::{self, Spi};
+use crate::PgOid;
+
+pub use pgrx_macros::TempTableRow;
+
+/// A Rust type whose values can be the rows of a [`TempTable`], usually derived with
+/// [`#[derive(TempTableRow)]`](derive@TempTableRow)
+pub trait TempTableRow {
+    /// The table's columns, in the order [`into_datums()`](TempTableRow::into_datums) returns
+    /// their values
+    fn columns() -> Vec<Column>;
+
+    /// This row's values, allocated in the `CurrentMemoryContext`
+    fn into_datums(self) -> Vec<Option<pg_sys::Datum>>;
+}
+
+/// Distinguishes the temporary tables of one session
+static NEXT_TABLE: AtomicU64 = AtomicU64::new(1);
+
+/// A temporary table holding `T`s, dropped when this is
+///
+/// Temporary tables are private to the session, in the `pg_temp` schema.  Use
+/// [`qualified_name()`](TempTable::qualified_name) to refer to it in queries.
+pub struct TempTable<T: TempTableRow> {
+    name: String,
+    columns: Vec<Column>,
+    __marker: PhantomData<fn(T)>,
+}
+
+impl Spi {
+    /// Create a temporary table whose columns are the fields of `T`, insert `rows` into it, and
+    /// `ANALYZE` it so the planner knows what it holds
+    pub fn create_temp_table<T: TempTableRow>(
+        rows: impl IntoIterator<Item = T>,
+    ) -> Result<TempTable<T>, spi::Error> {
+        let name = format!("pgrx_temp_{}", NEXT_TABLE.fetch_add(1, Ordering::Relaxed));
+        let columns = T::columns();
+        columns
+            .iter()
+            .cloned()
+            .fold(CreateTableBuilder::new(&name).schema("pg_temp").temporary(), |table, column| {
+                table.column(column)
+            })
+            .execute()?;
+
+        let table = TempTable { name, columns, __marker: PhantomData };
+        table.insert(rows)?;
+        Spi::run(&format!("ANALYZE {}", table.qualified_name()))?;
+        Ok(table)
+    }
+}
+
+impl<T: TempTableRow> TempTable<T> {
+    /// The table's name, unquoted
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The table's name, quoted and qualified with `pg_temp`, to interpolate into a query
+    pub fn qualified_name(&self) -> String {
+        quote_qualified_ident("pg_temp", &self.name)
+    }
+
+    /// Insert more rows, returning how many were inserted
+    pub fn insert(&self, rows: impl IntoIterator<Item = T>) -> Result<u64, spi::Error> {
+        let columns: Vec<_> =
+            self.columns.iter().map(|column| quote_ident(column.name())).collect();
+        let params: Vec<_> = (1..=self.columns.len()).map(|i| format!("${i}")).collect();
+        let query = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.qualified_name(),
+            columns.join(", "),
+            params.join(", ")
+        );
+        let types = self.columns.iter().map(|column| PgOid::from(column.type_oid())).collect();
+
+        Spi::connect(|mut client| {
+            let statement = client.prepare(&query, Some(types))?;
+            let mut inserted = 0;
+            for row in rows {
+                client.update(&statement, None, Some(row.into_datums()))?;
+                inserted += 1;
+            }
+            Ok(inserted)
+        })
+    }
+}
+
+impl<T: TempTableRow> Drop for TempTable<T> {
+    fn drop(&mut self) {
+        // if we're unwinding then the transaction is aborting, which drops the table if it was
+        // created in it.  Otherwise it lasts until the end of the session
+        if !std::thread::panicking() {
+            Spi::run(&format!("DROP TABLE IF EXISTS {}", self.qualified_name()))
+                .expect("failed to drop temporary table");
+        }
+    }
+}
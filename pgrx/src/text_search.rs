@@ -0,0 +1,242 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Text search dictionaries and parsers implemented in Rust
+//!
+//! A [`TextSearchDictionary`] normalizes the tokens of a document into the lexemes that are
+//! indexed and searched for, and a [`TextSearchParser`] splits a document into tokens.  Annotate
+//! an implementation with [`#[pg_text_search]`](macro@crate::pg_text_search) to create the
+//! corresponding `TEXT SEARCH` objects, which can then be used in a `TEXT SEARCH CONFIGURATION`.
+//!
+//! ```rust,ignore
+//! use pgrx::prelude::*;
+//! use pgrx::text_search::{Lexeme, TextSearchDictionary};
+//!
+//! struct Reverse;
+//!
+//! #[pg_text_search]
+//! impl TextSearchDictionary for Reverse {
+//!     fn init(_options: Vec<(String, String)>) -> Self {
+//!         Reverse
+//!     }
+//!
+//!     fn lexize(&self, token: &str) -> Option<Vec<Lexeme>> {
+//!         Some(vec![Lexeme::new(token.chars().rev().collect::<String>())])
+//!     }
+//! }
+//!
+//! extension_sql!(
+//!     r#"
+//!     CREATE TEXT SEARCH CONFIGURATION reversed (COPY = simple);
+//!     ALTER TEXT SEARCH CONFIGURATION reversed ALTER MAPPING FOR asciiword WITH reverse;
+//!     "#,
+//!     name = "reversed_configuration",
+//!     requires = ["reverse_text_search_dictionary"]
+//! );
+//! ```
+use crate::datum::Internal;
+use crate::list::PgList;
+use crate::{pg_sys, PgMemoryContexts};
+use core::ffi::CStr;
+
+/// A text search dictionary, which turns tokens into lexemes
+pub trait TextSearchDictionary: Sized {
+    /// Create the dictionary, from the options given to `CREATE TEXT SEARCH DICTIONARY` or
+    /// `ALTER TEXT SEARCH DICTIONARY`, as `(name, value)` pairs
+    ///
+    /// Dictionaries are created once per backend, the first time they're used, and kept until
+    /// they're altered or dropped.
+    fn init(options: Vec<(String, String)>) -> Self;
+
+    /// Normalize `token`.  Return `None` if the dictionary doesn't recognize the token, so it's
+    /// passed on to the next dictionary in the configuration, or an empty `Vec` if the token is a
+    /// stop word that shouldn't be indexed
+    fn lexize(&self, token: &str) -> Option<Vec<Lexeme>>;
+}
+
+/// A normalized form of a token, as produced by a [`TextSearchDictionary`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lexeme {
+    pub lexeme: String,
+    /// Lexemes with the same variant number are alternative normalizations that must all
+    /// match, as for the parts of a compound word.  Lexemes with different variant numbers are
+    /// alternatives of which any may match
+    pub variant: u16,
+    /// `TSL_*` flags, such as `pg_sys::TSL_PREFIX`
+    pub flags: u16,
+}
+
+impl Lexeme {
+    pub fn new(lexeme: impl Into<String>) -> Self {
+        Lexeme { lexeme: lexeme.into(), variant: 0, flags: 0 }
+    }
+
+    /// This lexeme with the given variant number
+    pub fn variant(mut self, variant: u16) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+impl From<&str> for Lexeme {
+    fn from(lexeme: &str) -> Self {
+        Lexeme::new(lexeme)
+    }
+}
+
+impl From<String> for Lexeme {
+    fn from(lexeme: String) -> Self {
+        Lexeme::new(lexeme)
+    }
+}
+
+/// A text search parser, which splits a document into tokens of different types
+pub trait TextSearchParser {
+    /// The types of tokens the parser produces, as `(id, alias, description)`.  Ids must be
+    /// greater than zero.  The alias is how the token type is named in
+    /// `ALTER TEXT SEARCH CONFIGURATION ... ADD MAPPING FOR`
+    const TOKEN_TYPES: &'static [(i32, &'static str, &'static str)];
+
+    /// Split `text` into `(token type id, token)` pairs.  Every token must be a slice of `text`,
+    /// and they must be in order
+    fn tokenize(text: &str) -> Vec<(i32, &str)>;
+}
+
+/// Make a `palloc`'d copy of `s` as a C string
+fn pstrdup(s: &str) -> *mut std::os::raw::c_char {
+    unsafe { pg_sys::pnstrdup(s.as_ptr().cast(), s.len() as _) }
+}
+
+/// Interpret the `len` bytes at `ptr` as a string.  Text search strings are in the database
+/// encoding, which must be UTF-8 for them to be given to Rust
+unsafe fn as_str<'a>(ptr: *const std::os::raw::c_char, len: usize) -> &'a str {
+    let bytes = if ptr.is_null() { &[] } else { std::slice::from_raw_parts(ptr.cast(), len) };
+    std::str::from_utf8(bytes).expect("text search input is not valid UTF-8")
+}
+
+#[doc(hidden)]
+pub unsafe fn init<T: TextSearchDictionary>(options: Internal) -> Internal {
+    let options = options.unwrap().map_or(std::ptr::null_mut(), |datum| datum.cast_mut_ptr());
+    let options = PgList::<pg_sys::DefElem>::from_pg(options)
+        .iter_ptr()
+        .map(|def| {
+            let name = CStr::from_ptr((*def).defname).to_string_lossy().into_owned();
+            let value = CStr::from_ptr(pg_sys::defGetString(def)).to_string_lossy().into_owned();
+            (name, value)
+        })
+        .collect();
+
+    // we're called in the dictionary's own memory context, which lives as long as it does
+    let dictionary =
+        PgMemoryContexts::CurrentMemoryContext.leak_and_drop_on_delete(T::init(options));
+    Internal::from(Some(pg_sys::Datum::from(dictionary)))
+}
+
+#[doc(hidden)]
+pub unsafe fn lexize<T: TextSearchDictionary>(
+    dictionary: Internal,
+    token: Internal,
+    len: Internal,
+    _state: Internal,
+) -> Internal {
+    let dictionary = dictionary.get::<T>().expect("no dictionary");
+    // these arguments are really a `char *` and an `int`
+    let len = len.unwrap().map_or(0, |datum| datum.value() as i32);
+    let token = as_str(
+        token.unwrap().map_or(std::ptr::null(), |datum| datum.cast_mut_ptr()),
+        len.max(0) as usize,
+    );
+
+    let lexemes = match dictionary.lexize(token) {
+        // a NULL pointer means the token wasn't recognized
+        None => return Internal::from(Some(pg_sys::Datum::from(0))),
+        Some(lexemes) => lexemes,
+    };
+
+    // an array terminated by an entry without a lexeme, so an empty one means "stop word"
+    let array = pg_sys::palloc0(std::mem::size_of::<pg_sys::TSLexeme>() * (lexemes.len() + 1))
+        .cast::<pg_sys::TSLexeme>();
+    for (i, lexeme) in lexemes.iter().enumerate() {
+        let entry = &mut *array.add(i);
+        entry.lexeme = pstrdup(&lexeme.lexeme);
+        entry.nvariant = lexeme.variant;
+        entry.flags = lexeme.flags;
+    }
+    Internal::from(Some(pg_sys::Datum::from(array)))
+}
+
+/// The tokens of the document being parsed, which are handed out one at a time
+struct ParserState {
+    text: *mut std::os::raw::c_char,
+    tokens: std::vec::IntoIter<(i32, usize, usize)>,
+}
+
+#[doc(hidden)]
+pub unsafe fn start<T: TextSearchParser>(text: Internal, len: Internal) -> Internal {
+    // these arguments are really a `char *` and an `int`
+    let text_ptr = text.unwrap().map_or(std::ptr::null_mut(), |datum| datum.cast_mut_ptr());
+    let len = len.unwrap().map_or(0, |datum| datum.value() as i32);
+    let text = as_str(text_ptr, len.max(0) as usize);
+
+    let range = text.as_bytes().as_ptr_range();
+    let tokens = T::tokenize(text)
+        .into_iter()
+        .map(|(token_type, token)| {
+            assert!(
+                range.contains(&token.as_ptr()) || token.is_empty(),
+                "text search tokens must be slices of the parsed text"
+            );
+            (token_type, token.as_ptr() as usize - range.start as usize, token.len())
+        })
+        .collect::<Vec<_>>();
+
+    let state = PgMemoryContexts::CurrentMemoryContext
+        .leak_and_drop_on_delete(ParserState { text: text_ptr, tokens: tokens.into_iter() });
+    Internal::from(Some(pg_sys::Datum::from(state)))
+}
+
+#[doc(hidden)]
+pub unsafe fn gettoken<T: TextSearchParser>(
+    state: Internal,
+    token: Internal,
+    len: Internal,
+) -> i32 {
+    let state = state.get_mut::<ParserState>().expect("no text search parser state");
+    let token = token.get_mut::<*mut std::os::raw::c_char>().expect("no token pointer");
+    let len = len.get_mut::<std::os::raw::c_int>().expect("no token length");
+    match state.tokens.next() {
+        Some((token_type, offset, token_len)) => {
+            *token = state.text.add(offset);
+            *len = token_len as _;
+            token_type
+        }
+        // zero means there are no more tokens
+        None => 0,
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn end<T: TextSearchParser>(_state: Internal) {
+    // the state is released along with the memory context it was allocated in
+}
+
+#[doc(hidden)]
+pub unsafe fn lextypes<T: TextSearchParser>(_unused: Internal) -> Internal {
+    // an array terminated by an entry with a zero `lexid`
+    let array =
+        pg_sys::palloc0(std::mem::size_of::<pg_sys::LexDescr>() * (T::TOKEN_TYPES.len() + 1))
+            .cast::<pg_sys::LexDescr>();
+    for (i, (id, alias, description)) in T::TOKEN_TYPES.iter().enumerate() {
+        let entry = &mut *array.add(i);
+        entry.lexid = *id;
+        entry.alias = pstrdup(alias);
+        entry.descr = pstrdup(description);
+    }
+    Internal::from(Some(pg_sys::Datum::from(array)))
+}
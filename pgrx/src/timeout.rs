@@ -0,0 +1,191 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Bounding how long a piece of Rust code may run.
+//!
+//! Code that never reaches a `check_for_interrupts!()` -- a long computation in a third-party
+//! library, say -- can't be stopped by `statement_timeout` or a query cancel.  [`with_timeout`]
+//! runs such code on its own thread and waits for it on the backend thread, giving up once a
+//! timeout registered with Postgres' timeout machinery (the same one behind `statement_timeout`
+//! and `lock_timeout`) fires.
+//!
+//! ```rust,no_run
+//! use pgrx::timeout::with_timeout;
+//! use std::time::Duration;
+//!
+//! let answer = with_timeout(Duration::from_secs(5), || {
+//!     // pure Rust only -- this runs on another thread and must not call into Postgres
+//!     (1..=20u64).product::<u64>()
+//! });
+//! match answer {
+//!     Ok(answer) => pgrx::info!("computed {answer}"),
+//!     Err(e) => pgrx::warning!("{e}"),
+//! }
+//! ```
+//!
+//! A thread can't be killed, so when the timeout fires the closure's thread is abandoned and keeps
+//! running until the closure returns on its own.  Its result is then dropped.  So that a closure
+//! that never returns can't pile up threads call after call, each backend has at most one abandoned
+//! thread: while it's still running, [`with_timeout`] refuses to start another and returns
+//! [`TimeoutError::StillRunning`] instead.
+//!
+//! There is one such timeout per backend, so calls to [`with_timeout`] can't be nested.
+use crate::backend_channel::{BackendChannel, RecvTimeoutError};
+use crate::pg_sys;
+use once_cell::sync::OnceCell;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The error returned by [`with_timeout`] when the closure didn't get to finish
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimeoutError {
+    /// The closure didn't finish within the timeout, and its thread was abandoned
+    #[error("timed out after {0:?}")]
+    TimedOut(Duration),
+
+    /// The closure wasn't run, because the thread abandoned by an earlier timed-out call is still
+    /// running
+    #[error("the thread of an earlier with_timeout() call that timed out is still running")]
+    StillRunning,
+}
+
+static TIMEOUT_ID: OnceCell<pg_sys::TimeoutId> = OnceCell::new();
+static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+/// Is a [`with_timeout`] call waiting?  Nested calls would share, and so lose, its timeout.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+/// The thread of the last call that timed out, until it's seen to have finished
+static ABANDONED: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// Run `f` on a new thread, returning its result if it finishes within `timeout`.
+///
+/// While waiting, the backend still services interrupts, so a query cancel or
+/// `statement_timeout` raises its usual `ERROR`.  A panic in `f` is resumed on the backend
+/// thread.
+///
+/// `f` runs off the backend thread and so must never call into Postgres.
+///
+/// If `f` doesn't finish in time its thread is abandoned, and until that thread finishes, further
+/// calls don't run their closure and return [`TimeoutError::StillRunning`].
+///
+/// # Panics
+///
+/// If the thread can't be spawned, or if called while another `with_timeout` is running, including
+/// from its closure.
+pub fn with_timeout<R, F>(timeout: Duration, f: F) -> Result<R, TimeoutError>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    if ACTIVE.swap(true, Ordering::SeqCst) {
+        panic!("with_timeout() can't be nested");
+    }
+    let _active = Active;
+
+    {
+        let mut abandoned = abandoned();
+        match abandoned.take() {
+            Some(thread) if !thread.is_finished() => {
+                *abandoned = Some(thread);
+                return Err(TimeoutError::StillRunning);
+            }
+            // it caught any panic of its closure, so there's nothing to report
+            Some(thread) => drop(thread.join()),
+            None => {}
+        }
+    }
+
+    let channel = BackendChannel::<Result<R, Box<dyn Any + Send>>>::new();
+    let sender = channel.sender();
+    let thread = spawn_without_signals(move || {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+        // if the backend already gave up on us, nobody's listening anymore
+        sender.send(result).ok();
+    });
+    // the thread counts as abandoned until its result arrives, so that a query cancel unwinding
+    // out of the wait below leaves it tracked too
+    *abandoned() = Some(thread);
+
+    let id = *TIMEOUT_ID.get_or_init(|| unsafe {
+        pg_sys::RegisterTimeout(pg_sys::TimeoutId_USER_TIMEOUT, Some(on_timeout))
+    });
+    TIMED_OUT.store(false, Ordering::SeqCst);
+    let delay_ms = timeout.as_millis().clamp(1, i32::MAX as u128) as i32;
+    unsafe {
+        pg_sys::enable_timeout_after(id, delay_ms);
+    }
+
+    // disable the timeout however we leave, including by unwinding from a query cancel
+    let _disable = DisableTimeout(id);
+    match channel.recv_until(|| TIMED_OUT.load(Ordering::SeqCst)) {
+        Ok(result) => {
+            // having sent its result, all that's left for the thread is to exit
+            if let Some(thread) = abandoned().take() {
+                drop(thread.join());
+            }
+            match result {
+                Ok(result) => Ok(result),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+        Err(RecvTimeoutError::Timeout) => Err(TimeoutError::TimedOut(timeout)),
+        Err(RecvTimeoutError::Disconnected) => {
+            unreachable!("with_timeout()'s thread exited without sending its result")
+        }
+    }
+}
+
+fn abandoned() -> MutexGuard<'static, Option<JoinHandle<()>>> {
+    // only the backend thread ever takes the lock, so it can't really be poisoned
+    ABANDONED.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+struct Active;
+
+impl Drop for Active {
+    fn drop(&mut self) {
+        ACTIVE.store(false, Ordering::SeqCst);
+    }
+}
+
+struct DisableTimeout(pg_sys::TimeoutId);
+
+impl Drop for DisableTimeout {
+    fn drop(&mut self) {
+        unsafe {
+            pg_sys::disable_timeout(self.0, false);
+        }
+    }
+}
+
+/// Runs in Postgres' `SIGALRM` handler, on the backend thread
+unsafe extern "C" fn on_timeout() {
+    TIMED_OUT.store(true, Ordering::SeqCst);
+    pg_sys::SetLatch(pg_sys::MyLatch);
+}
+
+/// Spawn a thread with every signal blocked.
+///
+/// Postgres' timeouts are delivered with process-directed `SIGALRM`s, which the kernel may hand to
+/// any thread that doesn't block them -- and Postgres' signal handlers must only ever run on the
+/// backend thread.  The new thread inherits the mask in effect when it's spawned.
+fn spawn_without_signals(f: impl FnOnce() + Send + 'static) -> JoinHandle<()> {
+    unsafe {
+        let mut all: libc::sigset_t = std::mem::zeroed();
+        let mut previous: libc::sigset_t = std::mem::zeroed();
+        libc::sigfillset(&mut all);
+        libc::pthread_sigmask(libc::SIG_SETMASK, &all, &mut previous);
+        let spawned = std::thread::Builder::new().name("pgrx with_timeout".into()).spawn(f);
+        libc::pthread_sigmask(libc::SIG_SETMASK, &previous, std::ptr::null_mut());
+        spawned.expect("failed to spawn a thread for with_timeout()")
+    }
+}
@@ -8,10 +8,8 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 //! Provides a safe wrapper around Postgres' `pg_sys::TupleDescData` struct
-use crate::{pg_sys, regtypein, void_mut_ptr, PgBox, PgRelation};
+use crate::{pg_sys, void_mut_ptr, PgBox, PgRelation, TypeOid};
 
-use pgrx_pg_sys::errcodes::PgSqlErrorCode;
-use pgrx_pg_sys::PgTryBuilder;
 use std::ops::Deref;
 
 /// This struct is passed around within the backend to describe the structure
@@ -154,6 +152,8 @@ impl<'a> PgTupleDesc<'a> {
 
     /** Retrieve the tuple description of the shape of a defined composite type
 
+    The type's name is only resolved once per session, until a type or schema changes.
+
     ```rust,no_run
     use pgrx::{prelude::*, PgTupleDesc};
 
@@ -175,11 +175,7 @@ impl<'a> PgTupleDesc<'a> {
     ```
     */
     pub fn for_composite_type(name: &str) -> Option<PgTupleDesc<'a>> {
-        let typoid = PgTryBuilder::new(|| Some(regtypein(name)))
-            .catch_when(PgSqlErrorCode::ERRCODE_UNDEFINED_OBJECT, |_| None)
-            .execute()?;
-
-        Self::for_composite_type_by_oid(typoid)
+        Self::for_composite_type_by_oid(composite_type_oid(name)?)
     }
 
     /// Similar to [`PgTupleDesc::for_composite_type()`] but using the type's [`pg_sys::Oid`] instead
@@ -212,6 +208,26 @@ impl<'a> PgTupleDesc<'a> {
         self.tupdesc.as_ref().unwrap().tdtypmod
     }
 
+    /// Has this anonymous `RECORD` descriptor been registered with the type cache, or does it describe
+    /// a named composite type?
+    ///
+    /// Composite datums only carry their type's oid and typmod, so an unregistered `RECORD` can't
+    /// be decoded again once it has been returned to Postgres: "record type has not been registered".
+    pub fn is_blessed(&self) -> bool {
+        self.oid() != pg_sys::RECORDOID || self.typmod() >= 0
+    }
+
+    /// Register this anonymous `RECORD` descriptor with the type cache, like Postgres' `BlessTupleDesc()`,
+    /// so composites built with it can be returned to Postgres.  This does nothing for a descriptor
+    /// that [is already blessed](PgTupleDesc::is_blessed).
+    pub fn bless(&mut self) {
+        if !self.is_blessed() {
+            unsafe {
+                pg_sys::BlessTupleDesc(self.as_ptr());
+            }
+        }
+    }
+
     /// How many attributes do we have?
     pub fn len(&self) -> usize {
         self.tupdesc.as_ref().unwrap().natts as usize
@@ -348,3 +364,12 @@ impl<'a> Iterator for TupleDescDataIntoIterator<'a> {
         Some(result)
     }
 }
+
+/// Resolve the (possibly schema-qualified) `name` of a type to its oid, once per session
+///
+/// Parsing the name and searching the catalogs for it is by far the most expensive part of
+/// building a composite with [`PgHeapTuple::new_composite_type()`](crate::PgHeapTuple::new_composite_type),
+/// which functions returning composites tend to do for every row.
+pub(crate) fn composite_type_oid(name: &str) -> Option<pg_sys::Oid> {
+    TypeOid::named(name).map(TypeOid::oid)
+}
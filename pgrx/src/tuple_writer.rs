@@ -0,0 +1,134 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Forming many tuples of the same shape, one after another, without allocating anew for each
+//!
+//! A [`TupleWriter`] keeps the `Datum` and null buffers for its tuple descriptor, and forms each
+//! tuple in a memory context of its own that's reset when the next one is started, so a
+//! set-returning function's memory stays the same size however many rows it returns.  This is what
+//! [`TableIterator`](crate::iter::TableIterator) uses, and it can be used directly for rows that
+//! aren't Rust tuples, like ones wider than 32 columns:
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::tuple_writer::TupleWriter;
+//!
+//! unsafe fn wide_row(writer: &mut TupleWriter, n: i64) -> *mut pg_sys::HeapTupleData {
+//!     writer.reset();
+//!     for i in 0..writer.len() {
+//!         writer.set(i, n * i as i64);
+//!     }
+//!     writer.form_tuple()
+//! }
+//! ```
+use crate::{pg_sys, IntoDatum, PgMemoryContexts};
+
+/// Forms tuples for one tuple descriptor, reusing its buffers and memory for each
+pub struct TupleWriter {
+    tupdesc: pg_sys::TupleDesc,
+    datums: Vec<pg_sys::Datum>,
+    nulls: Vec<bool>,
+    arena: pg_sys::MemoryContext,
+}
+
+impl TupleWriter {
+    /// Create a writer for tuples described by `tupdesc`, with its memory in a new child of
+    /// `parent`, such as the `multi_call_memory_ctx` of a set-returning function
+    ///
+    /// # Safety
+    ///
+    /// `tupdesc` must be valid for as long as the writer is used, and the writer must not be used
+    /// after `parent` is reset or deleted, which frees its memory along with `parent`'s.
+    pub unsafe fn new(tupdesc: pg_sys::TupleDesc, parent: pg_sys::MemoryContext) -> Self {
+        let natts = (*tupdesc).natts as usize;
+        let arena = pg_sys::AllocSetContextCreateExtended(
+            parent,
+            b"TupleWriter\0".as_ptr().cast(),
+            pg_sys::ALLOCSET_DEFAULT_MINSIZE as usize,
+            pg_sys::ALLOCSET_DEFAULT_INITSIZE as usize,
+            pg_sys::ALLOCSET_DEFAULT_MAXSIZE as usize,
+        );
+        TupleWriter {
+            tupdesc,
+            datums: vec![pg_sys::Datum::from(0); natts],
+            nulls: vec![true; natts],
+            arena,
+        }
+    }
+
+    /// The tuple descriptor this writer forms tuples for
+    pub fn tupdesc(&self) -> pg_sys::TupleDesc {
+        self.tupdesc
+    }
+
+    /// The memory context tuples are formed in, which is reset by [`TupleWriter::reset()`]
+    pub fn memory_context(&self) -> pg_sys::MemoryContext {
+        self.arena
+    }
+
+    /// The number of attributes in each tuple
+    pub fn len(&self) -> usize {
+        self.datums.len()
+    }
+
+    /// Does each tuple have no attributes?
+    pub fn is_empty(&self) -> bool {
+        self.datums.is_empty()
+    }
+
+    /// Start a new tuple, with every attribute `NULL`.  This frees the last tuple the writer
+    /// formed, and everything allocated for it.
+    pub fn reset(&mut self) {
+        unsafe {
+            // SAFETY:  `new()`'s caller promised the writer isn't used after the arena is gone
+            pg_sys::MemoryContextReset(self.arena);
+        }
+        self.nulls.fill(true);
+    }
+
+    /// Set the attribute at `index`, starting from zero, to `value`, converting it in the
+    /// writer's memory
+    ///
+    /// # Panics
+    ///
+    /// If `index` is past the last attribute
+    pub fn set<T: IntoDatum>(&mut self, index: usize, value: T) {
+        assert!(index < self.len(), "attribute {index} is out of bounds");
+        let datum = unsafe { PgMemoryContexts::For(self.arena).switch_to(|_| value.into_datum()) };
+        self.set_datum(index, datum);
+    }
+
+    /// Set the attribute at `index`, starting from zero, to `datum`, or `NULL` if it's `None`
+    ///
+    /// # Panics
+    ///
+    /// If `index` is past the last attribute
+    pub fn set_datum(&mut self, index: usize, datum: Option<pg_sys::Datum>) {
+        match datum {
+            Some(datum) => {
+                self.datums[index] = datum;
+                self.nulls[index] = false;
+            }
+            None => self.nulls[index] = true,
+        }
+    }
+
+    /// Form the tuple from the attributes set since the last [`TupleWriter::reset()`].  It's valid
+    /// until the next one.
+    ///
+    /// # Safety
+    ///
+    /// Each attribute that was set must be of the type its tuple descriptor says.
+    pub unsafe fn form_tuple(&mut self) -> *mut pg_sys::HeapTupleData {
+        let (tupdesc, datums, nulls) =
+            (self.tupdesc, self.datums.as_mut_ptr(), self.nulls.as_mut_ptr());
+        PgMemoryContexts::For(self.arena)
+            .switch_to(|_| pg_sys::heap_form_tuple(tupdesc, datums, nulls))
+    }
+}
@@ -0,0 +1,209 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Sorting values with Postgres' `tuplesort`, which spills to disk once `work_mem` is exceeded
+//!
+//! Values are ordered using the default btree operator class of their SQL type, exactly as
+//! `ORDER BY` would order them.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::Tuplesort;
+//!
+//! #[pg_extern]
+//! fn sorted_lengths(words: Vec<String>) -> Vec<Option<i32>> {
+//!     let mut sort = Tuplesort::<i32>::new();
+//!     for word in words {
+//!         sort.push(Some(word.len() as i32));
+//!     }
+//!     sort.sort().collect()
+//! }
+//! ```
+use crate::{pg_sys, FromDatum, IntoDatum};
+use std::marker::PhantomData;
+
+/// Sorts values of type `T`, spilling to temporary files when they don't fit in `work_mem`
+///
+/// Pass-by-reference values are copied into the sort's own memory context as they're pushed, and
+/// everything is released when the `Tuplesort` (or the iterator returned by [`Tuplesort::sort`])
+/// is dropped.
+pub struct Tuplesort<T> {
+    state: *mut pg_sys::Tuplesortstate,
+    __marker: PhantomData<T>,
+}
+
+impl<T: IntoDatum + FromDatum> Tuplesort<T> {
+    /// Sort in ascending order, `NULLS LAST`, using up to `work_mem` of memory
+    pub fn new() -> Self {
+        Tuplesort::builder().build()
+    }
+
+    /// Configure the sort order and memory limit before creating the [`Tuplesort`]
+    pub fn builder() -> TuplesortBuilder<T> {
+        TuplesortBuilder {
+            descending: false,
+            nulls_first: None,
+            work_mem: None,
+            __marker: PhantomData,
+        }
+    }
+
+    /// Add a value to the sort
+    pub fn push(&mut self, value: Option<T>) {
+        let datum = value.and_then(|value| value.into_datum());
+        unsafe {
+            pg_sys::tuplesort_putdatum(self.state, datum.unwrap_or(0.into()), datum.is_none());
+        }
+    }
+
+    /// Perform the sort and return an iterator over the sorted values
+    pub fn sort(self) -> TuplesortIter<T> {
+        unsafe {
+            pg_sys::tuplesort_performsort(self.state);
+        }
+        TuplesortIter { sort: self }
+    }
+}
+
+impl<T: IntoDatum + FromDatum> Default for Tuplesort<T> {
+    fn default() -> Self {
+        Tuplesort::new()
+    }
+}
+
+impl<T: IntoDatum + FromDatum> Extend<Option<T>> for Tuplesort<T> {
+    fn extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl<T> Drop for Tuplesort<T> {
+    fn drop(&mut self) {
+        // if we're unwinding then the transaction is going to abort and Postgres will release the
+        // sort's memory and temp files itself
+        if !std::thread::panicking() {
+            unsafe {
+                pg_sys::tuplesort_end(self.state);
+            }
+        }
+    }
+}
+
+/// Configures a [`Tuplesort`]
+pub struct TuplesortBuilder<T> {
+    descending: bool,
+    nulls_first: Option<bool>,
+    work_mem: Option<i32>,
+    __marker: PhantomData<T>,
+}
+
+impl<T: IntoDatum + FromDatum> TuplesortBuilder<T> {
+    /// Sort in descending order.  Like `ORDER BY ... DESC`, this also defaults to `NULLS FIRST`
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    /// Should `NULL` values sort before all other values?
+    pub fn nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = Some(nulls_first);
+        self
+    }
+
+    /// The amount of memory, in kilobytes, to use before spilling to disk.  Defaults to `work_mem`
+    pub fn work_mem(mut self, kbytes: i32) -> Self {
+        self.work_mem = Some(kbytes);
+        self
+    }
+
+    /// Create the [`Tuplesort`]
+    ///
+    /// ## Panics
+    ///
+    /// If the SQL type of `T` has no default btree ordering
+    pub fn build(self) -> Tuplesort<T> {
+        let typoid = T::type_oid();
+        let (sort_operator, collation) = unsafe {
+            let flags =
+                if self.descending { pg_sys::TYPECACHE_GT_OPR } else { pg_sys::TYPECACHE_LT_OPR };
+            let typentry = pg_sys::lookup_type_cache(typoid, flags as _);
+            let sort_operator =
+                if self.descending { (*typentry).gt_opr } else { (*typentry).lt_opr };
+            (sort_operator, pg_sys::get_typcollation(typoid))
+        };
+        if sort_operator == pg_sys::InvalidOid {
+            panic!("type oid `{typoid}` has no default ordering");
+        }
+
+        let nulls_first = self.nulls_first.unwrap_or(self.descending);
+        let work_mem = self.work_mem.unwrap_or_else(|| unsafe { pg_sys::work_mem });
+
+        #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13", feature = "pg14"))]
+        let sortopt = false;
+        #[cfg(any(feature = "pg15", feature = "pg16"))]
+        let sortopt = pg_sys::TUPLESORT_NONE as i32;
+
+        // the sort creates its own memory context as a child of the current one
+        let state = unsafe {
+            pg_sys::tuplesort_begin_datum(
+                typoid,
+                sort_operator,
+                collation,
+                nulls_first,
+                work_mem,
+                std::ptr::null_mut(),
+                sortopt,
+            )
+        };
+
+        Tuplesort { state, __marker: PhantomData }
+    }
+}
+
+/// Yields the values of a [`Tuplesort`] in sorted order
+pub struct TuplesortIter<T> {
+    sort: Tuplesort<T>,
+}
+
+impl<T: IntoDatum + FromDatum> Iterator for TuplesortIter<T> {
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut datum = pg_sys::Datum::from(0);
+        let mut is_null = false;
+        unsafe {
+            // pass-by-reference values are copied into the current memory context.  Before pg16
+            // that always happened, but now it must be asked for, otherwise they'd only be valid
+            // until the next call
+            #[cfg(not(feature = "pg16"))]
+            let found = pg_sys::tuplesort_getdatum(
+                self.sort.state,
+                true,
+                &mut datum,
+                &mut is_null,
+                std::ptr::null_mut(),
+            );
+            #[cfg(feature = "pg16")]
+            let found = pg_sys::tuplesort_getdatum(
+                self.sort.state,
+                true,
+                true,
+                &mut datum,
+                &mut is_null,
+                std::ptr::null_mut(),
+            );
+
+            if found {
+                Some(T::from_polymorphic_datum(datum, is_null, T::type_oid()))
+            } else {
+                None
+            }
+        }
+    }
+}
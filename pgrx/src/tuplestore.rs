@@ -0,0 +1,183 @@
+//LICENSE Portions Copyright 2019-2021 ZomboDB, LLC.
+//LICENSE
+//LICENSE Portions Copyright 2021-2023 Technology Concepts & Design, Inc.
+//LICENSE
+//LICENSE Portions Copyright 2023-2023 PgCentral Foundation, Inc. <contact@pgcentral.org>
+//LICENSE
+//LICENSE All rights reserved.
+//LICENSE
+//LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+//! Storing tuples with Postgres' `tuplestore`, which spills to disk once `work_mem` is exceeded
+//!
+//! This is the same structure Postgres uses to materialize the results of set-returning functions,
+//! CTEs, and cursors.  Tuples can be appended and then read back, in order, any number of times.
+//!
+//! ```rust,no_run
+//! use pgrx::prelude::*;
+//! use pgrx::{PgTupleDesc, Tuplestore};
+//!
+//! let tupdesc = PgTupleDesc::for_composite_type("dog").unwrap();
+//! let mut store = Tuplestore::new(tupdesc);
+//! for age in 0..1_000_000i32 {
+//!     unsafe { store.put_datums(["Brandy".into_datum(), age.into_datum()]) };
+//! }
+//!
+//! let total: i64 = store.iter().map(|dog| dog.get_by_name::<i32>("age").unwrap().unwrap() as i64).sum();
+//! ```
+use crate::heap_tuple::PgHeapTuple;
+use crate::{pg_sys, AllocatedByRust, PgTupleDesc, WhoAllocated};
+
+/// An append-only store of tuples sharing a single [`PgTupleDesc`], spilling to temporary files
+/// when they don't fit in memory
+///
+/// The store allocates its memory in the `CurrentMemoryContext` at the time it's created and is
+/// released when dropped.
+pub struct Tuplestore<'a> {
+    state: *mut pg_sys::Tuplestorestate,
+    slot: *mut pg_sys::TupleTableSlot,
+    tupdesc: PgTupleDesc<'a>,
+}
+
+impl<'a> Tuplestore<'a> {
+    /// Create a new, empty, store for tuples described by `tupdesc`, using up to `work_mem` of memory
+    pub fn new(tupdesc: PgTupleDesc<'a>) -> Self {
+        Tuplestore::with_work_mem(tupdesc, unsafe { pg_sys::work_mem })
+    }
+
+    /// Create a new, empty, store for tuples described by `tupdesc`, using up to `kbytes` kilobytes
+    /// of memory before spilling to disk
+    pub fn with_work_mem(tupdesc: PgTupleDesc<'a>, kbytes: i32) -> Self {
+        unsafe {
+            let state = pg_sys::tuplestore_begin_heap(false, false, kbytes);
+
+            #[cfg(feature = "pg11")]
+            let slot = pg_sys::MakeSingleTupleTableSlot(tupdesc.as_ptr());
+            #[cfg(not(feature = "pg11"))]
+            let slot =
+                pg_sys::MakeSingleTupleTableSlot(tupdesc.as_ptr(), &pg_sys::TTSOpsMinimalTuple);
+
+            Tuplestore { state, slot, tupdesc }
+        }
+    }
+
+    /// The [`PgTupleDesc`] describing the tuples in this store
+    pub fn tupdesc(&self) -> &PgTupleDesc<'a> {
+        &self.tupdesc
+    }
+
+    /// Append a copy of `tuple` to the store
+    ///
+    /// ## Safety
+    ///
+    /// The tuple must be described by this store's [`PgTupleDesc`]
+    pub unsafe fn put_tuple<AllocatedBy: WhoAllocated>(
+        &mut self,
+        tuple: &PgHeapTuple<'_, AllocatedBy>,
+    ) {
+        pg_sys::tuplestore_puttuple(self.state, tuple.as_ptr());
+    }
+
+    /// Append a tuple made from `datums`, one per attribute
+    ///
+    /// ## Panics
+    ///
+    /// If the number of datums doesn't match the number of attributes in this store's [`PgTupleDesc`]
+    ///
+    /// ## Safety
+    ///
+    /// The datums must be valid for the types of the corresponding attributes
+    pub unsafe fn put_datums<I: IntoIterator<Item = Option<pg_sys::Datum>>>(&mut self, datums: I) {
+        let (mut datums, mut nulls): (Vec<_>, Vec<_>) =
+            datums.into_iter().map(|datum| (datum.unwrap_or(0.into()), datum.is_none())).unzip();
+        assert_eq!(datums.len(), self.tupdesc.len(), "incorrect number of datums for tuple");
+
+        pg_sys::tuplestore_putvalues(
+            self.state,
+            self.tupdesc.as_ptr(),
+            datums.as_mut_ptr(),
+            nulls.as_mut_ptr(),
+        );
+    }
+
+    /// The number of tuples in the store
+    pub fn len(&self) -> usize {
+        unsafe { pg_sys::tuplestore_tuple_count(self.state) as usize }
+    }
+
+    /// Is the store empty?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over copies of every tuple in the store, from the beginning
+    pub fn iter(&mut self) -> TuplestoreIter<'_, 'a> {
+        unsafe {
+            pg_sys::tuplestore_rescan(self.state);
+        }
+        TuplestoreIter { store: self }
+    }
+
+    /// Get the raw `Tuplestorestate` pointer
+    pub fn as_ptr(&self) -> *mut pg_sys::Tuplestorestate {
+        self.state
+    }
+
+    /// Consume this [`Tuplestore`] and return the raw `Tuplestorestate` pointer, which will no longer
+    /// be freed when dropped.  This is how a store can be handed to Postgres as the result of a
+    /// set-returning function in `SFRM_Materialize` mode.
+    pub fn into_pg(self) -> *mut pg_sys::Tuplestorestate {
+        let state = self.state;
+        unsafe {
+            pg_sys::ExecDropSingleTupleTableSlot(self.slot);
+        }
+        std::mem::forget(self);
+        state
+    }
+}
+
+impl<'a> Drop for Tuplestore<'a> {
+    fn drop(&mut self) {
+        // if we're unwinding then the transaction is going to abort and Postgres will release the
+        // store's memory and temp files itself
+        if !std::thread::panicking() {
+            unsafe {
+                pg_sys::ExecDropSingleTupleTableSlot(self.slot);
+                pg_sys::tuplestore_end(self.state);
+            }
+        }
+    }
+}
+
+/// Yields copies of the tuples in a [`Tuplestore`], in the order they were added
+pub struct TuplestoreIter<'s, 'a> {
+    store: &'s mut Tuplestore<'a>,
+}
+
+impl<'s, 'a> Iterator for TuplestoreIter<'s, 'a> {
+    type Item = PgHeapTuple<'a, AllocatedByRust>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            if !pg_sys::tuplestore_gettupleslot(self.store.state, true, false, self.store.slot) {
+                return None;
+            }
+
+            // the slot holds a "minimal tuple", which needs to be turned into a full heap tuple
+            #[cfg(feature = "pg11")]
+            let tuple = pg_sys::heap_copytuple(pg_sys::ExecFetchSlotTuple(self.store.slot));
+            #[cfg(not(feature = "pg11"))]
+            let tuple = {
+                let mut should_free = false;
+                let tuple =
+                    pg_sys::ExecFetchSlotHeapTuple(self.store.slot, false, &mut should_free);
+                if should_free {
+                    tuple
+                } else {
+                    pg_sys::heap_copytuple(tuple)
+                }
+            };
+
+            Some(PgHeapTuple::from_owned_heap_tuple(self.store.tupdesc.clone(), tuple))
+        }
+    }
+}
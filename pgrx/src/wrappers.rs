@@ -8,7 +8,7 @@
 //LICENSE
 //LICENSE Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 //! Provides safe wrapper functions around some of Postgres' useful functions.
-use crate::{direct_function_call, pg_sys, IntoDatum};
+use crate::{direct_function_call, ereport, pg_sys, IntoDatum, PgSqlErrorCode, TypeOid};
 
 /// A helper function for Postgres' `regtypein` function to lookup a type by a specific name
 ///
@@ -26,7 +26,8 @@ pub fn regtypein(type_name: &str) -> pg_sys::Oid {
 ///
 /// We truncate the type name to its last value, unless its a primitive type.
 ///
-/// Returns the `oid` of the specified type name.  Will panic if Postgres can't find the type
+/// Returns the `oid` of the specified type name, which is looked up once per session with
+/// [`TypeOid::named()`].  Raises an ERROR if Postgres can't find the type
 pub fn rust_regtypein<T>() -> pg_sys::Oid {
     let type_name = std::any::type_name::<T>();
 
@@ -37,5 +38,18 @@ pub fn rust_regtypein<T>() -> pg_sys::Oid {
     };
 
     let type_name = &type_name[idx..];
-    regtypein(type_name)
+    type_oid_or_error(type_name)
+}
+
+/// The oid of the type `type_name`, looked up once per session, raising the same ERROR as
+/// `regtypein` if there's no such type
+pub(crate) fn type_oid_or_error(type_name: &str) -> pg_sys::Oid {
+    let Some(typoid) = TypeOid::named(type_name) else {
+        ereport!(
+            ERROR,
+            PgSqlErrorCode::ERRCODE_UNDEFINED_OBJECT,
+            format!("type \"{type_name}\" does not exist")
+        );
+    };
+    typoid.oid()
 }